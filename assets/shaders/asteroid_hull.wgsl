@@ -0,0 +1,56 @@
+// Instanced asteroid hulls: every hull with the same vertex count shares one
+// canonical fan mesh whose UV x is the vertex index.  Positions and colours
+// are pulled from the slot named by the entity's `MeshTag`.  Slot data comes
+// from `HullInstance` in src/asteroid_rendering/instancing.rs.
+
+#import bevy_sprite::{
+    mesh2d_functions::{get_tag, get_world_from_local, mesh2d_position_local_to_clip},
+    mesh2d_view_bindings::view,
+}
+
+#ifdef TONEMAP_IN_SHADER
+#import bevy_core_pipeline::tonemapping
+#endif
+
+const MAX_HULL_VERTICES: u32 = 32u;
+
+struct HullInstance {
+    vertices: array<vec2<f32>, MAX_HULL_VERTICES>,
+    color: vec4<f32>,
+}
+
+@group(#{MATERIAL_BIND_GROUP}) @binding(0) var<storage, read> hulls: array<HullInstance>;
+
+struct Vertex {
+    @builtin(instance_index) instance_index: u32,
+    @location(0) position: vec3<f32>,
+    @location(2) uv: vec2<f32>,
+}
+
+struct VertexOutput {
+    @builtin(position) position: vec4<f32>,
+    @location(0) color: vec4<f32>,
+}
+
+@vertex
+fn vertex(vertex: Vertex) -> VertexOutput {
+    let slot = get_tag(vertex.instance_index);
+    let local = hulls[slot].vertices[u32(vertex.uv.x)];
+
+    var out: VertexOutput;
+    out.position = mesh2d_position_local_to_clip(
+        get_world_from_local(vertex.instance_index),
+        vec4<f32>(local, 0.0, 1.0),
+    );
+    out.color = hulls[slot].color;
+    return out;
+}
+
+@fragment
+fn fragment(in: VertexOutput) -> @location(0) vec4<f32> {
+    var color = vec4<f32>(in.color.rgb, 1.0);
+#ifdef TONEMAP_IN_SHADER
+    color = tonemapping::tone_mapping(color, view.color_grading);
+#endif
+    return color;
+}
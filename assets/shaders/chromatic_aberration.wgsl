@@ -0,0 +1,24 @@
+// Chromatic aberration: pulls the red and blue channels apart towards the
+// screen edges.  Strength comes from `ChromaticAberration` in
+// src/graphics/feedback.rs.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+struct ChromaticAberration {
+    // Channel offset at the screen edge, as a fraction of the screen.
+    strength: f32,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+@group(0) @binding(2) var<uniform> aberration: ChromaticAberration;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    // Zero at the centre, `strength` at the edges.
+    let offset = (in.uv - vec2<f32>(0.5)) * 2.0 * aberration.strength;
+    let centre = textureSample(screen_texture, screen_sampler, in.uv);
+    let red = textureSample(screen_texture, screen_sampler, in.uv + offset).r;
+    let blue = textureSample(screen_texture, screen_sampler, in.uv - offset).b;
+    return vec4<f32>(red, centre.g, blue, centre.a);
+}
@@ -0,0 +1,42 @@
+// Gravity lensing: offsets each pixel's lookup towards nearby massive bodies.
+// Lens data comes from `GravityLensing` in src/lensing.rs.
+
+#import bevy_core_pipeline::fullscreen_vertex_shader::FullscreenVertexOutput
+
+const MAX_LENSES: u32 = 8u;
+
+struct GravityLensing {
+    // xy: viewport UV centre, z: lens radius (viewport heights),
+    // w: peak offset as a fraction of the radius.
+    lenses: array<vec4<f32>, MAX_LENSES>,
+    count: u32,
+    aspect: f32,
+    core_fraction: f32,
+}
+
+@group(0) @binding(0) var screen_texture: texture_2d<f32>;
+@group(0) @binding(1) var screen_sampler: sampler;
+@group(0) @binding(2) var<uniform> lensing: GravityLensing;
+
+@fragment
+fn fragment(in: FullscreenVertexOutput) -> @location(0) vec4<f32> {
+    // Work in viewport-height units so lenses stay round on wide screens.
+    let scale = vec2<f32>(lensing.aspect, 1.0);
+    var offset = vec2<f32>(0.0);
+    for (var i = 0u; i < min(lensing.count, MAX_LENSES); i++) {
+        let lens = lensing.lenses[i];
+        let d = (in.uv - lens.xy) * scale;
+        let dist = length(d);
+        let radius = lens.z;
+        if dist <= 0.0 || dist >= radius {
+            continue;
+        }
+        // Quadratic falloff to the lens edge, faded in just outside the body
+        // so the body itself is not smeared.
+        let falloff = 1.0 - dist / radius;
+        let core = radius * lensing.core_fraction;
+        let rim = smoothstep(core, core * 1.4, dist);
+        offset += (d / dist) * lens.w * radius * falloff * falloff * rim;
+    }
+    return textureSample(screen_texture, screen_sampler, in.uv - offset / scale);
+}
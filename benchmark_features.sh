@@ -39,13 +39,14 @@ max_gravity_dist = 1000.0
 velocity_threshold_locking = 5.0
 hull_extent_base = 60.0
 hull_extent_per_member = 20.0
-restitution_small = 0.0
+restitution_max = 0.0
 friction_asteroid = 1.0
 tidal_torque_scale = 0.0
 cull_distance = 1000.0
 soft_boundary_radius = 900.0
 soft_boundary_strength = 0.0
-hard_cull_distance = 1250.0
+sector_stream_out_radius = 1250.0
+sector_stream_in_radius = 1100.0
 EOF
 
 ACCRETION_TEST=baseline_100 cargo run --release 2>&1 | tail -30
@@ -66,13 +67,14 @@ max_gravity_dist = 1000.0
 velocity_threshold_locking = 5.0
 hull_extent_base = 60.0
 hull_extent_per_member = 20.0
-restitution_small = 0.0
+restitution_max = 0.0
 friction_asteroid = 1.0
 tidal_torque_scale = 1.0
 cull_distance = 1000.0
 soft_boundary_radius = 900.0
 soft_boundary_strength = 0.0
-hard_cull_distance = 1250.0
+sector_stream_out_radius = 1250.0
+sector_stream_in_radius = 1100.0
 EOF
 
 ACCRETION_TEST=tidal_only cargo run --release 2>&1 | tail -30
@@ -93,13 +95,14 @@ max_gravity_dist = 1000.0
 velocity_threshold_locking = 5.0
 hull_extent_base = 60.0
 hull_extent_per_member = 20.0
-restitution_small = 0.0
+restitution_max = 0.0
 friction_asteroid = 1.0
 tidal_torque_scale = 0.0
 cull_distance = 1000.0
 soft_boundary_radius = 900.0
 soft_boundary_strength = 2.0
-hard_cull_distance = 1250.0
+sector_stream_out_radius = 1250.0
+sector_stream_in_radius = 1100.0
 EOF
 
 ACCRETION_TEST=soft_boundary_only cargo run --release 2>&1 | tail -30
@@ -121,13 +124,14 @@ max_gravity_dist = 1000.0
 velocity_threshold_locking = 5.0
 hull_extent_base = 60.0
 hull_extent_per_member = 20.0
-restitution_small = 0.0
+restitution_max = 0.0
 friction_asteroid = 1.0
 tidal_torque_scale = 0.0
 cull_distance = 1000.0
 soft_boundary_radius = 900.0
 soft_boundary_strength = 0.0
-hard_cull_distance = 1250.0
+sector_stream_out_radius = 1250.0
+sector_stream_in_radius = 1100.0
 EOF
 
 ACCRETION_TEST=kdtree_only cargo run --release 2>&1 | tail -30
@@ -148,13 +152,14 @@ max_gravity_dist = 1000.0
 velocity_threshold_locking = 5.0
 hull_extent_base = 60.0
 hull_extent_per_member = 20.0
-restitution_small = 0.0
+restitution_max = 0.0
 friction_asteroid = 1.0
 tidal_torque_scale = 1.0
 cull_distance = 1000.0
 soft_boundary_radius = 900.0
 soft_boundary_strength = 2.0
-hard_cull_distance = 1250.0
+sector_stream_out_radius = 1250.0
+sector_stream_in_radius = 1100.0
 EOF
 
 ACCRETION_TEST=all_three cargo run --release 2>&1 | tail -30
@@ -0,0 +1,61 @@
+//! Run a parameter sweep spec and write the results table.
+//!
+//! ```text
+//! cargo run --release --example sweep -- assets/sweeps/gravity.toml sweep.csv
+//! ```
+//!
+//! Without an output path the table is printed to stdout.
+
+use accretion::sweep::{run_sweep, sweep_csv, SweepSpec};
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    let mut args = std::env::args().skip(1);
+    let Some(spec_path) = args.next() else {
+        eprintln!("usage: sweep <spec.toml> [out.csv]");
+        return ExitCode::FAILURE;
+    };
+    let out_path = args.next();
+
+    let spec = match std::fs::read_to_string(&spec_path)
+        .map_err(|err| format!("failed reading {spec_path}: {err}"))
+        .and_then(|contents| SweepSpec::parse(&contents))
+    {
+        Ok(spec) => spec,
+        Err(err) => {
+            eprintln!("✗ {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let rows = run_sweep(&spec, |index, total, row| {
+        eprintln!(
+            "  [{}/{}] {:?} → {} bodies, largest {}",
+            index + 1,
+            total,
+            row.values,
+            row.report.bodies,
+            row.report.largest_mass
+        );
+    });
+    let rows = match rows {
+        Ok(rows) => rows,
+        Err(err) => {
+            eprintln!("✗ {err}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let table = sweep_csv(&spec.axes, &rows);
+    match out_path {
+        Some(path) => {
+            if let Err(err) = std::fs::write(&path, table) {
+                eprintln!("✗ failed writing {path}: {err}");
+                return ExitCode::FAILURE;
+            }
+            println!("✓ {} runs written to {path}", rows.len());
+        }
+        None => print!("{table}"),
+    }
+    ExitCode::SUCCESS
+}
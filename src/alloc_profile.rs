@@ -1,5 +1,22 @@
+//! Counting global allocator, per-system allocation tags, and report export.
+//!
+//! Profiling is off unless `ACCRETION_ALLOC_PROFILE=1`.  When enabled the
+//! allocator keeps process-wide counters and, for code running inside an
+//! [`AllocTag`] scope, per-tag counters.  Tags are thread-local, so they
+//! attribute exactly the allocations a system makes on its own thread; work a
+//! system hands to other task-pool threads lands in the untagged bucket.
+//!
+//! [`AllocReportPlugin`] writes the counters to a CSV file named by
+//! `ACCRETION_ALLOC_REPORT`, every `ACCRETION_ALLOC_REPORT_INTERVAL` seconds
+//! (real time) and once more on exit.
+
+use bevy::app::AppExit;
+use bevy::prelude::*;
 use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write as _;
 use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
 
 pub struct CountingAlloc;
 
@@ -12,10 +29,24 @@ static ALLOC_CALLS: AtomicU64 = AtomicU64::new(0);
 static DEALLOC_CALLS: AtomicU64 = AtomicU64::new(0);
 static REALLOC_CALLS: AtomicU64 = AtomicU64::new(0);
 
+/// Tag slots, including slot 0 for untagged allocations.
+const MAX_ALLOC_TAGS: usize = 32;
+
+static TAG_ALLOC_BYTES: [AtomicU64; MAX_ALLOC_TAGS] = [const { AtomicU64::new(0) }; MAX_ALLOC_TAGS];
+static TAG_DEALLOC_BYTES: [AtomicU64; MAX_ALLOC_TAGS] =
+    [const { AtomicU64::new(0) }; MAX_ALLOC_TAGS];
+static TAG_ALLOC_CALLS: [AtomicU64; MAX_ALLOC_TAGS] = [const { AtomicU64::new(0) }; MAX_ALLOC_TAGS];
+/// `(schedule, system)` for each registered tag; index `i` is slot `i + 1`.
+static TAG_NAMES: Mutex<Vec<(&'static str, &'static str)>> = Mutex::new(Vec::new());
+
+thread_local! {
+    static CURRENT_TAG: Cell<usize> = const { Cell::new(0) };
+}
+
 #[global_allocator]
 static GLOBAL: CountingAlloc = CountingAlloc;
 
-#[derive(Clone, Copy, Debug, Default)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct AllocProfileSnapshot {
     pub live_bytes: usize,
     pub peak_live_bytes: usize,
@@ -32,6 +63,12 @@ impl AllocProfileSnapshot {
     }
 }
 
+/// Tag slot active on this thread (0 when untagged or during TLS teardown).
+#[inline]
+fn current_tag() -> usize {
+    CURRENT_TAG.try_with(Cell::get).unwrap_or(0)
+}
+
 #[inline]
 fn update_peak(new_live: usize) {
     let mut peak = PEAK_BYTES.load(Ordering::Relaxed);
@@ -46,6 +83,9 @@ fn update_peak(new_live: usize) {
 
 #[inline]
 fn on_alloc(size: usize) {
+    let tag = current_tag();
+    TAG_ALLOC_BYTES[tag].fetch_add(size as u64, Ordering::Relaxed);
+    TAG_ALLOC_CALLS[tag].fetch_add(1, Ordering::Relaxed);
     TOTAL_ALLOC_BYTES.fetch_add(size as u64, Ordering::Relaxed);
     ALLOC_CALLS.fetch_add(1, Ordering::Relaxed);
     let new_live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
@@ -54,6 +94,7 @@ fn on_alloc(size: usize) {
 
 #[inline]
 fn on_dealloc(size: usize) {
+    TAG_DEALLOC_BYTES[current_tag()].fetch_add(size as u64, Ordering::Relaxed);
     TOTAL_DEALLOC_BYTES.fetch_add(size as u64, Ordering::Relaxed);
     DEALLOC_CALLS.fetch_add(1, Ordering::Relaxed);
     LIVE_BYTES.fetch_sub(size, Ordering::Relaxed);
@@ -62,13 +103,16 @@ fn on_dealloc(size: usize) {
 #[inline]
 fn on_realloc(old_size: usize, new_size: usize) {
     REALLOC_CALLS.fetch_add(1, Ordering::Relaxed);
+    let tag = current_tag();
     if new_size >= old_size {
         let delta = new_size - old_size;
+        TAG_ALLOC_BYTES[tag].fetch_add(delta as u64, Ordering::Relaxed);
         TOTAL_ALLOC_BYTES.fetch_add(delta as u64, Ordering::Relaxed);
         let new_live = LIVE_BYTES.fetch_add(delta, Ordering::Relaxed) + delta;
         update_peak(new_live);
     } else {
         let delta = old_size - new_size;
+        TAG_DEALLOC_BYTES[tag].fetch_add(delta as u64, Ordering::Relaxed);
         TOTAL_DEALLOC_BYTES.fetch_add(delta as u64, Ordering::Relaxed);
         LIVE_BYTES.fetch_sub(delta, Ordering::Relaxed);
     }
@@ -126,6 +170,11 @@ pub fn reset_counters() {
     ALLOC_CALLS.store(0, Ordering::Relaxed);
     DEALLOC_CALLS.store(0, Ordering::Relaxed);
     REALLOC_CALLS.store(0, Ordering::Relaxed);
+    for slot in 0..MAX_ALLOC_TAGS {
+        TAG_ALLOC_BYTES[slot].store(0, Ordering::Relaxed);
+        TAG_DEALLOC_BYTES[slot].store(0, Ordering::Relaxed);
+        TAG_ALLOC_CALLS[slot].store(0, Ordering::Relaxed);
+    }
 }
 
 pub fn snapshot() -> AllocProfileSnapshot {
@@ -139,3 +188,322 @@ pub fn snapshot() -> AllocProfileSnapshot {
         realloc_calls: REALLOC_CALLS.load(Ordering::Relaxed),
     }
 }
+
+// ── Allocation tags ───────────────────────────────────────────────────────────
+
+/// Static label attributing a system's allocations to `schedule` / `system`.
+///
+/// Declare one per instrumented system and hold the guard from
+/// [`AllocTag::enter`] for the body of the system:
+///
+/// ```ignore
+/// static ALLOC_TAG: AllocTag = AllocTag::new("FixedUpdate", "nbody_gravity_system");
+/// let _alloc_scope = ALLOC_TAG.enter();
+/// ```
+pub struct AllocTag {
+    schedule: &'static str,
+    system: &'static str,
+    slot: OnceLock<usize>,
+}
+
+impl AllocTag {
+    pub const fn new(schedule: &'static str, system: &'static str) -> Self {
+        Self {
+            schedule,
+            system,
+            slot: OnceLock::new(),
+        }
+    }
+
+    /// Attribute this thread's allocations to the tag until the guard drops.
+    ///
+    /// A no-op while profiling is disabled.
+    pub fn enter(&'static self) -> AllocScope {
+        if !is_enabled() {
+            return AllocScope { previous: None };
+        }
+        let slot = *self
+            .slot
+            .get_or_init(|| register_tag(self.schedule, self.system));
+        let previous = CURRENT_TAG.with(|tag| tag.replace(slot));
+        AllocScope {
+            previous: Some(previous),
+        }
+    }
+}
+
+/// Guard returned by [`AllocTag::enter`]; restores the previous tag on drop.
+pub struct AllocScope {
+    previous: Option<usize>,
+}
+
+impl Drop for AllocScope {
+    fn drop(&mut self) {
+        if let Some(previous) = self.previous {
+            CURRENT_TAG.with(|tag| tag.set(previous));
+        }
+    }
+}
+
+/// Assign a counter slot; tags past the slot limit share the untagged bucket.
+fn register_tag(schedule: &'static str, system: &'static str) -> usize {
+    let mut names = TAG_NAMES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(index) = names.iter().position(|&name| name == (schedule, system)) {
+        return index + 1;
+    }
+    if names.len() + 1 >= MAX_ALLOC_TAGS {
+        return 0;
+    }
+    names.push((schedule, system));
+    names.len()
+}
+
+/// Counters for one tag since the last [`reset_counters`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocTagSnapshot {
+    pub schedule: &'static str,
+    pub system: &'static str,
+    pub alloc_calls: u64,
+    pub alloc_bytes: u64,
+    pub dealloc_bytes: u64,
+}
+
+/// Per-tag counters, untagged bucket first, then tags in registration order.
+pub fn tag_snapshots() -> Vec<AllocTagSnapshot> {
+    let names = TAG_NAMES.lock().unwrap_or_else(|e| e.into_inner()).clone();
+    let read = |slot: usize, schedule, system| AllocTagSnapshot {
+        schedule,
+        system,
+        alloc_calls: TAG_ALLOC_CALLS[slot].load(Ordering::Relaxed),
+        alloc_bytes: TAG_ALLOC_BYTES[slot].load(Ordering::Relaxed),
+        dealloc_bytes: TAG_DEALLOC_BYTES[slot].load(Ordering::Relaxed),
+    };
+    std::iter::once(read(0, "*", "(untagged)"))
+        .chain(
+            names
+                .iter()
+                .enumerate()
+                .map(|(index, &(schedule, system))| read(index + 1, schedule, system)),
+        )
+        .collect()
+}
+
+// ── Report export ─────────────────────────────────────────────────────────────
+
+/// Header row of the allocation report CSV.
+pub const ALLOC_REPORT_CSV_HEADER: &str = "elapsed_secs,frame,schedule,system,alloc_calls,alloc_bytes,dealloc_bytes,net_bytes,interval_alloc_calls,interval_alloc_bytes";
+
+/// Where and how often [`AllocReportPlugin`] writes the report.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct AllocReportSettings {
+    pub path: String,
+    /// Seconds between periodic writes; `0` writes only on exit.
+    pub interval_secs: f32,
+}
+
+impl AllocReportSettings {
+    /// Read `ACCRETION_ALLOC_REPORT` (path) and `ACCRETION_ALLOC_REPORT_INTERVAL`
+    /// (seconds).  Returns `None` when profiling or the report is disabled.
+    pub fn from_env() -> Option<Self> {
+        if !is_enabled() {
+            return None;
+        }
+        let path = std::env::var("ACCRETION_ALLOC_REPORT").ok()?;
+        if path.trim().is_empty() {
+            return None;
+        }
+        let interval_secs = std::env::var("ACCRETION_ALLOC_REPORT_INTERVAL")
+            .ok()
+            .and_then(|value| value.parse::<f32>().ok())
+            .filter(|secs| secs.is_finite() && *secs >= 0.0)
+            .unwrap_or(0.0);
+        Some(Self {
+            path: crate::save::storage::tracked_output_path(
+                &crate::save::storage::telemetry_dir(),
+                &path,
+            ),
+            interval_secs,
+        })
+    }
+}
+
+/// Writer state: elapsed time, frame count, and last written counters.
+#[derive(Resource, Debug, Default)]
+pub struct AllocReportState {
+    pub elapsed_secs: f32,
+    pub since_write_secs: f32,
+    pub frame: u64,
+    /// The first write truncates the file and writes the header.
+    pub header_written: bool,
+    /// Counters from the previous write, keyed by `(schedule, system)`.
+    pub previous: Vec<AllocTagSnapshot>,
+}
+
+/// Format one report block: a `(total)` row followed by one row per tag.
+///
+/// `interval_*` columns are the change since the matching row in `previous`.
+pub fn format_alloc_report_rows(
+    elapsed_secs: f32,
+    frame: u64,
+    total: AllocProfileSnapshot,
+    tags: &[AllocTagSnapshot],
+    previous: &[AllocTagSnapshot],
+) -> String {
+    let total_row = AllocTagSnapshot {
+        schedule: "*",
+        system: "(total)",
+        alloc_calls: total.alloc_calls,
+        alloc_bytes: total.total_alloc_bytes,
+        dealloc_bytes: total.total_dealloc_bytes,
+    };
+    let mut out = String::new();
+    for row in std::iter::once(&total_row).chain(tags) {
+        let before = previous
+            .iter()
+            .find(|prev| prev.schedule == row.schedule && prev.system == row.system)
+            .copied()
+            .unwrap_or_default();
+        let _ = writeln!(
+            out,
+            "{elapsed_secs:.2},{frame},{},{},{},{},{},{},{},{}",
+            row.schedule,
+            row.system,
+            row.alloc_calls,
+            row.alloc_bytes,
+            row.dealloc_bytes,
+            row.alloc_bytes as i64 - row.dealloc_bytes as i64,
+            row.alloc_calls.saturating_sub(before.alloc_calls),
+            row.alloc_bytes.saturating_sub(before.alloc_bytes),
+        );
+    }
+    out
+}
+
+/// Append the current counters to the report file.
+fn write_alloc_report(settings: &AllocReportSettings, state: &mut AllocReportState) {
+    let total = snapshot();
+    let tags = tag_snapshots();
+    let mut body = String::new();
+    if !state.header_written {
+        body.push_str(ALLOC_REPORT_CSV_HEADER);
+        body.push('\n');
+    }
+    body.push_str(&format_alloc_report_rows(
+        state.elapsed_secs,
+        state.frame,
+        total,
+        &tags,
+        &state.previous,
+    ));
+
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(state.header_written)
+        .truncate(!state.header_written)
+        .open(&settings.path)
+        .and_then(|mut file| std::io::Write::write_all(&mut file, body.as_bytes()));
+    match result {
+        Ok(()) => {
+            state.header_written = true;
+            state.previous = std::iter::once(AllocTagSnapshot {
+                schedule: "*",
+                system: "(total)",
+                alloc_calls: total.alloc_calls,
+                alloc_bytes: total.total_alloc_bytes,
+                dealloc_bytes: total.total_dealloc_bytes,
+            })
+            .chain(tags)
+            .collect();
+        }
+        Err(err) => warn!("alloc report: failed to write {}: {err}", settings.path),
+    }
+}
+
+/// Write the report every `interval_secs` of real time and once on exit.
+///
+/// Runs in `Last` so the exit write sees the `AppExit` sent earlier in the
+/// frame, before the runner shuts the app down.
+pub fn alloc_report_system(
+    real_time: Res<Time<Real>>,
+    settings: Res<AllocReportSettings>,
+    mut state: ResMut<AllocReportState>,
+    mut exit: MessageReader<AppExit>,
+) {
+    let dt = real_time.delta_secs();
+    state.elapsed_secs += dt;
+    state.since_write_secs += dt;
+    state.frame += 1;
+
+    let exiting = exit.read().count() > 0;
+    let interval_due =
+        settings.interval_secs > 0.0 && state.since_write_secs >= settings.interval_secs;
+    if exiting || interval_due {
+        state.since_write_secs = 0.0;
+        write_alloc_report(&settings, &mut state);
+    }
+}
+
+/// Registers [`alloc_report_system`] when [`AllocReportSettings::from_env`]
+/// finds a report path; otherwise adds nothing.
+pub struct AllocReportPlugin;
+
+impl Plugin for AllocReportPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(settings) = AllocReportSettings::from_env() else {
+            return;
+        };
+        info!(
+            "alloc report: writing {} (interval {}s, 0 = on exit only)",
+            settings.path, settings.interval_secs
+        );
+        app.insert_resource(settings)
+            .init_resource::<AllocReportState>()
+            .add_systems(Last, alloc_report_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tag(system: &'static str, alloc_calls: u64, alloc_bytes: u64) -> AllocTagSnapshot {
+        AllocTagSnapshot {
+            schedule: "FixedUpdate",
+            system,
+            alloc_calls,
+            alloc_bytes,
+            dealloc_bytes: 16,
+        }
+    }
+
+    #[test]
+    fn report_rows_include_total_and_interval_deltas() {
+        let total = AllocProfileSnapshot {
+            total_alloc_bytes: 4096,
+            total_dealloc_bytes: 1024,
+            alloc_calls: 40,
+            ..Default::default()
+        };
+        let previous = [tag("nbody_gravity_system", 3, 64)];
+        let rows = format_alloc_report_rows(
+            2.5,
+            150,
+            total,
+            &[tag("nbody_gravity_system", 5, 128)],
+            &previous,
+        );
+
+        let lines: Vec<_> = rows.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0], "2.50,150,*,(total),40,4096,1024,3072,40,4096");
+        assert_eq!(
+            lines[1],
+            "2.50,150,FixedUpdate,nbody_gravity_system,5,128,16,112,2,64"
+        );
+        assert_eq!(
+            ALLOC_REPORT_CSV_HEADER.split(',').count(),
+            lines[1].split(',').count()
+        );
+    }
+}
@@ -9,8 +9,9 @@ use std::{cmp::Ordering, f32::consts::TAU};
 use crate::config::PhysicsConfig;
 use crate::constants::{
     FRICTION_ASTEROID, HEPTAGON_BASE_RADIUS, HULL_DEDUP_MIN_DIST, OCTAGON_BASE_RADIUS,
-    POLYGON_BASE_RADIUS, RESTITUTION_SMALL, SQUARE_BASE_HALF, TRIANGLE_BASE_SIDE,
+    POLYGON_BASE_RADIUS, SQUARE_BASE_HALF, TRIANGLE_BASE_SIDE,
 };
+use crate::loading::ScenarioSpawnQueue;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 use rand::rngs::StdRng;
@@ -18,27 +19,34 @@ use rand::seq::SliceRandom;
 use rand::{Rng, SeedableRng};
 
 /// Marker component for any asteroid entity
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
 pub struct Asteroid;
 
 /// Marker component for a planet body.
 ///
 /// Planets participate in gravity but are fixed in place and excluded from
-/// asteroid merge/split weapon-damage logic.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// asteroid merge/split weapon-damage logic.  Missiles and heavy impacts
+/// excavate persistent craters instead (see
+/// `player::combat::projectile_missile_planet_hit_system`).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
 pub struct Planet;
 
 /// How many "unit" (single triangle) asteroids this entity represents.
 /// Single triangles = 1; composites = sum of constituents.
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Component)]
 pub struct AsteroidSize(pub u32);
 
 /// Count of nearby asteroids for environmental damping calculation
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct NeighborCount(pub usize);
 
 /// Polygon vertices for wireframe rendering (stored in local space)
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct Vertices(pub Vec<Vec2>);
 
 /// Original undeformed vertices in local space.
@@ -46,14 +54,16 @@ pub struct Vertices(pub Vec<Vec2>);
 /// Used as the base shape for crater deformation.  The visual [`Vertices`] are
 /// regenerated from these base vertices + accumulated craters.  Collider uses
 /// base vertices (convex hull) for stable physics (Option A strategy).
-#[derive(Component, Debug, Clone)]
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
 pub struct BaseVertices(pub Vec<Vec2>);
 
 /// Accumulated craters from repeated non-lethal projectile impacts.
 ///
 /// Each crater is `(local_position, depth, radius)`.  The visual mesh is
 /// regenerated by applying all craters to [`BaseVertices`] on each hit.
-#[derive(Component, Debug, Clone, Default)]
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
 pub struct CraterData {
     pub craters: Vec<(Vec2, f32, f32)>,
 }
@@ -64,9 +74,41 @@ pub struct CraterData {
 /// Unlike `ExternalForce` (which also accumulates soft-boundary corrections),
 /// this component stores the pure N-body gravity vector and is used by the
 /// force-vector debug overlay so boundary forces don't contaminate the display.
-#[derive(Component, Debug, Clone, Copy, Default)]
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
 pub struct GravityForce(pub Vec2);
 
+/// Split lineage of a fresh fragment.
+///
+/// Every fragment of one split carries the same `lineage` (the split
+/// asteroid's entity bits).  While tagged, a fragment neither merges nor
+/// velocity-locks with its siblings, so a split cannot immediately undo
+/// itself.  `merge_cooldown_system` removes the tag once `age` has reached
+/// `merge_cooldown_secs` and the fragment is `merge_rearm_gap` clear of every
+/// sibling, or unconditionally at `merge_cooldown_max_secs`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct MergeCooldown {
+    pub lineage: u64,
+    /// Seconds since the split.
+    pub age: f32,
+}
+
+impl MergeCooldown {
+    /// Tag for a fragment of `parent`.
+    pub fn from_split(parent: Entity) -> Self {
+        Self {
+            lineage: parent.to_bits(),
+            age: 0.0,
+        }
+    }
+
+    /// Whether two asteroids are siblings that may not merge yet.
+    pub fn blocks(a: Option<&Self>, b: Option<&Self>) -> bool {
+        matches!((a, b), (Some(a), Some(b)) if a.lineage == b.lineage)
+    }
+}
+
 /// Simple hash-based noise generator for clustering asteroids.
 /// Returns a float in [0, 1) that varies smoothly across space.
 fn noise_2d(x: f32, y: f32, frequency: f32, offset: Vec2) -> f32 {
@@ -76,12 +118,13 @@ fn noise_2d(x: f32, y: f32, frequency: f32, offset: Vec2) -> f32 {
     let grid_x = (sample_x * frequency).floor();
     let grid_y = (sample_y * frequency).floor();
 
-    // Hash function: mix bits from grid coordinates
-    let h = ((13.0 * grid_x + 31.0 * grid_y).sin() * 13131.0).fract();
+    // Hash function: mix bits from grid coordinates. `rem_euclid` rather
+    // than `fract` keeps negative inputs in [0, 1) too.
+    let h = ((13.0 * grid_x + 31.0 * grid_y).sin() * 13131.0).rem_euclid(1.0);
 
     // Smooth transition within cell
-    let local_x = (sample_x * frequency).fract();
-    let local_y = (sample_y * frequency).fract();
+    let local_x = sample_x * frequency - grid_x;
+    let local_y = sample_y * frequency - grid_y;
     let smooth_x = local_x * local_x * (3.0 - 2.0 * local_x);
     let smooth_y = local_y * local_y * (3.0 - 2.0 * local_y);
 
@@ -266,6 +309,14 @@ fn build_spawn_shape_with_variation(
     (normalized, unit_size)
 }
 
+/// Rapier restitution for asteroid colliders: perfectly inelastic.
+///
+/// Asteroid–asteroid bounce comes from the impact-energy curve applied after
+/// each step (see [`crate::simulation::collision_response`]).
+pub fn asteroid_restitution() -> Restitution {
+    Restitution::coefficient(0.0)
+}
+
 /// Build a safe asteroid collider from local-space polygon vertices.
 ///
 /// Always uses base vertices (from BaseVertices component) to create a convex
@@ -347,10 +398,311 @@ pub fn apply_crater_deformation(
     Some(deformed)
 }
 
+/// Local-space shape of an asteroid after mass has been removed at one point.
+#[derive(Debug, Clone)]
+pub struct CarvedShape {
+    /// Cratered hull for the visual [`Vertices`].
+    pub vertices: Vec<Vec2>,
+    /// Undeformed hull for [`BaseVertices`] and the collider.
+    pub base_vertices: Vec<Vec2>,
+    pub craters: CraterData,
+}
+
+/// Carve an asteroid down to `new_mass`, cratering it at `impact_local`.
+///
+/// Shared by the projectile chip path and the mining laser.  A crater of
+/// `crater_depth_per_hit` is added at the impact point (the oldest is dropped
+/// past `max_craters_per_asteroid`), all craters are applied to the base
+/// hull, and both hulls are rescaled to the area `new_mass` covers at
+/// `asteroid_density`.
+pub fn carve_asteroid_shape(
+    base_vertices: &[Vec2],
+    crater_data: Option<&CraterData>,
+    impact_local: Vec2,
+    new_mass: u32,
+    config: &PhysicsConfig,
+) -> CarvedShape {
+    let bounding_radius = base_vertices
+        .iter()
+        .map(|v| v.length())
+        .fold(0.0f32, f32::max);
+    carve_shape(
+        base_vertices,
+        crater_data,
+        (
+            impact_local,
+            config.crater_depth_per_hit,
+            bounding_radius * config.crater_radius_ratio,
+        ),
+        config.max_craters_per_asteroid,
+        new_mass,
+        config,
+    )
+}
+
+/// Excavate a planet down to `new_mass`, cratering it at `impact_local`.
+///
+/// Like [`carve_asteroid_shape`], but the crater has the fixed
+/// `planet_crater_radius` / `planet_crater_depth` (planets are far larger
+/// than the bodies the ratio-based asteroid crater is tuned for) and up to
+/// `max_craters_per_planet` are kept, so a planet's surface records its
+/// history.  Crater centres are moved with the shrinking hull so old craters
+/// stay on the surface across many excavations.
+pub fn excavate_planet_shape(
+    base_vertices: &[Vec2],
+    crater_data: Option<&CraterData>,
+    impact_local: Vec2,
+    new_mass: u32,
+    config: &PhysicsConfig,
+) -> CarvedShape {
+    let mut carved = carve_shape(
+        base_vertices,
+        crater_data,
+        (
+            impact_local,
+            config.planet_crater_depth,
+            config.planet_crater_radius,
+        ),
+        config.max_craters_per_planet,
+        new_mass,
+        config,
+    );
+    let old_area = polygon_area(base_vertices);
+    if old_area > 1e-6 {
+        let scale = (polygon_area(&carved.base_vertices) / old_area).sqrt();
+        let centroid = base_vertices.iter().copied().sum::<Vec2>() / base_vertices.len() as f32;
+        for (centre, _, _) in carved.craters.craters.iter_mut() {
+            *centre = centroid + (*centre - centroid) * scale;
+        }
+    }
+    carved
+}
+
+fn carve_shape(
+    base_vertices: &[Vec2],
+    crater_data: Option<&CraterData>,
+    crater: (Vec2, f32, f32),
+    max_craters: usize,
+    new_mass: u32,
+    config: &PhysicsConfig,
+) -> CarvedShape {
+    let mut craters = crater_data.cloned().unwrap_or_default();
+    craters.craters.push(crater);
+    if craters.craters.len() > max_craters {
+        craters.craters.remove(0);
+    }
+
+    let deformed = apply_crater_deformation(base_vertices, &craters.craters, config)
+        .unwrap_or_else(|| base_vertices.to_vec());
+    let target_area = new_mass as f32 / config.asteroid_density;
+    CarvedShape {
+        vertices: rescale_vertices_to_area(&deformed, target_area),
+        base_vertices: rescale_vertices_to_area(base_vertices, target_area),
+        craters,
+    }
+}
+
+/// Bisection steps when solving for the bulge that restores a deformed hull's
+/// area.
+const DEFORM_BISECTION_STEPS: usize = 24;
+
+/// `(min, max)` of the vertices projected onto `axis`.
+fn projected_extent(vertices: &[Vec2], axis: Vec2) -> (f32, f32) {
+    vertices
+        .iter()
+        .map(|v| v.dot(axis))
+        .fold((f32::MAX, f32::MIN), |(lo, hi), p| (lo.min(p), hi.max(p)))
+}
+
+/// Deform a hull for a heavy impact arriving from `contact_dir` (local
+/// space, pointing from the centre toward the contact).
+///
+/// A constrained displacement of the vertices:
+/// 1. The hull is cut flat at a plane facing the contact, `strain` × its
+///    depth along the impact axis in from the leading point.
+/// 2. The far half is scaled out from the middle of the axis to bulge the
+///    opposite side, ramping from nothing at the middle to fully a quarter of
+///    the depth behind it.  The scale is solved by bisection so the hull
+///    keeps its area, and so its mass.
+/// 3. The result is re-hulled, rescaled to the exact area and recentred, so
+///    it stays a convex polygon around the body origin.
+///
+/// The flattening stops at `min_aspect` (depth along the axis over width
+/// across it); a hull already that flat, a zero `strain` or a degenerate
+/// input returns `None`.
+pub fn deform_hull_on_impact(
+    vertices: &[Vec2],
+    contact_dir: Vec2,
+    strain: f32,
+    min_aspect: f32,
+) -> Option<Vec<Vec2>> {
+    let axis = contact_dir.try_normalize()?;
+    let area = polygon_area(vertices);
+    if strain <= 0.0 || area < 1e-6 {
+        return None;
+    }
+    let (near, far) = projected_extent(vertices, axis);
+    let (side_min, side_max) = projected_extent(vertices, axis.perp());
+    let depth = far - near;
+    let flatten = (strain.min(1.0) * depth).min(depth - (side_max - side_min) * min_aspect);
+    if flatten <= 1e-3 {
+        return None;
+    }
+
+    let plane = far - flatten;
+    let mut pressed = Vec::with_capacity(vertices.len() + 2);
+    for (i, &a) in vertices.iter().enumerate() {
+        let b = vertices[(i + 1) % vertices.len()];
+        let (pa, pb) = (a.dot(axis) - plane, b.dot(axis) - plane);
+        if pa <= 0.0 {
+            pressed.push(a);
+        }
+        if (pa < 0.0 && pb > 0.0) || (pa > 0.0 && pb < 0.0) {
+            pressed.push(a + (b - a) * (pa / (pa - pb)));
+        }
+    }
+    let middle = (near + plane) * 0.5;
+    let centre = axis * middle;
+    let weights: Vec<f32> = pressed
+        .iter()
+        .map(|v| (4.0 * (middle - v.dot(axis)) / (middle - near)).clamp(0.0, 1.0))
+        .collect();
+    let bulged = |scale: f32| -> Vec<Vec2> {
+        pressed
+            .iter()
+            .zip(&weights)
+            .map(|(&v, &w)| centre + (v - centre) * (1.0 + scale * w))
+            .collect()
+    };
+
+    // The area grows with the scale; bracket the original area, then bisect.
+    let (mut lo, mut hi) = (0.0f32, strain);
+    while polygon_area(&bulged(hi)) < area && hi < 4.0 {
+        hi *= 2.0;
+    }
+    for _ in 0..DEFORM_BISECTION_STEPS {
+        let mid = (lo + hi) * 0.5;
+        if polygon_area(&bulged(mid)) < area {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+
+    let hull = compute_convex_hull_from_points(&bulged(hi))?;
+    if hull.len() < 3 {
+        return None;
+    }
+    let hull = rescale_vertices_to_area(&hull, area);
+    let centroid = hull.iter().copied().sum::<Vec2>() / hull.len() as f32;
+    Some(hull.into_iter().map(|v| v - centroid).collect())
+}
+
+/// Seeded offsets into the two noise layers that shape field density.
+///
+/// Shared by the starting field ([`spawn_initial_asteroids`]) and procedural
+/// chunks, which sample it in world space so clusters continue across chunk
+/// edges.
+#[derive(Debug, Clone, Copy)]
+pub struct FieldNoise {
+    coarse_offset: Vec2,
+    fine_offset: Vec2,
+}
+
+impl FieldNoise {
+    const COARSE_FREQUENCY: f32 = 0.0045;
+    const FINE_FREQUENCY: f32 = 0.018;
+
+    /// Draw both offsets from `rng`.
+    pub fn from_rng(rng: &mut impl Rng) -> Self {
+        let coarse_offset = Vec2::new(
+            rng.gen_range(-40_000.0..40_000.0),
+            rng.gen_range(-40_000.0..40_000.0),
+        );
+        let fine_offset = Vec2::new(
+            rng.gen_range(-40_000.0..40_000.0),
+            rng.gen_range(-40_000.0..40_000.0),
+        );
+        Self {
+            coarse_offset,
+            fine_offset,
+        }
+    }
+
+    /// Probability of an asteroid in the sample cell at `pos`.
+    pub fn spawn_probability(&self, pos: Vec2) -> f32 {
+        let coarse_noise = noise_2d(pos.x, pos.y, Self::COARSE_FREQUENCY, self.coarse_offset);
+        let fine_noise = noise_2d(pos.x, pos.y, Self::FINE_FREQUENCY, self.fine_offset);
+
+        // Ridge term increases patch boundaries so nearby dense pockets form.
+        let ridge = (1.0 - (2.0 * coarse_noise - 1.0).abs()).powf(1.7);
+        let cluster_weight = (0.65 * coarse_noise + 0.35 * ridge).clamp(0.0, 1.0);
+
+        // Probability is heavily cluster-weighted with fine local modulation.
+        (0.08 + cluster_weight * 0.34 + fine_noise.powf(2.0) * 0.22).clamp(0.0, 0.72)
+    }
+}
+
+/// Shape and motion of one freshly generated field asteroid.
+#[derive(Debug, Clone)]
+pub struct FieldBody {
+    pub vertices: Vec<Vec2>,
+    pub size: u32,
+    pub velocity: Vec2,
+    pub rotation: f32,
+    pub angvel: f32,
+}
+
+/// Roll a random field asteroid: polygon, size, spawn-shape variation and
+/// gentle initial motion.
+pub fn random_field_body(rng: &mut impl Rng, config: &PhysicsConfig) -> FieldBody {
+    let size_scale_min = (config.asteroid_size_scale_min * 0.7).max(0.2);
+    let size_scale_max = (config.asteroid_size_scale_max * 1.35).max(size_scale_min + 0.05);
+    let size_scale = rng.gen_range(size_scale_min..size_scale_max);
+
+    // Random shape (triangle, square, pentagon, hexagon, heptagon, octagon).
+    let shape = rng.gen_range(0..6);
+    let vertices = match shape {
+        0 => generate_triangle(size_scale, config.triangle_base_side),
+        1 => generate_square(size_scale, config.square_base_half),
+        2 => generate_pentagon(size_scale, config.polygon_base_radius),
+        3 => generate_hexagon(size_scale, config.polygon_base_radius),
+        4 => generate_heptagon(size_scale, config.heptagon_base_radius),
+        _ => generate_octagon(size_scale, config.octagon_base_radius),
+    };
+
+    // Apply procedural spawn-shape variation and normalize to mass/area invariant.
+    let (vertices, size) = build_spawn_shape_with_variation(vertices, size_scale, rng, config);
+
+    // Random velocity (gentle to avoid instant collisions).
+    let speed_scale = rng.gen_range(0.35..1.55);
+    let velocity_range = config.asteroid_initial_velocity_range * speed_scale;
+    let velocity = Vec2::new(
+        rng.gen_range(-velocity_range..velocity_range),
+        rng.gen_range(-velocity_range..velocity_range),
+    );
+    let rotation = rng.gen_range(0.0..TAU);
+    let angvel = rng.gen_range(
+        -config.asteroid_initial_angvel_range * 1.6..config.asteroid_initial_angvel_range * 1.6,
+    );
+
+    FieldBody {
+        vertices,
+        size,
+        velocity,
+        rotation,
+        angvel,
+    }
+}
+
 /// Spawns asteroids with clustered distributions using noise-based seeding.
 /// Creates natural asteroid field patterns rather than even distribution.
-pub fn spawn_initial_asteroids(commands: &mut Commands, count: usize, config: &PhysicsConfig) {
-    let seed = rand::random::<u64>();
+pub fn spawn_initial_asteroids(
+    queue: &mut ScenarioSpawnQueue,
+    count: usize,
+    config: &PhysicsConfig,
+    seed: u64,
+) {
     let mut rng = StdRng::seed_from_u64(seed);
     info!("Field scenario seed: {}", seed);
 
@@ -369,19 +721,7 @@ pub fn spawn_initial_asteroids(commands: &mut Commands, count: usize, config: &P
     let sample_step_y = (sim_height - 2.0 * grid_margin) / sample_grid_size as f32;
 
     // Multi-scale seeded noise produces richer nearby cluster patches.
-    let coarse_noise_frequency = 0.0045;
-    let fine_noise_frequency = 0.018;
-    let coarse_noise_offset = Vec2::new(
-        rng.gen_range(-40_000.0..40_000.0),
-        rng.gen_range(-40_000.0..40_000.0),
-    );
-    let fine_noise_offset = Vec2::new(
-        rng.gen_range(-40_000.0..40_000.0),
-        rng.gen_range(-40_000.0..40_000.0),
-    );
-
-    let size_scale_min = (config.asteroid_size_scale_min * 0.7).max(0.2);
-    let size_scale_max = (config.asteroid_size_scale_max * 1.35).max(size_scale_min + 0.05);
+    let noise = FieldNoise::from_rng(&mut rng);
 
     let mut spawned = 0;
     let mut cell_indices: Vec<usize> = (0..sample_grid_size * sample_grid_size).collect();
@@ -398,18 +738,7 @@ pub fn spawn_initial_asteroids(commands: &mut Commands, count: usize, config: &P
         let base_x = -sim_width / 2.0 + grid_margin + sample_x as f32 * sample_step_x;
         let base_y = -sim_height / 2.0 + grid_margin + sample_y as f32 * sample_step_y;
 
-        // Evaluate seeded multi-scale noise at this grid position.
-        let coarse_noise = noise_2d(base_x, base_y, coarse_noise_frequency, coarse_noise_offset);
-        let fine_noise = noise_2d(base_x, base_y, fine_noise_frequency, fine_noise_offset);
-
-        // Ridge term increases patch boundaries so nearby dense pockets form.
-        let ridge = (1.0 - (2.0 * coarse_noise - 1.0).abs()).powf(1.7);
-        let cluster_weight = (0.65 * coarse_noise + 0.35 * ridge).clamp(0.0, 1.0);
-
-        // Probability is heavily cluster-weighted with fine local modulation.
-        let spawn_prob =
-            (0.08 + cluster_weight * 0.34 + fine_noise.powf(2.0) * 0.22).clamp(0.0, 0.72);
-
+        let spawn_prob = noise.spawn_probability(Vec2::new(base_x, base_y));
         if rng.gen::<f32>() > spawn_prob {
             continue;
         }
@@ -427,40 +756,17 @@ pub fn spawn_initial_asteroids(commands: &mut Commands, count: usize, config: &P
 
         spawned += 1;
 
-        // Random size scale.
-        let size_scale = rng.gen_range(size_scale_min..size_scale_max);
-
-        // Random shape (triangle, square, pentagon, hexagon, heptagon, octagon).
-        let shape = rng.gen_range(0..6);
-        let vertices = match shape {
-            0 => generate_triangle(size_scale, config.triangle_base_side),
-            1 => generate_square(size_scale, config.square_base_half),
-            2 => generate_pentagon(size_scale, config.polygon_base_radius),
-            3 => generate_hexagon(size_scale, config.polygon_base_radius),
-            4 => generate_heptagon(size_scale, config.heptagon_base_radius),
-            _ => generate_octagon(size_scale, config.octagon_base_radius),
-        };
-
-        // Apply procedural spawn-shape variation and normalize to mass/area invariant.
-        let (vertices, unit_size) =
-            build_spawn_shape_with_variation(vertices, size_scale, &mut rng, config);
-
-        // Random velocity (gentle to avoid instant collisions).
-        let speed_scale = rng.gen_range(0.35..1.55);
-        let velocity_range = config.asteroid_initial_velocity_range * speed_scale;
-        let velocity = Vec2::new(
-            rng.gen_range(-velocity_range..velocity_range),
-            rng.gen_range(-velocity_range..velocity_range),
-        );
-        let initial_rotation = Quat::from_rotation_z(rng.gen_range(0.0..TAU));
+        let body = random_field_body(&mut rng, config);
+        let vertices = body.vertices;
 
         // Spawn the asteroid.
-        commands.spawn((
+        queue.spawn((
             (
-                Transform::from_translation(position.extend(0.05)).with_rotation(initial_rotation),
+                Transform::from_translation(position.extend(0.05))
+                    .with_rotation(Quat::from_rotation_z(body.rotation)),
                 GlobalTransform::default(),
                 Asteroid,
-                AsteroidSize(unit_size),
+                AsteroidSize(body.size),
                 NeighborCount(0),
                 Vertices(vertices.clone()),
                 BaseVertices(vertices.clone()),
@@ -469,14 +775,11 @@ pub fn spawn_initial_asteroids(commands: &mut Commands, count: usize, config: &P
             ),
             (
                 collider_for_vertices(&vertices),
-                Restitution::coefficient(RESTITUTION_SMALL),
+                asteroid_restitution(),
                 Friction::coefficient(FRICTION_ASTEROID),
                 Velocity {
-                    linvel: velocity,
-                    angvel: rng.gen_range(
-                        -config.asteroid_initial_angvel_range * 1.6
-                            ..config.asteroid_initial_angvel_range * 1.6,
-                    ),
+                    linvel: body.velocity,
+                    angvel: body.angvel,
                 },
                 Damping {
                     linear_damping: 0.0,
@@ -538,7 +841,7 @@ pub fn spawn_planet(commands: &mut Commands, position: Vec2, config: &PhysicsCon
                 Collider::convex_hull(&vertices)
                     .unwrap_or_else(|| Collider::ball(config.planetoid_base_radius))
             },
-            Restitution::coefficient(RESTITUTION_SMALL),
+            asteroid_restitution(),
             Friction::coefficient(FRICTION_ASTEROID),
             Velocity::zero(),
             Damping {
@@ -558,7 +861,67 @@ pub fn spawn_planet(commands: &mut Commands, position: Vec2, config: &PhysicsCon
                     | bevy_rapier2d::geometry::Group::GROUP_5
                     | bevy_rapier2d::geometry::Group::GROUP_6,
             ),
-            ActiveEvents::COLLISION_EVENTS,
+            ActiveEvents::COLLISION_EVENTS | ActiveEvents::CONTACT_FORCE_EVENTS,
+            ContactForceEventThreshold(config.planet_impact_force_threshold),
+            Sleeping::disabled(),
+        ),
+    ));
+}
+
+/// Spawns an anchored 16-gon planet with an explicit gravitational mass.
+///
+/// Used by scenarios that need a dominant central well (Orbit, Catalog).
+/// The polygon is drawn at `radius` and then rescaled so its Rapier area
+/// matches `mass / asteroid_density`, like every other body.
+pub fn spawn_massive_planet(
+    commands: &mut Commands,
+    position: Vec2,
+    mass: u32,
+    radius: f32,
+    config: &PhysicsConfig,
+) {
+    let vertices = rescale_vertices_to_area(
+        &generate_regular_polygon(16, 1.0, radius),
+        mass as f32 / config.asteroid_density,
+    );
+
+    commands.spawn((
+        (
+            Transform::from_translation(position.extend(0.05)),
+            GlobalTransform::default(),
+            Asteroid,
+            Planet,
+            AsteroidSize(mass),
+            NeighborCount(0),
+            Vertices(vertices.clone()),
+            BaseVertices(vertices.clone()),
+            CraterData::default(),
+            RigidBody::Fixed,
+        ),
+        (
+            Collider::convex_hull(&vertices).unwrap_or_else(|| Collider::ball(radius)),
+            asteroid_restitution(),
+            Friction::coefficient(FRICTION_ASTEROID),
+            Velocity::zero(),
+            Damping {
+                linear_damping: 0.0,
+                angular_damping: 0.0,
+            },
+            ExternalForce {
+                force: Vec2::ZERO,
+                torque: 0.0,
+            },
+            GravityForce::default(),
+            CollisionGroups::new(
+                bevy_rapier2d::geometry::Group::GROUP_1,
+                bevy_rapier2d::geometry::Group::GROUP_1
+                    | bevy_rapier2d::geometry::Group::GROUP_2
+                    | bevy_rapier2d::geometry::Group::GROUP_3
+                    | bevy_rapier2d::geometry::Group::GROUP_5
+                    | bevy_rapier2d::geometry::Group::GROUP_6,
+            ),
+            ActiveEvents::COLLISION_EVENTS | ActiveEvents::CONTACT_FORCE_EVENTS,
+            ContactForceEventThreshold(config.planet_impact_force_threshold),
             Sleeping::disabled(),
         ),
     ));
@@ -586,8 +949,7 @@ pub fn spawn_planetoid(commands: &mut Commands, position: Vec2, config: &Physics
 /// velocity formula below.
 const ORBIT_CENTRAL_MASS: u32 = 2800;
 
-pub fn spawn_orbit_scenario(commands: &mut Commands, config: &PhysicsConfig) {
-    let seed = rand::random::<u64>();
+pub fn spawn_orbit_scenario(queue: &mut ScenarioSpawnQueue, config: &PhysicsConfig, seed: u64) {
     let mut rng = StdRng::seed_from_u64(seed);
     info!("Orbit scenario seed: {}", seed);
 
@@ -604,51 +966,16 @@ pub fn spawn_orbit_scenario(commands: &mut Commands, config: &PhysicsConfig) {
     // that keeps orbits stable for many revolutions.
     let central_radius = config.planetoid_base_radius * 5.2;
     let central_pos = Vec2::new(800.0, 0.0);
-    let central_vertices = rescale_vertices_to_area(
-        &generate_regular_polygon(16, 1.0, central_radius),
-        ORBIT_CENTRAL_MASS as f32 / config.asteroid_density,
-    );
-
-    commands.spawn((
-        (
-            Transform::from_translation(central_pos.extend(0.05)),
-            GlobalTransform::default(),
-            Asteroid,
-            Planet,
-            AsteroidSize(ORBIT_CENTRAL_MASS),
-            NeighborCount(0),
-            Vertices(central_vertices.clone()),
-            BaseVertices(central_vertices.clone()),
-            CraterData::default(),
-            RigidBody::Fixed,
-        ),
-        (
-            Collider::convex_hull(&central_vertices)
-                .unwrap_or_else(|| Collider::ball(central_radius)),
-            Restitution::coefficient(RESTITUTION_SMALL),
-            Friction::coefficient(FRICTION_ASTEROID),
-            Velocity::zero(),
-            Damping {
-                linear_damping: 0.0,
-                angular_damping: 0.0,
-            },
-            ExternalForce {
-                force: Vec2::ZERO,
-                torque: 0.0,
-            },
-            GravityForce::default(),
-            CollisionGroups::new(
-                bevy_rapier2d::geometry::Group::GROUP_1,
-                bevy_rapier2d::geometry::Group::GROUP_1
-                    | bevy_rapier2d::geometry::Group::GROUP_2
-                    | bevy_rapier2d::geometry::Group::GROUP_3
-                    | bevy_rapier2d::geometry::Group::GROUP_5
-                    | bevy_rapier2d::geometry::Group::GROUP_6,
-            ),
-            ActiveEvents::COLLISION_EVENTS,
-            Sleeping::disabled(),
-        ),
-    ));
+    let planet_config = config.clone();
+    queue.push(move |commands| {
+        spawn_massive_planet(
+            commands,
+            central_pos,
+            ORBIT_CENTRAL_MASS,
+            central_radius,
+            &planet_config,
+        );
+    });
 
     // ── Orbital debris rings ─────────────────────────────────────────────────
     //
@@ -684,7 +1011,7 @@ pub fn spawn_orbit_scenario(commands: &mut Commands, config: &PhysicsConfig) {
             &mut rng,
             config,
         );
-        commands.spawn((
+        queue.spawn((
             (
                 Transform::from_translation(pos.extend(0.05)).with_rotation(initial_rotation),
                 GlobalTransform::default(),
@@ -699,7 +1026,7 @@ pub fn spawn_orbit_scenario(commands: &mut Commands, config: &PhysicsConfig) {
             (
                 Collider::convex_hull(&vertices)
                     .unwrap_or_else(|| Collider::ball(config.triangle_base_side / 2.0)),
-                Restitution::coefficient(RESTITUTION_SMALL),
+                asteroid_restitution(),
                 Friction::coefficient(FRICTION_ASTEROID),
                 Velocity {
                     linvel: tangent * v_orbit(radius) * speed_boost,
@@ -752,7 +1079,7 @@ pub fn spawn_orbit_scenario(commands: &mut Commands, config: &PhysicsConfig) {
         let (vertices, asteroid_size) =
             build_spawn_shape_with_variation(raw_verts, scale, &mut rng, config);
 
-        commands.spawn((
+        queue.spawn((
             (
                 Transform::from_translation(pos.extend(0.05)).with_rotation(initial_rotation),
                 GlobalTransform::default(),
@@ -767,7 +1094,7 @@ pub fn spawn_orbit_scenario(commands: &mut Commands, config: &PhysicsConfig) {
             (
                 Collider::convex_hull(&vertices)
                     .unwrap_or_else(|| Collider::ball(config.polygon_base_radius * scale)),
-                Restitution::coefficient(RESTITUTION_SMALL),
+                asteroid_restitution(),
                 Friction::coefficient(FRICTION_ASTEROID),
                 Velocity {
                     linvel: tangent * v_orbit(radius) * speed_boost,
@@ -827,7 +1154,7 @@ pub fn spawn_orbit_scenario(commands: &mut Commands, config: &PhysicsConfig) {
         let (vertices, asteroid_size) =
             build_spawn_shape_with_variation(raw_verts, scale, &mut rng, config);
 
-        commands.spawn((
+        queue.spawn((
             (
                 Transform::from_translation(pos.extend(0.05)).with_rotation(initial_rotation),
                 GlobalTransform::default(),
@@ -842,7 +1169,7 @@ pub fn spawn_orbit_scenario(commands: &mut Commands, config: &PhysicsConfig) {
             (
                 Collider::convex_hull(&vertices)
                     .unwrap_or_else(|| Collider::ball(config.polygon_base_radius * scale)),
-                Restitution::coefficient(RESTITUTION_SMALL),
+                asteroid_restitution(),
                 Friction::coefficient(FRICTION_ASTEROID),
                 Velocity {
                     linvel: tangent * v_orbit(radius) * speed_boost,
@@ -881,8 +1208,7 @@ pub fn spawn_orbit_scenario(commands: &mut Commands, config: &PhysicsConfig) {
 ///
 /// `AsteroidSize` is derived from the ratio of each polygon's area to the
 /// unit-triangle area so that the gravity system weights them correctly.
-pub fn spawn_comets_scenario(commands: &mut Commands, config: &PhysicsConfig) {
-    let seed = rand::random::<u64>();
+pub fn spawn_comets_scenario(queue: &mut ScenarioSpawnQueue, config: &PhysicsConfig, seed: u64) {
     let mut rng = StdRng::seed_from_u64(seed);
     info!("Comets scenario seed: {}", seed);
 
@@ -924,7 +1250,7 @@ pub fn spawn_comets_scenario(commands: &mut Commands, config: &PhysicsConfig) {
         let (vertices, unit_size) =
             build_spawn_shape_with_variation(raw_vertices, scale, &mut rng, config);
 
-        commands.spawn((
+        queue.spawn((
             (
                 Transform::from_translation(position.extend(0.05)).with_rotation(initial_rotation),
                 GlobalTransform::default(),
@@ -939,7 +1265,7 @@ pub fn spawn_comets_scenario(commands: &mut Commands, config: &PhysicsConfig) {
             (
                 Collider::convex_hull(&vertices)
                     .unwrap_or_else(|| Collider::ball(base_radius * scale)),
-                Restitution::coefficient(RESTITUTION_SMALL),
+                asteroid_restitution(),
                 Friction::coefficient(FRICTION_ASTEROID),
                 Velocity {
                     linvel: velocity,
@@ -969,13 +1295,37 @@ pub fn spawn_comets_scenario(commands: &mut Commands, config: &PhysicsConfig) {
     }
 }
 
+/// Spawns a single comet at `position` travelling with `velocity`.
+///
+/// Shape generation follows the Comets scenario (high-sided, mid-to-large
+/// bodies).  Used by survival waves to launch inbound comets at runtime.
+pub fn spawn_inbound_comet(
+    commands: &mut Commands,
+    config: &PhysicsConfig,
+    rng: &mut impl Rng,
+    position: Vec2,
+    velocity: Vec2,
+) -> Entity {
+    let scale: f32 = rng.gen_range(1.8..4.2);
+    let sides: usize = rng.gen_range(8usize..=12);
+    let raw_vertices = generate_regular_polygon(sides, scale, config.polygon_base_radius);
+    let (vertices, unit_size) = build_spawn_shape_with_variation(raw_vertices, scale, rng, config);
+
+    let entity =
+        spawn_asteroid_with_vertices(commands, position, &vertices, Color::WHITE, unit_size);
+    commands.entity(entity).insert(Velocity {
+        linvel: velocity,
+        angvel: rng.gen_range(-0.45..0.45),
+    });
+    entity
+}
+
 /// Spawns the "shower" scenario.
 ///
 /// 250 unit-triangle asteroids are scattered uniformly across a 1600-unit
 /// radius disk with near-zero initial velocity.  Mutual N-body gravity quickly
 /// collapses them into growing clusters — watch the field accrete in real time.
-pub fn spawn_shower_scenario(commands: &mut Commands, config: &PhysicsConfig) {
-    let seed = rand::random::<u64>();
+pub fn spawn_shower_scenario(queue: &mut ScenarioSpawnQueue, config: &PhysicsConfig, seed: u64) {
     let mut rng = StdRng::seed_from_u64(seed);
     info!("Shower scenario seed: {}", seed);
 
@@ -1020,7 +1370,7 @@ pub fn spawn_shower_scenario(commands: &mut Commands, config: &PhysicsConfig) {
         let velocity = (inward + tangential).normalize_or_zero() * rng.gen_range(8.0..24.0);
         let initial_rotation = Quat::from_rotation_z(rng.gen_range(0.0..TAU));
 
-        commands.spawn((
+        queue.spawn((
             (
                 Transform::from_translation(position.extend(0.05)).with_rotation(initial_rotation),
                 GlobalTransform::default(),
@@ -1035,7 +1385,7 @@ pub fn spawn_shower_scenario(commands: &mut Commands, config: &PhysicsConfig) {
             (
                 Collider::convex_hull(&vertices)
                     .unwrap_or_else(|| Collider::ball(config.triangle_base_side * 0.5)),
-                Restitution::coefficient(RESTITUTION_SMALL),
+                asteroid_restitution(),
                 Friction::coefficient(FRICTION_ASTEROID),
                 Velocity {
                     linvel: velocity,
@@ -1159,6 +1509,94 @@ pub fn canonical_vertices_for_mass(mass: u32) -> Vec<Vec2> {
     }
 }
 
+/// Perturb a canonical polygon with noise drawn from `seed`.
+///
+/// Each vertex turns by up to `amplitude` of half its angular slot and is
+/// scaled radially by up to ±`amplitude` (clamped to 0.45).  If that leaves
+/// the polygon non-convex or collapses a vertex, the radial part is halved
+/// and retried, ending with the angle-only perturbation, which is always
+/// convex for a regular input (its points share one circle).  The result is
+/// re-centred and rescaled to the input's area, and the same seed always
+/// yields the same hull.
+pub fn perturb_canonical_vertices(vertices: &[Vec2], seed: u64, amplitude: f32) -> Vec<Vec2> {
+    let n = vertices.len();
+    let amplitude = amplitude.clamp(0.0, 0.45);
+    if n < 3 || amplitude <= 0.0 {
+        return vertices.to_vec();
+    }
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    let half_slot = std::f32::consts::PI / n as f32;
+    let noise: Vec<(f32, f32)> = (0..n)
+        .map(|_| (rng.gen_range(-1.0..=1.0), rng.gen_range(-1.0..=1.0)))
+        .collect();
+    let centroid = vertices.iter().copied().sum::<Vec2>() / n as f32;
+
+    let perturb = |radial_amplitude: f32| -> Vec<Vec2> {
+        vertices
+            .iter()
+            .zip(&noise)
+            .map(|(v, &(turn, stretch))| {
+                let offset = Vec2::from_angle(turn * amplitude * half_slot).rotate(*v - centroid);
+                centroid + offset * (1.0 + stretch * radial_amplitude)
+            })
+            .collect()
+    };
+    let mut radial_amplitude = amplitude;
+    let shaped = loop {
+        let candidate = perturb(radial_amplitude);
+        if is_strictly_convex(&candidate) {
+            break candidate;
+        }
+        if radial_amplitude <= amplitude / 8.0 {
+            break perturb(0.0);
+        }
+        radial_amplitude *= 0.5;
+    };
+
+    let shaped_centroid = shaped.iter().copied().sum::<Vec2>() / n as f32;
+    let recentred: Vec<Vec2> = shaped.iter().map(|v| *v - shaped_centroid).collect();
+    rescale_vertices_to_area(&recentred, polygon_area(vertices))
+}
+
+/// Every turn of the polygon is strictly in the same direction (either
+/// winding), so it is convex with no collapsed vertex.
+fn is_strictly_convex(vertices: &[Vec2]) -> bool {
+    let n = vertices.len();
+    if n < 3 {
+        return false;
+    }
+    let turns: Vec<f32> = (0..n)
+        .map(|i| cross_product(vertices[i], vertices[(i + 1) % n], vertices[(i + 2) % n]))
+        .collect();
+    turns.iter().all(|&t| t > 1e-4) || turns.iter().all(|&t| t < -1e-4)
+}
+
+/// Local-space hull for a new body of `mass` that has no shape of its own
+/// (split/chip fragments, catalog bodies).
+///
+/// The canonical polygon for the mass, perturbed with
+/// [`perturb_canonical_vertices`] when `canonical_shape_noise_enabled`,
+/// scaled to the area `mass` covers.  The hull is stored in [`Vertices`], so
+/// saves and the sector store keep it exactly.
+pub fn fragment_vertices_for_mass(
+    mass: u32,
+    rng: &mut impl Rng,
+    config: &PhysicsConfig,
+) -> Vec<Vec2> {
+    let canonical = canonical_vertices_for_mass(mass);
+    let shaped = if config.canonical_shape_noise_enabled {
+        perturb_canonical_vertices(
+            &canonical,
+            rng.gen(),
+            config.canonical_shape_noise_amplitude,
+        )
+    } else {
+        canonical
+    };
+    rescale_vertices_to_area(&shaped, mass as f32 / config.asteroid_density)
+}
+
 /// Spawns an asteroid with arbitrary polygon vertices and an explicit unit-size count.
 /// `size` is how many unit triangles this asteroid represents (use 1 for fresh spawns).
 pub fn spawn_asteroid_with_vertices(
@@ -1201,7 +1639,7 @@ pub fn spawn_asteroid_with_vertices(
                 collider,
             ),
             (
-                Restitution::coefficient(RESTITUTION_SMALL),
+                asteroid_restitution(),
                 Friction::coefficient(FRICTION_ASTEROID),
                 Velocity::zero(),
                 Damping {
@@ -1348,6 +1786,88 @@ pub fn polygon_area(vertices: &[Vec2]) -> f32 {
     (area / 2.0).abs()
 }
 
+/// Distance along the ray from `origin` (unit direction `dir`) to the nearest
+/// edge of `polygon`, or `None` if the ray misses it.
+pub fn ray_polygon_distance(origin: Vec2, dir: Vec2, polygon: &[Vec2]) -> Option<f32> {
+    let n = polygon.len();
+    (0..n)
+        .filter_map(|i| {
+            let a = polygon[i];
+            let edge = polygon[(i + 1) % n] - a;
+            let denom = dir.perp_dot(edge);
+            if denom.abs() < 1e-6 {
+                return None;
+            }
+            let to_a = a - origin;
+            let t = to_a.perp_dot(edge) / denom;
+            let u = to_a.perp_dot(dir) / denom;
+            (t >= 0.0 && (0.0..=1.0).contains(&u)).then_some(t)
+        })
+        .min_by(f32::total_cmp)
+}
+
+/// Drop hull vertices that lie within `epsilon` of the outline without them
+/// (Douglas–Peucker on the closed polygon), then rescale to the original area.
+///
+/// The chain is split at vertex 0 and the vertex farthest from it, so both
+/// always survive.  A subset of a convex hull's vertices is still convex,
+/// and the rescale keeps the area, so mass stays consistent with
+/// `asteroid_density`.  Dropping vertices from one side moves their mean, so
+/// the result is recentred on the origin before the rescale, as local hulls
+/// must be.  Hulls that would drop below 3 vertices are returned unchanged.
+pub fn simplify_hull(vertices: &[Vec2], epsilon: f32) -> Vec<Vec2> {
+    let n = vertices.len();
+    if n <= 3 || epsilon <= 0.0 {
+        return vertices.to_vec();
+    }
+    let far = (1..n)
+        .max_by(|&a, &b| {
+            vertices[a]
+                .distance_squared(vertices[0])
+                .total_cmp(&vertices[b].distance_squared(vertices[0]))
+        })
+        .unwrap_or(n / 2);
+
+    let mut keep = vec![false; n];
+    keep[0] = true;
+    keep[far] = true;
+    let mut stack = vec![(0, far), (far, n)];
+    while let Some((start, end)) = stack.pop() {
+        let (a, b) = (vertices[start], vertices[end % n]);
+        let mut worst = (0.0, start);
+        for (i, &v) in vertices.iter().enumerate().take(end).skip(start + 1) {
+            let d = point_segment_distance(v, a, b);
+            if d > worst.0 {
+                worst = (d, i);
+            }
+        }
+        if worst.0 > epsilon {
+            keep[worst.1] = true;
+            stack.push((start, worst.1));
+            stack.push((worst.1, end));
+        }
+    }
+
+    let simplified: Vec<Vec2> = (0..n).filter(|&i| keep[i]).map(|i| vertices[i]).collect();
+    if simplified.len() < 3 || simplified.len() == n {
+        return vertices.to_vec();
+    }
+    let centroid = simplified.iter().copied().sum::<Vec2>() / simplified.len() as f32;
+    let recentred: Vec<Vec2> = simplified.iter().map(|v| *v - centroid).collect();
+    rescale_vertices_to_area(&recentred, polygon_area(vertices))
+}
+
+/// Distance from `p` to the segment `a`–`b`.
+fn point_segment_distance(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b - a;
+    let len_sq = ab.length_squared();
+    if len_sq <= f32::EPSILON {
+        return p.distance(a);
+    }
+    let t = ((p - a).dot(ab) / len_sq).clamp(0.0, 1.0);
+    p.distance(a + ab * t)
+}
+
 /// Rescale a polygon's vertices (in local space) so its enclosed area equals
 /// `target_area`.
 ///
@@ -1371,11 +1891,79 @@ pub fn rescale_vertices_to_area(vertices: &[Vec2], target_area: f32) -> Vec<Vec2
         .collect()
 }
 
+/// Blend colors by averaging RGB values
+#[allow(dead_code)]
+pub fn blend_colors(particles: &[(Entity, Vec2, Color)]) -> Color {
+    let mut r = 0.0;
+    let mut g = 0.0;
+    let mut b = 0.0;
+
+    for (_, _, color) in particles {
+        let c = Srgba::from(*color);
+        r += c.red;
+        g += c.green;
+        b += c.blue;
+    }
+
+    let count = particles.len() as f32;
+    Color::srgb(r / count, g / count, b / count)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use rand::{rngs::StdRng, SeedableRng};
 
+    // ── deform_hull_on_impact ─────────────────────────────────────────────────
+
+    fn is_convex(hull: &[Vec2]) -> bool {
+        let n = hull.len();
+        let turns: Vec<f32> = (0..n)
+            .map(|i| cross_product(hull[i], hull[(i + 1) % n], hull[(i + 2) % n]))
+            .collect();
+        n >= 3 && (turns.iter().all(|&t| t >= -1e-3) || turns.iter().all(|&t| t <= 1e-3))
+    }
+
+    #[test]
+    fn impact_deformation_flattens_the_contact_side_and_keeps_the_area() {
+        let hull = rescale_vertices_to_area(&canonical_vertices_for_mass(20), 200.0);
+        let area = polygon_area(&hull);
+        let (near, far) = projected_extent(&hull, Vec2::X);
+
+        let deformed = deform_hull_on_impact(&hull, Vec2::X, 0.2, 0.45).unwrap();
+
+        assert!((polygon_area(&deformed) - area).abs() < area * 1e-3);
+        assert!(is_convex(&deformed));
+        // Cut by a fifth of the depth, but the bulge gives some of it back.
+        let (new_near, new_far) = projected_extent(&deformed, Vec2::X);
+        let depth_ratio = (new_far - new_near) / (far - near);
+        assert!(depth_ratio > 0.8 && depth_ratio < 1.0, "far side bulged");
+        // The flattened face: two vertices share the leading plane.
+        let on_face = deformed
+            .iter()
+            .filter(|v| (v.x - new_far).abs() < 1e-3)
+            .count();
+        assert!(on_face >= 2);
+    }
+
+    #[test]
+    fn impact_deformation_stays_convex_and_stops_at_the_aspect_floor() {
+        let mut hull = rescale_vertices_to_area(&canonical_vertices_for_mass(30), 300.0);
+        let dir = Vec2::new(0.6, -0.8);
+        let mut hits = 0;
+        while let Some(next) = deform_hull_on_impact(&hull, dir, 0.12, 0.45) {
+            hull = next;
+            hits += 1;
+            assert!(hits < 100, "deformation never converged");
+            assert!(is_convex(&hull), "hull went concave");
+        }
+        assert!(hits > 0);
+        let (near, far) = projected_extent(&hull, dir);
+        let (side_min, side_max) = projected_extent(&hull, dir.perp());
+        assert!((far - near) >= (side_max - side_min) * 0.45 - 0.5);
+        assert!(deform_hull_on_impact(&hull, dir, 0.0, 0.45).is_none());
+    }
+
     // ── polygon_area ──────────────────────────────────────────────────────────
 
     #[test]
@@ -1501,10 +2089,12 @@ mod tests {
 
     #[test]
     fn spawn_shape_edge_subdivision_growth_is_bounded() {
-        let mut cfg = PhysicsConfig::default();
-        cfg.spawn_shape_edge_subdivision_chance = 1.0;
-        cfg.spawn_shape_subdivision_jitter_fraction = 0.0;
-        cfg.spawn_shape_noise_amplitude = 0.0;
+        let cfg = PhysicsConfig {
+            spawn_shape_edge_subdivision_chance: 1.0,
+            spawn_shape_subdivision_jitter_fraction: 0.0,
+            spawn_shape_noise_amplitude: 0.0,
+            ..Default::default()
+        };
 
         let mut rng = StdRng::seed_from_u64(99);
         let base = generate_regular_polygon(8, 1.0, 9.0);
@@ -1690,6 +2280,48 @@ mod tests {
         assert_eq!(polygon_area(&[Vec2::ZERO, Vec2::ONE]), 0.0);
     }
 
+    // ── ray_polygon_distance ──────────────────────────────────────────────────
+
+    #[test]
+    fn ray_hits_the_nearest_polygon_edge() {
+        let square = [
+            Vec2::new(90.0, -10.0),
+            Vec2::new(110.0, -10.0),
+            Vec2::new(110.0, 10.0),
+            Vec2::new(90.0, 10.0),
+        ];
+        let t = ray_polygon_distance(Vec2::ZERO, Vec2::X, &square).unwrap();
+        assert!((t - 90.0).abs() < 1e-4, "t = {t}");
+        assert_eq!(ray_polygon_distance(Vec2::ZERO, -Vec2::X, &square), None);
+        assert_eq!(ray_polygon_distance(Vec2::ZERO, Vec2::Y, &square), None);
+    }
+
+    // ── carve_asteroid_shape ──────────────────────────────────────────────────
+
+    #[test]
+    fn carve_shrinks_to_new_mass_and_records_crater() {
+        let config = PhysicsConfig::default();
+        let mass = 12;
+        let base = rescale_vertices_to_area(
+            &canonical_vertices_for_mass(mass),
+            mass as f32 / config.asteroid_density,
+        );
+        let impact = (base[0] + base[1]) * 0.5;
+        let carved = carve_asteroid_shape(&base, None, impact, mass - 1, &config);
+
+        let target_area = (mass - 1) as f32 / config.asteroid_density;
+        let base_area = polygon_area(&carved.base_vertices);
+        assert!(
+            (base_area - target_area).abs() / target_area < 1e-3,
+            "base area {base_area} vs {target_area}"
+        );
+        assert_eq!(carved.craters.craters.len(), 1);
+        assert_eq!(carved.craters.craters[0].0, impact);
+        // Edges near the crater are subdivided.
+        assert!(carved.vertices.len() > base.len());
+        assert!(Collider::convex_hull(&carved.base_vertices).is_some());
+    }
+
     // ── rescale_vertices_to_area ──────────────────────────────────────────────
 
     #[test]
@@ -1736,6 +2368,93 @@ mod tests {
         assert_eq!(unchanged, sq);
     }
 
+    // ── simplify_hull ─────────────────────────────────────────────────────────
+
+    /// A 24-gon with a near-collinear point added halfway along every edge,
+    /// as repeated merges leave behind.
+    fn merged_circle() -> Vec<Vec2> {
+        let corners: Vec<Vec2> = (0..24)
+            .map(|i| Vec2::from_angle(std::f32::consts::TAU * i as f32 / 24.0) * 20.0)
+            .collect();
+        corners
+            .iter()
+            .zip(corners.iter().cycle().skip(1))
+            .flat_map(|(&a, &b)| [a, (a + b) * 0.5 + (a + b).normalize() * 0.05])
+            .collect()
+    }
+
+    #[test]
+    fn simplify_drops_near_collinear_vertices_and_keeps_area() {
+        let hull = merged_circle();
+        let simplified = simplify_hull(&hull, 0.4);
+        // Midpoints the recursion splits on survive; the rest go.
+        assert!(
+            simplified.len() < hull.len() * 3 / 4,
+            "kept {}",
+            simplified.len()
+        );
+        assert!(simplified.len() >= 8);
+        assert!((polygon_area(&simplified) - polygon_area(&hull)).abs() < 1e-2);
+        assert!(is_strictly_convex(&simplified));
+        for v in &simplified {
+            assert!((v.length() - 20.0).abs() < 0.5, "stays on the outline: {v}");
+        }
+    }
+
+    #[test]
+    fn simplify_keeps_mass_consistent_with_density() {
+        let config = PhysicsConfig::default();
+        let mass = 180;
+        let hull =
+            rescale_vertices_to_area(&merged_circle(), mass as f32 / config.asteroid_density);
+        let simplified = simplify_hull(&hull, config.hull_simplify_epsilon);
+        let implied_mass = polygon_area(&simplified) * config.asteroid_density;
+        assert!((implied_mass - mass as f32).abs() < 0.01, "{implied_mass}");
+    }
+
+    #[test]
+    fn simplify_keeps_the_centroid_at_the_origin() {
+        // Near-collinear points only along the right half, so dropping them
+        // would pull the vertex mean left.
+        let corners: Vec<Vec2> = (0..24)
+            .map(|i| Vec2::from_angle(std::f32::consts::TAU * i as f32 / 24.0) * 20.0)
+            .collect();
+        let lopsided: Vec<Vec2> = corners
+            .iter()
+            .zip(corners.iter().cycle().skip(1))
+            .flat_map(|(&a, &b)| {
+                let mid = (a + b) * 0.5 + (a + b).normalize() * 0.05;
+                if mid.x > 0.0 {
+                    vec![a, mid]
+                } else {
+                    vec![a]
+                }
+            })
+            .collect();
+        let mean = lopsided.iter().copied().sum::<Vec2>() / lopsided.len() as f32;
+        let hull: Vec<Vec2> = lopsided.iter().map(|v| *v - mean).collect();
+
+        let simplified = simplify_hull(&hull, 0.4);
+        assert!(simplified.len() < hull.len());
+        let centroid = simplified.iter().copied().sum::<Vec2>() / simplified.len() as f32;
+        assert!(centroid.length() < 1e-3, "centroid drifted to {centroid}");
+        assert!((polygon_area(&simplified) - polygon_area(&hull)).abs() < 1e-2);
+    }
+
+    #[test]
+    fn simplify_leaves_small_and_sharp_hulls_alone() {
+        let triangle = vec![Vec2::ZERO, Vec2::new(10.0, 0.0), Vec2::new(0.0, 10.0)];
+        assert_eq!(simplify_hull(&triangle, 5.0), triangle);
+        let square = vec![
+            Vec2::new(-5.0, -5.0),
+            Vec2::new(5.0, -5.0),
+            Vec2::new(5.0, 5.0),
+            Vec2::new(-5.0, 5.0),
+        ];
+        assert_eq!(simplify_hull(&square, 0.4), square);
+        assert_eq!(simplify_hull(&merged_circle(), 0.0), merged_circle());
+    }
+
     // ── compute_convex_hull_from_points ───────────────────────────────────────
 
     #[test]
@@ -1955,22 +2674,60 @@ mod tests {
             );
         }
     }
-}
 
-/// Blend colors by averaging RGB values
-#[allow(dead_code)]
-pub fn blend_colors(particles: &[(Entity, Vec2, Color)]) -> Color {
-    let mut r = 0.0;
-    let mut g = 0.0;
-    let mut b = 0.0;
+    // ── perturb_canonical_vertices ─────────────────────────────────────────────
 
-    for (_, _, color) in particles {
-        let c = Srgba::from(*color);
-        r += c.red;
-        g += c.green;
-        b += c.blue;
+    #[test]
+    fn perturbed_canonical_hulls_stay_convex_and_keep_their_area() {
+        for mass in 1u32..=12 {
+            let canonical = canonical_vertices_for_mass(mass);
+            let area = polygon_area(&canonical);
+            for seed in 0..64u64 {
+                let hull = perturb_canonical_vertices(&canonical, seed, 0.45);
+                assert_eq!(hull.len(), canonical.len(), "mass {mass} seed {seed}");
+                assert!(is_strictly_convex(&hull), "mass {mass} seed {seed}");
+                assert!(
+                    (polygon_area(&hull) - area).abs() < area * 1e-3,
+                    "mass {mass} seed {seed}: area {} vs {area}",
+                    polygon_area(&hull)
+                );
+                assert_eq!(hull, perturb_canonical_vertices(&canonical, seed, 0.45));
+            }
+            assert_ne!(
+                perturb_canonical_vertices(&canonical, 1, 0.3),
+                perturb_canonical_vertices(&canonical, 2, 0.3),
+                "mass {mass}: different seeds give different hulls"
+            );
+        }
     }
 
-    let count = particles.len() as f32;
-    Color::srgb(r / count, g / count, b / count)
+    #[test]
+    fn fragment_vertices_follow_the_noise_toggle() {
+        let mut cfg = PhysicsConfig {
+            canonical_shape_noise_enabled: false,
+            ..Default::default()
+        };
+        let mut rng = StdRng::seed_from_u64(3);
+        let plain = fragment_vertices_for_mass(5, &mut rng, &cfg);
+        let expected =
+            rescale_vertices_to_area(&canonical_vertices_for_mass(5), 5.0 / cfg.asteroid_density);
+        assert_eq!(plain, expected);
+
+        cfg.canonical_shape_noise_enabled = true;
+        let noisy = fragment_vertices_for_mass(5, &mut rng, &cfg);
+        assert_ne!(noisy, expected);
+        assert!((polygon_area(&noisy) - polygon_area(&expected)).abs() < 1e-2);
+    }
+
+    #[test]
+    fn spawn_probability_is_finite_for_negative_coordinates() {
+        let noise = FieldNoise::from_rng(&mut StdRng::seed_from_u64(9));
+        for y in -40..40 {
+            for x in -40..40 {
+                let pos = Vec2::new(x as f32, y as f32) * 1_237.5;
+                let p = noise.spawn_probability(pos);
+                assert!((0.0..=0.72).contains(&p), "{p} at {pos}");
+            }
+        }
+    }
 }
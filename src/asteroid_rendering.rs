@@ -25,12 +25,64 @@
 //!
 //! The semi-transparent gizmo overlay (`show_wireframes`) is preserved as an
 //! additive debug option and continues to use immediate-mode gizmos.
+//!
+//! ## Level of Detail
+//!
+//! [`asteroid_lod_system`] classifies every non-planet asteroid by its distance
+//! from the camera into an [`AsteroidLod`] level:
+//!
+//! | Level        | Variant                                              |
+//! |--------------|------------------------------------------------------|
+//! | `Full`       | Hull polygon (fill or outline)                       |
+//! | `Simplified` | Low-poly disc / ring at the hull's bounding radius   |
+//! | `Hidden`     | `Visibility::Hidden` — not drawn                     |
+//!
+//! The simplified meshes are also generated at spawn time, so a level change
+//! is the same handle swap as the wireframe toggle.  Each threshold has a
+//! `asteroid_lod_hysteresis` dead band so bodies drifting along a boundary do
+//! not flicker between levels.  Under load the frame-time governor scales both
+//! thresholds toward the camera.  Per-level counts are published in
+//! [`AsteroidLodStats`] for the stats overlay.
+//!
+//! ## Shared Materials and LOD Meshes
+//!
+//! Allocating a `ColorMaterial` per asteroid fills the asset store with
+//! near-identical materials, so with `asteroid_shared_assets_enabled` the
+//! [`AsteroidRenderAssets`] cache hands out:
+//!
+//! - one fill material per entry of a fixed rock-tint palette (the per-asteroid
+//!   colour is a palette index derived from the entity);
+//! - a single white outline material and a single planet material;
+//! - one simplified disc/ring mesh per bounding-radius bucket, the canonical
+//!   shape shared by every distant asteroid of similar size.
+//!
+//! Turning the flag off falls back to the original path of per-asteroid
+//! materials and LOD meshes.
+//!
+//! ## Instanced Hulls
+//!
+//! Full-detail fills are drawn GPU-instanced by [`instancing`]: every hull
+//! with the same vertex count shares one canonical mesh and one material, and
+//! the shader pulls each asteroid's own vertices and colour from a storage
+//! buffer, so near-field draws stay flat as the field grows.  The retained
+//! per-asteroid hull meshes are still built, and are used for wireframe mode,
+//! the simplified LOD, merge ghosts, the kill-cam replay, and as the whole
+//! fallback path when instancing is unavailable or disabled with
+//! `asteroid_hull_instancing_enabled`.
 
 use crate::asteroid::{Asteroid, Planet, Vertices};
+use crate::config::PhysicsConfig;
 use crate::rendering::OverlayState;
+use crate::simulation::governor::FrameTimeGovernor;
+use bevy::camera::primitives::Aabb;
+use bevy::mesh::MeshTag;
 use bevy::prelude::*;
 use bevy_asset::RenderAssetUsages;
 use bevy_mesh::{Indices, PrimitiveTopology};
+use instancing::{AsteroidHullMaterial, HullInstanceSlot, HullInstances};
+use std::collections::HashMap;
+
+pub mod instancing;
 
 // ── Retained render handles ───────────────────────────────────────────────────
 
@@ -42,6 +94,256 @@ pub struct AsteroidRenderHandles {
     pub fill_material: Handle<ColorMaterial>,
     pub outline_mesh: Handle<Mesh>,
     pub outline_material: Handle<ColorMaterial>,
+    /// Low-poly disc used at [`AsteroidLod::Simplified`] in fill mode.
+    pub lod_fill_mesh: Handle<Mesh>,
+    /// Low-poly ring used at [`AsteroidLod::Simplified`] in wireframe mode.
+    pub lod_outline_mesh: Handle<Mesh>,
+}
+
+impl AsteroidRenderHandles {
+    /// Mesh/material pair to display for a given LOD level and render mode.
+    pub fn variant(
+        &self,
+        lod: AsteroidLod,
+        wireframe_only: bool,
+    ) -> (Handle<Mesh>, Handle<ColorMaterial>) {
+        match (lod, wireframe_only) {
+            (AsteroidLod::Full, false) => (self.fill_mesh.clone(), self.fill_material.clone()),
+            (AsteroidLod::Full, true) => (self.outline_mesh.clone(), self.outline_material.clone()),
+            (_, false) => (self.lod_fill_mesh.clone(), self.fill_material.clone()),
+            (_, true) => (self.lod_outline_mesh.clone(), self.outline_material.clone()),
+        }
+    }
+}
+
+/// What an asteroid draws for a LOD level and render mode.
+enum AsteroidVariant {
+    /// Retained per-asteroid or shared mesh with a `ColorMaterial`.
+    Retained(Handle<Mesh>, Handle<ColorMaterial>),
+    /// Canonical hull mesh with the shared instanced hull material.
+    Instanced(Handle<Mesh>, Handle<AsteroidHullMaterial>),
+}
+
+impl AsteroidVariant {
+    /// Full-detail fills of asteroids holding a [`HullInstanceSlot`] draw
+    /// instanced; everything else uses [`AsteroidRenderHandles::variant`].
+    fn resolve(
+        handles: &AsteroidRenderHandles,
+        slot: Option<&HullInstanceSlot>,
+        instances: &HullInstances,
+        lod: AsteroidLod,
+        wireframe_only: bool,
+    ) -> Self {
+        if let (Some(slot), Some(material), AsteroidLod::Full, false) =
+            (slot, instances.material(), lod, wireframe_only)
+        {
+            return Self::Instanced(slot.mesh.clone(), material);
+        }
+        let (mesh, material) = handles.variant(lod, wireframe_only);
+        Self::Retained(mesh, material)
+    }
+
+    fn mesh(&self) -> Handle<Mesh> {
+        match self {
+            Self::Retained(mesh, _) | Self::Instanced(mesh, _) => mesh.clone(),
+        }
+    }
+
+    /// Point an asteroid at this variant.  Swaps between retained variants
+    /// stay in place; entering or leaving the instanced path swaps the
+    /// material component.
+    fn show(
+        self,
+        commands: &mut Commands,
+        entity: Entity,
+        mesh: &mut Mesh2d,
+        material: Option<Mut<MeshMaterial2d<ColorMaterial>>>,
+    ) {
+        *mesh = Mesh2d(self.mesh());
+        match (self, material) {
+            (Self::Retained(_, wanted), Some(mut material)) => *material = MeshMaterial2d(wanted),
+            (Self::Retained(_, wanted), None) => {
+                commands
+                    .entity(entity)
+                    .insert(MeshMaterial2d(wanted))
+                    .remove::<MeshMaterial2d<AsteroidHullMaterial>>();
+            }
+            (Self::Instanced(_, wanted), Some(_)) => {
+                commands
+                    .entity(entity)
+                    .insert(MeshMaterial2d(wanted))
+                    .remove::<MeshMaterial2d<ColorMaterial>>();
+            }
+            (Self::Instanced(..), None) => {}
+        }
+    }
+}
+
+// ── Shared render assets ──────────────────────────────────────────────────────
+
+/// Number of distinct rock tints in the shared fill-material palette.
+const ROCK_PALETTE_SIZE: u32 = 16;
+
+/// Bounding-radius quantum (world units) for shared simplified LOD meshes.
+/// Distant bodies are at least `asteroid_lod_simplified_distance` away, so a
+/// few units of radius error is invisible.
+const LOD_RADIUS_BUCKET: f32 = 2.0;
+
+/// Cache of materials and LOD meshes shared between asteroids.  See the
+/// module docs.
+#[derive(Resource, Default)]
+pub struct AsteroidRenderAssets {
+    rock_materials: HashMap<u32, Handle<ColorMaterial>>,
+    planet_material: Option<Handle<ColorMaterial>>,
+    outline_material: Option<Handle<ColorMaterial>>,
+    lod_meshes: HashMap<u32, (Handle<Mesh>, Handle<Mesh>)>,
+}
+
+impl AsteroidRenderAssets {
+    /// Fill material for an asteroid.  Shared per palette entry when `shared`,
+    /// otherwise a fresh material with the entity's own tint.
+    fn fill_material(
+        &mut self,
+        seed: u32,
+        is_planet: bool,
+        shared: bool,
+        materials: &mut Assets<ColorMaterial>,
+    ) -> Handle<ColorMaterial> {
+        let color = |seed| {
+            if is_planet {
+                Color::srgb(0.55, 0.25, 0.85)
+            } else {
+                rock_color(seed)
+            }
+        };
+        if !shared {
+            return materials.add(ColorMaterial::from_color(color(seed)));
+        }
+        if is_planet {
+            return self
+                .planet_material
+                .get_or_insert_with(|| materials.add(ColorMaterial::from_color(color(0))))
+                .clone();
+        }
+        let slot = seed % ROCK_PALETTE_SIZE;
+        self.rock_materials
+            .entry(slot)
+            .or_insert_with(|| materials.add(ColorMaterial::from_color(color(slot))))
+            .clone()
+    }
+
+    /// White outline material used in wireframe-only mode.
+    fn outline_material(
+        &mut self,
+        shared: bool,
+        materials: &mut Assets<ColorMaterial>,
+    ) -> Handle<ColorMaterial> {
+        if !shared {
+            return materials.add(ColorMaterial::from_color(Color::WHITE));
+        }
+        self.outline_material
+            .get_or_insert_with(|| materials.add(ColorMaterial::from_color(Color::WHITE)))
+            .clone()
+    }
+
+    /// Simplified disc and ring meshes for a hull of the given bounding radius.
+    fn lod_meshes(
+        &mut self,
+        radius: f32,
+        shared: bool,
+        meshes: &mut Assets<Mesh>,
+    ) -> (Handle<Mesh>, Handle<Mesh>) {
+        let mut build = |radius: f32| {
+            (
+                meshes.add(disc_mesh(radius, LOD_CIRCLE_SEGMENTS)),
+                meshes.add(ring_mesh(radius, 0.8, LOD_CIRCLE_SEGMENTS)),
+            )
+        };
+        if !shared {
+            return build(radius);
+        }
+        let bucket = lod_radius_bucket(radius);
+        self.lod_meshes
+            .entry(bucket)
+            .or_insert_with(|| build(bucket as f32 * LOD_RADIUS_BUCKET))
+            .clone()
+    }
+}
+
+/// Bucket index for a bounding radius, rounding up so the stand-in disc never
+/// looks smaller than the hull it replaces.
+fn lod_radius_bucket(radius: f32) -> u32 {
+    (radius / LOD_RADIUS_BUCKET).ceil().max(1.0) as u32
+}
+
+// ── Level of detail ───────────────────────────────────────────────────────────
+
+/// Segment count of the simplified disc/ring meshes.
+const LOD_CIRCLE_SEGMENTS: usize = 8;
+
+/// Distance-based render detail level of an asteroid.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum AsteroidLod {
+    #[default]
+    Full,
+    Simplified,
+    Hidden,
+}
+
+/// Per-level asteroid counts from the most recent [`asteroid_lod_system`] pass.
+#[derive(Resource, Default, Clone, Copy, Debug)]
+pub struct AsteroidLodStats {
+    pub full: u32,
+    pub simplified: u32,
+    pub hidden: u32,
+}
+
+/// Pick the LOD level for a body at `distance` from the camera.
+///
+/// Thresholds are shifted outward by `asteroid_lod_hysteresis` while the body
+/// is nearer than them and inward while it is beyond them, so a level only
+/// changes once the body clears the dead band.
+pub fn next_asteroid_lod(
+    current: AsteroidLod,
+    distance: f32,
+    config: &PhysicsConfig,
+) -> AsteroidLod {
+    if !config.asteroid_lod_enabled {
+        return AsteroidLod::Full;
+    }
+    let band = config.asteroid_lod_hysteresis.max(0.0);
+    let simplified = config.asteroid_lod_simplified_distance;
+    let hidden = config.asteroid_lod_hidden_distance.max(simplified);
+    let boundary = |threshold: f32, beyond: bool| {
+        if beyond {
+            threshold - band
+        } else {
+            threshold + band
+        }
+    };
+
+    if distance > boundary(hidden, current == AsteroidLod::Hidden) {
+        AsteroidLod::Hidden
+    } else if distance > boundary(simplified, current >= AsteroidLod::Simplified) {
+        AsteroidLod::Simplified
+    } else {
+        AsteroidLod::Full
+    }
+}
+
+/// Bounding radius of a local-space hull, used to size the simplified meshes.
+fn bounding_radius(vertices: &[Vec2]) -> f32 {
+    vertices.iter().map(|v| v.length()).fold(0.0, f32::max)
+}
+
+/// Culling bounds covering every render variant of a hull: the simplified
+/// disc rounds its radius up to the next bucket and the outline and ring add
+/// up to 0.4 units.  Set explicitly because Bevy computes a `Mesh2d`'s bounds
+/// once, from the first mesh it shows, and the instanced canonical mesh is a
+/// unit polygon.
+fn hull_aabb(vertices: &[Vec2]) -> Aabb {
+    let r = lod_radius_bucket(bounding_radius(vertices)) as f32 * LOD_RADIUS_BUCKET + 0.4;
+    Aabb::from_min_max(Vec3::new(-r, -r, 0.0), Vec3::new(r, r, 0.0))
 }
 
 // ── Spawn-time mesh attachment ────────────────────────────────────────────────
@@ -61,13 +363,23 @@ pub struct AsteroidRenderHandles {
 /// Both mesh/material handles are stored in [`AsteroidRenderHandles`] so
 /// `sync_asteroid_render_mode_system` can swap between them instantly on
 /// `wireframe_only` toggle with no per-frame CPU cost.
+///
+/// Materials and LOD meshes come from [`AsteroidRenderAssets`], shared across
+/// asteroids when `asteroid_shared_assets_enabled` is set.  With
+/// `asteroid_hull_instancing_enabled` the asteroid also claims a
+/// [`HullInstanceSlot`] so its full-detail fill draws instanced.
+#[allow(clippy::too_many_arguments)]
 pub fn attach_asteroid_mesh_system(
     mut commands: Commands,
     query: Query<(Entity, &Vertices, Option<&Planet>), Added<Asteroid>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    mut shared_assets: ResMut<AsteroidRenderAssets>,
+    mut hull_instances: ResMut<HullInstances>,
+    config: Res<PhysicsConfig>,
     overlay: Res<OverlayState>,
 ) {
+    let shared = config.asteroid_shared_assets_enabled;
     for (entity, vertices, is_planet) in query.iter() {
         if vertices.0.len() < 3 {
             continue;
@@ -75,35 +387,64 @@ pub fn attach_asteroid_mesh_system(
 
         // ── Filled polygon mesh ───────────────────────────────────────────────
         let fill_mesh = meshes.add(filled_polygon_mesh(&vertices.0));
-        let fill_color = if is_planet.is_some() {
-            Color::srgb(0.55, 0.25, 0.85)
-        } else {
-            rock_color(entity.index())
-        };
-        let fill_material = materials.add(ColorMaterial::from_color(fill_color));
+        let fill_material = shared_assets.fill_material(
+            entity.index(),
+            is_planet.is_some(),
+            shared,
+            &mut materials,
+        );
 
         // ── Polygon outline mesh (used in wireframe_only mode) ────────────────
         // 0.4-unit half-width gives a crisp but thin outline at typical zoom levels.
         let outline_mesh = meshes.add(polygon_outline_mesh(&vertices.0, 0.4));
-        let outline_material = materials.add(ColorMaterial::from_color(Color::WHITE));
+        let outline_material = shared_assets.outline_material(shared, &mut materials);
+
+        // ── Simplified LOD meshes ─────────────────────────────────────────────
+        let (lod_fill_mesh, lod_outline_mesh) =
+            shared_assets.lod_meshes(bounding_radius(&vertices.0), shared, &mut meshes);
+
+        let handles = AsteroidRenderHandles {
+            fill_mesh,
+            fill_material,
+            outline_mesh,
+            outline_material,
+            lod_fill_mesh,
+            lod_outline_mesh,
+        };
 
-        // Start in whichever mode is current (fill vs wireframe).
-        let (active_mesh, active_material) = if overlay.wireframe_only {
-            (outline_mesh.clone(), outline_material.clone())
+        // ── Instanced hull slot ───────────────────────────────────────────────
+        let slot = if config.asteroid_hull_instancing_enabled {
+            let color = materials
+                .get(&handles.fill_material)
+                .map_or(Color::WHITE, |material| material.color);
+            hull_instances.allocate(entity, &vertices.0, color, &mut meshes)
         } else {
-            (fill_mesh.clone(), fill_material.clone())
+            None
         };
 
-        commands.entity(entity).insert((
-            Mesh2d(active_mesh),
-            MeshMaterial2d(active_material),
-            AsteroidRenderHandles {
-                fill_mesh,
-                fill_material,
-                outline_mesh,
-                outline_material,
-            },
-        ));
+        // Start at full detail in whichever mode is current (fill vs
+        // wireframe); `asteroid_lod_system` demotes distant bodies next frame.
+        let variant = AsteroidVariant::resolve(
+            &handles,
+            slot.as_ref(),
+            &hull_instances,
+            AsteroidLod::Full,
+            overlay.wireframe_only,
+        );
+
+        let mut entity_commands = commands.entity(entity);
+        entity_commands.insert((handles, AsteroidLod::Full, hull_aabb(&vertices.0)));
+        match variant {
+            AsteroidVariant::Retained(mesh, material) => {
+                entity_commands.insert((Mesh2d(mesh), MeshMaterial2d(material)))
+            }
+            AsteroidVariant::Instanced(mesh, material) => {
+                entity_commands.insert((Mesh2d(mesh), MeshMaterial2d(material)))
+            }
+        };
+        if let Some(slot) = slot {
+            entity_commands.insert((MeshTag(slot.index), slot));
+        }
     }
 }
 
@@ -112,14 +453,20 @@ pub fn attach_asteroid_mesh_system(
 ///
 /// Because both variants are pre-generated at spawn time this is a pure
 /// handle-swap with zero mesh rebuilds — it runs only when the flag changes,
-/// not every frame.
+/// not every frame.  The current [`AsteroidLod`] level is preserved.
+#[allow(clippy::type_complexity)]
 pub fn sync_asteroid_render_mode_system(
+    mut commands: Commands,
     overlay: Res<OverlayState>,
+    hull_instances: Res<HullInstances>,
     mut query: Query<
         (
+            Entity,
             &mut Mesh2d,
-            &mut MeshMaterial2d<ColorMaterial>,
+            Option<&mut MeshMaterial2d<ColorMaterial>>,
             &AsteroidRenderHandles,
+            Option<&AsteroidLod>,
+            Option<&HullInstanceSlot>,
         ),
         With<Asteroid>,
     >,
@@ -127,27 +474,114 @@ pub fn sync_asteroid_render_mode_system(
     if !overlay.is_changed() {
         return;
     }
-    for (mut mesh, mut material, handles) in query.iter_mut() {
-        if overlay.wireframe_only {
-            *mesh = Mesh2d(handles.outline_mesh.clone());
-            *material = MeshMaterial2d(handles.outline_material.clone());
-        } else {
-            *mesh = Mesh2d(handles.fill_mesh.clone());
-            *material = MeshMaterial2d(handles.fill_material.clone());
+    for (entity, mut mesh, material, handles, lod, slot) in query.iter_mut() {
+        let lod = lod.copied().unwrap_or_default();
+        AsteroidVariant::resolve(handles, slot, &hull_instances, lod, overlay.wireframe_only).show(
+            &mut commands,
+            entity,
+            &mut mesh,
+            material,
+        );
+    }
+}
+
+/// Reclassify asteroids by camera distance and swap their render variant when
+/// the [`AsteroidLod`] level changes.
+///
+/// Planets are excluded and always render at full detail.  Counts per level
+/// are written to [`AsteroidLodStats`] every frame.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn asteroid_lod_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    governor: Res<FrameTimeGovernor>,
+    overlay: Res<OverlayState>,
+    hull_instances: Res<HullInstances>,
+    mut lod_stats: ResMut<AsteroidLodStats>,
+    q_camera: Query<&Transform, With<Camera>>,
+    mut query: Query<
+        (
+            Entity,
+            &Transform,
+            &AsteroidRenderHandles,
+            Option<&HullInstanceSlot>,
+            &mut AsteroidLod,
+            &mut Mesh2d,
+            Option<&mut MeshMaterial2d<ColorMaterial>>,
+            &mut Visibility,
+        ),
+        (With<Asteroid>, Without<Planet>, Without<Camera>),
+    >,
+) {
+    let Ok(camera) = q_camera.single() else {
+        return;
+    };
+    let camera_pos = camera.translation.truncate();
+    // Under load the governor pulls the LOD thresholds in; dividing the
+    // distance instead keeps `next_asteroid_lod` on the configured values.
+    let distance_scale = governor.effective_lod_distance_scale(&config);
+
+    let mut counts = AsteroidLodStats::default();
+    for (entity, transform, handles, slot, mut lod, mut mesh, material, mut visibility) in
+        query.iter_mut()
+    {
+        let distance = transform.translation.truncate().distance(camera_pos) / distance_scale;
+        let next = next_asteroid_lod(*lod, distance, &config);
+
+        match next {
+            AsteroidLod::Full => counts.full += 1,
+            AsteroidLod::Simplified => counts.simplified += 1,
+            AsteroidLod::Hidden => counts.hidden += 1,
         }
+
+        if next == *lod {
+            continue;
+        }
+        AsteroidVariant::resolve(handles, slot, &hull_instances, next, overlay.wireframe_only)
+            .show(&mut commands, entity, &mut mesh, material);
+        *visibility = if next == AsteroidLod::Hidden {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        };
+        *lod = next;
     }
+    *lod_stats = counts;
 }
 
 /// Rebuild retained asteroid meshes when local-space polygon vertices change.
 ///
 /// This keeps visual geometry in sync for in-place deformation paths while
 /// preserving the retained-mesh rendering model.
-#[allow(clippy::type_complexity)]
+///
+/// Hull meshes are per-asteroid and are rewritten in place.  LOD meshes may be
+/// shared, so they are re-resolved from [`AsteroidRenderAssets`] instead, and
+/// the active `Mesh2d` is updated if the asteroid is currently simplified.
+/// Instanced hulls rewrite their slot and may move to the canonical mesh of a
+/// new vertex count; a hull that outgrows the instanced path falls back to its
+/// retained mesh.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub fn refresh_asteroid_mesh_on_vertices_change_system(
+    mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
-    query: Query<(&Vertices, &AsteroidRenderHandles), (With<Asteroid>, Changed<Vertices>)>,
+    mut shared_assets: ResMut<AsteroidRenderAssets>,
+    mut hull_instances: ResMut<HullInstances>,
+    config: Res<PhysicsConfig>,
+    overlay: Res<OverlayState>,
+    mut query: Query<
+        (
+            Entity,
+            &Vertices,
+            &mut AsteroidRenderHandles,
+            Option<&AsteroidLod>,
+            Option<&mut HullInstanceSlot>,
+            &mut Mesh2d,
+            Option<&mut MeshMaterial2d<ColorMaterial>>,
+        ),
+        (With<Asteroid>, Changed<Vertices>),
+    >,
 ) {
-    for (vertices, handles) in query.iter() {
+    for (entity, vertices, mut handles, lod, mut slot, mut mesh, material) in query.iter_mut() {
         if vertices.0.len() < 3 {
             continue;
         }
@@ -158,6 +592,35 @@ pub fn refresh_asteroid_mesh_on_vertices_change_system(
         if let Some(outline_mesh) = meshes.get_mut(&handles.outline_mesh) {
             *outline_mesh = polygon_outline_mesh(&vertices.0, 0.4);
         }
+
+        let (lod_fill_mesh, lod_outline_mesh) = shared_assets.lod_meshes(
+            bounding_radius(&vertices.0),
+            config.asteroid_shared_assets_enabled,
+            &mut meshes,
+        );
+        handles.lod_fill_mesh = lod_fill_mesh;
+        handles.lod_outline_mesh = lod_outline_mesh;
+        commands.entity(entity).insert(hull_aabb(&vertices.0));
+
+        let fell_back = match slot.as_mut() {
+            Some(slot) => !hull_instances.set_vertices(slot, &vertices.0, &mut meshes),
+            None => false,
+        };
+        if fell_back {
+            commands
+                .entity(entity)
+                .remove::<(HullInstanceSlot, MeshTag)>();
+        }
+
+        let lod = lod.copied().unwrap_or_default();
+        let slot = if fell_back { None } else { slot.as_deref() };
+        let variant =
+            AsteroidVariant::resolve(&handles, slot, &hull_instances, lod, overlay.wireframe_only);
+        if fell_back {
+            variant.show(&mut commands, entity, &mut mesh, material);
+        } else if lod != AsteroidLod::Full || matches!(variant, AsteroidVariant::Instanced(..)) {
+            *mesh = Mesh2d(variant.mesh());
+        }
     }
 }
 
@@ -313,3 +776,104 @@ pub fn ring_mesh(radius: f32, thickness: f32, segments: usize) -> Mesh {
     mesh.insert_indices(Indices::U32(indices));
     mesh
 }
+
+/// Build a filled regular `segments`-gon of the given circumradius.
+///
+/// Used as the simplified LOD stand-in for distant asteroids.
+pub fn disc_mesh(radius: f32, segments: usize) -> Mesh {
+    let vertices: Vec<Vec2> = (0..segments.max(3))
+        .map(|i| {
+            let theta = (i as f32 / segments.max(3) as f32) * std::f32::consts::TAU;
+            Vec2::from_angle(theta) * radius
+        })
+        .collect();
+    filled_polygon_mesh(&vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lod_levels_follow_camera_distance() {
+        let config = PhysicsConfig::default();
+        let near = config.asteroid_lod_simplified_distance * 0.5;
+        let mid =
+            (config.asteroid_lod_simplified_distance + config.asteroid_lod_hidden_distance) * 0.5;
+        let far = config.asteroid_lod_hidden_distance * 2.0;
+
+        assert_eq!(
+            next_asteroid_lod(AsteroidLod::Full, near, &config),
+            AsteroidLod::Full
+        );
+        assert_eq!(
+            next_asteroid_lod(AsteroidLod::Full, mid, &config),
+            AsteroidLod::Simplified
+        );
+        assert_eq!(
+            next_asteroid_lod(AsteroidLod::Full, far, &config),
+            AsteroidLod::Hidden
+        );
+    }
+
+    #[test]
+    fn lod_hysteresis_holds_level_inside_dead_band() {
+        let config = PhysicsConfig::default();
+        let threshold = config.asteroid_lod_simplified_distance;
+        let inside_band = threshold + config.asteroid_lod_hysteresis * 0.5;
+
+        // Approaching from inside: not yet past threshold + band.
+        assert_eq!(
+            next_asteroid_lod(AsteroidLod::Full, inside_band, &config),
+            AsteroidLod::Full
+        );
+        // Receding from outside: not yet back below threshold − band.
+        let inside_band = threshold - config.asteroid_lod_hysteresis * 0.5;
+        assert_eq!(
+            next_asteroid_lod(AsteroidLod::Simplified, inside_band, &config),
+            AsteroidLod::Simplified
+        );
+    }
+
+    #[test]
+    fn lod_radius_bucket_rounds_up() {
+        assert_eq!(lod_radius_bucket(0.0), 1);
+        assert_eq!(lod_radius_bucket(LOD_RADIUS_BUCKET), 1);
+        assert_eq!(lod_radius_bucket(LOD_RADIUS_BUCKET + 0.01), 2);
+    }
+
+    #[test]
+    fn shared_assets_reuse_materials_and_lod_meshes() {
+        let mut materials = Assets::<ColorMaterial>::default();
+        let mut meshes = Assets::<Mesh>::default();
+        let mut assets = AsteroidRenderAssets::default();
+
+        let a = assets.fill_material(3, false, true, &mut materials);
+        let b = assets.fill_material(3 + ROCK_PALETTE_SIZE, false, true, &mut materials);
+        assert_eq!(a, b);
+        assert_eq!(
+            assets.outline_material(true, &mut materials),
+            assets.outline_material(true, &mut materials)
+        );
+        assert_eq!(
+            assets.lod_meshes(10.1, true, &mut meshes),
+            assets.lod_meshes(11.9, true, &mut meshes)
+        );
+
+        // Fallback path allocates per asteroid.
+        let c = assets.fill_material(3, false, false, &mut materials);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn disabled_lod_always_renders_full_detail() {
+        let config = PhysicsConfig {
+            asteroid_lod_enabled: false,
+            ..Default::default()
+        };
+        assert_eq!(
+            next_asteroid_lod(AsteroidLod::Hidden, 1.0e6, &config),
+            AsteroidLod::Full
+        );
+    }
+}
@@ -0,0 +1,441 @@
+//! GPU-instanced drawing of full-detail asteroid hulls.
+//!
+//! Hull silhouettes are unique per asteroid, so instead of one mesh per hull
+//! every asteroid with `n` hull vertices shares the canonical `n`-vertex fan
+//! from [`HullInstances`].  The canonical mesh carries only the vertex index
+//! (in `UV_0.x`); the vertex shader pulls the actual local-space position and
+//! the fill colour from a storage buffer slot selected by the entity's
+//! [`MeshTag`](bevy::mesh::MeshTag).  The per-instance transform is the
+//! entity's own Rapier-driven `Transform`, which Bevy already uploads per
+//! instance.
+//!
+//! All instanced hulls share one [`AsteroidHullMaterial`], so the opaque 2D
+//! phase batches every hull of the same vertex count into a single instanced
+//! draw — at most [`MAX_INSTANCED_HULL_VERTICES`] − 2 draws however many
+//! asteroids are near the camera.
+//!
+//! Only the full-detail fill is instanced.  Wireframe outlines, simplified LOD
+//! discs, merge ghosts and the kill-cam replay keep using the retained
+//! per-asteroid meshes in [`AsteroidRenderHandles`](super::AsteroidRenderHandles),
+//! which are also the whole fallback path when instancing is unavailable
+//! (no storage buffers on the device, or `asteroid_hull_instancing_enabled`
+//! off) or a hull has more than [`MAX_INSTANCED_HULL_VERTICES`] vertices.
+
+use bevy::prelude::*;
+use bevy::render::render_resource::{AsBindGroup, ShaderType};
+use bevy::render::renderer::RenderDevice;
+use bevy::render::storage::ShaderStorageBuffer;
+use bevy::shader::ShaderRef;
+use bevy::sprite_render::{Material2d, Material2dPlugin};
+use bevy_asset::RenderAssetUsages;
+use bevy_mesh::{Indices, PrimitiveTopology};
+use std::collections::HashMap;
+
+const HULL_SHADER_PATH: &str = "shaders/asteroid_hull.wgsl";
+
+/// Largest hull the instanced path draws; must match `MAX_HULL_VERTICES` in
+/// the WGSL file.  Bigger hulls stay on their retained mesh.
+pub const MAX_INSTANCED_HULL_VERTICES: usize = 32;
+
+/// One storage-buffer slot: a hull's local-space vertices and linear fill
+/// colour.
+#[derive(Clone, Copy, Debug, PartialEq, ShaderType)]
+pub struct HullInstance {
+    pub vertices: [Vec2; MAX_INSTANCED_HULL_VERTICES],
+    pub color: Vec4,
+}
+
+impl Default for HullInstance {
+    fn default() -> Self {
+        Self {
+            vertices: [Vec2::ZERO; MAX_INSTANCED_HULL_VERTICES],
+            color: Vec4::ZERO,
+        }
+    }
+}
+
+/// Material shared by every instanced hull.
+#[derive(Asset, TypePath, AsBindGroup, Clone, Debug)]
+pub struct AsteroidHullMaterial {
+    #[storage(0, read_only)]
+    pub hulls: Handle<ShaderStorageBuffer>,
+}
+
+impl Material2d for AsteroidHullMaterial {
+    fn vertex_shader() -> ShaderRef {
+        HULL_SHADER_PATH.into()
+    }
+
+    fn fragment_shader() -> ShaderRef {
+        HULL_SHADER_PATH.into()
+    }
+}
+
+/// Storage-buffer slot held by an instanced asteroid.  The same index is in
+/// the entity's [`MeshTag`](bevy::mesh::MeshTag).
+#[derive(Component, Clone, Debug)]
+pub struct HullInstanceSlot {
+    pub index: u32,
+    /// Canonical mesh for the hull's vertex count.
+    pub mesh: Handle<Mesh>,
+    /// Fill colour without transient tints such as the hit flash.
+    pub color: Color,
+}
+
+/// Slots, canonical meshes and the shared material of the instanced path.
+/// See the module docs.
+#[derive(Resource, Default)]
+pub struct HullInstances {
+    /// `None` until [`AsteroidHullInstancingPlugin`] finds a device with
+    /// storage buffers; every asteroid then takes the fallback path.
+    material: Option<Handle<AsteroidHullMaterial>>,
+    buffer: Option<Handle<ShaderStorageBuffer>>,
+    instances: Vec<HullInstance>,
+    owners: HashMap<Entity, u32>,
+    free: Vec<u32>,
+    meshes: HashMap<usize, Handle<Mesh>>,
+    dirty: bool,
+}
+
+impl HullInstances {
+    /// Shared hull material, if the instanced path is available.
+    pub fn material(&self) -> Option<Handle<AsteroidHullMaterial>> {
+        self.material.clone()
+    }
+
+    /// Whether a hull with this many vertices can be drawn instanced.
+    pub fn fits(vertices: &[Vec2]) -> bool {
+        (3..=MAX_INSTANCED_HULL_VERTICES).contains(&vertices.len())
+    }
+
+    /// Claim a slot for `entity`, or `None` when the instanced path is
+    /// unavailable or the hull does not fit.
+    pub fn allocate(
+        &mut self,
+        entity: Entity,
+        vertices: &[Vec2],
+        color: Color,
+        meshes: &mut Assets<Mesh>,
+    ) -> Option<HullInstanceSlot> {
+        if self.material.is_none() || !Self::fits(vertices) {
+            return None;
+        }
+        let index = self.free.pop().unwrap_or_else(|| {
+            self.instances.push(HullInstance::default());
+            self.instances.len() as u32 - 1
+        });
+        self.owners.insert(entity, index);
+        let mut slot = HullInstanceSlot {
+            index,
+            mesh: Handle::default(),
+            color,
+        };
+        self.set_vertices(&mut slot, vertices, meshes);
+        self.set_color(index, color);
+        Some(slot)
+    }
+
+    /// Rewrite a slot's hull after deformation.  Returns `false` when the new
+    /// hull no longer fits, in which case the asteroid should fall back.
+    pub fn set_vertices(
+        &mut self,
+        slot: &mut HullInstanceSlot,
+        vertices: &[Vec2],
+        meshes: &mut Assets<Mesh>,
+    ) -> bool {
+        if !Self::fits(vertices) {
+            return false;
+        }
+        let instance = &mut self.instances[slot.index as usize];
+        instance.vertices = [Vec2::ZERO; MAX_INSTANCED_HULL_VERTICES];
+        instance.vertices[..vertices.len()].copy_from_slice(vertices);
+        slot.mesh = self
+            .meshes
+            .entry(vertices.len())
+            .or_insert_with(|| meshes.add(canonical_hull_mesh(vertices.len())))
+            .clone();
+        self.dirty = true;
+        true
+    }
+
+    /// Set the drawn colour of a slot.  Only marks the buffer for upload when
+    /// the colour actually changes.
+    pub fn set_color(&mut self, index: u32, color: Color) {
+        let color = LinearRgba::from(color).to_vec4();
+        let instance = &mut self.instances[index as usize];
+        if instance.color != color {
+            instance.color = color;
+            self.dirty = true;
+        }
+    }
+
+    /// Return `entity`'s slot to the free list.
+    fn release(&mut self, entity: Entity) {
+        if let Some(index) = self.owners.remove(&entity) {
+            self.instances[index as usize] = HullInstance::default();
+            self.free.push(index);
+            self.dirty = true;
+        }
+    }
+}
+
+/// Fan-triangulated `n`-gon whose `UV_0.x` is the vertex index the shader
+/// looks up.  Positions are a unit polygon so the mesh is still well formed on
+/// its own.
+pub fn canonical_hull_mesh(n: usize) -> Mesh {
+    let positions: Vec<[f32; 3]> = (0..n)
+        .map(|i| {
+            let v = Vec2::from_angle(i as f32 / n as f32 * std::f32::consts::TAU);
+            [v.x, v.y, 0.0]
+        })
+        .collect();
+    let uvs: Vec<[f32; 2]> = (0..n).map(|i| [i as f32, 0.0]).collect();
+
+    let mut indices: Vec<u32> = Vec::with_capacity((n - 2) * 3);
+    for i in 1..(n as u32 - 1) {
+        indices.extend_from_slice(&[0, i, i + 1]);
+    }
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+pub struct AsteroidHullInstancingPlugin;
+
+impl Plugin for AsteroidHullInstancingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<HullInstances>()
+            .add_plugins(Material2dPlugin::<AsteroidHullMaterial>::default())
+            .add_systems(PostUpdate, sync_hull_instances_system);
+    }
+
+    /// The render device only exists once the renderer has finished, so the
+    /// storage-buffer check and the shared material wait until here.
+    fn finish(&self, app: &mut App) {
+        let supported = app
+            .world()
+            .get_resource::<RenderDevice>()
+            .is_some_and(|device| device.limits().max_storage_buffers_per_shader_stage > 0);
+        if !supported {
+            info!("Storage buffers unavailable; asteroid hulls use per-asteroid meshes");
+            return;
+        }
+        let world = app.world_mut();
+        // Never empty: a zero-sized storage binding is invalid.
+        let buffer = world
+            .resource_mut::<Assets<ShaderStorageBuffer>>()
+            .add(ShaderStorageBuffer::from(vec![HullInstance::default()]));
+        let material =
+            world
+                .resource_mut::<Assets<AsteroidHullMaterial>>()
+                .add(AsteroidHullMaterial {
+                    hulls: buffer.clone(),
+                });
+        let mut instances = world.resource_mut::<HullInstances>();
+        instances.material = Some(material);
+        instances.buffer = Some(buffer);
+    }
+}
+
+/// Free the slots of despawned or demoted asteroids and upload the slot
+/// array when anything changed this frame.
+pub fn sync_hull_instances_system(
+    mut removed: RemovedComponents<HullInstanceSlot>,
+    mut instances: ResMut<HullInstances>,
+    mut buffers: ResMut<Assets<ShaderStorageBuffer>>,
+    mut materials: ResMut<Assets<AsteroidHullMaterial>>,
+) {
+    for entity in removed.read() {
+        instances.release(entity);
+    }
+    if !instances.dirty {
+        return;
+    }
+    let (Some(buffer), Some(material)) = (instances.buffer.clone(), instances.material.clone())
+    else {
+        return;
+    };
+    if let Some(buffer) = buffers.get_mut(&buffer) {
+        let mut data = instances.instances.clone();
+        if data.is_empty() {
+            data.push(HullInstance::default());
+        }
+        buffer.set_data(data);
+    }
+    // Touching the material rebuilds its bind group against the new buffer.
+    materials.get_mut(&material);
+    instances.dirty = false;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroid_rendering::{AsteroidLod, AsteroidRenderHandles, AsteroidVariant};
+
+    fn available() -> HullInstances {
+        HullInstances {
+            material: Some(Handle::default()),
+            ..Default::default()
+        }
+    }
+
+    fn ngon(n: usize) -> Vec<Vec2> {
+        (0..n)
+            .map(|i| Vec2::from_angle(i as f32 / n as f32 * std::f32::consts::TAU) * 5.0)
+            .collect()
+    }
+
+    #[test]
+    fn hulls_share_one_canonical_mesh_per_vertex_count() {
+        let mut meshes = Assets::<Mesh>::default();
+        let mut instances = available();
+        let a = instances
+            .allocate(
+                Entity::from_raw_u32(1).unwrap(),
+                &ngon(6),
+                Color::WHITE,
+                &mut meshes,
+            )
+            .unwrap();
+        let b = instances
+            .allocate(
+                Entity::from_raw_u32(2).unwrap(),
+                &ngon(6),
+                Color::BLACK,
+                &mut meshes,
+            )
+            .unwrap();
+        let c = instances
+            .allocate(
+                Entity::from_raw_u32(3).unwrap(),
+                &ngon(7),
+                Color::WHITE,
+                &mut meshes,
+            )
+            .unwrap();
+
+        assert_eq!(a.mesh, b.mesh);
+        assert_ne!(a.mesh, c.mesh);
+        assert_ne!(a.index, b.index);
+        assert_eq!(meshes.len(), 2);
+        assert_eq!(
+            instances.instances[b.index as usize].vertices[5],
+            ngon(6)[5]
+        );
+        assert_eq!(
+            instances.instances[b.index as usize].color,
+            Vec4::new(0.0, 0.0, 0.0, 1.0)
+        );
+    }
+
+    #[test]
+    fn released_slots_are_reused() {
+        let mut meshes = Assets::<Mesh>::default();
+        let mut instances = available();
+        let first = Entity::from_raw_u32(1).unwrap();
+        let slot = instances
+            .allocate(first, &ngon(5), Color::WHITE, &mut meshes)
+            .unwrap();
+        instances.release(first);
+        let again = instances
+            .allocate(
+                Entity::from_raw_u32(2).unwrap(),
+                &ngon(5),
+                Color::WHITE,
+                &mut meshes,
+            )
+            .unwrap();
+
+        assert_eq!(slot.index, again.index);
+        assert_eq!(instances.instances.len(), 1);
+    }
+
+    #[test]
+    fn unsupported_devices_and_oversized_hulls_fall_back() {
+        let mut meshes = Assets::<Mesh>::default();
+        let entity = Entity::from_raw_u32(1).unwrap();
+        assert!(HullInstances::default()
+            .allocate(entity, &ngon(5), Color::WHITE, &mut meshes)
+            .is_none());
+
+        let mut instances = available();
+        let big = ngon(MAX_INSTANCED_HULL_VERTICES + 1);
+        assert!(instances
+            .allocate(entity, &big, Color::WHITE, &mut meshes)
+            .is_none());
+        let mut slot = instances
+            .allocate(entity, &ngon(5), Color::WHITE, &mut meshes)
+            .unwrap();
+        assert!(!instances.set_vertices(&mut slot, &big, &mut meshes));
+    }
+
+    #[test]
+    fn colour_changes_mark_the_buffer_dirty_only_when_they_differ() {
+        let mut meshes = Assets::<Mesh>::default();
+        let mut instances = available();
+        let slot = instances
+            .allocate(
+                Entity::from_raw_u32(1).unwrap(),
+                &ngon(4),
+                Color::WHITE,
+                &mut meshes,
+            )
+            .unwrap();
+        instances.dirty = false;
+
+        instances.set_color(slot.index, Color::WHITE);
+        assert!(!instances.dirty);
+        instances.set_color(slot.index, Color::srgb(1.0, 0.0, 0.0));
+        assert!(instances.dirty);
+    }
+
+    #[test]
+    fn only_full_detail_fills_with_a_slot_draw_instanced() {
+        let mut meshes = Assets::<Mesh>::default();
+        let mut instances = available();
+        let slot = instances
+            .allocate(
+                Entity::from_raw_u32(1).unwrap(),
+                &ngon(5),
+                Color::WHITE,
+                &mut meshes,
+            )
+            .unwrap();
+        let handles = AsteroidRenderHandles {
+            fill_mesh: Handle::default(),
+            fill_material: Handle::default(),
+            outline_mesh: Handle::default(),
+            outline_material: Handle::default(),
+            lod_fill_mesh: Handle::default(),
+            lod_outline_mesh: Handle::default(),
+        };
+        let instanced = |slot, instances: &HullInstances, lod, wireframe| {
+            matches!(
+                AsteroidVariant::resolve(&handles, slot, instances, lod, wireframe),
+                AsteroidVariant::Instanced(..)
+            )
+        };
+
+        assert!(instanced(Some(&slot), &instances, AsteroidLod::Full, false));
+        assert!(!instanced(Some(&slot), &instances, AsteroidLod::Full, true));
+        assert!(!instanced(
+            Some(&slot),
+            &instances,
+            AsteroidLod::Simplified,
+            false
+        ));
+        assert!(!instanced(None, &instances, AsteroidLod::Full, false));
+        assert!(!instanced(
+            Some(&slot),
+            &HullInstances::default(),
+            AsteroidLod::Full,
+            false
+        ));
+    }
+}
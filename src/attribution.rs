@@ -0,0 +1,190 @@
+//! Kill credit: which actor destroyed an asteroid or enemy.
+//!
+//! Every kill writes a [`KillCredited`] message naming the [`Actor`]
+//! responsible.  Direct kills (a shot that destroys its target) are credited
+//! to the shooter and scored where they happen.  Indirect kills are credited
+//! through whatever the shooter left behind:
+//!
+//! - a lit volatile remembers who lit it ([`VolatileFuse::by`]); its
+//!   detonation, the rocks and enemies its blast destroys, and the volatiles
+//!   it chains into are all credited to that actor;
+//! - an enemy damaged by the player carries [`LastDamagedBy`], so an asteroid
+//!   collision that finishes it off is still the player's kill.  Enemies
+//!   nobody damaged die to [`Actor::Environment`].
+//!
+//! [`credit_indirect_kills_system`] awards the destroy bonus, the kill count
+//! and the per-weapon session stat for indirect kills by the player.  Kills by
+//! enemies or the environment score nothing.
+//!
+//! [`VolatileFuse::by`]: crate::simulation::volatile::VolatileFuse
+
+use crate::config::PhysicsConfig;
+use crate::enemy::EnemyArchetype;
+use crate::menu::GameState;
+use crate::player::PlayerScore;
+use crate::scoring::ScoringConfig;
+use crate::session_stats::{SessionStatEvent, StatWeapon};
+use bevy::prelude::*;
+
+/// Who dealt the damage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum Actor {
+    /// The player, through `weapon`.
+    Player(StatWeapon),
+    /// An enemy ship or the boss.
+    Enemy,
+    /// Collisions and blasts nobody set off.
+    Environment,
+}
+
+impl Actor {
+    /// The player's weapon, if the player is the actor.
+    pub fn player_weapon(self) -> Option<StatWeapon> {
+        match self {
+            Actor::Player(weapon) => Some(weapon),
+            Actor::Enemy | Actor::Environment => None,
+        }
+    }
+}
+
+/// The last actor that damaged this entity without killing it.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
+pub struct LastDamagedBy(pub Actor);
+
+/// Credit for a kill with no direct damager: the last actor that damaged the
+/// victim, else the environment.
+pub fn credit_for(last: Option<&LastDamagedBy>) -> Actor {
+    last.map_or(Actor::Environment, |last| last.0)
+}
+
+/// What was killed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KillVictim {
+    Asteroid,
+    Enemy(EnemyArchetype),
+}
+
+/// `victim` was destroyed by `by`.  `indirect` kills (blasts, collisions)
+/// were not scored where they happened.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KillCredited {
+    pub victim: KillVictim,
+    pub by: Actor,
+    pub indirect: bool,
+}
+
+/// Score indirect kills credited to the player.
+pub fn credit_indirect_kills_system(
+    mut kills: MessageReader<KillCredited>,
+    mut score: ResMut<PlayerScore>,
+    scoring: Res<ScoringConfig>,
+    config: Res<PhysicsConfig>,
+    mut session: MessageWriter<SessionStatEvent>,
+) {
+    for kill in kills.read() {
+        if !kill.indirect {
+            continue;
+        }
+        let Some(weapon) = kill.by.player_weapon() else {
+            continue;
+        };
+        score.destroyed += 1;
+        match kill.victim {
+            KillVictim::Asteroid => {
+                score.points += scoring.destroy_bonus_points;
+                session.write(SessionStatEvent::AsteroidDestroyed(weapon));
+            }
+            KillVictim::Enemy(archetype) => score.points += archetype.kill_score(&config),
+        }
+    }
+}
+
+pub struct AttributionPlugin;
+
+impl Plugin for AttributionPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<KillCredited>().add_systems(
+            Update,
+            credit_indirect_kills_system.run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    fn credit_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Playing)
+            .add_message::<SessionStatEvent>()
+            .insert_resource(PlayerScore::default())
+            .insert_resource(ScoringConfig::default())
+            .insert_resource(PhysicsConfig::default())
+            .add_plugins(AttributionPlugin);
+        app
+    }
+
+    fn credit(app: &mut App, victim: KillVictim, by: Actor, indirect: bool) {
+        app.world_mut().write_message(KillCredited {
+            victim,
+            by,
+            indirect,
+        });
+    }
+
+    #[test]
+    fn unattributed_damage_falls_to_the_environment() {
+        assert_eq!(credit_for(None), Actor::Environment);
+        let last = LastDamagedBy(Actor::Player(StatWeapon::Missile));
+        assert_eq!(credit_for(Some(&last)), Actor::Player(StatWeapon::Missile));
+        assert_eq!(Actor::Enemy.player_weapon(), None);
+    }
+
+    #[test]
+    fn only_indirect_player_kills_are_scored_here() {
+        let mut app = credit_app();
+        app.update();
+        let primary = Actor::Player(StatWeapon::Primary);
+        credit(&mut app, KillVictim::Asteroid, primary, true);
+        credit(
+            &mut app,
+            KillVictim::Enemy(EnemyArchetype::Miner),
+            primary,
+            true,
+        );
+        // Scored by the weapon that made them.
+        credit(&mut app, KillVictim::Asteroid, primary, false);
+        // Nobody's points.
+        credit(&mut app, KillVictim::Asteroid, Actor::Enemy, true);
+        credit(
+            &mut app,
+            KillVictim::Enemy(EnemyArchetype::Chaser),
+            Actor::Environment,
+            true,
+        );
+        app.update();
+
+        let scoring = ScoringConfig::default();
+        let config = PhysicsConfig::default();
+        let score = app.world().resource::<PlayerScore>();
+        assert_eq!(score.destroyed, 2);
+        assert_eq!(
+            score.points,
+            scoring.destroy_bonus_points + config.enemy_miner_kill_score
+        );
+        let destroyed: Vec<_> = app
+            .world()
+            .resource::<Messages<SessionStatEvent>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(
+            destroyed,
+            vec![SessionStatEvent::AsteroidDestroyed(StatWeapon::Primary)]
+        );
+    }
+}
@@ -0,0 +1,534 @@
+//! Sound effects and the layered ambient soundtrack.
+//!
+//! ## Sound bank
+//!
+//! All sounds are synthesized at startup by [`synth`] and registered as
+//! in-memory WAV [`AudioSource`]s in the [`SoundBank`] resource; no audio
+//! files are shipped.
+//!
+//! ## One-shot effects
+//!
+//! Gameplay code calls [`play_sfx`] / [`play_sfx_pitched`], which (like the
+//! particle spawn helpers) take only `&mut Commands`.  The queued command
+//! spawns a self-despawning [`AudioPlayer`] at the current effects volume, so
+//! the callers need no audio resources.  At most `sfx_max_per_frame` effects
+//! start per frame; later requests that frame are dropped.
+//!
+//! | Effect | Trigger |
+//! |--------|---------|
+//! | [`Sfx::Impact`] | `CollisionEvent::Started` between asteroids / the ship, gain and pitch from collision energy ([`impact_sound`]) |
+//! | [`Sfx::Blaster`] / [`Sfx::Missile`] | Player primary / secondary fire |
+//! | [`Sfx::Impact`] / [`Sfx::Explosion`] | Merge / split or destroy, gain and pitch from mass ([`crate::feedback`]) |
+//! | [`Sfx::Explosion`] | Enemy or ship destroyed |
+//! | [`Sfx::Warning`] | Every `low_health_warning_interval_secs` while hull ≤ `low_health_warning_fraction` |
+//!
+//! ## Ambient layers
+//!
+//! Three looping layers ([`AmbientLayer`]) start silent at startup and are
+//! mixed every frame by [`ambient_mix_system`]:
+//!
+//! - **Drone**: always present; quieter outside a session.
+//! - **Rumble**: follows asteroid density around the ship (spatial index
+//!   query within `ambient_density_radius`), never below a floor that rises
+//!   with each size class the field has reached.
+//! - **Tension**: follows the number of enemies within the same radius.
+//!
+//! Levels glide toward their targets over `ambient_fade_secs`.
+//!
+//! ## Volume
+//!
+//! [`AudioSettings`] holds the master, effects, and music volumes edited on
+//! the Settings screen.  Defaults come from `physics.toml`; changes last for
+//! the session.
+
+#[path = "audio/synth.rs"]
+pub mod synth;
+
+use bevy::audio::{AudioSinkPlayback, Volume};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{CollisionEvent, Velocity};
+
+use crate::asteroid::{Asteroid, AsteroidSize};
+use crate::config::PhysicsConfig;
+use crate::enemy::Enemy;
+use crate::menu::GameState;
+use crate::player::state::PlayerHealth;
+use crate::player::Player;
+use crate::simulation::size_class::FieldSizeClass;
+use crate::spatial_partition::SpatialGrid;
+
+/// One-shot sound effects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Sfx {
+    Impact,
+    Blaster,
+    Missile,
+    Explosion,
+    Warning,
+}
+
+/// Looping ambient soundtrack layers.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AmbientLayer {
+    Drone,
+    Rumble,
+    Tension,
+}
+
+/// Volume sliders on the Settings screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AudioChannel {
+    Master,
+    Effects,
+    Music,
+}
+
+impl AudioChannel {
+    pub const ALL: [AudioChannel; 3] = [
+        AudioChannel::Master,
+        AudioChannel::Effects,
+        AudioChannel::Music,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AudioChannel::Master => "MASTER",
+            AudioChannel::Effects => "EFFECTS",
+            AudioChannel::Music => "MUSIC",
+        }
+    }
+}
+
+/// Player volume settings, each in `[0, 1]`.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct AudioSettings {
+    pub master: f32,
+    pub effects: f32,
+    pub music: f32,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self::from_config(&PhysicsConfig::default())
+    }
+}
+
+impl AudioSettings {
+    pub fn from_config(config: &PhysicsConfig) -> Self {
+        Self {
+            master: config.audio_master_volume.clamp(0.0, 1.0),
+            effects: config.audio_effects_volume.clamp(0.0, 1.0),
+            music: config.audio_music_volume.clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn get(&self, channel: AudioChannel) -> f32 {
+        match channel {
+            AudioChannel::Master => self.master,
+            AudioChannel::Effects => self.effects,
+            AudioChannel::Music => self.music,
+        }
+    }
+
+    /// Step a slider, clamped to `[0, 1]` and snapped to whole percent so
+    /// repeated steps do not drift.
+    pub fn adjust(&mut self, channel: AudioChannel, delta: f32) {
+        let slot = match channel {
+            AudioChannel::Master => &mut self.master,
+            AudioChannel::Effects => &mut self.effects,
+            AudioChannel::Music => &mut self.music,
+        };
+        *slot = ((*slot + delta).clamp(0.0, 1.0) * 100.0).round() / 100.0;
+    }
+
+    /// Effective volume for one-shot effects.
+    pub fn effects_volume(&self) -> f32 {
+        self.master * self.effects
+    }
+
+    /// Effective volume for the ambient layers.
+    pub fn music_volume(&self) -> f32 {
+        self.master * self.music
+    }
+}
+
+/// Handles to every synthesized sound.
+#[derive(Resource, Debug, Clone)]
+pub struct SoundBank {
+    pub impact: Handle<AudioSource>,
+    pub blaster: Handle<AudioSource>,
+    pub missile: Handle<AudioSource>,
+    pub explosion: Handle<AudioSource>,
+    pub warning: Handle<AudioSource>,
+    pub drone: Handle<AudioSource>,
+    pub rumble: Handle<AudioSource>,
+    pub tension: Handle<AudioSource>,
+}
+
+impl SoundBank {
+    pub fn sfx(&self, sfx: Sfx) -> Handle<AudioSource> {
+        match sfx {
+            Sfx::Impact => self.impact.clone(),
+            Sfx::Blaster => self.blaster.clone(),
+            Sfx::Missile => self.missile.clone(),
+            Sfx::Explosion => self.explosion.clone(),
+            Sfx::Warning => self.warning.clone(),
+        }
+    }
+
+    pub fn ambient(&self, layer: AmbientLayer) -> Handle<AudioSource> {
+        match layer {
+            AmbientLayer::Drone => self.drone.clone(),
+            AmbientLayer::Rumble => self.rumble.clone(),
+            AmbientLayer::Tension => self.tension.clone(),
+        }
+    }
+}
+
+/// Effects still allowed to start this frame.
+#[derive(Resource, Debug, Default)]
+pub struct SfxBudget(pub u32);
+
+/// Current (smoothed) level of each ambient layer, in `[0, 1]`.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct AmbientMix {
+    pub drone: f32,
+    pub rumble: f32,
+    pub tension: f32,
+}
+
+pub struct GameAudioPlugin;
+
+impl Plugin for GameAudioPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioSettings>()
+            .init_resource::<SfxBudget>()
+            .init_resource::<AmbientMix>()
+            .add_systems(
+                Startup,
+                (init_audio_settings_system, setup_sound_bank_system)
+                    .after(crate::config::load_physics_config),
+            )
+            .add_systems(PreUpdate, reset_sfx_budget_system)
+            .add_systems(Update, ambient_mix_system)
+            .add_systems(
+                Update,
+                (impact_sfx_system, low_health_warning_system).run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Take the volume defaults from the loaded config.
+pub fn init_audio_settings_system(config: Res<PhysicsConfig>, mut settings: ResMut<AudioSettings>) {
+    *settings = AudioSettings::from_config(&config);
+}
+
+/// Synthesize the sound bank and start the (silent) ambient loops.
+pub fn setup_sound_bank_system(mut commands: Commands, mut sources: ResMut<Assets<AudioSource>>) {
+    let mut add = |samples: Vec<f32>| {
+        sources.add(AudioSource {
+            bytes: synth::encode_wav(&samples).into(),
+        })
+    };
+    let bank = SoundBank {
+        impact: add(synth::impact()),
+        blaster: add(synth::blaster()),
+        missile: add(synth::missile()),
+        explosion: add(synth::explosion()),
+        warning: add(synth::warning_beep()),
+        drone: add(synth::ambient_drone()),
+        rumble: add(synth::ambient_rumble()),
+        tension: add(synth::ambient_tension()),
+    };
+    for layer in [
+        AmbientLayer::Drone,
+        AmbientLayer::Rumble,
+        AmbientLayer::Tension,
+    ] {
+        commands.spawn((
+            layer,
+            AudioPlayer::new(bank.ambient(layer)),
+            PlaybackSettings::LOOP.with_volume(Volume::Linear(0.0)),
+        ));
+    }
+    commands.insert_resource(bank);
+}
+
+pub fn reset_sfx_budget_system(config: Res<PhysicsConfig>, mut budget: ResMut<SfxBudget>) {
+    budget.0 = config.sfx_max_per_frame;
+}
+
+/// Play a one-shot effect at `gain` × the effects volume.
+pub fn play_sfx(commands: &mut Commands, sfx: Sfx, gain: f32) {
+    play_sfx_pitched(commands, sfx, gain, 1.0);
+}
+
+/// [`play_sfx`] with a playback speed (pitch) multiplier.
+///
+/// A no-op until the [`SoundBank`] exists (e.g. in headless tests).
+pub fn play_sfx_pitched(commands: &mut Commands, sfx: Sfx, gain: f32, speed: f32) {
+    commands.queue(move |world: &mut World| {
+        let volume = world
+            .get_resource::<AudioSettings>()
+            .map_or(0.0, |settings| settings.effects_volume())
+            * gain.clamp(0.0, 1.0);
+        if volume <= 0.0 {
+            return;
+        }
+        let Some(mut budget) = world.get_resource_mut::<SfxBudget>() else {
+            return;
+        };
+        if budget.0 == 0 {
+            return;
+        }
+        budget.0 -= 1;
+        let Some(handle) = world.get_resource::<SoundBank>().map(|bank| bank.sfx(sfx)) else {
+            return;
+        };
+        world.spawn((
+            AudioPlayer::new(handle),
+            PlaybackSettings::DESPAWN
+                .with_volume(Volume::Linear(volume))
+                .with_speed(speed),
+        ));
+    });
+}
+
+/// Gain and playback speed of a collision sound, or `None` below the
+/// audible threshold.
+///
+/// Energy is the relative kinetic energy `½·μ·v²` with reduced mass
+/// `μ = m₁m₂ / (m₁ + m₂)`.  Gain grows with `√(E / reference)` up to 1;
+/// harder hits also play lower.
+pub fn impact_sound(
+    mass_a: f32,
+    mass_b: f32,
+    relative_speed: f32,
+    reference_energy: f32,
+    min_gain: f32,
+) -> Option<(f32, f32)> {
+    let total = mass_a + mass_b;
+    if total <= 0.0 || reference_energy <= 0.0 {
+        return None;
+    }
+    let reduced = mass_a * mass_b / total;
+    let energy = 0.5 * reduced * relative_speed * relative_speed;
+    let gain = (energy / reference_energy).sqrt().min(1.0);
+    (gain >= min_gain).then_some((gain, 1.25 - 0.5 * gain))
+}
+
+/// Collision sounds for asteroid ↔ asteroid and asteroid ↔ ship contacts,
+/// loudest first.
+///
+/// Asteroid mass is its [`AsteroidSize`]; the ship counts as one unit.
+#[allow(clippy::type_complexity)]
+pub fn impact_sfx_system(
+    mut commands: Commands,
+    mut collisions: MessageReader<CollisionEvent>,
+    q_bodies: Query<(&Velocity, Option<&AsteroidSize>), Or<(With<Asteroid>, With<Player>)>>,
+    config: Res<PhysicsConfig>,
+) {
+    let mut sounds: Vec<(f32, f32)> = collisions
+        .read()
+        .filter_map(|event| match event {
+            CollisionEvent::Started(a, b, _) => Some((*a, *b)),
+            CollisionEvent::Stopped(..) => None,
+        })
+        .filter_map(|(a, b)| {
+            let (vel_a, size_a) = q_bodies.get(a).ok()?;
+            let (vel_b, size_b) = q_bodies.get(b).ok()?;
+            let mass = |size: Option<&AsteroidSize>| size.map_or(1.0, |s| s.0.max(1) as f32);
+            impact_sound(
+                mass(size_a),
+                mass(size_b),
+                (vel_a.linvel - vel_b.linvel).length(),
+                config.impact_sfx_reference_energy,
+                config.impact_sfx_min_gain,
+            )
+        })
+        .collect();
+    sounds.sort_by(|a, b| b.0.total_cmp(&a.0));
+    for (gain, speed) in sounds {
+        play_sfx_pitched(&mut commands, Sfx::Impact, gain, speed);
+    }
+}
+
+/// Repeat the warning beep while the hull is low.
+pub fn low_health_warning_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    q_player: Query<&PlayerHealth, With<Player>>,
+    mut until_next: Local<f32>,
+) {
+    let low = q_player.single().is_ok_and(|health| {
+        health.hp > 0.0 && health.hp <= health.max_hp * config.low_health_warning_fraction
+    });
+    if !low {
+        *until_next = 0.0;
+        return;
+    }
+    *until_next -= time.delta_secs();
+    if *until_next <= 0.0 {
+        play_sfx(&mut commands, Sfx::Warning, 1.0);
+        *until_next = config.low_health_warning_interval_secs;
+    }
+}
+
+/// Target `(rumble, tension)` levels for the asteroids and enemies near the
+/// ship.
+pub fn ambient_targets(
+    asteroids_near: usize,
+    enemies_near: usize,
+    config: &PhysicsConfig,
+) -> (f32, f32) {
+    let level = |count: usize, full: u32| {
+        if full == 0 {
+            0.0
+        } else {
+            (count as f32 / full as f32).min(1.0)
+        }
+    };
+    (
+        level(asteroids_near, config.ambient_density_full_count),
+        level(enemies_near, config.ambient_enemy_full_count),
+    )
+}
+
+/// Move `current` toward `target`, covering the full range in `fade_secs`.
+pub fn glide(current: f32, target: f32, dt: f32, fade_secs: f32) -> f32 {
+    if fade_secs <= 0.0 {
+        return target;
+    }
+    let step = dt / fade_secs;
+    current + (target - current).clamp(-step, step)
+}
+
+/// Fade the ambient layers toward the current scene intensity.
+#[allow(clippy::too_many_arguments)]
+pub fn ambient_mix_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    settings: Res<AudioSettings>,
+    state: Res<State<GameState>>,
+    grid: Option<Res<SpatialGrid>>,
+    field: Option<Res<FieldSizeClass>>,
+    q_player: Query<&Transform, With<Player>>,
+    q_enemies: Query<&Transform, With<Enemy>>,
+    mut mix: ResMut<AmbientMix>,
+    mut layers: Query<(&AmbientLayer, &mut AudioSink)>,
+    mut neighbors: Local<Vec<Entity>>,
+) {
+    let in_session = matches!(
+        state.get(),
+        GameState::Playing | GameState::Paused | GameState::OreShop | GameState::GameOver
+    );
+    let (rumble_target, tension_target) = match (in_session, q_player.single()) {
+        (true, Ok(player)) => {
+            let pos = player.translation.truncate();
+            let radius = config.ambient_density_radius;
+            match grid.as_ref() {
+                Some(grid) => grid.within_radius(pos, radius, &mut neighbors),
+                None => neighbors.clear(),
+            }
+            let enemies = q_enemies
+                .iter()
+                .filter(|t| t.translation.truncate().distance_squared(pos) <= radius * radius)
+                .count();
+            let (rumble, tension) = ambient_targets(neighbors.len(), enemies, &config);
+            let floor = field
+                .as_ref()
+                .map_or(0.0, |field| field.rumble_floor(&config));
+            (rumble.max(floor), tension)
+        }
+        _ => (0.0, 0.0),
+    };
+    let drone_target = if in_session { 1.0 } else { 0.4 };
+
+    let dt = time.delta_secs();
+    let fade = config.ambient_fade_secs;
+    mix.drone = glide(mix.drone, drone_target, dt, fade);
+    mix.rumble = glide(mix.rumble, rumble_target, dt, fade);
+    mix.tension = glide(mix.tension, tension_target, dt, fade);
+
+    let music = settings.music_volume();
+    for (layer, mut sink) in layers.iter_mut() {
+        let level = match layer {
+            AmbientLayer::Drone => mix.drone,
+            AmbientLayer::Rumble => mix.rumble,
+            AmbientLayer::Tension => mix.tension,
+        };
+        sink.set_volume(Volume::Linear(music * level));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn impact_gain_follows_collision_energy() {
+        let config = PhysicsConfig::default();
+        let sound = |a: f32, b: f32, v: f32| {
+            impact_sound(
+                a,
+                b,
+                v,
+                config.impact_sfx_reference_energy,
+                config.impact_sfx_min_gain,
+            )
+        };
+
+        // A faint graze is inaudible.
+        assert_eq!(sound(1.0, 1.0, 1.0), None);
+        // Faster and heavier hits are louder and lower.
+        let (soft_gain, soft_speed) = sound(2.0, 2.0, 60.0).unwrap();
+        let (hard_gain, hard_speed) = sound(8.0, 8.0, 120.0).unwrap();
+        assert!(hard_gain > soft_gain);
+        assert!(hard_speed < soft_speed);
+        // Gain saturates at full volume.
+        assert_eq!(sound(50.0, 50.0, 1_000.0).unwrap().0, 1.0);
+    }
+
+    #[test]
+    fn volume_steps_clamp_and_do_not_drift() {
+        let mut settings = AudioSettings {
+            master: 0.8,
+            effects: 0.0,
+            music: 0.5,
+        };
+        for _ in 0..5 {
+            settings.adjust(AudioChannel::Master, 0.1);
+        }
+        assert_eq!(settings.master, 1.0);
+        settings.adjust(AudioChannel::Effects, -0.1);
+        assert_eq!(settings.effects, 0.0);
+        for _ in 0..3 {
+            settings.adjust(AudioChannel::Music, 0.1);
+        }
+        assert_eq!(settings.music, 0.8);
+        assert_eq!(settings.effects_volume(), 0.0);
+    }
+
+    #[test]
+    fn ambient_levels_scale_with_nearby_activity_and_glide() {
+        let config = PhysicsConfig::default();
+        assert_eq!(ambient_targets(0, 0, &config), (0.0, 0.0));
+        let (rumble, tension) = ambient_targets(
+            config.ambient_density_full_count as usize / 2,
+            config.ambient_enemy_full_count as usize * 3,
+            &config,
+        );
+        assert!((rumble - 0.5).abs() < 0.05);
+        assert_eq!(tension, 1.0);
+
+        let half = glide(
+            0.0,
+            1.0,
+            config.ambient_fade_secs / 2.0,
+            config.ambient_fade_secs,
+        );
+        assert!((half - 0.5).abs() < 1e-5);
+        assert_eq!(glide(0.9, 0.0, 10.0, config.ambient_fade_secs), 0.0);
+    }
+}
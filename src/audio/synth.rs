@@ -0,0 +1,269 @@
+//! Procedural sound synthesis.
+//!
+//! Every sound the game plays is generated here at startup and encoded as a
+//! 16-bit mono PCM WAV in memory, so the repository ships no audio assets.
+//! Generators are deterministic (a fixed-seed xorshift supplies the noise),
+//! which keeps the bank identical between runs and testable.
+//!
+//! Ambient layers are built to loop seamlessly: every oscillator completes a
+//! whole number of cycles over [`AMBIENT_LOOP_SECS`], and noise layers
+//! cross-fade their tail into their head.
+
+use std::f32::consts::TAU;
+
+/// Output sample rate (Hz).
+pub const SAMPLE_RATE: u32 = 22_050;
+
+/// Length of each ambient loop (s).
+pub const AMBIENT_LOOP_SECS: f32 = 4.0;
+
+/// Fixed-seed xorshift noise in `[-1, 1)`.
+struct Noise(u32);
+
+impl Noise {
+    fn next(&mut self) -> f32 {
+        let mut x = self.0;
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        self.0 = x;
+        (x as f32 / u32::MAX as f32) * 2.0 - 1.0
+    }
+}
+
+fn sample_count(secs: f32) -> usize {
+    (secs * SAMPLE_RATE as f32).round() as usize
+}
+
+/// One-pole low-pass filter; `alpha` in `(0, 1]`, lower is darker.
+fn low_pass(samples: &mut [f32], alpha: f32) {
+    let mut state = 0.0;
+    for s in samples.iter_mut() {
+        state += alpha * (*s - state);
+        *s = state;
+    }
+}
+
+/// Scale so the loudest sample reaches `peak`.
+fn normalize(samples: &mut [f32], peak: f32) {
+    let max = samples.iter().fold(0.0_f32, |m, s| m.max(s.abs()));
+    if max > 0.0 {
+        for s in samples.iter_mut() {
+            *s *= peak / max;
+        }
+    }
+}
+
+/// Short linear fade-in so one-shots start without a click.
+fn fade_in(samples: &mut [f32], secs: f32) {
+    let n = sample_count(secs).min(samples.len()).max(1);
+    for (i, s) in samples.iter_mut().take(n).enumerate() {
+        *s *= i as f32 / n as f32;
+    }
+}
+
+/// Blend the last `secs` of a loop into its start so the seam is inaudible,
+/// then drop the blended tail.
+fn crossfade_loop(mut samples: Vec<f32>, secs: f32) -> Vec<f32> {
+    let n = sample_count(secs).min(samples.len() / 2);
+    let len = samples.len() - n;
+    for i in 0..n {
+        let t = i as f32 / n as f32;
+        samples[i] = samples[i] * t + samples[len + i] * (1.0 - t);
+    }
+    samples.truncate(len);
+    samples
+}
+
+/// Rock-on-rock thud: low-passed noise over a 70 Hz body, fast decay.
+pub fn impact() -> Vec<f32> {
+    let mut noise = Noise(0x1234_5678);
+    let mut out: Vec<f32> = (0..sample_count(0.35))
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let env = (-t * 14.0).exp();
+            (noise.next() * 0.8 + (TAU * 70.0 * t).sin()) * env
+        })
+        .collect();
+    low_pass(&mut out, 0.12);
+    fade_in(&mut out, 0.002);
+    normalize(&mut out, 0.9);
+    out
+}
+
+/// Blaster shot: a square-ish chirp falling from 1.2 kHz to 380 Hz.
+pub fn blaster() -> Vec<f32> {
+    let n = sample_count(0.09);
+    let mut phase = 0.0_f32;
+    let mut out: Vec<f32> = (0..n)
+        .map(|i| {
+            let p = i as f32 / n as f32;
+            let freq = 1_200.0 + (380.0 - 1_200.0) * p;
+            phase += TAU * freq / SAMPLE_RATE as f32;
+            let square = (phase.sin() * 3.0).tanh();
+            square * (1.0 - p).powi(2)
+        })
+        .collect();
+    fade_in(&mut out, 0.002);
+    normalize(&mut out, 0.6);
+    out
+}
+
+/// Missile launch: a rising band of noise with a soft attack.
+pub fn missile() -> Vec<f32> {
+    let mut noise = Noise(0x0bad_cafe);
+    let n = sample_count(0.45);
+    let mut out: Vec<f32> = (0..n)
+        .map(|i| {
+            let p = i as f32 / n as f32;
+            let env = (p * 8.0).min(1.0) * (1.0 - p);
+            noise.next() * env
+        })
+        .collect();
+    low_pass(&mut out, 0.35);
+    normalize(&mut out, 0.7);
+    out
+}
+
+/// Explosion: dark noise with a long tail over a 45 Hz drop.
+pub fn explosion() -> Vec<f32> {
+    let mut noise = Noise(0xdead_beef);
+    let mut out: Vec<f32> = (0..sample_count(1.1))
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let env = (-t * 4.0).exp();
+            (noise.next() + 0.8 * (TAU * 45.0 * t).sin()) * env
+        })
+        .collect();
+    low_pass(&mut out, 0.08);
+    fade_in(&mut out, 0.003);
+    normalize(&mut out, 1.0);
+    out
+}
+
+/// Low-health warning: two short 880 Hz beeps.
+pub fn warning_beep() -> Vec<f32> {
+    let beep = sample_count(0.08);
+    let gap = sample_count(0.06);
+    (0..beep * 2 + gap)
+        .map(|i| {
+            let in_beep = i < beep || i >= beep + gap;
+            if !in_beep {
+                return 0.0;
+            }
+            let local = if i < beep { i } else { i - beep - gap };
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let edge = (local.min(beep - local) as f32 / 40.0).min(1.0);
+            (TAU * 880.0 * t).sin() * 0.45 * edge
+        })
+        .collect()
+}
+
+/// Base ambient layer: a slow-beating drone on 55 / 82.5 / 110.25 Hz.
+pub fn ambient_drone() -> Vec<f32> {
+    let mut out: Vec<f32> = (0..sample_count(AMBIENT_LOOP_SECS))
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let swell = 0.75 + 0.25 * (TAU * 0.25 * t).sin();
+            ((TAU * 55.0 * t).sin() + 0.6 * (TAU * 82.5 * t).sin() + 0.3 * (TAU * 110.25 * t).sin())
+                * swell
+        })
+        .collect();
+    normalize(&mut out, 0.5);
+    out
+}
+
+/// Density layer: a gravelly low rumble for crowded space.
+pub fn ambient_rumble() -> Vec<f32> {
+    let mut noise = Noise(0x5eed_0001);
+    let total = sample_count(AMBIENT_LOOP_SECS + 0.5);
+    let mut out: Vec<f32> = (0..total).map(|_| noise.next()).collect();
+    low_pass(&mut out, 0.03);
+    let mut out = crossfade_loop(out, 0.5);
+    normalize(&mut out, 0.5);
+    out
+}
+
+/// Tension layer: a 165 Hz pulse at 2 Hz for enemy presence.
+pub fn ambient_tension() -> Vec<f32> {
+    let mut out: Vec<f32> = (0..sample_count(AMBIENT_LOOP_SECS))
+        .map(|i| {
+            let t = i as f32 / SAMPLE_RATE as f32;
+            let pulse = (0.5 - 0.5 * (TAU * 2.0 * t).cos()).powi(2);
+            ((TAU * 165.0 * t).sin() + 0.4 * (TAU * 330.0 * t).sin()) * pulse
+        })
+        .collect();
+    normalize(&mut out, 0.45);
+    out
+}
+
+/// Encode samples in `[-1, 1]` as a 16-bit mono PCM WAV file.
+pub fn encode_wav(samples: &[f32]) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // mono
+    bytes.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    bytes.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes()); // byte rate
+    bytes.extend_from_slice(&2u16.to_le_bytes()); // block align
+    bytes.extend_from_slice(&16u16.to_le_bytes()); // bits per sample
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for s in samples {
+        let v = (s.clamp(-1.0, 1.0) * i16::MAX as f32) as i16;
+        bytes.extend_from_slice(&v.to_le_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wav_header_describes_the_payload() {
+        let wav = encode_wav(&[0.0, 1.0, -1.0]);
+        assert_eq!(wav.len(), 44 + 6);
+        assert_eq!(&wav[0..4], b"RIFF");
+        assert_eq!(u32::from_le_bytes(wav[4..8].try_into().unwrap()), 36 + 6);
+        assert_eq!(&wav[8..12], b"WAVE");
+        assert_eq!(
+            u32::from_le_bytes(wav[24..28].try_into().unwrap()),
+            SAMPLE_RATE
+        );
+        assert_eq!(u32::from_le_bytes(wav[40..44].try_into().unwrap()), 6);
+        assert_eq!(i16::from_le_bytes([wav[46], wav[47]]), i16::MAX);
+        assert_eq!(i16::from_le_bytes([wav[48], wav[49]]), -i16::MAX);
+    }
+
+    #[test]
+    fn generated_sounds_are_bounded_and_audible() {
+        for (name, samples) in [
+            ("impact", impact()),
+            ("blaster", blaster()),
+            ("missile", missile()),
+            ("explosion", explosion()),
+            ("warning", warning_beep()),
+            ("drone", ambient_drone()),
+            ("rumble", ambient_rumble()),
+            ("tension", ambient_tension()),
+        ] {
+            let peak = samples.iter().fold(0.0_f32, |m, s| m.max(s.abs()));
+            assert!(samples.iter().all(|s| s.is_finite()), "{name}");
+            assert!(peak > 0.1 && peak <= 1.0, "{name}: peak {peak}");
+        }
+    }
+
+    #[test]
+    fn ambient_loops_are_exactly_one_loop_long() {
+        let expected = sample_count(AMBIENT_LOOP_SECS);
+        assert_eq!(ambient_drone().len(), expected);
+        assert_eq!(ambient_rumble().len(), expected);
+        assert_eq!(ambient_tension().len(), expected);
+    }
+}
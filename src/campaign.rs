@@ -1,14 +1,23 @@
 use crate::config::PhysicsConfig;
+use crate::difficulty::ASSISTED_MUTATOR;
 use crate::enemy::Enemy;
-use crate::enemy::{EnemyProjectile, EnemySpawnState};
+use crate::enemy::{EnemyProjectile, EnemySpawnState, SpawnExclusions};
+use crate::graphics::cinematic::{CinematicFocus, CinematicPurpose, CinematicRequest};
+use crate::loading::ScenarioSpawnQueue;
 use crate::menu::{GameState, SelectedGameMode, SelectedScenario, ShopReturnState};
 use crate::mining::{OrePickup, PlayerOre};
 use crate::particles::Particle;
 use crate::player::state::{Missile, Projectile};
 use crate::player::Player;
 use crate::player::PlayerHealth;
+use crate::run_info::{next_run_seed, RunMetadata};
+use crate::simulation::size_class::FieldSizeClass;
 use bevy::prelude::*;
 
+#[path = "campaign/script.rs"]
+pub mod script;
+use script::{ScriptClock, ScriptStep, ScriptTimeline};
+
 /// Static campaign mission descriptor used by the foundation mission-loader.
 #[derive(Debug, Clone)]
 pub struct CampaignMissionDefinition {
@@ -17,6 +26,21 @@ pub struct CampaignMissionDefinition {
     pub wave_count: u32,
     pub reward_ore: u32,
     pub next_mission_id: Option<u32>,
+    /// Timed events for the mission, run by [`campaign_script_system`].
+    pub script: Vec<ScriptStep<CampaignScriptAction>>,
+}
+
+/// An event a mission script can schedule.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CampaignScriptAction {
+    /// Spawn a boss near the ship, on top of the final-wave boss.
+    SpawnBoss,
+    /// Queue extra enemies through the [`WaveReinforcementQueue`].
+    Reinforcements { count: u32, delay_secs: f32 },
+    /// Show a line of dialogue under the mode HUD for `secs`.
+    Dialogue { text: &'static str, secs: f32 },
+    /// Orbit the camera around `focus` for `secs` (see `graphics::cinematic`).
+    Cinematic { focus: CinematicFocus, secs: f32 },
 }
 
 /// Campaign mission catalog resource (A2 foundation).
@@ -45,6 +69,19 @@ impl Default for CampaignMissionCatalog {
                     wave_count: 3,
                     reward_ore: 20,
                     next_mission_id: Some(2),
+                    script: vec![
+                        ScriptStep::at(
+                            0.5,
+                            CampaignScriptAction::Dialogue {
+                                text: "Control: Clear the field. Hostiles inbound.",
+                                secs: 4.0,
+                            },
+                        ),
+                        ScriptStep::on_objective(CampaignScriptAction::Dialogue {
+                            text: "Control: Sector clear. Return for refit.",
+                            secs: 4.0,
+                        }),
+                    ],
                 },
                 CampaignMissionDefinition {
                     mission_id: 2,
@@ -52,6 +89,30 @@ impl Default for CampaignMissionCatalog {
                     wave_count: 4,
                     reward_ore: 35,
                     next_mission_id: Some(3),
+                    script: vec![
+                        ScriptStep::on_size_class(
+                            2,
+                            CampaignScriptAction::Dialogue {
+                                text:
+                                    "Control: Debris is clumping into a moonlet. Watch your flank.",
+                                secs: 4.0,
+                            },
+                        ),
+                        ScriptStep::on_wave(
+                            3,
+                            CampaignScriptAction::Dialogue {
+                                text: "Control: Second squadron on approach.",
+                                secs: 4.0,
+                            },
+                        ),
+                        ScriptStep::on_wave(
+                            3,
+                            CampaignScriptAction::Reinforcements {
+                                count: 2,
+                                delay_secs: 5.0,
+                            },
+                        ),
+                    ],
                 },
                 CampaignMissionDefinition {
                     mission_id: 3,
@@ -59,6 +120,38 @@ impl Default for CampaignMissionCatalog {
                     wave_count: 5,
                     reward_ore: 50,
                     next_mission_id: None,
+                    script: vec![
+                        ScriptStep::at(
+                            170.0,
+                            CampaignScriptAction::Dialogue {
+                                text: "Control: Heavy signature closing on your position.",
+                                secs: 5.0,
+                            },
+                        ),
+                        ScriptStep::at(180.0, CampaignScriptAction::SpawnBoss),
+                        ScriptStep::on_size_class(
+                            3,
+                            CampaignScriptAction::Dialogue {
+                                text: "Control: A planetoid is forming. Raiders will come for it.",
+                                secs: 4.0,
+                            },
+                        ),
+                        ScriptStep::on_size_class(
+                            3,
+                            CampaignScriptAction::Reinforcements {
+                                count: 2,
+                                delay_secs: 6.0,
+                            },
+                        ),
+                        ScriptStep::on_objective(CampaignScriptAction::Dialogue {
+                            text: "Control: Campaign complete. Well flown.",
+                            secs: 6.0,
+                        }),
+                        ScriptStep::on_objective(CampaignScriptAction::Cinematic {
+                            focus: CinematicFocus::LargestBody,
+                            secs: 5.0,
+                        }),
+                    ],
                 },
             ],
         }
@@ -122,6 +215,103 @@ pub struct CampaignProgressionState {
     pub next_mission_pending_shop: Option<u32>,
 }
 
+/// Shop beacon state for the break between campaign waves.
+///
+/// While the wave director is in [`CampaignWavePhase::InterWaveBreak`],
+/// [`wave_intermission_beacon_system`] keeps a [`ShopBeacon`] near the ship and
+/// tracks whether the ship is docked with it.  Opening the ore shop while
+/// docked sets `shopping`, which discounts upgrade prices and keeps physics and
+/// the intermission countdown running behind the shop overlay.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct WaveIntermissionShop {
+    pub beacon: Option<Entity>,
+    pub beacon_pos: Option<Vec2>,
+    /// Ship is within `wave_intermission_beacon_radius` of the beacon.
+    pub docked: bool,
+    /// The ore shop is currently open from the beacon.
+    pub shopping: bool,
+}
+
+impl WaveIntermissionShop {
+    /// Upgrade discount currently in effect (0 outside a beacon shop visit).
+    pub fn discount(&self, config: &PhysicsConfig) -> f32 {
+        if self.shopping {
+            config.wave_intermission_shop_discount.clamp(0.0, 0.95)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Deferred reinforcements called in by enemies that escaped the field.
+///
+/// Each escape queues a [`PendingReinforcement`].  Once its delay has elapsed,
+/// [`wave_reinforcement_system`] folds the extra ships into the spawn budget of
+/// the current (or next) [`CampaignWavePhase::ActiveWave`].
+#[derive(Resource, Debug, Clone, Default)]
+pub struct WaveReinforcementQueue {
+    pub pending: Vec<PendingReinforcement>,
+}
+
+/// One queued reinforcement call.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PendingReinforcement {
+    pub remaining_secs: f32,
+    pub count: u32,
+}
+
+impl WaveReinforcementQueue {
+    /// Queue `count` extra enemies to arrive after `delay_secs`.
+    pub fn call(&mut self, count: u32, delay_secs: f32) {
+        if count == 0 {
+            return;
+        }
+        self.pending.push(PendingReinforcement {
+            remaining_secs: delay_secs.max(0.0),
+            count,
+        });
+    }
+
+    /// Total enemies still waiting to arrive.
+    #[cfg(test)]
+    pub fn pending_count(&self) -> u32 {
+        self.pending.iter().map(|call| call.count).sum()
+    }
+}
+
+/// Runtime state of the loaded mission's script.
+///
+/// [`campaign_script_system`] reloads the timeline whenever a new campaign run
+/// or mission starts, so retries and mission advances replay the script from
+/// the top.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct CampaignScriptRunner {
+    /// `(run_counter, mission_index)` the timeline was loaded for.
+    loaded_for: Option<(u64, u32)>,
+    pub timeline: ScriptTimeline<CampaignScriptAction>,
+    /// Mission seconds; paused while the game is paused or in a shop.
+    pub elapsed_secs: f32,
+    /// Dialogue line on screen and its remaining seconds.
+    pub dialogue: Option<(&'static str, f32)>,
+}
+
+/// Marker for the temporary shop beacon spawned during a wave intermission.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ShopBeacon;
+
+/// Apply a fractional discount to an ore price, rounding up with a floor of 1.
+pub fn discounted_price(cost: u32, discount: f32) -> u32 {
+    if discount <= 0.0 {
+        return cost;
+    }
+    ((cost as f32 * (1.0 - discount)).ceil() as u32).clamp(1, cost.max(1))
+}
+
+/// Run condition: the ore shop was opened from the intermission beacon.
+pub fn intermission_shop_open(shop: Res<WaveIntermissionShop>) -> bool {
+    shop.shopping
+}
+
 pub fn campaign_progression_stage(mission_index: u32, wave_index: u32) -> u32 {
     let mission_stage = mission_index.saturating_sub(1).saturating_mul(3);
     let wave_stage = wave_index.saturating_sub(1);
@@ -170,14 +360,30 @@ fn load_mission_into_session(
 
 fn spawn_campaign_world_for_scenario(
     commands: &mut Commands,
+    queue: &mut ScenarioSpawnQueue,
     config: &PhysicsConfig,
     scenario: SelectedScenario,
+    seed: u64,
 ) {
     match scenario {
-        SelectedScenario::Field => crate::asteroid::spawn_initial_asteroids(commands, 100, config),
-        SelectedScenario::Orbit => crate::asteroid::spawn_orbit_scenario(commands, config),
-        SelectedScenario::Comets => crate::asteroid::spawn_comets_scenario(commands, config),
-        SelectedScenario::Shower => crate::asteroid::spawn_shower_scenario(commands, config),
+        SelectedScenario::Field => {
+            crate::asteroid::spawn_initial_asteroids(queue, 100, config, seed)
+        }
+        SelectedScenario::Orbit => crate::asteroid::spawn_orbit_scenario(queue, config, seed),
+        SelectedScenario::Comets => crate::asteroid::spawn_comets_scenario(queue, config, seed),
+        SelectedScenario::Shower => crate::asteroid::spawn_shower_scenario(queue, config, seed),
+        SelectedScenario::Survival => crate::asteroid::spawn_initial_asteroids(
+            queue,
+            config.survival_initial_asteroids,
+            config,
+            seed,
+        ),
+        SelectedScenario::Catalog => {
+            crate::orbital_import::spawn_catalog_scenario(queue, config, seed)
+        }
+        SelectedScenario::Frontier => {
+            commands.insert_resource(crate::simulation::procgen::ProceduralField::new(seed))
+        }
     }
 }
 
@@ -280,7 +486,7 @@ pub fn campaign_wave_director_system(
                     director.boss_spawned = false;
                 } else {
                     director.phase = CampaignWavePhase::InterWaveBreak;
-                    director.phase_timer_secs = 4.0;
+                    director.phase_timer_secs = config.wave_intermission_secs.max(0.0);
                 }
             }
         }
@@ -303,7 +509,8 @@ pub fn campaign_wave_director_system(
                 director.phase = CampaignWavePhase::BossOutro;
                 director.phase_timer_secs = 1.0;
                 if !director.mission_reward_granted {
-                    ore.count = ore.count.saturating_add(session.reward_ore);
+                    // Mission payouts arrive already refined.
+                    ore.credits = ore.credits.saturating_add(session.reward_ore);
                     director.mission_reward_granted = true;
                 }
             }
@@ -318,12 +525,128 @@ pub fn campaign_wave_director_system(
     }
 }
 
+/// Tick queued reinforcement calls and merge due ones into the active wave.
+///
+/// Due calls wait for the next `ActiveWave` so reinforcements never arrive
+/// during a break or boss fight.  Arrivals raise both the wave's spawn target
+/// and its concurrency cap, so the wave cannot complete until they are dealt
+/// with.  Runs without a campaign session check so survival waves, which
+/// drive the same director, receive reinforcements too.
+pub fn wave_reinforcement_system(
+    time: Res<Time>,
+    mut queue: ResMut<WaveReinforcementQueue>,
+    mut director: ResMut<CampaignWaveDirector>,
+) {
+    if queue.pending.is_empty() {
+        return;
+    }
+
+    let dt = time.delta_secs();
+    for call in queue.pending.iter_mut() {
+        call.remaining_secs = (call.remaining_secs - dt).max(0.0);
+    }
+    if director.phase != CampaignWavePhase::ActiveWave {
+        return;
+    }
+
+    let mut arrived = 0_u32;
+    queue.pending.retain(|call| {
+        if call.remaining_secs <= 0.0 {
+            arrived = arrived.saturating_add(call.count);
+            false
+        } else {
+            true
+        }
+    });
+    if arrived == 0 {
+        return;
+    }
+
+    director.target_spawns_this_wave = director.target_spawns_this_wave.saturating_add(arrived);
+    director.max_concurrent_enemies = director.max_concurrent_enemies.saturating_add(arrived);
+}
+
+/// Spawn, track, and retire the intermission shop beacon.
+///
+/// The beacon appears `wave_intermission_beacon_offset` ahead of the ship when
+/// a break begins and is despawned as soon as the director leaves
+/// [`CampaignWavePhase::InterWaveBreak`].
+#[allow(clippy::too_many_arguments)]
+pub fn wave_intermission_beacon_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    session: Res<CampaignSession>,
+    director: Res<CampaignWaveDirector>,
+    mut shop: ResMut<WaveIntermissionShop>,
+    q_player: Query<&Transform, With<Player>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if !session.active || director.phase != CampaignWavePhase::InterWaveBreak {
+        if let Some(beacon) = shop.beacon.take() {
+            if let Ok(mut entity) = commands.get_entity(beacon) {
+                entity.despawn();
+            }
+        }
+        shop.beacon_pos = None;
+        shop.docked = false;
+        return;
+    }
+
+    let Ok(player_transform) = q_player.single() else {
+        shop.docked = false;
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    let beacon_pos = match shop.beacon_pos {
+        Some(pos) => pos,
+        None => {
+            let forward = player_transform
+                .rotation
+                .mul_vec3(Vec3::Y)
+                .truncate()
+                .normalize_or(Vec2::Y);
+            let pos = player_pos + forward * config.wave_intermission_beacon_offset;
+            let radius = config.wave_intermission_beacon_radius.max(8.0);
+            let beacon = commands
+                .spawn((
+                    Mesh2d(meshes.add(crate::asteroid_rendering::ring_mesh(radius, 2.5, 40))),
+                    MeshMaterial2d(
+                        materials.add(ColorMaterial::from_color(Color::srgb(0.95, 0.80, 0.25))),
+                    ),
+                    Transform::from_translation(pos.extend(1.5)),
+                    Visibility::Visible,
+                    ShopBeacon,
+                ))
+                .id();
+            shop.beacon = Some(beacon);
+            shop.beacon_pos = Some(pos);
+            pos
+        }
+    };
+
+    shop.docked =
+        player_pos.distance_squared(beacon_pos) <= config.wave_intermission_beacon_radius.powi(2);
+}
+
+/// Close a beacon shop visit when the intermission countdown runs out.
+pub fn close_intermission_shop_system(
+    director: Res<CampaignWaveDirector>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    if director.phase != CampaignWavePhase::InterWaveBreak {
+        next_state.set(GameState::Playing);
+    }
+}
+
 pub fn campaign_boss_spawn_system(
     mut commands: Commands,
     session: Res<CampaignSession>,
     config: Res<PhysicsConfig>,
     q_player: Query<&Transform, With<Player>>,
     q_bosses: Query<Entity, With<crate::enemy::Boss>>,
+    exclusions: SpawnExclusions,
     mut director: ResMut<CampaignWaveDirector>,
 ) {
     if !session.active || director.phase != CampaignWavePhase::BossActive {
@@ -342,12 +665,118 @@ pub fn campaign_boss_spawn_system(
         &mut commands,
         &config,
         around_pos,
+        &exclusions.zones(),
         session.mission_index,
         director.current_wave.max(1),
     );
     director.boss_spawned = true;
 }
 
+/// Wave number whose fighting has started, for [`ScriptClock::wave`].
+fn started_wave(director: &CampaignWaveDirector) -> u32 {
+    match director.phase {
+        CampaignWavePhase::Inactive => 0,
+        CampaignWavePhase::Warmup => director.current_wave.saturating_sub(1),
+        _ => director.current_wave,
+    }
+}
+
+/// Run the current mission's script and carry out its due events.
+///
+/// The objective counts as complete once the mission reward is granted,
+/// i.e. when the final boss falls.
+#[allow(clippy::too_many_arguments)]
+pub fn campaign_script_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    session: Res<CampaignSession>,
+    catalog: Res<CampaignMissionCatalog>,
+    director: Res<CampaignWaveDirector>,
+    mut reinforcements: ResMut<WaveReinforcementQueue>,
+    mut runner: ResMut<CampaignScriptRunner>,
+    mut field: ResMut<FieldSizeClass>,
+    q_player: Query<&Transform, With<Player>>,
+    exclusions: SpawnExclusions,
+    mut cinematics: MessageWriter<CinematicRequest>,
+) {
+    if !session.active {
+        if runner.loaded_for.is_some() {
+            *runner = CampaignScriptRunner::default();
+        }
+        return;
+    }
+
+    let key = (session.run_counter, session.mission_index);
+    if runner.loaded_for != Some(key) {
+        let steps = catalog
+            .mission_by_id(session.mission_index)
+            .map(|mission| mission.script.clone())
+            .unwrap_or_default();
+        *runner = CampaignScriptRunner {
+            loaded_for: Some(key),
+            timeline: ScriptTimeline::new(steps),
+            ..default()
+        };
+        // Each mission's field grows from scratch.
+        *field = FieldSizeClass::default();
+    }
+
+    let dt = time.delta_secs();
+    runner.elapsed_secs += dt;
+    if let Some((_, remaining)) = runner.dialogue.as_mut() {
+        *remaining -= dt;
+    }
+    if runner
+        .dialogue
+        .is_some_and(|(_, remaining)| remaining <= 0.0)
+    {
+        runner.dialogue = None;
+    }
+    if runner.timeline.is_finished() {
+        return;
+    }
+
+    let clock = ScriptClock {
+        time: runner.elapsed_secs,
+        wave: started_wave(&director),
+        objective_complete: director.mission_reward_granted,
+        size_class: field.rank(),
+    };
+    for action in runner.timeline.advance(&clock) {
+        match action {
+            CampaignScriptAction::SpawnBoss => {
+                let around_pos = q_player
+                    .single()
+                    .map_or(Vec2::ZERO, |t| t.translation.truncate());
+                crate::enemy::spawn_campaign_boss(
+                    &mut commands,
+                    &config,
+                    around_pos,
+                    &exclusions.zones(),
+                    session.mission_index,
+                    director.current_wave.max(1),
+                );
+            }
+            CampaignScriptAction::Reinforcements { count, delay_secs } => {
+                reinforcements.call(count, delay_secs);
+            }
+            CampaignScriptAction::Dialogue { text, secs } => {
+                info!("Campaign dialogue: {text}");
+                runner.dialogue = Some((text, secs));
+            }
+            CampaignScriptAction::Cinematic { focus, secs } => {
+                cinematics.write(CinematicRequest::orbit(
+                    focus,
+                    secs,
+                    CinematicPurpose::Script,
+                    &config,
+                ));
+            }
+        }
+    }
+}
+
 /// Handle campaign mission completion/failure transitions during gameplay.
 #[allow(clippy::too_many_arguments)]
 #[allow(clippy::type_complexity)]
@@ -373,6 +802,8 @@ pub fn campaign_progression_system(
     ),
     mut next_state: ResMut<NextState<GameState>>,
     mut return_state: ResMut<ShopReturnState>,
+    mut run: ResMut<RunMetadata>,
+    mut spawn_queue: ResMut<ScenarioSpawnQueue>,
 ) {
     if !session.active {
         return;
@@ -405,7 +836,27 @@ pub fn campaign_progression_system(
             commands.entity(entity).despawn();
         }
 
-        spawn_campaign_world_for_scenario(&mut commands, &config, session.map_scenario);
+        let seed = next_run_seed();
+        // Assists can only change from the main menu, so the next mission
+        // is as assisted as this one.
+        let assisted = run.mutators.iter().any(|m| m == ASSISTED_MUTATOR);
+        run.begin(
+            session.map_scenario,
+            seed,
+            SelectedGameMode::Campaign,
+            session.mission_index,
+        );
+        if assisted {
+            run.mutators.push(ASSISTED_MUTATOR.to_string());
+        }
+        info!("Run: {}", run.summary());
+        spawn_campaign_world_for_scenario(
+            &mut commands,
+            &mut spawn_queue,
+            &config,
+            session.map_scenario,
+            seed,
+        );
 
         if let Ok(mut player_health) = q_player_health.single_mut() {
             player_health.hp = player_health.max_hp;
@@ -579,6 +1030,91 @@ mod tests {
         assert_eq!(director.phase, CampaignWavePhase::BossIntro);
     }
 
+    #[test]
+    fn wave_clear_starts_configured_intermission() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(PhysicsConfig::default());
+        world.insert_resource(PlayerOre::default());
+        world.insert_resource(CampaignSession {
+            active: true,
+            mission_index: 1,
+            map_scenario: SelectedScenario::Field,
+            wave_count: 3,
+            reward_ore: 20,
+            next_mission_id: None,
+            run_counter: 1,
+        });
+        world.insert_resource(CampaignWaveDirector {
+            phase: CampaignWavePhase::ActiveWave,
+            current_wave: 1,
+            total_waves: 3,
+            target_spawns_this_wave: 2,
+            spawned_this_wave: 2,
+            ..Default::default()
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(campaign_wave_director_system);
+        schedule.run(&mut world);
+
+        let director = world.resource::<CampaignWaveDirector>();
+        assert_eq!(director.phase, CampaignWavePhase::InterWaveBreak);
+        assert_eq!(
+            director.phase_timer_secs,
+            crate::constants::WAVE_INTERMISSION_SECS
+        );
+    }
+
+    #[test]
+    fn intermission_beacon_spawns_ahead_docks_and_retires() {
+        let mut world = World::new();
+        world.insert_resource(PhysicsConfig::default());
+        world.init_resource::<Assets<Mesh>>();
+        world.init_resource::<Assets<ColorMaterial>>();
+        world.insert_resource(WaveIntermissionShop::default());
+        world.insert_resource(CampaignSession {
+            active: true,
+            ..Default::default()
+        });
+        world.insert_resource(CampaignWaveDirector {
+            phase: CampaignWavePhase::InterWaveBreak,
+            ..Default::default()
+        });
+        let player = world
+            .spawn((Player, Transform::from_xyz(0.0, 0.0, 0.0)))
+            .id();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(wave_intermission_beacon_system);
+        schedule.run(&mut world);
+
+        let shop = world.resource::<WaveIntermissionShop>().clone();
+        let beacon_pos = shop.beacon_pos.expect("beacon spawned during break");
+        assert!((beacon_pos.y - crate::constants::WAVE_INTERMISSION_BEACON_OFFSET).abs() < 1e-3);
+        assert!(!shop.docked);
+        assert_eq!(world.query::<&ShopBeacon>().iter(&world).count(), 1);
+
+        world.get_mut::<Transform>(player).unwrap().translation = beacon_pos.extend(0.0);
+        schedule.run(&mut world);
+        assert!(world.resource::<WaveIntermissionShop>().docked);
+
+        world.resource_mut::<CampaignWaveDirector>().phase = CampaignWavePhase::Warmup;
+        schedule.run(&mut world);
+        let shop = world.resource::<WaveIntermissionShop>();
+        assert!(shop.beacon.is_none());
+        assert!(!shop.docked);
+        assert_eq!(world.query::<&ShopBeacon>().iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn discounted_price_rounds_up_and_never_drops_to_zero() {
+        assert_eq!(discounted_price(40, 0.0), 40);
+        assert_eq!(discounted_price(40, 0.25), 30);
+        assert_eq!(discounted_price(10, 0.25), 8);
+        assert_eq!(discounted_price(1, 0.9), 1);
+    }
+
     #[test]
     fn boss_spawn_system_spawns_once_in_boss_active_phase() {
         let mut world = World::new();
@@ -656,7 +1192,7 @@ mod tests {
         let director = world.resource::<CampaignWaveDirector>();
         assert_eq!(director.phase, CampaignWavePhase::BossOutro);
         assert!(director.mission_reward_granted);
-        assert_eq!(world.resource::<PlayerOre>().count, 15);
+        assert_eq!(world.resource::<PlayerOre>().credits, 15);
     }
 
     #[test]
@@ -695,7 +1231,7 @@ mod tests {
         let director = world.resource::<CampaignWaveDirector>();
         assert_eq!(director.phase, CampaignWavePhase::BossActive);
         assert!(!director.mission_reward_granted);
-        assert_eq!(world.resource::<PlayerOre>().count, 0);
+        assert_eq!(world.resource::<PlayerOre>().credits, 0);
     }
 
     #[test]
@@ -772,6 +1308,8 @@ mod tests {
         world.insert_resource(crate::enemy::EnemySpawnState::default());
         world.insert_resource(NextState::<GameState>::default());
         world.insert_resource(ShopReturnState::default());
+        world.insert_resource(RunMetadata::default());
+        world.insert_resource(ScenarioSpawnQueue::default());
 
         let mut schedule = Schedule::default();
         schedule.add_systems(campaign_progression_system);
@@ -828,6 +1366,8 @@ mod tests {
         world.insert_resource(crate::enemy::EnemySpawnState::default());
         world.insert_resource(NextState::<GameState>::default());
         world.insert_resource(ShopReturnState::default());
+        world.insert_resource(RunMetadata::default());
+        world.insert_resource(ScenarioSpawnQueue::default());
 
         let mut schedule = Schedule::default();
         schedule.add_systems(campaign_progression_system);
@@ -877,6 +1417,8 @@ mod tests {
         world.insert_resource(crate::enemy::EnemySpawnState::default());
         world.insert_resource(NextState::<GameState>::default());
         world.insert_resource(ShopReturnState::default());
+        world.insert_resource(RunMetadata::default());
+        world.insert_resource(ScenarioSpawnQueue::default());
 
         let mut schedule = Schedule::default();
         schedule.add_systems(campaign_progression_system);
@@ -885,6 +1427,10 @@ mod tests {
         let session = world.resource::<CampaignSession>();
         assert_eq!(session.mission_index, 2);
         assert_eq!(session.map_scenario, SelectedScenario::Comets);
+        assert!(
+            world.resource::<ScenarioSpawnQueue>().is_loading(),
+            "the next mission's field is queued for staggered spawning"
+        );
 
         let progression = world.resource::<CampaignProgressionState>();
         assert_eq!(progression.next_mission_pending_shop, None);
@@ -893,4 +1439,158 @@ mod tests {
         assert_eq!(wave.phase, CampaignWavePhase::Warmup);
         assert_eq!(wave.current_wave, 1);
     }
+
+    #[test]
+    fn due_reinforcements_wait_for_and_join_active_wave() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(WaveReinforcementQueue {
+            pending: vec![
+                PendingReinforcement {
+                    remaining_secs: 0.0,
+                    count: 2,
+                },
+                PendingReinforcement {
+                    remaining_secs: 30.0,
+                    count: 2,
+                },
+            ],
+        });
+        world.insert_resource(CampaignWaveDirector {
+            phase: CampaignWavePhase::InterWaveBreak,
+            target_spawns_this_wave: 3,
+            max_concurrent_enemies: 1,
+            ..Default::default()
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(wave_reinforcement_system);
+        schedule.run(&mut world);
+
+        // Breaks hold due reinforcements back.
+        assert_eq!(
+            world.resource::<WaveReinforcementQueue>().pending_count(),
+            4
+        );
+
+        world.resource_mut::<CampaignWaveDirector>().phase = CampaignWavePhase::ActiveWave;
+        schedule.run(&mut world);
+
+        let wave = world.resource::<CampaignWaveDirector>();
+        assert_eq!(wave.target_spawns_this_wave, 5);
+        assert_eq!(wave.max_concurrent_enemies, 3);
+        assert_eq!(
+            world.resource::<WaveReinforcementQueue>().pending_count(),
+            2
+        );
+    }
+
+    #[test]
+    fn mission_script_fires_wave_and_objective_events_once() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(PhysicsConfig::default());
+        world.insert_resource(CampaignMissionCatalog::default());
+        world.insert_resource(WaveReinforcementQueue::default());
+        world.insert_resource(CampaignScriptRunner::default());
+        world.insert_resource(FieldSizeClass::default());
+        world.init_resource::<Messages<CinematicRequest>>();
+        world.insert_resource(CampaignSession {
+            active: true,
+            mission_index: 2,
+            map_scenario: SelectedScenario::Comets,
+            wave_count: 4,
+            reward_ore: 35,
+            next_mission_id: Some(3),
+            run_counter: 1,
+        });
+        world.insert_resource(CampaignWaveDirector {
+            phase: CampaignWavePhase::Warmup,
+            current_wave: 3,
+            ..Default::default()
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(campaign_script_system);
+        schedule.run(&mut world);
+        // Wave 3 has not started fighting yet.
+        assert_eq!(
+            world.resource::<WaveReinforcementQueue>().pending_count(),
+            0
+        );
+
+        world.resource_mut::<CampaignWaveDirector>().phase = CampaignWavePhase::ActiveWave;
+        schedule.run(&mut world);
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<WaveReinforcementQueue>().pending_count(),
+            2
+        );
+        assert!(world.resource::<CampaignScriptRunner>().dialogue.is_some());
+
+        // A moonlet forming in the field cues its line.
+        world.resource_mut::<FieldSizeClass>().reached =
+            Some(crate::simulation::size_class::SizeClass::Moonlet);
+        schedule.run(&mut world);
+        let line = world
+            .resource::<CampaignScriptRunner>()
+            .dialogue
+            .map(|(text, _)| text);
+        assert!(
+            line.is_some_and(|text| text.contains("moonlet")),
+            "{line:?}"
+        );
+
+        // A retry reloads the script from the top.
+        world.resource_mut::<CampaignSession>().run_counter = 2;
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<WaveReinforcementQueue>().pending_count(),
+            4
+        );
+    }
+
+    #[test]
+    fn finished_script_stops_firing_but_its_dialogue_still_expires() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(PhysicsConfig::default());
+        world.insert_resource(CampaignMissionCatalog::default());
+        world.insert_resource(WaveReinforcementQueue::default());
+        world.insert_resource(FieldSizeClass::default());
+        world.init_resource::<Messages<CinematicRequest>>();
+        world.insert_resource(CampaignWaveDirector::default());
+        world.insert_resource(CampaignSession {
+            active: true,
+            mission_index: 1,
+            run_counter: 1,
+            ..Default::default()
+        });
+        world.insert_resource(CampaignScriptRunner {
+            loaded_for: Some((1, 1)),
+            timeline: ScriptTimeline::new(vec![ScriptStep::at(
+                0.0,
+                CampaignScriptAction::Dialogue {
+                    text: "hello",
+                    secs: 1.0,
+                },
+            )]),
+            ..default()
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(campaign_script_system);
+        schedule.run(&mut world);
+        let runner = world.resource::<CampaignScriptRunner>();
+        assert!(runner.timeline.is_finished());
+        assert!(runner.dialogue.is_some());
+
+        world
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs(2));
+        schedule.run(&mut world);
+        let runner = world.resource::<CampaignScriptRunner>();
+        assert!(runner.dialogue.is_none());
+        assert!(runner.elapsed_secs >= 2.0);
+    }
 }
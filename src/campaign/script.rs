@@ -0,0 +1,161 @@
+//! Lightweight event timeline interpreter.
+//!
+//! A [`ScriptTimeline`] is an ordered list of [`ScriptStep`]s, each pairing a
+//! [`ScriptTrigger`] with an action.  Every frame the owner builds a
+//! [`ScriptClock`] and calls [`ScriptTimeline::advance`], which returns the
+//! actions whose triggers have just been met.  Each step fires exactly once.
+//!
+//! The interpreter knows nothing about what the actions do, so the same
+//! executor drives campaign mission scripts (clock in mission seconds, see
+//! [`CampaignScriptAction`](super::CampaignScriptAction)) and the scripted
+//! ACCRETION_TEST scenarios (clock in frames).
+
+/// What the script reads to decide whether a step is due.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScriptClock {
+    /// Script time: mission seconds for campaigns, frames for test scripts.
+    pub time: f32,
+    /// Highest wave whose fighting has started (0 before the first wave).
+    pub wave: u32,
+    /// The mission objective has been completed.
+    pub objective_complete: bool,
+    /// Rank (1–4) of the largest size class a merge has reached, 0 for none.
+    pub size_class: u8,
+}
+
+/// When a step fires.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ScriptTrigger {
+    /// Script time reaches the value.
+    At(f32),
+    /// The given wave begins.
+    WaveStart(u32),
+    /// The mission objective is completed.
+    ObjectiveComplete,
+    /// A merge reaches the size class of the given rank.
+    SizeClass(u8),
+}
+
+impl ScriptTrigger {
+    pub fn is_met(&self, clock: &ScriptClock) -> bool {
+        match *self {
+            Self::At(time) => clock.time >= time,
+            Self::WaveStart(wave) => clock.wave >= wave,
+            Self::ObjectiveComplete => clock.objective_complete,
+            Self::SizeClass(rank) => clock.size_class >= rank,
+        }
+    }
+}
+
+/// One scheduled action.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptStep<A> {
+    pub trigger: ScriptTrigger,
+    pub action: A,
+}
+
+impl<A> ScriptStep<A> {
+    pub fn at(time: f32, action: A) -> Self {
+        Self {
+            trigger: ScriptTrigger::At(time),
+            action,
+        }
+    }
+
+    pub fn on_wave(wave: u32, action: A) -> Self {
+        Self {
+            trigger: ScriptTrigger::WaveStart(wave),
+            action,
+        }
+    }
+
+    pub fn on_objective(action: A) -> Self {
+        Self {
+            trigger: ScriptTrigger::ObjectiveComplete,
+            action,
+        }
+    }
+
+    pub fn on_size_class(rank: u8, action: A) -> Self {
+        Self {
+            trigger: ScriptTrigger::SizeClass(rank),
+            action,
+        }
+    }
+}
+
+/// Steps plus which of them have fired.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptTimeline<A> {
+    steps: Vec<ScriptStep<A>>,
+    fired: Vec<bool>,
+}
+
+impl<A> Default for ScriptTimeline<A> {
+    fn default() -> Self {
+        Self {
+            steps: Vec::new(),
+            fired: Vec::new(),
+        }
+    }
+}
+
+impl<A: Clone> ScriptTimeline<A> {
+    pub fn new(steps: Vec<ScriptStep<A>>) -> Self {
+        let fired = vec![false; steps.len()];
+        Self { steps, fired }
+    }
+
+    /// Fire every step whose trigger is met, in declaration order.
+    pub fn advance(&mut self, clock: &ScriptClock) -> Vec<A> {
+        let mut due = Vec::new();
+        for (step, fired) in self.steps.iter().zip(self.fired.iter_mut()) {
+            if !*fired && step.trigger.is_met(clock) {
+                *fired = true;
+                due.push(step.action.clone());
+            }
+        }
+        due
+    }
+
+    /// All steps have fired; the runner stops evaluating triggers.
+    pub fn is_finished(&self) -> bool {
+        self.fired.iter().all(|&fired| fired)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_fire_once_in_order_when_their_trigger_is_met() {
+        let mut timeline = ScriptTimeline::new(vec![
+            ScriptStep::at(180.0, "boss"),
+            ScriptStep::on_wave(2, "reinforce"),
+            ScriptStep::at(5.0, "hello"),
+            ScriptStep::on_objective("done"),
+            ScriptStep::on_size_class(3, "planetoid"),
+        ]);
+        let mut clock = ScriptClock::default();
+
+        assert!(timeline.advance(&clock).is_empty());
+        clock.time = 10.0;
+        assert_eq!(timeline.advance(&clock), vec!["hello"]);
+        assert!(timeline.advance(&clock).is_empty());
+
+        clock.time = 200.0;
+        clock.wave = 3;
+        assert_eq!(timeline.advance(&clock), vec!["boss", "reinforce"]);
+        assert!(!timeline.is_finished());
+
+        clock.objective_complete = true;
+        clock.size_class = 2;
+        assert_eq!(timeline.advance(&clock), vec!["done"]);
+        assert!(!timeline.is_finished());
+
+        clock.size_class = 4;
+        assert_eq!(timeline.advance(&clock), vec!["planetoid"]);
+        assert!(timeline.is_finished());
+    }
+}
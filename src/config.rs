@@ -22,7 +22,7 @@
 
 use crate::constants::*;
 use bevy::prelude::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
 
 const PHYSICS_CONFIG_PATH: &str = "assets/physics.toml";
@@ -49,7 +49,8 @@ impl Default for PhysicsConfigHotReloadState {
 /// All fields default to the corresponding compile-time constant from
 /// `src/constants.rs`.  Override any subset by setting the value in
 /// `assets/physics.toml`.
-#[derive(Resource, Debug, Clone, Deserialize)]
+#[derive(Resource, Debug, Clone, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
 #[serde(default)]
 pub struct PhysicsConfig {
     // ── World Bounds ─────────────────────────────────────────────────────────
@@ -58,31 +59,99 @@ pub struct PhysicsConfig {
     pub spawn_grid_margin: f32,
     pub player_buffer_radius: f32,
 
+    // ── Physics: Timestep ─────────────────────────────────────────────────────
+    pub physics_tick_hz: f32,
+    pub physics_substeps: usize,
+    pub physics_interpolation: bool,
+
     // ── Physics: Gravity ──────────────────────────────────────────────────────
     pub gravity_const: f32,
     pub min_gravity_dist: f32,
     pub max_gravity_dist: f32,
+    pub gravity_parallel: bool,
 
     // ── Physics: Cluster Formation ────────────────────────────────────────────
     pub velocity_threshold_locking: f32,
     pub hull_extent_base: f32,
     pub hull_extent_per_member: f32,
+    pub hull_simplify_min_vertices: usize,
+    pub hull_simplify_epsilon: f32,
+    pub merge_cooldown_secs: f32,
+    pub merge_rearm_gap: f32,
+    pub merge_cooldown_max_secs: f32,
+    pub formation_merge_budget: u32,
+    pub lineage_max_nodes: usize,
+
+    // ── Cluster Labeling ──────────────────────────────────────────────────────
+    pub cluster_label_interval_secs: f32,
+    pub cluster_link_distance: f32,
+    pub cluster_min_members: u32,
 
     // ── Physics: Collision ────────────────────────────────────────────────────
-    pub restitution_small: f32,
+    pub restitution_low_energy: f32,
+    pub restitution_high_energy: f32,
+    pub restitution_max: f32,
+    pub impact_fragment_energy: f32,
+    pub impact_fragment_fraction: f32,
     pub friction_asteroid: f32,
 
+    // ── Physics: Shockwaves ───────────────────────────────────────────────────
+    pub shockwave_radius: f32,
+    pub shockwave_impulse_per_mass: f32,
+    pub shockwave_max_impulse: f32,
+    pub shockwave_merge_min_mass: u32,
+
+    // ── Volatile Asteroids ────────────────────────────────────────────────────
+    pub volatile_asteroid_fraction: f32,
+    pub volatile_blast_radius: f32,
+    pub volatile_blast_damage: f32,
+    pub volatile_blast_push: f32,
+    pub volatile_blast_destroy_size: u32,
+    pub volatile_chain_delay_secs: f32,
+
     // ── Physics: Culling ──────────────────────────────────────────────────────
     pub cull_distance: f32,
     pub soft_boundary_radius: f32,
     pub soft_boundary_strength: f32,
-    pub hard_cull_distance: f32,
+
+    // ── Sector Streaming ──────────────────────────────────────────────────────
+    pub sector_size: f32,
+    pub sector_stream_out_radius: f32,
+    pub sector_stream_in_radius: f32,
+
+    // ── Procedural Field ──────────────────────────────────────────────────────
+    pub procgen_generate_radius: f32,
+    pub procgen_samples_per_chunk: u32,
+    pub procgen_density: f32,
+    pub origin_recenter_distance: f32,
+
+    // ── Density Field ─────────────────────────────────────────────────────────
+    pub density_cell_size: f32,
+    pub density_refresh_secs: f32,
+    pub enemy_spawn_density_samples: u32,
+    pub ore_vein_mass: f32,
+    pub ore_vein_drop_multiplier: f32,
+    pub procgen_crowd_mass: f32,
+
+    // ── Scenario Loading ──────────────────────────────────────────────────────
+    pub scenario_spawn_budget: u32,
+    pub loading_min_secs: f32,
+    pub loading_particle_prewarm: u32,
+
+    // ── Session Autosave ──────────────────────────────────────────────────────
+    pub session_autosave_interval_secs: f32,
+
+    // ── Save Storage ──────────────────────────────────────────────────────────
+    pub save_storage_quota_mb: f32,
+    pub storage_prune_keep: u32,
 
     // ── Physics: Neighbor Counting ────────────────────────────────────────────
     pub neighbor_threshold: f32,
 
     // ── Spatial Grid ──────────────────────────────────────────────────────────
     pub grid_cell_size: f32,
+    pub spatial_update_tolerance: f32,
+    pub spatial_rebuild_imbalance: f32,
 
     // ── Camera ────────────────────────────────────────────────────────────────
     pub min_zoom: f32,
@@ -124,6 +193,10 @@ pub struct PhysicsConfig {
     pub tractor_beam_freeze_max_target_speed_multiplier: f32,
     pub tractor_throw_cooldown_base: f32,
     pub tractor_throw_cooldown_per_level: f32,
+    pub tractor_deflect_min_level: u32,
+    pub tractor_deflect_window_secs: f32,
+    pub tractor_deflect_energy_cost: f32,
+    pub tractor_deflect_speed_multiplier: f32,
 
     // ── Enemy Ships: Foundation ─────────────────────────────────────────────
     pub enemy_spawn_base_cooldown: f32,
@@ -137,6 +210,8 @@ pub struct PhysicsConfig {
     pub enemy_spawn_radius: f32,
     pub enemy_min_player_spawn_distance: f32,
     pub enemy_min_enemy_spacing: f32,
+    pub spawn_exclusion_hauler_radius: f32,
+    pub spawn_exclusion_objective_radius: f32,
     pub enemy_seek_force: f32,
     pub enemy_max_speed: f32,
     pub enemy_arrive_radius: f32,
@@ -174,6 +249,74 @@ pub struct PhysicsConfig {
     pub boss_phase_two_burst_cooldown: f32,
     pub boss_phase_two_burst_shots: u32,
 
+    // ── Enemy Ships: Retreat ──────────────────────────────────────────────────
+    pub enemy_retreat_hp_fraction: f32,
+    pub enemy_retreat_escape_radius: f32,
+    pub enemy_reinforcement_delay_secs: f32,
+    pub enemy_reinforcements_per_escape: u32,
+
+    // ── Enemy Ships: Avoidance ────────────────────────────────────────────────
+    pub enemy_avoidance_enabled: bool,
+    pub enemy_avoidance_lookahead_secs: f32,
+    pub enemy_avoidance_min_size: u32,
+    pub enemy_avoidance_clearance: f32,
+    pub enemy_avoidance_weight: f32,
+
+    // ── Enemy Ships: Archetypes ───────────────────────────────────────────────
+    pub enemy_weight_chaser: u32,
+    pub enemy_weight_skirmisher: u32,
+    pub enemy_weight_kamikaze: u32,
+    pub enemy_weight_miner: u32,
+    pub enemy_skirmisher_min_stage: u32,
+    pub enemy_kamikaze_min_stage: u32,
+    pub enemy_miner_min_stage: u32,
+    pub enemy_kamikaze_hp_mult: f32,
+    pub enemy_kamikaze_dash_radius: f32,
+    pub enemy_kamikaze_dash_force_mult: f32,
+    pub enemy_kamikaze_dash_speed_mult: f32,
+    pub enemy_kamikaze_trigger_radius: f32,
+    pub enemy_kamikaze_blast_radius: f32,
+    pub enemy_kamikaze_blast_damage: f32,
+    pub enemy_kamikaze_kill_score: u32,
+    pub enemy_miner_hp_mult: f32,
+    pub enemy_miner_seek_radius: f32,
+    pub enemy_miner_pickup_radius: f32,
+    pub enemy_miner_capacity: u32,
+    pub enemy_miner_standoff_radius: f32,
+    pub enemy_miner_kill_score: u32,
+
+    // ── Campaign: Wave Intermission ───────────────────────────────────────────
+    pub wave_intermission_secs: f32,
+    pub wave_intermission_shop_discount: f32,
+    pub wave_intermission_beacon_offset: f32,
+    pub wave_intermission_beacon_radius: f32,
+
+    // ── Survival Mode ─────────────────────────────────────────────────────────
+    pub survival_initial_asteroids: usize,
+    pub survival_first_wave_delay_secs: f32,
+    pub survival_breather_secs: f32,
+    pub survival_comets_base: u32,
+    pub survival_comets_per_wave: u32,
+    pub survival_comet_interval_secs: f32,
+    pub survival_comet_speed_base: f32,
+    pub survival_comet_speed_per_wave: f32,
+    pub survival_enemies_base: u32,
+    pub survival_enemies_per_wave: u32,
+    pub survival_score_mult_per_wave: f32,
+
+    // ── Catalog Scenario ──────────────────────────────────────────────────────
+    pub catalog_au_to_world: f32,
+    pub catalog_center_x: f32,
+    pub catalog_center_y: f32,
+    pub catalog_central_mass: u32,
+    pub catalog_max_bodies: usize,
+    pub catalog_min_orbit_radius: f32,
+    pub catalog_max_orbit_radius: f32,
+    pub catalog_reference_diameter_km: f32,
+    pub catalog_size_min: u32,
+    pub catalog_size_max: u32,
+    pub catalog_velocity_scale: f32,
+
     // ── Player: Combat ────────────────────────────────────────────────────────
     pub projectile_speed: f32,
     pub fire_cooldown: f32,
@@ -192,10 +335,16 @@ pub struct PhysicsConfig {
     pub missile_collider_radius: f32,
     pub missile_recharge_secs: f32,
     pub missile_split_max_pieces: u32,
+    pub missile_homing_min_level: u32,
+    pub missile_homing_range: f32,
+    pub missile_homing_cone_dot: f32,
+    pub missile_homing_turn_rate: f32,
+    pub missile_homing_min_asteroid_size: u32,
 
     // ── Player: Health ────────────────────────────────────────────────────────
     pub player_max_hp: f32,
     pub damage_speed_threshold: f32,
+    pub player_damage_taken_multiplier: f32,
     pub invincibility_duration: f32,
 
     // ── Player: Lives & Respawn ────────────────────────────────────────────────
@@ -207,16 +356,122 @@ pub struct PhysicsConfig {
     pub passive_heal_delay_secs: f32,
     pub passive_heal_rate: f32,
 
+    // ── Player: Energy ────────────────────────────────────────────────────────
+    pub player_energy_max: f32,
+    pub player_energy_regen_rate: f32,
+    pub player_energy_regen_delay_secs: f32,
+    pub thrust_energy_per_sec: f32,
+    pub ion_cannon_energy_cost: f32,
+    pub tractor_beam_energy_per_sec: f32,
+
+    // ── Player: Lock-On ───────────────────────────────────────────────────────
+    pub lock_on_range: f32,
+    pub lock_on_cone_dot: f32,
+    pub lock_on_min_asteroid_size: u32,
+    pub lock_on_release_range_mult: f32,
+    pub missile_lock_on_turn_rate: f32,
+    pub lock_on_camera_framing: bool,
+    pub lock_on_frame_margin: f32,
+
+    // ── Player: Kill-Cam ──────────────────────────────────────────────────────
+    pub kill_cam_enabled: bool,
+    pub kill_cam_duration_secs: f32,
+    pub kill_cam_replay_secs: f32,
+    pub kill_cam_replay_radius: f32,
+
+    // ── Player: Time Control ──────────────────────────────────────────────────
+    pub time_control_enabled: bool,
+    pub time_control_slow_scale: f32,
+    pub time_control_fast_scale: f32,
+    pub time_control_danger_radius: f32,
+
+    // ── Player: Shield ────────────────────────────────────────────────────────
+    pub shield_hp_per_level: f32,
+    pub shield_regen_rate: f32,
+    pub shield_recharge_delay_secs: f32,
+    pub shield_rear_absorb_fraction: f32,
+    pub shield_regen_energy_per_hp: f32,
+    pub shield_hit_flash_secs: f32,
+
+    // ── Player: Turrets ───────────────────────────────────────────────────────
+    pub turret_range: f32,
+    pub turret_target_max_size: u32,
+    pub turret_fire_interval_secs: f32,
+    pub turret_energy_per_shot: f32,
+    pub turret_turn_rate: f32,
+    pub turret_arc_half_width: f32,
+    pub turret_projectile_speed: f32,
+
+    // ── Ore Pickups ───────────────────────────────────────────────────────────
+    pub ore_drop_velocity_inherit: f32,
+    pub ore_drop_spread: f32,
+    pub ore_drop_damping: f32,
+
     // ── Ore Magnet ────────────────────────────────────────────────────────────
     pub ore_magnet_radius: f32,
     pub ore_magnet_strength: f32,
     /// HP restored per ore unit spent on healing (`H` key).
     pub ore_heal_amount: f32,
+    /// Seconds the most recent ore shop purchase stays refundable.
+    pub shop_undo_window_secs: f32,
+
+    // ── Ore Types & Refining ──────────────────────────────────────────────────
+    pub asteroid_ice_fraction: f32,
+    pub asteroid_metal_fraction: f32,
+    pub ore_refine_secs_per_unit: f32,
+    pub ore_refine_credits_iron: u32,
+    pub ore_refine_credits_ice: u32,
+    pub ore_refine_credits_rare: u32,
+
+    // ── Mining Drone ──────────────────────────────────────────────────────────
+    pub mining_drone_orbit_radius: f32,
+    pub mining_drone_orbit_speed: f32,
+    pub mining_drone_seek_radius: f32,
+    pub mining_drone_contact_radius: f32,
+    pub mining_drone_base_speed: f32,
+    pub mining_drone_speed_per_level: f32,
+    pub mining_drone_base_capacity: u32,
+    pub mining_drone_capacity_per_level: u32,
+
+    // ── Mining Outposts ───────────────────────────────────────────────────────
+    pub outpost_build_cost: u32,
+    pub outpost_build_range: f32,
+    pub outpost_max_count: u32,
+    pub outpost_ore_interval_secs: f32,
+    pub outpost_ore_per_interval: u32,
+    pub outpost_max_hp: f32,
+    pub outpost_hit_radius: f32,
+    pub outpost_aggro_radius: f32,
+
+    // ── Mining Laser ──────────────────────────────────────────────────────────
+    pub mining_laser_range: f32,
+    pub mining_laser_carve_rate: f32,
+    pub mining_laser_heat_per_sec: f32,
+    pub mining_laser_cool_per_sec: f32,
+    pub mining_laser_recover_heat: f32,
+
+    // ── Audio ─────────────────────────────────────────────────────────────────
+    pub audio_master_volume: f32,
+    pub audio_effects_volume: f32,
+    pub audio_music_volume: f32,
+    pub sfx_max_per_frame: u32,
+    pub impact_sfx_reference_energy: f32,
+    pub impact_sfx_min_gain: f32,
+    pub low_health_warning_fraction: f32,
+    pub low_health_warning_interval_secs: f32,
+    pub ambient_density_radius: f32,
+    pub ambient_density_full_count: u32,
+    pub ambient_enemy_full_count: u32,
+    pub ambient_fade_secs: f32,
+    pub size_class_rumble_step: f32,
+    pub size_class_announce_secs: f32,
 
     // ── Gamepad ───────────────────────────────────────────────────────────────
     pub gamepad_left_deadzone: f32,
     pub gamepad_brake_damping: f32,
     pub aim_idle_snap_secs: f32,
+    pub aim_snap_target_memory: bool,
+    pub aim_snap_target_range: f32,
     pub gamepad_right_deadzone: f32,
     pub gamepad_fire_threshold: f32,
     pub gamepad_heading_snap_threshold: f32,
@@ -226,6 +481,103 @@ pub struct PhysicsConfig {
     pub force_vector_display_scale: f32,
     pub force_vector_min_length: f32,
     pub stats_font_size: f32,
+    pub hud_margin_px: f32,
+    pub hud_stack_gap_px: f32,
+    pub hud_max_aspect_ratio: f32,
+    pub streamer_hud_scale: f32,
+    pub streamer_webcam_width_px: f32,
+    pub streamer_webcam_height_px: f32,
+    pub asteroid_shared_assets_enabled: bool,
+    pub asteroid_hull_instancing_enabled: bool,
+    pub asteroid_lod_enabled: bool,
+    pub asteroid_lod_simplified_distance: f32,
+    pub asteroid_lod_hidden_distance: f32,
+    pub asteroid_lod_hysteresis: f32,
+    pub trajectory_horizon_secs: f32,
+    pub trajectory_step_secs: f32,
+    pub trajectory_max_asteroids: u32,
+    pub trajectory_max_sources: u32,
+    pub hover_tooltip_delay_secs: f32,
+    pub hover_tooltip_pick_radius: f32,
+
+    // ── Particles ─────────────────────────────────────────────────────────────
+    pub particle_budget: u32,
+
+    // ── Motion Trails ─────────────────────────────────────────────────────────
+    pub motion_trail_ghosts: u32,
+    pub motion_trail_interval_secs: f32,
+    pub motion_trail_min_speed: f32,
+    pub motion_trail_alpha: f32,
+    pub motion_streak_max: u32,
+    pub motion_streak_min_speed: f32,
+    pub motion_streak_secs: f32,
+    pub motion_streak_alpha: f32,
+
+    // ── Feedback Intensity ────────────────────────────────────────────────────
+    pub feedback_reference_mass: f32,
+    pub feedback_merge_weight: f32,
+    pub feedback_split_weight: f32,
+    pub feedback_destroy_weight: f32,
+    pub feedback_particles_min: u32,
+    pub feedback_particles_max: u32,
+    pub feedback_sfx_min_intensity: f32,
+    pub feedback_sfx_max_gain: f32,
+    pub feedback_pitch_small: f32,
+    pub feedback_pitch_large: f32,
+    pub feedback_shake_max_trauma: f32,
+    pub feedback_shake_radius: f32,
+    pub feedback_shake_max_offset: f32,
+    pub feedback_shake_decay_per_sec: f32,
+    pub feedback_hit_stop_min_intensity: f32,
+    pub feedback_hit_stop_max_secs: f32,
+    pub feedback_hit_stop_time_scale: f32,
+
+    // ── Hit Feedback ──────────────────────────────────────────────────────────
+    pub damage_feedback_reference: f32,
+    pub damage_shake_max_trauma: f32,
+    pub aberration_pulse_max: f32,
+    pub aberration_pulse_secs: f32,
+    pub hit_flash_secs: f32,
+    pub hit_flash_strength: f32,
+    pub merge_animation_secs: f32,
+
+    // ── Cinematics ────────────────────────────────────────────────────────────
+    pub victory_dominant_mass: u32,
+    pub victory_dominant_share: f32,
+    pub cinematic_victory_secs: f32,
+    pub cinematic_orbit_radius: f32,
+    pub cinematic_orbit_turns: f32,
+    pub cinematic_zoom_start: f32,
+    pub cinematic_zoom_end: f32,
+
+    // ── Frame-Time Governor ───────────────────────────────────────────────────
+    pub frame_governor_enabled: bool,
+    pub frame_governor_target_ms: f32,
+    pub frame_governor_degrade_secs: f32,
+    pub frame_governor_restore_secs: f32,
+    pub frame_governor_headroom_fraction: f32,
+    pub frame_governor_gravity_radius_step: f32,
+    pub frame_governor_particle_budget_step: f32,
+    pub frame_governor_gravity_epsilon_step: f32,
+    pub frame_governor_lod_distance_step: f32,
+
+    // ── Gravity Lensing ───────────────────────────────────────────────────────
+    pub gravity_lensing_enabled: bool,
+    pub gravity_lensing_strength: f32,
+    pub gravity_lensing_radius_scale: f32,
+    pub gravity_lensing_reference_mass: f32,
+
+    // ── Starfield ─────────────────────────────────────────────────────────────
+    pub starfield_enabled: bool,
+    pub starfield_layers: u32,
+    pub starfield_stars_per_layer: u32,
+    pub starfield_parallax_far: f32,
+    pub starfield_parallax_near: f32,
+    pub starfield_nebula_clouds: u32,
+    pub starfield_glow_strength: f32,
+    pub starfield_glow_radius_scale: f32,
+    pub starfield_glow_min_mass: f32,
+    pub starfield_glow_reference_mass: f32,
 
     // ── Asteroid Geometry ─────────────────────────────────────────────────────
     pub hull_dedup_min_dist: f32,
@@ -244,6 +596,8 @@ pub struct PhysicsConfig {
     pub spawn_shape_subdivision_jitter_fraction: f32,
     pub spawn_shape_noise_frequency: f32,
     pub spawn_shape_noise_amplitude: f32,
+    pub canonical_shape_noise_enabled: bool,
+    pub canonical_shape_noise_amplitude: f32,
     pub asteroid_initial_velocity_range: f32,
     pub asteroid_initial_angvel_range: f32,
     pub crater_radius_ratio: f32,
@@ -251,6 +605,23 @@ pub struct PhysicsConfig {
     pub crater_depth_per_hit: f32,
     pub crater_edge_subdivisions: usize,
     pub max_craters_per_asteroid: usize,
+    pub planet_crater_radius: f32,
+    pub planet_crater_depth: f32,
+    pub max_craters_per_planet: usize,
+    pub planet_excavation_mass: u32,
+    pub planet_min_mass: u32,
+    pub planet_ejecta_max_fragments: usize,
+    pub planet_ejecta_speed: f32,
+    pub planet_impact_min_mass: u32,
+    pub planet_impact_force_threshold: f32,
+    pub soft_body_min_mass: u32,
+    pub soft_body_force_threshold: f32,
+    pub soft_body_max_strain: f32,
+    pub soft_body_min_aspect: f32,
+    pub roche_radius_scale: f32,
+    pub ring_fragment_max_mass: u32,
+    pub ring_circularization_rate: f32,
+    pub ring_zone_scale: f32,
 
     // ── Physics: Density ──────────────────────────────────────────────────────
     /// Mass units per world-unit² used to scale polygon visual area to match mass.
@@ -266,26 +637,84 @@ impl Default for PhysicsConfig {
             sim_height: SIM_HEIGHT,
             spawn_grid_margin: SPAWN_GRID_MARGIN,
             player_buffer_radius: PLAYER_BUFFER_RADIUS,
+            // Timestep
+            physics_tick_hz: PHYSICS_TICK_HZ,
+            physics_substeps: PHYSICS_SUBSTEPS,
+            physics_interpolation: PHYSICS_INTERPOLATION,
             // Gravity
             gravity_const: GRAVITY_CONST,
             min_gravity_dist: MIN_GRAVITY_DIST,
             max_gravity_dist: MAX_GRAVITY_DIST,
+            gravity_parallel: GRAVITY_PARALLEL,
             // Cluster Formation
             velocity_threshold_locking: VELOCITY_THRESHOLD_LOCKING,
             hull_extent_base: HULL_EXTENT_BASE,
             hull_extent_per_member: HULL_EXTENT_PER_MEMBER,
+            hull_simplify_min_vertices: HULL_SIMPLIFY_MIN_VERTICES,
+            hull_simplify_epsilon: HULL_SIMPLIFY_EPSILON,
+            merge_cooldown_secs: MERGE_COOLDOWN_SECS,
+            merge_rearm_gap: MERGE_REARM_GAP,
+            merge_cooldown_max_secs: MERGE_COOLDOWN_MAX_SECS,
+            formation_merge_budget: FORMATION_MERGE_BUDGET,
+            lineage_max_nodes: LINEAGE_MAX_NODES,
+            // Cluster Labeling
+            cluster_label_interval_secs: CLUSTER_LABEL_INTERVAL_SECS,
+            cluster_link_distance: CLUSTER_LINK_DISTANCE,
+            cluster_min_members: CLUSTER_MIN_MEMBERS,
             // Collision
-            restitution_small: RESTITUTION_SMALL,
+            restitution_low_energy: RESTITUTION_LOW_ENERGY,
+            restitution_high_energy: RESTITUTION_HIGH_ENERGY,
+            restitution_max: RESTITUTION_MAX,
+            impact_fragment_energy: IMPACT_FRAGMENT_ENERGY,
+            impact_fragment_fraction: IMPACT_FRAGMENT_FRACTION,
             friction_asteroid: FRICTION_ASTEROID,
+            // Shockwaves
+            shockwave_radius: SHOCKWAVE_RADIUS,
+            shockwave_impulse_per_mass: SHOCKWAVE_IMPULSE_PER_MASS,
+            shockwave_max_impulse: SHOCKWAVE_MAX_IMPULSE,
+            shockwave_merge_min_mass: SHOCKWAVE_MERGE_MIN_MASS,
+            // Volatile Asteroids
+            volatile_asteroid_fraction: VOLATILE_ASTEROID_FRACTION,
+            volatile_blast_radius: VOLATILE_BLAST_RADIUS,
+            volatile_blast_damage: VOLATILE_BLAST_DAMAGE,
+            volatile_blast_push: VOLATILE_BLAST_PUSH,
+            volatile_blast_destroy_size: VOLATILE_BLAST_DESTROY_SIZE,
+            volatile_chain_delay_secs: VOLATILE_CHAIN_DELAY_SECS,
             // Culling
             cull_distance: CULL_DISTANCE,
             soft_boundary_radius: SOFT_BOUNDARY_RADIUS,
             soft_boundary_strength: SOFT_BOUNDARY_STRENGTH,
-            hard_cull_distance: HARD_CULL_DISTANCE,
+            // Sector Streaming
+            sector_size: SECTOR_SIZE,
+            sector_stream_out_radius: SECTOR_STREAM_OUT_RADIUS,
+            sector_stream_in_radius: SECTOR_STREAM_IN_RADIUS,
+            // Procedural Field
+            procgen_generate_radius: PROCGEN_GENERATE_RADIUS,
+            procgen_samples_per_chunk: PROCGEN_SAMPLES_PER_CHUNK,
+            procgen_density: PROCGEN_DENSITY,
+            origin_recenter_distance: ORIGIN_RECENTER_DISTANCE,
+            // Density Field
+            density_cell_size: DENSITY_CELL_SIZE,
+            density_refresh_secs: DENSITY_REFRESH_SECS,
+            enemy_spawn_density_samples: ENEMY_SPAWN_DENSITY_SAMPLES,
+            ore_vein_mass: ORE_VEIN_MASS,
+            ore_vein_drop_multiplier: ORE_VEIN_DROP_MULTIPLIER,
+            procgen_crowd_mass: PROCGEN_CROWD_MASS,
+            // Scenario Loading
+            scenario_spawn_budget: SCENARIO_SPAWN_BUDGET,
+            loading_min_secs: LOADING_MIN_SECS,
+            loading_particle_prewarm: LOADING_PARTICLE_PREWARM,
+            // Session Autosave
+            session_autosave_interval_secs: SESSION_AUTOSAVE_INTERVAL_SECS,
+            // Save Storage
+            save_storage_quota_mb: SAVE_STORAGE_QUOTA_MB,
+            storage_prune_keep: STORAGE_PRUNE_KEEP,
             // Neighbor Counting
             neighbor_threshold: NEIGHBOR_THRESHOLD,
             // Spatial Grid
             grid_cell_size: GRID_CELL_SIZE,
+            spatial_update_tolerance: SPATIAL_UPDATE_TOLERANCE,
+            spatial_rebuild_imbalance: SPATIAL_REBUILD_IMBALANCE,
             // Camera
             min_zoom: MIN_ZOOM,
             max_zoom: MAX_ZOOM,
@@ -325,6 +754,10 @@ impl Default for PhysicsConfig {
                 TRACTOR_BEAM_FREEZE_MAX_TARGET_SPEED_MULTIPLIER,
             tractor_throw_cooldown_base: TRACTOR_THROW_COOLDOWN_BASE,
             tractor_throw_cooldown_per_level: TRACTOR_THROW_COOLDOWN_PER_LEVEL,
+            tractor_deflect_min_level: TRACTOR_DEFLECT_MIN_LEVEL,
+            tractor_deflect_window_secs: TRACTOR_DEFLECT_WINDOW_SECS,
+            tractor_deflect_energy_cost: TRACTOR_DEFLECT_ENERGY_COST,
+            tractor_deflect_speed_multiplier: TRACTOR_DEFLECT_SPEED_MULTIPLIER,
             // Enemy Ships: Foundation
             enemy_spawn_base_cooldown: ENEMY_SPAWN_BASE_COOLDOWN,
             enemy_spawn_cooldown_per_stage: ENEMY_SPAWN_COOLDOWN_PER_STAGE,
@@ -337,6 +770,8 @@ impl Default for PhysicsConfig {
             enemy_spawn_radius: ENEMY_SPAWN_RADIUS,
             enemy_min_player_spawn_distance: ENEMY_MIN_PLAYER_SPAWN_DISTANCE,
             enemy_min_enemy_spacing: ENEMY_MIN_ENEMY_SPACING,
+            spawn_exclusion_hauler_radius: SPAWN_EXCLUSION_HAULER_RADIUS,
+            spawn_exclusion_objective_radius: SPAWN_EXCLUSION_OBJECTIVE_RADIUS,
             enemy_seek_force: ENEMY_SEEK_FORCE,
             enemy_max_speed: ENEMY_MAX_SPEED,
             enemy_arrive_radius: ENEMY_ARRIVE_RADIUS,
@@ -373,6 +808,68 @@ impl Default for PhysicsConfig {
             boss_phase_two_aim_cooldown: BOSS_PHASE_TWO_AIM_COOLDOWN,
             boss_phase_two_burst_cooldown: BOSS_PHASE_TWO_BURST_COOLDOWN,
             boss_phase_two_burst_shots: BOSS_PHASE_TWO_BURST_SHOTS,
+            // Enemy Ships: Retreat
+            enemy_retreat_hp_fraction: ENEMY_RETREAT_HP_FRACTION,
+            enemy_retreat_escape_radius: ENEMY_RETREAT_ESCAPE_RADIUS,
+            enemy_reinforcement_delay_secs: ENEMY_REINFORCEMENT_DELAY_SECS,
+            enemy_reinforcements_per_escape: ENEMY_REINFORCEMENTS_PER_ESCAPE,
+            // Enemy Ships: Avoidance
+            enemy_avoidance_enabled: ENEMY_AVOIDANCE_ENABLED,
+            enemy_avoidance_lookahead_secs: ENEMY_AVOIDANCE_LOOKAHEAD_SECS,
+            enemy_avoidance_min_size: ENEMY_AVOIDANCE_MIN_SIZE,
+            enemy_avoidance_clearance: ENEMY_AVOIDANCE_CLEARANCE,
+            enemy_avoidance_weight: ENEMY_AVOIDANCE_WEIGHT,
+            // Enemy Ships: Archetypes
+            enemy_weight_chaser: ENEMY_WEIGHT_CHASER,
+            enemy_weight_skirmisher: ENEMY_WEIGHT_SKIRMISHER,
+            enemy_weight_kamikaze: ENEMY_WEIGHT_KAMIKAZE,
+            enemy_weight_miner: ENEMY_WEIGHT_MINER,
+            enemy_skirmisher_min_stage: ENEMY_SKIRMISHER_MIN_STAGE,
+            enemy_kamikaze_min_stage: ENEMY_KAMIKAZE_MIN_STAGE,
+            enemy_miner_min_stage: ENEMY_MINER_MIN_STAGE,
+            enemy_kamikaze_hp_mult: ENEMY_KAMIKAZE_HP_MULT,
+            enemy_kamikaze_dash_radius: ENEMY_KAMIKAZE_DASH_RADIUS,
+            enemy_kamikaze_dash_force_mult: ENEMY_KAMIKAZE_DASH_FORCE_MULT,
+            enemy_kamikaze_dash_speed_mult: ENEMY_KAMIKAZE_DASH_SPEED_MULT,
+            enemy_kamikaze_trigger_radius: ENEMY_KAMIKAZE_TRIGGER_RADIUS,
+            enemy_kamikaze_blast_radius: ENEMY_KAMIKAZE_BLAST_RADIUS,
+            enemy_kamikaze_blast_damage: ENEMY_KAMIKAZE_BLAST_DAMAGE,
+            enemy_kamikaze_kill_score: ENEMY_KAMIKAZE_KILL_SCORE,
+            enemy_miner_hp_mult: ENEMY_MINER_HP_MULT,
+            enemy_miner_seek_radius: ENEMY_MINER_SEEK_RADIUS,
+            enemy_miner_pickup_radius: ENEMY_MINER_PICKUP_RADIUS,
+            enemy_miner_capacity: ENEMY_MINER_CAPACITY,
+            enemy_miner_standoff_radius: ENEMY_MINER_STANDOFF_RADIUS,
+            enemy_miner_kill_score: ENEMY_MINER_KILL_SCORE,
+            // Campaign: Wave Intermission
+            wave_intermission_secs: WAVE_INTERMISSION_SECS,
+            wave_intermission_shop_discount: WAVE_INTERMISSION_SHOP_DISCOUNT,
+            wave_intermission_beacon_offset: WAVE_INTERMISSION_BEACON_OFFSET,
+            wave_intermission_beacon_radius: WAVE_INTERMISSION_BEACON_RADIUS,
+            // Survival Mode
+            survival_initial_asteroids: SURVIVAL_INITIAL_ASTEROIDS,
+            survival_first_wave_delay_secs: SURVIVAL_FIRST_WAVE_DELAY_SECS,
+            survival_breather_secs: SURVIVAL_BREATHER_SECS,
+            survival_comets_base: SURVIVAL_COMETS_BASE,
+            survival_comets_per_wave: SURVIVAL_COMETS_PER_WAVE,
+            survival_comet_interval_secs: SURVIVAL_COMET_INTERVAL_SECS,
+            survival_comet_speed_base: SURVIVAL_COMET_SPEED_BASE,
+            survival_comet_speed_per_wave: SURVIVAL_COMET_SPEED_PER_WAVE,
+            survival_enemies_base: SURVIVAL_ENEMIES_BASE,
+            survival_enemies_per_wave: SURVIVAL_ENEMIES_PER_WAVE,
+            survival_score_mult_per_wave: SURVIVAL_SCORE_MULT_PER_WAVE,
+            // Catalog Scenario
+            catalog_au_to_world: CATALOG_AU_TO_WORLD,
+            catalog_center_x: CATALOG_CENTER_X,
+            catalog_center_y: CATALOG_CENTER_Y,
+            catalog_central_mass: CATALOG_CENTRAL_MASS,
+            catalog_max_bodies: CATALOG_MAX_BODIES,
+            catalog_min_orbit_radius: CATALOG_MIN_ORBIT_RADIUS,
+            catalog_max_orbit_radius: CATALOG_MAX_ORBIT_RADIUS,
+            catalog_reference_diameter_km: CATALOG_REFERENCE_DIAMETER_KM,
+            catalog_size_min: CATALOG_SIZE_MIN,
+            catalog_size_max: CATALOG_SIZE_MAX,
+            catalog_velocity_scale: CATALOG_VELOCITY_SCALE,
             // Player: Combat
             projectile_speed: PROJECTILE_SPEED,
             fire_cooldown: FIRE_COOLDOWN,
@@ -390,9 +887,15 @@ impl Default for PhysicsConfig {
             missile_collider_radius: MISSILE_COLLIDER_RADIUS,
             missile_recharge_secs: MISSILE_RECHARGE_SECS,
             missile_split_max_pieces: MISSILE_SPLIT_MAX_PIECES,
+            missile_homing_min_level: MISSILE_HOMING_MIN_LEVEL,
+            missile_homing_range: MISSILE_HOMING_RANGE,
+            missile_homing_cone_dot: MISSILE_HOMING_CONE_DOT,
+            missile_homing_turn_rate: MISSILE_HOMING_TURN_RATE,
+            missile_homing_min_asteroid_size: MISSILE_HOMING_MIN_ASTEROID_SIZE,
             // Player: Health
             player_max_hp: PLAYER_MAX_HP,
             damage_speed_threshold: DAMAGE_SPEED_THRESHOLD,
+            player_damage_taken_multiplier: PLAYER_DAMAGE_TAKEN_MULTIPLIER,
             invincibility_duration: INVINCIBILITY_DURATION,
             // Player: Lives & Respawn
             player_lives: PLAYER_LIVES,
@@ -401,14 +904,107 @@ impl Default for PhysicsConfig {
             // Player: Passive Healing
             passive_heal_delay_secs: PASSIVE_HEAL_DELAY_SECS,
             passive_heal_rate: PASSIVE_HEAL_RATE,
+            // Player: Energy
+            player_energy_max: PLAYER_ENERGY_MAX,
+            player_energy_regen_rate: PLAYER_ENERGY_REGEN_RATE,
+            player_energy_regen_delay_secs: PLAYER_ENERGY_REGEN_DELAY_SECS,
+            thrust_energy_per_sec: THRUST_ENERGY_PER_SEC,
+            ion_cannon_energy_cost: ION_CANNON_ENERGY_COST,
+            tractor_beam_energy_per_sec: TRACTOR_BEAM_ENERGY_PER_SEC,
+            // Player: Lock-On
+            lock_on_range: LOCK_ON_RANGE,
+            lock_on_cone_dot: LOCK_ON_CONE_DOT,
+            lock_on_min_asteroid_size: LOCK_ON_MIN_ASTEROID_SIZE,
+            lock_on_release_range_mult: LOCK_ON_RELEASE_RANGE_MULT,
+            missile_lock_on_turn_rate: MISSILE_LOCK_ON_TURN_RATE,
+            lock_on_camera_framing: LOCK_ON_CAMERA_FRAMING,
+            lock_on_frame_margin: LOCK_ON_FRAME_MARGIN,
+            // Player: Kill-Cam
+            kill_cam_enabled: KILL_CAM_ENABLED,
+            kill_cam_duration_secs: KILL_CAM_DURATION_SECS,
+            kill_cam_replay_secs: KILL_CAM_REPLAY_SECS,
+            kill_cam_replay_radius: KILL_CAM_REPLAY_RADIUS,
+            // Player: Time Control
+            time_control_enabled: TIME_CONTROL_ENABLED,
+            time_control_slow_scale: TIME_CONTROL_SLOW_SCALE,
+            time_control_fast_scale: TIME_CONTROL_FAST_SCALE,
+            time_control_danger_radius: TIME_CONTROL_DANGER_RADIUS,
+            // Player: Shield
+            shield_hp_per_level: SHIELD_HP_PER_LEVEL,
+            shield_regen_rate: SHIELD_REGEN_RATE,
+            shield_recharge_delay_secs: SHIELD_RECHARGE_DELAY_SECS,
+            shield_rear_absorb_fraction: SHIELD_REAR_ABSORB_FRACTION,
+            shield_regen_energy_per_hp: SHIELD_REGEN_ENERGY_PER_HP,
+            shield_hit_flash_secs: SHIELD_HIT_FLASH_SECS,
+            // Player: Turrets
+            turret_range: TURRET_RANGE,
+            turret_target_max_size: TURRET_TARGET_MAX_SIZE,
+            turret_fire_interval_secs: TURRET_FIRE_INTERVAL_SECS,
+            turret_energy_per_shot: TURRET_ENERGY_PER_SHOT,
+            turret_turn_rate: TURRET_TURN_RATE,
+            turret_arc_half_width: TURRET_ARC_HALF_WIDTH,
+            turret_projectile_speed: TURRET_PROJECTILE_SPEED,
+            // Ore Pickups
+            ore_drop_velocity_inherit: ORE_DROP_VELOCITY_INHERIT,
+            ore_drop_spread: ORE_DROP_SPREAD,
+            ore_drop_damping: ORE_DROP_DAMPING,
             // Ore Magnet
             ore_magnet_radius: ORE_MAGNET_BASE_RADIUS,
             ore_magnet_strength: ORE_MAGNET_BASE_STRENGTH,
             ore_heal_amount: ORE_HEAL_AMOUNT,
+            shop_undo_window_secs: SHOP_UNDO_WINDOW_SECS,
+            // Ore Types & Refining
+            asteroid_ice_fraction: ASTEROID_ICE_FRACTION,
+            asteroid_metal_fraction: ASTEROID_METAL_FRACTION,
+            ore_refine_secs_per_unit: ORE_REFINE_SECS_PER_UNIT,
+            ore_refine_credits_iron: ORE_REFINE_CREDITS_IRON,
+            ore_refine_credits_ice: ORE_REFINE_CREDITS_ICE,
+            ore_refine_credits_rare: ORE_REFINE_CREDITS_RARE,
+            // Mining Drone
+            mining_drone_orbit_radius: MINING_DRONE_ORBIT_RADIUS,
+            mining_drone_orbit_speed: MINING_DRONE_ORBIT_SPEED,
+            mining_drone_seek_radius: MINING_DRONE_SEEK_RADIUS,
+            mining_drone_contact_radius: MINING_DRONE_CONTACT_RADIUS,
+            mining_drone_base_speed: MINING_DRONE_BASE_SPEED,
+            mining_drone_speed_per_level: MINING_DRONE_SPEED_PER_LEVEL,
+            mining_drone_base_capacity: MINING_DRONE_BASE_CAPACITY,
+            mining_drone_capacity_per_level: MINING_DRONE_CAPACITY_PER_LEVEL,
+            // Mining Outposts
+            outpost_build_cost: OUTPOST_BUILD_COST,
+            outpost_build_range: OUTPOST_BUILD_RANGE,
+            outpost_max_count: OUTPOST_MAX_COUNT,
+            outpost_ore_interval_secs: OUTPOST_ORE_INTERVAL_SECS,
+            outpost_ore_per_interval: OUTPOST_ORE_PER_INTERVAL,
+            outpost_max_hp: OUTPOST_MAX_HP,
+            outpost_hit_radius: OUTPOST_HIT_RADIUS,
+            outpost_aggro_radius: OUTPOST_AGGRO_RADIUS,
+            // Mining Laser
+            mining_laser_range: MINING_LASER_RANGE,
+            mining_laser_carve_rate: MINING_LASER_CARVE_RATE,
+            mining_laser_heat_per_sec: MINING_LASER_HEAT_PER_SEC,
+            mining_laser_cool_per_sec: MINING_LASER_COOL_PER_SEC,
+            mining_laser_recover_heat: MINING_LASER_RECOVER_HEAT,
+            // Audio
+            audio_master_volume: AUDIO_MASTER_VOLUME,
+            audio_effects_volume: AUDIO_EFFECTS_VOLUME,
+            audio_music_volume: AUDIO_MUSIC_VOLUME,
+            sfx_max_per_frame: SFX_MAX_PER_FRAME,
+            impact_sfx_reference_energy: IMPACT_SFX_REFERENCE_ENERGY,
+            impact_sfx_min_gain: IMPACT_SFX_MIN_GAIN,
+            low_health_warning_fraction: LOW_HEALTH_WARNING_FRACTION,
+            low_health_warning_interval_secs: LOW_HEALTH_WARNING_INTERVAL_SECS,
+            ambient_density_radius: AMBIENT_DENSITY_RADIUS,
+            ambient_density_full_count: AMBIENT_DENSITY_FULL_COUNT,
+            ambient_enemy_full_count: AMBIENT_ENEMY_FULL_COUNT,
+            ambient_fade_secs: AMBIENT_FADE_SECS,
+            size_class_rumble_step: SIZE_CLASS_RUMBLE_STEP,
+            size_class_announce_secs: SIZE_CLASS_ANNOUNCE_SECS,
             // Gamepad
             gamepad_left_deadzone: GAMEPAD_LEFT_DEADZONE,
             gamepad_brake_damping: GAMEPAD_BRAKE_DAMPING,
             aim_idle_snap_secs: AIM_IDLE_SNAP_SECS,
+            aim_snap_target_memory: AIM_SNAP_TARGET_MEMORY,
+            aim_snap_target_range: AIM_SNAP_TARGET_RANGE,
             gamepad_right_deadzone: GAMEPAD_RIGHT_DEADZONE,
             gamepad_fire_threshold: GAMEPAD_FIRE_THRESHOLD,
             gamepad_heading_snap_threshold: GAMEPAD_HEADING_SNAP_THRESHOLD,
@@ -417,6 +1013,95 @@ impl Default for PhysicsConfig {
             force_vector_display_scale: FORCE_VECTOR_DISPLAY_SCALE,
             force_vector_min_length: FORCE_VECTOR_MIN_LENGTH,
             stats_font_size: STATS_FONT_SIZE,
+            hud_margin_px: HUD_MARGIN_PX,
+            hud_stack_gap_px: HUD_STACK_GAP_PX,
+            hud_max_aspect_ratio: HUD_MAX_ASPECT_RATIO,
+            streamer_hud_scale: STREAMER_HUD_SCALE,
+            streamer_webcam_width_px: STREAMER_WEBCAM_WIDTH_PX,
+            streamer_webcam_height_px: STREAMER_WEBCAM_HEIGHT_PX,
+            asteroid_shared_assets_enabled: ASTEROID_SHARED_ASSETS_ENABLED,
+            asteroid_hull_instancing_enabled: ASTEROID_HULL_INSTANCING_ENABLED,
+            asteroid_lod_enabled: ASTEROID_LOD_ENABLED,
+            asteroid_lod_simplified_distance: ASTEROID_LOD_SIMPLIFIED_DISTANCE,
+            asteroid_lod_hidden_distance: ASTEROID_LOD_HIDDEN_DISTANCE,
+            asteroid_lod_hysteresis: ASTEROID_LOD_HYSTERESIS,
+            trajectory_horizon_secs: TRAJECTORY_HORIZON_SECS,
+            trajectory_step_secs: TRAJECTORY_STEP_SECS,
+            trajectory_max_asteroids: TRAJECTORY_MAX_ASTEROIDS,
+            trajectory_max_sources: TRAJECTORY_MAX_SOURCES,
+            hover_tooltip_delay_secs: HOVER_TOOLTIP_DELAY_SECS,
+            hover_tooltip_pick_radius: HOVER_TOOLTIP_PICK_RADIUS,
+            // Particles
+            particle_budget: PARTICLE_BUDGET,
+            // Motion Trails
+            motion_trail_ghosts: MOTION_TRAIL_GHOSTS,
+            motion_trail_interval_secs: MOTION_TRAIL_INTERVAL_SECS,
+            motion_trail_min_speed: MOTION_TRAIL_MIN_SPEED,
+            motion_trail_alpha: MOTION_TRAIL_ALPHA,
+            motion_streak_max: MOTION_STREAK_MAX,
+            motion_streak_min_speed: MOTION_STREAK_MIN_SPEED,
+            motion_streak_secs: MOTION_STREAK_SECS,
+            motion_streak_alpha: MOTION_STREAK_ALPHA,
+            // Feedback Intensity
+            feedback_reference_mass: FEEDBACK_REFERENCE_MASS,
+            feedback_merge_weight: FEEDBACK_MERGE_WEIGHT,
+            feedback_split_weight: FEEDBACK_SPLIT_WEIGHT,
+            feedback_destroy_weight: FEEDBACK_DESTROY_WEIGHT,
+            feedback_particles_min: FEEDBACK_PARTICLES_MIN,
+            feedback_particles_max: FEEDBACK_PARTICLES_MAX,
+            feedback_sfx_min_intensity: FEEDBACK_SFX_MIN_INTENSITY,
+            feedback_sfx_max_gain: FEEDBACK_SFX_MAX_GAIN,
+            feedback_pitch_small: FEEDBACK_PITCH_SMALL,
+            feedback_pitch_large: FEEDBACK_PITCH_LARGE,
+            feedback_shake_max_trauma: FEEDBACK_SHAKE_MAX_TRAUMA,
+            feedback_shake_radius: FEEDBACK_SHAKE_RADIUS,
+            feedback_shake_max_offset: FEEDBACK_SHAKE_MAX_OFFSET,
+            feedback_shake_decay_per_sec: FEEDBACK_SHAKE_DECAY_PER_SEC,
+            feedback_hit_stop_min_intensity: FEEDBACK_HIT_STOP_MIN_INTENSITY,
+            feedback_hit_stop_max_secs: FEEDBACK_HIT_STOP_MAX_SECS,
+            feedback_hit_stop_time_scale: FEEDBACK_HIT_STOP_TIME_SCALE,
+            // Hit Feedback
+            damage_feedback_reference: DAMAGE_FEEDBACK_REFERENCE,
+            damage_shake_max_trauma: DAMAGE_SHAKE_MAX_TRAUMA,
+            aberration_pulse_max: ABERRATION_PULSE_MAX,
+            aberration_pulse_secs: ABERRATION_PULSE_SECS,
+            hit_flash_secs: HIT_FLASH_SECS,
+            hit_flash_strength: HIT_FLASH_STRENGTH,
+            merge_animation_secs: MERGE_ANIMATION_SECS,
+            // Cinematics
+            victory_dominant_mass: VICTORY_DOMINANT_MASS,
+            victory_dominant_share: VICTORY_DOMINANT_SHARE,
+            cinematic_victory_secs: CINEMATIC_VICTORY_SECS,
+            cinematic_orbit_radius: CINEMATIC_ORBIT_RADIUS,
+            cinematic_orbit_turns: CINEMATIC_ORBIT_TURNS,
+            cinematic_zoom_start: CINEMATIC_ZOOM_START,
+            cinematic_zoom_end: CINEMATIC_ZOOM_END,
+            // Frame-Time Governor
+            frame_governor_enabled: FRAME_GOVERNOR_ENABLED,
+            frame_governor_target_ms: FRAME_GOVERNOR_TARGET_MS,
+            frame_governor_degrade_secs: FRAME_GOVERNOR_DEGRADE_SECS,
+            frame_governor_restore_secs: FRAME_GOVERNOR_RESTORE_SECS,
+            frame_governor_headroom_fraction: FRAME_GOVERNOR_HEADROOM_FRACTION,
+            frame_governor_gravity_radius_step: FRAME_GOVERNOR_GRAVITY_RADIUS_STEP,
+            frame_governor_particle_budget_step: FRAME_GOVERNOR_PARTICLE_BUDGET_STEP,
+            frame_governor_gravity_epsilon_step: FRAME_GOVERNOR_GRAVITY_EPSILON_STEP,
+            frame_governor_lod_distance_step: FRAME_GOVERNOR_LOD_DISTANCE_STEP,
+            // Gravity Lensing
+            gravity_lensing_enabled: GRAVITY_LENSING_ENABLED,
+            gravity_lensing_strength: GRAVITY_LENSING_STRENGTH,
+            gravity_lensing_radius_scale: GRAVITY_LENSING_RADIUS_SCALE,
+            gravity_lensing_reference_mass: GRAVITY_LENSING_REFERENCE_MASS,
+            // Starfield
+            starfield_enabled: STARFIELD_ENABLED,
+            starfield_layers: STARFIELD_LAYERS,
+            starfield_stars_per_layer: STARFIELD_STARS_PER_LAYER,
+            starfield_parallax_far: STARFIELD_PARALLAX_FAR,
+            starfield_parallax_near: STARFIELD_PARALLAX_NEAR,
+            starfield_nebula_clouds: STARFIELD_NEBULA_CLOUDS,
+            starfield_glow_strength: STARFIELD_GLOW_STRENGTH,
+            starfield_glow_radius_scale: STARFIELD_GLOW_RADIUS_SCALE,
+            starfield_glow_min_mass: STARFIELD_GLOW_MIN_MASS,
+            starfield_glow_reference_mass: STARFIELD_GLOW_REFERENCE_MASS,
             // Asteroid Geometry
             hull_dedup_min_dist: HULL_DEDUP_MIN_DIST,
             triangle_base_side: TRIANGLE_BASE_SIDE,
@@ -434,6 +1119,8 @@ impl Default for PhysicsConfig {
             spawn_shape_subdivision_jitter_fraction: SPAWN_SHAPE_SUBDIVISION_JITTER_FRACTION,
             spawn_shape_noise_frequency: SPAWN_SHAPE_NOISE_FREQUENCY,
             spawn_shape_noise_amplitude: SPAWN_SHAPE_NOISE_AMPLITUDE,
+            canonical_shape_noise_enabled: CANONICAL_SHAPE_NOISE_ENABLED,
+            canonical_shape_noise_amplitude: CANONICAL_SHAPE_NOISE_AMPLITUDE,
             asteroid_initial_velocity_range: ASTEROID_INITIAL_VELOCITY_RANGE,
             asteroid_initial_angvel_range: ASTEROID_INITIAL_ANGVEL_RANGE,
             crater_radius_ratio: CRATER_RADIUS_RATIO,
@@ -441,6 +1128,23 @@ impl Default for PhysicsConfig {
             crater_depth_per_hit: CRATER_DEPTH_PER_HIT,
             crater_edge_subdivisions: CRATER_EDGE_SUBDIVISIONS,
             max_craters_per_asteroid: MAX_CRATERS_PER_ASTEROID,
+            planet_crater_radius: PLANET_CRATER_RADIUS,
+            planet_crater_depth: PLANET_CRATER_DEPTH,
+            max_craters_per_planet: MAX_CRATERS_PER_PLANET,
+            planet_excavation_mass: PLANET_EXCAVATION_MASS,
+            planet_min_mass: PLANET_MIN_MASS,
+            planet_ejecta_max_fragments: PLANET_EJECTA_MAX_FRAGMENTS,
+            planet_ejecta_speed: PLANET_EJECTA_SPEED,
+            planet_impact_min_mass: PLANET_IMPACT_MIN_MASS,
+            planet_impact_force_threshold: PLANET_IMPACT_FORCE_THRESHOLD,
+            soft_body_min_mass: SOFT_BODY_MIN_MASS,
+            soft_body_force_threshold: SOFT_BODY_FORCE_THRESHOLD,
+            soft_body_max_strain: SOFT_BODY_MAX_STRAIN,
+            soft_body_min_aspect: SOFT_BODY_MIN_ASPECT,
+            roche_radius_scale: ROCHE_RADIUS_SCALE,
+            ring_fragment_max_mass: RING_FRAGMENT_MAX_MASS,
+            ring_circularization_rate: RING_CIRCULARIZATION_RATE,
+            ring_zone_scale: RING_ZONE_SCALE,
             // Density
             asteroid_density: ASTEROID_DENSITY,
         }
@@ -518,8 +1222,12 @@ pub fn hot_reload_physics_config(
 fn read_physics_config_file(path: &str) -> Result<PhysicsConfig, String> {
     let contents =
         std::fs::read_to_string(path).map_err(|err| format!("failed reading {path}: {err}"))?;
-    toml::from_str::<PhysicsConfig>(&contents)
-        .map_err(|err| format!("failed parsing {path}: {err}"))
+    parse_physics_config(&contents).map_err(|err| format!("failed parsing {path}: {err}"))
+}
+
+/// Parse physics config TOML; missing keys keep their compiled defaults.
+pub fn parse_physics_config(contents: &str) -> Result<PhysicsConfig, toml::de::Error> {
+    toml::from_str::<PhysicsConfig>(contents)
 }
 
 fn physics_config_modified_time(path: &str) -> Option<SystemTime> {
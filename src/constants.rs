@@ -29,6 +29,23 @@ pub const SPAWN_GRID_MARGIN: f32 = 150.0;
 /// Decrease to make encounters start faster.
 pub const PLAYER_BUFFER_RADIUS: f32 = 100.0;
 
+// ── Physics: Timestep ─────────────────────────────────────────────────────────
+
+/// Fixed physics tick rate (Hz) shared by `FixedUpdate` and Rapier.
+///
+/// Gravity, boundary springs, merging, and the Rapier step all advance by
+/// exactly `1 / PHYSICS_TICK_HZ` seconds per tick, so results do not depend on
+/// the display refresh rate.  60 Hz matches the old variable-step cap.
+/// Tested range: 30–240.  Higher rates cost proportionally more CPU.
+pub const PHYSICS_TICK_HZ: f32 = 60.0;
+
+/// Rapier solver substeps per physics tick.
+pub const PHYSICS_SUBSTEPS: usize = 1;
+
+/// Whether rendered transforms are interpolated between the last two physics
+/// ticks.  Disable to see raw tick positions (stutters when FPS ≠ tick rate).
+pub const PHYSICS_INTERPOLATION: bool = true;
+
 // ── Physics: Gravity ──────────────────────────────────────────────────────────
 
 /// Inverse-square gravity strength constant.
@@ -52,6 +69,13 @@ pub const MIN_GRAVITY_DIST: f32 = 5.0;
 /// Decreasing this value can improve performance at large asteroid counts.
 pub const MAX_GRAVITY_DIST: f32 = 1000.0;
 
+/// Whether gravity forces are accumulated in parallel on the compute task pool.
+///
+/// The parallel path evaluates each pair from both sides, so its results are
+/// stable run-to-run but not bitwise identical to the serial path.  Disable
+/// for deterministic comparisons against serial reference runs.
+pub const GRAVITY_PARALLEL: bool = true;
+
 // ── Physics: Cluster Formation ────────────────────────────────────────────────
 
 /// Maximum linear speed (u/s) at which a touching asteroid qualifies for
@@ -70,22 +94,117 @@ pub const VELOCITY_THRESHOLD_LOCKING: f32 = 5.0;
 pub const HULL_EXTENT_BASE: f32 = 60.0;
 pub const HULL_EXTENT_PER_MEMBER: f32 = 20.0;
 
+/// Merged hulls with more vertices than this are simplified before spawning
+/// (see `asteroid::simplify_hull`).
+pub const HULL_SIMPLIFY_MIN_VERTICES: usize = 24;
+
+/// Largest distance (u) a dropped hull vertex may lie from the simplified
+/// outline.  The simplified hull is rescaled to the original area.
+pub const HULL_SIMPLIFY_EPSILON: f32 = 0.4;
+
+/// Seconds after a split during which its fragments cannot merge back into
+/// each other (see `asteroid::MergeCooldown`).
+pub const MERGE_COOLDOWN_SECS: f32 = 1.5;
+
+/// Gap (u) between the bounding circles of two sibling fragments that counts
+/// as separated.  A fragment is released from its split lineage only once the
+/// cooldown has run out *and* it is this far from every sibling, so fragments
+/// resting against each other do not flicker between merged and split.
+pub const MERGE_REARM_GAP: f32 = 6.0;
+
+/// Hard limit (s) on a split lineage: after this long fragments may merge
+/// even if they never separated.
+pub const MERGE_COOLDOWN_MAX_SECS: f32 = 8.0;
+
+/// Most bound clusters merged in one fixed tick.  Further clusters are
+/// deferred to later ticks, hardest contact impulse first, so a dense shower
+/// cannot spike the frame (0 = unlimited).
+pub const FORMATION_MERGE_BUDGET: u32 = 12;
+
+/// Maximum nodes kept in a merged body's `lineage::Lineage` tree.  Past this,
+/// the histories of the smallest members are collapsed into single entries.
+pub const LINEAGE_MAX_NODES: usize = 64;
+
+// ── Cluster Labeling ──────────────────────────────────────────────────────────
+
+/// Simulation seconds between cluster-labeling passes.
+pub const CLUSTER_LABEL_INTERVAL_SECS: f32 = 2.0;
+
+/// Centre-to-centre distance (u) at which two asteroids count as linked.
+/// Linked bodies, and bodies linked through them, form one cluster.
+pub const CLUSTER_LINK_DISTANCE: f32 = 40.0;
+
+/// Smallest linked group reported as a cluster.
+pub const CLUSTER_MIN_MEMBERS: u32 = 3;
+
 // ── Physics: Collision ────────────────────────────────────────────────────────
 
-/// Restitution coefficient for small (unit) asteroids.
-/// 0.0 = perfectly inelastic; 1.0 = perfectly elastic.
-pub const RESTITUTION_SMALL: f32 = 0.0;
+/// Impact energy (`½·μ·v²`, reduced mass in `AsteroidSize` units) at or
+/// below which asteroid contacts stay perfectly inelastic, so slow bodies
+/// stick and merge.
+pub const RESTITUTION_LOW_ENERGY: f32 = 50.0;
+
+/// Impact energy at which asteroid restitution reaches
+/// [`RESTITUTION_MAX`].  It eases up along a smoothstep in between.
+pub const RESTITUTION_HIGH_ENERGY: f32 = 2000.0;
+
+/// Restitution of the hardest asteroid impacts.
+pub const RESTITUTION_MAX: f32 = 0.5;
+
+/// Impact energy at or above which each asteroid of mass 2+ sheds a
+/// fragment.
+pub const IMPACT_FRAGMENT_ENERGY: f32 = 1500.0;
+
+/// Share of its mass an asteroid sheds in a fragmenting impact (at least
+/// 1, and it always keeps at least 1).
+pub const IMPACT_FRAGMENT_FRACTION: f32 = 0.25;
 
 /// Friction coefficient applied to asteroid–asteroid contacts.
 pub const FRICTION_ASTEROID: f32 = 1.0;
 
+// ── Physics: Shockwaves ───────────────────────────────────────────────────────
+
+/// Radius (u) of an impact shockwave; the push falls off linearly to zero here.
+pub const SHOCKWAVE_RADIUS: f32 = 220.0;
+
+/// Peak shockwave impulse per unit of destroyed or merged mass.
+pub const SHOCKWAVE_IMPULSE_PER_MASS: f32 = 30.0;
+
+/// Cap on the peak impulse of any one shockwave.
+pub const SHOCKWAVE_MAX_IMPULSE: f32 = 1500.0;
+
+/// Merges producing at least this mass emit a shockwave.  Missile destroys
+/// always do.
+pub const SHOCKWAVE_MERGE_MIN_MASS: u32 = 40;
+
+// ── Volatile Asteroids ────────────────────────────────────────────────────────
+
+/// Chance that a freshly spawned asteroid is volatile (detonates when hit).
+pub const VOLATILE_ASTEROID_FRACTION: f32 = 0.03;
+
+/// Radius (u) of a volatile detonation.  Damage and push fall off linearly to
+/// zero here.
+pub const VOLATILE_BLAST_RADIUS: f32 = 160.0;
+
+/// Ship damage at the centre of a volatile detonation.
+pub const VOLATILE_BLAST_DAMAGE: f32 = 35.0;
+
+/// Velocity change (u/s) a ship at the centre of a detonation receives.
+pub const VOLATILE_BLAST_PUSH: f32 = 180.0;
+
+/// Asteroids up to this size inside the blast radius are destroyed.
+pub const VOLATILE_BLAST_DESTROY_SIZE: u32 = 3;
+
+/// Fuse (seconds) of a volatile lit by a neighbour's detonation.
+pub const VOLATILE_CHAIN_DELAY_SECS: f32 = 0.15;
+
 // ── Physics: Culling ──────────────────────────────────────────────────────────
 
 /// Inner radius of the soft boundary zone (world units).
 ///
 /// Asteroids that drift beyond this distance feel a gentle inward spring force
 /// nudging them back toward the simulation centre.  Set to 90 % of the gravity
-/// horizon so the spring activates well before `HARD_CULL_DISTANCE`.
+/// horizon so the spring activates well before `SECTOR_STREAM_OUT_RADIUS`.
 pub const SOFT_BOUNDARY_RADIUS: f32 = 1800.0;
 
 /// Spring constant for the soft boundary restoring force.
@@ -96,17 +215,106 @@ pub const SOFT_BOUNDARY_RADIUS: f32 = 1800.0;
 /// hundred frames without an abrupt bounce.
 pub const SOFT_BOUNDARY_STRENGTH: f32 = 2.0;
 
-/// Absolute hard-cull distance: asteroids beyond this are forcibly removed.
+/// Distance from the world origin used as the stats / stats-display reference
+/// boundary.  Asteroids are no longer removed here; far bodies are parked in
+/// sectors at `SECTOR_STREAM_OUT_RADIUS`.
+pub const CULL_DISTANCE: f32 = 2000.0;
+
+// ── Sector Streaming ──────────────────────────────────────────────────────────
+
+/// Side length (world units) of one sector in the parked-asteroid store.
+pub const SECTOR_SIZE: f32 = 1000.0;
+
+/// Distance from the ship beyond which an asteroid is serialized into its
+/// sector and despawned.
 ///
-/// Acts as a safety net if the soft spring is insufficient (e.g., a very fast
-/// projectile).  Set comfortably outside `SOFT_BOUNDARY_RADIUS` so normal
-/// simulation objects almost never reach it.
-pub const HARD_CULL_DISTANCE: f32 = 2500.0;
+/// Matches the old hard-cull distance, so with the ship near the origin the
+/// live field is the same size as before.
+pub const SECTOR_STREAM_OUT_RADIUS: f32 = 2500.0;
 
-/// Distance from the world origin beyond which asteroids are permanently removed.
-/// Now acts as the stats / stats-display reference boundary; hard removal happens
-/// at `HARD_CULL_DISTANCE`.
-pub const CULL_DISTANCE: f32 = 2000.0;
+/// Distance from the ship within which parked asteroids are respawned.
+///
+/// Kept below `SECTOR_STREAM_OUT_RADIUS` so a body near the edge is not
+/// parked and rehydrated on alternate frames.
+pub const SECTOR_STREAM_IN_RADIUS: f32 = 2200.0;
+
+// ── Procedural Field ──────────────────────────────────────────────────────────
+
+/// Chunks within this distance of the ship are generated (FRONTIER scenario).
+///
+/// Chunks are the streaming sectors.  Kept above `SECTOR_STREAM_IN_RADIUS` so
+/// a chunk's asteroids are parked before the ship is close enough to stream
+/// them in.
+pub const PROCGEN_GENERATE_RADIUS: f32 = 2600.0;
+
+/// Noise samples per chunk side; each sample cell holds at most one asteroid.
+pub const PROCGEN_SAMPLES_PER_CHUNK: u32 = 8;
+
+/// Multiplier on the field-noise spawn probability for generated chunks.
+///
+/// At 0.4 a chunk averages about 8 asteroids, close to the starting-field
+/// density.
+pub const PROCGEN_DENSITY: f32 = 0.4;
+
+/// Ship distance (u) from the origin that moves the world origin to the
+/// ship's sector (FRONTIER only; see `simulation::origin`).
+pub const ORIGIN_RECENTER_DISTANCE: f32 = 20_000.0;
+
+// ── Density Field ─────────────────────────────────────────────────────────────
+
+/// Side (u) of one cell of the asteroid density map (see
+/// `simulation::density`).  Keep `SECTOR_SIZE` a multiple of it so origin
+/// shifts re-key the map instead of clearing it.
+pub const DENSITY_CELL_SIZE: f32 = 250.0;
+
+/// Seconds between density map refreshes.
+pub const DENSITY_REFRESH_SECS: f32 = 0.5;
+
+/// Clear ring points the enemy spawner compares before spawning at the
+/// emptiest.  1 takes the first clear point.
+pub const ENEMY_SPAWN_DENSITY_SAMPLES: u32 = 4;
+
+/// Mass in one density cell that makes it a rich ore vein.  A cell of open
+/// field holds one or two mass units.
+pub const ORE_VEIN_MASS: f32 = 20.0;
+
+/// Ore drop multiplier for asteroids destroyed inside a vein.
+pub const ORE_VEIN_DROP_MULTIPLIER: f32 = 1.5;
+
+/// Live mass already in a chunk at which FRONTIER generation adds nothing to
+/// it; less mass scales the spawn chance down linearly.  A fresh chunk
+/// averages about 20.  0 disables the thinning.
+pub const PROCGEN_CROWD_MASS: f32 = 60.0;
+
+// ── Scenario Loading ──────────────────────────────────────────────────────────
+
+/// Bodies spawned per frame while a scenario is being placed (see `loading`).
+/// A 250-body Shower loads in about seven frames at 40.
+pub const SCENARIO_SPAWN_BUDGET: u32 = 40;
+
+/// Minimum time (s) the loading screen stays up, so its tip is readable.
+pub const LOADING_MIN_SECS: f32 = 0.75;
+
+/// Dormant particles created on the loading screen so the first explosions
+/// reuse entities and materials instead of allocating them.  Capped by
+/// `particle_budget`.
+pub const LOADING_PARTICLE_PREWARM: u32 = 256;
+
+// ── Session Autosave ──────────────────────────────────────────────────────────
+
+/// Seconds of play between practice-session autosaves, used to offer a resume
+/// after a crash (see `save::autosave_session_system`).
+pub const SESSION_AUTOSAVE_INTERVAL_SECS: f32 = 60.0;
+
+// ── Save Storage ──────────────────────────────────────────────────────────────
+
+/// Size (MB) of `saves/`, `reports/`, `replays/` and `telemetry/` above which the load screen and the
+/// Settings storage panel warn.  0 disables the warning.
+pub const SAVE_STORAGE_QUOTA_MB: f32 = 50.0;
+
+/// Newest issue reports, replays and telemetry logs kept when they are pruned
+/// from Settings.
+pub const STORAGE_PRUNE_KEEP: u32 = 3;
 
 // ── Physics: Neighbor Counting ────────────────────────────────────────────────
 
@@ -123,6 +331,17 @@ pub const NEIGHBOR_THRESHOLD: f32 = 3.0;
 /// Using 100 u would require 21×21 = 441 cells, worse than O(N²) at low counts.
 pub const GRID_CELL_SIZE: f32 = 500.0;
 
+/// Per-axis distance (world units) an asteroid may drift from where it was
+/// placed in the KD-tree before it is reinserted.  Queries widen their split
+/// tests by the drift, so larger values mean fewer reinserts but looser
+/// pruning.  `0.0` rebuilds the whole tree every tick.
+pub const SPATIAL_UPDATE_TOLERANCE: f32 = 6.0;
+
+/// How far the incrementally updated KD-tree may degrade before a full
+/// rebuild: the deepest leaf may be this many times the balanced depth, and
+/// the node count (live plus tombstoned) this many times the live count.
+pub const SPATIAL_REBUILD_IMBALANCE: f32 = 2.0;
+
 // ── Camera ───────────────────────────────────────────────────────────────────
 
 /// Minimum camera zoom scale (zoom *out*).  Allows the full `CULL_DISTANCE` circle to fit comfortably.
@@ -249,6 +468,19 @@ pub const TRACTOR_THROW_COOLDOWN_BASE: f32 = 5.0;
 /// Cooldown reduction per tractor level (seconds).
 pub const TRACTOR_THROW_COOLDOWN_PER_LEVEL: f32 = 0.4;
 
+/// Tractor level (1-indexed display level) from which a throw press can catch
+/// and redirect an incoming enemy shot.
+pub const TRACTOR_DEFLECT_MIN_LEVEL: u32 = 5;
+
+/// Seconds after a throw press during which an enemy shot can be caught.
+pub const TRACTOR_DEFLECT_WINDOW_SECS: f32 = 0.3;
+
+/// Energy spent per caught shot.
+pub const TRACTOR_DEFLECT_ENERGY_COST: f32 = 15.0;
+
+/// Speed of a redirected shot, as a multiple of `enemy_projectile_speed`.
+pub const TRACTOR_DEFLECT_SPEED_MULTIPLIER: f32 = 1.5;
+
 // ── Enemy Ships: Foundation ──────────────────────────────────────────────────
 
 /// Seconds between enemy spawn attempts at session progression stage 0.
@@ -284,6 +516,13 @@ pub const ENEMY_MIN_PLAYER_SPAWN_DISTANCE: f32 = 300.0;
 /// Minimum spacing between enemies when selecting deterministic spawn points.
 pub const ENEMY_MIN_ENEMY_SPACING: f32 = 180.0;
 
+/// No enemy or boss spawns within this distance of a mining drone.
+pub const SPAWN_EXCLUSION_HAULER_RADIUS: f32 = 220.0;
+
+/// No enemy or boss spawns within this distance of a mining outpost or the
+/// shop beacon.
+pub const SPAWN_EXCLUSION_OBJECTIVE_RADIUS: f32 = 400.0;
+
 /// Steering force magnitude used by enemies while seeking the player.
 pub const ENEMY_SEEK_FORCE: f32 = 4_800.0;
 
@@ -392,6 +631,211 @@ pub const BOSS_PHASE_TWO_BURST_COOLDOWN: f32 = 2.20;
 /// Number of projectiles emitted in each phase-two radial burst.
 pub const BOSS_PHASE_TWO_BURST_SHOTS: u32 = 8;
 
+// ── Enemy Ships: Retreat ──────────────────────────────────────────────────────
+
+/// HP fraction at or below which a standard enemy breaks off and flees toward
+/// the field edge.
+pub const ENEMY_RETREAT_HP_FRACTION: f32 = 0.25;
+
+/// Distance from the world origin at which a fleeing enemy counts as escaped
+/// and is removed.  Kept inside `SOFT_BOUNDARY_RADIUS` so the boundary spring
+/// never holds a fleeing ship just short of escape.
+pub const ENEMY_RETREAT_ESCAPE_RADIUS: f32 = 1750.0;
+
+/// Delay (seconds) between an escape and its reinforcements joining the next
+/// active wave.
+pub const ENEMY_REINFORCEMENT_DELAY_SECS: f32 = 20.0;
+
+/// Extra enemy ships added to a later wave for each escaped enemy.
+pub const ENEMY_REINFORCEMENTS_PER_ESCAPE: u32 = 2;
+
+// ── Enemy Ships: Avoidance ────────────────────────────────────────────────────
+
+/// Whether enemies steer around large bodies on their projected path.
+pub const ENEMY_AVOIDANCE_ENABLED: bool = true;
+
+/// How far ahead (seconds of travel at the current speed) enemies look for
+/// obstacles.  Slow ships still look at least half a `ENEMY_MAX_SPEED` second
+/// ahead so they do not drift into a planet from rest.
+pub const ENEMY_AVOIDANCE_LOOKAHEAD_SECS: f32 = 1.2;
+
+/// Minimum asteroid size (units) treated as an obstacle.  Smaller rocks are
+/// cheap to bounce off; planets always count.
+pub const ENEMY_AVOIDANCE_MIN_SIZE: u32 = 8;
+
+/// Extra clearance (world units) kept between the enemy hull and an
+/// obstacle's bounding circle.
+pub const ENEMY_AVOIDANCE_CLEARANCE: f32 = 20.0;
+
+/// Strength of the sideways push relative to the seek direction at full
+/// threat (an obstacle right at the enemy's nose).
+pub const ENEMY_AVOIDANCE_WEIGHT: f32 = 1.6;
+
+// ── Enemy Ships: Archetypes ───────────────────────────────────────────────────
+//
+// Each spawn picks an archetype by weight among those unlocked at the current
+// progression stage.  A weight of 0 removes the archetype.
+
+/// Spawn weight of the chaser, the basic ship that closes in and fires.
+pub const ENEMY_WEIGHT_CHASER: u32 = 4;
+
+/// Spawn weight of the skirmisher, which circles and fires spreads.
+pub const ENEMY_WEIGHT_SKIRMISHER: u32 = 4;
+
+/// Spawn weight of the kamikaze drone.
+pub const ENEMY_WEIGHT_KAMIKAZE: u32 = 2;
+
+/// Spawn weight of the rival miner.
+pub const ENEMY_WEIGHT_MINER: u32 = 2;
+
+/// First progression stage at which skirmishers spawn.
+pub const ENEMY_SKIRMISHER_MIN_STAGE: u32 = 2;
+
+/// First progression stage at which kamikaze drones spawn.
+pub const ENEMY_KAMIKAZE_MIN_STAGE: u32 = 3;
+
+/// First progression stage at which rival miners spawn.
+pub const ENEMY_MINER_MIN_STAGE: u32 = 2;
+
+/// Kamikaze hull strength as a fraction of the stage's enemy HP.
+pub const ENEMY_KAMIKAZE_HP_MULT: f32 = 0.5;
+
+/// Distance (world units) from the player at which a kamikaze starts its dash.
+pub const ENEMY_KAMIKAZE_DASH_RADIUS: f32 = 260.0;
+
+/// Seek-force multiplier during the dash.
+pub const ENEMY_KAMIKAZE_DASH_FORCE_MULT: f32 = 2.2;
+
+/// Top-speed multiplier during the dash.
+pub const ENEMY_KAMIKAZE_DASH_SPEED_MULT: f32 = 1.7;
+
+/// Centre distance to the player at which a kamikaze detonates.
+pub const ENEMY_KAMIKAZE_TRIGGER_RADIUS: f32 = 28.0;
+
+/// Radius of the detonation; damage falls off linearly to 0 at the edge.
+pub const ENEMY_KAMIKAZE_BLAST_RADIUS: f32 = 80.0;
+
+/// Player damage at the centre of a detonation.
+pub const ENEMY_KAMIKAZE_BLAST_DAMAGE: f32 = 32.0;
+
+/// Score for shooting down a kamikaze before it detonates.
+pub const ENEMY_KAMIKAZE_KILL_SCORE: u32 = 45;
+
+/// Rival miner hull strength as a fraction of the stage's enemy HP.
+pub const ENEMY_MINER_HP_MULT: f32 = 0.8;
+
+/// How far (world units) a rival miner looks for ore pickups.
+pub const ENEMY_MINER_SEEK_RADIUS: f32 = 600.0;
+
+/// Distance at which a rival miner scoops up a pickup.
+pub const ENEMY_MINER_PICKUP_RADIUS: f32 = 16.0;
+
+/// Pickups a rival miner holds before it flees the field with them.
+pub const ENEMY_MINER_CAPACITY: u32 = 6;
+
+/// Distance a rival miner keeps from the player while no ore is in reach.
+pub const ENEMY_MINER_STANDOFF_RADIUS: f32 = 320.0;
+
+/// Score for shooting down a rival miner; its stolen ore drops as well.
+pub const ENEMY_MINER_KILL_SCORE: u32 = 50;
+
+// ── Campaign: Wave Intermission ───────────────────────────────────────────────
+
+/// Length (seconds) of the break between campaign waves.
+///
+/// The shop beacon is available for the whole break; the next wave's warmup
+/// starts when it runs out, whether or not the shop is open.
+pub const WAVE_INTERMISSION_SECS: f32 = 12.0;
+
+/// Fractional discount on ore-shop upgrade prices when shopping at the beacon.
+///
+/// `0.25` → upgrades cost 75 % of their normal price (rounded up, minimum 1).
+pub const WAVE_INTERMISSION_SHOP_DISCOUNT: f32 = 0.25;
+
+/// Distance (u) from the ship at which the shop beacon appears.
+pub const WAVE_INTERMISSION_BEACON_OFFSET: f32 = 140.0;
+
+/// Docking radius (u): the shop can be opened while the ship is this close to
+/// the beacon.
+pub const WAVE_INTERMISSION_BEACON_RADIUS: f32 = 60.0;
+
+// ── Survival Mode ─────────────────────────────────────────────────────────────
+
+/// Asteroids seeded into the Survival arena before the first wave.
+pub const SURVIVAL_INITIAL_ASTEROIDS: usize = 40;
+
+/// Countdown (seconds) before survival wave 1 starts.
+pub const SURVIVAL_FIRST_WAVE_DELAY_SECS: f32 = 5.0;
+
+/// Breathing room (seconds) between a cleared survival wave and the next one.
+pub const SURVIVAL_BREATHER_SECS: f32 = 8.0;
+
+/// Comets launched at the arena in survival wave 1.
+pub const SURVIVAL_COMETS_BASE: u32 = 6;
+
+/// Additional comets per survival wave after the first.
+pub const SURVIVAL_COMETS_PER_WAVE: u32 = 3;
+
+/// Seconds between comet launches within a survival wave.
+pub const SURVIVAL_COMET_INTERVAL_SECS: f32 = 1.2;
+
+/// Inbound comet speed (u/s) in survival wave 1.
+pub const SURVIVAL_COMET_SPEED_BASE: f32 = 40.0;
+
+/// Additional inbound comet speed (u/s) per survival wave.
+pub const SURVIVAL_COMET_SPEED_PER_WAVE: f32 = 6.0;
+
+/// Enemy ships spawned in survival wave 1.
+pub const SURVIVAL_ENEMIES_BASE: u32 = 1;
+
+/// Additional enemy ships per survival wave after the first.
+pub const SURVIVAL_ENEMIES_PER_WAVE: u32 = 1;
+
+/// Score multiplier added per survival wave after the first.
+///
+/// `0.25` → wave 1 ×1.00, wave 2 ×1.25, wave 5 ×2.00.  Applied on top of the
+/// streak multiplier.
+pub const SURVIVAL_SCORE_MULT_PER_WAVE: f32 = 0.25;
+
+// ── Catalog Scenario ──────────────────────────────────────────────────────────
+
+/// World units per astronomical unit when projecting `assets/small_bodies.csv`.
+///
+/// `180` puts the main belt (2.1–3.3 AU) at roughly 380–600 u from the star
+/// and Jupiter trojans (~5.2 AU) just inside the outer radius band.
+pub const CATALOG_AU_TO_WORLD: f32 = 180.0;
+
+/// World position of the Catalog scenario's central star (x, y).
+///
+/// Offset from the origin like the Orbit planet so the player spawns inside
+/// the belt rather than inside the star.
+pub const CATALOG_CENTER_X: f32 = 800.0;
+pub const CATALOG_CENTER_Y: f32 = 0.0;
+
+/// Gravitational mass (`AsteroidSize` units) of the central star.
+pub const CATALOG_CENTRAL_MASS: u32 = 2800;
+
+/// Maximum catalog rows spawned, taken in file order.
+pub const CATALOG_MAX_BODIES: usize = 160;
+
+/// Bodies whose projected distance from the star falls outside this band (u)
+/// are skipped: too close would overlap the star, too far would leave the
+/// soft boundary.
+pub const CATALOG_MIN_ORBIT_RADIUS: f32 = 150.0;
+pub const CATALOG_MAX_ORBIT_RADIUS: f32 = 960.0;
+
+/// Diameter (km) that maps to one `AsteroidSize` unit; size grows with area,
+/// `(D / reference)²`.
+pub const CATALOG_REFERENCE_DIAMETER_KM: f32 = 60.0;
+
+/// Clamp band for catalog body sizes (`AsteroidSize` units).
+pub const CATALOG_SIZE_MIN: u32 = 1;
+pub const CATALOG_SIZE_MAX: u32 = 14;
+
+/// Multiplier on the vis-viva orbital speed.  `1.0` = balanced orbits,
+/// `0.0` = bodies start at rest and fall inward.
+pub const CATALOG_VELOCITY_SCALE: f32 = 1.0;
+
 // ── Player: Combat ────────────────────────────────────────────────────────────
 
 /// Speed (u/s) of fired projectiles.
@@ -410,6 +854,33 @@ pub const PROJECTILE_MAX_DIST: f32 = 2000.0;
 /// Radius (u) of projectile ball collider.
 pub const PROJECTILE_COLLIDER_RADIUS: f32 = 2.0;
 
+// ── Scoring ───────────────────────────────────────────────────────────────────
+//
+// Defaults for `ScoringConfig` (`src/scoring.rs`), overridable in
+// `assets/scoring.toml`.
+
+/// Points for a primary projectile hit, before the streak multiplier.
+pub const SCORE_HIT_POINTS: u32 = 1;
+
+/// Extra points (× multiplier) when a projectile fully destroys its target.
+pub const SCORE_DESTROY_BONUS_POINTS: u32 = 5;
+
+/// Points for a missile hit, before the streak multiplier.
+pub const SCORE_MISSILE_HIT_POINTS: u32 = 1;
+
+/// Extra points (× multiplier) when a missile instantly destroys a small target.
+///
+/// Double the projectile bonus, since missiles are scarce.
+pub const SCORE_MISSILE_DESTROY_BONUS_POINTS: u32 = 10;
+
+/// Streak lengths at which the score multiplier steps up by one.
+///
+/// `[5, 10, 20, 40]` → ×1 below 5 hits, ×2 from 5, … ×5 from 40.
+pub const SCORE_MULTIPLIER_STREAK_THRESHOLDS: [u32; 4] = [5, 10, 20, 40];
+
+/// Bonus (× new multiplier) when a streak reaches a new tier.  `0` = off.
+pub const SCORE_CHAIN_BONUS_POINTS: u32 = 0;
+
 // ── Player: Missiles ─────────────────────────────────────────────────────────
 
 /// Maximum number of missiles the player can carry.
@@ -441,6 +912,22 @@ pub const MISSILE_COLLIDER_RADIUS: f32 = 5.0;
 /// Seconds for one missile to recharge automatically.
 pub const MISSILE_RECHARGE_SECS: f32 = 12.0;
 
+/// Missile upgrade level (1-indexed display level) from which missiles home.
+pub const MISSILE_HOMING_MIN_LEVEL: u32 = 3;
+
+/// Distance (u) within which a homing missile can acquire a target.
+pub const MISSILE_HOMING_RANGE: f32 = 320.0;
+
+/// Minimum dot product between a missile's heading and the direction to a
+/// candidate for it to be acquired (≈ 0.7 → ±45° seek cone).
+pub const MISSILE_HOMING_CONE_DOT: f32 = 0.7;
+
+/// Maximum rate (rad/s) at which a homing missile turns toward its target.
+pub const MISSILE_HOMING_TURN_RATE: f32 = 3.0;
+
+/// Smallest `AsteroidSize` a homing missile will chase; enemies always qualify.
+pub const MISSILE_HOMING_MIN_ASTEROID_SIZE: u32 = 2;
+
 // ── Player: Health ────────────────────────────────────────────────────────────
 
 /// Player ship starting and maximum HP.
@@ -450,6 +937,11 @@ pub const PLAYER_MAX_HP: f32 = 100.0;
 /// Slow grazes are harmless; only high-velocity impacts hurt.
 pub const DAMAGE_SPEED_THRESHOLD: f32 = 30.0;
 
+/// Scale on every hit the player's ship takes (collisions, enemy shots,
+/// blasts), before the shield soaks its share.  The damage-taken assist
+/// lowers it.
+pub const PLAYER_DAMAGE_TAKEN_MULTIPLIER: f32 = 1.0;
+
 /// Seconds of invincibility granted immediately after taking damage.
 /// Prevents rapid-fire damage from a sustained graze contact.
 pub const INVINCIBILITY_DURATION: f32 = 0.5;
@@ -481,6 +973,188 @@ pub const PASSIVE_HEAL_RATE: f32 = 6.0;
 /// combat: at 30 HP per ore a player with 5 ore can fully restore from near-zero.
 pub const ORE_HEAL_AMOUNT: f32 = 30.0;
 
+/// Real-time seconds during which the most recent ore shop purchase can be
+/// undone (ore refunded, stat reverted) while the shop stays open.
+pub const SHOP_UNDO_WINDOW_SECS: f32 = 10.0;
+
+// ── Player: Energy ────────────────────────────────────────────────────────────
+
+/// Capacity of the ship's shared energy pool.
+///
+/// Thrusters, the ion cannon, the tractor beam, and shields all draw from
+/// this single pool, so heavy use of one system starves the others.
+pub const PLAYER_ENERGY_MAX: f32 = 100.0;
+
+/// Energy regenerated per second once the regen delay has elapsed.
+pub const PLAYER_ENERGY_REGEN_RATE: f32 = 22.0;
+
+/// Seconds after the most recent energy draw before regeneration resumes.
+///
+/// Short enough that tapping thrust between manoeuvres still recovers energy,
+/// long enough that holding thrust continuously drains the pool.
+pub const PLAYER_ENERGY_REGEN_DELAY_SECS: f32 = 0.6;
+
+/// Energy drained per second at full thrust (forward, reverse, or strafe).
+///
+/// Partial gamepad stick deflection drains proportionally.
+pub const THRUST_ENERGY_PER_SEC: f32 = 9.0;
+
+/// Energy consumed by a single ion cannon shot.
+pub const ION_CANNON_ENERGY_COST: f32 = 30.0;
+
+/// Energy drained per second while tractor hold mode is engaged.
+pub const TRACTOR_BEAM_ENERGY_PER_SEC: f32 = 14.0;
+
+// ── Player: Lock-On ───────────────────────────────────────────────────────────
+
+/// Maximum distance (world units) at which a lock-on target can be acquired.
+pub const LOCK_ON_RANGE: f32 = 650.0;
+
+/// Minimum dot product between the aim direction and the direction to a
+/// candidate for it to be eligible (≈ 0.88 → ±28° cone).
+pub const LOCK_ON_CONE_DOT: f32 = 0.88;
+
+/// Smallest `AsteroidSize` that can be locked.  Small debris is ignored so the
+/// lock lands on threats worth tracking; enemies are always eligible.
+pub const LOCK_ON_MIN_ASTEROID_SIZE: u32 = 6;
+
+/// Multiplier on [`LOCK_ON_RANGE`] beyond which an existing lock is dropped.
+/// Hysteresis keeps the lock stable when a target drifts near the range edge.
+pub const LOCK_ON_RELEASE_RANGE_MULT: f32 = 1.5;
+
+/// Maximum rate (rad/s) at which in-flight missiles turn toward the locked target.
+///
+/// Low enough that missiles still need a roughly correct launch direction —
+/// lock-on biases seeking rather than guaranteeing hits.
+pub const MISSILE_LOCK_ON_TURN_RATE: f32 = 2.4;
+
+/// When true, the camera pans and zooms out to keep both ship and locked
+/// target on screen (never zooming in past the player's wheel zoom).
+pub const LOCK_ON_CAMERA_FRAMING: bool = true;
+
+/// Screen-space padding (world units at zoom 1.0) kept around the ship and
+/// target while lock-on framing is active.
+pub const LOCK_ON_FRAME_MARGIN: f32 = 140.0;
+
+// ── Player: Kill-Cam ──────────────────────────────────────────────────────────
+
+/// When true, the seconds leading up to the player's death are replayed, with
+/// the camera following the ship into the killing collision, before respawn /
+/// Game Over.
+pub const KILL_CAM_ENABLED: bool = true;
+
+/// Real-time length (seconds) of the kill-cam replay.
+///
+/// Virtual time is paused for the whole window, so the respawn delay is
+/// extended by this much.  Longer than [`KILL_CAM_REPLAY_SECS`], so the replay
+/// plays back slower than it happened.
+pub const KILL_CAM_DURATION_SECS: f32 = 3.0;
+
+/// Seconds of physics history kept in the kill-cam replay buffer.
+pub const KILL_CAM_REPLAY_SECS: f32 = 2.0;
+
+/// Radius (world units) around the ship within which asteroids, enemies and
+/// enemy shots are recorded for the replay.
+pub const KILL_CAM_REPLAY_RADIUS: f32 = 700.0;
+
+// ── Player: Time Control ──────────────────────────────────────────────────────
+
+/// When true, holding Shift slows the simulation and `V` toggles fast-forward.
+pub const TIME_CONTROL_ENABLED: bool = true;
+
+/// Simulation speed while Shift is held.
+pub const TIME_CONTROL_SLOW_SCALE: f32 = 0.25;
+
+/// Simulation speed while fast-forward is toggled on.
+pub const TIME_CONTROL_FAST_SCALE: f32 = 4.0;
+
+/// Time control is locked to normal speed while any enemy ship is within this
+/// distance (world units) of the player, so it cannot trivialise combat.
+pub const TIME_CONTROL_DANGER_RADIUS: f32 = 700.0;
+
+// ── Player: Shield ────────────────────────────────────────────────────────────
+
+/// Shield HP granted per purchased shield level (level 0 = no shield).
+pub const SHIELD_HP_PER_LEVEL: f32 = 25.0;
+
+/// Shield HP regenerated per second once the recharge delay has elapsed.
+pub const SHIELD_REGEN_RATE: f32 = 10.0;
+
+/// Seconds after the last shield hit before regeneration resumes.
+pub const SHIELD_RECHARGE_DELAY_SECS: f32 = 2.5;
+
+/// Fraction of impact damage the shield absorbs from hits behind the ship.
+///
+/// Hits in the forward hemisphere are fully absorbed (while shield HP lasts);
+/// rear hits only soak this fraction and the rest bleeds through to the hull.
+pub const SHIELD_REAR_ABSORB_FRACTION: f32 = 0.5;
+
+/// Ship energy spent per shield HP regenerated.
+pub const SHIELD_REGEN_ENERGY_PER_HP: f32 = 0.6;
+
+/// Seconds the directional hit arc stays visible after a shield impact.
+pub const SHIELD_HIT_FLASH_SECS: f32 = 0.45;
+
+/// Radius (world units) of the shield bubble drawn around the ship.
+pub const SHIELD_VISUAL_RADIUS: f32 = 16.0;
+
+// ── Player: Turrets ───────────────────────────────────────────────────────────
+
+/// Distance (world units) within which a turret acquires targets.
+pub const TURRET_RANGE: f32 = 320.0;
+
+/// Largest asteroid (`AsteroidSize`) a turret will shoot at; bigger bodies are
+/// left to the player.
+pub const TURRET_TARGET_MAX_SIZE: u32 = 3;
+
+/// Seconds between shots from one turret.
+pub const TURRET_FIRE_INTERVAL_SECS: f32 = 0.45;
+
+/// Ship energy spent per turret shot.  A turret holds fire while the pool
+/// cannot cover a whole shot.
+pub const TURRET_ENERGY_PER_SHOT: f32 = 3.0;
+
+/// Maximum rate (rad/s) at which a turret barrel swivels.
+pub const TURRET_TURN_RATE: f32 = 6.0;
+
+/// Half-width (rad) of the arc a turret can cover around its mount facing.
+/// Each mount faces outward, so the ship's own hull blocks the far side.
+pub const TURRET_ARC_HALF_WIDTH: f32 = 1.9;
+
+/// A turret fires once its barrel is within this angle (rad) of the lead point.
+pub const TURRET_FIRE_TOLERANCE: f32 = 0.12;
+
+/// Speed (u/s) of a turret round.
+pub const TURRET_PROJECTILE_SPEED: f32 = 520.0;
+
+/// Sideways offset (u) of each mount from the ship's centre; slot 0 is on the
+/// left (`-x`), slot 1 on the right.
+pub const TURRET_MOUNT_OFFSET_X: f32 = 7.0;
+
+/// Fore/aft offset (u) of both mounts from the ship's centre.
+pub const TURRET_MOUNT_OFFSET_Y: f32 = -3.0;
+
+/// Rest facing (rad) of the left mount relative to the ship's nose; the right
+/// mount mirrors it.
+pub const TURRET_MOUNT_ANGLE: f32 = 0.9;
+
+// ── Ore Pickups ───────────────────────────────────────────────────────────────
+
+/// Fraction of the destroyed body's velocity an ore drop inherits.
+///
+/// Below 1 so pickups from fast destroys trail behind the wreck instead of
+/// flying off with it.
+pub const ORE_DROP_VELOCITY_INHERIT: f32 = 0.5;
+
+/// Maximum random scatter (u/s per axis) added to a fresh ore drop.
+pub const ORE_DROP_SPREAD: f32 = 18.0;
+
+/// Linear damping (1/s) on ore drifting outside the magnet radius.
+///
+/// A drop coasts roughly `speed / damping` units before settling, so even a
+/// 400 u/s destroy leaves its pickups within base magnet range.
+pub const ORE_DROP_DAMPING: f32 = 1.2;
+
 // ── Ore Magnet ────────────────────────────────────────────────────────────────
 
 /// Base magnet pull radius at level 0 (world units).
@@ -508,6 +1182,149 @@ pub const ORE_AFFINITY_MAX_LEVEL: u32 = 10;
 /// Total to max-level: 5 + 10 + … + 50 = 275 ore.
 pub const ORE_AFFINITY_UPGRADE_BASE_COST: u32 = 5;
 
+// ── Ore Types & Refining ──────────────────────────────────────────────────────
+
+/// Fraction of freshly spawned asteroids made of ice (drops ice ore).
+pub const ASTEROID_ICE_FRACTION: f32 = 0.25;
+
+/// Fraction of freshly spawned asteroids made of metal (drops rare ore).
+/// Whatever the ice and metal fractions leave over is rock (drops iron).
+pub const ASTEROID_METAL_FRACTION: f32 = 0.1;
+
+/// Seconds the ore shop refinery takes per raw ore unit.
+pub const ORE_REFINE_SECS_PER_UNIT: f32 = 2.0;
+
+/// Credits produced by refining one unit of iron.
+pub const ORE_REFINE_CREDITS_IRON: u32 = 1;
+
+/// Credits produced by refining one unit of ice.
+pub const ORE_REFINE_CREDITS_ICE: u32 = 2;
+
+/// Credits produced by refining one unit of rare ore.
+///
+/// Metal asteroids are roughly a tenth of the field, so rare ore is worth
+/// several iron units.
+pub const ORE_REFINE_CREDITS_RARE: u32 = 6;
+
+// ── Mining Drone ──────────────────────────────────────────────────────────────
+
+/// Distance (u) at which an idle mining drone circles the ship.
+pub const MINING_DRONE_ORBIT_RADIUS: f32 = 45.0;
+
+/// Angular speed (rad/s) of the drone's idle orbit.
+pub const MINING_DRONE_ORBIT_SPEED: f32 = 1.6;
+
+/// Radius (u) around the ship the drone searches for ore pickups.
+pub const MINING_DRONE_SEEK_RADIUS: f32 = 600.0;
+
+/// Distance (u) at which the drone grabs an ore pickup or unloads at the ship.
+pub const MINING_DRONE_CONTACT_RADIUS: f32 = 12.0;
+
+/// Drone flight speed (u/s) at level 1.
+pub const MINING_DRONE_BASE_SPEED: f32 = 140.0;
+
+/// Extra drone speed (u/s) per level above 1.
+pub const MINING_DRONE_SPEED_PER_LEVEL: f32 = 35.0;
+
+/// Ore units the drone carries per trip at level 1.
+pub const MINING_DRONE_BASE_CAPACITY: u32 = 1;
+
+/// Extra carry capacity per level above 1.
+pub const MINING_DRONE_CAPACITY_PER_LEVEL: u32 = 1;
+
+// ── Mining Outposts ───────────────────────────────────────────────────────────
+
+/// Iron ore spent to build one mining outpost.
+pub const OUTPOST_BUILD_COST: u32 = 15;
+
+/// Furthest the ship may be from a planetoid's surface (u) to build on it.
+pub const OUTPOST_BUILD_RANGE: f32 = 120.0;
+
+/// Most outposts standing at once (one per planetoid).
+pub const OUTPOST_MAX_COUNT: u32 = 3;
+
+/// Seconds between ore deliveries from each outpost.
+pub const OUTPOST_ORE_INTERVAL_SECS: f32 = 10.0;
+
+/// Iron ore each outpost delivers per interval.
+pub const OUTPOST_ORE_PER_INTERVAL: u32 = 1;
+
+/// Outpost hit points; each enemy shot removes `ENEMY_PROJECTILE_DAMAGE`.
+pub const OUTPOST_MAX_HP: f32 = 60.0;
+
+/// Distance (u) at which an enemy shot hits an outpost.
+pub const OUTPOST_HIT_RADIUS: f32 = 14.0;
+
+/// Enemies within this distance (u) of an outpost shoot at it instead of the
+/// ship when it is the closer target.
+pub const OUTPOST_AGGRO_RADIUS: f32 = 450.0;
+
+// ── Mining Laser ──────────────────────────────────────────────────────────────
+
+/// Beam length (u) from the ship along the aim direction.
+pub const MINING_LASER_RANGE: f32 = 220.0;
+
+/// Mass units carved off the target per second of continuous cutting.  Each
+/// carved unit becomes one ore of the asteroid's material.
+pub const MINING_LASER_CARVE_RATE: f32 = 1.5;
+
+/// Heat gained per second of firing (1.0 = overheated).
+pub const MINING_LASER_HEAT_PER_SEC: f32 = 0.2;
+
+/// Heat shed per second while the beam is off.
+pub const MINING_LASER_COOL_PER_SEC: f32 = 0.3;
+
+/// After overheating the beam stays locked until heat falls to this level.
+pub const MINING_LASER_RECOVER_HEAT: f32 = 0.3;
+
+// ── Audio ─────────────────────────────────────────────────────────────────────
+
+/// Default master volume (0–1); adjustable on the Settings screen.
+pub const AUDIO_MASTER_VOLUME: f32 = 0.8;
+
+/// Default sound-effects volume (0–1), multiplied by the master volume.
+pub const AUDIO_EFFECTS_VOLUME: f32 = 0.8;
+
+/// Default ambient-soundtrack volume (0–1), multiplied by the master volume.
+pub const AUDIO_MUSIC_VOLUME: f32 = 0.5;
+
+/// Most one-shot sound effects started in a single frame.
+pub const SFX_MAX_PER_FRAME: u32 = 6;
+
+/// Collision energy (½·μ·v², size units × (u/s)²) that plays an impact at
+/// full volume.  Gain scales with the square root of the energy ratio.
+pub const IMPACT_SFX_REFERENCE_ENERGY: f32 = 40_000.0;
+
+/// Impacts quieter than this gain are not played.
+pub const IMPACT_SFX_MIN_GAIN: f32 = 0.08;
+
+/// Hull fraction at or below which the low-health warning repeats.
+pub const LOW_HEALTH_WARNING_FRACTION: f32 = 0.25;
+
+/// Seconds between low-health warning beeps.
+pub const LOW_HEALTH_WARNING_INTERVAL_SECS: f32 = 1.2;
+
+/// Radius (u) around the ship counted for ambient intensity.
+pub const AMBIENT_DENSITY_RADIUS: f32 = 600.0;
+
+/// Asteroids within `AMBIENT_DENSITY_RADIUS` that drive the rumble layer to
+/// full level.
+pub const AMBIENT_DENSITY_FULL_COUNT: u32 = 40;
+
+/// Enemies within `AMBIENT_DENSITY_RADIUS` that drive the tension layer to
+/// full level.
+pub const AMBIENT_ENEMY_FULL_COUNT: u32 = 4;
+
+/// Seconds for an ambient layer to fade across its full range.
+pub const AMBIENT_FADE_SECS: f32 = 2.0;
+
+/// Rumble layer floor added per size class the field has reached (see
+/// `simulation::size_class`), so the soundtrack swells as bodies grow.
+pub const SIZE_CLASS_RUMBLE_STEP: f32 = 0.15;
+
+/// Seconds a "… is forming" size-class announcement stays on the HUD.
+pub const SIZE_CLASS_ANNOUNCE_SECS: f32 = 4.0;
+
 // ── Gamepad ───────────────────────────────────────────────────────────────────
 
 /// Left-stick dead zone: inputs smaller than this fraction are ignored.
@@ -523,6 +1340,15 @@ pub const GAMEPAD_BRAKE_DAMPING: f32 = 0.82;
 /// the aim direction is automatically snapped back to the ship's forward direction.
 pub const AIM_IDLE_SNAP_SECS: f32 = 1.0;
 
+/// When the aim goes idle, snap towards the last enemy or boss the player
+/// damaged instead of the ship's forward direction, while it is alive and in
+/// range.
+pub const AIM_SNAP_TARGET_MEMORY: bool = true;
+
+/// Maximum distance (world units) from the ship at which the remembered target
+/// still attracts the idle aim snap.
+pub const AIM_SNAP_TARGET_RANGE: f32 = 900.0;
+
 /// Right-stick dead zone for aim updates.
 pub const GAMEPAD_RIGHT_DEADZONE: f32 = 0.2;
 
@@ -549,6 +1375,312 @@ pub const FORCE_VECTOR_MIN_LENGTH: f32 = 0.1;
 /// Font size for the on-screen statistics overlay.
 pub const STATS_FONT_SIZE: f32 = 20.0;
 
+/// Inset (logical px) between the HUD safe area and the window edge.
+pub const HUD_MARGIN_PX: f32 = 10.0;
+
+/// Vertical gap (logical px) between HUD widgets stacked in one corner.
+pub const HUD_STACK_GAP_PX: f32 = 8.0;
+
+/// Widest aspect ratio the HUD spreads across; wider windows keep the HUD in
+/// a centred 21:9 region.
+pub const HUD_MAX_ASPECT_RATIO: f32 = 21.0 / 9.0;
+
+/// Font scale of the score and wave line in streamer mode.
+pub const STREAMER_HUD_SCALE: f32 = 1.6;
+
+/// Width (logical px) of the webcam corner streamer mode keeps empty.
+pub const STREAMER_WEBCAM_WIDTH_PX: f32 = 360.0;
+
+/// Height (logical px) of the webcam corner streamer mode keeps empty.
+pub const STREAMER_WEBCAM_HEIGHT_PX: f32 = 270.0;
+
+/// Whether asteroids share palette materials and bucketed LOD meshes instead
+/// of allocating their own.  `false` restores per-asteroid materials and
+/// meshes (the fallback path).
+pub const ASTEROID_SHARED_ASSETS_ENABLED: bool = true;
+
+/// Whether full-detail asteroid hulls draw through the GPU-instanced hull
+/// material when the device supports it.  `false` keeps every hull on its own
+/// retained mesh (the fallback path).
+pub const ASTEROID_HULL_INSTANCING_ENABLED: bool = true;
+
+/// Whether distant asteroids switch to simplified or hidden render variants.
+pub const ASTEROID_LOD_ENABLED: bool = true;
+
+/// Camera distance (world units) beyond which asteroids render as low-poly
+/// discs instead of their full hull polygon.
+pub const ASTEROID_LOD_SIMPLIFIED_DISTANCE: f32 = 1400.0;
+
+/// Camera distance (world units) beyond which asteroids are not drawn at all.
+/// Defaults past `SECTOR_STREAM_OUT_RADIUS` so only far-edge bodies are skipped.
+pub const ASTEROID_LOD_HIDDEN_DISTANCE: f32 = 3200.0;
+
+/// Half-width (world units) of the dead band around each LOD threshold.
+/// A body must cross `threshold ± hysteresis` to change level, preventing
+/// popping when it hovers on a boundary.
+pub const ASTEROID_LOD_HYSTERESIS: f32 = 100.0;
+
+/// Seconds ahead the trajectory-prediction overlay integrates each path.
+pub const TRAJECTORY_HORIZON_SECS: f32 = 8.0;
+
+/// Integration step (seconds) for predicted paths.  Smaller steps follow
+/// tight orbits more closely at proportionally higher cost.
+pub const TRAJECTORY_STEP_SECS: f32 = 0.05;
+
+/// Asteroids predicted besides the ship: the lock-on target first, then the
+/// nearest to the ship.
+pub const TRAJECTORY_MAX_ASTEROIDS: u32 = 6;
+
+/// Heaviest bodies used as gravity sources for predicted paths.
+pub const TRAJECTORY_MAX_SOURCES: u32 = 48;
+
+/// Real seconds the cursor must rest on an asteroid before its hover tooltip
+/// appears.
+pub const HOVER_TOOLTIP_DELAY_SECS: f32 = 0.4;
+
+/// Radius (world units) of the spatial-index query around the cursor for the
+/// hover tooltip.  Bodies whose centre lies further away cannot be picked, so
+/// this should cover the largest hull radius in play.
+pub const HOVER_TOOLTIP_PICK_RADIUS: f32 = 400.0;
+
+// ── Particles ─────────────────────────────────────────────────────────────────
+
+/// Maximum live particles.  At the budget, new particles evict lower-priority
+/// ones (trails first, impacts last) or are dropped.  Also caps the number of
+/// dormant entities kept in the particle pool.
+pub const PARTICLE_BUDGET: u32 = 900;
+
+// ── Motion Trails ─────────────────────────────────────────────────────────────
+
+/// Ghost copies of the ship kept in the trail pool.
+pub const MOTION_TRAIL_GHOSTS: u32 = 8;
+
+/// Seconds between recorded ship poses; the trail spans
+/// `MOTION_TRAIL_GHOSTS × MOTION_TRAIL_INTERVAL_SECS`.
+pub const MOTION_TRAIL_INTERVAL_SECS: f32 = 0.04;
+
+/// Ship speed (u/s) below which no new poses are recorded, so the trail fades
+/// out when the ship slows down.
+pub const MOTION_TRAIL_MIN_SPEED: f32 = 60.0;
+
+/// Alpha of the newest ghost; older ghosts fade linearly to zero.
+pub const MOTION_TRAIL_ALPHA: f32 = 0.35;
+
+/// Streak quads kept in the pool; the fastest asteroids get them first.
+pub const MOTION_STREAK_MAX: u32 = 48;
+
+/// Asteroid speed (u/s) from which a motion streak is drawn.
+pub const MOTION_STREAK_MIN_SPEED: f32 = 120.0;
+
+/// Streak length in seconds of travel (length = speed × this).
+pub const MOTION_STREAK_SECS: f32 = 0.08;
+
+/// Alpha of a streak at twice `MOTION_STREAK_MIN_SPEED` and above.
+pub const MOTION_STREAK_ALPHA: f32 = 0.25;
+
+// ── Feedback Intensity ────────────────────────────────────────────────────────
+//
+// One intensity curve drives every feedback channel of merges, splits and
+// destroys (see `feedback::feedback_levels`): intensity is
+// `weight × √(mass / FEEDBACK_REFERENCE_MASS)`, clamped to `[0, 1]`.
+
+/// Mass (units) at which an event with weight 1 reaches full intensity.
+pub const FEEDBACK_REFERENCE_MASS: f32 = 40.0;
+
+/// Intensity weight of an asteroid merge (mass = the merged body).
+pub const FEEDBACK_MERGE_WEIGHT: f32 = 0.6;
+
+/// Intensity weight of an asteroid split or decomposition (mass = the parent).
+pub const FEEDBACK_SPLIT_WEIGHT: f32 = 0.8;
+
+/// Intensity weight of an asteroid destroyed outright.
+pub const FEEDBACK_DESTROY_WEIGHT: f32 = 1.0;
+
+/// Particles emitted at zero intensity.
+pub const FEEDBACK_PARTICLES_MIN: u32 = 6;
+
+/// Particles emitted at full intensity.
+pub const FEEDBACK_PARTICLES_MAX: u32 = 28;
+
+/// Events below this intensity make no sound.
+pub const FEEDBACK_SFX_MIN_INTENSITY: f32 = 0.2;
+
+/// Effect gain at full intensity; gain scales linearly with intensity.
+pub const FEEDBACK_SFX_MAX_GAIN: f32 = 1.0;
+
+/// Playback speed at zero intensity (small bodies sound higher).
+pub const FEEDBACK_PITCH_SMALL: f32 = 1.3;
+
+/// Playback speed at full intensity.
+pub const FEEDBACK_PITCH_LARGE: f32 = 0.7;
+
+/// Screen-shake trauma added at full intensity (grows with intensity²).
+pub const FEEDBACK_SHAKE_MAX_TRAUMA: f32 = 0.8;
+
+/// Events further than this from the camera add no shake; closer events
+/// fall off linearly.
+pub const FEEDBACK_SHAKE_RADIUS: f32 = 900.0;
+
+/// Camera offset (world units) at trauma 1; the offset grows with trauma².
+pub const FEEDBACK_SHAKE_MAX_OFFSET: f32 = 14.0;
+
+/// Trauma removed per real-time second.
+pub const FEEDBACK_SHAKE_DECAY_PER_SEC: f32 = 1.6;
+
+/// Events at or above this intensity trigger a hit-stop.
+pub const FEEDBACK_HIT_STOP_MIN_INTENSITY: f32 = 0.6;
+
+/// Hit-stop length (real-time seconds) at full intensity.
+pub const FEEDBACK_HIT_STOP_MAX_SECS: f32 = 0.08;
+
+/// Simulation speed during a hit-stop.
+pub const FEEDBACK_HIT_STOP_TIME_SCALE: f32 = 0.05;
+
+// ── Hit Feedback ──────────────────────────────────────────────────────────────
+//
+// Damage feedback (see `graphics::feedback`).  Player damage is scaled by
+// `DAMAGE_FEEDBACK_REFERENCE` into an intensity in `[0, 1]` that drives the
+// screen shake and the chromatic-aberration pulse.  REDUCE MOTION in Settings
+// turns both off.
+
+/// Player damage (HP) that gives full shake and aberration; fatal hits always do.
+pub const DAMAGE_FEEDBACK_REFERENCE: f32 = 40.0;
+
+/// Screen-shake trauma added by a full-intensity player hit.
+pub const DAMAGE_SHAKE_MAX_TRAUMA: f32 = 0.7;
+
+/// Colour-channel offset at the screen edge (fraction of the screen) at the
+/// start of a full-intensity aberration pulse; 0 disables the pulse.
+pub const ABERRATION_PULSE_MAX: f32 = 0.012;
+
+/// Length of the aberration pulse (real-time seconds).
+pub const ABERRATION_PULSE_SECS: f32 = 0.35;
+
+/// How long a chipped asteroid flashes (seconds); 0 disables the flash.
+pub const HIT_FLASH_SECS: f32 = 0.08;
+
+/// Flash colour as a blend from rock grey (0) to white (1).
+pub const HIT_FLASH_STRENGTH: f32 = 0.8;
+
+/// How long merging bodies blend into their composite (seconds); 0 snaps.
+pub const MERGE_ANIMATION_SECS: f32 = 0.2;
+
+// ── Cinematics ────────────────────────────────────────────────────────────────
+//
+// Scripted camera moves (see `graphics::cinematic`) and the practice victory
+// they play for (see `victory`).  A practice run is won once one body holds
+// at least `VICTORY_DOMINANT_MASS` units and `VICTORY_DOMINANT_SHARE` of the
+// field's mass.
+
+/// Mass (units) the dominant body must reach; 0 disables the victory.
+pub const VICTORY_DOMINANT_MASS: u32 = 100;
+
+/// Fraction of the field's asteroid mass the dominant body must hold.
+pub const VICTORY_DOMINANT_SHARE: f32 = 0.5;
+
+/// Length of the victory orbit (real-time seconds).
+pub const CINEMATIC_VICTORY_SECS: f32 = 6.0;
+
+/// Distance (world units) the orbit camera circles the focus at.
+pub const CINEMATIC_ORBIT_RADIUS: f32 = 120.0;
+
+/// Full turns the orbit camera makes over one cinematic.
+pub const CINEMATIC_ORBIT_TURNS: f32 = 0.5;
+
+/// Camera zoom at the start of an orbit (larger = further out).
+pub const CINEMATIC_ZOOM_START: f32 = 1.6;
+
+/// Camera zoom at the end of an orbit.
+pub const CINEMATIC_ZOOM_END: f32 = 0.8;
+
+// ── Frame-Time Governor ───────────────────────────────────────────────────────
+
+/// Whether the frame-time governor may lower simulation quality under load.
+pub const FRAME_GOVERNOR_ENABLED: bool = true;
+
+/// Frame-time budget (milliseconds).  Sustained smoothed frame times above
+/// this drop the quality tier.
+pub const FRAME_GOVERNOR_TARGET_MS: f32 = 16.7;
+
+/// Seconds the smoothed frame time must stay over budget before the governor
+/// drops one quality tier.
+pub const FRAME_GOVERNOR_DEGRADE_SECS: f32 = 0.5;
+
+/// Seconds of sustained headroom required before the governor restores one
+/// quality tier.  Longer than the degrade delay so tiers do not oscillate.
+pub const FRAME_GOVERNOR_RESTORE_SECS: f32 = 3.0;
+
+/// Fraction of the budget the smoothed frame time must fall below to count as
+/// headroom (0.75 → under 12.5 ms for a 16.7 ms budget).
+pub const FRAME_GOVERNOR_HEADROOM_FRACTION: f32 = 0.75;
+
+/// Multiplier applied to `max_gravity_dist` per degraded tier.
+pub const FRAME_GOVERNOR_GRAVITY_RADIUS_STEP: f32 = 0.75;
+
+/// Multiplier applied to `particle_budget` per degraded tier.
+pub const FRAME_GOVERNOR_PARTICLE_BUDGET_STEP: f32 = 0.5;
+
+/// Gravity cutoff epsilon added per degraded tier: pairs whose force
+/// magnitude (`G · m_i · m_j / r²`) falls below it are skipped.  Full quality
+/// applies every pair in range.
+pub const FRAME_GOVERNOR_GRAVITY_EPSILON_STEP: f32 = 0.02;
+
+/// Multiplier applied to the asteroid LOD distances per degraded tier, so
+/// bodies drop to simplified/hidden meshes closer to the camera.
+pub const FRAME_GOVERNOR_LOD_DISTANCE_STEP: f32 = 0.75;
+
+// ── Gravity Lensing ───────────────────────────────────────────────────────────
+
+/// Whether planets bend the image behind them with a screen-space distortion
+/// pass.  Only drawn while the frame-time governor is at full quality.
+pub const GRAVITY_LENSING_ENABLED: bool = true;
+
+/// Peak distortion as a fraction of the lens radius, reached by a body far
+/// heavier than `GRAVITY_LENSING_REFERENCE_MASS`.
+pub const GRAVITY_LENSING_STRENGTH: f32 = 0.35;
+
+/// Lens radius as a multiple of the body's own radius.
+pub const GRAVITY_LENSING_RADIUS_SCALE: f32 = 2.5;
+
+/// Mass at which a body lenses at half of `GRAVITY_LENSING_STRENGTH`.  A
+/// standard planetoid (16) barely ripples the background; the Orbit and
+/// Catalog central bodies (thousands) bend it strongly.
+pub const GRAVITY_LENSING_REFERENCE_MASS: f32 = 400.0;
+
+// ── Starfield ─────────────────────────────────────────────────────────────────
+
+/// Draw the parallax starfield and nebula background.
+pub const STARFIELD_ENABLED: bool = true;
+
+/// Star layers between the far and near parallax factors.
+pub const STARFIELD_LAYERS: u32 = 3;
+
+/// Stars per layer in one repeating background tile.
+pub const STARFIELD_STARS_PER_LAYER: u32 = 160;
+
+/// Fraction of the camera's motion the farthest star layer follows; smaller
+/// reads as farther away.
+pub const STARFIELD_PARALLAX_FAR: f32 = 0.05;
+
+/// Fraction of the camera's motion the nearest star layer follows.
+pub const STARFIELD_PARALLAX_NEAR: f32 = 0.3;
+
+/// Nebula clouds in one background tile, drawn behind every star layer.
+pub const STARFIELD_NEBULA_CLOUDS: u32 = 5;
+
+/// Peak brightening of background stars next to a massive body (0.8 → +80 %).
+pub const STARFIELD_GLOW_STRENGTH: f32 = 0.8;
+
+/// Glow radius as a multiple of the body's radius.
+pub const STARFIELD_GLOW_RADIUS_SCALE: f32 = 6.0;
+
+/// Lightest body that brightens the background.  Keeps the per-frame colour
+/// update off while only small asteroids are in view.
+pub const STARFIELD_GLOW_MIN_MASS: f32 = 25.0;
+
+/// Mass that glows at half strength.
+pub const STARFIELD_GLOW_REFERENCE_MASS: f32 = 400.0;
+
 // ── Asteroid Geometry ─────────────────────────────────────────────────────────
 
 /// Density of asteroid material (mass units per world-unit²).
@@ -631,6 +1763,14 @@ pub const SPAWN_SHAPE_NOISE_FREQUENCY: f32 = 0.035;
 /// silhouette generation.
 pub const SPAWN_SHAPE_NOISE_AMPLITUDE: f32 = 0.25;
 
+/// Perturb canonical fragment / catalog-body polygons with seeded noise at
+/// spawn (see `asteroid::perturb_canonical_vertices`).
+pub const CANONICAL_SHAPE_NOISE_ENABLED: bool = true;
+
+/// Strength of the canonical-shape perturbation, as a fraction of each
+/// vertex's angular slot and radius.  Clamped to `[0, 0.45]`.
+pub const CANONICAL_SHAPE_NOISE_AMPLITUDE: f32 = 0.18;
+
 /// Initial velocity range (u/s) assigned to each spawned asteroid axis component.
 pub const ASTEROID_INITIAL_VELOCITY_RANGE: f32 = 15.0;
 
@@ -666,6 +1806,88 @@ pub const CRATER_EDGE_SUBDIVISIONS: usize = 2;
 /// Prevents unbounded vertex growth from repeated impacts.
 pub const MAX_CRATERS_PER_ASTEROID: usize = 8;
 
+// ── Planet Excavation ─────────────────────────────────────────────────────────
+
+/// Radius (world units) of a crater excavated in a planet.
+///
+/// Fixed rather than a fraction of the body: a ratio tuned for asteroids
+/// would carve away a quarter of a large planet per hit.
+pub const PLANET_CRATER_RADIUS: f32 = 14.0;
+
+/// Depth (world units) of each planet crater, clamped by [`CRATER_MAX_DEPTH`].
+pub const PLANET_CRATER_DEPTH: f32 = 4.0;
+
+/// Maximum craters kept per planet before the oldest is dropped.
+///
+/// Higher than [`MAX_CRATERS_PER_ASTEROID`] so the surface keeps a long
+/// record of strikes.
+pub const MAX_CRATERS_PER_PLANET: usize = 32;
+
+/// Mass units removed from a planet per excavation and thrown out as ejecta.
+pub const PLANET_EXCAVATION_MASS: u32 = 3;
+
+/// Planets are never excavated below this mass.
+pub const PLANET_MIN_MASS: u32 = 6;
+
+/// Maximum number of ejecta asteroids spawned per excavation.
+pub const PLANET_EJECTA_MAX_FRAGMENTS: usize = 3;
+
+/// Launch speed (u/s) of ejecta along the surface normal.
+pub const PLANET_EJECTA_SPEED: f32 = 45.0;
+
+/// Minimum `AsteroidSize` an asteroid needs for its impact to excavate a planet.
+pub const PLANET_IMPACT_MIN_MASS: u32 = 8;
+
+/// Contact force above which an asteroid impact excavates a planet.
+///
+/// Rapier reports this as the summed contact force of one physics step, so
+/// a resting body produces far less than a hard strike.
+pub const PLANET_IMPACT_FORCE_THRESHOLD: f32 = 250_000.0;
+
+// ── Soft-Body Deformation ─────────────────────────────────────────────────────
+
+/// Minimum `AsteroidSize` for an asteroid to deform under heavy impacts.
+///
+/// Smaller bodies are too few pixels across for a dent to read, and chip or
+/// split under weapon fire anyway.
+pub const SOFT_BODY_MIN_MASS: u32 = 12;
+
+/// Contact force above which an impact deforms a heavy asteroid.
+///
+/// Same units as [`PLANET_IMPACT_FORCE_THRESHOLD`]; lower, because the
+/// bodies involved are lighter than a planet strike.
+pub const SOFT_BODY_FORCE_THRESHOLD: f32 = 120_000.0;
+
+/// Fraction of the hull's depth along the impact axis that the hardest
+/// impacts flatten.  Strain rises from zero at the threshold towards this.
+pub const SOFT_BODY_MAX_STRAIN: f32 = 0.12;
+
+/// Flattest shape deformation may produce: depth along the impact axis over
+/// width across it.  A hull at this ratio no longer deforms from that side.
+pub const SOFT_BODY_MIN_ASPECT: f32 = 0.45;
+
+// ── Planetary Rings ───────────────────────────────────────────────────────────
+
+/// Roche radius of a planet as a multiple of its bounding radius.  Asteroids
+/// heavier than [`RING_FRAGMENT_MAX_MASS`] inside it are torn apart.
+///
+/// 2.0 sits just under the fluid-body Roche limit (≈ 2.44 radii at equal
+/// density), so the Orbit scenario's inner ring at 260 u stays intact.
+pub const ROCHE_RADIUS_SCALE: f32 = 2.0;
+
+/// Heaviest fragment (AsteroidSize) a tidal disruption produces, and the
+/// heaviest body that survives inside a Roche radius.
+pub const RING_FRAGMENT_MAX_MASS: u32 = 2;
+
+/// Rate (1/s) at which ring particles lose radial velocity and approach the
+/// circular orbit speed at their radius.  0.5 leaves about 8 % of an
+/// eccentricity after five seconds.
+pub const RING_CIRCULARIZATION_RATE: f32 = 0.5;
+
+/// Ring particles further than this many Roche radii from their planet stop
+/// being circularized.
+pub const RING_ZONE_SCALE: f32 = 2.0;
+
 // ── Primary Weapon Upgrades ───────────────────────────────────────────────────
 
 /// Maximum level the primary weapon can be upgraded to (1-indexed display; 0 = base).
@@ -737,3 +1959,36 @@ pub const TRACTOR_BEAM_MAX_LEVEL: u32 = 10;
 ///
 /// Level 1 costs 5, Level 2 costs 10, …, Level 10 costs 50.
 pub const TRACTOR_BEAM_UPGRADE_BASE_COST: u32 = 5;
+
+// ── Shield Upgrades ───────────────────────────────────────────────────────────
+
+/// Maximum shield level.  Unlike other upgrades level 0 means *no shield*:
+/// the first purchase installs it.
+pub const SHIELD_MAX_LEVEL: u32 = 5;
+
+/// Ore cost for the next shield level = `SHIELD_UPGRADE_BASE_COST * next_level`.
+///
+/// Level 1 costs 8, Level 2 costs 16, …, Level 5 costs 40.
+pub const SHIELD_UPGRADE_BASE_COST: u32 = 8;
+
+// ── Turret Hardpoints ─────────────────────────────────────────────────────────
+
+/// Number of turret hardpoints on the ship.  Level 0 means *no turrets*; each
+/// purchase mounts one more.
+pub const TURRET_MAX_COUNT: u32 = 2;
+
+/// Credit cost for the next turret = `TURRET_UPGRADE_BASE_COST * next_count`.
+///
+/// The first turret costs 12, the second 24.
+pub const TURRET_UPGRADE_BASE_COST: u32 = 12;
+
+// ── Mining Drone Upgrades ─────────────────────────────────────────────────────
+
+/// Maximum mining drone level.  Level 0 means *no drone*: the first purchase
+/// launches it.
+pub const MINING_DRONE_MAX_LEVEL: u32 = 5;
+
+/// Ore cost for the next drone level = `MINING_DRONE_UPGRADE_BASE_COST * next_level`.
+///
+/// Level 1 costs 6, Level 2 costs 12, …, Level 5 costs 30.
+pub const MINING_DRONE_UPGRADE_BASE_COST: u32 = 6;
@@ -0,0 +1,409 @@
+//! Easy / Normal / Hard presets layered over [`PhysicsConfig`].
+//!
+//! The preset is picked on the scenario-select screen and kept in the
+//! [`Difficulty`] resource.  [`apply_difficulty_overlay_system`] keeps the live
+//! config equal to `difficulty.apply(&base)`, where the base is whatever was
+//! last written to `PhysicsConfig` from outside — the startup load, a
+//! hot-reload of `assets/physics.toml`, or an imported bundle.  Normal is the
+//! identity, so the file values are what Normal plays.
+//!
+//! A preset scales:
+//!
+//! | Field | Easy | Hard |
+//! |-------|------|------|
+//! | `enemy_base_hp` | ×0.75 | ×1.35 |
+//! | `enemy_fire_cooldown_base` | ÷0.75 (slower fire) | ÷1.30 |
+//! | `damage_speed_threshold` | ×1.25 | ×0.80 |
+//! | `enemy_ore_drop_*` | ×2 | ×0.5 |
+//! | `outpost_ore_interval_secs` | ÷2 | ÷0.5 |
+//! | `respawn_invincibility_secs` | ×1.5 | ×0.6 |
+//!
+//! Enemy ore counts are rounded down; a kill still drops at least one ore.
+//! The preset is stored in save snapshots, so a loaded run keeps it, and
+//! non-Normal presets are listed in the run's mutators.
+//!
+//! [`AssistOptions`] are accessibility handicaps set on the settings screen,
+//! each independent of the preset and of each other.  They are applied on
+//! top of the preset by the same overlay:
+//!
+//! | Assist | Field |
+//! |--------|-------|
+//! | Enemy shot speed | `enemy_projectile_speed` × level |
+//! | Enemy fire rate | `enemy_fire_cooldown_base` and the boss fire cooldowns ÷ level |
+//! | Damage taken | `player_damage_taken_multiplier` × level |
+//!
+//! A run started with any assist on carries the `assisted` mutator, and its
+//! leaderboard entry is marked assisted.
+
+use crate::config::PhysicsConfig;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Difficulty preset for practice runs.
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[reflect(Resource)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Normal,
+    Hard,
+}
+
+/// Multipliers a [`Difficulty`] applies to the base config.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultyMultipliers {
+    pub enemy_hp: f32,
+    /// Divides `enemy_fire_cooldown_base`.
+    pub enemy_fire_rate: f32,
+    pub damage_threshold: f32,
+    pub ore_yield: f32,
+    pub respawn_invincibility: f32,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Normal, Difficulty::Hard];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "EASY",
+            Difficulty::Normal => "NORMAL",
+            Difficulty::Hard => "HARD",
+        }
+    }
+
+    /// One-line summary for the scenario-select screen.
+    pub fn description(self) -> &'static str {
+        match self {
+            Difficulty::Easy => "Weaker, slower-firing enemies · tougher hull · double ore drops",
+            Difficulty::Normal => "The game as tuned in physics.toml",
+            Difficulty::Hard => "Tougher, faster-firing enemies · fragile hull · half ore drops",
+        }
+    }
+
+    pub fn multipliers(self) -> DifficultyMultipliers {
+        match self {
+            Difficulty::Easy => DifficultyMultipliers {
+                enemy_hp: 0.75,
+                enemy_fire_rate: 0.75,
+                damage_threshold: 1.25,
+                ore_yield: 2.0,
+                respawn_invincibility: 1.5,
+            },
+            Difficulty::Normal => DifficultyMultipliers {
+                enemy_hp: 1.0,
+                enemy_fire_rate: 1.0,
+                damage_threshold: 1.0,
+                ore_yield: 1.0,
+                respawn_invincibility: 1.0,
+            },
+            Difficulty::Hard => DifficultyMultipliers {
+                enemy_hp: 1.35,
+                enemy_fire_rate: 1.30,
+                damage_threshold: 0.80,
+                ore_yield: 0.5,
+                respawn_invincibility: 0.6,
+            },
+        }
+    }
+
+    /// `base` with this preset's multipliers applied.
+    pub fn apply(self, base: &PhysicsConfig) -> PhysicsConfig {
+        let m = self.multipliers();
+        let ore = |count: u32| (count as f32 * m.ore_yield).floor() as u32;
+        PhysicsConfig {
+            enemy_base_hp: base.enemy_base_hp * m.enemy_hp,
+            enemy_fire_cooldown_base: base.enemy_fire_cooldown_base / m.enemy_fire_rate,
+            damage_speed_threshold: base.damage_speed_threshold * m.damage_threshold,
+            enemy_ore_drop_base: ore(base.enemy_ore_drop_base),
+            enemy_ore_drop_per_tier: ore(base.enemy_ore_drop_per_tier),
+            enemy_ore_drop_per_wave: ore(base.enemy_ore_drop_per_wave),
+            outpost_ore_interval_secs: base.outpost_ore_interval_secs / m.ore_yield,
+            respawn_invincibility_secs: base.respawn_invincibility_secs * m.respawn_invincibility,
+            ..base.clone()
+        }
+    }
+
+    /// Run-mutator tag, or `None` for Normal.
+    pub fn mutator(self) -> Option<&'static str> {
+        match self {
+            Difficulty::Normal => None,
+            Difficulty::Easy => Some("easy"),
+            Difficulty::Hard => Some("hard"),
+        }
+    }
+}
+
+/// Levels an assist cycles through on the settings screen; 1.0 is off.
+pub const ASSIST_LEVELS: [f32; 4] = [1.0, 0.75, 0.5, 0.25];
+
+/// Run-mutator tag for a run played with assists on.
+pub const ASSISTED_MUTATOR: &str = "assisted";
+
+/// Accessibility handicaps, each a multiplier from [`ASSIST_LEVELS`].
+#[derive(Resource, Reflect, Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[reflect(Resource)]
+pub struct AssistOptions {
+    /// Scales the speed of enemy and boss shots.
+    pub enemy_projectile_speed: f32,
+    /// Scales how often enemies and the boss fire.
+    pub enemy_fire_rate: f32,
+    /// Scales the damage the player's ship takes.
+    pub player_damage_taken: f32,
+}
+
+impl Default for AssistOptions {
+    fn default() -> Self {
+        Self {
+            enemy_projectile_speed: 1.0,
+            enemy_fire_rate: 1.0,
+            player_damage_taken: 1.0,
+        }
+    }
+}
+
+impl AssistOptions {
+    /// Whether any assist is on.
+    pub fn is_assisted(&self) -> bool {
+        *self != Self::default()
+    }
+
+    /// `base` with the assists applied.
+    pub fn apply(&self, base: &PhysicsConfig) -> PhysicsConfig {
+        PhysicsConfig {
+            enemy_projectile_speed: base.enemy_projectile_speed * self.enemy_projectile_speed,
+            enemy_fire_cooldown_base: base.enemy_fire_cooldown_base / self.enemy_fire_rate,
+            boss_phase_one_fire_cooldown: base.boss_phase_one_fire_cooldown / self.enemy_fire_rate,
+            boss_phase_two_aim_cooldown: base.boss_phase_two_aim_cooldown / self.enemy_fire_rate,
+            boss_phase_two_burst_cooldown: base.boss_phase_two_burst_cooldown
+                / self.enemy_fire_rate,
+            player_damage_taken_multiplier: base.player_damage_taken_multiplier
+                * self.player_damage_taken,
+            ..base.clone()
+        }
+    }
+
+    /// Run-mutator tag, or `None` with every assist off.
+    pub fn mutator(&self) -> Option<&'static str> {
+        self.is_assisted().then_some(ASSISTED_MUTATOR)
+    }
+}
+
+/// One assist on the settings screen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Assist {
+    EnemyProjectileSpeed,
+    EnemyFireRate,
+    PlayerDamageTaken,
+}
+
+impl Assist {
+    pub const ALL: [Assist; 3] = [
+        Assist::EnemyProjectileSpeed,
+        Assist::EnemyFireRate,
+        Assist::PlayerDamageTaken,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Assist::EnemyProjectileSpeed => "ENEMY SHOT SPEED",
+            Assist::EnemyFireRate => "ENEMY FIRE RATE",
+            Assist::PlayerDamageTaken => "DAMAGE TAKEN",
+        }
+    }
+
+    fn level_mut(self, assists: &mut AssistOptions) -> &mut f32 {
+        match self {
+            Assist::EnemyProjectileSpeed => &mut assists.enemy_projectile_speed,
+            Assist::EnemyFireRate => &mut assists.enemy_fire_rate,
+            Assist::PlayerDamageTaken => &mut assists.player_damage_taken,
+        }
+    }
+
+    pub fn get(self, assists: &AssistOptions) -> f32 {
+        match self {
+            Assist::EnemyProjectileSpeed => assists.enemy_projectile_speed,
+            Assist::EnemyFireRate => assists.enemy_fire_rate,
+            Assist::PlayerDamageTaken => assists.player_damage_taken,
+        }
+    }
+
+    /// Step to the next of [`ASSIST_LEVELS`], wrapping back to off.
+    pub fn cycle(self, assists: &mut AssistOptions) {
+        let level = self.level_mut(assists);
+        let index = ASSIST_LEVELS
+            .iter()
+            .position(|l| (l - *level).abs() < 1e-4)
+            .map_or(0, |i| (i + 1) % ASSIST_LEVELS.len());
+        *level = ASSIST_LEVELS[index];
+    }
+}
+
+/// The config the current [`Difficulty`] overlay was applied to.
+#[derive(Resource, Debug, Default)]
+pub struct DifficultyOverlay {
+    base: Option<PhysicsConfig>,
+}
+
+impl DifficultyOverlay {
+    /// Config without the overlay, once the first overlay has been applied.
+    pub fn base(&self) -> Option<&PhysicsConfig> {
+        self.base.as_ref()
+    }
+}
+
+/// Re-apply the overlay when the preset, the assists or the base config
+/// change.
+///
+/// Overlay writes bypass change detection, so any change seen here came from
+/// outside and becomes the new base.
+pub fn apply_difficulty_overlay_system(
+    difficulty: Res<Difficulty>,
+    assists: Res<AssistOptions>,
+    mut overlay: ResMut<DifficultyOverlay>,
+    mut config: ResMut<PhysicsConfig>,
+) {
+    if config.is_changed() || overlay.base.is_none() {
+        overlay.base = Some(config.clone());
+    } else if !difficulty.is_changed() && !assists.is_changed() {
+        return;
+    }
+    let Some(base) = overlay.base.as_ref() else {
+        return;
+    };
+    *config.bypass_change_detection() = assists.apply(&difficulty.apply(base));
+}
+
+pub struct DifficultyPlugin;
+
+impl Plugin for DifficultyPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Difficulty>()
+            .init_resource::<AssistOptions>()
+            .init_resource::<DifficultyOverlay>()
+            .add_systems(
+                Update,
+                apply_difficulty_overlay_system.after(crate::config::hot_reload_physics_config),
+            );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn overlay_app(difficulty: Difficulty) -> App {
+        let mut app = App::new();
+        app.insert_resource(PhysicsConfig::default())
+            .insert_resource(difficulty)
+            .init_resource::<AssistOptions>()
+            .init_resource::<DifficultyOverlay>()
+            .add_systems(Update, apply_difficulty_overlay_system);
+        app
+    }
+
+    #[test]
+    fn normal_is_the_identity() {
+        let base = PhysicsConfig::default();
+        let applied = Difficulty::Normal.apply(&base);
+        assert_eq!(applied.enemy_base_hp, base.enemy_base_hp);
+        assert_eq!(applied.enemy_ore_drop_base, base.enemy_ore_drop_base);
+        assert_eq!(
+            applied.respawn_invincibility_secs,
+            base.respawn_invincibility_secs
+        );
+    }
+
+    #[test]
+    fn presets_scale_in_opposite_directions() {
+        let base = PhysicsConfig::default();
+        let easy = Difficulty::Easy.apply(&base);
+        let hard = Difficulty::Hard.apply(&base);
+
+        assert!(easy.enemy_base_hp < base.enemy_base_hp);
+        assert!(hard.enemy_base_hp > base.enemy_base_hp);
+        assert!(easy.enemy_fire_cooldown_base > base.enemy_fire_cooldown_base);
+        assert!(hard.enemy_fire_cooldown_base < base.enemy_fire_cooldown_base);
+        assert!(easy.damage_speed_threshold > hard.damage_speed_threshold);
+        assert!(easy.enemy_ore_drop_base > hard.enemy_ore_drop_base);
+        assert!(easy.respawn_invincibility_secs > hard.respawn_invincibility_secs);
+        // Untouched fields come from the base.
+        assert_eq!(hard.gravity_const, base.gravity_const);
+    }
+
+    #[test]
+    fn overlay_never_compounds_and_follows_reloads() {
+        let mut app = overlay_app(Difficulty::Hard);
+        let base_hp = PhysicsConfig::default().enemy_base_hp;
+        let hard_hp = base_hp * Difficulty::Hard.multipliers().enemy_hp;
+
+        app.update();
+        app.update();
+        assert_eq!(
+            app.world().resource::<PhysicsConfig>().enemy_base_hp,
+            hard_hp
+        );
+
+        // A reload replaces the base; the overlay follows it.
+        app.world_mut()
+            .resource_mut::<PhysicsConfig>()
+            .enemy_base_hp = 100.0;
+        app.update();
+        let reloaded = 100.0 * Difficulty::Hard.multipliers().enemy_hp;
+        assert_eq!(
+            app.world().resource::<PhysicsConfig>().enemy_base_hp,
+            reloaded
+        );
+
+        // Switching back to Normal restores the base exactly.
+        *app.world_mut().resource_mut::<Difficulty>() = Difficulty::Normal;
+        app.update();
+        assert_eq!(app.world().resource::<PhysicsConfig>().enemy_base_hp, 100.0);
+        assert_eq!(
+            app.world()
+                .resource::<DifficultyOverlay>()
+                .base()
+                .map(|base| base.enemy_base_hp),
+            Some(100.0)
+        );
+    }
+
+    #[test]
+    fn assists_cycle_independently_and_stack_on_the_preset() {
+        let mut assists = AssistOptions::default();
+        assert!(!assists.is_assisted());
+        assert_eq!(assists.mutator(), None);
+        Assist::EnemyFireRate.cycle(&mut assists);
+        Assist::PlayerDamageTaken.cycle(&mut assists);
+        Assist::PlayerDamageTaken.cycle(&mut assists);
+        assert_eq!(Assist::EnemyProjectileSpeed.get(&assists), 1.0);
+        assert_eq!(Assist::EnemyFireRate.get(&assists), 0.75);
+        assert_eq!(Assist::PlayerDamageTaken.get(&assists), 0.5);
+        assert_eq!(assists.mutator(), Some(ASSISTED_MUTATOR));
+
+        let mut app = overlay_app(Difficulty::Hard);
+        app.insert_resource(assists);
+        app.update();
+        let base = PhysicsConfig::default();
+        let hard = Difficulty::Hard.apply(&base);
+        let config = app.world().resource::<PhysicsConfig>();
+        assert_eq!(config.enemy_projectile_speed, base.enemy_projectile_speed);
+        assert_eq!(
+            config.enemy_fire_cooldown_base,
+            hard.enemy_fire_cooldown_base / 0.75
+        );
+        assert_eq!(config.player_damage_taken_multiplier, 0.5);
+        assert_eq!(config.enemy_base_hp, hard.enemy_base_hp);
+
+        // Cycling past the last level switches the assist off again.
+        for _ in 0..3 {
+            Assist::EnemyFireRate.cycle(&mut app.world_mut().resource_mut::<AssistOptions>());
+        }
+        app.update();
+        assert_eq!(
+            app.world()
+                .resource::<PhysicsConfig>()
+                .enemy_fire_cooldown_base,
+            hard.enemy_fire_cooldown_base
+        );
+    }
+}
@@ -1,24 +1,53 @@
 //! Enemy ship foundation: deterministic spawning, basic seek movement, and rendering.
+//!
+//! Four archetypes share the spawn, damage and render paths:
+//!
+//! - **Chaser** closes in and fires single shots;
+//! - **Skirmisher** circles at range and fires spreads;
+//! - **Kamikaze** never fires; it dashes at the ship and detonates on
+//!   contact (`enemy_kamikaze_detonation_system`);
+//! - **Miner** never fires; it scoops up ore pickups before the player can,
+//!   flees once full, and drops its haul when shot down
+//!   (`enemy_miner_system`).
+//!
+//! Each spawn picks an archetype by weight among those unlocked at the
+//! current progression stage (`enemy_archetype_for_spawn`).
 
 use crate::asteroid::{
-    canonical_vertices_for_mass, compute_convex_hull_from_points, rescale_vertices_to_area,
-    spawn_asteroid_with_vertices, Asteroid, AsteroidSize, Planet, Vertices,
+    compute_convex_hull_from_points, fragment_vertices_for_mass, rescale_vertices_to_area,
+    spawn_asteroid_with_vertices, Asteroid, AsteroidSize, MergeCooldown, Planet, Vertices,
 };
 use crate::asteroid_rendering::filled_polygon_mesh;
+use crate::attribution::{credit_for, Actor, KillCredited, KillVictim, LastDamagedBy};
+use crate::audio::{play_sfx, Sfx};
 use crate::campaign::{
     campaign_progression_stage, CampaignSession, CampaignWaveDirector, CampaignWavePhase,
+    ShopBeacon, WaveReinforcementQueue,
 };
 use crate::config::PhysicsConfig;
+use crate::graphics::palette::Palette;
 use crate::menu::{GameState, SelectedGameMode};
-use crate::mining::spawn_ore_drop;
+use crate::mining::outpost::enemy_fire_target;
+use crate::mining::{
+    spawn_ore_drop, AsteroidMaterial, MiningDrone, MiningOutpost, OreKind, OrePickup,
+};
 use crate::particles::{
     spawn_debris_particles, spawn_impact_particles, spawn_ship_thrust_particles,
+    spawn_shockwave_ring_particles,
 };
 use crate::player::state::{Missile, Projectile};
 use crate::player::{
-    CampaignLoadout, CampaignPrimaryWeapon, Player, PlayerHealth, PlayerLives, PlayerScore,
-    PrimaryWeaponUpgradeTracks, SecondaryWeaponLevel,
+    AimTargetMemory, CampaignLoadout, CampaignPrimaryWeapon, Player, PlayerHealth, PlayerLives,
+    PlayerScore, PlayerShield, PrimaryWeaponUpgradeTracks, SecondaryWeaponLevel, TurretRound,
 };
+use crate::rng::{visual_rng, GameplayRng};
+use crate::session_stats::{SessionStatEvent, StatWeapon};
+use crate::simulation::density::DensityField;
+use crate::simulation::procgen::ProceduralField;
+use crate::simulation::volatile::{blast_falloff, ignite_volatile, Volatile};
+use crate::spatial_partition::SpatialGrid;
+use crate::wave_telemetry::{DamageSource, PlayerDamaged};
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 use rand::Rng;
@@ -32,33 +61,42 @@ const ENEMY_FORMATION_MIN_MEMBERS: usize = 3;
 const ENEMY_FORMATION_BREAK_DISTANCE: f32 = 280.0;
 const ENEMY_FORMATION_LATERAL_SPACING: f32 = 34.0;
 const ENEMY_FORMATION_FOLLOW_DISTANCE: f32 = 52.0;
+/// Distance from the look-ahead segment within which the spatial index
+/// returns obstacle centres, so large bodies sitting well off the path are
+/// still found.
+const ENEMY_AVOIDANCE_PROBE_MARGIN: f32 = 140.0;
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct Enemy;
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct Boss;
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct BossHealth {
     pub hp: f32,
     pub max_hp: f32,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct BossWeakpoint {
     pub exposed: bool,
     pub timer_secs: f32,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
 pub enum BossAttackPhase {
     PhaseOne,
     Telegraph,
     PhaseTwo,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct BossAttackState {
     pub phase: BossAttackPhase,
     pub phase_timer_secs: f32,
@@ -67,7 +105,8 @@ pub struct BossAttackState {
     pub telegraph_emit_timer: f32,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyHealth {
     pub hp: f32,
     pub max_hp: f32,
@@ -87,7 +126,8 @@ pub struct EnemyHealthBarFill {
     pub material: Handle<ColorMaterial>,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyFireCooldown {
     pub timer: f32,
 }
@@ -97,51 +137,149 @@ pub struct EnemyThrustVfxTimer {
     pub timer: f32,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyProjectile {
     pub age: f32,
     pub distance_traveled: f32,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+/// Archetype of the enemy that fired a shot, for damage attribution.
+/// Boss shots carry none.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct EnemyShotSource(pub EnemyArchetype);
+
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyTier {
     pub level: u32,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyProgressionStage {
     pub stage: u32,
 }
 
-#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+#[reflect(Component)]
 pub enum EnemyArchetype {
     Chaser,
     Skirmisher,
+    Kamikaze,
+    Miner,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+impl EnemyArchetype {
+    pub const ALL: [Self; 4] = [Self::Chaser, Self::Skirmisher, Self::Kamikaze, Self::Miner];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Chaser => "chaser",
+            Self::Skirmisher => "skirmisher",
+            Self::Kamikaze => "kamikaze",
+            Self::Miner => "miner",
+        }
+    }
+
+    /// Relative chance of this archetype among those unlocked at a stage.
+    pub fn spawn_weight(self, config: &PhysicsConfig) -> u32 {
+        match self {
+            Self::Chaser => config.enemy_weight_chaser,
+            Self::Skirmisher => config.enemy_weight_skirmisher,
+            Self::Kamikaze => config.enemy_weight_kamikaze,
+            Self::Miner => config.enemy_weight_miner,
+        }
+    }
+
+    /// First progression stage at which this archetype spawns.
+    pub fn min_stage(self, config: &PhysicsConfig) -> u32 {
+        match self {
+            Self::Chaser => 0,
+            Self::Skirmisher => config.enemy_skirmisher_min_stage,
+            Self::Kamikaze => config.enemy_kamikaze_min_stage,
+            Self::Miner => config.enemy_miner_min_stage,
+        }
+    }
+
+    /// Hull strength relative to the stage's enemy HP.
+    pub fn hp_mult(self, config: &PhysicsConfig) -> f32 {
+        match self {
+            Self::Chaser | Self::Skirmisher => 1.0,
+            Self::Kamikaze => config.enemy_kamikaze_hp_mult,
+            Self::Miner => config.enemy_miner_hp_mult,
+        }
+    }
+
+    /// Points for destroying one.
+    pub fn kill_score(self, config: &PhysicsConfig) -> u32 {
+        match self {
+            Self::Chaser | Self::Skirmisher => config.enemy_kill_score,
+            Self::Kamikaze => config.enemy_kamikaze_kill_score,
+            Self::Miner => config.enemy_miner_kill_score,
+        }
+    }
+
+    /// Whether it shoots at the player.
+    pub fn fires(self) -> bool {
+        matches!(self, Self::Chaser | Self::Skirmisher)
+    }
+
+    /// Whether it can lead or join a formation.
+    pub fn flies_in_formation(self) -> bool {
+        matches!(self, Self::Chaser | Self::Skirmisher)
+    }
+}
+
+/// Ore a rival miner has scooped up, and the pickup it is heading for.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct EnemyMinerHold {
+    pub stolen: Vec<OreKind>,
+    pub target: Option<Vec2>,
+}
+
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyFormationLeader;
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyFormationMember {
     pub leader: Entity,
     pub slot_index: u8,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyFormationTarget {
     pub world_anchor: Vec2,
 }
 
-#[derive(Component, Debug, Clone, Copy)]
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
 pub struct EnemyStun {
     pub remaining_secs: f32,
 }
 
+/// Enemy has dropped below `enemy_retreat_hp_fraction` and is fleeing toward
+/// the field edge.  Escaping calls in reinforcements for a later wave.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct EnemyRetreating;
+
 #[derive(Component)]
 pub struct EnemyProjectileRenderMarker;
 
-#[derive(Resource, Debug, Clone)]
+/// Enemy shot caught by the tractor beam and turned into a player round.
+/// Its expiry does not break the hit streak.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct DeflectedShot;
+
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
 pub struct EnemySpawnState {
     pub timer_secs: f32,
     pub session_elapsed_secs: f32,
@@ -170,8 +308,11 @@ impl Plugin for EnemyPlugin {
                     enemy_spawn_system,
                     boss_weakpoint_cycle_system,
                     enemy_stun_tick_system,
+                    enemy_retreat_system,
                     enemy_formation_behavior_system,
+                    enemy_miner_system,
                     enemy_seek_player_system,
+                    enemy_kamikaze_detonation_system,
                     boss_attack_system,
                     enemy_fire_system,
                     despawn_old_enemy_projectiles_system,
@@ -191,6 +332,7 @@ impl Plugin for EnemyPlugin {
                     enemy_damage_from_player_weapons_system,
                     enemy_collision_damage_system,
                     enemy_player_collision_damage_system,
+                    enemy_projectile_intercept_system,
                     enemy_projectile_hit_system,
                 )
                     .chain()
@@ -232,17 +374,41 @@ fn enemy_fire_cooldown_for_stage(config: &PhysicsConfig, stage: u32) -> f32 {
     (config.enemy_fire_cooldown_base * cooldown_scale).max(0.45)
 }
 
-fn enemy_archetype_for_spawn(stage: u32, spawn_serial: u64) -> EnemyArchetype {
-    if stage >= 2 && spawn_serial % 2 == 1 {
-        EnemyArchetype::Skirmisher
-    } else {
-        EnemyArchetype::Chaser
+/// Pick the archetype for the `spawn_serial`-th spawn at `stage`.
+///
+/// A weighted draw among the archetypes unlocked at `stage`, keyed on a hash
+/// of the serial so a wave plays out the same way every time.  Falls back to
+/// the chaser when every unlocked weight is 0.
+fn enemy_archetype_for_spawn(
+    config: &PhysicsConfig,
+    stage: u32,
+    spawn_serial: u64,
+) -> EnemyArchetype {
+    let unlocked = EnemyArchetype::ALL
+        .into_iter()
+        .filter(|archetype| stage >= archetype.min_stage(config))
+        .map(|archetype| (archetype, archetype.spawn_weight(config)));
+    let total: u64 = unlocked.clone().map(|(_, weight)| weight as u64).sum();
+    if total == 0 {
+        return EnemyArchetype::Chaser;
+    }
+    let mut roll = (spawn_serial
+        .wrapping_add(1)
+        .wrapping_mul(0x9E37_79B9_7F4A_7C15)
+        >> 32)
+        % total;
+    for (archetype, weight) in unlocked {
+        if roll < weight as u64 {
+            return archetype;
+        }
+        roll -= weight as u64;
     }
+    EnemyArchetype::Chaser
 }
 
 fn enemy_fire_cooldown_for_archetype(base_cooldown: f32, archetype: EnemyArchetype) -> f32 {
     match archetype {
-        EnemyArchetype::Chaser => base_cooldown,
+        EnemyArchetype::Chaser | EnemyArchetype::Kamikaze | EnemyArchetype::Miner => base_cooldown,
         EnemyArchetype::Skirmisher => (base_cooldown * 1.15).max(0.45),
     }
 }
@@ -302,8 +468,9 @@ fn spawn_enemy_projectile(
     config: &PhysicsConfig,
     spawn_pos: Vec2,
     fire_dir: Vec2,
+    source: Option<EnemyArchetype>,
 ) {
-    commands.spawn((
+    let mut shot = commands.spawn((
         EnemyProjectile {
             age: 0.0,
             distance_traveled: 0.0,
@@ -319,26 +486,75 @@ fn spawn_enemy_projectile(
         Collider::ball(config.enemy_projectile_collider_radius),
         Sensor,
         Ccd { enabled: true },
+        // GROUP_3 lets player blaster shots intercept enemy fire; both are
+        // kinematic sensors, so KINEMATIC_KINEMATIC must be active too.
         CollisionGroups::new(
             bevy_rapier2d::geometry::Group::GROUP_6,
-            bevy_rapier2d::geometry::Group::GROUP_1 | bevy_rapier2d::geometry::Group::GROUP_2,
+            bevy_rapier2d::geometry::Group::GROUP_1
+                | bevy_rapier2d::geometry::Group::GROUP_2
+                | bevy_rapier2d::geometry::Group::GROUP_3,
         ),
-        ActiveCollisionTypes::DYNAMIC_KINEMATIC,
+        ActiveCollisionTypes::DYNAMIC_KINEMATIC | ActiveCollisionTypes::KINEMATIC_KINEMATIC,
         ActiveEvents::COLLISION_EVENTS,
     ));
+    if let Some(archetype) = source {
+        shot.insert(EnemyShotSource(archetype));
+    }
+}
+
+/// Turn an enemy shot into a player round travelling at `linvel`.
+///
+/// The shot switches to the player-shot collision groups, so from here on it
+/// hits asteroids, enemies and other enemy fire but passes through the ship.
+/// `attach_projectile_mesh_system` re-skins it as a blaster round.
+pub fn deflect_enemy_projectile(commands: &mut Commands, shot: Entity, linvel: Vec2) {
+    commands
+        .entity(shot)
+        .remove::<(
+            EnemyProjectile,
+            EnemyShotSource,
+            EnemyProjectileRenderMarker,
+        )>()
+        .insert((
+            Projectile::default(),
+            DeflectedShot,
+            Velocity {
+                linvel,
+                angvel: 0.0,
+            },
+            CollisionGroups::new(
+                bevy_rapier2d::geometry::Group::GROUP_3,
+                bevy_rapier2d::geometry::Group::GROUP_1
+                    | bevy_rapier2d::geometry::Group::GROUP_5
+                    | bevy_rapier2d::geometry::Group::GROUP_6,
+            ),
+        ));
 }
 
 pub fn spawn_campaign_boss(
     commands: &mut Commands,
     config: &PhysicsConfig,
     around_pos: Vec2,
+    zones: &[SpawnExclusion],
     mission_index: u32,
     wave_index: u32,
 ) -> Entity {
     let stage = campaign_progression_stage(mission_index.max(1), wave_index.max(1));
     let hp_scale = (1.0 + stage as f32 * 0.20).min(3.0);
     let hp = config.boss_base_hp * hp_scale;
-    let spawn_pos = around_pos + Vec2::new(0.0, (config.enemy_spawn_radius * 0.45).max(260.0));
+    let ring = (config.enemy_spawn_radius * 0.45).max(260.0);
+    let preferred = around_pos + Vec2::new(0.0, ring);
+    let spawn_pos = if spawn_point_is_clear(preferred, zones) {
+        preferred
+    } else {
+        fallback_spawn_point(
+            around_pos,
+            &[ring, ring * 1.5, ring * 2.0, ring * 3.0],
+            zones,
+            |_| true,
+        )
+        .unwrap_or(preferred)
+    };
 
     commands
         .spawn((
@@ -510,7 +726,7 @@ fn boss_attack_system(
                         }
                         let shot_dir = rotate_vec2(to_player_dir, offset).normalize_or_zero();
                         let spawn_pos = pos + shot_dir * (config.boss_collider_radius + 10.0);
-                        spawn_enemy_projectile(&mut commands, &config, spawn_pos, shot_dir);
+                        spawn_enemy_projectile(&mut commands, &config, spawn_pos, shot_dir, None);
                         remaining_projectile_budget = remaining_projectile_budget.saturating_sub(1);
                     }
                     attack_state.fire_timer_secs = config.boss_phase_one_fire_cooldown.max(0.2);
@@ -544,7 +760,7 @@ fn boss_attack_system(
                         }
                         let shot_dir = rotate_vec2(to_player_dir, offset).normalize_or_zero();
                         let spawn_pos = pos + shot_dir * (config.boss_collider_radius + 10.0);
-                        spawn_enemy_projectile(&mut commands, &config, spawn_pos, shot_dir);
+                        spawn_enemy_projectile(&mut commands, &config, spawn_pos, shot_dir, None);
                         remaining_projectile_budget = remaining_projectile_budget.saturating_sub(1);
                     }
                     attack_state.fire_timer_secs = config.boss_phase_two_aim_cooldown.max(0.2);
@@ -560,7 +776,7 @@ fn boss_attack_system(
                         let angle = t * std::f32::consts::TAU;
                         let shot_dir = Vec2::new(angle.cos(), angle.sin());
                         let spawn_pos = pos + shot_dir * (config.boss_collider_radius + 12.0);
-                        spawn_enemy_projectile(&mut commands, &config, spawn_pos, shot_dir);
+                        spawn_enemy_projectile(&mut commands, &config, spawn_pos, shot_dir, None);
                         remaining_projectile_budget = remaining_projectile_budget.saturating_sub(1);
                     }
                     attack_state.burst_timer_secs = config.boss_phase_two_burst_cooldown.max(0.3);
@@ -570,6 +786,85 @@ fn boss_attack_system(
     }
 }
 
+/// Points tried on each ring by [`fallback_spawn_point`].
+const SPAWN_FALLBACK_POINTS: usize = 24;
+
+/// A circle that enemy and boss spawns must stay out of.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SpawnExclusion {
+    pub centre: Vec2,
+    pub radius: f32,
+}
+
+/// Player, hauler and objective positions that spawns keep clear of.
+///
+/// The player ship uses `enemy_min_player_spawn_distance`, mining drones
+/// `spawn_exclusion_hauler_radius`, and mining outposts and the shop beacon
+/// `spawn_exclusion_objective_radius`.
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct SpawnExclusions<'w, 's> {
+    config: Res<'w, PhysicsConfig>,
+    q_player: Query<'w, 's, &'static Transform, With<Player>>,
+    q_haulers: Query<'w, 's, &'static Transform, With<MiningDrone>>,
+    q_beacons: Query<'w, 's, &'static Transform, With<ShopBeacon>>,
+    /// Outposts are parented to their planetoid, so read the world position.
+    q_outposts: Query<'w, 's, &'static GlobalTransform, With<MiningOutpost>>,
+}
+
+impl SpawnExclusions<'_, '_> {
+    pub fn zones(&self) -> Vec<SpawnExclusion> {
+        let zone = |centre: Vec3, radius: f32| SpawnExclusion {
+            centre: centre.truncate(),
+            radius: radius.max(0.0),
+        };
+        let player = self.config.enemy_min_player_spawn_distance.max(1.0);
+        let hauler = self.config.spawn_exclusion_hauler_radius;
+        let objective = self.config.spawn_exclusion_objective_radius;
+        self.q_player
+            .iter()
+            .map(|t| zone(t.translation, player))
+            .chain(self.q_haulers.iter().map(|t| zone(t.translation, hauler)))
+            .chain(
+                self.q_beacons
+                    .iter()
+                    .map(|t| zone(t.translation, objective)),
+            )
+            .chain(
+                self.q_outposts
+                    .iter()
+                    .map(|t| zone(t.translation(), objective)),
+            )
+            .collect()
+    }
+}
+
+/// `true` when `pos` lies outside every exclusion zone.
+pub fn spawn_point_is_clear(pos: Vec2, zones: &[SpawnExclusion]) -> bool {
+    zones
+        .iter()
+        .all(|zone| zone.centre.distance_squared(pos) >= zone.radius * zone.radius)
+}
+
+/// First clear point that `accept` also allows, searching rings of `radii`
+/// around `centre` in order.
+pub fn fallback_spawn_point(
+    centre: Vec2,
+    radii: &[f32],
+    zones: &[SpawnExclusion],
+    accept: impl Fn(Vec2) -> bool,
+) -> Option<Vec2> {
+    radii
+        .iter()
+        .flat_map(|&radius| {
+            (0..SPAWN_FALLBACK_POINTS).map(move |i| {
+                let angle = i as f32 / SPAWN_FALLBACK_POINTS as f32 * std::f32::consts::TAU;
+                centre + Vec2::from_angle(angle) * radius
+            })
+        })
+        .find(|&pos| spawn_point_is_clear(pos, zones) && accept(pos))
+}
+
 fn deterministic_spawn_offset(index: u64, radius: f32) -> Vec2 {
     const GOLDEN_ANGLE: f32 = 2.3999631;
     let a = index as f32 * GOLDEN_ANGLE;
@@ -602,6 +897,9 @@ fn enemy_spawn_system(
     mut wave_director: Option<ResMut<CampaignWaveDirector>>,
     config: Res<PhysicsConfig>,
     score: Res<PlayerScore>,
+    procgen: Option<Res<ProceduralField>>,
+    density: Option<Res<DensityField>>,
+    exclusions: SpawnExclusions,
     q_player: Query<&Transform, With<Player>>,
     q_enemies: Query<&Transform, With<Enemy>>,
 ) {
@@ -663,31 +961,67 @@ fn enemy_spawn_system(
     let min_player_dist = config.enemy_min_player_spawn_distance.max(1.0);
 
     let edge_radius = (config.cull_distance * 0.92)
-        .min((config.hard_cull_distance - 24.0).max(1.0))
+        .min((config.sector_stream_in_radius - 24.0).max(1.0))
         .max(min_player_dist + 8.0);
+    // The FRONTIER field has no centre, so the ring follows the ship there.
+    let ring_centre = if procgen.is_some_and(|field| field.enabled) {
+        player_pos
+    } else {
+        Vec2::ZERO
+    };
+
+    let zones = exclusions.zones();
+    let spacing_sq = config.enemy_min_enemy_spacing * config.enemy_min_enemy_spacing;
+    let well_spaced = |candidate: Vec2| {
+        !q_enemies
+            .iter()
+            .any(|t| t.translation.truncate().distance_squared(candidate) < spacing_sq)
+    };
 
+    // Compare the first few clear ring points and take the one with the
+    // least asteroid mass around it, so enemies arrive from open space.
+    let samples = if density.is_some() {
+        config.enemy_spawn_density_samples.max(1)
+    } else {
+        1
+    };
     let mut spawn_pos = None;
+    let mut sparsest = f32::INFINITY;
+    let mut sampled = 0;
+    let mut consumed = 0;
     for attempt in 0..18_u64 {
-        let offset = deterministic_spawn_offset(state.total_spawned + attempt, edge_radius);
-        if offset.length() < min_player_dist {
-            continue;
-        }
-
-        let candidate = offset;
-        if candidate.distance_squared(player_pos) < min_player_dist * min_player_dist {
+        let candidate =
+            ring_centre + deterministic_spawn_offset(state.total_spawned + attempt, edge_radius);
+        if !spawn_point_is_clear(candidate, &zones) || !well_spaced(candidate) {
             continue;
         }
-        let too_close = q_enemies.iter().any(|t| {
-            t.translation.truncate().distance_squared(candidate)
-                < config.enemy_min_enemy_spacing * config.enemy_min_enemy_spacing
+        let mass = density.as_ref().map_or(0.0, |density| {
+            density.mass_near(candidate, density.cell_size())
         });
-
-        if !too_close {
+        if mass < sparsest {
+            sparsest = mass;
             spawn_pos = Some(candidate);
-            state.total_spawned += attempt + 1;
+        }
+        consumed = attempt + 1;
+        sampled += 1;
+        if sampled >= samples {
             break;
         }
     }
+    state.total_spawned += consumed;
+    // Every ring point was excluded or crowded: search smaller rings inside
+    // the cull radius before giving up for this tick.
+    if spawn_pos.is_none() {
+        spawn_pos = fallback_spawn_point(
+            ring_centre,
+            &[edge_radius, edge_radius * 0.8, edge_radius * 0.6],
+            &zones,
+            well_spaced,
+        );
+        if spawn_pos.is_some() {
+            state.total_spawned += 18;
+        }
+    }
 
     let Some(pos) = spawn_pos else {
         state.timer_secs = 0.8;
@@ -696,8 +1030,9 @@ fn enemy_spawn_system(
 
     let toward_player = (player_pos - pos).normalize_or_zero();
     let spawn_index = state.total_spawned;
-    let archetype = enemy_archetype_for_spawn(stage, wave_spawn_serial.unwrap_or(spawn_index));
-    let hp = enemy_hp_for_stage(&config, stage);
+    let archetype =
+        enemy_archetype_for_spawn(&config, stage, wave_spawn_serial.unwrap_or(spawn_index));
+    let hp = enemy_hp_for_stage(&config, stage) * archetype.hp_mult(&config);
     let fire_cooldown =
         enemy_fire_cooldown_for_archetype(enemy_fire_cooldown_for_stage(&config, stage), archetype);
 
@@ -744,6 +1079,11 @@ fn enemy_spawn_system(
             remaining_secs: 0.0,
         },
     ));
+    if archetype == EnemyArchetype::Miner {
+        commands
+            .entity(enemy_entity)
+            .insert(EnemyMinerHold::default());
+    }
 
     if let Some(ref mut wave) = wave_director {
         if wave.phase == CampaignWavePhase::ActiveWave {
@@ -765,6 +1105,138 @@ fn enemy_stun_tick_system(time: Res<Time>, mut q_enemy: Query<&mut EnemyStun, Wi
     }
 }
 
+/// Flag badly damaged enemies as retreating and retire the ones that escape.
+///
+/// An escape queues `enemy_reinforcements_per_escape` ships on the
+/// [`WaveReinforcementQueue`] while a wave director is running, so letting a
+/// wounded enemy go trades an easier wave now for a larger one later.
+/// Kamikaze drones never retreat.
+#[allow(clippy::type_complexity)]
+fn enemy_retreat_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    wave_director: Option<Res<CampaignWaveDirector>>,
+    mut reinforcements: Option<ResMut<WaveReinforcementQueue>>,
+    q_enemies: Query<
+        (
+            Entity,
+            &Transform,
+            &EnemyHealth,
+            Option<&EnemyArchetype>,
+            Option<&EnemyRetreating>,
+        ),
+        With<Enemy>,
+    >,
+) {
+    let director_running = wave_director
+        .as_deref()
+        .is_some_and(|wave| wave.phase != CampaignWavePhase::Inactive);
+    let escape_radius = config.enemy_retreat_escape_radius.max(1.0);
+
+    for (entity, transform, health, archetype, retreating) in q_enemies.iter() {
+        if retreating.is_none() {
+            if archetype != Some(&EnemyArchetype::Kamikaze)
+                && health.hp > 0.0
+                && health.hp <= health.max_hp * config.enemy_retreat_hp_fraction
+            {
+                commands.entity(entity).insert(EnemyRetreating).remove::<(
+                    EnemyFormationLeader,
+                    EnemyFormationMember,
+                    EnemyFormationTarget,
+                )>();
+            }
+            continue;
+        }
+
+        if transform.translation.truncate().length() < escape_radius {
+            continue;
+        }
+        commands.entity(entity).despawn();
+        if director_running {
+            if let Some(queue) = reinforcements.as_deref_mut() {
+                queue.call(
+                    config.enemy_reinforcements_per_escape,
+                    config.enemy_reinforcement_delay_secs,
+                );
+            }
+        }
+    }
+}
+
+/// Steering direction for a fleeing enemy: outward toward the field edge,
+/// biased away from the player.  When the player sits between the enemy and
+/// the edge, a sideways component grows so it swings around rather than
+/// fleeing through them.
+fn enemy_retreat_steer_dir(pos: Vec2, player_pos: Vec2) -> Vec2 {
+    let away_from_player = (pos - player_pos).normalize_or_zero();
+    let outward = pos.normalize_or(away_from_player);
+    let blocked = (-outward.dot(away_from_player)).max(0.0);
+    let mut sideways = Vec2::new(-outward.y, outward.x);
+    if sideways.dot(away_from_player) < 0.0 {
+        sideways = -sideways;
+    }
+    (outward + away_from_player * 0.5 + sideways * blocked).normalize_or(Vec2::Y)
+}
+
+/// Bend `steer_dir` around the most threatening obstacle on the enemy's path.
+///
+/// Obstacles are `(centre, bounding radius)` pairs.  Only bodies ahead of the
+/// enemy, within `lookahead` plus their radius, and whose bounding circle
+/// (grown by `clearance`) overlaps the projected path count.  The nearest one
+/// wins and pushes the ship sideways, harder the closer it is; a body dead
+/// ahead is passed on whichever side `steer_dir` already leans toward.
+fn enemy_avoidance_steer_dir(
+    pos: Vec2,
+    velocity: Vec2,
+    steer_dir: Vec2,
+    obstacles: &[(Vec2, f32)],
+    lookahead: f32,
+    clearance: f32,
+    weight: f32,
+) -> Vec2 {
+    let heading = velocity.try_normalize().unwrap_or(steer_dir);
+    let left = heading.perp();
+
+    let mut strongest: Option<(f32, Vec2)> = None;
+    for &(centre, radius) in obstacles {
+        let offset = centre - pos;
+        let ahead = offset.dot(heading);
+        let reach = radius + clearance;
+        if ahead <= 0.0 || ahead > lookahead + reach {
+            continue;
+        }
+        let lateral = offset.dot(left);
+        if lateral.abs() >= reach {
+            continue;
+        }
+
+        let threat = 1.0 - ahead / (lookahead + reach);
+        if strongest.is_some_and(|(best, _)| best >= threat) {
+            continue;
+        }
+        let push = if lateral.abs() > 1e-3 {
+            -left * lateral.signum()
+        } else if steer_dir.dot(left) >= 0.0 {
+            left
+        } else {
+            -left
+        };
+        strongest = Some((threat, push));
+    }
+
+    match strongest {
+        Some((threat, push)) => (steer_dir + push * (threat * weight)).normalize_or(steer_dir),
+        None => steer_dir,
+    }
+}
+
+/// Reused buffers for the per-enemy obstacle probe.
+#[derive(Default)]
+struct EnemyAvoidanceScratch {
+    neighbors: Vec<Entity>,
+    obstacles: Vec<(Vec2, f32)>,
+}
+
 #[allow(clippy::type_complexity)]
 fn enemy_formation_behavior_system(
     mut commands: Commands,
@@ -779,7 +1251,7 @@ fn enemy_formation_behavior_system(
             Option<&EnemyFormationMember>,
             Option<&EnemyStun>,
         ),
-        With<Enemy>,
+        (With<Enemy>, Without<EnemyRetreating>),
     >,
 ) {
     let formation_enabled = formation_enabled_for_wave(wave_director.as_deref());
@@ -816,14 +1288,11 @@ fn enemy_formation_behavior_system(
         let mut available: Vec<(Entity, Vec2, EnemyArchetype)> = q_enemies
             .iter()
             .filter_map(|(entity, transform, archetype, _, _, stun)| {
-                if stun.is_some_and(|s| s.remaining_secs > 0.0) {
+                let archetype = archetype.copied().unwrap_or(EnemyArchetype::Chaser);
+                if stun.is_some_and(|s| s.remaining_secs > 0.0) || !archetype.flies_in_formation() {
                     return None;
                 }
-                Some((
-                    entity,
-                    transform.translation.truncate(),
-                    archetype.copied().unwrap_or(EnemyArchetype::Chaser),
-                ))
+                Some((entity, transform.translation.truncate(), archetype))
             })
             .collect();
 
@@ -910,7 +1379,70 @@ fn enemy_formation_behavior_system(
     }
 }
 
+/// Send rival miners after ore pickups and pocket the ones they reach.
+///
+/// A miner targets the nearest pickup within `enemy_miner_seek_radius`; the
+/// seek system steers it there.  Once it holds `enemy_miner_capacity`
+/// pickups it retreats like a wounded ship and takes the ore off the field.
 #[allow(clippy::type_complexity)]
+fn enemy_miner_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    q_ore: Query<(Entity, &Transform, &OreKind), With<OrePickup>>,
+    mut q_miners: Query<
+        (
+            Entity,
+            &Transform,
+            &mut EnemyMinerHold,
+            &EnemyStun,
+            Has<EnemyRetreating>,
+        ),
+        (With<Enemy>, Without<OrePickup>),
+    >,
+) {
+    let seek_radius_sq = config.enemy_miner_seek_radius * config.enemy_miner_seek_radius;
+    let pickup_radius_sq = config.enemy_miner_pickup_radius * config.enemy_miner_pickup_radius;
+    let capacity = config.enemy_miner_capacity.max(1) as usize;
+    // Pickups grabbed this frame are still in the query until the despawn
+    // is applied.
+    let mut taken: Vec<Entity> = Vec::new();
+
+    for (entity, transform, mut hold, stun, retreating) in q_miners.iter_mut() {
+        hold.target = None;
+        if retreating || stun.remaining_secs > 0.0 {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        let nearest = q_ore
+            .iter()
+            .filter(|(ore, ..)| !taken.contains(ore))
+            .map(|(ore, ore_transform, &kind)| {
+                let ore_pos = ore_transform.translation.truncate();
+                (ore, ore_pos, kind, ore_pos.distance_squared(pos))
+            })
+            .filter(|&(.., dist_sq)| dist_sq <= seek_radius_sq)
+            .min_by(|a, b| a.3.total_cmp(&b.3));
+        let Some((ore, ore_pos, kind, dist_sq)) = nearest else {
+            continue;
+        };
+        if dist_sq > pickup_radius_sq {
+            hold.target = Some(ore_pos);
+            continue;
+        }
+        commands.entity(ore).despawn();
+        taken.push(ore);
+        hold.stolen.push(kind);
+        if hold.stolen.len() >= capacity {
+            commands.entity(entity).insert(EnemyRetreating).remove::<(
+                EnemyFormationLeader,
+                EnemyFormationMember,
+                EnemyFormationTarget,
+            )>();
+        }
+    }
+}
+
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 fn enemy_seek_player_system(
     mut commands: Commands,
     time: Res<Time>,
@@ -924,9 +1456,14 @@ fn enemy_seek_player_system(
             Option<&EnemyArchetype>,
             Option<&EnemyFormationTarget>,
             &mut EnemyThrustVfxTimer,
+            Option<&EnemyRetreating>,
+            Option<&EnemyMinerHold>,
         ),
         With<Enemy>,
     >,
+    q_obstacles: Query<(&Transform, &AsteroidSize, &Vertices, Has<Planet>), With<Asteroid>>,
+    grid: Res<SpatialGrid>,
+    mut scratch: Local<EnemyAvoidanceScratch>,
     config: Res<PhysicsConfig>,
 ) {
     const ENEMY_THRUST_PARTICLE_INTERVAL_SECS: f32 = 0.032;
@@ -937,8 +1474,17 @@ fn enemy_seek_player_system(
     let player_pos = player_transform.translation.truncate();
     let dt = time.delta_secs();
 
-    for (transform, mut force, mut velocity, stun, archetype, formation_target, mut thrust_vfx) in
-        q_enemy.iter_mut()
+    for (
+        transform,
+        mut force,
+        mut velocity,
+        stun,
+        archetype,
+        formation_target,
+        mut thrust_vfx,
+        retreating,
+        miner_hold,
+    ) in q_enemy.iter_mut()
     {
         if stun.remaining_secs > 0.0 {
             force.force = Vec2::ZERO;
@@ -957,8 +1503,13 @@ fn enemy_seek_player_system(
         let to_player_dir = to_player / dist;
         let mut steer_dir = to_player_dir;
         let mut thrust_factor = (dist / config.enemy_arrive_radius.max(1.0)).clamp(0.2, 1.0);
+        let mut seek_force = config.enemy_seek_force;
+        let mut max_speed = config.enemy_max_speed;
 
-        if let Some(target) = formation_target {
+        if retreating.is_some() {
+            steer_dir = enemy_retreat_steer_dir(pos, player_pos);
+            thrust_factor = 1.0;
+        } else if let Some(target) = formation_target {
             let to_anchor = target.world_anchor - pos;
             let anchor_dist = to_anchor.length();
             if anchor_dist > 1e-3 {
@@ -984,14 +1535,76 @@ fn enemy_seek_player_system(
             } else {
                 0.75
             };
+        } else if matches!(archetype, Some(EnemyArchetype::Kamikaze)) {
+            // Full thrust all the way in; the dash adds force and top speed.
+            thrust_factor = 1.0;
+            if dist <= config.enemy_kamikaze_dash_radius {
+                seek_force *= config.enemy_kamikaze_dash_force_mult;
+                max_speed *= config.enemy_kamikaze_dash_speed_mult;
+            }
+        } else if matches!(archetype, Some(EnemyArchetype::Miner)) {
+            if let Some(ore_pos) = miner_hold.and_then(|hold| hold.target) {
+                let to_ore = ore_pos - pos;
+                steer_dir = to_ore.normalize_or(to_player_dir);
+                thrust_factor =
+                    (to_ore.length() / config.enemy_arrive_radius.max(1.0) * 2.0).clamp(0.3, 1.0);
+            } else {
+                // Nothing to steal: hang back out of the ship's reach.
+                let standoff = config.enemy_miner_standoff_radius.max(1.0);
+                let tangential = Vec2::new(-to_player_dir.y, to_player_dir.x);
+                let radial_weight = if dist > standoff * 1.15 {
+                    0.8
+                } else if dist < standoff * 0.85 {
+                    -1.0
+                } else {
+                    0.0
+                };
+                steer_dir = (tangential * 0.6 + to_player_dir * radial_weight).normalize_or_zero();
+                thrust_factor = 0.7;
+            }
+        }
+
+        if config.enemy_avoidance_enabled {
+            let speed = velocity.linvel.length().max(config.enemy_max_speed * 0.5);
+            let lookahead = speed * config.enemy_avoidance_lookahead_secs;
+            let heading = velocity.linvel.try_normalize().unwrap_or(steer_dir);
+            let EnemyAvoidanceScratch {
+                neighbors,
+                obstacles,
+            } = &mut *scratch;
+            grid.ray_query(
+                pos,
+                heading,
+                lookahead,
+                ENEMY_AVOIDANCE_PROBE_MARGIN,
+                neighbors,
+            );
+            obstacles.clear();
+            obstacles.extend(neighbors.iter().filter_map(|&entity| {
+                let (obstacle_tf, size, vertices, is_planet) = q_obstacles.get(entity).ok()?;
+                if !is_planet && size.0 < config.enemy_avoidance_min_size {
+                    return None;
+                }
+                let radius = vertices.0.iter().map(|v| v.length()).fold(0.0, f32::max);
+                Some((obstacle_tf.translation.truncate(), radius))
+            }));
+            steer_dir = enemy_avoidance_steer_dir(
+                pos,
+                velocity.linvel,
+                steer_dir,
+                obstacles,
+                lookahead,
+                config.enemy_collider_radius + config.enemy_avoidance_clearance,
+                config.enemy_avoidance_weight,
+            );
         }
 
-        force.force = steer_dir * (config.enemy_seek_force * thrust_factor.clamp(0.25, 1.0));
+        force.force = steer_dir * (seek_force * thrust_factor.clamp(0.25, 1.0));
         force.torque = 0.0;
 
         let speed = velocity.linvel.length();
-        if speed > config.enemy_max_speed {
-            velocity.linvel = velocity.linvel.normalize_or_zero() * config.enemy_max_speed;
+        if speed > max_speed {
+            velocity.linvel = velocity.linvel.normalize_or_zero() * max_speed;
         }
 
         let target_angle = steer_dir.y.atan2(steer_dir.x) - std::f32::consts::FRAC_PI_2;
@@ -1023,6 +1636,93 @@ fn enemy_seek_player_system(
     }
 }
 
+/// Detonate kamikaze drones that reach the ship.
+///
+/// The blast deals `enemy_kamikaze_blast_damage`, falling off to 0 at
+/// `enemy_kamikaze_blast_radius`, and goes through the shield and the
+/// invincibility window like any other hit.  A drone that detonates is not a
+/// kill and scores nothing.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+fn enemy_kamikaze_detonation_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    q_enemies: Query<(Entity, &Transform, &EnemyArchetype), (With<Enemy>, Without<Player>)>,
+    mut q_player: Query<
+        (
+            Entity,
+            &Transform,
+            &mut PlayerHealth,
+            Option<&mut PlayerShield>,
+        ),
+        With<Player>,
+    >,
+    mut lives: ResMut<PlayerLives>,
+    mut score: ResMut<PlayerScore>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut damage_events: MessageWriter<PlayerDamaged>,
+) {
+    let Ok((player_entity, player_transform, mut health, mut shield)) = q_player.single_mut()
+    else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    for (entity, transform, archetype) in q_enemies.iter() {
+        if *archetype != EnemyArchetype::Kamikaze {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        let dist = pos.distance(player_pos);
+        if dist > config.enemy_kamikaze_trigger_radius {
+            continue;
+        }
+        commands.entity(entity).despawn();
+        play_sfx(&mut commands, Sfx::Explosion, 0.9);
+        spawn_shockwave_ring_particles(&mut commands, pos, config.enemy_kamikaze_blast_radius);
+        spawn_debris_particles(&mut commands, pos, Vec2::ZERO, 6);
+
+        if health.inv_timer > 0.0 {
+            continue;
+        }
+        let mut damage = config.enemy_kamikaze_blast_damage
+            * blast_falloff(dist, config.enemy_kamikaze_blast_radius)
+            * config.player_damage_taken_multiplier;
+        if let Some(shield) = shield.as_deref_mut() {
+            let forward = player_transform.rotation.mul_vec3(Vec3::Y).truncate();
+            damage = shield.absorb(
+                damage,
+                (pos - player_pos).normalize_or_zero(),
+                forward,
+                &config,
+            );
+        }
+        health.inv_timer = config.invincibility_duration;
+        if damage <= 0.0 {
+            continue;
+        }
+        health.hp -= damage;
+        health.time_since_damage = 0.0;
+        damage_events.write(PlayerDamaged {
+            amount: damage,
+            source: DamageSource::Enemy(EnemyArchetype::Kamikaze),
+            fatal: health.hp <= 0.0,
+        });
+        if health.hp <= 0.0 {
+            commands.entity(player_entity).despawn();
+            play_sfx(&mut commands, Sfx::Explosion, 1.0);
+            lives.remaining -= 1;
+            score.streak = 0;
+            if lives.remaining <= 0 {
+                lives.remaining = 0;
+                next_state.set(GameState::GameOver);
+            } else {
+                lives.respawn_timer = Some(config.respawn_delay_secs);
+            }
+            break;
+        }
+    }
+}
+
 #[allow(clippy::type_complexity)]
 fn enemy_fire_system(
     mut commands: Commands,
@@ -1040,6 +1740,7 @@ fn enemy_fire_system(
         With<Enemy>,
     >,
     q_enemy_projectiles: Query<(), With<EnemyProjectile>>,
+    q_outposts: Query<&GlobalTransform, With<MiningOutpost>>,
 ) {
     let Ok(player_transform) = q_player.single() else {
         return;
@@ -1052,6 +1753,9 @@ fn enemy_fire_system(
 
     for (transform, mut cooldown, stun, progression_stage, archetype) in q_enemy.iter_mut() {
         let archetype = archetype.copied().unwrap_or(EnemyArchetype::Chaser);
+        if !archetype.fires() {
+            continue;
+        }
         let enemy_stage = progression_stage.map_or(0, |stage| stage.stage);
         let base_cooldown = enemy_fire_cooldown_for_stage(&config, enemy_stage);
         let fire_cooldown = enemy_fire_cooldown_for_archetype(base_cooldown, archetype);
@@ -1069,14 +1773,22 @@ fn enemy_fire_system(
         }
 
         let enemy_pos = transform.translation.truncate();
-        let fire_dir = (player_pos - enemy_pos).normalize_or_zero();
+        let target_pos = enemy_fire_target(
+            enemy_pos,
+            player_pos,
+            q_outposts.iter().map(|g| g.translation().truncate()),
+            config.outpost_aggro_radius,
+        );
+        let fire_dir = (target_pos - enemy_pos).normalize_or_zero();
         if fire_dir.length_squared() <= 1e-5 {
             cooldown.timer = fire_cooldown;
             continue;
         }
 
         let (pattern, pattern_len) = match archetype {
-            EnemyArchetype::Chaser => ([0.0_f32, 0.0, 0.0], 1),
+            EnemyArchetype::Chaser | EnemyArchetype::Kamikaze | EnemyArchetype::Miner => {
+                ([0.0_f32, 0.0, 0.0], 1)
+            }
             EnemyArchetype::Skirmisher => {
                 if remaining_projectile_budget >= 3 {
                     ([-0.18_f32, 0.0, 0.18], 3)
@@ -1092,7 +1804,7 @@ fn enemy_fire_system(
             }
             let shot_dir = rotate_vec2(fire_dir, offset).normalize_or_zero();
             let spawn_pos = enemy_pos + shot_dir * (config.enemy_collider_radius + 6.0);
-            spawn_enemy_projectile(&mut commands, &config, spawn_pos, shot_dir);
+            spawn_enemy_projectile(&mut commands, &config, spawn_pos, shot_dir, Some(archetype));
             remaining_projectile_budget = remaining_projectile_budget.saturating_sub(1);
         }
 
@@ -1118,31 +1830,36 @@ fn despawn_old_enemy_projectiles_system(
     }
 }
 
-#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+/// Components [`apply_enemy_damage`] reads from a damaged enemy.
+type EnemyDamageTarget = (
+    Entity,
+    &'static mut EnemyHealth,
+    &'static Transform,
+    &'static Velocity,
+    Option<&'static EnemyTier>,
+    Option<&'static EnemyArchetype>,
+    Option<&'static EnemyMinerHold>,
+);
+
+#[allow(clippy::too_many_arguments)]
 fn apply_enemy_damage(
     commands: &mut Commands,
     score: &mut PlayerScore,
-    q_enemy: &mut Query<
-        (
-            Entity,
-            &mut EnemyHealth,
-            &Transform,
-            &Velocity,
-            Option<&EnemyTier>,
-        ),
-        With<Enemy>,
-    >,
-    damage_by_enemy: HashMap<Entity, f32>,
+    q_enemy: &mut Query<EnemyDamageTarget, With<Enemy>>,
+    kills: &mut MessageWriter<KillCredited>,
+    damage_by_enemy: HashMap<Entity, (f32, Actor)>,
     config: &PhysicsConfig,
     campaign_wave: Option<u32>,
-    kill_score: u32,
     award_score: bool,
+    rng: &mut impl Rng,
 ) {
-    for (enemy_entity, damage) in damage_by_enemy {
-        let Ok((entity, mut health, transform, velocity, tier)) = q_enemy.get_mut(enemy_entity)
+    for (enemy_entity, (damage, by)) in damage_by_enemy {
+        let Ok((entity, mut health, transform, velocity, tier, archetype, miner_hold)) =
+            q_enemy.get_mut(enemy_entity)
         else {
             continue;
         };
+        let archetype = archetype.copied().unwrap_or(EnemyArchetype::Chaser);
         health.hp -= damage;
         if health.hp <= 0.0 {
             let wave_index = campaign_wave.unwrap_or(1).max(1);
@@ -1153,12 +1870,34 @@ fn apply_enemy_damage(
                 transform.translation.truncate(),
                 velocity.linvel,
                 ore_drop_count,
+                config,
+                rng,
             );
+            // A rival miner drops everything it stole.
+            for (index, &kind) in miner_hold.iter().flat_map(|hold| &hold.stolen).enumerate() {
+                let radial = Vec2::from_angle(index as f32 * 2.4);
+                spawn_ore_drop(
+                    commands,
+                    transform.translation.truncate() + radial * 10.0,
+                    velocity.linvel + radial * 16.0,
+                    kind,
+                    config,
+                    rng,
+                );
+            }
             commands.entity(entity).despawn();
+            play_sfx(commands, Sfx::Explosion, 0.8);
             if award_score {
                 score.destroyed += 1;
-                score.points += kill_score;
+                score.points += archetype.kill_score(config);
             }
+            kills.write(KillCredited {
+                victim: KillVictim::Enemy(archetype),
+                by,
+                indirect: false,
+            });
+        } else {
+            commands.entity(entity).try_insert(LastDamagedBy(by));
         }
     }
 }
@@ -1200,7 +1939,14 @@ fn enemy_ore_drop_count(config: &PhysicsConfig, tier_level: u32, wave_index: u32
         .max(1)
 }
 
-fn spawn_enemy_ore_drops(commands: &mut Commands, pos: Vec2, velocity: Vec2, count: u32) {
+fn spawn_enemy_ore_drops(
+    commands: &mut Commands,
+    pos: Vec2,
+    velocity: Vec2,
+    count: u32,
+    config: &PhysicsConfig,
+    rng: &mut impl Rng,
+) {
     if count == 0 {
         return;
     }
@@ -1211,26 +1957,34 @@ fn spawn_enemy_ore_drops(commands: &mut Commands, pos: Vec2, velocity: Vec2, cou
         let tangential = Vec2::new(-radial.y, radial.x);
         let drop_pos = pos + radial * 8.0;
         let drop_vel = velocity + tangential * 12.0;
-        spawn_ore_drop(commands, drop_pos, drop_vel);
+        // Wrecks break up into scrap iron.
+        spawn_ore_drop(commands, drop_pos, drop_vel, OreKind::Iron, config, rng);
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn spawn_fragment_of_mass(
     commands: &mut Commands,
     pos: Vec2,
     velocity: Vec2,
     angvel: f32,
-    density: f32,
+    config: &PhysicsConfig,
     mass: u32,
-) {
-    let grey = 0.4 + rand::random::<f32>() * 0.4;
-    let verts = rescale_vertices_to_area(&canonical_vertices_for_mass(mass), mass as f32 / density);
+    material: AsteroidMaterial,
+    rng: &mut impl Rng,
+) -> Entity {
+    let grey = 0.4 + visual_rng().gen::<f32>() * 0.4;
+    let verts = fragment_vertices_for_mass(mass, rng, config);
     let ent =
         spawn_asteroid_with_vertices(commands, pos, &verts, Color::srgb(grey, grey, grey), mass);
-    commands.entity(ent).insert(Velocity {
-        linvel: velocity,
-        angvel,
-    });
+    commands.entity(ent).insert((
+        Velocity {
+            linvel: velocity,
+            angvel,
+        },
+        material,
+    ));
+    ent
 }
 
 #[inline]
@@ -1270,6 +2024,8 @@ fn primary_max_chip_size(
     }
 }
 
+/// Break an asteroid hit by an enemy shot.  Returns whether it was destroyed
+/// outright.
 #[allow(clippy::too_many_arguments)]
 fn apply_blaster_like_asteroid_hit(
     commands: &mut Commands,
@@ -1278,12 +2034,14 @@ fn apply_blaster_like_asteroid_hit(
     transform: &Transform,
     velocity: &Velocity,
     vertices: &Vertices,
+    material: AsteroidMaterial,
     proj_pos: Vec2,
     primary_weapon: CampaignPrimaryWeapon,
     weapon_tracks: &PrimaryWeaponUpgradeTracks,
     config: &PhysicsConfig,
     stats: &mut crate::simulation::SimulationStats,
-) {
+    rng: &mut impl Rng,
+) -> bool {
     let pos = transform.translation.truncate();
     let rot = transform.rotation;
     let vel = velocity.linvel;
@@ -1307,34 +2065,38 @@ fn apply_blaster_like_asteroid_hit(
         for i in 0..drop_count {
             let angle = std::f32::consts::TAU * (i as f32 / drop_count as f32);
             let offset = Vec2::new(angle.cos(), angle.sin()) * 6.0;
-            spawn_ore_drop(commands, pos + offset, vel);
+            spawn_ore_drop(
+                commands,
+                pos + offset,
+                vel,
+                material.ore_kind(),
+                config,
+                rng,
+            );
         }
         spawn_impact_particles(commands, proj_pos, impact_dir, vel);
         spawn_debris_particles(commands, pos, vel, n.max(1));
-        return;
+        return true;
     }
 
     if weapon_tracks.should_fragment_sub_chip_target(n) {
         commands.entity(asteroid_entity).despawn();
         stats.split_total += 1;
 
+        let lineage = MergeCooldown::from_split(asteroid_entity);
         for i in 0..n {
             let angle = std::f32::consts::TAU * (i as f32 / n as f32);
             let dir = Vec2::new(angle.cos(), angle.sin());
             let spawn_pos = pos + dir * 9.0;
             let spawn_vel = vel + dir * 24.0;
-            spawn_fragment_of_mass(
-                commands,
-                spawn_pos,
-                spawn_vel,
-                ang_vel,
-                config.asteroid_density,
-                1,
+            let fragment = spawn_fragment_of_mass(
+                commands, spawn_pos, spawn_vel, ang_vel, config, 1, material, rng,
             );
+            commands.entity(fragment).insert(lineage);
         }
 
         spawn_debris_particles(commands, pos, vel, n.min(6));
-        return;
+        return false;
     }
 
     spawn_impact_particles(commands, proj_pos, impact_dir, vel);
@@ -1351,7 +2113,6 @@ fn apply_blaster_like_asteroid_hit(
 
     let chip_pos = world_verts[closest_idx];
     let chip_dir = (chip_pos - pos).normalize_or_zero();
-    let mut rng = rand::thread_rng();
 
     let max_chip_size = primary_max_chip_size(primary_weapon, weapon_tracks, n);
     let chip_size = if max_chip_size <= 1 {
@@ -1362,14 +2123,11 @@ fn apply_blaster_like_asteroid_hit(
 
     let chip_vel =
         vel + chip_dir * 40.0 + Vec2::new(rng.gen_range(-15.0..15.0), rng.gen_range(-15.0..15.0));
-    spawn_fragment_of_mass(
-        commands,
-        chip_pos,
-        chip_vel,
-        0.0,
-        config.asteroid_density,
-        chip_size,
+    let lineage = MergeCooldown::from_split(asteroid_entity);
+    let chip = spawn_fragment_of_mass(
+        commands, chip_pos, chip_vel, 0.0, config, chip_size, material, rng,
     );
+    commands.entity(chip).insert(lineage);
 
     let n_verts = world_verts.len();
     let prev_idx = (closest_idx + n_verts - 1) % n_verts;
@@ -1403,7 +2161,7 @@ fn apply_blaster_like_asteroid_hit(
 
     commands.entity(asteroid_entity).despawn();
 
-    let grey = 0.4 + rand::random::<f32>() * 0.3;
+    let grey = 0.4 + visual_rng().gen::<f32>() * 0.3;
     let new_ent = spawn_asteroid_with_vertices(
         commands,
         hull_centroid,
@@ -1411,16 +2169,21 @@ fn apply_blaster_like_asteroid_hit(
         Color::srgb(grey, grey, grey),
         new_mass,
     );
-    commands.entity(new_ent).insert(Velocity {
-        linvel: vel,
-        angvel: ang_vel,
-    });
+    commands.entity(new_ent).insert((
+        Velocity {
+            linvel: vel,
+            angvel: ang_vel,
+        },
+        material,
+        lineage,
+    ));
     let preserved_transform =
         Transform::from_translation(hull_centroid.extend(0.05)).with_rotation(rot);
     commands.entity(new_ent).insert((
         preserved_transform,
         GlobalTransform::from(preserved_transform),
     ));
+    false
 }
 
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
@@ -1430,7 +2193,9 @@ fn boss_damage_from_player_weapons_system(
     mut q_boss: Query<(Entity, &mut BossHealth, &BossWeakpoint), With<Boss>>,
     mut q_projectiles: Query<(&Transform, &mut Projectile)>,
     q_missiles: Query<&Transform, With<Missile>>,
+    aim_memory: Option<ResMut<AimTargetMemory>>,
     config: Res<PhysicsConfig>,
+    mut session: MessageWriter<SessionStatEvent>,
 ) {
     let mut damage_by_boss: HashMap<Entity, f32> = HashMap::default();
 
@@ -1470,6 +2235,7 @@ fn boss_damage_from_player_weapons_system(
             let missile_pos = missile_transform.translation.truncate();
             spawn_impact_particles(&mut commands, missile_pos, Vec2::ZERO, Vec2::ZERO);
             commands.entity(other).despawn();
+            session.write(SessionStatEvent::Hit(StatWeapon::Missile));
             if weakpoint.exposed {
                 *damage_by_boss.entry(boss_entity).or_default() +=
                     config.boss_damage_from_player_missile;
@@ -1477,6 +2243,7 @@ fn boss_damage_from_player_weapons_system(
         }
     }
 
+    let mut survivor = None;
     for (boss_entity, damage) in damage_by_boss {
         let Ok((entity, mut health, _)) = q_boss.get_mut(boss_entity) else {
             continue;
@@ -1484,36 +2251,38 @@ fn boss_damage_from_player_weapons_system(
         health.hp -= damage;
         if health.hp <= 0.0 {
             commands.entity(entity).despawn();
+            play_sfx(&mut commands, Sfx::Explosion, 0.8);
+        } else {
+            survivor = Some(entity);
         }
     }
+    if let (Some(entity), Some(mut memory)) = (survivor, aim_memory) {
+        memory.target = Some(entity);
+    }
 }
 
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn enemy_damage_from_player_weapons_system(
     mut commands: Commands,
     mut collision_events: MessageReader<CollisionEvent>,
-    mut q_enemy: Query<
-        (
-            Entity,
-            &mut EnemyHealth,
-            &Transform,
-            &Velocity,
-            Option<&EnemyTier>,
-        ),
-        With<Enemy>,
-    >,
-    mut q_projectiles: Query<(&Transform, &mut Projectile)>,
+    mut q_enemy: Query<EnemyDamageTarget, With<Enemy>>,
+    mut q_projectiles: Query<(&Transform, &mut Projectile, Has<TurretRound>)>,
     q_missiles: Query<&Transform, With<Missile>>,
     mut score: ResMut<PlayerScore>,
+    mut rng: ResMut<GameplayRng>,
     weapon_tracks: Res<PrimaryWeaponUpgradeTracks>,
     selected_mode: Res<SelectedGameMode>,
     campaign_loadout: Res<CampaignLoadout>,
     missile_level: Res<SecondaryWeaponLevel>,
     wave_director: Option<Res<CampaignWaveDirector>>,
+    aim_memory: Option<ResMut<AimTargetMemory>>,
     config: Res<PhysicsConfig>,
+    mut session: MessageWriter<SessionStatEvent>,
+    mut kills: MessageWriter<KillCredited>,
 ) {
     let primary_weapon = active_primary_weapon(*selected_mode, &campaign_loadout);
-    let mut damage_by_enemy: HashMap<Entity, f32> = HashMap::default();
+    // Damage per enemy, credited to the last weapon that connected.
+    let mut damage_by_enemy: HashMap<Entity, (f32, Actor)> = HashMap::default();
 
     for event in collision_events.read() {
         let (e1, e2) = match event {
@@ -1533,12 +2302,22 @@ fn enemy_damage_from_player_weapons_system(
 
         let other = if enemy_entity == e1 { e2 } else { e1 };
 
-        if let Ok((projectile_transform, mut projectile)) = q_projectiles.get_mut(other) {
+        if let Ok((projectile_transform, mut projectile, turret_round)) =
+            q_projectiles.get_mut(other)
+        {
             projectile.was_hit = true;
             let proj_pos = projectile_transform.translation.truncate();
             spawn_impact_particles(&mut commands, proj_pos, Vec2::ZERO, Vec2::ZERO);
-            *damage_by_enemy.entry(enemy_entity).or_default() +=
-                projectile_damage_vs_enemy(&config, primary_weapon, &weapon_tracks);
+            let weapon = if turret_round {
+                StatWeapon::Turret
+            } else {
+                StatWeapon::Primary
+            };
+            let entry = damage_by_enemy
+                .entry(enemy_entity)
+                .or_insert((0.0, Actor::Player(weapon)));
+            entry.0 += projectile_damage_vs_enemy(&config, primary_weapon, &weapon_tracks);
+            entry.1 = Actor::Player(weapon);
             continue;
         }
 
@@ -1546,36 +2325,66 @@ fn enemy_damage_from_player_weapons_system(
             let missile_pos = missile_transform.translation.truncate();
             spawn_impact_particles(&mut commands, missile_pos, Vec2::ZERO, Vec2::ZERO);
             commands.entity(other).despawn();
-            *damage_by_enemy.entry(enemy_entity).or_default() +=
-                missile_damage_vs_enemy(&config, &missile_level);
+            session.write(SessionStatEvent::Hit(StatWeapon::Missile));
+            let by = Actor::Player(StatWeapon::Missile);
+            let entry = damage_by_enemy.entry(enemy_entity).or_insert((0.0, by));
+            entry.0 += missile_damage_vs_enemy(&config, &missile_level);
+            entry.1 = by;
         }
     }
 
+    // The hardest-hit enemy becomes the idle aim's snap target if it lives.
+    let hardest_hit = damage_by_enemy
+        .iter()
+        .max_by(|a, b| a.1 .0.total_cmp(&b.1 .0))
+        .map(|(&entity, _)| entity);
+
     apply_enemy_damage(
         &mut commands,
         &mut score,
         &mut q_enemy,
+        &mut kills,
         damage_by_enemy,
         &config,
         wave_director.as_ref().map(|wave| wave.current_wave.max(1)),
-        config.enemy_kill_score,
         true,
+        &mut *rng,
     );
+
+    if let (Some(entity), Some(mut memory)) = (hardest_hit, aim_memory) {
+        if q_enemy
+            .get(entity)
+            .is_ok_and(|(_, health, ..)| health.hp > 0.0)
+        {
+            memory.target = Some(entity);
+        }
+    }
 }
 
+#[allow(clippy::type_complexity)]
 fn enemy_collision_damage_system(
     mut commands: Commands,
-    mut q_enemy: Query<(Entity, &mut EnemyHealth, &Velocity), With<Enemy>>,
+    mut q_enemy: Query<
+        (
+            Entity,
+            &mut EnemyHealth,
+            &Velocity,
+            Option<&LastDamagedBy>,
+            Option<&EnemyArchetype>,
+        ),
+        With<Enemy>,
+    >,
     q_asteroid_vel: Query<&Velocity, With<Asteroid>>,
     rapier_context: ReadRapierContext,
     config: Res<PhysicsConfig>,
+    mut kills: MessageWriter<KillCredited>,
 ) {
     let Ok(rapier) = rapier_context.single() else {
         return;
     };
 
     let mut damage_by_enemy: HashMap<Entity, f32> = HashMap::default();
-    for (enemy_entity, _health, enemy_vel) in q_enemy.iter_mut() {
+    for (enemy_entity, _health, enemy_vel, ..) in q_enemy.iter_mut() {
         let mut total_damage = 0.0_f32;
 
         for contact_pair in rapier.contact_pairs_with(enemy_entity) {
@@ -1614,25 +2423,33 @@ fn enemy_collision_damage_system(
     }
 
     for (enemy_entity, damage) in damage_by_enemy {
-        let Ok((entity, mut health, _)) = q_enemy.get_mut(enemy_entity) else {
+        let Ok((entity, mut health, _, last, archetype)) = q_enemy.get_mut(enemy_entity) else {
             continue;
         };
         health.hp -= damage;
         if health.hp <= 0.0 {
             commands.entity(entity).despawn();
+            play_sfx(&mut commands, Sfx::Explosion, 0.8);
+            // Rammed into a rock: whoever softened it up gets the kill.
+            kills.write(KillCredited {
+                victim: KillVictim::Enemy(archetype.copied().unwrap_or(EnemyArchetype::Chaser)),
+                by: credit_for(last),
+                indirect: true,
+            });
         }
     }
 }
 
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn enemy_player_collision_damage_system(
     mut commands: Commands,
     mut q_player: Query<(Entity, &mut PlayerHealth, &Velocity), With<Player>>,
-    mut q_enemy: Query<(Entity, &mut EnemyHealth, &Velocity), With<Enemy>>,
+    mut q_enemy: Query<(Entity, &mut EnemyHealth, &Velocity, Option<&EnemyArchetype>), With<Enemy>>,
     rapier_context: ReadRapierContext,
     mut lives: ResMut<PlayerLives>,
     mut score: ResMut<PlayerScore>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut damage_events: MessageWriter<PlayerDamaged>,
     config: Res<PhysicsConfig>,
 ) {
     let Ok((player_entity, mut player_health, player_velocity)) = q_player.single_mut() else {
@@ -1664,7 +2481,7 @@ fn enemy_player_collision_damage_system(
             continue;
         };
 
-        let Ok((_, _, enemy_velocity)) = q_enemy.get(enemy_entity) else {
+        let Ok((_, _, enemy_velocity, _)) = q_enemy.get(enemy_entity) else {
             continue;
         };
 
@@ -1674,17 +2491,33 @@ fn enemy_player_collision_damage_system(
         }
 
         let overlap_damage = (rel_speed - config.damage_speed_threshold) * 0.35;
-        total_player_damage += overlap_damage;
+        total_player_damage += overlap_damage * config.player_damage_taken_multiplier;
         *damage_by_enemy.entry(enemy_entity).or_default() += overlap_damage;
     }
 
+    let player_hit = total_player_damage > 0.0 && player_health.inv_timer <= 0.0;
+    let fatal = player_hit && player_health.hp <= total_player_damage;
+    // The hardest rammer gets the kill when several collide at once.
+    let killer = damage_by_enemy
+        .iter()
+        .max_by(|a, b| a.1.total_cmp(b.1))
+        .map(|(&entity, _)| entity);
+
     for (enemy_entity, damage) in damage_by_enemy {
-        let Ok((entity, mut health, _)) = q_enemy.get_mut(enemy_entity) else {
+        let Ok((entity, mut health, _, archetype)) = q_enemy.get_mut(enemy_entity) else {
             continue;
         };
+        if player_hit {
+            damage_events.write(PlayerDamaged {
+                amount: damage * config.player_damage_taken_multiplier,
+                source: DamageSource::Enemy(archetype.copied().unwrap_or(EnemyArchetype::Chaser)),
+                fatal: fatal && killer == Some(enemy_entity),
+            });
+        }
         health.hp -= damage;
         if health.hp <= 0.0 {
             commands.entity(entity).despawn();
+            play_sfx(&mut commands, Sfx::Explosion, 0.8);
         }
     }
 
@@ -1695,6 +2528,7 @@ fn enemy_player_collision_damage_system(
 
         if player_health.hp <= 0.0 {
             commands.entity(player_entity).despawn();
+            play_sfx(&mut commands, Sfx::Explosion, 1.0);
             lives.remaining -= 1;
             score.streak = 0;
             if lives.remaining <= 0 {
@@ -1707,13 +2541,78 @@ fn enemy_player_collision_damage_system(
     }
 }
 
+/// Player blaster shots destroy enemy projectiles on contact.
+///
+/// Both shots are consumed: the enemy projectile is despawned and the player
+/// projectile is marked [`Projectile::was_hit`] (so it does not break the hit
+/// streak) for `despawn_old_projectiles_system` to remove.  A small spark
+/// marks the interception.  Runs before [`enemy_projectile_hit_system`] so an
+/// intercepted shot never reaches an asteroid or the player in the same
+/// frame.
+fn enemy_projectile_intercept_system(
+    mut commands: Commands,
+    mut collision_events: MessageReader<CollisionEvent>,
+    q_enemy_projectiles: Query<&Transform, With<EnemyProjectile>>,
+    mut q_projectiles: Query<(&Transform, &Velocity, &mut Projectile)>,
+) {
+    let mut intercepted: std::collections::HashSet<Entity> = Default::default();
+
+    for event in collision_events.read() {
+        let (e1, e2) = match event {
+            CollisionEvent::Started(e1, e2, _) => (*e1, *e2),
+            CollisionEvent::Stopped(..) => continue,
+        };
+
+        let (enemy_shot, player_shot) =
+            if q_enemy_projectiles.contains(e1) && q_projectiles.contains(e2) {
+                (e1, e2)
+            } else if q_enemy_projectiles.contains(e2) && q_projectiles.contains(e1) {
+                (e2, e1)
+            } else {
+                continue;
+            };
+
+        if intercepted.contains(&enemy_shot) {
+            continue;
+        }
+        let Ok((player_tf, player_vel, mut projectile)) = q_projectiles.get_mut(player_shot) else {
+            continue;
+        };
+        if projectile.was_hit {
+            continue;
+        }
+        projectile.was_hit = true;
+        intercepted.insert(enemy_shot);
+
+        let enemy_pos = q_enemy_projectiles
+            .get(enemy_shot)
+            .map(|t| t.translation.truncate())
+            .unwrap_or_else(|_| player_tf.translation.truncate());
+        let spark_pos = (enemy_pos + player_tf.translation.truncate()) * 0.5;
+        spawn_impact_particles(
+            &mut commands,
+            spark_pos,
+            -player_vel.linvel.normalize_or_zero(),
+            Vec2::ZERO,
+        );
+        commands.entity(enemy_shot).despawn();
+    }
+}
+
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 fn enemy_projectile_hit_system(
     mut commands: Commands,
     mut collision_events: MessageReader<CollisionEvent>,
-    q_enemy_projectiles: Query<&Transform, With<EnemyProjectile>>,
+    q_enemy_projectiles: Query<(&Transform, Option<&EnemyShotSource>), With<EnemyProjectile>>,
     q_asteroids: Query<
-        (&AsteroidSize, &Transform, &Velocity, &Vertices),
+        (
+            &AsteroidSize,
+            &Transform,
+            &Velocity,
+            &Vertices,
+            Option<&AsteroidMaterial>,
+            Has<Volatile>,
+        ),
         (With<Asteroid>, Without<Planet>),
     >,
     q_planets: Query<(), With<Planet>>,
@@ -1721,10 +2620,12 @@ fn enemy_projectile_hit_system(
     mut lives: ResMut<PlayerLives>,
     mut score: ResMut<PlayerScore>,
     mut stats: ResMut<crate::simulation::SimulationStats>,
+    mut rng: ResMut<GameplayRng>,
     weapon_tracks: Res<PrimaryWeaponUpgradeTracks>,
-    selected_mode: Res<SelectedGameMode>,
-    campaign_loadout: Res<CampaignLoadout>,
+    (selected_mode, campaign_loadout): (Res<SelectedGameMode>, Res<CampaignLoadout>),
     mut next_state: ResMut<NextState<GameState>>,
+    mut damage_events: MessageWriter<PlayerDamaged>,
+    mut kills: MessageWriter<KillCredited>,
     config: Res<PhysicsConfig>,
 ) {
     let Ok((player_entity, mut health)) = q_player.single_mut() else {
@@ -1755,34 +2656,49 @@ fn enemy_projectile_hit_system(
         processed_projectiles.insert(projectile_entity);
 
         let other = if projectile_entity == e1 { e2 } else { e1 };
-        let proj_pos = q_enemy_projectiles
+        let (proj_pos, shot_source) = q_enemy_projectiles
             .get(projectile_entity)
-            .map(|t| t.translation.truncate())
-            .unwrap_or(Vec2::ZERO);
+            .map(|(t, source)| (t.translation.truncate(), source.copied()))
+            .unwrap_or((Vec2::ZERO, None));
         commands.entity(projectile_entity).despawn();
 
         if q_planets.contains(other) {
             continue;
         }
 
-        if let Ok((size, transform, velocity, vertices)) = q_asteroids.get(other) {
+        if let Ok((size, transform, velocity, vertices, material, volatile)) =
+            q_asteroids.get(other)
+        {
             if processed_asteroids.contains(&other) {
                 continue;
             }
             processed_asteroids.insert(other);
-            apply_blaster_like_asteroid_hit(
+            if volatile {
+                ignite_volatile(&mut commands, other, 0.0, Actor::Enemy);
+                continue;
+            }
+            let destroyed = apply_blaster_like_asteroid_hit(
                 &mut commands,
                 other,
                 size,
                 transform,
                 velocity,
                 vertices,
+                material.copied().unwrap_or_default(),
                 proj_pos,
                 primary_weapon,
                 &weapon_tracks,
                 &config,
                 &mut stats,
+                &mut *rng,
             );
+            if destroyed {
+                kills.write(KillCredited {
+                    victim: KillVictim::Asteroid,
+                    by: Actor::Enemy,
+                    indirect: false,
+                });
+            }
             continue;
         }
 
@@ -1793,12 +2709,19 @@ fn enemy_projectile_hit_system(
         let impact_dir = (health.max_hp * Vec2::X).normalize_or_zero();
         spawn_impact_particles(&mut commands, proj_pos, impact_dir, Vec2::ZERO);
 
-        health.hp -= config.enemy_projectile_damage;
+        let damage = config.enemy_projectile_damage * config.player_damage_taken_multiplier;
+        health.hp -= damage;
         health.inv_timer = config.invincibility_duration;
         health.time_since_damage = 0.0;
+        damage_events.write(PlayerDamaged {
+            amount: damage,
+            source: shot_source.map_or(DamageSource::Boss, |s| DamageSource::Enemy(s.0)),
+            fatal: health.hp <= 0.0,
+        });
 
         if health.hp <= 0.0 {
             commands.entity(player_entity).despawn();
+            play_sfx(&mut commands, Sfx::Explosion, 1.0);
             lives.remaining -= 1;
             score.streak = 0;
             if lives.remaining <= 0 {
@@ -1815,6 +2738,7 @@ fn enemy_projectile_hit_system(
 fn attach_enemy_mesh_system(
     mut commands: Commands,
     query: Query<(Entity, &EnemyHealth, Option<&EnemyArchetype>), Added<EnemyRenderMarker>>,
+    palette: Res<Palette>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
@@ -1831,11 +2755,28 @@ fn attach_enemy_mesh_system(
                 Vec2::new(0.0, -8.0),
                 Vec2::new(8.0, 0.0),
             ],
+            // Notched arrowhead; the mesh fans from the nose.
+            EnemyArchetype::Kamikaze => vec![
+                Vec2::new(0.0, 13.0),
+                Vec2::new(-7.0, -8.0),
+                Vec2::new(0.0, -3.0),
+                Vec2::new(7.0, -8.0),
+            ],
+            // Squat hauler hull with a scoop at the nose.
+            EnemyArchetype::Miner => vec![
+                Vec2::new(-4.0, 9.0),
+                Vec2::new(-9.0, 4.0),
+                Vec2::new(-9.0, -5.0),
+                Vec2::new(-5.0, -9.0),
+                Vec2::new(5.0, -9.0),
+                Vec2::new(9.0, -5.0),
+                Vec2::new(9.0, 4.0),
+                Vec2::new(4.0, 9.0),
+            ],
         };
         let mesh = meshes.add(filled_polygon_mesh(&vertices));
         let hp_ratio = (health.hp / health.max_hp.max(1.0)).clamp(0.0, 1.0);
-        let tint = Color::srgb(0.75 + 0.20 * hp_ratio, 0.22 + 0.10 * hp_ratio, 0.22);
-        let mat = materials.add(ColorMaterial::from_color(tint));
+        let mat = materials.add(ColorMaterial::from_color(palette.enemy(hp_ratio)));
         commands
             .entity(entity)
             .insert((Mesh2d(mesh), MeshMaterial2d(mat)));
@@ -1845,6 +2786,7 @@ fn attach_enemy_mesh_system(
 fn attach_boss_mesh_system(
     mut commands: Commands,
     query: Query<(Entity, &BossHealth), Added<Boss>>,
+    palette: Res<Palette>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
@@ -1860,11 +2802,7 @@ fn attach_boss_mesh_system(
         ];
         let mesh = meshes.add(filled_polygon_mesh(&vertices));
         let hp_ratio = (health.hp / health.max_hp.max(1.0)).clamp(0.0, 1.0);
-        let mat = materials.add(ColorMaterial::from_color(Color::srgb(
-            0.70 + 0.18 * hp_ratio,
-            0.18 + 0.08 * hp_ratio,
-            0.14,
-        )));
+        let mat = materials.add(ColorMaterial::from_color(palette.boss(hp_ratio)));
         commands
             .entity(entity)
             .insert((Mesh2d(mesh), MeshMaterial2d(mat)));
@@ -1874,6 +2812,7 @@ fn attach_boss_mesh_system(
 fn attach_enemy_projectile_mesh_system(
     mut commands: Commands,
     query: Query<Entity, Added<EnemyProjectileRenderMarker>>,
+    palette: Res<Palette>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
@@ -1884,7 +2823,7 @@ fn attach_enemy_projectile_mesh_system(
             Vec2::new(2.5, -3.0),
         ];
         let mesh = meshes.add(filled_polygon_mesh(&vertices));
-        let mat = materials.add(ColorMaterial::from_color(Color::srgb(1.0, 0.45, 0.25)));
+        let mat = materials.add(ColorMaterial::from_color(palette.enemy_projectile));
         commands
             .entity(entity)
             .insert((Mesh2d(mesh), MeshMaterial2d(mat)));
@@ -1894,6 +2833,7 @@ fn attach_enemy_projectile_mesh_system(
 fn attach_enemy_health_bar_system(
     mut commands: Commands,
     query: Query<Entity, (Added<EnemyRenderMarker>, With<Enemy>)>,
+    palette: Res<Palette>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
@@ -1915,7 +2855,7 @@ fn attach_enemy_health_bar_system(
             },
         ));
 
-        let fill_material = materials.add(ColorMaterial::from_color(Color::srgb(0.25, 0.92, 0.25)));
+        let fill_material = materials.add(ColorMaterial::from_color(palette.health(1.0)));
         commands.spawn((
             Mesh2d(bar_mesh),
             MeshMaterial2d(fill_material.clone()),
@@ -1947,6 +2887,7 @@ fn sync_enemy_health_bar_system(
         (Entity, &EnemyHealthBarFill, &mut Transform),
         (With<EnemyHealthBarFill>, Without<EnemyHealthBarBg>),
     >,
+    palette: Res<Palette>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
     const BAR_HALF: f32 = 8.0;
@@ -1983,7 +2924,7 @@ fn sync_enemy_health_bar_system(
         transform.scale = Vec3::new(fill_w, BAR_HEIGHT, 1.0);
 
         if let Some(material) = materials.get_mut(&bar.material) {
-            material.color = Color::srgb(1.0 - hp_frac, hp_frac, 0.0);
+            material.color = palette.health(hp_frac);
         }
     }
 }
@@ -2000,12 +2941,18 @@ mod tests {
         app.add_plugins((MinimalPlugins, StatesPlugin));
         app.init_state::<GameState>();
         app.add_message::<CollisionEvent>();
+        app.add_message::<PlayerDamaged>();
+        app.add_message::<SessionStatEvent>();
+        app.add_message::<KillCredited>();
         app.insert_resource(PhysicsConfig::default());
         app.insert_resource(PlayerScore::default());
         app.insert_resource(PlayerLives::default());
         app.insert_resource(PrimaryWeaponUpgradeTracks::default());
         app.insert_resource(SecondaryWeaponLevel::default());
         app.insert_resource(SimulationStats::default());
+        app.insert_resource(SelectedGameMode::Practice);
+        app.insert_resource(CampaignLoadout::default());
+        app.init_resource::<GameplayRng>();
         app
     }
 
@@ -2023,6 +2970,130 @@ mod tests {
         assert!((a - b).length() < 1e-6);
     }
 
+    #[test]
+    fn enemy_spawns_stay_out_of_player_hauler_and_objective_zones() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let config = PhysicsConfig::default();
+        app.insert_resource(config.clone());
+        app.insert_resource(PlayerScore::default());
+        app.insert_resource(EnemySpawnState::default());
+        app.add_systems(Update, enemy_spawn_system);
+
+        // Block the first ring points the deterministic sequence would pick.
+        let ring = config.cull_distance * 0.92;
+        let world = app.world_mut();
+        world.spawn((Player, Transform::default()));
+        for index in 0..6_u64 {
+            let pos = deterministic_spawn_offset(index, ring).extend(0.0);
+            let transform = Transform::from_translation(pos);
+            match index % 3 {
+                0 => world.spawn((MiningDrone::default(), transform)),
+                1 => world.spawn((
+                    MiningOutpost::new(&config),
+                    transform,
+                    GlobalTransform::from(transform),
+                )),
+                _ => world.spawn((ShopBeacon, transform)),
+            };
+        }
+        let zones: Vec<SpawnExclusion> = world
+            .run_system_once(|exclusions: SpawnExclusions| exclusions.zones())
+            .unwrap();
+        assert_eq!(zones.len(), 7);
+
+        let mut spawned = 0;
+        for _ in 0..40 {
+            app.world_mut().resource_mut::<EnemySpawnState>().timer_secs = 0.0;
+            app.update();
+            let world = app.world_mut();
+            let enemies: Vec<(Entity, Vec2)> = world
+                .query_filtered::<(Entity, &Transform), With<Enemy>>()
+                .iter(world)
+                .map(|(entity, t)| (entity, t.translation.truncate()))
+                .collect();
+            for (entity, pos) in enemies {
+                assert!(
+                    spawn_point_is_clear(pos, &zones),
+                    "enemy spawned at {pos} inside an exclusion zone"
+                );
+                world.despawn(entity);
+                spawned += 1;
+            }
+        }
+        assert_eq!(spawned, 40, "the fallback search always finds a point");
+    }
+
+    #[test]
+    fn enemies_spawn_at_the_emptiest_sampled_ring_point() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        let config = PhysicsConfig::default();
+        app.insert_resource(config.clone());
+        app.insert_resource(PlayerScore::default());
+        app.insert_resource(EnemySpawnState::default());
+        app.add_systems(Update, enemy_spawn_system);
+
+        // Asteroids crowd the first three ring points; the fourth is open.
+        let ring = config.cull_distance * 0.92;
+        let mut density = DensityField::default();
+        density.refresh(
+            config.density_cell_size,
+            (0..3_u64).map(|index| (deterministic_spawn_offset(index, ring), 50.0)),
+        );
+        app.insert_resource(density);
+        app.world_mut().spawn((Player, Transform::default()));
+        app.world_mut().resource_mut::<EnemySpawnState>().timer_secs = 0.0;
+        app.update();
+
+        let world = app.world_mut();
+        let spawned: Vec<Vec2> = world
+            .query_filtered::<&Transform, With<Enemy>>()
+            .iter(world)
+            .map(|t| t.translation.truncate())
+            .collect();
+        assert_eq!(spawned.len(), 1);
+        assert!(spawned[0].distance(deterministic_spawn_offset(3, ring)) < 1e-3);
+        assert_eq!(
+            world.resource::<EnemySpawnState>().total_spawned,
+            u64::from(config.enemy_spawn_density_samples)
+        );
+    }
+
+    #[test]
+    fn fallback_search_moves_to_the_next_ring_when_one_is_blocked() {
+        let zones = [SpawnExclusion {
+            centre: Vec2::ZERO,
+            radius: 200.0,
+        }];
+        let pos = fallback_spawn_point(Vec2::ZERO, &[150.0, 300.0], &zones, |_| true).unwrap();
+        assert!((pos.length() - 300.0).abs() < 1e-3);
+
+        let left_only = fallback_spawn_point(Vec2::ZERO, &[300.0], &zones, |p| p.x < -100.0);
+        assert!(left_only.is_some_and(|p| p.x < -100.0));
+        assert_eq!(
+            fallback_spawn_point(Vec2::ZERO, &[150.0], &zones, |_| true),
+            None
+        );
+    }
+
+    #[test]
+    fn boss_spawn_moves_out_of_the_player_zone() {
+        let mut world = World::new();
+        let config = PhysicsConfig::default();
+        let zones = [SpawnExclusion {
+            centre: Vec2::ZERO,
+            radius: config.enemy_spawn_radius,
+        }];
+        let boss = spawn_campaign_boss(&mut world.commands(), &config, Vec2::ZERO, &zones, 1, 1);
+        world.flush();
+
+        let pos = world.get::<Transform>(boss).unwrap().translation.truncate();
+        assert!(spawn_point_is_clear(pos, &zones), "boss spawned at {pos}");
+    }
+
     #[test]
     fn spawn_profile_progression_increases_count_and_reduces_cooldown() {
         let cfg = PhysicsConfig::default();
@@ -2041,7 +3112,7 @@ mod tests {
         let t1 = initial_enemy_fire_timer(42, 2.0);
         let t2 = initial_enemy_fire_timer(42, 2.0);
         assert!((t1 - t2).abs() < 1e-6);
-        assert!(t1 >= 0.8 && t1 <= 2.0);
+        assert!((0.8..=2.0).contains(&t1));
     }
 
     #[test]
@@ -2063,6 +3134,7 @@ mod tests {
         app.init_state::<GameState>();
         app.insert_resource(Assets::<Mesh>::default());
         app.insert_resource(Assets::<ColorMaterial>::default());
+        app.init_resource::<Palette>();
         app.add_systems(
             Update,
             (attach_enemy_health_bar_system, sync_enemy_health_bar_system).chain(),
@@ -2139,11 +3211,238 @@ mod tests {
 
     #[test]
     fn enemy_archetype_varies_with_progression_stage_and_spawn_order() {
-        assert_eq!(enemy_archetype_for_spawn(0, 0), EnemyArchetype::Chaser);
-        assert_eq!(enemy_archetype_for_spawn(1, 1), EnemyArchetype::Chaser);
-        assert_eq!(enemy_archetype_for_spawn(2, 0), EnemyArchetype::Chaser);
-        assert_eq!(enemy_archetype_for_spawn(2, 1), EnemyArchetype::Skirmisher);
-        assert_eq!(enemy_archetype_for_spawn(3, 5), EnemyArchetype::Skirmisher);
+        let config = PhysicsConfig::default();
+        let counts = |stage: u32| {
+            let mut counts = [0_u32; EnemyArchetype::ALL.len()];
+            for serial in 0..1200 {
+                let archetype = enemy_archetype_for_spawn(&config, stage, serial);
+                assert_eq!(
+                    archetype,
+                    enemy_archetype_for_spawn(&config, stage, serial),
+                    "deterministic"
+                );
+                counts[archetype as usize] += 1;
+            }
+            counts
+        };
+
+        assert_eq!(counts(0), [1200, 0, 0, 0], "only chasers early on");
+        assert_eq!(counts(1), [1200, 0, 0, 0]);
+        let [chaser, skirmisher, kamikaze, miner] = counts(2);
+        assert_eq!(kamikaze, 0, "kamikazes unlock at stage 3");
+        assert!(chaser > 400 && skirmisher > 400 && miner > 150);
+        let [chaser, skirmisher, kamikaze, miner] = counts(3);
+        // Weights 4 : 4 : 2 : 2.
+        for (count, expected) in [
+            (chaser, 400),
+            (skirmisher, 400),
+            (kamikaze, 200),
+            (miner, 200),
+        ] {
+            assert!(count.abs_diff(expected) < 60, "{count} vs {expected}");
+        }
+
+        let chasers_only = PhysicsConfig {
+            enemy_weight_chaser: 0,
+            enemy_weight_skirmisher: 0,
+            enemy_weight_kamikaze: 0,
+            enemy_weight_miner: 0,
+            ..PhysicsConfig::default()
+        };
+        assert_eq!(
+            enemy_archetype_for_spawn(&chasers_only, 5, 7),
+            EnemyArchetype::Chaser,
+            "falls back to the chaser when every weight is 0"
+        );
+    }
+
+    #[test]
+    fn archetypes_score_and_fire_by_type() {
+        let config = PhysicsConfig::default();
+        assert_eq!(
+            EnemyArchetype::Chaser.kill_score(&config),
+            config.enemy_kill_score
+        );
+        assert_eq!(
+            EnemyArchetype::Kamikaze.kill_score(&config),
+            config.enemy_kamikaze_kill_score
+        );
+        assert_eq!(
+            EnemyArchetype::Miner.kill_score(&config),
+            config.enemy_miner_kill_score
+        );
+        assert!(EnemyArchetype::Skirmisher.fires());
+        assert!(!EnemyArchetype::Kamikaze.fires());
+        assert!(!EnemyArchetype::Miner.fires());
+        assert!(!EnemyArchetype::Miner.flies_in_formation());
+    }
+
+    #[test]
+    fn kamikaze_detonates_at_the_ship_and_damages_it() {
+        let mut app = enemy_collision_test_app();
+        app.add_systems(Update, enemy_kamikaze_detonation_system);
+
+        let config = PhysicsConfig::default();
+        let player = app
+            .world_mut()
+            .spawn((
+                Player,
+                PlayerHealth {
+                    hp: 100.0,
+                    max_hp: 100.0,
+                    inv_timer: 0.0,
+                    time_since_damage: 0.0,
+                },
+                Transform::default(),
+            ))
+            .id();
+        let far = app
+            .world_mut()
+            .spawn((
+                Enemy,
+                EnemyArchetype::Kamikaze,
+                Transform::from_xyz(config.enemy_kamikaze_trigger_radius + 40.0, 0.0, 0.0),
+            ))
+            .id();
+        let chaser = app
+            .world_mut()
+            .spawn((
+                Enemy,
+                EnemyArchetype::Chaser,
+                Transform::from_xyz(5.0, 0.0, 0.0),
+            ))
+            .id();
+        let near = app
+            .world_mut()
+            .spawn((
+                Enemy,
+                EnemyArchetype::Kamikaze,
+                Transform::from_xyz(10.0, 0.0, 0.0),
+            ))
+            .id();
+
+        app.update();
+
+        assert!(app.world().get_entity(near).is_err(), "detonated");
+        assert!(app.world().get_entity(far).is_ok(), "out of range");
+        assert!(
+            app.world().get_entity(chaser).is_ok(),
+            "only kamikazes detonate"
+        );
+        let expected = config.enemy_kamikaze_blast_damage
+            * blast_falloff(10.0, config.enemy_kamikaze_blast_radius)
+            * config.player_damage_taken_multiplier;
+        let health = app.world().get::<PlayerHealth>(player).unwrap();
+        assert!((100.0 - health.hp - expected).abs() < 1e-3);
+        assert!(health.inv_timer > 0.0);
+        let damaged: Vec<_> = app
+            .world()
+            .resource::<Messages<PlayerDamaged>>()
+            .iter_current_update_messages()
+            .map(|damage| damage.source)
+            .collect();
+        assert_eq!(damaged, vec![DamageSource::Enemy(EnemyArchetype::Kamikaze)]);
+        assert!(particle_count(app.world_mut()) > 0);
+    }
+
+    #[test]
+    fn miner_steals_ore_flees_when_full_and_drops_it_when_shot_down() {
+        use crate::mining::{OreKind, OrePickup};
+
+        let mut app = enemy_collision_test_app();
+        app.world_mut()
+            .resource_mut::<PhysicsConfig>()
+            .enemy_miner_capacity = 2;
+        app.add_systems(Update, enemy_miner_system);
+        app.add_systems(PostUpdate, enemy_damage_from_player_weapons_system);
+
+        let config = app.world().resource::<PhysicsConfig>().clone();
+        let miner = app
+            .world_mut()
+            .spawn((
+                Enemy,
+                EnemyArchetype::Miner,
+                EnemyMinerHold::default(),
+                EnemyStun {
+                    remaining_secs: 0.0,
+                },
+                EnemyHealth {
+                    hp: 1000.0,
+                    max_hp: 1000.0,
+                },
+                Transform::default(),
+                Velocity::zero(),
+            ))
+            .id();
+        let ore_at = |app: &mut App, x: f32, kind: OreKind| {
+            app.world_mut()
+                .spawn((OrePickup, kind, Transform::from_xyz(x, 0.0, 0.0)))
+                .id()
+        };
+        let within_reach = ore_at(&mut app, 5.0, OreKind::Rare);
+        let distant = ore_at(&mut app, 200.0, OreKind::Ice);
+        ore_at(
+            &mut app,
+            config.enemy_miner_seek_radius + 50.0,
+            OreKind::Iron,
+        );
+
+        app.update();
+        assert!(app.world().get_entity(within_reach).is_err(), "scooped up");
+        let hold = app.world().get::<EnemyMinerHold>(miner).unwrap();
+        assert_eq!(hold.stolen, vec![OreKind::Rare]);
+        assert_eq!(hold.target, None);
+
+        app.update();
+        let hold = app.world().get::<EnemyMinerHold>(miner).unwrap();
+        assert_eq!(
+            hold.target,
+            Some(Vec2::new(200.0, 0.0)),
+            "heads for the next"
+        );
+
+        app.world_mut()
+            .get_mut::<Transform>(miner)
+            .unwrap()
+            .translation
+            .x = 198.0;
+        app.update();
+        assert!(app.world().get_entity(distant).is_err());
+        assert!(
+            app.world().get::<EnemyRetreating>(miner).is_some(),
+            "flees once full"
+        );
+
+        app.world_mut().get_mut::<EnemyHealth>(miner).unwrap().hp = 1.0;
+        let projectile = app
+            .world_mut()
+            .spawn((Projectile::default(), Transform::from_xyz(198.0, 0.0, 0.0)))
+            .id();
+        app.world_mut().write_message(CollisionEvent::Started(
+            miner,
+            projectile,
+            bevy_rapier2d::rapier::geometry::CollisionEventFlags::empty(),
+        ));
+        app.update();
+
+        assert!(app.world().get_entity(miner).is_err());
+        let dropped: Vec<OreKind> = app
+            .world_mut()
+            .query_filtered::<&OreKind, With<OrePickup>>()
+            .iter(app.world())
+            .copied()
+            .collect();
+        let scrap = enemy_ore_drop_count(&config, 1, 1) as usize;
+        assert_eq!(
+            dropped.len(),
+            scrap + 2 + 1,
+            "scrap, the haul, and the far pickup"
+        );
+        assert!(dropped.contains(&OreKind::Rare) && dropped.contains(&OreKind::Ice));
+        assert_eq!(
+            app.world().resource::<PlayerScore>().points,
+            config.enemy_miner_kill_score
+        );
     }
 
     #[test]
@@ -2458,7 +3757,7 @@ mod tests {
     fn enemy_kill_spawns_scaled_ore_drops() {
         use crate::campaign::CampaignWaveDirector;
         use crate::campaign::CampaignWavePhase;
-        use crate::mining::OrePickup;
+        use crate::mining::{OreKind, OrePickup};
 
         let mut app = enemy_collision_test_app();
         app.add_systems(PostUpdate, enemy_damage_from_player_weapons_system);
@@ -2511,12 +3810,28 @@ mod tests {
         app.update();
 
         assert!(app.world().get_entity(enemy).is_err());
-        let ore_count = app
+        let drops: Vec<OreKind> = app
             .world_mut()
-            .query_filtered::<Entity, With<OrePickup>>()
+            .query_filtered::<&OreKind, With<OrePickup>>()
             .iter(app.world())
-            .count() as u32;
-        assert_eq!(ore_count, expected_ore);
+            .copied()
+            .collect();
+        assert_eq!(drops.len() as u32, expected_ore);
+        assert!(drops.iter().all(|kind| *kind == OreKind::Iron));
+        let kills: Vec<_> = app
+            .world()
+            .resource::<Messages<KillCredited>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        assert_eq!(
+            kills,
+            vec![KillCredited {
+                victim: KillVictim::Enemy(EnemyArchetype::Chaser),
+                by: Actor::Player(StatWeapon::Primary),
+                indirect: false,
+            }]
+        );
     }
 
     #[test]
@@ -2526,11 +3841,15 @@ mod tests {
 
         let enemy = app
             .world_mut()
-            .spawn(EnemyHealth {
-                hp: 100.0,
-                max_hp: 100.0,
-            })
-            .insert(Enemy)
+            .spawn((
+                Enemy,
+                EnemyHealth {
+                    hp: 100.0,
+                    max_hp: 100.0,
+                },
+                Transform::default(),
+                Velocity::zero(),
+            ))
             .id();
         let projectile = app
             .world_mut()
@@ -2550,6 +3869,10 @@ mod tests {
 
         let enemy_hp = app.world().get::<EnemyHealth>(enemy).map(|h| h.hp).unwrap();
         assert!(enemy_hp < 100.0);
+        assert_eq!(
+            app.world().get::<LastDamagedBy>(enemy),
+            Some(&LastDamagedBy(Actor::Player(StatWeapon::Primary)))
+        );
 
         let projectile_state = app.world().get::<Projectile>(projectile).unwrap();
         assert!(projectile_state.was_hit);
@@ -2557,6 +3880,43 @@ mod tests {
         assert!(particle_count(app.world_mut()) > 0);
     }
 
+    #[test]
+    fn player_projectile_intercepts_enemy_projectile() {
+        let mut app = enemy_collision_test_app();
+        app.add_systems(PostUpdate, enemy_projectile_intercept_system);
+
+        let enemy_shot = app
+            .world_mut()
+            .spawn((
+                EnemyProjectile {
+                    age: 0.0,
+                    distance_traveled: 0.0,
+                },
+                Transform::from_translation(Vec3::new(10.0, 0.0, 0.0)),
+            ))
+            .id();
+        let player_shot = app
+            .world_mut()
+            .spawn((
+                Projectile::default(),
+                Transform::from_translation(Vec3::new(8.0, 0.0, 0.0)),
+                Velocity::linear(Vec2::new(400.0, 0.0)),
+            ))
+            .id();
+
+        app.world_mut().write_message(CollisionEvent::Started(
+            player_shot,
+            enemy_shot,
+            bevy_rapier2d::rapier::geometry::CollisionEventFlags::empty(),
+        ));
+
+        app.update();
+
+        assert!(app.world().get_entity(enemy_shot).is_err());
+        assert!(app.world().get::<Projectile>(player_shot).unwrap().was_hit);
+        assert!(particle_count(app.world_mut()) > 0);
+    }
+
     #[test]
     fn player_missile_hit_enemy_despawns_missile_and_spawns_particles() {
         let mut app = enemy_collision_test_app();
@@ -2570,6 +3930,8 @@ mod tests {
                     hp: 120.0,
                     max_hp: 120.0,
                 },
+                Transform::default(),
+                Velocity::zero(),
             ))
             .id();
         let missile = app
@@ -2781,4 +4143,109 @@ mod tests {
             "replacement asteroid should preserve original orientation"
         );
     }
+
+    #[test]
+    fn wounded_enemy_retreats_and_escape_calls_reinforcements() {
+        let mut app = enemy_collision_test_app();
+        app.insert_resource(CampaignWaveDirector {
+            phase: CampaignWavePhase::ActiveWave,
+            ..Default::default()
+        });
+        app.insert_resource(WaveReinforcementQueue::default());
+        app.add_systems(Update, enemy_retreat_system);
+
+        let config = PhysicsConfig::default();
+        let enemy = app
+            .world_mut()
+            .spawn((
+                Enemy,
+                EnemyHealth {
+                    hp: config.enemy_base_hp * 0.2,
+                    max_hp: config.enemy_base_hp,
+                },
+                Transform::from_xyz(200.0, 0.0, 0.0),
+            ))
+            .id();
+
+        app.update();
+        assert!(app.world().get::<EnemyRetreating>(enemy).is_some());
+        assert_eq!(
+            app.world()
+                .resource::<WaveReinforcementQueue>()
+                .pending_count(),
+            0
+        );
+
+        app.world_mut()
+            .get_mut::<Transform>(enemy)
+            .unwrap()
+            .translation
+            .x = config.enemy_retreat_escape_radius + 10.0;
+        app.update();
+
+        assert!(app.world().get_entity(enemy).is_err());
+        assert_eq!(
+            app.world()
+                .resource::<WaveReinforcementQueue>()
+                .pending_count(),
+            config.enemy_reinforcements_per_escape
+        );
+    }
+
+    #[test]
+    fn retreat_steering_heads_outward_and_away_from_player() {
+        let dir = enemy_retreat_steer_dir(Vec2::new(500.0, 0.0), Vec2::new(400.0, 0.0));
+        assert!(dir.x > 0.99);
+
+        // Player outside the enemy: flee sideways rather than through them.
+        let dir = enemy_retreat_steer_dir(Vec2::new(500.0, 0.0), Vec2::new(600.0, 0.0));
+        assert!(dir.dot(Vec2::new(1.0, 0.0)) < 0.99);
+    }
+
+    #[test]
+    fn avoidance_steers_around_planet_on_path() {
+        let velocity = Vec2::new(150.0, 0.0);
+        let planet = [(Vec2::new(120.0, 8.0), 40.0)];
+
+        let dir =
+            enemy_avoidance_steer_dir(Vec2::ZERO, velocity, Vec2::X, &planet, 180.0, 30.0, 1.6);
+
+        // Planet sits slightly left of the path, so the ship veers right.
+        assert!(dir.y < -0.3, "expected a rightward swerve, got {dir:?}");
+        assert!(dir.x > 0.0);
+        assert!((dir.length() - 1.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn avoidance_ignores_bodies_behind_or_beside_the_path() {
+        let velocity = Vec2::new(150.0, 0.0);
+        let obstacles = [
+            (Vec2::new(-90.0, 0.0), 40.0),
+            (Vec2::new(100.0, 120.0), 40.0),
+            (Vec2::new(600.0, 0.0), 40.0),
+        ];
+
+        let dir =
+            enemy_avoidance_steer_dir(Vec2::ZERO, velocity, Vec2::X, &obstacles, 180.0, 30.0, 1.6);
+
+        assert_eq!(dir, Vec2::X);
+    }
+
+    #[test]
+    fn avoidance_passes_dead_ahead_body_on_seek_side() {
+        let seek = Vec2::new(1.0, 0.2).normalize();
+        let planet = [(Vec2::new(100.0, 0.0), 40.0)];
+
+        let dir = enemy_avoidance_steer_dir(
+            Vec2::ZERO,
+            Vec2::new(150.0, 0.0),
+            seek,
+            &planet,
+            180.0,
+            30.0,
+            1.6,
+        );
+
+        assert!(dir.y > seek.y);
+    }
 }
@@ -0,0 +1,347 @@
+//! Mass-scaled feedback for asteroid merges, splits and destroys.
+//!
+//! Gameplay systems report what happened with a [`FeedbackEvent`] (kind,
+//! position, velocity and the mass involved) instead of picking particle
+//! counts and sound levels themselves.  [`feedback_system`] maps each event
+//! through one intensity curve, [`feedback_levels`], onto every channel:
+//!
+//! | Channel | At intensity `i` |
+//! |---------|------------------|
+//! | Particles | `feedback_particles_min` → `feedback_particles_max` |
+//! | Sound gain | `i × feedback_sfx_max_gain`; silent below `feedback_sfx_min_intensity` |
+//! | Sound pitch | `feedback_pitch_small` → `feedback_pitch_large` |
+//! | Screen shake | `i² × feedback_shake_max_trauma`, fading out by `feedback_shake_radius` from the camera |
+//! | Hit-stop | `i × feedback_hit_stop_max_secs` at or above `feedback_hit_stop_min_intensity` |
+//!
+//! `i = weight × √(mass / feedback_reference_mass)`, clamped to `[0, 1]`, with
+//! a per-kind weight.  All of it is tunable under **Feedback Intensity** in
+//! `assets/physics.toml`.
+//!
+//! [`ScreenShake`] trauma decays in real time and moves the camera through
+//! `CameraState::shake_offset`; REDUCE MOTION (`OverlayState::reduce_motion`)
+//! keeps the camera still.  Player damage adds trauma too, from
+//! `graphics::feedback`.  [`HitStop`] slows `Time<Virtual>` (and with
+//! it the physics tick) for a few real-time frames; see
+//! `simulation::time_control`.
+
+use crate::audio::{play_sfx_pitched, Sfx};
+use crate::config::PhysicsConfig;
+use crate::menu::GameState;
+use crate::particles::{spawn_debris_burst, spawn_merge_particles};
+use crate::player::camera_follow_system;
+use crate::rendering::OverlayState;
+use crate::simulation::time_control::time_control_system;
+use crate::simulation::CameraState;
+use bevy::prelude::*;
+
+/// What produced a [`FeedbackEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum FeedbackKind {
+    /// Bodies merged; mass is the merged body.
+    Merge,
+    /// A body split or decomposed into fragments; mass is the parent.
+    Split,
+    /// A body was destroyed outright.
+    Destroy,
+}
+
+/// One merge, split or destroy to give feedback for.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct FeedbackEvent {
+    pub kind: FeedbackKind,
+    pub pos: Vec2,
+    /// Velocity of the body involved; debris inherits part of it.
+    pub vel: Vec2,
+    pub mass: u32,
+}
+
+/// Every channel's output for one event.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FeedbackLevels {
+    pub intensity: f32,
+    pub particles: u32,
+    /// Effect gain; `0` plays nothing.
+    pub gain: f32,
+    /// Playback speed multiplier.
+    pub pitch: f32,
+    /// Screen-shake trauma at the event (before distance falloff).
+    pub shake_trauma: f32,
+    /// Real-time hit-stop length; `0` for none.
+    pub hit_stop_secs: f32,
+}
+
+/// Event intensity in `[0, 1]`.
+pub fn feedback_intensity(config: &PhysicsConfig, kind: FeedbackKind, mass: u32) -> f32 {
+    let weight = match kind {
+        FeedbackKind::Merge => config.feedback_merge_weight,
+        FeedbackKind::Split => config.feedback_split_weight,
+        FeedbackKind::Destroy => config.feedback_destroy_weight,
+    };
+    let reference = config.feedback_reference_mass.max(1.0);
+    (weight * (mass as f32 / reference).sqrt()).clamp(0.0, 1.0)
+}
+
+/// Map an event onto every feedback channel.
+pub fn feedback_levels(config: &PhysicsConfig, kind: FeedbackKind, mass: u32) -> FeedbackLevels {
+    let intensity = feedback_intensity(config, kind, mass);
+    let min = config.feedback_particles_min as f32;
+    let max = (config.feedback_particles_max as f32).max(min);
+    let audible = intensity >= config.feedback_sfx_min_intensity;
+    let stops = intensity >= config.feedback_hit_stop_min_intensity;
+    FeedbackLevels {
+        intensity,
+        particles: (min + (max - min) * intensity).round() as u32,
+        gain: if audible {
+            intensity * config.feedback_sfx_max_gain
+        } else {
+            0.0
+        },
+        pitch: config
+            .feedback_pitch_small
+            .lerp(config.feedback_pitch_large, intensity),
+        shake_trauma: intensity * intensity * config.feedback_shake_max_trauma,
+        hit_stop_secs: if stops {
+            intensity * config.feedback_hit_stop_max_secs
+        } else {
+            0.0
+        },
+    }
+}
+
+/// Accumulated camera shake.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct ScreenShake {
+    /// `0..=1`; the camera offset grows with `trauma²`.
+    pub trauma: f32,
+}
+
+impl ScreenShake {
+    pub fn add(&mut self, trauma: f32) {
+        self.trauma = (self.trauma + trauma.max(0.0)).min(1.0);
+    }
+}
+
+/// Brief simulation slow-down after a heavy event.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct HitStop {
+    /// Real-time seconds left (`0` = inactive).
+    pub remaining_secs: f32,
+    /// Simulation speed while active.
+    pub time_scale: f32,
+}
+
+impl HitStop {
+    /// Start a hit-stop, or extend the running one.
+    pub fn trigger(&mut self, secs: f32, time_scale: f32) {
+        if secs <= 0.0 {
+            return;
+        }
+        self.remaining_secs = self.remaining_secs.max(secs);
+        self.time_scale = time_scale.clamp(0.0, 1.0);
+    }
+
+    /// Factor applied to the simulation speed; `1` when inactive.
+    #[inline]
+    pub fn speed_factor(&self) -> f32 {
+        if self.remaining_secs > 0.0 {
+            self.time_scale
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Turn this frame's [`FeedbackEvent`]s into particles, sound, shake and
+/// hit-stop, loudest first so the per-frame sound budget keeps the big ones.
+pub fn feedback_system(
+    mut commands: Commands,
+    mut events: MessageReader<FeedbackEvent>,
+    config: Res<PhysicsConfig>,
+    mut shake: ResMut<ScreenShake>,
+    mut hit_stop: ResMut<HitStop>,
+    q_camera: Query<&Transform, With<Camera>>,
+) {
+    let mut batch: Vec<(FeedbackEvent, FeedbackLevels)> = events
+        .read()
+        .map(|event| (*event, feedback_levels(&config, event.kind, event.mass)))
+        .collect();
+    if batch.is_empty() {
+        return;
+    }
+    batch.sort_by(|a, b| b.1.intensity.total_cmp(&a.1.intensity));
+
+    let camera = q_camera.single().ok().map(|t| t.translation.truncate());
+    let radius = config.feedback_shake_radius.max(1.0);
+    for (event, levels) in batch {
+        match event.kind {
+            FeedbackKind::Merge => {
+                spawn_merge_particles(&mut commands, event.pos, levels.particles)
+            }
+            FeedbackKind::Split | FeedbackKind::Destroy => {
+                spawn_debris_burst(&mut commands, event.pos, event.vel, levels.particles)
+            }
+        }
+        if levels.gain > 0.0 {
+            let sfx = match event.kind {
+                FeedbackKind::Merge => Sfx::Impact,
+                FeedbackKind::Split | FeedbackKind::Destroy => Sfx::Explosion,
+            };
+            play_sfx_pitched(&mut commands, sfx, levels.gain, levels.pitch);
+        }
+        if let Some(camera) = camera {
+            let falloff = (1.0 - camera.distance(event.pos) / radius).max(0.0);
+            shake.add(levels.shake_trauma * falloff);
+        }
+        hit_stop.trigger(levels.hit_stop_secs, config.feedback_hit_stop_time_scale);
+    }
+}
+
+/// Count the hit-stop down in real time.
+pub fn hit_stop_tick_system(time: Res<Time<Real>>, mut hit_stop: ResMut<HitStop>) {
+    if hit_stop.remaining_secs > 0.0 {
+        hit_stop.remaining_secs = (hit_stop.remaining_secs - time.delta_secs()).max(0.0);
+    }
+}
+
+/// Decay trauma and set the camera's shake offset for this frame.
+pub fn screen_shake_system(
+    time: Res<Time<Real>>,
+    config: Res<PhysicsConfig>,
+    overlay: Res<OverlayState>,
+    mut shake: ResMut<ScreenShake>,
+    mut camera_state: ResMut<CameraState>,
+) {
+    if overlay.reduce_motion {
+        shake.trauma = 0.0;
+    }
+    let offset = if shake.trauma > 0.0 {
+        let t = time.elapsed_secs();
+        let amplitude = shake.trauma * shake.trauma * config.feedback_shake_max_offset;
+        shake.trauma =
+            (shake.trauma - config.feedback_shake_decay_per_sec * time.delta_secs()).max(0.0);
+        // Two incommensurate sines per axis: cheap, smooth, never repeats visibly.
+        Vec2::new(
+            (t * 47.0).sin() * 0.7 + (t * 31.0).cos() * 0.3,
+            (t * 53.0).cos() * 0.7 + (t * 29.0).sin() * 0.3,
+        ) * amplitude
+    } else {
+        Vec2::ZERO
+    };
+    if camera_state.shake_offset != offset {
+        camera_state.shake_offset = offset;
+    }
+}
+
+/// Clear shake and hit-stop when a session ends.
+pub fn reset_feedback_system(
+    mut shake: ResMut<ScreenShake>,
+    mut hit_stop: ResMut<HitStop>,
+    mut camera_state: ResMut<CameraState>,
+) {
+    *shake = ScreenShake::default();
+    *hit_stop = HitStop::default();
+    camera_state.shake_offset = Vec2::ZERO;
+}
+
+pub struct FeedbackPlugin;
+
+impl Plugin for FeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<FeedbackEvent>()
+            .init_resource::<ScreenShake>()
+            .init_resource::<HitStop>()
+            .add_systems(
+                Update,
+                (
+                    feedback_system,
+                    hit_stop_tick_system.before(time_control_system),
+                    screen_shake_system.before(camera_follow_system),
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), reset_feedback_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intensity_grows_with_mass_and_saturates() {
+        let config = PhysicsConfig::default();
+        let small = feedback_intensity(&config, FeedbackKind::Destroy, 1);
+        let medium = feedback_intensity(&config, FeedbackKind::Destroy, 10);
+        let reference = config.feedback_reference_mass as u32;
+        assert!(small < medium);
+        assert_eq!(
+            feedback_intensity(&config, FeedbackKind::Destroy, reference),
+            1.0
+        );
+        assert_eq!(
+            feedback_intensity(&config, FeedbackKind::Destroy, reference * 10),
+            1.0
+        );
+        // Merges of the same mass are gentler than destroys.
+        assert!(feedback_intensity(&config, FeedbackKind::Merge, 10) < medium);
+    }
+
+    #[test]
+    fn channels_scale_together() {
+        let config = PhysicsConfig::default();
+        let small = feedback_levels(&config, FeedbackKind::Split, 1);
+        let large = feedback_levels(&config, FeedbackKind::Split, 60);
+
+        assert!(small.particles < large.particles);
+        assert!(large.particles <= config.feedback_particles_max);
+        assert_eq!(small.gain, 0.0, "a unit split is below the audible floor");
+        assert!(large.gain > 0.0);
+        assert!(small.pitch > large.pitch, "bigger bodies sound lower");
+        assert!(small.shake_trauma < large.shake_trauma);
+        assert_eq!(small.hit_stop_secs, 0.0);
+        assert!(large.hit_stop_secs > 0.0);
+    }
+
+    #[test]
+    fn events_add_shake_and_hit_stop_near_the_camera() {
+        let mut app = App::new();
+        app.insert_resource(PhysicsConfig::default())
+            .add_message::<FeedbackEvent>()
+            .init_resource::<ScreenShake>()
+            .init_resource::<HitStop>()
+            .add_systems(Update, feedback_system);
+        app.world_mut().spawn((Camera2d, Transform::default()));
+
+        let far = PhysicsConfig::default().feedback_shake_radius * 2.0;
+        app.world_mut().write_message(FeedbackEvent {
+            kind: FeedbackKind::Destroy,
+            pos: Vec2::new(far, 0.0),
+            vel: Vec2::ZERO,
+            mass: 80,
+        });
+        app.update();
+        assert_eq!(app.world().resource::<ScreenShake>().trauma, 0.0);
+        assert!(app.world().resource::<HitStop>().remaining_secs > 0.0);
+
+        app.world_mut().write_message(FeedbackEvent {
+            kind: FeedbackKind::Destroy,
+            pos: Vec2::new(50.0, 0.0),
+            vel: Vec2::ZERO,
+            mass: 80,
+        });
+        app.update();
+        assert!(app.world().resource::<ScreenShake>().trauma > 0.0);
+    }
+
+    #[test]
+    fn hit_stop_extends_but_never_shortens() {
+        let mut hit_stop = HitStop::default();
+        assert_eq!(hit_stop.speed_factor(), 1.0);
+        hit_stop.trigger(0.08, 0.05);
+        hit_stop.trigger(0.02, 0.05);
+        assert_eq!(hit_stop.remaining_secs, 0.08);
+        assert_eq!(hit_stop.speed_factor(), 0.05);
+    }
+}
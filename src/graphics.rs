@@ -3,6 +3,17 @@ use std::collections::HashSet;
 use std::fs;
 use ttf_parser::Face;
 
+#[path = "graphics/cinematic.rs"]
+pub mod cinematic;
+#[path = "graphics/feedback.rs"]
+pub mod feedback;
+#[path = "graphics/merge_animation.rs"]
+pub mod merge_animation;
+#[path = "graphics/palette.rs"]
+pub mod palette;
+#[path = "graphics/starfield.rs"]
+pub mod starfield;
+
 fn preferred_unicode_fallback() -> (&'static str, &'static str, &'static str) {
     if fs::metadata("assets/fonts/NotoSans-Regular.ttf").is_ok() {
         (
@@ -206,7 +217,13 @@ pub fn log_font_substitution_diagnostics() {
 
 /// Setup camera for 2D rendering
 pub fn setup_camera(mut commands: Commands) {
-    // Default Camera2d with default scale shows roughly the full window area
-    commands.spawn(Camera2d);
+    // Default Camera2d with default scale shows roughly the full window area.
+    // The lens list is filled by `lensing::update_gravity_lensing_system`,
+    // the aberration pulse by `feedback::chromatic_aberration_system`.
+    commands.spawn((
+        Camera2d,
+        crate::lensing::GravityLensing::default(),
+        feedback::ChromaticAberration::default(),
+    ));
     eprintln!("[SETUP] Camera spawned");
 }
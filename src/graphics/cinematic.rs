@@ -0,0 +1,368 @@
+//! Camera cinematics: short scripted camera moves along a spline.
+//!
+//! A [`CinematicRequest`] names a focus and a [`CameraSpline`] of keys
+//! relative to it (offset, zoom, roll).  [`cinematic_camera_system`] runs
+//! after the follow and zoom systems and, while a cinematic plays, overwrites
+//! the camera transform with the spline sampled at the elapsed fraction.
+//! When it ends (or Enter skips it) the roll is cleared, the follow camera
+//! takes over again next frame, and a [`CinematicFinished`] is written with
+//! the request's [`CinematicPurpose`].
+//!
+//! Requesters:
+//!
+//! | Requester | Purpose | Focus |
+//! |-----------|---------|-------|
+//! | `victory::victory_check_system` | [`CinematicPurpose::Victory`] | The dominant body |
+//! | `CampaignScriptAction::Cinematic` | [`CinematicPurpose::Script`] | Whatever the mission names |
+//!
+//! Cinematics run on real time, so hit-stop and time control do not stretch
+//! them; the world keeps simulating underneath.  A new request replaces the
+//! one playing.
+
+use crate::asteroid::{Asteroid, AsteroidSize, Planet};
+use crate::config::PhysicsConfig;
+use crate::menu::GameState;
+use crate::player::Player;
+use crate::simulation::camera_zoom_system;
+use bevy::prelude::*;
+use std::f32::consts::TAU;
+
+/// Keys in an orbit built by [`CameraSpline::orbit`].
+const ORBIT_KEYS: usize = 9;
+
+/// One key of a camera spline, relative to the focus.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CameraKey {
+    /// Camera position minus the focus position (world units).
+    pub offset: Vec2,
+    /// Camera scale (larger = further out).
+    pub zoom: f32,
+    /// Camera roll (radians).
+    pub angle: f32,
+}
+
+/// Evenly timed keys, interpolated with a Catmull-Rom curve that passes
+/// through every key.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CameraSpline {
+    pub keys: Vec<CameraKey>,
+}
+
+impl CameraSpline {
+    /// Circle the focus `turns` times at `radius`, zooming from `zoom_start`
+    /// to `zoom_end`.  The roll follows the orbit so the focus turns beneath
+    /// the camera.
+    pub fn orbit(radius: f32, turns: f32, zoom_start: f32, zoom_end: f32) -> Self {
+        let keys = (0..ORBIT_KEYS)
+            .map(|i| {
+                let t = i as f32 / (ORBIT_KEYS - 1) as f32;
+                let angle = turns * TAU * t;
+                CameraKey {
+                    offset: Vec2::from_angle(angle - TAU / 4.0) * radius,
+                    zoom: zoom_start + (zoom_end - zoom_start) * t,
+                    angle,
+                }
+            })
+            .collect();
+        Self { keys }
+    }
+
+    /// The orbit described by the `cinematic_orbit_*` and `cinematic_zoom_*`
+    /// tunables.
+    pub fn orbit_from_config(config: &PhysicsConfig) -> Self {
+        Self::orbit(
+            config.cinematic_orbit_radius,
+            config.cinematic_orbit_turns,
+            config.cinematic_zoom_start,
+            config.cinematic_zoom_end,
+        )
+    }
+
+    /// The camera at `t` in `[0, 1]`; `None` without keys.
+    pub fn sample(&self, t: f32) -> Option<CameraKey> {
+        let last = self.keys.len().checked_sub(1)?;
+        if last == 0 {
+            return Some(self.keys[0]);
+        }
+        let pos = t.clamp(0.0, 1.0) * last as f32;
+        let i = (pos.floor() as usize).min(last - 1);
+        let u = pos - i as f32;
+        let key = |k: isize| self.keys[k.clamp(0, last as isize) as usize];
+        let i = i as isize;
+        let (k0, k1, k2, k3) = (key(i - 1), key(i), key(i + 1), key(i + 2));
+        Some(CameraKey {
+            offset: catmull_rom(k0.offset, k1.offset, k2.offset, k3.offset, u),
+            zoom: catmull_rom(k0.zoom, k1.zoom, k2.zoom, k3.zoom, u).max(0.01),
+            angle: catmull_rom(k0.angle, k1.angle, k2.angle, k3.angle, u),
+        })
+    }
+}
+
+fn catmull_rom<T>(p0: T, p1: T, p2: T, p3: T, u: f32) -> T
+where
+    T: Copy
+        + std::ops::Add<Output = T>
+        + std::ops::Sub<Output = T>
+        + std::ops::Mul<f32, Output = T>,
+{
+    let u2 = u * u;
+    let u3 = u2 * u;
+    (p1 * 2.0
+        + (p2 - p0) * u
+        + (p0 * 2.0 - p1 * 5.0 + p2 * 4.0 - p3) * u2
+        + (p1 * 3.0 - p0 - p2 * 3.0 + p3) * u3)
+        * 0.5
+}
+
+/// What a cinematic circles.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CinematicFocus {
+    /// The player ship.
+    Ship,
+    /// The most massive asteroid (planets excluded) when the cinematic starts.
+    LargestBody,
+    Entity(Entity),
+}
+
+/// Who asked for a cinematic; echoed in [`CinematicFinished`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CinematicPurpose {
+    /// A campaign mission script.
+    Script,
+    /// The practice victory.
+    Victory,
+}
+
+/// Play `spline` around `focus` over `secs` real-time seconds.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct CinematicRequest {
+    pub focus: CinematicFocus,
+    pub spline: CameraSpline,
+    pub secs: f32,
+    pub purpose: CinematicPurpose,
+}
+
+impl CinematicRequest {
+    /// The configured orbit around `focus`.
+    pub fn orbit(
+        focus: CinematicFocus,
+        secs: f32,
+        purpose: CinematicPurpose,
+        config: &PhysicsConfig,
+    ) -> Self {
+        Self {
+            focus,
+            spline: CameraSpline::orbit_from_config(config),
+            secs,
+            purpose,
+        }
+    }
+}
+
+/// A cinematic ended, played out or skipped.
+#[derive(Message, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CinematicFinished {
+    pub purpose: CinematicPurpose,
+}
+
+/// The cinematic playing, if any.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ActiveCinematic {
+    pub request: Option<CinematicRequest>,
+    pub elapsed_secs: f32,
+    /// Last known focus position; held if the focus despawns mid-shot.
+    pub anchor: Vec2,
+}
+
+impl ActiveCinematic {
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.request.is_some()
+    }
+}
+
+/// Start requested cinematics and drive the camera along the active one.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn cinematic_camera_system(
+    real_time: Res<Time<Real>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut requests: MessageReader<CinematicRequest>,
+    mut finished: MessageWriter<CinematicFinished>,
+    mut active: ResMut<ActiveCinematic>,
+    q_player: Query<Entity, With<Player>>,
+    q_bodies: Query<(Entity, &AsteroidSize), (With<Asteroid>, Without<Planet>)>,
+    q_targets: Query<&Transform, Without<Camera>>,
+    mut q_camera: Query<&mut Transform, With<Camera>>,
+) {
+    let skip = active.is_active() && keys.just_pressed(KeyCode::Enter);
+
+    if let Some(mut request) = requests.read().last().cloned() {
+        request.focus = match request.focus {
+            CinematicFocus::LargestBody => q_bodies
+                .iter()
+                .max_by_key(|(entity, size)| (size.0, std::cmp::Reverse(*entity)))
+                .map_or(CinematicFocus::Ship, |(entity, _)| {
+                    CinematicFocus::Entity(entity)
+                }),
+            focus => focus,
+        };
+        active.request = Some(request);
+        active.elapsed_secs = 0.0;
+    }
+
+    let Some(request) = active.request.as_ref() else {
+        return;
+    };
+    let t = if request.secs > 0.0 {
+        active.elapsed_secs / request.secs
+    } else {
+        1.0
+    };
+    let key = request.spline.sample(t);
+    let purpose = request.purpose;
+    let focus_entity = match request.focus {
+        CinematicFocus::Entity(entity) => Some(entity),
+        _ => q_player.single().ok(),
+    };
+    if let Some(pos) = focus_entity.and_then(|e| q_targets.get(e).ok()) {
+        active.anchor = pos.translation.truncate();
+    }
+
+    let Ok(mut cam) = q_camera.single_mut() else {
+        return;
+    };
+
+    if skip || t >= 1.0 {
+        cam.rotation = Quat::IDENTITY;
+        active.request = None;
+        finished.write(CinematicFinished { purpose });
+        return;
+    }
+    if let Some(key) = key {
+        let pos = active.anchor + key.offset;
+        cam.translation.x = pos.x;
+        cam.translation.y = pos.y;
+        cam.scale = Vec3::new(key.zoom, key.zoom, 1.0);
+        cam.rotation = Quat::from_rotation_z(key.angle);
+    }
+    active.elapsed_secs += real_time.delta_secs();
+}
+
+/// Drop any running cinematic and clear the camera roll.
+///
+/// Runs on `OnEnter(MainMenu)` so quitting mid-shot does not leave the next
+/// session's camera tilted.
+pub fn reset_cinematic_system(
+    mut active: ResMut<ActiveCinematic>,
+    mut q_camera: Query<&mut Transform, With<Camera>>,
+) {
+    *active = ActiveCinematic::default();
+    for mut cam in q_camera.iter_mut() {
+        cam.rotation = Quat::IDENTITY;
+    }
+}
+
+pub struct CinematicPlugin;
+
+impl Plugin for CinematicPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<CinematicRequest>()
+            .add_message::<CinematicFinished>()
+            .init_resource::<ActiveCinematic>()
+            .add_systems(
+                Update,
+                cinematic_camera_system
+                    .after(camera_zoom_system)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), reset_cinematic_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    #[test]
+    fn orbit_spline_passes_through_its_keys() {
+        let spline = CameraSpline::orbit(100.0, 0.5, 2.0, 1.0);
+        let start = spline.sample(0.0).unwrap();
+        let end = spline.sample(1.0).unwrap();
+        assert!((start.offset - Vec2::new(0.0, -100.0)).length() < 1e-3);
+        assert!((end.offset - Vec2::new(0.0, 100.0)).length() < 1e-3);
+        assert!((start.zoom - 2.0).abs() < 1e-4 && (end.zoom - 1.0).abs() < 1e-4);
+
+        // Halfway round a half turn the camera is off to the side, still on
+        // the circle.
+        let mid = spline.sample(0.5).unwrap();
+        assert!((mid.offset - Vec2::new(100.0, 0.0)).length() < 1e-2);
+        assert!((mid.angle - TAU / 4.0).abs() < 1e-4);
+        let between = spline.sample(0.3).unwrap();
+        assert!((between.offset.length() - 100.0).abs() < 2.0);
+        assert_eq!(CameraSpline::default().sample(0.5), None);
+    }
+
+    #[test]
+    fn cinematic_orbits_the_largest_body_then_hands_back_the_camera() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            100,
+        )));
+        app.init_resource::<ButtonInput<KeyCode>>();
+        app.add_message::<CinematicRequest>()
+            .add_message::<CinematicFinished>()
+            .init_resource::<ActiveCinematic>()
+            .add_systems(Update, cinematic_camera_system);
+
+        let camera = app.world_mut().spawn((Camera2d, Transform::default())).id();
+        app.world_mut().spawn((
+            Asteroid,
+            AsteroidSize(5),
+            Transform::from_xyz(500.0, 0.0, 0.0),
+        ));
+        app.world_mut().spawn((
+            Asteroid,
+            AsteroidSize(80),
+            Transform::from_xyz(-300.0, 40.0, 0.0),
+        ));
+
+        let config = PhysicsConfig::default();
+        app.world_mut().write_message(CinematicRequest::orbit(
+            CinematicFocus::LargestBody,
+            0.5,
+            CinematicPurpose::Victory,
+            &config,
+        ));
+        app.update();
+
+        let cam = *app.world().get::<Transform>(camera).unwrap();
+        let offset = cam.translation.truncate() - Vec2::new(-300.0, 40.0);
+        assert!((offset.length() - config.cinematic_orbit_radius).abs() < 1e-2);
+        assert!((cam.scale.x - config.cinematic_zoom_start).abs() < 1e-4);
+
+        // Drain every frame: messages only outlive two updates.
+        let mut finished = Vec::new();
+        for _ in 0..8 {
+            app.update();
+            finished.extend(
+                app.world_mut()
+                    .resource_mut::<Messages<CinematicFinished>>()
+                    .drain(),
+            );
+        }
+        assert!(!app.world().resource::<ActiveCinematic>().is_active());
+        assert_eq!(
+            app.world().get::<Transform>(camera).unwrap().rotation,
+            Quat::IDENTITY
+        );
+        assert_eq!(
+            finished,
+            vec![CinematicFinished {
+                purpose: CinematicPurpose::Victory
+            }]
+        );
+    }
+}
@@ -0,0 +1,467 @@
+//! Hit feedback: damage shake, asteroid hit flashes and an aberration pulse.
+//!
+//! Merges, splits and destroys already shake the camera by mass through
+//! [`crate::feedback`].  This layer adds the damage side, driven by messages:
+//!
+//! | Message | Effect |
+//! |---------|--------|
+//! | [`PlayerDamaged`] | Screen-shake trauma and a chromatic-aberration pulse, both scaled by [`damage_intensity`] |
+//! | [`AsteroidDamaged`] | The chipped body flashes towards white for `hit_flash_secs` ([`HitFlash`]) |
+//!
+//! Collision damage grows with the impact speed, so hard rams shake harder
+//! than grazes.  The shake goes through the same [`ScreenShake`] trauma as
+//! the mass-scaled events.
+//!
+//! The aberration pulse is a fullscreen pass ([`AberrationNode`]) that pulls
+//! the red and blue channels apart towards the screen edges.  Its strength is
+//! the camera's [`ChromaticAberration`] uniform, which fades quadratically
+//! over `aberration_pulse_secs`; at 0 the pass is skipped.
+//!
+//! Asteroid fill materials are shared between bodies (see
+//! `asteroid_rendering`), so a flash swaps the body onto one shared flash
+//! material and back, instead of tinting its own.  In wireframe-only mode
+//! nothing flashes.
+//!
+//! **REDUCE MOTION** on the Settings screen (`OverlayState::reduce_motion`)
+//! turns off all screen shake, including the mass-scaled kind, and the
+//! aberration pulse.  Hit flashes stay.
+
+use crate::asteroid_rendering::instancing::{HullInstanceSlot, HullInstances};
+use crate::asteroid_rendering::{AsteroidLod, AsteroidRenderHandles};
+use crate::config::PhysicsConfig;
+use crate::feedback::ScreenShake;
+use crate::menu::GameState;
+use crate::rendering::OverlayState;
+use crate::wave_telemetry::PlayerDamaged;
+use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
+use bevy::core_pipeline::FullscreenShader;
+use bevy::ecs::query::QueryItem;
+use bevy::image::BevyDefault;
+use bevy::prelude::*;
+use bevy::render::extract_component::{
+    ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+    UniformComponentPlugin,
+};
+use bevy::render::render_graph::{
+    NodeRunError, RenderGraphContext, RenderGraphExt, RenderLabel, ViewNode, ViewNodeRunner,
+};
+use bevy::render::render_resource::binding_types::{sampler, texture_2d, uniform_buffer};
+use bevy::render::render_resource::*;
+use bevy::render::renderer::{RenderContext, RenderDevice};
+use bevy::render::view::ViewTarget;
+use bevy::render::{RenderApp, RenderStartup};
+
+const ABERRATION_SHADER_PATH: &str = "shaders/chromatic_aberration.wgsl";
+
+/// Grey the flash blends from: the middle of the rock tints.
+const FLASH_BASE_GREY: f32 = 0.55;
+
+/// A weapon hit chipped an asteroid that survived.  `entity` is the
+/// rebuilt body.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct AsteroidDamaged {
+    pub entity: Entity,
+    pub pos: Vec2,
+    /// Mass left after the chip.
+    pub mass: u32,
+}
+
+/// The asteroid shows the flash material for `remaining_secs` more.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct HitFlash {
+    pub remaining_secs: f32,
+}
+
+/// Aberration strength uploaded to the fullscreen pass.  Attached to the main
+/// camera.
+#[derive(Component, Clone, Copy, Debug, Default, ExtractComponent, ShaderType)]
+pub struct ChromaticAberration {
+    /// Channel offset at the screen edge, as a fraction of the screen; 0
+    /// skips the pass.
+    pub strength: f32,
+}
+
+/// The running aberration pulse.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq)]
+pub struct DamagePulse {
+    /// Strength at the start of the pulse.
+    pub peak: f32,
+    /// Real-time seconds left (`0` = inactive).
+    pub remaining_secs: f32,
+}
+
+impl DamagePulse {
+    /// Start a pulse at `intensity`, unless a stronger one is still running.
+    pub fn trigger(&mut self, intensity: f32, config: &PhysicsConfig) {
+        let peak = intensity.clamp(0.0, 1.0) * config.aberration_pulse_max;
+        if peak <= 0.0 || config.aberration_pulse_secs <= 0.0 {
+            return;
+        }
+        if peak >= self.strength(config) {
+            self.peak = peak;
+            self.remaining_secs = config.aberration_pulse_secs;
+        }
+    }
+
+    /// Current strength, fading quadratically to 0.
+    pub fn strength(&self, config: &PhysicsConfig) -> f32 {
+        if self.remaining_secs <= 0.0 || config.aberration_pulse_secs <= 0.0 {
+            return 0.0;
+        }
+        let t = (self.remaining_secs / config.aberration_pulse_secs).min(1.0);
+        self.peak * t * t
+    }
+}
+
+/// Feedback intensity of a player hit in `[0, 1]`; fatal hits are always 1.
+pub fn damage_intensity(damage: &PlayerDamaged, config: &PhysicsConfig) -> f32 {
+    if damage.fatal {
+        return 1.0;
+    }
+    (damage.amount / config.damage_feedback_reference.max(1e-3)).clamp(0.0, 1.0)
+}
+
+/// Turn this frame's damage messages into shake, pulses and flashes.
+pub fn damage_feedback_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    overlay: Res<OverlayState>,
+    mut player_damage: MessageReader<PlayerDamaged>,
+    mut asteroid_damage: MessageReader<AsteroidDamaged>,
+    mut shake: ResMut<ScreenShake>,
+    mut pulse: ResMut<DamagePulse>,
+) {
+    for damage in player_damage.read() {
+        if overlay.reduce_motion {
+            continue;
+        }
+        let intensity = damage_intensity(damage, &config);
+        shake.add(intensity * config.damage_shake_max_trauma);
+        pulse.trigger(intensity, &config);
+    }
+    for hit in asteroid_damage.read() {
+        if config.hit_flash_secs > 0.0 {
+            commands.entity(hit.entity).try_insert(HitFlash {
+                remaining_secs: config.hit_flash_secs,
+            });
+        }
+    }
+}
+
+/// Count flashes down, showing the shared flash material meanwhile.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn hit_flash_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    overlay: Res<OverlayState>,
+    mut flash_material: Local<Option<Handle<ColorMaterial>>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut hull_instances: Option<ResMut<HullInstances>>,
+    mut q_flashing: Query<(
+        Entity,
+        &mut HitFlash,
+        Option<&mut MeshMaterial2d<ColorMaterial>>,
+        &AsteroidRenderHandles,
+        Option<&AsteroidLod>,
+        Option<&HullInstanceSlot>,
+    )>,
+) {
+    let grey = FLASH_BASE_GREY.lerp(1.0, config.hit_flash_strength.clamp(0.0, 1.0));
+    let flash = match flash_material.as_ref() {
+        Some(handle) => {
+            if config.is_changed() {
+                if let Some(mat) = materials.get_mut(handle) {
+                    mat.color = Color::srgb(grey, grey, grey);
+                }
+            }
+            handle.clone()
+        }
+        None => flash_material
+            .insert(materials.add(ColorMaterial::from_color(Color::srgb(grey, grey, grey))))
+            .clone(),
+    };
+
+    let dt = time.delta_secs();
+    for (entity, mut hit_flash, material, handles, lod, slot) in q_flashing.iter_mut() {
+        hit_flash.remaining_secs -= dt;
+        let lod = lod.copied().unwrap_or_default();
+        let flashing =
+            hit_flash.remaining_secs > 0.0 && !overlay.wireframe_only && lod != AsteroidLod::Hidden;
+        if let Some(mut material) = material {
+            let wanted = if flashing {
+                flash.clone()
+            } else {
+                handles.variant(lod, overlay.wireframe_only).1
+            };
+            if material.0 != wanted {
+                material.0 = wanted;
+            }
+        }
+        // Instanced hulls have no `ColorMaterial`; flash their slot colour.
+        if let (Some(slot), Some(instances)) = (slot, hull_instances.as_mut()) {
+            let color = if flashing {
+                Color::srgb(grey, grey, grey)
+            } else {
+                slot.color
+            };
+            instances.set_color(slot.index, color);
+        }
+        if hit_flash.remaining_secs <= 0.0 {
+            commands.entity(entity).remove::<HitFlash>();
+        }
+    }
+}
+
+/// Fade the pulse in real time and upload it to the camera.
+pub fn chromatic_aberration_system(
+    time: Res<Time<Real>>,
+    config: Res<PhysicsConfig>,
+    overlay: Res<OverlayState>,
+    mut pulse: ResMut<DamagePulse>,
+    mut q_camera: Query<&mut ChromaticAberration>,
+) {
+    if pulse.remaining_secs > 0.0 {
+        pulse.remaining_secs = (pulse.remaining_secs - time.delta_secs()).max(0.0);
+    }
+    let strength = if overlay.reduce_motion {
+        0.0
+    } else {
+        pulse.strength(&config)
+    };
+    for mut aberration in q_camera.iter_mut() {
+        if aberration.strength != strength {
+            aberration.strength = strength;
+        }
+    }
+}
+
+/// Drop any running pulse when a session ends.
+pub fn reset_damage_pulse_system(mut pulse: ResMut<DamagePulse>) {
+    *pulse = DamagePulse::default();
+}
+
+pub struct HitFeedbackPlugin;
+
+impl Plugin for HitFeedbackPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<AsteroidDamaged>()
+            .init_resource::<DamagePulse>()
+            .add_plugins((
+                ExtractComponentPlugin::<ChromaticAberration>::default(),
+                UniformComponentPlugin::<ChromaticAberration>::default(),
+            ))
+            .add_systems(
+                Update,
+                (damage_feedback_system, hit_flash_system)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(PostUpdate, chromatic_aberration_system)
+            .add_systems(OnEnter(GameState::MainMenu), reset_damage_pulse_system);
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .add_systems(RenderStartup, init_aberration_pipeline)
+            .add_render_graph_node::<ViewNodeRunner<AberrationNode>>(Core2d, AberrationLabel)
+            .add_render_graph_edges(
+                Core2d,
+                (
+                    Node2d::Tonemapping,
+                    AberrationLabel,
+                    Node2d::EndMainPassPostProcessing,
+                ),
+            );
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct AberrationLabel;
+
+/// Fullscreen channel-split pass over the main view target.
+#[derive(Default)]
+pub struct AberrationNode;
+
+impl ViewNode for AberrationNode {
+    type ViewQuery = (
+        &'static ViewTarget,
+        &'static ChromaticAberration,
+        &'static DynamicUniformIndex<ChromaticAberration>,
+    );
+
+    fn run<'w>(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext<'w>,
+        (view_target, aberration, uniform_index): QueryItem<'w, '_, Self::ViewQuery>,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        if aberration.strength <= 0.0 {
+            return Ok(());
+        }
+        let aberration_pipeline = world.resource::<AberrationPipeline>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(aberration_pipeline.pipeline_id)
+        else {
+            return Ok(());
+        };
+        let uniforms = world.resource::<ComponentUniforms<ChromaticAberration>>();
+        let Some(uniform_binding) = uniforms.uniforms().binding() else {
+            return Ok(());
+        };
+
+        let post_process = view_target.post_process_write();
+        let bind_group = render_context.render_device().create_bind_group(
+            "chromatic_aberration_bind_group",
+            &aberration_pipeline.layout,
+            &BindGroupEntries::sequential((
+                post_process.source,
+                &aberration_pipeline.sampler,
+                uniform_binding,
+            )),
+        );
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("chromatic_aberration_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                depth_slice: None,
+                resolve_target: None,
+                ops: Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[uniform_index.index()]);
+        render_pass.draw(0..3, 0..1);
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct AberrationPipeline {
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    pipeline_id: CachedRenderPipelineId,
+}
+
+fn init_aberration_pipeline(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    asset_server: Res<AssetServer>,
+    fullscreen_shader: Res<FullscreenShader>,
+    pipeline_cache: Res<PipelineCache>,
+) {
+    let layout = render_device.create_bind_group_layout(
+        "chromatic_aberration_bind_group_layout",
+        &BindGroupLayoutEntries::sequential(
+            ShaderStages::FRAGMENT,
+            (
+                texture_2d(TextureSampleType::Float { filterable: true }),
+                sampler(SamplerBindingType::Filtering),
+                uniform_buffer::<ChromaticAberration>(true),
+            ),
+        ),
+    );
+    let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+    let pipeline_id = pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+        label: Some("chromatic_aberration_pipeline".into()),
+        layout: vec![layout.clone()],
+        vertex: fullscreen_shader.to_vertex_state(),
+        fragment: Some(FragmentState {
+            shader: asset_server.load(ABERRATION_SHADER_PATH),
+            targets: vec![Some(ColorTargetState {
+                format: TextureFormat::bevy_default(),
+                blend: None,
+                write_mask: ColorWrites::ALL,
+            })],
+            ..default()
+        }),
+        ..default()
+    });
+    commands.insert_resource(AberrationPipeline {
+        layout,
+        sampler,
+        pipeline_id,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wave_telemetry::DamageSource;
+
+    fn hit(amount: f32) -> PlayerDamaged {
+        PlayerDamaged {
+            amount,
+            source: DamageSource::Asteroid,
+            fatal: false,
+        }
+    }
+
+    #[test]
+    fn the_pulse_scales_with_damage_and_fades_out() {
+        let config = PhysicsConfig::default();
+        let graze = damage_intensity(&hit(config.damage_feedback_reference * 0.25), &config);
+        assert!((graze - 0.25).abs() < 1e-6);
+        assert_eq!(damage_intensity(&hit(1e6), &config), 1.0);
+        let fatal = PlayerDamaged {
+            fatal: true,
+            ..hit(1.0)
+        };
+        assert_eq!(damage_intensity(&fatal, &config), 1.0);
+
+        let mut pulse = DamagePulse::default();
+        pulse.trigger(1.0, &config);
+        assert_eq!(pulse.strength(&config), config.aberration_pulse_max);
+        // A weaker hit does not cut a strong pulse short.
+        pulse.remaining_secs *= 0.9;
+        let before = pulse;
+        pulse.trigger(0.1, &config);
+        assert_eq!(pulse, before);
+        pulse.remaining_secs = config.aberration_pulse_secs * 0.5;
+        assert!((pulse.strength(&config) - config.aberration_pulse_max * 0.25).abs() < 1e-6);
+        pulse.remaining_secs = 0.0;
+        assert_eq!(pulse.strength(&config), 0.0);
+    }
+
+    #[test]
+    fn reduce_motion_keeps_the_flash_but_drops_shake_and_pulse() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(PhysicsConfig::default())
+            .insert_resource(OverlayState {
+                reduce_motion: true,
+                ..Default::default()
+            })
+            .init_resource::<ScreenShake>()
+            .init_resource::<DamagePulse>()
+            .add_message::<PlayerDamaged>()
+            .add_message::<AsteroidDamaged>()
+            .add_systems(Update, damage_feedback_system);
+        let asteroid = app.world_mut().spawn_empty().id();
+        app.world_mut().write_message(hit(100.0));
+        app.world_mut().write_message(AsteroidDamaged {
+            entity: asteroid,
+            pos: Vec2::ZERO,
+            mass: 5,
+        });
+        app.update();
+        assert_eq!(app.world().resource::<ScreenShake>().trauma, 0.0);
+        assert_eq!(app.world().resource::<DamagePulse>().remaining_secs, 0.0);
+        assert!(app.world().get::<HitFlash>(asteroid).is_some());
+
+        app.world_mut().resource_mut::<OverlayState>().reduce_motion = false;
+        app.world_mut().write_message(hit(100.0));
+        app.update();
+        let config = PhysicsConfig::default();
+        assert_eq!(
+            app.world().resource::<ScreenShake>().trauma,
+            config.damage_shake_max_trauma
+        );
+        assert!(app.world().resource::<DamagePulse>().remaining_secs > 0.0);
+    }
+}
@@ -0,0 +1,401 @@
+//! Merge animation: source bodies flow into their composite.
+//!
+//! `merge_resolution_system` swaps the members of a bound cluster for one
+//! composite in a single tick, and physics carries on with the composite
+//! straight away.  Drawn as-is, the bodies would snap into the new hull.  To
+//! hide the snap the composite is spawned with a [`MergeAnimation`] holding
+//! each member's outline, in the composite's local space, as it was at the
+//! merge.  For `merge_animation_secs` [`merge_animation_system`]:
+//!
+//! - hides the composite's own mesh and draws one ghost per member instead,
+//!   as a child of the composite, so the ghosts move and spin with it;
+//! - blends each ghost's outline towards the composite hull
+//!   ([`blend_outline`]), so at the end every ghost covers the composite
+//!   exactly and the hand-over is seamless;
+//! - on the first frame, sends a short stream of merge-glow particles from
+//!   each member towards the composite's centre.
+//!
+//! Outlines of different vertex counts are blended by resampling both to
+//! [`OUTLINE_POINTS`] points evenly spaced along the perimeter, starting at
+//! the point due +X of the centroid.
+//!
+//! Bodies the LOD pass draws simplified or hides skip the animation, as does
+//! everything when `merge_animation_secs` is 0.  A composite that merges again
+//! mid-animation takes its ghosts with it.
+
+use crate::asteroid::Vertices;
+use crate::asteroid_rendering::{
+    asteroid_lod_system, filled_polygon_mesh, polygon_outline_mesh, AsteroidLod,
+    AsteroidRenderHandles,
+};
+use crate::menu::GameState;
+use crate::particles::spawn_merge_join_particles;
+use crate::rendering::OverlayState;
+use bevy::prelude::*;
+
+/// Points each outline is resampled to before blending.
+pub const OUTLINE_POINTS: usize = 32;
+
+/// Half-width of a ghost outline in wireframe-only mode; matches the
+/// asteroid outline mesh.
+const GHOST_OUTLINE_HALF_WIDTH: f32 = 0.4;
+
+/// A composite still blending in from the bodies it merged.
+#[derive(Component, Debug, Clone, PartialEq)]
+pub struct MergeAnimation {
+    /// Each member's outline in the composite's local space at the merge.
+    pub sources: Vec<Vec<Vec2>>,
+    pub elapsed_secs: f32,
+    pub duration_secs: f32,
+    /// Ghost entity and mesh per source, once spawned.
+    ghosts: Vec<(Entity, Handle<Mesh>)>,
+}
+
+impl MergeAnimation {
+    pub fn new(sources: Vec<Vec<Vec2>>, duration_secs: f32) -> Self {
+        Self {
+            sources,
+            elapsed_secs: 0.0,
+            duration_secs,
+            ghosts: Vec::new(),
+        }
+    }
+
+    /// Blend factor in `[0, 1]`, eased in and out.
+    pub fn progress(&self) -> f32 {
+        if self.duration_secs <= 0.0 {
+            return 1.0;
+        }
+        let t = (self.elapsed_secs / self.duration_secs).clamp(0.0, 1.0);
+        t * t * (3.0 - 2.0 * t)
+    }
+
+    pub fn finished(&self) -> bool {
+        self.elapsed_secs >= self.duration_secs
+    }
+}
+
+/// Marks a ghost outline drawn for a [`MergeAnimation`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MergeGhost;
+
+/// `outline` resampled to `points` points evenly spaced along its perimeter,
+/// counter-clockwise from the point due +X of its centroid.
+///
+/// Fewer than 3 vertices (or a zero-length perimeter) give `points` copies of
+/// the centroid.
+pub fn resample_outline(outline: &[Vec2], points: usize) -> Vec<Vec2> {
+    if outline.is_empty() || points == 0 {
+        return Vec::new();
+    }
+    let centroid = outline.iter().copied().sum::<Vec2>() / outline.len() as f32;
+    let mut ring: Vec<Vec2> = outline.to_vec();
+    if signed_area(&ring) < 0.0 {
+        ring.reverse();
+    }
+    let edges = ring.len();
+    let perimeter: f32 = (0..edges)
+        .map(|i| ring[i].distance(ring[(i + 1) % edges]))
+        .sum();
+    if edges < 3 || perimeter <= f32::EPSILON {
+        return vec![centroid; points];
+    }
+
+    // Walk from vertex 0 at even steps, then rotate so the sample closest to
+    // +X of the centroid comes first.
+    let step = perimeter / points as f32;
+    let mut samples = Vec::with_capacity(points);
+    let (mut edge, mut walked) = (0, 0.0);
+    for i in 0..points {
+        let target = i as f32 * step;
+        loop {
+            let len = ring[edge].distance(ring[(edge + 1) % edges]);
+            if walked + len >= target || edge + 1 == edges {
+                let t = if len > 0.0 {
+                    ((target - walked) / len).clamp(0.0, 1.0)
+                } else {
+                    0.0
+                };
+                samples.push(ring[edge].lerp(ring[(edge + 1) % edges], t));
+                break;
+            }
+            walked += len;
+            edge += 1;
+        }
+    }
+    let start = samples
+        .iter()
+        .enumerate()
+        .min_by(|(_, a), (_, b)| {
+            let angle = |p: Vec2| (p - centroid).to_angle().rem_euclid(std::f32::consts::TAU);
+            angle(**a).total_cmp(&angle(**b))
+        })
+        .map_or(0, |(i, _)| i);
+    samples.rotate_left(start);
+    samples
+}
+
+fn signed_area(outline: &[Vec2]) -> f32 {
+    let n = outline.len();
+    (0..n)
+        .map(|i| outline[i].perp_dot(outline[(i + 1) % n]))
+        .sum::<f32>()
+        * 0.5
+}
+
+/// The outline `t` of the way from `source` to `target` (both resampled to
+/// [`OUTLINE_POINTS`]).
+pub fn blend_outline(source: &[Vec2], target: &[Vec2], t: f32) -> Vec<Vec2> {
+    let t = t.clamp(0.0, 1.0);
+    resample_outline(source, OUTLINE_POINTS)
+        .into_iter()
+        .zip(resample_outline(target, OUTLINE_POINTS))
+        .map(|(from, to)| from.lerp(to, t))
+        .collect()
+}
+
+fn ghost_mesh(outline: &[Vec2], wireframe_only: bool) -> Mesh {
+    if wireframe_only {
+        polygon_outline_mesh(outline, GHOST_OUTLINE_HALF_WIDTH)
+    } else {
+        filled_polygon_mesh(outline)
+    }
+}
+
+/// Advance merge animations: spawn, reshape and finally drop the ghosts.
+///
+/// Runs after `asteroid_lod_system` so the composite stays hidden even if the
+/// LOD pass touched its visibility this frame.
+#[allow(clippy::type_complexity)]
+pub fn merge_animation_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    overlay: Res<OverlayState>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut q_merging: Query<(
+        Entity,
+        &mut MergeAnimation,
+        &Vertices,
+        &GlobalTransform,
+        Option<&AsteroidRenderHandles>,
+        Option<&AsteroidLod>,
+        &mut Visibility,
+    )>,
+) {
+    let dt = time.delta_secs();
+    for (entity, mut anim, vertices, transform, handles, lod, mut visibility) in
+        q_merging.iter_mut()
+    {
+        let Some(handles) = handles else {
+            // The mesh is attached a frame after the spawn; hide the
+            // composite until the ghosts can take over.
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+        anim.elapsed_secs += dt;
+        let lod = lod.copied().unwrap_or_default();
+        if anim.finished() || lod != AsteroidLod::Full {
+            for (ghost, _) in anim.ghosts.drain(..) {
+                if let Ok(mut cmd) = commands.get_entity(ghost) {
+                    cmd.despawn();
+                }
+            }
+            *visibility = if lod == AsteroidLod::Hidden {
+                Visibility::Hidden
+            } else {
+                Visibility::Inherited
+            };
+            commands.entity(entity).remove::<MergeAnimation>();
+            continue;
+        }
+
+        *visibility = Visibility::Hidden;
+        let t = anim.progress();
+        if anim.ghosts.is_empty() {
+            let material = handles.variant(AsteroidLod::Full, overlay.wireframe_only).1;
+            let centre = transform.translation().truncate();
+            let remaining = anim.duration_secs - anim.elapsed_secs;
+            let mut ghosts = Vec::with_capacity(anim.sources.len());
+            for source in &anim.sources {
+                let outline = blend_outline(source, &vertices.0, t);
+                let mesh = meshes.add(ghost_mesh(&outline, overlay.wireframe_only));
+                let ghost = commands
+                    .spawn((
+                        Mesh2d(mesh.clone()),
+                        MeshMaterial2d(material.clone()),
+                        Transform::default(),
+                        // Shown although the composite is hidden.
+                        Visibility::Visible,
+                        MergeGhost,
+                        ChildOf(entity),
+                    ))
+                    .id();
+                ghosts.push((ghost, mesh));
+
+                let source_centre = source.iter().copied().sum::<Vec2>() / source.len() as f32;
+                let from = transform
+                    .transform_point(source_centre.extend(0.0))
+                    .truncate();
+                spawn_merge_join_particles(&mut commands, from, centre, remaining);
+            }
+            anim.ghosts = ghosts;
+            continue;
+        }
+
+        for ((_, mesh), source) in anim.ghosts.iter().zip(&anim.sources) {
+            if let Some(mesh) = meshes.get_mut(mesh) {
+                *mesh = ghost_mesh(
+                    &blend_outline(source, &vertices.0, t),
+                    overlay.wireframe_only,
+                );
+            }
+        }
+    }
+}
+
+pub struct MergeAnimationPlugin;
+
+impl Plugin for MergeAnimationPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            Update,
+            merge_animation_system
+                .after(asteroid_lod_system)
+                .run_if(in_state(GameState::Playing)),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn square(centre: Vec2, half: f32) -> Vec<Vec2> {
+        [(-1.0, -1.0), (1.0, -1.0), (1.0, 1.0), (-1.0, 1.0)]
+            .into_iter()
+            .map(|(x, y)| centre + Vec2::new(x, y) * half)
+            .collect()
+    }
+
+    fn close(a: Vec2, b: Vec2) -> bool {
+        a.distance(b) < 1e-3
+    }
+
+    #[test]
+    fn resampling_keeps_points_on_the_outline_from_due_east() {
+        let outline = square(Vec2::new(5.0, 0.0), 2.0);
+        let samples = resample_outline(&outline, 16);
+        assert_eq!(samples.len(), 16);
+        assert!(close(samples[0], Vec2::new(7.0, 0.0)), "{:?}", samples[0]);
+        for p in &samples {
+            let d = *p - Vec2::new(5.0, 0.0);
+            assert!(
+                (d.x.abs().max(d.y.abs()) - 2.0).abs() < 1e-3,
+                "{p:?} off the outline"
+            );
+        }
+        // Clockwise input gives the same counter-clockwise samples.
+        let mut clockwise = outline.clone();
+        clockwise.reverse();
+        let reversed = resample_outline(&clockwise, 16);
+        assert!(samples.iter().zip(&reversed).all(|(a, b)| close(*a, *b)));
+        assert_eq!(resample_outline(&outline[..2], 4).len(), 4);
+    }
+
+    #[test]
+    fn blends_run_from_the_source_to_the_target() {
+        let source = square(Vec2::new(-6.0, 0.0), 2.0);
+        let target = square(Vec2::ZERO, 4.0);
+        let start = blend_outline(&source, &target, 0.0);
+        let end = blend_outline(&source, &target, 1.0);
+        let resampled_source = resample_outline(&source, OUTLINE_POINTS);
+        let resampled_target = resample_outline(&target, OUTLINE_POINTS);
+        assert!(start
+            .iter()
+            .zip(&resampled_source)
+            .all(|(a, b)| close(*a, *b)));
+        assert!(end
+            .iter()
+            .zip(&resampled_target)
+            .all(|(a, b)| close(*a, *b)));
+
+        let mut anim = MergeAnimation::new(vec![source], 0.2);
+        assert_eq!(anim.progress(), 0.0);
+        anim.elapsed_secs = 0.1;
+        assert!((anim.progress() - 0.5).abs() < 1e-6);
+        anim.elapsed_secs = 0.3;
+        assert!(anim.finished() && anim.progress() == 1.0);
+        assert_eq!(MergeAnimation::new(Vec::new(), 0.0).progress(), 1.0);
+    }
+
+    #[test]
+    fn the_composite_hides_behind_its_ghosts_until_the_blend_ends() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, AssetPlugin::default()))
+            .init_asset::<Mesh>()
+            .init_asset::<ColorMaterial>()
+            .init_resource::<OverlayState>()
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+                Duration::from_millis(50),
+            ))
+            .add_systems(Update, merge_animation_system);
+        let handles = AsteroidRenderHandles {
+            fill_mesh: Handle::default(),
+            fill_material: Handle::default(),
+            outline_mesh: Handle::default(),
+            outline_material: Handle::default(),
+            lod_fill_mesh: Handle::default(),
+            lod_outline_mesh: Handle::default(),
+        };
+        let composite = app
+            .world_mut()
+            .spawn((
+                Vertices(square(Vec2::ZERO, 4.0)),
+                Transform::default(),
+                GlobalTransform::default(),
+                Visibility::Inherited,
+                handles,
+                AsteroidLod::Full,
+                MergeAnimation::new(
+                    vec![
+                        square(Vec2::new(-4.0, 0.0), 2.0),
+                        square(Vec2::new(4.0, 0.0), 2.0),
+                    ],
+                    0.2,
+                ),
+            ))
+            .id();
+
+        app.update();
+        app.update();
+        let world = app.world_mut();
+        assert_eq!(
+            world.get::<Visibility>(composite),
+            Some(&Visibility::Hidden)
+        );
+        let ghosts = world
+            .query_filtered::<&ChildOf, With<MergeGhost>>()
+            .iter(world)
+            .filter(|parent| parent.parent() == composite)
+            .count();
+        assert_eq!(ghosts, 2);
+
+        for _ in 0..5 {
+            app.update();
+        }
+        let world = app.world_mut();
+        assert!(world.get::<MergeAnimation>(composite).is_none());
+        assert_eq!(
+            world.get::<Visibility>(composite),
+            Some(&Visibility::Inherited)
+        );
+        assert_eq!(
+            world
+                .query_filtered::<(), With<MergeGhost>>()
+                .iter(world)
+                .count(),
+            0
+        );
+    }
+}
@@ -0,0 +1,421 @@
+//! Accessibility: colour-blind-safe palettes and the UI scale.
+//!
+//! Colours that carry meaning — health and energy gradients, enemy ships and
+//! their shots, ore kinds, volatile asteroids, the ore shop's upgrade status
+//! labels, the debug panel's ON/OFF toggles, and the menus' confirm, cancel
+//! and buy buttons — are read from the [`Palette`] resource instead of being
+//! written inline.  [`Palette::new`] builds one per [`PaletteKind`]:
+//!
+//! | Kind | Avoids | Built from |
+//! |------|--------|------------|
+//! | `Standard` | — | The original red/green/amber colours |
+//! | `Deuteranopia` | Red against green | Blue / orange / yellow (Okabe–Ito) |
+//! | `Protanopia` | Red against green, dark reds | As deuteranopia, with brighter oranges for reds |
+//! | `Tritanopia` | Blue against yellow | Red / teal / magenta |
+//!
+//! Rock greys, menu chrome (including neutral Back / Close buttons) and
+//! weapon icons stay as they are: they carry no meaning that depends on hue.
+//!
+//! [`AccessibilitySettings`] holds the chosen palette and a UI scale factor
+//! (written to Bevy's [`UiScale`]).  Both are set on the Settings screen and
+//! saved in the profile.  [`apply_accessibility_system`] rebuilds the palette
+//! when they change; materials take the new colours when they are next
+//! created, i.e. from the next session on.
+
+use crate::mining::OreKind;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// UI scale change per Settings button press.
+pub const UI_SCALE_STEP: f32 = 0.125;
+/// Smallest UI scale the Settings screen allows.
+pub const UI_SCALE_MIN: f32 = 0.75;
+/// Largest UI scale the Settings screen allows.
+pub const UI_SCALE_MAX: f32 = 1.5;
+
+/// Which colour set [`Palette`] holds.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum PaletteKind {
+    #[default]
+    Standard,
+    Deuteranopia,
+    Protanopia,
+    Tritanopia,
+}
+
+impl PaletteKind {
+    pub const ALL: [PaletteKind; 4] = [
+        PaletteKind::Standard,
+        PaletteKind::Deuteranopia,
+        PaletteKind::Protanopia,
+        PaletteKind::Tritanopia,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PaletteKind::Standard => "STANDARD",
+            PaletteKind::Deuteranopia => "DEUTERAN",
+            PaletteKind::Protanopia => "PROTAN",
+            PaletteKind::Tritanopia => "TRITAN",
+        }
+    }
+
+    /// The next kind, wrapping round.
+    pub fn next(self) -> Self {
+        let i = Self::ALL.iter().position(|&k| k == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+}
+
+/// Background, border and label colours of one kind of menu button.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ButtonColors {
+    pub bg: Color,
+    pub border: Color,
+    pub text: Color,
+}
+
+impl ButtonColors {
+    const fn srgb(bg: [f32; 3], border: [f32; 3], text: [f32; 3]) -> Self {
+        Self {
+            bg: Color::srgb(bg[0], bg[1], bg[2]),
+            border: Color::srgb(border[0], border[1], border[2]),
+            text: Color::srgb(text[0], text[1], text[2]),
+        }
+    }
+}
+
+/// Meaningful gameplay and HUD colours for the chosen [`PaletteKind`].
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    pub kind: PaletteKind,
+    /// Health bar fill at full and zero health.
+    pub health_full: Color,
+    pub health_empty: Color,
+    /// Energy bar fill when charged and when drained.
+    pub energy_full: Color,
+    pub energy_empty: Color,
+    /// Enemy hull at full and zero health.
+    pub enemy_full: Color,
+    pub enemy_damaged: Color,
+    /// Boss hull at full and zero health.
+    pub boss_full: Color,
+    pub boss_damaged: Color,
+    pub enemy_projectile: Color,
+    pub ore_iron: Color,
+    pub ore_ice: Color,
+    pub ore_rare: Color,
+    pub volatile: Color,
+    /// Debug-panel toggle backgrounds and labels.
+    pub toggle_on_bg: Color,
+    pub toggle_off_bg: Color,
+    pub toggle_on_text: Color,
+    pub toggle_off_text: Color,
+    /// Ore shop upgrade status: affordable, too expensive, fully upgraded.
+    pub shop_affordable: Color,
+    pub shop_unaffordable: Color,
+    pub shop_maxed: Color,
+    /// Menu buttons that go ahead (start, resume, continue) or back out of
+    /// the game (quit), and the ore shop's buy buttons.
+    pub confirm: ButtonColors,
+    pub cancel: ButtonColors,
+    pub buy: ButtonColors,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::new(PaletteKind::Standard)
+    }
+}
+
+impl Palette {
+    pub fn new(kind: PaletteKind) -> Self {
+        let standard = Self {
+            kind,
+            health_full: Color::srgb(0.0, 1.0, 0.0),
+            health_empty: Color::srgb(1.0, 0.0, 0.0),
+            energy_full: Color::srgb(0.3, 0.9, 1.0),
+            energy_empty: Color::srgb(1.0, 0.55, 0.2),
+            enemy_full: Color::srgb(0.95, 0.32, 0.22),
+            enemy_damaged: Color::srgb(0.75, 0.22, 0.22),
+            boss_full: Color::srgb(0.88, 0.26, 0.14),
+            boss_damaged: Color::srgb(0.70, 0.18, 0.14),
+            enemy_projectile: Color::srgb(1.0, 0.45, 0.25),
+            ore_iron: Color::srgb(0.85, 0.55, 0.35),
+            ore_ice: Color::srgb(0.55, 0.85, 1.0),
+            ore_rare: Color::srgb(0.25, 0.95, 0.50),
+            volatile: Color::srgb(0.95, 0.45, 0.10),
+            toggle_on_bg: Color::srgb(0.08, 0.44, 0.12),
+            toggle_off_bg: Color::srgb(0.35, 0.07, 0.07),
+            toggle_on_text: Color::srgb(0.75, 1.0, 0.80),
+            toggle_off_text: Color::srgb(0.65, 0.65, 0.65),
+            shop_affordable: Color::srgb(0.75, 0.90, 0.75),
+            shop_unaffordable: Color::srgb(0.75, 0.40, 0.40),
+            shop_maxed: Color::srgb(0.90, 0.80, 0.30),
+            confirm: ButtonColors::srgb([0.08, 0.36, 0.14], [0.18, 0.72, 0.28], [0.75, 1.0, 0.80]),
+            cancel: ButtonColors::srgb([0.28, 0.06, 0.06], [0.60, 0.12, 0.12], [1.0, 0.65, 0.65]),
+            buy: ButtonColors::srgb([0.06, 0.22, 0.06], [0.18, 0.56, 0.18], [0.55, 1.0, 0.55]),
+        };
+        match kind {
+            PaletteKind::Standard => standard,
+            PaletteKind::Deuteranopia => Self {
+                health_full: Color::srgb(0.35, 0.70, 0.90),
+                health_empty: Color::srgb(0.90, 0.60, 0.0),
+                energy_full: Color::srgb(0.35, 0.70, 0.90),
+                energy_empty: Color::srgb(0.95, 0.90, 0.25),
+                enemy_full: Color::srgb(0.84, 0.37, 0.0),
+                enemy_damaged: Color::srgb(0.55, 0.24, 0.0),
+                boss_full: Color::srgb(0.80, 0.47, 0.65),
+                boss_damaged: Color::srgb(0.55, 0.30, 0.45),
+                enemy_projectile: Color::srgb(0.95, 0.90, 0.25),
+                ore_iron: Color::srgb(0.90, 0.60, 0.0),
+                ore_ice: Color::srgb(0.35, 0.70, 0.90),
+                ore_rare: Color::srgb(0.95, 0.90, 0.25),
+                volatile: Color::srgb(0.80, 0.47, 0.65),
+                toggle_on_bg: Color::srgb(0.0, 0.30, 0.55),
+                toggle_off_bg: Color::srgb(0.40, 0.24, 0.0),
+                toggle_on_text: Color::srgb(0.80, 0.92, 1.0),
+                shop_affordable: Color::srgb(0.70, 0.85, 1.0),
+                shop_unaffordable: Color::srgb(0.84, 0.37, 0.0),
+                shop_maxed: Color::srgb(0.95, 0.90, 0.25),
+                confirm: ButtonColors::srgb(
+                    [0.0, 0.22, 0.40],
+                    [0.35, 0.70, 0.90],
+                    [0.80, 0.92, 1.0],
+                ),
+                cancel: ButtonColors::srgb([0.34, 0.16, 0.0], [0.84, 0.37, 0.0], [1.0, 0.78, 0.50]),
+                buy: ButtonColors::srgb([0.02, 0.16, 0.30], [0.30, 0.60, 0.85], [0.70, 0.85, 1.0]),
+                ..standard
+            },
+            PaletteKind::Protanopia => Self {
+                health_full: Color::srgb(0.35, 0.70, 0.90),
+                health_empty: Color::srgb(0.95, 0.70, 0.10),
+                energy_full: Color::srgb(0.35, 0.70, 0.90),
+                energy_empty: Color::srgb(0.95, 0.90, 0.25),
+                enemy_full: Color::srgb(0.95, 0.65, 0.10),
+                enemy_damaged: Color::srgb(0.65, 0.44, 0.05),
+                boss_full: Color::srgb(0.85, 0.55, 0.75),
+                boss_damaged: Color::srgb(0.60, 0.38, 0.52),
+                enemy_projectile: Color::srgb(0.95, 0.90, 0.25),
+                ore_iron: Color::srgb(0.95, 0.65, 0.10),
+                ore_ice: Color::srgb(0.35, 0.70, 0.90),
+                ore_rare: Color::srgb(0.95, 0.90, 0.25),
+                volatile: Color::srgb(0.85, 0.55, 0.75),
+                toggle_on_bg: Color::srgb(0.0, 0.30, 0.55),
+                toggle_off_bg: Color::srgb(0.45, 0.30, 0.0),
+                toggle_on_text: Color::srgb(0.80, 0.92, 1.0),
+                shop_affordable: Color::srgb(0.70, 0.85, 1.0),
+                shop_unaffordable: Color::srgb(0.95, 0.65, 0.10),
+                shop_maxed: Color::srgb(0.95, 0.95, 0.55),
+                confirm: ButtonColors::srgb(
+                    [0.0, 0.22, 0.40],
+                    [0.35, 0.70, 0.90],
+                    [0.80, 0.92, 1.0],
+                ),
+                cancel: ButtonColors::srgb(
+                    [0.38, 0.25, 0.0],
+                    [0.95, 0.65, 0.10],
+                    [1.0, 0.85, 0.55],
+                ),
+                buy: ButtonColors::srgb([0.02, 0.16, 0.30], [0.30, 0.60, 0.85], [0.70, 0.85, 1.0]),
+                ..standard
+            },
+            PaletteKind::Tritanopia => Self {
+                health_full: Color::srgb(0.0, 0.75, 0.70),
+                health_empty: Color::srgb(0.90, 0.15, 0.25),
+                energy_full: Color::srgb(0.0, 0.75, 0.70),
+                energy_empty: Color::srgb(0.90, 0.35, 0.55),
+                enemy_projectile: Color::srgb(1.0, 0.40, 0.55),
+                ore_iron: Color::srgb(0.90, 0.35, 0.35),
+                ore_ice: Color::srgb(0.30, 0.85, 0.80),
+                ore_rare: Color::srgb(0.90, 0.45, 0.85),
+                volatile: Color::srgb(1.0, 0.30, 0.40),
+                shop_affordable: Color::srgb(0.55, 0.90, 0.85),
+                shop_unaffordable: Color::srgb(0.90, 0.35, 0.40),
+                shop_maxed: Color::srgb(0.90, 0.55, 0.85),
+                confirm: ButtonColors::srgb(
+                    [0.0, 0.26, 0.24],
+                    [0.0, 0.75, 0.70],
+                    [0.70, 1.0, 0.95],
+                ),
+                cancel: ButtonColors::srgb(
+                    [0.32, 0.04, 0.10],
+                    [0.90, 0.15, 0.25],
+                    [1.0, 0.65, 0.72],
+                ),
+                buy: ButtonColors::srgb([0.0, 0.18, 0.17], [0.0, 0.60, 0.56], [0.55, 0.95, 0.90]),
+                ..standard
+            },
+        }
+    }
+
+    /// Health bar fill at `frac` of full health.
+    pub fn health(&self, frac: f32) -> Color {
+        lerp_srgb(self.health_empty, self.health_full, frac)
+    }
+
+    /// Energy bar fill at `frac` of a full pool.
+    pub fn energy(&self, frac: f32) -> Color {
+        lerp_srgb(self.energy_empty, self.energy_full, frac)
+    }
+
+    /// Enemy hull tint at `hp_ratio` of full health.
+    pub fn enemy(&self, hp_ratio: f32) -> Color {
+        lerp_srgb(self.enemy_damaged, self.enemy_full, hp_ratio)
+    }
+
+    /// Boss hull tint at `hp_ratio` of full health.
+    pub fn boss(&self, hp_ratio: f32) -> Color {
+        lerp_srgb(self.boss_damaged, self.boss_full, hp_ratio)
+    }
+
+    /// Ore shop status label colour for an upgrade.
+    pub fn shop_status(&self, maxed: bool, affordable: bool) -> Color {
+        if maxed {
+            self.shop_maxed
+        } else if affordable {
+            self.shop_affordable
+        } else {
+            self.shop_unaffordable
+        }
+    }
+
+    /// Pickup diamond colour.
+    pub fn ore(&self, kind: OreKind) -> Color {
+        match kind {
+            OreKind::Iron => self.ore_iron,
+            OreKind::Ice => self.ore_ice,
+            OreKind::Rare => self.ore_rare,
+        }
+    }
+}
+
+/// Component-wise sRGB blend from `a` (`t = 0`) to `b` (`t = 1`).
+fn lerp_srgb(a: Color, b: Color, t: f32) -> Color {
+    let t = t.clamp(0.0, 1.0);
+    let (a, b) = (a.to_srgba(), b.to_srgba());
+    Color::srgba(
+        a.red + (b.red - a.red) * t,
+        a.green + (b.green - a.green) * t,
+        a.blue + (b.blue - a.blue) * t,
+        a.alpha + (b.alpha - a.alpha) * t,
+    )
+}
+
+/// Palette and UI scale chosen on the Settings screen.
+#[derive(Resource, Debug, Clone, Copy, PartialEq)]
+pub struct AccessibilitySettings {
+    pub palette: PaletteKind,
+    /// Multiplier on every UI node size and font size.
+    pub ui_scale: f32,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            palette: PaletteKind::Standard,
+            ui_scale: 1.0,
+        }
+    }
+}
+
+impl AccessibilitySettings {
+    /// Step the UI scale by `delta`, clamped to the allowed range.
+    pub fn adjust_ui_scale(&mut self, delta: f32) {
+        self.ui_scale = (self.ui_scale + delta).clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+    }
+}
+
+/// Rebuild [`Palette`] and set [`UiScale`] when the settings change.
+pub fn apply_accessibility_system(
+    settings: Res<AccessibilitySettings>,
+    mut palette: ResMut<Palette>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    if palette.kind != settings.palette {
+        *palette = Palette::new(settings.palette);
+    }
+    ui_scale.0 = settings.ui_scale;
+}
+
+pub struct PalettePlugin;
+
+impl Plugin for PalettePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AccessibilitySettings>()
+            .init_resource::<Palette>()
+            .init_resource::<UiScale>()
+            .add_systems(Update, apply_accessibility_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn close(a: Color, b: Color) -> bool {
+        let (a, b) = (a.to_srgba(), b.to_srgba());
+        (a.red - b.red).abs() + (a.green - b.green).abs() + (a.blue - b.blue).abs() < 1e-5
+    }
+
+    #[test]
+    fn every_palette_keeps_ores_and_health_ends_apart() {
+        for kind in PaletteKind::ALL {
+            let palette = Palette::new(kind);
+            let ores = [palette.ore_iron, palette.ore_ice, palette.ore_rare];
+            for (i, a) in ores.iter().enumerate() {
+                for b in &ores[i + 1..] {
+                    assert_ne!(a, b, "{kind:?} repeats an ore colour");
+                }
+            }
+            assert_ne!(palette.health(1.0), palette.health(0.0));
+            assert!(close(palette.health(1.0), palette.health_full));
+            assert_eq!(palette.enemy(0.0), palette.enemy_damaged);
+            let shop = [
+                palette.shop_status(true, true),
+                palette.shop_status(false, true),
+                palette.shop_status(false, false),
+            ];
+            for (i, a) in shop.iter().enumerate() {
+                for b in &shop[i + 1..] {
+                    assert_ne!(a, b, "{kind:?} repeats a shop status colour");
+                }
+            }
+            assert_ne!(palette.confirm, palette.cancel, "{kind:?}");
+            assert_ne!(palette.buy.border, palette.cancel.border, "{kind:?}");
+        }
+        assert_eq!(PaletteKind::Tritanopia.next(), PaletteKind::Standard);
+    }
+
+    #[test]
+    fn standard_palette_keeps_the_original_gradients() {
+        let palette = Palette::default();
+        let hp = 0.3;
+        assert!(close(palette.health(hp), Color::srgb(1.0 - hp, hp, 0.0)));
+        assert!(close(
+            palette.energy(hp),
+            Color::srgb(1.0 - hp * 0.7, 0.55 + hp * 0.35, 0.2 + hp * 0.8)
+        ));
+        assert!(close(
+            palette.enemy(hp),
+            Color::srgb(0.75 + 0.20 * hp, 0.22 + 0.10 * hp, 0.22)
+        ));
+    }
+
+    #[test]
+    fn ui_scale_steps_within_its_range() {
+        let mut settings = AccessibilitySettings::default();
+        for _ in 0..20 {
+            settings.adjust_ui_scale(UI_SCALE_STEP);
+        }
+        assert_eq!(settings.ui_scale, UI_SCALE_MAX);
+        for _ in 0..20 {
+            settings.adjust_ui_scale(-UI_SCALE_STEP);
+        }
+        assert_eq!(settings.ui_scale, UI_SCALE_MIN);
+    }
+}
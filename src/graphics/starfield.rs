@@ -0,0 +1,459 @@
+//! Parallax starfield and nebula background.
+//!
+//! Without a background the screen is pure black, and with no asteroid in
+//! view nothing shows that the camera is moving.  [`setup_starfield`] builds
+//! `starfield_layers` star layers and one nebula layer once at startup, each
+//! a single vertex-coloured `Mesh2d` holding a [`STARFIELD_TILE`]-sized tile
+//! of procedural stars (or soft clouds) repeated 3 × 3.  The tiles come from a
+//! fixed seed, so the sky is the same every run, and they never draw from
+//! `GameplayRng`.
+//!
+//! [`starfield_scroll_system`] keeps every layer centred on the camera and
+//! scaled with its zoom, then shifts it back by the camera position times the
+//! layer's parallax, wrapped to one tile ([`scroll_offset`]).  A layer with
+//! parallax 0.05 therefore drifts past at 5 % of the camera's speed.  The
+//! camera position counts from the seed's absolute origin
+//! ([`ProceduralField::origin`]), so the sky does not jump when FRONTIER
+//! recenters the world.  Layer parallax runs from `starfield_parallax_far` to
+//! `starfield_parallax_near`; the nebula sits behind them at half the far
+//! value.  Stars keep their screen size when the camera zooms.
+//!
+//! Up to [`MAX_GLOW_SOURCES`] bodies of at least `starfield_glow_min_mass`
+//! nearest the camera brighten the background around them
+//! ([`glow_boost`]).  Vertex colours are only rewritten while such a body is
+//! near, and once more when the last one leaves.  The gravity lensing pass
+//! runs after the background is drawn, so the stars also bend around planets.
+//!
+//! Layer, star and cloud counts are read at startup.
+
+use crate::asteroid::AsteroidSize;
+use crate::config::PhysicsConfig;
+use crate::simulation::procgen::ProceduralField;
+use bevy::math::DVec2;
+use bevy::prelude::*;
+use bevy_asset::RenderAssetUsages;
+use bevy_mesh::{Indices, PrimitiveTopology};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+/// Side of one repeating background tile in screen pixels.  The 3 × 3 copies
+/// cover at least one tile in every direction around the camera.
+pub const STARFIELD_TILE: f32 = 2048.0;
+
+/// Bodies that can brighten the background at once.
+pub const MAX_GLOW_SOURCES: usize = 8;
+
+/// Seed of the background tiles.
+const STARFIELD_SEED: u64 = 0x5749_4e44_0057_a11e;
+
+/// Triangles in a nebula cloud fan.
+const NEBULA_SEGMENTS: usize = 20;
+
+/// Depth of the nebula layer; star layers sit just in front of it.
+const NEBULA_Z: f32 = -0.99;
+
+/// One background layer: the local position and base colour of every vertex
+/// of its mesh, in mesh order.
+#[derive(Component, Debug, Clone)]
+pub struct StarLayer {
+    pub parallax: f32,
+    pub z: f32,
+    positions: Vec<Vec2>,
+    base_colors: Vec<[f32; 4]>,
+    indices: Vec<u32>,
+    /// Whether the colours last written include a glow.
+    glowing: bool,
+}
+
+impl StarLayer {
+    fn new(parallax: f32, z: f32, (positions, base_colors, indices): TileMesh) -> Self {
+        Self {
+            parallax,
+            z,
+            positions,
+            base_colors,
+            indices,
+            glowing: false,
+        }
+    }
+
+    /// Mesh of the layer with vertex colours `colors`.
+    fn mesh(&self, colors: Vec<[f32; 4]>) -> Mesh {
+        let positions: Vec<[f32; 3]> = self.positions.iter().map(|p| [p.x, p.y, 0.0]).collect();
+        let normals: Vec<[f32; 3]> = vec![[0.0, 0.0, 1.0]; positions.len()];
+        let uvs: Vec<[f32; 2]> = vec![[0.0, 0.0]; positions.len()];
+        let mut mesh = Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::RENDER_WORLD,
+        );
+        mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+        mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+        mesh.insert_indices(Indices::U32(self.indices.clone()));
+        mesh
+    }
+
+    /// Vertex colours with the glow of `sources`, for the layer placed at
+    /// `transform`.
+    fn glow_colors(&self, transform: &Transform, sources: &[GlowSource]) -> Vec<[f32; 4]> {
+        let origin = transform.translation.truncate();
+        let scale = transform.scale.x;
+        self.positions
+            .iter()
+            .zip(&self.base_colors)
+            .map(|(&local, &[r, g, b, a])| {
+                let k = 1.0 + glow_boost(origin + local * scale, sources);
+                [r * k, g * k, b * k, a]
+            })
+            .collect()
+    }
+}
+
+/// Vertex positions, colours and indices of a repeated tile.
+type TileMesh = (Vec<Vec2>, Vec<[f32; 4]>, Vec<u32>);
+
+/// A body brightening the background: world position, glow radius and peak
+/// boost.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GlowSource {
+    pub position: Vec2,
+    pub radius: f32,
+    pub strength: f32,
+}
+
+impl GlowSource {
+    /// Glow of a body of `mass` at `position`, if it is heavy enough.
+    pub fn of_body(position: Vec2, mass: f32, config: &PhysicsConfig) -> Option<Self> {
+        if config.starfield_glow_strength <= 0.0 || mass < config.starfield_glow_min_mass {
+            return None;
+        }
+        let body_radius = (mass / config.asteroid_density / std::f32::consts::PI).sqrt();
+        Some(Self {
+            position,
+            radius: body_radius * config.starfield_glow_radius_scale.max(1.0),
+            strength: config.starfield_glow_strength * mass
+                / (mass + config.starfield_glow_reference_mass.max(1.0)),
+        })
+    }
+}
+
+/// Parallax of star layer `index`, 0 being the farthest.
+pub fn layer_parallax(index: u32, config: &PhysicsConfig) -> f32 {
+    let t = if config.starfield_layers > 1 {
+        index as f32 / (config.starfield_layers - 1) as f32
+    } else {
+        0.0
+    };
+    config.starfield_parallax_far
+        + (config.starfield_parallax_near - config.starfield_parallax_far) * t
+}
+
+/// How far a layer has scrolled for a camera at `camera` in a world whose
+/// origin sits at `origin` (absolute units), wrapped to `[-tile / 2, tile / 2)`.
+///
+/// The origin part is wrapped in `f64` on its own, so it stays exact however
+/// far the world has been recentered.
+pub fn scroll_offset(camera: Vec2, origin: DVec2, parallax: f32, tile: f32) -> Vec2 {
+    let origin_scroll = (origin * f64::from(parallax))
+        .rem_euclid(DVec2::splat(f64::from(tile)))
+        .as_vec2();
+    let half = Vec2::splat(tile * 0.5);
+    (camera * parallax + origin_scroll + half).rem_euclid(Vec2::splat(tile)) - half
+}
+
+/// Transform of a layer behind a camera at `camera`, with the world origin at
+/// `origin` (see [`scroll_offset`]).
+pub fn layer_transform(camera: &Transform, origin: DVec2, parallax: f32, z: f32) -> Transform {
+    let scale = camera.scale.x;
+    let offset = scroll_offset(
+        camera.translation.truncate(),
+        origin,
+        parallax,
+        STARFIELD_TILE,
+    );
+    Transform {
+        translation: (camera.translation.truncate() - offset * scale).extend(z),
+        rotation: Quat::IDENTITY,
+        scale: Vec3::new(scale, scale, 1.0),
+    }
+}
+
+/// Brightening at `point`: each source adds its strength × (1 − d / r)²,
+/// capped at the strongest source's strength.
+pub fn glow_boost(point: Vec2, sources: &[GlowSource]) -> f32 {
+    let mut boost = 0.0;
+    let mut cap = 0.0f32;
+    for source in sources {
+        let d = point.distance(source.position);
+        if d < source.radius {
+            let falloff = 1.0 - d / source.radius;
+            boost += source.strength * falloff * falloff;
+            cap = cap.max(source.strength);
+        }
+    }
+    boost.min(cap)
+}
+
+/// Build the 3 × 3 repeat of one tile's shapes.  `shape` appends the shape
+/// of one item shifted by a whole tile.
+fn repeat_tile<T>(items: &[T], mut shape: impl FnMut(&T, Vec2, &mut TileMesh)) -> TileMesh {
+    let mut mesh = TileMesh::default();
+    for dy in -1..=1 {
+        for dx in -1..=1 {
+            let shift = Vec2::new(dx as f32, dy as f32) * STARFIELD_TILE;
+            for item in items {
+                shape(item, shift, &mut mesh);
+            }
+        }
+    }
+    mesh
+}
+
+/// Uniform random point in a tile centred on the origin.
+fn tile_point(rng: &mut StdRng) -> Vec2 {
+    (Vec2::new(rng.gen::<f32>(), rng.gen::<f32>()) - Vec2::splat(0.5)) * STARFIELD_TILE
+}
+
+/// Star layer `index`: small diamonds, smaller and dimmer the farther the
+/// layer.
+fn star_layer(index: u32, config: &PhysicsConfig) -> StarLayer {
+    let mut rng = StdRng::seed_from_u64(STARFIELD_SEED ^ (index as u64 + 1));
+    let depth = if config.starfield_layers > 1 {
+        index as f32 / (config.starfield_layers - 1) as f32
+    } else {
+        1.0
+    };
+    let stars: Vec<(Vec2, f32, [f32; 4])> = (0..config.starfield_stars_per_layer)
+        .map(|_| {
+            let pos = tile_point(&mut rng);
+            let size = (0.8 + 0.8 * depth) * rng.gen_range(0.7..1.3);
+            let light = rng.gen_range(0.35..0.9) * (0.6 + 0.4 * depth);
+            // Mostly white, some blue-white and some warm stars.
+            let tint = match rng.gen_range(0..6) {
+                0 => [0.75, 0.85, 1.0],
+                1 => [1.0, 0.9, 0.7],
+                _ => [1.0, 1.0, 1.0],
+            };
+            (
+                pos,
+                size,
+                [tint[0] * light, tint[1] * light, tint[2] * light, 1.0],
+            )
+        })
+        .collect();
+    let tile = repeat_tile(
+        &stars,
+        |&(pos, size, color), shift, (positions, colors, indices)| {
+            let base = positions.len() as u32;
+            for corner in [Vec2::Y, Vec2::X, Vec2::NEG_Y, Vec2::NEG_X] {
+                positions.push(pos + shift + corner * size);
+                colors.push(color);
+            }
+            indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+        },
+    );
+    StarLayer::new(
+        layer_parallax(index, config),
+        NEBULA_Z + 0.01 * (index + 1) as f32,
+        tile,
+    )
+}
+
+/// Nebula layer: soft fans fading from a dim centre colour to nothing.
+fn nebula_layer(config: &PhysicsConfig) -> StarLayer {
+    let mut rng = StdRng::seed_from_u64(STARFIELD_SEED);
+    let clouds: Vec<(Vec2, f32, [f32; 4])> = (0..config.starfield_nebula_clouds)
+        .map(|_| {
+            let pos = tile_point(&mut rng);
+            let radius = rng.gen_range(250.0..650.0);
+            // Faint violet to teal.
+            let t = rng.gen::<f32>();
+            let alpha = rng.gen_range(0.08..0.16);
+            (pos, radius, [0.25 - 0.15 * t, 0.1 + 0.15 * t, 0.35, alpha])
+        })
+        .collect();
+    let tile = repeat_tile(
+        &clouds,
+        |&(pos, radius, color), shift, (positions, colors, indices)| {
+            let base = positions.len() as u32;
+            positions.push(pos + shift);
+            colors.push(color);
+            for i in 0..NEBULA_SEGMENTS {
+                let angle = i as f32 / NEBULA_SEGMENTS as f32 * std::f32::consts::TAU;
+                positions.push(pos + shift + Vec2::from_angle(angle) * radius);
+                colors.push([color[0], color[1], color[2], 0.0]);
+            }
+            for i in 0..NEBULA_SEGMENTS as u32 {
+                let next = (i + 1) % NEBULA_SEGMENTS as u32;
+                indices.extend_from_slice(&[base, base + 1 + i, base + 1 + next]);
+            }
+        },
+    );
+    StarLayer::new(config.starfield_parallax_far * 0.5, NEBULA_Z, tile)
+}
+
+/// Spawn the nebula and star layers.
+pub fn setup_starfield(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    config: Res<PhysicsConfig>,
+) {
+    // The default material blends, so the nebula's vertex alpha shows.
+    let material = materials.add(ColorMaterial::default());
+    let layers = std::iter::once(nebula_layer(&config))
+        .chain((0..config.starfield_layers).map(|index| star_layer(index, &config)));
+    for layer in layers {
+        let mesh = meshes.add(layer.mesh(layer.base_colors.clone()));
+        commands.spawn((
+            Mesh2d(mesh),
+            MeshMaterial2d(material.clone()),
+            Transform::from_translation(Vec3::new(0.0, 0.0, layer.z)),
+            if config.starfield_enabled {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            },
+            layer,
+        ));
+    }
+}
+
+/// Keep the background behind the camera and brighten it near heavy bodies.
+#[allow(clippy::type_complexity)]
+pub fn starfield_scroll_system(
+    config: Res<PhysicsConfig>,
+    field: Option<Res<ProceduralField>>,
+    q_camera: Query<&Transform, (With<Camera>, Without<StarLayer>)>,
+    q_bodies: Query<(&Transform, &AsteroidSize), (Without<Camera>, Without<StarLayer>)>,
+    mut q_layers: Query<(&mut StarLayer, &mut Transform, &mut Visibility, &Mesh2d)>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let Ok(camera) = q_camera.single() else {
+        return;
+    };
+    if !config.starfield_enabled {
+        for (_, _, mut vis, _) in q_layers.iter_mut() {
+            vis.set_if_neq(Visibility::Hidden);
+        }
+        return;
+    }
+
+    let origin = field.map_or(DVec2::ZERO, |field| {
+        field.origin.as_dvec2() * f64::from(config.sector_size)
+    });
+    let centre = camera.translation.truncate();
+    // The repeated tile reaches past the view, so bodies beyond one tile of
+    // glow range cannot light anything on screen.
+    let reach = STARFIELD_TILE * camera.scale.x;
+    let mut sources: Vec<GlowSource> = q_bodies
+        .iter()
+        .filter_map(|(transform, size)| {
+            GlowSource::of_body(transform.translation.truncate(), size.0 as f32, &config)
+        })
+        .filter(|source| source.position.distance(centre) < source.radius + reach)
+        .collect();
+    sources.sort_by(|a, b| {
+        a.position
+            .distance_squared(centre)
+            .total_cmp(&b.position.distance_squared(centre))
+    });
+    sources.truncate(MAX_GLOW_SOURCES);
+
+    for (mut layer, mut transform, mut vis, mesh_handle) in q_layers.iter_mut() {
+        vis.set_if_neq(Visibility::Visible);
+        *transform = layer_transform(camera, origin, layer.parallax, layer.z);
+        if sources.is_empty() && !layer.glowing {
+            continue;
+        }
+        let colors = if sources.is_empty() {
+            layer.base_colors.clone()
+        } else {
+            layer.glow_colors(&transform, &sources)
+        };
+        layer.glowing = !sources.is_empty();
+        if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+            *mesh = layer.mesh(colors);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layers_scroll_at_their_parallax_and_wrap() {
+        let config = PhysicsConfig::default();
+        let far = layer_parallax(0, &config);
+        let near = layer_parallax(config.starfield_layers - 1, &config);
+        assert_eq!(far, config.starfield_parallax_far);
+        assert!((near - config.starfield_parallax_near).abs() < 1e-6);
+
+        // A star's screen position moves by the camera motion times the
+        // layer's parallax.
+        let camera = Transform::from_translation(Vec3::new(100.0, -40.0, 0.0));
+        let moved = Transform::from_translation(Vec3::new(300.0, -40.0, 0.0));
+        let local = Vec2::new(12.0, 34.0);
+        let screen = |cam: &Transform| {
+            let layer = layer_transform(cam, DVec2::ZERO, near, 0.0);
+            layer.translation.truncate() + local * layer.scale.x - cam.translation.truncate()
+        };
+        let shift = screen(&moved) - screen(&camera);
+        assert!(
+            (shift - Vec2::new(-200.0 * near, 0.0)).length() < 1e-3,
+            "{shift}"
+        );
+
+        // Far from the origin the offset stays within one tile.
+        let offset = scroll_offset(Vec2::splat(1.0e7), DVec2::ZERO, near, STARFIELD_TILE);
+        assert!(offset.abs().max_element() <= STARFIELD_TILE * 0.5);
+    }
+
+    #[test]
+    fn recentering_the_origin_does_not_move_the_sky() {
+        let config = PhysicsConfig::default();
+        let near = layer_parallax(config.starfield_layers - 1, &config);
+        let camera = Vec2::new(20_345.5, -7_812.25);
+        let chunks = crate::spatial_partition::sector_of(camera, config.sector_size);
+        let shift = chunks.as_vec2() * config.sector_size;
+
+        let before = scroll_offset(camera, DVec2::ZERO, near, STARFIELD_TILE);
+        let after = scroll_offset(camera - shift, shift.as_dvec2(), near, STARFIELD_TILE);
+        let jump = (after - before + Vec2::splat(STARFIELD_TILE * 0.5))
+            .rem_euclid(Vec2::splat(STARFIELD_TILE))
+            - Vec2::splat(STARFIELD_TILE * 0.5);
+        assert!(jump.length() < 1e-2, "sky jumped by {jump}");
+    }
+
+    #[test]
+    fn only_heavy_bodies_brighten_nearby_stars() {
+        let config = PhysicsConfig::default();
+        assert!(
+            GlowSource::of_body(Vec2::ZERO, config.starfield_glow_min_mass * 0.5, &config)
+                .is_none()
+        );
+        let source = GlowSource::of_body(Vec2::ZERO, 400.0, &config).unwrap();
+        let sources = [source];
+        assert!((glow_boost(Vec2::ZERO, &sources) - source.strength).abs() < 1e-6);
+        let halfway = glow_boost(Vec2::X * source.radius * 0.5, &sources);
+        assert!(halfway > 0.0 && halfway < source.strength);
+        assert_eq!(glow_boost(Vec2::X * source.radius * 1.01, &sources), 0.0);
+        // Overlapping glows never exceed the strongest one.
+        assert!(glow_boost(Vec2::ZERO, &[source, source]) <= source.strength + 1e-6);
+    }
+
+    #[test]
+    fn the_sky_is_the_same_every_run() {
+        let config = PhysicsConfig::default();
+        let a = star_layer(1, &config);
+        let b = star_layer(1, &config);
+        assert_eq!(a.positions, b.positions);
+        assert_eq!(
+            a.positions.len(),
+            9 * 4 * config.starfield_stars_per_layer as usize
+        );
+        assert_ne!(a.positions, star_layer(0, &config).positions);
+    }
+}
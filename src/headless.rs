@@ -0,0 +1,285 @@
+//! Windowless scenario runs for tooling and parameter sweeps.
+//!
+//! [`run_scenario_headless`] builds a physics-only app: Rapier, gravity,
+//! shockwaves, merging and sector streaming, with no window, renderer, input,
+//! ship or enemies.  It places a scenario, steps it for a number of frames and
+//! returns a [`ScenarioReport`] of the final field.
+//!
+//! Each frame advances exactly one physics tick (`physics_tick_hz`), so the
+//! simulated time does not depend on how fast the host runs.  Frame times in
+//! the report are wall-clock.  The scenario is placed in the first frame
+//! rather than staggered.
+//!
+//! ```no_run
+//! use accretion::config::PhysicsConfig;
+//! use accretion::headless::run_scenario_headless_with;
+//! use accretion::menu::SelectedScenario;
+//!
+//! for gravity_const in [5.0, 10.0, 20.0] {
+//!     let config = PhysicsConfig { gravity_const, ..Default::default() };
+//!     let report = run_scenario_headless_with(config, SelectedScenario::Field, 7, 600);
+//!     println!("G={gravity_const}: largest {}", report.largest_mass);
+//! }
+//! ```
+
+use crate::asteroid::{Asteroid, AsteroidSize, Planet};
+use crate::config::PhysicsConfig;
+use crate::feedback::FeedbackEvent;
+use crate::lineage::BiggestAccretion;
+use crate::loading::{queue_scenario, scenario_spawn_drain_system, ScenarioSpawnQueue};
+use crate::menu::{GameState, SelectedScenario};
+use crate::particles::ParticlesPlugin;
+use crate::rng::GameplayRng;
+use crate::simulation::collision_response::{
+    collision_response_system, record_pre_step_velocities_system, PreStepVelocities,
+};
+use crate::simulation::fixed_step::{physics_tick_hz, sync_physics_tick_rate_system};
+use crate::simulation::governor::FrameTimeGovernor;
+use crate::simulation::merge_queue::{merge_resolution_system, MergeIntent, MergeScratch};
+use crate::simulation::procgen::{procedural_field_system, ProceduralField};
+use crate::simulation::rapier_guard::{
+    apply_physics_resume_system, validate_rapier_handles_system, PhysicsResumeGuard,
+};
+use crate::simulation::sectors::{sector_streaming_system, SectorStore};
+use crate::simulation::shockwave::{shockwave_force_system, Shockwave};
+use crate::simulation::size_class::SizeClassReached;
+use crate::simulation::{
+    asteroid_formation_system, merge_cooldown_system, nbody_gravity_system,
+    neighbor_counting_system, particle_locking_system, soft_boundary_system, stats_counting_system,
+    FormationScratch, GravityScratch, SimulationStats,
+};
+use crate::spatial_partition::{rebuild_spatial_grid_system, SpatialGrid};
+use crate::testing::FrameStats;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use bevy_rapier2d::prelude::*;
+use std::time::{Duration, Instant};
+
+/// Final state of a headless scenario run.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ScenarioReport {
+    pub scenario: SelectedScenario,
+    pub seed: u64,
+    pub frames: u32,
+    /// Simulated time (s): one physics tick per frame.
+    pub sim_secs: f32,
+    /// Free asteroids left in the world; planets and bodies parked in the
+    /// sector store are not counted.
+    pub bodies: u32,
+    /// Sum of [`AsteroidSize`] over those bodies.
+    pub total_mass: u32,
+    /// Body count per mass bucket: bucket `i` holds masses in `[2^i, 2^(i+1))`.
+    pub mass_histogram: Vec<u32>,
+    pub largest_mass: u32,
+    /// Composites formed during the run.
+    pub merges: u32,
+    /// Wall-clock cost of each frame (ms).
+    pub frame_ms: FrameStats,
+}
+
+/// Bucket counts for `masses`; see [`ScenarioReport::mass_histogram`].
+pub fn mass_histogram(masses: impl IntoIterator<Item = u32>) -> Vec<u32> {
+    let mut buckets = Vec::new();
+    for mass in masses {
+        let bucket = mass.max(1).ilog2() as usize;
+        if buckets.len() <= bucket {
+            buckets.resize(bucket + 1, 0);
+        }
+        buckets[bucket] += 1;
+    }
+    buckets
+}
+
+/// Run `scenario` with the compiled-default config.
+pub fn run_scenario_headless(scenario: SelectedScenario, seed: u64, frames: u32) -> ScenarioReport {
+    run_scenario_headless_with(PhysicsConfig::default(), scenario, seed, frames)
+}
+
+/// Run `scenario` with `config` for `frames` physics ticks.
+pub fn run_scenario_headless_with(
+    config: PhysicsConfig,
+    scenario: SelectedScenario,
+    seed: u64,
+    frames: u32,
+) -> ScenarioReport {
+    let mut app = headless_app(config, scenario, seed);
+
+    let mut frame_ms = Vec::with_capacity(frames as usize);
+    for _ in 0..frames {
+        let start = Instant::now();
+        app.update();
+        frame_ms.push(start.elapsed().as_secs_f32() * 1000.0);
+    }
+
+    let world = app.world_mut();
+    let masses: Vec<u32> = world
+        .query_filtered::<&AsteroidSize, (With<Asteroid>, Without<Planet>)>()
+        .iter(world)
+        .map(|size| size.0)
+        .collect();
+    let tick_hz = physics_tick_hz(world.resource::<PhysicsConfig>());
+    ScenarioReport {
+        scenario,
+        seed,
+        frames,
+        sim_secs: frames as f32 / tick_hz,
+        bodies: masses.len() as u32,
+        total_mass: masses.iter().sum(),
+        mass_histogram: mass_histogram(masses.iter().copied()),
+        largest_mass: masses.iter().copied().max().unwrap_or(0),
+        merges: world.resource::<SimulationStats>().merged_total,
+        frame_ms: FrameStats::from_samples(&frame_ms),
+    }
+}
+
+/// Physics-only app with `scenario` queued for the first frame.
+pub fn headless_app(mut config: PhysicsConfig, scenario: SelectedScenario, seed: u64) -> App {
+    // Place the whole scenario in the first frame.
+    config.scenario_spawn_budget = u32::MAX;
+    let tick = Duration::from_secs_f32(1.0 / physics_tick_hz(&config));
+
+    let mut queue = ScenarioSpawnQueue::default();
+    queue_scenario(&mut queue, &config, scenario, seed);
+    let field = if scenario == SelectedScenario::Frontier {
+        ProceduralField::new(seed)
+    } else {
+        ProceduralField::default()
+    };
+
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        AssetPlugin::default(),
+        bevy::scene::ScenePlugin,
+        StatesPlugin,
+    ))
+    .init_asset::<Mesh>()
+    .init_asset::<ColorMaterial>()
+    .insert_state(GameState::Playing)
+    .insert_resource(TimeUpdateStrategy::ManualDuration(tick))
+    .insert_resource(Time::<Fixed>::from_duration(tick))
+    .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0).in_fixed_schedule())
+    .insert_resource(TimestepMode::Fixed {
+        dt: tick.as_secs_f32(),
+        substeps: config.physics_substeps.max(1),
+    })
+    .insert_resource(config)
+    .insert_resource(queue)
+    .insert_resource(field)
+    .add_plugins(ParticlesPlugin)
+    .insert_resource(SimulationStats::default())
+    .insert_resource(GravityScratch::default())
+    .insert_resource(FormationScratch::default())
+    .insert_resource(MergeScratch::default())
+    .insert_resource(PreStepVelocities::default())
+    .insert_resource(GameplayRng::from_run_seed(seed))
+    .insert_resource(FrameTimeGovernor::default())
+    .insert_resource(PhysicsResumeGuard::default())
+    .insert_resource(SpatialGrid::default())
+    .insert_resource(SectorStore::default())
+    .insert_resource(BiggestAccretion::default())
+    .add_message::<Shockwave>()
+    .add_message::<FeedbackEvent>()
+    .add_message::<SizeClassReached>()
+    .add_message::<MergeIntent>()
+    .add_systems(
+        Startup,
+        (zero_rapier_gravity_system, sync_physics_tick_rate_system),
+    )
+    .add_systems(
+        Update,
+        (
+            scenario_spawn_drain_system,
+            procedural_field_system,
+            sector_streaming_system,
+            stats_counting_system,
+        )
+            .chain(),
+    )
+    .add_systems(
+        FixedUpdate,
+        (
+            rebuild_spatial_grid_system,
+            nbody_gravity_system,
+            shockwave_force_system,
+            soft_boundary_system,
+            neighbor_counting_system,
+        )
+            .chain(),
+    )
+    .add_systems(
+        FixedPostUpdate,
+        (
+            merge_cooldown_system,
+            collision_response_system,
+            particle_locking_system,
+            asteroid_formation_system,
+            merge_resolution_system,
+        )
+            .chain()
+            .after(PhysicsSet::Writeback),
+    )
+    .add_systems(
+        FixedPostUpdate,
+        record_pre_step_velocities_system.before(PhysicsSet::SyncBackend),
+    )
+    .add_systems(
+        FixedPostUpdate,
+        (
+            validate_rapier_handles_system
+                .after(PhysicsSet::SyncBackend)
+                .before(PhysicsSet::StepSimulation),
+            apply_physics_resume_system.after(PhysicsSet::Writeback),
+        ),
+    );
+    app
+}
+
+/// Space has no ambient gravity; bodies only attract each other.
+fn zero_rapier_gravity_system(mut config: Query<&mut RapierConfiguration>) {
+    for mut cfg in config.iter_mut() {
+        cfg.gravity = Vec2::ZERO;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_buckets_by_powers_of_two() {
+        assert_eq!(
+            mass_histogram([1, 2, 3, 4, 7, 8, 40]),
+            vec![1, 2, 2, 1, 0, 1]
+        );
+        assert!(mass_histogram([]).is_empty());
+    }
+
+    #[test]
+    fn field_run_reports_the_final_field() {
+        let report = run_scenario_headless(SelectedScenario::Field, 11, 120);
+
+        assert_eq!(report.frames, 120);
+        let tick_hz = physics_tick_hz(&PhysicsConfig::default());
+        assert!((report.sim_secs - 120.0 / tick_hz).abs() < 1e-4);
+        assert!(report.bodies > 0);
+        assert_eq!(
+            report.mass_histogram.iter().sum::<u32>(),
+            report.bodies,
+            "every body is in one bucket"
+        );
+        assert!(report.largest_mass >= 1 && report.largest_mass <= report.total_mass);
+        assert!(report.frame_ms.max >= report.frame_ms.min);
+    }
+
+    #[test]
+    fn merging_conserves_mass() {
+        let start = run_scenario_headless(SelectedScenario::Field, 5, 1);
+        let end = run_scenario_headless(SelectedScenario::Field, 5, 600);
+        assert_eq!(start.total_mass, end.total_mass);
+        assert!(end.bodies <= start.bodies);
+        assert!(end.largest_mass >= start.largest_mass);
+    }
+}
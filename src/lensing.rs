@@ -0,0 +1,299 @@
+//! Gravity lensing: a screen-space distortion around massive bodies.
+//!
+//! Every frame [`update_gravity_lensing_system`] projects the heaviest
+//! on-screen planets into the main camera's viewport and writes them into the
+//! camera's [`GravityLensing`] component.  The component is extracted to the
+//! render world as a uniform, and [`LensingNode`] runs a fullscreen pass
+//! after tonemapping that offsets each pixel's texture lookup towards the
+//! nearest lenses, so the starfield and bodies behind a planet bend around it.
+//!
+//! The offset of a lens falls off quadratically from the body's rim to the
+//! edge of its lens radius (`gravity_lensing_radius_scale` × body radius) and
+//! scales with `mass / (mass + gravity_lensing_reference_mass)`, so the Orbit
+//! and Catalog central wells dominate while planetoids barely ripple.  The
+//! body itself is left undistorted.
+//!
+//! The pass is skipped entirely when `gravity_lensing_enabled` is off or the
+//! frame-time governor has dropped below [`QualityTier::Full`].
+
+use crate::asteroid::{AsteroidSize, Planet};
+use crate::config::PhysicsConfig;
+use crate::simulation::governor::{FrameTimeGovernor, QualityTier};
+use bevy::core_pipeline::core_2d::graph::{Core2d, Node2d};
+use bevy::core_pipeline::FullscreenShader;
+use bevy::ecs::query::QueryItem;
+use bevy::image::BevyDefault;
+use bevy::prelude::*;
+use bevy::render::extract_component::{
+    ComponentUniforms, DynamicUniformIndex, ExtractComponent, ExtractComponentPlugin,
+    UniformComponentPlugin,
+};
+use bevy::render::render_graph::{
+    NodeRunError, RenderGraphContext, RenderGraphExt, RenderLabel, ViewNode, ViewNodeRunner,
+};
+use bevy::render::render_resource::binding_types::{sampler, texture_2d, uniform_buffer};
+use bevy::render::render_resource::*;
+use bevy::render::renderer::{RenderContext, RenderDevice};
+use bevy::render::view::ViewTarget;
+use bevy::render::{RenderApp, RenderStartup};
+
+const LENSING_SHADER_PATH: &str = "shaders/gravity_lensing.wgsl";
+
+/// Lenses the shader evaluates per pixel; must match `MAX_LENSES` in the
+/// WGSL file.
+pub const MAX_LENSES: usize = 8;
+
+/// Lens list uploaded to the distortion shader.  Attached to the main camera.
+#[derive(Component, Clone, Copy, Debug, Default, ExtractComponent, ShaderType)]
+pub struct GravityLensing {
+    /// Per lens: viewport UV centre (`xy`), lens radius in viewport heights
+    /// (`z`) and peak offset as a fraction of that radius (`w`).
+    pub lenses: [Vec4; MAX_LENSES],
+    /// Number of valid entries in `lenses`; 0 skips the pass.
+    pub count: u32,
+    /// Viewport width / height.
+    pub aspect: f32,
+    /// Body radius / lens radius: the undistorted core.
+    pub core_fraction: f32,
+}
+
+impl GravityLensing {
+    /// Keep the strongest `MAX_LENSES` of `candidates`.
+    pub fn set_lenses(&mut self, mut candidates: Vec<Vec4>) {
+        candidates.sort_by(|a, b| (b.w * b.z).total_cmp(&(a.w * a.z)));
+        candidates.truncate(MAX_LENSES);
+        self.lenses = [Vec4::ZERO; MAX_LENSES];
+        self.lenses[..candidates.len()].copy_from_slice(&candidates);
+        self.count = candidates.len() as u32;
+    }
+}
+
+/// Peak distortion for a body of `mass`: rises towards
+/// `gravity_lensing_strength` as the mass passes the reference mass.
+pub fn lens_strength(mass: u32, config: &PhysicsConfig) -> f32 {
+    let mass = mass as f32;
+    config.gravity_lensing_strength.max(0.0) * mass
+        / (mass + config.gravity_lensing_reference_mass.max(1.0))
+}
+
+pub struct LensingPlugin;
+
+impl Plugin for LensingPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_plugins((
+            ExtractComponentPlugin::<GravityLensing>::default(),
+            UniformComponentPlugin::<GravityLensing>::default(),
+        ))
+        .add_systems(PostUpdate, update_gravity_lensing_system);
+
+        let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
+            return;
+        };
+        render_app
+            .add_systems(RenderStartup, init_lensing_pipeline)
+            .add_render_graph_node::<ViewNodeRunner<LensingNode>>(Core2d, LensingLabel)
+            .add_render_graph_edges(
+                Core2d,
+                (
+                    Node2d::Tonemapping,
+                    LensingLabel,
+                    Node2d::EndMainPassPostProcessing,
+                ),
+            );
+    }
+}
+
+/// Project the heaviest visible planets into the camera's lens list.
+#[allow(clippy::type_complexity)]
+pub fn update_gravity_lensing_system(
+    config: Res<PhysicsConfig>,
+    governor: Option<Res<FrameTimeGovernor>>,
+    mut q_camera: Query<(&Camera, &GlobalTransform, &mut GravityLensing)>,
+    q_planets: Query<(&GlobalTransform, &AsteroidSize), With<Planet>>,
+) {
+    let full_quality = governor.is_none_or(|g| g.tier == QualityTier::Full);
+    for (camera, camera_transform, mut lensing) in q_camera.iter_mut() {
+        let viewport = camera.logical_viewport_size().unwrap_or(Vec2::ONE);
+        if !config.gravity_lensing_enabled || !full_quality || viewport.y <= 0.0 {
+            if lensing.count != 0 {
+                lensing.count = 0;
+            }
+            continue;
+        }
+
+        let radius_scale = config.gravity_lensing_radius_scale.max(1.0);
+        let mut candidates = Vec::new();
+        for (transform, size) in q_planets.iter() {
+            let centre = transform.translation();
+            let body_radius =
+                (size.0 as f32 / config.asteroid_density / std::f32::consts::PI).sqrt();
+            let (Ok(centre_px), Ok(rim_px)) = (
+                camera.world_to_viewport(camera_transform, centre),
+                camera.world_to_viewport(
+                    camera_transform,
+                    centre + Vec3::X * body_radius * radius_scale,
+                ),
+            ) else {
+                continue;
+            };
+            let radius = centre_px.distance(rim_px) / viewport.y;
+            let uv = centre_px / viewport;
+            let margin = Vec2::new(radius * viewport.y / viewport.x, radius);
+            if uv.cmplt(-margin).any() || uv.cmpgt(Vec2::ONE + margin).any() {
+                continue;
+            }
+            candidates.push(Vec4::new(
+                uv.x,
+                uv.y,
+                radius,
+                lens_strength(size.0, &config),
+            ));
+        }
+
+        lensing.aspect = viewport.x / viewport.y;
+        lensing.core_fraction = 1.0 / radius_scale;
+        lensing.set_lenses(candidates);
+    }
+}
+
+#[derive(Debug, Hash, PartialEq, Eq, Clone, RenderLabel)]
+pub struct LensingLabel;
+
+/// Fullscreen distortion pass over the main view target.
+#[derive(Default)]
+pub struct LensingNode;
+
+impl ViewNode for LensingNode {
+    type ViewQuery = (
+        &'static ViewTarget,
+        &'static GravityLensing,
+        &'static DynamicUniformIndex<GravityLensing>,
+    );
+
+    fn run<'w>(
+        &self,
+        _graph: &mut RenderGraphContext,
+        render_context: &mut RenderContext<'w>,
+        (view_target, lensing, uniform_index): QueryItem<'w, '_, Self::ViewQuery>,
+        world: &'w World,
+    ) -> Result<(), NodeRunError> {
+        if lensing.count == 0 {
+            return Ok(());
+        }
+        let lensing_pipeline = world.resource::<LensingPipeline>();
+        let pipeline_cache = world.resource::<PipelineCache>();
+        let Some(pipeline) = pipeline_cache.get_render_pipeline(lensing_pipeline.pipeline_id)
+        else {
+            return Ok(());
+        };
+        let uniforms = world.resource::<ComponentUniforms<GravityLensing>>();
+        let Some(uniform_binding) = uniforms.uniforms().binding() else {
+            return Ok(());
+        };
+
+        let post_process = view_target.post_process_write();
+        let bind_group = render_context.render_device().create_bind_group(
+            "gravity_lensing_bind_group",
+            &lensing_pipeline.layout,
+            &BindGroupEntries::sequential((
+                post_process.source,
+                &lensing_pipeline.sampler,
+                uniform_binding,
+            )),
+        );
+        let mut render_pass = render_context.begin_tracked_render_pass(RenderPassDescriptor {
+            label: Some("gravity_lensing_pass"),
+            color_attachments: &[Some(RenderPassColorAttachment {
+                view: post_process.destination,
+                depth_slice: None,
+                resolve_target: None,
+                ops: Operations::default(),
+            })],
+            depth_stencil_attachment: None,
+            timestamp_writes: None,
+            occlusion_query_set: None,
+        });
+        render_pass.set_render_pipeline(pipeline);
+        render_pass.set_bind_group(0, &bind_group, &[uniform_index.index()]);
+        render_pass.draw(0..3, 0..1);
+        Ok(())
+    }
+}
+
+#[derive(Resource)]
+struct LensingPipeline {
+    layout: BindGroupLayout,
+    sampler: Sampler,
+    pipeline_id: CachedRenderPipelineId,
+}
+
+fn init_lensing_pipeline(
+    mut commands: Commands,
+    render_device: Res<RenderDevice>,
+    asset_server: Res<AssetServer>,
+    fullscreen_shader: Res<FullscreenShader>,
+    pipeline_cache: Res<PipelineCache>,
+) {
+    let layout = render_device.create_bind_group_layout(
+        "gravity_lensing_bind_group_layout",
+        &BindGroupLayoutEntries::sequential(
+            ShaderStages::FRAGMENT,
+            (
+                texture_2d(TextureSampleType::Float { filterable: true }),
+                sampler(SamplerBindingType::Filtering),
+                uniform_buffer::<GravityLensing>(true),
+            ),
+        ),
+    );
+    let sampler = render_device.create_sampler(&SamplerDescriptor::default());
+    let pipeline_id = pipeline_cache.queue_render_pipeline(RenderPipelineDescriptor {
+        label: Some("gravity_lensing_pipeline".into()),
+        layout: vec![layout.clone()],
+        vertex: fullscreen_shader.to_vertex_state(),
+        fragment: Some(FragmentState {
+            shader: asset_server.load(LENSING_SHADER_PATH),
+            targets: vec![Some(ColorTargetState {
+                format: TextureFormat::bevy_default(),
+                blend: None,
+                write_mask: ColorWrites::ALL,
+            })],
+            ..default()
+        }),
+        ..default()
+    });
+    commands.insert_resource(LensingPipeline {
+        layout,
+        sampler,
+        pipeline_id,
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heavier_bodies_lens_harder_up_to_the_configured_strength() {
+        let config = PhysicsConfig::default();
+        let planetoid = lens_strength(config.planetoid_unit_size, &config);
+        let well = lens_strength(2800, &config);
+
+        assert!(planetoid > 0.0 && planetoid < well);
+        assert!(well < config.gravity_lensing_strength);
+        let half = lens_strength(config.gravity_lensing_reference_mass as u32, &config);
+        assert!((half - config.gravity_lensing_strength * 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn only_the_strongest_lenses_are_uploaded() {
+        let mut lensing = GravityLensing::default();
+        let candidates = (0..12)
+            .map(|i| Vec4::new(0.5, 0.5, 0.1, i as f32 * 0.01))
+            .collect();
+        lensing.set_lenses(candidates);
+
+        assert_eq!(lensing.count as usize, MAX_LENSES);
+        assert_eq!(lensing.lenses[0].w, 11.0 * 0.01);
+        assert!(lensing.lenses.iter().all(|lens| lens.w >= 0.04));
+    }
+}
@@ -3,18 +3,40 @@
 pub mod alloc_profile;
 pub mod asteroid;
 pub mod asteroid_rendering;
+pub mod attribution;
+pub mod audio;
 pub mod campaign;
 pub mod config;
 pub mod constants;
+pub mod difficulty;
 pub mod enemy;
 pub mod error;
+pub mod feedback;
 pub mod graphics;
+pub mod headless;
+pub mod lensing;
+pub mod lineage;
+pub mod loading;
 pub mod menu;
 pub mod mining;
+pub mod orbital_import;
 pub mod particles;
 pub mod player;
+pub mod profile;
+pub mod reflection;
 pub mod rendering;
+pub mod report;
+pub mod rng;
+pub mod run_info;
 pub mod save;
+pub mod scoring;
+pub mod session_stats;
 pub mod simulation;
 pub mod spatial_partition;
+pub mod speedrun;
+pub mod survival;
+pub mod sweep;
 pub mod testing;
+pub mod timelapse;
+pub mod victory;
+pub mod wave_telemetry;
@@ -0,0 +1,285 @@
+//! Merge history of accreted bodies.
+//!
+//! Every asteroid produced by `merge_resolution_system` carries a
+//! [`Lineage`]: the tree of bodies that merged to form it, with each
+//! ancestor's mass and the run time it formed.  Bodies that never merged
+//! have no component and count as a single leaf when they join one.
+//!
+//! The tree is stored flat in post-order (children before their parent, the
+//! root last) with only a child count per node, so it costs a few bytes per
+//! ancestor and serializes as a plain list.  `lineage_max_nodes` bounds it:
+//! when a merge would exceed the limit, the smallest members' histories are
+//! collapsed into single leaves first.
+//!
+//! [`BiggestAccretion`] keeps the heaviest lineage formed during the run for
+//! the game-over summary graphic.  Lineages survive sector parking and saves
+//! through `save::AsteroidSnapshot`.
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// One ancestor in a [`Lineage`] tree.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Reflect)]
+pub struct LineageNode {
+    /// Mass (`AsteroidSize` units) of the body when it joined its parent, or
+    /// of the current body for the root.
+    pub mass: u32,
+    /// Run time (s) the node formed: its merge time, or for a body with no
+    /// recorded history, the time it first joined a merge.
+    pub time: f32,
+    /// Number of direct ancestors; 0 for a leaf.
+    pub children: u16,
+}
+
+/// Merge tree of an accreted body, in post-order with the root last.
+#[derive(Component, Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct Lineage {
+    nodes: Vec<LineageNode>,
+}
+
+/// A node's span in an icicle chart of a [`Lineage`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct IcicleCell {
+    /// Distance from the root (0 = the root itself).
+    pub depth: usize,
+    /// Left edge as a fraction of the root's width.
+    pub start: f32,
+    /// Width as a fraction of the root's width.
+    pub width: f32,
+    pub mass: u32,
+    /// The node is a merge rather than a leaf body.
+    pub merged: bool,
+}
+
+impl Lineage {
+    /// A body with no recorded history.
+    pub fn leaf(mass: u32, time: f32) -> Self {
+        Self {
+            nodes: vec![LineageNode {
+                mass,
+                time,
+                children: 0,
+            }],
+        }
+    }
+
+    /// The lineage of a body formed at `time` from `members`.
+    ///
+    /// The new root's mass is the sum of the members' root masses.  If the
+    /// result would exceed `max_nodes`, the smallest members are collapsed to
+    /// leaves first; if there are still too many members, the smallest are
+    /// pooled into one leaf.
+    pub fn merge(mut members: Vec<Lineage>, time: f32, max_nodes: usize) -> Self {
+        let max_nodes = max_nodes.max(3);
+
+        let mut total = members.iter().map(|m| m.nodes.len()).sum::<usize>() + 1;
+        if total > max_nodes {
+            let mut order: Vec<usize> = (0..members.len()).collect();
+            order.sort_by_key(|&i| members[i].mass());
+            for i in order {
+                if total <= max_nodes {
+                    break;
+                }
+                let len = members[i].nodes.len();
+                if len > 1 {
+                    let root = *members[i].root();
+                    members[i] = Self::leaf(root.mass, root.time);
+                    total -= len - 1;
+                }
+            }
+        }
+        if members.len() + 1 > max_nodes {
+            members.sort_by_key(|m| std::cmp::Reverse(m.mass()));
+            let pooled = members.split_off(max_nodes - 2);
+            let mass = pooled.iter().map(Self::mass).sum();
+            let first = pooled
+                .iter()
+                .map(|m| m.root().time)
+                .fold(f32::INFINITY, f32::min);
+            members.push(Self::leaf(mass, first));
+        }
+
+        let mut nodes =
+            Vec::with_capacity(members.iter().map(|m| m.nodes.len()).sum::<usize>() + 1);
+        for member in &members {
+            nodes.extend_from_slice(&member.nodes);
+        }
+        nodes.push(LineageNode {
+            mass: members.iter().map(Self::mass).sum(),
+            time,
+            children: members.len() as u16,
+        });
+        Self { nodes }
+    }
+
+    /// Copy with the root mass replaced, e.g. by a body's current size after
+    /// it was chipped since it formed.
+    pub fn with_mass(mut self, mass: u32) -> Self {
+        if let Some(root) = self.nodes.last_mut() {
+            root.mass = mass;
+        }
+        self
+    }
+
+    pub fn root(&self) -> &LineageNode {
+        self.nodes.last().expect("lineage has a root")
+    }
+
+    pub fn mass(&self) -> u32 {
+        self.root().mass
+    }
+
+    pub fn nodes(&self) -> &[LineageNode] {
+        &self.nodes
+    }
+
+    /// Merges recorded in the tree.
+    pub fn merge_count(&self) -> usize {
+        self.nodes.iter().filter(|n| n.children > 0).count()
+    }
+
+    /// Leaf bodies recorded in the tree.
+    pub fn body_count(&self) -> usize {
+        self.nodes.iter().filter(|n| n.children == 0).count()
+    }
+
+    /// Run time of the earliest recorded merge.
+    pub fn first_merge_time(&self) -> f32 {
+        self.nodes
+            .iter()
+            .filter(|n| n.children > 0)
+            .map(|n| n.time)
+            .fold(self.root().time, f32::min)
+    }
+
+    /// Direct ancestors of every node, by index.
+    fn children(&self) -> Vec<Vec<usize>> {
+        let mut children = vec![Vec::new(); self.nodes.len()];
+        let mut stack: Vec<usize> = Vec::new();
+        for (i, node) in self.nodes.iter().enumerate() {
+            let split = stack.len().saturating_sub(node.children as usize);
+            children[i] = stack.split_off(split);
+            stack.push(i);
+        }
+        children
+    }
+
+    /// Levels below the root, 0 for a leaf.
+    pub fn depth(&self) -> usize {
+        let children = self.children();
+        let mut depth = vec![0usize; self.nodes.len()];
+        for (i, ancestors) in children.iter().enumerate() {
+            depth[i] = ancestors.iter().map(|&c| depth[c] + 1).max().unwrap_or(0);
+        }
+        depth[self.nodes.len() - 1]
+    }
+
+    /// Lay the tree out as an icicle chart down to `max_depth` levels: the
+    /// root spans the full width and each node splits its span among its
+    /// ancestors by mass.
+    pub fn icicle(&self, max_depth: usize) -> Vec<IcicleCell> {
+        let children = self.children();
+        let mut cells = Vec::new();
+        let mut pending = vec![(self.nodes.len() - 1, 0usize, 0.0f32, 1.0f32)];
+        while let Some((index, depth, start, width)) = pending.pop() {
+            let node = self.nodes[index];
+            cells.push(IcicleCell {
+                depth,
+                start,
+                width,
+                mass: node.mass,
+                merged: node.children > 0,
+            });
+            if depth + 1 >= max_depth {
+                continue;
+            }
+            let total: u32 = children[index]
+                .iter()
+                .map(|&c| self.nodes[c].mass.max(1))
+                .sum();
+            let mut offset = start;
+            for &c in &children[index] {
+                let share = width * self.nodes[c].mass.max(1) as f32 / total as f32;
+                pending.push((c, depth + 1, offset, share));
+                offset += share;
+            }
+        }
+        cells
+    }
+}
+
+/// The heaviest lineage formed this run, for the game-over summary.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
+pub struct BiggestAccretion {
+    pub lineage: Option<Lineage>,
+}
+
+impl BiggestAccretion {
+    /// Keep `lineage` if it is heavier than the current record.
+    pub fn offer(&mut self, lineage: &Lineage) {
+        if self
+            .lineage
+            .as_ref()
+            .is_none_or(|best| lineage.mass() > best.mass())
+        {
+            self.lineage = Some(lineage.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merges_nest_and_record_masses_and_times() {
+        let pair = Lineage::merge(vec![Lineage::leaf(1, 2.0), Lineage::leaf(2, 2.0)], 2.0, 64);
+        let body = Lineage::merge(vec![pair, Lineage::leaf(4, 9.0)], 9.0, 64);
+
+        assert_eq!(body.mass(), 7);
+        assert_eq!(body.merge_count(), 2);
+        assert_eq!(body.body_count(), 3);
+        assert_eq!(body.depth(), 2);
+        assert_eq!(body.root().time, 9.0);
+        assert_eq!(body.first_merge_time(), 2.0);
+
+        let encoded = toml::to_string(&body).unwrap();
+        assert_eq!(toml::from_str::<Lineage>(&encoded).unwrap(), body);
+    }
+
+    #[test]
+    fn merges_past_the_node_limit_collapse_the_smallest_histories() {
+        let small = Lineage::merge(vec![Lineage::leaf(1, 0.0); 3], 1.0, 64);
+        let large = Lineage::merge(vec![Lineage::leaf(5, 0.0); 3], 1.0, 64);
+        let body = Lineage::merge(vec![small, large], 2.0, 6);
+
+        assert!(body.nodes().len() <= 6);
+        assert_eq!(body.mass(), 18);
+        assert_eq!(body.body_count(), 4, "the small member became one leaf");
+
+        let crowd = Lineage::merge(vec![Lineage::leaf(1, 0.0); 20], 3.0, 6);
+        assert_eq!(crowd.nodes().len(), 6);
+        assert_eq!(crowd.mass(), 20, "pooled members keep their mass");
+    }
+
+    #[test]
+    fn icicle_splits_each_span_by_ancestor_mass() {
+        let pair = Lineage::merge(vec![Lineage::leaf(1, 0.0), Lineage::leaf(3, 0.0)], 1.0, 64);
+        let body = Lineage::merge(vec![pair, Lineage::leaf(4, 0.0)], 2.0, 64);
+        let cells = body.icicle(8);
+
+        assert_eq!(cells.len(), 5);
+        let level_width = |depth| {
+            cells
+                .iter()
+                .filter(|c| c.depth == depth)
+                .map(|c| c.width)
+                .sum::<f32>()
+        };
+        assert!((level_width(1) - 1.0).abs() < 1e-6);
+        assert!((level_width(2) - 0.5).abs() < 1e-6);
+        assert_eq!(body.icicle(1).len(), 1);
+    }
+}
@@ -0,0 +1,304 @@
+//! Staggered scenario startup behind a loading overlay.
+//!
+//! Scenario builders (`spawn_initial_asteroids`, `spawn_shower_scenario`, …)
+//! do not spawn directly: they push one job per body into the
+//! [`ScenarioSpawnQueue`].  [`scenario_spawn_drain_system`] then runs at most
+//! `scenario_spawn_budget` jobs per frame, so Rapier body creation and mesh
+//! attachment for a 250-body field are spread over several frames instead of
+//! hitching the first one.
+//!
+//! While jobs remain the Rapier pipeline is held off and a resume stays
+//! pending; [`apply_physics_resume_system`] will not turn it back on until the
+//! queue is empty.  Nothing moves or collides until the whole set is placed,
+//! so a half-spawned field never starts accreting early.
+//!
+//! [`scenario_loading_overlay_system`] shows a dimmed overlay with a progress
+//! bar for as long as the queue is loading.
+//!
+//! Practice scenarios reach `Playing` through the `GameState::Loading` screen
+//! (`menu/loading_screen.rs`), which readies fonts, audio and pools; bodies
+//! are still placed here, after the transition.
+//!
+//! [`apply_physics_resume_system`]: crate::simulation::rapier_guard::apply_physics_resume_system
+
+use crate::config::PhysicsConfig;
+use crate::graphics::GameFont;
+use crate::menu::{GameState, SelectedScenario};
+use crate::simulation::rapier_guard::PhysicsResumeGuard;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::RapierConfiguration;
+use std::collections::VecDeque;
+
+/// One deferred spawn.
+pub type SpawnJob = Box<dyn FnOnce(&mut Commands) + Send + Sync>;
+
+/// Pending scenario spawns, drained a budget at a time.
+#[derive(Resource, Default)]
+pub struct ScenarioSpawnQueue {
+    jobs: VecDeque<SpawnJob>,
+    /// Jobs queued since the queue was last empty (progress denominator).
+    total: usize,
+}
+
+impl ScenarioSpawnQueue {
+    /// Queue a bundle to be spawned as a new entity.
+    pub fn spawn<B: Bundle>(&mut self, bundle: B) {
+        self.push(move |commands| {
+            commands.spawn(bundle);
+        });
+    }
+
+    /// Queue an arbitrary spawn, e.g. a helper that spawns and then inserts.
+    pub fn push(&mut self, job: impl FnOnce(&mut Commands) + Send + Sync + 'static) {
+        self.jobs.push_back(Box::new(job));
+        self.total += 1;
+    }
+
+    /// True while queued jobs remain.
+    pub fn is_loading(&self) -> bool {
+        !self.jobs.is_empty()
+    }
+
+    /// Fraction of the current batch already spawned, in `[0, 1]`.
+    pub fn progress(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        1.0 - self.jobs.len() as f32 / self.total as f32
+    }
+
+    /// Run up to `budget` jobs.  Returns how many ran.
+    pub fn drain_into(&mut self, commands: &mut Commands, budget: usize) -> usize {
+        let count = budget.max(1).min(self.jobs.len());
+        for job in self.jobs.drain(..count) {
+            job(commands);
+        }
+        if self.jobs.is_empty() {
+            self.total = 0;
+        }
+        count
+    }
+}
+
+/// Queue the fixed layout of `scenario`.
+///
+/// FRONTIER has none: its chunks are generated around the ship, so the caller
+/// enables a [`ProceduralField`](crate::simulation::procgen::ProceduralField)
+/// instead.
+pub fn queue_scenario(
+    queue: &mut ScenarioSpawnQueue,
+    config: &PhysicsConfig,
+    scenario: SelectedScenario,
+    seed: u64,
+) {
+    match scenario {
+        SelectedScenario::Field => {
+            crate::asteroid::spawn_initial_asteroids(queue, 100, config, seed);
+        }
+        SelectedScenario::Orbit => crate::asteroid::spawn_orbit_scenario(queue, config, seed),
+        SelectedScenario::Comets => crate::asteroid::spawn_comets_scenario(queue, config, seed),
+        SelectedScenario::Shower => crate::asteroid::spawn_shower_scenario(queue, config, seed),
+        SelectedScenario::Survival => {
+            crate::asteroid::spawn_initial_asteroids(
+                queue,
+                config.survival_initial_asteroids,
+                config,
+                seed,
+            );
+        }
+        SelectedScenario::Catalog => {
+            crate::orbital_import::spawn_catalog_scenario(queue, config, seed);
+        }
+        SelectedScenario::Frontier => {}
+    }
+}
+
+/// Spawn this frame's share of the queue, holding physics off until done.
+pub fn scenario_spawn_drain_system(
+    mut commands: Commands,
+    mut queue: ResMut<ScenarioSpawnQueue>,
+    config: Res<PhysicsConfig>,
+    mut guard: ResMut<PhysicsResumeGuard>,
+    mut rapier_config: Query<&mut RapierConfiguration>,
+) {
+    if !queue.is_loading() {
+        return;
+    }
+    for mut cfg in rapier_config.iter_mut() {
+        cfg.physics_pipeline_active = false;
+    }
+    guard.pending = true;
+
+    let total = queue.total;
+    queue.drain_into(&mut commands, config.scenario_spawn_budget as usize);
+    if !queue.is_loading() {
+        info!("Scenario loaded: {total} bodies placed");
+    }
+}
+
+/// Root node of the loading overlay.
+#[derive(Component)]
+pub struct ScenarioLoadingOverlay;
+
+/// Fill node of the overlay's progress bar.
+#[derive(Component)]
+pub struct ScenarioLoadingBarFill;
+
+/// Show the loading overlay while the queue drains in `Playing`; remove it
+/// once done or when another screen (pause, menu) takes over.
+pub fn scenario_loading_overlay_system(
+    mut commands: Commands,
+    state: Res<State<GameState>>,
+    queue: Res<ScenarioSpawnQueue>,
+    config: Res<PhysicsConfig>,
+    font: Res<GameFont>,
+    q_overlay: Query<Entity, With<ScenarioLoadingOverlay>>,
+    mut q_fill: Query<&mut Node, With<ScenarioLoadingBarFill>>,
+) {
+    if !queue.is_loading() || *state.get() != GameState::Playing {
+        for entity in q_overlay.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    let percent = Val::Percent(queue.progress() * 100.0);
+    if let Ok(mut fill) = q_fill.single_mut() {
+        fill.width = percent;
+        return;
+    }
+    if !q_overlay.is_empty() {
+        return;
+    }
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.02, 0.85)),
+            GlobalZIndex(200),
+            ScenarioLoadingOverlay,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new("Placing asteroids..."),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: config.stats_font_size,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.92, 1.0)),
+            ));
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(320.0),
+                        height: Val::Px(10.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.12)),
+                ))
+                .with_children(|bar| {
+                    bar.spawn((
+                        Node {
+                            width: percent,
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.45, 0.75, 1.0)),
+                        ScenarioLoadingBarFill,
+                    ));
+                });
+        });
+}
+
+pub struct ScenarioLoadingPlugin;
+
+impl Plugin for ScenarioLoadingPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScenarioSpawnQueue>().add_systems(
+            Update,
+            (
+                scenario_spawn_drain_system.run_if(in_state(GameState::Playing)),
+                scenario_loading_overlay_system,
+            )
+                .chain(),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    #[derive(Component)]
+    struct Body(usize);
+
+    fn loading_app(jobs: usize, budget: u32) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Playing)
+            .insert_resource(PhysicsConfig {
+                scenario_spawn_budget: budget,
+                ..default()
+            })
+            .init_resource::<PhysicsResumeGuard>()
+            .init_resource::<ScenarioSpawnQueue>()
+            .add_systems(Update, scenario_spawn_drain_system);
+        let mut queue = app.world_mut().resource_mut::<ScenarioSpawnQueue>();
+        for i in 0..jobs {
+            queue.spawn(Body(i));
+        }
+        app
+    }
+
+    fn bodies(app: &mut App) -> usize {
+        let world = app.world_mut();
+        world.query::<&Body>().iter(world).count()
+    }
+
+    #[test]
+    fn queue_spawns_at_most_the_budget_per_frame() {
+        let mut app = loading_app(250, 40);
+        let mut frames = 0;
+        while app.world().resource::<ScenarioSpawnQueue>().is_loading() {
+            app.update();
+            frames += 1;
+            assert!(bodies(&mut app) <= frames * 40);
+        }
+        assert_eq!(frames, 7);
+        assert_eq!(bodies(&mut app), 250);
+
+        let mut seen: Vec<usize> = {
+            let world = app.world_mut();
+            world.query::<&Body>().iter(world).map(|b| b.0).collect()
+        };
+        seen.sort_unstable();
+        assert_eq!(seen, (0..250).collect::<Vec<_>>(), "every job ran once");
+    }
+
+    #[test]
+    fn physics_resume_stays_pending_until_the_queue_is_empty() {
+        let mut app = loading_app(10, 4);
+        app.update();
+        let queue = app.world().resource::<ScenarioSpawnQueue>();
+        assert!(queue.is_loading());
+        assert!((queue.progress() - 0.4).abs() < 1e-6);
+        assert!(app.world().resource::<PhysicsResumeGuard>().pending);
+
+        app.update();
+        app.update();
+        let queue = app.world().resource::<ScenarioSpawnQueue>();
+        assert!(!queue.is_loading());
+        assert_eq!(queue.progress(), 1.0);
+        assert!(app.world().resource::<PhysicsResumeGuard>().pending);
+    }
+}
@@ -1,4 +1,5 @@
 use bevy::diagnostic::FrameTimeDiagnosticsPlugin;
+use bevy::log::LogPlugin;
 use bevy::prelude::*;
 use bevy::window::WindowResolution;
 use bevy::winit::WinitSettings;
@@ -8,22 +9,42 @@ use std::env;
 mod alloc_profile;
 mod asteroid;
 mod asteroid_rendering;
+mod attribution;
+mod audio;
 mod campaign;
 mod config;
 mod constants;
+mod difficulty;
 mod enemy;
 mod error;
+mod feedback;
 mod graphics;
+mod lensing;
+mod lineage;
+mod loading;
 mod menu;
 mod mining;
+mod orbital_import;
 mod particles;
 mod player;
+mod profile;
+mod reflection;
 mod rendering;
+mod report;
+mod rng;
+mod run_info;
 mod save;
+mod scoring;
+mod session_stats;
 mod simulation;
 mod spatial_partition;
+mod speedrun;
+mod survival;
 mod test_mode;
 mod testing;
+mod timelapse;
+mod victory;
+mod wave_telemetry;
 
 use config::PhysicsConfig;
 use menu::{GameState, SelectedGameMode, SelectedScenario, ShopReturnState};
@@ -31,35 +52,43 @@ use testing::TestConfig;
 
 /// Spawn the initial asteroid world for the chosen scenario.
 ///
-/// Registered via `OnTransition{ScenarioSelect→Playing}` so it runs only after
-/// the player selects a scenario from the scenario-select screen.
+/// Registered via `OnTransition{Loading→Playing}` so it runs only after the
+/// player selects a scenario and the loading screen has prepared the session.
+/// Bodies go into the [`loading::ScenarioSpawnQueue`] and are placed over the
+/// next few frames behind the loading overlay.
 /// Using OnTransition (not OnEnter) prevents re-spawning on Paused↔Playing or
 /// GameOver→Playing transitions.
+#[allow(clippy::too_many_arguments)]
 fn spawn_initial_world(
     mut commands: Commands,
     config: Res<PhysicsConfig>,
     mode: Res<SelectedGameMode>,
     campaign: Res<campaign::CampaignSession>,
     scenario: Res<SelectedScenario>,
+    difficulty: Res<difficulty::Difficulty>,
+    assists: Res<difficulty::AssistOptions>,
+    mut run: ResMut<run_info::RunMetadata>,
+    mut queue: ResMut<loading::ScenarioSpawnQueue>,
 ) {
     let scenario_to_spawn = match *mode {
         SelectedGameMode::Practice => *scenario,
         SelectedGameMode::Campaign => campaign.map_scenario,
     };
+    let seed = run_info::next_run_seed();
+    run.begin(scenario_to_spawn, seed, *mode, campaign.mission_index);
+    run.mutators
+        .extend(difficulty.mutator().map(str::to_string));
+    run.mutators.extend(assists.mutator().map(str::to_string));
+    info!("Run: {}", run.summary());
+    commands.insert_resource(run_info::RunPlaytime::default());
+    commands.insert_resource(speedrun::SpeedrunSplits::default());
+    commands.insert_resource(lineage::BiggestAccretion::default());
 
-    match scenario_to_spawn {
-        SelectedScenario::Field => {
-            asteroid::spawn_initial_asteroids(&mut commands, 100, &config);
-        }
-        SelectedScenario::Orbit => {
-            asteroid::spawn_orbit_scenario(&mut commands, &config);
-        }
-        SelectedScenario::Comets => {
-            asteroid::spawn_comets_scenario(&mut commands, &config);
-        }
-        SelectedScenario::Shower => {
-            asteroid::spawn_shower_scenario(&mut commands, &config);
-        }
+    if scenario_to_spawn == SelectedScenario::Frontier {
+        // No fixed layout: the first chunks are generated next tick.
+        commands.insert_resource(simulation::procgen::ProceduralField::new(seed));
+    } else {
+        loading::queue_scenario(&mut queue, &config, scenario_to_spawn, seed);
     }
 }
 
@@ -80,7 +109,7 @@ fn is_not_campaign_mode(mode: Res<SelectedGameMode>) -> bool {
 
 fn add_playing_transition_hud_systems(app: &mut App) {
     add_playing_transition_hud_systems_for(app, GameState::MainMenu);
-    add_playing_transition_hud_systems_for(app, GameState::ScenarioSelect);
+    add_playing_transition_hud_systems_for(app, GameState::Loading);
     add_playing_transition_hud_systems_for(app, GameState::LoadGameMenu);
     add_playing_transition_hud_systems_for(app, GameState::CampaignSelect);
 }
@@ -95,11 +124,15 @@ fn add_playing_transition_hud_systems_for(app: &mut App, exited: GameState) {
             rendering::setup_boundary_ring,
             rendering::setup_debug_line_layers,
             rendering::setup_hud_score,
+            rendering::streamer::setup_streamer_stats,
             rendering::setup_lives_hud,
             rendering::setup_missile_hud,
             rendering::setup_ore_hud,
             rendering::setup_stats_text,
             rendering::setup_physics_inspector_text,
+            rendering::inspector::setup_asteroid_inspector_text,
+            rendering::hover_tooltip::setup_hover_tooltip,
+            rendering::motion_trail::setup_motion_trail_pool,
             rendering::setup_profiler_text,
             rendering::setup_debug_panel,
         ),
@@ -109,6 +142,12 @@ fn add_playing_transition_hud_systems_for(app: &mut App, exited: GameState) {
 fn main() {
     alloc_profile::init_from_env();
 
+    // Time-lapse viewer replaces the game entirely.
+    if let Ok(path) = env::var("ACCRETION_TIMELAPSE_PLAY") {
+        timelapse::run_timelapse_playback(&path);
+        return;
+    }
+
     // Check for test mode — bypasses the menu and starts directly in Playing.
     let test_mode = env::var("ACCRETION_TEST").ok();
 
@@ -118,19 +157,28 @@ fn main() {
         app.insert_resource(WinitSettings::game());
     }
 
-    app.add_plugins(DefaultPlugins.set(WindowPlugin {
-        primary_window: Some(Window {
-            title: "Accretion".into(),
-            resolution: WindowResolution::new(1200, 680),
-            ..Default::default()
-        }),
-        ..Default::default()
-    }))
+    app.add_plugins(
+        DefaultPlugins
+            .set(WindowPlugin {
+                primary_window: Some(Window {
+                    title: "Accretion".into(),
+                    resolution: WindowResolution::new(1200, 680),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            })
+            // Mirror log lines into the buffer bundled with issue reports.
+            .set(LogPlugin {
+                custom_layer: report::recent_log_layer,
+                ..Default::default()
+            }),
+    )
     .insert_resource(ClearColor(Color::BLACK))
     // Insert PhysicsConfig with compiled defaults; load_physics_config will
     // overwrite it from assets/physics.toml (if present) in the Startup schedule.
     .insert_resource(PhysicsConfig::default())
     .insert_resource(config::PhysicsConfigHotReloadState::default())
+    .insert_resource(scoring::ScoringConfig::default())
     // Insert GameFont resource early so menu systems can access it; the actual
     // font handle will be loaded during Startup via load_game_font.
     .insert_resource(graphics::GameFont::default())
@@ -142,8 +190,19 @@ fn main() {
     // (scale = 1.0 was the default in bevy_rapier2d 0.18).  Setting this to any
     // larger value shrinks collider mass in physics-space quadratically and causes
     // ExternalForce to produce runaway acceleration at the same numeric values.
-    .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0))
+    //
+    // The Rapier step runs in the fixed schedule at `physics_tick_hz` (see
+    // `simulation::fixed_step`) so results do not depend on the frame rate.
+    .add_plugins(RapierPhysicsPlugin::<NoUserData>::pixels_per_meter(1.0).in_fixed_schedule())
+    .insert_resource(TimestepMode::Fixed {
+        dt: 1.0 / constants::PHYSICS_TICK_HZ,
+        substeps: constants::PHYSICS_SUBSTEPS,
+    })
     .add_plugins(FrameTimeDiagnosticsPlugin::default())
+    // Only registers the report writer when ACCRETION_ALLOC_REPORT is set.
+    .add_plugins(alloc_profile::AllocReportPlugin)
+    // Only records when ACCRETION_TIMELAPSE_RECORD is set.
+    .add_plugins(timelapse::TimelapseRecordPlugin)
     .insert_resource(player::PlayerFireCooldown::default())
     .insert_resource(SelectedGameMode::Practice)
     .insert_resource(SelectedScenario::Field)
@@ -157,13 +216,38 @@ fn main() {
     .insert_resource(campaign::CampaignSession::default())
     .insert_resource(campaign::CampaignWaveDirector::default())
     .insert_resource(campaign::CampaignProgressionState::default())
+    .insert_resource(campaign::WaveIntermissionShop::default())
+    .insert_resource(campaign::WaveReinforcementQueue::default())
+    .insert_resource(campaign::CampaignScriptRunner::default())
+    .insert_resource(survival::SurvivalState::default())
+    .add_plugins(difficulty::DifficultyPlugin)
     .add_plugins(save::SavePlugin)
+    .add_plugins(run_info::RunInfoPlugin)
+    .add_plugins(rng::RngPlugin)
+    .add_plugins(report::ReportPlugin)
+    .add_plugins(wave_telemetry::WaveTelemetryPlugin)
+    .add_plugins(session_stats::SessionStatsPlugin)
+    .add_plugins(attribution::AttributionPlugin)
+    .add_plugins(audio::GameAudioPlugin)
+    .add_plugins(lensing::LensingPlugin)
+    .add_plugins(asteroid_rendering::instancing::AsteroidHullInstancingPlugin)
+    .add_plugins(graphics::palette::PalettePlugin)
+    .add_plugins(loading::ScenarioLoadingPlugin)
+    .add_plugins(reflection::ReflectionPlugin)
+    .add_plugins(profile::ProfilePlugin {
+        persist: test_mode.is_none(),
+    })
+    .add_plugins(speedrun::SpeedrunPlugin)
+    .add_plugins(save::leaderboard::LeaderboardPlugin {
+        persist: test_mode.is_none(),
+    })
     // Global startup: config + camera + physics settings (needed by both menu and gameplay).
     .add_systems(
         Startup,
         (
             config::load_physics_config,
             config::init_physics_hot_reload_state.after(config::load_physics_config),
+            scoring::load_scoring_config,
             graphics::load_game_font,
             graphics::load_symbol_font,
             graphics::load_symbol_font_2,
@@ -171,20 +255,56 @@ fn main() {
             graphics::load_emoji_font,
             graphics::log_font_substitution_diagnostics,
             graphics::setup_camera.after(config::load_physics_config),
+            graphics::starfield::setup_starfield.after(config::load_physics_config),
             setup_physics_config,
         ),
     )
-    .add_systems(Update, config::hot_reload_physics_config)
     .add_systems(
         Update,
         (
+            config::hot_reload_physics_config,
+            scoring::hot_reload_scoring_config,
+        ),
+    )
+    // The background follows the camera after it has moved this frame.
+    .add_systems(
+        PostUpdate,
+        graphics::starfield::starfield_scroll_system
+            .before(bevy::transform::TransformSystems::Propagate),
+    )
+    .add_systems(
+        Update,
+        (
+            campaign::wave_reinforcement_system,
             campaign::campaign_wave_director_system,
+            campaign::wave_intermission_beacon_system,
             campaign::campaign_boss_spawn_system,
+            campaign::campaign_script_system,
             campaign::campaign_progression_system,
         )
             .chain()
             .run_if(in_state(GameState::Playing)),
     )
+    // A beacon shop visit keeps the intermission countdown ticking and closes
+    // the shop when the next wave's warmup begins.
+    .add_systems(
+        Update,
+        (
+            campaign::campaign_wave_director_system,
+            campaign::close_intermission_shop_system,
+        )
+            .chain()
+            .run_if(in_state(GameState::OreShop).and(campaign::intermission_shop_open)),
+    )
+    .add_systems(
+        Update,
+        (
+            survival::survival_wave_system,
+            survival::survival_score_multiplier_system,
+        )
+            .chain()
+            .run_if(in_state(GameState::Playing)),
+    )
     // Game-world setup: runs only on transitions into Playing so world entities and HUD are
     // spawned exactly once per session.
     ;
@@ -199,17 +319,27 @@ fn main() {
         // from the very first frame.
         app.insert_state(GameState::Playing)
             .add_plugins(particles::ParticlesPlugin)
+            .add_plugins(feedback::FeedbackPlugin)
+            .add_plugins(graphics::feedback::HitFeedbackPlugin)
+            .add_plugins(graphics::merge_animation::MergeAnimationPlugin)
+            .add_plugins(graphics::cinematic::CinematicPlugin)
+            .add_plugins(victory::VictoryPlugin)
             .add_plugins(simulation::SimulationPlugin)
             .add_plugins(enemy::EnemyPlugin)
             .add_plugins(mining::MiningPlugin);
     } else {
-        // World and player spawned only when transitioning from ScenarioSelect → Playing.
+        // World and player spawned only when transitioning from Loading → Playing.
         // Using OnTransition (not OnEnter) prevents re-spawning on Paused → Playing resume.
         // resume_physics is included here because returning to the menu from a paused game
         // (Paused → MainMenu) leaves the pipeline disabled; it must be re-enabled for the
         // new session to actually simulate.
         app.add_plugins(menu::MainMenuPlugin)
             .add_plugins(particles::ParticlesPlugin)
+            .add_plugins(feedback::FeedbackPlugin)
+            .add_plugins(graphics::feedback::HitFeedbackPlugin)
+            .add_plugins(graphics::merge_animation::MergeAnimationPlugin)
+            .add_plugins(graphics::cinematic::CinematicPlugin)
+            .add_plugins(victory::VictoryPlugin)
             .add_plugins(simulation::SimulationPlugin)
             .add_plugins(enemy::EnemyPlugin)
             .add_plugins(mining::MiningPlugin)
@@ -224,6 +354,7 @@ fn main() {
                     campaign::bootstrap_campaign_wave_director,
                     campaign::bootstrap_campaign_progression_state,
                     spawn_initial_world,
+                    survival::bootstrap_survival_session,
                     player::spawn_player,
                     menu::resume_physics,
                 )
@@ -231,7 +362,7 @@ fn main() {
             )
             .add_systems(
                 OnTransition {
-                    exited: GameState::ScenarioSelect,
+                    exited: GameState::Loading,
                     entered: GameState::Playing,
                 },
                 (
@@ -239,6 +370,7 @@ fn main() {
                     campaign::bootstrap_campaign_wave_director,
                     campaign::bootstrap_campaign_progression_state,
                     spawn_initial_world,
+                    survival::bootstrap_survival_session,
                     player::spawn_player,
                     menu::resume_physics,
                 )
@@ -254,6 +386,7 @@ fn main() {
                     campaign::bootstrap_campaign_wave_director,
                     campaign::bootstrap_campaign_progression_state,
                     save::apply_pending_loaded_snapshot_system,
+                    survival::bootstrap_survival_session,
                     menu::resume_physics,
                 )
                     .chain(),
@@ -269,6 +402,7 @@ fn main() {
                     campaign::bootstrap_campaign_wave_director,
                     campaign::bootstrap_campaign_progression_state,
                     spawn_initial_world,
+                    survival::bootstrap_survival_session,
                     player::spawn_player,
                     menu::resume_physics,
                 )
@@ -291,6 +425,7 @@ fn main() {
                     campaign::bootstrap_campaign_wave_director,
                     campaign::bootstrap_campaign_progression_state,
                     spawn_initial_world,
+                    survival::bootstrap_survival_session,
                     player::spawn_player,
                     menu::resume_physics,
                 )
@@ -314,5 +449,8 @@ fn main() {
         test_mode::configure_test_mode(&mut app, &test_name);
     }
 
-    app.run();
+    // A failing `ACCRETION_TEST=all` suite exits with an error code.
+    if app.run().is_error() {
+        std::process::exit(1);
+    }
 }
@@ -9,12 +9,14 @@
 //! |--------------------|------------------------------------------------------|
 //! | `MainMenu`         | Initial state; splash screen shown                    |
 //! | `LoadGameMenu`     | Save-slot load screen                                 |
+//! | `Settings`         | Audio volume sliders                                  |
 //! | `ScenarioSelect`   | Scenario picker                                       |
 //! | `CampaignSelect`   | Campaign slot picker and naming                       |
+//! | `Loading`          | Asset/pool preparation and scenario tip before play   |
 //! | `Playing`          | Simulation running; all game systems active           |
 //! | `Paused`           | Simulation frozen; in-game pause overlay is visible   |
-//! | `OreShop`          | Shop overlay (simulation paused)                      |
-//! | `GameOver`         | Game-over overlay                                     |
+//! | `OreShop`          | Shop overlay (simulation paused, except at the wave-intermission beacon) |
+//! | `GameOver`         | Game-over overlay with the session summary            |
 //!
 //! ## Systems Registered by `MainMenuPlugin`
 //!
@@ -22,32 +24,57 @@
 //! |---------------------------|-------------------------------|------------------------------------|
 //! | `setup_main_menu_when_font_ready` | `Update / in MainMenu` | Spawn menu after font is loaded    |
 //! | `cleanup_main_menu`       | `OnExit(MainMenu)`            | Despawn menu UI entities           |
-//! | `menu_button_system`      | `Update / in MainMenu`        | Handle Start / Load / Quit clicks  |
+//! | `menu_button_system`      | `Update / in MainMenu`        | Handle Start / Load / Settings / Quit clicks |
+//! | `crash_recovery_prompt_system` | `Update / in MainMenu`   | Resume or discard the autosave of a crashed session |
+//! | `session_summary_dismiss_system` | `Update / in MainMenu` | Dismiss the "Last session" summary card |
+//! | `setup_loading_screen`    | `OnEnter(Loading)`            | Spawn loading screen with scenario tip |
+//! | `prewarm_particle_pool`   | `OnEnter(Loading)`            | Park dormant particles in the pool |
+//! | `loading_screen_system`   | `Update / in Loading`         | Wait for fonts/audio/config/pools, then Playing |
+//! | `setup_settings_menu`     | `OnEnter(Settings)`           | Spawn volume sliders               |
+//! | `settings_button_system`  | `Update / in Settings`        | Volume steps and Back              |
+//! | `settings_accessibility_system` | `Update / in Settings`  | Cycle palette, step UI scale       |
+//! | `settings_storage_system` | `Update / in Settings`        | Prune autosave / issue reports     |
 //! | `setup_pause_menu`        | `OnEnter(Paused)`             | Spawn semi-transparent pause overlay|
 //! | `cleanup_pause_menu`      | `OnExit(Paused)`              | Despawn pause overlay entities     |
 //! | `pause_physics`           | `OnEnter(Paused)`             | Disable Rapier physics pipeline    |
-//! | `resume_physics`          | `OnExit(Paused)`              | Re-enable Rapier physics pipeline  |
+//! | `resume_physics`          | `Paused → Playing`            | Request Rapier pipeline re-enable  |
 //! | `pause_menu_button_system`| `Update / in Paused`          | Handle Resume / Debug / Quit clicks|
 //! | `toggle_pause_system`     | `Update / in Playing`         | ESC → transition to Paused         |
-//! | `pause_resume_input_system`| `Update / in Paused`         | ESC → transition back to Playing   |
 //! | `toggle_ore_shop_system`  | `Update / in Playing`         | Tab → transition to OreShop        |
 //! | `ore_shop_button_system`  | `Update / in OreShop`         | Handle ore shop button presses     |
+//! | `ore_shop_undo_system`    | `Update / in OreShop`         | Undo countdown and purchase refund |
+//! | `ore_shop_balance_text_system` | `Update / in OreShop`    | Live ore / credit header           |
+//! | `ore_shop_intermission_countdown_system` | `Update / in OreShop` (beacon visit) | Next-wave countdown on shop banner |
+//! | `victory_overlay_system`  | `Update / in Playing`         | Victory summary over the orbit, then KEEP PLAYING |
+//! | `victory_button_system`   | `Update / in Playing`         | Dismiss the victory screen         |
+//! | `cleanup_victory_overlay` | `OnEnter(MainMenu)`           | Despawn the victory overlay        |
+//! | `menu_navigation_system`  | `PreUpdate / outside Playing, Loading` | Arrow / d-pad focus, Enter / South press, ESC / East back |
 
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::RapierConfiguration;
 
+use crate::campaign::{
+    discounted_price, intermission_shop_open, CampaignWaveDirector, WaveIntermissionShop,
+};
 use crate::config::PhysicsConfig;
+use crate::difficulty::{Difficulty, DifficultyOverlay};
+use crate::graphics::palette::Palette;
 use crate::graphics::GameFont;
-use crate::mining::{OreAffinityLevel, PlayerOre};
+use crate::mining::{Currency, MiningDroneLevel, OreAffinityLevel, OreKind, PlayerOre};
 use crate::player::{
     state::{MissileAmmo, PlayerHealth},
     IonCannonLevel, Player, PlayerLives, PlayerScore, PrimaryWeaponFireRateLevel,
-    PrimaryWeaponUpgradeTracks, SecondaryWeaponLevel, TractorBeamLevel,
+    PrimaryWeaponUpgradeTracks, SecondaryWeaponLevel, ShieldLevel, TractorBeamLevel, TurretLevel,
+};
+use crate::save::bundle::{
+    export_bundle, import_bundle, import_dir, newest_import, BUNDLE_EXTENSION,
 };
+use crate::save::storage::{format_bytes, PruneTarget, StorageKind, StorageUsage};
 use crate::save::{
-    campaign_slot_metadata, ensure_campaign_slot, load_slot, save_campaign_slot_named,
-    slot_loadable, slot_metadata, ActiveCampaignSlot, PendingLoadedCampaign, PendingLoadedSnapshot,
-    SaveSlotRequest, SAVE_SLOT_COUNT,
+    campaign_slot_metadata, discard_autosave, ensure_campaign_slot, load_slot,
+    save_campaign_slot_named, slot_loadable, slot_metadata, ActiveCampaignSlot, CrashRecovery,
+    PendingLoadedCampaign, PendingLoadedSnapshot, SaveSlotRequest, SaveSlotSummary, SaveSnapshot,
+    SAVE_SLOT_COUNT,
 };
 
 #[path = "menu/types.rs"]
@@ -58,18 +85,46 @@ mod menu_common;
 use menu_common::*;
 #[path = "menu/main_menu.rs"]
 mod menu_main_menu;
-use menu_main_menu::{cleanup_main_menu, menu_button_system, setup_main_menu_when_font_ready};
+use menu_main_menu::{
+    cleanup_main_menu, crash_recovery_prompt_system, menu_button_system,
+    setup_main_menu_when_font_ready,
+};
 #[path = "menu/game_over.rs"]
 mod menu_game_over;
 use menu_game_over::{cleanup_game_over, game_over_button_system, setup_game_over};
+#[path = "menu/victory.rs"]
+mod menu_victory;
+use menu_victory::{cleanup_victory_overlay, victory_button_system, victory_overlay_system};
+#[path = "menu/leaderboard.rs"]
+mod menu_leaderboard;
+use menu_leaderboard::{spawn_game_over_leaderboard, spawn_scenario_best};
+#[path = "menu/session_summary.rs"]
+mod menu_session_summary;
+use menu_session_summary::{
+    clear_session_summary_request, request_session_summary, session_summary_dismiss_system,
+    spawn_session_summary, spawn_session_summary_card,
+};
 #[path = "menu/load_game.rs"]
 mod menu_load_game;
-use menu_load_game::{cleanup_load_game_menu, load_game_menu_button_system, setup_load_game_menu};
+use menu_load_game::{
+    cleanup_load_game_menu, load_game_details_toggle_system, load_game_export_system,
+    load_game_import_system, load_game_menu_button_system, setup_load_game_menu,
+};
+#[path = "menu/settings.rs"]
+mod menu_settings;
+use menu_settings::{
+    cleanup_settings_menu, settings_accessibility_system, settings_button_system,
+    settings_storage_system, settings_volume_display_system, setup_settings_menu,
+};
 #[path = "menu/scenario_select.rs"]
 mod menu_scenario_select;
 use menu_scenario_select::{
-    cleanup_scenario_select, scenario_select_button_system, setup_scenario_select_when_fonts_ready,
+    cleanup_scenario_select, difficulty_button_system, scenario_select_button_system,
+    setup_scenario_select_when_fonts_ready,
 };
+#[path = "menu/loading_screen.rs"]
+mod menu_loading_screen;
+use menu_loading_screen::{cleanup_loading_screen, loading_screen_system, setup_loading_screen};
 #[path = "menu/campaign_select.rs"]
 mod menu_campaign_select;
 use menu_campaign_select::{
@@ -79,17 +134,23 @@ use menu_campaign_select::{
 #[path = "menu/pause.rs"]
 mod menu_pause;
 use menu_pause::{
-    cleanup_pause_menu, pause_menu_button_system, pause_physics, pause_resume_input_system,
-    setup_pause_menu, toggle_ore_shop_system, toggle_pause_system,
+    cleanup_pause_menu, pause_menu_button_system, setup_pause_menu, toggle_ore_shop_system,
+    toggle_pause_system,
 };
 #[path = "menu/ore_shop.rs"]
 mod menu_ore_shop;
-use menu_ore_shop::{cleanup_ore_shop, ore_shop_button_system, setup_ore_shop};
+use menu_ore_shop::{
+    cleanup_ore_shop, ore_shop_balance_text_system, ore_shop_button_system,
+    ore_shop_intermission_countdown_system, ore_shop_undo_system, setup_ore_shop,
+};
+#[path = "menu/navigation.rs"]
+mod menu_navigation;
+pub use menu_navigation::MenuFocus;
+use menu_navigation::MenuNavigationPlugin;
 #[path = "menu/cleanup.rs"]
 mod menu_cleanup;
-use menu_cleanup::cleanup_game_world;
-pub use menu_cleanup::reset_campaign_retry_world;
-pub use menu_pause::resume_physics;
+pub use menu_cleanup::{cleanup_game_world, reset_campaign_retry_world};
+pub use menu_pause::{pause_physics, resume_physics};
 
 // ── Plugin ────────────────────────────────────────────────────────────────────
 
@@ -107,22 +168,53 @@ impl Plugin for MainMenuPlugin {
             .init_resource::<SelectedGameMode>()
             .init_resource::<CampaignNameEditor>()
             .init_resource::<ShopReturnState>()
+            .init_resource::<ShopTransactionLog>()
+            .init_resource::<SessionSummaryPending>()
+            .add_plugins(MenuNavigationPlugin)
             // ── Main menu ─────────────────────────────────────────────────────
             .add_systems(
                 Update,
                 setup_main_menu_when_font_ready.run_if(in_state(GameState::MainMenu)),
             )
-            .add_systems(OnExit(GameState::MainMenu), cleanup_main_menu)
+            .add_systems(
+                OnExit(GameState::MainMenu),
+                (cleanup_main_menu, clear_session_summary_request),
+            )
             .add_systems(
                 Update,
-                menu_button_system.run_if(in_state(GameState::MainMenu)),
+                (
+                    menu_button_system,
+                    crash_recovery_prompt_system,
+                    session_summary_dismiss_system,
+                )
+                    .run_if(in_state(GameState::MainMenu)),
             )
             // ── Load game menu ───────────────────────────────────────────────
             .add_systems(OnEnter(GameState::LoadGameMenu), setup_load_game_menu)
             .add_systems(OnExit(GameState::LoadGameMenu), cleanup_load_game_menu)
             .add_systems(
                 Update,
-                load_game_menu_button_system.run_if(in_state(GameState::LoadGameMenu)),
+                (
+                    load_game_menu_button_system,
+                    load_game_details_toggle_system,
+                    load_game_export_system,
+                    load_game_import_system,
+                )
+                    .run_if(in_state(GameState::LoadGameMenu)),
+            )
+            // ── Settings ──────────────────────────────────────────────────────
+            .add_systems(OnEnter(GameState::Settings), setup_settings_menu)
+            .add_systems(OnExit(GameState::Settings), cleanup_settings_menu)
+            .add_systems(
+                Update,
+                (
+                    settings_button_system,
+                    settings_volume_display_system,
+                    settings_accessibility_system,
+                    settings_storage_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Settings)),
             )
             // ── Scenario select ───────────────────────────────────────────────
             .add_systems(
@@ -132,7 +224,22 @@ impl Plugin for MainMenuPlugin {
             .add_systems(OnExit(GameState::ScenarioSelect), cleanup_scenario_select)
             .add_systems(
                 Update,
-                scenario_select_button_system.run_if(in_state(GameState::ScenarioSelect)),
+                (scenario_select_button_system, difficulty_button_system)
+                    .run_if(in_state(GameState::ScenarioSelect)),
+            )
+            // ── Loading ───────────────────────────────────────────────────────
+            .add_systems(
+                OnEnter(GameState::Loading),
+                (
+                    setup_loading_screen,
+                    crate::player::prepare_shot_meshes_system,
+                    crate::particles::prewarm_particle_pool,
+                ),
+            )
+            .add_systems(OnExit(GameState::Loading), cleanup_loading_screen)
+            .add_systems(
+                Update,
+                loading_screen_system.run_if(in_state(GameState::Loading)),
             )
             // ── Campaign select ─────────────────────────────────────────────
             .add_systems(
@@ -172,11 +279,7 @@ impl Plugin for MainMenuPlugin {
             )
             .add_systems(
                 Update,
-                (
-                    pause_menu_button_system,
-                    pause_resume_input_system,
-                    toggle_ore_shop_system,
-                )
+                (pause_menu_button_system, toggle_ore_shop_system)
                     .run_if(in_state(GameState::Paused)),
             )
             .add_systems(
@@ -184,7 +287,15 @@ impl Plugin for MainMenuPlugin {
                 (toggle_pause_system, toggle_ore_shop_system).run_if(in_state(GameState::Playing)),
             )
             // ── Ore shop ──────────────────────────────────────────────────────
-            .add_systems(OnEnter(GameState::OreShop), (setup_ore_shop, pause_physics))
+            // Beacon visits during a campaign wave intermission keep physics
+            // running so the world (and the countdown) carry on behind the shop.
+            .add_systems(
+                OnEnter(GameState::OreShop),
+                (
+                    setup_ore_shop,
+                    pause_physics.run_if(not(intermission_shop_open)),
+                ),
+            )
             .add_systems(OnExit(GameState::OreShop), cleanup_ore_shop)
             // Resume physics only when returning to Playing, not when returning
             // to Paused (physics were already paused in that case).
@@ -197,15 +308,37 @@ impl Plugin for MainMenuPlugin {
             )
             .add_systems(
                 Update,
-                ore_shop_button_system.run_if(in_state(GameState::OreShop)),
+                (
+                    ore_shop_button_system,
+                    ore_shop_undo_system,
+                    ore_shop_balance_text_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::OreShop)),
+            )
+            .add_systems(
+                Update,
+                ore_shop_intermission_countdown_system
+                    .run_if(in_state(GameState::OreShop).and(intermission_shop_open)),
             )
             // ── Game Over ─────────────────────────────────────────────────────
-            .add_systems(OnEnter(GameState::GameOver), setup_game_over)
+            .add_systems(
+                OnEnter(GameState::GameOver),
+                setup_game_over.after(crate::save::leaderboard::record_leaderboard_system),
+            )
             .add_systems(OnExit(GameState::GameOver), cleanup_game_over)
             .add_systems(
                 Update,
                 game_over_button_system.run_if(in_state(GameState::GameOver)),
             )
+            // ── Victory ───────────────────────────────────────────────────────
+            .add_systems(
+                Update,
+                (victory_overlay_system, victory_button_system)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnEnter(GameState::MainMenu), cleanup_victory_overlay)
             // ── Quit to main menu ─────────────────────────────────────────────
             // Despawn all game-world entities and reset resources so the engine
             // is clean for the next play session.
@@ -214,7 +347,7 @@ impl Plugin for MainMenuPlugin {
                     exited: GameState::Paused,
                     entered: GameState::MainMenu,
                 },
-                cleanup_game_world,
+                (cleanup_game_world, request_session_summary),
             );
     }
 }
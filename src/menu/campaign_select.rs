@@ -25,6 +25,7 @@ pub fn setup_campaign_select_menu(
     mut commands: Commands,
     font: Res<GameFont>,
     mut editor: ResMut<CampaignNameEditor>,
+    palette: Res<Palette>,
 ) {
     let selected_slot = editor.selected_slot;
     set_editor_from_slot(&mut editor, selected_slot);
@@ -282,8 +283,8 @@ pub fn setup_campaign_select_menu(
                     border: UiRect::all(Val::Px(2.0)),
                     ..default()
                 },
-                BackgroundColor(start_bg()),
-                BorderColor::all(start_border()),
+                BackgroundColor(palette.confirm.bg),
+                BorderColor::all(palette.confirm.border),
                 CampaignStartButton,
             ))
             .with_children(|btn| {
@@ -294,7 +295,7 @@ pub fn setup_campaign_select_menu(
                         font_size: 16.0,
                         ..default()
                     },
-                    TextColor(start_text()),
+                    TextColor(palette.confirm.text),
                 ));
             });
 
@@ -472,6 +473,7 @@ pub fn campaign_select_button_system(
     mut selected_mode: ResMut<SelectedGameMode>,
     mut active_campaign_slot: ResMut<ActiveCampaignSlot>,
     mut editor: ResMut<CampaignNameEditor>,
+    palette: Res<Palette>,
 ) {
     let mut handle_slot = |slot: u8, interaction: &Interaction| -> bool {
         if *interaction != Interaction::Pressed {
@@ -664,7 +666,7 @@ pub fn campaign_select_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(start_text());
+                        *color = TextColor(palette.confirm.text);
                     }
                 }
             }
@@ -10,7 +10,7 @@ use super::*;
 /// against parry2d BVH "key not present" panics: `step_simulation` must not
 /// run with a live pipeline while entity handles are being flushed from
 /// Rapier's internal data structures.  `resume_physics` is called again on
-/// the `ScenarioSelect → Playing` transition when a new session begins.
+/// the `Loading → Playing` transition when a new session begins.
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn cleanup_game_world(
     mut commands: Commands,
@@ -21,6 +21,7 @@ pub fn cleanup_game_world(
             With<crate::player::Player>,
             With<crate::enemy::Enemy>,
             With<crate::enemy::Boss>,
+            With<crate::mining::MiningDrone>,
         )>,
     >,
     projectiles: Query<
@@ -33,30 +34,54 @@ pub fn cleanup_game_world(
     >,
     particles: Query<Entity, With<crate::particles::Particle>>,
     ore_pickups: Query<Entity, With<crate::mining::OrePickup>>,
-    hud: Query<
-        Entity,
-        Or<(
-            With<crate::rendering::HudScoreDisplay>,
-            With<crate::rendering::StatsTextDisplay>,
-            With<crate::rendering::PhysicsInspectorDisplay>,
-            With<crate::rendering::ProfilerDisplay>,
-            With<crate::rendering::DebugPanel>,
-            With<crate::rendering::LivesHudDisplay>,
-            With<crate::rendering::MissileHudDisplay>,
-            With<crate::rendering::BoundaryRing>,
-            With<crate::rendering::WireframeOverlayLayer>,
-            With<crate::rendering::ForceVectorLayer>,
-            With<crate::rendering::VelocityArrowLayer>,
-            With<crate::rendering::SpatialGridLayer>,
-            With<crate::rendering::OreHudDisplay>,
-        )>,
-    >,
+    // Grouped so the system stays within Bevy's 16-parameter limit.
+    (hud, debug_layers): (
+        Query<
+            Entity,
+            Or<(
+                With<crate::rendering::HudScoreDisplay>,
+                With<crate::rendering::StatsTextDisplay>,
+                With<crate::rendering::PhysicsInspectorDisplay>,
+                With<crate::rendering::ProfilerDisplay>,
+                With<crate::rendering::DebugPanel>,
+                With<crate::rendering::LivesHudDisplay>,
+                With<crate::rendering::MissileHudDisplay>,
+                With<crate::rendering::BoundaryRing>,
+                With<crate::rendering::WireframeOverlayLayer>,
+                With<crate::rendering::ForceVectorLayer>,
+                With<crate::rendering::VelocityArrowLayer>,
+                With<crate::rendering::SpatialGridLayer>,
+                With<crate::rendering::OreHudDisplay>,
+            )>,
+        >,
+        Query<
+            Entity,
+            Or<(
+                With<crate::rendering::ClusterOutlineLayer>,
+                With<crate::rendering::physics_layers::GravityFieldLayer>,
+                With<crate::rendering::gravity_heatmap::GravityHeatmapLayer>,
+                With<crate::rendering::physics_layers::ColliderOutlineLayer>,
+                With<crate::rendering::physics_layers::NeighborCountLayer>,
+                With<crate::rendering::trajectory::TrajectoryLayer>,
+                With<crate::rendering::split_preview::MissileSplitPreviewLayer>,
+                With<crate::rendering::inspector::SelectionHighlightLayer>,
+                With<crate::rendering::inspector::AsteroidInspectorDisplay>,
+                With<crate::rendering::streamer::StreamerStatsPanel>,
+                With<crate::rendering::motion_trail::MotionGhost>,
+                With<crate::rendering::motion_trail::MotionStreak>,
+            )>,
+        >,
+    ),
     player_ui: Query<
         Entity,
         Or<(
             With<crate::player::rendering::HealthBarBg>,
             With<crate::player::rendering::HealthBarFill>,
             With<crate::player::rendering::AimIndicatorMesh>,
+            With<crate::player::LockOnBracket>,
+            With<crate::player::rendering::ShieldRingMesh>,
+            With<crate::player::rendering::ShieldArcMesh>,
+            With<crate::campaign::ShopBeacon>,
         )>,
     >,
     mut player_ui_res: ResMut<crate::player::PlayerUiEntities>,
@@ -75,6 +100,7 @@ pub fn cleanup_game_world(
         .chain(particles.iter())
         .chain(ore_pickups.iter())
         .chain(hud.iter())
+        .chain(debug_layers.iter())
         .chain(player_ui.iter())
     {
         commands.entity(e).despawn();
@@ -82,20 +108,41 @@ pub fn cleanup_game_world(
     *player_ui_res = crate::player::PlayerUiEntities::default();
     *score = PlayerScore::default();
     lives.reset();
-    *overlay = crate::rendering::OverlayState::default();
+    // Overlay choices belong to the profile; only the panel closes.
+    overlay.menu_open = false;
     *sim_stats = crate::simulation::SimulationStats::default();
     *ore = crate::mining::PlayerOre::default();
     *campaign_session = crate::campaign::CampaignSession::default();
     commands.insert_resource(crate::campaign::CampaignWaveDirector::default());
     commands.insert_resource(crate::campaign::CampaignProgressionState::default());
+    commands.insert_resource(crate::campaign::WaveIntermissionShop::default());
+    commands.insert_resource(crate::campaign::CampaignScriptRunner::default());
+    commands.insert_resource(crate::campaign::WaveReinforcementQueue::default());
+    commands.insert_resource(crate::survival::SurvivalState::default());
+    commands.insert_resource(crate::particles::ParticlePool::default());
     // Reset upgrades so a new session starts fresh.
     commands.insert_resource(PrimaryWeaponUpgradeTracks::from_legacy_level(0));
     commands.insert_resource(PrimaryWeaponFireRateLevel::default());
     commands.insert_resource(SecondaryWeaponLevel::default());
     commands.insert_resource(OreAffinityLevel::default());
     commands.insert_resource(TractorBeamLevel::default());
+    commands.insert_resource(crate::player::ShieldLevel::default());
+    commands.insert_resource(crate::player::TurretLevel::default());
+    commands.insert_resource(crate::mining::MiningDroneLevel::default());
+    commands.insert_resource(crate::mining::MiningLaserState::default());
+    commands.insert_resource(crate::player::PlayerEnergy::default());
+    commands.insert_resource(crate::player::LockOnState::default());
+    commands.insert_resource(crate::player::AimTargetMemory::default());
+    commands.insert_resource(crate::player::KillCamState::default());
+    commands.insert_resource(crate::player::KillCamReplay::default());
+    commands.insert_resource(crate::simulation::clusters::ClusterStats::default());
+    commands.insert_resource(crate::simulation::sectors::SectorStore::default());
+    commands.insert_resource(crate::simulation::procgen::ProceduralField::default());
+    commands.insert_resource(crate::wave_telemetry::WaveTelemetry::default());
+    commands.insert_resource(crate::rendering::inspector::AsteroidSelection::default());
+    commands.insert_resource(crate::loading::ScenarioSpawnQueue::default());
     // Keep the physics pipeline disabled until a new session begins.
-    // resume_physics is called on OnTransition { ScenarioSelect → Playing }.
+    // resume_physics is called on OnTransition { Loading → Playing }.
     for mut cfg in rapier_config.iter_mut() {
         cfg.physics_pipeline_active = false;
     }
@@ -117,6 +164,7 @@ pub fn reset_campaign_retry_world(
             With<crate::player::Player>,
             With<crate::enemy::Enemy>,
             With<crate::enemy::Boss>,
+            With<crate::mining::MiningDrone>,
         )>,
     >,
     projectiles: Query<
@@ -136,6 +184,10 @@ pub fn reset_campaign_retry_world(
             With<crate::player::rendering::HealthBarBg>,
             With<crate::player::rendering::HealthBarFill>,
             With<crate::player::rendering::AimIndicatorMesh>,
+            With<crate::player::LockOnBracket>,
+            With<crate::player::rendering::ShieldRingMesh>,
+            With<crate::player::rendering::ShieldArcMesh>,
+            With<crate::campaign::ShopBeacon>,
         )>,
     >,
     mut player_ui_res: ResMut<crate::player::PlayerUiEntities>,
@@ -168,6 +220,15 @@ pub fn reset_campaign_retry_world(
     *ore = crate::mining::PlayerOre::default();
     *ammo = crate::player::MissileAmmo::default();
     *enemy_spawn = crate::enemy::EnemySpawnState::default();
+    commands.insert_resource(crate::campaign::WaveIntermissionShop::default());
+    commands.insert_resource(crate::campaign::WaveReinforcementQueue::default());
+    commands.insert_resource(crate::player::LockOnState::default());
+    commands.insert_resource(crate::player::AimTargetMemory::default());
+    commands.insert_resource(crate::player::KillCamState::default());
+    commands.insert_resource(crate::player::KillCamReplay::default());
+    commands.insert_resource(crate::simulation::sectors::SectorStore::default());
+    commands.insert_resource(crate::simulation::procgen::ProceduralField::default());
+    commands.insert_resource(crate::loading::ScenarioSpawnQueue::default());
 }
 
 #[cfg(test)]
@@ -195,11 +256,21 @@ mod tests {
         world.insert_resource(SimulationStats {
             live_count: 10,
             culled_total: 2,
+            stored_count: 5,
             merged_total: 3,
             split_total: 1,
             destroyed_total: 5,
+            formation_deferred: 2,
+            formation_deferred_total: 8,
+            spatial_reinserted: 4,
+            spatial_rebuilds_total: 6,
+        });
+        world.insert_resource(PlayerOre {
+            raw: [42, 7, 1],
+            credits: 30,
+            refining: [2, 0, 0],
+            refine_progress_secs: 0.5,
         });
-        world.insert_resource(PlayerOre { count: 42 });
         world.insert_resource(MissileAmmo { count: 1 });
         world.insert_resource(EnemySpawnState {
             timer_secs: 1.0,
@@ -255,7 +326,7 @@ mod tests {
         assert_eq!(lives.respawn_timer, None);
 
         let ore = world.resource::<PlayerOre>();
-        assert_eq!(ore.count, 0);
+        assert_eq!(*ore, PlayerOre::default());
 
         let ammo = world.resource::<MissileAmmo>();
         assert_eq!(ammo.count, MissileAmmo::default().count);
@@ -266,6 +337,8 @@ mod tests {
         let stats = world.resource::<SimulationStats>();
         assert_eq!(stats.culled_total, 0);
         assert_eq!(stats.destroyed_total, 0);
+        assert_eq!(stats.formation_deferred_total, 0);
+        assert_eq!(stats.spatial_rebuilds_total, 0);
 
         let ui = world.resource::<PlayerUiEntities>();
         assert!(ui.health_bar_bg.is_none());
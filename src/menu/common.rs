@@ -1,23 +1,5 @@
 use bevy::prelude::*;
 
-pub(super) fn start_bg() -> Color {
-    Color::srgb(0.08, 0.36, 0.14)
-}
-pub(super) fn start_border() -> Color {
-    Color::srgb(0.18, 0.72, 0.28)
-}
-pub(super) fn start_text() -> Color {
-    Color::srgb(0.75, 1.0, 0.80)
-}
-pub(super) fn quit_bg() -> Color {
-    Color::srgb(0.28, 0.06, 0.06)
-}
-pub(super) fn quit_border() -> Color {
-    Color::srgb(0.60, 0.12, 0.12)
-}
-pub(super) fn quit_text() -> Color {
-    Color::srgb(1.0, 0.65, 0.65)
-}
 pub(super) fn title_color() -> Color {
     Color::srgb(0.95, 0.88, 0.45)
 }
@@ -27,6 +9,12 @@ pub(super) fn subtitle_color() -> Color {
 pub(super) fn hint_color() -> Color {
     Color::srgb(0.28, 0.28, 0.35)
 }
+pub(super) fn storage_warning_color() -> Color {
+    Color::srgb(1.0, 0.72, 0.30)
+}
+pub(super) fn focus_outline_color() -> Color {
+    Color::srgb(1.0, 0.92, 0.55)
+}
 
 pub(super) fn scenario_card_bg() -> Color {
     Color::srgb(0.06, 0.09, 0.18)
@@ -53,15 +41,6 @@ pub(super) fn back_text() -> Color {
     Color::srgb(0.55, 0.55, 0.70)
 }
 
-pub(super) fn pause_resume_bg() -> Color {
-    Color::srgb(0.08, 0.36, 0.14)
-}
-pub(super) fn pause_resume_border() -> Color {
-    Color::srgb(0.18, 0.72, 0.28)
-}
-pub(super) fn pause_resume_text() -> Color {
-    Color::srgb(0.75, 1.0, 0.80)
-}
 pub(super) fn pause_debug_bg() -> Color {
     Color::srgb(0.10, 0.18, 0.36)
 }
@@ -71,15 +50,6 @@ pub(super) fn pause_debug_border() -> Color {
 pub(super) fn pause_debug_text() -> Color {
     Color::srgb(0.65, 0.80, 1.0)
 }
-pub(super) fn shop_buy_bg() -> Color {
-    Color::srgb(0.06, 0.22, 0.06)
-}
-pub(super) fn shop_buy_border() -> Color {
-    Color::srgb(0.18, 0.56, 0.18)
-}
-pub(super) fn shop_buy_text() -> Color {
-    Color::srgb(0.55, 1.0, 0.55)
-}
 pub(super) fn shop_close_bg() -> Color {
     Color::srgb(0.14, 0.14, 0.20)
 }
@@ -113,6 +83,16 @@ pub(super) fn format_saved_at(unix_secs: u64) -> String {
     }
 }
 
+pub(super) fn format_playtime(secs: f32) -> String {
+    let total = secs.max(0.0) as u64;
+    let (hours, minutes, seconds) = (total / 3600, total / 60 % 60, total % 60);
+    if hours > 0 {
+        format!("{hours}h {minutes:02}m")
+    } else {
+        format!("{minutes}m {seconds:02}s")
+    }
+}
+
 pub(super) fn spacer(parent: &mut ChildSpawnerCommands<'_>, px: f32) {
     parent.spawn(Node {
         height: Val::Px(px),
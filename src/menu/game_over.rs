@@ -1,15 +1,36 @@
 use super::*;
+use crate::lineage::{BiggestAccretion, Lineage};
+use crate::save::leaderboard::LeaderboardStore;
+use crate::save::SaveScenario;
+use crate::session_stats::SessionStats;
+
+/// Width (px) of the biggest-accretion icicle chart.
+const ACCRETION_CHART_WIDTH: f32 = 300.0;
+/// Height (px) of one level of the chart.
+const ACCRETION_CHART_ROW: f32 = 9.0;
+/// Levels drawn below the root.
+const ACCRETION_CHART_DEPTH: usize = 8;
 
 /// Spawn the game-over overlay centred over the frozen world.
 ///
-/// Shows final score and a "PLAY AGAIN" button.
+/// Shows final score, the scenario's leaderboard with a "NEW RECORD" callout
+/// (practice runs; see `save/leaderboard.rs`), the session summary (see
+/// `menu/session_summary.rs`), the biggest accretion of the run, and a
+/// "PLAY AGAIN" button.
 ///
 /// - Practice mode: re-spawns the player with fresh lives in the existing world.
 /// - Campaign mode: triggers a clean mission retry on `GameOver -> Playing`.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn setup_game_over(
     mut commands: Commands,
     score: Res<PlayerScore>,
+    biggest: Res<BiggestAccretion>,
+    stats: Res<SessionStats>,
+    leaderboard: Res<LeaderboardStore>,
+    mode: Res<SelectedGameMode>,
+    scenario: Res<SelectedScenario>,
     font: Res<GameFont>,
+    palette: Res<Palette>,
 ) {
     commands
         .spawn((
@@ -70,6 +91,25 @@ pub(super) fn setup_game_over(
                         TextColor(subtitle_color()),
                     ));
 
+                    if *mode == SelectedGameMode::Practice {
+                        pause_spacer(card, 4.0);
+                        spawn_game_over_leaderboard(
+                            card,
+                            &leaderboard.board,
+                            SaveScenario::from(*scenario),
+                            leaderboard.last_record.map(|(_, rank)| rank),
+                            &font,
+                        );
+                    }
+
+                    pause_spacer(card, 4.0);
+                    spawn_session_summary(card, &stats, &font);
+
+                    if let Some(lineage) = &biggest.lineage {
+                        pause_spacer(card, 4.0);
+                        spawn_accretion_summary(card, lineage, &font);
+                    }
+
                     pause_spacer(card, 8.0);
 
                     card.spawn((
@@ -82,8 +122,8 @@ pub(super) fn setup_game_over(
                             border: UiRect::all(Val::Px(2.0)),
                             ..default()
                         },
-                        BackgroundColor(pause_resume_bg()),
-                        BorderColor::all(pause_resume_border()),
+                        BackgroundColor(palette.confirm.bg),
+                        BorderColor::all(palette.confirm.border),
                         GameOverPlayAgainButton,
                     ))
                     .with_children(|btn| {
@@ -94,7 +134,7 @@ pub(super) fn setup_game_over(
                                 font_size: 18.0,
                                 ..default()
                             },
-                            TextColor(pause_resume_text()),
+                            TextColor(palette.confirm.text),
                         ));
                     });
 
@@ -108,8 +148,8 @@ pub(super) fn setup_game_over(
                             border: UiRect::all(Val::Px(2.0)),
                             ..default()
                         },
-                        BackgroundColor(quit_bg()),
-                        BorderColor::all(quit_border()),
+                        BackgroundColor(palette.cancel.bg),
+                        BorderColor::all(palette.cancel.border),
                         MenuQuitButton,
                     ))
                     .with_children(|btn| {
@@ -120,7 +160,7 @@ pub(super) fn setup_game_over(
                                 font_size: 18.0,
                                 ..default()
                             },
-                            TextColor(quit_text()),
+                            TextColor(palette.cancel.text),
                         ));
                     });
 
@@ -139,6 +179,61 @@ pub(super) fn setup_game_over(
         });
 }
 
+/// "Biggest accretion of the run": a one-line summary over an icicle chart
+/// of its merge tree.  Each row is one generation of ancestors, each block's
+/// width is its share of the mass, and merged ancestors are drawn brighter
+/// than bodies that joined whole.
+fn spawn_accretion_summary(
+    card: &mut ChildSpawnerCommands<'_>,
+    lineage: &Lineage,
+    font: &GameFont,
+) {
+    card.spawn((
+        Text::new(format!(
+            "☄ Biggest accretion: {} units from {} bodies · {} merges · first at {}",
+            lineage.mass(),
+            lineage.body_count(),
+            lineage.merge_count(),
+            format_playtime(lineage.first_merge_time()),
+        )),
+        TextFont {
+            font: font.0.clone(),
+            font_size: 13.0,
+            ..default()
+        },
+        TextColor(hint_color()),
+    ));
+
+    let cells = lineage.icicle(ACCRETION_CHART_DEPTH);
+    let rows = cells.iter().map(|cell| cell.depth + 1).max().unwrap_or(1);
+    card.spawn(Node {
+        width: Val::Px(ACCRETION_CHART_WIDTH),
+        height: Val::Px(rows as f32 * ACCRETION_CHART_ROW),
+        ..default()
+    })
+    .with_children(|chart| {
+        for cell in cells {
+            let shade = 0.85 - 0.07 * cell.depth as f32;
+            let color = if cell.merged {
+                Color::srgb(shade, 0.55 * shade, 0.2 * shade)
+            } else {
+                Color::srgb(0.45 * shade, 0.45 * shade, 0.5 * shade)
+            };
+            chart.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(cell.start * ACCRETION_CHART_WIDTH),
+                    top: Val::Px(cell.depth as f32 * ACCRETION_CHART_ROW),
+                    width: Val::Px((cell.width * ACCRETION_CHART_WIDTH - 1.0).max(1.0)),
+                    height: Val::Px(ACCRETION_CHART_ROW - 1.0),
+                    ..default()
+                },
+                BackgroundColor(color),
+            ));
+        }
+    });
+}
+
 /// Recursively despawn all game-over overlay entities.
 pub(super) fn cleanup_game_over(mut commands: Commands, query: Query<Entity, With<GameOverRoot>>) {
     for entity in query.iter() {
@@ -147,7 +242,7 @@ pub(super) fn cleanup_game_over(mut commands: Commands, query: Query<Entity, Wit
 }
 
 /// Handle Play Again / Quit actions in the game-over overlay.
-#[allow(clippy::type_complexity)]
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
 pub(super) fn game_over_button_system(
     play_query: Query<
         (&Interaction, &Children),
@@ -158,9 +253,12 @@ pub(super) fn game_over_button_system(
     mut next_state: ResMut<NextState<GameState>>,
     mut exit: MessageWriter<bevy::app::AppExit>,
     keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<MenuFocus>,
     mut lives: ResMut<PlayerLives>,
+    palette: Res<Palette>,
 ) {
-    let wants_play_again = keys.just_pressed(KeyCode::Enter)
+    // With a menu focus, Enter presses the focused button instead.
+    let wants_play_again = (keys.just_pressed(KeyCode::Enter) && focus.focused.is_none())
         || play_query.iter().any(|(i, _)| *i == Interaction::Pressed);
 
     if wants_play_again {
@@ -181,7 +279,7 @@ pub(super) fn game_over_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(pause_resume_text());
+                        *color = TextColor(palette.confirm.text);
                     }
                 }
             }
@@ -204,7 +302,7 @@ pub(super) fn game_over_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(quit_text());
+                        *color = TextColor(palette.cancel.text);
                     }
                 }
             }
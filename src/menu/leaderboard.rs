@@ -0,0 +1,142 @@
+use super::*;
+use crate::save::leaderboard::{format_date, Leaderboard, LeaderboardEntry};
+use crate::save::SaveScenario;
+
+/// Column widths (px) of the leaderboard table: rank, score, largest
+/// accretion, duration, date, assisted tag.
+const LEADERBOARD_COLUMNS: [f32; 6] = [36.0, 76.0, 84.0, 76.0, 96.0, 72.0];
+
+/// Tag shown on runs played with assists.
+const ASSISTED_TAG: &str = "ASSISTED";
+
+/// Rows shown on the game-over overlay.
+const GAME_OVER_ROWS: usize = 5;
+
+fn leaderboard_record_color() -> Color {
+    Color::srgb(1.0, 0.84, 0.30)
+}
+
+/// One-line best run under a scenario-select card's description.
+pub(super) fn spawn_scenario_best(
+    card: &mut ChildSpawnerCommands<'_>,
+    board: &Leaderboard,
+    scenario: SaveScenario,
+    font: &GameFont,
+) {
+    let line = match board.best(scenario) {
+        Some(best) => format!(
+            "Best {}{}  ·  largest {}  ·  {}  ·  {} runs recorded",
+            best.score,
+            if best.assisted { " (assisted)" } else { "" },
+            best.largest_accretion,
+            format_playtime(best.duration_secs),
+            board.scenario(scenario).count()
+        ),
+        None => "No runs recorded yet".to_string(),
+    };
+    spacer(card, 6.0);
+    card.spawn((
+        Text::new(line),
+        TextFont {
+            font: font.0.clone(),
+            font_size: 12.0,
+            ..default()
+        },
+        TextColor(subtitle_color()),
+    ));
+}
+
+/// Game-over callout and top runs for `scenario`, highlighting `rank` (the
+/// run that just ended) when it made the board.
+pub(super) fn spawn_game_over_leaderboard(
+    card: &mut ChildSpawnerCommands<'_>,
+    board: &Leaderboard,
+    scenario: SaveScenario,
+    rank: Option<usize>,
+    font: &GameFont,
+) {
+    let callout = match rank {
+        Some(1) => Some("★ NEW RECORD ★".to_string()),
+        Some(rank) => Some(format!("#{rank} on the {} leaderboard", scenario.label())),
+        None => None,
+    };
+    if let Some(callout) = callout {
+        card.spawn((
+            Text::new(callout),
+            TextFont {
+                font: font.0.clone(),
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(leaderboard_record_color()),
+        ));
+    }
+
+    let entries: Vec<&LeaderboardEntry> = board.scenario(scenario).take(GAME_OVER_ROWS).collect();
+    if entries.is_empty() {
+        return;
+    }
+    card.spawn(Node {
+        flex_direction: FlexDirection::Column,
+        row_gap: Val::Px(2.0),
+        ..default()
+    })
+    .with_children(|table| {
+        spawn_leaderboard_row(
+            table,
+            font,
+            ["#", "SCORE", "LARGEST", "TIME", "DATE", ""].map(String::from),
+            subtitle_color(),
+        );
+        for (i, entry) in entries.into_iter().enumerate() {
+            let color = if rank == Some(i + 1) {
+                leaderboard_record_color()
+            } else {
+                scenario_active_text()
+            };
+            spawn_leaderboard_row(
+                table,
+                font,
+                [
+                    (i + 1).to_string(),
+                    entry.score.to_string(),
+                    entry.largest_accretion.to_string(),
+                    format_playtime(entry.duration_secs),
+                    format_date(entry.recorded_at_unix),
+                    if entry.assisted { ASSISTED_TAG } else { "" }.to_string(),
+                ],
+                color,
+            );
+        }
+    });
+}
+
+fn spawn_leaderboard_row(
+    table: &mut ChildSpawnerCommands<'_>,
+    font: &GameFont,
+    cells: [String; 6],
+    color: Color,
+) {
+    table
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            ..default()
+        })
+        .with_children(|row| {
+            for (cell, width) in cells.into_iter().zip(LEADERBOARD_COLUMNS) {
+                row.spawn((
+                    Node {
+                        width: Val::Px(width),
+                        ..default()
+                    },
+                    Text::new(cell),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+        });
+}
@@ -1,6 +1,13 @@
 use super::*;
+use bevy::window::FileDragAndDrop;
 
-pub fn setup_load_game_menu(mut commands: Commands, font: Res<GameFont>) {
+pub fn setup_load_game_menu(
+    mut commands: Commands,
+    font: Res<GameFont>,
+    config: Res<PhysicsConfig>,
+    palette: Res<Palette>,
+) {
+    let usage = StorageUsage::scan_default();
     commands
         .spawn((
             Node {
@@ -37,26 +44,43 @@ pub fn setup_load_game_menu(mut commands: Commands, font: Res<GameFont>) {
                 TextColor(subtitle_color()),
             ));
 
+            if usage.over_quota(config.save_storage_quota_mb) {
+                spacer(root, 6.0);
+                root.spawn((
+                    Text::new(format!(
+                        "⚠ Saves use {} of a {:.0} MB quota. Prune old files in Settings.",
+                        format_bytes(usage.total_bytes()),
+                        config.save_storage_quota_mb
+                    )),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(storage_warning_color()),
+                ));
+            }
+
             spacer(root, 30.0);
 
             for slot in 1..=SAVE_SLOT_COUNT {
                 let meta = slot_metadata(slot);
                 let button_bg = if meta.loadable {
-                    start_bg()
+                    palette.confirm.bg
                 } else if meta.exists {
                     Color::srgb(0.22, 0.10, 0.10)
                 } else {
                     Color::srgb(0.10, 0.10, 0.10)
                 };
                 let button_border = if meta.loadable {
-                    start_border()
+                    palette.confirm.border
                 } else if meta.exists {
                     Color::srgb(0.55, 0.25, 0.25)
                 } else {
                     Color::srgb(0.22, 0.22, 0.22)
                 };
                 let button_text_color = if meta.loadable {
-                    start_text()
+                    palette.confirm.text
                 } else {
                     Color::srgb(0.45, 0.45, 0.45)
                 };
@@ -69,64 +93,227 @@ pub fn setup_load_game_menu(mut commands: Commands, font: Res<GameFont>) {
                 };
                 let details = if let Some(scenario) = meta.scenario {
                     let ts = meta.saved_at_unix.unwrap_or(0);
-                    format!("{}  •  {}", scenario.label(), format_saved_at(ts))
+                    format!(
+                        "{}  •  {}  •  {}",
+                        scenario.label(),
+                        format_saved_at(ts),
+                        format_bytes(usage.kind_bytes(StorageKind::Slot(slot)))
+                    )
                 } else if meta.exists {
                     "unreadable save file".to_string()
                 } else {
                     "no save data".to_string()
                 };
+                let stats = meta.summary.as_ref().map(|summary| {
+                    let wave = summary
+                        .wave
+                        .map_or(String::new(), |wave| format!("  •  wave {wave}"));
+                    format!(
+                        "{} asteroids  •  largest {}  •  score {}{wave}",
+                        summary.asteroid_count, summary.largest_mass, summary.score
+                    )
+                });
 
-                let mut entity = root.spawn((
-                    Button,
-                    Node {
-                        width: Val::Px(260.0),
-                        height: Val::Px(72.0),
-                        justify_content: JustifyContent::Center,
-                        align_items: AlignItems::Center,
-                        flex_direction: FlexDirection::Column,
-                        border: UiRect::all(Val::Px(2.0)),
-                        ..default()
-                    },
-                    BackgroundColor(button_bg),
-                    BorderColor::all(button_border),
-                ));
+                let mut row = root.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                });
+                row.with_children(|row| {
+                    let mut entity = row.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(320.0),
+                            height: Val::Px(88.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            flex_direction: FlexDirection::Column,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(button_bg),
+                        BorderColor::all(button_border),
+                    ));
 
-                match slot {
-                    1 => {
-                        entity.insert(LoadSlot1Button);
+                    match slot {
+                        1 => {
+                            entity.insert(LoadSlot1Button);
+                        }
+                        2 => {
+                            entity.insert(LoadSlot2Button);
+                        }
+                        _ => {
+                            entity.insert(LoadSlot3Button);
+                        }
                     }
-                    2 => {
-                        entity.insert(LoadSlot2Button);
+
+                    entity.with_children(|btn| {
+                        btn.spawn((
+                            Text::new(label),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 17.0,
+                                ..default()
+                            },
+                            TextColor(button_text_color),
+                        ));
+                        btn.spawn((
+                            Text::new(details),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.60, 0.66, 0.72)),
+                        ));
+                        if let Some(stats) = stats {
+                            btn.spawn((
+                                Text::new(stats),
+                                TextFont {
+                                    font: font.0.clone(),
+                                    font_size: 11.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(0.52, 0.58, 0.64)),
+                            ));
+                        }
+                    });
+
+                    if meta.summary.is_some() {
+                        row.spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(84.0),
+                                height: Val::Px(88.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(back_bg()),
+                            BorderColor::all(back_border()),
+                            LoadSlotDetailsButton(slot),
+                        ))
+                        .with_children(|btn| {
+                            btn.spawn((
+                                Text::new("DETAILS"),
+                                TextFont {
+                                    font: font.0.clone(),
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(back_text()),
+                            ));
+                        });
                     }
-                    _ => {
-                        entity.insert(LoadSlot3Button);
+
+                    if meta.loadable {
+                        row.spawn((
+                            Button,
+                            Node {
+                                width: Val::Px(84.0),
+                                height: Val::Px(88.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(back_bg()),
+                            BorderColor::all(back_border()),
+                            LoadSlotExportButton(slot),
+                        ))
+                        .with_children(|btn| {
+                            btn.spawn((
+                                Text::new("EXPORT"),
+                                TextFont {
+                                    font: font.0.clone(),
+                                    font_size: 12.0,
+                                    ..default()
+                                },
+                                TextColor(back_text()),
+                            ));
+                        });
                     }
-                }
+                });
 
-                entity.with_children(|btn| {
-                    btn.spawn((
-                        Text::new(label),
+                if let Some(summary) = &meta.summary {
+                    let up = summary.upgrades;
+                    root.spawn((
+                        Text::new(format!(
+                            "playtime {}\n\
+                             blaster chip {} / destroy {} / rate {}  •  secondary {}\n\
+                             ore affinity {}  •  tractor {}  •  shield {}  •  drone {}",
+                            format_playtime(summary.playtime_secs),
+                            up.primary_chip,
+                            up.primary_destroy,
+                            up.fire_rate,
+                            up.secondary,
+                            up.ore_affinity,
+                            up.tractor,
+                            up.shield,
+                            up.mining_drone,
+                        )),
                         TextFont {
                             font: font.0.clone(),
-                            font_size: 17.0,
+                            font_size: 11.0,
                             ..default()
                         },
-                        TextColor(button_text_color),
-                    ));
-                    btn.spawn((
-                        Text::new(details),
-                        TextFont {
-                            font: font.0.clone(),
-                            font_size: 12.0,
+                        TextColor(Color::srgb(0.60, 0.66, 0.72)),
+                        Node {
+                            display: Display::None,
+                            margin: UiRect::top(Val::Px(6.0)),
                             ..default()
                         },
-                        TextColor(Color::srgb(0.60, 0.66, 0.72)),
+                        LoadSlotDetailsPanel(slot),
                     ));
-                });
+                }
 
                 spacer(root, 12.0);
             }
 
+            root.spawn((
+                Button,
+                Node {
+                    width: Val::Px(180.0),
+                    height: Val::Px(36.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(back_bg()),
+                BorderColor::all(back_border()),
+                LoadGameImportButton,
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new("IMPORT"),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 14.0,
+                        ..default()
+                    },
+                    TextColor(back_text()),
+                ));
+            });
+
+            spacer(root, 6.0);
+
+            root.spawn((
+                Text::new(format!(
+                    "drop a .{BUNDLE_EXTENSION} file on the window, or put one in {}/",
+                    import_dir().display()
+                )),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.52, 0.58, 0.64)),
+                LoadGameStatusText,
+            ));
+
             spacer(root, 16.0);
 
             root.spawn((
@@ -174,6 +361,7 @@ pub fn load_game_menu_button_system(
     mut btn_text: Query<&mut TextColor>,
     mut next_state: ResMut<NextState<GameState>>,
     mut selected_mode: ResMut<SelectedGameMode>,
+    palette: Res<Palette>,
 ) {
     let mut handle_slot = |slot: u8, interaction: &Interaction| -> bool {
         if *interaction != Interaction::Pressed {
@@ -210,7 +398,7 @@ pub fn load_game_menu_button_system(
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
                         *color = TextColor(if slot_loadable(1) {
-                            start_text()
+                            palette.confirm.text
                         } else {
                             Color::srgb(0.45, 0.45, 0.45)
                         });
@@ -237,7 +425,7 @@ pub fn load_game_menu_button_system(
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
                         *color = TextColor(if slot_loadable(2) {
-                            start_text()
+                            palette.confirm.text
                         } else {
                             Color::srgb(0.45, 0.45, 0.45)
                         });
@@ -264,7 +452,7 @@ pub fn load_game_menu_button_system(
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
                         *color = TextColor(if slot_loadable(3) {
-                            start_text()
+                            palette.confirm.text
                         } else {
                             Color::srgb(0.45, 0.45, 0.45)
                         });
@@ -297,3 +485,113 @@ pub fn load_game_menu_button_system(
         }
     }
 }
+
+/// Expand or collapse a load card's details when its button is pressed.
+#[allow(clippy::type_complexity)]
+pub fn load_game_details_toggle_system(
+    q_buttons: Query<(&Interaction, &LoadSlotDetailsButton), Changed<Interaction>>,
+    mut q_panels: Query<(&LoadSlotDetailsPanel, &mut Node)>,
+) {
+    for (interaction, button) in q_buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        for (panel, mut node) in q_panels.iter_mut() {
+            if panel.0 == button.0 {
+                node.display = match node.display {
+                    Display::None => Display::Flex,
+                    _ => Display::None,
+                };
+            }
+        }
+    }
+}
+
+/// Write a slot's `.accretion` bundle to `exports/` when its EXPORT button is
+/// pressed.  The bundle gets the config without the difficulty overlay; the
+/// slot's own preset is reapplied on import.
+pub fn load_game_export_system(
+    q_buttons: Query<(&Interaction, &LoadSlotExportButton), Changed<Interaction>>,
+    config: Res<PhysicsConfig>,
+    overlay: Res<DifficultyOverlay>,
+    mut q_status: Query<&mut Text, With<LoadGameStatusText>>,
+) {
+    let config = overlay.base().unwrap_or(&config);
+    for (interaction, button) in q_buttons.iter() {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+        let status = match load_slot(button.0).and_then(|snapshot| export_bundle(&snapshot, config))
+        {
+            Ok(path) => {
+                info!("Exported slot {} to {}", button.0, path.display());
+                format!("slot {} exported to {}", button.0, path.display())
+            }
+            Err(err) => {
+                error!("Failed to export slot {}: {}", button.0, err);
+                format!("export failed: {err}")
+            }
+        };
+        for mut text in q_status.iter_mut() {
+            text.0 = status.clone();
+        }
+    }
+}
+
+/// Load an `.accretion` bundle dropped on the window, or the newest one in
+/// `imports/` when IMPORT is pressed.  The bundle's physics config replaces
+/// the live one before the world is spawned.
+#[allow(clippy::too_many_arguments)]
+pub fn load_game_import_system(
+    mut commands: Commands,
+    mut dropped: MessageReader<FileDragAndDrop>,
+    q_import: Query<&Interaction, (Changed<Interaction>, With<LoadGameImportButton>)>,
+    mut q_status: Query<&mut Text, With<LoadGameStatusText>>,
+    mut config: ResMut<PhysicsConfig>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut selected_mode: ResMut<SelectedGameMode>,
+) {
+    let mut path = dropped
+        .read()
+        .filter_map(|message| match message {
+            FileDragAndDrop::DroppedFile { path_buf, .. } => Some(path_buf.clone()),
+            _ => None,
+        })
+        .last();
+    if path.is_none() && q_import.iter().any(|i| *i == Interaction::Pressed) {
+        path = newest_import();
+        if path.is_none() {
+            for mut text in q_status.iter_mut() {
+                text.0 = format!(
+                    "no .{BUNDLE_EXTENSION} files in {}/",
+                    import_dir().display()
+                );
+            }
+            return;
+        }
+    }
+    let Some(path) = path else {
+        return;
+    };
+
+    match import_bundle(&path) {
+        Ok(bundle) => {
+            info!(
+                "Imported {} ({} seed {})",
+                path.display(),
+                bundle.manifest.scenario,
+                bundle.manifest.seed
+            );
+            *config = bundle.physics;
+            commands.insert_resource(PendingLoadedSnapshot(Some(bundle.snapshot)));
+            *selected_mode = SelectedGameMode::Practice;
+            next_state.set(GameState::Playing);
+        }
+        Err(err) => {
+            error!("Failed to import bundle: {}", err);
+            for mut text in q_status.iter_mut() {
+                text.0 = format!("import failed: {err}");
+            }
+        }
+    }
+}
@@ -0,0 +1,289 @@
+use super::*;
+use crate::audio::{AudioSettings, SoundBank};
+use crate::graphics::{EmojiFont, SymbolFont, SymbolFont2, UnicodeFallbackFont};
+use crate::particles::ParticleDormant;
+use crate::player::ShotMeshes;
+use crate::save::SaveScenario;
+use crate::scoring::ScoringConfig;
+use bevy::ecs::system::SystemParam;
+
+/// One-line hint shown under the scenario name while it loads.
+pub fn scenario_tip(scenario: SelectedScenario) -> &'static str {
+    match scenario {
+        SelectedScenario::Field => {
+            "Small rocks merge on contact. Break up dense clusters before they grow into planetoids."
+        }
+        SelectedScenario::Orbit => {
+            "The debris rings orbit the planetoid. Drift with them instead of flying against them."
+        }
+        SelectedScenario::Comets => {
+            "Big bodies cross the field slowly. Pull them apart with the tractor to stop early merges."
+        }
+        SelectedScenario::Shower => {
+            "Hundreds of small bodies rain inward. Missiles clear a crowded lane faster than the blaster."
+        }
+        SelectedScenario::Survival => {
+            "Waves escalate on a timer. Spend ore in the shop (Tab) between waves."
+        }
+        SelectedScenario::Catalog => {
+            "Bodies follow real orbital elements around the star. Inner orbits move fastest."
+        }
+        SelectedScenario::Frontier => {
+            "The field never ends. New chunks appear as you fly, and sectors you leave are remembered."
+        }
+    }
+}
+
+/// Every font the HUD and menus draw with, plus the font asset store.
+#[derive(SystemParam)]
+pub struct SessionFonts<'w> {
+    loaded: Res<'w, Assets<Font>>,
+    game: Res<'w, GameFont>,
+    symbol: Res<'w, SymbolFont>,
+    symbol_2: Res<'w, SymbolFont2>,
+    unicode: Res<'w, UnicodeFallbackFont>,
+    emoji: Res<'w, EmojiFont>,
+}
+
+impl SessionFonts<'_> {
+    fn ready(&self) -> bool {
+        [
+            &self.game.0,
+            &self.symbol.0,
+            &self.symbol_2.0,
+            &self.unicode.0,
+            &self.emoji.0,
+        ]
+        .into_iter()
+        .all(|handle| self.loaded.contains(handle.id()))
+    }
+}
+
+/// Spawn the loading screen for the selected scenario.
+///
+/// Layout:
+/// ```text
+/// ┌───────────────────────────────────────────────┐
+/// │                  LOADING                      │
+/// │                  SHOWER                       │
+/// │                                               │
+/// │   TIP  Hundreds of small bodies rain inward…  │
+/// │                                               │
+/// │              Building pools...                │
+/// └───────────────────────────────────────────────┘
+/// ```
+pub fn setup_loading_screen(
+    mut commands: Commands,
+    font: Res<GameFont>,
+    scenario: Res<SelectedScenario>,
+) {
+    commands.insert_resource(LoadingScreenTimer::default());
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+            LoadingScreenRoot,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("LOADING"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 42.0,
+                    ..default()
+                },
+                TextColor(title_color()),
+            ));
+
+            spacer(root, 10.0);
+
+            root.spawn((
+                Text::new(SaveScenario::from(*scenario).label()),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(subtitle_color()),
+            ));
+
+            spacer(root, 36.0);
+
+            root.spawn((
+                Text::new(format!("TIP  {}", scenario_tip(*scenario))),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(scenario_desc_color()),
+                Node {
+                    max_width: Val::Px(560.0),
+                    ..default()
+                },
+            ));
+
+            spacer(root, 36.0);
+
+            root.spawn((
+                Text::new("Preparing..."),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(hint_color()),
+                LoadingScreenStatus,
+            ));
+        });
+}
+
+/// Despawn the loading screen.
+pub fn cleanup_loading_screen(
+    mut commands: Commands,
+    query: Query<Entity, With<LoadingScreenRoot>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Wait until the session's assets and pools are ready and the screen has
+/// been up for `loading_min_secs`, then enter `Playing`.
+///
+/// Checked in order, the status line names the first step still pending:
+/// fonts, synthesized audio (only when the audio plugin is present), the
+/// scoring config, and the shot meshes and prewarmed particles.
+#[allow(clippy::too_many_arguments)]
+pub fn loading_screen_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    fonts: SessionFonts,
+    sounds: Option<Res<SoundBank>>,
+    audio_settings: Option<Res<AudioSettings>>,
+    scoring: Option<Res<ScoringConfig>>,
+    shot_meshes: Option<Res<ShotMeshes>>,
+    unmeshed_particles: Query<(), (With<ParticleDormant>, Without<Mesh2d>)>,
+    mut timer: ResMut<LoadingScreenTimer>,
+    mut status: Query<&mut Text, With<LoadingScreenStatus>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    timer.elapsed += time.delta_secs();
+
+    let pending = if !fonts.ready() {
+        Some("Loading fonts...")
+    } else if sounds.is_none() && audio_settings.is_some() {
+        Some("Synthesizing audio...")
+    } else if scoring.is_none() {
+        Some("Reading config...")
+    } else if shot_meshes.is_none() || !unmeshed_particles.is_empty() {
+        Some("Building pools...")
+    } else {
+        None
+    };
+
+    if let Ok(mut text) = status.single_mut() {
+        let line = pending.unwrap_or("Ready");
+        if text.0 != line {
+            text.0 = line.to_string();
+        }
+    }
+
+    if pending.is_none() && timer.elapsed >= config.loading_min_secs {
+        next_state.set(GameState::Playing);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    fn loading_app(min_secs: f32) -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Loading)
+            .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+                100,
+            )))
+            .insert_resource(PhysicsConfig {
+                loading_min_secs: min_secs,
+                ..default()
+            })
+            .insert_resource(ScoringConfig::default())
+            .init_resource::<LoadingScreenTimer>()
+            .init_resource::<Assets<Font>>()
+            .init_resource::<Assets<Mesh>>()
+            .init_resource::<Assets<ColorMaterial>>()
+            .init_resource::<GameFont>()
+            .init_resource::<SymbolFont>()
+            .init_resource::<SymbolFont2>()
+            .init_resource::<UnicodeFallbackFont>()
+            .init_resource::<EmojiFont>()
+            .add_systems(
+                Update,
+                (
+                    crate::player::prepare_shot_meshes_system,
+                    loading_screen_system.run_if(in_state(GameState::Loading)),
+                )
+                    .chain(),
+            );
+        app
+    }
+
+    fn load_fonts(app: &mut App) {
+        let bytes = std::fs::read("assets/fonts/Tektur-Regular.ttf").unwrap();
+        let handle = app
+            .world_mut()
+            .resource_mut::<Assets<Font>>()
+            .add(Font::try_from_bytes(bytes).unwrap());
+        let world = app.world_mut();
+        world.resource_mut::<GameFont>().0 = handle.clone();
+        world.resource_mut::<SymbolFont>().0 = handle.clone();
+        world.resource_mut::<SymbolFont2>().0 = handle.clone();
+        world.resource_mut::<UnicodeFallbackFont>().0 = handle.clone();
+        world.resource_mut::<EmojiFont>().0 = handle;
+    }
+
+    fn state(app: &App) -> GameState {
+        app.world().resource::<State<GameState>>().get().clone()
+    }
+
+    #[test]
+    fn loading_waits_for_fonts() {
+        let mut app = loading_app(0.2);
+        for _ in 0..10 {
+            app.update();
+        }
+        assert_eq!(state(&app), GameState::Loading);
+
+        load_fonts(&mut app);
+        app.update();
+        app.update();
+        assert_eq!(state(&app), GameState::Playing);
+    }
+
+    #[test]
+    fn loading_screen_stays_up_for_the_minimum_time() {
+        let mut app = loading_app(0.5);
+        load_fonts(&mut app);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(state(&app), GameState::Loading);
+        for _ in 0..4 {
+            app.update();
+        }
+        assert_eq!(state(&app), GameState::Playing);
+        assert!(app.world().contains_resource::<ShotMeshes>());
+    }
+}
@@ -1,4 +1,5 @@
 use super::*;
+use crate::session_stats::SessionStats;
 
 /// Spawn the full-screen main-menu overlay.
 ///
@@ -8,7 +9,16 @@ use super::*;
 /// │         Accretion                           │
 /// │   A gravitational aggregation simulation    │
 /// │                                             │
+/// │  ┌ Resume last session? ────────────────┐   │  (after an unclean
+/// │  │ FIELD  •  12:30  •  score 420        │   │   exit only)
+/// │  │      [ RESUME ]  [ DISCARD ]         │   │
+/// │  └──────────────────────────────────────┘   │
+/// │  ┌ Last session ────────────────────────┐   │  (after quitting
+/// │  │ totals · per-weapon table            │   │   from the pause
+/// │  │            [ DISMISS ]               │   │   menu only)
+/// │  └──────────────────────────────────────┘   │
 /// │         [ START GAME ]                      │
+/// │          [ SETTINGS ]                       │
 /// │            [ QUIT ]                         │
 /// │                                             │
 /// │          v0.1.0  ·  Bevy 0.17               │
@@ -19,6 +29,9 @@ fn setup_main_menu(
     font: Res<GameFont>,
     symbol_font_2: Res<crate::graphics::SymbolFont2>,
     emoji_font: Res<crate::graphics::EmojiFont>,
+    recovery: Res<CrashRecovery>,
+    summary: Option<&SessionStats>,
+    palette: Res<Palette>,
 ) {
     commands
         .spawn((
@@ -84,6 +97,16 @@ fn setup_main_menu(
 
             spacer(root, 52.0);
 
+            if let Some(snapshot) = recovery.0.as_ref() {
+                spawn_crash_recovery_prompt(root, &font, snapshot, &palette);
+                spacer(root, 28.0);
+            }
+
+            if let Some(stats) = summary {
+                spawn_session_summary_card(root, &font, stats);
+                spacer(root, 28.0);
+            }
+
             root.spawn((
                 Button,
                 Node {
@@ -94,8 +117,8 @@ fn setup_main_menu(
                     border: UiRect::all(Val::Px(2.0)),
                     ..default()
                 },
-                BackgroundColor(start_bg()),
-                BorderColor::all(start_border()),
+                BackgroundColor(palette.confirm.bg),
+                BorderColor::all(palette.confirm.border),
                 MenuCampaignButton,
             ))
             .with_children(|btn| {
@@ -106,7 +129,7 @@ fn setup_main_menu(
                         font_size: 18.0,
                         ..default()
                     },
-                    TextColor(start_text()),
+                    TextColor(palette.confirm.text),
                 ));
                 btn.spawn((
                     Text::new("CAMPAIGN"),
@@ -115,7 +138,7 @@ fn setup_main_menu(
                         font_size: 18.0,
                         ..default()
                     },
-                    TextColor(start_text()),
+                    TextColor(palette.confirm.text),
                 ));
                 btn.spawn((
                     Text::new(" ✦"),
@@ -124,7 +147,7 @@ fn setup_main_menu(
                         font_size: 18.0,
                         ..default()
                     },
-                    TextColor(start_text()),
+                    TextColor(palette.confirm.text),
                 ));
             });
 
@@ -140,8 +163,8 @@ fn setup_main_menu(
                     border: UiRect::all(Val::Px(2.0)),
                     ..default()
                 },
-                BackgroundColor(start_bg()),
-                BorderColor::all(start_border()),
+                BackgroundColor(palette.confirm.bg),
+                BorderColor::all(palette.confirm.border),
                 MenuPracticeButton,
             ))
             .with_children(|btn| {
@@ -152,7 +175,7 @@ fn setup_main_menu(
                         font_size: 18.0,
                         ..default()
                     },
-                    TextColor(start_text()),
+                    TextColor(palette.confirm.text),
                 ));
                 btn.spawn((
                     Text::new("PRACTICE"),
@@ -161,7 +184,7 @@ fn setup_main_menu(
                         font_size: 18.0,
                         ..default()
                     },
-                    TextColor(start_text()),
+                    TextColor(palette.confirm.text),
                 ));
                 btn.spawn((
                     Text::new(" ✧"),
@@ -170,7 +193,7 @@ fn setup_main_menu(
                         font_size: 18.0,
                         ..default()
                     },
-                    TextColor(start_text()),
+                    TextColor(palette.confirm.text),
                 ));
             });
 
@@ -232,8 +255,54 @@ fn setup_main_menu(
                     border: UiRect::all(Val::Px(2.0)),
                     ..default()
                 },
-                BackgroundColor(quit_bg()),
-                BorderColor::all(quit_border()),
+                BackgroundColor(back_bg()),
+                BorderColor::all(back_border()),
+                MenuSettingsButton,
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new("✧ "),
+                    TextFont {
+                        font: symbol_font_2.0.clone(),
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(back_text()),
+                ));
+                btn.spawn((
+                    Text::new("SETTINGS"),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(back_text()),
+                ));
+                btn.spawn((
+                    Text::new(" ✧"),
+                    TextFont {
+                        font: symbol_font_2.0.clone(),
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(back_text()),
+                ));
+            });
+
+            spacer(root, 14.0);
+
+            root.spawn((
+                Button,
+                Node {
+                    width: Val::Px(220.0),
+                    height: Val::Px(50.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(palette.cancel.bg),
+                BorderColor::all(palette.cancel.border),
                 MenuQuitButton,
             ))
             .with_children(|btn| {
@@ -244,7 +313,7 @@ fn setup_main_menu(
                         font_size: 18.0,
                         ..default()
                     },
-                    TextColor(quit_text()),
+                    TextColor(palette.cancel.text),
                 ));
                 btn.spawn((
                     Text::new("QUIT"),
@@ -253,7 +322,7 @@ fn setup_main_menu(
                         font_size: 18.0,
                         ..default()
                     },
-                    TextColor(quit_text()),
+                    TextColor(palette.cancel.text),
                 ));
                 btn.spawn((
                     Text::new(" ✦"),
@@ -262,7 +331,7 @@ fn setup_main_menu(
                         font_size: 18.0,
                         ..default()
                     },
-                    TextColor(quit_text()),
+                    TextColor(palette.cancel.text),
                 ));
             });
 
@@ -280,10 +349,107 @@ fn setup_main_menu(
         });
 }
 
+/// Card offering to resume the autosave of a session that did not exit cleanly.
+fn spawn_crash_recovery_prompt(
+    root: &mut ChildSpawnerCommands<'_>,
+    font: &GameFont,
+    snapshot: &SaveSnapshot,
+    palette: &Palette,
+) {
+    let summary = SaveSlotSummary::from_snapshot(snapshot, None);
+    root.spawn((
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(8.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(scenario_card_bg()),
+        BorderColor::all(scenario_card_border()),
+        CrashRecoveryPrompt,
+    ))
+    .with_children(|card| {
+        card.spawn((
+            Text::new("Resume last session?"),
+            TextFont {
+                font: font.0.clone(),
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(scenario_active_text()),
+        ));
+        card.spawn((
+            Text::new(format!(
+                "{}  •  {}  •  score {}  •  saved {}",
+                summary.scenario.label(),
+                format_playtime(summary.playtime_secs),
+                summary.score,
+                format_saved_at(summary.saved_at_unix)
+            )),
+            TextFont {
+                font: font.0.clone(),
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(scenario_desc_color()),
+        ));
+        card.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(12.0),
+            margin: UiRect::top(Val::Px(4.0)),
+            ..default()
+        })
+        .with_children(|row| {
+            for (label, bg, border, text) in [
+                (
+                    "RESUME",
+                    palette.confirm.bg,
+                    palette.confirm.border,
+                    palette.confirm.text,
+                ),
+                ("DISCARD", back_bg(), back_border(), back_text()),
+            ] {
+                let mut button = row.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(130.0),
+                        height: Val::Px(36.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(bg),
+                    BorderColor::all(border),
+                ));
+                if label == "RESUME" {
+                    button.insert(CrashRecoveryResumeButton);
+                } else {
+                    button.insert(CrashRecoveryDiscardButton);
+                }
+                button.with_children(|btn| {
+                    btn.spawn((
+                        Text::new(label),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 15.0,
+                            ..default()
+                        },
+                        TextColor(text),
+                    ));
+                });
+            }
+        });
+    });
+}
+
 /// Spawn the main menu once the configured font asset is loaded.
 ///
 /// This prevents first-frame fallback text when entering `MainMenu` before
 /// the font handle has finished loading.
+#[allow(clippy::too_many_arguments)]
 pub(super) fn setup_main_menu_when_font_ready(
     commands: Commands,
     font: Res<GameFont>,
@@ -291,6 +457,10 @@ pub(super) fn setup_main_menu_when_font_ready(
     symbol_font_2: Res<crate::graphics::SymbolFont2>,
     loaded_fonts: Res<Assets<Font>>,
     existing_menu: Query<Entity, With<MainMenuRoot>>,
+    recovery: Res<CrashRecovery>,
+    summary_pending: Res<SessionSummaryPending>,
+    stats: Res<SessionStats>,
+    palette: Res<Palette>,
 ) {
     if !existing_menu.is_empty() {
         return;
@@ -303,7 +473,16 @@ pub(super) fn setup_main_menu_when_font_ready(
         return;
     }
 
-    setup_main_menu(commands, font, symbol_font_2, emoji_font);
+    let summary = summary_pending.0.then_some(&*stats);
+    setup_main_menu(
+        commands,
+        font,
+        symbol_font_2,
+        emoji_font,
+        recovery,
+        summary,
+        palette,
+    );
 }
 
 /// Recursively despawn all main-menu entities.
@@ -313,11 +492,12 @@ pub(super) fn cleanup_main_menu(mut commands: Commands, query: Query<Entity, Wit
     }
 }
 
-/// Handle Campaign / Practice / Load / Quit button presses.
+/// Handle Campaign / Practice / Load / Settings / Quit button presses.
 ///
-/// - **Campaign** → sets [`SelectedGameMode::Campaign`] then transitions to [`GameState::CampaignSelect`].
+/// - **Campaign** → sets [`SelectedGameMode::Campaign`] and [`Difficulty::Normal`] then transitions to [`GameState::CampaignSelect`].
 /// - **Practice** → sets [`SelectedGameMode::Practice`] then transitions to [`GameState::ScenarioSelect`].
 /// - **Load Game** → transitions to [`GameState::LoadGameMenu`].
+/// - **Settings** → transitions to [`GameState::Settings`].
 /// - **Quit** → sends [`AppExit`] to gracefully shut down.
 #[allow(clippy::type_complexity)]
 #[allow(clippy::too_many_arguments)]
@@ -331,16 +511,24 @@ pub(super) fn menu_button_system(
         (Changed<Interaction>, With<MenuPracticeButton>),
     >,
     load_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<MenuLoadButton>)>,
+    settings_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<MenuSettingsButton>),
+    >,
     quit_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<MenuQuitButton>)>,
     mut btn_text: Query<&mut TextColor>,
     mut next_state: ResMut<NextState<GameState>>,
     mut selected_mode: ResMut<SelectedGameMode>,
+    mut difficulty: ResMut<Difficulty>,
     mut exit: MessageWriter<bevy::app::AppExit>,
+    palette: Res<Palette>,
 ) {
     for (interaction, children) in campaign_query.iter() {
         match interaction {
             Interaction::Pressed => {
                 *selected_mode = SelectedGameMode::Campaign;
+                // Presets are a practice option; missions play as tuned.
+                *difficulty = Difficulty::Normal;
                 next_state.set(GameState::CampaignSelect);
             }
             Interaction::Hovered => {
@@ -353,7 +541,7 @@ pub(super) fn menu_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(start_text());
+                        *color = TextColor(palette.confirm.text);
                     }
                 }
             }
@@ -376,7 +564,7 @@ pub(super) fn menu_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(start_text());
+                        *color = TextColor(palette.confirm.text);
                     }
                 }
             }
@@ -405,6 +593,28 @@ pub(super) fn menu_button_system(
         }
     }
 
+    for (interaction, children) in settings_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                next_state.set(GameState::Settings);
+            }
+            Interaction::Hovered => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(Color::WHITE);
+                    }
+                }
+            }
+            Interaction::None => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(back_text());
+                    }
+                }
+            }
+        }
+    }
+
     for (interaction, children) in quit_query.iter() {
         match interaction {
             Interaction::Pressed => {
@@ -420,7 +630,82 @@ pub(super) fn menu_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(quit_text());
+                        *color = TextColor(palette.cancel.text);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Handle the crash prompt's buttons.
+///
+/// - **Resume** → loads the autosave as a practice session and enters [`GameState::Playing`].
+/// - **Discard** → deletes the autosave and removes the prompt.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub(super) fn crash_recovery_prompt_system(
+    mut commands: Commands,
+    resume_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<CrashRecoveryResumeButton>),
+    >,
+    discard_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<CrashRecoveryDiscardButton>),
+    >,
+    prompt_query: Query<Entity, With<CrashRecoveryPrompt>>,
+    mut btn_text: Query<&mut TextColor>,
+    mut recovery: ResMut<CrashRecovery>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut selected_mode: ResMut<SelectedGameMode>,
+    palette: Res<Palette>,
+) {
+    for (interaction, children) in resume_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                if let Some(snapshot) = recovery.0.take() {
+                    commands.insert_resource(PendingLoadedSnapshot(Some(snapshot)));
+                    *selected_mode = SelectedGameMode::Practice;
+                    next_state.set(GameState::Playing);
+                }
+            }
+            Interaction::Hovered => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(Color::WHITE);
+                    }
+                }
+            }
+            Interaction::None => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(palette.confirm.text);
+                    }
+                }
+            }
+        }
+    }
+
+    for (interaction, children) in discard_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                recovery.0 = None;
+                discard_autosave();
+                for entity in prompt_query.iter() {
+                    commands.entity(entity).despawn();
+                }
+            }
+            Interaction::Hovered => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(Color::WHITE);
+                    }
+                }
+            }
+            Interaction::None => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(back_text());
                     }
                 }
             }
@@ -0,0 +1,256 @@
+//! Keyboard and gamepad navigation shared by every menu screen.
+//!
+//! Menu screens are built from `Button` nodes whose handlers react to
+//! `Interaction::Pressed`.  Instead of teaching each screen about keys,
+//! [`menu_navigation_system`] keeps a focus on one of the visible buttons and
+//! *presses* it by writing `Interaction::Pressed`, so every screen's existing
+//! handler runs unchanged:
+//!
+//! | Action    | Keyboard   | Gamepad |
+//! |-----------|------------|---------|
+//! | Move      | Arrow keys | D-pad   |
+//! | Activate  | Enter      | South   |
+//! | Back      | Escape     | East    |
+//!
+//! - The first move focuses the top-left button; later moves go to the
+//!   nearest button in that direction.  The focused button gets an outline.
+//! - Back presses the screen's back button (Back, Resume, Close), whether or
+//!   not anything is focused.
+//! - Moving the mouse drops the focus.  While nothing is focused, Enter is
+//!   left to the screen's own shortcut (e.g. Play Again on Game Over).
+//! - Focus survives screen changes: when the focused button goes away the
+//!   top-left button of the new screen takes it.
+//!
+//! The system runs in `PreUpdate` after UI focus, so handlers in `Update` see
+//! the press in the same frame; it is released on the next frame.
+
+use super::*;
+use bevy::input::gamepad::GamepadButton;
+use bevy::ui::{ComputedNode, UiGlobalTransform, UiSystems};
+use bevy::window::CursorMoved;
+
+/// Keyboard / gamepad focus shared by every menu screen.
+#[derive(Resource, Debug, Default)]
+pub struct MenuFocus {
+    /// Button carrying the focus outline; `None` until a navigation key is used.
+    pub focused: Option<Entity>,
+    /// Button pressed last frame, released on the next.
+    pressed: Option<Entity>,
+}
+
+/// One navigation input, read from the keyboard or any gamepad.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum NavInput {
+    /// Move the focus; UI space, so +y is down.
+    Move(Vec2),
+    Activate,
+    Back,
+}
+
+/// Buttons that leave their screen, pressed by Escape / East.
+type BackButtonFilter = Or<(
+    With<LoadGameBackButton>,
+    With<SettingsBackButton>,
+    With<ScenarioBackButton>,
+    With<CampaignBackButton>,
+    With<PauseResumeButton>,
+    With<OreShopCloseButton>,
+)>;
+
+fn read_nav_input(keys: &ButtonInput<KeyCode>, gamepads: &Query<&Gamepad>) -> Option<NavInput> {
+    let pressed = |key: KeyCode, button: GamepadButton| {
+        keys.just_pressed(key) || gamepads.iter().any(|gp| gp.just_pressed(button))
+    };
+    if pressed(KeyCode::ArrowUp, GamepadButton::DPadUp) {
+        Some(NavInput::Move(Vec2::NEG_Y))
+    } else if pressed(KeyCode::ArrowDown, GamepadButton::DPadDown) {
+        Some(NavInput::Move(Vec2::Y))
+    } else if pressed(KeyCode::ArrowLeft, GamepadButton::DPadLeft) {
+        Some(NavInput::Move(Vec2::NEG_X))
+    } else if pressed(KeyCode::ArrowRight, GamepadButton::DPadRight) {
+        Some(NavInput::Move(Vec2::X))
+    } else if pressed(KeyCode::Enter, GamepadButton::South) {
+        Some(NavInput::Activate)
+    } else if pressed(KeyCode::Escape, GamepadButton::East) {
+        Some(NavInput::Back)
+    } else {
+        None
+    }
+}
+
+/// The button nearest `from` in direction `dir`, if any lies that way.
+///
+/// Sideways distance counts double, so the focus prefers buttons in line
+/// with the current one over closer buttons off to the side.
+pub fn step_focus(from: Vec2, dir: Vec2, candidates: &[(Entity, Vec2)]) -> Option<Entity> {
+    candidates
+        .iter()
+        .filter_map(|&(entity, pos)| {
+            let offset = pos - from;
+            let along = offset.dot(dir);
+            (along > 1.0).then(|| (entity, along + 2.0 * (offset - dir * along).length()))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// The first button in reading order: top to bottom, then left to right.
+pub fn first_in_reading_order(candidates: &[(Entity, Vec2)]) -> Option<Entity> {
+    candidates
+        .iter()
+        .min_by(|a, b| {
+            a.1.y
+                .round()
+                .total_cmp(&b.1.y.round())
+                .then(a.1.x.total_cmp(&b.1.x))
+        })
+        .map(|&(entity, _)| entity)
+}
+
+/// Menus navigate in every state except gameplay and loading.
+pub fn menu_navigation_enabled(state: Res<State<GameState>>) -> bool {
+    !matches!(state.get(), GameState::Playing | GameState::Loading)
+}
+
+/// Move, draw and press the menu focus.
+#[allow(clippy::type_complexity)]
+pub(super) fn menu_navigation_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut cursor_moved: MessageReader<CursorMoved>,
+    mut focus: ResMut<MenuFocus>,
+    mut q_buttons: Query<
+        (
+            Entity,
+            &mut Interaction,
+            &UiGlobalTransform,
+            &ComputedNode,
+            &InheritedVisibility,
+            Has<Outline>,
+        ),
+        With<Button>,
+    >,
+    q_back: Query<(), BackButtonFilter>,
+) {
+    if let Some(entity) = focus.pressed.take() {
+        if let Ok((_, mut interaction, ..)) = q_buttons.get_mut(entity) {
+            if *interaction == Interaction::Pressed {
+                *interaction = Interaction::None;
+            }
+        }
+    }
+
+    let candidates: Vec<(Entity, Vec2)> = q_buttons
+        .iter()
+        .filter(|(_, _, _, node, visibility, _)| visibility.get() && !node.is_empty())
+        .map(|(entity, _, transform, ..)| (entity, transform.translation))
+        .collect();
+
+    if cursor_moved.read().count() > 0 {
+        focus.focused = None;
+    }
+    let current = focus
+        .focused
+        .and_then(|entity| candidates.iter().find(|(e, _)| *e == entity).copied());
+    if focus.focused.is_some() && current.is_none() {
+        // The focused button left with its screen.
+        focus.focused = first_in_reading_order(&candidates);
+    }
+
+    let press = match read_nav_input(&keys, &gamepads) {
+        Some(NavInput::Move(dir)) => {
+            focus.focused = match current {
+                Some((entity, pos)) => step_focus(pos, dir, &candidates).or(Some(entity)),
+                None => focus
+                    .focused
+                    .or_else(|| first_in_reading_order(&candidates)),
+            };
+            None
+        }
+        Some(NavInput::Activate) => focus.focused,
+        Some(NavInput::Back) => candidates
+            .iter()
+            .map(|&(entity, _)| entity)
+            .find(|&entity| q_back.contains(entity)),
+        None => None,
+    };
+    if let Some(entity) = press {
+        if let Ok((_, mut interaction, ..)) = q_buttons.get_mut(entity) {
+            *interaction = Interaction::Pressed;
+            focus.pressed = Some(entity);
+        }
+    }
+
+    for (entity, _, _, _, _, outlined) in q_buttons.iter() {
+        let focused = focus.focused == Some(entity);
+        if focused && !outlined {
+            commands.entity(entity).insert(Outline::new(
+                Val::Px(2.0),
+                Val::Px(2.0),
+                focus_outline_color(),
+            ));
+        } else if !focused && outlined {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+}
+
+pub(super) struct MenuNavigationPlugin;
+
+impl Plugin for MenuNavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MenuFocus>().add_systems(
+            PreUpdate,
+            menu_navigation_system
+                .after(UiSystems::Focus)
+                .run_if(menu_navigation_enabled),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grid() -> (World, Vec<(Entity, Vec2)>) {
+        // Two columns of two buttons, plus a wide button below.
+        let mut world = World::new();
+        let positions = [
+            Vec2::new(100.0, 100.0),
+            Vec2::new(300.0, 100.0),
+            Vec2::new(100.0, 160.0),
+            Vec2::new(300.0, 160.0),
+            Vec2::new(200.0, 240.0),
+        ];
+        let buttons = positions
+            .iter()
+            .map(|&pos| (world.spawn_empty().id(), pos))
+            .collect();
+        (world, buttons)
+    }
+
+    #[test]
+    fn focus_steps_to_the_nearest_button_in_line() {
+        let (_world, buttons) = grid();
+        let id = |i: usize| buttons[i].0;
+        assert_eq!(step_focus(buttons[0].1, Vec2::Y, &buttons), Some(id(2)));
+        assert_eq!(step_focus(buttons[0].1, Vec2::X, &buttons), Some(id(1)));
+        assert_eq!(step_focus(buttons[3].1, Vec2::Y, &buttons), Some(id(4)));
+        assert_eq!(step_focus(buttons[4].1, Vec2::NEG_Y, &buttons), Some(id(2)));
+        assert_eq!(
+            step_focus(buttons[0].1, Vec2::NEG_Y, &buttons),
+            None,
+            "nothing above the top row"
+        );
+    }
+
+    #[test]
+    fn first_focus_is_the_top_left_button() {
+        let (_world, mut buttons) = grid();
+        let top_left = buttons[0].0;
+        buttons.reverse();
+        assert_eq!(first_in_reading_order(&buttons), Some(top_left));
+        assert_eq!(first_in_reading_order(&[]), None);
+    }
+}
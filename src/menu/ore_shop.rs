@@ -1,5 +1,24 @@
 use super::*;
 
+/// Ore shop header: every balance, plus what is still in the refinery.
+fn ore_shop_balance_label(ore: &PlayerOre) -> String {
+    let mut label = OreKind::ALL
+        .iter()
+        .map(|kind| format!("{} {}", kind.symbol(), ore.raw(*kind)))
+        .collect::<Vec<_>>()
+        .join("   ");
+    label.push_str(&format!(
+        "   {} {}",
+        Currency::Credits.symbol(),
+        ore.credits
+    ));
+    let refining = ore.total_refining();
+    if refining > 0 {
+        label.push_str(&format!("   · refining {refining}"));
+    }
+    label
+}
+
 /// Spawn the ore shop UI overlay.
 ///
 /// Called by [`setup_ore_shop`] (the `OnEnter(OreShop)` system) and by the
@@ -8,7 +27,7 @@ use super::*;
 fn spawn_ore_shop_overlay(
     commands: &mut Commands,
     config: &PhysicsConfig,
-    ore: u32,
+    ore: &PlayerOre,
     hp: f32,
     max_hp: f32,
     heal_amount: f32,
@@ -20,11 +39,18 @@ fn spawn_ore_shop_overlay(
     magnet_level: &OreAffinityLevel,
     tractor_level: &TractorBeamLevel,
     ion_level: &IonCannonLevel,
+    shield_level: &ShieldLevel,
+    drone_level: &MiningDroneLevel,
+    turret_level: &TurretLevel,
+    discount: f32,
     font: &GameFont,
+    palette: &Palette,
 ) {
-    let ore_text = format!("💎 available: {ore}");
+    let ore_text = ore_shop_balance_label(ore);
+    let heal_price = ShopPurchase::HEAL_CURRENCY;
+    let missile_price = ShopPurchase::Missile.currency();
 
-    let can_heal = ore > 0 && hp < max_hp;
+    let can_heal = ore.balance(heal_price) > 0 && hp < max_hp;
     let heal_btn_bg = if can_heal {
         ore_shop_item_bg()
     } else {
@@ -41,11 +67,14 @@ fn spawn_ore_shop_overlay(
         Color::srgb(0.38, 0.38, 0.38)
     };
     let heal_label = format!(
-        "❤️ HEAL ❤️  (❤️: {:.0} / {:.0})  -  1 💎 -> +{:.0} ❤️",
-        hp, max_hp, heal_amount
+        "❤️ HEAL ❤️  (❤️: {:.0} / {:.0})  -  1 {} -> +{:.0} ❤️",
+        hp,
+        max_hp,
+        heal_price.symbol(),
+        heal_amount
     );
 
-    let can_missile = ore > 0 && ammo < ammo_max;
+    let can_missile = ore.balance(missile_price) > 0 && ammo < ammo_max;
     let missile_btn_bg = if can_missile {
         ore_shop_item_bg()
     } else {
@@ -61,7 +90,10 @@ fn spawn_ore_shop_overlay(
     } else {
         Color::srgb(0.38, 0.38, 0.38)
     };
-    let missile_label = format!("🚀 MISSILE 🚀  ({ammo} / {ammo_max})  -  1 💎 -> +1 🚀",);
+    let missile_label = format!(
+        "🚀 MISSILE 🚀  ({ammo} / {ammo_max})  -  1 {} -> +1 🚀",
+        missile_price.symbol()
+    );
 
     commands
         .spawn((
@@ -105,6 +137,22 @@ fn spawn_ore_shop_overlay(
                         TextColor(ore_shop_btn_text()),
                     ));
 
+                    if discount > 0.0 {
+                        card.spawn((
+                            Text::new(format!(
+                                "INTERMISSION BEACON · UPGRADES -{:.0}%",
+                                discount * 100.0
+                            )),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 13.0,
+                                ..default()
+                            },
+                            TextColor(Color::srgb(0.95, 0.80, 0.25)),
+                            OreShopIntermissionText,
+                        ));
+                    }
+
                     card.spawn(Node {
                         height: Val::Px(4.0),
                         ..default()
@@ -192,15 +240,75 @@ fn spawn_ore_shop_overlay(
                             });
                     });
 
+                    // ── Refinery row ──────────────────────────────────────────
+                    card.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(12.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|refinery| {
+                        for kind in OreKind::ALL {
+                            let raw = ore.raw(kind);
+                            let (bg, border, text_color) = if raw > 0 {
+                                (
+                                    ore_shop_item_bg(),
+                                    ore_shop_item_border(),
+                                    ore_shop_item_text(),
+                                )
+                            } else {
+                                (
+                                    Color::srgb(0.10, 0.10, 0.10),
+                                    Color::srgb(0.22, 0.22, 0.22),
+                                    Color::srgb(0.38, 0.38, 0.38),
+                                )
+                            };
+                            refinery
+                                .spawn((
+                                    Button,
+                                    Node {
+                                        width: Val::Px(248.0),
+                                        height: Val::Px(40.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        border: UiRect::all(Val::Px(2.0)),
+                                        ..default()
+                                    },
+                                    BackgroundColor(bg),
+                                    BorderColor::all(border),
+                                    OreShopRefineButton(kind),
+                                ))
+                                .with_children(|btn| {
+                                    btn.spawn((
+                                        Text::new(format!(
+                                            "REFINE {raw} {} -> {} ¢",
+                                            kind.symbol(),
+                                            raw * kind.refined_credits(config)
+                                        )),
+                                        TextFont {
+                                            font: font.0.clone(),
+                                            font_size: 14.0,
+                                            ..default()
+                                        },
+                                        TextColor(text_color),
+                                    ));
+                                });
+                        }
+                    });
+
                     card.spawn(Node {
                         height: Val::Px(8.0),
                         ..default()
                     });
 
                     // ── Upgrades row ───────────────────────────────────────────
+                    // Wraps so the drone card stays on screen at narrow widths.
                     card.spawn(Node {
                         flex_direction: FlexDirection::Row,
+                        flex_wrap: FlexWrap::Wrap,
+                        justify_content: JustifyContent::Center,
                         column_gap: Val::Px(12.0),
+                        row_gap: Val::Px(12.0),
                         align_items: AlignItems::FlexStart,
                         ..default()
                     })
@@ -220,55 +328,70 @@ fn spawn_ore_shop_overlay(
                                 BorderColor::all(Color::srgb(0.22, 0.22, 0.22)),
                             ))
                             .with_children(|card_col| {
+                                let destroy_price = ShopPurchase::DestroyTrack.currency();
+                                let chip_price = ShopPurchase::ChipTrack.currency();
+                                let rate_price = ShopPurchase::FireRate.currency();
                                 let can_upgrade_destroy = !weapon_tracks.is_destroy_maxed()
-                                    && weapon_tracks.can_afford_next_destroy(ore);
+                                    && weapon_tracks.cost_for_next_destroy_level().is_some_and(
+                                        |cost| {
+                                            ore.balance(destroy_price)
+                                                >= discounted_price(cost, discount)
+                                        },
+                                    );
                                 let can_upgrade_chip = !weapon_tracks.is_chip_maxed()
-                                    && weapon_tracks.can_afford_next_chip(ore);
+                                    && weapon_tracks.cost_for_next_chip_level().is_some_and(
+                                        |cost| {
+                                            ore.balance(chip_price)
+                                                >= discounted_price(cost, discount)
+                                        },
+                                    );
                                 let can_upgrade_rate = !fire_rate_level.is_maxed()
-                                    && fire_rate_level.can_afford_next(ore);
+                                    && fire_rate_level.cost_for_next_level().is_some_and(|cost| {
+                                        ore.balance(rate_price) >= discounted_price(cost, discount)
+                                    });
 
                                 let destroy_btn_bg = if can_upgrade_destroy {
-                                    shop_buy_bg()
+                                    palette.buy.bg
                                 } else {
                                     Color::srgb(0.14, 0.14, 0.14)
                                 };
                                 let destroy_btn_border = if can_upgrade_destroy {
-                                    shop_buy_border()
+                                    palette.buy.border
                                 } else {
                                     Color::srgb(0.28, 0.28, 0.28)
                                 };
                                 let destroy_btn_text_color = if can_upgrade_destroy {
-                                    shop_buy_text()
+                                    palette.buy.text
                                 } else {
                                     Color::srgb(0.40, 0.40, 0.40)
                                 };
                                 let chip_btn_bg = if can_upgrade_chip {
-                                    shop_buy_bg()
+                                    palette.buy.bg
                                 } else {
                                     Color::srgb(0.14, 0.14, 0.14)
                                 };
                                 let chip_btn_border = if can_upgrade_chip {
-                                    shop_buy_border()
+                                    palette.buy.border
                                 } else {
                                     Color::srgb(0.28, 0.28, 0.28)
                                 };
                                 let chip_btn_text_color = if can_upgrade_chip {
-                                    shop_buy_text()
+                                    palette.buy.text
                                 } else {
                                     Color::srgb(0.40, 0.40, 0.40)
                                 };
                                 let rate_btn_bg = if can_upgrade_rate {
-                                    shop_buy_bg()
+                                    palette.buy.bg
                                 } else {
                                     Color::srgb(0.14, 0.14, 0.14)
                                 };
                                 let rate_btn_border = if can_upgrade_rate {
-                                    shop_buy_border()
+                                    palette.buy.border
                                 } else {
                                     Color::srgb(0.28, 0.28, 0.28)
                                 };
                                 let rate_btn_text_color = if can_upgrade_rate {
-                                    shop_buy_text()
+                                    palette.buy.text
                                 } else {
                                     Color::srgb(0.40, 0.40, 0.40)
                                 };
@@ -276,54 +399,68 @@ fn spawn_ore_shop_overlay(
                                 let destroy_upg_label = if weapon_tracks.is_destroy_maxed() {
                                     "— MAX LEVEL —".to_string()
                                 } else {
-                                    let cost =
-                                        weapon_tracks.cost_for_next_destroy_level().unwrap_or(0);
-                                    format!("UPGRADE DESTROY ({cost} 💎)")
+                                    let cost = discounted_price(
+                                        weapon_tracks.cost_for_next_destroy_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    format!("UPGRADE DESTROY ({cost} {})", destroy_price.symbol())
                                 };
                                 let chip_upg_label = if weapon_tracks.is_chip_maxed() {
                                     "— MAX LEVEL —".to_string()
                                 } else {
-                                    let cost =
-                                        weapon_tracks.cost_for_next_chip_level().unwrap_or(0);
-                                    format!("UPGRADE CHIP ({cost} 💎)")
+                                    let cost = discounted_price(
+                                        weapon_tracks.cost_for_next_chip_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    format!("UPGRADE CHIP ({cost} {})", chip_price.symbol())
                                 };
                                 let rate_upg_label = if fire_rate_level.is_maxed() {
                                     "— MAX LEVEL —".to_string()
                                 } else {
-                                    let cost = fire_rate_level.cost_for_next_level().unwrap_or(0);
-                                    format!("UPGRADE FIRE RATE ({cost} 💎)")
+                                    let cost = discounted_price(
+                                        fire_rate_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    format!("UPGRADE FIRE RATE ({cost} {})", rate_price.symbol())
                                 };
 
                                 let destroy_status = if weapon_tracks.is_destroy_maxed() {
                                     "DESTROY TRACK: MAX LEVEL REACHED".to_string()
                                 } else {
-                                    let cost =
-                                        weapon_tracks.cost_for_next_destroy_level().unwrap_or(0);
+                                    let cost = discounted_price(
+                                        weapon_tracks.cost_for_next_destroy_level().unwrap_or(0),
+                                        discount,
+                                    );
                                     if can_upgrade_destroy {
-                                        format!("Destroy cost: {cost} 💎")
+                                        format!("Destroy cost: {cost} {}", destroy_price.symbol())
                                     } else {
-                                        format!("Destroy needs {cost} 💎")
+                                        format!("Destroy needs {cost} {}", destroy_price.symbol())
                                     }
                                 };
                                 let chip_status = if weapon_tracks.is_chip_maxed() {
                                     "CHIP TRACK: MAX LEVEL REACHED".to_string()
                                 } else {
-                                    let cost =
-                                        weapon_tracks.cost_for_next_chip_level().unwrap_or(0);
+                                    let cost = discounted_price(
+                                        weapon_tracks.cost_for_next_chip_level().unwrap_or(0),
+                                        discount,
+                                    );
                                     if can_upgrade_chip {
-                                        format!("Chip cost: {cost} 💎")
+                                        format!("Chip cost: {cost} {}", chip_price.symbol())
                                     } else {
-                                        format!("Chip needs {cost} 💎")
+                                        format!("Chip needs {cost} {}", chip_price.symbol())
                                     }
                                 };
                                 let rate_status = if fire_rate_level.is_maxed() {
                                     "FIRE-RATE TRACK: MAX LEVEL REACHED".to_string()
                                 } else {
-                                    let cost = fire_rate_level.cost_for_next_level().unwrap_or(0);
+                                    let cost = discounted_price(
+                                        fire_rate_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
                                     if can_upgrade_rate {
-                                        format!("Fire-rate cost: {cost} 💎")
+                                        format!("Fire-rate cost: {cost} {}", rate_price.symbol())
                                     } else {
-                                        format!("Fire-rate needs {cost} 💎")
+                                        format!("Fire-rate needs {cost} {}", rate_price.symbol())
                                     }
                                 };
 
@@ -389,13 +526,10 @@ fn spawn_ore_shop_overlay(
                                         font_size: 13.0,
                                         ..default()
                                     },
-                                    TextColor(if weapon_tracks.is_destroy_maxed() {
-                                        Color::srgb(0.90, 0.80, 0.30)
-                                    } else if can_upgrade_destroy {
-                                        Color::srgb(0.75, 0.90, 0.75)
-                                    } else {
-                                        Color::srgb(0.75, 0.40, 0.40)
-                                    }),
+                                    TextColor(palette.shop_status(
+                                        weapon_tracks.is_destroy_maxed(),
+                                        can_upgrade_destroy,
+                                    )),
                                 ));
                                 card_col
                                     .spawn((
@@ -431,13 +565,10 @@ fn spawn_ore_shop_overlay(
                                         font_size: 13.0,
                                         ..default()
                                     },
-                                    TextColor(if weapon_tracks.is_chip_maxed() {
-                                        Color::srgb(0.90, 0.80, 0.30)
-                                    } else if can_upgrade_chip {
-                                        Color::srgb(0.75, 0.90, 0.75)
-                                    } else {
-                                        Color::srgb(0.75, 0.40, 0.40)
-                                    }),
+                                    TextColor(palette.shop_status(
+                                        weapon_tracks.is_chip_maxed(),
+                                        can_upgrade_chip,
+                                    )),
                                 ));
                                 card_col
                                     .spawn((
@@ -473,13 +604,12 @@ fn spawn_ore_shop_overlay(
                                         font_size: 13.0,
                                         ..default()
                                     },
-                                    TextColor(if fire_rate_level.is_maxed() {
-                                        Color::srgb(0.90, 0.80, 0.30)
-                                    } else if can_upgrade_rate {
-                                        Color::srgb(0.75, 0.90, 0.75)
-                                    } else {
-                                        Color::srgb(0.75, 0.40, 0.40)
-                                    }),
+                                    TextColor(
+                                        palette.shop_status(
+                                            fire_rate_level.is_maxed(),
+                                            can_upgrade_rate,
+                                        ),
+                                    ),
                                 ));
                                 card_col
                                     .spawn((
@@ -524,37 +654,46 @@ fn spawn_ore_shop_overlay(
                                 BorderColor::all(Color::srgb(0.22, 0.22, 0.22)),
                             ))
                             .with_children(|card_col| {
-                                let can_upgrade =
-                                    !missile_level.is_maxed() && missile_level.can_afford_next(ore);
+                                let price = ShopPurchase::MissileLevel.currency();
+                                let can_upgrade = !missile_level.is_maxed()
+                                    && missile_level.cost_for_next_level().is_some_and(|cost| {
+                                        ore.balance(price) >= discounted_price(cost, discount)
+                                    });
                                 let upg_btn_bg = if can_upgrade {
-                                    shop_buy_bg()
+                                    palette.buy.bg
                                 } else {
                                     Color::srgb(0.14, 0.14, 0.14)
                                 };
                                 let upg_btn_border = if can_upgrade {
-                                    shop_buy_border()
+                                    palette.buy.border
                                 } else {
                                     Color::srgb(0.28, 0.28, 0.28)
                                 };
                                 let upg_btn_text_color = if can_upgrade {
-                                    shop_buy_text()
+                                    palette.buy.text
                                 } else {
                                     Color::srgb(0.40, 0.40, 0.40)
                                 };
                                 let upg_label = if missile_level.is_maxed() {
                                     "— MAX LEVEL —".to_string()
                                 } else {
-                                    let cost = missile_level.cost_for_next_level().unwrap_or(0);
-                                    format!("UPGRADE ({cost} 💎)")
+                                    let cost = discounted_price(
+                                        missile_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    format!("UPGRADE ({cost} {})", price.symbol())
                                 };
                                 let cost_status = if missile_level.is_maxed() {
                                     "MAX LEVEL REACHED".to_string()
                                 } else {
-                                    let cost = missile_level.cost_for_next_level().unwrap_or(0);
+                                    let cost = discounted_price(
+                                        missile_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
                                     if can_upgrade {
-                                        format!("Cost: {cost} 💎")
+                                        format!("Cost: {cost} {}", price.symbol())
                                     } else {
-                                        format!("Need {cost} 💎")
+                                        format!("Need {cost} {}", price.symbol())
                                     }
                                 };
                                 let level_text = format!(
@@ -606,13 +745,9 @@ fn spawn_ore_shop_overlay(
                                         font_size: 13.0,
                                         ..default()
                                     },
-                                    TextColor(if missile_level.is_maxed() {
-                                        Color::srgb(0.90, 0.80, 0.30)
-                                    } else if can_upgrade {
-                                        Color::srgb(0.75, 0.90, 0.75)
-                                    } else {
-                                        Color::srgb(0.75, 0.40, 0.40)
-                                    }),
+                                    TextColor(
+                                        palette.shop_status(missile_level.is_maxed(), can_upgrade),
+                                    ),
                                 ));
                                 card_col
                                     .spawn((
@@ -657,37 +792,46 @@ fn spawn_ore_shop_overlay(
                                 BorderColor::all(Color::srgb(0.22, 0.22, 0.22)),
                             ))
                             .with_children(|card_col| {
-                                let can_upgrade =
-                                    !magnet_level.is_maxed() && magnet_level.can_afford_next(ore);
+                                let price = ShopPurchase::Magnet.currency();
+                                let can_upgrade = !magnet_level.is_maxed()
+                                    && magnet_level.cost_for_next_level().is_some_and(|cost| {
+                                        ore.balance(price) >= discounted_price(cost, discount)
+                                    });
                                 let upg_btn_bg = if can_upgrade {
-                                    shop_buy_bg()
+                                    palette.buy.bg
                                 } else {
                                     Color::srgb(0.14, 0.14, 0.14)
                                 };
                                 let upg_btn_border = if can_upgrade {
-                                    shop_buy_border()
+                                    palette.buy.border
                                 } else {
                                     Color::srgb(0.28, 0.28, 0.28)
                                 };
                                 let upg_btn_text_color = if can_upgrade {
-                                    shop_buy_text()
+                                    palette.buy.text
                                 } else {
                                     Color::srgb(0.40, 0.40, 0.40)
                                 };
                                 let upg_label = if magnet_level.is_maxed() {
                                     "— MAX LEVEL —".to_string()
                                 } else {
-                                    let cost = magnet_level.cost_for_next_level().unwrap_or(0);
-                                    format!("UPGRADE ({cost} 💎)")
+                                    let cost = discounted_price(
+                                        magnet_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    format!("UPGRADE ({cost} {})", price.symbol())
                                 };
                                 let cost_status = if magnet_level.is_maxed() {
                                     "MAX LEVEL REACHED".to_string()
                                 } else {
-                                    let cost = magnet_level.cost_for_next_level().unwrap_or(0);
+                                    let cost = discounted_price(
+                                        magnet_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
                                     if can_upgrade {
-                                        format!("Cost: {cost} 💎")
+                                        format!("Cost: {cost} {}", price.symbol())
                                     } else {
-                                        format!("Need {cost} 💎")
+                                        format!("Need {cost} {}", price.symbol())
                                     }
                                 };
                                 let level_text = format!(
@@ -739,13 +883,9 @@ fn spawn_ore_shop_overlay(
                                         font_size: 13.0,
                                         ..default()
                                     },
-                                    TextColor(if magnet_level.is_maxed() {
-                                        Color::srgb(0.90, 0.80, 0.30)
-                                    } else if can_upgrade {
-                                        Color::srgb(0.75, 0.90, 0.75)
-                                    } else {
-                                        Color::srgb(0.75, 0.40, 0.40)
-                                    }),
+                                    TextColor(
+                                        palette.shop_status(magnet_level.is_maxed(), can_upgrade),
+                                    ),
                                 ));
                                 card_col
                                     .spawn((
@@ -790,37 +930,46 @@ fn spawn_ore_shop_overlay(
                                 BorderColor::all(Color::srgb(0.22, 0.22, 0.22)),
                             ))
                             .with_children(|card_col| {
-                                let can_upgrade =
-                                    !tractor_level.is_maxed() && tractor_level.can_afford_next(ore);
+                                let price = ShopPurchase::Tractor.currency();
+                                let can_upgrade = !tractor_level.is_maxed()
+                                    && tractor_level.cost_for_next_level().is_some_and(|cost| {
+                                        ore.balance(price) >= discounted_price(cost, discount)
+                                    });
                                 let upg_btn_bg = if can_upgrade {
-                                    shop_buy_bg()
+                                    palette.buy.bg
                                 } else {
                                     Color::srgb(0.14, 0.14, 0.14)
                                 };
                                 let upg_btn_border = if can_upgrade {
-                                    shop_buy_border()
+                                    palette.buy.border
                                 } else {
                                     Color::srgb(0.28, 0.28, 0.28)
                                 };
                                 let upg_btn_text_color = if can_upgrade {
-                                    shop_buy_text()
+                                    palette.buy.text
                                 } else {
                                     Color::srgb(0.40, 0.40, 0.40)
                                 };
                                 let upg_label = if tractor_level.is_maxed() {
                                     "— MAX LEVEL —".to_string()
                                 } else {
-                                    let cost = tractor_level.cost_for_next_level().unwrap_or(0);
-                                    format!("UPGRADE ({cost} 💎)")
+                                    let cost = discounted_price(
+                                        tractor_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    format!("UPGRADE ({cost} {})", price.symbol())
                                 };
                                 let cost_status = if tractor_level.is_maxed() {
                                     "MAX LEVEL REACHED".to_string()
                                 } else {
-                                    let cost = tractor_level.cost_for_next_level().unwrap_or(0);
+                                    let cost = discounted_price(
+                                        tractor_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
                                     if can_upgrade {
-                                        format!("Cost: {cost} 💎")
+                                        format!("Cost: {cost} {}", price.symbol())
                                     } else {
-                                        format!("Need {cost} 💎")
+                                        format!("Need {cost} {}", price.symbol())
                                     }
                                 };
                                 let level_text = format!(
@@ -875,13 +1024,9 @@ fn spawn_ore_shop_overlay(
                                         font_size: 13.0,
                                         ..default()
                                     },
-                                    TextColor(if tractor_level.is_maxed() {
-                                        Color::srgb(0.90, 0.80, 0.30)
-                                    } else if can_upgrade {
-                                        Color::srgb(0.75, 0.90, 0.75)
-                                    } else {
-                                        Color::srgb(0.75, 0.40, 0.40)
-                                    }),
+                                    TextColor(
+                                        palette.shop_status(tractor_level.is_maxed(), can_upgrade),
+                                    ),
                                 ));
                                 card_col
                                     .spawn((
@@ -926,37 +1071,46 @@ fn spawn_ore_shop_overlay(
                                 BorderColor::all(Color::srgb(0.22, 0.22, 0.22)),
                             ))
                             .with_children(|card_col| {
-                                let can_upgrade =
-                                    !ion_level.is_maxed() && ion_level.can_afford_next(ore);
+                                let price = ShopPurchase::IonCannon.currency();
+                                let can_upgrade = !ion_level.is_maxed()
+                                    && ion_level.cost_for_next_level().is_some_and(|cost| {
+                                        ore.balance(price) >= discounted_price(cost, discount)
+                                    });
                                 let upg_btn_bg = if can_upgrade {
-                                    shop_buy_bg()
+                                    palette.buy.bg
                                 } else {
                                     Color::srgb(0.14, 0.14, 0.14)
                                 };
                                 let upg_btn_border = if can_upgrade {
-                                    shop_buy_border()
+                                    palette.buy.border
                                 } else {
                                     Color::srgb(0.28, 0.28, 0.28)
                                 };
                                 let upg_btn_text_color = if can_upgrade {
-                                    shop_buy_text()
+                                    palette.buy.text
                                 } else {
                                     Color::srgb(0.40, 0.40, 0.40)
                                 };
                                 let upg_label = if ion_level.is_maxed() {
                                     "— MAX LEVEL —".to_string()
                                 } else {
-                                    let cost = ion_level.cost_for_next_level().unwrap_or(0);
-                                    format!("UPGRADE ({cost} 💎)")
+                                    let cost = discounted_price(
+                                        ion_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    format!("UPGRADE ({cost} {})", price.symbol())
                                 };
                                 let cost_status = if ion_level.is_maxed() {
                                     "MAX LEVEL REACHED".to_string()
                                 } else {
-                                    let cost = ion_level.cost_for_next_level().unwrap_or(0);
+                                    let cost = discounted_price(
+                                        ion_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
                                     if can_upgrade {
-                                        format!("Cost: {cost} 💎")
+                                        format!("Cost: {cost} {}", price.symbol())
                                     } else {
-                                        format!("Need {cost} 💎")
+                                        format!("Need {cost} {}", price.symbol())
                                     }
                                 };
 
@@ -1024,13 +1178,9 @@ fn spawn_ore_shop_overlay(
                                         font_size: 13.0,
                                         ..default()
                                     },
-                                    TextColor(if ion_level.is_maxed() {
-                                        Color::srgb(0.90, 0.80, 0.30)
-                                    } else if can_upgrade {
-                                        Color::srgb(0.75, 0.90, 0.75)
-                                    } else {
-                                        Color::srgb(0.75, 0.40, 0.40)
-                                    }),
+                                    TextColor(
+                                        palette.shop_status(ion_level.is_maxed(), can_upgrade),
+                                    ),
                                 ));
                                 card_col
                                     .spawn((
@@ -1059,111 +1209,687 @@ fn spawn_ore_shop_overlay(
                                         ));
                                     });
                             });
-                    });
-
-                    card.spawn(Node {
-                        height: Val::Px(4.0),
-                        ..default()
-                    });
-
-                    // ── Close button ──────────────────────────────────────────
-                    card.spawn((
-                        Button,
-                        Node {
-                            width: Val::Px(220.0),
-                            height: Val::Px(44.0),
-                            justify_content: JustifyContent::Center,
-                            align_items: AlignItems::Center,
-                            border: UiRect::all(Val::Px(2.0)),
-                            ..default()
-                        },
-                        BackgroundColor(shop_close_bg()),
-                        BorderColor::all(shop_close_border()),
-                        OreShopCloseButton,
-                    ))
-                    .with_children(|btn| {
-                        btn.spawn((
-                            Text::new("CLOSE"),
-                            TextFont {
-                                font: font.0.clone(),
-                                font_size: 16.0,
-                                ..default()
-                            },
-                            TextColor(shop_close_text()),
-                        ));
-                    });
+                        // ── Shield card ──────────────────────────────────────
+                        upgrades_row
+                            .spawn((
+                                Node {
+                                    width: Val::Px(248.0),
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(6.0),
+                                    padding: UiRect::all(Val::Px(12.0)),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.09, 0.09, 0.08)),
+                                BorderColor::all(Color::srgb(0.22, 0.22, 0.22)),
+                            ))
+                            .with_children(|card_col| {
+                                let price = ShopPurchase::Shield.currency();
+                                let can_upgrade = !shield_level.is_maxed()
+                                    && shield_level.cost_for_next_level().is_some_and(|cost| {
+                                        ore.balance(price) >= discounted_price(cost, discount)
+                                    });
+                                let upg_btn_bg = if can_upgrade {
+                                    palette.buy.bg
+                                } else {
+                                    Color::srgb(0.14, 0.14, 0.14)
+                                };
+                                let upg_btn_border = if can_upgrade {
+                                    palette.buy.border
+                                } else {
+                                    Color::srgb(0.28, 0.28, 0.28)
+                                };
+                                let upg_btn_text_color = if can_upgrade {
+                                    palette.buy.text
+                                } else {
+                                    Color::srgb(0.40, 0.40, 0.40)
+                                };
+                                let upg_label = if shield_level.is_maxed() {
+                                    "— MAX LEVEL —".to_string()
+                                } else {
+                                    let cost = discounted_price(
+                                        shield_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    let verb = if shield_level.level == 0 {
+                                        "INSTALL"
+                                    } else {
+                                        "UPGRADE"
+                                    };
+                                    format!("{verb} ({cost} {})", price.symbol())
+                                };
+                                let cost_status = if shield_level.is_maxed() {
+                                    "MAX LEVEL REACHED".to_string()
+                                } else {
+                                    let cost = discounted_price(
+                                        shield_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    if can_upgrade {
+                                        format!("Cost: {cost} {}", price.symbol())
+                                    } else {
+                                        format!("Need {cost} {}", price.symbol())
+                                    }
+                                };
+                                let level_text = if shield_level.level == 0 {
+                                    "Not installed".to_string()
+                                } else {
+                                    format!(
+                                        "Level {} / {}",
+                                        shield_level.display_level(),
+                                        crate::constants::SHIELD_MAX_LEVEL
+                                    )
+                                };
+                                let range_text = if shield_level.is_maxed() {
+                                    format!("Shield: {:.0} HP", shield_level.capacity(config))
+                                } else {
+                                    let next = ShieldLevel {
+                                        level: shield_level.level + 1,
+                                    };
+                                    format!(
+                                        "Shield: {:.0} -> {:.0} HP",
+                                        shield_level.capacity(config),
+                                        next.capacity(config)
+                                    )
+                                };
 
-                    card.spawn((
-                        Text::new("Press Tab or ESC to close"),
-                        TextFont {
-                            font: font.0.clone(),
-                            font_size: 12.0,
-                            ..default()
-                        },
-                        TextColor(hint_color()),
-                    ));
-                });
-        });
-}
+                                card_col.spawn((
+                                    Text::new("◈ SHIELD ◈"),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 13.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.45, 0.45, 0.45)),
+                                ));
+                                card_col.spawn((
+                                    Text::new(level_text),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 15.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                                ));
+                                card_col.spawn((
+                                    Text::new(range_text),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.55, 0.65, 0.60)),
+                                ));
+                                card_col.spawn((
+                                    Text::new(cost_status),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 13.0,
+                                        ..default()
+                                    },
+                                    TextColor(
+                                        palette.shop_status(shield_level.is_maxed(), can_upgrade),
+                                    ),
+                                ));
+                                card_col
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Percent(100.0),
+                                            height: Val::Px(42.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            border: UiRect::all(Val::Px(2.0)),
+                                            ..default()
+                                        },
+                                        BackgroundColor(upg_btn_bg),
+                                        BorderColor::all(upg_btn_border),
+                                        OreShopShieldUpgradeButton,
+                                    ))
+                                    .with_children(|btn| {
+                                        btn.spawn((
+                                            Text::new(upg_label),
+                                            TextFont {
+                                                font: font.0.clone(),
+                                                font_size: 14.0,
+                                                ..default()
+                                            },
+                                            TextColor(upg_btn_text_color),
+                                        ));
+                                    });
+                            });
+                        // ── Mining drone card ────────────────────────────────
+                        upgrades_row
+                            .spawn((
+                                Node {
+                                    width: Val::Px(248.0),
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(6.0),
+                                    padding: UiRect::all(Val::Px(12.0)),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.09, 0.09, 0.08)),
+                                BorderColor::all(Color::srgb(0.22, 0.22, 0.22)),
+                            ))
+                            .with_children(|card_col| {
+                                let price = ShopPurchase::Drone.currency();
+                                let can_upgrade = !drone_level.is_maxed()
+                                    && drone_level.cost_for_next_level().is_some_and(|cost| {
+                                        ore.balance(price) >= discounted_price(cost, discount)
+                                    });
+                                let upg_btn_bg = if can_upgrade {
+                                    palette.buy.bg
+                                } else {
+                                    Color::srgb(0.14, 0.14, 0.14)
+                                };
+                                let upg_btn_border = if can_upgrade {
+                                    palette.buy.border
+                                } else {
+                                    Color::srgb(0.28, 0.28, 0.28)
+                                };
+                                let upg_btn_text_color = if can_upgrade {
+                                    palette.buy.text
+                                } else {
+                                    Color::srgb(0.40, 0.40, 0.40)
+                                };
+                                let upg_label = if drone_level.is_maxed() {
+                                    "— MAX LEVEL —".to_string()
+                                } else {
+                                    let cost = discounted_price(
+                                        drone_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    let verb = if drone_level.level == 0 {
+                                        "LAUNCH"
+                                    } else {
+                                        "UPGRADE"
+                                    };
+                                    format!("{verb} ({cost} {})", price.symbol())
+                                };
+                                let cost_status = if drone_level.is_maxed() {
+                                    "MAX LEVEL REACHED".to_string()
+                                } else {
+                                    let cost = discounted_price(
+                                        drone_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    if can_upgrade {
+                                        format!("Cost: {cost} {}", price.symbol())
+                                    } else {
+                                        format!("Need {cost} {}", price.symbol())
+                                    }
+                                };
+                                let level_text = if drone_level.level == 0 {
+                                    "No drone".to_string()
+                                } else {
+                                    format!(
+                                        "Level {} / {}",
+                                        drone_level.display_level(),
+                                        crate::constants::MINING_DRONE_MAX_LEVEL
+                                    )
+                                };
+                                let range_text = if drone_level.is_maxed() {
+                                    format!(
+                                        "Drone: {:.0} u/s, carries {}",
+                                        drone_level.speed(config),
+                                        drone_level.capacity(config)
+                                    )
+                                } else if drone_level.level == 0 {
+                                    let next = MiningDroneLevel { level: 1 };
+                                    format!(
+                                        "Drone: {:.0} u/s, carries {}",
+                                        next.speed(config),
+                                        next.capacity(config)
+                                    )
+                                } else {
+                                    let next = MiningDroneLevel {
+                                        level: drone_level.level + 1,
+                                    };
+                                    format!(
+                                        "Drone: {:.0} -> {:.0} u/s, carries {} -> {}",
+                                        drone_level.speed(config),
+                                        next.speed(config),
+                                        drone_level.capacity(config),
+                                        next.capacity(config)
+                                    )
+                                };
 
-/// Spawn the ore shop overlay when entering [`GameState::OreShop`].
-#[allow(clippy::too_many_arguments)]
-pub fn setup_ore_shop(
-    mut commands: Commands,
-    ore: Res<PlayerOre>,
-    q_health: Query<&PlayerHealth, With<Player>>,
-    ammo: Res<MissileAmmo>,
-    config: Res<PhysicsConfig>,
-    weapon_tracks: Res<PrimaryWeaponUpgradeTracks>,
-    fire_rate_level: Res<PrimaryWeaponFireRateLevel>,
-    missile_level: Res<SecondaryWeaponLevel>,
-    magnet_level: Res<OreAffinityLevel>,
-    tractor_level: Res<TractorBeamLevel>,
-    ion_level: Res<IonCannonLevel>,
-    font: Res<GameFont>,
-) {
-    let (hp, max_hp) = q_health
-        .single()
-        .map(|h| (h.hp, h.max_hp))
-        .unwrap_or((config.player_max_hp, config.player_max_hp));
-    spawn_ore_shop_overlay(
-        &mut commands,
-        &config,
-        ore.count,
-        hp,
-        max_hp,
-        config.ore_heal_amount,
-        ammo.count,
-        config.missile_ammo_max,
-        &weapon_tracks,
-        &fire_rate_level,
-        &missile_level,
+                                card_col.spawn((
+                                    Text::new("◈ MINING DRONE ◈"),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 13.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.45, 0.45, 0.45)),
+                                ));
+                                card_col.spawn((
+                                    Text::new(level_text),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 15.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                                ));
+                                card_col.spawn((
+                                    Text::new(range_text),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.55, 0.65, 0.60)),
+                                ));
+                                card_col.spawn((
+                                    Text::new(cost_status),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 13.0,
+                                        ..default()
+                                    },
+                                    TextColor(
+                                        palette.shop_status(drone_level.is_maxed(), can_upgrade),
+                                    ),
+                                ));
+                                card_col
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Percent(100.0),
+                                            height: Val::Px(42.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            border: UiRect::all(Val::Px(2.0)),
+                                            ..default()
+                                        },
+                                        BackgroundColor(upg_btn_bg),
+                                        BorderColor::all(upg_btn_border),
+                                        OreShopDroneUpgradeButton,
+                                    ))
+                                    .with_children(|btn| {
+                                        btn.spawn((
+                                            Text::new(upg_label),
+                                            TextFont {
+                                                font: font.0.clone(),
+                                                font_size: 14.0,
+                                                ..default()
+                                            },
+                                            TextColor(upg_btn_text_color),
+                                        ));
+                                    });
+                            });
+                        // ── Auto-turret card ─────────────────────────────────
+                        upgrades_row
+                            .spawn((
+                                Node {
+                                    width: Val::Px(248.0),
+                                    flex_direction: FlexDirection::Column,
+                                    row_gap: Val::Px(6.0),
+                                    padding: UiRect::all(Val::Px(12.0)),
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(Color::srgb(0.09, 0.09, 0.08)),
+                                BorderColor::all(Color::srgb(0.22, 0.22, 0.22)),
+                            ))
+                            .with_children(|card_col| {
+                                let price = ShopPurchase::Turret.currency();
+                                let can_upgrade = !turret_level.is_maxed()
+                                    && turret_level.cost_for_next_level().is_some_and(|cost| {
+                                        ore.balance(price) >= discounted_price(cost, discount)
+                                    });
+                                let upg_btn_bg = if can_upgrade {
+                                    palette.buy.bg
+                                } else {
+                                    Color::srgb(0.14, 0.14, 0.14)
+                                };
+                                let upg_btn_border = if can_upgrade {
+                                    palette.buy.border
+                                } else {
+                                    Color::srgb(0.28, 0.28, 0.28)
+                                };
+                                let upg_btn_text_color = if can_upgrade {
+                                    palette.buy.text
+                                } else {
+                                    Color::srgb(0.40, 0.40, 0.40)
+                                };
+                                let upg_label = if turret_level.is_maxed() {
+                                    "— MAX LEVEL —".to_string()
+                                } else {
+                                    let cost = discounted_price(
+                                        turret_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    format!("MOUNT TURRET ({cost} {})", price.symbol())
+                                };
+                                let cost_status = if turret_level.is_maxed() {
+                                    "MAX LEVEL REACHED".to_string()
+                                } else {
+                                    let cost = discounted_price(
+                                        turret_level.cost_for_next_level().unwrap_or(0),
+                                        discount,
+                                    );
+                                    if can_upgrade {
+                                        format!("Cost: {cost} {}", price.symbol())
+                                    } else {
+                                        format!("Need {cost} {}", price.symbol())
+                                    }
+                                };
+                                let level_text = if turret_level.level == 0 {
+                                    "No turrets".to_string()
+                                } else {
+                                    format!(
+                                        "{} / {} mounted",
+                                        turret_level.display_level(),
+                                        crate::constants::TURRET_MAX_COUNT
+                                    )
+                                };
+                                let range_text = format!(
+                                    "Range {:.0} u, {:.0} energy/shot",
+                                    config.turret_range, config.turret_energy_per_shot
+                                );
+
+                                card_col.spawn((
+                                    Text::new("◈ AUTO-TURRETS ◈"),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 13.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.45, 0.45, 0.45)),
+                                ));
+                                card_col.spawn((
+                                    Text::new(level_text),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 15.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.85, 0.85, 0.85)),
+                                ));
+                                card_col.spawn((
+                                    Text::new(range_text),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 12.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.55, 0.65, 0.60)),
+                                ));
+                                card_col.spawn((
+                                    Text::new(cost_status),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 13.0,
+                                        ..default()
+                                    },
+                                    TextColor(
+                                        palette.shop_status(turret_level.is_maxed(), can_upgrade),
+                                    ),
+                                ));
+                                card_col
+                                    .spawn((
+                                        Button,
+                                        Node {
+                                            width: Val::Percent(100.0),
+                                            height: Val::Px(42.0),
+                                            justify_content: JustifyContent::Center,
+                                            align_items: AlignItems::Center,
+                                            border: UiRect::all(Val::Px(2.0)),
+                                            ..default()
+                                        },
+                                        BackgroundColor(upg_btn_bg),
+                                        BorderColor::all(upg_btn_border),
+                                        OreShopTurretUpgradeButton,
+                                    ))
+                                    .with_children(|btn| {
+                                        btn.spawn((
+                                            Text::new(upg_label),
+                                            TextFont {
+                                                font: font.0.clone(),
+                                                font_size: 14.0,
+                                                ..default()
+                                            },
+                                            TextColor(upg_btn_text_color),
+                                        ));
+                                    });
+                            });
+                    });
+
+                    card.spawn(Node {
+                        height: Val::Px(4.0),
+                        ..default()
+                    });
+
+                    // ── Undo + close buttons ──────────────────────────────────
+                    // The undo button starts hidden; `ore_shop_undo_system`
+                    // shows it while the last purchase can still be refunded.
+                    card.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(12.0),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    })
+                    .with_children(|actions| {
+                        actions
+                            .spawn((
+                                Button,
+                                Node {
+                                    min_width: Val::Px(220.0),
+                                    height: Val::Px(44.0),
+                                    padding: UiRect::horizontal(Val::Px(12.0)),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    display: Display::None,
+                                    ..default()
+                                },
+                                BackgroundColor(ore_shop_item_bg()),
+                                BorderColor::all(ore_shop_item_border()),
+                                OreShopUndoButton,
+                            ))
+                            .with_children(|btn| {
+                                btn.spawn((
+                                    Text::new("UNDO"),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 14.0,
+                                        ..default()
+                                    },
+                                    TextColor(ore_shop_item_text()),
+                                    OreShopUndoText,
+                                ));
+                            });
+
+                        actions
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Px(220.0),
+                                    height: Val::Px(44.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(shop_close_bg()),
+                                BorderColor::all(shop_close_border()),
+                                OreShopCloseButton,
+                            ))
+                            .with_children(|btn| {
+                                btn.spawn((
+                                    Text::new("CLOSE"),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 16.0,
+                                        ..default()
+                                    },
+                                    TextColor(shop_close_text()),
+                                ));
+                            });
+                    });
+
+                    card.spawn((
+                        Text::new("Press Tab or ESC to close"),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(hint_color()),
+                    ));
+                });
+        });
+}
+
+/// Buy an upgrade at `discount` off its list price.
+///
+/// `upgrade` is the level's own `try_upgrade*` method; it is handed a wallet
+/// holding exactly the list price so its max-level and bookkeeping checks still
+/// apply, while the player is only charged the discounted price.
+fn try_discounted_upgrade(
+    ore: &mut u32,
+    list_price: Option<u32>,
+    discount: f32,
+    upgrade: impl FnOnce(&mut u32) -> Option<u32>,
+) -> Option<u32> {
+    let list_price = list_price?;
+    let price = discounted_price(list_price, discount);
+    if *ore < price {
+        return None;
+    }
+    let mut wallet = list_price;
+    upgrade(&mut wallet)?;
+    *ore -= price;
+    Some(price)
+}
+
+/// Spawn the ore shop overlay when entering [`GameState::OreShop`].
+#[allow(clippy::too_many_arguments)]
+pub fn setup_ore_shop(
+    mut commands: Commands,
+    ore: Res<PlayerOre>,
+    q_health: Query<&PlayerHealth, With<Player>>,
+    ammo: Res<MissileAmmo>,
+    config: Res<PhysicsConfig>,
+    weapon_tracks: Res<PrimaryWeaponUpgradeTracks>,
+    fire_rate_level: Res<PrimaryWeaponFireRateLevel>,
+    missile_level: Res<SecondaryWeaponLevel>,
+    magnet_level: Res<OreAffinityLevel>,
+    tractor_level: Res<TractorBeamLevel>,
+    ion_level: Res<IonCannonLevel>,
+    shield_level: Res<ShieldLevel>,
+    drone_level: Res<MiningDroneLevel>,
+    turret_level: Res<TurretLevel>,
+    intermission: Res<WaveIntermissionShop>,
+    ui: (Res<GameFont>, Res<Palette>),
+) {
+    let (font, palette) = ui;
+    let (hp, max_hp) = q_health
+        .single()
+        .map(|h| (h.hp, h.max_hp))
+        .unwrap_or((config.player_max_hp, config.player_max_hp));
+    spawn_ore_shop_overlay(
+        &mut commands,
+        &config,
+        &ore,
+        hp,
+        max_hp,
+        config.ore_heal_amount,
+        ammo.count,
+        config.missile_ammo_max,
+        &weapon_tracks,
+        &fire_rate_level,
+        &missile_level,
         &magnet_level,
         &tractor_level,
         &ion_level,
+        &shield_level,
+        &drone_level,
+        &turret_level,
+        intermission.discount(&config),
         &font,
+        &palette,
     );
 }
 
+/// Keep the ore shop header in step with the refinery while the shop is open.
+pub fn ore_shop_balance_text_system(
+    ore: Res<PlayerOre>,
+    mut q_text: Query<&mut Text, With<OreShopOreText>>,
+) {
+    if !ore.is_changed() {
+        return;
+    }
+    let label = ore_shop_balance_label(&ore);
+    for mut text in q_text.iter_mut() {
+        if text.0 != label {
+            *text = Text::new(label.clone());
+        }
+    }
+}
+
 /// Despawn the ore shop overlay when exiting [`GameState::OreShop`].
-pub fn cleanup_ore_shop(mut commands: Commands, query: Query<Entity, With<OreShopRoot>>) {
+pub fn cleanup_ore_shop(
+    mut commands: Commands,
+    query: Query<Entity, With<OreShopRoot>>,
+    mut intermission: ResMut<WaveIntermissionShop>,
+    mut transactions: ResMut<ShopTransactionLog>,
+) {
     for entity in query.iter() {
         commands.entity(entity).despawn();
     }
+    intermission.shopping = false;
+    // Purchases become final once the shop closes.
+    transactions.entries.clear();
+}
+
+/// Keep the next-wave countdown on the intermission banner up to date.
+///
+/// Only runs while the shop is open from the intermission beacon; the wave
+/// director keeps ticking behind the overlay and closes the shop at zero.
+pub fn ore_shop_intermission_countdown_system(
+    config: Res<PhysicsConfig>,
+    intermission: Res<WaveIntermissionShop>,
+    director: Res<CampaignWaveDirector>,
+    mut q_text: Query<&mut Text, With<OreShopIntermissionText>>,
+) {
+    let label = format!(
+        "INTERMISSION BEACON · UPGRADES -{:.0}% · NEXT WAVE IN {:.0}s",
+        intermission.discount(&config) * 100.0,
+        director.phase_timer_secs.ceil()
+    );
+    for mut text in q_text.iter_mut() {
+        *text = Text::new(label.clone());
+    }
 }
 
 /// Handle button and keyboard interactions in the ore shop.
 ///
-/// - **HEAL** button: spend 1 ore, restore `ore_heal_amount` HP (capped at max).
-/// - **MISSILE** button: spend 1 ore, restore 1 missile (capped at `missile_ammo_max`).
+/// - **HEAL** button: spend 1 ice, restore `ore_heal_amount` HP (capped at max).
+/// - **MISSILE** button: spend 1 iron, restore 1 missile (capped at `missile_ammo_max`).
+/// - **REFINE** buttons: move all raw ore of one kind into the refinery.
 /// - **UPGRADE WEAPON** button: spend ore to increase weapon level.
+/// - **SHIELD** button: spend credits to install or upgrade the shield.
+/// - **DRONE** button: spend iron to launch or upgrade the mining drone.
+/// - **TURRET** button: spend credits to mount another auto-turret.
 /// - **CLOSE** button / **ESC** / **Tab**: return to the originating state.
+///
+/// Each item is charged in its [`ShopPurchase::currency`].  Every purchase is
+/// recorded in [`ShopTransactionLog`] so [`ore_shop_undo_system`] can refund
+/// it.  The overlay is also rebuilt when the refinery delivers credits, so
+/// credit-priced items light up without reopening the shop.
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn ore_shop_button_system(
     mut commands: Commands,
     keys: Res<ButtonInput<KeyCode>>,
-    heal_query: Query<&Interaction, (Changed<Interaction>, With<OreShopHealButton>)>,
-    missile_query: Query<&Interaction, (Changed<Interaction>, With<OreShopMissileButton>)>,
+    consumable_queries: (
+        Query<&Interaction, (Changed<Interaction>, With<OreShopHealButton>)>,
+        Query<&Interaction, (Changed<Interaction>, With<OreShopMissileButton>)>,
+        Query<(&Interaction, &OreShopRefineButton), Changed<Interaction>>,
+    ),
     close_query: Query<&Interaction, (Changed<Interaction>, With<OreShopCloseButton>)>,
     upgrade_queries: (
         Query<&Interaction, (Changed<Interaction>, With<OreShopDestroyUpgradeButton>)>,
@@ -1173,6 +1899,9 @@ pub fn ore_shop_button_system(
         Query<&Interaction, (Changed<Interaction>, With<OreShopMagnetUpgradeButton>)>,
         Query<&Interaction, (Changed<Interaction>, With<OreShopTractorUpgradeButton>)>,
         Query<&Interaction, (Changed<Interaction>, With<OreShopIonUpgradeButton>)>,
+        Query<&Interaction, (Changed<Interaction>, With<OreShopShieldUpgradeButton>)>,
+        Query<&Interaction, (Changed<Interaction>, With<OreShopDroneUpgradeButton>)>,
+        Query<&Interaction, (Changed<Interaction>, With<OreShopTurretUpgradeButton>)>,
     ),
     shop_root_query: Query<Entity, With<OreShopRoot>>,
     mut ore: ResMut<PlayerOre>,
@@ -1186,12 +1915,20 @@ pub fn ore_shop_button_system(
         ResMut<OreAffinityLevel>,
         ResMut<TractorBeamLevel>,
         ResMut<IonCannonLevel>,
+        ResMut<ShieldLevel>,
+        ResMut<MiningDroneLevel>,
+        ResMut<TurretLevel>,
     ),
     mut next_state: ResMut<NextState<GameState>>,
     return_state: Res<ShopReturnState>,
-    font: Res<GameFont>,
+    session: (Res<WaveIntermissionShop>, ResMut<ShopTransactionLog>),
+    ui: (Res<GameFont>, Res<Palette>),
+    mut shown_credits: Local<Option<u32>>,
 ) {
     // Destructure tuple parameters
+    let (intermission, mut transactions) = session;
+    let (font, palette) = ui;
+    let (heal_query, missile_query, refine_query) = consumable_queries;
     let (
         destroy_upgrade_query,
         chip_upgrade_query,
@@ -1200,6 +1937,9 @@ pub fn ore_shop_button_system(
         magnet_upgrade_query,
         tractor_upgrade_query,
         ion_upgrade_query,
+        shield_upgrade_query,
+        drone_upgrade_query,
+        turret_upgrade_query,
     ) = upgrade_queries;
     let (
         mut weapon_tracks,
@@ -1208,12 +1948,15 @@ pub fn ore_shop_button_system(
         mut magnet_level,
         mut tractor_level,
         mut ion_level,
+        mut shield_level,
+        mut drone_level,
+        mut turret_level,
     ) = levels;
+    let discount = intermission.discount(&config);
 
-    // ── Close (ESC / Tab / button) ────────────────────────────────────────────
-    let wants_close = keys.just_pressed(KeyCode::Escape)
-        || keys.just_pressed(KeyCode::Tab)
-        || close_query.iter().any(|i| *i == Interaction::Pressed);
+    // ── Close (Tab / button; ESC presses the button via menu navigation) ─────
+    let wants_close =
+        keys.just_pressed(KeyCode::Tab) || close_query.iter().any(|i| *i == Interaction::Pressed);
 
     if wants_close {
         let target = match *return_state {
@@ -1226,13 +1969,21 @@ pub fn ore_shop_button_system(
 
     // ── Heal ──────────────────────────────────────────────────────────────────
     let heal_pressed = heal_query.iter().any(|i| *i == Interaction::Pressed);
-    if heal_pressed && ore.count > 0 {
+    if heal_pressed && ore.balance(ShopPurchase::HEAL_CURRENCY) > 0 {
         if let Ok(mut health) = q_health.single_mut() {
             if health.hp < health.max_hp {
+                let before = health.hp;
                 health.hp = (health.hp + config.ore_heal_amount).min(health.max_hp);
-                ore.count -= 1;
+                *ore.balance_mut(ShopPurchase::HEAL_CURRENCY) -= 1;
+                transactions.record(
+                    ShopPurchase::Heal {
+                        hp_restored: health.hp - before,
+                    },
+                    1,
+                    config.shop_undo_window_secs,
+                );
                 let (hp, max_hp) = (health.hp, health.max_hp);
-                let ore_count = ore.count;
+                let ore_now = *ore;
                 let ammo_count = ammo.count;
                 let heal_amount = config.ore_heal_amount;
                 let ammo_max = config.missile_ammo_max;
@@ -1242,7 +1993,7 @@ pub fn ore_shop_button_system(
                 spawn_ore_shop_overlay(
                     &mut commands,
                     &config,
-                    ore_count,
+                    &ore_now,
                     hp,
                     max_hp,
                     heal_amount,
@@ -1254,7 +2005,12 @@ pub fn ore_shop_button_system(
                     &magnet_level,
                     &tractor_level,
                     &ion_level,
+                    &shield_level,
+                    &drone_level,
+                    &turret_level,
+                    discount,
                     &font,
+                    &palette,
                 );
                 return;
             }
@@ -1263,14 +2019,60 @@ pub fn ore_shop_button_system(
 
     // ── Missile restock ───────────────────────────────────────────────────────
     let missile_pressed = missile_query.iter().any(|i| *i == Interaction::Pressed);
-    if missile_pressed && ore.count > 0 && ammo.count < config.missile_ammo_max {
+    let missile_price = ShopPurchase::Missile.currency();
+    if missile_pressed && ore.balance(missile_price) > 0 && ammo.count < config.missile_ammo_max {
         ammo.count += 1;
-        ore.count -= 1;
+        *ore.balance_mut(missile_price) -= 1;
+        transactions.record(ShopPurchase::Missile, 1, config.shop_undo_window_secs);
+        let (hp, max_hp) = q_health
+            .single()
+            .map(|h| (h.hp, h.max_hp))
+            .unwrap_or((config.player_max_hp, config.player_max_hp));
+        let ore_now = *ore;
+        let ammo_count = ammo.count;
+        let heal_amount = config.ore_heal_amount;
+        let ammo_max = config.missile_ammo_max;
+        for entity in shop_root_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        spawn_ore_shop_overlay(
+            &mut commands,
+            &config,
+            &ore_now,
+            hp,
+            max_hp,
+            heal_amount,
+            ammo_count,
+            ammo_max,
+            &weapon_tracks,
+            &fire_rate_level,
+            &missile_level,
+            &magnet_level,
+            &tractor_level,
+            &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
+            &font,
+            &palette,
+        );
+        return;
+    }
+
+    // ── Refinery ──────────────────────────────────────────────────────────────
+    let refine_kind = refine_query
+        .iter()
+        .find(|(i, _)| **i == Interaction::Pressed)
+        .map(|(_, button)| button.0);
+    if let Some(kind) = refine_kind {
+        let raw = ore.raw(kind);
+        ore.queue_refining(kind, raw);
         let (hp, max_hp) = q_health
             .single()
             .map(|h| (h.hp, h.max_hp))
             .unwrap_or((config.player_max_hp, config.player_max_hp));
-        let ore_count = ore.count;
+        let ore_now = *ore;
         let ammo_count = ammo.count;
         let heal_amount = config.ore_heal_amount;
         let ammo_max = config.missile_ammo_max;
@@ -1280,7 +2082,7 @@ pub fn ore_shop_button_system(
         spawn_ore_shop_overlay(
             &mut commands,
             &config,
-            ore_count,
+            &ore_now,
             hp,
             max_hp,
             heal_amount,
@@ -1292,7 +2094,12 @@ pub fn ore_shop_button_system(
             &magnet_level,
             &tractor_level,
             &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
             &font,
+            &palette,
         );
         return;
     }
@@ -1302,12 +2109,23 @@ pub fn ore_shop_button_system(
         .iter()
         .any(|i| *i == Interaction::Pressed);
     if destroy_upgrade_pressed {
-        weapon_tracks.try_upgrade_destroy(&mut ore.count);
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::DestroyTrack.currency()),
+            weapon_tracks.cost_for_next_destroy_level(),
+            discount,
+            |wallet| weapon_tracks.try_upgrade_destroy(wallet),
+        ) {
+            transactions.record(
+                ShopPurchase::DestroyTrack,
+                price,
+                config.shop_undo_window_secs,
+            );
+        }
         let (hp, max_hp) = q_health
             .single()
             .map(|h| (h.hp, h.max_hp))
             .unwrap_or((config.player_max_hp, config.player_max_hp));
-        let ore_count = ore.count;
+        let ore_now = *ore;
         let ammo_count = ammo.count;
         let heal_amount = config.ore_heal_amount;
         let ammo_max = config.missile_ammo_max;
@@ -1317,7 +2135,7 @@ pub fn ore_shop_button_system(
         spawn_ore_shop_overlay(
             &mut commands,
             &config,
-            ore_count,
+            &ore_now,
             hp,
             max_hp,
             heal_amount,
@@ -1329,7 +2147,12 @@ pub fn ore_shop_button_system(
             &magnet_level,
             &tractor_level,
             &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
             &font,
+            &palette,
         );
         return;
     }
@@ -1339,12 +2162,19 @@ pub fn ore_shop_button_system(
         .iter()
         .any(|i| *i == Interaction::Pressed);
     if chip_upgrade_pressed {
-        weapon_tracks.try_upgrade_chip(&mut ore.count);
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::ChipTrack.currency()),
+            weapon_tracks.cost_for_next_chip_level(),
+            discount,
+            |wallet| weapon_tracks.try_upgrade_chip(wallet),
+        ) {
+            transactions.record(ShopPurchase::ChipTrack, price, config.shop_undo_window_secs);
+        }
         let (hp, max_hp) = q_health
             .single()
             .map(|h| (h.hp, h.max_hp))
             .unwrap_or((config.player_max_hp, config.player_max_hp));
-        let ore_count = ore.count;
+        let ore_now = *ore;
         let ammo_count = ammo.count;
         let heal_amount = config.ore_heal_amount;
         let ammo_max = config.missile_ammo_max;
@@ -1354,7 +2184,7 @@ pub fn ore_shop_button_system(
         spawn_ore_shop_overlay(
             &mut commands,
             &config,
-            ore_count,
+            &ore_now,
             hp,
             max_hp,
             heal_amount,
@@ -1366,7 +2196,12 @@ pub fn ore_shop_button_system(
             &magnet_level,
             &tractor_level,
             &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
             &font,
+            &palette,
         );
         return;
     }
@@ -1376,12 +2211,19 @@ pub fn ore_shop_button_system(
         .iter()
         .any(|i| *i == Interaction::Pressed);
     if fire_rate_upgrade_pressed {
-        fire_rate_level.try_upgrade(&mut ore.count);
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::FireRate.currency()),
+            fire_rate_level.cost_for_next_level(),
+            discount,
+            |wallet| fire_rate_level.try_upgrade(wallet),
+        ) {
+            transactions.record(ShopPurchase::FireRate, price, config.shop_undo_window_secs);
+        }
         let (hp, max_hp) = q_health
             .single()
             .map(|h| (h.hp, h.max_hp))
             .unwrap_or((config.player_max_hp, config.player_max_hp));
-        let ore_count = ore.count;
+        let ore_now = *ore;
         let ammo_count = ammo.count;
         let heal_amount = config.ore_heal_amount;
         let ammo_max = config.missile_ammo_max;
@@ -1391,7 +2233,7 @@ pub fn ore_shop_button_system(
         spawn_ore_shop_overlay(
             &mut commands,
             &config,
-            ore_count,
+            &ore_now,
             hp,
             max_hp,
             heal_amount,
@@ -1403,7 +2245,12 @@ pub fn ore_shop_button_system(
             &magnet_level,
             &tractor_level,
             &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
             &font,
+            &palette,
         );
     }
 
@@ -1412,12 +2259,23 @@ pub fn ore_shop_button_system(
         .iter()
         .any(|i| *i == Interaction::Pressed);
     if missile_upgrade_pressed {
-        missile_level.try_upgrade(&mut ore.count);
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::MissileLevel.currency()),
+            missile_level.cost_for_next_level(),
+            discount,
+            |wallet| missile_level.try_upgrade(wallet),
+        ) {
+            transactions.record(
+                ShopPurchase::MissileLevel,
+                price,
+                config.shop_undo_window_secs,
+            );
+        }
         let (hp, max_hp) = q_health
             .single()
             .map(|h| (h.hp, h.max_hp))
             .unwrap_or((config.player_max_hp, config.player_max_hp));
-        let ore_count = ore.count;
+        let ore_now = *ore;
         let ammo_count = ammo.count;
         let heal_amount = config.ore_heal_amount;
         let ammo_max = config.missile_ammo_max;
@@ -1427,7 +2285,7 @@ pub fn ore_shop_button_system(
         spawn_ore_shop_overlay(
             &mut commands,
             &config,
-            ore_count,
+            &ore_now,
             hp,
             max_hp,
             heal_amount,
@@ -1439,7 +2297,12 @@ pub fn ore_shop_button_system(
             &magnet_level,
             &tractor_level,
             &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
             &font,
+            &palette,
         );
     }
 
@@ -1448,12 +2311,19 @@ pub fn ore_shop_button_system(
         .iter()
         .any(|i| *i == Interaction::Pressed);
     if magnet_upgrade_pressed {
-        magnet_level.try_upgrade(&mut ore.count);
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::Magnet.currency()),
+            magnet_level.cost_for_next_level(),
+            discount,
+            |wallet| magnet_level.try_upgrade(wallet),
+        ) {
+            transactions.record(ShopPurchase::Magnet, price, config.shop_undo_window_secs);
+        }
         let (hp, max_hp) = q_health
             .single()
             .map(|h| (h.hp, h.max_hp))
             .unwrap_or((config.player_max_hp, config.player_max_hp));
-        let ore_count = ore.count;
+        let ore_now = *ore;
         let ammo_count = ammo.count;
         let heal_amount = config.ore_heal_amount;
         let ammo_max = config.missile_ammo_max;
@@ -1463,7 +2333,7 @@ pub fn ore_shop_button_system(
         spawn_ore_shop_overlay(
             &mut commands,
             &config,
-            ore_count,
+            &ore_now,
             hp,
             max_hp,
             heal_amount,
@@ -1475,7 +2345,12 @@ pub fn ore_shop_button_system(
             &magnet_level,
             &tractor_level,
             &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
             &font,
+            &palette,
         );
     }
 
@@ -1484,12 +2359,19 @@ pub fn ore_shop_button_system(
         .iter()
         .any(|i| *i == Interaction::Pressed);
     if tractor_upgrade_pressed {
-        tractor_level.try_upgrade(&mut ore.count);
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::Tractor.currency()),
+            tractor_level.cost_for_next_level(),
+            discount,
+            |wallet| tractor_level.try_upgrade(wallet),
+        ) {
+            transactions.record(ShopPurchase::Tractor, price, config.shop_undo_window_secs);
+        }
         let (hp, max_hp) = q_health
             .single()
             .map(|h| (h.hp, h.max_hp))
             .unwrap_or((config.player_max_hp, config.player_max_hp));
-        let ore_count = ore.count;
+        let ore_now = *ore;
         let ammo_count = ammo.count;
         let heal_amount = config.ore_heal_amount;
         let ammo_max = config.missile_ammo_max;
@@ -1499,7 +2381,7 @@ pub fn ore_shop_button_system(
         spawn_ore_shop_overlay(
             &mut commands,
             &config,
-            ore_count,
+            &ore_now,
             hp,
             max_hp,
             heal_amount,
@@ -1511,19 +2393,177 @@ pub fn ore_shop_button_system(
             &magnet_level,
             &tractor_level,
             &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
             &font,
+            &palette,
         );
     }
 
     // ── Ion cannon upgrade ────────────────────────────────────────────────────
     let ion_upgrade_pressed = ion_upgrade_query.iter().any(|i| *i == Interaction::Pressed);
     if ion_upgrade_pressed {
-        ion_level.try_upgrade(&mut ore.count);
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::IonCannon.currency()),
+            ion_level.cost_for_next_level(),
+            discount,
+            |wallet| ion_level.try_upgrade(wallet),
+        ) {
+            transactions.record(ShopPurchase::IonCannon, price, config.shop_undo_window_secs);
+        }
+        let (hp, max_hp) = q_health
+            .single()
+            .map(|h| (h.hp, h.max_hp))
+            .unwrap_or((config.player_max_hp, config.player_max_hp));
+        let ore_now = *ore;
+        let ammo_count = ammo.count;
+        let heal_amount = config.ore_heal_amount;
+        let ammo_max = config.missile_ammo_max;
+        for entity in shop_root_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        spawn_ore_shop_overlay(
+            &mut commands,
+            &config,
+            &ore_now,
+            hp,
+            max_hp,
+            heal_amount,
+            ammo_count,
+            ammo_max,
+            &weapon_tracks,
+            &fire_rate_level,
+            &missile_level,
+            &magnet_level,
+            &tractor_level,
+            &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
+            &font,
+            &palette,
+        );
+    }
+
+    // ── Shield upgrade ────────────────────────────────────────────────────────
+    let shield_upgrade_pressed = shield_upgrade_query
+        .iter()
+        .any(|i| *i == Interaction::Pressed);
+    if shield_upgrade_pressed {
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::Shield.currency()),
+            shield_level.cost_for_next_level(),
+            discount,
+            |wallet| shield_level.try_upgrade(wallet),
+        ) {
+            transactions.record(ShopPurchase::Shield, price, config.shop_undo_window_secs);
+        }
+        let (hp, max_hp) = q_health
+            .single()
+            .map(|h| (h.hp, h.max_hp))
+            .unwrap_or((config.player_max_hp, config.player_max_hp));
+        let ore_now = *ore;
+        let ammo_count = ammo.count;
+        let heal_amount = config.ore_heal_amount;
+        let ammo_max = config.missile_ammo_max;
+        for entity in shop_root_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        spawn_ore_shop_overlay(
+            &mut commands,
+            &config,
+            &ore_now,
+            hp,
+            max_hp,
+            heal_amount,
+            ammo_count,
+            ammo_max,
+            &weapon_tracks,
+            &fire_rate_level,
+            &missile_level,
+            &magnet_level,
+            &tractor_level,
+            &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
+            &font,
+            &palette,
+        );
+        return;
+    }
+
+    // ── Mining drone ──────────────────────────────────────────────────────────
+    let drone_upgrade_pressed = drone_upgrade_query
+        .iter()
+        .any(|i| *i == Interaction::Pressed);
+    if drone_upgrade_pressed {
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::Drone.currency()),
+            drone_level.cost_for_next_level(),
+            discount,
+            |wallet| drone_level.try_upgrade(wallet),
+        ) {
+            transactions.record(ShopPurchase::Drone, price, config.shop_undo_window_secs);
+        }
+        let (hp, max_hp) = q_health
+            .single()
+            .map(|h| (h.hp, h.max_hp))
+            .unwrap_or((config.player_max_hp, config.player_max_hp));
+        let ore_now = *ore;
+        let ammo_count = ammo.count;
+        let heal_amount = config.ore_heal_amount;
+        let ammo_max = config.missile_ammo_max;
+        for entity in shop_root_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        spawn_ore_shop_overlay(
+            &mut commands,
+            &config,
+            &ore_now,
+            hp,
+            max_hp,
+            heal_amount,
+            ammo_count,
+            ammo_max,
+            &weapon_tracks,
+            &fire_rate_level,
+            &missile_level,
+            &magnet_level,
+            &tractor_level,
+            &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
+            &font,
+            &palette,
+        );
+        return;
+    }
+
+    // ── Auto-turrets ──────────────────────────────────────────────────────────
+    let turret_upgrade_pressed = turret_upgrade_query
+        .iter()
+        .any(|i| *i == Interaction::Pressed);
+    if turret_upgrade_pressed {
+        if let Some(price) = try_discounted_upgrade(
+            ore.balance_mut(ShopPurchase::Turret.currency()),
+            turret_level.cost_for_next_level(),
+            discount,
+            |wallet| turret_level.try_upgrade(wallet),
+        ) {
+            transactions.record(ShopPurchase::Turret, price, config.shop_undo_window_secs);
+        }
         let (hp, max_hp) = q_health
             .single()
             .map(|h| (h.hp, h.max_hp))
             .unwrap_or((config.player_max_hp, config.player_max_hp));
-        let ore_count = ore.count;
+        let ore_now = *ore;
         let ammo_count = ammo.count;
         let heal_amount = config.ore_heal_amount;
         let ammo_max = config.missile_ammo_max;
@@ -1533,7 +2573,7 @@ pub fn ore_shop_button_system(
         spawn_ore_shop_overlay(
             &mut commands,
             &config,
-            ore_count,
+            &ore_now,
             hp,
             max_hp,
             heal_amount,
@@ -1545,7 +2585,317 @@ pub fn ore_shop_button_system(
             &magnet_level,
             &tractor_level,
             &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
+            &font,
+            &palette,
+        );
+        return;
+    }
+
+    // ── Refinery deliveries ───────────────────────────────────────────────────
+    let previous_credits = shown_credits.replace(ore.credits);
+    if previous_credits.is_some_and(|credits| credits < ore.credits) {
+        let (hp, max_hp) = q_health
+            .single()
+            .map(|h| (h.hp, h.max_hp))
+            .unwrap_or((config.player_max_hp, config.player_max_hp));
+        for entity in shop_root_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        spawn_ore_shop_overlay(
+            &mut commands,
+            &config,
+            &ore,
+            hp,
+            max_hp,
+            config.ore_heal_amount,
+            ammo.count,
+            config.missile_ammo_max,
+            &weapon_tracks,
+            &fire_rate_level,
+            &missile_level,
+            &magnet_level,
+            &tractor_level,
+            &ion_level,
+            &shield_level,
+            &drone_level,
+            &turret_level,
+            discount,
             &font,
+            &palette,
+        );
+    }
+}
+
+/// Count down the undo window and refund the most recent purchase on request.
+///
+/// Runs after [`ore_shop_button_system`] so a purchase made this frame shows
+/// its undo button immediately.  The window is measured in real time, since
+/// the shop is open while the simulation is paused.  Undoing refunds the ore
+/// that was actually charged and steps the bought stat back down, then
+/// rebuilds the overlay so prices and availability refresh.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn ore_shop_undo_system(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    config: Res<PhysicsConfig>,
+    mut transactions: ResMut<ShopTransactionLog>,
+    undo_query: Query<&Interaction, (Changed<Interaction>, With<OreShopUndoButton>)>,
+    mut undo_widgets: (
+        Query<&mut Node, With<OreShopUndoButton>>,
+        Query<&mut Text, With<OreShopUndoText>>,
+    ),
+    shop_root_query: Query<Entity, With<OreShopRoot>>,
+    mut ore: ResMut<PlayerOre>,
+    mut q_health: Query<&mut PlayerHealth, With<Player>>,
+    mut ammo: ResMut<MissileAmmo>,
+    levels: (
+        ResMut<PrimaryWeaponUpgradeTracks>,
+        ResMut<PrimaryWeaponFireRateLevel>,
+        ResMut<SecondaryWeaponLevel>,
+        ResMut<OreAffinityLevel>,
+        ResMut<TractorBeamLevel>,
+        ResMut<IonCannonLevel>,
+        ResMut<ShieldLevel>,
+        ResMut<MiningDroneLevel>,
+        ResMut<TurretLevel>,
+    ),
+    intermission: Res<WaveIntermissionShop>,
+    font: Res<GameFont>,
+    palette: Res<Palette>,
+) {
+    let (
+        mut weapon_tracks,
+        mut fire_rate_level,
+        mut missile_level,
+        mut magnet_level,
+        mut tractor_level,
+        mut ion_level,
+        mut shield_level,
+        mut drone_level,
+        mut turret_level,
+    ) = levels;
+
+    transactions.tick(real_time.delta_secs());
+
+    let undo_pressed = undo_query.iter().any(|i| *i == Interaction::Pressed);
+    if undo_pressed {
+        if let Some(undone) = transactions.take_undoable() {
+            *ore.balance_mut(undone.purchase.currency()) += undone.ore_spent;
+            match undone.purchase {
+                ShopPurchase::Heal { hp_restored } => {
+                    if let Ok(mut health) = q_health.single_mut() {
+                        health.hp = (health.hp - hp_restored).max(0.0);
+                    }
+                }
+                ShopPurchase::Missile => ammo.count = ammo.count.saturating_sub(1),
+                ShopPurchase::DestroyTrack => {
+                    weapon_tracks.destroy_level = weapon_tracks.destroy_level.saturating_sub(1)
+                }
+                ShopPurchase::ChipTrack => {
+                    weapon_tracks.chip_level = weapon_tracks.chip_level.saturating_sub(1)
+                }
+                ShopPurchase::FireRate => {
+                    fire_rate_level.level = fire_rate_level.level.saturating_sub(1)
+                }
+                ShopPurchase::MissileLevel => {
+                    missile_level.level = missile_level.level.saturating_sub(1)
+                }
+                ShopPurchase::Magnet => magnet_level.level = magnet_level.level.saturating_sub(1),
+                ShopPurchase::Tractor => {
+                    tractor_level.level = tractor_level.level.saturating_sub(1)
+                }
+                ShopPurchase::IonCannon => ion_level.level = ion_level.level.saturating_sub(1),
+                ShopPurchase::Shield => shield_level.level = shield_level.level.saturating_sub(1),
+                ShopPurchase::Drone => drone_level.level = drone_level.level.saturating_sub(1),
+                ShopPurchase::Turret => turret_level.level = turret_level.level.saturating_sub(1),
+            }
+
+            let (hp, max_hp) = q_health
+                .single()
+                .map(|h| (h.hp, h.max_hp))
+                .unwrap_or((config.player_max_hp, config.player_max_hp));
+            for entity in shop_root_query.iter() {
+                commands.entity(entity).despawn();
+            }
+            spawn_ore_shop_overlay(
+                &mut commands,
+                &config,
+                &ore,
+                hp,
+                max_hp,
+                config.ore_heal_amount,
+                ammo.count,
+                config.missile_ammo_max,
+                &weapon_tracks,
+                &fire_rate_level,
+                &missile_level,
+                &magnet_level,
+                &tractor_level,
+                &ion_level,
+                &shield_level,
+                &drone_level,
+                &turret_level,
+                intermission.discount(&config),
+                &font,
+                &palette,
+            );
+        }
+    }
+
+    // ── Undo button visibility and countdown ──────────────────────────────────
+    let (q_undo_node, q_undo_text) = &mut undo_widgets;
+    let undoable = transactions.undoable().copied();
+    for mut node in q_undo_node.iter_mut() {
+        let display = if undoable.is_some() {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if node.display != display {
+            node.display = display;
+        }
+    }
+    if let Some(entry) = undoable {
+        let label = format!(
+            "UNDO {} (+{} {}) · {:.0}s",
+            entry.purchase.label(),
+            entry.ore_spent,
+            entry.purchase.currency().symbol(),
+            entry.undo_secs_left.ceil()
+        );
+        for mut text in q_undo_text.iter_mut() {
+            if text.0 != label {
+                *text = Text::new(label.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::SHOP_UNDO_WINDOW_SECS;
+
+    fn build_undo_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(PhysicsConfig::default());
+        app.insert_resource(ShopTransactionLog::default());
+        app.insert_resource(PlayerOre {
+            raw: [3; 3],
+            credits: 3,
+            ..Default::default()
+        });
+        app.insert_resource(MissileAmmo { count: 2 });
+        app.insert_resource(PrimaryWeaponUpgradeTracks::default());
+        app.insert_resource(PrimaryWeaponFireRateLevel::default());
+        app.insert_resource(SecondaryWeaponLevel::default());
+        app.insert_resource(OreAffinityLevel::default());
+        app.insert_resource(TractorBeamLevel::default());
+        app.insert_resource(IonCannonLevel::default());
+        app.insert_resource(ShieldLevel { level: 2 });
+        app.insert_resource(MiningDroneLevel::default());
+        app.insert_resource(TurretLevel { level: 1 });
+        app.insert_resource(WaveIntermissionShop::default());
+        app.insert_resource(GameFont::default());
+        app.insert_resource(Palette::default());
+        app.add_systems(Update, ore_shop_undo_system);
+        app
+    }
+
+    fn press_undo(app: &mut App) {
+        app.world_mut()
+            .spawn((Button, Interaction::Pressed, OreShopUndoButton));
+        app.update();
+    }
+
+    #[test]
+    fn undo_refunds_ore_and_reverts_upgrade() {
+        let mut app = build_undo_test_app();
+        app.world_mut().resource_mut::<ShopTransactionLog>().record(
+            ShopPurchase::Shield,
+            4,
+            SHOP_UNDO_WINDOW_SECS,
+        );
+
+        press_undo(&mut app);
+
+        // Shields are priced in credits.
+        let ore = app.world().resource::<PlayerOre>();
+        assert_eq!(ore.credits, 7);
+        assert_eq!(ore.raw, [3; 3]);
+        assert_eq!(app.world().resource::<ShieldLevel>().level, 1);
+        assert!(app
+            .world()
+            .resource::<ShopTransactionLog>()
+            .entries
+            .is_empty());
+    }
+
+    #[test]
+    fn undo_unmounts_the_last_turret() {
+        let mut app = build_undo_test_app();
+        app.world_mut().resource_mut::<ShopTransactionLog>().record(
+            ShopPurchase::Turret,
+            12,
+            SHOP_UNDO_WINDOW_SECS,
+        );
+
+        press_undo(&mut app);
+
+        assert_eq!(app.world().resource::<PlayerOre>().credits, 15);
+        assert_eq!(app.world().resource::<TurretLevel>().level, 0);
+    }
+
+    #[test]
+    fn undo_restores_hp_spent_on_heal() {
+        let mut app = build_undo_test_app();
+        app.world_mut().spawn((
+            Player,
+            PlayerHealth {
+                hp: 80.0,
+                max_hp: 100.0,
+                ..Default::default()
+            },
+        ));
+        app.world_mut().resource_mut::<ShopTransactionLog>().record(
+            ShopPurchase::Heal { hp_restored: 20.0 },
+            1,
+            SHOP_UNDO_WINDOW_SECS,
+        );
+
+        press_undo(&mut app);
+
+        let hp = app
+            .world_mut()
+            .query_filtered::<&PlayerHealth, With<Player>>()
+            .single(app.world())
+            .unwrap()
+            .hp;
+        assert_eq!(hp, 60.0);
+        assert_eq!(app.world().resource::<PlayerOre>().raw(OreKind::Ice), 4);
+    }
+
+    #[test]
+    fn expired_purchase_cannot_be_undone() {
+        let mut app = build_undo_test_app();
+        {
+            let mut log = app.world_mut().resource_mut::<ShopTransactionLog>();
+            log.record(ShopPurchase::Missile, 1, SHOP_UNDO_WINDOW_SECS);
+            log.tick(SHOP_UNDO_WINDOW_SECS + 0.1);
+        }
+
+        press_undo(&mut app);
+
+        assert_eq!(app.world().resource::<PlayerOre>().raw, [3; 3]);
+        assert_eq!(app.world().resource::<MissileAmmo>().count, 2);
+        assert_eq!(
+            app.world().resource::<ShopTransactionLog>().entries.len(),
+            1
         );
     }
 }
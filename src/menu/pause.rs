@@ -1,18 +1,29 @@
 use super::*;
-use crate::campaign::CampaignProgressionState;
+use crate::campaign::{CampaignProgressionState, WaveIntermissionShop};
+use crate::simulation::rapier_guard::PhysicsResumeGuard;
 
 /// Disable the Rapier physics pipeline so asteroids freeze in place while paused.
-pub fn pause_physics(mut config: Query<&mut RapierConfiguration>) {
+///
+/// Also drops any resume still waiting on
+/// [`apply_physics_resume_system`](crate::simulation::rapier_guard::apply_physics_resume_system),
+/// so a quick `Playing → Paused` cannot re-enable physics behind the pause menu.
+pub fn pause_physics(
+    mut config: Query<&mut RapierConfiguration>,
+    mut guard: ResMut<PhysicsResumeGuard>,
+) {
+    guard.pending = false;
     for mut cfg in config.iter_mut() {
         cfg.physics_pipeline_active = false;
     }
 }
 
-/// Re-enable the Rapier physics pipeline when the player resumes.
-pub fn resume_physics(mut config: Query<&mut RapierConfiguration>) {
-    for mut cfg in config.iter_mut() {
-        cfg.physics_pipeline_active = true;
-    }
+/// Request the Rapier physics pipeline back on when play resumes.
+///
+/// The pipeline is re-enabled after the next fixed tick's writeback rather
+/// than here, so despawns queued by the same transition are flushed from
+/// Rapier before it steps (see [`crate::simulation::rapier_guard`]).
+pub fn resume_physics(mut guard: ResMut<PhysicsResumeGuard>) {
+    guard.pending = true;
 }
 
 /// ESC while in `Playing` → transition to `Paused`.
@@ -25,32 +36,32 @@ pub fn toggle_pause_system(
     }
 }
 
-/// ESC while in `Paused` → transition back to `Playing`.
-pub fn pause_resume_input_system(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut next_state: ResMut<NextState<GameState>>,
-) {
-    if keys.just_pressed(KeyCode::Escape) {
-        next_state.set(GameState::Playing);
-    }
-}
-
 /// Tab while in `Playing` → open the ore shop (freeze simulation).
+///
+/// In campaign mode the shop is only reachable between missions, or while
+/// docked at the wave-intermission beacon.  A beacon visit marks the shop as
+/// `shopping`, which discounts upgrades and leaves the simulation running.
 pub fn toggle_ore_shop_system(
     keys: Res<ButtonInput<KeyCode>>,
     current_state: Res<State<GameState>>,
     selected_mode: Res<SelectedGameMode>,
     progression: Res<CampaignProgressionState>,
+    mut intermission: ResMut<WaveIntermissionShop>,
     mut next_state: ResMut<NextState<GameState>>,
     mut return_state: ResMut<ShopReturnState>,
 ) {
+    let at_beacon = *selected_mode == SelectedGameMode::Campaign
+        && *current_state == GameState::Playing
+        && intermission.docked;
     if *selected_mode == SelectedGameMode::Campaign
         && progression.next_mission_pending_shop.is_none()
+        && !at_beacon
     {
         return;
     }
 
     if keys.just_pressed(KeyCode::Tab) {
+        intermission.shopping = at_beacon;
         *return_state = if *current_state == GameState::Paused {
             ShopReturnState::Paused
         } else {
@@ -70,12 +81,14 @@ pub fn toggle_ore_shop_system(
 /// │ ░░░░░   │      — PAUSED —       │   ░░░░░░ │
 /// │ ░░░░░   │    [ RESUME     ]     │   ░░░░░░ │
 /// │ ░░░░░   │    [ DEBUG OVL. ]     │   ░░░░░░ │
+/// │ ░░░░░   │    [ RUN INFO   ]     │   ░░░░░░ │
+/// │ ░░░░░   │    [ REPORT     ]     │   ░░░░░░ │
 /// │ ░░░░░   │    [ QUIT       ]     │   ░░░░░░ │
 /// │ ░░░░░   │   ESC to resume       │   ░░░░░░ │
 /// │ ░░░░░   └───────────────────────┘   ░░░░░░ │
 /// └─────────────────────────────────────────────┘
 /// ```
-pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
+pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>, palette: Res<Palette>) {
     // ── Full-screen dim overlay ───────────────────────────────────────────────
     commands
         .spawn((
@@ -134,8 +147,8 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                             border: UiRect::all(Val::Px(2.0)),
                             ..default()
                         },
-                        BackgroundColor(pause_resume_bg()),
-                        BorderColor::all(pause_resume_border()),
+                        BackgroundColor(palette.confirm.bg),
+                        BorderColor::all(palette.confirm.border),
                         PauseResumeButton,
                     ))
                     .with_children(|btn| {
@@ -146,7 +159,7 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                                 font_size: 18.0,
                                 ..default()
                             },
-                            TextColor(pause_resume_text()),
+                            TextColor(palette.confirm.text),
                         ));
                     });
 
@@ -195,8 +208,8 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                                 border: UiRect::all(Val::Px(2.0)),
                                 ..default()
                             },
-                            BackgroundColor(shop_buy_bg()),
-                            BorderColor::all(shop_buy_border()),
+                            BackgroundColor(palette.buy.bg),
+                            BorderColor::all(palette.buy.border),
                             PauseSaveSlot1Button,
                         ))
                         .with_children(|btn| {
@@ -207,7 +220,7 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                                     font_size: 12.0,
                                     ..default()
                                 },
-                                TextColor(shop_buy_text()),
+                                TextColor(palette.buy.text),
                             ));
                         });
 
@@ -221,8 +234,8 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                                 border: UiRect::all(Val::Px(2.0)),
                                 ..default()
                             },
-                            BackgroundColor(shop_buy_bg()),
-                            BorderColor::all(shop_buy_border()),
+                            BackgroundColor(palette.buy.bg),
+                            BorderColor::all(palette.buy.border),
                             PauseSaveSlot2Button,
                         ))
                         .with_children(|btn| {
@@ -233,7 +246,7 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                                     font_size: 12.0,
                                     ..default()
                                 },
-                                TextColor(shop_buy_text()),
+                                TextColor(palette.buy.text),
                             ));
                         });
 
@@ -247,8 +260,8 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                                 border: UiRect::all(Val::Px(2.0)),
                                 ..default()
                             },
-                            BackgroundColor(shop_buy_bg()),
-                            BorderColor::all(shop_buy_border()),
+                            BackgroundColor(palette.buy.bg),
+                            BorderColor::all(palette.buy.border),
                             PauseSaveSlot3Button,
                         ))
                         .with_children(|btn| {
@@ -259,11 +272,65 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                                     font_size: 12.0,
                                     ..default()
                                 },
-                                TextColor(shop_buy_text()),
+                                TextColor(palette.buy.text),
                             ));
                         });
                     });
 
+                    // Copy run info button
+                    card.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(220.0),
+                            height: Val::Px(40.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(pause_debug_bg()),
+                        BorderColor::all(pause_debug_border()),
+                        PauseCopyRunInfoButton,
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new("⧉ COPY RUN INFO ⧉"),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(pause_debug_text()),
+                        ));
+                    });
+
+                    // Report issue button
+                    card.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(220.0),
+                            height: Val::Px(40.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(pause_debug_bg()),
+                        BorderColor::all(pause_debug_border()),
+                        PauseReportIssueButton,
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new("⚠ REPORT ISSUE ⚠"),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 14.0,
+                                ..default()
+                            },
+                            TextColor(pause_debug_text()),
+                        ));
+                    });
+
                     // Upgrades / Shop button removed — use Tab to open Ore Shop directly.
 
                     // Main Menu button
@@ -277,8 +344,8 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                             border: UiRect::all(Val::Px(2.0)),
                             ..default()
                         },
-                        BackgroundColor(quit_bg()),
-                        BorderColor::all(quit_border()),
+                        BackgroundColor(palette.cancel.bg),
+                        BorderColor::all(palette.cancel.border),
                         PauseMainMenuButton,
                     ))
                     .with_children(|btn| {
@@ -289,7 +356,7 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                                 font_size: 18.0,
                                 ..default()
                             },
-                            TextColor(quit_text()),
+                            TextColor(palette.cancel.text),
                         ));
                     });
 
@@ -305,6 +372,15 @@ pub fn setup_pause_menu(mut commands: Commands, font: Res<GameFont>) {
                         },
                         TextColor(hint_color()),
                     ));
+                    card.spawn((
+                        Text::new("Shift → slow-mo  ·  V → fast-forward  ·  T → lock-on"),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 12.0,
+                            ..default()
+                        },
+                        TextColor(hint_color()),
+                    ));
                 });
         });
 }
@@ -320,8 +396,13 @@ pub fn cleanup_pause_menu(mut commands: Commands, pause_query: Query<Entity, Wit
 ///
 /// - **Resume** → transitions back to [`GameState::Playing`].
 /// - **Debug Overlays** → opens / closes the floating debug overlay panel.
+/// - **Copy Run Info** → puts the [`RunMetadata`](crate::run_info::RunMetadata)
+///   summary (scenario, seed, mode, version) on the clipboard.
+/// - **Report Issue** → zips the session, log, config and a screenshot into
+///   `reports/` (see [`crate::report`]).
 /// - **Main Menu** → cleans up the game world and returns to [`GameState::MainMenu`].
 /// - (Ore shop opened via Tab key; see [`toggle_ore_shop_system`].)
+/// - (ESC presses **Resume** through the shared menu navigation.)
 #[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn pause_menu_button_system(
     resume_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<PauseResumeButton>)>,
@@ -338,12 +419,23 @@ pub fn pause_menu_button_system(
         (&Interaction, &Children),
         (Changed<Interaction>, With<PauseSaveSlot3Button>),
     >,
+    copy_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<PauseCopyRunInfoButton>),
+    >,
+    report_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<PauseReportIssueButton>),
+    >,
     quit_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<PauseMainMenuButton>)>,
     mut btn_text: Query<&mut TextColor>,
     mut next_state: ResMut<NextState<GameState>>,
     mut debug_panel_query: Query<&mut Visibility, With<crate::rendering::DebugPanel>>,
     mut overlay: ResMut<crate::rendering::OverlayState>,
     mut save_writer: MessageWriter<SaveSlotRequest>,
+    mut report_writer: MessageWriter<crate::report::ReportIssueRequest>,
+    run: Res<crate::run_info::RunMetadata>,
+    palette: Res<Palette>,
 ) {
     for (interaction, children) in resume_query.iter() {
         match interaction {
@@ -360,7 +452,7 @@ pub fn pause_menu_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(pause_resume_text());
+                        *color = TextColor(palette.confirm.text);
                     }
                 }
             }
@@ -412,7 +504,7 @@ pub fn pause_menu_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(shop_buy_text());
+                        *color = TextColor(palette.buy.text);
                     }
                 }
             }
@@ -434,7 +526,7 @@ pub fn pause_menu_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(shop_buy_text());
+                        *color = TextColor(palette.buy.text);
                     }
                 }
             }
@@ -456,7 +548,52 @@ pub fn pause_menu_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(shop_buy_text());
+                        *color = TextColor(palette.buy.text);
+                    }
+                }
+            }
+        }
+    }
+
+    for (interaction, children) in copy_query.iter() {
+        match interaction {
+            Interaction::Pressed => match crate::run_info::copy_run_info_to_clipboard(&run) {
+                Ok(()) => info!("Copied run info: {}", run.summary()),
+                Err(err) => warn!("Clipboard unavailable ({err}); run info: {}", run.summary()),
+            },
+            Interaction::Hovered => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(Color::WHITE);
+                    }
+                }
+            }
+            Interaction::None => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(pause_debug_text());
+                    }
+                }
+            }
+        }
+    }
+
+    for (interaction, children) in report_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                report_writer.write(crate::report::ReportIssueRequest);
+            }
+            Interaction::Hovered => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(Color::WHITE);
+                    }
+                }
+            }
+            Interaction::None => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(pause_debug_text());
                     }
                 }
             }
@@ -478,7 +615,7 @@ pub fn pause_menu_button_system(
             Interaction::None => {
                 for child in children.iter() {
                     if let Ok(mut color) = btn_text.get_mut(child) {
-                        *color = TextColor(quit_text());
+                        *color = TextColor(palette.cancel.text);
                     }
                 }
             }
@@ -499,6 +636,7 @@ mod tests {
         app.insert_resource(ButtonInput::<KeyCode>::default());
         app.insert_resource(SelectedGameMode::Campaign);
         app.insert_resource(CampaignProgressionState::default());
+        app.insert_resource(WaveIntermissionShop::default());
         app.insert_resource(ShopReturnState::default());
         app.add_systems(Update, toggle_ore_shop_system);
 
@@ -531,4 +669,28 @@ mod tests {
         let next_state = app.world().resource::<NextState<GameState>>().clone();
         assert!(matches!(next_state, NextState::Pending(GameState::OreShop)));
     }
+    #[test]
+    fn campaign_tab_opens_discounted_shop_when_docked_at_beacon() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.insert_state(GameState::Playing);
+        app.insert_resource(ButtonInput::<KeyCode>::default());
+        app.insert_resource(SelectedGameMode::Campaign);
+        app.insert_resource(CampaignProgressionState::default());
+        app.insert_resource(WaveIntermissionShop {
+            docked: true,
+            ..Default::default()
+        });
+        app.insert_resource(ShopReturnState::default());
+        app.add_systems(Update, toggle_ore_shop_system);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(KeyCode::Tab);
+        app.update();
+
+        let next_state = app.world().resource::<NextState<GameState>>().clone();
+        assert!(matches!(next_state, NextState::Pending(GameState::OreShop)));
+        assert!(app.world().resource::<WaveIntermissionShop>().shopping);
+    }
 }
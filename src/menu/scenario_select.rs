@@ -1,4 +1,6 @@
 use super::*;
+use crate::save::leaderboard::LeaderboardStore;
+use crate::save::SaveScenario;
 
 /// Spawn the full-screen scenario / save selection UI.
 ///
@@ -11,12 +13,15 @@ use super::*;
 /// │   ┌─────────────────────────────────────┐     │
 /// │   │  FIELD                              │     │
 /// │   │  100 asteroids in noise clusters    │     │
+/// │   │  Best 1840 · largest 96 · 4m 12s    │     │
 /// │   └─────────────────────────────────────┘     │
 /// │   ┌─────────────────────────────────────┐     │
 /// │   │  ORBIT                              │     │
 /// │   │  Planetoid with orbital debris rings│     │
 /// │   └─────────────────────────────────────┘     │
 /// │                                               │
+/// │      Difficulty  [EASY] [NORMAL] [HARD]       │
+/// │                                               │
 /// │              [ BACK ]                         │
 /// └───────────────────────────────────────────────┘
 /// ```
@@ -25,6 +30,9 @@ fn setup_scenario_select(
     font: Res<GameFont>,
     unicode_font: Res<crate::graphics::UnicodeFallbackFont>,
     emoji_font: Res<crate::graphics::EmojiFont>,
+    difficulty: Res<Difficulty>,
+    leaderboard: Res<LeaderboardStore>,
+    palette: Res<Palette>,
 ) {
     commands
         .spawn((
@@ -174,6 +182,7 @@ fn setup_scenario_select(
                     },
                     TextColor(scenario_desc_color()),
                 ));
+                spawn_scenario_best(card, &leaderboard.board, SaveScenario::Field, &font);
             });
 
             spacer(root, 14.0);
@@ -246,6 +255,7 @@ fn setup_scenario_select(
                     },
                     TextColor(scenario_desc_color()),
                 ));
+                spawn_scenario_best(card, &leaderboard.board, SaveScenario::Orbit, &font);
             });
 
             spacer(root, 14.0);
@@ -318,6 +328,7 @@ fn setup_scenario_select(
                     },
                     TextColor(scenario_desc_color()),
                 ));
+                spawn_scenario_best(card, &leaderboard.board, SaveScenario::Comets, &font);
             });
 
             spacer(root, 14.0);
@@ -390,9 +401,291 @@ fn setup_scenario_select(
                     },
                     TextColor(scenario_desc_color()),
                 ));
+                spawn_scenario_best(card, &leaderboard.board, SaveScenario::Shower, &font);
             });
 
-            spacer(root, 36.0);
+            spacer(root, 14.0);
+
+            // ── SURVIVAL card ────────────────────────────────────────────────
+            root.spawn((
+                Button,
+                Node {
+                    width: Val::Px(460.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::FlexStart,
+                    padding: UiRect {
+                        left: Val::Px(22.0),
+                        right: Val::Px(22.0),
+                        top: Val::Px(18.0),
+                        bottom: Val::Px(18.0),
+                    },
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(scenario_card_bg()),
+                BorderColor::all(scenario_card_border()),
+                ScenarioSurvivalButton,
+            ))
+            .with_children(|card| {
+                card.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|label| {
+                    label.spawn((
+                        Text::new("☄️ "),
+                        TextFont {
+                            font: emoji_font.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                    ));
+                    label.spawn((
+                        Text::new("SURVIVAL"),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                    ));
+                    label.spawn((
+                        Text::new(" ☄️"),
+                        TextFont {
+                            font: emoji_font.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                    ));
+                });
+                spacer(card, 6.0);
+                card.spawn((
+                    Text::new(
+                        "Escalating timed waves of inbound comets and enemy ships.\n\
+                         Short breathers between waves; later waves multiply score.",
+                    ),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(scenario_desc_color()),
+                ));
+                spawn_scenario_best(card, &leaderboard.board, SaveScenario::Survival, &font);
+            });
+
+            spacer(root, 14.0);
+
+            // ── CATALOG card ─────────────────────────────────────────────────
+            root.spawn((
+                Button,
+                Node {
+                    width: Val::Px(460.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::FlexStart,
+                    padding: UiRect {
+                        left: Val::Px(22.0),
+                        right: Val::Px(22.0),
+                        top: Val::Px(18.0),
+                        bottom: Val::Px(18.0),
+                    },
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(scenario_card_bg()),
+                BorderColor::all(scenario_card_border()),
+                ScenarioCatalogButton,
+            ))
+            .with_children(|card| {
+                card.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|label| {
+                    label.spawn((
+                        Text::new("🔭 "),
+                        TextFont {
+                            font: emoji_font.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                    ));
+                    label.spawn((
+                        Text::new("CATALOG"),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                    ));
+                    label.spawn((
+                        Text::new(" 🔭"),
+                        TextFont {
+                            font: emoji_font.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                    ));
+                });
+                spacer(card, 6.0);
+                card.spawn((
+                    Text::new(
+                        "Real asteroid orbits from assets/small_bodies.csv,\n\
+                         projected onto the ecliptic around a central star.",
+                    ),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(scenario_desc_color()),
+                ));
+                spawn_scenario_best(card, &leaderboard.board, SaveScenario::Catalog, &font);
+            });
+
+            spacer(root, 14.0);
+
+            // ── FRONTIER card ────────────────────────────────────────────────
+            root.spawn((
+                Button,
+                Node {
+                    width: Val::Px(460.0),
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::FlexStart,
+                    padding: UiRect {
+                        left: Val::Px(22.0),
+                        right: Val::Px(22.0),
+                        top: Val::Px(18.0),
+                        bottom: Val::Px(18.0),
+                    },
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(scenario_card_bg()),
+                BorderColor::all(scenario_card_border()),
+                ScenarioFrontierButton,
+            ))
+            .with_children(|card| {
+                card.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    ..default()
+                })
+                .with_children(|label| {
+                    label.spawn((
+                        Text::new("🧭 "),
+                        TextFont {
+                            font: emoji_font.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                    ));
+                    label.spawn((
+                        Text::new("FRONTIER"),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                    ));
+                    label.spawn((
+                        Text::new(" 🧭"),
+                        TextFont {
+                            font: emoji_font.0.clone(),
+                            font_size: 22.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                    ));
+                });
+                spacer(card, 6.0);
+                card.spawn((
+                    Text::new(
+                        "An endless seeded field with no boundary.\n\
+                         New asteroid clusters appear as you explore.",
+                    ),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 13.0,
+                        ..default()
+                    },
+                    TextColor(scenario_desc_color()),
+                ));
+                spawn_scenario_best(card, &leaderboard.board, SaveScenario::Frontier, &font);
+            });
+
+            spacer(root, 28.0);
+
+            // ── Difficulty ───────────────────────────────────────────────────
+            root.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new("Difficulty"),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(subtitle_color()),
+                ));
+                for preset in Difficulty::ALL {
+                    let selected = preset == *difficulty;
+                    row.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(100.0),
+                            height: Val::Px(34.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(difficulty_bg(&palette, selected)),
+                        BorderColor::all(difficulty_border(&palette, selected)),
+                        DifficultyButton(preset),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(preset.label()),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 15.0,
+                                ..default()
+                            },
+                            TextColor(difficulty_text(&palette, selected)),
+                        ));
+                    });
+                }
+            });
+
+            spacer(root, 8.0);
+
+            root.spawn((
+                Text::new(difficulty.description()),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(scenario_desc_color()),
+                DifficultyDescriptionText,
+            ));
+
+            spacer(root, 28.0);
 
             // ── Back button ──────────────────────────────────────────────────
             root.spawn((
@@ -442,6 +735,7 @@ fn setup_scenario_select(
 }
 
 /// Spawn scenario select only after the required fonts are loaded.
+#[allow(clippy::too_many_arguments)]
 pub fn setup_scenario_select_when_fonts_ready(
     commands: Commands,
     font: Res<GameFont>,
@@ -449,6 +743,9 @@ pub fn setup_scenario_select_when_fonts_ready(
     emoji_font: Res<crate::graphics::EmojiFont>,
     loaded_fonts: Res<Assets<Font>>,
     existing_menu: Query<Entity, With<ScenarioSelectRoot>>,
+    difficulty: Res<Difficulty>,
+    leaderboard: Res<LeaderboardStore>,
+    palette: Res<Palette>,
 ) {
     if !existing_menu.is_empty() {
         return;
@@ -461,7 +758,15 @@ pub fn setup_scenario_select_when_fonts_ready(
         return;
     }
 
-    setup_scenario_select(commands, font, unicode_font, emoji_font);
+    setup_scenario_select(
+        commands,
+        font,
+        unicode_font,
+        emoji_font,
+        difficulty,
+        leaderboard,
+        palette,
+    );
 }
 
 /// Recursively despawn all scenario-select entities.
@@ -474,12 +779,15 @@ pub fn cleanup_scenario_select(
     }
 }
 
-/// Handle Field, Orbit, Comets, Shower, and Back button presses on the scenario-select screen.
+/// Handle Field, Orbit, Comets, Shower, Survival, Catalog, Frontier, and Back button presses on the scenario-select screen.
 ///
-/// - **Field**  → records [`SelectedScenario::Field`]  then transitions to [`GameState::Playing`].
-/// - **Orbit**  → records [`SelectedScenario::Orbit`]  then transitions to [`GameState::Playing`].
-/// - **Comets** → records [`SelectedScenario::Comets`] then transitions to [`GameState::Playing`].
-/// - **Shower** → records [`SelectedScenario::Shower`] then transitions to [`GameState::Playing`].
+/// - **Field**  → records [`SelectedScenario::Field`]  then transitions to [`GameState::Loading`].
+/// - **Orbit**  → records [`SelectedScenario::Orbit`]  then transitions to [`GameState::Loading`].
+/// - **Comets** → records [`SelectedScenario::Comets`] then transitions to [`GameState::Loading`].
+/// - **Shower** → records [`SelectedScenario::Shower`] then transitions to [`GameState::Loading`].
+/// - **Survival** → records [`SelectedScenario::Survival`] then transitions to [`GameState::Loading`].
+/// - **Catalog** → records [`SelectedScenario::Catalog`] then transitions to [`GameState::Loading`].
+/// - **Frontier** → records [`SelectedScenario::Frontier`] then transitions to [`GameState::Loading`].
 /// - **Back**   → returns to [`GameState::MainMenu`].
 #[allow(clippy::type_complexity)]
 #[allow(clippy::too_many_arguments)]
@@ -500,6 +808,18 @@ pub fn scenario_select_button_system(
         (&Interaction, &Children),
         (Changed<Interaction>, With<ScenarioShowerButton>),
     >,
+    survival_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<ScenarioSurvivalButton>),
+    >,
+    catalog_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<ScenarioCatalogButton>),
+    >,
+    frontier_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<ScenarioFrontierButton>),
+    >,
     back_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<ScenarioBackButton>)>,
     mut btn_text: Query<&mut TextColor>,
     child_nodes: Query<&Children>,
@@ -529,7 +849,7 @@ pub fn scenario_select_button_system(
             Interaction::Pressed => {
                 *selected_mode = SelectedGameMode::Practice;
                 *selected = SelectedScenario::Field;
-                next_state.set(GameState::Playing);
+                next_state.set(GameState::Loading);
             }
             Interaction::Hovered => {
                 set_descendant_text_color(
@@ -555,7 +875,7 @@ pub fn scenario_select_button_system(
             Interaction::Pressed => {
                 *selected_mode = SelectedGameMode::Practice;
                 *selected = SelectedScenario::Orbit;
-                next_state.set(GameState::Playing);
+                next_state.set(GameState::Loading);
             }
             Interaction::Hovered => {
                 set_descendant_text_color(
@@ -581,7 +901,7 @@ pub fn scenario_select_button_system(
             Interaction::Pressed => {
                 *selected_mode = SelectedGameMode::Practice;
                 *selected = SelectedScenario::Comets;
-                next_state.set(GameState::Playing);
+                next_state.set(GameState::Loading);
             }
             Interaction::Hovered => {
                 set_descendant_text_color(
@@ -607,7 +927,85 @@ pub fn scenario_select_button_system(
             Interaction::Pressed => {
                 *selected_mode = SelectedGameMode::Practice;
                 *selected = SelectedScenario::Shower;
-                next_state.set(GameState::Playing);
+                next_state.set(GameState::Loading);
+            }
+            Interaction::Hovered => {
+                set_descendant_text_color(
+                    children,
+                    scenario_active_text(),
+                    &mut btn_text,
+                    &child_nodes,
+                );
+            }
+            Interaction::None => {
+                set_descendant_text_color(
+                    children,
+                    scenario_label_color(),
+                    &mut btn_text,
+                    &child_nodes,
+                );
+            }
+        }
+    }
+
+    for (interaction, children) in survival_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                *selected_mode = SelectedGameMode::Practice;
+                *selected = SelectedScenario::Survival;
+                next_state.set(GameState::Loading);
+            }
+            Interaction::Hovered => {
+                set_descendant_text_color(
+                    children,
+                    scenario_active_text(),
+                    &mut btn_text,
+                    &child_nodes,
+                );
+            }
+            Interaction::None => {
+                set_descendant_text_color(
+                    children,
+                    scenario_label_color(),
+                    &mut btn_text,
+                    &child_nodes,
+                );
+            }
+        }
+    }
+
+    for (interaction, children) in catalog_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                *selected_mode = SelectedGameMode::Practice;
+                *selected = SelectedScenario::Catalog;
+                next_state.set(GameState::Loading);
+            }
+            Interaction::Hovered => {
+                set_descendant_text_color(
+                    children,
+                    scenario_active_text(),
+                    &mut btn_text,
+                    &child_nodes,
+                );
+            }
+            Interaction::None => {
+                set_descendant_text_color(
+                    children,
+                    scenario_label_color(),
+                    &mut btn_text,
+                    &child_nodes,
+                );
+            }
+        }
+    }
+
+    for (interaction, children) in frontier_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                *selected_mode = SelectedGameMode::Practice;
+                *selected = SelectedScenario::Frontier;
+                next_state.set(GameState::Loading);
             }
             Interaction::Hovered => {
                 set_descendant_text_color(
@@ -642,3 +1040,75 @@ pub fn scenario_select_button_system(
         }
     }
 }
+
+fn difficulty_bg(palette: &Palette, selected: bool) -> Color {
+    if selected {
+        palette.confirm.bg
+    } else {
+        back_bg()
+    }
+}
+
+fn difficulty_border(palette: &Palette, selected: bool) -> Color {
+    if selected {
+        palette.confirm.border
+    } else {
+        back_border()
+    }
+}
+
+fn difficulty_text(palette: &Palette, selected: bool) -> Color {
+    if selected {
+        palette.confirm.text
+    } else {
+        back_text()
+    }
+}
+
+/// Pick a difficulty preset and keep the buttons and description in step.
+///
+/// The preset applies to the next scenario started from this screen.
+#[allow(clippy::type_complexity)]
+pub fn difficulty_button_system(
+    mut q_buttons: Query<(
+        &Interaction,
+        &DifficultyButton,
+        &Children,
+        &mut BackgroundColor,
+        &mut BorderColor,
+    )>,
+    mut btn_text: Query<&mut TextColor>,
+    mut q_description: Query<&mut Text, With<DifficultyDescriptionText>>,
+    mut difficulty: ResMut<Difficulty>,
+    palette: Res<Palette>,
+) {
+    for (interaction, DifficultyButton(preset), ..) in q_buttons.iter() {
+        if *interaction == Interaction::Pressed && *difficulty != *preset {
+            *difficulty = *preset;
+        }
+    }
+
+    for (interaction, DifficultyButton(preset), children, mut bg, mut border) in
+        q_buttons.iter_mut()
+    {
+        let selected = *preset == *difficulty;
+        bg.set_if_neq(BackgroundColor(difficulty_bg(&palette, selected)));
+        border.set_if_neq(BorderColor::all(difficulty_border(&palette, selected)));
+        let text = if *interaction == Interaction::None {
+            difficulty_text(&palette, selected)
+        } else {
+            Color::WHITE
+        };
+        for child in children.iter() {
+            if let Ok(mut color) = btn_text.get_mut(child) {
+                color.set_if_neq(TextColor(text));
+            }
+        }
+    }
+
+    if difficulty.is_changed() {
+        for mut text in q_description.iter_mut() {
+            **text = difficulty.description().to_string();
+        }
+    }
+}
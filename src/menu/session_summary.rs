@@ -0,0 +1,257 @@
+use super::*;
+use crate::session_stats::{SessionStats, WeaponStats};
+
+/// Column widths (px) of the per-weapon table: weapon, shots, hits,
+/// accuracy, asteroids destroyed.
+const WEAPON_TABLE_COLUMNS: [f32; 5] = [110.0, 56.0, 56.0, 76.0, 84.0];
+
+/// `"73%"`, or `"—"` before the first shot.
+fn format_accuracy(accuracy: Option<f32>) -> String {
+    accuracy.map_or_else(|| "—".to_string(), |a| format!("{:.0}%", a * 100.0))
+}
+
+/// `"🌑 Boulder 0m 42s · Moonlet 3m 10s"`: when each size class first formed.
+fn size_class_line(stats: &SessionStats) -> String {
+    let reached: Vec<String> = stats
+        .size_classes_reached()
+        .map(|(class, at)| format!("{} {}", class.label(), format_playtime(at)))
+        .collect();
+    if reached.is_empty() {
+        "🌑 No body reached Boulder size".to_string()
+    } else {
+        format!("🌑 {}", reached.join("   ·   "))
+    }
+}
+
+/// Session totals over a per-weapon table, shared by the game-over overlay
+/// and the main menu's "Last session" card.
+pub(super) fn spawn_session_summary(
+    card: &mut ChildSpawnerCommands<'_>,
+    stats: &SessionStats,
+    font: &GameFont,
+) {
+    let lines = [
+        format!(
+            "⏱ Time survived {}   ·   ⛏ Ore mined {}",
+            format_playtime(stats.time_survived_secs),
+            stats.ore_mined
+        ),
+        format!(
+            "☄ Asteroids destroyed {}   ·   merged {}   ·   largest body {}",
+            stats.asteroids_destroyed(),
+            stats.asteroids_merged,
+            stats.largest_body
+        ),
+        format!(
+            "🎯 Accuracy {}   ·   💥 Damage taken {:.0}   ·   ships lost {}",
+            format_accuracy(stats.accuracy()),
+            stats.damage_taken,
+            stats.ships_lost
+        ),
+        size_class_line(stats),
+    ];
+    for line in lines {
+        card.spawn((
+            Text::new(line),
+            TextFont {
+                font: font.0.clone(),
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(scenario_desc_color()),
+        ));
+    }
+
+    let mut weapons = stats.used_weapons().peekable();
+    if weapons.peek().is_none() {
+        card.spawn((
+            Text::new("No weapons fired"),
+            TextFont {
+                font: font.0.clone(),
+                font_size: 12.0,
+                ..default()
+            },
+            TextColor(subtitle_color()),
+        ));
+        return;
+    }
+
+    card.spawn(Node {
+        flex_direction: FlexDirection::Column,
+        margin: UiRect::top(Val::Px(4.0)),
+        row_gap: Val::Px(2.0),
+        ..default()
+    })
+    .with_children(|table| {
+        spawn_weapon_row(
+            table,
+            font,
+            ["WEAPON", "SHOTS", "HITS", "ACCURACY", "DESTROYED"].map(String::from),
+            subtitle_color(),
+        );
+        for (weapon, weapon_stats) in weapons {
+            spawn_weapon_row(
+                table,
+                font,
+                weapon_row_cells(weapon.label(), weapon_stats),
+                scenario_active_text(),
+            );
+        }
+    });
+}
+
+/// Table cells for one weapon; beam weapons show no shot columns.
+fn weapon_row_cells(label: &str, stats: &WeaponStats) -> [String; 5] {
+    let (shots, hits) = if stats.shots == 0 {
+        ("—".to_string(), "—".to_string())
+    } else {
+        (
+            stats.shots.to_string(),
+            stats.hits.min(stats.shots).to_string(),
+        )
+    };
+    [
+        label.to_string(),
+        shots,
+        hits,
+        format_accuracy(stats.accuracy()),
+        stats.asteroids_destroyed.to_string(),
+    ]
+}
+
+fn spawn_weapon_row(
+    table: &mut ChildSpawnerCommands<'_>,
+    font: &GameFont,
+    cells: [String; 5],
+    color: Color,
+) {
+    table
+        .spawn(Node {
+            flex_direction: FlexDirection::Row,
+            ..default()
+        })
+        .with_children(|row| {
+            for (cell, width) in cells.into_iter().zip(WEAPON_TABLE_COLUMNS) {
+                row.spawn((
+                    Node {
+                        width: Val::Px(width),
+                        ..default()
+                    },
+                    Text::new(cell),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 12.0,
+                        ..default()
+                    },
+                    TextColor(color),
+                ));
+            }
+        });
+}
+
+/// "Last session" card the main menu shows after quitting from the pause menu.
+pub(super) fn spawn_session_summary_card(
+    root: &mut ChildSpawnerCommands<'_>,
+    font: &GameFont,
+    stats: &SessionStats,
+) {
+    root.spawn((
+        Node {
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(14.0)),
+            row_gap: Val::Px(6.0),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(scenario_card_bg()),
+        BorderColor::all(scenario_card_border()),
+        SessionSummaryCard,
+    ))
+    .with_children(|card| {
+        card.spawn((
+            Text::new("Last session"),
+            TextFont {
+                font: font.0.clone(),
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(scenario_active_text()),
+        ));
+        spawn_session_summary(card, stats, font);
+        card.spawn((
+            Button,
+            Node {
+                width: Val::Px(130.0),
+                height: Val::Px(36.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                border: UiRect::all(Val::Px(2.0)),
+                margin: UiRect::top(Val::Px(6.0)),
+                ..default()
+            },
+            BackgroundColor(back_bg()),
+            BorderColor::all(back_border()),
+            SessionSummaryDismissButton,
+        ))
+        .with_children(|btn| {
+            btn.spawn((
+                Text::new("DISMISS"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 15.0,
+                    ..default()
+                },
+                TextColor(back_text()),
+            ));
+        });
+    });
+}
+
+/// Ask the main menu to show the summary of the session being quit.
+pub(super) fn request_session_summary(mut pending: ResMut<SessionSummaryPending>) {
+    pending.0 = true;
+}
+
+/// Remove the "Last session" card when its Dismiss button is pressed.
+#[allow(clippy::type_complexity)]
+pub(super) fn session_summary_dismiss_system(
+    mut commands: Commands,
+    dismiss_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<SessionSummaryDismissButton>),
+    >,
+    card_query: Query<Entity, With<SessionSummaryCard>>,
+    mut btn_text: Query<&mut TextColor>,
+    mut pending: ResMut<SessionSummaryPending>,
+) {
+    for (interaction, children) in dismiss_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                pending.0 = false;
+                for entity in card_query.iter() {
+                    commands.entity(entity).despawn();
+                }
+            }
+            Interaction::Hovered => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(Color::WHITE);
+                    }
+                }
+            }
+            Interaction::None => {
+                for child in children.iter() {
+                    if let Ok(mut color) = btn_text.get_mut(child) {
+                        *color = TextColor(back_text());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The card belongs to one visit of the main menu.
+pub(super) fn clear_session_summary_request(mut pending: ResMut<SessionSummaryPending>) {
+    pending.0 = false;
+}
@@ -0,0 +1,846 @@
+use super::*;
+use crate::audio::{AudioChannel, AudioSettings};
+use crate::difficulty::{Assist, AssistOptions};
+use crate::graphics::palette::{AccessibilitySettings, UI_SCALE_STEP};
+use crate::rendering::OverlayState;
+
+/// Volume change per slider button press.
+const VOLUME_STEP: f32 = 0.1;
+
+/// Width of a volume slider's track (px).
+const VOLUME_TRACK_WIDTH: f32 = 200.0;
+
+fn volume_fill_color() -> Color {
+    Color::srgb(0.30, 0.62, 1.0)
+}
+
+fn volume_track_bg() -> Color {
+    Color::srgb(0.08, 0.10, 0.16)
+}
+
+/// Spawn the settings screen.
+///
+/// Layout:
+/// ```text
+/// ┌─────────────────────────────────────────────┐
+/// │               ✦ SETTINGS ✦                  │
+/// │                   Audio                     │
+/// │   MASTER   [−] ██████████░░░ [+]   80%      │
+/// │   EFFECTS  [−] ██████████░░░ [+]   80%      │
+/// │   MUSIC    [−] ██████░░░░░░░ [+]   50%      │
+/// │                  Display                    │
+/// │   HOVER TOOLTIP          [ OFF ]            │
+/// │   SESSION CLOCK          [ OFF ]            │
+/// │   SPEEDRUN MODE          [ OFF ]            │
+/// │                Accessibility                │
+/// │   PALETTE            [ STANDARD ]           │
+/// │   UI SCALE         [−]  100%  [+]           │
+/// │                  Assists                    │
+/// │   ENEMY SHOT SPEED       [ OFF ]            │
+/// │   ENEMY FIRE RATE        [ 75% ]            │
+/// │   DAMAGE TAKEN           [ OFF ]            │
+/// │                  Storage                    │
+/// │        Saves use 1.2 MB of 50 MB            │
+/// │   AUTOSAVE               [PRUNE]            │
+/// │   ISSUE REPORTS          [PRUNE]            │
+/// │                 [ BACK ]                    │
+/// └─────────────────────────────────────────────┘
+/// ```
+pub fn setup_settings_menu(
+    mut commands: Commands,
+    font: Res<GameFont>,
+    settings: Res<AudioSettings>,
+    overlay: Res<OverlayState>,
+    assists: Res<AssistOptions>,
+    accessibility: Res<AccessibilitySettings>,
+    config: Res<PhysicsConfig>,
+) {
+    let usage = StorageUsage::scan_default();
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                flex_direction: FlexDirection::Column,
+                ..default()
+            },
+            BackgroundColor(Color::BLACK),
+            SettingsRoot,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Text::new("✦ SETTINGS ✦"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 42.0,
+                    ..default()
+                },
+                TextColor(title_color()),
+            ));
+
+            spacer(root, 10.0);
+
+            root.spawn((
+                Text::new("Audio"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(subtitle_color()),
+            ));
+
+            spacer(root, 24.0);
+
+            for channel in AudioChannel::ALL {
+                let volume = settings.get(channel);
+                root.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    margin: UiRect::vertical(Val::Px(8.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(channel.label()),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                        Node {
+                            width: Val::Px(90.0),
+                            ..default()
+                        },
+                    ));
+
+                    volume_step_button(row, &font, channel, -VOLUME_STEP, "−");
+
+                    row.spawn((
+                        Node {
+                            width: Val::Px(VOLUME_TRACK_WIDTH),
+                            height: Val::Px(14.0),
+                            border: UiRect::all(Val::Px(1.0)),
+                            ..default()
+                        },
+                        BackgroundColor(volume_track_bg()),
+                        BorderColor::all(pause_debug_border()),
+                    ))
+                    .with_children(|track| {
+                        track.spawn((
+                            Node {
+                                width: Val::Percent(volume * 100.0),
+                                height: Val::Percent(100.0),
+                                ..default()
+                            },
+                            BackgroundColor(volume_fill_color()),
+                            VolumeBarFill(channel),
+                        ));
+                    });
+
+                    volume_step_button(row, &font, channel, VOLUME_STEP, "+");
+
+                    row.spawn((
+                        Text::new(format!("{:>3.0}%", volume * 100.0)),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(pause_debug_text()),
+                        Node {
+                            width: Val::Px(54.0),
+                            ..default()
+                        },
+                        VolumeValueText(channel),
+                    ));
+                });
+            }
+
+            spacer(root, 20.0);
+
+            root.spawn((
+                Text::new("Display"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(subtitle_color()),
+            ));
+
+            spacer(root, 12.0);
+
+            for toggle in DisplayToggle::ALL {
+                root.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    margin: UiRect::vertical(Val::Px(4.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(toggle.label()),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                        Node {
+                            width: Val::Px(200.0),
+                            ..default()
+                        },
+                    ));
+                    row.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(80.0),
+                            height: Val::Px(32.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(pause_debug_bg()),
+                        BorderColor::all(pause_debug_border()),
+                        DisplayToggleButton(toggle),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(on_off_label(toggle.get(&overlay))),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(pause_debug_text()),
+                        ));
+                    });
+                });
+            }
+
+            spacer(root, 20.0);
+
+            root.spawn((
+                Text::new("Accessibility"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(subtitle_color()),
+            ));
+
+            spacer(root, 12.0);
+
+            root.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                margin: UiRect::vertical(Val::Px(4.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new("PALETTE"),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(scenario_label_color()),
+                    Node {
+                        width: Val::Px(160.0),
+                        ..default()
+                    },
+                ));
+                row.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(120.0),
+                        height: Val::Px(32.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        border: UiRect::all(Val::Px(2.0)),
+                        ..default()
+                    },
+                    BackgroundColor(pause_debug_bg()),
+                    BorderColor::all(pause_debug_border()),
+                    PaletteButton,
+                ))
+                .with_children(|btn| {
+                    btn.spawn((
+                        Text::new(accessibility.palette.label()),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(pause_debug_text()),
+                    ));
+                });
+            });
+
+            root.spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                margin: UiRect::vertical(Val::Px(4.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new("UI SCALE"),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(scenario_label_color()),
+                    Node {
+                        width: Val::Px(160.0),
+                        ..default()
+                    },
+                ));
+                ui_scale_step_button(row, &font, -UI_SCALE_STEP, "−");
+                row.spawn((
+                    Text::new(ui_scale_label(accessibility.ui_scale)),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(pause_debug_text()),
+                    Node {
+                        width: Val::Px(48.0),
+                        justify_content: JustifyContent::Center,
+                        ..default()
+                    },
+                    UiScaleValueText,
+                ));
+                ui_scale_step_button(row, &font, UI_SCALE_STEP, "+");
+            });
+
+            spacer(root, 20.0);
+
+            root.spawn((
+                Text::new("Assists"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(subtitle_color()),
+            ));
+
+            spacer(root, 12.0);
+
+            for assist in Assist::ALL {
+                root.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    margin: UiRect::vertical(Val::Px(4.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(assist.label()),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                        Node {
+                            width: Val::Px(200.0),
+                            ..default()
+                        },
+                    ));
+                    row.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(80.0),
+                            height: Val::Px(32.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(pause_debug_bg()),
+                        BorderColor::all(pause_debug_border()),
+                        AssistButton(assist),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new(assist_level_label(assist.get(&assists))),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(pause_debug_text()),
+                        ));
+                    });
+                });
+            }
+
+            spacer(root, 12.0);
+
+            root.spawn((
+                Text::new("Assisted runs are marked on the leaderboard"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(hint_color()),
+            ));
+
+            spacer(root, 20.0);
+
+            root.spawn((
+                Text::new("Storage"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(subtitle_color()),
+            ));
+
+            spacer(root, 8.0);
+
+            root.spawn((
+                Text::new(storage_usage_label(&usage, &config)),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(storage_usage_color(&usage, &config)),
+                StorageUsageText,
+            ));
+
+            spacer(root, 8.0);
+
+            for target in PruneTarget::ALL {
+                root.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(10.0),
+                    margin: UiRect::vertical(Val::Px(4.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(target.label()),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(scenario_label_color()),
+                        Node {
+                            width: Val::Px(200.0),
+                            ..default()
+                        },
+                    ));
+                    row.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(80.0),
+                            height: Val::Px(32.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            border: UiRect::all(Val::Px(2.0)),
+                            ..default()
+                        },
+                        BackgroundColor(pause_debug_bg()),
+                        BorderColor::all(pause_debug_border()),
+                        StoragePruneButton(target),
+                    ))
+                    .with_children(|btn| {
+                        btn.spawn((
+                            Text::new("PRUNE"),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 16.0,
+                                ..default()
+                            },
+                            TextColor(pause_debug_text()),
+                        ));
+                    });
+                });
+            }
+
+            spacer(root, 8.0);
+
+            root.spawn((
+                Text::new(format!(
+                    "Pruning keeps the newest {} of each kind of file; save slots are never pruned",
+                    config.storage_prune_keep
+                )),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(hint_color()),
+            ));
+
+            spacer(root, 28.0);
+
+            root.spawn((
+                Button,
+                Node {
+                    width: Val::Px(180.0),
+                    height: Val::Px(44.0),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    border: UiRect::all(Val::Px(2.0)),
+                    ..default()
+                },
+                BackgroundColor(back_bg()),
+                BorderColor::all(back_border()),
+                SettingsBackButton,
+            ))
+            .with_children(|btn| {
+                btn.spawn((
+                    Text::new("✦ BACK ✦"),
+                    TextFont {
+                        font: font.0.clone(),
+                        font_size: 16.0,
+                        ..default()
+                    },
+                    TextColor(back_text()),
+                ));
+            });
+
+            spacer(root, 16.0);
+
+            root.spawn((
+                Text::new("Volumes reset to physics.toml defaults and assists turn off on restart"),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(hint_color()),
+            ));
+        });
+}
+
+/// "Saves use 1.2 MB of 50 MB", with a warning once over the quota.
+fn storage_usage_label(usage: &StorageUsage, config: &PhysicsConfig) -> String {
+    let total = format_bytes(usage.total_bytes());
+    if config.save_storage_quota_mb <= 0.0 {
+        format!("Saves use {total}")
+    } else if usage.over_quota(config.save_storage_quota_mb) {
+        format!(
+            "⚠ Saves use {total}, over the {:.0} MB quota",
+            config.save_storage_quota_mb
+        )
+    } else {
+        format!(
+            "Saves use {total} of {:.0} MB",
+            config.save_storage_quota_mb
+        )
+    }
+}
+
+fn storage_usage_color(usage: &StorageUsage, config: &PhysicsConfig) -> Color {
+    if usage.over_quota(config.save_storage_quota_mb) {
+        storage_warning_color()
+    } else {
+        pause_debug_text()
+    }
+}
+
+fn on_off_label(on: bool) -> &'static str {
+    if on {
+        "ON"
+    } else {
+        "OFF"
+    }
+}
+
+/// `OFF` at full strength, otherwise the level as a percentage.
+fn assist_level_label(level: f32) -> String {
+    if level >= 1.0 {
+        "OFF".to_string()
+    } else {
+        format!("{:.0}%", level * 100.0)
+    }
+}
+
+fn ui_scale_label(scale: f32) -> String {
+    format!("{:.0}%", scale * 100.0)
+}
+
+fn ui_scale_step_button(
+    row: &mut ChildSpawnerCommands<'_>,
+    font: &GameFont,
+    step: f32,
+    label: &str,
+) {
+    row.spawn((
+        Button,
+        Node {
+            width: Val::Px(32.0),
+            height: Val::Px(32.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(pause_debug_bg()),
+        BorderColor::all(pause_debug_border()),
+        UiScaleStepButton { step },
+    ))
+    .with_children(|btn| {
+        btn.spawn((
+            Text::new(label),
+            TextFont {
+                font: font.0.clone(),
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(pause_debug_text()),
+        ));
+    });
+}
+
+fn volume_step_button(
+    row: &mut ChildSpawnerCommands<'_>,
+    font: &GameFont,
+    channel: AudioChannel,
+    step: f32,
+    label: &str,
+) {
+    row.spawn((
+        Button,
+        Node {
+            width: Val::Px(32.0),
+            height: Val::Px(32.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(pause_debug_bg()),
+        BorderColor::all(pause_debug_border()),
+        VolumeStepButton { channel, step },
+    ))
+    .with_children(|btn| {
+        btn.spawn((
+            Text::new(label),
+            TextFont {
+                font: font.0.clone(),
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(pause_debug_text()),
+        ));
+    });
+}
+
+pub fn cleanup_settings_menu(mut commands: Commands, query: Query<Entity, With<SettingsRoot>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Handle volume step, display toggle, assist and Back button presses.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn settings_button_system(
+    step_query: Query<(&Interaction, &Children, &VolumeStepButton), Changed<Interaction>>,
+    toggle_query: Query<(&Interaction, &Children, &DisplayToggleButton), Changed<Interaction>>,
+    assist_query: Query<(&Interaction, &Children, &AssistButton), Changed<Interaction>>,
+    back_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<SettingsBackButton>)>,
+    mut btn_text: Query<(&mut TextColor, &mut Text)>,
+    mut settings: ResMut<AudioSettings>,
+    mut overlay: ResMut<OverlayState>,
+    mut assists: ResMut<AssistOptions>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (interaction, children, button) in step_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                settings.adjust(button.channel, button.step);
+            }
+            Interaction::Hovered => {
+                for child in children.iter() {
+                    if let Ok((mut color, _)) = btn_text.get_mut(child) {
+                        *color = TextColor(Color::WHITE);
+                    }
+                }
+            }
+            Interaction::None => {
+                for child in children.iter() {
+                    if let Ok((mut color, _)) = btn_text.get_mut(child) {
+                        *color = TextColor(pause_debug_text());
+                    }
+                }
+            }
+        }
+    }
+
+    for (interaction, children, DisplayToggleButton(toggle)) in toggle_query.iter() {
+        if *interaction == Interaction::Pressed {
+            toggle.flip(&mut overlay);
+        }
+        for child in children.iter() {
+            if let Ok((mut color, mut text)) = btn_text.get_mut(child) {
+                **text = on_off_label(toggle.get(&overlay)).to_string();
+                *color = TextColor(if *interaction == Interaction::None {
+                    pause_debug_text()
+                } else {
+                    Color::WHITE
+                });
+            }
+        }
+    }
+
+    for (interaction, children, AssistButton(assist)) in assist_query.iter() {
+        if *interaction == Interaction::Pressed {
+            assist.cycle(&mut assists);
+        }
+        for child in children.iter() {
+            if let Ok((mut color, mut text)) = btn_text.get_mut(child) {
+                **text = assist_level_label(assist.get(&assists));
+                *color = TextColor(if *interaction == Interaction::None {
+                    pause_debug_text()
+                } else {
+                    Color::WHITE
+                });
+            }
+        }
+    }
+
+    for (interaction, children) in back_query.iter() {
+        match interaction {
+            Interaction::Pressed => {
+                next_state.set(GameState::MainMenu);
+            }
+            Interaction::Hovered => {
+                for child in children.iter() {
+                    if let Ok((mut color, _)) = btn_text.get_mut(child) {
+                        *color = TextColor(Color::WHITE);
+                    }
+                }
+            }
+            Interaction::None => {
+                for child in children.iter() {
+                    if let Ok((mut color, _)) = btn_text.get_mut(child) {
+                        *color = TextColor(back_text());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Redraw slider fills and percentages when a volume changes.
+pub fn settings_volume_display_system(
+    settings: Res<AudioSettings>,
+    mut fills: Query<(&VolumeBarFill, &mut Node)>,
+    mut labels: Query<(&VolumeValueText, &mut Text)>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for (fill, mut node) in fills.iter_mut() {
+        node.width = Val::Percent(settings.get(fill.0) * 100.0);
+    }
+    for (label, mut text) in labels.iter_mut() {
+        **text = format!("{:>3.0}%", settings.get(label.0) * 100.0);
+    }
+}
+
+/// Cycle the palette and step the UI scale, refreshing their labels.
+#[allow(clippy::type_complexity)]
+pub fn settings_accessibility_system(
+    palette_query: Query<(&Interaction, &Children), (Changed<Interaction>, With<PaletteButton>)>,
+    step_query: Query<(&Interaction, &Children, &UiScaleStepButton), Changed<Interaction>>,
+    mut btn_text: Query<(&mut TextColor, &mut Text), Without<UiScaleValueText>>,
+    mut scale_text: Query<&mut Text, With<UiScaleValueText>>,
+    mut accessibility: ResMut<AccessibilitySettings>,
+) {
+    for (interaction, children) in palette_query.iter() {
+        if *interaction == Interaction::Pressed {
+            accessibility.palette = accessibility.palette.next();
+        }
+        for child in children.iter() {
+            if let Ok((mut color, mut text)) = btn_text.get_mut(child) {
+                **text = accessibility.palette.label().to_string();
+                *color = TextColor(if *interaction == Interaction::None {
+                    pause_debug_text()
+                } else {
+                    Color::WHITE
+                });
+            }
+        }
+    }
+
+    for (interaction, children, button) in step_query.iter() {
+        if *interaction == Interaction::Pressed {
+            accessibility.adjust_ui_scale(button.step);
+            for mut text in scale_text.iter_mut() {
+                **text = ui_scale_label(accessibility.ui_scale);
+            }
+        }
+        for child in children.iter() {
+            if let Ok((mut color, _)) = btn_text.get_mut(child) {
+                *color = TextColor(if *interaction == Interaction::None {
+                    pause_debug_text()
+                } else {
+                    Color::WHITE
+                });
+            }
+        }
+    }
+}
+
+/// Prune the pressed target's files and refresh the usage line.
+#[allow(clippy::type_complexity)]
+pub fn settings_storage_system(
+    prune_query: Query<(&Interaction, &Children, &StoragePruneButton), Changed<Interaction>>,
+    mut btn_text: Query<&mut TextColor, Without<StorageUsageText>>,
+    mut usage_text: Query<(&mut Text, &mut TextColor), With<StorageUsageText>>,
+    config: Res<PhysicsConfig>,
+) {
+    for (interaction, children, StoragePruneButton(target)) in prune_query.iter() {
+        if *interaction == Interaction::Pressed {
+            let mut usage = StorageUsage::scan_default();
+            match usage.prune(*target, config.storage_prune_keep as usize) {
+                Ok(outcome) => info!(
+                    "Pruned {}: {} files, {}",
+                    target.label(),
+                    outcome.files,
+                    format_bytes(outcome.bytes)
+                ),
+                Err(err) => warn!("Pruning {} failed: {err}", target.label()),
+            }
+            for (mut text, mut color) in usage_text.iter_mut() {
+                **text = storage_usage_label(&usage, &config);
+                *color = TextColor(storage_usage_color(&usage, &config));
+            }
+        }
+        for child in children.iter() {
+            if let Ok(mut color) = btn_text.get_mut(child) {
+                *color = TextColor(if *interaction == Interaction::None {
+                    pause_debug_text()
+                } else {
+                    Color::WHITE
+                });
+            }
+        }
+    }
+}
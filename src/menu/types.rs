@@ -1,6 +1,11 @@
 use bevy::prelude::*;
 
+use crate::audio::AudioChannel;
+use crate::difficulty::{Assist, Difficulty};
+use crate::mining::{Currency, OreKind};
 use crate::player::{CampaignPrimaryWeapon, CampaignSecondaryWeapon};
+use crate::rendering::OverlayState;
+use crate::save::storage::PruneTarget;
 
 /// Top-level application state machine.
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Default)]
@@ -10,10 +15,15 @@ pub enum GameState {
     MainMenu,
     /// Load game slot picker shown from MainMenu.
     LoadGameMenu,
+    /// Settings screen (audio volumes) shown from MainMenu.
+    Settings,
     /// Practice scenario picker shown when starting Practice mode.
     ScenarioSelect,
     /// Campaign slot picker and naming screen shown when starting Campaign mode.
     CampaignSelect,
+    /// Between ScenarioSelect and Playing: waits for fonts, audio and config,
+    /// pre-builds particle and shot pools, and shows a scenario tip.
+    Loading,
     /// Active simulation / gameplay.
     Playing,
     /// Simulation frozen; in-game pause overlay is visible.
@@ -44,6 +54,12 @@ pub enum SelectedScenario {
     Comets,
     /// Dense small-body outer shower with inward rain trajectories.
     Shower,
+    /// Light field under escalating timed comet and enemy waves.
+    Survival,
+    /// Real small-body orbital elements projected around a central star.
+    Catalog,
+    /// Endless seeded field generated chunk by chunk as the ship explores.
+    Frontier,
 }
 
 /// Active top-level gameplay mode selected from the main menu.
@@ -72,10 +88,39 @@ pub struct MenuCampaignButton;
 #[derive(Component)]
 pub struct MenuLoadButton;
 
+/// Tags the "Settings" button.
+#[derive(Component)]
+pub struct MenuSettingsButton;
+
 /// Tags the "Quit" button.
 #[derive(Component)]
 pub struct MenuQuitButton;
 
+/// "Resume last session?" card shown after an unclean exit.
+#[derive(Component)]
+pub struct CrashRecoveryPrompt;
+
+/// Tags the crash prompt's "Resume" button.
+#[derive(Component)]
+pub struct CrashRecoveryResumeButton;
+
+/// Tags the crash prompt's "Discard" button.
+#[derive(Component)]
+pub struct CrashRecoveryDiscardButton;
+
+/// Set when a session is quit from the pause menu: the main menu then shows
+/// the "Last session" summary card until it is dismissed or the menu is left.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SessionSummaryPending(pub bool);
+
+/// "Last session" summary card on the main menu.
+#[derive(Component)]
+pub struct SessionSummaryCard;
+
+/// Tags the summary card's "Dismiss" button.
+#[derive(Component)]
+pub struct SessionSummaryDismissButton;
+
 /// Root node of the load-game screen; despawned on `OnExit(LoadGameMenu)`.
 #[derive(Component)]
 pub struct LoadGameRoot;
@@ -96,6 +141,145 @@ pub struct LoadSlot3Button;
 #[derive(Component)]
 pub struct LoadGameBackButton;
 
+/// Tags the "Details" button next to a load card; holds the slot.
+#[derive(Component)]
+pub struct LoadSlotDetailsButton(pub u8);
+
+/// Tags a load card's collapsible details panel; holds the slot.
+#[derive(Component)]
+pub struct LoadSlotDetailsPanel(pub u8);
+
+/// Tags the "Export" button next to a load card; holds the slot.
+#[derive(Component)]
+pub struct LoadSlotExportButton(pub u8);
+
+/// Tags the "Import" button on the load-game screen.
+#[derive(Component)]
+pub struct LoadGameImportButton;
+
+/// Tags the load screen's export/import status line.
+#[derive(Component)]
+pub struct LoadGameStatusText;
+
+/// Root node of the settings screen; despawned on `OnExit(Settings)`.
+#[derive(Component)]
+pub struct SettingsRoot;
+
+/// A volume slider's step button: adds `step` to `channel` when pressed.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VolumeStepButton {
+    pub channel: AudioChannel,
+    pub step: f32,
+}
+
+/// Fill bar of a volume slider; its width is the channel's volume.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VolumeBarFill(pub AudioChannel);
+
+/// Percentage readout next to a volume slider.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct VolumeValueText(pub AudioChannel);
+
+/// Display options on the settings screen, each an `OverlayState` flag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayToggle {
+    HoverTooltip,
+    MotionTrails,
+    ReduceMotion,
+    SessionClock,
+    SpeedrunMode,
+    StreamerMode,
+    WebcamCorner,
+    EdgeHud,
+}
+
+impl DisplayToggle {
+    pub const ALL: [DisplayToggle; 8] = [
+        DisplayToggle::HoverTooltip,
+        DisplayToggle::MotionTrails,
+        DisplayToggle::ReduceMotion,
+        DisplayToggle::SessionClock,
+        DisplayToggle::SpeedrunMode,
+        DisplayToggle::StreamerMode,
+        DisplayToggle::WebcamCorner,
+        DisplayToggle::EdgeHud,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::HoverTooltip => "HOVER TOOLTIP",
+            Self::MotionTrails => "MOTION TRAILS",
+            Self::ReduceMotion => "REDUCE MOTION",
+            Self::SessionClock => "SESSION CLOCK",
+            Self::SpeedrunMode => "SPEEDRUN MODE",
+            Self::StreamerMode => "STREAMER MODE",
+            Self::WebcamCorner => "WEBCAM CORNER",
+            Self::EdgeHud => "EDGE HUD",
+        }
+    }
+
+    pub fn get(self, overlay: &OverlayState) -> bool {
+        match self {
+            Self::HoverTooltip => overlay.show_hover_tooltip,
+            Self::MotionTrails => overlay.show_motion_trails,
+            Self::ReduceMotion => overlay.reduce_motion,
+            Self::SessionClock => overlay.show_session_clock,
+            Self::SpeedrunMode => overlay.speedrun_mode,
+            Self::StreamerMode => overlay.streamer_mode,
+            Self::WebcamCorner => overlay.webcam_corner,
+            Self::EdgeHud => overlay.hud_edges,
+        }
+    }
+
+    pub fn flip(self, overlay: &mut OverlayState) {
+        let flag = match self {
+            Self::HoverTooltip => &mut overlay.show_hover_tooltip,
+            Self::MotionTrails => &mut overlay.show_motion_trails,
+            Self::ReduceMotion => &mut overlay.reduce_motion,
+            Self::SessionClock => &mut overlay.show_session_clock,
+            Self::SpeedrunMode => &mut overlay.speedrun_mode,
+            Self::StreamerMode => &mut overlay.streamer_mode,
+            Self::WebcamCorner => &mut overlay.webcam_corner,
+            Self::EdgeHud => &mut overlay.hud_edges,
+        };
+        *flag = !*flag;
+    }
+}
+
+/// Settings-screen button that flips one [`DisplayToggle`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DisplayToggleButton(pub DisplayToggle);
+
+/// Settings-screen button that steps one [`Assist`] to its next level.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AssistButton(pub Assist);
+
+/// Settings-screen button that cycles the colour palette.
+#[derive(Component)]
+pub struct PaletteButton;
+
+/// Settings-screen UI scale button: adds `step` to the scale when pressed.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct UiScaleStepButton {
+    pub step: f32,
+}
+
+/// Percentage readout of the UI scale.
+#[derive(Component)]
+pub struct UiScaleValueText;
+
+/// Settings-screen button that deletes one [`PruneTarget`]'s files.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct StoragePruneButton(pub PruneTarget);
+
+/// Settings-screen line showing save storage use against the quota.
+#[derive(Component)]
+pub struct StorageUsageText;
+
+/// Tags the "Back" button on the settings screen.
+#[derive(Component)]
+pub struct SettingsBackButton;
+
 /// Root node of the scenario-select screen; despawned on `OnExit(ScenarioSelect)`.
 #[derive(Component)]
 pub struct ScenarioSelectRoot;
@@ -104,6 +288,20 @@ pub struct ScenarioSelectRoot;
 #[derive(Component)]
 pub struct CampaignSelectRoot;
 
+/// Root node of the loading screen; despawned on `OnExit(Loading)`.
+#[derive(Component)]
+pub struct LoadingScreenRoot;
+
+/// Status line on the loading screen naming the step still pending.
+#[derive(Component)]
+pub struct LoadingScreenStatus;
+
+/// Time spent on the current loading screen; reset on `OnEnter(Loading)`.
+#[derive(Resource, Debug, Default)]
+pub struct LoadingScreenTimer {
+    pub elapsed: f32,
+}
+
 /// Tags the "Field" scenario button.
 #[derive(Component)]
 pub struct ScenarioFieldButton;
@@ -120,10 +318,30 @@ pub struct ScenarioCometButton;
 #[derive(Component)]
 pub struct ScenarioShowerButton;
 
+/// Tags the "Survival" scenario button.
+#[derive(Component)]
+pub struct ScenarioSurvivalButton;
+
+/// Tags the "Catalog" scenario button.
+#[derive(Component)]
+pub struct ScenarioCatalogButton;
+
+/// Tags the "Frontier" scenario button.
+#[derive(Component)]
+pub struct ScenarioFrontierButton;
+
 /// Tags the "Back" button on the scenario-select screen.
 #[derive(Component)]
 pub struct ScenarioBackButton;
 
+/// Scenario-select button that picks one [`Difficulty`] preset.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct DifficultyButton(pub Difficulty);
+
+/// Line under the difficulty buttons describing the selected preset.
+#[derive(Component)]
+pub struct DifficultyDescriptionText;
+
 /// Tags the campaign slot 1 button.
 #[derive(Component)]
 pub struct CampaignSlot1Button;
@@ -212,6 +430,14 @@ pub struct PauseSaveSlot2Button;
 #[derive(Component)]
 pub struct PauseSaveSlot3Button;
 
+/// Tags the "COPY RUN INFO" button in the pause menu.
+#[derive(Component)]
+pub struct PauseCopyRunInfoButton;
+
+/// Tags the "REPORT ISSUE" button in the pause menu.
+#[derive(Component)]
+pub struct PauseReportIssueButton;
+
 /// Root node of the ore shop overlay; despawned when the shop is closed.
 #[derive(Component)]
 pub struct OreShopRoot;
@@ -232,6 +458,11 @@ pub struct OreShopCloseButton;
 #[derive(Component)]
 pub struct OreShopOreText;
 
+/// Tags a refinery button in the ore shop; pressing it queues all raw ore of
+/// that kind for refining.
+#[derive(Component)]
+pub struct OreShopRefineButton(pub OreKind);
+
 /// Tags the health row status text.
 #[derive(Component)]
 pub struct OreShopHealText;
@@ -268,6 +499,146 @@ pub struct OreShopTractorUpgradeButton;
 #[derive(Component)]
 pub struct OreShopIonUpgradeButton;
 
+/// Tags the shield install/upgrade button in the ore shop.
+#[derive(Component)]
+pub struct OreShopShieldUpgradeButton;
+
+/// Tags the mining drone launch/upgrade button in the ore shop.
+#[derive(Component)]
+pub struct OreShopDroneUpgradeButton;
+
+/// Tags the auto-turret mount button in the ore shop.
+#[derive(Component)]
+pub struct OreShopTurretUpgradeButton;
+
+/// Tags the intermission banner (discount + next-wave countdown) in the ore shop.
+#[derive(Component)]
+pub struct OreShopIntermissionText;
+
+/// Tags the "UNDO" button in the ore shop (hidden while nothing can be undone).
+#[derive(Component)]
+pub struct OreShopUndoButton;
+
+/// Tags the label inside the ore shop "UNDO" button.
+#[derive(Component)]
+pub struct OreShopUndoText;
+
+/// What a single ore shop purchase bought.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ShopPurchase {
+    /// HP actually restored (less than `ore_heal_amount` when topping off).
+    Heal {
+        hp_restored: f32,
+    },
+    Missile,
+    DestroyTrack,
+    ChipTrack,
+    FireRate,
+    MissileLevel,
+    Magnet,
+    Tractor,
+    IonCannon,
+    Shield,
+    Drone,
+    Turret,
+}
+
+impl ShopPurchase {
+    /// Currency healing is paid in (see [`ShopPurchase::currency`]).
+    pub const HEAL_CURRENCY: Currency = Currency::Ore(OreKind::Ice);
+
+    /// Balance in [`crate::mining::PlayerOre`] this purchase is charged to.
+    ///
+    /// Consumables and early upgrades take raw ore; the late-game upgrades
+    /// take refined credits or rare ore.
+    pub fn currency(&self) -> Currency {
+        match self {
+            ShopPurchase::Heal { .. } => Self::HEAL_CURRENCY,
+            ShopPurchase::Missile
+            | ShopPurchase::DestroyTrack
+            | ShopPurchase::ChipTrack
+            | ShopPurchase::Drone => Currency::Ore(OreKind::Iron),
+            ShopPurchase::Magnet | ShopPurchase::Tractor => Currency::Ore(OreKind::Ice),
+            ShopPurchase::IonCannon => Currency::Ore(OreKind::Rare),
+            ShopPurchase::FireRate
+            | ShopPurchase::MissileLevel
+            | ShopPurchase::Shield
+            | ShopPurchase::Turret => Currency::Credits,
+        }
+    }
+
+    /// Short name shown on the undo button.
+    pub fn label(&self) -> &'static str {
+        match self {
+            ShopPurchase::Heal { .. } => "HEAL",
+            ShopPurchase::Missile => "MISSILE",
+            ShopPurchase::DestroyTrack => "DESTROY UPGRADE",
+            ShopPurchase::ChipTrack => "CHIP UPGRADE",
+            ShopPurchase::FireRate => "FIRE RATE UPGRADE",
+            ShopPurchase::MissileLevel => "MISSILE UPGRADE",
+            ShopPurchase::Magnet => "MAGNET UPGRADE",
+            ShopPurchase::Tractor => "TRACTOR UPGRADE",
+            ShopPurchase::IonCannon => "ION CANNON UPGRADE",
+            ShopPurchase::Shield => "SHIELD UPGRADE",
+            ShopPurchase::Drone => "DRONE UPGRADE",
+            ShopPurchase::Turret => "TURRET",
+        }
+    }
+}
+
+/// One entry in the [`ShopTransactionLog`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShopTransaction {
+    pub purchase: ShopPurchase,
+    /// Ore actually charged (after any intermission discount).
+    pub ore_spent: u32,
+    /// Real-time seconds left before the purchase becomes final.
+    pub undo_secs_left: f32,
+}
+
+/// Purchases made since the ore shop was opened.
+///
+/// Purchases still apply immediately; the log records what each one bought
+/// and what it cost so the most recent can be refunded and reverted while its
+/// `shop_undo_window_secs` window is open.  Undoing pops the entry, so an
+/// earlier purchase whose window is still open becomes undoable in turn.  The
+/// log is cleared whenever the shop closes.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ShopTransactionLog {
+    pub entries: Vec<ShopTransaction>,
+}
+
+impl ShopTransactionLog {
+    /// Record a completed purchase.
+    pub fn record(&mut self, purchase: ShopPurchase, ore_spent: u32, window_secs: f32) {
+        self.entries.push(ShopTransaction {
+            purchase,
+            ore_spent,
+            undo_secs_left: window_secs.max(0.0),
+        });
+    }
+
+    /// Count every entry's undo window down by `dt` seconds.
+    pub fn tick(&mut self, dt: f32) {
+        for entry in &mut self.entries {
+            entry.undo_secs_left = (entry.undo_secs_left - dt).max(0.0);
+        }
+    }
+
+    /// The most recent purchase, if its undo window is still open.
+    pub fn undoable(&self) -> Option<&ShopTransaction> {
+        self.entries
+            .last()
+            .filter(|entry| entry.undo_secs_left > 0.0)
+    }
+
+    /// Remove and return the most recent purchase if it can still be undone.
+    pub fn take_undoable(&mut self) -> Option<ShopTransaction> {
+        self.undoable()?;
+        self.entries.pop()
+    }
+}
+
 /// Root node of the game-over overlay; despawned on `OnExit(GameOver)`.
 #[derive(Component)]
 pub struct GameOverRoot;
@@ -275,3 +646,16 @@ pub struct GameOverRoot;
 /// Tags the "Play Again" button in the game-over overlay.
 #[derive(Component)]
 pub struct GameOverPlayAgainButton;
+
+/// Root node of the victory overlay; despawned once the victory screen is
+/// dismissed or the session ends.
+#[derive(Component)]
+pub struct VictoryRoot;
+
+/// Row holding KEEP PLAYING; hidden while the victory orbit plays.
+#[derive(Component)]
+pub struct VictoryActions;
+
+/// Tags the "Keep Playing" button in the victory overlay.
+#[derive(Component)]
+pub struct VictoryKeepPlayingButton;
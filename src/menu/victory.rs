@@ -0,0 +1,198 @@
+use super::*;
+use crate::victory::{VictoryPhase, VictoryState, VictorySummary};
+
+/// Keep the victory overlay in step with [`VictoryState`].
+///
+/// The card appears at the bottom of the screen when the victory orbit
+/// starts, so the orbit stays visible behind the run summary.  Once the
+/// orbit ends the card shows its **KEEP PLAYING** row; dismissing it
+/// despawns the card.
+pub(super) fn victory_overlay_system(
+    mut commands: Commands,
+    state: Res<VictoryState>,
+    font: Res<GameFont>,
+    q_root: Query<Entity, With<VictoryRoot>>,
+    mut q_actions: Query<&mut Node, With<VictoryActions>>,
+    palette: Res<Palette>,
+) {
+    let showing = matches!(state.phase, VictoryPhase::Cinematic | VictoryPhase::Screen);
+    match (showing, q_root.iter().next()) {
+        (true, None) => spawn_victory_overlay(&mut commands, &state, &font, &palette),
+        (false, Some(root)) => commands.entity(root).despawn(),
+        _ => {}
+    }
+    if state.phase == VictoryPhase::Screen {
+        for mut node in q_actions.iter_mut() {
+            node.display = Display::Flex;
+        }
+    }
+}
+
+fn summary_lines(summary: &VictorySummary) -> [String; 2] {
+    [
+        format!(
+            "☄ Dominant body {} units   ·   {:.0}% of the field",
+            summary.mass,
+            summary.share * 100.0
+        ),
+        format!(
+            "⏱ {}   ·   merged {}   ·   💎 Score {}",
+            format_playtime(summary.time_secs),
+            summary.merges,
+            summary.score
+        ),
+    ]
+}
+
+fn spawn_victory_overlay(
+    commands: &mut Commands,
+    state: &VictoryState,
+    font: &GameFont,
+    palette: &Palette,
+) {
+    let summary = state.summary.unwrap_or_default();
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                position_type: PositionType::Absolute,
+                bottom: Val::Px(48.0),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            ZIndex(200),
+            VictoryRoot,
+        ))
+        .with_children(|overlay| {
+            overlay
+                .spawn((
+                    Node {
+                        flex_direction: FlexDirection::Column,
+                        align_items: AlignItems::Center,
+                        padding: UiRect::all(Val::Px(24.0)),
+                        row_gap: Val::Px(10.0),
+                        border: UiRect::all(Val::Px(2.0)),
+                        min_width: Val::Px(320.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.02, 0.04, 0.08, 0.78)),
+                    BorderColor::all(palette.confirm.border),
+                ))
+                .with_children(|card| {
+                    card.spawn((
+                        Text::new("✦ VICTORY ✦"),
+                        TextFont {
+                            font: font.0.clone(),
+                            font_size: 40.0,
+                            ..default()
+                        },
+                        TextColor(title_color()),
+                    ));
+                    for line in summary_lines(&summary) {
+                        card.spawn((
+                            Text::new(line),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 15.0,
+                                ..default()
+                            },
+                            TextColor(subtitle_color()),
+                        ));
+                    }
+
+                    card.spawn((
+                        Node {
+                            flex_direction: FlexDirection::Column,
+                            align_items: AlignItems::Center,
+                            row_gap: Val::Px(6.0),
+                            display: Display::None,
+                            ..default()
+                        },
+                        VictoryActions,
+                    ))
+                    .with_children(|actions| {
+                        pause_spacer(actions, 4.0);
+                        actions
+                            .spawn((
+                                Button,
+                                Node {
+                                    width: Val::Px(220.0),
+                                    height: Val::Px(50.0),
+                                    justify_content: JustifyContent::Center,
+                                    align_items: AlignItems::Center,
+                                    border: UiRect::all(Val::Px(2.0)),
+                                    ..default()
+                                },
+                                BackgroundColor(palette.confirm.bg),
+                                BorderColor::all(palette.confirm.border),
+                                VictoryKeepPlayingButton,
+                            ))
+                            .with_children(|btn| {
+                                btn.spawn((
+                                    Text::new("⮝ KEEP PLAYING ⮝"),
+                                    TextFont {
+                                        font: font.0.clone(),
+                                        font_size: 18.0,
+                                        ..default()
+                                    },
+                                    TextColor(palette.confirm.text),
+                                ));
+                            });
+                        actions.spawn((
+                            Text::new("Press Enter to keep playing"),
+                            TextFont {
+                                font: font.0.clone(),
+                                font_size: 12.0,
+                                ..default()
+                            },
+                            TextColor(hint_color()),
+                        ));
+                    });
+                });
+        });
+}
+
+/// Dismiss the victory screen on KEEP PLAYING or Enter.
+#[allow(clippy::type_complexity)]
+pub(super) fn victory_button_system(
+    keep_query: Query<
+        (&Interaction, &Children),
+        (Changed<Interaction>, With<VictoryKeepPlayingButton>),
+    >,
+    mut btn_text: Query<&mut TextColor>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut state: ResMut<VictoryState>,
+    palette: Res<Palette>,
+) {
+    if state.phase != VictoryPhase::Screen {
+        return;
+    }
+    for (interaction, children) in keep_query.iter() {
+        let color = match interaction {
+            Interaction::Pressed => {
+                state.phase = VictoryPhase::Dismissed;
+                continue;
+            }
+            Interaction::Hovered => Color::WHITE,
+            Interaction::None => palette.confirm.text,
+        };
+        for child in children.iter() {
+            if let Ok(mut text) = btn_text.get_mut(child) {
+                *text = TextColor(color);
+            }
+        }
+    }
+    if keys.just_pressed(KeyCode::Enter) {
+        state.phase = VictoryPhase::Dismissed;
+    }
+}
+
+/// Despawn the victory overlay when the session ends.
+pub(super) fn cleanup_victory_overlay(
+    mut commands: Commands,
+    query: Query<Entity, With<VictoryRoot>>,
+) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -3,15 +3,30 @@
 //!
 //! ## Flow
 //!
-//! 1. `spawn_ore_drop()` is called by the combat system whenever an asteroid is
+//! 1. Every asteroid carries an [`AsteroidMaterial`]: rolled from the
+//!    `asteroid_*_fraction` config weights at spawn, inherited by fragments, and
+//!    taken from the heaviest material share when bodies merge.
+//! 2. `spawn_ore_drop()` is called by the combat system whenever an asteroid is
 //!    terminally destroyed (size 0–1 bullet hit, or missile hit on size ≤ 3).
-//! 2. The ore entity drifts with the parent asteroid's velocity plus a small
-//!    random scatter, and rotates slowly for visibility.
-//! 3. `ore_collection_system` listens for `CollisionEvent::Started`; when the
-//!    player overlaps an ore sensor, the ore entity is despawned and
-//!    [`PlayerOre::count`] is incremented.
-//! 4. Ore entities older than [`ORE_LIFETIME_SECS`] are automatically despawned.
-//! 5. Ore can be spent via the in-game **Ore Shop** (Tab key, or Pause → Ore Shop).
+//!    The drop's [`OreKind`] follows the material: rock → iron, ice → ice,
+//!    metal → rare.  Enemy wrecks drop iron scrap.
+//! 3. The ore entity starts with `ore_drop_velocity_inherit` of the parent's
+//!    velocity plus up to `ore_drop_spread` of random scatter, rotates slowly
+//!    for visibility, and slows down under `ore_drop_damping` until the
+//!    magnet takes over.
+//! 4. `ore_collection_system` listens for `CollisionEvent::Started`; when the
+//!    player overlaps an ore sensor, the ore entity is despawned and that kind's
+//!    balance in [`PlayerOre`] is incremented.
+//! 5. Ore entities older than [`ORE_LIFETIME_SECS`] are automatically despawned.
+//! 6. Ore can be spent via the in-game **Ore Shop** (Tab key, or Pause → Ore Shop).
+//!    Each item is priced in one [`Currency`].  Raw ore queued at the shop's
+//!    refinery turns into credits over time (`ore_refining_system`).
+//! 7. A purchased mining drone ([`drone`]) fetches pickups the ship has not
+//!    reached yet and delivers them straight into [`PlayerOre`].
+//! 8. Mining outposts ([`outpost`]) built on planetoids trickle iron into
+//!    [`PlayerOre`] until enemies destroy them.
+//! 9. The mining laser ([`laser`]) carves asteroids down in place and credits
+//!    the removed mass to [`PlayerOre`] directly, with no pickups.
 //!
 //! ## Collision groups
 //!
@@ -24,13 +39,36 @@
 //! `CollisionGroups` filter is broadened to `GROUP_1 | GROUP_4` so the
 //! player-ore sensor events fire correctly.
 
+#[path = "mining/drone.rs"]
+pub mod drone;
+use drone::{mining_drone_system, sync_mining_drone_system};
+pub use drone::{MiningDrone, MiningDroneLevel};
+#[path = "mining/laser.rs"]
+pub mod laser;
+pub use laser::MiningLaserState;
+use laser::{mining_laser_hud_display_system, mining_laser_system, sync_mining_laser_beam_system};
+#[path = "mining/outpost.rs"]
+pub mod outpost;
+pub use outpost::MiningOutpost;
+use outpost::{
+    outpost_build_system, outpost_damage_system, outpost_hud_display_system,
+    outpost_production_system,
+};
+
+use crate::asteroid::{Asteroid, Planet};
+use crate::config::PhysicsConfig;
 use crate::menu::GameState;
 use crate::player::Player;
+use crate::rng::{visual_rng, GameplayRng};
+use crate::session_stats::SessionStatEvent;
+use crate::simulation::volatile::Volatile;
+use crate::spatial_partition::SpatialGrid;
 use bevy::prelude::*;
 use bevy_asset::RenderAssetUsages;
 use bevy_mesh::{Indices, PrimitiveTopology};
 use bevy_rapier2d::prelude::*;
 use rand::Rng;
+use serde::{Deserialize, Serialize};
 
 /// How long an ore pickup lingers in space before auto-despawning (seconds).
 const ORE_LIFETIME_SECS: f32 = 25.0;
@@ -45,17 +83,224 @@ const ORE_COLLIDER_RADIUS: f32 = 8.0;
 // ── Components & Resources ────────────────────────────────────────────────────
 
 /// Marker component for ore pickup entities.
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 pub struct OrePickup;
 
 /// Seconds this ore entity has been alive.
 #[derive(Component)]
 struct OreAge(f32);
 
-/// The player's total accumulated ore.
-#[derive(Resource, Default, Debug, Clone, Copy)]
+/// Raw ore kinds.  Also a component on each ore pickup.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Reflect)]
+#[reflect(Component)]
+pub enum OreKind {
+    #[default]
+    Iron,
+    Ice,
+    Rare,
+}
+
+impl OreKind {
+    pub const ALL: [OreKind; 3] = [OreKind::Iron, OreKind::Ice, OreKind::Rare];
+
+    /// Slot in the per-kind arrays of [`PlayerOre`].
+    #[inline]
+    pub fn index(self) -> usize {
+        match self {
+            OreKind::Iron => 0,
+            OreKind::Ice => 1,
+            OreKind::Rare => 2,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            OreKind::Iron => "IRON",
+            OreKind::Ice => "ICE",
+            OreKind::Rare => "RARE",
+        }
+    }
+
+    /// Short symbol used in shop prices and the HUD.
+    pub fn symbol(self) -> &'static str {
+        match self {
+            OreKind::Iron => "⛏",
+            OreKind::Ice => "❄",
+            OreKind::Rare => "💎",
+        }
+    }
+
+    /// Credits one unit refines into.
+    pub fn refined_credits(self, config: &PhysicsConfig) -> u32 {
+        match self {
+            OreKind::Iron => config.ore_refine_credits_iron,
+            OreKind::Ice => config.ore_refine_credits_ice,
+            OreKind::Rare => config.ore_refine_credits_rare,
+        }
+    }
+}
+
+/// A balance held in [`PlayerOre`]: one of the raw ores, or refined credits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Currency {
+    Ore(OreKind),
+    Credits,
+}
+
+impl Currency {
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Currency::Ore(kind) => kind.symbol(),
+            Currency::Credits => "¢",
+        }
+    }
+}
+
+/// What an asteroid is made of; decides the [`OreKind`] it drops.
+#[derive(
+    Component, Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize, Reflect,
+)]
+#[reflect(Component)]
+pub enum AsteroidMaterial {
+    #[default]
+    Rock,
+    Ice,
+    Metal,
+}
+
+impl AsteroidMaterial {
+    pub fn label(self) -> &'static str {
+        match self {
+            AsteroidMaterial::Rock => "Rock",
+            AsteroidMaterial::Ice => "Ice",
+            AsteroidMaterial::Metal => "Metal",
+        }
+    }
+
+    pub fn ore_kind(self) -> OreKind {
+        match self {
+            AsteroidMaterial::Rock => OreKind::Iron,
+            AsteroidMaterial::Ice => OreKind::Ice,
+            AsteroidMaterial::Metal => OreKind::Rare,
+        }
+    }
+
+    /// Pick a material from the `asteroid_ice_fraction` and
+    /// `asteroid_metal_fraction` weights; the remainder is rock.
+    pub fn roll(rng: &mut impl Rng, config: &PhysicsConfig) -> Self {
+        let roll: f32 = rng.gen_range(0.0..1.0);
+        let ice = config.asteroid_ice_fraction.max(0.0);
+        if roll < ice {
+            AsteroidMaterial::Ice
+        } else if roll < ice + config.asteroid_metal_fraction.max(0.0) {
+            AsteroidMaterial::Metal
+        } else {
+            AsteroidMaterial::Rock
+        }
+    }
+
+    /// Material carrying the most mass among `(material, mass)` parts; rock
+    /// when `parts` is empty.
+    pub fn dominant(parts: impl IntoIterator<Item = (AsteroidMaterial, u32)>) -> Self {
+        let mut mass = [0u32; 3];
+        for (material, m) in parts {
+            mass[material.ore_kind().index()] += m;
+        }
+        let materials = [
+            AsteroidMaterial::Rock,
+            AsteroidMaterial::Ice,
+            AsteroidMaterial::Metal,
+        ];
+        // Ties go to the commoner material.
+        (0..3)
+            .rev()
+            .max_by_key(|&i| mass[i])
+            .map_or(AsteroidMaterial::Rock, |i| materials[i])
+    }
+}
+
+/// The player's ore wallet: raw ore per [`OreKind`], refined credits, and the
+/// refinery queue.
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
 pub struct PlayerOre {
-    pub count: u32,
+    /// Raw ore per kind, indexed by [`OreKind::index`].
+    pub raw: [u32; 3],
+    pub credits: u32,
+    /// Raw ore waiting in the refinery, indexed by [`OreKind::index`].
+    pub refining: [u32; 3],
+    /// Progress towards the next refined unit (seconds).
+    pub refine_progress_secs: f32,
+}
+
+impl PlayerOre {
+    #[inline]
+    pub fn raw(&self, kind: OreKind) -> u32 {
+        self.raw[kind.index()]
+    }
+
+    #[inline]
+    pub fn add_raw(&mut self, kind: OreKind, amount: u32) {
+        let slot = &mut self.raw[kind.index()];
+        *slot = slot.saturating_add(amount);
+    }
+
+    /// Units waiting in the refinery.
+    pub fn total_refining(&self) -> u32 {
+        self.refining.iter().sum()
+    }
+
+    #[inline]
+    pub fn balance(&self, currency: Currency) -> u32 {
+        match currency {
+            Currency::Ore(kind) => self.raw(kind),
+            Currency::Credits => self.credits,
+        }
+    }
+
+    #[inline]
+    pub fn balance_mut(&mut self, currency: Currency) -> &mut u32 {
+        match currency {
+            Currency::Ore(kind) => &mut self.raw[kind.index()],
+            Currency::Credits => &mut self.credits,
+        }
+    }
+
+    /// Move up to `amount` raw ore of `kind` into the refinery.  Returns the
+    /// amount moved.
+    pub fn queue_refining(&mut self, kind: OreKind, amount: u32) -> u32 {
+        let moved = amount.min(self.raw[kind.index()]);
+        self.raw[kind.index()] -= moved;
+        self.refining[kind.index()] += moved;
+        moved
+    }
+
+    /// Refine for `dt` seconds, one unit every `secs_per_unit`, in
+    /// [`OreKind::ALL`] order.  Returns the credits produced.
+    pub fn refine(&mut self, dt: f32, secs_per_unit: f32, config: &PhysicsConfig) -> u32 {
+        if self.total_refining() == 0 {
+            self.refine_progress_secs = 0.0;
+            return 0;
+        }
+        let secs_per_unit = secs_per_unit.max(0.01);
+        self.refine_progress_secs += dt;
+        let mut produced = 0;
+        while self.refine_progress_secs >= secs_per_unit {
+            let Some(kind) = OreKind::ALL
+                .into_iter()
+                .find(|kind| self.refining[kind.index()] > 0)
+            else {
+                self.refine_progress_secs = 0.0;
+                break;
+            };
+            self.refine_progress_secs -= secs_per_unit;
+            self.refining[kind.index()] -= 1;
+            produced += kind.refined_credits(config);
+        }
+        self.credits = self.credits.saturating_add(produced);
+        produced
+    }
 }
 
 // ══════════════════════════════════════════════════════════════════════════════
@@ -73,7 +318,8 @@ pub struct PlayerOre {
 /// - Level 1: 300 u radius, 56 u/s pull
 /// - Level 5: 500 u radius, 120 u/s pull
 /// - Level 9 (max): 700 u radius, 184 u/s pull
-#[derive(Resource, Debug, Clone, Default)]
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
 pub struct OreAffinityLevel {
     /// Internal 0-indexed level (0 = Level 1 / base, 9 = Level 10 / max).
     pub level: u32,
@@ -119,12 +365,6 @@ impl OreAffinityLevel {
         }
     }
 
-    /// Returns `true` when the player has enough ore to afford the next upgrade.
-    #[inline]
-    pub fn can_afford_next(&self, ore: u32) -> bool {
-        self.cost_for_next_level().is_some_and(|cost| ore >= cost)
-    }
-
     /// Spend ore and increment the level.  Returns the amount spent, or `None`
     /// if maxed-out or the player cannot afford it.
     pub fn try_upgrade(&mut self, ore: &mut u32) -> Option<u32> {
@@ -150,16 +390,39 @@ impl Plugin for MiningPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<PlayerOre>()
             .init_resource::<OreAffinityLevel>()
+            .init_resource::<MiningDroneLevel>()
+            .init_resource::<MiningLaserState>()
             .add_systems(Startup, setup_ore_mesh)
             .add_systems(
                 Update,
                 (
+                    assign_asteroid_material_system,
                     attach_ore_mesh_system,
                     ore_lifetime_system,
                     ore_magnet_system,
+                    (sync_mining_drone_system, mining_drone_system).chain(),
+                    (
+                        mining_laser_system,
+                        sync_mining_laser_beam_system,
+                        mining_laser_hud_display_system,
+                    )
+                        .chain(),
+                    (
+                        outpost_build_system,
+                        outpost_production_system,
+                        outpost_damage_system,
+                        outpost_hud_display_system,
+                    )
+                        .chain(),
                 )
                     .run_if(in_state(GameState::Playing)),
             )
+            // The refinery keeps working behind the shop overlay.
+            .add_systems(
+                Update,
+                ore_refining_system
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::OreShop))),
+            )
             .add_systems(
                 // Run alongside the other hit systems that read CollisionEvents.
                 PostUpdate,
@@ -177,23 +440,43 @@ fn setup_ore_mesh(mut commands: Commands, mut meshes: ResMut<Assets<Mesh>>) {
 
 // ── Spawn helper ──────────────────────────────────────────────────────────────
 
-/// Spawn an ore pickup at `pos` with `base_vel` plus a small random scatter.
+/// Initial velocity of an ore drop: a damped share of the parent's velocity
+/// plus scatter.  `jitter` is a random sample in `[-1, 1]²`.
+pub fn ore_drop_velocity(parent_vel: Vec2, jitter: Vec2, config: &PhysicsConfig) -> Vec2 {
+    parent_vel * config.ore_drop_velocity_inherit.clamp(0.0, 1.0)
+        + jitter.clamp(Vec2::NEG_ONE, Vec2::ONE) * config.ore_drop_spread
+}
+
+/// Ore velocity after drifting for `dt` under linear `damping` (1/s).
+pub fn damp_ore_velocity(vel: Vec2, dt: f32, damping: f32) -> Vec2 {
+    vel / (1.0 + dt * damping.max(0.0))
+}
+
+/// Spawn an ore pickup of `kind` at `pos`, moving with the parent's
+/// `base_vel` as shaped by [`ore_drop_velocity`].
 ///
 /// Called by the combat system on terminal asteroid destruction.
-pub fn spawn_ore_drop(commands: &mut Commands, pos: Vec2, base_vel: Vec2) {
-    let mut rng = rand::thread_rng();
-    let scatter = Vec2::new(rng.gen_range(-18.0..18.0), rng.gen_range(-18.0..18.0));
+pub fn spawn_ore_drop(
+    commands: &mut Commands,
+    pos: Vec2,
+    base_vel: Vec2,
+    kind: OreKind,
+    config: &PhysicsConfig,
+    rng: &mut impl Rng,
+) {
+    let jitter = Vec2::new(rng.gen_range(-1.0..1.0), rng.gen_range(-1.0..1.0));
     let transform = Transform::from_translation(pos.extend(0.2));
     commands.spawn((
         OrePickup,
+        kind,
         OreAge(0.0),
         transform, // Z slightly above asteroids
         GlobalTransform::from(transform),
         Visibility::default(),
         RigidBody::KinematicVelocityBased,
         Velocity {
-            linvel: base_vel + scatter,
-            angvel: rng.gen_range(1.2..2.8),
+            linvel: ore_drop_velocity(base_vel, jitter, config),
+            angvel: visual_rng().gen_range(1.2..2.8),
         },
         Collider::ball(ORE_COLLIDER_RADIUS),
         Sensor,
@@ -209,52 +492,111 @@ pub fn spawn_ore_drop(commands: &mut Commands, pos: Vec2, base_vel: Vec2) {
 
 // ── Systems ───────────────────────────────────────────────────────────────────
 
-/// Attach a filled diamond `Mesh2d` to every freshly-spawned ore pickup.
+/// Attach a filled diamond `Mesh2d`, tinted by [`OreKind`] through the
+/// [`Palette`](crate::graphics::palette::Palette), to every freshly-spawned
+/// ore pickup.
 fn attach_ore_mesh_system(
     mut commands: Commands,
-    query: Query<Entity, Added<OrePickup>>,
+    query: Query<(Entity, &OreKind), Added<OrePickup>>,
     ore_mesh: Res<OreMesh>,
+    palette: Res<crate::graphics::palette::Palette>,
     mut materials: ResMut<Assets<ColorMaterial>>,
 ) {
-    for entity in query.iter() {
-        let mat = materials.add(ColorMaterial::from_color(Color::srgb(0.25, 0.95, 0.50)));
+    for (entity, kind) in query.iter() {
+        let mat = materials.add(ColorMaterial::from_color(palette.ore(*kind)));
         commands
             .entity(entity)
             .insert((Mesh2d(ore_mesh.0.clone()), MeshMaterial2d(mat)));
     }
 }
 
-/// Pull ore pickups toward the player when they are within `ore_magnet_radius`.
+/// Roll an [`AsteroidMaterial`] for asteroids spawned without one.
+///
+/// Fragments and merge products get theirs at spawn, so only fresh bodies
+/// (field spawns, scenario setup, orbital imports) reach this.  The same
+/// bodies have a `volatile_asteroid_fraction` chance of being [`Volatile`].
+#[allow(clippy::type_complexity)]
+pub fn assign_asteroid_material_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    mut rng: ResMut<GameplayRng>,
+    query: Query<Entity, (With<Asteroid>, Without<Planet>, Without<AsteroidMaterial>)>,
+) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert(AsteroidMaterial::roll(&mut *rng, &config));
+        if rng.gen::<f32>() < config.volatile_asteroid_fraction {
+            commands.entity(entity).insert(Volatile);
+        }
+    }
+}
+
+/// Turn queued raw ore into credits, one unit every
+/// `ore_refine_secs_per_unit` of simulation time.
+pub fn ore_refining_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    mut ore: ResMut<PlayerOre>,
+) {
+    if ore.total_refining() == 0 {
+        return;
+    }
+    ore.refine(time.delta_secs(), config.ore_refine_secs_per_unit, &config);
+}
+
+/// Pull ore pickups toward the player when they are within `ore_magnet_radius`,
+/// and let the rest slow down.
 ///
 /// Uses a velocity lerp so the attraction feels smooth rather than a hard snap:
 /// each frame the ore's `linvel` is blended toward a target vector pointing
 /// directly at the player at `ore_magnet_strength` u/s.  Outside the magnet
-/// radius the ore velocity is left completely untouched.
+/// radius (or with no ship) the ore decelerates under `ore_drop_damping`.
+///
+/// Pickups are not in the asteroid [`SpatialGrid`], so the system keeps its
+/// own index of them, updated in place like the asteroid one, and finds the
+/// pickups in range with [`SpatialGrid::within_radius`].
 fn ore_magnet_system(
     affinity_level: Res<OreAffinityLevel>,
+    config: Res<PhysicsConfig>,
     time: Res<Time>,
     q_player: Query<&Transform, With<Player>>,
-    mut q_ore: Query<(&Transform, &mut Velocity), With<OrePickup>>,
+    mut q_ore: Query<(Entity, &Transform, &mut Velocity), With<OrePickup>>,
+    mut ore_index: Local<SpatialGrid>,
+    mut in_range: Local<Vec<Entity>>,
 ) {
-    let Ok(player_transform) = q_player.single() else {
-        return;
-    };
-    let player_pos = player_transform.translation.truncate();
-    let radius = affinity_level.radius_at_level();
-    let radius_sq = radius * radius;
     let dt = time.delta_secs();
+    let player_pos = q_player.single().ok().map(|t| t.translation.truncate());
     // Lerp alpha: at 4 × dt the velocity rotates ~14% per frame (≈60 fps →
     // fully pointing at player in ~0.25 s), giving a smooth but responsive pull.
     let alpha = (dt * 4.0).min(1.0);
 
-    for (ore_transform, mut vel) in q_ore.iter_mut() {
-        let ore_pos = ore_transform.translation.truncate();
-        let delta = player_pos - ore_pos;
-        if delta.length_squared() > radius_sq {
-            continue;
+    ore_index.pts_scratch.clear();
+    for (entity, transform, _) in q_ore.iter() {
+        ore_index
+            .pts_scratch
+            .push((entity, transform.translation.truncate()));
+    }
+    ore_index.update_in_place(
+        config.spatial_update_tolerance,
+        config.spatial_rebuild_imbalance,
+    );
+    match player_pos {
+        Some(player) => {
+            ore_index.within_radius(player, affinity_level.radius_at_level(), &mut in_range)
         }
+        None => in_range.clear(),
+    }
+    in_range.sort_unstable();
+
+    for (entity, ore_transform, mut vel) in q_ore.iter_mut() {
+        let Some(player) = player_pos.filter(|_| in_range.binary_search(&entity).is_ok()) else {
+            vel.linvel = damp_ore_velocity(vel.linvel, dt, config.ore_drop_damping);
+            continue;
+        };
         // direction is guaranteed non-zero: ore can't overlap the player sensor
         // without already triggering collection.
+        let delta = player - ore_transform.translation.truncate();
         let target_linvel = delta.normalize_or_zero() * affinity_level.strength_at_level();
         vel.linvel = vel.linvel.lerp(target_linvel, alpha);
     }
@@ -279,9 +621,10 @@ fn ore_lifetime_system(
 pub fn ore_collection_system(
     mut commands: Commands,
     mut collision_events: MessageReader<CollisionEvent>,
-    q_ore: Query<Entity, With<OrePickup>>,
+    q_ore: Query<&OreKind, With<OrePickup>>,
     q_player: Query<Entity, With<Player>>,
     mut ore: ResMut<PlayerOre>,
+    mut session: MessageWriter<SessionStatEvent>,
 ) {
     let Ok(player_entity) = q_player.single() else {
         return;
@@ -301,8 +644,11 @@ pub fn ore_collection_system(
             continue;
         };
 
+        if let Ok(&kind) = q_ore.get(ore_entity) {
+            ore.add_raw(kind, 1);
+            session.write(SessionStatEvent::OreMined(1));
+        }
         commands.entity(ore_entity).despawn();
-        ore.count += 1;
     }
 }
 
@@ -327,3 +673,131 @@ fn diamond_mesh(half_w: f32, half_h: f32) -> Mesh {
     mesh.insert_indices(indices);
     mesh
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn refinery_turns_queued_ore_into_credits_over_time() {
+        let config = PhysicsConfig::default();
+        let mut ore = PlayerOre {
+            raw: [3, 1, 0],
+            ..Default::default()
+        };
+
+        assert_eq!(ore.queue_refining(OreKind::Iron, 5), 3);
+        assert_eq!(ore.queue_refining(OreKind::Ice, 1), 1);
+        assert_eq!(ore.raw, [0, 0, 0]);
+
+        let secs = config.ore_refine_secs_per_unit;
+        assert_eq!(ore.refine(secs * 0.5, secs, &config), 0);
+        // Iron goes first: two units finish here, the third is half done.
+        let produced = ore.refine(secs * 2.0, secs, &config);
+        assert_eq!(produced, 2 * config.ore_refine_credits_iron);
+        assert_eq!(ore.refining, [1, 1, 0]);
+
+        ore.refine(secs * 10.0, secs, &config);
+        assert_eq!(ore.total_refining(), 0);
+        assert_eq!(
+            ore.credits,
+            3 * config.ore_refine_credits_iron + config.ore_refine_credits_ice
+        );
+        assert_eq!(ore.refine_progress_secs, 0.0);
+    }
+
+    #[test]
+    fn merged_material_follows_the_heaviest_share() {
+        assert_eq!(
+            AsteroidMaterial::dominant([
+                (AsteroidMaterial::Rock, 3),
+                (AsteroidMaterial::Metal, 2),
+                (AsteroidMaterial::Metal, 2),
+            ]),
+            AsteroidMaterial::Metal
+        );
+        // Ties go to the commoner material.
+        assert_eq!(
+            AsteroidMaterial::dominant([(AsteroidMaterial::Ice, 2), (AsteroidMaterial::Rock, 2)]),
+            AsteroidMaterial::Rock
+        );
+        assert_eq!(AsteroidMaterial::dominant([]), AsteroidMaterial::Rock);
+    }
+
+    #[test]
+    fn ore_from_high_speed_destroys_stays_collectible() {
+        let config = PhysicsConfig::default();
+        let magnet_radius = OreAffinityLevel::default().radius_at_level();
+        let dt = 1.0 / 60.0;
+        // A fast wreck, with the worst-case scatter in its direction of travel.
+        let parent_vel = Vec2::new(400.0, 0.0);
+        let mut vel = ore_drop_velocity(parent_vel, Vec2::new(1.0, 1.0), &config);
+        assert!(vel.length() < parent_vel.length());
+
+        let mut pos = Vec2::ZERO;
+        let mut t = 0.0;
+        while t < ORE_LIFETIME_SECS {
+            vel = damp_ore_velocity(vel, dt, config.ore_drop_damping);
+            pos += vel * dt;
+            t += dt;
+        }
+        // The pickup settles within base magnet range of where it dropped,
+        // and has all but stopped well before it expires.
+        assert!(pos.length() < magnet_radius, "drifted {}", pos.length());
+        assert!(vel.length() < 1.0);
+    }
+
+    #[test]
+    fn magnet_pulls_exactly_the_pickups_within_its_radius() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(PhysicsConfig::default());
+        world.insert_resource(OreAffinityLevel::default());
+        let radius = OreAffinityLevel::default().radius_at_level();
+        let player = Vec2::new(40.0, -25.0);
+        world.spawn((Player, Transform::from_translation(player.extend(0.0))));
+        let drift = Vec2::new(0.0, 5.0);
+        let ore: Vec<(Entity, Vec2)> = (0..60)
+            .map(|i| {
+                let angle = i as f32 * 0.7;
+                let pos = player + Vec2::from_angle(angle) * (i as f32 * radius / 30.0 + 1.0);
+                let entity = world
+                    .spawn((
+                        OrePickup,
+                        Transform::from_translation(pos.extend(0.0)),
+                        Velocity::linear(drift),
+                    ))
+                    .id();
+                (entity, pos)
+            })
+            .collect();
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(ore_magnet_system);
+        // Two frames, so the second goes through the in-place index update.
+        for _ in 0..2 {
+            world
+                .resource_mut::<Time>()
+                .advance_by(std::time::Duration::from_millis(16));
+            schedule.run(&mut world);
+        }
+
+        let damped = damp_ore_velocity(
+            damp_ore_velocity(drift, 0.016, PhysicsConfig::default().ore_drop_damping),
+            0.016,
+            PhysicsConfig::default().ore_drop_damping,
+        );
+        for (entity, pos) in ore {
+            let vel = world.get::<Velocity>(entity).unwrap().linvel;
+            if pos.distance(player) <= radius {
+                assert!(
+                    (vel - drift).dot(player - pos) > 0.0,
+                    "pickup {} u away is not pulled",
+                    pos.distance(player)
+                );
+            } else {
+                assert!((vel - damped).length() < 1e-4, "{vel} != {damped}");
+            }
+        }
+    }
+}
@@ -0,0 +1,347 @@
+//! Mining drone: an autonomous companion that hauls ore pickups to the ship.
+//!
+//! [`MiningDroneLevel`] (bought in the ore shop) controls whether a drone is
+//! flying and how fast and how much it carries.  While the level is non-zero
+//! and the ship is alive, [`sync_mining_drone_system`] keeps exactly one
+//! [`MiningDrone`] entity next to it; the drone (and any cargo) is lost when
+//! the ship is destroyed and relaunched when it respawns.
+//!
+//! [`mining_drone_system`] runs a small state machine each frame:
+//!
+//! - **Orbiting** — circle the ship at `mining_drone_orbit_radius` until an
+//!   ore pickup drifts within `mining_drone_seek_radius` of the ship.
+//! - **Seeking** — fly to the nearest pickup and take it on board.  Once the
+//!   hold is full, or nothing is left in range, head home.
+//! - **Returning** — fly back to the ship and add the cargo to [`PlayerOre`].
+//!
+//! Steering is kinematic: the drone moves its own `Transform` and has no
+//! collider, so it never pushes asteroids around or triggers ore sensors.
+//! While orbiting or returning it also inherits the ship's velocity, which
+//! keeps it in formation with a ship that is moving faster than it flies.
+
+use super::{diamond_mesh, OreKind, OrePickup, PlayerOre};
+use crate::config::PhysicsConfig;
+use crate::constants::{MINING_DRONE_MAX_LEVEL, MINING_DRONE_UPGRADE_BASE_COST};
+use crate::player::Player;
+use crate::session_stats::SessionStatEvent;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+/// Visual half-extents of the drone's diamond hull (world units).
+const DRONE_HALF_W: f32 = 5.0;
+const DRONE_HALF_H: f32 = 3.0;
+
+/// Mining drone upgrade level.
+///
+/// Level 0 means no drone has been bought; each level raises its flight speed
+/// and how many ore units it carries per trip.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
+pub struct MiningDroneLevel {
+    /// Purchased level (0 = no drone).
+    pub level: u32,
+}
+
+impl MiningDroneLevel {
+    /// Maximum level value (inclusive).
+    pub const MAX: u32 = MINING_DRONE_MAX_LEVEL;
+
+    /// Human-readable display level (0 until the first purchase).
+    #[inline]
+    pub fn display_level(&self) -> u32 {
+        self.level
+    }
+
+    /// Flight speed (u/s) at the current level.
+    #[inline]
+    pub fn speed(&self, config: &PhysicsConfig) -> f32 {
+        config.mining_drone_base_speed
+            + self.level.saturating_sub(1) as f32 * config.mining_drone_speed_per_level
+    }
+
+    /// Ore units carried per trip at the current level.
+    #[inline]
+    pub fn capacity(&self, config: &PhysicsConfig) -> u32 {
+        config.mining_drone_base_capacity
+            + self.level.saturating_sub(1) * config.mining_drone_capacity_per_level
+    }
+
+    /// Whether the drone can be upgraded further.
+    #[inline]
+    pub fn is_maxed(&self) -> bool {
+        self.level >= Self::MAX
+    }
+
+    /// Ore cost to buy the next drone level.
+    /// Returns `None` when already at max level.
+    #[inline]
+    pub fn cost_for_next_level(&self) -> Option<u32> {
+        if self.is_maxed() {
+            None
+        } else {
+            Some(MINING_DRONE_UPGRADE_BASE_COST * (self.level + 1))
+        }
+    }
+
+    /// Spend ore and increment the level. Returns the amount spent, or `None`
+    /// if maxed-out or the player cannot afford it.
+    pub fn try_upgrade(&mut self, ore: &mut u32) -> Option<u32> {
+        let cost = self.cost_for_next_level()?;
+        if *ore < cost {
+            return None;
+        }
+        *ore -= cost;
+        self.level += 1;
+        Some(cost)
+    }
+}
+
+/// What the drone is currently doing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum DroneTask {
+    /// Circling the ship, waiting for ore to come within range.
+    #[default]
+    Orbiting,
+    /// Flying to this ore pickup.
+    Seeking(Entity),
+    /// Carrying ore back to the ship.
+    Returning,
+}
+
+/// The player's mining drone.
+#[derive(Component, Debug, Clone, Default, Reflect)]
+#[reflect(Component)]
+pub struct MiningDrone {
+    pub task: DroneTask,
+    /// Ore on board, delivered when the drone reaches the ship.
+    pub cargo: Vec<OreKind>,
+    /// Current angle (radians) on the idle orbit.
+    pub orbit_angle: f32,
+}
+
+/// Move `from` toward `to` by at most `max_step`, without overshooting.
+///
+/// Returns the new position and whether it reached `to`.
+pub fn steer_toward(from: Vec2, to: Vec2, max_step: f32) -> (Vec2, bool) {
+    let delta = to - from;
+    let dist = delta.length();
+    if dist <= max_step {
+        (to, true)
+    } else {
+        (from + delta / dist * max_step, false)
+    }
+}
+
+/// Pick the pickup nearest `origin` within `radius`.
+pub fn nearest_ore(
+    origin: Vec2,
+    radius: f32,
+    candidates: impl IntoIterator<Item = (Entity, Vec2)>,
+) -> Option<Entity> {
+    let radius_sq = radius * radius;
+    candidates
+        .into_iter()
+        .map(|(entity, pos)| (entity, origin.distance_squared(pos)))
+        .filter(|&(_, dist_sq)| dist_sq <= radius_sq)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// Keep one drone alive next to the ship while a drone level is owned.
+pub fn sync_mining_drone_system(
+    mut commands: Commands,
+    level: Res<MiningDroneLevel>,
+    q_player: Query<&Transform, With<Player>>,
+    q_drone: Query<Entity, With<MiningDrone>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let Some(player_transform) = q_player.single().ok().filter(|_| level.level > 0) else {
+        for entity in q_drone.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+    if !q_drone.is_empty() {
+        return;
+    }
+
+    let transform = Transform::from_translation(player_transform.translation.with_z(0.3));
+    commands.spawn((
+        MiningDrone::default(),
+        transform,
+        GlobalTransform::from(transform),
+        Visibility::default(),
+        Mesh2d(meshes.add(diamond_mesh(DRONE_HALF_W, DRONE_HALF_H))),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgb(0.45, 0.85, 1.0)))),
+    ));
+}
+
+/// Run the drone's orbit → seek → return loop and deliver its cargo.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn mining_drone_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    level: Res<MiningDroneLevel>,
+    q_player: Query<(&Transform, &Velocity), With<Player>>,
+    q_ore: Query<(Entity, &Transform, &OreKind), With<OrePickup>>,
+    mut q_drone: Query<(&mut MiningDrone, &mut Transform), (Without<Player>, Without<OrePickup>)>,
+    mut ore: ResMut<PlayerOre>,
+    mut session: MessageWriter<SessionStatEvent>,
+) {
+    let Ok((player_transform, player_vel)) = q_player.single() else {
+        return;
+    };
+    let dt = time.delta_secs();
+    let ship = player_transform.translation.truncate();
+    let step = level.speed(&config) * dt;
+    let capacity = level.capacity(&config) as usize;
+    let contact = config.mining_drone_contact_radius;
+
+    for (mut drone, mut transform) in q_drone.iter_mut() {
+        let drone = &mut *drone;
+        let mut pos = transform.translation.truncate();
+        let find_ore = |from: Vec2| {
+            nearest_ore(
+                from,
+                config.mining_drone_seek_radius,
+                q_ore
+                    .iter()
+                    .map(|(entity, t, _)| (entity, t.translation.truncate())),
+            )
+        };
+
+        match drone.task {
+            DroneTask::Orbiting => {
+                pos += player_vel.linvel * dt;
+                drone.orbit_angle = (drone.orbit_angle + config.mining_drone_orbit_speed * dt)
+                    % std::f32::consts::TAU;
+                let slot =
+                    ship + Vec2::from_angle(drone.orbit_angle) * config.mining_drone_orbit_radius;
+                pos = steer_toward(pos, slot, step).0;
+                if let Some(target) = find_ore(ship) {
+                    drone.task = DroneTask::Seeking(target);
+                }
+            }
+            DroneTask::Seeking(target) => match q_ore.get(target) {
+                Ok((_, ore_transform, &kind)) => {
+                    let (next, reached) =
+                        steer_toward(pos, ore_transform.translation.truncate(), step);
+                    pos = next;
+                    if reached || pos.distance(ore_transform.translation.truncate()) <= contact {
+                        commands.entity(target).despawn();
+                        drone.cargo.push(kind);
+                        drone.task = if drone.cargo.len() >= capacity {
+                            DroneTask::Returning
+                        } else {
+                            // The grabbed pickup is still in the query until
+                            // the despawn is applied.
+                            nearest_ore(
+                                ship,
+                                config.mining_drone_seek_radius,
+                                q_ore
+                                    .iter()
+                                    .filter(|(e, ..)| *e != target)
+                                    .map(|(entity, t, _)| (entity, t.translation.truncate())),
+                            )
+                            .map_or(DroneTask::Returning, DroneTask::Seeking)
+                        };
+                    }
+                }
+                // Collected by the ship, expired, or grabbed elsewhere.
+                Err(_) => {
+                    drone.task = match find_ore(ship) {
+                        Some(next) => DroneTask::Seeking(next),
+                        None if drone.cargo.is_empty() => DroneTask::Orbiting,
+                        None => DroneTask::Returning,
+                    };
+                }
+            },
+            DroneTask::Returning => {
+                pos += player_vel.linvel * dt;
+                let (next, reached) = steer_toward(pos, ship, step);
+                pos = next;
+                if reached || pos.distance(ship) <= contact {
+                    if !drone.cargo.is_empty() {
+                        session.write(SessionStatEvent::OreMined(drone.cargo.len() as u32));
+                    }
+                    for kind in drone.cargo.drain(..) {
+                        ore.add_raw(kind, 1);
+                    }
+                    drone.task = DroneTask::Orbiting;
+                    drone.orbit_angle = (pos - ship).to_angle();
+                }
+            }
+        }
+
+        let heading = pos - transform.translation.truncate();
+        if heading.length_squared() > f32::EPSILON {
+            transform.rotation = Quat::from_rotation_z(heading.to_angle());
+        }
+        transform.translation = pos.extend(transform.translation.z);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(i: u32) -> Entity {
+        Entity::from_raw_u32(i).unwrap()
+    }
+
+    #[test]
+    fn steering_moves_at_most_one_step_and_never_overshoots() {
+        let (pos, reached) = steer_toward(Vec2::ZERO, Vec2::new(10.0, 0.0), 4.0);
+        assert_eq!(pos, Vec2::new(4.0, 0.0));
+        assert!(!reached);
+
+        let (pos, reached) = steer_toward(Vec2::ZERO, Vec2::new(3.0, 4.0), 6.0);
+        assert_eq!(pos, Vec2::new(3.0, 4.0));
+        assert!(reached);
+    }
+
+    #[test]
+    fn nearest_ore_ignores_pickups_out_of_range() {
+        let pickups = [
+            (entity(1), Vec2::new(50.0, 0.0)),
+            (entity(2), Vec2::new(0.0, 20.0)),
+            (entity(3), Vec2::new(5.0, 0.0)),
+        ];
+        assert_eq!(nearest_ore(Vec2::ZERO, 100.0, pickups), Some(entity(3)));
+        assert_eq!(
+            nearest_ore(Vec2::new(60.0, 0.0), 15.0, pickups),
+            Some(entity(1))
+        );
+        assert_eq!(nearest_ore(Vec2::new(500.0, 0.0), 100.0, pickups), None);
+    }
+
+    #[test]
+    fn drone_levels_scale_speed_capacity_and_cost() {
+        let config = PhysicsConfig::default();
+        let mut level = MiningDroneLevel::default();
+        assert_eq!(
+            level.cost_for_next_level(),
+            Some(MINING_DRONE_UPGRADE_BASE_COST)
+        );
+
+        let mut ore = MINING_DRONE_UPGRADE_BASE_COST * 3;
+        assert!(level.try_upgrade(&mut ore).is_some());
+        assert_eq!(level.speed(&config), config.mining_drone_base_speed);
+        assert_eq!(level.capacity(&config), config.mining_drone_base_capacity);
+
+        assert!(level.try_upgrade(&mut ore).is_some());
+        assert_eq!(ore, 0);
+        assert_eq!(
+            level.capacity(&config),
+            config.mining_drone_base_capacity + config.mining_drone_capacity_per_level
+        );
+        assert!(level.speed(&config) > config.mining_drone_base_speed);
+        assert!(level.try_upgrade(&mut ore).is_none());
+
+        level.level = MiningDroneLevel::MAX;
+        assert!(level.is_maxed());
+        assert_eq!(level.cost_for_next_level(), None);
+    }
+}
@@ -0,0 +1,383 @@
+//! Mining laser: a continuous beam that carves ore straight out of asteroids.
+//!
+//! Holding **G** (gamepad: left-stick click) fires a beam along the aim
+//! direction, up to `mining_laser_range`.  The first asteroid the beam
+//! crosses is cut at `mining_laser_carve_rate` mass units per second.  Each
+//! whole unit goes into [`PlayerOre`] as the asteroid's ore kind.  The
+//! asteroid shrinks in place through [`carve_asteroid_shape`], with a crater
+//! at the cut point.  No fragments or pickups are spawned.  The last unit
+//! removes the asteroid.  Planets are not cut.
+//!
+//! Firing builds heat at `mining_laser_heat_per_sec` and idling sheds it at
+//! `mining_laser_cool_per_sec`.  At full heat the beam locks out until heat
+//! falls to `mining_laser_recover_heat`.  Switching targets drops any partial
+//! unit.
+//!
+//! The beam is a stretched quad parented to the ship, tinted from white-hot
+//! to red as heat rises.  The ore HUD shows a heat row while the laser is
+//! warm.  In campaign the laser is only available with the **MINING LASER**
+//! primary selected.
+
+use super::{AsteroidMaterial, PlayerOre};
+use crate::asteroid::{
+    carve_asteroid_shape, collider_for_vertices, ray_polygon_distance, Asteroid, AsteroidSize,
+    BaseVertices, CraterData, Planet, Vertices,
+};
+use crate::config::PhysicsConfig;
+use crate::menu::SelectedGameMode;
+use crate::particles::{spawn_debris_particles, spawn_impact_particles};
+use crate::player::{
+    AimDirection, CampaignLoadout, CampaignPrimaryWeapon, Player, PreferredGamepad,
+};
+use crate::session_stats::{SessionStatEvent, StatWeapon};
+use crate::simulation::SimulationStats;
+use crate::spatial_partition::SpatialGrid;
+use bevy::input::gamepad::GamepadButton;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+/// Beam quad width (world units).
+const BEAM_WIDTH: f32 = 2.5;
+
+/// Seconds between cutting-spark bursts at the beam tip.
+const SPARK_INTERVAL_SECS: f32 = 0.06;
+
+/// Distance from the beam within which the spatial index returns asteroid
+/// centres; covers the largest hulls the beam can clip.
+const BEAM_PROBE_MARGIN: f32 = 140.0;
+
+#[derive(Default)]
+pub struct MiningLaserScratch {
+    spark_timer: f32,
+    nearby: Vec<Entity>,
+}
+
+/// Heat level and current cut of the mining laser.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
+pub struct MiningLaserState {
+    /// 0 (cold) to 1 (overheated).
+    pub heat: f32,
+    /// Set at full heat; cleared once heat falls to `mining_laser_recover_heat`.
+    pub overheated: bool,
+    /// Beam start and end (world space) while firing.
+    pub beam: Option<(Vec2, Vec2)>,
+    /// Asteroid being cut.
+    pub target: Option<Entity>,
+    /// Mass carved from `target` towards the next whole unit.
+    pub carve_progress: f32,
+}
+
+impl MiningLaserState {
+    /// Advance heat by `dt`; returns whether the beam fires this frame.
+    pub fn tick_heat(&mut self, trigger: bool, dt: f32, config: &PhysicsConfig) -> bool {
+        if self.overheated && self.heat <= config.mining_laser_recover_heat {
+            self.overheated = false;
+        }
+        let firing = trigger && !self.overheated;
+        if firing {
+            self.heat = (self.heat + config.mining_laser_heat_per_sec * dt).min(1.0);
+            if self.heat >= 1.0 {
+                self.overheated = true;
+            }
+        } else {
+            self.heat = (self.heat - config.mining_laser_cool_per_sec * dt).max(0.0);
+        }
+        firing
+    }
+
+    /// Cut `target` for `dt` at `rate` mass/s; returns whole units carved.
+    pub fn carve(&mut self, target: Entity, dt: f32, rate: f32) -> u32 {
+        if self.target != Some(target) {
+            self.target = Some(target);
+            self.carve_progress = 0.0;
+        }
+        self.carve_progress += rate.max(0.0) * dt;
+        let units = self.carve_progress.floor();
+        self.carve_progress -= units;
+        units as u32
+    }
+
+    fn clear_target(&mut self) {
+        self.target = None;
+        self.carve_progress = 0.0;
+    }
+}
+
+/// The beam quad, a child of the ship.  Holds its material for the heat tint.
+#[derive(Component)]
+pub struct MiningLaserBeam(Handle<ColorMaterial>);
+
+/// Marker for the mining laser row in the ore HUD.
+#[derive(Component)]
+pub struct MiningLaserHudRow;
+
+/// Marker for the mining laser row's text.
+#[derive(Component)]
+pub struct MiningLaserHudValueText;
+
+/// Beam colour: white-hot when cold, red near overheating.
+fn beam_color(heat: f32) -> Color {
+    let cool = Vec3::new(0.85, 1.0, 1.0);
+    let hot = Vec3::new(1.0, 0.25, 0.1);
+    let c = cool.lerp(hot, heat.clamp(0.0, 1.0));
+    Color::srgba(c.x, c.y, c.z, 0.85)
+}
+
+/// **Hold G**: heat the laser, find the first asteroid along the aim and
+/// carve whole mass units off it into [`PlayerOre`].
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn mining_laser_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    preferred: Res<PreferredGamepad>,
+    gamepads: Query<&Gamepad>,
+    time: Res<Time>,
+    aim: Res<AimDirection>,
+    (selected_mode, campaign_loadout): (Res<SelectedGameMode>, Res<CampaignLoadout>),
+    config: Res<PhysicsConfig>,
+    grid: Res<SpatialGrid>,
+    mut state: ResMut<MiningLaserState>,
+    mut ore: ResMut<PlayerOre>,
+    mut stats: ResMut<SimulationStats>,
+    mut session: MessageWriter<SessionStatEvent>,
+    q_player: Query<&Transform, With<Player>>,
+    q_asteroids: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&Velocity>,
+            &AsteroidSize,
+            &Vertices,
+            Option<&BaseVertices>,
+            Option<&CraterData>,
+            Option<&AsteroidMaterial>,
+        ),
+        (With<Asteroid>, Without<Planet>),
+    >,
+    mut scratch: Local<MiningLaserScratch>,
+) {
+    let available = *selected_mode != SelectedGameMode::Campaign
+        || campaign_loadout.primary == CampaignPrimaryWeapon::MiningLaser;
+    let gamepad_held = preferred
+        .0
+        .and_then(|entity| gamepads.get(entity).ok())
+        .is_some_and(|gp| gp.pressed(GamepadButton::LeftThumb));
+    let trigger = available && (keys.pressed(KeyCode::KeyG) || gamepad_held);
+
+    let dt = time.delta_secs();
+    let Ok(player_transform) = q_player.single() else {
+        state.beam = None;
+        state.clear_target();
+        return;
+    };
+    if !state.tick_heat(trigger, dt, &config) {
+        state.beam = None;
+        state.clear_target();
+        return;
+    }
+
+    let origin = player_transform.translation.truncate();
+    let ship_forward = player_transform.rotation.mul_vec3(Vec3::Y).truncate();
+    let dir = if aim.0.length_squared() > 0.01 {
+        aim.0.normalize_or_zero()
+    } else {
+        ship_forward.normalize_or_zero()
+    };
+    if dir == Vec2::ZERO {
+        state.beam = None;
+        return;
+    }
+
+    let range = config.mining_laser_range;
+    let scratch = &mut *scratch;
+    grid.ray_query(origin, dir, range, BEAM_PROBE_MARGIN, &mut scratch.nearby);
+    let hit = scratch
+        .nearby
+        .iter()
+        .filter_map(|&entity| q_asteroids.get(entity).ok())
+        .filter_map(|item| {
+            let (_, transform, _, _, vertices, ..) = item;
+            let pos = transform.translation.truncate();
+            let radius = vertices.0.iter().map(|v| v.length()).fold(0.0, f32::max);
+            if pos.distance(origin) - radius > range {
+                return None;
+            }
+            let world: Vec<Vec2> = vertices
+                .0
+                .iter()
+                .map(|v| pos + transform.rotation.mul_vec3(v.extend(0.0)).truncate())
+                .collect();
+            ray_polygon_distance(origin, dir, &world)
+                .filter(|&t| t <= range)
+                .map(|t| (item, t))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1));
+
+    let Some((item, t)) = hit else {
+        state.beam = Some((origin, origin + dir * range));
+        state.clear_target();
+        return;
+    };
+    let (entity, transform, velocity, size, vertices, base_vertices, crater_data, material) = item;
+    let cut = origin + dir * t;
+    state.beam = Some((origin, cut));
+
+    let vel = velocity.map_or(Vec2::ZERO, |v| v.linvel);
+    scratch.spark_timer -= dt;
+    if scratch.spark_timer <= 0.0 {
+        scratch.spark_timer = SPARK_INTERVAL_SECS;
+        spawn_impact_particles(&mut commands, cut, dir, vel);
+    }
+
+    let units = state.carve(entity, dt, config.mining_laser_carve_rate);
+    if units == 0 {
+        return;
+    }
+    let kind = material.copied().unwrap_or_default().ore_kind();
+    let pos = transform.translation.truncate();
+    ore.add_raw(kind, units.min(size.0));
+    session.write(SessionStatEvent::OreMined(units.min(size.0)));
+    if units >= size.0 {
+        commands.entity(entity).despawn();
+        stats.destroyed_total += 1;
+        session.write(SessionStatEvent::AsteroidDestroyed(StatWeapon::MiningLaser));
+        spawn_debris_particles(&mut commands, pos, vel, size.0.max(1));
+        state.clear_target();
+        return;
+    }
+
+    let new_mass = size.0 - units;
+    let impact_local = transform
+        .rotation
+        .inverse()
+        .mul_vec3((cut - pos).extend(0.0))
+        .truncate();
+    let base_local = base_vertices.map_or(&vertices.0, |base| &base.0);
+    let carved = carve_asteroid_shape(base_local, crater_data, impact_local, new_mass, &config);
+    commands.entity(entity).insert((
+        AsteroidSize(new_mass),
+        collider_for_vertices(&carved.base_vertices),
+        Vertices(carved.vertices),
+        BaseVertices(carved.base_vertices),
+        carved.craters,
+    ));
+}
+
+/// Place, tint and show/hide the beam quad; spawns it on first use.
+#[allow(clippy::type_complexity)]
+pub fn sync_mining_laser_beam_system(
+    mut commands: Commands,
+    state: Res<MiningLaserState>,
+    q_player: Query<(Entity, &Transform), With<Player>>,
+    mut q_beam: Query<(&MiningLaserBeam, &mut Transform, &mut Visibility), Without<Player>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let Ok((player, player_transform)) = q_player.single() else {
+        return;
+    };
+    let Some((start, end)) = state.beam else {
+        for (_, _, mut vis) in q_beam.iter_mut() {
+            vis.set_if_neq(Visibility::Hidden);
+        }
+        return;
+    };
+
+    // The quad is a child of the ship: express the world-space beam in the
+    // ship's frame.
+    let span = end - start;
+    let inv = player_transform.rotation.inverse();
+    let mid_local =
+        inv * ((start + end) * 0.5 - player_transform.translation.truncate()).extend(0.0);
+    let rotation = inv * Quat::from_rotation_z(span.to_angle() - std::f32::consts::FRAC_PI_2);
+    let transform = Transform {
+        translation: mid_local.with_z(0.1),
+        rotation,
+        scale: Vec3::new(BEAM_WIDTH, span.length().max(0.01), 1.0),
+    };
+
+    match q_beam.single_mut() {
+        Ok((beam, mut t, mut vis)) => {
+            *t = transform;
+            vis.set_if_neq(Visibility::Visible);
+            if let Some(mat) = materials.get_mut(&beam.0) {
+                mat.color = beam_color(state.heat);
+            }
+        }
+        Err(_) => {
+            let material = materials.add(ColorMaterial::from_color(beam_color(state.heat)));
+            commands.spawn((
+                MiningLaserBeam(material.clone()),
+                transform,
+                Visibility::Visible,
+                ChildOf(player),
+                Mesh2d(meshes.add(Rectangle::new(1.0, 1.0))),
+                MeshMaterial2d(material),
+            ));
+        }
+    }
+}
+
+/// Refresh the mining laser row of the ore HUD.
+pub fn mining_laser_hud_display_system(
+    state: Res<MiningLaserState>,
+    mut q_row: Query<&mut Visibility, With<MiningLaserHudRow>>,
+    mut q_text: Query<(&mut Text, &mut TextColor), With<MiningLaserHudValueText>>,
+) {
+    let vis = if state.heat > 0.0 {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut row_vis in q_row.iter_mut() {
+        row_vis.set_if_neq(vis);
+    }
+    let Ok((mut text, mut color)) = q_text.single_mut() else {
+        return;
+    };
+    let value = if state.overheated {
+        "LASER OVERHEATED".to_string()
+    } else {
+        format!("LASER HEAT {:.0}%", state.heat * 100.0)
+    };
+    if **text != value {
+        **text = value;
+    }
+    color.set_if_neq(TextColor(beam_color(state.heat).with_alpha(1.0)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overheating_locks_the_beam_until_it_cools() {
+        let config = PhysicsConfig::default();
+        let mut state = MiningLaserState::default();
+        let to_full = 1.0 / config.mining_laser_heat_per_sec;
+        assert!(state.tick_heat(true, to_full, &config));
+        assert!(state.overheated);
+        // Still held, but locked out and cooling.
+        assert!(!state.tick_heat(true, 0.5, &config));
+        assert!(state.heat < 1.0);
+
+        let to_recover =
+            (state.heat - config.mining_laser_recover_heat) / config.mining_laser_cool_per_sec;
+        state.tick_heat(false, to_recover + 0.01, &config);
+        assert!(state.tick_heat(true, 0.01, &config));
+        assert!(!state.overheated);
+    }
+
+    #[test]
+    fn carving_carries_partial_units_and_resets_on_retarget() {
+        let mut state = MiningLaserState::default();
+        let a = Entity::from_raw_u32(1).unwrap();
+        let b = Entity::from_raw_u32(2).unwrap();
+        assert_eq!(state.carve(a, 0.5, 1.5), 0);
+        assert_eq!(state.carve(a, 0.5, 1.5), 1);
+        assert!((state.carve_progress - 0.5).abs() < 1e-4);
+        assert_eq!(state.carve(b, 0.5, 1.5), 0);
+        assert!((state.carve_progress - 0.75).abs() < 1e-4);
+    }
+}
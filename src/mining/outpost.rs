@@ -0,0 +1,367 @@
+//! Mining outposts: player-built stations anchored to planetoid surfaces.
+//!
+//! Pressing **B** within `outpost_build_range` of a planetoid's surface spends
+//! `outpost_build_cost` iron and anchors a [`MiningOutpost`] there, facing
+//! away from the planetoid's centre.  The outpost is a child of the planetoid
+//! entity, so it follows its transform and is removed with it.  Each planetoid
+//! holds one outpost, and at most `outpost_max_count` stand at once.
+//!
+//! Every `outpost_ore_interval_secs` each outpost adds
+//! `outpost_ore_per_interval` iron to [`PlayerOre`].
+//!
+//! Outposts must be defended.  Enemies within `outpost_aggro_radius` of an
+//! outpost shoot it instead of the ship when it is the closer target (see
+//! [`enemy_fire_target`]).  Shots that pass within `outpost_hit_radius` deal
+//! `enemy_projectile_damage`.  An outpost at zero hit points is destroyed.
+//!
+//! Outposts have no collider: hits are distance checks, like the mining
+//! drone's pickups, so they never disturb the planetoid's physics.  The ore
+//! HUD gains an outpost row showing the count, the weakest outpost's health,
+//! an alert while one is under fire, and a build prompt when the ship is in
+//! range of a free planetoid.
+
+use super::{OreKind, PlayerOre};
+use crate::asteroid::{Planet, Vertices};
+use crate::config::PhysicsConfig;
+use crate::enemy::EnemyProjectile;
+use crate::particles::spawn_debris_particles;
+use crate::player::Player;
+use crate::session_stats::SessionStatEvent;
+use bevy::prelude::*;
+
+/// Outpost body size (world units).
+const OUTPOST_HALF_SIZE: f32 = 6.0;
+
+/// Seconds after a hit during which the HUD shows the attack alert.
+const OUTPOST_ALERT_SECS: f32 = 3.0;
+
+/// A mining outpost anchored to a planetoid.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component)]
+pub struct MiningOutpost {
+    pub health: f32,
+    /// Seconds accumulated towards the next ore delivery.
+    pub produce_secs: f32,
+    /// Seconds since the last enemy hit (`f32::INFINITY` if never hit).
+    pub since_hit_secs: f32,
+}
+
+impl MiningOutpost {
+    pub fn new(config: &PhysicsConfig) -> Self {
+        Self {
+            health: config.outpost_max_hp,
+            produce_secs: 0.0,
+            since_hit_secs: f32::INFINITY,
+        }
+    }
+
+    /// Advance production by `dt`; returns how many intervals completed.
+    pub fn tick_production(&mut self, dt: f32, interval_secs: f32) -> u32 {
+        let interval_secs = interval_secs.max(0.1);
+        self.produce_secs += dt;
+        let done = (self.produce_secs / interval_secs).floor();
+        self.produce_secs -= done * interval_secs;
+        done as u32
+    }
+}
+
+/// Marker for the outpost row in the ore HUD.
+#[derive(Component)]
+pub struct OutpostHudRow;
+
+/// Marker for the outpost row's text.
+#[derive(Component)]
+pub struct OutpostHudValueText;
+
+/// Planetoid radius: the farthest hull vertex from its centre.
+fn planet_radius(vertices: &Vertices) -> f32 {
+    vertices.0.iter().map(|v| v.length()).fold(0.0, f32::max)
+}
+
+/// Closest planetoid whose surface lies within `range` of `ship`.
+///
+/// `planets` holds `(entity, centre, radius)`.
+pub fn outpost_site(
+    ship: Vec2,
+    range: f32,
+    planets: impl IntoIterator<Item = (Entity, Vec2, f32)>,
+) -> Option<(Entity, Vec2)> {
+    planets
+        .into_iter()
+        .map(|(entity, centre, radius)| (entity, centre, ship.distance(centre) - radius))
+        .filter(|&(_, _, surface_dist)| surface_dist <= range)
+        .min_by(|a, b| a.2.total_cmp(&b.2))
+        .map(|(entity, centre, _)| (entity, centre))
+}
+
+/// Where an enemy at `enemy` should shoot: the nearest outpost within
+/// `aggro_radius` if it is closer than the ship, otherwise the ship.
+pub fn enemy_fire_target(
+    enemy: Vec2,
+    ship: Vec2,
+    outposts: impl IntoIterator<Item = Vec2>,
+    aggro_radius: f32,
+) -> Vec2 {
+    let ship_dist = enemy.distance(ship);
+    outposts
+        .into_iter()
+        .map(|pos| (pos, enemy.distance(pos)))
+        .filter(|&(_, dist)| dist <= aggro_radius && dist < ship_dist)
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map_or(ship, |(pos, _)| pos)
+}
+
+/// **B**: build an outpost on the nearest free planetoid in range.
+#[allow(clippy::too_many_arguments)]
+pub fn outpost_build_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    config: Res<PhysicsConfig>,
+    q_player: Query<&Transform, With<Player>>,
+    q_planets: Query<(Entity, &Transform, &Vertices), With<Planet>>,
+    q_outposts: Query<&ChildOf, With<MiningOutpost>>,
+    mut ore: ResMut<PlayerOre>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyB) {
+        return;
+    }
+    let Ok(player_transform) = q_player.single() else {
+        return;
+    };
+    let ship = player_transform.translation.truncate();
+
+    if q_outposts.iter().count() as u32 >= config.outpost_max_count {
+        info!("Outpost limit reached ({})", config.outpost_max_count);
+        return;
+    }
+    let occupied: Vec<Entity> = q_outposts
+        .iter()
+        .map(|child_of| child_of.parent())
+        .collect();
+    let Some((planet, centre)) = outpost_site(
+        ship,
+        config.outpost_build_range,
+        q_planets
+            .iter()
+            .filter(|(entity, ..)| !occupied.contains(entity))
+            .map(|(entity, transform, vertices)| {
+                (
+                    entity,
+                    transform.translation.truncate(),
+                    planet_radius(vertices),
+                )
+            }),
+    ) else {
+        info!("No free planetoid within outpost build range");
+        return;
+    };
+    let iron = &mut ore.raw[OreKind::Iron.index()];
+    if *iron < config.outpost_build_cost {
+        info!(
+            "Outpost needs {} iron (have {})",
+            config.outpost_build_cost, *iron
+        );
+        return;
+    }
+    *iron -= config.outpost_build_cost;
+
+    let Ok((_, planet_transform, vertices)) = q_planets.get(planet) else {
+        return;
+    };
+    // Anchor on the surface point facing the ship, in the planetoid's frame.
+    let outward = (ship - centre).normalize_or(Vec2::Y);
+    let local_dir = (planet_transform.rotation.inverse() * outward.extend(0.0))
+        .truncate()
+        .normalize_or(Vec2::Y);
+    let local = local_dir * (planet_radius(vertices) + OUTPOST_HALF_SIZE);
+    let transform = Transform::from_translation(local.extend(0.1)).with_rotation(
+        Quat::from_rotation_z(local_dir.to_angle() - std::f32::consts::FRAC_PI_2),
+    );
+    commands.spawn((
+        MiningOutpost::new(&config),
+        transform,
+        Visibility::default(),
+        ChildOf(planet),
+        Mesh2d(meshes.add(Rectangle::new(
+            OUTPOST_HALF_SIZE * 2.0,
+            OUTPOST_HALF_SIZE * 2.0,
+        ))),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgb(1.0, 0.75, 0.3)))),
+    ));
+    info!("Outpost built ({} iron)", config.outpost_build_cost);
+}
+
+/// Deliver ore from every outpost on its interval.
+pub fn outpost_production_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    mut q_outposts: Query<&mut MiningOutpost>,
+    mut ore: ResMut<PlayerOre>,
+    mut session: MessageWriter<SessionStatEvent>,
+) {
+    let dt = time.delta_secs();
+    for mut outpost in q_outposts.iter_mut() {
+        outpost.since_hit_secs += dt;
+        let done = outpost.tick_production(dt, config.outpost_ore_interval_secs);
+        if done > 0 {
+            let mined = done * config.outpost_ore_per_interval;
+            ore.add_raw(OreKind::Iron, mined);
+            session.write(SessionStatEvent::OreMined(mined));
+        }
+    }
+}
+
+/// Enemy shots that reach an outpost damage it; destroyed outposts despawn.
+pub fn outpost_damage_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    q_projectiles: Query<(Entity, &Transform), With<EnemyProjectile>>,
+    mut q_outposts: Query<(Entity, &GlobalTransform, &mut MiningOutpost)>,
+) {
+    if q_outposts.is_empty() {
+        return;
+    }
+    let hit_radius_sq = config.outpost_hit_radius * config.outpost_hit_radius;
+    for (projectile, projectile_transform) in q_projectiles.iter() {
+        let shot = projectile_transform.translation.truncate();
+        let Some((entity, pos, mut outpost)) = q_outposts
+            .iter_mut()
+            .map(|(entity, global, outpost)| (entity, global.translation().truncate(), outpost))
+            .find(|(_, pos, outpost)| {
+                outpost.health > 0.0 && pos.distance_squared(shot) <= hit_radius_sq
+            })
+        else {
+            continue;
+        };
+        commands.entity(projectile).despawn();
+        outpost.health -= config.enemy_projectile_damage;
+        outpost.since_hit_secs = 0.0;
+        if outpost.health <= 0.0 {
+            spawn_debris_particles(&mut commands, pos, Vec2::ZERO, 10);
+            commands.entity(entity).despawn();
+            warn!("Mining outpost destroyed");
+        }
+    }
+}
+
+/// Refresh the outpost row of the ore HUD.
+#[allow(clippy::type_complexity)]
+pub fn outpost_hud_display_system(
+    config: Res<PhysicsConfig>,
+    q_player: Query<&Transform, With<Player>>,
+    q_planets: Query<(Entity, &Transform, &Vertices), With<Planet>>,
+    q_outposts: Query<(&ChildOf, &MiningOutpost)>,
+    mut q_row: Query<&mut Visibility, With<OutpostHudRow>>,
+    mut q_text: Query<&mut Text, With<OutpostHudValueText>>,
+) {
+    let count = q_outposts.iter().count() as u32;
+    let can_build = count < config.outpost_max_count
+        && q_player.single().ok().is_some_and(|transform| {
+            outpost_site(
+                transform.translation.truncate(),
+                config.outpost_build_range,
+                q_planets
+                    .iter()
+                    .filter(|(entity, ..)| {
+                        !q_outposts
+                            .iter()
+                            .any(|(child_of, _)| child_of.parent() == *entity)
+                    })
+                    .map(|(entity, transform, vertices)| {
+                        (
+                            entity,
+                            transform.translation.truncate(),
+                            planet_radius(vertices),
+                        )
+                    }),
+            )
+            .is_some()
+        });
+
+    let vis = if count > 0 || can_build {
+        Visibility::Inherited
+    } else {
+        Visibility::Hidden
+    };
+    for mut row_vis in q_row.iter_mut() {
+        row_vis.set_if_neq(vis);
+    }
+    let Ok(mut text) = q_text.single_mut() else {
+        return;
+    };
+
+    let mut value = format!("OUTPOSTS {count}/{}", config.outpost_max_count);
+    if let Some(weakest) = q_outposts
+        .iter()
+        .map(|(_, outpost)| outpost.health)
+        .min_by(f32::total_cmp)
+    {
+        let pct = 100.0 * weakest / config.outpost_max_hp.max(1.0);
+        value.push_str(&format!("  HP {pct:.0}%"));
+    }
+    if q_outposts
+        .iter()
+        .any(|(_, outpost)| outpost.since_hit_secs < OUTPOST_ALERT_SECS)
+    {
+        value.push_str("  !! UNDER ATTACK");
+    }
+    if can_build {
+        value.push_str(&format!("  [B] BUILD ({} IRON)", config.outpost_build_cost));
+    }
+    if **text != value {
+        **text = value;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(i: u32) -> Entity {
+        Entity::from_raw_u32(i).unwrap()
+    }
+
+    #[test]
+    fn build_site_is_nearest_surface_in_range() {
+        let planets = [
+            (entity(1), Vec2::new(0.0, 0.0), 100.0),
+            (entity(2), Vec2::new(400.0, 0.0), 50.0),
+        ];
+        // 140 from the big one's surface, far from the small one.
+        assert_eq!(outpost_site(Vec2::new(0.0, -240.0), 120.0, planets), None);
+        assert_eq!(
+            outpost_site(Vec2::new(290.0, 0.0), 120.0, planets),
+            Some((entity(2), Vec2::new(400.0, 0.0)))
+        );
+        assert_eq!(
+            outpost_site(Vec2::new(0.0, 150.0), 120.0, planets),
+            Some((entity(1), Vec2::ZERO))
+        );
+    }
+
+    #[test]
+    fn enemies_shoot_a_closer_outpost_within_aggro_range() {
+        let enemy = Vec2::ZERO;
+        let ship = Vec2::new(500.0, 0.0);
+        let near = Vec2::new(0.0, 200.0);
+        let far = Vec2::new(0.0, -480.0);
+        assert_eq!(enemy_fire_target(enemy, ship, [far, near], 450.0), near);
+        assert_eq!(enemy_fire_target(enemy, ship, [far], 450.0), ship);
+        // An outpost behind the ship's distance is ignored.
+        assert_eq!(
+            enemy_fire_target(enemy, Vec2::new(100.0, 0.0), [near], 450.0),
+            Vec2::new(100.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn production_carries_leftover_time() {
+        let config = PhysicsConfig::default();
+        let mut outpost = MiningOutpost::new(&config);
+        assert_eq!(outpost.tick_production(9.0, 10.0), 0);
+        assert_eq!(outpost.tick_production(12.0, 10.0), 2);
+        assert!((outpost.produce_secs - 1.0).abs() < 1e-4);
+    }
+}
@@ -0,0 +1,428 @@
+//! Small-body catalog importer for the Catalog scenario.
+//!
+//! Reads a CSV of heliocentric orbital elements (the column layout of a JPL
+//! Small-Body Database query export) from `assets/small_bodies.csv`, places
+//! every body at its position on the orbit for the listed mean anomaly, and
+//! projects that position onto the ecliptic plane.  The result is scaled into
+//! world units around an anchored central star and each body is given the
+//! vis-viva speed for its orbit under the game's own gravity.
+//!
+//! ## CSV format
+//!
+//! The first non-comment line is a header; columns are matched by name
+//! (case-insensitive) and may appear in any order.  Lines starting with `#`
+//! are ignored.
+//!
+//! | Column              | Meaning                                  | Required |
+//! |---------------------|------------------------------------------|----------|
+//! | `full_name`/`name`  | Label used in log output                 | no       |
+//! | `a`                 | Semi-major axis (AU)                     | yes      |
+//! | `e`                 | Eccentricity (`0 ≤ e < 1`)               | yes      |
+//! | `i`                 | Inclination (deg)                        | no (0)   |
+//! | `om`                | Longitude of ascending node (deg)        | no (0)   |
+//! | `w`                 | Argument of perihelion (deg)             | no (0)   |
+//! | `ma`                | Mean anomaly (deg)                       | yes      |
+//! | `diameter`          | Diameter (km)                            | no       |
+//! | `h`                 | Absolute magnitude, used when no diameter| no       |
+//!
+//! Rows with missing or unparseable required values, or unbound orbits, are
+//! skipped and counted rather than failing the whole import.
+//!
+//! ## Mapping
+//!
+//! All mapping parameters live in the `catalog_*` keys of
+//! [`PhysicsConfig`]: AU-to-world scale, central star position and mass,
+//! accepted orbit radius band, body cap, diameter → `AsteroidSize` mapping and
+//! a global orbital speed multiplier.
+
+use crate::asteroid::{
+    fragment_vertices_for_mass, spawn_asteroid_with_vertices, spawn_massive_planet,
+};
+use crate::config::PhysicsConfig;
+use crate::loading::ScenarioSpawnQueue;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f32::consts::TAU;
+
+/// Catalog file read by [`spawn_catalog_scenario`].
+pub const SMALL_BODY_CATALOG_PATH: &str = "assets/small_bodies.csv";
+
+/// Geometric albedo assumed when a diameter has to be estimated from `H`.
+const ASSUMED_ALBEDO: f32 = 0.14;
+
+/// Newton iterations for Kepler's equation; converges well below `f32`
+/// precision for every bound orbit in a few steps.
+const KEPLER_ITERATIONS: usize = 12;
+
+/// Visual radius of the central star, as a multiple of `planetoid_base_radius`.
+const CATALOG_STAR_RADIUS_SCALE: f32 = 5.2;
+
+/// One row of the catalog.  Angles are stored in degrees, as in the file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrbitalElements {
+    pub name: String,
+    pub semi_major_au: f32,
+    pub eccentricity: f32,
+    pub inclination_deg: f32,
+    pub ascending_node_deg: f32,
+    pub arg_perihelion_deg: f32,
+    pub mean_anomaly_deg: f32,
+    pub diameter_km: Option<f32>,
+    pub abs_magnitude: Option<f32>,
+}
+
+impl OrbitalElements {
+    /// Best available diameter estimate (km).
+    ///
+    /// Uses the listed diameter when present, otherwise the standard
+    /// `D = 1329 / √p · 10^(−H/5)` estimate with [`ASSUMED_ALBEDO`].
+    pub fn estimated_diameter_km(&self) -> Option<f32> {
+        self.diameter_km.or_else(|| {
+            self.abs_magnitude
+                .map(|h| 1329.0 / ASSUMED_ALBEDO.sqrt() * 10f32.powf(-h / 5.0))
+        })
+    }
+}
+
+/// Result of parsing a catalog file.
+#[derive(Debug, Clone, Default)]
+pub struct ParsedCatalog {
+    pub bodies: Vec<OrbitalElements>,
+    /// Data rows that were dropped (bad numbers, missing values, `e ≥ 1`).
+    pub skipped_rows: usize,
+}
+
+/// A body's ecliptic-plane projection, still in AU.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProjectedBody {
+    pub position_au: Vec2,
+    /// Unit direction of travel in the ecliptic plane.
+    pub velocity_dir: Vec2,
+}
+
+/// Column indices resolved from the header row.
+struct CatalogColumns {
+    name: Option<usize>,
+    a: usize,
+    e: usize,
+    i: Option<usize>,
+    om: Option<usize>,
+    w: Option<usize>,
+    ma: usize,
+    diameter: Option<usize>,
+    h: Option<usize>,
+}
+
+impl CatalogColumns {
+    fn from_header(header: &str) -> Result<Self, String> {
+        let names: Vec<String> = header
+            .split(',')
+            .map(|field| field.trim().trim_matches('"').to_ascii_lowercase())
+            .collect();
+        let find = |candidates: &[&str]| {
+            names
+                .iter()
+                .position(|name| candidates.contains(&name.as_str()))
+        };
+        let require = |candidates: &[&str]| {
+            find(candidates).ok_or_else(|| format!("missing required column '{}'", candidates[0]))
+        };
+
+        Ok(Self {
+            name: find(&["full_name", "name"]),
+            a: require(&["a"])?,
+            e: require(&["e"])?,
+            i: find(&["i"]),
+            om: find(&["om", "node"]),
+            w: find(&["w", "peri"]),
+            ma: require(&["ma", "m"])?,
+            diameter: find(&["diameter"]),
+            h: find(&["h"]),
+        })
+    }
+}
+
+/// Parse catalog CSV text.
+///
+/// Fails only when the header is absent or lacks a required column; bad data
+/// rows are counted in [`ParsedCatalog::skipped_rows`].
+pub fn parse_orbital_csv(contents: &str) -> Result<ParsedCatalog, String> {
+    let mut lines = contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'));
+    let header = lines.next().ok_or("catalog has no header row")?;
+    let columns = CatalogColumns::from_header(header)?;
+
+    let mut catalog = ParsedCatalog::default();
+    for line in lines {
+        let fields: Vec<&str> = line
+            .split(',')
+            .map(|field| field.trim().trim_matches('"'))
+            .collect();
+        let number = |index: usize| fields.get(index).and_then(|v| v.parse::<f32>().ok());
+        let optional = |index: Option<usize>| index.and_then(number);
+        let angle = |index: Option<usize>| optional(index).unwrap_or(0.0);
+
+        let (Some(a), Some(e), Some(ma)) =
+            (number(columns.a), number(columns.e), number(columns.ma))
+        else {
+            catalog.skipped_rows += 1;
+            continue;
+        };
+        if a <= 0.0 || !(0.0..1.0).contains(&e) {
+            catalog.skipped_rows += 1;
+            continue;
+        }
+
+        let name = columns
+            .name
+            .and_then(|index| fields.get(index))
+            .map(|name| name.to_string())
+            .unwrap_or_else(|| format!("#{}", catalog.bodies.len() + 1));
+
+        catalog.bodies.push(OrbitalElements {
+            name,
+            semi_major_au: a,
+            eccentricity: e,
+            inclination_deg: angle(columns.i),
+            ascending_node_deg: angle(columns.om),
+            arg_perihelion_deg: angle(columns.w),
+            mean_anomaly_deg: ma,
+            diameter_km: optional(columns.diameter).filter(|d| *d > 0.0),
+            abs_magnitude: optional(columns.h),
+        });
+    }
+
+    Ok(catalog)
+}
+
+/// Solve Kepler's equation `E − e·sin E = M` for the eccentric anomaly.
+pub fn solve_kepler(mean_anomaly: f32, eccentricity: f32) -> f32 {
+    let m = mean_anomaly.rem_euclid(TAU);
+    let mut ecc_anomaly = if eccentricity < 0.8 {
+        m
+    } else {
+        std::f32::consts::PI
+    };
+    for _ in 0..KEPLER_ITERATIONS {
+        let f = ecc_anomaly - eccentricity * ecc_anomaly.sin() - m;
+        let f_prime = 1.0 - eccentricity * ecc_anomaly.cos();
+        ecc_anomaly -= f / f_prime;
+    }
+    ecc_anomaly
+}
+
+/// Place a body on its orbit and drop the ecliptic-normal component.
+pub fn project_to_ecliptic(elements: &OrbitalElements) -> ProjectedBody {
+    let a = elements.semi_major_au;
+    let e = elements.eccentricity;
+    let ecc_anomaly = solve_kepler(elements.mean_anomaly_deg.to_radians(), e);
+    let (sin_e, cos_e) = ecc_anomaly.sin_cos();
+    let root = (1.0 - e * e).sqrt();
+
+    // Perifocal frame: x toward perihelion, y along the direction of motion.
+    let perifocal_pos = Vec2::new(a * (cos_e - e), a * root * sin_e);
+    let perifocal_vel = Vec2::new(-sin_e, root * cos_e);
+
+    let (sin_node, cos_node) = elements.ascending_node_deg.to_radians().sin_cos();
+    let (sin_peri, cos_peri) = elements.arg_perihelion_deg.to_radians().sin_cos();
+    let cos_inc = elements.inclination_deg.to_radians().cos();
+
+    // First two rows of R_z(Ω)·R_x(i)·R_z(ω); the third (out-of-plane) row
+    // is what the projection discards.
+    let col_x = Vec2::new(
+        cos_node * cos_peri - sin_node * sin_peri * cos_inc,
+        sin_node * cos_peri + cos_node * sin_peri * cos_inc,
+    );
+    let col_y = Vec2::new(
+        -cos_node * sin_peri - sin_node * cos_peri * cos_inc,
+        -sin_node * sin_peri + cos_node * cos_peri * cos_inc,
+    );
+    let rotate = |v: Vec2| col_x * v.x + col_y * v.y;
+
+    ProjectedBody {
+        position_au: rotate(perifocal_pos),
+        velocity_dir: rotate(perifocal_vel).normalize_or_zero(),
+    }
+}
+
+/// Map a diameter to an `AsteroidSize` unit count.
+///
+/// Size follows area (`(D / reference)²`) and is clamped to the configured
+/// band; bodies without any size information get the minimum.
+pub fn catalog_size_for_diameter(diameter_km: Option<f32>, config: &PhysicsConfig) -> u32 {
+    let min = config.catalog_size_min.max(1);
+    let max = config.catalog_size_max.max(min);
+    let Some(diameter) = diameter_km else {
+        return min;
+    };
+    let reference = config.catalog_reference_diameter_km.max(f32::EPSILON);
+    ((diameter / reference).powi(2).round() as u32).clamp(min, max)
+}
+
+/// Spawns the "catalog" scenario from [`SMALL_BODY_CATALOG_PATH`].
+///
+/// An anchored star of mass `catalog_central_mass` sits at
+/// `(catalog_center_x, catalog_center_y)`.  Up to `catalog_max_bodies` rows
+/// are projected, scaled by `catalog_au_to_world`, and spawned if their
+/// projected radius falls inside `[catalog_min_orbit_radius,
+/// catalog_max_orbit_radius]`.  Speeds come from vis-viva,
+/// `v² = G·M·ρ·(2/r − 1/a)`, the same mass-scaled balance the Orbit scenario
+/// uses for circular rings, multiplied by `catalog_velocity_scale`.
+///
+/// If the file is missing or unreadable the Orbit scenario is spawned instead
+/// so the run never starts empty.
+pub fn spawn_catalog_scenario(queue: &mut ScenarioSpawnQueue, config: &PhysicsConfig, seed: u64) {
+    let catalog = match std::fs::read_to_string(SMALL_BODY_CATALOG_PATH)
+        .map_err(|err| format!("failed reading {SMALL_BODY_CATALOG_PATH}: {err}"))
+        .and_then(|contents| {
+            parse_orbital_csv(&contents)
+                .map_err(|err| format!("failed parsing {SMALL_BODY_CATALOG_PATH}: {err}"))
+        }) {
+        Ok(catalog) => catalog,
+        Err(err) => {
+            eprintln!("⚠ {err}; falling back to the Orbit scenario");
+            crate::asteroid::spawn_orbit_scenario(queue, config, seed);
+            return;
+        }
+    };
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    info!("Catalog scenario seed: {}", seed);
+
+    let center = Vec2::new(config.catalog_center_x, config.catalog_center_y);
+    let star_mass = config.catalog_central_mass;
+    let star_radius = config.planetoid_base_radius * CATALOG_STAR_RADIUS_SCALE;
+    let star_config = config.clone();
+    queue.push(move |commands| {
+        spawn_massive_planet(commands, center, star_mass, star_radius, &star_config);
+    });
+
+    let mu = config.gravity_const * config.catalog_central_mass as f32 * config.asteroid_density;
+    let mut spawned = 0usize;
+    let mut out_of_range = 0usize;
+
+    for body in catalog.bodies.iter().take(config.catalog_max_bodies) {
+        let projected = project_to_ecliptic(body);
+        let offset = projected.position_au * config.catalog_au_to_world;
+        let radius = offset.length();
+        if radius < config.catalog_min_orbit_radius || radius > config.catalog_max_orbit_radius {
+            debug!(
+                "Catalog body {} projects to r={:.0}u, outside the arena; skipped",
+                body.name, radius
+            );
+            out_of_range += 1;
+            continue;
+        }
+
+        let size = catalog_size_for_diameter(body.estimated_diameter_km(), config);
+        let vertices = fragment_vertices_for_mass(size, &mut rng, config);
+        let semi_major = body.semi_major_au * config.catalog_au_to_world;
+        let speed = (mu * (2.0 / radius - 1.0 / semi_major)).max(0.0).sqrt()
+            * config.catalog_velocity_scale;
+
+        let position = center + offset;
+        let velocity = Velocity {
+            linvel: projected.velocity_dir * speed,
+            angvel: rng.gen_range(-0.6..0.6),
+        };
+        queue.push(move |commands| {
+            let entity =
+                spawn_asteroid_with_vertices(commands, position, &vertices, Color::WHITE, size);
+            commands.entity(entity).insert(velocity);
+        });
+        spawned += 1;
+    }
+
+    info!(
+        "Catalog scenario: spawned {} bodies ({} outside radius band, {} bad rows)",
+        spawned, out_of_range, catalog.skipped_rows
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn circular(a: f32, mean_anomaly_deg: f32) -> OrbitalElements {
+        OrbitalElements {
+            name: "test".to_string(),
+            semi_major_au: a,
+            eccentricity: 0.0,
+            inclination_deg: 0.0,
+            ascending_node_deg: 0.0,
+            arg_perihelion_deg: 0.0,
+            mean_anomaly_deg,
+            diameter_km: None,
+            abs_magnitude: None,
+        }
+    }
+
+    #[test]
+    fn parser_matches_columns_by_name_and_skips_bad_rows() {
+        let csv = "# comment line\n\
+                   full_name,e,a,ma,i,om,w,diameter,H\n\
+                   Ceres,0.0785,2.767,291.4,10.59,80.3,73.6,939.4,3.3\n\
+                   broken,0.1,not_a_number,10,0,0,0,,\n\
+                   hyperbolic,1.2,3.0,10,0,0,0,,\n\
+                   NoSize,0.2,1.5,45,,,,,17.5\n";
+        let catalog = parse_orbital_csv(csv).unwrap();
+
+        assert_eq!(catalog.skipped_rows, 2);
+        assert_eq!(catalog.bodies.len(), 2);
+        assert_eq!(catalog.bodies[0].name, "Ceres");
+        assert_eq!(catalog.bodies[0].semi_major_au, 2.767);
+        assert_eq!(catalog.bodies[0].diameter_km, Some(939.4));
+        assert_eq!(catalog.bodies[1].inclination_deg, 0.0);
+        assert_eq!(catalog.bodies[1].diameter_km, None);
+        assert!(catalog.bodies[1].estimated_diameter_km().unwrap() > 0.5);
+
+        assert!(parse_orbital_csv("name,a,e\n1,2,3\n").is_err());
+    }
+
+    #[test]
+    fn kepler_solution_satisfies_equation() {
+        for &e in &[0.0, 0.1, 0.5, 0.9] {
+            for &m in &[0.1, 1.0, 2.5, 4.0, 6.0] {
+                let ecc_anomaly = solve_kepler(m, e);
+                let residual = ecc_anomaly - e * ecc_anomaly.sin() - m;
+                assert!(residual.abs() < 1e-4, "e={e} M={m} residual={residual}");
+            }
+        }
+    }
+
+    #[test]
+    fn circular_planar_orbit_projects_to_radius_a_with_tangent_velocity() {
+        let projected = project_to_ecliptic(&circular(2.0, 90.0));
+        assert!((projected.position_au - Vec2::new(0.0, 2.0)).length() < 1e-4);
+        assert!(projected.position_au.dot(projected.velocity_dir).abs() < 1e-4);
+        // Prograde: counter-clockwise seen from the ecliptic north pole.
+        assert!(projected.position_au.perp_dot(projected.velocity_dir) > 0.0);
+
+        let mut polar = circular(2.0, 90.0);
+        polar.inclination_deg = 90.0;
+        let flattened = project_to_ecliptic(&polar);
+        assert!(flattened.position_au.length() < 1e-4);
+    }
+
+    #[test]
+    fn diameter_maps_to_clamped_area_size() {
+        let config = PhysicsConfig::default();
+        let reference = config.catalog_reference_diameter_km;
+        assert_eq!(
+            catalog_size_for_diameter(None, &config),
+            config.catalog_size_min
+        );
+        assert_eq!(
+            catalog_size_for_diameter(Some(reference * 2.0), &config),
+            4.clamp(config.catalog_size_min, config.catalog_size_max)
+        );
+        assert_eq!(
+            catalog_size_for_diameter(Some(reference * 100.0), &config),
+            config.catalog_size_max
+        );
+    }
+}
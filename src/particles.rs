@@ -1,4 +1,4 @@
-//! Particle effects: impact sparks, missile trails, ship thrust exhaust, debris dust, and merge glows.
+//! Particle effects: impact sparks, missile trails, ship thrust exhaust, debris dust, merge glows and shockwave rings.
 //!
 //! ## Design
 //!
@@ -12,7 +12,9 @@
 //! | `particle_update_system`   | Update   | Move, fade, and despawn expired particles  |
 //!
 //! Particle entities are spawned by free functions (`spawn_impact_particles`,
-//! `spawn_missile_trail_particles`, `spawn_debris_particles`, `spawn_merge_particles`) that take only
+//! `spawn_missile_trail_particles`, `spawn_debris_particles`, `spawn_debris_burst`,
+//! `spawn_merge_particles`, `spawn_merge_join_particles`,
+//! `spawn_shockwave_ring_particles`) that take only
 //! `&mut Commands` — no `Assets` access needed at spawn time.  The
 //! `attach_particle_mesh_system` supplies the Mesh2d one frame later, which is
 //! imperceptible at 60 Hz.
@@ -20,7 +22,30 @@
 //! A single shared circle-mesh [`ParticleMesh`] resource is created at plugin
 //! startup to avoid per-particle mesh allocation.  Each particle receives its
 //! own unique [`ColorMaterial`] so its alpha can be faded individually.
-
+//!
+//! ## Pooling & Budget
+//!
+//! Spawn helpers do not spawn entities directly.  They queue a command that
+//! goes through [`ParticlePool`]:
+//!
+//! 1. Below `particle_budget` live particles, a dormant pooled entity is
+//!    reused (keeping its mesh and material) or, if none is free, a new one is
+//!    spawned.
+//! 2. At the budget, the live particle with the lowest [`ParticlePriority`]
+//!    below the request's is evicted and reused in place.  If nothing ranks
+//!    lower, the request is dropped.
+//!
+//! Expired particles are hidden and tagged [`ParticleDormant`] rather than
+//! despawned, up to `particle_budget` pooled entities, so steady combat
+//! recycles the same entities instead of churning archetype moves and
+//! allocations.  Counters live in [`ParticlePoolStats`] for the stats overlay.
+//! Under sustained frame-time pressure the effective budget shrinks with the
+//! quality tier chosen by [`crate::simulation::governor`].
+
+use crate::config::PhysicsConfig;
+use crate::constants::PARTICLE_BUDGET;
+use crate::rng::visual_rng;
+use crate::simulation::governor::FrameTimeGovernor;
 use bevy::prelude::*;
 use bevy_asset::RenderAssetUsages;
 use bevy_mesh::{Indices, PrimitiveTopology};
@@ -32,6 +57,40 @@ use rand::Rng;
 #[derive(Resource)]
 pub struct ParticleMesh(pub Handle<Mesh>);
 
+/// Free list and counters for pooled particle entities.
+#[derive(Resource, Default, Debug)]
+pub struct ParticlePool {
+    /// Dormant entities ready for reuse.
+    free: Vec<Entity>,
+    /// Active particles as of the last update plus those spawned since.
+    live: u32,
+    pub stats: ParticlePoolStats,
+}
+
+/// Particle pool counters shown in the stats overlay.
+#[derive(Default, Debug, Clone, Copy)]
+pub struct ParticlePoolStats {
+    pub live: u32,
+    pub pooled: u32,
+    pub peak_live: u32,
+    /// New entities spawned because the free list was empty.
+    pub spawned_total: u64,
+    /// Requests served by a dormant or evicted entity.
+    pub reused_total: u64,
+    /// Live particles cut short for a higher-priority request.
+    pub evicted_total: u64,
+    /// Requests discarded at the budget with nothing lower to evict.
+    pub dropped_total: u64,
+}
+
+impl ParticlePool {
+    fn sync_stats(&mut self) {
+        self.stats.live = self.live;
+        self.stats.pooled = self.free.len() as u32;
+        self.stats.peak_live = self.stats.peak_live.max(self.live);
+    }
+}
+
 // ── Component ────────────────────────────────────────────────────────────────
 
 /// Short-lived visual particle entity.
@@ -58,6 +117,29 @@ pub struct Particle {
     pub material: Option<Handle<ColorMaterial>>,
 }
 
+/// Eviction rank of a particle when the budget is full; higher survives.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ParticlePriority {
+    /// Missile and ion trails.
+    Trail,
+    /// Ship exhaust.
+    Thrust,
+    /// Tractor beam emission.
+    Tractor,
+    /// Merge glow.
+    Merge,
+    /// Impact shockwave ring.
+    Shockwave,
+    /// Destruction dust.
+    Debris,
+    /// Hit sparks.
+    Impact,
+}
+
+/// Marks an expired particle entity parked in the [`ParticlePool`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct ParticleDormant;
+
 /// Visual mode for tractor beam particle emission.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TractorBeamVfxMode {
@@ -72,10 +154,12 @@ pub struct ParticlesPlugin;
 
 impl Plugin for ParticlesPlugin {
     fn build(&self, app: &mut App) {
-        app.add_systems(Startup, init_particle_mesh).add_systems(
-            Update,
-            (attach_particle_mesh_system, particle_update_system).chain(),
-        );
+        app.insert_resource(ParticlePool::default())
+            .add_systems(Startup, init_particle_mesh)
+            .add_systems(
+                Update,
+                (attach_particle_mesh_system, particle_update_system).chain(),
+            );
     }
 }
 
@@ -111,22 +195,28 @@ pub fn attach_particle_mesh_system(
 }
 
 /// Advance all particles: translate by velocity, fade alpha quadratically,
-/// and despawn any whose age has exceeded their lifetime.
+/// and return any whose age has exceeded their lifetime to the pool.
+///
+/// Also recounts live particles so the pool budget self-corrects after bulk
+/// despawns (session cleanup, mission transitions).
 pub fn particle_update_system(
     mut commands: Commands,
     time: Res<Time>,
+    mut pool: ResMut<ParticlePool>,
     mut materials: ResMut<Assets<ColorMaterial>>,
-    mut query: Query<(Entity, &mut Transform, &mut Particle)>,
+    mut query: Query<(Entity, &mut Transform, &mut Particle), Without<ParticleDormant>>,
 ) {
     let dt = time.delta_secs();
+    let mut live = 0_u32;
 
     for (entity, mut transform, mut particle) in query.iter_mut() {
         particle.age += dt;
 
         if particle.age >= particle.lifetime {
-            commands.entity(entity).despawn();
+            commands.queue(move |world: &mut World| retire_particle(world, entity));
             continue;
         }
+        live += 1;
 
         // Translate by velocity.
         transform.translation.x += particle.velocity.x * dt;
@@ -142,6 +232,206 @@ pub fn particle_update_system(
             }
         }
     }
+
+    pool.live = live;
+    pool.sync_stats();
+}
+
+// ── Pool commands ─────────────────────────────────────────────────────────────
+
+/// Live particle budget, scaled down by the frame-time governor under load.
+fn particle_budget(world: &World) -> u32 {
+    let Some(config) = world.get_resource::<PhysicsConfig>() else {
+        return PARTICLE_BUDGET;
+    };
+    world
+        .get_resource::<FrameTimeGovernor>()
+        .map_or(config.particle_budget, |governor| {
+            governor.effective_particle_budget(config)
+        })
+        .max(1)
+}
+
+/// Queue a particle spawn through the [`ParticlePool`].
+fn emit_particle(
+    commands: &mut Commands,
+    priority: ParticlePriority,
+    particle: Particle,
+    translation: Vec3,
+) {
+    commands.queue(move |world: &mut World| {
+        spawn_pooled_particle(world, priority, particle, translation);
+    });
+}
+
+/// Serve one particle request: reuse, evict, spawn, or drop.
+///
+/// Worlds without a [`ParticlePool`] (headless unit tests) spawn directly.
+fn spawn_pooled_particle(
+    world: &mut World,
+    priority: ParticlePriority,
+    mut particle: Particle,
+    translation: Vec3,
+) {
+    let Some(live) = world.get_resource::<ParticlePool>().map(|pool| pool.live) else {
+        world.spawn((
+            particle,
+            priority,
+            Transform::from_translation(translation),
+            Visibility::default(),
+        ));
+        return;
+    };
+
+    let target = if live >= particle_budget(world) {
+        let victim = eviction_victim(world, priority);
+        let mut pool = world.resource_mut::<ParticlePool>();
+        let Some(victim) = victim else {
+            pool.stats.dropped_total += 1;
+            return;
+        };
+        pool.stats.evicted_total += 1;
+        pool.live = pool.live.saturating_sub(1);
+        Some(victim)
+    } else {
+        take_free_particle(world)
+    };
+
+    match target {
+        Some(entity) => {
+            // Keep the entity's existing material so no new asset is allocated.
+            let material = world
+                .get::<Particle>(entity)
+                .and_then(|old| old.material.clone());
+            if let Some(handle) = &material {
+                if let Some(mut materials) = world.get_resource_mut::<Assets<ColorMaterial>>() {
+                    if let Some(mat) = materials.get_mut(handle) {
+                        mat.color = Color::srgba(particle.r, particle.g, particle.b, 1.0);
+                    }
+                }
+            }
+            particle.material = material;
+            world
+                .entity_mut(entity)
+                .insert((
+                    particle,
+                    priority,
+                    Transform::from_translation(translation),
+                    Visibility::Inherited,
+                ))
+                .remove::<ParticleDormant>();
+            world.resource_mut::<ParticlePool>().stats.reused_total += 1;
+        }
+        None => {
+            world.spawn((
+                particle,
+                priority,
+                Transform::from_translation(translation),
+                Visibility::default(),
+            ));
+            world.resource_mut::<ParticlePool>().stats.spawned_total += 1;
+        }
+    }
+
+    let mut pool = world.resource_mut::<ParticlePool>();
+    pool.live += 1;
+    pool.sync_stats();
+}
+
+/// Park up to `loading_particle_prewarm` dormant particles in the pool.
+///
+/// Run on the loading screen: `attach_particle_mesh_system` gives each one its
+/// mesh and material on the next update, so the first bursts of a session
+/// reuse entities instead of spawning them.  Never fills the free list past
+/// the particle budget.
+pub fn prewarm_particle_pool(world: &mut World) {
+    let Some(config) = world.get_resource::<PhysicsConfig>() else {
+        return;
+    };
+    let target = config.loading_particle_prewarm.min(particle_budget(world));
+    let Some(pooled) = world
+        .get_resource::<ParticlePool>()
+        .map(|pool| pool.free.len() as u32)
+    else {
+        return;
+    };
+
+    for _ in pooled..target {
+        let entity = world
+            .spawn((
+                Particle {
+                    velocity: Vec2::ZERO,
+                    age: 0.0,
+                    lifetime: 0.0,
+                    r: 1.0,
+                    g: 1.0,
+                    b: 1.0,
+                    material: None,
+                },
+                ParticlePriority::Trail,
+                Transform::default(),
+                Visibility::Hidden,
+                ParticleDormant,
+            ))
+            .id();
+        world.resource_mut::<ParticlePool>().free.push(entity);
+    }
+    world.resource_mut::<ParticlePool>().sync_stats();
+}
+
+/// Pop the first still-valid dormant entity from the free list.
+fn take_free_particle(world: &mut World) -> Option<Entity> {
+    loop {
+        let entity = world.resource_mut::<ParticlePool>().free.pop()?;
+        if world.get::<ParticleDormant>(entity).is_some() {
+            return Some(entity);
+        }
+    }
+}
+
+/// Lowest-priority live particle ranked below `priority`, preferring the one
+/// closest to expiry.
+fn eviction_victim(world: &mut World, priority: ParticlePriority) -> Option<Entity> {
+    let mut query =
+        world.query_filtered::<(Entity, &Particle, &ParticlePriority), Without<ParticleDormant>>();
+    query
+        .iter(world)
+        .filter(|(_, _, p)| **p < priority)
+        .min_by(|(_, pa, a), (_, pb, b)| {
+            let progress = |p: &Particle| p.age / p.lifetime.max(1e-6);
+            a.cmp(b).then_with(|| progress(pb).total_cmp(&progress(pa)))
+        })
+        .map(|(entity, _, _)| entity)
+}
+
+/// Park an expired particle in the pool, or despawn it if the pool is full.
+///
+/// Skips entities that were already recycled for a new request between the
+/// expiry being detected and this command applying.
+fn retire_particle(world: &mut World, entity: Entity) {
+    let budget = particle_budget(world);
+    let still_expired = world
+        .get::<Particle>(entity)
+        .is_some_and(|p| p.age >= p.lifetime)
+        && world.get::<ParticleDormant>(entity).is_none();
+    if !still_expired {
+        return;
+    }
+    let pooled = match world.get_resource_mut::<ParticlePool>() {
+        Some(mut pool) if (pool.free.len() as u32) < budget => {
+            pool.free.push(entity);
+            pool.sync_stats();
+            true
+        }
+        _ => false,
+    };
+    if pooled {
+        world
+            .entity_mut(entity)
+            .insert((ParticleDormant, Visibility::Hidden));
+    } else {
+        world.entity_mut(entity).despawn();
+    }
 }
 
 // ── Public spawn helpers ──────────────────────────────────────────────────────
@@ -157,7 +447,7 @@ pub fn spawn_impact_particles(
     impact_dir: Vec2,
     asteroid_vel: Vec2,
 ) {
-    let mut rng = rand::thread_rng();
+    let mut rng = visual_rng();
     let count = 8_u32;
 
     for _ in 0..count {
@@ -176,7 +466,9 @@ pub fn spawn_impact_particles(
         let lifetime = rng.gen_range(0.20_f32..0.40_f32);
         let offset = Vec2::new(rng.gen_range(-2.0..2.0), rng.gen_range(-2.0..2.0));
 
-        commands.spawn((
+        emit_particle(
+            commands,
+            ParticlePriority::Impact,
             Particle {
                 velocity,
                 age: 0.0,
@@ -186,9 +478,8 @@ pub fn spawn_impact_particles(
                 b,
                 material: None,
             },
-            Transform::from_translation((pos + offset).extend(0.9)),
-            Visibility::default(),
-        ));
+            (pos + offset).extend(0.9),
+        );
     }
 }
 
@@ -201,7 +492,7 @@ pub fn spawn_missile_trail_particles(
     reverse_dir: Vec2,
     missile_vel: Vec2,
 ) {
-    let mut rng = rand::thread_rng();
+    let mut rng = visual_rng();
     let count = 2_u32;
 
     let base = if reverse_dir.length_squared() > 1e-6 {
@@ -224,7 +515,9 @@ pub fn spawn_missile_trail_particles(
         let lateral = Vec2::new(-base.y, base.x) * rng.gen_range(-1.1_f32..1.1_f32);
         let back_offset = base * rng.gen_range(0.0_f32..2.5_f32);
 
-        commands.spawn((
+        emit_particle(
+            commands,
+            ParticlePriority::Trail,
             Particle {
                 velocity,
                 age: 0.0,
@@ -234,9 +527,8 @@ pub fn spawn_missile_trail_particles(
                 b,
                 material: None,
             },
-            Transform::from_translation((pos + lateral + back_offset).extend(0.9)),
-            Visibility::default(),
-        ));
+            (pos + lateral + back_offset).extend(0.9),
+        );
     }
 }
 
@@ -248,7 +540,7 @@ pub fn spawn_ship_thrust_particles(
     ship_vel: Vec2,
     intensity: f32,
 ) {
-    let mut rng = rand::thread_rng();
+    let mut rng = visual_rng();
     let clamped_intensity = intensity.clamp(0.15, 1.0);
     let count = if clamped_intensity >= 0.7 {
         3_u32
@@ -276,7 +568,9 @@ pub fn spawn_ship_thrust_particles(
         let lateral = Vec2::new(-base.y, base.x) * rng.gen_range(-1.2_f32..1.2_f32);
         let back_offset = base * rng.gen_range(0.0_f32..3.0_f32);
 
-        commands.spawn((
+        emit_particle(
+            commands,
+            ParticlePriority::Thrust,
             Particle {
                 velocity,
                 age: 0.0,
@@ -286,9 +580,8 @@ pub fn spawn_ship_thrust_particles(
                 b,
                 material: None,
             },
-            Transform::from_translation((pos + lateral + back_offset).extend(0.9)),
-            Visibility::default(),
-        ));
+            (pos + lateral + back_offset).extend(0.9),
+        );
     }
 }
 
@@ -296,8 +589,14 @@ pub fn spawn_ship_thrust_particles(
 ///
 /// `n` controls the density: more fragments = more particles.
 pub fn spawn_debris_particles(commands: &mut Commands, pos: Vec2, asteroid_vel: Vec2, n: u32) {
-    let mut rng = rand::thread_rng();
-    let count = (6 + n * 2).min(16);
+    spawn_debris_burst(commands, pos, asteroid_vel, (6 + n * 2).min(16));
+}
+
+/// Spawn exactly `count` debris dust particles.
+///
+/// Used by [`crate::feedback`], which sizes the burst from event intensity.
+pub fn spawn_debris_burst(commands: &mut Commands, pos: Vec2, asteroid_vel: Vec2, count: u32) {
+    let mut rng = visual_rng();
 
     for _ in 0..count {
         let angle = rng.gen_range(0.0_f32..std::f32::consts::TAU);
@@ -314,7 +613,9 @@ pub fn spawn_debris_particles(commands: &mut Commands, pos: Vec2, asteroid_vel:
         let lifetime = rng.gen_range(0.25_f32..0.55_f32);
         let offset = Vec2::new(rng.gen_range(-4.0..4.0), rng.gen_range(-4.0..4.0));
 
-        commands.spawn((
+        emit_particle(
+            commands,
+            ParticlePriority::Debris,
             Particle {
                 velocity,
                 age: 0.0,
@@ -324,16 +625,15 @@ pub fn spawn_debris_particles(commands: &mut Commands, pos: Vec2, asteroid_vel:
                 b,
                 material: None,
             },
-            Transform::from_translation((pos + offset).extend(0.9)),
-            Visibility::default(),
-        ));
+            (pos + offset).extend(0.9),
+        );
     }
 }
 
-/// Spawn a cyan glow burst at `center` when two or more asteroids merge.
-pub fn spawn_merge_particles(commands: &mut Commands, center: Vec2) {
-    let mut rng = rand::thread_rng();
-    let count = 10_u32;
+/// Spawn a cyan glow burst of `count` particles at `center` when two or more
+/// asteroids merge.
+pub fn spawn_merge_particles(commands: &mut Commands, center: Vec2, count: u32) {
+    let mut rng = visual_rng();
 
     for _ in 0..count {
         let angle = rng.gen_range(0.0_f32..std::f32::consts::TAU);
@@ -348,7 +648,9 @@ pub fn spawn_merge_particles(commands: &mut Commands, center: Vec2) {
         let lifetime = rng.gen_range(0.35_f32..0.60_f32);
         let offset = Vec2::new(rng.gen_range(-6.0..6.0), rng.gen_range(-6.0..6.0));
 
-        commands.spawn((
+        emit_particle(
+            commands,
+            ParticlePriority::Merge,
             Particle {
                 velocity,
                 age: 0.0,
@@ -358,9 +660,75 @@ pub fn spawn_merge_particles(commands: &mut Commands, center: Vec2) {
                 b,
                 material: None,
             },
-            Transform::from_translation((center + offset).extend(0.9)),
-            Visibility::default(),
-        ));
+            (center + offset).extend(0.9),
+        );
+    }
+}
+
+/// Spawn a short stream of merge-glow particles from `from` that reaches `to`
+/// as it fades out `secs` later.
+///
+/// Emitted once per source body of an animated merge (see
+/// `graphics::merge_animation`), so the bodies visibly pour into the
+/// composite.
+pub fn spawn_merge_join_particles(commands: &mut Commands, from: Vec2, to: Vec2, secs: f32) {
+    let mut rng = visual_rng();
+    let count = 6_u32;
+    let lifetime = secs.max(0.05);
+    let velocity = (to - from) / lifetime;
+
+    for _ in 0..count {
+        let g = rng.gen_range(0.80_f32..1.0_f32);
+        let offset = Vec2::new(rng.gen_range(-3.0..3.0), rng.gen_range(-3.0..3.0));
+
+        emit_particle(
+            commands,
+            ParticlePriority::Merge,
+            Particle {
+                velocity: velocity * rng.gen_range(0.85_f32..1.0_f32),
+                age: 0.0,
+                lifetime,
+                r: rng.gen_range(0.20_f32..0.55_f32),
+                g,
+                b: g,
+                material: None,
+            },
+            (from + offset).extend(0.9),
+        );
+    }
+}
+
+/// Spawn a pale ring of particles at `center` that expands to `radius`.
+///
+/// Emitted for every [`crate::simulation::shockwave::Shockwave`]; the ring
+/// reaches `radius` as it fades out.
+pub fn spawn_shockwave_ring_particles(commands: &mut Commands, center: Vec2, radius: f32) {
+    let mut rng = visual_rng();
+    let count = 24_u32;
+    let lifetime = 0.45_f32;
+    let speed = radius / lifetime;
+
+    for i in 0..count {
+        let angle = std::f32::consts::TAU * (i as f32 + rng.gen_range(-0.2..0.2)) / count as f32;
+        let dir = Vec2::new(angle.cos(), angle.sin());
+
+        // Warm white, like the debris dust but brighter.
+        let lum = rng.gen_range(0.85_f32..1.0_f32);
+
+        emit_particle(
+            commands,
+            ParticlePriority::Shockwave,
+            Particle {
+                velocity: dir * speed * rng.gen_range(0.92_f32..1.0_f32),
+                age: 0.0,
+                lifetime,
+                r: lum,
+                g: lum * 0.92,
+                b: lum * 0.78,
+                material: None,
+            },
+            (center + dir * 6.0).extend(0.9),
+        );
     }
 }
 
@@ -376,7 +744,7 @@ pub fn spawn_tractor_beam_particles(
     mode: TractorBeamVfxMode,
     intensity: f32,
 ) {
-    let mut rng = rand::thread_rng();
+    let mut rng = visual_rng();
     let base_dir = if force_dir.length_squared() > 1e-6 {
         force_dir.normalize()
     } else {
@@ -432,7 +800,9 @@ pub fn spawn_tractor_beam_particles(
         let lateral = Vec2::new(-base_dir.y, base_dir.x) * rng.gen_range(-2.5_f32..2.5_f32);
         let offset = base_dir * rng.gen_range(-2.0_f32..2.0_f32) + lateral;
 
-        commands.spawn((
+        emit_particle(
+            commands,
+            ParticlePriority::Tractor,
             Particle {
                 velocity,
                 age: 0.0,
@@ -442,9 +812,8 @@ pub fn spawn_tractor_beam_particles(
                 b,
                 material: None,
             },
-            Transform::from_translation((origin + offset).extend(0.9)),
-            Visibility::default(),
-        ));
+            (origin + offset).extend(0.9),
+        );
     }
 }
 
@@ -452,7 +821,7 @@ pub fn spawn_tractor_beam_particles(
 ///
 /// `dir_hint` biases the spray direction when non-zero.
 pub fn spawn_ion_particles(commands: &mut Commands, origin: Vec2, dir_hint: Vec2, base_vel: Vec2) {
-    let mut rng = rand::thread_rng();
+    let mut rng = visual_rng();
     let count = 2_u32;
 
     let use_dir = if dir_hint.length_squared() > 1e-6 {
@@ -486,7 +855,9 @@ pub fn spawn_ion_particles(commands: &mut Commands, origin: Vec2, dir_hint: Vec2
             )
         };
 
-        commands.spawn((
+        emit_particle(
+            commands,
+            ParticlePriority::Trail,
             Particle {
                 velocity,
                 age: 0.0,
@@ -496,9 +867,8 @@ pub fn spawn_ion_particles(commands: &mut Commands, origin: Vec2, dir_hint: Vec2
                 b,
                 material: None,
             },
-            Transform::from_translation((origin + lateral).extend(0.9)),
-            Visibility::default(),
-        ));
+            (origin + lateral).extend(0.9),
+        );
     }
 }
 
@@ -545,3 +915,154 @@ fn circle_mesh(radius: f32, sides: u32) -> Mesh {
     mesh.insert_indices(Indices::U32(indices));
     mesh
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pooled_world(budget: u32) -> World {
+        let mut world = World::new();
+        world.insert_resource(PhysicsConfig {
+            particle_budget: budget,
+            ..Default::default()
+        });
+        world.insert_resource(ParticlePool::default());
+        world
+    }
+
+    fn particle(age: f32) -> Particle {
+        Particle {
+            velocity: Vec2::ZERO,
+            age,
+            lifetime: 1.0,
+            r: 1.0,
+            g: 1.0,
+            b: 1.0,
+            material: None,
+        }
+    }
+
+    fn priorities(world: &mut World) -> Vec<ParticlePriority> {
+        let mut query =
+            world.query_filtered::<&ParticlePriority, (With<Particle>, Without<ParticleDormant>)>();
+        let mut found: Vec<_> = query.iter(world).copied().collect();
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn full_budget_evicts_lowest_priority_first() {
+        let mut world = pooled_world(2);
+        spawn_pooled_particle(
+            &mut world,
+            ParticlePriority::Trail,
+            particle(0.0),
+            Vec3::ZERO,
+        );
+        spawn_pooled_particle(
+            &mut world,
+            ParticlePriority::Debris,
+            particle(0.0),
+            Vec3::ZERO,
+        );
+
+        spawn_pooled_particle(
+            &mut world,
+            ParticlePriority::Impact,
+            particle(0.0),
+            Vec3::ZERO,
+        );
+
+        assert_eq!(
+            priorities(&mut world),
+            vec![ParticlePriority::Debris, ParticlePriority::Impact]
+        );
+        let stats = world.resource::<ParticlePool>().stats;
+        assert_eq!(stats.evicted_total, 1);
+        assert_eq!(stats.live, 2);
+    }
+
+    #[test]
+    fn full_budget_drops_request_with_nothing_lower_to_evict() {
+        let mut world = pooled_world(1);
+        spawn_pooled_particle(
+            &mut world,
+            ParticlePriority::Impact,
+            particle(0.0),
+            Vec3::ZERO,
+        );
+
+        spawn_pooled_particle(
+            &mut world,
+            ParticlePriority::Trail,
+            particle(0.0),
+            Vec3::ZERO,
+        );
+
+        assert_eq!(priorities(&mut world), vec![ParticlePriority::Impact]);
+        assert_eq!(world.resource::<ParticlePool>().stats.dropped_total, 1);
+    }
+
+    #[test]
+    fn retired_particle_is_reused_instead_of_spawning() {
+        let mut world = pooled_world(4);
+        spawn_pooled_particle(
+            &mut world,
+            ParticlePriority::Thrust,
+            particle(2.0),
+            Vec3::ZERO,
+        );
+        let entity = world
+            .query_filtered::<Entity, With<Particle>>()
+            .single(&world)
+            .unwrap();
+
+        retire_particle(&mut world, entity);
+        assert!(world.get::<ParticleDormant>(entity).is_some());
+        world.resource_mut::<ParticlePool>().live = 0;
+
+        spawn_pooled_particle(
+            &mut world,
+            ParticlePriority::Impact,
+            particle(0.0),
+            Vec3::ONE,
+        );
+
+        assert!(world.get::<ParticleDormant>(entity).is_none());
+        assert_eq!(
+            world.get::<ParticlePriority>(entity),
+            Some(&ParticlePriority::Impact)
+        );
+        let stats = world.resource::<ParticlePool>().stats;
+        assert_eq!(stats.spawned_total, 1);
+        assert_eq!(stats.reused_total, 1);
+    }
+
+    #[test]
+    fn prewarm_fills_the_pool_up_to_the_budget() {
+        let mut world = pooled_world(4);
+        world
+            .resource_mut::<PhysicsConfig>()
+            .loading_particle_prewarm = 10;
+
+        prewarm_particle_pool(&mut world);
+        prewarm_particle_pool(&mut world);
+
+        let dormant = world
+            .query_filtered::<Entity, (With<Particle>, With<ParticleDormant>)>()
+            .iter(&world)
+            .count();
+        assert_eq!(dormant, 4, "capped by the budget, and idempotent");
+        assert_eq!(world.resource::<ParticlePool>().stats.pooled, 4);
+
+        spawn_pooled_particle(
+            &mut world,
+            ParticlePriority::Impact,
+            particle(0.0),
+            Vec3::ZERO,
+        );
+        let stats = world.resource::<ParticlePool>().stats;
+        assert_eq!(stats.spawned_total, 0);
+        assert_eq!(stats.reused_total, 1);
+    }
+}
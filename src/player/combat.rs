@@ -1,5 +1,6 @@
 //! Projectile firing, lifetime management, player-asteroid collision damage,
 //! and the asteroid splitting / chipping logic triggered on projectile hits.
+//! Bullet and missile hits share one pipeline in [`combat_resolution`].
 //!
 //! ## Destruction rules by asteroid size
 //!
@@ -32,20 +33,26 @@
 use super::state::{
     AimDirection, AimIdleTimer, CampaignLoadout, CampaignPrimaryWeapon, CampaignSecondaryWeapon,
     Missile, MissileAmmo, MissileCooldown, Player, PlayerFireCooldown, PlayerHealth, PlayerLives,
-    PlayerScore, PreferredGamepad, PrimaryWeaponFireRateLevel, PrimaryWeaponUpgradeTracks,
-    Projectile,
+    PlayerScore, PlayerShield, PreferredGamepad, PrimaryWeaponFireRateLevel,
+    PrimaryWeaponUpgradeTracks, Projectile,
 };
+use super::turret::TurretRound;
+use crate::alloc_profile::AllocTag;
 use crate::asteroid::{
-    apply_crater_deformation, canonical_vertices_for_mass, rescale_vertices_to_area,
-    spawn_asteroid_with_vertices, Asteroid, AsteroidSize, BaseVertices, CraterData, Planet,
-    Vertices,
+    collider_for_vertices, excavate_planet_shape, fragment_vertices_for_mass, ray_polygon_distance,
+    spawn_asteroid_with_vertices, Asteroid, AsteroidSize, BaseVertices, CraterData, MergeCooldown,
+    Planet, Vertices,
 };
+use crate::audio::{play_sfx, Sfx};
 use crate::config::PhysicsConfig;
+use crate::enemy::DeflectedShot;
 use crate::menu::{GameState, SelectedGameMode};
-use crate::mining::spawn_ore_drop;
-use crate::particles::{
-    spawn_debris_particles, spawn_impact_particles, spawn_missile_trail_particles,
-};
+use crate::mining::AsteroidMaterial;
+use crate::particles::spawn_missile_trail_particles;
+use crate::rng::{visual_rng, GameplayRng};
+use crate::session_stats::{SessionStatEvent, StatWeapon};
+use crate::simulation::volatile::Volatile;
+use crate::wave_telemetry::{DamageSource, PlayerDamaged};
 use bevy::input::gamepad::GamepadAxis;
 use bevy::input::gamepad::GamepadButton;
 use bevy::input::mouse::MouseButton;
@@ -55,10 +62,11 @@ use rand::Rng;
 
 #[path = "combat_helpers.rs"]
 mod helpers;
-use helpers::{
-    area_weighted_mass_partition, even_mass_partition, impact_radiating_split_basis,
-    normalized_fragment_hull, polygon_area, split_convex_polygon_world,
-};
+use helpers::{even_mass_partition, missile_split_hulls};
+
+#[path = "combat_resolution.rs"]
+pub mod combat_resolution;
+use combat_resolution::{HitOutcome, HitResolver, HitTarget, WeaponProfile};
 
 #[derive(Clone, Copy)]
 struct PrimaryProjectileProfile {
@@ -98,31 +106,6 @@ fn primary_projectile_profile(
     }
 }
 
-#[inline]
-fn primary_destroy_threshold(
-    primary: CampaignPrimaryWeapon,
-    tracks: &PrimaryWeaponUpgradeTracks,
-) -> u32 {
-    match primary {
-        CampaignPrimaryWeapon::Blaster => tracks.max_destroy_size(),
-        CampaignPrimaryWeapon::MiningLaser => tracks.max_destroy_size(),
-        CampaignPrimaryWeapon::PlasmaRifle => tracks.max_destroy_size(),
-    }
-}
-
-#[inline]
-fn primary_max_chip_size(
-    primary: CampaignPrimaryWeapon,
-    tracks: &PrimaryWeaponUpgradeTracks,
-    target_mass: u32,
-) -> u32 {
-    match primary {
-        CampaignPrimaryWeapon::Blaster => tracks.max_chip_size().min(target_mass / 2).max(1),
-        CampaignPrimaryWeapon::MiningLaser => tracks.max_chip_size().min(target_mass / 2).max(1),
-        CampaignPrimaryWeapon::PlasmaRifle => tracks.max_chip_size().min(target_mass / 2).max(1),
-    }
-}
-
 // ── Projectile firing ─────────────────────────────────────────────────────────
 
 /// Unified fire system: handles Space / left-click (keyboard+mouse) and the
@@ -215,13 +198,17 @@ pub fn projectile_fire_system(
         // rather than the sci-fi "energy blaster" behaviour intended.
         Sensor,
         Ccd { enabled: true },
+        // GROUP_6 (enemy projectiles) so shots can intercept incoming fire.
         CollisionGroups::new(
             bevy_rapier2d::geometry::Group::GROUP_3,
-            bevy_rapier2d::geometry::Group::GROUP_1 | bevy_rapier2d::geometry::Group::GROUP_5,
+            bevy_rapier2d::geometry::Group::GROUP_1
+                | bevy_rapier2d::geometry::Group::GROUP_5
+                | bevy_rapier2d::geometry::Group::GROUP_6,
         ),
-        ActiveCollisionTypes::DYNAMIC_KINEMATIC,
+        ActiveCollisionTypes::DYNAMIC_KINEMATIC | ActiveCollisionTypes::KINEMATIC_KINEMATIC,
         ActiveEvents::COLLISION_EVENTS,
     ));
+    play_sfx(&mut commands, Sfx::Blaster, 0.5);
 }
 
 // ── Projectile lifetime ───────────────────────────────────────────────────────
@@ -229,25 +216,41 @@ pub fn projectile_fire_system(
 /// Age projectiles each frame and despawn them when they expire or leave bounds.
 ///
 /// A projectile that expires without [`Projectile::was_hit`] being set is
-/// considered a **miss** and resets the hit streak to zero.
+/// considered a **miss** and resets the hit streak to zero.  Turret rounds
+/// ([`TurretRound`]) are not the player's shots and never break the streak.
+#[allow(clippy::type_complexity)]
 pub fn despawn_old_projectiles_system(
     mut commands: Commands,
-    mut q: Query<(Entity, &mut Projectile, &Velocity)>,
+    mut q: Query<(
+        Entity,
+        &mut Projectile,
+        &Velocity,
+        Has<TurretRound>,
+        Has<DeflectedShot>,
+    )>,
     time: Res<Time>,
     config: Res<PhysicsConfig>,
     mut score: ResMut<PlayerScore>,
+    mut session: MessageWriter<SessionStatEvent>,
 ) {
     let dt = time.delta_secs();
-    for (entity, mut proj, velocity) in q.iter_mut() {
+    for (entity, mut proj, velocity, turret_round, deflected) in q.iter_mut() {
         proj.age += dt;
         proj.distance_traveled += velocity.linvel.length() * dt;
         let expired = proj.age >= config.projectile_lifetime
             || proj.distance_traveled > config.projectile_max_dist;
         if expired || proj.was_hit {
-            if expired && !proj.was_hit {
+            if expired && !proj.was_hit && !turret_round && !deflected {
                 // Projectile ran out of range without hitting anything — break streak.
                 score.streak = 0;
             }
+            if proj.was_hit && !deflected {
+                session.write(SessionStatEvent::Hit(if turret_round {
+                    StatWeapon::Turret
+                } else {
+                    StatWeapon::Primary
+                }));
+            }
             commands.entity(entity).despawn();
         }
     }
@@ -325,6 +328,7 @@ pub fn missile_fire_system(
             age: 0.0,
             distance_traveled: 0.0,
             trail_emit_timer: 0.0,
+            homing_target: None,
         },
         Transform::from_translation(spawn_pos.extend(0.0)),
         Visibility::default(),
@@ -343,6 +347,7 @@ pub fn missile_fire_system(
         ActiveCollisionTypes::DYNAMIC_KINEMATIC,
         ActiveEvents::COLLISION_EVENTS,
     ));
+    play_sfx(&mut commands, Sfx::Missile, 0.8);
 }
 
 /// Accelerate missiles in-flight until they reach configured max speed.
@@ -422,21 +427,37 @@ pub fn missile_trail_particles_system(
 /// | `display_level >= size` | Full decomposition into unit fragments |
 /// | `<= destroy_threshold()` | Immediate destroy + double destroy bonus |
 /// | `> destroy_threshold()` | Split into level-scaled convex fragments (no chip path) |
-#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+///
+/// Destroys and full decompositions emit a
+/// [`Shockwave`](crate::simulation::shockwave::Shockwave) scaled by the
+/// asteroid's mass; splits do not.  A [`Volatile`] asteroid is lit instead.
+///
+/// This system pairs missiles with asteroids and keeps the missile telemetry;
+/// the hit itself goes through [`HitResolver::resolve_weapon_hit`] with
+/// [`WeaponProfile::missile`].
+#[allow(clippy::type_complexity)]
 pub fn missile_asteroid_hit_system(
     mut commands: Commands,
     mut collision_events: MessageReader<CollisionEvent>,
     q_asteroids: Query<
-        (&AsteroidSize, &Transform, &Velocity, &Vertices),
+        (
+            &AsteroidSize,
+            &Transform,
+            &Velocity,
+            &Vertices,
+            Option<&BaseVertices>,
+            Option<&CraterData>,
+            Option<&AsteroidMaterial>,
+            Has<Volatile>,
+        ),
         (With<Asteroid>, Without<Planet>),
     >,
     q_missiles: Query<&Transform, With<Missile>>,
-    mut stats: ResMut<crate::simulation::SimulationStats>,
-    mut score: ResMut<PlayerScore>,
+    mut resolver: HitResolver,
     mut missile_telemetry: ResMut<crate::simulation::MissileTelemetry>,
-    config: Res<PhysicsConfig>,
     missile_level: Res<super::SecondaryWeaponLevel>,
 ) {
+    let profile = WeaponProfile::missile(&missile_level, &resolver.config);
     let mut processed_asteroids: std::collections::HashSet<Entity> = Default::default();
     let mut processed_missiles: std::collections::HashSet<Entity> = Default::default();
 
@@ -461,7 +482,9 @@ pub fn missile_asteroid_hit_system(
             continue;
         }
 
-        let Ok((size, transform, velocity, vertices)) = q_asteroids.get(asteroid_entity) else {
+        let Ok((size, transform, velocity, vertices, base_vertices, craters, material, volatile)) =
+            q_asteroids.get(asteroid_entity)
+        else {
             continue;
         };
 
@@ -475,215 +498,64 @@ pub fn missile_asteroid_hit_system(
             .unwrap_or_else(|_| transform.translation.truncate());
 
         commands.entity(missile_entity).despawn();
-
-        let pos = transform.translation.truncate();
-        let vel = velocity.linvel;
-        let ang_vel = velocity.angvel;
-        let n = size.0;
-
-        // Missiles grant streak + multiplier like bullets.
-        score.hits += 1;
-        score.streak += 1;
-        let multiplier = score.multiplier();
         missile_telemetry.hits += 1;
-
-        let destroy_threshold = missile_level.destroy_threshold();
-
-        if n <= destroy_threshold {
-            // ── Instant destroy (small asteroids) ─────────────────────────────
-            commands.entity(asteroid_entity).despawn();
-            stats.destroyed_total += 1;
-            score.destroyed += 1;
-            missile_telemetry.instant_destroy_events += 1;
-            missile_telemetry.destroyed_mass_total += n;
-            // Missiles award double the destroy bonus for small targets.
-            score.points += multiplier + 10 * multiplier;
-
-            // Spawn ore drops (one per unit mass destroyed).
-            let drop_count = n.max(1);
-            for i in 0..drop_count {
-                let angle = std::f32::consts::TAU * (i as f32 / drop_count as f32);
-                let offset = Vec2::new(angle.cos(), angle.sin()) * 6.0;
-                spawn_ore_drop(&mut commands, pos + offset, vel);
-            }
-            spawn_debris_particles(&mut commands, pos, vel, n + 2);
-        } else if missile_level.can_fully_decompose_size(n) {
-            // ── Full decomposition into unit asteroids ───────────────────────
-            commands.entity(asteroid_entity).despawn();
-            stats.split_total += 1;
-            score.points += multiplier;
-            missile_telemetry.full_decompose_events += 1;
-            missile_telemetry.decomposed_mass_total += n;
-
-            let impact_dir = (missile_pos - pos).normalize_or_zero();
-            let impact_dir = if impact_dir == Vec2::ZERO {
-                Vec2::X
-            } else {
-                impact_dir
-            };
-            let base_angle = impact_dir.y.atan2(impact_dir.x);
-            for i in 0..n {
-                let angle = base_angle + std::f32::consts::TAU * (i as f32 / n as f32);
-                let dir = Vec2::new(angle.cos(), angle.sin());
-                let spawn_pos = pos + dir * 9.0;
-                let spawn_vel = vel + dir * 30.0;
-                spawn_fragment_of_mass(
-                    &mut commands,
-                    spawn_pos,
-                    spawn_vel,
-                    ang_vel,
-                    config.asteroid_density,
-                    1,
-                );
-            }
-            spawn_debris_particles(&mut commands, pos, vel, n.min(10));
-        } else {
-            // ── Split large asteroid into level-scaled convex fragments ───────
-            score.points += multiplier;
-            missile_telemetry.split_events += 1;
-            let rot = transform.rotation;
-            let world_verts: Vec<Vec2> = vertices
-                .0
-                .iter()
-                .map(|v| pos + rot.mul_vec3(v.extend(0.0)).truncate())
-                .collect();
-
-            let split_axis = (missile_pos - pos).normalize_or_zero();
-            let split_axis = if split_axis == Vec2::ZERO {
-                Vec2::X
-            } else {
-                split_axis
-            };
-
-            let target_pieces = missile_level.split_piece_count(&config).min(n).max(2);
-            let mut fragment_hulls: Vec<Vec<Vec2>> = vec![world_verts.clone()];
-            let mut split_attempt = 0_u32;
-
-            while fragment_hulls.len() < target_pieces as usize {
-                let Some((largest_idx, largest_hull)) = fragment_hulls
-                    .iter()
-                    .enumerate()
-                    .max_by(|(_, a), (_, b)| polygon_area(a).total_cmp(&polygon_area(b)))
-                    .map(|(idx, hull)| (idx, hull.clone()))
-                else {
-                    break;
-                };
-
-                let Some((split_origin, base_normal)) =
-                    impact_radiating_split_basis(&largest_hull, missile_pos, split_axis)
-                else {
-                    break;
-                };
-
-                let spread = 0.42 * (split_attempt as f32 + 1.0);
-                let base_angle = base_normal.to_angle();
-                let candidate_axes = [
-                    base_normal,
-                    Vec2::from_angle(base_angle + spread),
-                    Vec2::from_angle(base_angle - spread),
-                    Vec2::new(-base_normal.y, base_normal.x).normalize_or_zero(),
-                ];
-
-                let mut split_result: Option<(Vec<Vec2>, Vec<Vec2>)> = None;
-                for axis in candidate_axes {
-                    if axis.length_squared() < 1e-5 {
-                        continue;
-                    }
-                    let (front_raw, back_raw) =
-                        split_convex_polygon_world(&largest_hull, split_origin, axis);
-                    let Some(front_hull) = normalized_fragment_hull(&front_raw) else {
-                        continue;
-                    };
-                    let Some(back_hull) = normalized_fragment_hull(&back_raw) else {
-                        continue;
-                    };
-                    split_result = Some((front_hull, back_hull));
-                    break;
-                }
-
-                let Some((front_hull, back_hull)) = split_result else {
-                    break;
-                };
-
-                fragment_hulls.swap_remove(largest_idx);
-                fragment_hulls.push(front_hull);
-                fragment_hulls.push(back_hull);
-                split_attempt += 1;
+        resolver
+            .session
+            .write(SessionStatEvent::Hit(StatWeapon::Missile));
+
+        let target = HitTarget {
+            entity: asteroid_entity,
+            mass: size.0,
+            transform,
+            velocity: *velocity,
+            vertices,
+            base_vertices,
+            craters,
+            material: material.copied().unwrap_or_default(),
+            volatile,
+        };
+        let n = size.0;
+        match resolver.resolve_weapon_hit(&profile, &target, missile_pos) {
+            HitOutcome::Destroy => {
+                missile_telemetry.instant_destroy_events += 1;
+                missile_telemetry.destroyed_mass_total += n;
             }
-
-            commands.entity(asteroid_entity).despawn();
-            stats.split_total += 1;
-
-            let areas: Vec<f32> = fragment_hulls
-                .iter()
-                .map(|hull| polygon_area(hull))
-                .collect();
-            let masses = area_weighted_mass_partition(&areas, n, target_pieces as usize);
-
-            if fragment_hulls.len() == target_pieces as usize {
-                for (hull_world, mass) in fragment_hulls.into_iter().zip(masses.into_iter()) {
-                    let centroid =
-                        hull_world.iter().copied().sum::<Vec2>() / hull_world.len() as f32;
-                    let local: Vec<Vec2> = hull_world
-                        .iter()
-                        .map(|v| {
-                            rot.inverse()
-                                .mul_vec3((*v - centroid).extend(0.0))
-                                .truncate()
-                        })
-                        .collect();
-                    let target_area = mass as f32 / config.asteroid_density;
-                    let local = rescale_vertices_to_area(&local, target_area);
-                    let grey = 0.4 + rand::random::<f32>() * 0.3;
-                    let frag_ent = spawn_asteroid_with_vertices(
-                        &mut commands,
-                        centroid,
-                        &local,
-                        Color::srgb(grey, grey, grey),
-                        mass,
-                    );
-
-                    let kick_dir = (centroid - pos).normalize_or_zero();
-                    let kick_dir = if kick_dir == Vec2::ZERO {
-                        split_axis
-                    } else {
-                        kick_dir
-                    };
-                    commands.entity(frag_ent).insert(Velocity {
-                        linvel: vel + kick_dir * 25.0,
-                        angvel: ang_vel,
-                    });
-                    let preserved_transform =
-                        Transform::from_translation(centroid.extend(0.05)).with_rotation(rot);
-                    commands.entity(frag_ent).insert((
-                        preserved_transform,
-                        GlobalTransform::from(preserved_transform),
-                    ));
-                }
-            } else {
-                // Geometry fallback: still keep split-only semantics and target piece count.
-                let fallback_masses = even_mass_partition(n, target_pieces as usize);
-                for (idx, mass) in fallback_masses.into_iter().enumerate() {
-                    let angle = std::f32::consts::TAU * idx as f32 / target_pieces as f32;
-                    let dir = (split_axis + Vec2::from_angle(angle)).normalize_or_zero();
-                    let dir = if dir == Vec2::ZERO { split_axis } else { dir };
-                    let spawn_pos = pos + dir * 10.0;
-                    spawn_fragment_of_mass(
-                        &mut commands,
-                        spawn_pos,
-                        vel + dir * 28.0,
-                        ang_vel,
-                        config.asteroid_density,
-                        mass,
-                    );
-                }
+            HitOutcome::Decompose => {
+                missile_telemetry.full_decompose_events += 1;
+                missile_telemetry.decomposed_mass_total += n;
             }
-
-            spawn_debris_particles(&mut commands, pos, vel, n.min(8));
+            HitOutcome::Split { .. } => missile_telemetry.split_events += 1,
+            HitOutcome::Ignite | HitOutcome::Chip { .. } => {}
         }
     }
 }
 
+/// Cut lines a missile fired from `ship` along `aim` would make on an
+/// asteroid of `mass` whose world-space hull is `hull_world` and whose centre
+/// is `centre`.
+///
+/// Mirrors the missile split in [`HitResolver::resolve_weapon_hit`]: the impact is
+/// where the aim ray meets the hull.  Empty when the hit would destroy or
+/// fully decompose the asteroid instead of splitting it.
+pub fn predicted_missile_split_lines(
+    hull_world: &[Vec2],
+    centre: Vec2,
+    mass: u32,
+    ship: Vec2,
+    aim: Vec2,
+    missile_level: &super::SecondaryWeaponLevel,
+    config: &PhysicsConfig,
+) -> Vec<(Vec2, Vec2)> {
+    let profile = WeaponProfile::missile(missile_level, config);
+    let HitOutcome::Split { pieces } = HitOutcome::classify(&profile, mass, false) else {
+        return Vec::new();
+    };
+    let aim = aim.normalize_or_zero();
+    let impact = ray_polygon_distance(ship, aim, hull_world).map_or(ship, |t| ship + aim * t);
+    let split_axis = (impact - centre).normalize_or(Vec2::X);
+    missile_split_hulls(hull_world, impact, split_axis, pieces as usize).1
+}
+
 // ── Player collision damage ────────────────────────────────────────────────────
 
 /// Detect asteroid–player collisions and deal proportional damage.
@@ -691,12 +563,25 @@ pub fn missile_asteroid_hit_system(
 /// Only activates when relative speed exceeds `DAMAGE_SPEED_THRESHOLD`.
 /// Grants invincibility frames after each successful damage event.
 ///
+/// When the ship carries a [`PlayerShield`], impact damage is routed through
+/// [`PlayerShield::absorb`] first; the impact direction is estimated from the
+/// damage-weighted relative velocities of the colliding asteroids.
+///
 /// On death: decrements [`PlayerLives`] and starts a respawn countdown.
 /// When no lives remain, transitions to [`GameState::GameOver`].
-#[allow(clippy::too_many_arguments)]
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn player_collision_damage_system(
     mut commands: Commands,
-    mut q_player: Query<(Entity, &mut PlayerHealth, &Velocity), With<Player>>,
+    mut q_player: Query<
+        (
+            Entity,
+            &mut PlayerHealth,
+            &Velocity,
+            &Transform,
+            Option<&mut PlayerShield>,
+        ),
+        With<Player>,
+    >,
     q_asteroids: Query<&Velocity, With<Asteroid>>,
     rapier_context: ReadRapierContext,
     time: Res<Time>,
@@ -704,8 +589,11 @@ pub fn player_collision_damage_system(
     mut lives: ResMut<PlayerLives>,
     mut score: ResMut<PlayerScore>,
     mut next_state: ResMut<NextState<GameState>>,
+    mut damage_events: MessageWriter<PlayerDamaged>,
 ) {
-    let Ok((player_entity, mut health, player_vel)) = q_player.single_mut() else {
+    let Ok((player_entity, mut health, player_vel, player_transform, shield)) =
+        q_player.single_mut()
+    else {
         return;
     };
 
@@ -719,6 +607,7 @@ pub fn player_collision_damage_system(
     }
 
     let mut total_damage = 0.0_f32;
+    let mut impact_dir_sum = Vec2::ZERO;
 
     let Ok(rapier) = rapier_context.single() else {
         return;
@@ -743,20 +632,37 @@ pub fn player_collision_damage_system(
         };
 
         if let Ok(ast_vel) = q_asteroids.get(asteroid_entity) {
-            let rel_speed = (player_vel.linvel - ast_vel.linvel).length();
+            let rel_vel = player_vel.linvel - ast_vel.linvel;
+            let rel_speed = rel_vel.length();
             if rel_speed > config.damage_speed_threshold {
-                total_damage += (rel_speed - config.damage_speed_threshold) * 0.5;
+                let damage = (rel_speed - config.damage_speed_threshold) * 0.5;
+                total_damage += damage;
+                // The ship is moving *into* the asteroid along rel_vel.
+                impact_dir_sum += rel_vel / rel_speed * damage;
             }
         }
     }
 
+    total_damage *= config.player_damage_taken_multiplier;
     if total_damage > 0.0 {
-        health.hp -= total_damage;
+        if let Some(mut shield) = shield {
+            let forward = player_transform.rotation.mul_vec3(Vec3::Y).truncate();
+            total_damage = shield.absorb(total_damage, impact_dir_sum, forward, &config);
+        }
         health.inv_timer = config.invincibility_duration;
-        health.time_since_damage = 0.0;
+        if total_damage > 0.0 {
+            health.hp -= total_damage;
+            health.time_since_damage = 0.0;
+            damage_events.write(PlayerDamaged {
+                amount: total_damage,
+                source: DamageSource::Asteroid,
+                fatal: health.hp <= 0.0,
+            });
+        }
         if health.hp <= 0.0 {
             // Ship destroyed — consume one life.
             commands.entity(player_entity).despawn();
+            play_sfx(&mut commands, Sfx::Explosion, 1.0);
             lives.remaining -= 1;
             score.streak = 0; // death breaks the hit streak
             if lives.remaining <= 0 {
@@ -837,12 +743,15 @@ pub fn player_respawn_system(
 
 /// Process projectile-asteroid collision events and apply size-appropriate destruction.
 ///
+/// A [`Volatile`] asteroid is lit instead of destroyed or chipped.  The hit is
+/// resolved by [`HitResolver::resolve_weapon_hit`] with
+/// [`WeaponProfile::primary`], credited to the turret for turret rounds.
+///
 /// Matches `CollisionEvent::Started` pairs; ignores `Stopped`.
 /// Uses two `HashSet`s to ensure each projectile and each asteroid is processed at
 /// most once per frame even if they appear in multiple cascade events.
-#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+#[allow(clippy::type_complexity)]
 pub fn projectile_asteroid_hit_system(
-    mut commands: Commands,
     mut collision_events: MessageReader<CollisionEvent>,
     q_asteroids: Query<
         (
@@ -852,17 +761,19 @@ pub fn projectile_asteroid_hit_system(
             &Vertices,
             Option<&BaseVertices>,
             Option<&CraterData>,
+            Option<&AsteroidMaterial>,
+            Has<Volatile>,
         ),
         (With<Asteroid>, Without<Planet>),
     >,
-    mut q_proj: Query<(&Transform, &mut Projectile)>,
-    mut stats: ResMut<crate::simulation::SimulationStats>,
-    mut score: ResMut<PlayerScore>,
-    config: Res<PhysicsConfig>,
+    mut q_proj: Query<(&Transform, &mut Projectile, Has<TurretRound>)>,
+    mut resolver: HitResolver,
     weapon_tracks: Res<PrimaryWeaponUpgradeTracks>,
     selected_mode: Res<SelectedGameMode>,
     campaign_loadout: Res<CampaignLoadout>,
 ) {
+    static ALLOC_TAG: AllocTag = AllocTag::new("PostUpdate", "projectile_asteroid_hit_system");
+    let _alloc_scope = ALLOC_TAG.enter();
     let active_primary = if *selected_mode == SelectedGameMode::Campaign {
         campaign_loadout.primary
     } else {
@@ -893,7 +804,7 @@ pub fn projectile_asteroid_hit_system(
             continue;
         }
 
-        let Ok((size, transform, velocity, vertices, base_vertices, crater_data)) =
+        let Ok((size, transform, velocity, vertices, base_vertices, craters, material, volatile)) =
             q_asteroids.get(asteroid_entity)
         else {
             continue; // Asteroid may have been despawned already
@@ -905,201 +816,83 @@ pub fn projectile_asteroid_hit_system(
         // Mark the projectile as hit so the lifetime system knows to despawn it
         // without counting it as a missed shot.  We do NOT despawn immediately so
         // that split/chip paths can still read its world-space position this frame.
-        let proj_pos = q_proj
-            .get(proj_entity)
-            .map(|(t, _)| t.translation.truncate())
-            .unwrap_or_else(|_| transform.translation.truncate());
-        if let Ok((_, mut proj)) = q_proj.get_mut(proj_entity) {
+        let (proj_pos, weapon) = q_proj.get(proj_entity).map_or(
+            (transform.translation.truncate(), StatWeapon::Primary),
+            |(t, _, turret_round)| {
+                let weapon = if turret_round {
+                    StatWeapon::Turret
+                } else {
+                    StatWeapon::Primary
+                };
+                (t.translation.truncate(), weapon)
+            },
+        );
+        if let Ok((_, mut proj, _)) = q_proj.get_mut(proj_entity) {
             proj.was_hit = true;
         }
 
-        let pos = transform.translation.truncate();
-        let rot = transform.rotation;
-        let vel = velocity.map_or(Vec2::ZERO, |v| v.linvel);
-        let ang_vel = velocity.map_or(0.0, |v| v.angvel);
-        let n = size.0;
-
-        // World-space hull vertices (used by split and chip paths)
-        let world_verts: Vec<Vec2> = vertices
-            .0
-            .iter()
-            .map(|v| pos + rot.mul_vec3(v.extend(0.0)).truncate())
-            .collect();
-
-        // Increment streak and compute multiplier BEFORE accumulating points so
-        // the threshold hit itself immediately benefits from the new tier.
-        score.hits += 1;
-        score.streak += 1;
-        let multiplier = score.multiplier();
-        score.points += multiplier; // 1 × multiplier for the hit itself
-
-        // Unified impact direction for particle effects (projectile → asteroid).
-        let impact_dir = (pos - proj_pos).normalize_or_zero();
-
-        let destroy_threshold = primary_destroy_threshold(active_primary, &weapon_tracks);
-
-        // ── Level-gated full destroy ──────────────────────────────────────────
-        // The primary weapon fully eliminates asteroids up to `max_destroy_size`.
-        // Anything larger is always chipped (1 vertex removed, 1-unit fragment
-        // ejected) regardless of level, so no hit ever removes more than half the
-        // target.
-        if n <= destroy_threshold {
-            commands.entity(asteroid_entity).despawn();
-            stats.destroyed_total += 1;
-            score.destroyed += 1;
-            score.points += 5 * multiplier; // bonus for full destroy
-                                            // Scatter one ore drop per mass unit so larger destroys yield more ore.
-            let drop_count = n.max(1);
-            for i in 0..drop_count {
-                let angle = std::f32::consts::TAU * (i as f32 / drop_count as f32);
-                let offset = Vec2::new(angle.cos(), angle.sin()) * 6.0;
-                spawn_ore_drop(&mut commands, pos + offset, vel);
-            }
-            spawn_impact_particles(&mut commands, proj_pos, impact_dir, vel);
-            spawn_debris_particles(&mut commands, pos, vel, n.max(1));
-        } else {
-            if weapon_tracks.should_fragment_sub_chip_target(n) {
-                commands.entity(asteroid_entity).despawn();
-                stats.split_total += 1;
-
-                for i in 0..n {
-                    let angle = std::f32::consts::TAU * (i as f32 / n as f32);
-                    let dir = Vec2::new(angle.cos(), angle.sin());
-                    let spawn_pos = pos + dir * 9.0;
-                    let spawn_vel = vel + dir * 24.0;
-                    spawn_fragment_of_mass(
-                        &mut commands,
-                        spawn_pos,
-                        spawn_vel,
-                        ang_vel,
-                        config.asteroid_density,
-                        1,
-                    );
-                }
-
-                spawn_debris_particles(&mut commands, pos, vel, n.min(6));
-                continue;
-            }
-
-            // ── Chip: spawn fragment + inward local deformation ───────────────
-            spawn_impact_particles(&mut commands, proj_pos, impact_dir, vel);
-            let closest_idx = world_verts
-                .iter()
-                .enumerate()
-                .min_by(|(_, a), (_, b)| {
-                    a.distance(proj_pos)
-                        .partial_cmp(&b.distance(proj_pos))
-                        .unwrap_or(std::cmp::Ordering::Equal)
-                })
-                .map(|(i, _)| i)
-                .unwrap_or(0);
-
-            let chip_pos = world_verts[closest_idx];
-            let chip_dir = (chip_pos - pos).normalize_or_zero();
-            let mut rng = rand::thread_rng();
-
-            // Chip size scales with chip-track level: level L can chip 1..=min(L, n/2).
-            let max_chip_size = primary_max_chip_size(active_primary, &weapon_tracks, n);
-            let chip_size = if max_chip_size <= 1 {
-                1u32
-            } else {
-                rng.gen_range(1u32..=max_chip_size)
-            };
-
-            let chip_vel = vel
-                + chip_dir * 40.0
-                + Vec2::new(rng.gen_range(-15.0..15.0), rng.gen_range(-15.0..15.0));
-            spawn_fragment_of_mass(
-                &mut commands,
-                chip_pos,
-                chip_vel,
-                0.0,
-                config.asteroid_density,
-                chip_size,
-            );
-            let new_mass = (n - chip_size).max(1);
-
-            // Add new crater at impact point
-            let impact_local = rot
-                .inverse()
-                .mul_vec3((proj_pos - pos).extend(0.0))
-                .truncate();
-            let base_vertices_local = base_vertices
-                .map(|base| base.0.clone())
-                .unwrap_or_else(|| vertices.0.clone());
-            let bounding_radius = base_vertices_local
-                .iter()
-                .map(|v| v.length())
-                .fold(0.0f32, f32::max);
-            let crater_radius = bounding_radius * config.crater_radius_ratio;
-
-            let mut new_crater_data = crater_data.cloned().unwrap_or_default();
-            new_crater_data.craters.push((
-                impact_local,
-                config.crater_depth_per_hit,
-                crater_radius,
-            ));
-
-            // Limit crater count
-            if new_crater_data.craters.len() > config.max_craters_per_asteroid {
-                new_crater_data.craters.remove(0);
-            }
-
-            // Apply all craters to base vertices to get deformed shape
-            let deformed_local =
-                apply_crater_deformation(&base_vertices_local, &new_crater_data.craters, &config)
-                    .unwrap_or_else(|| base_vertices_local.clone());
-
-            // Rescale to match new mass after chip removal
-            let target_area = new_mass as f32 / config.asteroid_density;
-            let new_local = rescale_vertices_to_area(&deformed_local, target_area);
-
-            // Also rescale base vertices to match new mass
-            let new_base = rescale_vertices_to_area(&base_vertices_local, target_area);
-            let hull_centroid = pos;
-
-            commands.entity(asteroid_entity).despawn();
-
-            let grey = 0.4 + rand::random::<f32>() * 0.3;
-            let new_ent = spawn_asteroid_with_vertices(
-                &mut commands,
-                hull_centroid,
-                &new_local,
-                Color::srgb(grey, grey, grey),
-                new_mass,
-            );
-            let preserved_transform =
-                Transform::from_translation(hull_centroid.extend(0.05)).with_rotation(rot);
-            commands.entity(new_ent).insert(Velocity {
-                linvel: vel,
-                angvel: ang_vel,
-            });
-            commands.entity(new_ent).insert((
-                preserved_transform,
-                GlobalTransform::from(preserved_transform),
-            ));
-            commands
-                .entity(new_ent)
-                .insert((new_crater_data, BaseVertices(new_base)));
-        }
+        let profile = WeaponProfile::primary(active_primary, &weapon_tracks, weapon);
+        let target = HitTarget {
+            entity: asteroid_entity,
+            mass: size.0,
+            transform,
+            velocity: velocity.copied().unwrap_or(Velocity::zero()),
+            vertices,
+            base_vertices,
+            craters,
+            material: material.copied().unwrap_or_default(),
+            volatile,
+        };
+        resolver.resolve_weapon_hit(&profile, &target, proj_pos);
     }
 }
 
-/// Consume projectile/missile hits against planets without awarding score.
+/// Resolve weapon hits and heavy impacts against planets.  No score is awarded.
 ///
-/// - Projectiles are marked as hit so lifetime cleanup despawns them.
-/// - Missiles are despawned immediately on contact.
+/// - Projectiles are marked as hit so lifetime cleanup despawns them; they
+///   are too light to dent a planet.
+/// - Missiles are despawned on contact and excavate a crater.
+/// - Asteroids of at least `planet_impact_min_mass` whose contact force
+///   exceeds `planet_impact_force_threshold` excavate a crater once per
+///   contact.
+///
+/// Each excavation persists a crater in the planet's [`CraterData`], removes
+/// `planet_excavation_mass` (never below `planet_min_mass`) and throws the
+/// removed mass out as ejecta asteroids along the surface normal.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn projectile_missile_planet_hit_system(
     mut commands: Commands,
     mut collision_events: MessageReader<CollisionEvent>,
-    q_planets: Query<Entity, With<Planet>>,
+    mut contact_force_events: MessageReader<ContactForceEvent>,
+    mut q_planets: Query<
+        (
+            &Transform,
+            &mut AsteroidSize,
+            &mut Vertices,
+            &mut BaseVertices,
+            &mut CraterData,
+            Option<&AsteroidMaterial>,
+        ),
+        With<Planet>,
+    >,
     mut q_proj: Query<&mut Projectile>,
-    q_missiles: Query<(), With<Missile>>,
+    q_missiles: Query<&Transform, With<Missile>>,
+    q_impactors: Query<(&Transform, &AsteroidSize), (With<Asteroid>, Without<Planet>)>,
+    mut impacting: Local<std::collections::HashSet<(Entity, Entity)>>,
+    config: Res<PhysicsConfig>,
+    mut rng: ResMut<GameplayRng>,
+    mut session: MessageWriter<SessionStatEvent>,
 ) {
+    let mut strikes: Vec<(Entity, Vec2)> = Vec::new();
+
     for event in collision_events.read() {
         let (e1, e2) = match event {
             CollisionEvent::Started(e1, e2, _) => (*e1, *e2),
-            CollisionEvent::Stopped(..) => continue,
+            CollisionEvent::Stopped(e1, e2, _) => {
+                impacting.remove(&(*e1, *e2));
+                impacting.remove(&(*e2, *e1));
+                continue;
+            }
         };
 
         let (planet_entity, other_entity) = if q_planets.contains(e1) {
@@ -1110,27 +903,160 @@ pub fn projectile_missile_planet_hit_system(
             continue;
         };
 
-        let _ = planet_entity;
-
         if let Ok(mut projectile) = q_proj.get_mut(other_entity) {
             projectile.was_hit = true;
             continue;
         }
 
-        if q_missiles.contains(other_entity) {
+        if let Ok(missile_transform) = q_missiles.get(other_entity) {
+            strikes.push((planet_entity, missile_transform.translation.truncate()));
             commands.entity(other_entity).despawn();
+            session.write(SessionStatEvent::Hit(StatWeapon::Missile));
+        }
+    }
+
+    for event in contact_force_events.read() {
+        let (planet_entity, other_entity) = if q_planets.contains(event.collider1) {
+            (event.collider1, event.collider2)
+        } else if q_planets.contains(event.collider2) {
+            (event.collider2, event.collider1)
+        } else {
+            continue;
+        };
+        let Ok((transform, size)) = q_impactors.get(other_entity) else {
+            continue;
+        };
+        if size.0 < config.planet_impact_min_mass
+            || event.total_force_magnitude < config.planet_impact_force_threshold
+            || !impacting.insert((planet_entity, other_entity))
+        {
+            continue;
         }
+        strikes.push((planet_entity, transform.translation.truncate()));
     }
+
+    for (planet_entity, impact_world) in strikes {
+        let Ok((transform, mut size, mut vertices, mut base_vertices, mut craters, material)) =
+            q_planets.get_mut(planet_entity)
+        else {
+            continue;
+        };
+        let excavated = config
+            .planet_excavation_mass
+            .min(size.0.saturating_sub(config.planet_min_mass));
+        if excavated == 0 {
+            continue;
+        }
+
+        // Crater on the surface along the line from the centre to the impact.
+        let planet_pos = transform.translation.truncate();
+        let rot = transform.rotation;
+        let dir_local = rot
+            .inverse()
+            .mul_vec3((impact_world - planet_pos).extend(0.0))
+            .truncate()
+            .normalize_or(Vec2::X);
+        let surface_dist = ray_polygon_distance(Vec2::ZERO, dir_local, &base_vertices.0)
+            .unwrap_or_else(|| {
+                base_vertices
+                    .0
+                    .iter()
+                    .map(|v| v.length())
+                    .fold(0.0, f32::max)
+            });
+        let impact_local = dir_local * surface_dist;
+
+        let new_mass = size.0 - excavated;
+        let carved = excavate_planet_shape(
+            &base_vertices.0,
+            Some(&craters),
+            impact_local,
+            new_mass,
+            &config,
+        );
+        commands
+            .entity(planet_entity)
+            .insert(collider_for_vertices(&carved.base_vertices));
+        size.0 = new_mass;
+        vertices.0 = carved.vertices;
+        base_vertices.0 = carved.base_vertices;
+        *craters = carved.craters;
+
+        // Ejecta leave the rim of the new surface, fanned around the normal.
+        let normal = rot.mul_vec3(dir_local.extend(0.0)).truncate();
+        let rim = planet_pos
+            + normal * surface_dist * (new_mass as f32 / (new_mass + excavated) as f32).sqrt();
+        let material = material.copied().unwrap_or_default();
+        let lineage = MergeCooldown::from_split(planet_entity);
+        let pieces = config
+            .planet_ejecta_max_fragments
+            .clamp(1, excavated as usize);
+        for (i, mass) in even_mass_partition(excavated, pieces)
+            .into_iter()
+            .enumerate()
+        {
+            let spread = (i as f32 - (pieces - 1) as f32 * 0.5) * 0.5 + rng.gen_range(-0.15..0.15);
+            let dir = Vec2::from_angle(spread).rotate(normal);
+            let clearance = (mass as f32 / config.asteroid_density).sqrt() + 2.0;
+            let ejecta = spawn_fragment_of_mass(
+                &mut commands,
+                rim + dir * clearance,
+                dir * config.planet_ejecta_speed * rng.gen_range(0.8..1.2),
+                rng.gen_range(-3.0..3.0),
+                &config,
+                mass,
+                material,
+                &mut *rng,
+            );
+            commands.entity(ejecta).insert(lineage);
+        }
+    }
+}
+
+/// Spawn an asteroid fragment of arbitrary `mass` at `pos` with the given velocity.
+///
+/// Fragment shape comes from [`fragment_vertices_for_mass`]: the canonical
+/// polygon for the mass, optionally noise-perturbed, at the correct area.
+/// Used by the chip path when a higher weapon level chips off more than one
+/// mass unit.  The fragment keeps the parent's `material`; its shape is
+/// drawn from `rng`.
+#[allow(clippy::too_many_arguments)]
+fn spawn_fragment_of_mass(
+    commands: &mut Commands,
+    pos: Vec2,
+    velocity: Vec2,
+    angvel: f32,
+    config: &PhysicsConfig,
+    mass: u32,
+    material: AsteroidMaterial,
+    rng: &mut impl Rng,
+) -> Entity {
+    let grey = 0.4 + visual_rng().gen::<f32>() * 0.4;
+    let verts = fragment_vertices_for_mass(mass, rng, config);
+    let ent =
+        spawn_asteroid_with_vertices(commands, pos, &verts, Color::srgb(grey, grey, grey), mass);
+    commands.entity(ent).insert((
+        Velocity {
+            linvel: velocity,
+            angvel,
+        },
+        material,
+    ));
+    ent
 }
 
 #[cfg(test)]
 mod tests {
+    use super::helpers::{impact_radiating_split_basis, polygon_area, split_convex_polygon_world};
     use super::*;
+    use crate::feedback::FeedbackEvent;
+    use crate::simulation::shockwave::Shockwave;
     use bevy::prelude::{App, MinimalPlugins};
 
     fn setup_projectile_lifetime_test_app() -> App {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins)
+            .add_message::<SessionStatEvent>()
             .insert_resource(crate::config::PhysicsConfig::default())
             .insert_resource(PlayerScore::default())
             .add_systems(Update, despawn_old_projectiles_system);
@@ -1149,11 +1075,18 @@ mod tests {
         let mut app = App::new();
         app.add_plugins(MinimalPlugins)
             .add_message::<CollisionEvent>()
+            .add_message::<Shockwave>()
+            .add_message::<FeedbackEvent>()
+            .add_message::<crate::graphics::feedback::AsteroidDamaged>()
+            .add_message::<SessionStatEvent>()
+            .add_message::<crate::attribution::KillCredited>()
             .insert_resource(crate::config::PhysicsConfig::default())
             .insert_resource(crate::simulation::SimulationStats::default())
             .insert_resource(PlayerScore::default())
+            .insert_resource(crate::scoring::ScoringConfig::default())
             .insert_resource(crate::simulation::MissileTelemetry::default())
             .insert_resource(crate::player::state::SecondaryWeaponLevel::default())
+            .init_resource::<GameplayRng>()
             .add_systems(PostUpdate, missile_asteroid_hit_system);
         app
     }
@@ -1178,7 +1111,11 @@ mod tests {
                     linvel: Vec2::ZERO,
                     angvel: 0.0,
                 },
-                Transform::from_translation(Vec3::new(cfg.hard_cull_distance * 2.0, 0.0, 0.0)),
+                Transform::from_translation(Vec3::new(
+                    cfg.sector_stream_out_radius * 2.0,
+                    0.0,
+                    0.0,
+                )),
             ))
             .id();
 
@@ -1237,12 +1174,17 @@ mod tests {
                     age: 0.0,
                     distance_traveled: 0.0,
                     trail_emit_timer: 0.0,
+                    homing_target: None,
                 },
                 Velocity {
                     linvel: Vec2::ZERO,
                     angvel: 0.0,
                 },
-                Transform::from_translation(Vec3::new(cfg.hard_cull_distance * 2.0, 0.0, 0.0)),
+                Transform::from_translation(Vec3::new(
+                    cfg.sector_stream_out_radius * 2.0,
+                    0.0,
+                    0.0,
+                )),
             ))
             .id();
 
@@ -1320,6 +1262,176 @@ mod tests {
         }
     }
 
+    #[test]
+    fn missile_split_fragments_share_a_merge_cooldown_lineage() {
+        let mut app = setup_missile_hit_test_app();
+
+        let asteroid = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                AsteroidSize(6),
+                Transform::default(),
+                Velocity::zero(),
+                Vertices(vec![
+                    Vec2::new(0.0, 10.0),
+                    Vec2::new(-9.0, 0.0),
+                    Vec2::new(0.0, -10.0),
+                    Vec2::new(9.0, 0.0),
+                ]),
+            ))
+            .id();
+        let missile = app
+            .world_mut()
+            .spawn((
+                Missile::default(),
+                Transform::from_translation(Vec3::new(20.0, 0.0, 0.0)),
+            ))
+            .id();
+
+        app.world_mut().write_message(CollisionEvent::Started(
+            missile,
+            asteroid,
+            bevy_rapier2d::rapier::geometry::CollisionEventFlags::empty(),
+        ));
+        app.update();
+
+        let mut q_fragments = app.world_mut().query::<&MergeCooldown>();
+        let tags: Vec<MergeCooldown> = q_fragments.iter(app.world()).copied().collect();
+        assert_eq!(tags.len(), 2, "every fragment should carry a lineage");
+        assert!(MergeCooldown::blocks(Some(&tags[0]), Some(&tags[1])));
+        assert_eq!(tags[0].lineage, asteroid.to_bits());
+    }
+
+    fn planet_hull(radius: f32) -> Vec<Vec2> {
+        (0..16)
+            .map(|i| Vec2::from_angle(std::f32::consts::TAU * i as f32 / 16.0) * radius)
+            .collect()
+    }
+
+    #[test]
+    fn missile_excavates_planet_and_throws_out_its_mass() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<CollisionEvent>()
+            .add_message::<ContactForceEvent>()
+            .add_message::<SessionStatEvent>()
+            .insert_resource(crate::config::PhysicsConfig::default())
+            .init_resource::<GameplayRng>()
+            .add_systems(PostUpdate, projectile_missile_planet_hit_system);
+        let cfg = app
+            .world()
+            .resource::<crate::config::PhysicsConfig>()
+            .clone();
+
+        let hull = planet_hull(18.0);
+        let planet = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                Planet,
+                AsteroidSize(100),
+                Transform::default(),
+                Vertices(hull.clone()),
+                BaseVertices(hull),
+                CraterData::default(),
+            ))
+            .id();
+        for _ in 0..2 {
+            let missile = app
+                .world_mut()
+                .spawn((
+                    Missile::default(),
+                    Transform::from_translation(Vec3::new(20.0, 0.0, 0.0)),
+                ))
+                .id();
+            app.world_mut().write_message(CollisionEvent::Started(
+                missile,
+                planet,
+                bevy_rapier2d::rapier::geometry::CollisionEventFlags::empty(),
+            ));
+            app.update();
+            assert!(app.world().get_entity(missile).is_err());
+        }
+
+        let excavated = 2 * cfg.planet_excavation_mass;
+        let world = app.world();
+        assert_eq!(
+            world.get::<AsteroidSize>(planet).unwrap().0,
+            100 - excavated
+        );
+        let craters = &world.get::<CraterData>(planet).unwrap().craters;
+        assert_eq!(craters.len(), 2, "planet craters persist across hits");
+        assert!(
+            craters
+                .iter()
+                .all(|(centre, _, _)| centre.x > 15.0 && centre.y.abs() < 1.0),
+            "craters sit on the struck face"
+        );
+
+        let mut q_ejecta = app
+            .world_mut()
+            .query_filtered::<(&AsteroidSize, &Transform), Without<Planet>>();
+        let ejecta: Vec<_> = q_ejecta.iter(app.world()).collect();
+        assert_eq!(
+            ejecta.iter().map(|(size, _)| size.0).sum::<u32>(),
+            excavated,
+            "ejecta carry exactly the excavated mass"
+        );
+        assert!(ejecta.iter().all(|(_, t)| t.translation.x > 0.0));
+    }
+
+    #[test]
+    fn planet_is_never_excavated_below_its_minimum_mass() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<CollisionEvent>()
+            .add_message::<ContactForceEvent>()
+            .add_message::<SessionStatEvent>()
+            .insert_resource(crate::config::PhysicsConfig::default())
+            .init_resource::<GameplayRng>()
+            .add_systems(PostUpdate, projectile_missile_planet_hit_system);
+        let min_mass = app
+            .world()
+            .resource::<crate::config::PhysicsConfig>()
+            .planet_min_mass;
+
+        let hull = planet_hull(20.0);
+        let planet = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                Planet,
+                AsteroidSize(min_mass),
+                Transform::default(),
+                Vertices(hull.clone()),
+                BaseVertices(hull),
+                CraterData::default(),
+            ))
+            .id();
+        let missile = app
+            .world_mut()
+            .spawn((
+                Missile::default(),
+                Transform::from_translation(Vec3::new(0.0, 22.0, 0.0)),
+            ))
+            .id();
+        app.world_mut().write_message(CollisionEvent::Started(
+            planet,
+            missile,
+            bevy_rapier2d::rapier::geometry::CollisionEventFlags::empty(),
+        ));
+        app.update();
+
+        assert_eq!(app.world().get::<AsteroidSize>(planet).unwrap().0, min_mass);
+        assert!(app
+            .world()
+            .get::<CraterData>(planet)
+            .unwrap()
+            .craters
+            .is_empty());
+    }
+
     #[test]
     fn impact_radiating_split_basis_anchors_near_impact_edge() {
         let square = vec![
@@ -1392,6 +1504,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn predicted_split_lines_match_piece_count_and_cross_the_hull() {
+        let config = PhysicsConfig::default();
+        let square = vec![
+            Vec2::new(-20.0, -20.0),
+            Vec2::new(20.0, -20.0),
+            Vec2::new(20.0, 20.0),
+            Vec2::new(-20.0, 20.0),
+        ];
+        let level = crate::player::SecondaryWeaponLevel { level: 2 };
+        let pieces = level.split_piece_count(&config) as usize;
+        let lines = predicted_missile_split_lines(
+            &square,
+            Vec2::ZERO,
+            40,
+            Vec2::new(-100.0, 3.0),
+            Vec2::X,
+            &level,
+            &config,
+        );
+        assert_eq!(lines.len(), pieces - 1);
+        for (a, b) in &lines {
+            assert!(a.distance(*b) > 1.0);
+            assert!(a.abs().max_element() <= 20.01 && b.abs().max_element() <= 20.01);
+        }
+
+        // Small enough to be destroyed outright: nothing to preview.
+        let small = predicted_missile_split_lines(
+            &square,
+            Vec2::ZERO,
+            level.destroy_threshold(),
+            Vec2::new(-100.0, 0.0),
+            Vec2::X,
+            &level,
+            &config,
+        );
+        assert!(small.is_empty());
+    }
+
     // ── split_convex_polygon ──────────────────────────────────────────────────
 
     /// Split a convex polygon (world-space vertices) with a plane through `origin`
@@ -1491,7 +1642,7 @@ mod tests {
     #[test]
     fn split_triangle_does_not_panic() {
         // Equilateral triangle split along Y → apex on boundary, two base corners split
-        let tri = vec![
+        let tri = [
             Vec2::new(0.0, 10.0),
             Vec2::new(-10.0, -5.0),
             Vec2::new(10.0, -5.0),
@@ -1885,7 +2036,7 @@ mod tests {
     #[test]
     fn chip_bevel_adds_vertex_and_produces_valid_collider() {
         // Chip a triangle at its first vertex: expect a quadrilateral (4 verts).
-        let tri = vec![
+        let tri = [
             Vec2::new(0.0, 10.0),
             Vec2::new(-8.0, -5.0),
             Vec2::new(8.0, -5.0),
@@ -1919,26 +2070,3 @@ mod tests {
         );
     }
 }
-
-/// Spawn an asteroid fragment of arbitrary `mass` at `pos` with the given velocity.
-///
-/// Fragment shape is determined by [`canonical_vertices_for_mass`] and scaled to
-/// the correct area for the requested mass.  Used by the chip path when a higher
-/// weapon level chips off more than one mass unit.
-fn spawn_fragment_of_mass(
-    commands: &mut Commands,
-    pos: Vec2,
-    velocity: Vec2,
-    angvel: f32,
-    density: f32,
-    mass: u32,
-) {
-    let grey = 0.4 + rand::random::<f32>() * 0.4;
-    let verts = rescale_vertices_to_area(&canonical_vertices_for_mass(mass), mass as f32 / density);
-    let ent =
-        spawn_asteroid_with_vertices(commands, pos, &verts, Color::srgb(grey, grey, grey), mass);
-    commands.entity(ent).insert(Velocity {
-        linvel: velocity,
-        angvel,
-    });
-}
@@ -121,6 +121,99 @@ pub(super) fn impact_radiating_split_basis(
     Some((split_origin, base_normal))
 }
 
+/// Endpoints of the chord a line through `origin` with normal `axis` cuts
+/// across a convex polygon.
+fn cut_chord(verts: &[Vec2], origin: Vec2, axis: Vec2) -> Option<(Vec2, Vec2)> {
+    let n = verts.len();
+    let mut points = Vec::with_capacity(2);
+    for i in 0..n {
+        let a = verts[i];
+        let b = verts[(i + 1) % n];
+        let da = (a - origin).dot(axis);
+        let db = (b - origin).dot(axis);
+        if da == 0.0 {
+            points.push(a);
+        } else if (da > 0.0 && db < 0.0) || (da < 0.0 && db > 0.0) {
+            points.push(a + (b - a) * (da / (da - db)));
+        }
+    }
+    (points.len() >= 2).then(|| (points[0], points[1]))
+}
+
+/// Cut a world-space hull into `target_pieces` convex pieces the way a missile
+/// does: repeatedly split the largest piece along a line radiating from the
+/// impact side, fanning the cut angle out on each attempt.
+///
+/// Returns the pieces and the chord of every cut made.  Fewer than
+/// `target_pieces` pieces come back when the geometry cannot be cut further.
+pub(super) fn missile_split_hulls(
+    hull: &[Vec2],
+    impact_point: Vec2,
+    split_axis: Vec2,
+    target_pieces: usize,
+) -> (Vec<Vec<Vec2>>, Vec<(Vec2, Vec2)>) {
+    let mut pieces: Vec<Vec<Vec2>> = vec![hull.to_vec()];
+    let mut cuts: Vec<(Vec2, Vec2)> = Vec::new();
+    let mut split_attempt = 0_u32;
+
+    while pieces.len() < target_pieces {
+        let Some((largest_idx, largest_hull)) = pieces
+            .iter()
+            .enumerate()
+            .max_by(|(_, a), (_, b)| polygon_area(a).total_cmp(&polygon_area(b)))
+            .map(|(idx, hull)| (idx, hull.clone()))
+        else {
+            break;
+        };
+
+        let Some((split_origin, base_normal)) =
+            impact_radiating_split_basis(&largest_hull, impact_point, split_axis)
+        else {
+            break;
+        };
+
+        let spread = 0.42 * (split_attempt as f32 + 1.0);
+        let base_angle = base_normal.to_angle();
+        let candidate_axes = [
+            base_normal,
+            Vec2::from_angle(base_angle + spread),
+            Vec2::from_angle(base_angle - spread),
+            Vec2::new(-base_normal.y, base_normal.x).normalize_or_zero(),
+        ];
+
+        let mut split_result: Option<(Vec<Vec2>, Vec<Vec2>, Vec2)> = None;
+        for axis in candidate_axes {
+            if axis.length_squared() < 1e-5 {
+                continue;
+            }
+            let (front_raw, back_raw) =
+                split_convex_polygon_world(&largest_hull, split_origin, axis);
+            let Some(front_hull) = normalized_fragment_hull(&front_raw) else {
+                continue;
+            };
+            let Some(back_hull) = normalized_fragment_hull(&back_raw) else {
+                continue;
+            };
+            split_result = Some((front_hull, back_hull, axis));
+            break;
+        }
+
+        let Some((front_hull, back_hull, axis)) = split_result else {
+            break;
+        };
+
+        if let Some(chord) = cut_chord(&largest_hull, split_origin, axis) {
+            cuts.push(chord);
+        }
+        pieces.swap_remove(largest_idx);
+        pieces.push(front_hull);
+        pieces.push(back_hull);
+        split_attempt += 1;
+    }
+
+    (pieces, cuts)
+}
+
 pub(super) fn even_mass_partition(total_mass: u32, piece_count: usize) -> Vec<u32> {
     if piece_count == 0 {
         return Vec::new();
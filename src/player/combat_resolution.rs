@@ -0,0 +1,724 @@
+//! Shared hit resolution for player weapons that break asteroids.
+//!
+//! Projectile and missile hit systems find the colliding pair, then hand the
+//! asteroid to [`HitResolver::resolve_weapon_hit`] with the firing weapon's
+//! [`WeaponProfile`].  The outcome depends only on the asteroid's mass and the
+//! profile ([`HitOutcome::classify`]):
+//!
+//! | Target | Outcome |
+//! |---|---|
+//! | [`Volatile`] | Lit instead of broken |
+//! | mass ≤ `destroy_max` | Destroyed: ore drops, destroy bonus, [`Shockwave`] for missiles |
+//! | mass ≤ `decompose_max` | Broken into unit fragments |
+//! | larger, [`LargeHitRule::Chip`] | Chip of `1..=min(max_chip, mass / 2)` knocked off, crater carved |
+//! | larger, [`LargeHitRule::Split`] | Split into convex pieces along the impact axis |
+//!
+//! Every hit registers with the score streak first, so the hit points are
+//! awarded whatever the outcome.  New weapons or perks get the same rules by
+//! building a profile.
+
+use super::helpers::{
+    area_weighted_mass_partition, even_mass_partition, missile_split_hulls, polygon_area,
+};
+use super::spawn_fragment_of_mass;
+use crate::asteroid::{
+    carve_asteroid_shape, rescale_vertices_to_area, spawn_asteroid_with_vertices, BaseVertices,
+    CraterData, MergeCooldown, Vertices,
+};
+use crate::attribution::{Actor, KillCredited, KillVictim};
+use crate::config::PhysicsConfig;
+use crate::feedback::{FeedbackEvent, FeedbackKind};
+use crate::graphics::feedback::AsteroidDamaged;
+use crate::mining::{spawn_ore_drop, AsteroidMaterial};
+use crate::particles::spawn_impact_particles;
+use crate::player::state::{
+    CampaignPrimaryWeapon, PlayerScore, PrimaryWeaponUpgradeTracks, SecondaryWeaponLevel,
+};
+use crate::rng::{visual_rng, GameplayRng};
+use crate::scoring::ScoringConfig;
+use crate::session_stats::{SessionStatEvent, StatWeapon};
+use crate::simulation::density::DensityField;
+use crate::simulation::shockwave::Shockwave;
+use crate::simulation::volatile::ignite_volatile;
+use crate::simulation::SimulationStats;
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+use rand::Rng;
+
+/// Which point values from [`ScoringConfig`] a weapon scores with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitScoring {
+    Bullet,
+    Missile,
+}
+
+impl HitScoring {
+    /// `(hit points, destroy bonus points)`.
+    pub fn points(self, scoring: &ScoringConfig) -> (u32, u32) {
+        match self {
+            HitScoring::Bullet => (scoring.hit_points, scoring.destroy_bonus_points),
+            HitScoring::Missile => (
+                scoring.missile_hit_points,
+                scoring.missile_destroy_bonus_points,
+            ),
+        }
+    }
+}
+
+/// What a weapon does to asteroids too large to destroy or decompose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LargeHitRule {
+    /// Knock off a chip of at most `max_chip_size` (and never more than half
+    /// the target) and carve a crater.
+    Chip { max_chip_size: u32 },
+    /// Split into `pieces` convex fragments (capped by the target's mass).
+    Split { pieces: u32 },
+}
+
+/// How a weapon breaks asteroids.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeaponProfile {
+    /// Session-stat credit for kills.
+    pub weapon: StatWeapon,
+    pub scoring: HitScoring,
+    /// Largest mass destroyed outright.
+    pub destroy_max: u32,
+    /// Largest mass broken into unit fragments.
+    pub decompose_max: u32,
+    pub large_hit: LargeHitRule,
+    /// Outward speed of unit fragments from a decomposition.
+    pub fragment_kick: f32,
+    /// Impact sparks on destroy and chip.
+    pub impact_particles: bool,
+    /// Destroys and decompositions push nearby bodies away.
+    pub shockwave: bool,
+}
+
+impl WeaponProfile {
+    /// The primary gun (or a turret, credited as `weapon`) at the player's
+    /// current upgrade tracks.
+    pub fn primary(
+        primary: CampaignPrimaryWeapon,
+        tracks: &PrimaryWeaponUpgradeTracks,
+        weapon: StatWeapon,
+    ) -> Self {
+        // Every primary currently shares the upgrade tracks.
+        let (destroy_max, max_chip_size) = match primary {
+            CampaignPrimaryWeapon::Blaster
+            | CampaignPrimaryWeapon::MiningLaser
+            | CampaignPrimaryWeapon::PlasmaRifle => {
+                (tracks.max_destroy_size(), tracks.max_chip_size())
+            }
+        };
+        Self {
+            weapon,
+            scoring: HitScoring::Bullet,
+            destroy_max,
+            // Targets the chip track would consume whole break into units.
+            decompose_max: max_chip_size,
+            large_hit: LargeHitRule::Chip { max_chip_size },
+            fragment_kick: 24.0,
+            impact_particles: true,
+            shockwave: false,
+        }
+    }
+
+    /// Missiles at `level`.
+    pub fn missile(level: &SecondaryWeaponLevel, config: &PhysicsConfig) -> Self {
+        Self {
+            weapon: StatWeapon::Missile,
+            scoring: HitScoring::Missile,
+            destroy_max: level.destroy_threshold(),
+            decompose_max: level.display_level(),
+            large_hit: LargeHitRule::Split {
+                pieces: level.split_piece_count(config),
+            },
+            fragment_kick: 30.0,
+            impact_particles: false,
+            shockwave: true,
+        }
+    }
+}
+
+/// What a hit does to its target.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HitOutcome {
+    /// A volatile asteroid: its fuse is lit.
+    Ignite,
+    Destroy,
+    /// Broken into `mass` unit fragments.
+    Decompose,
+    /// A chip of `1..=max_size` is knocked off.
+    Chip {
+        max_size: u32,
+    },
+    /// Split into `pieces` fragments.
+    Split {
+        pieces: u32,
+    },
+}
+
+impl HitOutcome {
+    /// Outcome of `profile` hitting an asteroid of `mass`.
+    pub fn classify(profile: &WeaponProfile, mass: u32, volatile: bool) -> Self {
+        if volatile {
+            HitOutcome::Ignite
+        } else if mass <= profile.destroy_max {
+            HitOutcome::Destroy
+        } else if mass <= profile.decompose_max {
+            HitOutcome::Decompose
+        } else {
+            match profile.large_hit {
+                LargeHitRule::Chip { max_chip_size } => HitOutcome::Chip {
+                    max_size: max_chip_size.min(mass / 2).max(1),
+                },
+                LargeHitRule::Split { pieces } => HitOutcome::Split {
+                    pieces: pieces.min(mass).max(2),
+                },
+            }
+        }
+    }
+}
+
+/// The asteroid being hit.
+pub struct HitTarget<'a> {
+    pub entity: Entity,
+    pub mass: u32,
+    pub transform: &'a Transform,
+    pub velocity: Velocity,
+    pub vertices: &'a Vertices,
+    pub base_vertices: Option<&'a BaseVertices>,
+    pub craters: Option<&'a CraterData>,
+    pub material: AsteroidMaterial,
+    pub volatile: bool,
+}
+
+/// Everything a weapon hit writes to.
+#[derive(SystemParam)]
+pub struct HitResolver<'w, 's> {
+    pub commands: Commands<'w, 's>,
+    pub stats: ResMut<'w, SimulationStats>,
+    pub score: ResMut<'w, PlayerScore>,
+    pub scoring: Res<'w, ScoringConfig>,
+    pub config: Res<'w, PhysicsConfig>,
+    pub shockwaves: MessageWriter<'w, Shockwave>,
+    pub feedback: MessageWriter<'w, FeedbackEvent>,
+    pub damaged: MessageWriter<'w, AsteroidDamaged>,
+    pub session: MessageWriter<'w, SessionStatEvent>,
+    pub kills: MessageWriter<'w, KillCredited>,
+    pub rng: ResMut<'w, GameplayRng>,
+    pub density: Option<Res<'w, DensityField>>,
+}
+
+impl HitResolver<'_, '_> {
+    /// Score the hit and break `target` as `profile` dictates.  `impact` is
+    /// the world position of the shot at contact.
+    pub fn resolve_weapon_hit(
+        &mut self,
+        profile: &WeaponProfile,
+        target: &HitTarget,
+        impact: Vec2,
+    ) -> HitOutcome {
+        let (hit_points, destroy_bonus_points) = profile.scoring.points(&self.scoring);
+        // Streak first, so the hit that crosses a tier scores at it.
+        let multiplier = self.scoring.register_hit(&mut self.score, hit_points);
+
+        let outcome = HitOutcome::classify(profile, target.mass, target.volatile);
+        let pos = target.transform.translation.truncate();
+        let vel = target.velocity.linvel;
+        let n = target.mass;
+        match outcome {
+            // Volatiles detonate instead; see `simulation::volatile`.
+            HitOutcome::Ignite => ignite_volatile(
+                &mut self.commands,
+                target.entity,
+                0.0,
+                Actor::Player(profile.weapon),
+            ),
+            HitOutcome::Destroy => {
+                self.commands.entity(target.entity).despawn();
+                self.stats.destroyed_total += 1;
+                self.score.destroyed += 1;
+                self.score.points += destroy_bonus_points * multiplier;
+                self.session
+                    .write(SessionStatEvent::AsteroidDestroyed(profile.weapon));
+                self.kills.write(KillCredited {
+                    victim: KillVictim::Asteroid,
+                    by: Actor::Player(profile.weapon),
+                    indirect: false,
+                });
+                // One ore drop per mass unit so larger destroys yield more
+                // ore, more inside a rich vein.
+                let drop_count = self.density.as_ref().map_or(n.max(1), |density| {
+                    density.ore_drop_count(n, pos, &self.config)
+                });
+                for i in 0..drop_count {
+                    let angle = std::f32::consts::TAU * (i as f32 / drop_count as f32);
+                    let offset = Vec2::new(angle.cos(), angle.sin()) * 6.0;
+                    spawn_ore_drop(
+                        &mut self.commands,
+                        pos + offset,
+                        vel,
+                        target.material.ore_kind(),
+                        &self.config,
+                        &mut *self.rng,
+                    );
+                }
+                if profile.impact_particles {
+                    let impact_dir = (pos - impact).normalize_or_zero();
+                    spawn_impact_particles(&mut self.commands, impact, impact_dir, vel);
+                }
+                self.write_feedback(FeedbackKind::Destroy, target);
+                if profile.shockwave {
+                    self.shockwaves.write(Shockwave {
+                        origin: pos,
+                        mass: n,
+                    });
+                }
+            }
+            HitOutcome::Decompose => {
+                self.decompose(profile, target, impact);
+                self.write_feedback(FeedbackKind::Split, target);
+                if profile.shockwave {
+                    self.shockwaves.write(Shockwave {
+                        origin: pos,
+                        mass: n,
+                    });
+                }
+            }
+            HitOutcome::Chip { max_size } => {
+                if profile.impact_particles {
+                    let impact_dir = (pos - impact).normalize_or_zero();
+                    spawn_impact_particles(&mut self.commands, impact, impact_dir, vel);
+                }
+                self.chip(target, impact, max_size);
+            }
+            HitOutcome::Split { pieces } => {
+                self.split(target, impact, pieces);
+                self.write_feedback(FeedbackKind::Split, target);
+            }
+        }
+        outcome
+    }
+
+    fn write_feedback(&mut self, kind: FeedbackKind, target: &HitTarget) {
+        self.feedback.write(FeedbackEvent {
+            kind,
+            pos: target.transform.translation.truncate(),
+            vel: target.velocity.linvel,
+            mass: target.mass,
+        });
+    }
+
+    /// Replace the target with a ring of unit fragments, the first one on the
+    /// impact side.
+    fn decompose(&mut self, profile: &WeaponProfile, target: &HitTarget, impact: Vec2) {
+        let pos = target.transform.translation.truncate();
+        let n = target.mass;
+        self.commands.entity(target.entity).despawn();
+        self.stats.split_total += 1;
+
+        let impact_dir = (impact - pos).normalize_or(Vec2::X);
+        let base_angle = impact_dir.y.atan2(impact_dir.x);
+        let lineage = MergeCooldown::from_split(target.entity);
+        for i in 0..n {
+            let angle = base_angle + std::f32::consts::TAU * (i as f32 / n as f32);
+            let dir = Vec2::new(angle.cos(), angle.sin());
+            let fragment = spawn_fragment_of_mass(
+                &mut self.commands,
+                pos + dir * 9.0,
+                target.velocity.linvel + dir * profile.fragment_kick,
+                target.velocity.angvel,
+                &self.config,
+                1,
+                target.material,
+                &mut *self.rng,
+            );
+            self.commands.entity(fragment).insert(lineage);
+        }
+    }
+
+    /// Knock a chip off the vertex nearest the impact and respawn the body
+    /// with a crater at the impact point, rescaled to the mass left.
+    fn chip(&mut self, target: &HitTarget, impact: Vec2, max_size: u32) {
+        let pos = target.transform.translation.truncate();
+        let rot = target.transform.rotation;
+        let vel = target.velocity.linvel;
+        let n = target.mass;
+
+        let chip_pos = target
+            .vertices
+            .0
+            .iter()
+            .map(|v| pos + rot.mul_vec3(v.extend(0.0)).truncate())
+            .min_by(|a, b| a.distance(impact).total_cmp(&b.distance(impact)))
+            .unwrap_or(pos);
+        let chip_dir = (chip_pos - pos).normalize_or_zero();
+        let chip_size = if max_size <= 1 {
+            1
+        } else {
+            self.rng.gen_range(1u32..=max_size)
+        };
+
+        let chip_vel = vel
+            + chip_dir * 40.0
+            + Vec2::new(
+                self.rng.gen_range(-15.0..15.0),
+                self.rng.gen_range(-15.0..15.0),
+            );
+        let lineage = MergeCooldown::from_split(target.entity);
+        let chip = spawn_fragment_of_mass(
+            &mut self.commands,
+            chip_pos,
+            chip_vel,
+            0.0,
+            &self.config,
+            chip_size,
+            target.material,
+            &mut *self.rng,
+        );
+        self.commands.entity(chip).insert(lineage);
+        let new_mass = (n - chip_size).max(1);
+
+        let impact_local = rot
+            .inverse()
+            .mul_vec3((impact - pos).extend(0.0))
+            .truncate();
+        let base_vertices_local = target
+            .base_vertices
+            .map(|base| base.0.clone())
+            .unwrap_or_else(|| target.vertices.0.clone());
+        let carved = carve_asteroid_shape(
+            &base_vertices_local,
+            target.craters,
+            impact_local,
+            new_mass,
+            &self.config,
+        );
+
+        self.commands.entity(target.entity).despawn();
+
+        let grey = 0.4 + visual_rng().gen::<f32>() * 0.3;
+        let new_ent = spawn_asteroid_with_vertices(
+            &mut self.commands,
+            pos,
+            &carved.vertices,
+            Color::srgb(grey, grey, grey),
+            new_mass,
+        );
+        let preserved_transform = Transform::from_translation(pos.extend(0.05)).with_rotation(rot);
+        self.commands.entity(new_ent).insert((
+            target.velocity,
+            preserved_transform,
+            GlobalTransform::from(preserved_transform),
+            carved.craters,
+            BaseVertices(carved.base_vertices),
+            target.material,
+            lineage,
+        ));
+        self.damaged.write(AsteroidDamaged {
+            entity: new_ent,
+            pos,
+            mass: new_mass,
+        });
+    }
+
+    /// Split the target into `pieces` convex fragments radiating from the
+    /// impact; falls back to evenly sized fragments if the cut fails.
+    fn split(&mut self, target: &HitTarget, impact: Vec2, pieces: u32) {
+        let pos = target.transform.translation.truncate();
+        let rot = target.transform.rotation;
+        let vel = target.velocity.linvel;
+        let ang_vel = target.velocity.angvel;
+        let n = target.mass;
+        let world_verts: Vec<Vec2> = target
+            .vertices
+            .0
+            .iter()
+            .map(|v| pos + rot.mul_vec3(v.extend(0.0)).truncate())
+            .collect();
+
+        let split_axis = (impact - pos).normalize_or(Vec2::X);
+        let (fragment_hulls, _) =
+            missile_split_hulls(&world_verts, impact, split_axis, pieces as usize);
+
+        self.commands.entity(target.entity).despawn();
+        self.stats.split_total += 1;
+
+        let areas: Vec<f32> = fragment_hulls
+            .iter()
+            .map(|hull| polygon_area(hull))
+            .collect();
+        let masses = area_weighted_mass_partition(&areas, n, pieces as usize);
+        let lineage = MergeCooldown::from_split(target.entity);
+
+        if fragment_hulls.len() == pieces as usize {
+            for (hull_world, mass) in fragment_hulls.into_iter().zip(masses) {
+                let centroid = hull_world.iter().copied().sum::<Vec2>() / hull_world.len() as f32;
+                let local: Vec<Vec2> = hull_world
+                    .iter()
+                    .map(|v| {
+                        rot.inverse()
+                            .mul_vec3((*v - centroid).extend(0.0))
+                            .truncate()
+                    })
+                    .collect();
+                let target_area = mass as f32 / self.config.asteroid_density;
+                let local = rescale_vertices_to_area(&local, target_area);
+                let grey = 0.4 + visual_rng().gen::<f32>() * 0.3;
+                let frag_ent = spawn_asteroid_with_vertices(
+                    &mut self.commands,
+                    centroid,
+                    &local,
+                    Color::srgb(grey, grey, grey),
+                    mass,
+                );
+
+                let kick_dir = (centroid - pos).normalize_or(split_axis);
+                let preserved_transform =
+                    Transform::from_translation(centroid.extend(0.05)).with_rotation(rot);
+                self.commands.entity(frag_ent).insert((
+                    Velocity {
+                        linvel: vel + kick_dir * 25.0,
+                        angvel: ang_vel,
+                    },
+                    target.material,
+                    lineage,
+                    preserved_transform,
+                    GlobalTransform::from(preserved_transform),
+                ));
+            }
+        } else {
+            // Geometry fallback: keep split-only semantics and the piece count.
+            for (idx, mass) in even_mass_partition(n, pieces as usize)
+                .into_iter()
+                .enumerate()
+            {
+                let angle = std::f32::consts::TAU * idx as f32 / pieces as f32;
+                let dir = (split_axis + Vec2::from_angle(angle)).normalize_or(split_axis);
+                let fragment = spawn_fragment_of_mass(
+                    &mut self.commands,
+                    pos + dir * 10.0,
+                    vel + dir * 28.0,
+                    ang_vel,
+                    &self.config,
+                    mass,
+                    target.material,
+                    &mut *self.rng,
+                );
+                self.commands.entity(fragment).insert(lineage);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroid::{Asteroid, AsteroidSize};
+
+    const MASSES: std::ops::RangeInclusive<u32> = 1..=24;
+
+    #[test]
+    fn primary_hits_follow_the_destroy_and_chip_tracks_at_every_level() {
+        for destroy_level in 0..=PrimaryWeaponUpgradeTracks::MAX {
+            for chip_level in 0..=PrimaryWeaponUpgradeTracks::MAX {
+                let tracks = PrimaryWeaponUpgradeTracks {
+                    chip_level,
+                    destroy_level,
+                };
+                let profile = WeaponProfile::primary(
+                    CampaignPrimaryWeapon::Blaster,
+                    &tracks,
+                    StatWeapon::Primary,
+                );
+                for n in MASSES {
+                    let outcome = HitOutcome::classify(&profile, n, false);
+                    let expected = if n <= tracks.max_destroy_size() {
+                        HitOutcome::Destroy
+                    } else if tracks.should_fragment_sub_chip_target(n) {
+                        HitOutcome::Decompose
+                    } else {
+                        HitOutcome::Chip {
+                            max_size: tracks.max_chip_size().min(n / 2).max(1),
+                        }
+                    };
+                    assert_eq!(
+                        outcome, expected,
+                        "destroy {destroy_level} chip {chip_level} mass {n}"
+                    );
+                    if let HitOutcome::Chip { max_size } = outcome {
+                        assert!(max_size <= n / 2, "chip of {max_size} from mass {n}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_primary_weapon_shares_the_tracks() {
+        let tracks = PrimaryWeaponUpgradeTracks {
+            chip_level: 3,
+            destroy_level: 1,
+        };
+        let blaster =
+            WeaponProfile::primary(CampaignPrimaryWeapon::Blaster, &tracks, StatWeapon::Primary);
+        for primary in [
+            CampaignPrimaryWeapon::MiningLaser,
+            CampaignPrimaryWeapon::PlasmaRifle,
+        ] {
+            assert_eq!(
+                WeaponProfile::primary(primary, &tracks, StatWeapon::Primary),
+                blaster
+            );
+        }
+    }
+
+    #[test]
+    fn missile_hits_destroy_small_bodies_and_split_the_rest_at_every_level() {
+        let config = PhysicsConfig::default();
+        for level in 0..=SecondaryWeaponLevel::MAX {
+            let missile = SecondaryWeaponLevel { level };
+            let profile = WeaponProfile::missile(&missile, &config);
+            for n in MASSES {
+                let outcome = HitOutcome::classify(&profile, n, false);
+                let expected = if n <= missile.destroy_threshold() {
+                    HitOutcome::Destroy
+                } else if missile.can_fully_decompose_size(n) {
+                    HitOutcome::Decompose
+                } else {
+                    HitOutcome::Split {
+                        pieces: missile.split_piece_count(&config).min(n).max(2),
+                    }
+                };
+                assert_eq!(outcome, expected, "level {level} mass {n}");
+                if let HitOutcome::Split { pieces } = outcome {
+                    assert!((2..=n).contains(&pieces));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn volatiles_are_lit_whatever_the_weapon_or_mass() {
+        let config = PhysicsConfig::default();
+        let profiles = [
+            WeaponProfile::primary(
+                CampaignPrimaryWeapon::Blaster,
+                &PrimaryWeaponUpgradeTracks::default(),
+                StatWeapon::Turret,
+            ),
+            WeaponProfile::missile(&SecondaryWeaponLevel::default(), &config),
+        ];
+        for profile in profiles {
+            for n in MASSES {
+                assert_eq!(HitOutcome::classify(&profile, n, true), HitOutcome::Ignite);
+            }
+        }
+    }
+
+    #[derive(Resource)]
+    struct PendingHit(WeaponProfile, Entity, Option<HitOutcome>);
+
+    fn resolve_pending_hit(
+        mut resolver: HitResolver,
+        mut pending: ResMut<PendingHit>,
+        q: Query<(&AsteroidSize, &Transform, &Vertices)>,
+    ) {
+        let Ok((size, transform, vertices)) = q.get(pending.1) else {
+            return;
+        };
+        let target = HitTarget {
+            entity: pending.1,
+            mass: size.0,
+            transform,
+            velocity: Velocity::zero(),
+            vertices,
+            base_vertices: None,
+            craters: None,
+            material: AsteroidMaterial::default(),
+            volatile: false,
+        };
+        let outcome = resolver.resolve_weapon_hit(&pending.0, &target, Vec2::new(-20.0, 0.0));
+        pending.2 = Some(outcome);
+    }
+
+    fn resolve_once(profile: WeaponProfile, mass: u32) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<Shockwave>()
+            .add_message::<FeedbackEvent>()
+            .add_message::<AsteroidDamaged>()
+            .add_message::<SessionStatEvent>()
+            .add_message::<KillCredited>()
+            .insert_resource(PhysicsConfig::default())
+            .insert_resource(SimulationStats::default())
+            .insert_resource(PlayerScore::default())
+            .insert_resource(ScoringConfig::default())
+            .init_resource::<GameplayRng>()
+            .add_systems(Update, resolve_pending_hit);
+        let square = vec![
+            Vec2::new(-10.0, -10.0),
+            Vec2::new(10.0, -10.0),
+            Vec2::new(10.0, 10.0),
+            Vec2::new(-10.0, 10.0),
+        ];
+        let asteroid = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                AsteroidSize(mass),
+                Transform::default(),
+                Vertices(square),
+            ))
+            .id();
+        app.insert_resource(PendingHit(profile, asteroid, None));
+        app.update();
+        app
+    }
+
+    #[test]
+    fn resolver_scores_destroys_and_credits_the_weapon() {
+        let tracks = PrimaryWeaponUpgradeTracks::default();
+        let app = resolve_once(
+            WeaponProfile::primary(CampaignPrimaryWeapon::Blaster, &tracks, StatWeapon::Turret),
+            1,
+        );
+        assert_eq!(
+            app.world().resource::<PendingHit>().2,
+            Some(HitOutcome::Destroy)
+        );
+        let scoring = ScoringConfig::default();
+        let score = app.world().resource::<PlayerScore>();
+        assert_eq!((score.hits, score.destroyed), (1, 1));
+        assert_eq!(
+            score.points,
+            scoring.hit_points + scoring.destroy_bonus_points
+        );
+        assert_eq!(app.world().resource::<SimulationStats>().destroyed_total, 1);
+        let events = app.world().resource::<Messages<SessionStatEvent>>();
+        assert!(events
+            .iter_current_update_messages()
+            .any(|e| *e == SessionStatEvent::AsteroidDestroyed(StatWeapon::Turret)));
+        // Bullets make no shockwave.
+        assert!(app.world().resource::<Messages<Shockwave>>().is_empty());
+    }
+
+    #[test]
+    fn resolver_splits_large_bodies_into_the_profile_piece_count() {
+        let config = PhysicsConfig::default();
+        let missile = SecondaryWeaponLevel::default();
+        let mut app = resolve_once(WeaponProfile::missile(&missile, &config), 12);
+        let pieces = missile.split_piece_count(&config);
+        assert_eq!(
+            app.world().resource::<PendingHit>().2,
+            Some(HitOutcome::Split { pieces })
+        );
+        let mut q = app.world_mut().query::<&AsteroidSize>();
+        let masses: Vec<u32> = q.iter(app.world()).map(|s| s.0).collect();
+        assert_eq!(masses.len(), pieces as usize);
+        assert_eq!(masses.iter().sum::<u32>(), 12);
+        assert_eq!(app.world().resource::<SimulationStats>().split_total, 1);
+    }
+}
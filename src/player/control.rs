@@ -12,14 +12,16 @@
 //!
 //! Also contains helper systems that are not part of the core thrust pipeline:
 //! - [`gamepad_connection_system`] — tracks which gamepad is preferred
-//! - [`aim_snap_system`] — snaps aim to ship forward after idle period
+//! - [`aim_snap_system`] — snaps aim to the remembered target or ship forward after idle period
 
 use super::state::{
-    AimDirection, AimIdleTimer, Player, PlayerIntent, PreferredGamepad, TractorBeamLevel,
-    TractorCaptureState, TractorHoldState, TractorThrowCooldown,
+    AimDirection, AimIdleTimer, AimTargetMemory, Player, PlayerEnergy, PlayerIntent,
+    PreferredGamepad, TractorBeamLevel, TractorCaptureState, TractorDeflectWindow,
+    TractorHoldState, TractorThrowCooldown,
 };
 use crate::asteroid::{Asteroid, AsteroidSize, Planet};
 use crate::config::PhysicsConfig;
+use crate::enemy::{deflect_enemy_projectile, EnemyProjectile};
 use crate::menu::SelectedGameMode;
 use crate::particles::{
     spawn_ship_thrust_particles, spawn_tractor_beam_particles, TractorBeamVfxMode,
@@ -215,6 +217,107 @@ pub fn tractor_throw_cooldown_tick_system(
     cooldown.timer_secs = (cooldown.timer_secs - time.delta_secs()).max(0.0);
 }
 
+/// Catch an incoming enemy shot with the tractor beam and send it back.
+///
+/// From tractor level `tractor_deflect_min_level`, pressing throw (`R` / `RB`)
+/// opens a [`TractorDeflectWindow`] of `tractor_deflect_window_secs`.  The
+/// first enemy shot seen inside the beam's range and aim cone during the
+/// window is caught for `tractor_deflect_energy_cost` energy and redirected
+/// along the aim at `tractor_deflect_speed_multiplier` times enemy shot speed.
+/// One press catches at most one shot; a shot the ship cannot pay for is left
+/// alone.
+///
+/// Throw and deflect share the button, so a press while the beam holds an
+/// asteroid is left to [`tractor_beam_force_system`] as a throw and opens no
+/// window.  This system runs just before it in `FixedUpdate` so both see the
+/// hold as it was before the press.
+#[allow(clippy::too_many_arguments)]
+pub fn tractor_deflect_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    preferred: Res<PreferredGamepad>,
+    gamepads: Query<&Gamepad>,
+    time: Res<Time>,
+    aim: Res<AimDirection>,
+    selected_mode: Res<SelectedGameMode>,
+    beam_level: Res<TractorBeamLevel>,
+    hold_state: Res<TractorHoldState>,
+    capture_state: Res<TractorCaptureState>,
+    mut window: ResMut<TractorDeflectWindow>,
+    mut energy: ResMut<PlayerEnergy>,
+    q_player: Query<&Transform, With<Player>>,
+    q_shots: Query<(Entity, &Transform), With<EnemyProjectile>>,
+    config: Res<PhysicsConfig>,
+) {
+    if *selected_mode == SelectedGameMode::Campaign
+        || beam_level.display_level() < config.tractor_deflect_min_level
+    {
+        window.remaining_secs = 0.0;
+        return;
+    }
+
+    let gamepad = preferred.0.and_then(|entity| gamepads.get(entity).ok());
+    let pressed = keys.just_pressed(KeyCode::KeyR)
+        || gamepad.is_some_and(|gp| gp.just_pressed(GamepadButton::RightTrigger));
+    let throwing = hold_state.engaged && capture_state.target.is_some();
+    if pressed && !throwing {
+        window.remaining_secs = config.tractor_deflect_window_secs;
+    }
+    if window.remaining_secs <= 0.0 {
+        return;
+    }
+    window.remaining_secs = (window.remaining_secs - time.delta_secs()).max(0.0);
+
+    let Ok(player_transform) = q_player.single() else {
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+    let beam_dir = if aim.0.length_squared() > 1e-6 {
+        aim.0.normalize_or_zero()
+    } else {
+        player_transform
+            .rotation
+            .mul_vec3(Vec3::Y)
+            .truncate()
+            .normalize_or_zero()
+    };
+    if beam_dir.length_squared() <= 1e-6 {
+        return;
+    }
+
+    let range_sq = beam_level.range_at_level(&config).powi(2);
+    let Some((shot, shot_pos)) = q_shots
+        .iter()
+        .map(|(entity, transform)| (entity, transform.translation.truncate()))
+        .filter(|(_, pos)| {
+            let to_shot = *pos - player_pos;
+            to_shot.length_squared() <= range_sq
+                && beam_dir.dot(to_shot.normalize_or_zero()) >= config.tractor_beam_aim_cone_dot
+        })
+        .min_by(|(_, a), (_, b)| {
+            a.distance_squared(player_pos)
+                .total_cmp(&b.distance_squared(player_pos))
+        })
+    else {
+        return;
+    };
+    if !energy.try_spend(config.tractor_deflect_energy_cost) {
+        return;
+    }
+
+    let linvel = beam_dir * config.enemy_projectile_speed * config.tractor_deflect_speed_multiplier;
+    deflect_enemy_projectile(&mut commands, shot, linvel);
+    spawn_tractor_beam_particles(
+        &mut commands,
+        shot_pos,
+        beam_dir,
+        linvel,
+        TractorBeamVfxMode::Push,
+        1.0,
+    );
+    window.remaining_secs = 0.0;
+}
+
 // ── Step 3: Apply intent → physics ───────────────────────────────────────────
 
 /// Convert [`PlayerIntent`] into `ExternalForce` and `Velocity` on the ship.
@@ -344,23 +447,39 @@ pub fn player_thrust_particles_system(
 
 // ── Aim idle snap ─────────────────────────────────────────────────────────────
 
-/// Snap the aim direction back to the ship's local forward when no aim input
-/// has been received for [`AIM_IDLE_SNAP_SECS`] seconds.
+/// Snap the aim direction when no aim input has been received for
+/// [`AIM_IDLE_SNAP_SECS`] seconds.
 ///
 /// Increments [`AimIdleTimer`] every frame.  When the threshold is crossed and
-/// the player entity exists, `AimDirection` is overwritten with the ship's
-/// world-space +Y.
+/// the player entity exists, `AimDirection` points at the target in
+/// [`AimTargetMemory`] if `aim_snap_target_memory` is on and the target is
+/// alive and within `aim_snap_target_range`; otherwise it is overwritten with
+/// the ship's world-space +Y.  A despawned target is forgotten.
 pub fn aim_snap_system(
     q_player: Query<&Transform, With<Player>>,
+    q_targets: Query<&Transform, Without<Player>>,
     mut aim: ResMut<AimDirection>,
     mut idle: ResMut<AimIdleTimer>,
+    mut memory: ResMut<AimTargetMemory>,
     time: Res<Time>,
     config: Res<PhysicsConfig>,
 ) {
+    let target = memory.target.and_then(|entity| q_targets.get(entity).ok());
+    if memory.target.is_some() && target.is_none() {
+        memory.target = None;
+    }
+
     idle.secs += time.delta_secs();
     if idle.secs >= config.aim_idle_snap_secs {
         if let Ok(transform) = q_player.single() {
-            aim.0 = transform.rotation.mul_vec3(Vec3::Y).truncate();
+            let ship_pos = transform.translation.truncate();
+            let towards_target = target
+                .filter(|_| config.aim_snap_target_memory)
+                .map(|t| t.translation.truncate() - ship_pos)
+                .filter(|offset| offset.length() <= config.aim_snap_target_range)
+                .and_then(Vec2::try_normalize);
+            aim.0 =
+                towards_target.unwrap_or_else(|| transform.rotation.mul_vec3(Vec3::Y).truncate());
         }
     }
 }
@@ -633,6 +752,8 @@ pub fn tractor_beam_force_system(
 mod tests {
     use super::*;
     use crate::constants::{REVERSE_FORCE, ROTATION_SPEED, THRUST_FORCE};
+    use crate::enemy::DeflectedShot;
+    use crate::player::state::Projectile;
 
     // ── helpers ───────────────────────────────────────────────────────────────
 
@@ -920,6 +1041,7 @@ mod tests {
         app.insert_resource(TractorThrowCooldown::default());
         app.insert_resource(PreferredGamepad::default());
         app.insert_resource(AimDirection::default());
+        app.insert_resource(SelectedGameMode::Practice);
         app.add_systems(Update, tractor_beam_force_system);
         app
     }
@@ -1122,4 +1244,213 @@ mod tests {
             "expected aim-direction capture selection"
         );
     }
+
+    // ── aim_snap_system ───────────────────────────────────────────────────────
+
+    fn build_aim_snap_test_app() -> App {
+        let mut app = build_test_app();
+        app.insert_resource(AimDirection(Vec2::X));
+        app.insert_resource(AimIdleTimer {
+            secs: 10.0,
+            last_cursor: None,
+        });
+        app.insert_resource(AimTargetMemory::default());
+        app.add_systems(Update, aim_snap_system);
+        spawn_test_player(&mut app);
+        app
+    }
+
+    #[test]
+    fn idle_aim_snaps_to_the_remembered_target_in_range() {
+        let mut app = build_aim_snap_test_app();
+        let target = app
+            .world_mut()
+            .spawn(Transform::from_xyz(-300.0, 0.0, 0.0))
+            .id();
+        app.world_mut().resource_mut::<AimTargetMemory>().target = Some(target);
+
+        app.update();
+        assert_eq!(app.world().resource::<AimDirection>().0, Vec2::NEG_X);
+
+        // Out of range: fall back to ship forward but keep the target.
+        app.world_mut()
+            .resource_mut::<PhysicsConfig>()
+            .aim_snap_target_range = 200.0;
+        app.update();
+        assert_eq!(app.world().resource::<AimDirection>().0, Vec2::Y);
+        assert_eq!(
+            app.world().resource::<AimTargetMemory>().target,
+            Some(target)
+        );
+
+        // The toggle turns target memory off entirely.
+        let mut config = app.world_mut().resource_mut::<PhysicsConfig>();
+        config.aim_snap_target_range = 900.0;
+        config.aim_snap_target_memory = false;
+        app.update();
+        assert_eq!(app.world().resource::<AimDirection>().0, Vec2::Y);
+    }
+
+    #[test]
+    fn despawned_target_is_forgotten() {
+        let mut app = build_aim_snap_test_app();
+        let target = app
+            .world_mut()
+            .spawn(Transform::from_xyz(-300.0, 0.0, 0.0))
+            .id();
+        app.world_mut().resource_mut::<AimTargetMemory>().target = Some(target);
+        app.world_mut().despawn(target);
+
+        app.update();
+        assert_eq!(app.world().resource::<AimTargetMemory>().target, None);
+        assert_eq!(app.world().resource::<AimDirection>().0, Vec2::Y);
+    }
+
+    // ── tractor_deflect_system ────────────────────────────────────────────────
+
+    fn build_deflect_test_app(tractor_level: u32) -> (App, Entity) {
+        let mut app = build_test_app();
+        app.insert_resource(ButtonInput::<KeyCode>::default());
+        app.insert_resource(PreferredGamepad::default());
+        app.insert_resource(AimDirection(Vec2::Y));
+        app.insert_resource(SelectedGameMode::Practice);
+        app.insert_resource(TractorBeamLevel {
+            level: tractor_level,
+        });
+        app.insert_resource(TractorDeflectWindow::default());
+        app.insert_resource(TractorHoldState::default());
+        app.insert_resource(TractorCaptureState::default());
+        app.insert_resource(TractorThrowCooldown::default());
+        app.insert_resource(PlayerEnergy::default());
+        app.add_systems(
+            Update,
+            (tractor_deflect_system, tractor_beam_force_system).chain(),
+        );
+        spawn_test_player(&mut app);
+        let shot = app
+            .world_mut()
+            .spawn((
+                EnemyProjectile {
+                    age: 0.0,
+                    distance_traveled: 0.0,
+                },
+                Transform::from_xyz(0.0, 120.0, 0.0),
+                Velocity::linear(Vec2::new(0.0, -300.0)),
+            ))
+            .id();
+        (app, shot)
+    }
+
+    fn press_throw(app: &mut App) {
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::KeyR);
+        app.update();
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(KeyCode::KeyR);
+        keys.clear();
+    }
+
+    #[test]
+    fn throw_press_redirects_a_shot_in_the_beam() {
+        let min_level = PhysicsConfig::default().tractor_deflect_min_level;
+        let (mut app, shot) = build_deflect_test_app(min_level - 1);
+        press_throw(&mut app);
+
+        let config = PhysicsConfig::default();
+        let world = app.world();
+        assert!(!world.entity(shot).contains::<EnemyProjectile>());
+        assert!(world.entity(shot).contains::<Projectile>());
+        assert!(world.entity(shot).contains::<DeflectedShot>());
+        let linvel = world.get::<Velocity>(shot).unwrap().linvel;
+        let speed = config.enemy_projectile_speed * config.tractor_deflect_speed_multiplier;
+        assert!((linvel - Vec2::Y * speed).length() < 1e-3, "{linvel:?}");
+        let energy = world.resource::<PlayerEnergy>();
+        assert!((energy.max - energy.current - config.tractor_deflect_energy_cost).abs() < 1e-4);
+        assert_eq!(world.resource::<TractorDeflectWindow>().remaining_secs, 0.0);
+    }
+
+    #[test]
+    fn low_tractor_levels_cannot_deflect() {
+        let min_level = PhysicsConfig::default().tractor_deflect_min_level;
+        let (mut app, shot) = build_deflect_test_app(min_level - 2);
+        press_throw(&mut app);
+
+        assert!(app.world().entity(shot).contains::<EnemyProjectile>());
+        let energy = app.world().resource::<PlayerEnergy>();
+        assert_eq!(energy.current, energy.max);
+    }
+
+    #[test]
+    fn window_catches_a_shot_that_enters_the_beam_late() {
+        let min_level = PhysicsConfig::default().tractor_deflect_min_level;
+        let (mut app, shot) = build_deflect_test_app(min_level - 1);
+        app.world_mut()
+            .get_mut::<Transform>(shot)
+            .unwrap()
+            .translation = Vec3::new(0.0, -120.0, 0.0);
+        press_throw(&mut app);
+        assert!(app.world().entity(shot).contains::<EnemyProjectile>());
+        assert!(
+            app.world()
+                .resource::<TractorDeflectWindow>()
+                .remaining_secs
+                > 0.0
+        );
+
+        app.world_mut()
+            .get_mut::<Transform>(shot)
+            .unwrap()
+            .translation = Vec3::new(0.0, 80.0, 0.0);
+        app.update();
+        assert!(app.world().entity(shot).contains::<Projectile>());
+    }
+
+    #[test]
+    fn one_press_throws_a_held_asteroid_or_deflects_never_both() {
+        let min_level = PhysicsConfig::default().tractor_deflect_min_level;
+
+        // Holding an asteroid: the press throws it and leaves the shot alone.
+        let (mut app, shot) = build_deflect_test_app(min_level - 1);
+        let rock = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                AsteroidSize(1),
+                Transform::from_xyz(0.0, 60.0, 0.0),
+                Velocity::zero(),
+                ExternalForce::default(),
+            ))
+            .id();
+        app.world_mut().resource_mut::<TractorHoldState>().engaged = true;
+        app.world_mut().resource_mut::<TractorCaptureState>().target = Some(rock);
+        press_throw(&mut app);
+
+        let world = app.world();
+        assert_eq!(world.resource::<TractorCaptureState>().target, None);
+        assert!(world.resource::<TractorThrowCooldown>().timer_secs > 0.0);
+        assert!(world.entity(shot).contains::<EnemyProjectile>());
+        assert_eq!(world.resource::<TractorDeflectWindow>().remaining_secs, 0.0);
+        let energy = world.resource::<PlayerEnergy>();
+        assert_eq!(energy.current, energy.max);
+
+        // Nothing held: the same press deflects and starts no throw cooldown.
+        let (mut app, shot) = build_deflect_test_app(min_level - 1);
+        press_throw(&mut app);
+        assert!(app.world().entity(shot).contains::<Projectile>());
+        assert_eq!(
+            app.world().resource::<TractorThrowCooldown>().timer_secs,
+            0.0
+        );
+    }
+
+    #[test]
+    fn deflect_needs_the_energy_cost() {
+        let min_level = PhysicsConfig::default().tractor_deflect_min_level;
+        let (mut app, shot) = build_deflect_test_app(min_level - 1);
+        app.world_mut().resource_mut::<PlayerEnergy>().current = 1.0;
+        press_throw(&mut app);
+
+        assert!(app.world().entity(shot).contains::<EnemyProjectile>());
+        assert_eq!(app.world().resource::<PlayerEnergy>().current, 1.0);
+    }
 }
@@ -0,0 +1,200 @@
+//! Shared ship energy pool: regeneration and per-system draws.
+//!
+//! [`PlayerEnergy`] is a single pool that every powered ship system draws on:
+//!
+//! | Consumer      | Draw type  | Config key                    | Where drawn                     |
+//! |---------------|------------|-------------------------------|---------------------------------|
+//! | Thrusters     | continuous | `thrust_energy_per_sec`       | [`player_energy_system`]        |
+//! | Ion cannon    | per shot   | `ion_cannon_energy_cost`      | `ion_cannon_fire_system`        |
+//! | Tractor beam  | continuous | `tractor_beam_energy_per_sec` | [`tractor_energy_drain_system`] |
+//! | Shield regen  | continuous | `shield_regen_energy_per_hp`  | `player_shield_system`          |
+//!
+//! Continuous draws degrade gracefully: when the pool cannot cover a frame's
+//! demand the thrust intent is scaled down by the satisfied fraction, and the
+//! tractor beam disengages.  Discrete draws (ion shots) are all-or-nothing.
+//!
+//! Regeneration resumes `player_energy_regen_delay_secs` after the most recent
+//! draw at `player_energy_regen_rate` per second.
+
+use super::state::{Player, PlayerEnergy, PlayerIntent, TractorHoldState};
+use crate::config::PhysicsConfig;
+use bevy::prelude::*;
+
+/// Normalised thrust load (`0..=1`) requested by the current intent.
+///
+/// Forward, reverse, and strafe inputs are combined and capped at `1.0` so a
+/// diagonal manoeuvre costs the same as a straight burn.
+pub fn thrust_load(intent: &PlayerIntent) -> f32 {
+    (intent.thrust_forward
+        + intent.thrust_reverse
+        + intent.strafe_local.abs()
+        + intent.strafe_world.length().min(1.0))
+    .clamp(0.0, 1.0)
+}
+
+/// Regenerate the energy pool and throttle thrust intent by available energy.
+///
+/// Runs after the keyboard/gamepad intent systems and before
+/// `apply_player_intent_system`, so an empty pool leaves the ship coasting
+/// without touching the movement code itself.  While the ship is dead the pool
+/// is held full so every respawn starts fully charged.
+pub fn player_energy_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    q_player: Query<(), With<Player>>,
+    mut energy: ResMut<PlayerEnergy>,
+    mut intent: ResMut<PlayerIntent>,
+) {
+    // Track hot-reloaded capacity without refilling a partially drained pool.
+    if energy.max != config.player_energy_max {
+        let was_full = energy.current >= energy.max;
+        energy.max = config.player_energy_max.max(0.0);
+        energy.current = if was_full {
+            energy.max
+        } else {
+            energy.current.min(energy.max)
+        };
+    }
+
+    if q_player.is_empty() {
+        *energy = PlayerEnergy::full(energy.max);
+        return;
+    }
+
+    let dt = time.delta_secs();
+    let load = thrust_load(&intent);
+    if load > 0.0 {
+        let satisfied = energy.drain(config.thrust_energy_per_sec * load * dt);
+        if satisfied < 1.0 {
+            intent.thrust_forward *= satisfied;
+            intent.thrust_reverse *= satisfied;
+            intent.strafe_local *= satisfied;
+            intent.strafe_world *= satisfied;
+        }
+    }
+
+    energy.regenerate(
+        dt,
+        config.player_energy_regen_rate,
+        config.player_energy_regen_delay_secs,
+    );
+}
+
+/// Drain energy while tractor hold mode is engaged; drop the hold when empty.
+///
+/// Runs after `tractor_beam_force_system` in `FixedUpdate`; once the hold is
+/// released the force system lets go of the captured asteroid on its next tick.
+pub fn tractor_energy_drain_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    mut hold_state: ResMut<TractorHoldState>,
+    mut energy: ResMut<PlayerEnergy>,
+) {
+    if !hold_state.engaged {
+        return;
+    }
+
+    let satisfied = energy.drain(config.tractor_beam_energy_per_sec * time.delta_secs());
+    if satisfied < 1.0 {
+        hold_state.engaged = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_energy_test_app(energy: PlayerEnergy) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(PhysicsConfig::default());
+        app.insert_resource(PlayerIntent::default());
+        app.insert_resource(TractorHoldState::default());
+        app.insert_resource(energy);
+        app
+    }
+
+    #[test]
+    fn spend_and_drain_track_since_draw_timer() {
+        let mut energy = PlayerEnergy::full(50.0);
+        energy.since_draw_secs = 3.0;
+
+        assert!(energy.try_spend(20.0));
+        assert_eq!(energy.current, 30.0);
+        assert_eq!(energy.since_draw_secs, 0.0);
+        assert!(!energy.try_spend(40.0), "all-or-nothing spend must fail");
+        assert_eq!(energy.current, 30.0);
+
+        let satisfied = energy.drain(60.0);
+        assert!((satisfied - 0.5).abs() < 1e-6);
+        assert_eq!(energy.current, 0.0);
+    }
+
+    #[test]
+    fn regenerate_waits_for_delay_then_caps_at_max() {
+        let mut energy = PlayerEnergy::full(10.0);
+        energy.current = 5.0;
+
+        energy.regenerate(0.2, 100.0, 0.5);
+        assert_eq!(energy.current, 5.0, "no regen inside the delay window");
+
+        energy.regenerate(0.4, 100.0, 0.5);
+        assert_eq!(energy.current, 10.0, "regen clamps to max");
+    }
+
+    #[test]
+    fn empty_pool_throttles_thrust_intent() {
+        let mut app = build_energy_test_app(PlayerEnergy {
+            current: 0.0,
+            max: crate::constants::PLAYER_ENERGY_MAX,
+            since_draw_secs: 0.0,
+        });
+        app.world_mut().spawn(Player);
+        app.add_systems(
+            Update,
+            (
+                |mut intent: ResMut<PlayerIntent>| intent.thrust_forward = 1.0,
+                player_energy_system,
+            )
+                .chain(),
+        );
+
+        app.update();
+        app.update();
+
+        let intent = app.world().resource::<PlayerIntent>();
+        assert_eq!(intent.thrust_forward, 0.0, "empty pool must cut thrust");
+    }
+
+    #[test]
+    fn dead_ship_keeps_pool_full() {
+        let mut app = build_energy_test_app(PlayerEnergy {
+            current: 1.0,
+            max: crate::constants::PLAYER_ENERGY_MAX,
+            since_draw_secs: 0.0,
+        });
+        app.add_systems(Update, player_energy_system);
+
+        app.update();
+
+        let energy = app.world().resource::<PlayerEnergy>();
+        assert_eq!(energy.current, energy.max);
+    }
+
+    #[test]
+    fn tractor_hold_disengages_when_pool_empty() {
+        let mut app = build_energy_test_app(PlayerEnergy {
+            current: 0.0,
+            max: crate::constants::PLAYER_ENERGY_MAX,
+            since_draw_secs: 0.0,
+        });
+        app.world_mut().resource_mut::<TractorHoldState>().engaged = true;
+        app.add_systems(Update, tractor_energy_drain_system);
+
+        // First update has a zero delta; the second drains a real frame.
+        app.update();
+        app.update();
+
+        assert!(!app.world().resource::<TractorHoldState>().engaged);
+    }
+}
@@ -1,10 +1,12 @@
 use super::state::{
-    AimDirection, CampaignLoadout, CampaignSecondaryWeapon, IonCannonLevel, Player,
+    AimDirection, CampaignLoadout, CampaignSecondaryWeapon, IonCannonLevel, Player, PlayerEnergy,
 };
 use crate::asteroid_rendering::filled_polygon_mesh;
+use crate::config::PhysicsConfig;
 use crate::enemy::{Enemy, EnemyStun, EnemyTier};
 use crate::menu::SelectedGameMode;
 use crate::particles::spawn_ion_particles;
+use crate::session_stats::{SessionStatEvent, StatWeapon};
 use bevy::input::gamepad::GamepadButton;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
@@ -63,6 +65,8 @@ pub fn ion_cannon_fire_system(
     q_player: Query<&Transform, With<Player>>,
     selected_mode: Res<SelectedGameMode>,
     campaign_loadout: Res<CampaignLoadout>,
+    mut energy: ResMut<PlayerEnergy>,
+    config: Res<PhysicsConfig>,
 ) {
     if *selected_mode == SelectedGameMode::Campaign
         && campaign_loadout.secondary != CampaignSecondaryWeapon::IonCannon
@@ -94,6 +98,11 @@ pub fn ion_cannon_fire_system(
     if fire_dir == Vec2::ZERO {
         return;
     }
+    // Not enough charge: the trigger is swallowed and the cooldown stays clear
+    // so the shot fires as soon as the pool recovers.
+    if !energy.try_spend(config.ion_cannon_energy_cost) {
+        return;
+    }
 
     let spawn_pos = player_transform.translation.truncate() + fire_dir * 14.0;
 
@@ -191,6 +200,7 @@ pub fn ion_cannon_hit_enemy_system(
     q_shots: Query<&Transform, With<IonCannonShot>>,
     mut q_enemy: Query<(&EnemyTier, &mut EnemyStun), With<Enemy>>,
     ion_level: Res<IonCannonLevel>,
+    mut session: MessageWriter<SessionStatEvent>,
 ) {
     let max_tier = ion_level.max_enemy_tier_affected();
     let stun_secs = ion_level.stun_duration_secs();
@@ -226,6 +236,7 @@ pub fn ion_cannon_hit_enemy_system(
         let Ok((tier, mut stun)) = q_enemy.get_mut(enemy_entity) else {
             continue;
         };
+        session.write(SessionStatEvent::Hit(StatWeapon::IonCannon));
         let applied_stun = if tier.level <= max_tier {
             stun_secs
         } else {
@@ -0,0 +1,597 @@
+//! Kill-cam: a replay of the last seconds before the ship died.
+//!
+//! [`record_kill_cam_history_system`] runs in `FixedLast` and keeps a ring
+//! buffer ([`KillCamReplay`]) of the last `kill_cam_replay_secs` of physics
+//! poses: the ship, plus every asteroid, enemy ship and enemy shot within
+//! `kill_cam_replay_radius` of it, along with the mesh and material each was
+//! drawn with.
+//!
+//! Player death is resolved in several places (asteroid impacts in
+//! `combat::player_collision_damage_system`, enemy rams and enemy shots in
+//! `enemy.rs`).  Rather than thread kill-cam state through each of them,
+//! [`kill_cam_system`] runs in `Last` — after every death site for the frame —
+//! and detects the death from the ship disappearing while a respawn or Game
+//! Over is pending.
+//!
+//! On death it:
+//! 1. pauses `Time<Virtual>`, freezing physics and the respawn countdown;
+//! 2. hides the live bodies in the buffer and spawns a [`KillCamGhost`] for
+//!    every recorded body, including the destroyed ship and anything else
+//!    that no longer exists;
+//! 3. plays the buffer back over `kill_cam_duration_secs` of real time, with
+//!    the camera following the ship's recorded position into the killing
+//!    collision;
+//! 4. defers any pending `GameOver` transition until the replay ends, then
+//!    removes the ghosts, shows the live bodies again and unpauses.
+//!
+//! With an empty buffer the camera simply holds on the ship's last position
+//! for the window.
+
+use super::state::{KillCamState, Player, PlayerLives};
+use crate::asteroid::Asteroid;
+use crate::asteroid_rendering::{AsteroidLod, AsteroidRenderHandles};
+use crate::config::PhysicsConfig;
+use crate::enemy::{Boss, Enemy, EnemyProjectile};
+use crate::menu::GameState;
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+/// Stand-in mesh drawing a recorded body during the replay.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct KillCamGhost;
+
+/// One recorded physics tick.
+#[derive(Debug, Clone, Default)]
+pub struct KillCamFrame {
+    pub time_secs: f32,
+    pub player: Option<Vec2>,
+    pub bodies: Vec<(Entity, Transform)>,
+}
+
+/// Mesh and material a recorded body was last drawn with.
+#[derive(Debug, Clone)]
+struct ReplayLook {
+    mesh: Handle<Mesh>,
+    material: Handle<ColorMaterial>,
+    last_seen_secs: f32,
+}
+
+/// Ring buffer of recent poses, and the ghosts replaying them.
+#[derive(Resource, Debug, Default)]
+pub struct KillCamReplay {
+    frames: VecDeque<KillCamFrame>,
+    looks: HashMap<Entity, ReplayLook>,
+    /// Recorded body → the ghost standing in for it.
+    ghosts: HashMap<Entity, Entity>,
+    /// Live bodies hidden for the replay, with the visibility to restore.
+    hidden: Vec<(Entity, Visibility)>,
+}
+
+impl KillCamReplay {
+    #[cfg(test)]
+    pub fn frames(&self) -> &VecDeque<KillCamFrame> {
+        &self.frames
+    }
+
+    /// Append a frame and drop the ones older than `keep_secs` before it.
+    pub fn push(&mut self, frame: KillCamFrame, keep_secs: f32) {
+        let oldest = frame.time_secs - keep_secs.max(0.0);
+        self.frames.push_back(frame);
+        while self
+            .frames
+            .front()
+            .is_some_and(|front| front.time_secs < oldest)
+        {
+            self.frames.pop_front();
+        }
+        self.looks.retain(|_, look| look.last_seen_secs >= oldest);
+    }
+
+    /// Times of the first and last recorded frames.
+    pub fn span(&self) -> Option<(f32, f32)> {
+        Some((
+            self.frames.front()?.time_secs,
+            self.frames.back()?.time_secs,
+        ))
+    }
+
+    /// Ship position and body poses at `time_secs`, blended between the two
+    /// frames around it.  Bodies missing from the later frame keep the pose
+    /// of the earlier one.
+    pub fn pose_at(&self, time_secs: f32) -> (Option<Vec2>, Vec<(Entity, Transform)>) {
+        let Some(next) = self.frames.iter().position(|f| f.time_secs > time_secs) else {
+            return self
+                .frames
+                .back()
+                .map_or((None, Vec::new()), |f| (f.player, f.bodies.clone()));
+        };
+        let Some(prev) = next.checked_sub(1).map(|i| &self.frames[i]) else {
+            let first = &self.frames[next];
+            return (first.player, first.bodies.clone());
+        };
+        let next = &self.frames[next];
+        let alpha = ((time_secs - prev.time_secs) / (next.time_secs - prev.time_secs).max(1e-6))
+            .clamp(0.0, 1.0);
+        let later: HashMap<Entity, &Transform> = next.bodies.iter().map(|(e, t)| (*e, t)).collect();
+        let bodies = prev
+            .bodies
+            .iter()
+            .map(|&(entity, from)| {
+                let pose = later.get(&entity).map_or(from, |to| Transform {
+                    translation: from.translation.lerp(to.translation, alpha),
+                    rotation: from.rotation.slerp(to.rotation, alpha),
+                    scale: from.scale.lerp(to.scale, alpha),
+                });
+                (entity, pose)
+            })
+            .collect();
+        let player = match (prev.player, next.player) {
+            (Some(a), Some(b)) => Some(a.lerp(b, alpha)),
+            (a, b) => a.or(b),
+        };
+        (player, bodies)
+    }
+
+    /// Move every recorded pose by `delta` (floating-origin shift).
+    pub fn shift(&mut self, delta: Vec2) {
+        for frame in &mut self.frames {
+            if let Some(pos) = frame.player.as_mut() {
+                *pos += delta;
+            }
+            for (_, transform) in &mut frame.bodies {
+                transform.translation += delta.extend(0.0);
+            }
+        }
+    }
+
+    /// Hide the recorded live bodies and spawn a ghost for each.
+    fn begin(
+        &mut self,
+        commands: &mut Commands,
+        q_visibility: &mut Query<&mut Visibility, Without<KillCamGhost>>,
+    ) {
+        let start = self.span().map(|(start, _)| start);
+        let first: HashMap<Entity, Transform> = start
+            .map(|start| self.pose_at(start).1.into_iter().collect())
+            .unwrap_or_default();
+        for (&entity, look) in &self.looks {
+            if let Ok(mut visibility) = q_visibility.get_mut(entity) {
+                self.hidden.push((entity, *visibility));
+                *visibility = Visibility::Hidden;
+            }
+            let (transform, visibility) = first
+                .get(&entity)
+                .map_or((Transform::default(), Visibility::Hidden), |&t| {
+                    (t, Visibility::Visible)
+                });
+            let ghost = commands
+                .spawn((
+                    KillCamGhost,
+                    Mesh2d(look.mesh.clone()),
+                    MeshMaterial2d(look.material.clone()),
+                    transform,
+                    visibility,
+                ))
+                .id();
+            self.ghosts.insert(entity, ghost);
+        }
+    }
+
+    /// Remove the ghosts, show the live bodies again and empty the buffer,
+    /// so the next death does not replay this one.
+    fn finish(
+        &mut self,
+        commands: &mut Commands,
+        q_visibility: &mut Query<&mut Visibility, Without<KillCamGhost>>,
+    ) {
+        for (_, ghost) in self.ghosts.drain() {
+            commands.entity(ghost).try_despawn();
+        }
+        for (entity, visibility) in self.hidden.drain(..) {
+            if let Ok(mut current) = q_visibility.get_mut(entity) {
+                *current = visibility;
+            }
+        }
+        self.frames.clear();
+        self.looks.clear();
+    }
+}
+
+/// Record this tick's poses near the ship into the replay buffer.
+///
+/// Stops while no ship exists or the kill-cam is playing, so the buffer ends
+/// on the tick of the killing collision.
+#[allow(clippy::type_complexity)]
+pub fn record_kill_cam_history_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    kill_cam: Res<KillCamState>,
+    mut replay: ResMut<KillCamReplay>,
+    q_player: Query<&Transform, With<Player>>,
+    q_bodies: Query<
+        (
+            Entity,
+            &Transform,
+            &Mesh2d,
+            Option<&MeshMaterial2d<ColorMaterial>>,
+            Option<&AsteroidRenderHandles>,
+        ),
+        Or<(
+            With<Player>,
+            With<Asteroid>,
+            With<Enemy>,
+            With<Boss>,
+            With<EnemyProjectile>,
+        )>,
+    >,
+) {
+    if !config.kill_cam_enabled || kill_cam.is_active() {
+        return;
+    }
+    let Ok(player) = q_player.single() else {
+        return;
+    };
+    let now = time.elapsed_secs();
+    let centre = player.translation.truncate();
+    let radius_sq = config.kill_cam_replay_radius * config.kill_cam_replay_radius;
+
+    let mut bodies = Vec::new();
+    for (entity, transform, mesh, material, handles) in q_bodies.iter() {
+        if transform.translation.truncate().distance_squared(centre) > radius_sq {
+            continue;
+        }
+        // Instanced hulls replay with their retained full-detail mesh.
+        let (mesh, material) = match (material, handles) {
+            (Some(material), _) => (mesh.0.clone(), material.0.clone()),
+            (None, Some(handles)) => handles.variant(AsteroidLod::Full, false),
+            (None, None) => continue,
+        };
+        bodies.push((entity, *transform));
+        replay.looks.insert(
+            entity,
+            ReplayLook {
+                mesh,
+                material,
+                last_seen_secs: now,
+            },
+        );
+    }
+    replay.push(
+        KillCamFrame {
+            time_secs: now,
+            player: Some(centre),
+            bodies,
+        },
+        config.kill_cam_replay_secs,
+    );
+}
+
+/// Detect player death, play the replay, and release deferred Game Over
+/// transitions.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn kill_cam_system(
+    mut commands: Commands,
+    real_time: Res<Time<Real>>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    config: Res<PhysicsConfig>,
+    lives: Res<PlayerLives>,
+    q_player: Query<&Transform, With<Player>>,
+    mut q_camera: Query<&mut Transform, (With<Camera>, Without<Player>, Without<KillCamGhost>)>,
+    mut q_visibility: Query<&mut Visibility, Without<KillCamGhost>>,
+    mut q_ghosts: Query<
+        (&mut Transform, &mut Visibility),
+        (With<KillCamGhost>, Without<Camera>, Without<Player>),
+    >,
+    mut kill_cam: ResMut<KillCamState>,
+    mut replay: ResMut<KillCamReplay>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    let player_pos = q_player.single().ok().map(|t| t.translation.truncate());
+
+    let died = kill_cam.last_player_pos.is_some()
+        && player_pos.is_none()
+        && (lives.respawn_timer.is_some() || lives.remaining <= 0);
+    let died_at = kill_cam.last_player_pos;
+    kill_cam.last_player_pos = player_pos;
+
+    if died && config.kill_cam_enabled && !kill_cam.is_active() {
+        kill_cam.remaining_secs = config.kill_cam_duration_secs.max(0.0);
+        kill_cam.focus = died_at.unwrap_or_default();
+        if matches!(*next_state, NextState::Pending(GameState::GameOver)) {
+            next_state.reset();
+            kill_cam.hold_game_over = true;
+        }
+        virtual_time.pause();
+        replay.begin(&mut commands, &mut q_visibility);
+    }
+
+    if !kill_cam.is_active() {
+        return;
+    }
+
+    let duration = config.kill_cam_duration_secs.max(1e-3);
+    let progress = (1.0 - kill_cam.remaining_secs / duration).clamp(0.0, 1.0);
+    if let Some((start, end)) = replay.span() {
+        let (focus, poses) = replay.pose_at(start + (end - start) * progress);
+        if let Some(focus) = focus {
+            kill_cam.focus = focus;
+        }
+        for &ghost in replay.ghosts.values() {
+            if let Ok((_, mut visibility)) = q_ghosts.get_mut(ghost) {
+                *visibility = Visibility::Hidden;
+            }
+        }
+        for (entity, pose) in poses {
+            let Some(&ghost) = replay.ghosts.get(&entity) else {
+                continue;
+            };
+            if let Ok((mut transform, mut visibility)) = q_ghosts.get_mut(ghost) {
+                *transform = pose;
+                *visibility = Visibility::Visible;
+            }
+        }
+    }
+
+    if let Ok(mut cam) = q_camera.single_mut() {
+        cam.translation.x = kill_cam.focus.x;
+        cam.translation.y = kill_cam.focus.y;
+    }
+
+    kill_cam.remaining_secs -= real_time.delta_secs();
+    // A respawn that lands mid-window ends the kill-cam early.
+    if kill_cam.remaining_secs <= 0.0 || player_pos.is_some() {
+        kill_cam.remaining_secs = 0.0;
+        virtual_time.unpause();
+        replay.finish(&mut commands, &mut q_visibility);
+        if std::mem::take(&mut kill_cam.hold_game_over) {
+            next_state.set(GameState::GameOver);
+        }
+    }
+}
+
+/// Restore normal time flow and clear kill-cam state.
+///
+/// Runs on `OnEnter(MainMenu)` so quitting from the pause menu mid-kill-cam
+/// does not leave the next session paused or its ghosts behind.
+pub fn reset_kill_cam_system(
+    mut commands: Commands,
+    mut virtual_time: ResMut<Time<Virtual>>,
+    mut kill_cam: ResMut<KillCamState>,
+    mut replay: ResMut<KillCamReplay>,
+    q_ghosts: Query<Entity, With<KillCamGhost>>,
+) {
+    virtual_time.unpause();
+    virtual_time.set_relative_speed(1.0);
+    *kill_cam = KillCamState::default();
+    *replay = KillCamReplay::default();
+    for ghost in q_ghosts.iter() {
+        commands.entity(ghost).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::system::RunSystemOnce;
+    use bevy::state::app::StatesPlugin;
+
+    fn build_kill_cam_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin));
+        app.init_state::<GameState>();
+        app.insert_resource(PhysicsConfig::default());
+        app.insert_resource(PlayerLives::default());
+        app.insert_resource(KillCamState::default());
+        app.init_resource::<KillCamReplay>();
+        app.add_systems(Last, kill_cam_system);
+        app
+    }
+
+    fn frame(time_secs: f32, player: Vec2, bodies: &[(Entity, Vec2)]) -> KillCamFrame {
+        KillCamFrame {
+            time_secs,
+            player: Some(player),
+            bodies: bodies
+                .iter()
+                .map(|&(e, p)| (e, Transform::from_translation(p.extend(0.0))))
+                .collect(),
+        }
+    }
+
+    fn look() -> ReplayLook {
+        ReplayLook {
+            mesh: Handle::default(),
+            material: Handle::default(),
+            last_seen_secs: 0.0,
+        }
+    }
+
+    fn ghost_count(app: &mut App) -> usize {
+        app.world_mut()
+            .query_filtered::<(), With<KillCamGhost>>()
+            .iter(app.world())
+            .count()
+    }
+
+    #[test]
+    fn buffer_keeps_window_and_interpolates() {
+        let body = Entity::from_raw_u32(7).unwrap();
+        let mut replay = KillCamReplay::default();
+        for i in 0..=4 {
+            let t = i as f32 * 0.5;
+            replay.push(
+                frame(t, Vec2::new(t * 10.0, 0.0), &[(body, Vec2::new(0.0, t))]),
+                1.0,
+            );
+        }
+        // Only frames within one second of the newest survive.
+        assert_eq!(replay.span(), Some((1.0, 2.0)));
+        assert_eq!(replay.frames().len(), 3);
+
+        let (player, bodies) = replay.pose_at(1.25);
+        assert!(player.unwrap().distance(Vec2::new(12.5, 0.0)) < 1e-4);
+        assert_eq!(bodies.len(), 1);
+        assert!((bodies[0].1.translation.y - 1.25).abs() < 1e-4);
+
+        replay.shift(Vec2::new(100.0, 0.0));
+        let (player, _) = replay.pose_at(2.0);
+        assert!(player.unwrap().distance(Vec2::new(120.0, 0.0)) < 1e-4);
+    }
+
+    #[test]
+    fn recording_keeps_only_bodies_near_the_ship() {
+        let mut world = World::new();
+        world.init_resource::<Time>();
+        world.insert_resource(PhysicsConfig::default());
+        world.insert_resource(KillCamState::default());
+        world.init_resource::<KillCamReplay>();
+        let drawn = || {
+            (
+                Mesh2d(Handle::default()),
+                MeshMaterial2d::<ColorMaterial>(Handle::default()),
+            )
+        };
+        let player = world.spawn((Player, Transform::default(), drawn())).id();
+        let near = world
+            .spawn((Asteroid, Transform::from_xyz(100.0, 0.0, 0.0), drawn()))
+            .id();
+        let far = world
+            .spawn((Asteroid, Transform::from_xyz(1.0e5, 0.0, 0.0), drawn()))
+            .id();
+
+        world
+            .run_system_once(record_kill_cam_history_system)
+            .expect("record system runs");
+
+        let replay = world.resource::<KillCamReplay>();
+        let recorded: Vec<Entity> = replay.frames()[0].bodies.iter().map(|(e, _)| *e).collect();
+        assert!(recorded.contains(&player));
+        assert!(recorded.contains(&near));
+        assert!(!recorded.contains(&far));
+    }
+
+    #[test]
+    fn death_pauses_time_and_defers_game_over() {
+        let mut app = build_kill_cam_test_app();
+        let player = app
+            .world_mut()
+            .spawn((Player, Transform::from_xyz(40.0, -10.0, 0.0)))
+            .id();
+        app.update();
+
+        // Kill the ship from inside the frame, as the real death sites do, so
+        // the Game Over request is still pending when `Last` runs.
+        app.add_systems(
+            Update,
+            move |mut commands: Commands,
+                  q: Query<(), With<Player>>,
+                  mut lives: ResMut<PlayerLives>,
+                  mut next_state: ResMut<NextState<GameState>>| {
+                if q.contains(player) {
+                    commands.entity(player).despawn();
+                    lives.remaining = 0;
+                    next_state.set(GameState::GameOver);
+                }
+            },
+        );
+        app.update();
+
+        let kill_cam = *app.world().resource::<KillCamState>();
+        assert!(kill_cam.is_active());
+        assert!(kill_cam.hold_game_over);
+        assert_eq!(kill_cam.focus, Vec2::new(40.0, -10.0));
+        assert!(matches!(
+            *app.world().resource::<NextState<GameState>>(),
+            NextState::Unchanged
+        ));
+        assert!(app.world().resource::<Time<Virtual>>().is_paused());
+    }
+
+    #[test]
+    fn replay_swaps_live_bodies_for_ghosts_until_it_ends() {
+        let mut app = build_kill_cam_test_app();
+        let player = app.world_mut().spawn((Player, Transform::default())).id();
+        let rock = app
+            .world_mut()
+            .spawn((Transform::default(), Visibility::Visible))
+            .id();
+        {
+            let mut replay = app.world_mut().resource_mut::<KillCamReplay>();
+            replay.push(
+                frame(
+                    0.0,
+                    Vec2::ZERO,
+                    &[(player, Vec2::ZERO), (rock, Vec2::new(10.0, 0.0))],
+                ),
+                2.0,
+            );
+            replay.push(
+                frame(
+                    1.0,
+                    Vec2::new(5.0, 0.0),
+                    &[(player, Vec2::new(5.0, 0.0)), (rock, Vec2::new(20.0, 0.0))],
+                ),
+                2.0,
+            );
+            replay.looks.insert(player, look());
+            replay.looks.insert(rock, look());
+        }
+        app.update();
+
+        app.world_mut().despawn(player);
+        app.world_mut().resource_mut::<PlayerLives>().remaining = 0;
+        app.update();
+
+        assert!(app.world().resource::<KillCamState>().is_active());
+        assert_eq!(ghost_count(&mut app), 2);
+        assert_eq!(
+            *app.world().get::<Visibility>(rock).unwrap(),
+            Visibility::Hidden
+        );
+
+        // The replay opens on the oldest recorded frame.
+        app.update();
+        let ghost = app.world().resource::<KillCamReplay>().ghosts[&rock];
+        let pos = app.world().get::<Transform>(ghost).unwrap().translation;
+        assert!((pos.x - 10.0).abs() < 0.5);
+
+        app.world_mut()
+            .resource_mut::<KillCamState>()
+            .remaining_secs = 1e-6;
+        app.update();
+        app.update();
+
+        assert!(!app.world().resource::<KillCamState>().is_active());
+        assert_eq!(ghost_count(&mut app), 0);
+        assert_eq!(
+            *app.world().get::<Visibility>(rock).unwrap(),
+            Visibility::Visible
+        );
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+        assert!(app.world().resource::<KillCamReplay>().frames().is_empty());
+    }
+
+    #[test]
+    fn expired_window_unpauses_and_releases_game_over() {
+        let mut app = build_kill_cam_test_app();
+        app.insert_resource(KillCamState {
+            remaining_secs: 1e-6,
+            hold_game_over: true,
+            ..Default::default()
+        });
+        app.world_mut().resource_mut::<Time<Virtual>>().pause();
+
+        // First frame has zero real delta; the second consumes the window and
+        // the third applies the released Game Over transition.
+        app.update();
+        app.update();
+        app.update();
+
+        assert!(!app.world().resource::<KillCamState>().is_active());
+        assert!(!app.world().resource::<Time<Virtual>>().is_paused());
+        assert_eq!(
+            *app.world().resource::<State<GameState>>().get(),
+            GameState::GameOver
+        );
+    }
+}
@@ -7,6 +7,11 @@
 //! | [`state`] | ECS components (`Player`, `PlayerHealth`, `Projectile`) and Bevy resources (`AimDirection`, `PreferredGamepad`, `PlayerFireCooldown`) |
 //! | [`control`] | Input systems: WASD thrust, A/D rotation, gamepad left-stick movement, out-of-bounds damping |
 //! | [`combat`] | Projectile firing, lifetime management, player-asteroid damage, asteroid splitting/chipping |
+//! | [`energy`] | Shared energy pool regeneration and per-system draws (thrust, ion cannon, tractor) |
+//! | [`kill_cam`] | Kill-cam replay of the seconds before player death; defers Game Over until it finishes |
+//! | [`shield`] | Purchasable shield layer: attach/resize on `ShieldLevel`, energy-backed regeneration |
+//! | [`turret`] | Purchasable auto-turrets: hardpoint children on `TurretLevel`, target acquisition, energy-costed fire |
+//! | [`targeting`] | Lock-on target selection, bracket mesh, missile heading bias, camera framing |
 //! | [`rendering`] | Ship gizmo outline, health bar, aim indicator, projectile circles, camera follow |
 //!
 //! All public items are re-exported at this level so that the rest of the crate
@@ -15,9 +20,14 @@
 
 pub mod combat;
 pub mod control;
+pub mod energy;
 pub mod ion_cannon;
+pub mod kill_cam;
 pub mod rendering;
+pub mod shield;
 pub mod state;
+pub mod targeting;
+pub mod turret;
 
 // ── Flat re-exports (backward-compatible API surface) ─────────────────────────
 
@@ -30,28 +40,42 @@ pub use combat::{
 pub use control::{
     aim_snap_system, apply_player_intent_system, gamepad_connection_system,
     gamepad_to_intent_system, keyboard_to_intent_system, player_intent_clear_system,
-    player_thrust_particles_system, tractor_beam_force_system, tractor_hold_toggle_system,
-    tractor_throw_cooldown_tick_system,
+    player_thrust_particles_system, tractor_beam_force_system, tractor_deflect_system,
+    tractor_hold_toggle_system, tractor_throw_cooldown_tick_system,
 };
+pub use energy::{player_energy_system, tractor_energy_drain_system};
 pub use ion_cannon::{
     attach_ion_cannon_shot_mesh_system, despawn_old_ion_cannon_shots_system,
     ion_cannon_fire_system, ion_cannon_hit_enemy_system, ion_shot_particles_system,
     stunned_enemy_particles_system, IonCannonCooldown,
 };
+pub use kill_cam::{
+    kill_cam_system, record_kill_cam_history_system, reset_kill_cam_system, KillCamReplay,
+};
 pub use rendering::{
     attach_missile_mesh_system, attach_player_ship_mesh_system, attach_player_ui_system,
     attach_projectile_mesh_system, camera_follow_system, cleanup_player_ui_system,
-    sync_aim_indicator_system, sync_player_and_projectile_mesh_visibility_system,
-    sync_player_health_bar_system, sync_projectile_outline_visibility_system,
+    prepare_shot_meshes_system, sync_aim_indicator_system,
+    sync_player_and_projectile_mesh_visibility_system, sync_player_health_bar_system,
+    sync_player_shield_visual_system, sync_projectile_outline_visibility_system,
     sync_projectile_rotation_system, sync_ship_outline_visibility_and_color_system,
-    PlayerUiEntities,
+    PlayerUiEntities, ShotMeshes,
 };
+pub use shield::player_shield_system;
 pub use state::{
-    AimDirection, AimIdleTimer, CampaignLoadout, CampaignPrimaryWeapon, CampaignSecondaryWeapon,
-    IonCannonLevel, MissileAmmo, MissileCooldown, Player, PlayerFireCooldown, PlayerHealth,
-    PlayerIntent, PlayerLives, PlayerScore, PreferredGamepad, PrimaryWeaponFireRateLevel,
-    PrimaryWeaponUpgradeTracks, SecondaryWeaponLevel, TractorBeamLevel, TractorCaptureState,
-    TractorHoldState, TractorThrowCooldown,
+    AimDirection, AimIdleTimer, AimTargetMemory, CampaignLoadout, CampaignPrimaryWeapon,
+    CampaignSecondaryWeapon, IonCannonLevel, KillCamState, LockOnState, MissileAmmo,
+    MissileCooldown, Player, PlayerEnergy, PlayerFireCooldown, PlayerHealth, PlayerIntent,
+    PlayerLives, PlayerScore, PlayerShield, PreferredGamepad, PrimaryWeaponFireRateLevel,
+    PrimaryWeaponUpgradeTracks, SecondaryWeaponLevel, ShieldLevel, TractorBeamLevel,
+    TractorCaptureState, TractorDeflectWindow, TractorHoldState, TractorThrowCooldown, TurretLevel,
+};
+pub use targeting::{
+    lock_on_camera_framing_system, lock_on_system, missile_homing_system,
+    missile_lock_on_steer_system, sync_lock_on_bracket_system, LockOnBracket,
+};
+pub use turret::{
+    attach_turret_mesh_system, player_turret_system, turret_aim_fire_system, Turret, TurretRound,
 };
 
 // ── Ship spawn ─────────────────────────────────────────────────────────────────
@@ -9,12 +9,19 @@
 //! | Ship wireframe outline | `Mesh2d`   | OFF     | `show_ship_outline`        |
 //! | Aim direction indicator| `Mesh2d`   | OFF     | `show_aim_indicator`       |
 //! | Health bar             | `Mesh2d`   | always  | —                          |
+//! | Shield ring + hit arc  | `Mesh2d`   | with shield | —                      |
 //! | Projectile filled disc | `Mesh2d`   | ON      | hidden in `wireframe_only` |
 //! | Projectile outline     | `Mesh2d`   | OFF     | `show_projectile_outline`  |
 
-use super::state::{AimDirection, Missile, Player, PlayerHealth, Projectile};
+use super::state::{AimDirection, Missile, Player, PlayerHealth, PlayerShield, Projectile};
+use crate::asteroid::Asteroid;
 use crate::asteroid_rendering::{filled_polygon_mesh, polygon_outline_mesh, ring_mesh};
+use crate::config::PhysicsConfig;
+use crate::constants::SHIELD_VISUAL_RADIUS;
+use crate::graphics::palette::Palette;
+use crate::rendering::inspector::AsteroidSelection;
 use crate::rendering::OverlayState;
+use crate::simulation::CameraState;
 use bevy::prelude::*;
 use bevy_asset::RenderAssetUsages;
 use bevy_mesh::{Indices, PrimitiveTopology};
@@ -36,6 +43,10 @@ pub struct PlayerUiEntities {
     pub health_bar_fill: Option<Entity>,
     /// Orange aim-direction arrow.
     pub aim_indicator: Option<Entity>,
+    /// Faint shield bubble ring (hidden while no shield is installed).
+    pub shield_ring: Option<Entity>,
+    /// Directional arc flashed toward the most recent shield impact.
+    pub shield_arc: Option<Entity>,
 }
 
 // ── ECS component markers ─────────────────────────────────────────────────────
@@ -54,6 +65,14 @@ pub struct HealthBarFill(pub Handle<ColorMaterial>);
 #[derive(Component)]
 pub struct AimIndicatorMesh;
 
+/// Marker + material handle for the shield bubble ring entity.
+#[derive(Component)]
+pub struct ShieldRingMesh(pub Handle<ColorMaterial>);
+
+/// Marker + material handle for the directional shield hit-arc entity.
+#[derive(Component)]
+pub struct ShieldArcMesh(pub Handle<ColorMaterial>);
+
 /// Marker for retained projectile outline meshes.
 #[derive(Component)]
 pub struct ProjectileOutlineMesh;
@@ -70,6 +89,97 @@ pub struct ShipOutlineMesh(pub Handle<ColorMaterial>);
 #[derive(Component)]
 pub struct ShipNoseMesh;
 
+// ── Shared shot meshes ────────────────────────────────────────────────────────
+
+/// Mesh and material handles shared by every projectile and missile.
+///
+/// Built on the loading screen by [`prepare_shot_meshes_system`] so the first
+/// shots of a session do not allocate; the attach systems build it on demand
+/// when a session skipped the loading screen (test scenarios).
+#[derive(Resource, Clone)]
+pub struct ShotMeshes {
+    pub projectile: Handle<Mesh>,
+    pub projectile_material: Handle<ColorMaterial>,
+    pub projectile_outline: Handle<Mesh>,
+    pub projectile_outline_material: Handle<ColorMaterial>,
+    pub missile: Handle<Mesh>,
+    pub missile_material: Handle<ColorMaterial>,
+    pub missile_outline: Handle<Mesh>,
+    pub missile_outline_material: Handle<ColorMaterial>,
+}
+
+const PROJ_RADIUS: f32 = 2.0;
+const PROJ_LENGTH: f32 = 10.0;
+const PROJ_OUTLINE_RADIUS: f32 = 3.0;
+const PROJ_OUTLINE_THICKNESS: f32 = 0.8;
+const MISSILE_BODY_WIDTH: f32 = 6.0;
+const MISSILE_BODY_LENGTH: f32 = 12.0;
+const MISSILE_NOSE_LENGTH: f32 = 6.0;
+const MISSILE_FIN_SIZE: f32 = 4.0;
+const MISSILE_OUTLINE_RADIUS: f32 = 5.5;
+const MISSILE_OUTLINE_THICKNESS: f32 = 1.0;
+
+impl ShotMeshes {
+    pub fn build(meshes: &mut Assets<Mesh>, materials: &mut Assets<ColorMaterial>) -> Self {
+        Self {
+            projectile: meshes.add(elongated_projectile_mesh(PROJ_RADIUS, PROJ_LENGTH, 16)),
+            projectile_material: materials
+                .add(ColorMaterial::from_color(Color::srgb(1.0, 0.85, 0.1))),
+            projectile_outline: meshes.add(ring_mesh(
+                PROJ_OUTLINE_RADIUS,
+                PROJ_OUTLINE_THICKNESS,
+                24,
+            )),
+            projectile_outline_material: materials
+                .add(ColorMaterial::from_color(Color::srgb(1.0, 0.9, 0.2))),
+            missile: meshes.add(rocket_mesh(
+                MISSILE_BODY_WIDTH,
+                MISSILE_BODY_LENGTH,
+                MISSILE_NOSE_LENGTH,
+                MISSILE_FIN_SIZE,
+            )),
+            missile_material: materials
+                .add(ColorMaterial::from_color(Color::srgb(1.0, 0.45, 0.05))),
+            missile_outline: meshes.add(ring_mesh(
+                MISSILE_OUTLINE_RADIUS,
+                MISSILE_OUTLINE_THICKNESS,
+                28,
+            )),
+            missile_outline_material: materials
+                .add(ColorMaterial::from_color(Color::srgb(1.0, 0.45, 0.05))),
+        }
+    }
+}
+
+/// Build [`ShotMeshes`] once, if it does not exist yet.
+pub fn prepare_shot_meshes_system(
+    mut commands: Commands,
+    existing: Option<Res<ShotMeshes>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if existing.is_none() {
+        commands.insert_resource(ShotMeshes::build(&mut meshes, &mut materials));
+    }
+}
+
+/// The shared shot meshes, building (and inserting) them if missing.
+fn shot_meshes_or_build(
+    commands: &mut Commands,
+    existing: Option<Res<ShotMeshes>>,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<ColorMaterial>,
+) -> ShotMeshes {
+    match existing {
+        Some(shot) => shot.clone(),
+        None => {
+            let shot = ShotMeshes::build(meshes, materials);
+            commands.insert_resource(shot.clone());
+            shot
+        }
+    }
+}
+
 // ── Mesh geometry helpers ─────────────────────────────────────────────────────
 
 /// A unit square centred at the origin (−0.5 to +0.5 on both axes).
@@ -110,13 +220,46 @@ fn aim_arrow_mesh() -> Mesh {
     mesh.insert_indices(Indices::U32(vec![0, 1, 2]));
     mesh
 }
+
+/// A thick arc of `radius` spanning ±`half_angle` around local +X.
+///
+/// Rotated via [`Transform`] so +X faces the shield impact direction.
+fn shield_arc_mesh(radius: f32, thickness: f32, half_angle: f32, segments: usize) -> Mesh {
+    let r_outer = radius + thickness * 0.5;
+    let r_inner = (radius - thickness * 0.5).max(0.0);
+
+    let mut positions: Vec<[f32; 3]> = Vec::with_capacity((segments + 1) * 2);
+    let mut indices: Vec<u32> = Vec::with_capacity(segments * 6);
+    for i in 0..=segments {
+        let theta = -half_angle + (i as f32 / segments as f32) * half_angle * 2.0;
+        let (sin, cos) = theta.sin_cos();
+        positions.push([cos * r_outer, sin * r_outer, 0.0]);
+        positions.push([cos * r_inner, sin * r_inner, 0.0]);
+        if i < segments {
+            let base = (i * 2) as u32;
+            indices.extend_from_slice(&[base, base + 2, base + 1, base + 1, base + 2, base + 3]);
+        }
+    }
+
+    let vcount = positions.len();
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, vec![[0.0, 0.0, 1.0]; vcount]);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0.0, 0.0]; vcount]);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
 // ── Ship geometry ─────────────────────────────────────────────────────────────
 
 /// Local-space vertices of the player ship polygon (dart / arrowhead shape).
 ///
 /// The ship's nose points along local +Y; the two fins sweep back along −Y.
 /// This orientation means the ship always thrusts in its transform's +Y direction.
-fn ship_vertices() -> Vec<Vec2> {
+pub fn ship_vertices() -> Vec<Vec2> {
     vec![
         Vec2::new(0.0, 12.0),  // nose
         Vec2::new(-8.0, -8.0), // left fin tip
@@ -251,20 +394,20 @@ pub fn attach_player_ship_mesh_system(
 ///
 /// Runs once per projectile entity (via [`Added<Projectile>`]).
 /// The mesh is oriented along +Y; a separate system rotates it to match velocity.
+/// Mesh and material handles come from the shared [`ShotMeshes`].
 pub fn attach_projectile_mesh_system(
     mut commands: Commands,
     mut query: Query<(Entity, &Velocity, &mut Transform), Added<Projectile>>,
+    shot_meshes: Option<Res<ShotMeshes>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     overlay: Res<OverlayState>,
 ) {
-    const PROJ_RADIUS: f32 = 2.0;
-    const PROJ_LENGTH: f32 = 10.0;
-    const OUTLINE_RADIUS: f32 = 3.0;
-    const OUTLINE_THICKNESS: f32 = 0.8;
-    let mat_handle = materials.add(ColorMaterial::from_color(Color::srgb(1.0, 0.85, 0.1)));
+    if query.is_empty() {
+        return;
+    }
+    let shot = shot_meshes_or_build(&mut commands, shot_meshes, &mut meshes, &mut materials);
     for (entity, velocity, mut transform) in query.iter_mut() {
-        let mesh_handle = meshes.add(elongated_projectile_mesh(PROJ_RADIUS, PROJ_LENGTH, 16));
         let visibility = if overlay.wireframe_only {
             Visibility::Hidden
         } else {
@@ -277,8 +420,8 @@ pub fn attach_projectile_mesh_system(
         transform.rotation = Quat::from_rotation_z(angle);
 
         commands.entity(entity).insert((
-            Mesh2d(mesh_handle),
-            MeshMaterial2d(mat_handle.clone()),
+            Mesh2d(shot.projectile.clone()),
+            MeshMaterial2d(shot.projectile_material.clone()),
             visibility,
         ));
 
@@ -289,10 +432,8 @@ pub fn attach_projectile_mesh_system(
         };
         let outline = commands
             .spawn((
-                Mesh2d(meshes.add(ring_mesh(OUTLINE_RADIUS, OUTLINE_THICKNESS, 24))),
-                MeshMaterial2d(
-                    materials.add(ColorMaterial::from_color(Color::srgb(1.0, 0.9, 0.2))),
-                ),
+                Mesh2d(shot.projectile_outline.clone()),
+                MeshMaterial2d(shot.projectile_outline_material.clone()),
                 Transform::from_translation(Vec3::new(0.0, 0.0, 0.02)),
                 outline_visibility,
                 ProjectileOutlineMesh,
@@ -309,20 +450,16 @@ pub fn attach_projectile_mesh_system(
 pub fn attach_missile_mesh_system(
     mut commands: Commands,
     mut query: Query<(Entity, &Velocity, &mut Transform), Added<Missile>>,
+    shot_meshes: Option<Res<ShotMeshes>>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     overlay: Res<OverlayState>,
 ) {
-    const BODY_WIDTH: f32 = 6.0;
-    const BODY_LENGTH: f32 = 12.0;
-    const NOSE_LENGTH: f32 = 6.0;
-    const FIN_SIZE: f32 = 4.0;
-    const OUTLINE_RADIUS: f32 = 5.5;
-    const OUTLINE_THICKNESS: f32 = 1.0;
-
-    let mat_handle = materials.add(ColorMaterial::from_color(Color::srgb(1.0, 0.45, 0.05)));
+    if query.is_empty() {
+        return;
+    }
+    let shot = shot_meshes_or_build(&mut commands, shot_meshes, &mut meshes, &mut materials);
     for (entity, velocity, mut transform) in query.iter_mut() {
-        let mesh_handle = meshes.add(rocket_mesh(BODY_WIDTH, BODY_LENGTH, NOSE_LENGTH, FIN_SIZE));
         let visibility = if overlay.wireframe_only {
             Visibility::Hidden
         } else {
@@ -335,8 +472,8 @@ pub fn attach_missile_mesh_system(
         transform.rotation = Quat::from_rotation_z(angle);
 
         commands.entity(entity).insert((
-            Mesh2d(mesh_handle),
-            MeshMaterial2d(mat_handle.clone()),
+            Mesh2d(shot.missile.clone()),
+            MeshMaterial2d(shot.missile_material.clone()),
             visibility,
         ));
 
@@ -347,10 +484,8 @@ pub fn attach_missile_mesh_system(
         };
         let outline = commands
             .spawn((
-                Mesh2d(meshes.add(ring_mesh(OUTLINE_RADIUS, OUTLINE_THICKNESS, 28))),
-                MeshMaterial2d(
-                    materials.add(ColorMaterial::from_color(Color::srgb(1.0, 0.45, 0.05))),
-                ),
+                Mesh2d(shot.missile_outline.clone()),
+                MeshMaterial2d(shot.missile_outline_material.clone()),
                 Transform::from_translation(Vec3::new(0.0, 0.0, 0.02)),
                 outline_visibility,
                 MissileOutlineMesh,
@@ -374,6 +509,7 @@ pub fn attach_player_ui_system(
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
     mut ui: ResMut<PlayerUiEntities>,
+    palette: Res<Palette>,
 ) {
     for _ in query.iter() {
         // ── Health bar background (dark-red track) ───────────────────────────
@@ -390,7 +526,7 @@ pub fn attach_player_ui_system(
 
         // ── Health bar fill (green→red) ────────────────────────────────────
         let fill_mesh = meshes.add(unit_rect_mesh());
-        let fill_mat_handle = materials.add(ColorMaterial::from_color(Color::srgb(0.0, 1.0, 0.0)));
+        let fill_mat_handle = materials.add(ColorMaterial::from_color(palette.health(1.0)));
         let fill_entity = commands
             .spawn((
                 Mesh2d(fill_mesh),
@@ -413,10 +549,39 @@ pub fn attach_player_ui_system(
             ))
             .id();
 
+        // ── Shield ring + directional hit arc ────────────────────────────────
+        let ring_mat = materials.add(ColorMaterial::from_color(Color::srgba(0.3, 0.7, 1.0, 0.0)));
+        let ring_entity = commands
+            .spawn((
+                Mesh2d(meshes.add(ring_mesh(SHIELD_VISUAL_RADIUS, 1.0, 32))),
+                MeshMaterial2d(ring_mat.clone()),
+                Transform::default(),
+                Visibility::Hidden,
+                ShieldRingMesh(ring_mat),
+            ))
+            .id();
+        let arc_mat = materials.add(ColorMaterial::from_color(Color::srgba(0.5, 0.85, 1.0, 0.0)));
+        let arc_entity = commands
+            .spawn((
+                Mesh2d(meshes.add(shield_arc_mesh(
+                    SHIELD_VISUAL_RADIUS,
+                    3.0,
+                    std::f32::consts::FRAC_PI_3,
+                    12,
+                ))),
+                MeshMaterial2d(arc_mat.clone()),
+                Transform::default(),
+                Visibility::Hidden,
+                ShieldArcMesh(arc_mat),
+            ))
+            .id();
+
         *ui = PlayerUiEntities {
             health_bar_bg: Some(bg_entity),
             health_bar_fill: Some(fill_entity),
             aim_indicator: Some(aim_entity),
+            shield_ring: Some(ring_entity),
+            shield_arc: Some(arc_entity),
         };
     }
 }
@@ -433,6 +598,7 @@ pub fn sync_player_health_bar_system(
     mut q_bg: Query<&mut Transform, (With<HealthBarBg>, Without<Player>, Without<HealthBarFill>)>,
     mut q_fill: Query<(&HealthBarFill, &mut Transform), (Without<Player>, Without<HealthBarBg>)>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    palette: Res<Palette>,
 ) {
     let Ok((ptrans, health)) = q_player.single() else {
         return;
@@ -460,9 +626,9 @@ pub fn sync_player_health_bar_system(
             t.translation = Vec3::new(fill_x, pos.y + BAR_Y, 1.0);
             t.scale = Vec3::new(fill_w, BAR_HEIGHT, 1.0);
             t.rotation = Quat::IDENTITY;
-            // Colour: green at full HP → red at empty.
+            // Colour: the palette's full → empty health gradient.
             if let Some(mat) = materials.get_mut(&fill_comp.0) {
-                mat.color = Color::srgb(1.0 - hp_frac, hp_frac, 0.0);
+                mat.color = palette.health(hp_frac);
             }
         }
     }
@@ -504,6 +670,62 @@ pub fn sync_aim_indicator_system(
     }
 }
 
+/// Position the shield ring and directional hit arc around the player.
+///
+/// The ring is visible while a [`PlayerShield`] is attached, with opacity
+/// tracking the charge fraction.  The arc points toward
+/// [`PlayerShield::last_hit_dir`] and fades out over `hit_flash_secs`.
+#[allow(clippy::type_complexity)]
+pub fn sync_player_shield_visual_system(
+    q_player: Query<(&Transform, Option<&PlayerShield>), With<Player>>,
+    ui: Res<PlayerUiEntities>,
+    mut q_ring: Query<
+        (&ShieldRingMesh, &mut Transform, &mut Visibility),
+        (Without<Player>, Without<ShieldArcMesh>),
+    >,
+    mut q_arc: Query<
+        (&ShieldArcMesh, &mut Transform, &mut Visibility),
+        (Without<Player>, Without<ShieldRingMesh>),
+    >,
+    config: Res<PhysicsConfig>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let Ok((ptrans, shield)) = q_player.single() else {
+        return;
+    };
+    let pos = ptrans.translation.with_z(1.8);
+
+    if let Some((ring, mut t, mut vis)) = ui.shield_ring.and_then(|e| q_ring.get_mut(e).ok()) {
+        match shield.filter(|s| s.hp > 0.0) {
+            Some(shield) => {
+                t.translation = pos;
+                *vis = Visibility::Visible;
+                if let Some(mat) = materials.get_mut(&ring.0) {
+                    mat.color = Color::srgba(0.3, 0.7, 1.0, 0.15 + 0.45 * shield.fraction());
+                }
+            }
+            None => *vis = Visibility::Hidden,
+        }
+    }
+
+    if let Some((arc, mut t, mut vis)) = ui.shield_arc.and_then(|e| q_arc.get_mut(e).ok()) {
+        match shield.filter(|s| s.hit_flash_secs > 0.0) {
+            Some(shield) => {
+                let dir = shield.last_hit_dir;
+                t.translation = pos.with_z(1.9);
+                t.rotation = Quat::from_rotation_z(dir.y.atan2(dir.x));
+                *vis = Visibility::Visible;
+                let fade = (shield.hit_flash_secs / config.shield_hit_flash_secs.max(1e-3))
+                    .clamp(0.0, 1.0);
+                if let Some(mat) = materials.get_mut(&arc.0) {
+                    mat.color = Color::srgba(0.5, 0.85, 1.0, 0.9 * fade);
+                }
+            }
+            None => *vis = Visibility::Hidden,
+        }
+    }
+}
+
 /// Despawn the floating health bar and aim indicator entities when the player
 /// is removed (i.e., when they are destroyed).
 ///
@@ -531,6 +753,12 @@ pub fn cleanup_player_ui_system(
         if let Some(e) = ui.aim_indicator.take() {
             commands.entity(e).despawn();
         }
+        if let Some(e) = ui.shield_ring.take() {
+            commands.entity(e).despawn();
+        }
+        if let Some(e) = ui.shield_arc.take() {
+            commands.entity(e).despawn();
+        }
     }
 }
 
@@ -670,17 +898,34 @@ pub fn sync_ship_outline_visibility_and_color_system(
 ///
 /// Camera Z is preserved (used internally by Bevy for rendering order).
 /// Zoom scale is applied separately in `simulation::camera_zoom_system`.
+/// While lock-on framing is active the camera is shifted by
+/// `CameraState::frame_offset` so both ship and target stay on screen, and
+/// `CameraState::shake_offset` adds screen shake on top.
+/// With the inspector's follow camera on, the selected asteroid is centred
+/// instead.
+#[allow(clippy::type_complexity)]
 pub fn camera_follow_system(
     q_player: Query<&Transform, With<Player>>,
-    mut q_camera: Query<&mut Transform, (With<Camera>, Without<Player>)>,
+    q_asteroids: Query<&Transform, (With<Asteroid>, Without<Camera>)>,
+    mut q_camera: Query<&mut Transform, (With<Camera>, Without<Player>, Without<Asteroid>)>,
+    camera_state: Res<CameraState>,
+    selection: Res<AsteroidSelection>,
 ) {
-    let Ok(player_transform) = q_player.single() else {
+    let Ok(mut cam) = q_camera.single_mut() else {
         return;
     };
-    let Ok(mut cam) = q_camera.single_mut() else {
+    if selection.follow {
+        if let Some(target) = selection.entity.and_then(|e| q_asteroids.get(e).ok()) {
+            cam.translation.x = target.translation.x + camera_state.shake_offset.x;
+            cam.translation.y = target.translation.y + camera_state.shake_offset.y;
+            return;
+        }
+    }
+    let Ok(player_transform) = q_player.single() else {
         return;
     };
 
-    cam.translation.x = player_transform.translation.x;
-    cam.translation.y = player_transform.translation.y;
+    let offset = camera_state.frame_offset + camera_state.shake_offset;
+    cam.translation.x = player_transform.translation.x + offset.x;
+    cam.translation.y = player_transform.translation.y + offset.y;
 }
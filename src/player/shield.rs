@@ -0,0 +1,122 @@
+//! Purchasable shield: component lifecycle and energy-backed regeneration.
+//!
+//! [`ShieldLevel`] (bought in the ore shop) sets the shield capacity.  While it
+//! is non-zero [`player_shield_system`] keeps a [`PlayerShield`] component on
+//! the ship, grows it when a new level is bought, and regenerates it once
+//! `shield_recharge_delay_secs` have passed since the last absorbed hit.
+//!
+//! Regeneration draws `shield_regen_energy_per_hp` from the shared
+//! [`PlayerEnergy`] pool per HP restored, so a recharging shield competes with
+//! thrust, the ion cannon, and the tractor beam.
+//!
+//! Damage absorption happens in `combat::player_collision_damage_system`; the
+//! ring and directional hit-arc visuals are in
+//! `rendering::sync_player_shield_visual_system`.
+
+use super::state::{Player, PlayerEnergy, PlayerShield, ShieldLevel};
+use crate::config::PhysicsConfig;
+use bevy::prelude::*;
+
+/// Attach, resize, and regenerate the player's shield.
+///
+/// A freshly spawned (or respawned) ship receives a fully charged shield.
+/// Buying a level while alive adds the new capacity on top of the current
+/// charge rather than refilling the whole shield.
+pub fn player_shield_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    level: Res<ShieldLevel>,
+    mut energy: ResMut<PlayerEnergy>,
+    mut q_player: Query<(Entity, Option<&mut PlayerShield>), With<Player>>,
+) {
+    let Ok((player_entity, shield)) = q_player.single_mut() else {
+        return;
+    };
+
+    let capacity = level.capacity(&config);
+    let Some(mut shield) = shield else {
+        if capacity > 0.0 {
+            commands
+                .entity(player_entity)
+                .insert(PlayerShield::full(capacity));
+        }
+        return;
+    };
+
+    if capacity <= 0.0 {
+        commands.entity(player_entity).remove::<PlayerShield>();
+        return;
+    }
+
+    if shield.max_hp != capacity {
+        let added = (capacity - shield.max_hp).max(0.0);
+        shield.max_hp = capacity;
+        shield.hp = (shield.hp + added).min(capacity);
+    }
+
+    let dt = time.delta_secs();
+    shield.hit_flash_secs = (shield.hit_flash_secs - dt).max(0.0);
+    shield.since_hit_secs += dt;
+
+    if shield.since_hit_secs < config.shield_recharge_delay_secs || shield.hp >= shield.max_hp {
+        return;
+    }
+
+    let wanted = (config.shield_regen_rate * dt).min(shield.max_hp - shield.hp);
+    let cost = wanted * config.shield_regen_energy_per_hp;
+    let satisfied = if cost > 0.0 { energy.drain(cost) } else { 1.0 };
+    shield.hp = (shield.hp + wanted * satisfied).min(shield.max_hp);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_shield_test_app(level: u32) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(PhysicsConfig::default());
+        app.insert_resource(ShieldLevel { level });
+        app.insert_resource(PlayerEnergy::default());
+        app.add_systems(Update, player_shield_system);
+        app
+    }
+
+    #[test]
+    fn purchased_level_attaches_full_shield() {
+        let mut app = build_shield_test_app(2);
+        let player = app.world_mut().spawn(Player).id();
+
+        app.update();
+
+        let shield = app.world().get::<PlayerShield>(player).copied().unwrap();
+        let expected = 2.0 * PhysicsConfig::default().shield_hp_per_level;
+        assert_eq!(shield.max_hp, expected);
+        assert_eq!(shield.hp, expected);
+    }
+
+    #[test]
+    fn no_shield_without_purchase() {
+        let mut app = build_shield_test_app(0);
+        let player = app.world_mut().spawn(Player).id();
+
+        app.update();
+
+        assert!(app.world().get::<PlayerShield>(player).is_none());
+    }
+
+    #[test]
+    fn recently_hit_shield_waits_for_recharge_delay() {
+        let mut app = build_shield_test_app(1);
+        let mut shield = PlayerShield::full(PhysicsConfig::default().shield_hp_per_level);
+        shield.hp = 1.0;
+        shield.since_hit_secs = 0.0;
+        let player = app.world_mut().spawn((Player, shield)).id();
+
+        app.update();
+        app.update();
+
+        assert_eq!(app.world().get::<PlayerShield>(player).unwrap().hp, 1.0);
+    }
+}
@@ -9,10 +9,11 @@
 use crate::config::PhysicsConfig;
 use crate::constants::{
     INVINCIBILITY_DURATION, ION_CANNON_BASE_STUN_SECS, ION_CANNON_MAX_LEVEL,
-    ION_CANNON_STUN_SECS_PER_LEVEL, ION_CANNON_UPGRADE_BASE_COST, MISSILE_AMMO_MAX, PLAYER_LIVES,
-    PLAYER_MAX_HP, PRIMARY_WEAPON_MAX_LEVEL, SECONDARY_WEAPON_MAX_LEVEL,
-    SECONDARY_WEAPON_UPGRADE_BASE_COST, TRACTOR_BEAM_MAX_LEVEL, TRACTOR_BEAM_UPGRADE_BASE_COST,
-    WEAPON_UPGRADE_BASE_COST,
+    ION_CANNON_STUN_SECS_PER_LEVEL, ION_CANNON_UPGRADE_BASE_COST, MISSILE_AMMO_MAX,
+    PLAYER_ENERGY_MAX, PLAYER_LIVES, PLAYER_MAX_HP, PRIMARY_WEAPON_MAX_LEVEL,
+    SECONDARY_WEAPON_MAX_LEVEL, SECONDARY_WEAPON_UPGRADE_BASE_COST, SHIELD_MAX_LEVEL,
+    SHIELD_UPGRADE_BASE_COST, TRACTOR_BEAM_MAX_LEVEL, TRACTOR_BEAM_UPGRADE_BASE_COST,
+    TURRET_MAX_COUNT, TURRET_UPGRADE_BASE_COST, WEAPON_UPGRADE_BASE_COST,
 };
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -20,7 +21,8 @@ use serde::{Deserialize, Serialize};
 // ── Components ─────────────────────────────────────────────────────────────────
 
 /// Marker component for the player ship entity.
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 pub struct Player;
 
 /// Tracks current HP and the remaining invincibility window after a hit.
@@ -28,7 +30,8 @@ pub struct Player;
 /// HP depletes when the player collides with an asteroid faster than
 /// `DAMAGE_SPEED_THRESHOLD`.  Invincibility frames prevent rapid damage
 /// stacking from a single sustained contact.
-#[derive(Component)]
+#[derive(Component, Reflect)]
+#[reflect(Component)]
 pub struct PlayerHealth {
     pub hp: f32,
     pub max_hp: f32,
@@ -49,8 +52,82 @@ impl Default for PlayerHealth {
     }
 }
 
+/// Regenerating shield layer attached to the player ship once a shield level
+/// has been purchased.
+///
+/// Asteroid impact damage is routed through [`PlayerShield::absorb`] before it
+/// reaches [`PlayerHealth`].  Absorption is directional: hits in the forward
+/// hemisphere are fully soaked, rear hits only partially.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct PlayerShield {
+    pub hp: f32,
+    pub max_hp: f32,
+    /// Seconds since the shield last absorbed a hit; gates regeneration.
+    pub since_hit_secs: f32,
+    /// World-space unit vector from the ship toward the most recent impact.
+    pub last_hit_dir: Vec2,
+    /// Remaining seconds of the directional hit-arc flash.
+    pub hit_flash_secs: f32,
+}
+
+impl PlayerShield {
+    /// A fully charged shield with `max_hp` capacity.
+    pub fn full(max_hp: f32) -> Self {
+        Self {
+            hp: max_hp,
+            max_hp,
+            since_hit_secs: f32::MAX,
+            last_hit_dir: Vec2::Y,
+            hit_flash_secs: 0.0,
+        }
+    }
+
+    /// Current charge as a `0..=1` fraction.
+    #[inline]
+    pub fn fraction(&self) -> f32 {
+        if self.max_hp <= 0.0 {
+            0.0
+        } else {
+            (self.hp / self.max_hp).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Soak `damage` arriving from world direction `hit_dir` and return the
+    /// remainder that passes through to the hull.
+    ///
+    /// `ship_forward` decides the absorb fraction: `1.0` for the forward
+    /// hemisphere, `config.shield_rear_absorb_fraction` behind.  Any absorbed
+    /// hit resets the recharge delay and starts the hit-arc flash.
+    pub fn absorb(
+        &mut self,
+        damage: f32,
+        hit_dir: Vec2,
+        ship_forward: Vec2,
+        config: &PhysicsConfig,
+    ) -> f32 {
+        if damage <= 0.0 || self.hp <= 0.0 {
+            return damage.max(0.0);
+        }
+        let fraction = if hit_dir.dot(ship_forward) >= 0.0 {
+            1.0
+        } else {
+            config.shield_rear_absorb_fraction.clamp(0.0, 1.0)
+        };
+        let absorbed = (damage * fraction).min(self.hp);
+        self.hp -= absorbed;
+        self.since_hit_secs = 0.0;
+        if hit_dir.length_squared() > 1e-6 {
+            self.last_hit_dir = hit_dir.normalize();
+        }
+        self.hit_flash_secs = config.shield_hit_flash_secs;
+        damage - absorbed
+    }
+}
+
 /// Per-projectile state attached to each fired round.
-#[derive(Component, Default)]
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
 pub struct Projectile {
     /// Seconds since this projectile was spawned.
     pub age: f32,
@@ -65,7 +142,8 @@ pub struct Projectile {
 ///
 /// Missiles are fired with `X` / right-click and have different destruction
 /// rules from normal projectiles (see `combat::missile_asteroid_hit_system`).
-#[derive(Component, Default)]
+#[derive(Component, Default, Reflect)]
+#[reflect(Component)]
 pub struct Missile {
     /// Seconds since this missile was spawned.
     pub age: f32,
@@ -73,19 +151,23 @@ pub struct Missile {
     pub distance_traveled: f32,
     /// Time accumulator used by the trail emission system.
     pub trail_emit_timer: f32,
+    /// Target acquired by homing guidance (missile level ≥
+    /// `missile_homing_min_level`).
+    pub homing_target: Option<Entity>,
 }
 
 // ── Resources ──────────────────────────────────────────────────────────────────
 
 /// Tracks available missile ammo.
-#[derive(Resource, Debug, Clone)]
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
 pub struct MissileAmmo {
     /// Missiles currently available to fire.
     pub count: u32,
 }
 
 /// Supported primary-weapon choices for campaign loadout selection.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, Reflect)]
 pub enum CampaignPrimaryWeapon {
     #[default]
     Blaster,
@@ -105,7 +187,7 @@ impl CampaignPrimaryWeapon {
 }
 
 /// Supported secondary-weapon choices for campaign loadout selection.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default, Reflect)]
 pub enum CampaignSecondaryWeapon {
     #[default]
     Missile,
@@ -131,7 +213,8 @@ impl CampaignSecondaryWeapon {
 }
 
 /// Active campaign loadout applied at runtime.
-#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Reflect)]
+#[reflect(Resource)]
 pub struct CampaignLoadout {
     pub primary: CampaignPrimaryWeapon,
     pub secondary: CampaignSecondaryWeapon,
@@ -155,14 +238,16 @@ impl Default for MissileAmmo {
 }
 
 /// Enforces a minimum interval between consecutive missile shots.
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
 pub struct MissileCooldown {
     /// Remaining cooldown in seconds; decremented each frame, clamped to 0.
     pub timer: f32,
 }
 
 /// Enforces a minimum interval between consecutive shots.
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
 pub struct PlayerFireCooldown {
     /// Remaining cooldown in seconds; decremented each frame, clamped to 0.
     pub timer: f32,
@@ -174,7 +259,8 @@ pub struct PlayerFireCooldown {
 /// or by `projectile_fire_system` (gamepad right stick).
 /// Falls back to the ship's local +Y (forward) direction when no explicit aim
 /// source is active.
-#[derive(Resource, Clone, Copy)]
+#[derive(Resource, Clone, Copy, Reflect)]
+#[reflect(Resource)]
 pub struct AimDirection(pub Vec2);
 
 impl Default for AimDirection {
@@ -188,16 +274,18 @@ impl Default for AimDirection {
 ///
 /// Updated by `gamepad_connection_system`.  Always prefers the *last* connected
 /// gamepad; cleared when that gamepad disconnects.
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
 pub struct PreferredGamepad(pub Option<Entity>);
 
 /// Tracks how long (seconds) since any active aim input was last received.
 ///
 /// Reset to 0.0 whenever the mouse cursor moves, the gamepad left stick is
 /// active, or the right stick is active.  When the timer exceeds
-/// `AIM_IDLE_SNAP_SECS` the aim direction is snapped back to the ship's
-/// local forward (+Y).
-#[derive(Resource, Default)]
+/// `AIM_IDLE_SNAP_SECS` the aim direction is snapped back to the remembered
+/// target ([`AimTargetMemory`]) or the ship's local forward (+Y).
+#[derive(Resource, Default, Reflect)]
+#[reflect(Resource)]
 pub struct AimIdleTimer {
     /// Seconds since the last active aim input.
     pub secs: f32,
@@ -205,11 +293,22 @@ pub struct AimIdleTimer {
     pub last_cursor: Option<Vec2>,
 }
 
+/// The enemy or boss the player's weapons damaged most recently.
+///
+/// Set by the enemy and boss damage systems when a hit leaves the target
+/// alive; read by `aim_snap_system`, which clears it once the entity is gone.
+#[derive(Resource, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct AimTargetMemory {
+    pub target: Option<Entity>,
+}
+
 /// Latched tractor hold-mode state.
 ///
 /// Toggled by keyboard/gamepad input and consumed by the tractor-beam force
 /// system to decide whether pull/throw actions are currently active.
-#[derive(Resource, Default, Debug, Clone, Copy)]
+#[derive(Resource, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
 pub struct TractorHoldState {
     pub engaged: bool,
 }
@@ -218,34 +317,148 @@ pub struct TractorHoldState {
 ///
 /// Stores which asteroid is currently captured (if any) and the maintained
 /// hold distance from the player while tractor mode is engaged.
-#[derive(Resource, Default, Debug, Clone, Copy)]
+#[derive(Resource, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
 pub struct TractorCaptureState {
     pub target: Option<Entity>,
     pub hold_distance: f32,
 }
 
 /// Cooldown timer after using tractor throw.
-#[derive(Resource, Default, Debug, Clone, Copy)]
+#[derive(Resource, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
 pub struct TractorThrowCooldown {
     pub timer_secs: f32,
 }
 
-/// Multiplier tier thresholds (streak → multiplier).
+/// Time left to catch an enemy shot after a throw press.
+///
+/// Opened by `R` / `RB` once the tractor reaches `tractor_deflect_min_level`;
+/// closed by the first caught shot.
+#[derive(Resource, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct TractorDeflectWindow {
+    pub remaining_secs: f32,
+}
+
+/// Current lock-on target and its world-space bracket entity.
+///
+/// Toggled by `T` / right-stick click.  While `target` is `Some`, missiles
+/// bias their heading toward it and the camera may frame ship + target.
+#[derive(Resource, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct LockOnState {
+    pub target: Option<Entity>,
+    /// Retained bracket `Mesh2d` drawn around the target (spawned on demand).
+    pub bracket: Option<Entity>,
+}
+
+/// Kill-cam playback state for the player's most recent death.
 ///
-/// | Streak | Multiplier |
-/// |--------|------------|
-/// | 0–4    | ×1         |
-/// | 5–9    | ×2         |
-/// | 10–19  | ×3         |
-/// | 20–39  | ×4         |
-/// | 40+    | ×5         |
-pub fn streak_to_multiplier(streak: u32) -> u32 {
-    match streak {
-        0..=4 => 1,
-        5..=9 => 2,
-        10..=19 => 3,
-        20..=39 => 4,
-        _ => 5,
+/// While `remaining_secs > 0` virtual time is paused, the recorded
+/// [`KillCamReplay`](super::KillCamReplay) plays back and the camera follows
+/// `focus`.  A Game Over requested during the death frame is
+/// deferred via `hold_game_over` until the kill-cam finishes.
+#[derive(Resource, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct KillCamState {
+    /// Real-time seconds left in the replay (`0` = inactive).
+    pub remaining_secs: f32,
+    /// World-space point the camera follows (the ship's replayed position).
+    pub focus: Vec2,
+    /// Ship position on the previous frame; `None` while no ship exists.
+    pub last_player_pos: Option<Vec2>,
+    /// Game Over transition to issue once the kill-cam ends.
+    pub hold_game_over: bool,
+}
+
+impl KillCamState {
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.remaining_secs > 0.0
+    }
+}
+
+/// Shared ship energy pool drawn on by thrusters, the ion cannon, the tractor
+/// beam, and shields.
+///
+/// Every draw resets `since_draw_secs`; regeneration only resumes once it
+/// exceeds `player_energy_regen_delay_secs`, so sustained use of any one
+/// system starves the others until the ship stops drawing power.
+#[derive(Resource, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
+pub struct PlayerEnergy {
+    pub current: f32,
+    pub max: f32,
+    /// Seconds since the most recent energy draw; gates regeneration.
+    pub since_draw_secs: f32,
+}
+
+impl Default for PlayerEnergy {
+    fn default() -> Self {
+        Self {
+            current: PLAYER_ENERGY_MAX,
+            max: PLAYER_ENERGY_MAX,
+            since_draw_secs: 0.0,
+        }
+    }
+}
+
+impl PlayerEnergy {
+    /// Full pool with the given capacity.
+    pub fn full(max: f32) -> Self {
+        Self {
+            current: max,
+            max,
+            since_draw_secs: 0.0,
+        }
+    }
+
+    /// Fill fraction in `[0, 1]` (used by the HUD bar).
+    #[inline]
+    pub fn fraction(&self) -> f32 {
+        if self.max <= 0.0 {
+            0.0
+        } else {
+            (self.current / self.max).clamp(0.0, 1.0)
+        }
+    }
+
+    /// Spend `amount` energy only if the whole amount is available.
+    ///
+    /// Used for discrete actions (ion shots) that must not fire half-charged.
+    pub fn try_spend(&mut self, amount: f32) -> bool {
+        if amount <= 0.0 {
+            return true;
+        }
+        if self.current < amount {
+            return false;
+        }
+        self.current -= amount;
+        self.since_draw_secs = 0.0;
+        true
+    }
+
+    /// Drain up to `amount` energy and return the fraction of the request that
+    /// was satisfied (`1.0` = fully powered, `0.0` = pool empty).
+    ///
+    /// Used for continuous draws (thrust, tractor hold) that degrade gracefully.
+    pub fn drain(&mut self, amount: f32) -> f32 {
+        if amount <= 0.0 {
+            return 1.0;
+        }
+        let taken = amount.min(self.current.max(0.0));
+        self.current -= taken;
+        self.since_draw_secs = 0.0;
+        taken / amount
+    }
+
+    /// Advance the regen timer and refill once the delay has elapsed.
+    pub fn regenerate(&mut self, dt: f32, rate: f32, delay_secs: f32) {
+        self.since_draw_secs += dt;
+        if self.since_draw_secs >= delay_secs {
+            self.current = (self.current + rate * dt).min(self.max);
+        }
     }
 }
 
@@ -255,7 +468,11 @@ pub fn streak_to_multiplier(streak: u32) -> u32 {
 /// - `destroyed`: Asteroids fully eliminated (size 0–1, no fragments).
 /// - `streak`: Consecutive hits without a miss; resets on miss or death.
 /// - `points`: Accumulated score (multiplier-weighted hits and destroys).
-#[derive(Resource, Default, Debug, Clone, Copy)]
+///
+/// Point values and the streak → multiplier curve come from
+/// [`crate::scoring::ScoringConfig`].
+#[derive(Resource, Default, Debug, Clone, Copy, Reflect)]
+#[reflect(Resource)]
 pub struct PlayerScore {
     pub hits: u32,
     pub destroyed: u32,
@@ -269,12 +486,6 @@ impl PlayerScore {
     pub fn total(self) -> u32 {
         self.points
     }
-
-    /// Active scoring multiplier derived from the current streak.
-    #[inline]
-    pub fn multiplier(self) -> u32 {
-        streak_to_multiplier(self.streak)
-    }
 }
 
 /// Tracks the player's current lives and pending respawn state.
@@ -283,7 +494,8 @@ impl PlayerScore {
 ///   Decremented on each death; reaching 0 triggers a game-over.
 /// - `respawn_timer`: when `Some(t)`, counts down `t` seconds before
 ///   re-spawning the player ship.  `None` means the player is alive.
-#[derive(Resource, Debug, Clone)]
+#[derive(Resource, Debug, Clone, Reflect)]
+#[reflect(Resource)]
 pub struct PlayerLives {
     /// Lives remaining (including the current life).
     pub remaining: i32,
@@ -320,7 +532,8 @@ impl PlayerLives {
 /// level is 10, full-destroy only applies to asteroids small enough to disappear
 /// without leaving a sizable remnant.  Anything above the threshold always takes
 /// exactly the chip path (1-unit fragment removed, asteroid shrinks by 1).
-#[derive(Resource, Debug, Clone, Default)]
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
 pub struct PrimaryWeaponLevel {
     /// Internal 0-indexed level (0 = Level 1 / base, 9 = Level 10 / max).
     pub level: u32,
@@ -385,7 +598,8 @@ impl PrimaryWeaponLevel {
 /// This is the data-layer slice for decoupling chip and destroy behavior.
 /// Runtime combat now consumes this split-track model; [`PrimaryWeaponLevel`]
 /// remains only for legacy serialization compatibility.
-#[derive(Resource, Debug, Clone, Default)]
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
 pub struct PrimaryWeaponUpgradeTracks {
     /// Internal 0-indexed level for chip power scaling.
     pub chip_level: u32,
@@ -468,18 +682,6 @@ impl PrimaryWeaponUpgradeTracks {
         }
     }
 
-    #[inline]
-    pub fn can_afford_next_chip(&self, ore: u32) -> bool {
-        self.cost_for_next_chip_level()
-            .is_some_and(|cost| ore >= cost)
-    }
-
-    #[inline]
-    pub fn can_afford_next_destroy(&self, ore: u32) -> bool {
-        self.cost_for_next_destroy_level()
-            .is_some_and(|cost| ore >= cost)
-    }
-
     pub fn try_upgrade_chip(&mut self, ore: &mut u32) -> Option<u32> {
         let cost = self.cost_for_next_chip_level()?;
         if *ore < cost {
@@ -505,7 +707,8 @@ impl PrimaryWeaponUpgradeTracks {
 ///
 /// Level increases scale effective primary fire rate (inverse cooldown)
 /// independently from chip/destroy tracks.
-#[derive(Resource, Debug, Clone, Default)]
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
 pub struct PrimaryWeaponFireRateLevel {
     /// Internal 0-indexed level (0 = Level 1 / base, 9 = Level 10 / max).
     pub level: u32,
@@ -547,11 +750,6 @@ impl PrimaryWeaponFireRateLevel {
         }
     }
 
-    #[inline]
-    pub fn can_afford_next(&self, ore: u32) -> bool {
-        self.cost_for_next_level().is_some_and(|cost| ore >= cost)
-    }
-
     pub fn try_upgrade(&mut self, ore: &mut u32) -> Option<u32> {
         let cost = self.cost_for_next_level()?;
         if *ore < cost {
@@ -578,7 +776,8 @@ impl PrimaryWeaponFireRateLevel {
 /// - Level 1: destroys 0–3
 /// - Level 5: destroys 0–7
 /// - Level 9 (max): destroys 0–11
-#[derive(Resource, Debug, Clone, Default)]
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
 pub struct SecondaryWeaponLevel {
     /// Internal 0-indexed level (0 = Level 1 / base, 9 = Level 10 / max).
     pub level: u32,
@@ -588,7 +787,8 @@ pub struct SecondaryWeaponLevel {
 ///
 /// Level scaling controls how aggressively the beam can interact with asteroids:
 /// radius, force, max affected asteroid size, and max affected asteroid speed.
-#[derive(Resource, Debug, Clone, Default)]
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
 pub struct TractorBeamLevel {
     /// Internal 0-indexed level (0 = base).
     pub level: u32,
@@ -599,7 +799,8 @@ pub struct TractorBeamLevel {
 /// The ion cannon emits a short EMP-like pulse that can temporarily disable
 /// enemy ships. Higher levels increase stun duration and improve effectiveness
 /// against stronger enemy tiers.
-#[derive(Resource, Debug, Clone, Default)]
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
 pub struct IonCannonLevel {
     /// Internal 0-indexed level (0 = Level 1 / base).
     pub level: u32,
@@ -661,12 +862,6 @@ impl TractorBeamLevel {
         }
     }
 
-    /// Returns `true` when the player has enough ore to afford the next upgrade.
-    #[inline]
-    pub fn can_afford_next(&self, ore: u32) -> bool {
-        self.cost_for_next_level().is_some_and(|cost| ore >= cost)
-    }
-
     /// Spend ore and increment the level. Returns the amount spent, or `None`
     /// if maxed-out or the player cannot afford it.
     pub fn try_upgrade(&mut self, ore: &mut u32) -> Option<u32> {
@@ -711,10 +906,17 @@ impl SecondaryWeaponLevel {
             .min(config.missile_split_max_pieces.max(2))
     }
 
+    /// Whether missiles at this level seek targets on their own.
+    #[inline]
+    pub fn has_homing(&self, config: &PhysicsConfig) -> bool {
+        self.display_level() >= config.missile_homing_min_level
+    }
+
     /// Whether this missile level should fully decompose an asteroid of `size`
     /// into unit fragments on impact.
     ///
     /// Rule: display level (1-indexed) must be at least the asteroid size.
+    #[allow(dead_code)]
     #[inline]
     pub fn can_fully_decompose_size(&self, size: u32) -> bool {
         self.display_level() >= size
@@ -738,12 +940,6 @@ impl SecondaryWeaponLevel {
         }
     }
 
-    /// Returns `true` when the player has enough ore to afford the next upgrade.
-    #[inline]
-    pub fn can_afford_next(&self, ore: u32) -> bool {
-        self.cost_for_next_level().is_some_and(|cost| ore >= cost)
-    }
-
     /// Spend ore and increment the level.  Returns the amount spent, or `None`
     /// if maxed-out or the player cannot afford it.
     pub fn try_upgrade(&mut self, ore: &mut u32) -> Option<u32> {
@@ -796,10 +992,61 @@ impl IonCannonLevel {
         }
     }
 
-    /// Returns `true` when the player has enough ore to afford the next upgrade.
+    /// Spend ore and increment the level. Returns the amount spent, or `None`
+    /// if maxed-out or the player cannot afford it.
+    pub fn try_upgrade(&mut self, ore: &mut u32) -> Option<u32> {
+        let cost = self.cost_for_next_level()?;
+        if *ore < cost {
+            return None;
+        }
+        *ore -= cost;
+        self.level += 1;
+        Some(cost)
+    }
+}
+
+/// Shield upgrade level.
+///
+/// Level 0 means no shield is installed; each level adds
+/// `shield_hp_per_level` capacity to the [`PlayerShield`] component.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
+pub struct ShieldLevel {
+    /// Purchased level (0 = not installed).
+    pub level: u32,
+}
+
+impl ShieldLevel {
+    /// Maximum level value (inclusive).
+    pub const MAX: u32 = SHIELD_MAX_LEVEL;
+
+    /// Human-readable display level (0 until the first purchase).
     #[inline]
-    pub fn can_afford_next(&self, ore: u32) -> bool {
-        self.cost_for_next_level().is_some_and(|cost| ore >= cost)
+    pub fn display_level(&self) -> u32 {
+        self.level
+    }
+
+    /// Shield capacity at the current level.
+    #[inline]
+    pub fn capacity(&self, config: &PhysicsConfig) -> f32 {
+        self.level as f32 * config.shield_hp_per_level
+    }
+
+    /// Whether the shield can be upgraded further.
+    #[inline]
+    pub fn is_maxed(&self) -> bool {
+        self.level >= Self::MAX
+    }
+
+    /// Ore cost to buy the next shield level.
+    /// Returns `None` when already at max level.
+    #[inline]
+    pub fn cost_for_next_level(&self) -> Option<u32> {
+        if self.is_maxed() {
+            None
+        } else {
+            Some(SHIELD_UPGRADE_BASE_COST * (self.level + 1))
+        }
     }
 
     /// Spend ore and increment the level. Returns the amount spent, or `None`
@@ -815,10 +1062,114 @@ impl IonCannonLevel {
     }
 }
 
+/// Number of auto-turrets mounted on the ship.
+///
+/// Level 0 means no turrets; each level mounts one more, up to
+/// [`TURRET_MAX_COUNT`].  See `turret::player_turret_system`.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
+pub struct TurretLevel {
+    /// Turrets bought (0 = none).
+    pub level: u32,
+}
+
+impl TurretLevel {
+    /// Maximum level value (inclusive).
+    pub const MAX: u32 = TURRET_MAX_COUNT;
+
+    /// Human-readable display level (0 until the first purchase).
+    #[inline]
+    pub fn display_level(&self) -> u32 {
+        self.level
+    }
+
+    /// Number of turrets mounted at the current level.
+    #[inline]
+    pub fn count(&self) -> u32 {
+        self.level.min(Self::MAX)
+    }
+
+    /// Whether every hardpoint is filled.
+    #[inline]
+    pub fn is_maxed(&self) -> bool {
+        self.level >= Self::MAX
+    }
+
+    /// Credit cost of the next turret.
+    /// Returns `None` when every hardpoint is filled.
+    #[inline]
+    pub fn cost_for_next_level(&self) -> Option<u32> {
+        if self.is_maxed() {
+            None
+        } else {
+            Some(TURRET_UPGRADE_BASE_COST * (self.level + 1))
+        }
+    }
+
+    /// Spend credits and mount one more turret. Returns the amount spent, or
+    /// `None` if maxed-out or the player cannot afford it.
+    pub fn try_upgrade(&mut self, credits: &mut u32) -> Option<u32> {
+        let cost = self.cost_for_next_level()?;
+        if *credits < cost {
+            return None;
+        }
+        *credits -= cost;
+        self.level += 1;
+        Some(cost)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn shield_absorbs_front_hits_fully_and_rear_hits_partially() {
+        let config = PhysicsConfig {
+            shield_rear_absorb_fraction: 0.5,
+            ..Default::default()
+        };
+
+        let mut shield = PlayerShield::full(50.0);
+        let through = shield.absorb(20.0, Vec2::Y, Vec2::Y, &config);
+        assert_eq!(through, 0.0);
+        assert_eq!(shield.hp, 30.0);
+        assert_eq!(shield.since_hit_secs, 0.0);
+
+        let through = shield.absorb(20.0, Vec2::NEG_Y, Vec2::Y, &config);
+        assert_eq!(through, 10.0, "rear hits bleed through");
+        assert_eq!(shield.hp, 20.0);
+        assert_eq!(shield.last_hit_dir, Vec2::NEG_Y);
+    }
+
+    #[test]
+    fn depleted_shield_passes_remaining_damage_to_hull() {
+        let config = PhysicsConfig::default();
+        let mut shield = PlayerShield::full(5.0);
+
+        let through = shield.absorb(12.0, Vec2::Y, Vec2::Y, &config);
+        assert_eq!(through, 7.0);
+        assert_eq!(shield.hp, 0.0);
+        assert_eq!(shield.absorb(4.0, Vec2::Y, Vec2::Y, &config), 4.0);
+    }
+
+    #[test]
+    fn shield_level_first_purchase_installs_shield() {
+        let config = PhysicsConfig::default();
+        let mut level = ShieldLevel::default();
+        assert_eq!(level.capacity(&config), 0.0);
+
+        let mut ore = SHIELD_UPGRADE_BASE_COST;
+        assert_eq!(level.try_upgrade(&mut ore), Some(SHIELD_UPGRADE_BASE_COST));
+        assert_eq!(ore, 0);
+        assert_eq!(level.display_level(), 1);
+        assert_eq!(level.capacity(&config), config.shield_hp_per_level);
+        assert_eq!(
+            level.cost_for_next_level(),
+            Some(SHIELD_UPGRADE_BASE_COST * 2)
+        );
+    }
+
     #[test]
     fn missile_split_piece_count_scales_with_level() {
         let config = PhysicsConfig::default();
@@ -833,8 +1184,10 @@ mod tests {
 
     #[test]
     fn missile_split_piece_count_respects_config_clamp() {
-        let mut config = PhysicsConfig::default();
-        config.missile_split_max_pieces = 4;
+        let config = PhysicsConfig {
+            missile_split_max_pieces: 4,
+            ..Default::default()
+        };
         let high_level = SecondaryWeaponLevel { level: 9 };
 
         assert_eq!(high_level.split_piece_count(&config), 4);
@@ -948,7 +1301,8 @@ impl PlayerHealth {
 /// is cleared.  [`super::control::apply_player_intent_system`] reads it and
 /// applies the corresponding physics forces.  Tests can populate this directly
 /// to drive ship behaviour without a real input device.
-#[derive(Resource, Default, Debug, Clone, Copy, PartialEq)]
+#[derive(Resource, Default, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
 pub struct PlayerIntent {
     /// Forward thrust multiplier.  `1.0` applies full `THRUST_FORCE`; `0.0` means no thrust.
     pub thrust_forward: f32,
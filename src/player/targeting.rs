@@ -0,0 +1,578 @@
+//! Target lock-on: acquisition, bracket rendering, missile bias, camera framing.
+//!
+//! ## Flow
+//!
+//! 1. [`lock_on_system`] toggles the lock with `T` / right-stick click.  On
+//!    engage it picks the nearest enemy, boss, or large asteroid inside the aim
+//!    cone; every frame it drops a lock whose target despawned or drifted past
+//!    `lock_on_range × lock_on_release_range_mult`.
+//! 2. [`missile_lock_on_steer_system`] turns in-flight missiles toward the
+//!    locked target at a capped rate, biasing rather than guaranteeing hits.
+//!    Without a lock, missiles at upgrade level `missile_homing_min_level` and
+//!    above pick their own targets in [`missile_homing_system`].
+//! 3. [`lock_on_camera_framing_system`] writes a smoothed camera offset and
+//!    zoom into `CameraState` so ship and target stay on screen together.
+//! 4. [`sync_lock_on_bracket_system`] keeps a retained square bracket mesh
+//!    around the target and despawns it when the lock ends.
+
+use super::state::{
+    AimDirection, LockOnState, Missile, Player, PreferredGamepad, SecondaryWeaponLevel,
+};
+use crate::asteroid::{Asteroid, AsteroidSize, Planet, Vertices};
+use crate::asteroid_rendering::polygon_outline_mesh;
+use crate::config::PhysicsConfig;
+use crate::enemy::{Boss, Enemy};
+use crate::simulation::CameraState;
+use crate::spatial_partition::SpatialGrid;
+use bevy::input::gamepad::GamepadButton;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+/// Rate (1/s) at which camera framing blends toward its target offset/zoom.
+const LOCK_ON_FRAME_BLEND_RATE: f32 = 4.0;
+
+/// Bracket radius used when the target has no polygon (enemy ships, bosses).
+const LOCK_ON_DEFAULT_TARGET_RADIUS: f32 = 20.0;
+
+/// Marker for the retained lock-on bracket mesh.
+#[derive(Component)]
+pub struct LockOnBracket;
+
+/// Pick the best lock candidate from `(entity, position)` pairs.
+///
+/// Candidates must lie within `range` and inside the aim cone
+/// (`aim_dir · to_target ≥ cone_dot`); the nearest one wins.
+pub fn select_lock_on_target(
+    player_pos: Vec2,
+    aim_dir: Vec2,
+    range: f32,
+    cone_dot: f32,
+    candidates: impl IntoIterator<Item = (Entity, Vec2)>,
+) -> Option<Entity> {
+    let aim_dir = aim_dir.normalize_or_zero();
+    if aim_dir == Vec2::ZERO {
+        return None;
+    }
+    let range_sq = range * range;
+
+    let mut best: Option<(Entity, f32)> = None;
+    for (entity, pos) in candidates {
+        let to_target = pos - player_pos;
+        let dist_sq = to_target.length_squared();
+        if dist_sq <= 1e-6 || dist_sq > range_sq {
+            continue;
+        }
+        if aim_dir.dot(to_target.normalize_or_zero()) < cone_dot {
+            continue;
+        }
+        if best.is_none_or(|(_, best_dist_sq)| dist_sq < best_dist_sq) {
+            best = Some((entity, dist_sq));
+        }
+    }
+    best.map(|(entity, _)| entity)
+}
+
+/// Toggle lock-on and drop stale locks.
+///
+/// - Keyboard: `T` toggles lock-on.
+/// - Gamepad: right-stick click (`RightThumb`) toggles lock-on.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn lock_on_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    preferred: Res<PreferredGamepad>,
+    gamepads: Query<&Gamepad>,
+    aim: Res<AimDirection>,
+    config: Res<PhysicsConfig>,
+    grid: Res<SpatialGrid>,
+    mut lock: ResMut<LockOnState>,
+    q_player: Query<&Transform, With<Player>>,
+    q_enemies: Query<(Entity, &Transform), Or<(With<Enemy>, With<Boss>)>>,
+    q_asteroids: Query<(Entity, &Transform, &AsteroidSize), (With<Asteroid>, Without<Planet>)>,
+    mut nearby: Local<Vec<Entity>>,
+) {
+    let Ok(player_transform) = q_player.single() else {
+        lock.target = None;
+        return;
+    };
+    let player_pos = player_transform.translation.truncate();
+
+    let target_pos = |entity: Entity| {
+        q_enemies
+            .get(entity)
+            .map(|(_, t)| t.translation.truncate())
+            .or_else(|_| {
+                q_asteroids
+                    .get(entity)
+                    .map(|(_, t, _)| t.translation.truncate())
+            })
+            .ok()
+    };
+
+    if let Some(target) = lock.target {
+        let release_range = config.lock_on_range * config.lock_on_release_range_mult;
+        let keep = target_pos(target)
+            .is_some_and(|pos| pos.distance_squared(player_pos) <= release_range * release_range);
+        if !keep {
+            lock.target = None;
+        }
+    }
+
+    let kb_toggle = keys.just_pressed(KeyCode::KeyT);
+    let gp_toggle = preferred
+        .0
+        .and_then(|entity| gamepads.get(entity).ok())
+        .is_some_and(|gp| gp.just_pressed(GamepadButton::RightThumb));
+    if !(kb_toggle || gp_toggle) {
+        return;
+    }
+
+    if lock.target.take().is_some() {
+        return;
+    }
+
+    let ship_forward = player_transform.rotation.mul_vec3(Vec3::Y).truncate();
+    let aim_dir = if aim.0.length_squared() > 1e-6 {
+        aim.0
+    } else {
+        ship_forward
+    };
+
+    let enemies = q_enemies
+        .iter()
+        .map(|(entity, t)| (entity, t.translation.truncate()));
+    grid.within_radius(player_pos, config.lock_on_range, &mut nearby);
+    let asteroids = nearby
+        .iter()
+        .filter_map(|&entity| q_asteroids.get(entity).ok())
+        .filter(|(_, _, size)| size.0 >= config.lock_on_min_asteroid_size)
+        .map(|(entity, t, _)| (entity, t.translation.truncate()));
+
+    lock.target = select_lock_on_target(
+        player_pos,
+        aim_dir,
+        config.lock_on_range,
+        config.lock_on_cone_dot,
+        enemies.chain(asteroids),
+    );
+}
+
+/// Steer in-flight missiles toward the locked target at a capped turn rate.
+///
+/// Speed is preserved; only heading changes, so `missile_acceleration_system`
+/// keeps full control of the speed ramp.
+pub fn missile_lock_on_steer_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    lock: Res<LockOnState>,
+    q_targets: Query<&Transform, Without<Missile>>,
+    mut q_missiles: Query<(&Transform, &mut Velocity), With<Missile>>,
+) {
+    let Some(target) = lock.target else {
+        return;
+    };
+    let Ok(target_transform) = q_targets.get(target) else {
+        return;
+    };
+    let target_pos = target_transform.translation.truncate();
+    let max_turn = config.missile_lock_on_turn_rate * time.delta_secs();
+    if max_turn <= 0.0 {
+        return;
+    }
+
+    for (transform, mut velocity) in q_missiles.iter_mut() {
+        let toward = target_pos - transform.translation.truncate();
+        velocity.linvel = turn_toward(velocity.linvel, toward, max_turn);
+    }
+}
+
+/// Rotate `velocity` toward `toward` by at most `max_turn` radians, keeping
+/// its speed.
+pub fn turn_toward(velocity: Vec2, toward: Vec2, max_turn: f32) -> Vec2 {
+    let speed = velocity.length();
+    let desired = toward.normalize_or_zero();
+    if speed <= 1e-4 || desired == Vec2::ZERO {
+        return velocity;
+    }
+
+    let heading = velocity / speed;
+    let mut angle_diff = desired.y.atan2(desired.x) - heading.y.atan2(heading.x);
+    while angle_diff > std::f32::consts::PI {
+        angle_diff -= std::f32::consts::TAU;
+    }
+    while angle_diff < -std::f32::consts::PI {
+        angle_diff += std::f32::consts::TAU;
+    }
+    let turn = angle_diff.clamp(-max_turn, max_turn);
+    Vec2::from_angle(turn).rotate(heading) * speed
+}
+
+/// Home missiles on the nearest asteroid or enemy inside their seek cone.
+///
+/// Active from missile level `missile_homing_min_level`.  Each missile keeps
+/// its [`Missile::homing_target`] until the target despawns or leaves
+/// `missile_homing_range`, then acquires a new one ahead of its heading.
+/// Asteroid candidates come from the `SpatialGrid`; enemies are few and are
+/// scanned directly.  While the player holds a lock,
+/// [`missile_lock_on_steer_system`] steers instead.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn missile_homing_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    level: Res<SecondaryWeaponLevel>,
+    lock: Res<LockOnState>,
+    grid: Res<SpatialGrid>,
+    q_asteroids: Query<(&Transform, &AsteroidSize), (With<Asteroid>, Without<Planet>)>,
+    q_enemies: Query<(Entity, &Transform), Or<(With<Enemy>, With<Boss>)>>,
+    mut q_missiles: Query<(&Transform, &mut Velocity, &mut Missile)>,
+    mut nearby: Local<Vec<Entity>>,
+) {
+    if !level.has_homing(&config) || lock.target.is_some() {
+        return;
+    }
+    let max_turn = config.missile_homing_turn_rate * time.delta_secs();
+    if max_turn <= 0.0 {
+        return;
+    }
+    let range = config.missile_homing_range;
+
+    let target_pos = |entity: Entity| {
+        q_enemies
+            .get(entity)
+            .map(|(_, t)| t.translation.truncate())
+            .or_else(|_| {
+                q_asteroids
+                    .get(entity)
+                    .map(|(t, _)| t.translation.truncate())
+            })
+            .ok()
+    };
+
+    for (transform, mut velocity, mut missile) in q_missiles.iter_mut() {
+        let pos = transform.translation.truncate();
+        let tracked = missile
+            .homing_target
+            .and_then(target_pos)
+            .filter(|target| target.distance_squared(pos) <= range * range);
+
+        let target = match tracked {
+            Some(target) => Some(target),
+            None => {
+                grid.within_radius(pos, range, &mut nearby);
+                let asteroids = nearby.iter().filter_map(|&entity| {
+                    let (t, size) = q_asteroids.get(entity).ok()?;
+                    (size.0 >= config.missile_homing_min_asteroid_size)
+                        .then(|| (entity, t.translation.truncate()))
+                });
+                let enemies = q_enemies
+                    .iter()
+                    .map(|(entity, t)| (entity, t.translation.truncate()));
+                missile.homing_target = select_lock_on_target(
+                    pos,
+                    velocity.linvel,
+                    range,
+                    config.missile_homing_cone_dot,
+                    asteroids.chain(enemies),
+                );
+                missile.homing_target.and_then(target_pos)
+            }
+        };
+
+        if let Some(target) = target {
+            velocity.linvel = turn_toward(velocity.linvel, target - pos, max_turn);
+        }
+    }
+}
+
+/// Blend `CameraState::frame_offset` / `frame_zoom` toward a framing that
+/// keeps both the ship and the locked target on screen.
+///
+/// Framing only ever zooms *out* from the player's wheel zoom and is clamped
+/// to `max_zoom`.  When the lock ends (or framing is disabled) the offset and
+/// zoom blend back to the plain player-follow camera before clearing.
+pub fn lock_on_camera_framing_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    lock: Res<LockOnState>,
+    windows: Query<&Window>,
+    q_player: Query<&Transform, With<Player>>,
+    q_targets: Query<&Transform, Without<Player>>,
+    mut camera_state: ResMut<CameraState>,
+) {
+    let framed = if config.lock_on_camera_framing {
+        q_player.single().ok().and_then(|player| {
+            let target = q_targets.get(lock.target?).ok()?;
+            Some((player.translation.truncate(), target.translation.truncate()))
+        })
+    } else {
+        None
+    };
+
+    let (target_offset, target_zoom) = match (framed, windows.single()) {
+        (Some((player_pos, target_pos)), Ok(window)) => {
+            let delta = target_pos - player_pos;
+            let half_w = (window.width() * 0.5).max(1.0);
+            let half_h = (window.height() * 0.5).max(1.0);
+            let needed_x = (delta.x.abs() * 0.5 + config.lock_on_frame_margin) / half_w;
+            let needed_y = (delta.y.abs() * 0.5 + config.lock_on_frame_margin) / half_h;
+            let zoom = needed_x
+                .max(needed_y)
+                .clamp(camera_state.zoom, config.max_zoom.max(camera_state.zoom));
+            (delta * 0.5, zoom)
+        }
+        _ => {
+            if camera_state.frame_zoom.is_none() && camera_state.frame_offset == Vec2::ZERO {
+                return;
+            }
+            (Vec2::ZERO, camera_state.zoom)
+        }
+    };
+
+    let alpha = (time.delta_secs() * LOCK_ON_FRAME_BLEND_RATE).min(1.0);
+    let current_zoom = camera_state.effective_zoom();
+    let next_zoom = current_zoom + (target_zoom - current_zoom) * alpha;
+    let next_offset = camera_state.frame_offset.lerp(target_offset, alpha);
+
+    let released = framed.is_none()
+        && next_offset.length_squared() < 0.25
+        && (next_zoom - camera_state.zoom).abs() < 1e-3;
+    if released {
+        camera_state.frame_offset = Vec2::ZERO;
+        camera_state.frame_zoom = None;
+    } else {
+        camera_state.frame_offset = next_offset;
+        camera_state.frame_zoom = Some(next_zoom);
+    }
+}
+
+/// Spawn, position, and despawn the retained lock-on bracket mesh.
+#[allow(clippy::type_complexity)]
+pub fn sync_lock_on_bracket_system(
+    mut commands: Commands,
+    mut lock: ResMut<LockOnState>,
+    q_targets: Query<(&Transform, Option<&Vertices>), Without<LockOnBracket>>,
+    mut q_bracket: Query<&mut Transform, With<LockOnBracket>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let target = lock.target.and_then(|entity| q_targets.get(entity).ok());
+
+    let Some((target_transform, vertices)) = target else {
+        if let Some(bracket) = lock.bracket.take() {
+            if let Ok(mut entity) = commands.get_entity(bracket) {
+                entity.despawn();
+            }
+        }
+        return;
+    };
+
+    let radius = vertices
+        .and_then(|v| v.0.iter().map(|p| p.length()).reduce(f32::max))
+        .unwrap_or(LOCK_ON_DEFAULT_TARGET_RADIUS)
+        .max(8.0);
+    let bracket_transform = Transform {
+        translation: target_transform.translation.truncate().extend(2.5),
+        rotation: Quat::IDENTITY,
+        scale: Vec3::new(radius * 1.35, radius * 1.35, 1.0),
+    };
+
+    if let Some(mut t) = lock.bracket.and_then(|e| q_bracket.get_mut(e).ok()) {
+        *t = bracket_transform;
+        return;
+    }
+
+    // Unit square outline; scaled per-target via Transform.
+    let square = [
+        Vec2::new(-1.0, -1.0),
+        Vec2::new(1.0, -1.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(-1.0, 1.0),
+    ];
+    let bracket = commands
+        .spawn((
+            Mesh2d(meshes.add(polygon_outline_mesh(&square, 0.04))),
+            MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgb(1.0, 0.3, 0.25)))),
+            bracket_transform,
+            Visibility::Visible,
+            LockOnBracket,
+        ))
+        .id();
+    lock.bracket = Some(bracket);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    fn entities(n: u32) -> Vec<Entity> {
+        let mut world = World::new();
+        (0..n).map(|_| world.spawn_empty().id()).collect()
+    }
+
+    #[test]
+    fn select_prefers_nearest_candidate_inside_cone() {
+        let e = entities(3);
+        let picked = select_lock_on_target(
+            Vec2::ZERO,
+            Vec2::Y,
+            500.0,
+            0.9,
+            [
+                (e[0], Vec2::new(0.0, 300.0)),
+                (e[1], Vec2::new(0.0, 120.0)),
+                (e[2], Vec2::new(0.0, 600.0)),
+            ],
+        );
+        assert_eq!(picked, Some(e[1]));
+    }
+
+    #[test]
+    fn select_ignores_candidates_outside_cone() {
+        let e = entities(2);
+        let picked = select_lock_on_target(
+            Vec2::ZERO,
+            Vec2::Y,
+            500.0,
+            0.9,
+            [
+                (e[0], Vec2::new(100.0, 0.0)),
+                (e[1], Vec2::new(0.0, -100.0)),
+            ],
+        );
+        assert_eq!(picked, None);
+    }
+
+    #[test]
+    fn missile_turns_toward_target_at_capped_rate() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+        app.insert_resource(PhysicsConfig {
+            missile_lock_on_turn_rate: 1_000.0,
+            ..Default::default()
+        });
+
+        let target = app
+            .world_mut()
+            .spawn(Transform::from_translation(Vec3::new(200.0, 0.0, 0.0)))
+            .id();
+        app.insert_resource(LockOnState {
+            target: Some(target),
+            bracket: None,
+        });
+        let missile = app
+            .world_mut()
+            .spawn((
+                Missile::default(),
+                Transform::default(),
+                Velocity {
+                    linvel: Vec2::new(0.0, 100.0),
+                    angvel: 0.0,
+                },
+            ))
+            .id();
+        app.add_systems(Update, missile_lock_on_steer_system);
+
+        app.update();
+        app.update();
+
+        let vel = app.world().get::<Velocity>(missile).unwrap().linvel;
+        assert!(
+            (vel.length() - 100.0).abs() < 1e-3,
+            "speed must be preserved"
+        );
+        assert!(
+            vel.x > 99.0,
+            "missile should now head toward +X, got {vel:?}"
+        );
+    }
+
+    #[test]
+    fn turn_toward_is_capped_and_keeps_speed() {
+        let turned = turn_toward(Vec2::new(50.0, 0.0), Vec2::new(0.0, 10.0), 0.5);
+        assert!((turned.length() - 50.0).abs() < 1e-3);
+        assert!((turned.y.atan2(turned.x) - 0.5).abs() < 1e-4);
+
+        let reached = turn_toward(Vec2::new(50.0, 0.0), Vec2::new(1.0, 1.0), 2.0);
+        assert!((reached.normalize() - Vec2::new(1.0, 1.0).normalize()).length() < 1e-4);
+    }
+
+    fn homing_app(level: u32, rock_at: Vec2) -> (App, Entity, Entity) {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_millis(16),
+            ))
+            .insert_resource(PhysicsConfig {
+                missile_homing_turn_rate: 1_000.0,
+                ..default()
+            })
+            .insert_resource(SecondaryWeaponLevel { level })
+            .init_resource::<LockOnState>()
+            .add_systems(Update, missile_homing_system);
+
+        let rock = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                AsteroidSize(4),
+                Transform::from_translation(rock_at.extend(0.0)),
+            ))
+            .id();
+        let mut grid = SpatialGrid::default();
+        grid.insert(rock, rock_at);
+        grid.build();
+        app.insert_resource(grid);
+
+        let missile = app
+            .world_mut()
+            .spawn((
+                Missile::default(),
+                Transform::default(),
+                Velocity::linear(Vec2::new(100.0, 0.0)),
+            ))
+            .id();
+        app.update();
+        app.update();
+        (app, missile, rock)
+    }
+
+    #[test]
+    fn upgraded_missiles_home_on_targets_ahead() {
+        let rock_at = Vec2::new(150.0, 60.0);
+        let (app, missile, rock) = homing_app(2, rock_at);
+
+        assert_eq!(
+            app.world().get::<Missile>(missile).unwrap().homing_target,
+            Some(rock)
+        );
+        let vel = app.world().get::<Velocity>(missile).unwrap().linvel;
+        assert!(
+            (vel.length() - 100.0).abs() < 1e-3,
+            "speed must be preserved"
+        );
+        assert!((vel.normalize() - rock_at.normalize()).length() < 1e-3);
+    }
+
+    #[test]
+    fn homing_needs_the_upgrade_and_a_target_in_the_cone() {
+        let (app, missile, _) = homing_app(1, Vec2::new(150.0, 60.0));
+        assert_eq!(
+            app.world().get::<Velocity>(missile).unwrap().linvel,
+            Vec2::new(100.0, 0.0),
+            "below missile_homing_min_level nothing steers"
+        );
+
+        let (app, missile, _) = homing_app(2, Vec2::new(-150.0, 20.0));
+        let world = app.world();
+        assert_eq!(world.get::<Missile>(missile).unwrap().homing_target, None);
+        assert_eq!(
+            world.get::<Velocity>(missile).unwrap().linvel,
+            Vec2::new(100.0, 0.0),
+            "targets behind the missile are ignored"
+        );
+    }
+}
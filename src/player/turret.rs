@@ -0,0 +1,495 @@
+//! Purchasable auto-turrets: hardpoint lifecycle, target acquisition and fire.
+//!
+//! [`TurretLevel`] (bought in the ore shop) sets how many of the ship's two
+//! hardpoints are filled.  [`player_turret_system`] keeps one [`Turret`] child
+//! entity per filled hardpoint, so turrets follow the ship's transform and are
+//! despawned with it; a respawned ship gets its turrets back.
+//!
+//! [`turret_aim_fire_system`] aims each turret on its own.  A turret prefers the
+//! nearest incoming [`EnemyProjectile`] within `turret_range`, then the nearest
+//! asteroid no bigger than `turret_target_max_size`; planets and larger bodies
+//! are left to the player.  The barrel swivels at most `turret_turn_rate` and
+//! never leaves the arc of `turret_arc_half_width` around its mount facing, so
+//! targets behind the hull are ignored.  Two turrets spread over two targets
+//! when there are two to choose from.
+//!
+//! Each shot draws `turret_energy_per_shot` from the shared [`PlayerEnergy`]
+//! pool; a turret holds fire while the pool cannot cover a whole shot.
+//!
+//! Turret rounds are ordinary [`Projectile`]s tagged [`TurretRound`], so the
+//! existing hit systems chip asteroids and intercept enemy fire.  A round that
+//! misses does not break the player's hit streak.
+
+use super::state::{Player, PlayerEnergy, Projectile, TurretLevel};
+use crate::asteroid::{Asteroid, AsteroidSize, Planet};
+use crate::audio::{play_sfx, Sfx};
+use crate::config::PhysicsConfig;
+use crate::constants::{
+    TURRET_FIRE_TOLERANCE, TURRET_MAX_COUNT, TURRET_MOUNT_ANGLE, TURRET_MOUNT_OFFSET_X,
+    TURRET_MOUNT_OFFSET_Y,
+};
+use crate::enemy::EnemyProjectile;
+use crate::spatial_partition::SpatialGrid;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use std::f32::consts::{PI, TAU};
+
+/// One mounted auto-turret, a child of the player ship.
+#[derive(Component, Debug, Clone, Copy, Reflect)]
+#[reflect(Component)]
+pub struct Turret {
+    /// Hardpoint index: 0 = left, 1 = right.
+    pub slot: u32,
+    /// Barrel angle (rad) relative to the ship's nose, counter-clockwise.
+    pub angle: f32,
+    /// Seconds until the turret may fire again.
+    pub cooldown_secs: f32,
+    /// Entity the turret is tracking, if any.
+    pub target: Option<Entity>,
+}
+
+impl Turret {
+    /// A turret at rest on hardpoint `slot`.
+    pub fn new(slot: u32) -> Self {
+        Self {
+            slot,
+            angle: Self::mount_angle(slot),
+            cooldown_secs: 0.0,
+            target: None,
+        }
+    }
+
+    /// Rest facing of hardpoint `slot` relative to the ship's nose.
+    pub fn mount_angle(slot: u32) -> f32 {
+        if slot == 0 {
+            TURRET_MOUNT_ANGLE
+        } else {
+            -TURRET_MOUNT_ANGLE
+        }
+    }
+
+    /// Position of hardpoint `slot` in ship-local space.
+    pub fn mount_offset(slot: u32) -> Vec2 {
+        let side = if slot == 0 { -1.0 } else { 1.0 };
+        Vec2::new(side * TURRET_MOUNT_OFFSET_X, TURRET_MOUNT_OFFSET_Y)
+    }
+}
+
+/// Tags a [`Projectile`] fired by a turret rather than the player.
+#[derive(Component, Debug, Clone, Copy, Default, Reflect)]
+#[reflect(Component)]
+pub struct TurretRound;
+
+/// Wrap an angle into `(-PI, PI]`.
+fn wrap_angle(angle: f32) -> f32 {
+    let wrapped = (angle + PI).rem_euclid(TAU) - PI;
+    if wrapped <= -PI {
+        wrapped + TAU
+    } else {
+        wrapped
+    }
+}
+
+/// Mount and unmount turret children to match [`TurretLevel`].
+pub fn player_turret_system(
+    mut commands: Commands,
+    level: Res<TurretLevel>,
+    q_player: Query<Entity, With<Player>>,
+    q_turrets: Query<(Entity, &Turret, &ChildOf)>,
+) {
+    let Ok(player) = q_player.single() else {
+        return;
+    };
+
+    let count = level.count();
+    let mut mounted = [false; TURRET_MAX_COUNT as usize];
+    for (entity, turret, child_of) in q_turrets.iter() {
+        if child_of.parent() != player {
+            continue;
+        }
+        if turret.slot >= count {
+            commands.entity(entity).despawn();
+        } else {
+            mounted[turret.slot as usize] = true;
+        }
+    }
+
+    for slot in 0..count {
+        if mounted[slot as usize] {
+            continue;
+        }
+        let turret = Turret::new(slot);
+        commands.entity(player).with_child((
+            Transform::from_translation(Turret::mount_offset(slot).extend(0.1))
+                .with_rotation(Quat::from_rotation_z(turret.angle)),
+            Visibility::default(),
+            turret,
+        ));
+    }
+}
+
+/// A body a turret could shoot at.
+struct Candidate {
+    entity: Entity,
+    pos: Vec2,
+    vel: Vec2,
+    /// Enemy fire is a threat; rocks are only a nuisance.
+    is_enemy_shot: bool,
+}
+
+/// Pick a target within range and inside the turret's arc.
+///
+/// Unclaimed targets beat ones the other turret already tracks, enemy fire
+/// beats asteroids, and nearer beats farther.  Returns the target and the
+/// point to lead it by.
+fn pick_target(
+    origin: Vec2,
+    arc_centre: f32,
+    config: &PhysicsConfig,
+    candidates: impl Iterator<Item = Candidate>,
+    claimed: &[Entity],
+) -> Option<(Entity, Vec2)> {
+    let range_sq = config.turret_range * config.turret_range;
+    let mut best: Option<((bool, bool, f32), Candidate)> = None;
+    for candidate in candidates {
+        let offset = candidate.pos - origin;
+        let dist_sq = offset.length_squared();
+        if dist_sq > range_sq || dist_sq < 1e-6 {
+            continue;
+        }
+        let bearing = Vec2::Y.angle_to(offset);
+        if wrap_angle(bearing - arc_centre).abs() > config.turret_arc_half_width {
+            continue;
+        }
+        let key = (
+            claimed.contains(&candidate.entity),
+            !candidate.is_enemy_shot,
+            dist_sq,
+        );
+        if best.as_ref().is_none_or(|(best_key, _)| key < *best_key) {
+            best = Some((key, candidate));
+        }
+    }
+    best.map(|((.., dist_sq), target)| {
+        let flight_secs = dist_sq.sqrt() / config.turret_projectile_speed.max(1.0);
+        (target.entity, target.pos + target.vel * flight_secs)
+    })
+}
+
+/// Acquire targets, swivel each turret toward its lead point, and fire.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn turret_aim_fire_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    grid: Res<SpatialGrid>,
+    mut energy: ResMut<PlayerEnergy>,
+    q_player: Query<&Transform, (With<Player>, Without<Turret>)>,
+    mut q_turrets: Query<(&mut Turret, &mut Transform), Without<Player>>,
+    q_enemy_shots: Query<
+        (Entity, &Transform, Option<&Velocity>),
+        (With<EnemyProjectile>, Without<Turret>, Without<Player>),
+    >,
+    q_asteroids: Query<
+        (Entity, &Transform, Option<&Velocity>, &AsteroidSize),
+        (
+            With<Asteroid>,
+            Without<Planet>,
+            Without<Turret>,
+            Without<Player>,
+        ),
+    >,
+    mut nearby: Local<Vec<Entity>>,
+) {
+    let Ok(ship) = q_player.single() else {
+        return;
+    };
+    let dt = time.delta_secs();
+    let ship_pos = ship.translation.truncate();
+    let ship_angle = ship.rotation.to_euler(EulerRot::ZYX).0;
+    let max_step = config.turret_turn_rate * dt;
+
+    let mut turrets: Vec<_> = q_turrets.iter_mut().collect();
+    turrets.sort_by_key(|(turret, _)| turret.slot);
+
+    let mut claimed: Vec<Entity> = Vec::with_capacity(turrets.len());
+    for (turret, transform) in turrets.iter_mut() {
+        turret.cooldown_secs = (turret.cooldown_secs - dt).max(0.0);
+
+        let mount = Turret::mount_angle(turret.slot);
+        let origin =
+            ship_pos + (ship.rotation * Turret::mount_offset(turret.slot).extend(0.0)).truncate();
+        let arc_centre = ship_angle + mount;
+
+        let shots = q_enemy_shots.iter().map(|(entity, tf, vel)| Candidate {
+            entity,
+            pos: tf.translation.truncate(),
+            vel: vel.map_or(Vec2::ZERO, |v| v.linvel),
+            is_enemy_shot: true,
+        });
+        grid.within_radius(origin, config.turret_range, &mut nearby);
+        let rocks = nearby
+            .iter()
+            .filter_map(|&entity| q_asteroids.get(entity).ok())
+            .filter(|(.., size)| size.0 <= config.turret_target_max_size)
+            .map(|(entity, tf, vel, _)| Candidate {
+                entity,
+                pos: tf.translation.truncate(),
+                vel: vel.map_or(Vec2::ZERO, |v| v.linvel),
+                is_enemy_shot: false,
+            });
+        let target = pick_target(origin, arc_centre, &config, shots.chain(rocks), &claimed);
+
+        // Work in offsets from the mount facing so the barrel never swings
+        // through the blocked side of the arc.
+        let wanted = match target {
+            Some((_, lead)) => {
+                let bearing = Vec2::Y.angle_to(lead - origin);
+                wrap_angle(bearing - arc_centre)
+                    .clamp(-config.turret_arc_half_width, config.turret_arc_half_width)
+            }
+            None => 0.0,
+        };
+        let current = wrap_angle(turret.angle - mount);
+        let next = current + (wanted - current).clamp(-max_step, max_step);
+        turret.angle = mount + next;
+        transform.rotation = Quat::from_rotation_z(turret.angle);
+        turret.target = target.map(|(entity, _)| entity);
+
+        let Some((entity, _)) = target else {
+            continue;
+        };
+        claimed.push(entity);
+        if turret.cooldown_secs > 0.0
+            || (wanted - next).abs() > TURRET_FIRE_TOLERANCE
+            || !energy.try_spend(config.turret_energy_per_shot)
+        {
+            continue;
+        }
+        turret.cooldown_secs = config.turret_fire_interval_secs;
+
+        let fire_dir = Vec2::from_angle(ship_angle + turret.angle).rotate(Vec2::Y);
+        commands.spawn((
+            Projectile::default(),
+            TurretRound,
+            Transform::from_translation((origin + fire_dir * 6.0).extend(0.0)),
+            Visibility::default(),
+            RigidBody::KinematicVelocityBased,
+            Velocity {
+                linvel: fire_dir * config.turret_projectile_speed,
+                angvel: 0.0,
+            },
+            Collider::ball(config.projectile_collider_radius),
+            Sensor,
+            Ccd { enabled: true },
+            // Same groups as blaster shots: asteroids, enemies, enemy fire.
+            CollisionGroups::new(
+                bevy_rapier2d::geometry::Group::GROUP_3,
+                bevy_rapier2d::geometry::Group::GROUP_1
+                    | bevy_rapier2d::geometry::Group::GROUP_5
+                    | bevy_rapier2d::geometry::Group::GROUP_6,
+            ),
+            ActiveCollisionTypes::DYNAMIC_KINEMATIC | ActiveCollisionTypes::KINEMATIC_KINEMATIC,
+            ActiveEvents::COLLISION_EVENTS,
+        ));
+        play_sfx(&mut commands, Sfx::Blaster, 0.2);
+    }
+}
+
+/// Give newly mounted turrets a base disc and a barrel.
+pub fn attach_turret_mesh_system(
+    mut commands: Commands,
+    query: Query<Entity, Added<Turret>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    if query.is_empty() {
+        return;
+    }
+    let base = meshes.add(Circle::new(3.0));
+    let barrel = meshes.add(Rectangle::new(1.6, 7.0));
+    let mat = materials.add(ColorMaterial::from_color(Color::srgb(0.75, 0.8, 0.9)));
+
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .insert((Mesh2d(base.clone()), MeshMaterial2d(mat.clone())))
+            .with_child((
+                Mesh2d(barrel.clone()),
+                MeshMaterial2d(mat.clone()),
+                Transform::from_xyz(0.0, 3.5, 0.0),
+            ));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spatial_partition::rebuild_spatial_grid_system;
+    use bevy::time::TimeUpdateStrategy;
+    use std::time::Duration;
+
+    fn build_turret_test_app(level: u32) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(
+            50,
+        )));
+        app.insert_resource(PhysicsConfig::default());
+        app.insert_resource(TurretLevel { level });
+        app.insert_resource(PlayerEnergy::default());
+        app.insert_resource(SpatialGrid::default());
+        app.add_systems(
+            Update,
+            (
+                player_turret_system,
+                rebuild_spatial_grid_system,
+                turret_aim_fire_system,
+            )
+                .chain(),
+        );
+        app
+    }
+
+    fn turrets(app: &mut App) -> Vec<Turret> {
+        let world = app.world_mut();
+        let mut turrets: Vec<Turret> = world.query::<&Turret>().iter(world).copied().collect();
+        turrets.sort_by_key(|turret| turret.slot);
+        turrets
+    }
+
+    fn turret_rounds(app: &mut App) -> usize {
+        let world = app.world_mut();
+        world
+            .query_filtered::<(), (With<Projectile>, With<TurretRound>)>()
+            .iter(world)
+            .count()
+    }
+
+    #[test]
+    fn level_mounts_and_unmounts_turret_children() {
+        let mut app = build_turret_test_app(2);
+        let player = app.world_mut().spawn((Player, Transform::default())).id();
+
+        app.update();
+        let mounted = turrets(&mut app);
+        assert_eq!(
+            mounted.iter().map(|t| t.slot).collect::<Vec<_>>(),
+            vec![0, 1]
+        );
+        let world = app.world_mut();
+        for child_of in world.query::<&ChildOf>().iter(world) {
+            assert_eq!(child_of.parent(), player);
+        }
+
+        app.world_mut().resource_mut::<TurretLevel>().level = 1;
+        app.update();
+        assert_eq!(turrets(&mut app).len(), 1);
+    }
+
+    #[test]
+    fn turret_fires_at_small_asteroid_and_spends_energy() {
+        let mut app = build_turret_test_app(1);
+        app.world_mut().spawn((Player, Transform::default()));
+        // Dead ahead of the left mount's arc.
+        let rock = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                AsteroidSize(1),
+                Transform::from_xyz(-60.0, 80.0, 0.0),
+            ))
+            .id();
+
+        let before = app.world().resource::<PlayerEnergy>().current;
+        for _ in 0..30 {
+            app.update();
+        }
+
+        assert_eq!(turrets(&mut app)[0].target, Some(rock));
+        assert!(turret_rounds(&mut app) >= 1);
+        let spent = before - app.world().resource::<PlayerEnergy>().current;
+        assert!(spent >= PhysicsConfig::default().turret_energy_per_shot * 0.99);
+    }
+
+    #[test]
+    fn turrets_ignore_large_bodies_and_targets_behind_the_arc() {
+        let mut app = build_turret_test_app(2);
+        app.world_mut().spawn((Player, Transform::default()));
+        let config = PhysicsConfig::default();
+        app.world_mut().spawn((
+            Asteroid,
+            AsteroidSize(config.turret_target_max_size + 1),
+            Transform::from_xyz(0.0, 80.0, 0.0),
+        ));
+
+        for _ in 0..10 {
+            app.update();
+        }
+        assert!(turrets(&mut app).iter().all(|t| t.target.is_none()));
+        assert_eq!(turret_rounds(&mut app), 0);
+
+        // Directly astern is outside both mounts' arcs.
+        app.world_mut().spawn((
+            EnemyProjectile {
+                age: 0.0,
+                distance_traveled: 0.0,
+            },
+            Transform::from_xyz(0.0, -80.0, 0.0),
+        ));
+        app.update();
+        assert!(turrets(&mut app).iter().all(|t| t.target.is_none()));
+    }
+
+    #[test]
+    fn two_turrets_split_two_targets_and_prefer_enemy_fire() {
+        let mut app = build_turret_test_app(2);
+        app.world_mut().spawn((Player, Transform::default()));
+        let shot = app
+            .world_mut()
+            .spawn((
+                EnemyProjectile {
+                    age: 0.0,
+                    distance_traveled: 0.0,
+                },
+                Transform::from_xyz(0.0, 150.0, 0.0),
+            ))
+            .id();
+        let rock = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                AsteroidSize(1),
+                Transform::from_xyz(0.0, 60.0, 0.0),
+            ))
+            .id();
+
+        app.update();
+        app.update();
+
+        let targets: Vec<_> = turrets(&mut app).iter().map(|t| t.target).collect();
+        assert_eq!(targets[0], Some(shot), "enemy fire outranks a closer rock");
+        assert_eq!(
+            targets[1],
+            Some(rock),
+            "second turret takes the other target"
+        );
+    }
+
+    #[test]
+    fn empty_energy_pool_holds_fire() {
+        let mut app = build_turret_test_app(1);
+        app.world_mut().resource_mut::<PlayerEnergy>().current = 0.0;
+        app.world_mut().spawn((Player, Transform::default()));
+        app.world_mut().spawn((
+            Asteroid,
+            AsteroidSize(1),
+            Transform::from_xyz(-60.0, 80.0, 0.0),
+        ));
+
+        for _ in 0..30 {
+            app.update();
+        }
+        assert_eq!(turret_rounds(&mut app), 0);
+    }
+}
@@ -0,0 +1,474 @@
+//! Per-profile preferences that survive restarts.
+//!
+//! A profile stores the diagnostic view and settings the player left
+//! enabled: every debug overlay and HUD panel toggle in [`OverlayState`]
+//! (except whether the debug panel itself is open), the [`AudioSettings`]
+//! volumes and the [`AccessibilitySettings`] palette and UI scale.  It is
+//! loaded at startup, after the config defaults, and written back whenever
+//! one of those resources changes to something not yet on disk.
+//!
+//! Profiles live in `saves/profiles/<name>.toml`.  The name comes from
+//! `ACCRETION_PROFILE` and defaults to `default`, so separate tuning setups
+//! can keep separate views.  Test runs (`ACCRETION_TEST`) neither read nor
+//! write a profile.
+
+use crate::audio::AudioSettings;
+use crate::graphics::palette::{AccessibilitySettings, PaletteKind, UI_SCALE_MAX, UI_SCALE_MIN};
+use crate::rendering::OverlayState;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+/// Environment variable that selects the profile name.
+pub const PROFILE_ENV: &str = "ACCRETION_PROFILE";
+
+/// Profile used when `ACCRETION_PROFILE` is unset or empty.
+pub const DEFAULT_PROFILE: &str = "default";
+
+const PROFILE_VERSION: u32 = 1;
+
+/// Overlay toggles as stored in a profile.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverlaySnapshot {
+    pub show_wireframes: bool,
+    pub show_force_vectors: bool,
+    pub show_boundary: bool,
+    pub show_velocity_arrows: bool,
+    pub wireframe_only: bool,
+    pub show_ship_outline: bool,
+    pub show_aim_indicator: bool,
+    pub show_projectile_outline: bool,
+    pub show_debug_grid: bool,
+    pub show_profiler: bool,
+    pub show_stats: bool,
+    pub show_physics_inspector: bool,
+    pub show_trajectories: bool,
+    pub show_cluster_outlines: bool,
+    /// Added after version 1 shipped; missing in older profiles.
+    #[serde(default)]
+    pub show_hover_tooltip: bool,
+    #[serde(default)]
+    pub show_session_clock: bool,
+    #[serde(default)]
+    pub speedrun_mode: bool,
+    #[serde(default)]
+    pub streamer_mode: bool,
+    #[serde(default)]
+    pub webcam_corner: bool,
+    #[serde(default)]
+    pub show_gravity_field: bool,
+    #[serde(default)]
+    pub show_gravity_heatmap: bool,
+    #[serde(default)]
+    pub show_collider_outlines: bool,
+    #[serde(default)]
+    pub show_neighbor_counts: bool,
+    #[serde(default)]
+    pub show_motion_trails: bool,
+    #[serde(default)]
+    pub reduce_motion: bool,
+    #[serde(default)]
+    pub hud_edges: bool,
+}
+
+impl OverlaySnapshot {
+    pub fn capture(overlay: &OverlayState) -> Self {
+        Self {
+            show_wireframes: overlay.show_wireframes,
+            show_force_vectors: overlay.show_force_vectors,
+            show_boundary: overlay.show_boundary,
+            show_velocity_arrows: overlay.show_velocity_arrows,
+            wireframe_only: overlay.wireframe_only,
+            show_ship_outline: overlay.show_ship_outline,
+            show_aim_indicator: overlay.show_aim_indicator,
+            show_projectile_outline: overlay.show_projectile_outline,
+            show_debug_grid: overlay.show_debug_grid,
+            show_profiler: overlay.show_profiler,
+            show_stats: overlay.show_stats,
+            show_physics_inspector: overlay.show_physics_inspector,
+            show_trajectories: overlay.show_trajectories,
+            show_cluster_outlines: overlay.show_cluster_outlines,
+            show_hover_tooltip: overlay.show_hover_tooltip,
+            show_session_clock: overlay.show_session_clock,
+            speedrun_mode: overlay.speedrun_mode,
+            streamer_mode: overlay.streamer_mode,
+            webcam_corner: overlay.webcam_corner,
+            show_gravity_field: overlay.show_gravity_field,
+            show_gravity_heatmap: overlay.show_gravity_heatmap,
+            show_collider_outlines: overlay.show_collider_outlines,
+            show_neighbor_counts: overlay.show_neighbor_counts,
+            show_motion_trails: overlay.show_motion_trails,
+            reduce_motion: overlay.reduce_motion,
+            hud_edges: overlay.hud_edges,
+        }
+    }
+
+    /// Copy the stored toggles onto `overlay`, leaving `menu_open` alone.
+    pub fn apply(&self, overlay: &mut OverlayState) {
+        overlay.show_wireframes = self.show_wireframes;
+        overlay.show_force_vectors = self.show_force_vectors;
+        overlay.show_boundary = self.show_boundary;
+        overlay.show_velocity_arrows = self.show_velocity_arrows;
+        overlay.wireframe_only = self.wireframe_only;
+        overlay.show_ship_outline = self.show_ship_outline;
+        overlay.show_aim_indicator = self.show_aim_indicator;
+        overlay.show_projectile_outline = self.show_projectile_outline;
+        overlay.show_debug_grid = self.show_debug_grid;
+        overlay.show_profiler = self.show_profiler;
+        overlay.show_stats = self.show_stats;
+        overlay.show_physics_inspector = self.show_physics_inspector;
+        overlay.show_trajectories = self.show_trajectories;
+        overlay.show_cluster_outlines = self.show_cluster_outlines;
+        overlay.show_hover_tooltip = self.show_hover_tooltip;
+        overlay.show_session_clock = self.show_session_clock;
+        overlay.speedrun_mode = self.speedrun_mode;
+        overlay.streamer_mode = self.streamer_mode;
+        overlay.webcam_corner = self.webcam_corner;
+        overlay.show_gravity_field = self.show_gravity_field;
+        overlay.show_gravity_heatmap = self.show_gravity_heatmap;
+        overlay.show_collider_outlines = self.show_collider_outlines;
+        overlay.show_neighbor_counts = self.show_neighbor_counts;
+        overlay.show_motion_trails = self.show_motion_trails;
+        overlay.reduce_motion = self.reduce_motion;
+        overlay.hud_edges = self.hud_edges;
+    }
+}
+
+/// Volumes as stored in a profile, each in `[0, 1]`.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct AudioSnapshot {
+    pub master: f32,
+    pub effects: f32,
+    pub music: f32,
+}
+
+impl AudioSnapshot {
+    pub fn capture(settings: &AudioSettings) -> Self {
+        Self {
+            master: settings.master,
+            effects: settings.effects,
+            music: settings.music,
+        }
+    }
+
+    pub fn apply(&self, settings: &mut AudioSettings) {
+        settings.master = self.master.clamp(0.0, 1.0);
+        settings.effects = self.effects.clamp(0.0, 1.0);
+        settings.music = self.music.clamp(0.0, 1.0);
+    }
+}
+
+/// Palette and UI scale as stored in a profile.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+pub struct AccessibilitySnapshot {
+    pub palette: PaletteKind,
+    pub ui_scale: f32,
+}
+
+impl Default for AccessibilitySnapshot {
+    fn default() -> Self {
+        Self::capture(&AccessibilitySettings::default())
+    }
+}
+
+impl AccessibilitySnapshot {
+    pub fn capture(settings: &AccessibilitySettings) -> Self {
+        Self {
+            palette: settings.palette,
+            ui_scale: settings.ui_scale,
+        }
+    }
+
+    pub fn apply(&self, settings: &mut AccessibilitySettings) {
+        settings.palette = self.palette;
+        settings.ui_scale = self.ui_scale.clamp(UI_SCALE_MIN, UI_SCALE_MAX);
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ProfileSnapshot {
+    pub version: u32,
+    pub overlay: OverlaySnapshot,
+    pub audio: AudioSnapshot,
+    /// Added after version 1 shipped; missing in older profiles.
+    #[serde(default)]
+    pub accessibility: AccessibilitySnapshot,
+}
+
+impl ProfileSnapshot {
+    pub fn capture(
+        overlay: &OverlayState,
+        audio: &AudioSettings,
+        accessibility: &AccessibilitySettings,
+    ) -> Self {
+        Self {
+            version: PROFILE_VERSION,
+            overlay: OverlaySnapshot::capture(overlay),
+            audio: AudioSnapshot::capture(audio),
+            accessibility: AccessibilitySnapshot::capture(accessibility),
+        }
+    }
+}
+
+/// Parse a profile file.
+pub fn parse_profile(contents: &str) -> Result<ProfileSnapshot, String> {
+    let profile: ProfileSnapshot =
+        toml::from_str(contents).map_err(|err| format!("failed to parse profile TOML: {err}"))?;
+    if profile.version != PROFILE_VERSION {
+        return Err(format!(
+            "unsupported profile version {} (expected {})",
+            profile.version, PROFILE_VERSION
+        ));
+    }
+    Ok(profile)
+}
+
+/// The profile this session reads and writes.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ActiveProfile {
+    /// File backing the profile; `None` disables persistence.
+    pub path: Option<PathBuf>,
+    /// What is on disk, so unchanged state is not rewritten.
+    written: Option<ProfileSnapshot>,
+}
+
+impl ActiveProfile {
+    /// The profile named by `ACCRETION_PROFILE`.
+    pub fn from_env() -> Self {
+        let name = std::env::var(PROFILE_ENV)
+            .ok()
+            .map(|name| name.trim().to_string())
+            .filter(|name| !name.is_empty())
+            .unwrap_or_else(|| DEFAULT_PROFILE.to_string());
+        Self {
+            path: Some(profile_path(&name)),
+            written: None,
+        }
+    }
+}
+
+fn profile_path(name: &str) -> PathBuf {
+    let file: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    PathBuf::from("saves")
+        .join("profiles")
+        .join(format!("{file}.toml"))
+}
+
+/// Registers profile loading and saving.
+pub struct ProfilePlugin {
+    /// Read and write the profile file; off in test runs.
+    pub persist: bool,
+}
+
+impl Plugin for ProfilePlugin {
+    fn build(&self, app: &mut App) {
+        let profile = if self.persist {
+            ActiveProfile::from_env()
+        } else {
+            ActiveProfile::default()
+        };
+        app.insert_resource(profile)
+            .add_systems(
+                Startup,
+                load_profile_system.after(crate::audio::init_audio_settings_system),
+            )
+            .add_systems(Last, save_profile_system);
+    }
+}
+
+/// Apply the stored profile over the config defaults.
+pub fn load_profile_system(
+    mut profile: ResMut<ActiveProfile>,
+    mut overlay: ResMut<OverlayState>,
+    mut audio: ResMut<AudioSettings>,
+    mut accessibility: ResMut<AccessibilitySettings>,
+) {
+    let Some(path) = profile.path.clone() else {
+        return;
+    };
+    let Ok(contents) = fs::read_to_string(&path) else {
+        // No profile yet: nothing is written until a setting changes.
+        profile.written = Some(ProfileSnapshot::capture(&overlay, &audio, &accessibility));
+        return;
+    };
+    match parse_profile(&contents) {
+        Ok(stored) => {
+            stored.overlay.apply(&mut overlay);
+            stored.audio.apply(&mut audio);
+            stored.accessibility.apply(&mut accessibility);
+            info!("Loaded profile {}", path.display());
+            profile.written = Some(ProfileSnapshot::capture(&overlay, &audio, &accessibility));
+        }
+        Err(err) => warn!("Ignoring profile {}: {err}", path.display()),
+    }
+}
+
+/// Write the profile when the overlay, audio or accessibility settings change.
+pub fn save_profile_system(
+    mut profile: ResMut<ActiveProfile>,
+    overlay: Res<OverlayState>,
+    audio: Res<AudioSettings>,
+    accessibility: Res<AccessibilitySettings>,
+) {
+    if !overlay.is_changed() && !audio.is_changed() && !accessibility.is_changed() {
+        return;
+    }
+    let Some(path) = profile.path.clone() else {
+        return;
+    };
+    let snapshot = ProfileSnapshot::capture(&overlay, &audio, &accessibility);
+    if profile.written.as_ref() == Some(&snapshot) {
+        return;
+    }
+
+    let result = path
+        .parent()
+        .map_or(Ok(()), fs::create_dir_all)
+        .map_err(|err| format!("failed to create profile dir: {err}"))
+        .and_then(|()| {
+            toml::to_string_pretty(&snapshot)
+                .map_err(|err| format!("failed to serialize profile TOML: {err}"))
+        })
+        .and_then(|serialized| {
+            fs::write(&path, serialized)
+                .map_err(|err| format!("failed to write {}: {err}", path.display()))
+        });
+    match result {
+        Ok(()) => profile.written = Some(snapshot),
+        Err(err) => {
+            warn!("{err}");
+            // Do not retry every frame until something changes again.
+            profile.written = Some(snapshot);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_round_trips_everything_but_the_open_panel() {
+        let mut overlay = OverlayState {
+            show_stats: true,
+            show_profiler: true,
+            show_trajectories: true,
+            show_hover_tooltip: true,
+            speedrun_mode: true,
+            webcam_corner: true,
+            show_collider_outlines: true,
+            show_gravity_heatmap: true,
+            show_motion_trails: true,
+            reduce_motion: true,
+            hud_edges: true,
+            menu_open: true,
+            ..Default::default()
+        };
+        let audio = AudioSettings {
+            master: 0.3,
+            effects: 0.6,
+            music: 0.0,
+        };
+        let accessibility = AccessibilitySettings {
+            palette: PaletteKind::Tritanopia,
+            ui_scale: 1.25,
+        };
+        let encoded =
+            toml::to_string_pretty(&ProfileSnapshot::capture(&overlay, &audio, &accessibility))
+                .unwrap();
+        let stored = parse_profile(&encoded).unwrap();
+
+        overlay = OverlayState::default();
+        let mut restored_audio = AudioSettings::default();
+        let mut restored_accessibility = AccessibilitySettings::default();
+        stored.overlay.apply(&mut overlay);
+        stored.audio.apply(&mut restored_audio);
+        stored.accessibility.apply(&mut restored_accessibility);
+        assert!(overlay.show_stats && overlay.show_profiler && overlay.show_trajectories);
+        assert!(overlay.show_hover_tooltip && overlay.speedrun_mode && overlay.webcam_corner);
+        assert!(!overlay.show_debug_grid && !overlay.show_session_clock);
+        assert!(!overlay.streamer_mode);
+        assert!(overlay.show_collider_outlines && overlay.show_gravity_heatmap);
+        assert!(!overlay.show_gravity_field && !overlay.show_neighbor_counts);
+        assert!(overlay.show_motion_trails && overlay.reduce_motion);
+        assert!(overlay.hud_edges);
+        assert!(!overlay.menu_open);
+        assert_eq!(restored_audio, audio);
+        assert_eq!(restored_accessibility, accessibility);
+    }
+
+    #[test]
+    fn profiles_without_the_newer_display_keys_still_load() {
+        let overlay = OverlayState {
+            show_hover_tooltip: true,
+            show_session_clock: true,
+            speedrun_mode: true,
+            streamer_mode: true,
+            webcam_corner: true,
+            ..Default::default()
+        };
+        let encoded = toml::to_string_pretty(&ProfileSnapshot::capture(
+            &overlay,
+            &AudioSettings::default(),
+            &AccessibilitySettings {
+                palette: PaletteKind::Deuteranopia,
+                ui_scale: 1.5,
+            },
+        ))
+        .unwrap();
+        let legacy: String = encoded
+            .split("[accessibility]")
+            .next()
+            .unwrap()
+            .lines()
+            .filter(|line| {
+                ![
+                    "show_hover_tooltip",
+                    "show_session_clock",
+                    "speedrun_mode",
+                    "streamer_mode",
+                    "webcam_corner",
+                ]
+                .iter()
+                .any(|key| line.starts_with(key))
+            })
+            .map(|line| format!("{line}\n"))
+            .collect();
+
+        let stored = parse_profile(&legacy).unwrap();
+        assert!(!stored.overlay.show_hover_tooltip);
+        assert!(!stored.overlay.show_session_clock && !stored.overlay.speedrun_mode);
+        assert!(!stored.overlay.streamer_mode && !stored.overlay.webcam_corner);
+        assert_eq!(stored.accessibility, AccessibilitySnapshot::default());
+    }
+
+    #[test]
+    fn unknown_profile_versions_are_rejected() {
+        let encoded = toml::to_string_pretty(&ProfileSnapshot::capture(
+            &OverlayState::default(),
+            &AudioSettings::default(),
+            &AccessibilitySettings::default(),
+        ))
+        .unwrap()
+        .replace("version = 1", "version = 99");
+        assert!(parse_profile(&encoded).is_err());
+    }
+
+    #[test]
+    fn profile_names_map_to_safe_file_names() {
+        assert_eq!(
+            profile_path("tuning/../x"),
+            PathBuf::from("saves/profiles/tuning____x.toml")
+        );
+    }
+}
@@ -0,0 +1,314 @@
+//! Reflection registration for gameplay types, and the optional world inspector.
+//!
+//! Every gameplay component and resource derives `Reflect` and carries
+//! `#[reflect(Component)]` or `#[reflect(Resource)]`.  [`ReflectionPlugin`]
+//! registers them all in the `AppTypeRegistry`, so tooling can enumerate,
+//! read and write them without per-type code: the inspector below, scene
+//! export, and [`dump_entities`], which issue reports use to capture the
+//! world ([`crate::report`]).
+//!
+//! UI markers, render handles and internal scratch buffers are not
+//! registered.
+//!
+//! Building with `--features inspector` adds `bevy-inspector-egui`'s world
+//! inspector, toggled with **F10**.  It lists every entity and resource and
+//! edits any registered type live, including `PhysicsConfig`.
+
+use crate::asteroid::{
+    Asteroid, AsteroidSize, BaseVertices, CraterData, GravityForce, MergeCooldown, NeighborCount,
+    Planet, Vertices,
+};
+use crate::attribution::LastDamagedBy;
+use crate::config::PhysicsConfig;
+use crate::difficulty::{AssistOptions, Difficulty};
+use crate::enemy::{
+    Boss, BossAttackPhase, BossAttackState, BossHealth, BossWeakpoint, DeflectedShot, Enemy,
+    EnemyArchetype, EnemyFireCooldown, EnemyFormationLeader, EnemyFormationMember,
+    EnemyFormationTarget, EnemyHealth, EnemyMinerHold, EnemyProgressionStage, EnemyProjectile,
+    EnemyRetreating, EnemyShotSource, EnemySpawnState, EnemyStun, EnemyTier,
+};
+use crate::feedback::{FeedbackKind, HitStop, ScreenShake};
+use crate::lineage::{BiggestAccretion, Lineage, LineageNode};
+use crate::mining::drone::DroneTask;
+use crate::mining::{
+    AsteroidMaterial, MiningDrone, MiningDroneLevel, MiningLaserState, MiningOutpost,
+    OreAffinityLevel, OreKind, OrePickup, PlayerOre,
+};
+use crate::player::state::{Missile, PrimaryWeaponLevel, Projectile};
+use crate::player::{
+    AimDirection, AimIdleTimer, AimTargetMemory, CampaignLoadout, CampaignPrimaryWeapon,
+    CampaignSecondaryWeapon, IonCannonLevel, KillCamState, LockOnState, MissileAmmo,
+    MissileCooldown, Player, PlayerEnergy, PlayerFireCooldown, PlayerHealth, PlayerIntent,
+    PlayerLives, PlayerScore, PlayerShield, PreferredGamepad, PrimaryWeaponFireRateLevel,
+    PrimaryWeaponUpgradeTracks, SecondaryWeaponLevel, ShieldLevel, TractorBeamLevel,
+    TractorCaptureState, TractorDeflectWindow, TractorHoldState, TractorThrowCooldown, Turret,
+    TurretLevel, TurretRound,
+};
+use crate::run_info::{RunMetadata, RunPlaytime};
+use crate::session_stats::{SessionStats, StatWeapon, WeaponStats};
+use crate::simulation::procgen::ProceduralField;
+use crate::simulation::rings::RingParticle;
+use crate::simulation::time_control::TimeControl;
+use crate::simulation::volatile::{Volatile, VolatileFuse};
+use crate::simulation::{MissileTelemetry, SimulationStats};
+use crate::survival::{SurvivalPhase, SurvivalState};
+use bevy::ecs::reflect::ReflectComponent;
+use bevy::prelude::*;
+use bevy::scene::SceneFilter;
+use std::any::TypeId;
+
+/// Key that shows and hides the world inspector (`inspector` feature).
+#[cfg(feature = "inspector")]
+const INSPECTOR_TOGGLE_KEY: KeyCode = KeyCode::F10;
+
+pub struct ReflectionPlugin;
+
+impl Plugin for ReflectionPlugin {
+    fn build(&self, app: &mut App) {
+        // Asteroids
+        app.register_type::<Asteroid>()
+            .register_type::<Planet>()
+            .register_type::<AsteroidSize>()
+            .register_type::<NeighborCount>()
+            .register_type::<Vertices>()
+            .register_type::<BaseVertices>()
+            .register_type::<CraterData>()
+            .register_type::<GravityForce>()
+            .register_type::<MergeCooldown>()
+            .register_type::<RingParticle>()
+            .register_type::<AsteroidMaterial>()
+            .register_type::<Volatile>()
+            .register_type::<VolatileFuse>()
+            .register_type::<Lineage>()
+            .register_type::<LineageNode>();
+
+        // Player ship and weapons
+        app.register_type::<Player>()
+            .register_type::<PlayerHealth>()
+            .register_type::<PlayerShield>()
+            .register_type::<Turret>()
+            .register_type::<TurretRound>()
+            .register_type::<Projectile>()
+            .register_type::<Missile>()
+            .register_type::<MissileAmmo>()
+            .register_type::<MissileCooldown>()
+            .register_type::<PlayerFireCooldown>()
+            .register_type::<CampaignPrimaryWeapon>()
+            .register_type::<CampaignSecondaryWeapon>()
+            .register_type::<CampaignLoadout>()
+            .register_type::<AimDirection>()
+            .register_type::<AimIdleTimer>()
+            .register_type::<AimTargetMemory>()
+            .register_type::<PreferredGamepad>()
+            .register_type::<PlayerIntent>()
+            .register_type::<TractorHoldState>()
+            .register_type::<TractorCaptureState>()
+            .register_type::<TractorThrowCooldown>()
+            .register_type::<TractorDeflectWindow>()
+            .register_type::<LockOnState>()
+            .register_type::<KillCamState>()
+            .register_type::<PlayerEnergy>()
+            .register_type::<PlayerScore>()
+            .register_type::<PlayerLives>()
+            .register_type::<PrimaryWeaponLevel>()
+            .register_type::<PrimaryWeaponUpgradeTracks>()
+            .register_type::<PrimaryWeaponFireRateLevel>()
+            .register_type::<SecondaryWeaponLevel>()
+            .register_type::<TractorBeamLevel>()
+            .register_type::<IonCannonLevel>()
+            .register_type::<ShieldLevel>()
+            .register_type::<TurretLevel>();
+
+        // Enemies
+        app.register_type::<Enemy>()
+            .register_type::<EnemyHealth>()
+            .register_type::<EnemyArchetype>()
+            .register_type::<EnemyTier>()
+            .register_type::<EnemyFireCooldown>()
+            .register_type::<EnemyStun>()
+            .register_type::<EnemyRetreating>()
+            .register_type::<EnemyMinerHold>()
+            .register_type::<EnemyShotSource>()
+            .register_type::<LastDamagedBy>()
+            .register_type::<EnemyProjectile>()
+            .register_type::<DeflectedShot>()
+            .register_type::<EnemyFormationTarget>()
+            .register_type::<EnemyFormationMember>()
+            .register_type::<EnemyFormationLeader>()
+            .register_type::<EnemyProgressionStage>()
+            .register_type::<EnemySpawnState>()
+            .register_type::<Boss>()
+            .register_type::<BossHealth>()
+            .register_type::<BossWeakpoint>()
+            .register_type::<BossAttackPhase>()
+            .register_type::<BossAttackState>();
+
+        // Mining
+        app.register_type::<OreKind>()
+            .register_type::<OrePickup>()
+            .register_type::<PlayerOre>()
+            .register_type::<OreAffinityLevel>()
+            .register_type::<DroneTask>()
+            .register_type::<MiningDrone>()
+            .register_type::<MiningDroneLevel>()
+            .register_type::<MiningOutpost>()
+            .register_type::<MiningLaserState>();
+
+        // Session and world state
+        app.register_type::<PhysicsConfig>()
+            .register_type::<SimulationStats>()
+            .register_type::<MissileTelemetry>()
+            .register_type::<TimeControl>()
+            .register_type::<ProceduralField>()
+            .register_type::<BiggestAccretion>()
+            .register_type::<RunPlaytime>()
+            .register_type::<RunMetadata>()
+            .register_type::<Difficulty>()
+            .register_type::<AssistOptions>()
+            .register_type::<FeedbackKind>()
+            .register_type::<ScreenShake>()
+            .register_type::<HitStop>()
+            .register_type::<StatWeapon>()
+            .register_type::<WeaponStats>()
+            .register_type::<SessionStats>()
+            .register_type::<SurvivalPhase>()
+            .register_type::<SurvivalState>();
+
+        #[cfg(feature = "inspector")]
+        {
+            use bevy::input::common_conditions::input_toggle_active;
+            use bevy_inspector_egui::bevy_egui::EguiPlugin;
+            use bevy_inspector_egui::quick::WorldInspectorPlugin;
+
+            if !app.is_plugin_added::<EguiPlugin>() {
+                app.add_plugins(EguiPlugin::default());
+            }
+            app.add_plugins(
+                WorldInspectorPlugin::new()
+                    .run_if(input_toggle_active(false, INSPECTOR_TOGGLE_KEY)),
+            );
+        }
+    }
+}
+
+/// Serialize the gameplay components of `entities` as a RON scene.
+///
+/// Generic over the type registry: every component of this crate registered
+/// by [`ReflectionPlugin`] is included, plus `Transform`.  Other Bevy
+/// components (render handles, UI) are left out, and so are entities with
+/// nothing left to write.
+pub fn dump_entities(
+    world: &World,
+    entities: impl Iterator<Item = Entity>,
+) -> Result<String, String> {
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let crate_prefix = concat!(env!("CARGO_CRATE_NAME"), "::");
+    let mut filter = SceneFilter::deny_all();
+    for registration in registry.iter() {
+        let gameplay = registration
+            .type_info()
+            .type_path()
+            .starts_with(crate_prefix)
+            || registration.type_id() == TypeId::of::<Transform>();
+        if gameplay && registration.data::<ReflectComponent>().is_some() {
+            filter = filter.allow_by_id(registration.type_id());
+        }
+    }
+
+    let mut scene = DynamicSceneBuilder::from_world(world)
+        .with_component_filter(filter)
+        .deny_all_resources()
+        .extract_entities(entities)
+        .build();
+    scene
+        .entities
+        .retain(|entity| !entity.components.is_empty());
+    scene
+        .serialize(&registry)
+        .map_err(|err| format!("failed to serialize entities: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::ecs::reflect::ReflectResource;
+
+    fn reflection_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(ReflectionPlugin);
+        app
+    }
+
+    #[test]
+    fn gameplay_types_are_registered_as_components_and_resources() {
+        let app = reflection_app();
+        let registry = app.world().resource::<AppTypeRegistry>().read();
+
+        for component in [
+            TypeId::of::<Asteroid>(),
+            TypeId::of::<AsteroidSize>(),
+            TypeId::of::<Volatile>(),
+            TypeId::of::<PlayerHealth>(),
+            TypeId::of::<EnemyHealth>(),
+            TypeId::of::<MiningDrone>(),
+        ] {
+            let registration = registry.get(component).expect("registered");
+            assert!(
+                registration.data::<ReflectComponent>().is_some(),
+                "{} lacks ReflectComponent",
+                registration.type_info().type_path()
+            );
+        }
+        for resource in [
+            TypeId::of::<PhysicsConfig>(),
+            TypeId::of::<PlayerScore>(),
+            TypeId::of::<SurvivalState>(),
+            TypeId::of::<ProceduralField>(),
+        ] {
+            let registration = registry.get(resource).expect("registered");
+            assert!(
+                registration.data::<ReflectResource>().is_some(),
+                "{} lacks ReflectResource",
+                registration.type_info().type_path()
+            );
+        }
+    }
+
+    #[test]
+    fn entities_serialize_generically() {
+        let mut app = reflection_app();
+        let rock = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                AsteroidSize(7),
+                AsteroidMaterial::Metal,
+                Volatile,
+                Vertices(vec![Vec2::X, Vec2::Y, Vec2::NEG_X]),
+                Transform::from_xyz(3.0, 4.0, 0.0),
+            ))
+            .id();
+        let label = app.world_mut().spawn(Name::new("hud label")).id();
+
+        let ron = dump_entities(app.world(), [rock, label].into_iter()).unwrap();
+        for name in ["AsteroidSize", "Metal", "Volatile", "Vertices", "Transform"] {
+            assert!(ron.contains(name), "{name} missing from:\n{ron}");
+        }
+        assert!(
+            !ron.contains("hud label"),
+            "non-gameplay entity dumped:\n{ron}"
+        );
+    }
+
+    #[test]
+    fn physics_config_fields_are_editable_by_name() {
+        let mut config = PhysicsConfig::default();
+        let field = config
+            .field_mut("gravity_const")
+            .and_then(|field| field.try_downcast_mut::<f32>())
+            .expect("gravity_const is a reflected f32");
+        *field = 4.5;
+        assert_eq!(config.gravity_const, 4.5);
+    }
+}
@@ -15,7 +15,20 @@
 //! | Aim indicator      | `Mesh2d`     | OFF     | `show_aim_indicator`    |
 //! | Projectile fills   | `Mesh2d`     | ON      | `wireframe_only` flag   |
 //! | Projectile outline | `Mesh2d`     | OFF     | `show_projectile_outline`|
+//! | Trajectory paths   | `Mesh2d`     | OFF     | `show_trajectories`     |
+//! | Cluster outlines   | `Mesh2d`     | OFF     | `show_cluster_outlines` |
+//! | Gravity field      | `Mesh2d`     | OFF     | `show_gravity_field`    |
+//! | Gravity heatmap    | `Mesh2d`     | OFF     | `show_gravity_heatmap`  |
+//! | Collider outlines  | `Mesh2d`     | OFF     | `show_collider_outlines`|
+//! | Neighbor counts    | `Mesh2d`     | OFF     | `show_neighbor_counts`  |
+//! | Motion trails      | `Mesh2d`     | OFF     | `show_motion_trails`    |
+//! | Selection outline  | `Mesh2d`     | —       | Middle-click selection  |
+//! | Asteroid inspector | Bevy UI      | —       | Middle-click selection  |
+//! | Hover tooltip      | Bevy UI      | OFF     | `show_hover_tooltip`    |
+//! | Session clock      | Bevy UI      | OFF     | `show_session_clock` / `speedrun_mode` |
+//! | Live stats panel   | Bevy UI      | OFF     | `streamer_mode` (see [`streamer`]) |
 //! | Health bar         | `Mesh2d`     | always  | —                       |
+//! | Energy bar         | Bevy UI      | always  | —                       |
 //! | Stats overlay      | Bevy UI      | OFF     | `show_stats`            |
 //! | Score HUD          | Bevy UI      | always  | —                       |
 //! | Debug panel        | Bevy UI      | hidden  | Pause menu button       |
@@ -29,30 +42,51 @@
 //! | `setup_debug_panel`           | Startup  | Spawn collapsible debug panel       |
 //! | `setup_hud_score`             | Startup  | Spawn permanent score HUD node      |
 //! | `setup_stats_overlay`         | Startup  | Spawn toggleable stats overlay node |
-//! | `stats_display_system`        | Update   | Refresh live/culled/merged text     |
+//! | `stats_display_system`        | Update   | Refresh live/culled/merged/LOD text |
 //! | `hud_score_display_system`    | Update   | Refresh score HUD text              |
 //! | `sync_boundary_ring_visibility_system` | Update | Show/hide boundary ring   |
 //! | `sync_stats_overlay_visibility_system` | Update | Show/hide stats overlay   |
 //! | `debug_panel_button_system`   | Update   | Process toggle button clicks        |
 //! | `sync_debug_line_layers_system` | Update | Refresh retained debug line layers   |
+//! | `sync_trajectory_layer_system` | Update | Redraw predicted paths (see [`trajectory`]) |
+//! | `sync_physics_debug_layers_system` | Update | Field, collider and neighbor layers (see [`physics_layers`]) |
+//! | `sync_gravity_heatmap_system` | Update | Resample gravity heatmap (see [`gravity_heatmap`]) |
+//! | `asteroid_inspector_display_system` | Update | Selection outline + inspector (see [`inspector`]) |
+//! | `hover_tooltip_system`        | Update   | Tooltip for the hovered asteroid (see [`hover_tooltip`]) |
+//! | `motion_trail_system`         | Update   | Ship ghosts + asteroid streaks (see [`motion_trail`]) |
+//! | `streamer_hud_style_system`   | Update   | Streamer-mode HUD scale + stats panel (see [`streamer`]) |
 
 use crate::asteroid::{Asteroid, GravityForce, Vertices};
-use crate::asteroid_rendering::ring_mesh;
-use crate::campaign::{CampaignSession, CampaignWaveDirector, CampaignWavePhase};
+use crate::asteroid_rendering::{ring_mesh, AsteroidLodStats};
+use crate::campaign::{
+    CampaignScriptRunner, CampaignSession, CampaignWaveDirector, CampaignWavePhase,
+};
 use crate::config::PhysicsConfig;
 use crate::enemy::{Boss, BossAttackPhase, BossAttackState};
+use crate::graphics::palette::Palette;
 use crate::graphics::{EmojiFont, GameFont, SymbolFont, SymbolFont2, UnicodeFallbackFont};
 use crate::menu::SelectedGameMode;
-use crate::mining::{OreAffinityLevel, PlayerOre};
+use crate::mining::laser::{MiningLaserHudRow, MiningLaserHudValueText};
+use crate::mining::outpost::{OutpostHudRow, OutpostHudValueText};
+use crate::mining::{OreAffinityLevel, OreKind, PlayerOre};
+use crate::particles::ParticlePool;
 use crate::player::state::MissileAmmo;
 use crate::player::Player;
 use crate::player::{
-    CampaignLoadout, CampaignSecondaryWeapon, IonCannonCooldown, IonCannonLevel, PlayerLives,
-    PlayerScore, PrimaryWeaponFireRateLevel, PrimaryWeaponUpgradeTracks, SecondaryWeaponLevel,
-    TractorBeamLevel, TractorHoldState, TractorThrowCooldown,
+    CampaignLoadout, CampaignSecondaryWeapon, IonCannonCooldown, IonCannonLevel, PlayerEnergy,
+    PlayerLives, PlayerScore, PrimaryWeaponFireRateLevel, PrimaryWeaponUpgradeTracks,
+    SecondaryWeaponLevel, TractorBeamLevel, TractorHoldState, TractorThrowCooldown,
 };
+use crate::run_info::RunPlaytime;
+use crate::scoring::ScoringConfig;
+use crate::simulation::clusters::ClusterStats;
+use crate::simulation::governor::FrameTimeGovernor;
+use crate::simulation::size_class::FieldSizeClass;
+use crate::simulation::time_control::TimeControl;
 use crate::simulation::{ProfilerStats, SimulationStats};
 use crate::spatial_partition::SpatialGrid;
+use crate::speedrun::{format_clock, SpeedrunSplits, Split, HUD_SPLITS_SHOWN};
+use crate::survival::{SurvivalPhase, SurvivalState};
 use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
 use bevy::ecs::hierarchy::ChildSpawnerCommands;
 use bevy::prelude::*;
@@ -61,6 +95,34 @@ use bevy_mesh::{Indices, PrimitiveTopology};
 use bevy_rapier2d::prelude::{ReadRapierContext, Velocity};
 use std::collections::HashMap;
 
+#[path = "rendering/gravity_heatmap.rs"]
+pub mod gravity_heatmap;
+use gravity_heatmap::GravityHeatmapLayer;
+#[path = "rendering/hud_layout.rs"]
+pub mod hud_layout;
+use hud_layout::{
+    hud_line_height as line, HudAnchor, HudAnchored, HudAppExt, HudLayoutMode, HudVisibility,
+    HudWidget, HudWidgetSpec,
+};
+#[path = "rendering/hover_tooltip.rs"]
+pub mod hover_tooltip;
+#[path = "rendering/inspector.rs"]
+pub mod inspector;
+use inspector::SelectionHighlightLayer;
+#[path = "rendering/motion_trail.rs"]
+pub mod motion_trail;
+#[path = "rendering/physics_layers.rs"]
+pub mod physics_layers;
+use physics_layers::{ColliderOutlineLayer, GravityFieldLayer, NeighborCountLayer};
+#[path = "rendering/split_preview.rs"]
+pub mod split_preview;
+#[path = "rendering/streamer.rs"]
+pub mod streamer;
+use split_preview::MissileSplitPreviewLayer;
+#[path = "rendering/trajectory.rs"]
+pub mod trajectory;
+use trajectory::TrajectoryLayer;
+
 // ── Overlay state resource ────────────────────────────────────────────────────
 
 /// Controls which debug overlay layers are rendered at runtime.
@@ -94,6 +156,48 @@ pub struct OverlayState {
     pub show_stats: bool,
     /// Show the physics inspector overlay (entity IDs, velocities, contacts).
     pub show_physics_inspector: bool,
+    /// Draw predicted paths for the ship, lock-on target, and nearby asteroids.
+    pub show_trajectories: bool,
+    /// Draw faint convex outlines around labeled asteroid clusters.
+    pub show_cluster_outlines: bool,
+    /// Draw the gravity-field arrow grid over the camera view.
+    pub show_gravity_field: bool,
+    /// Shade the camera view by gravity field strength.
+    pub show_gravity_heatmap: bool,
+    /// Draw each asteroid's collider hull (`BaseVertices`).
+    pub show_collider_outlines: bool,
+    /// Draw one tick per neighbor around each asteroid.
+    pub show_neighbor_counts: bool,
+    /// Show mass and composition of the asteroid under the cursor.  Toggled
+    /// from the Settings screen rather than the debug panel.
+    pub show_hover_tooltip: bool,
+    /// Draw a fading ghost trail behind the ship and streaks behind fast
+    /// asteroids (Settings screen).
+    pub show_motion_trails: bool,
+    /// Accessibility (Settings screen): no screen shake and no aberration
+    /// pulse on damage.
+    pub reduce_motion: bool,
+    /// Show the session clock under the score (Settings screen).
+    pub show_session_clock: bool,
+    /// Show speedrun splits and record finished runs (Settings screen).
+    pub speedrun_mode: bool,
+    /// Streamer mode (Settings screen or F9): hide the debug text, enlarge
+    /// the score and wave line, and show the live stats panel.
+    pub streamer_mode: bool,
+    /// Keep the bottom-right corner empty for a webcam in streamer mode
+    /// (Settings screen).
+    pub webcam_corner: bool,
+    /// Centre the score, lives and ore along the top and bottom edges
+    /// instead of the corners (Settings screen).
+    pub hud_edges: bool,
+}
+
+impl OverlayState {
+    /// Whether a debug text overlay whose own toggle is `flag` should show;
+    /// streamer mode hides them all.
+    pub fn debug_text_shown(&self, flag: bool) -> bool {
+        flag && !self.streamer_mode
+    }
 }
 
 // ── Component markers ─────────────────────────────────────────────────────────
@@ -118,6 +222,10 @@ pub struct HudScoreText;
 #[derive(Component)]
 pub struct HudModeText;
 
+/// Marker for the session clock and speedrun splits under the mode text.
+#[derive(Component)]
+pub struct HudClockText;
+
 /// Marker for the retrained GPU boundary-ring entity.
 #[derive(Component)]
 pub struct BoundaryRing;
@@ -138,6 +246,10 @@ pub struct RespawnCountdownText;
 #[derive(Component)]
 pub struct LivesHudValueText;
 
+/// Marker for the energy-bar fill node within the lives HUD.
+#[derive(Component)]
+pub struct EnergyHudFill;
+
 /// Marker for the missile-ammo HUD node (row 3, below lives HUD).
 #[derive(Component)]
 pub struct MissileHudDisplay;
@@ -218,6 +330,10 @@ pub struct VelocityArrowLayer;
 #[derive(Component)]
 pub struct SpatialGridLayer;
 
+/// Marker for retained cluster-outline overlay line mesh.
+#[derive(Component)]
+pub struct ClusterOutlineLayer;
+
 /// Tags a toggle button in the debug panel with the overlay field it controls.
 #[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum OverlayToggle {
@@ -233,6 +349,12 @@ pub enum OverlayToggle {
     Profiler,
     StatsOverlay,
     PhysicsInspector,
+    Trajectories,
+    ClusterOutlines,
+    GravityField,
+    GravityHeatmap,
+    ColliderOutlines,
+    NeighborCounts,
 }
 
 impl OverlayToggle {
@@ -251,6 +373,12 @@ impl OverlayToggle {
             Self::Profiler => state.show_profiler,
             Self::StatsOverlay => state.show_stats,
             Self::PhysicsInspector => state.show_physics_inspector,
+            Self::Trajectories => state.show_trajectories,
+            Self::ClusterOutlines => state.show_cluster_outlines,
+            Self::GravityField => state.show_gravity_field,
+            Self::GravityHeatmap => state.show_gravity_heatmap,
+            Self::ColliderOutlines => state.show_collider_outlines,
+            Self::NeighborCounts => state.show_neighbor_counts,
         }
     }
 
@@ -273,6 +401,18 @@ impl OverlayToggle {
             Self::PhysicsInspector => {
                 state.show_physics_inspector = !state.show_physics_inspector;
             }
+            Self::Trajectories => state.show_trajectories = !state.show_trajectories,
+            Self::ClusterOutlines => {
+                state.show_cluster_outlines = !state.show_cluster_outlines;
+            }
+            Self::GravityField => state.show_gravity_field = !state.show_gravity_field,
+            Self::GravityHeatmap => {
+                state.show_gravity_heatmap = !state.show_gravity_heatmap;
+            }
+            Self::ColliderOutlines => {
+                state.show_collider_outlines = !state.show_collider_outlines;
+            }
+            Self::NeighborCounts => state.show_neighbor_counts = !state.show_neighbor_counts,
         }
     }
 
@@ -291,23 +431,25 @@ impl OverlayToggle {
             Self::Profiler => "Profiler",
             Self::StatsOverlay => "Stats Overlay",
             Self::PhysicsInspector => "Physics Inspector",
+            Self::Trajectories => "Trajectory Prediction",
+            Self::ClusterOutlines => "Cluster Outlines",
+            Self::GravityField => "Gravity Field",
+            Self::GravityHeatmap => "Gravity Heatmap",
+            Self::ColliderOutlines => "Collider Outlines",
+            Self::NeighborCounts => "Neighbor Counts",
         }
     }
 }
 
 // ── Colour helpers ────────────────────────────────────────────────────────────
 
-fn on_bg() -> Color {
-    Color::srgb(0.08, 0.44, 0.12)
-}
-fn off_bg() -> Color {
-    Color::srgb(0.35, 0.07, 0.07)
-}
-fn on_text() -> Color {
-    Color::srgb(0.75, 1.0, 0.80)
-}
-fn off_text() -> Color {
-    Color::srgb(0.65, 0.65, 0.65)
+/// Toggle button background and label colours for `active`.
+fn toggle_colors(palette: &Palette, active: bool) -> (Color, Color) {
+    if active {
+        (palette.toggle_on_bg, palette.toggle_on_text)
+    } else {
+        (palette.toggle_off_bg, palette.toggle_off_text)
+    }
 }
 
 fn circled_number_level(level: u32) -> &'static str {
@@ -394,6 +536,7 @@ pub struct DebugLineScratch {
     force: Vec<(Vec2, Vec2)>,
     velocity: Vec<(Vec2, Vec2)>,
     grid: Vec<(Vec2, Vec2)>,
+    clusters: Vec<(Vec2, Vec2)>,
 }
 
 // ── Startup: boundary ring ───────────────────────────────────────────────────
@@ -457,7 +600,7 @@ pub fn setup_debug_line_layers(
     ));
 
     commands.spawn((
-        Mesh2d(empty),
+        Mesh2d(empty.clone()),
         MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgba(
             0.35, 0.95, 0.35, 0.45,
         )))),
@@ -465,6 +608,73 @@ pub fn setup_debug_line_layers(
         Visibility::Hidden,
         SpatialGridLayer,
     ));
+
+    commands.spawn((
+        Mesh2d(empty.clone()),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgba(1.0, 0.85, 0.4, 0.3)))),
+        Transform::from_translation(Vec3::new(0.0, 0.0, 2.68)),
+        Visibility::Hidden,
+        ClusterOutlineLayer,
+    ));
+
+    commands.spawn((
+        Mesh2d(empty.clone()),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgba(0.6, 0.5, 1.0, 0.5)))),
+        Transform::from_translation(Vec3::new(0.0, 0.0, 2.62)),
+        Visibility::Hidden,
+        GravityFieldLayer,
+    ));
+
+    // Colours come from the mesh's vertex colours.
+    commands.spawn((
+        Mesh2d(empty.clone()),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::WHITE))),
+        Transform::from_translation(Vec3::new(0.0, 0.0, -0.4)),
+        Visibility::Hidden,
+        GravityHeatmapLayer,
+    ));
+
+    commands.spawn((
+        Mesh2d(empty.clone()),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgba(0.3, 1.0, 0.8, 0.7)))),
+        Transform::from_translation(Vec3::new(0.0, 0.0, 2.63)),
+        Visibility::Hidden,
+        ColliderOutlineLayer,
+    ));
+
+    commands.spawn((
+        Mesh2d(empty.clone()),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgb(1.0, 0.95, 0.3)))),
+        Transform::from_translation(Vec3::new(0.0, 0.0, 2.64)),
+        Visibility::Hidden,
+        NeighborCountLayer,
+    ));
+
+    commands.spawn((
+        Mesh2d(empty.clone()),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgba(
+            1.0, 0.45, 0.95, 0.7,
+        )))),
+        Transform::from_translation(Vec3::new(0.0, 0.0, 2.7)),
+        Visibility::Hidden,
+        TrajectoryLayer,
+    ));
+
+    commands.spawn((
+        Mesh2d(empty.clone()),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgba(1.0, 0.6, 0.3, 0.35)))),
+        Transform::from_translation(Vec3::new(0.0, 0.0, 2.72)),
+        Visibility::Hidden,
+        MissileSplitPreviewLayer,
+    ));
+
+    commands.spawn((
+        Mesh2d(empty),
+        MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgb(1.0, 0.92, 0.55)))),
+        Transform::from_translation(Vec3::new(0.0, 0.0, 2.75)),
+        Visibility::Hidden,
+        SelectionHighlightLayer,
+    ));
 }
 
 /// Show or hide the boundary ring when `show_boundary` changes.
@@ -490,6 +700,47 @@ pub fn sync_boundary_ring_visibility_system(
 // ── Startup: score HUD ────────────────────────────────────────────────────────
 
 /// Spawn the permanent score HUD (always visible).
+/// Font size of the score; streamer mode scales it (see [`streamer`]).
+pub fn hud_score_font_size(config: &PhysicsConfig) -> f32 {
+    (config.stats_font_size * 2.0).max(28.0)
+}
+
+/// Font size of the mode / wave line under the score.
+pub fn hud_mode_font_size(config: &PhysicsConfig) -> f32 {
+    (config.stats_font_size * 0.65).max(12.0)
+}
+
+/// Register the built-in HUD widgets, in stacking order.  In the edge layout
+/// the score, lives and ore move to the middle of the top and bottom edges;
+/// the debug overlays stay in their corners.
+pub fn register_builtin_hud_widgets(app: &mut App) {
+    app.register_hud_widget(score_hud_spec())
+        .register_hud_widget(lives_hud_spec())
+        .register_hud_widget(streamer::streamer_stats_hud_spec())
+        .register_hud_widget(stats_hud_spec())
+        .register_hud_widget(physics_inspector_hud_spec())
+        .register_hud_widget(profiler_hud_spec())
+        .register_hud_widget(ore_hud_spec())
+        .register_hud_widget(debug_panel_hud_spec())
+        .register_hud_widget(inspector::asteroid_inspector_hud_spec());
+}
+
+/// Score with the mode / wave line below it, top right.
+pub fn score_hud_spec() -> HudWidgetSpec {
+    HudWidgetSpec::new(HudWidget::SCORE, HudAnchor::TopRight, score_hud_size)
+        .at_edge(HudAnchor::Top)
+}
+
+/// Score plus a mode line that may wrap once; both grow in streamer mode.
+fn score_hud_size(config: &PhysicsConfig, mode: HudLayoutMode) -> Vec2 {
+    let scale = mode.score_scale(config);
+    Vec2::new(
+        640.0 * scale,
+        line(hud_score_font_size(config) * scale) + line(hud_mode_font_size(config) * scale) * 2.0,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn setup_hud_score(
     mut commands: Commands,
     config: Res<PhysicsConfig>,
@@ -497,6 +748,7 @@ pub fn setup_hud_score(
     selected_mode: Option<Res<SelectedGameMode>>,
     campaign: Option<Res<CampaignSession>>,
     wave: Option<Res<CampaignWaveDirector>>,
+    survival: Option<Res<SurvivalState>>,
     q_boss: Query<&BossAttackState, With<Boss>>,
 ) {
     let mode_text = campaign_mode_text(
@@ -505,19 +757,19 @@ pub fn setup_hud_score(
             .unwrap_or(SelectedGameMode::Practice),
         campaign.as_deref(),
         wave.as_deref(),
+        survival.as_deref(),
+        &config,
         q_boss.iter().next(),
     );
 
     commands
         .spawn((
             Node {
-                position_type: PositionType::Absolute,
-                right: Val::Px(14.0),
-                top: Val::Px(10.0),
                 flex_direction: FlexDirection::Column,
                 align_items: AlignItems::End,
-                ..default()
+                ..HudAnchored::node()
             },
+            HudAnchored(HudWidget::SCORE),
             HudScoreDisplay,
         ))
         .with_children(|parent| {
@@ -525,7 +777,7 @@ pub fn setup_hud_score(
                 Text::new("0"),
                 TextFont {
                     font: font.0.clone(),
-                    font_size: (config.stats_font_size * 2.0).max(28.0),
+                    font_size: hud_score_font_size(&config),
                     ..default()
                 },
                 TextColor(Color::WHITE),
@@ -535,12 +787,34 @@ pub fn setup_hud_score(
                 Text::new(mode_text),
                 TextFont {
                     font: font.0.clone(),
-                    font_size: (config.stats_font_size * 0.65).max(12.0),
+                    font_size: hud_mode_font_size(&config),
                     ..default()
                 },
                 TextColor(Color::srgb(0.80, 0.86, 0.92)),
+                TextLayout::new_with_justify(Justify::Right),
+                // Stretched so a long line wraps inside the widget's box
+                // instead of spilling out to the left of it.
+                Node {
+                    align_self: AlignSelf::Stretch,
+                    ..default()
+                },
                 HudModeText,
             ));
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: (config.stats_font_size * 0.75).max(13.0),
+                    ..default()
+                },
+                TextColor(Color::srgb(0.95, 0.90, 0.60)),
+                TextLayout::new_with_justify(Justify::Right),
+                Node {
+                    display: Display::None,
+                    ..default()
+                },
+                HudClockText,
+            ));
         });
 }
 
@@ -548,6 +822,8 @@ fn campaign_mode_text(
     selected_mode: SelectedGameMode,
     campaign: Option<&CampaignSession>,
     wave: Option<&CampaignWaveDirector>,
+    survival: Option<&SurvivalState>,
+    config: &PhysicsConfig,
     boss_attack: Option<&BossAttackState>,
 ) -> String {
     match selected_mode {
@@ -566,9 +842,10 @@ fn campaign_mode_text(
                         format!("WAVE {}/{}", w.current_wave.max(1), w.total_waves.max(1))
                     }
                     CampaignWavePhase::InterWaveBreak => format!(
-                        "WAVE {}/{} · BREAK",
+                        "WAVE {}/{} · NEXT WAVE IN {:.0}s · TAB AT BEACON: SHOP",
                         w.current_wave.max(1),
-                        w.total_waves.max(1)
+                        w.total_waves.max(1),
+                        w.phase_timer_secs.ceil()
                     ),
                     CampaignWavePhase::BossIntro => {
                         format!(
@@ -604,17 +881,53 @@ fn campaign_mode_text(
                 .unwrap_or_else(|| "WAVE 1/1".to_string());
             format!("MODE: CAMPAIGN · MISSION {mission} · {wave_label}")
         }
-        SelectedGameMode::Practice => "MODE: PRACTICE".to_string(),
+        SelectedGameMode::Practice => match survival.filter(|s| s.is_active()) {
+            Some(survival) => survival_mode_text(survival, config),
+            None => "MODE: PRACTICE".to_string(),
+        },
     }
 }
 
+fn survival_mode_text(survival: &SurvivalState, config: &PhysicsConfig) -> String {
+    let wave_label = match survival.phase {
+        SurvivalPhase::Breather if survival.wave == 0 => {
+            format!("WAVE 1 IN {:.0}s", survival.phase_timer_secs.ceil())
+        }
+        SurvivalPhase::Breather => format!(
+            "WAVE {} CLEAR · NEXT IN {:.0}s",
+            survival.wave,
+            survival.phase_timer_secs.ceil()
+        ),
+        SurvivalPhase::Wave | SurvivalPhase::Inactive => format!("WAVE {}", survival.wave.max(1)),
+    };
+    format!(
+        "MODE: SURVIVAL · {wave_label} · SCORE ×{:.2}",
+        survival.score_multiplier(config)
+    )
+}
+
 // ── Startup: stats overlay text ───────────────────────────────────────────────
 
-/// Spawn the lives counter and respawn-countdown HUD (always visible during play).
+const ENERGY_BAR_WIDTH_PX: f32 = 96.0;
+const ENERGY_BAR_HEIGHT_PX: f32 = 6.0;
+
+/// Spawn the lives counter, energy bar, and respawn-countdown HUD (always visible during play).
 ///
+/// Lives and the energy bar, top left.
+pub fn lives_hud_spec() -> HudWidgetSpec {
+    HudWidgetSpec::new(HudWidget::LIVES, HudAnchor::TopLeft, lives_hud_size).at_edge(HudAnchor::Top)
+}
+
+/// Lives row, energy bar, respawn countdown.
+fn lives_hud_size(config: &PhysicsConfig, _: HudLayoutMode) -> Vec2 {
+    let f = config.stats_font_size;
+    Vec2::new(260.0, line(f) + line(f - 2.0) + 16.0)
+}
+
 /// Structure (top-left column, below score):
 /// ```text
 ///  Lives: ♥ ♥ ♥
+///  [██████░░░]          ← shared ship energy pool
 ///  RESPAWNING IN 2.4s   ← hidden while alive
 /// ```
 pub fn setup_lives_hud(
@@ -622,17 +935,16 @@ pub fn setup_lives_hud(
     config: Res<PhysicsConfig>,
     font: Res<GameFont>,
     symbol_font_2: Res<SymbolFont2>,
+    palette: Res<Palette>,
 ) {
     commands
         .spawn((
             Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(10.0),
-                top: Val::Px(10.0),
                 flex_direction: FlexDirection::Column,
                 row_gap: Val::Px(2.0),
-                ..default()
+                ..HudAnchored::node()
             },
+            HudAnchored(HudWidget::LIVES),
             LivesHudDisplay,
         ))
         .with_children(|parent| {
@@ -656,6 +968,27 @@ pub fn setup_lives_hud(
                         LivesHudValueText,
                     ));
                 });
+            // Energy bar: fixed-width track with a percentage-width fill
+            parent
+                .spawn((
+                    Node {
+                        width: Val::Px(ENERGY_BAR_WIDTH_PX),
+                        height: Val::Px(ENERGY_BAR_HEIGHT_PX),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.08, 0.12, 0.22, 0.85)),
+                ))
+                .with_children(|track| {
+                    track.spawn((
+                        Node {
+                            width: Val::Percent(100.0),
+                            height: Val::Percent(100.0),
+                            ..default()
+                        },
+                        BackgroundColor(palette.energy(1.0)),
+                        EnergyHudFill,
+                    ));
+                });
             // Respawn countdown — hidden while alive
             parent.spawn((
                 Text::new(""),
@@ -707,6 +1040,23 @@ pub fn lives_hud_display_system(
     }
 }
 
+/// Resize and tint the energy-bar fill from [`PlayerEnergy`] (the palette's
+/// charged → drained gradient).
+pub fn energy_hud_display_system(
+    energy: Res<PlayerEnergy>,
+    palette: Res<Palette>,
+    mut q_fill: Query<(&mut Node, &mut BackgroundColor), With<EnergyHudFill>>,
+) {
+    if !energy.is_changed() {
+        return;
+    }
+    let frac = energy.fraction();
+    for (mut node, mut color) in q_fill.iter_mut() {
+        node.width = Val::Percent(frac * 100.0);
+        color.0 = palette.energy(frac);
+    }
+}
+
 /// Startup: spawn the missile-ammo indicator HUD (row 3, below lives HUD).
 pub fn setup_missile_hud(
     _commands: Commands,
@@ -730,6 +1080,19 @@ pub fn missile_hud_display_system(
     }
 }
 
+/// Ore and loadout rows, bottom left.
+pub fn ore_hud_spec() -> HudWidgetSpec {
+    HudWidgetSpec::new(HudWidget::ORE, HudAnchor::BottomLeft, ore_hud_size)
+        .at_edge(HudAnchor::Bottom)
+}
+
+/// Ore, weapon, missile, magnet, tractor, ion and shield rows, then the
+/// smaller outpost row, which may wrap once.
+fn ore_hud_size(config: &PhysicsConfig, _: HudLayoutMode) -> Vec2 {
+    let f = config.stats_font_size;
+    Vec2::new(360.0, line(f) * 7.0 + line(f - 4.0) * 2.0 + 14.0)
+}
+
 /// Startup: bottom-left HUD indicator block.
 pub fn setup_ore_hud(
     mut commands: Commands,
@@ -742,12 +1105,8 @@ pub fn setup_ore_hud(
 ) {
     commands
         .spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(10.0),
-                bottom: Val::Px(12.0),
-                ..default()
-            },
+            HudAnchored::node(),
+            HudAnchored(HudWidget::ORE),
             OreHudDisplay,
         ))
         .with_children(|parent| {
@@ -956,6 +1315,36 @@ pub fn setup_ore_hud(
                             IonHudValueText,
                         ));
                     });
+
+                    // Mining outposts (hidden until one is built or in range)
+                    col.spawn((Node::default(), Visibility::Hidden, OutpostHudRow))
+                        .with_children(|entry| {
+                            entry.spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font: font.0.clone(),
+                                    font_size: config.stats_font_size - 4.0,
+                                    ..default()
+                                },
+                                TextColor(Color::srgb(1.0, 0.75, 0.3)),
+                                OutpostHudValueText,
+                            ));
+                        });
+
+                    // Mining laser heat (hidden while the laser is cold)
+                    col.spawn((Node::default(), Visibility::Hidden, MiningLaserHudRow))
+                        .with_children(|entry| {
+                            entry.spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font: font.0.clone(),
+                                    font_size: config.stats_font_size - 4.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                                MiningLaserHudValueText,
+                            ));
+                        });
                 });
         });
 }
@@ -1019,6 +1408,17 @@ pub fn sync_loadout_hud_visibility_system(
     }
 }
 
+/// Ore HUD readout: raw ore per kind, then refined credits.
+fn ore_hud_value(ore: &PlayerOre) -> String {
+    let mut value = OreKind::ALL
+        .iter()
+        .map(|kind| format!("{} {}", ore.raw(*kind), kind.label()))
+        .collect::<Vec<_>>()
+        .join("  ");
+    value.push_str(&format!("  {} ¢", ore.credits));
+    value
+}
+
 /// Refresh the ore-count HUD each frame.
 ///
 /// When ore > 0 the text includes key-binding hints for spending it so players
@@ -1087,7 +1487,7 @@ pub fn ore_hud_display_system(
 
     for (mut text, tags) in text_query.iter_mut() {
         if tags.0.is_some() {
-            *text = Text::new(ore.count.to_string());
+            *text = Text::new(ore_hud_value(&ore));
         } else if tags.1.is_some() {
             *text = Text::new(blaster_text.clone());
         } else if tags.2.is_some() {
@@ -1102,17 +1502,22 @@ pub fn ore_hud_display_system(
     }
 }
 
+/// Simulation stats overlay, top left.
+pub fn stats_hud_spec() -> HudWidgetSpec {
+    HudWidgetSpec::new(HudWidget::STATS, HudAnchor::TopLeft, |config, _| {
+        // One line; wraps to two on narrow windows.
+        Vec2::new(640.0, line(config.stats_font_size) * 2.0)
+    })
+    .overlay()
+    .visible(HudVisibility::OutsideStreamer)
+}
+
 /// Startup: stats overlay text — Spawn the toggleable simulation-stats overlay (starts hidden; enable via debug panel).
 pub fn setup_stats_text(mut commands: Commands, config: Res<PhysicsConfig>, font: Res<GameFont>) {
-    let row_h = config.stats_font_size + 6.0;
     commands
         .spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(10.0),
-                top: Val::Px(10.0 + row_h * 2.0),
-                ..default()
-            },
+            HudAnchored::node(),
+            HudAnchored(HudWidget::STATS),
             StatsTextDisplay,
             Visibility::Hidden,
         ))
@@ -1130,21 +1535,27 @@ pub fn setup_stats_text(mut commands: Commands, config: Res<PhysicsConfig>, font
         });
 }
 
+/// Physics inspector overlay, top left.
+pub fn physics_inspector_hud_spec() -> HudWidgetSpec {
+    HudWidgetSpec::new(
+        HudWidget::PHYSICS_INSPECTOR,
+        HudAnchor::TopLeft,
+        |config, _| Vec2::new(440.0, line((config.stats_font_size - 4.0).max(10.0)) * 9.0),
+    )
+    .overlay()
+    .visible(HudVisibility::OutsideStreamer)
+}
+
 /// Startup: spawn physics-inspector text overlay (hidden by default).
 pub fn setup_physics_inspector_text(
     mut commands: Commands,
     config: Res<PhysicsConfig>,
     font: Res<GameFont>,
 ) {
-    let row_h = config.stats_font_size + 6.0;
     commands
         .spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(10.0),
-                top: Val::Px(10.0 + row_h * 3.0),
-                ..default()
-            },
+            HudAnchored::node(),
+            HudAnchored(HudWidget::PHYSICS_INSPECTOR),
             PhysicsInspectorDisplay,
             Visibility::Hidden,
         ))
@@ -1162,21 +1573,25 @@ pub fn setup_physics_inspector_text(
         });
 }
 
+/// Profiler overlay, top left.
+pub fn profiler_hud_spec() -> HudWidgetSpec {
+    HudWidgetSpec::new(HudWidget::PROFILER, HudAnchor::TopLeft, |config, _| {
+        Vec2::new(360.0, line((config.stats_font_size - 3.0).max(10.0)) * 19.0)
+    })
+    .overlay()
+    .visible(HudVisibility::OutsideStreamer)
+}
+
 /// Startup: spawn profiler text overlay (hidden by default).
 pub fn setup_profiler_text(
     mut commands: Commands,
     config: Res<PhysicsConfig>,
     font: Res<GameFont>,
 ) {
-    let row_h = config.stats_font_size + 6.0;
     commands
         .spawn((
-            Node {
-                position_type: PositionType::Absolute,
-                left: Val::Px(10.0),
-                top: Val::Px(10.0 + row_h * 9.0),
-                ..default()
-            },
+            HudAnchored::node(),
+            HudAnchored(HudWidget::PROFILER),
             ProfilerDisplay,
             Visibility::Hidden,
         ))
@@ -1196,42 +1611,61 @@ pub fn setup_profiler_text(
 
 // ── Startup: debug panel ──────────────────────────────────────────────────────
 
+/// Debug panel, top right below the score.  It stays in streamer mode, since
+/// it only opens from the pause menu.
+pub fn debug_panel_hud_spec() -> HudWidgetSpec {
+    // Title, separator, 14 toggle rows, two hint lines.
+    HudWidgetSpec::new(HudWidget::DEBUG_PANEL, HudAnchor::TopRight, |_, _| {
+        Vec2::new(235.0, 470.0)
+    })
+    .overlay()
+}
+
 /// Spawn the debug overlay panel (hidden until the user presses ESC).
 ///
-/// The panel appears in the top-right corner and provides per-layer toggle
+/// The panel sits in the top-right column below the score and provides per-layer toggle
 /// buttons for all gizmo overlays plus a wireframe-only fallback mode.
-pub fn setup_debug_panel(mut commands: Commands, font: Res<GameFont>) {
-    // Each entry: (toggle variant, initial "active" state) — must match OverlayState::default().
-    let defaults: &[(OverlayToggle, bool)] = &[
-        (OverlayToggle::Boundary, false),
-        (OverlayToggle::Wireframes, false),
-        (OverlayToggle::ForceVectors, false),
-        (OverlayToggle::VelocityArrows, false),
-        (OverlayToggle::WireframeOnly, false),
-        (OverlayToggle::AimIndicator, false),
-        (OverlayToggle::ShipOutline, false),
-        (OverlayToggle::ProjectileOutline, false),
-        (OverlayToggle::DebugGrid, false),
-        (OverlayToggle::Profiler, false),
-        (OverlayToggle::StatsOverlay, false),
-        (OverlayToggle::PhysicsInspector, false),
+pub fn setup_debug_panel(
+    mut commands: Commands,
+    font: Res<GameFont>,
+    overlay: Res<OverlayState>,
+    palette: Res<Palette>,
+) {
+    // Rows start from the current state, which may come from the profile.
+    let toggles = [
+        OverlayToggle::Boundary,
+        OverlayToggle::Wireframes,
+        OverlayToggle::ForceVectors,
+        OverlayToggle::VelocityArrows,
+        OverlayToggle::WireframeOnly,
+        OverlayToggle::AimIndicator,
+        OverlayToggle::ShipOutline,
+        OverlayToggle::ProjectileOutline,
+        OverlayToggle::DebugGrid,
+        OverlayToggle::GravityField,
+        OverlayToggle::GravityHeatmap,
+        OverlayToggle::ColliderOutlines,
+        OverlayToggle::NeighborCounts,
+        OverlayToggle::Profiler,
+        OverlayToggle::StatsOverlay,
+        OverlayToggle::PhysicsInspector,
+        OverlayToggle::Trajectories,
+        OverlayToggle::ClusterOutlines,
     ];
 
     commands
         .spawn((
             Node {
-                position_type: PositionType::Absolute,
-                right: Val::Px(12.0),
-                top: Val::Px(10.0),
                 width: Val::Px(235.0),
                 flex_direction: FlexDirection::Column,
                 padding: UiRect::all(Val::Px(10.0)),
                 row_gap: Val::Px(6.0),
                 border: UiRect::all(Val::Px(1.0)),
-                ..default()
+                ..HudAnchored::node()
             },
             BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.93)),
             BorderColor::all(Color::srgb(0.32, 0.32, 0.44)),
+            HudAnchored(HudWidget::DEBUG_PANEL),
             DebugPanel,
             Visibility::Hidden,
         ))
@@ -1256,8 +1690,8 @@ pub fn setup_debug_panel(mut commands: Commands, font: Res<GameFont>) {
                 TextColor(Color::srgb(0.28, 0.28, 0.38)),
             ));
 
-            for &(toggle, initial) in defaults {
-                spawn_toggle_row(panel, toggle, initial, &font);
+            for toggle in toggles {
+                spawn_toggle_row(panel, toggle, toggle.get(&overlay), &font, &palette);
             }
 
             panel.spawn((
@@ -1288,7 +1722,9 @@ fn spawn_toggle_row(
     toggle: OverlayToggle,
     initial: bool,
     font: &GameFont,
+    palette: &Palette,
 ) {
+    let (bg, text) = toggle_colors(palette, initial);
     parent
         .spawn(Node {
             flex_direction: FlexDirection::Row,
@@ -1307,7 +1743,7 @@ fn spawn_toggle_row(
                     border: UiRect::all(Val::Px(1.0)),
                     ..default()
                 },
-                BackgroundColor(if initial { on_bg() } else { off_bg() }),
+                BackgroundColor(bg),
                 BorderColor::all(Color::srgb(0.5, 0.5, 0.5)),
                 toggle,
             ))
@@ -1319,7 +1755,7 @@ fn spawn_toggle_row(
                         font_size: 10.0,
                         ..default()
                     },
-                    TextColor(if initial { on_text() } else { off_text() }),
+                    TextColor(text),
                 ));
             });
 
@@ -1350,10 +1786,16 @@ pub fn hud_score_display_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn hud_mode_display_system(
+    config: Res<PhysicsConfig>,
     selected_mode: Option<Res<SelectedGameMode>>,
     campaign: Option<Res<CampaignSession>>,
     wave: Option<Res<CampaignWaveDirector>>,
+    survival: Option<Res<SurvivalState>>,
+    script: Option<Res<CampaignScriptRunner>>,
+    field: Option<Res<FieldSizeClass>>,
+    time_control: Option<Res<TimeControl>>,
     q_boss: Query<&BossAttackState, With<Boss>>,
     mut text_query: Query<&mut Text, With<HudModeText>>,
 ) {
@@ -1361,13 +1803,73 @@ pub fn hud_mode_display_system(
         .as_ref()
         .map(|selected_mode| **selected_mode)
         .unwrap_or(SelectedGameMode::Practice);
+    let dialogue = script
+        .as_ref()
+        .and_then(|script| script.dialogue)
+        .filter(|_| mode == SelectedGameMode::Campaign);
+    let time_label = time_control
+        .as_ref()
+        .and_then(|control| control.hud_label(&config));
     for mut text in text_query.iter_mut() {
-        *text = Text::new(campaign_mode_text(
+        let mut line = campaign_mode_text(
             mode,
             campaign.as_deref(),
             wave.as_deref(),
+            survival.as_deref(),
+            &config,
             q_boss.iter().next(),
-        ));
+        );
+        if let Some(label) = &time_label {
+            line.push_str(" · ");
+            line.push_str(label);
+        }
+        if let Some((dialogue, _)) = dialogue {
+            line.push('\n');
+            line.push_str(dialogue);
+        }
+        if let Some((announcement, _)) = field.as_ref().and_then(|field| field.announcement) {
+            line.push('\n');
+            line.push_str(announcement);
+        }
+        *text = Text::new(line);
+    }
+}
+
+/// Session clock text: the clock, plus the latest splits in speedrun mode.
+pub fn session_clock_text(secs: f32, splits: &[Split], speedrun: bool) -> String {
+    let mut text = format_clock(secs);
+    if speedrun {
+        let shown = splits.len().saturating_sub(HUD_SPLITS_SHOWN);
+        for split in &splits[shown..] {
+            text.push_str(&format!(
+                "\n{}  {}",
+                split.label,
+                format_clock(split.at_secs)
+            ));
+        }
+    }
+    text
+}
+
+/// Refresh the session clock under the score.
+pub fn hud_clock_display_system(
+    overlay: Res<OverlayState>,
+    playtime: Res<RunPlaytime>,
+    splits: Res<SpeedrunSplits>,
+    mut q_clock: Query<(&mut Text, &mut Node), With<HudClockText>>,
+) {
+    let shown = overlay.show_session_clock || overlay.speedrun_mode;
+    for (mut text, mut node) in q_clock.iter_mut() {
+        let display = if shown { Display::Flex } else { Display::None };
+        if node.display != display {
+            node.display = display;
+        }
+        if shown {
+            let body = session_clock_text(playtime.secs, &splits.splits, overlay.speedrun_mode);
+            if text.0 != body {
+                text.0 = body;
+            }
+        }
     }
 }
 
@@ -1383,7 +1885,7 @@ pub fn sync_stats_overlay_visibility_system(
     if !overlay.is_changed() {
         return;
     }
-    let vis = if overlay.show_stats {
+    let vis = if overlay.debug_text_shown(overlay.show_stats) {
         Visibility::Visible
     } else {
         Visibility::Hidden
@@ -1401,7 +1903,7 @@ pub fn sync_physics_inspector_visibility_system(
     if !overlay.is_changed() {
         return;
     }
-    let vis = if overlay.show_physics_inspector {
+    let vis = if overlay.debug_text_shown(overlay.show_physics_inspector) {
         Visibility::Visible
     } else {
         Visibility::Hidden
@@ -1419,7 +1921,7 @@ pub fn sync_profiler_visibility_system(
     if !overlay.is_changed() {
         return;
     }
-    let vis = if overlay.show_profiler {
+    let vis = if overlay.debug_text_shown(overlay.show_profiler) {
         Visibility::Visible
     } else {
         Visibility::Hidden
@@ -1432,22 +1934,58 @@ pub fn sync_profiler_visibility_system(
 // ── Update: stats text ────────────────────────────────────────────────────────
 
 /// Refresh the stats text content each frame.
+#[allow(clippy::too_many_arguments)]
 pub fn stats_display_system(
     stats: Res<SimulationStats>,
+    lod: Res<AsteroidLodStats>,
+    particles: Res<ParticlePool>,
+    config: Res<PhysicsConfig>,
     score: Res<PlayerScore>,
+    scoring: Res<ScoringConfig>,
+    governor: Res<FrameTimeGovernor>,
+    clusters: Res<ClusterStats>,
     mut text_query: Query<&mut Text, With<StatsOverlayText>>,
 ) {
+    // Heaviest three clusters: label, members, mass, and growth rate.
+    let mut top_clusters = String::new();
+    for cluster in clusters.clusters.iter().take(3) {
+        top_clusters.push_str(&format!(
+            " | #{} n={} m={} ({:+.1}/s)",
+            cluster.label, cluster.members, cluster.mass, cluster.growth_per_sec
+        ));
+    }
     for mut text in text_query.iter_mut() {
         *text = Text::new(format!(
-            "Live: {} | Culled: {} | Merged: {} | Split: {} | Destroyed: {}\nScoreDBG: hits={} destroyed={} combo={} streak={}",
+            "Live: {} | Parked: {} ({} total) | Merged: {} (deferred {}) | Split: {} | Destroyed: {}\nLOD: full={} simplified={} hidden={} | KD: reinserted={} rebuilds={}\nParticles: {}/{} pooled={} peak={} new={} reused={} evicted={} dropped={}\nQuality: {} (frame {:.1}/{:.1} ms, gravity r={:.0})\nClusters: {}{}\nScoreDBG: hits={} destroyed={} combo={} streak={}",
             stats.live_count,
+            stats.stored_count,
             stats.culled_total,
             stats.merged_total,
+            stats.formation_deferred,
             stats.split_total,
             stats.destroyed_total,
+            lod.full,
+            lod.simplified,
+            lod.hidden,
+            stats.spatial_reinserted,
+            stats.spatial_rebuilds_total,
+            particles.stats.live,
+            governor.effective_particle_budget(&config),
+            particles.stats.pooled,
+            particles.stats.peak_live,
+            particles.stats.spawned_total,
+            particles.stats.reused_total,
+            particles.stats.evicted_total,
+            particles.stats.dropped_total,
+            governor.tier.label(),
+            governor.smoothed_frame_ms,
+            config.frame_governor_target_ms,
+            governor.effective_max_gravity_dist(&config),
+            clusters.clusters.len(),
+            top_clusters,
             score.hits,
             score.destroyed,
-            score.multiplier(),
+            scoring.multiplier(score.streak),
             score.streak,
         ));
     }
@@ -1534,6 +2072,8 @@ pub fn profiler_display_system(
     overlay: Res<OverlayState>,
     profiler: Res<ProfilerStats>,
     diagnostics: Res<DiagnosticsStore>,
+    config: Res<PhysicsConfig>,
+    governor: Res<FrameTimeGovernor>,
     mut text_query: Query<&mut Text, With<ProfilerText>>,
 ) {
     if !overlay.show_profiler {
@@ -1550,13 +2090,21 @@ pub fn profiler_display_system(
         .unwrap_or(0.0);
 
     let display = format!(
-        "Profiler\nFrame: {frame_ms:.2} ms ({fps:.1} FPS)\n\nECS/Update\n  Group1(Input+Core): {g1:.2} ms\n  Group2A(Mesh+Camera): {g2a:.2} ms\n  Group2B(Overlay+Player): {g2b:.2} ms\n  Update Total: {ut:.2} ms\n\nPhysics\n  FixedUpdate: {fx:.2} ms\n  PostUpdate: {po:.2} ms",
+        "Profiler\nFrame: {frame_ms:.2} ms ({fps:.1} FPS)\n\nECS/Update\n  Group1(Input+Core): {g1:.2} ms\n  Group2A(Mesh+Camera): {g2a:.2} ms\n  Group2B(Overlay+Player): {g2b:.2} ms\n  Update Total: {ut:.2} ms\n\nPhysics\n  FixedUpdate: {fx:.2} ms\n  PostUpdate: {po:.2} ms\n\nGovernor\n  Level {lvl} ({tier}): {smoothed:.1}/{budget:.1} ms\n  Particles: {particles}  LOD x{lod:.2}\n  Gravity r={radius:.0}  eps={eps:.3}",
         g1 = profiler.update_group1_ms,
         g2a = profiler.update_group2a_ms,
         g2b = profiler.update_group2b_ms,
         ut = profiler.update_total_ms,
         fx = profiler.fixed_update_ms,
         po = profiler.post_update_ms,
+        lvl = governor.tier.level(),
+        tier = governor.tier.label(),
+        smoothed = governor.smoothed_frame_ms,
+        budget = config.frame_governor_target_ms,
+        particles = governor.effective_particle_budget(&config),
+        lod = governor.effective_lod_distance_scale(&config),
+        radius = governor.effective_max_gravity_dist(&config),
+        eps = governor.effective_gravity_force_epsilon(&config),
     );
 
     for mut text in text_query.iter_mut() {
@@ -1582,6 +2130,7 @@ pub fn debug_panel_button_system(
         (Changed<Interaction>, With<Button>),
     >,
     mut text_query: Query<(&mut Text, &mut TextColor)>,
+    palette: Res<Palette>,
 ) {
     for (interaction, &toggle, children, mut bg) in btn_query.iter_mut() {
         if *interaction != Interaction::Pressed {
@@ -1590,12 +2139,13 @@ pub fn debug_panel_button_system(
         toggle.toggle(&mut overlay);
         let active = toggle.get(&overlay);
 
-        *bg = BackgroundColor(if active { on_bg() } else { off_bg() });
+        let (bg_color, text_color) = toggle_colors(&palette, active);
+        *bg = BackgroundColor(bg_color);
 
         for child in children.iter() {
             if let Ok((mut text, mut color)) = text_query.get_mut(child) {
                 *text = Text::new(if active { "ON" } else { "OFF" });
-                *color = TextColor(if active { on_text() } else { off_text() });
+                *color = TextColor(text_color);
             }
         }
     }
@@ -1610,6 +2160,7 @@ pub fn sync_debug_line_layers_system(
     stats: Res<SimulationStats>,
     config: Res<PhysicsConfig>,
     grid: Res<SpatialGrid>,
+    clusters: Res<ClusterStats>,
     overlay: Res<OverlayState>,
     mut meshes: ResMut<Assets<Mesh>>,
     mut debug_layers: ParamSet<(
@@ -1617,6 +2168,7 @@ pub fn sync_debug_line_layers_system(
         Query<(&Mesh2d, &mut Visibility), With<ForceVectorLayer>>,
         Query<(&Mesh2d, &mut Visibility), With<VelocityArrowLayer>>,
         Query<(&Mesh2d, &mut Visibility), With<SpatialGridLayer>>,
+        Query<(&Mesh2d, &mut Visibility), With<ClusterOutlineLayer>>,
     )>,
     mut scratch: Local<DebugLineScratch>,
 ) {
@@ -1625,8 +2177,9 @@ pub fn sync_debug_line_layers_system(
         overlay.show_force_vectors && stats.live_count < config.force_vector_hide_threshold;
     let show_velocity = overlay.show_velocity_arrows;
     let show_grid = overlay.show_debug_grid;
+    let show_clusters = overlay.show_cluster_outlines;
 
-    if !show_wire && !show_force && !show_velocity && !show_grid {
+    if !show_wire && !show_force && !show_velocity && !show_grid && !show_clusters {
         if let Ok((_, mut vis)) = debug_layers.p0().single_mut() {
             *vis = Visibility::Hidden;
         }
@@ -1639,6 +2192,9 @@ pub fn sync_debug_line_layers_system(
         if let Ok((_, mut vis)) = debug_layers.p3().single_mut() {
             *vis = Visibility::Hidden;
         }
+        if let Ok((_, mut vis)) = debug_layers.p4().single_mut() {
+            *vis = Visibility::Hidden;
+        }
         return;
     }
 
@@ -1702,6 +2258,18 @@ pub fn sync_debug_line_layers_system(
         grid.collect_debug_split_lines(min, max, &mut scratch.grid);
     }
 
+    if show_clusters {
+        scratch.clusters.clear();
+        for cluster in &clusters.clusters {
+            let n = cluster.hull.len();
+            for i in 0..n {
+                scratch
+                    .clusters
+                    .push((cluster.hull[i], cluster.hull[(i + 1) % n]));
+            }
+        }
+    }
+
     if let Ok((mesh_handle, mut vis)) = debug_layers.p0().single_mut() {
         *vis = if show_wire {
             Visibility::Visible
@@ -1753,4 +2321,17 @@ pub fn sync_debug_line_layers_system(
             }
         }
     }
+
+    if let Ok((mesh_handle, mut vis)) = debug_layers.p4().single_mut() {
+        *vis = if show_clusters {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+        if show_clusters {
+            if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+                *mesh = line_segments_mesh(&scratch.clusters, 0.5);
+            }
+        }
+    }
 }
@@ -0,0 +1,253 @@
+//! Gravity heatmap overlay ("Gravity Heatmap" in the debug panel).
+//!
+//! The camera view is divided into square cells of [`HEATMAP_CELL_PX`]
+//! screen pixels.  At each cell centre the overlay sums the acceleration from
+//! every body the [`SpatialGrid`] returns within the governor's current
+//! gravity range, the same neighbourhood `nbody_gravity_system` uses, so the
+//! picture matches what the simulation actually applies.  Cells are coloured
+//! from transparent (no pull) through blue and yellow to red (strong pull)
+//! by [`heat_color`] and drawn as one vertex-coloured retained mesh.
+//!
+//! The arrow grid in [`super::physics_layers`] shows the direction of the
+//! same field; the heatmap shows where it is strong, which is where clusters
+//! gather.
+
+use super::OverlayState;
+use crate::asteroid::{Asteroid, AsteroidSize};
+use crate::config::PhysicsConfig;
+use crate::simulation::governor::FrameTimeGovernor;
+use crate::spatial_partition::SpatialGrid;
+use bevy::prelude::*;
+use bevy_asset::RenderAssetUsages;
+use bevy_mesh::{Indices, PrimitiveTopology};
+
+/// Screen-space size of one heatmap cell (px).
+pub const HEATMAP_CELL_PX: f32 = 32.0;
+
+/// Most cells sampled per frame; the cell size grows to stay under it.
+const HEATMAP_MAX_CELLS: usize = 4096;
+
+/// Field strength drawn at the middle of the colour ramp (u/s²).
+const HEATMAP_HALF_ACCEL: f32 = 12.0;
+
+/// Marker for the retained gravity-heatmap mesh.
+#[derive(Component)]
+pub struct GravityHeatmapLayer;
+
+#[derive(Default)]
+pub struct HeatmapScratch {
+    neighbors: Vec<Entity>,
+    cells: Vec<(Vec2, [f32; 4])>,
+}
+
+/// Net gravitational acceleration at `point` from the bodies within
+/// `max_dist` in `grid`, looked up with `body` as `(position, mass)`.
+///
+/// `gravity_scale` is `G · asteroid_density`; bodies closer than
+/// `min_dist` are skipped, like contact range in the simulation.
+pub fn sample_gravity(
+    point: Vec2,
+    grid: &SpatialGrid,
+    body: impl Fn(Entity) -> Option<(Vec2, f32)>,
+    gravity_scale: f32,
+    min_dist: f32,
+    max_dist: f32,
+    neighbors: &mut Vec<Entity>,
+) -> Vec2 {
+    grid.within_radius(point, max_dist, neighbors);
+    let min_dist_sq = min_dist * min_dist;
+    let mut accel = Vec2::ZERO;
+    for &entity in neighbors.iter() {
+        let Some((position, mass)) = body(entity) else {
+            continue;
+        };
+        let delta = position - point;
+        let dist_sq = delta.length_squared();
+        if dist_sq < min_dist_sq {
+            continue;
+        }
+        accel += delta / dist_sq.sqrt() * gravity_scale * mass / dist_sq;
+    }
+    accel
+}
+
+/// Heatmap colour for a field of `strength` (u/s²): a transparent → blue →
+/// yellow → red ramp that saturates for the strongest wells.
+pub fn heat_color(strength: f32) -> [f32; 4] {
+    let strength = strength.max(0.0);
+    let t = strength / (strength + HEATMAP_HALF_ACCEL);
+    let (r, g, b) = if t < 0.5 {
+        let k = t / 0.5;
+        (k, k, 1.0 - k)
+    } else {
+        (1.0, 1.0 - (t - 0.5) / 0.5, 0.0)
+    };
+    [r, g, b, 0.5 * t.sqrt()]
+}
+
+/// Vertex-coloured quads of side `cell` centred on each of `cells`.
+fn heatmap_mesh(cells: &[(Vec2, [f32; 4])], cell: f32) -> Mesh {
+    let half = cell * 0.5;
+    let mut positions: Vec<[f32; 3]> = Vec::with_capacity(cells.len() * 4);
+    let mut colors: Vec<[f32; 4]> = Vec::with_capacity(cells.len() * 4);
+    let mut indices: Vec<u32> = Vec::with_capacity(cells.len() * 6);
+    for &(center, color) in cells {
+        let base = positions.len() as u32;
+        for corner in [
+            Vec2::new(-half, -half),
+            Vec2::new(half, -half),
+            Vec2::new(half, half),
+            Vec2::new(-half, half),
+        ] {
+            let p = center + corner;
+            positions.push([p.x, p.y, 0.0]);
+            colors.push(color);
+        }
+        indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    let normals: Vec<[f32; 3]> = vec![[0.0, 0.0, 1.0]; positions.len()];
+    let uvs: Vec<[f32; 2]> = vec![[0.0, 0.0]; positions.len()];
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::RENDER_WORLD,
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_COLOR, colors);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
+/// Resample and redraw the heatmap while `show_gravity_heatmap` is on.
+#[allow(clippy::too_many_arguments)]
+pub fn sync_gravity_heatmap_system(
+    overlay: Res<OverlayState>,
+    config: Res<PhysicsConfig>,
+    governor: Res<FrameTimeGovernor>,
+    grid: Res<SpatialGrid>,
+    windows: Query<&Window>,
+    q_camera: Query<&Transform, With<Camera>>,
+    q_bodies: Query<(&Transform, &AsteroidSize), With<Asteroid>>,
+    mut q_layer: Query<(&Mesh2d, &mut Visibility), With<GravityHeatmapLayer>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut scratch: Local<HeatmapScratch>,
+) {
+    let Ok((mesh_handle, mut vis)) = q_layer.single_mut() else {
+        return;
+    };
+    if !overlay.show_gravity_heatmap {
+        if *vis != Visibility::Hidden {
+            *vis = Visibility::Hidden;
+        }
+        return;
+    }
+    *vis = Visibility::Visible;
+    let (Ok(window), Ok(camera)) = (windows.single(), q_camera.single()) else {
+        return;
+    };
+
+    let zoom = camera.scale.x;
+    let view = Vec2::new(window.width(), window.height()) * zoom;
+    let mut cell = HEATMAP_CELL_PX * zoom;
+    let cells_needed = (view.x / cell).ceil() * (view.y / cell).ceil();
+    if cells_needed > HEATMAP_MAX_CELLS as f32 {
+        cell *= (cells_needed / HEATMAP_MAX_CELLS as f32).sqrt();
+    }
+    let center = camera.translation.truncate();
+    // Snap to world multiples of the cell size so the map does not shimmer
+    // while the camera pans.
+    let first = ((center - view * 0.5) / cell).floor() * cell + Vec2::splat(cell * 0.5);
+
+    let gravity_scale = config.gravity_const * config.asteroid_density;
+    let max_dist = governor.effective_max_gravity_dist(&config);
+    let body = |entity| {
+        q_bodies
+            .get(entity)
+            .ok()
+            .map(|(transform, size)| (transform.translation.truncate(), size.0 as f32))
+    };
+
+    let scratch = &mut *scratch;
+    scratch.cells.clear();
+    let mut y = first.y;
+    while y <= center.y + view.y * 0.5 + cell * 0.5 {
+        let mut x = first.x;
+        while x <= center.x + view.x * 0.5 + cell * 0.5 {
+            let point = Vec2::new(x, y);
+            let accel = sample_gravity(
+                point,
+                &grid,
+                body,
+                gravity_scale,
+                config.min_gravity_dist,
+                max_dist,
+                &mut scratch.neighbors,
+            );
+            let color = heat_color(accel.length());
+            if color[3] > 0.01 {
+                scratch.cells.push((point, color));
+            }
+            x += cell;
+        }
+        y += cell;
+    }
+
+    if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+        *mesh = heatmap_mesh(&scratch.cells, cell);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn samples_only_bodies_in_gravity_range() {
+        let mut grid = SpatialGrid::default();
+        let near = Entity::from_raw_u32(1).unwrap();
+        let far = Entity::from_raw_u32(2).unwrap();
+        grid.insert(near, Vec2::new(10.0, 0.0));
+        grid.insert(far, Vec2::new(0.0, 500.0));
+        grid.build();
+        let body = |entity| {
+            if entity == near {
+                Some((Vec2::new(10.0, 0.0), 50.0))
+            } else if entity == far {
+                Some((Vec2::new(0.0, 500.0), 1.0e6))
+            } else {
+                None
+            }
+        };
+
+        let mut neighbors = Vec::new();
+        let accel = sample_gravity(Vec2::ZERO, &grid, body, 2.0, 1.0, 100.0, &mut neighbors);
+        assert!((accel - Vec2::new(1.0, 0.0)).length() < 1e-5, "{accel}");
+        let inside = sample_gravity(
+            Vec2::new(9.5, 0.0),
+            &grid,
+            body,
+            2.0,
+            1.0,
+            100.0,
+            &mut neighbors,
+        );
+        assert_eq!(inside, Vec2::ZERO);
+    }
+
+    #[test]
+    fn heat_ramps_from_clear_to_red() {
+        assert_eq!(heat_color(0.0)[3], 0.0);
+        let weak = heat_color(HEATMAP_HALF_ACCEL * 0.2);
+        let mid = heat_color(HEATMAP_HALF_ACCEL);
+        let strong = heat_color(HEATMAP_HALF_ACCEL * 100.0);
+        assert!(weak[2] > weak[0], "weak fields are blue");
+        assert_eq!(mid, [1.0, 1.0, 0.0, 0.5 * 0.5f32.sqrt()]);
+        assert!(
+            strong[0] > 0.99 && strong[1] < 0.05,
+            "strong fields are red"
+        );
+        assert!(weak[3] < mid[3] && mid[3] < strong[3]);
+    }
+}
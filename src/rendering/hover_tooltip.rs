@@ -0,0 +1,236 @@
+//! Hover tooltip: mass, size and composition of the asteroid under the cursor.
+//!
+//! [`hover_tooltip_system`] converts the cursor to world space and asks the
+//! [`SpatialGrid`] for bodies within `hover_tooltip_pick_radius`, then picks
+//! among those few with the inspector's [`pick_asteroid`] rule.  The tooltip
+//! appears next to the cursor once the same body has been hovered for
+//! `hover_tooltip_delay_secs` of real time, so it stays out of the way while
+//! the mouse is sweeping to aim.
+//!
+//! Off by default; `OverlayState::show_hover_tooltip` is toggled from the
+//! Settings screen and persisted in the player profile.
+
+use super::inspector::{cursor_world_position, pick_asteroid};
+use super::OverlayState;
+use crate::asteroid::{Asteroid, AsteroidSize, Planet, Vertices};
+use crate::config::PhysicsConfig;
+use crate::graphics::GameFont;
+use crate::mining::AsteroidMaterial;
+use crate::spatial_partition::SpatialGrid;
+use bevy::prelude::*;
+
+/// Extra pick radius (screen pixels) around each hull.
+const HOVER_PADDING_PX: f32 = 4.0;
+
+/// Tooltip offset from the cursor (logical px), down and to the right.
+const TOOLTIP_OFFSET_PX: Vec2 = Vec2::new(16.0, 16.0);
+
+/// The body under the cursor and how long it has been there.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct HoverTooltipState {
+    pub entity: Option<Entity>,
+    /// Real seconds the cursor has stayed over `entity`.
+    pub hover_secs: f32,
+}
+
+/// Marker for the tooltip root node.
+#[derive(Component)]
+pub struct HoverTooltip;
+
+/// Marker for the tooltip text.
+#[derive(Component)]
+pub struct HoverTooltipText;
+
+/// Tooltip body for a hovered asteroid.
+pub fn hover_tooltip_text(
+    mass: u32,
+    radius: f32,
+    material: AsteroidMaterial,
+    is_planet: bool,
+) -> String {
+    format!(
+        "{}\nmass {}  ·  radius {:.0} u\n{} ({} ore)",
+        if is_planet { "Planet" } else { "Asteroid" },
+        mass,
+        radius,
+        material.label(),
+        material.ore_kind().label(),
+    )
+}
+
+/// Startup: spawn the tooltip (hidden until something is hovered).
+pub fn setup_hover_tooltip(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    font: Res<GameFont>,
+) {
+    commands
+        .spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                padding: UiRect::axes(Val::Px(6.0), Val::Px(4.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.85)),
+            ZIndex(150),
+            HoverTooltip,
+            Visibility::Hidden,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: (config.stats_font_size - 6.0).max(10.0),
+                    ..default()
+                },
+                TextColor(Color::srgb(0.85, 0.92, 1.0)),
+                HoverTooltipText,
+            ));
+        });
+}
+
+/// Pick the hovered body through the spatial index and show its tooltip
+/// after the hover delay.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn hover_tooltip_system(
+    time: Res<Time<Real>>,
+    overlay: Res<OverlayState>,
+    config: Res<PhysicsConfig>,
+    grid: Res<SpatialGrid>,
+    windows: Query<&Window>,
+    q_camera: Query<&Transform, With<Camera>>,
+    q_asteroids: Query<
+        (
+            &Transform,
+            &Vertices,
+            &AsteroidSize,
+            Option<&AsteroidMaterial>,
+            Has<Planet>,
+        ),
+        With<Asteroid>,
+    >,
+    mut state: ResMut<HoverTooltipState>,
+    mut q_tooltip: Query<(&mut Node, &mut Visibility), With<HoverTooltip>>,
+    mut q_text: Query<&mut Text, With<HoverTooltipText>>,
+    mut nearby: Local<Vec<Entity>>,
+) {
+    let Ok((mut node, mut visibility)) = q_tooltip.single_mut() else {
+        return;
+    };
+    let target = windows
+        .single()
+        .ok()
+        .zip(q_camera.single().ok())
+        .and_then(|(window, camera)| Some((window, camera, window.cursor_position()?)))
+        .filter(|_| overlay.show_hover_tooltip);
+    let Some((window, camera, cursor)) = target else {
+        *state = HoverTooltipState::default();
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+
+    let zoom = camera.scale.x;
+    let world = cursor_world_position(window, camera, cursor);
+    grid.within_radius(world, config.hover_tooltip_pick_radius, &mut nearby);
+    let hull_radius =
+        |vertices: &Vertices| vertices.0.iter().map(|v| v.length()).fold(0.0, f32::max);
+    let picked = pick_asteroid(
+        world,
+        nearby.iter().filter_map(|&entity| {
+            q_asteroids
+                .get(entity)
+                .ok()
+                .map(|(transform, vertices, ..)| {
+                    (
+                        entity,
+                        transform.translation.truncate(),
+                        hull_radius(vertices),
+                    )
+                })
+        }),
+        HOVER_PADDING_PX * zoom,
+    );
+
+    if picked != state.entity {
+        state.entity = picked;
+        state.hover_secs = 0.0;
+    } else {
+        state.hover_secs += time.delta_secs();
+    }
+
+    let shown = picked
+        .filter(|_| state.hover_secs >= config.hover_tooltip_delay_secs)
+        .and_then(|entity| q_asteroids.get(entity).ok());
+    let Some((_, vertices, size, material, is_planet)) = shown else {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    };
+
+    let position = cursor + TOOLTIP_OFFSET_PX;
+    node.left = Val::Px(position.x);
+    node.top = Val::Px(position.y);
+    visibility.set_if_neq(Visibility::Visible);
+    if let Ok(mut text) = q_text.single_mut() {
+        let body = hover_tooltip_text(
+            size.0,
+            hull_radius(vertices),
+            material.copied().unwrap_or_default(),
+            is_planet,
+        );
+        if text.0 != body {
+            text.0 = body;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tooltip_lists_mass_size_and_composition() {
+        let text = hover_tooltip_text(42, 17.6, AsteroidMaterial::Metal, false);
+
+        assert!(text.starts_with("Asteroid\n"));
+        assert!(text.contains("mass 42"));
+        assert!(text.contains("radius 18 u"));
+        assert!(text.contains("Metal (RARE ore)"));
+        assert!(hover_tooltip_text(900, 80.0, AsteroidMaterial::Rock, true).starts_with("Planet"));
+    }
+
+    #[test]
+    fn spatial_index_candidates_feed_the_hull_pick() {
+        let small = Entity::from_raw_u32(1).unwrap();
+        let large = Entity::from_raw_u32(2).unwrap();
+        let far = Entity::from_raw_u32(3).unwrap();
+        let bodies = [
+            (small, Vec2::new(30.0, 0.0), 8.0),
+            (large, Vec2::new(0.0, 0.0), 40.0),
+            (far, Vec2::new(900.0, 0.0), 500.0),
+        ];
+        let mut grid = SpatialGrid::default();
+        for (entity, pos, _) in bodies {
+            grid.insert(entity, pos);
+        }
+        grid.build();
+
+        let mut nearby = Vec::new();
+        let cursor = Vec2::new(28.0, 0.0);
+        grid.within_radius(cursor, 160.0, &mut nearby);
+        assert!(
+            !nearby.contains(&far),
+            "bodies outside the radius are never considered"
+        );
+
+        let pick = |cursor| {
+            pick_asteroid(
+                cursor,
+                bodies.iter().copied().filter(|b| nearby.contains(&b.0)),
+                HOVER_PADDING_PX,
+            )
+        };
+        assert_eq!(pick(cursor), Some(small));
+        assert_eq!(pick(Vec2::new(-20.0, 0.0)), Some(large));
+    }
+}
@@ -0,0 +1,951 @@
+//! Window-size-aware anchoring for the HUD widgets.
+//!
+//! Every HUD root carries a [`HudAnchored`] tag naming its [`HudWidget`].
+//! How a widget is placed comes from its [`HudWidgetSpec`] in the
+//! [`HudRegistry`]: an anchor for the corner layout and one for the edge
+//! layout, whether it is a shortenable debug overlay, which modes show it,
+//! and its preferred size.  Widgets join the layout by registering a spec
+//! with [`HudAppExt::register_hud_widget`] and spawning their root with
+//! `HudAnchored`; nothing here needs editing.  The built-in widgets define
+//! their specs next to their `setup_*` functions and are registered by
+//! [`crate::rendering::register_builtin_hud_widgets`].
+//!
+//! The boxes come from [`compute_hud_layout`], not from hard-coded offsets:
+//!
+//! - Widgets are laid out inside a **safe area**: the window minus
+//!   `hud_margin_px`, narrowed to at most `hud_max_aspect_ratio` and centred.
+//!   On ultrawide monitors the HUD therefore stays near the player's focus
+//!   instead of in the far corners.
+//! - The safe area is split into columns.  The corner layout has a left and
+//!   a right half; the edge layout ([`HudLayoutMode::edges`]) adds a centre
+//!   column, and each column is a third of the width.  Widgets are capped to
+//!   their column's width (text wraps), so columns cannot overlap.
+//! - Within a column, the bottom widgets are placed first at their full
+//!   preferred height.  The top widgets then stack downwards,
+//!   `hud_stack_gap_px` apart, in the remaining space.  A stack entry that
+//!   runs out of room is shortened (and clipped), never pushed off-screen or
+//!   under another widget.
+//! - In streamer mode ([`HudLayoutMode`]) the debug overlays get no room, the
+//!   score grows by `streamer_hud_scale`, the live stats panel joins the
+//!   top-left stack, and the webcam corner (bottom-right) can be kept empty.
+//!
+//! [`hud_anchor_system`] applies the layout whenever the window size, the
+//! layout mode or the registry changes.
+//! The tests run the same layout pass at several window sizes, including
+//! ultrawide and portrait, and check that nothing overlaps or leaves the
+//! window.  One of them also runs Bevy's UI layout over the spawned HUD and
+//! checks the computed nodes the same way.
+
+use super::OverlayState;
+use crate::config::PhysicsConfig;
+use bevy::prelude::*;
+use bevy::ui::{UiTransform, Val2};
+use bevy::window::PrimaryWindow;
+
+/// Where in the safe area a widget hangs: a corner, or the middle of the top
+/// or bottom edge.
+///
+/// The corner layout has no centre column; `Top` and `Bottom` fall back to
+/// the left column there.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HudAnchor {
+    TopLeft,
+    Top,
+    TopRight,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl HudAnchor {
+    fn column(self, mode: HudLayoutMode) -> HudColumn {
+        match self {
+            Self::TopLeft | Self::BottomLeft => HudColumn::Left,
+            Self::Top | Self::Bottom if mode.edges => HudColumn::Centre,
+            Self::Top | Self::Bottom => HudColumn::Left,
+            Self::TopRight | Self::BottomRight => HudColumn::Right,
+        }
+    }
+
+    fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::Top | Self::TopRight)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum HudColumn {
+    Left,
+    Centre,
+    Right,
+}
+
+impl HudColumn {
+    const ALL: [HudColumn; 3] = [HudColumn::Left, HudColumn::Centre, HudColumn::Right];
+}
+
+/// Identifies a HUD widget.  The built-in widgets are associated constants;
+/// a new widget picks its own name.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HudWidget(pub &'static str);
+
+impl HudWidget {
+    pub const SCORE: HudWidget = HudWidget("score");
+    pub const LIVES: HudWidget = HudWidget("lives");
+    pub const STREAMER_STATS: HudWidget = HudWidget("streamer_stats");
+    pub const STATS: HudWidget = HudWidget("stats");
+    pub const PHYSICS_INSPECTOR: HudWidget = HudWidget("physics_inspector");
+    pub const PROFILER: HudWidget = HudWidget("profiler");
+    pub const ORE: HudWidget = HudWidget("ore");
+    pub const DEBUG_PANEL: HudWidget = HudWidget("debug_panel");
+    pub const ASTEROID_INSPECTOR: HudWidget = HudWidget("asteroid_inspector");
+}
+
+/// Which layout modes show a widget.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HudVisibility {
+    Always,
+    /// Hidden in streamer mode (debug text, asteroid inspector).
+    OutsideStreamer,
+    /// Only in streamer mode (live stats panel).
+    StreamerOnly,
+}
+
+/// How one widget takes part in the HUD layout.
+#[derive(Debug, Clone, Copy)]
+pub struct HudWidgetSpec {
+    pub widget: HudWidget,
+    /// Anchor in the corner layout.
+    pub corner: HudAnchor,
+    /// Anchor in the edge layout.
+    pub edge: HudAnchor,
+    /// Debug overlays (hidden by default) may be shortened on small windows;
+    /// the gameplay HUD never is.
+    pub overlay: bool,
+    pub visibility: HudVisibility,
+    /// Largest box the widget's content needs, in logical pixels.  The node
+    /// is capped to this box, so it is also the box the layout tests reason
+    /// about.
+    pub size: fn(&PhysicsConfig, HudLayoutMode) -> Vec2,
+}
+
+impl HudWidgetSpec {
+    /// A gameplay widget shown in every mode, at `anchor` in both layouts.
+    pub fn new(
+        widget: HudWidget,
+        anchor: HudAnchor,
+        size: fn(&PhysicsConfig, HudLayoutMode) -> Vec2,
+    ) -> Self {
+        Self {
+            widget,
+            corner: anchor,
+            edge: anchor,
+            overlay: false,
+            visibility: HudVisibility::Always,
+            size,
+        }
+    }
+
+    /// Use `anchor` in the edge layout instead.
+    pub fn at_edge(mut self, anchor: HudAnchor) -> Self {
+        self.edge = anchor;
+        self
+    }
+
+    /// Mark as a debug overlay that may be shortened.
+    pub fn overlay(mut self) -> Self {
+        self.overlay = true;
+        self
+    }
+
+    pub fn visible(mut self, visibility: HudVisibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    pub fn anchor(&self, mode: HudLayoutMode) -> HudAnchor {
+        if mode.edges {
+            self.edge
+        } else {
+            self.corner
+        }
+    }
+
+    pub fn shown_in(&self, mode: HudLayoutMode) -> bool {
+        match self.visibility {
+            HudVisibility::Always => true,
+            HudVisibility::OutsideStreamer => !mode.streamer,
+            HudVisibility::StreamerOnly => mode.streamer,
+        }
+    }
+
+    pub fn preferred_size(&self, config: &PhysicsConfig, mode: HudLayoutMode) -> Vec2 {
+        (self.size)(config, mode)
+    }
+}
+
+/// Height of one text line at `font_size`, for widgets' preferred sizes.
+pub fn hud_line_height(font_size: f32) -> f32 {
+    font_size * 1.3
+}
+
+/// Every widget the layout pass places, in stacking order (top to bottom
+/// within each column).
+#[derive(Resource, Debug, Clone, Default)]
+pub struct HudRegistry {
+    specs: Vec<HudWidgetSpec>,
+}
+
+impl HudRegistry {
+    /// Add `spec` at the end of its stack, or replace the spec already
+    /// registered for the same widget in place.
+    pub fn register(&mut self, spec: HudWidgetSpec) {
+        match self.specs.iter_mut().find(|s| s.widget == spec.widget) {
+            Some(existing) => *existing = spec,
+            None => self.specs.push(spec),
+        }
+    }
+
+    pub fn get(&self, widget: HudWidget) -> Option<&HudWidgetSpec> {
+        self.specs.iter().find(|s| s.widget == widget)
+    }
+
+    pub fn specs(&self) -> &[HudWidgetSpec] {
+        &self.specs
+    }
+}
+
+/// Declarative HUD widget registration on the [`App`].
+pub trait HudAppExt {
+    /// Add a widget to the HUD layout; its root must carry
+    /// `HudAnchored(spec.widget)`.
+    fn register_hud_widget(&mut self, spec: HudWidgetSpec) -> &mut Self;
+}
+
+impl HudAppExt for App {
+    fn register_hud_widget(&mut self, spec: HudWidgetSpec) -> &mut Self {
+        self.init_resource::<HudRegistry>();
+        self.world_mut()
+            .resource_mut::<HudRegistry>()
+            .register(spec);
+        self
+    }
+}
+
+/// What the HUD is laid out for, from the HUD settings in [`OverlayState`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct HudLayoutMode {
+    /// Hide the debug overlays, enlarge the score and show the live stats panel.
+    pub streamer: bool,
+    /// Keep the bottom-right corner empty for a webcam (streamer mode only).
+    pub webcam_corner: bool,
+    /// Use each widget's edge anchor and a three-column layout.
+    pub edges: bool,
+}
+
+impl HudLayoutMode {
+    pub fn from_overlay(overlay: &OverlayState) -> Self {
+        Self {
+            streamer: overlay.streamer_mode,
+            webcam_corner: overlay.streamer_mode && overlay.webcam_corner,
+            edges: overlay.hud_edges,
+        }
+    }
+
+    /// Font scale of the score and mode line.
+    pub fn score_scale(self, config: &PhysicsConfig) -> f32 {
+        if self.streamer {
+            config.streamer_hud_scale.max(1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+/// Tags a UI root whose placement is driven by [`hud_anchor_system`].
+#[derive(Component, Debug, Clone, Copy)]
+pub struct HudAnchored(pub HudWidget);
+
+impl HudAnchored {
+    /// Base node for an anchored root; position and size caps are filled in
+    /// by [`hud_anchor_system`] before the first layout.
+    pub fn node() -> Node {
+        Node {
+            position_type: PositionType::Absolute,
+            overflow: Overflow::clip(),
+            ..default()
+        }
+    }
+}
+
+/// Centred region the HUD may occupy, in window coordinates (y down).
+pub fn hud_safe_area(window: Vec2, config: &PhysicsConfig) -> Rect {
+    let max_width = window.y * config.hud_max_aspect_ratio.max(1.0);
+    let width = window.x.min(max_width);
+    let left = (window.x - width) * 0.5;
+    let margin = config.hud_margin_px.max(0.0);
+    Rect::new(
+        left + margin,
+        margin,
+        left + width - margin,
+        window.y - margin,
+    )
+}
+
+/// Horizontal span `(min, max)` of `column` within `safe`.
+fn column_span(safe: Rect, gap: f32, mode: HudLayoutMode, column: HudColumn) -> (f32, f32) {
+    let columns = if mode.edges { 3.0 } else { 2.0 };
+    let width = ((safe.width() - gap * (columns - 1.0)) / columns).max(0.0);
+    let centre = safe.center().x;
+    match column {
+        HudColumn::Left => (safe.min.x, safe.min.x + width),
+        HudColumn::Centre => (centre - width * 0.5, centre + width * 0.5),
+        HudColumn::Right => (safe.max.x - width, safe.max.x),
+    }
+}
+
+/// Box kept empty for a webcam in the bottom-right of the safe area, capped
+/// to the right column and half the safe height.
+pub fn webcam_corner_rect(window: Vec2, config: &PhysicsConfig, mode: HudLayoutMode) -> Rect {
+    let safe = hud_safe_area(window, config);
+    let (x0, x1) = column_span(
+        safe,
+        config.hud_stack_gap_px.max(0.0),
+        mode,
+        HudColumn::Right,
+    );
+    let width = config.streamer_webcam_width_px.clamp(0.0, x1 - x0);
+    let height = config
+        .streamer_webcam_height_px
+        .min(safe.height() * 0.5)
+        .max(0.0);
+    Rect::new(
+        safe.max.x - width,
+        safe.max.y - height,
+        safe.max.x,
+        safe.max.y,
+    )
+}
+
+/// Box assigned to every registered widget for a window of logical size
+/// `window`.
+///
+/// A widget shortened to nothing, or not shown in `mode`, gets a zero-height
+/// rect.
+pub fn compute_hud_layout(
+    window: Vec2,
+    config: &PhysicsConfig,
+    mode: HudLayoutMode,
+    registry: &HudRegistry,
+) -> Vec<(HudWidget, Rect)> {
+    let safe = hud_safe_area(window, config);
+    let gap = config.hud_stack_gap_px.max(0.0);
+    let mut layout = Vec::with_capacity(registry.specs().len());
+
+    for spec in registry.specs().iter().filter(|s| !s.shown_in(mode)) {
+        let anchor = spec.anchor(mode);
+        let (x0, x1) = column_span(safe, gap, mode, anchor.column(mode));
+        let x = match anchor.column(mode) {
+            HudColumn::Left => x0,
+            HudColumn::Centre => (x0 + x1) * 0.5,
+            HudColumn::Right => x1,
+        };
+        let y = if anchor.is_top() {
+            safe.min.y
+        } else {
+            safe.max.y
+        };
+        layout.push((spec.widget, Rect::new(x, y, x, y)));
+    }
+
+    for column in HudColumn::ALL {
+        let stack: Vec<&HudWidgetSpec> = registry
+            .specs()
+            .iter()
+            .filter(|s| s.shown_in(mode) && s.anchor(mode).column(mode) == column)
+            .collect();
+        let (col_min, col_max) = column_span(safe, gap, mode, column);
+        let column_width = col_max - col_min;
+        let place_x = |width: f32| match column {
+            HudColumn::Left => (col_min, col_min + width),
+            HudColumn::Centre => {
+                let centre = (col_min + col_max) * 0.5;
+                (centre - width * 0.5, centre + width * 0.5)
+            }
+            HudColumn::Right => (col_max - width, col_max),
+        };
+
+        // Bottom widgets first, at full height; the webcam corner comes first
+        // on the right.
+        let mut floor = safe.max.y;
+        if column == HudColumn::Right && mode.webcam_corner {
+            floor -= webcam_corner_rect(window, config, mode).height() + gap;
+        }
+        for spec in stack.iter().filter(|s| !s.anchor(mode).is_top()) {
+            let size = spec.preferred_size(config, mode);
+            let width = size.x.min(column_width);
+            let height = size.y.min(safe.height());
+            let (x0, x1) = place_x(width);
+            layout.push((spec.widget, Rect::new(x0, floor - height, x1, floor)));
+            floor -= height + gap;
+        }
+
+        // Top stack in the remaining space.
+        let mut cursor = safe.min.y;
+        for spec in stack.iter().filter(|s| s.anchor(mode).is_top()) {
+            let size = spec.preferred_size(config, mode);
+            let width = size.x.min(column_width);
+            let height = size.y.min((floor - cursor).max(0.0));
+            let (x0, x1) = place_x(width);
+            layout.push((spec.widget, Rect::new(x0, cursor, x1, cursor + height)));
+            if height > 0.0 {
+                cursor += height + gap;
+            }
+        }
+    }
+    layout
+}
+
+/// Re-anchor [`HudAnchored`] nodes when the window, the config, the layout
+/// mode or the registry changes.
+///
+/// Corner widgets are pinned by their outer edges.  Centre widgets are pinned
+/// by the middle of their box and shifted back by half their own width, so
+/// content narrower than the box stays centred.
+#[allow(clippy::too_many_arguments)]
+pub fn hud_anchor_system(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    config: Res<PhysicsConfig>,
+    overlay: Res<OverlayState>,
+    registry: Res<HudRegistry>,
+    ui_scale: Res<UiScale>,
+    mut last: Local<(Vec2, HudLayoutMode)>,
+    mut q_nodes: Query<(&HudAnchored, &mut Node, &mut UiTransform)>,
+    q_added: Query<(), Added<HudAnchored>>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let scale = ui_scale.0.max(f32::EPSILON);
+    let size = Vec2::new(window.width(), window.height()) / scale;
+    let mode = HudLayoutMode::from_overlay(&overlay);
+    if (size, mode) == *last && !config.is_changed() && !registry.is_changed() && q_added.is_empty()
+    {
+        return;
+    }
+    *last = (size, mode);
+
+    let layout = compute_hud_layout(size, &config, mode, &registry);
+    for (anchored, mut node, mut transform) in q_nodes.iter_mut() {
+        let (Some(&(_, rect)), Some(spec)) = (
+            layout.iter().find(|(w, _)| *w == anchored.0),
+            registry.get(anchored.0),
+        ) else {
+            continue;
+        };
+        let anchor = spec.anchor(mode);
+        let column = anchor.column(mode);
+        let mut placed = node.clone();
+        placed.left = Val::Auto;
+        placed.right = Val::Auto;
+        placed.top = Val::Auto;
+        placed.bottom = Val::Auto;
+        match column {
+            HudColumn::Left => placed.left = Val::Px(rect.min.x),
+            HudColumn::Centre => placed.left = Val::Px(rect.center().x),
+            HudColumn::Right => placed.right = Val::Px(size.x - rect.max.x),
+        }
+        if anchor.is_top() {
+            placed.top = Val::Px(rect.min.y);
+        } else {
+            placed.bottom = Val::Px(size.y - rect.max.y);
+        }
+        placed.max_width = Val::Px(rect.width());
+        placed.max_height = Val::Px(rect.height());
+        if *node != placed {
+            *node = placed;
+        }
+        let shift = if column == HudColumn::Centre {
+            Val2::percent(-50.0, 0.0)
+        } else {
+            Val2::ZERO
+        };
+        if transform.translation != shift {
+            transform.translation = shift;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WINDOW_SIZES: [(f32, f32); 8] = [
+        (800.0, 600.0),
+        (1024.0, 768.0),
+        (1280.0, 720.0),
+        (1920.0, 1080.0),
+        (2560.0, 1080.0),
+        (3440.0, 1440.0),
+        (5120.0, 1440.0),
+        (1080.0, 1920.0),
+    ];
+
+    const MODES: [HudLayoutMode; 6] = [
+        HudLayoutMode {
+            streamer: false,
+            webcam_corner: false,
+            edges: false,
+        },
+        HudLayoutMode {
+            streamer: true,
+            webcam_corner: false,
+            edges: false,
+        },
+        HudLayoutMode {
+            streamer: true,
+            webcam_corner: true,
+            edges: false,
+        },
+        HudLayoutMode {
+            streamer: false,
+            webcam_corner: false,
+            edges: true,
+        },
+        HudLayoutMode {
+            streamer: true,
+            webcam_corner: false,
+            edges: true,
+        },
+        HudLayoutMode {
+            streamer: true,
+            webcam_corner: true,
+            edges: true,
+        },
+    ];
+
+    /// A square widget in the top-centre / top-right, as a minimap would be.
+    fn minimap_spec() -> HudWidgetSpec {
+        HudWidgetSpec::new(HudWidget("minimap"), HudAnchor::TopRight, |_, _| {
+            Vec2::splat(180.0)
+        })
+        .at_edge(HudAnchor::Top)
+    }
+
+    /// The registry the game builds, through the same registration calls.
+    fn builtin_registry() -> HudRegistry {
+        let mut app = App::new();
+        crate::rendering::register_builtin_hud_widgets(&mut app);
+        app.world().resource::<HudRegistry>().clone()
+    }
+
+    fn overlaps(a: Rect, b: Rect) -> bool {
+        a.min.x < b.max.x && b.min.x < a.max.x && a.min.y < b.max.y && b.min.y < a.max.y
+    }
+
+    #[test]
+    fn widgets_stay_on_screen_and_never_overlap() {
+        let config = PhysicsConfig::default();
+        let mut registry = builtin_registry();
+        registry.register(minimap_spec());
+        for (w, h) in WINDOW_SIZES {
+            for mode in MODES {
+                let window = Vec2::new(w, h);
+                let layout = compute_hud_layout(window, &config, mode, &registry);
+                assert_eq!(layout.len(), registry.specs().len());
+                for &(widget, rect) in &layout {
+                    assert!(
+                        rect.min.x >= 0.0
+                            && rect.min.y >= 0.0
+                            && rect.max.x <= w
+                            && rect.max.y <= h,
+                        "{widget:?} off-screen at {w}x{h} ({mode:?}): {rect:?}"
+                    );
+                }
+                for (i, &(a, ra)) in layout.iter().enumerate() {
+                    for &(b, rb) in &layout[i + 1..] {
+                        assert!(
+                            !overlaps(ra, rb),
+                            "{a:?} overlaps {b:?} at {w}x{h} ({mode:?}): {ra:?} / {rb:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn gameplay_hud_is_never_shortened() {
+        let config = PhysicsConfig::default();
+        let registry = builtin_registry();
+        for (w, h) in WINDOW_SIZES {
+            for mode in MODES {
+                for (widget, rect) in compute_hud_layout(Vec2::new(w, h), &config, mode, &registry)
+                {
+                    let spec = registry.get(widget).unwrap();
+                    if !spec.overlay && spec.shown_in(mode) {
+                        assert!(
+                            (rect.height() - spec.preferred_size(&config, mode).y).abs() < 1e-3,
+                            "{widget:?} shortened at {w}x{h} ({mode:?})"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn streamer_mode_hides_debug_text_and_keeps_the_webcam_corner_empty() {
+        let config = PhysicsConfig::default();
+        let registry = builtin_registry();
+        let mode = HudLayoutMode {
+            streamer: true,
+            webcam_corner: true,
+            edges: false,
+        };
+        for (w, h) in WINDOW_SIZES {
+            let window = Vec2::new(w, h);
+            let webcam = webcam_corner_rect(window, &config, mode);
+            assert!(webcam.width() > 0.0 && webcam.height() > 0.0);
+            for (widget, rect) in compute_hud_layout(window, &config, mode, &registry) {
+                if !registry.get(widget).unwrap().shown_in(mode) {
+                    assert_eq!(rect.height(), 0.0, "{widget:?} has room in streamer mode");
+                }
+                assert!(
+                    !overlaps(rect, webcam),
+                    "{widget:?} intrudes on the webcam corner at {w}x{h}"
+                );
+            }
+        }
+
+        let score = registry.get(HudWidget::SCORE).unwrap();
+        let normal = score.preferred_size(&config, HudLayoutMode::default());
+        let streamer = score.preferred_size(&config, mode);
+        assert!(streamer.y > normal.y * 1.2);
+    }
+
+    #[test]
+    fn ultrawide_hud_is_pillarboxed_to_max_aspect() {
+        let config = PhysicsConfig::default();
+        let window = Vec2::new(5120.0, 1440.0);
+        let safe = hud_safe_area(window, &config);
+        let expected_width = 1440.0 * config.hud_max_aspect_ratio - 2.0 * config.hud_margin_px;
+        assert!((safe.width() - expected_width).abs() < 1e-3);
+        assert!((safe.center().x - window.x * 0.5).abs() < 1e-3);
+        for (widget, rect) in compute_hud_layout(
+            window,
+            &config,
+            HudLayoutMode::default(),
+            &builtin_registry(),
+        ) {
+            assert!(
+                rect.min.x >= safe.min.x && rect.max.x <= safe.max.x,
+                "{widget:?} outside the safe area"
+            );
+        }
+    }
+
+    #[test]
+    fn edge_layout_centres_the_gameplay_hud() {
+        let config = PhysicsConfig::default();
+        let mut registry = builtin_registry();
+        registry.register(minimap_spec());
+        let mode = HudLayoutMode {
+            edges: true,
+            ..default()
+        };
+        let window = Vec2::new(1920.0, 1080.0);
+        let centre = hud_safe_area(window, &config).center().x;
+        let layout = compute_hud_layout(window, &config, mode, &registry);
+        let rect = |widget| layout.iter().find(|(w, _)| *w == widget).unwrap().1;
+
+        for widget in [
+            HudWidget::SCORE,
+            HudWidget::LIVES,
+            HudWidget::ORE,
+            HudWidget("minimap"),
+        ] {
+            assert!(
+                (rect(widget).center().x - centre).abs() < 1e-3,
+                "{widget:?} is not centred"
+            );
+        }
+        // Stacked in registration order below the top edge; ore on the bottom.
+        assert!(rect(HudWidget::SCORE).max.y <= rect(HudWidget::LIVES).min.y);
+        assert!(rect(HudWidget::LIVES).max.y <= rect(HudWidget("minimap")).min.y);
+        assert!((rect(HudWidget::ORE).max.y - (window.y - config.hud_margin_px)).abs() < 1e-3);
+        // Overlays keep their corners.
+        assert!(rect(HudWidget::DEBUG_PANEL).max.x > rect(HudWidget::SCORE).max.x);
+    }
+
+    #[test]
+    fn registering_a_widget_twice_replaces_it() {
+        let mut registry = builtin_registry();
+        let count = registry.specs().len();
+        registry.register(minimap_spec());
+        registry.register(minimap_spec().at_edge(HudAnchor::Bottom));
+        assert_eq!(registry.specs().len(), count + 1);
+        assert_eq!(
+            registry.get(HudWidget("minimap")).unwrap().edge,
+            HudAnchor::Bottom
+        );
+    }
+
+    #[test]
+    fn anchor_system_places_spawned_hud_roots() {
+        for edges in [false, true] {
+            let mut app = App::new();
+            app.insert_resource(PhysicsConfig::default());
+            app.insert_resource(OverlayState {
+                hud_edges: edges,
+                ..default()
+            });
+            app.insert_resource(UiScale::default());
+            app.register_hud_widget(minimap_spec());
+            app.add_systems(Update, hud_anchor_system);
+
+            let mut window = Window::default();
+            window.resolution.set(1920.0, 1080.0);
+            app.world_mut().spawn((window, PrimaryWindow));
+            let registry = app.world().resource::<HudRegistry>().clone();
+            let roots: Vec<(HudWidget, Entity)> = registry
+                .specs()
+                .iter()
+                .map(|spec| {
+                    (
+                        spec.widget,
+                        app.world_mut()
+                            .spawn((HudAnchored::node(), HudAnchored(spec.widget)))
+                            .id(),
+                    )
+                })
+                .collect();
+            app.update();
+
+            let size = Vec2::new(1920.0, 1080.0);
+            let mode = HudLayoutMode { edges, ..default() };
+            let layout = compute_hud_layout(size, &PhysicsConfig::default(), mode, &registry);
+            for (widget, entity) in roots {
+                let rect = layout.iter().find(|(w, _)| *w == widget).unwrap().1;
+                let node = app.world().get::<Node>(entity).unwrap();
+                let shift = app.world().get::<UiTransform>(entity).unwrap().translation;
+                assert_eq!(node.max_width, Val::Px(rect.width()));
+                assert_eq!(node.max_height, Val::Px(rect.height()));
+                let anchor = registry.get(widget).unwrap().anchor(mode);
+                match anchor {
+                    HudAnchor::TopLeft | HudAnchor::BottomLeft => {
+                        assert_eq!(node.left, Val::Px(rect.min.x));
+                    }
+                    HudAnchor::Top | HudAnchor::Bottom => {
+                        assert_eq!(node.left, Val::Px(rect.center().x));
+                        assert_eq!(shift, Val2::percent(-50.0, 0.0));
+                    }
+                    HudAnchor::TopRight | HudAnchor::BottomRight => {
+                        assert_eq!(node.right, Val::Px(size.x - rect.max.x));
+                    }
+                }
+                if anchor.is_top() {
+                    assert_eq!(node.top, Val::Px(rect.min.y));
+                } else {
+                    assert_eq!(node.bottom, Val::Px(size.y - rect.max.y));
+                }
+            }
+        }
+    }
+
+    /// Copies the primary window's size onto the camera, standing in for the
+    /// render crate's `camera_system` so the UI has a target to lay out on.
+    fn window_camera_target_system(
+        windows: Query<&Window, With<PrimaryWindow>>,
+        mut cameras: Query<&mut Camera>,
+    ) {
+        let Ok(window) = windows.single() else {
+            return;
+        };
+        for mut camera in cameras.iter_mut() {
+            camera.computed.target_info = Some(bevy::camera::RenderTargetInfo {
+                physical_size: window.physical_size(),
+                scale_factor: window.scale_factor(),
+            });
+        }
+    }
+
+    /// A headless app running Bevy's real text measurement and UI layout over
+    /// the game's HUD, spawned by its own setup systems with its own fonts.
+    fn hud_ui_app() -> App {
+        use crate::graphics::palette::Palette;
+        use crate::graphics::{EmojiFont, GameFont, SymbolFont, SymbolFont2, UnicodeFallbackFont};
+        use crate::rendering::{inspector, streamer};
+
+        let mut app = App::new();
+        app.add_plugins((
+            MinimalPlugins,
+            AssetPlugin::default(),
+            ImagePlugin::default(),
+            bevy::image::TextureAtlasPlugin,
+            bevy::input::InputPlugin,
+            bevy::picking::PickingPlugin,
+            bevy::picking::InteractionPlugin,
+            TransformPlugin,
+            bevy::text::TextPlugin,
+            bevy::ui::UiPlugin,
+        ));
+        app.add_systems(
+            PostUpdate,
+            window_camera_target_system.in_set(bevy::camera::CameraUpdateSystems),
+        );
+        app.insert_resource(PhysicsConfig::default())
+            .init_resource::<OverlayState>()
+            .init_resource::<Palette>();
+        crate::rendering::register_builtin_hud_widgets(&mut app);
+
+        let mut load = |file: &str| {
+            let path = format!("{}/assets/fonts/{file}", env!("CARGO_MANIFEST_DIR"));
+            let bytes = std::fs::read(&path).unwrap_or_else(|e| panic!("{path}: {e}"));
+            app.world_mut()
+                .resource_mut::<Assets<Font>>()
+                .add(Font::try_from_bytes(bytes).unwrap())
+        };
+        let game = load("Tektur-Regular.ttf");
+        let symbols = load("NotoSansSymbols-Regular.ttf");
+        let symbols_2 = load("NotoSansSymbols2-Regular.ttf");
+        let emoji = load("NotoEmoji-Regular.ttf");
+        let fallback = load("NotoSans-Regular.ttf");
+        app.insert_resource(GameFont(game))
+            .insert_resource(SymbolFont(symbols))
+            .insert_resource(SymbolFont2(symbols_2))
+            .insert_resource(EmojiFont(emoji))
+            .insert_resource(UnicodeFallbackFont(fallback));
+
+        app.add_systems(
+            Startup,
+            (
+                crate::rendering::setup_hud_score,
+                crate::rendering::setup_lives_hud,
+                crate::rendering::setup_ore_hud,
+                crate::rendering::setup_stats_text,
+                crate::rendering::setup_physics_inspector_text,
+                crate::rendering::setup_profiler_text,
+                crate::rendering::setup_debug_panel,
+                streamer::setup_streamer_stats,
+                inspector::setup_asteroid_inspector_text,
+            ),
+        );
+        app.add_systems(
+            Update,
+            (streamer::streamer_hud_style_system, hud_anchor_system).chain(),
+        );
+        app.world_mut().spawn((Window::default(), PrimaryWindow));
+        app.world_mut().spawn(Camera2d);
+        app
+    }
+
+    fn ui_rect(node: &ComputedNode, transform: &UiGlobalTransform) -> Rect {
+        Rect::from_center_size(transform.translation, node.size())
+    }
+
+    fn contains(outer: Rect, inner: Rect) -> bool {
+        const SLACK: f32 = 0.5;
+        inner.min.x >= outer.min.x - SLACK
+            && inner.min.y >= outer.min.y - SLACK
+            && inner.max.x <= outer.max.x + SLACK
+            && inner.max.y <= outer.max.y + SLACK
+    }
+
+    #[test]
+    fn laid_out_hud_fits_every_window() {
+        let mut app = hud_ui_app();
+        app.update();
+
+        // The longest mode line the game shows, between campaign waves.
+        let mut q_mode = app
+            .world_mut()
+            .query_filtered::<&mut Text, With<crate::rendering::HudModeText>>();
+        for mut text in q_mode.iter_mut(app.world_mut()) {
+            text.0 =
+                "MODE: CAMPAIGN · MISSION 12 · WAVE 10/10 · NEXT WAVE IN 30s · TAB AT BEACON: SHOP"
+                    .to_string();
+        }
+
+        let registry = app.world().resource::<HudRegistry>().clone();
+        for (w, h) in WINDOW_SIZES {
+            for mode in MODES {
+                {
+                    let world = app.world_mut();
+                    let mut window = world
+                        .query_filtered::<&mut Window, With<PrimaryWindow>>()
+                        .single_mut(world)
+                        .unwrap();
+                    window.resolution.set(w, h);
+                    let mut overlay = world.resource_mut::<OverlayState>();
+                    overlay.streamer_mode = mode.streamer;
+                    overlay.webcam_corner = mode.webcam_corner;
+                    overlay.hud_edges = mode.edges;
+                }
+                app.update();
+
+                let world = app.world_mut();
+                let roots: Vec<(HudWidget, Entity, Rect)> = world
+                    .query::<(Entity, &HudAnchored, &ComputedNode, &UiGlobalTransform)>()
+                    .iter(world)
+                    .map(|(entity, anchored, node, transform)| {
+                        (anchored.0, entity, ui_rect(node, transform))
+                    })
+                    .collect();
+                assert_eq!(roots.len(), registry.specs().len());
+
+                let screen = Rect::new(0.0, 0.0, w, h);
+                for &(widget, root, rect) in &roots {
+                    assert!(
+                        contains(screen, rect),
+                        "{widget:?} off-screen at {w}x{h} ({mode:?}): {rect:?}"
+                    );
+                    let spec = registry.get(widget).unwrap();
+                    if spec.overlay {
+                        // Overlays may be shortened; their root must clip then.
+                        let overflow = world.get::<Node>(root).unwrap().overflow;
+                        assert_eq!(overflow, Overflow::clip(), "{widget:?} does not clip");
+                        continue;
+                    }
+                    if !spec.shown_in(mode) {
+                        continue;
+                    }
+                    let mut q_children = world.query::<&Children>();
+                    let mut q_node = world.query::<(&ComputedNode, &UiGlobalTransform)>();
+                    let mut stack: Vec<Entity> = q_children
+                        .get(world, root)
+                        .map(|children| children.to_vec())
+                        .unwrap_or_default();
+                    while let Some(entity) = stack.pop() {
+                        if let Ok(children) = q_children.get(world, entity) {
+                            stack.extend(children.iter());
+                        }
+                        let Ok((node, transform)) = q_node.get(world, entity) else {
+                            continue;
+                        };
+                        if node.size() == Vec2::ZERO {
+                            continue;
+                        }
+                        let inner = ui_rect(node, transform);
+                        assert!(
+                            contains(rect, inner),
+                            "{widget:?} clips its content at {w}x{h} ({mode:?}): {inner:?} outside {rect:?}"
+                        );
+                    }
+                }
+                // Widgets the mode hides are not drawn, so only the rest count.
+                let shown: Vec<_> = roots
+                    .iter()
+                    .filter(|(widget, _, _)| registry.get(*widget).unwrap().shown_in(mode))
+                    .collect();
+                for (i, &&(a, _, ra)) in shown.iter().enumerate() {
+                    for &&(b, _, rb) in &shown[i + 1..] {
+                        assert!(
+                            !overlaps(ra, rb),
+                            "{a:?} overlaps {b:?} at {w}x{h} ({mode:?}): {ra:?} / {rb:?}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
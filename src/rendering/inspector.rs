@@ -0,0 +1,326 @@
+//! Click-to-select asteroid inspector.
+//!
+//! Middle-clicking an asteroid selects it.  Middle-clicking empty space clears
+//! the selection.  A selected body gets a bright outline on a retained
+//! [`SelectionHighlightLayer`] mesh, and a panel in the bottom-right corner
+//! shows its entity id, mass, velocity, vertex/crater/neighbour counts,
+//! cluster label, and merge history.  `F` toggles a follow camera that centres on the selection
+//! instead of the ship (see `camera_follow_system`).
+//!
+//! Left and right click stay bound to the weapons, so selection uses the
+//! middle button.
+
+use super::hud_layout::{
+    hud_line_height, HudAnchor, HudAnchored, HudVisibility, HudWidget, HudWidgetSpec,
+};
+use super::line_segments_mesh;
+use super::OverlayState;
+use crate::asteroid::{Asteroid, AsteroidSize, CraterData, NeighborCount, Planet, Vertices};
+use crate::config::PhysicsConfig;
+use crate::graphics::GameFont;
+use crate::lineage::Lineage;
+use crate::simulation::clusters::ClusterStats;
+use crate::spatial_partition::SpatialGrid;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+/// Extra pick radius (screen pixels) so small bodies stay clickable.
+const PICK_PADDING_PX: f32 = 6.0;
+
+/// Bodies nearest the click considered for selection.  A body can only be
+/// missed if this many centres are closer to the click than its own, which
+/// takes a dense ring hugging a planet.
+const PICK_CANDIDATES: usize = 16;
+
+/// The asteroid picked for inspection, if any.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct AsteroidSelection {
+    pub entity: Option<Entity>,
+    /// Centre the camera on the selection instead of the ship.
+    pub follow: bool,
+}
+
+/// Marker for the retained selection-outline mesh.
+#[derive(Component)]
+pub struct SelectionHighlightLayer;
+
+/// Marker for the inspector panel root node.
+#[derive(Component)]
+pub struct AsteroidInspectorDisplay;
+
+/// Marker for the inspector panel text.
+#[derive(Component)]
+pub struct AsteroidInspectorText;
+
+/// Pick the body under `click` from `(entity, centre, radius)` candidates.
+///
+/// A body is hit when the click lies within its radius plus `padding`; among
+/// several hits the one whose centre is closest relative to its size wins, so
+/// a small body in front of a large one can still be picked.
+pub fn pick_asteroid(
+    click: Vec2,
+    candidates: impl IntoIterator<Item = (Entity, Vec2, f32)>,
+    padding: f32,
+) -> Option<Entity> {
+    candidates
+        .into_iter()
+        .filter_map(|(entity, centre, radius)| {
+            let reach = radius + padding;
+            let dist = centre.distance(click);
+            (dist <= reach).then_some((entity, dist / reach.max(f32::EPSILON)))
+        })
+        .min_by(|a, b| a.1.total_cmp(&b.1))
+        .map(|(entity, _)| entity)
+}
+
+/// World position under the window-space `cursor` for the 2D camera at
+/// `camera`.
+pub fn cursor_world_position(window: &Window, camera: &Transform, cursor: Vec2) -> Vec2 {
+    let offset = Vec2::new(
+        cursor.x - window.width() / 2.0,
+        -(cursor.y - window.height() / 2.0), // flip Y: Bevy world +Y = screen up
+    );
+    camera.translation.truncate() + offset * camera.scale.x
+}
+
+/// Handle middle-click selection and the `F` follow toggle.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn asteroid_select_click_system(
+    mouse: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    windows: Query<&Window>,
+    q_camera: Query<&Transform, With<Camera>>,
+    grid: Res<SpatialGrid>,
+    q_asteroids: Query<(Entity, &Transform, &Vertices), With<Asteroid>>,
+    mut selection: ResMut<AsteroidSelection>,
+    mut nearest: Local<Vec<(Entity, f32)>>,
+) {
+    if selection.entity.is_some() && keys.just_pressed(KeyCode::KeyF) {
+        selection.follow = !selection.follow;
+    }
+    if !mouse.just_pressed(MouseButton::Middle) {
+        return;
+    }
+    let (Ok(window), Ok(camera)) = (windows.single(), q_camera.single()) else {
+        return;
+    };
+    let Some(cursor) = window.cursor_position() else {
+        return;
+    };
+
+    let zoom = camera.scale.x;
+    let click = cursor_world_position(window, camera, cursor);
+
+    grid.nearest_n(click, PICK_CANDIDATES, &mut nearest);
+    let picked = pick_asteroid(
+        click,
+        nearest
+            .iter()
+            .filter_map(|&(entity, _)| q_asteroids.get(entity).ok())
+            .map(|(entity, transform, vertices)| {
+                let radius = vertices.0.iter().map(|v| v.length()).fold(0.0, f32::max);
+                (entity, transform.translation.truncate(), radius)
+            }),
+        PICK_PADDING_PX * zoom,
+    );
+    if picked != selection.entity {
+        selection.follow = selection.follow && picked.is_some();
+        selection.entity = picked;
+    }
+}
+
+/// Asteroid inspector panel, bottom right; hidden in streamer mode.
+pub fn asteroid_inspector_hud_spec() -> HudWidgetSpec {
+    // Five text lines plus padding.
+    HudWidgetSpec::new(
+        HudWidget::ASTEROID_INSPECTOR,
+        HudAnchor::BottomRight,
+        |config, _| {
+            Vec2::new(
+                460.0,
+                hud_line_height((config.stats_font_size - 4.0).max(10.0)) * 5.0 + 16.0,
+            )
+        },
+    )
+    .visible(HudVisibility::OutsideStreamer)
+}
+
+/// Startup: spawn the inspector panel (hidden until something is selected).
+pub fn setup_asteroid_inspector_text(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    font: Res<GameFont>,
+) {
+    commands
+        .spawn((
+            Node {
+                padding: UiRect::all(Val::Px(8.0)),
+                ..HudAnchored::node()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.08, 0.8)),
+            HudAnchored(HudWidget::ASTEROID_INSPECTOR),
+            AsteroidInspectorDisplay,
+            Visibility::Hidden,
+        ))
+        .with_children(|parent| {
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: (config.stats_font_size - 4.0).max(10.0),
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.92, 0.55)),
+                AsteroidInspectorText,
+            ));
+        });
+}
+
+/// Refresh the inspector panel and highlight outline for the selection.
+///
+/// Clears the selection once the body is gone (merged, destroyed, culled).
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn asteroid_inspector_display_system(
+    mut selection: ResMut<AsteroidSelection>,
+    clusters: Res<ClusterStats>,
+    overlay: Res<OverlayState>,
+    q_asteroids: Query<
+        (
+            &Transform,
+            &AsteroidSize,
+            &Velocity,
+            &Vertices,
+            Option<&CraterData>,
+            Option<&NeighborCount>,
+            Option<&Lineage>,
+            Has<Planet>,
+        ),
+        With<Asteroid>,
+    >,
+    mut q_panel: Query<&mut Visibility, With<AsteroidInspectorDisplay>>,
+    mut q_text: Query<&mut Text, With<AsteroidInspectorText>>,
+    mut q_highlight: Query<
+        (&Mesh2d, &mut Visibility),
+        (
+            With<SelectionHighlightLayer>,
+            Without<AsteroidInspectorDisplay>,
+        ),
+    >,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let selected = selection
+        .entity
+        .and_then(|entity| q_asteroids.get(entity).ok().map(|data| (entity, data)));
+    if selected.is_none() && selection.entity.is_some() {
+        *selection = AsteroidSelection::default();
+    }
+    let vis = if selected.is_some() {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+    let panel = if overlay.debug_text_shown(selected.is_some()) {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+    for mut panel_vis in q_panel.iter_mut() {
+        panel_vis.set_if_neq(panel);
+    }
+    let Ok((mesh_handle, mut highlight_vis)) = q_highlight.single_mut() else {
+        return;
+    };
+    highlight_vis.set_if_neq(vis);
+
+    let Some((
+        entity,
+        (transform, size, velocity, vertices, craters, neighbors, lineage, is_planet),
+    )) = selected
+    else {
+        return;
+    };
+
+    let pos = transform.translation.truncate();
+    let rot = transform.rotation;
+    let world: Vec<Vec2> = vertices
+        .0
+        .iter()
+        .map(|v| pos + rot.mul_vec3(v.extend(0.0)).truncate())
+        .collect();
+    let n = world.len();
+    let outline: Vec<(Vec2, Vec2)> = (0..n).map(|i| (world[i], world[(i + 1) % n])).collect();
+    if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+        *mesh = line_segments_mesh(&outline, 0.6);
+    }
+
+    if let Ok(mut text) = q_text.single_mut() {
+        let cluster = clusters
+            .label_of(entity)
+            .map_or_else(|| "-".to_string(), |label| format!("#{label}"));
+        let history = lineage.map_or_else(
+            || "never merged".to_string(),
+            |lineage| {
+                format!(
+                    "{} merges of {} bodies, depth {}, first at {:.0}s",
+                    lineage.merge_count(),
+                    lineage.body_count(),
+                    lineage.depth(),
+                    lineage.first_merge_time()
+                )
+            },
+        );
+        **text = format!(
+            "{} id={}\nmass={}  vertices={}  craters={}\nvel=({:.1},{:.1}) |v|={:.1}  ω={:.2}\npos=({:.0},{:.0})  neighbours={}  cluster={}\nlineage: {}\n[F] follow: {}",
+            if is_planet { "Planet" } else { "Asteroid" },
+            entity.index(),
+            size.0,
+            vertices.0.len(),
+            craters.map_or(0, |c| c.craters.len()),
+            velocity.linvel.x,
+            velocity.linvel.y,
+            velocity.linvel.length(),
+            velocity.angvel,
+            pos.x,
+            pos.y,
+            neighbors.map_or(0, |n| n.0),
+            cluster,
+            history,
+            if selection.follow { "on" } else { "off" },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(i: u32) -> Entity {
+        Entity::from_raw_u32(i).unwrap()
+    }
+
+    #[test]
+    fn click_outside_every_body_picks_nothing() {
+        let bodies = [(entity(1), Vec2::ZERO, 10.0)];
+        assert_eq!(pick_asteroid(Vec2::new(20.0, 0.0), bodies, 2.0), None);
+        assert_eq!(
+            pick_asteroid(Vec2::new(11.0, 0.0), bodies, 2.0),
+            Some(entity(1))
+        );
+    }
+
+    #[test]
+    fn small_body_inside_large_one_wins_when_clicked_near_its_centre() {
+        let bodies = [
+            (entity(1), Vec2::ZERO, 50.0),
+            (entity(2), Vec2::new(20.0, 0.0), 4.0),
+        ];
+        assert_eq!(
+            pick_asteroid(Vec2::new(21.0, 0.0), bodies, 1.0),
+            Some(entity(2))
+        );
+        assert_eq!(
+            pick_asteroid(Vec2::new(-10.0, 0.0), bodies, 1.0),
+            Some(entity(1))
+        );
+    }
+}
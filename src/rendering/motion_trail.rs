@@ -0,0 +1,334 @@
+//! Motion trails ("MOTION TRAILS" on the Settings screen).
+//!
+//! The background is pure black, so nothing in view shows how fast the ship
+//! is moving.  This optional effect adds two cues:
+//!
+//! - **Ghost trail**: while the ship moves faster than
+//!   `motion_trail_min_speed`, its pose is recorded every
+//!   `motion_trail_interval_secs` into [`ShipTrail`].  Each recorded pose is
+//!   drawn as a translucent copy of the ship that fades out over
+//!   `motion_trail_ghosts × motion_trail_interval_secs`.
+//! - **Motion streaks**: the `motion_streak_max` fastest asteroids at or above
+//!   `motion_streak_min_speed` get a quad stretched behind them along their
+//!   velocity, `speed × motion_streak_secs` long.
+//!
+//! Both draw from fixed pools of `Mesh2d` entities spawned when play starts
+//! ([`setup_motion_trail_pool`]).  Ghosts share the ship mesh and streaks a
+//! unit quad; each pooled entity owns its material so its alpha can fade on
+//! its own.  Unused entities are hidden, never despawned, so the effect does
+//! not allocate while playing.  Pool sizes are read at setup, so changes to
+//! them apply from the next session.
+
+use super::OverlayState;
+use crate::asteroid::{Asteroid, Planet, Vertices};
+use crate::asteroid_rendering::filled_polygon_mesh;
+use crate::config::PhysicsConfig;
+use crate::player::rendering::ship_vertices;
+use crate::player::Player;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+use std::collections::VecDeque;
+
+/// Ghost tint (the ship outline's cyan).
+const GHOST_COLOR: (f32, f32, f32) = (0.2, 0.8, 1.0);
+
+/// Streak tint.
+const STREAK_COLOR: (f32, f32, f32) = (0.8, 0.85, 0.9);
+
+/// Pooled translucent copy of the ship; `0` is its slot, newest pose first.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MotionGhost(pub usize);
+
+/// Pooled motion-streak quad.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct MotionStreak;
+
+/// A recorded ship pose and its age in seconds.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrailPose {
+    pub position: Vec2,
+    pub rotation: Quat,
+    pub age: f32,
+}
+
+/// Recent ship poses, newest first.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct ShipTrail {
+    poses: VecDeque<TrailPose>,
+    since_sample: f32,
+}
+
+impl ShipTrail {
+    pub fn poses(&self) -> impl Iterator<Item = &TrailPose> {
+        self.poses.iter()
+    }
+
+    /// Seconds a pose stays on screen.
+    pub fn span(config: &PhysicsConfig) -> f32 {
+        config.motion_trail_ghosts as f32 * config.motion_trail_interval_secs.max(1e-3)
+    }
+
+    /// Age every pose by `dt`, drop expired ones and, if the ship is moving
+    /// fast enough and an interval has passed, record `ship`.
+    pub fn advance(&mut self, dt: f32, ship: Option<(Vec2, Quat, f32)>, config: &PhysicsConfig) {
+        let span = Self::span(config);
+        for pose in &mut self.poses {
+            pose.age += dt;
+        }
+        while self.poses.back().is_some_and(|pose| pose.age >= span) {
+            self.poses.pop_back();
+        }
+
+        self.since_sample += dt;
+        let Some((position, rotation, speed)) = ship else {
+            return;
+        };
+        if speed < config.motion_trail_min_speed
+            || self.since_sample < config.motion_trail_interval_secs
+        {
+            return;
+        }
+        self.since_sample = 0.0;
+        self.poses.push_front(TrailPose {
+            position,
+            rotation,
+            age: 0.0,
+        });
+        self.poses.truncate(config.motion_trail_ghosts as usize);
+    }
+
+    /// Alpha of a ghost whose pose is `age` seconds old.
+    pub fn ghost_alpha(age: f32, config: &PhysicsConfig) -> f32 {
+        config.motion_trail_alpha * (1.0 - age / Self::span(config)).clamp(0.0, 1.0)
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Move every recorded pose by `delta` (world-origin recentering).
+    pub fn shift(&mut self, delta: Vec2) {
+        for pose in &mut self.poses {
+            pose.position += delta;
+        }
+    }
+}
+
+/// Transform and alpha of the streak behind a body of hull `radius` at `pos`
+/// moving at `velocity`, or `None` below `motion_streak_min_speed`.
+///
+/// The quad is `radius` wide and runs back from the body's centre, so the
+/// body itself covers its front end.
+pub fn streak_placement(
+    pos: Vec2,
+    velocity: Vec2,
+    radius: f32,
+    config: &PhysicsConfig,
+) -> Option<(Transform, f32)> {
+    let speed = velocity.length();
+    if speed < config.motion_streak_min_speed || speed <= 0.0 {
+        return None;
+    }
+    let dir = velocity / speed;
+    let length = speed * config.motion_streak_secs;
+    let centre = pos - dir * (length * 0.5);
+    let alpha = config.motion_streak_alpha
+        * (speed / config.motion_streak_min_speed.max(1e-3) - 1.0).clamp(0.0, 1.0);
+    Some((
+        Transform {
+            translation: centre.extend(-0.05),
+            rotation: Quat::from_rotation_z(dir.to_angle()),
+            scale: Vec3::new(length, radius.max(0.5), 1.0),
+        },
+        alpha,
+    ))
+}
+
+/// Spawn the hidden ghost and streak pools.
+pub fn setup_motion_trail_pool(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    config: Res<PhysicsConfig>,
+    mut trail: ResMut<ShipTrail>,
+) {
+    trail.clear();
+    let ship = meshes.add(filled_polygon_mesh(&ship_vertices()));
+    let quad = meshes.add(Rectangle::new(1.0, 1.0));
+    let (r, g, b) = GHOST_COLOR;
+    for slot in 0..config.motion_trail_ghosts as usize {
+        commands.spawn((
+            Mesh2d(ship.clone()),
+            MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgba(r, g, b, 0.0)))),
+            Transform::from_translation(Vec3::new(0.0, 0.0, -0.1)),
+            Visibility::Hidden,
+            MotionGhost(slot),
+        ));
+    }
+    let (r, g, b) = STREAK_COLOR;
+    for _ in 0..config.motion_streak_max {
+        commands.spawn((
+            Mesh2d(quad.clone()),
+            MeshMaterial2d(materials.add(ColorMaterial::from_color(Color::srgba(r, g, b, 0.0)))),
+            Transform::default(),
+            Visibility::Hidden,
+            MotionStreak,
+        ));
+    }
+}
+
+/// Record the ship's pose and place the pooled ghosts and streaks.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn motion_trail_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    overlay: Res<OverlayState>,
+    mut trail: ResMut<ShipTrail>,
+    q_player: Query<(&Transform, &Velocity), With<Player>>,
+    q_asteroids: Query<(&Transform, &Velocity, &Vertices), (With<Asteroid>, Without<Planet>)>,
+    mut q_ghosts: Query<
+        (
+            &MotionGhost,
+            &mut Transform,
+            &mut Visibility,
+            &MeshMaterial2d<ColorMaterial>,
+        ),
+        (Without<Player>, Without<Asteroid>, Without<MotionStreak>),
+    >,
+    mut q_streaks: Query<
+        (
+            &mut Transform,
+            &mut Visibility,
+            &MeshMaterial2d<ColorMaterial>,
+        ),
+        (
+            With<MotionStreak>,
+            Without<Player>,
+            Without<Asteroid>,
+            Without<MotionGhost>,
+        ),
+    >,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+) {
+    let enabled = overlay.show_motion_trails && !overlay.wireframe_only;
+    if !enabled {
+        trail.clear();
+        for (_, _, mut vis, _) in q_ghosts.iter_mut() {
+            vis.set_if_neq(Visibility::Hidden);
+        }
+        for (_, mut vis, _) in q_streaks.iter_mut() {
+            vis.set_if_neq(Visibility::Hidden);
+        }
+        return;
+    }
+
+    let ship = q_player.single().ok().map(|(transform, velocity)| {
+        (
+            transform.translation.truncate(),
+            transform.rotation,
+            velocity.linvel.length(),
+        )
+    });
+    trail.advance(time.delta_secs(), ship, &config);
+
+    let poses: Vec<TrailPose> = trail.poses().copied().collect();
+    for (ghost, mut transform, mut vis, material) in q_ghosts.iter_mut() {
+        let Some(pose) = poses.get(ghost.0) else {
+            vis.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        transform.translation = pose.position.extend(-0.1);
+        transform.rotation = pose.rotation;
+        if let Some(mat) = materials.get_mut(&material.0) {
+            let (r, g, b) = GHOST_COLOR;
+            mat.color = Color::srgba(r, g, b, ShipTrail::ghost_alpha(pose.age, &config));
+        }
+        vis.set_if_neq(Visibility::Visible);
+    }
+
+    let mut fast: Vec<(f32, Vec2, Vec2, f32)> = q_asteroids
+        .iter()
+        .filter_map(|(transform, velocity, vertices)| {
+            let speed = velocity.linvel.length();
+            (speed >= config.motion_streak_min_speed).then(|| {
+                let radius = vertices.0.iter().map(|v| v.length()).fold(0.0, f32::max);
+                (
+                    speed,
+                    transform.translation.truncate(),
+                    velocity.linvel,
+                    radius,
+                )
+            })
+        })
+        .collect();
+    fast.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+
+    let mut placements = fast
+        .into_iter()
+        .filter_map(|(_, pos, velocity, radius)| streak_placement(pos, velocity, radius, &config));
+    for (mut transform, mut vis, material) in q_streaks.iter_mut() {
+        let Some((placed, alpha)) = placements.next() else {
+            vis.set_if_neq(Visibility::Hidden);
+            continue;
+        };
+        *transform = placed;
+        if let Some(mat) = materials.get_mut(&material.0) {
+            let (r, g, b) = STREAK_COLOR;
+            mat.color = Color::srgba(r, g, b, alpha);
+        }
+        vis.set_if_neq(Visibility::Visible);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn the_trail_records_fast_poses_and_fades_them_out() {
+        let config = PhysicsConfig::default();
+        let dt = config.motion_trail_interval_secs;
+        let mut trail = ShipTrail::default();
+
+        // Too slow: nothing is recorded.
+        trail.advance(dt, Some((Vec2::ZERO, Quat::IDENTITY, 1.0)), &config);
+        assert_eq!(trail.poses().count(), 0);
+
+        let fast = config.motion_trail_min_speed * 2.0;
+        for step in 0..config.motion_trail_ghosts * 2 {
+            let pos = Vec2::new(step as f32, 0.0);
+            trail.advance(dt, Some((pos, Quat::IDENTITY, fast)), &config);
+        }
+        let poses: Vec<TrailPose> = trail.poses().copied().collect();
+        assert_eq!(poses.len(), config.motion_trail_ghosts as usize);
+        assert!(poses.windows(2).all(|w| w[0].age < w[1].age));
+        assert_eq!(
+            ShipTrail::ghost_alpha(poses[0].age, &config),
+            config.motion_trail_alpha
+        );
+
+        // Once the ship stops, every ghost expires within one span.
+        let span = ShipTrail::span(&config);
+        trail.advance(span, None, &config);
+        assert_eq!(trail.poses().count(), 0);
+    }
+
+    #[test]
+    fn streaks_trail_behind_fast_bodies_only() {
+        let config = PhysicsConfig::default();
+        let slow = Vec2::X * config.motion_streak_min_speed * 0.5;
+        assert!(streak_placement(Vec2::ZERO, slow, 5.0, &config).is_none());
+
+        let velocity = Vec2::Y * config.motion_streak_min_speed * 3.0;
+        let (transform, alpha) =
+            streak_placement(Vec2::new(10.0, 10.0), velocity, 5.0, &config).unwrap();
+        let length = velocity.length() * config.motion_streak_secs;
+        assert!((transform.scale.x - length).abs() < 1e-4);
+        assert!(
+            transform.translation.y < 10.0,
+            "the streak sits behind the body"
+        );
+        assert!((transform.translation.x - 10.0).abs() < 1e-4);
+        assert!((alpha - config.motion_streak_alpha).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,292 @@
+//! Physics debug layers: gravity field, collider outlines and neighbor counts.
+//!
+//! Three retained line meshes toggled from the debug panel alongside the
+//! velocity arrows and spatial grid:
+//!
+//! - **Gravity field** (`show_gravity_field`): an arrow grid over the camera
+//!   view, one arrow every [`GRAVITY_FIELD_SPACING_PX`] screen pixels,
+//!   pointing along the acceleration a free body would feel there
+//!   ([`field_acceleration`]).  Like the trajectory overlay it only sums the
+//!   `trajectory_max_sources` heaviest bodies.  Arrow length saturates, so
+//!   strong wells do not swamp the grid.
+//! - **Collider outlines** (`show_collider_outlines`): each asteroid's
+//!   `BaseVertices` hull, the shape its Rapier collider is built from, to
+//!   compare with the cratered render mesh.
+//! - **Neighbor counts** (`show_neighbor_counts`): one radial tick per
+//!   neighbor in the asteroid's `NeighborCount`, up to
+//!   [`NEIGHBOR_TICK_CAP`], around its hull.
+
+use super::trajectory::GravitySource;
+use super::{line_segments_mesh, OverlayState};
+use crate::asteroid::{Asteroid, AsteroidSize, BaseVertices, NeighborCount, Vertices};
+use crate::config::PhysicsConfig;
+use crate::simulation::governor::FrameTimeGovernor;
+use bevy::prelude::*;
+
+/// Screen-space distance between gravity field samples (px).
+pub const GRAVITY_FIELD_SPACING_PX: f32 = 64.0;
+
+/// Field strength drawn at half the full arrow length (u/s²).
+const GRAVITY_FIELD_HALF_ACCEL: f32 = 20.0;
+
+/// Most ticks drawn around one asteroid.
+pub const NEIGHBOR_TICK_CAP: usize = 16;
+
+/// Marker for the retained gravity-field arrow mesh.
+#[derive(Component)]
+pub struct GravityFieldLayer;
+
+/// Marker for the retained collider-outline mesh.
+#[derive(Component)]
+pub struct ColliderOutlineLayer;
+
+/// Marker for the retained neighbor-count tick mesh.
+#[derive(Component)]
+pub struct NeighborCountLayer;
+
+#[derive(Default)]
+pub struct PhysicsLayerScratch {
+    sources: Vec<GravitySource>,
+    field: Vec<(Vec2, Vec2)>,
+    colliders: Vec<(Vec2, Vec2)>,
+    neighbors: Vec<(Vec2, Vec2)>,
+}
+
+/// Gravitational acceleration at `point` from `sources`, with the
+/// simulation's contact and range cut-offs.
+///
+/// `gravity_scale` is `G · asteroid_density`, as in the trajectory overlay.
+pub fn field_acceleration(
+    point: Vec2,
+    sources: &[GravitySource],
+    gravity_scale: f32,
+    min_dist_sq: f32,
+    max_dist_sq: f32,
+) -> Vec2 {
+    let mut accel = Vec2::ZERO;
+    for source in sources {
+        let delta = source.position - point;
+        let dist_sq = delta.length_squared();
+        if dist_sq < min_dist_sq || dist_sq > max_dist_sq {
+            continue;
+        }
+        accel += delta / dist_sq.sqrt() * gravity_scale * source.mass / dist_sq;
+    }
+    accel
+}
+
+/// Arrow for a field sample at `point`: length eases towards `max_len` as the
+/// field strengthens, with a two-stroke head.
+pub fn field_arrow(point: Vec2, accel: Vec2, max_len: f32, out: &mut Vec<(Vec2, Vec2)>) {
+    let strength = accel.length();
+    if strength <= f32::EPSILON {
+        return;
+    }
+    let dir = accel / strength;
+    let tip = point + dir * max_len * strength / (strength + GRAVITY_FIELD_HALF_ACCEL);
+    out.push((point, tip));
+    let head = (tip - point).length() * 0.3;
+    let perp = dir.perp();
+    out.push((tip, tip - dir * head + perp * head * 0.5));
+    out.push((tip, tip - dir * head - perp * head * 0.5));
+}
+
+/// Radial ticks just outside `radius` around `center`, one per neighbor up
+/// to [`NEIGHBOR_TICK_CAP`].
+pub fn neighbor_ticks(center: Vec2, radius: f32, count: usize, out: &mut Vec<(Vec2, Vec2)>) {
+    let ticks = count.min(NEIGHBOR_TICK_CAP);
+    for i in 0..ticks {
+        let dir = Vec2::from_angle(std::f32::consts::TAU * i as f32 / ticks as f32);
+        out.push((center + dir * (radius + 1.0), center + dir * (radius + 3.0)));
+    }
+}
+
+/// Rebuild the gravity-field, collider-outline and neighbor-count meshes
+/// while their toggles are on.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn sync_physics_debug_layers_system(
+    overlay: Res<OverlayState>,
+    config: Res<PhysicsConfig>,
+    governor: Res<FrameTimeGovernor>,
+    windows: Query<&Window>,
+    q_camera: Query<&Transform, With<Camera>>,
+    q_asteroids: Query<
+        (
+            &Transform,
+            &AsteroidSize,
+            &Vertices,
+            Option<&BaseVertices>,
+            Option<&NeighborCount>,
+        ),
+        With<Asteroid>,
+    >,
+    mut layers: ParamSet<(
+        Query<(&Mesh2d, &mut Visibility), With<GravityFieldLayer>>,
+        Query<(&Mesh2d, &mut Visibility), With<ColliderOutlineLayer>>,
+        Query<(&Mesh2d, &mut Visibility), With<NeighborCountLayer>>,
+    )>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut scratch: Local<PhysicsLayerScratch>,
+) {
+    let show_field = overlay.show_gravity_field;
+    let show_colliders = overlay.show_collider_outlines;
+    let show_neighbors = overlay.show_neighbor_counts;
+    let scratch = &mut *scratch;
+
+    if show_field {
+        scratch.field.clear();
+        scratch.sources.clear();
+        scratch.sources.extend(
+            q_asteroids
+                .iter()
+                .map(|(transform, size, ..)| GravitySource {
+                    entity: Entity::PLACEHOLDER,
+                    position: transform.translation.truncate(),
+                    mass: size.0 as f32,
+                }),
+        );
+        let max_sources = config.trajectory_max_sources as usize;
+        if scratch.sources.len() > max_sources && max_sources > 0 {
+            scratch
+                .sources
+                .select_nth_unstable_by(max_sources - 1, |a, b| b.mass.total_cmp(&a.mass));
+            scratch.sources.truncate(max_sources);
+        }
+
+        if let (Ok(window), Ok(camera)) = (windows.single(), q_camera.single()) {
+            let zoom = camera.scale.x;
+            let spacing = GRAVITY_FIELD_SPACING_PX * zoom;
+            let half = Vec2::new(window.width(), window.height()) * 0.5 * zoom;
+            let center = camera.translation.truncate();
+            // Snap the grid to world multiples of the spacing so arrows stay
+            // put while the camera pans.
+            let first = ((center - half) / spacing).ceil() * spacing;
+            let max_gravity_dist = governor.effective_max_gravity_dist(&config);
+            let gravity_scale = config.gravity_const * config.asteroid_density;
+            let min_dist_sq = config.min_gravity_dist * config.min_gravity_dist;
+            let max_dist_sq = max_gravity_dist * max_gravity_dist;
+            let mut y = first.y;
+            while y <= center.y + half.y {
+                let mut x = first.x;
+                while x <= center.x + half.x {
+                    let point = Vec2::new(x, y);
+                    let accel = field_acceleration(
+                        point,
+                        &scratch.sources,
+                        gravity_scale,
+                        min_dist_sq,
+                        max_dist_sq,
+                    );
+                    field_arrow(point, accel, spacing * 0.8, &mut scratch.field);
+                    x += spacing;
+                }
+                y += spacing;
+            }
+        }
+    }
+
+    if show_colliders || show_neighbors {
+        scratch.colliders.clear();
+        scratch.neighbors.clear();
+        for (transform, _, vertices, base, neighbors) in q_asteroids.iter() {
+            let pos = transform.translation.truncate();
+            let hull = base.map_or(&vertices.0, |base| &base.0);
+            if show_colliders && hull.len() >= 2 {
+                let n = hull.len();
+                for i in 0..n {
+                    let p1 = pos + transform.rotation.mul_vec3(hull[i].extend(0.0)).truncate();
+                    let p2 = pos
+                        + transform
+                            .rotation
+                            .mul_vec3(hull[(i + 1) % n].extend(0.0))
+                            .truncate();
+                    scratch.colliders.push((p1, p2));
+                }
+            }
+            if let (true, Some(neighbors)) = (show_neighbors, neighbors) {
+                let radius = hull.iter().map(|v| v.length()).fold(0.0, f32::max);
+                neighbor_ticks(pos, radius, neighbors.0, &mut scratch.neighbors);
+            }
+        }
+    }
+
+    let mut update = |shown: bool,
+                      lines: &[(Vec2, Vec2)],
+                      width: f32,
+                      (mesh_handle, mut vis): (&Mesh2d, Mut<Visibility>)| {
+        let target = if shown {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+        if *vis != target {
+            *vis = target;
+        }
+        if shown {
+            if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+                *mesh = line_segments_mesh(lines, width);
+            }
+        }
+    };
+    if let Ok(layer) = layers.p0().single_mut() {
+        update(show_field, &scratch.field, 0.35, layer);
+    }
+    if let Ok(layer) = layers.p1().single_mut() {
+        update(show_colliders, &scratch.colliders, 0.25, layer);
+    }
+    if let Ok(layer) = layers.p2().single_mut() {
+        update(show_neighbors, &scratch.neighbors, 0.3, layer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(position: Vec2, mass: f32) -> GravitySource {
+        GravitySource {
+            entity: Entity::PLACEHOLDER,
+            position,
+            mass,
+        }
+    }
+
+    #[test]
+    fn field_points_at_the_source_and_respects_the_cut_offs() {
+        let sources = [source(Vec2::new(10.0, 0.0), 50.0)];
+        let accel = field_acceleration(Vec2::ZERO, &sources, 2.0, 1.0, 1.0e4);
+        assert!((accel - Vec2::new(1.0, 0.0)).length() < 1e-5, "{accel}");
+
+        let far = field_acceleration(Vec2::ZERO, &sources, 2.0, 1.0, 50.0);
+        assert_eq!(far, Vec2::ZERO);
+        let inside = field_acceleration(Vec2::new(9.5, 0.0), &sources, 2.0, 1.0, 1.0e4);
+        assert_eq!(inside, Vec2::ZERO);
+    }
+
+    #[test]
+    fn arrows_saturate_and_ticks_are_capped() {
+        let mut lines = Vec::new();
+        field_arrow(Vec2::ZERO, Vec2::ZERO, 10.0, &mut lines);
+        assert!(lines.is_empty());
+        field_arrow(
+            Vec2::ZERO,
+            Vec2::X * GRAVITY_FIELD_HALF_ACCEL,
+            10.0,
+            &mut lines,
+        );
+        assert_eq!(lines.len(), 3);
+        assert!((lines[0].1 - Vec2::X * 5.0).length() < 1e-5);
+        lines.clear();
+        field_arrow(Vec2::ZERO, Vec2::Y * 1.0e6, 10.0, &mut lines);
+        assert!(lines[0].1.length() < 10.0);
+
+        let mut ticks = Vec::new();
+        neighbor_ticks(Vec2::ZERO, 5.0, 3, &mut ticks);
+        assert_eq!(ticks.len(), 3);
+        ticks.clear();
+        neighbor_ticks(Vec2::ZERO, 5.0, NEIGHBOR_TICK_CAP + 20, &mut ticks);
+        assert_eq!(ticks.len(), NEIGHBOR_TICK_CAP);
+        neighbor_ticks(Vec2::ZERO, 5.0, 0, &mut ticks);
+        assert_eq!(ticks.len(), NEIGHBOR_TICK_CAP);
+    }
+}
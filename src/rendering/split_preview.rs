@@ -0,0 +1,107 @@
+//! Missile split preview.
+//!
+//! While a missile is armed, the asteroid under the cursor gets faint lines
+//! where a missile fired along the current aim would cut it.  The lines come
+//! from [`predicted_missile_split_lines`], which runs the same
+//! `impact_radiating_split_basis` cuts as the hit system, so players can line
+//! up a split deliberately.
+//!
+//! Only the hovered asteroid is evaluated, once per frame.  Nothing is drawn
+//! for planets, for asteroids a missile would destroy or fully decompose, or
+//! when the missile is unavailable (no ammo, or another campaign secondary).
+
+use super::inspector::pick_asteroid;
+use super::line_segments_mesh;
+use crate::asteroid::{Asteroid, AsteroidSize, Planet, Vertices};
+use crate::config::PhysicsConfig;
+use crate::menu::SelectedGameMode;
+use crate::player::combat::predicted_missile_split_lines;
+use crate::player::{
+    AimDirection, CampaignLoadout, CampaignSecondaryWeapon, MissileAmmo, Player,
+    SecondaryWeaponLevel,
+};
+use bevy::prelude::*;
+
+/// Preview line half-width (world units).
+const PREVIEW_HALF_WIDTH: f32 = 0.3;
+
+/// Marker for the retained missile split-preview line mesh.
+#[derive(Component)]
+pub struct MissileSplitPreviewLayer;
+
+/// Redraw the split preview for the asteroid under the cursor.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn sync_missile_split_preview_system(
+    windows: Query<&Window>,
+    q_camera: Query<&Transform, With<Camera>>,
+    q_player: Query<&Transform, With<Player>>,
+    q_asteroids: Query<
+        (Entity, &Transform, &AsteroidSize, &Vertices),
+        (With<Asteroid>, Without<Planet>),
+    >,
+    (aim, ammo, missile_level): (
+        Res<AimDirection>,
+        Res<MissileAmmo>,
+        Res<SecondaryWeaponLevel>,
+    ),
+    (selected_mode, campaign_loadout): (Res<SelectedGameMode>, Res<CampaignLoadout>),
+    config: Res<PhysicsConfig>,
+    mut q_layer: Query<(&Mesh2d, &mut Visibility), With<MissileSplitPreviewLayer>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+) {
+    let Ok((mesh_handle, mut vis)) = q_layer.single_mut() else {
+        return;
+    };
+    let armed = ammo.count > 0
+        && (*selected_mode != SelectedGameMode::Campaign
+            || campaign_loadout.secondary == CampaignSecondaryWeapon::Missile);
+
+    let lines = armed
+        .then(|| {
+            let window = windows.single().ok()?;
+            let camera = q_camera.single().ok()?;
+            let ship = q_player.single().ok()?.translation.truncate();
+            let cursor = window.cursor_position()?;
+            let offset = Vec2::new(
+                cursor.x - window.width() / 2.0,
+                -(cursor.y - window.height() / 2.0),
+            );
+            let hover = camera.translation.truncate() + offset * camera.scale.x;
+
+            let hovered = pick_asteroid(
+                hover,
+                q_asteroids.iter().map(|(entity, transform, _, vertices)| {
+                    let radius = vertices.0.iter().map(|v| v.length()).fold(0.0, f32::max);
+                    (entity, transform.translation.truncate(), radius)
+                }),
+                0.0,
+            )?;
+            let (_, transform, size, vertices) = q_asteroids.get(hovered).ok()?;
+            let centre = transform.translation.truncate();
+            let hull: Vec<Vec2> = vertices
+                .0
+                .iter()
+                .map(|v| centre + transform.rotation.mul_vec3(v.extend(0.0)).truncate())
+                .collect();
+            let lines = predicted_missile_split_lines(
+                &hull,
+                centre,
+                size.0,
+                ship,
+                aim.0,
+                &missile_level,
+                &config,
+            );
+            (!lines.is_empty()).then_some(lines)
+        })
+        .flatten();
+
+    let Some(lines) = lines else {
+        vis.set_if_neq(Visibility::Hidden);
+        return;
+    };
+    vis.set_if_neq(Visibility::Visible);
+    if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+        *mesh = line_segments_mesh(&lines, PREVIEW_HALF_WIDTH);
+    }
+}
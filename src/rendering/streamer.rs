@@ -0,0 +1,213 @@
+//! Streamer mode: a clean HUD for recording and streaming.
+//!
+//! [`OverlayState::streamer_mode`] is set from the Settings screen or with
+//! [`STREAMER_TOGGLE_KEY`], and persists with the profile.  While it is on:
+//!
+//! - the stats, physics inspector, profiler and asteroid inspector text stay
+//!   hidden, whatever their own toggles say ([`OverlayState::debug_text_shown`]);
+//! - the score and the mode / wave line grow by `streamer_hud_scale`;
+//! - a compact live stats panel (score, wave, biggest asteroid) joins the
+//!   top-left HUD stack;
+//! - with [`OverlayState::webcam_corner`] on, the HUD layout keeps the
+//!   bottom-right corner empty (`streamer_webcam_width_px` ×
+//!   `streamer_webcam_height_px`) for a webcam overlay.
+//!
+//! Placement is handled by the HUD layout pass (see [`super::hud_layout`]).
+
+use super::hud_layout::{
+    hud_line_height, HudAnchor, HudAnchored, HudLayoutMode, HudVisibility, HudWidget, HudWidgetSpec,
+};
+use super::{hud_mode_font_size, hud_score_font_size, HudModeText, HudScoreText, OverlayState};
+use crate::asteroid::{Asteroid, AsteroidSize};
+use crate::campaign::{CampaignWaveDirector, CampaignWavePhase};
+use crate::config::PhysicsConfig;
+use crate::graphics::GameFont;
+use crate::player::PlayerScore;
+use crate::survival::SurvivalState;
+use bevy::prelude::*;
+
+/// Key that turns streamer mode on and off.
+pub const STREAMER_TOGGLE_KEY: KeyCode = KeyCode::F9;
+
+/// Root node of the live stats panel.
+#[derive(Component)]
+pub struct StreamerStatsPanel;
+
+/// Text of the live stats panel.
+#[derive(Component)]
+pub struct StreamerStatsText;
+
+/// Wave in progress, from whichever wave system is running.
+pub fn current_wave(
+    campaign: Option<&CampaignWaveDirector>,
+    survival: Option<&SurvivalState>,
+) -> Option<u32> {
+    if let Some(survival) = survival.filter(|s| s.is_active()) {
+        return Some(survival.wave.max(1));
+    }
+    campaign
+        .filter(|director| director.phase != CampaignWavePhase::Inactive)
+        .map(|director| director.current_wave.max(1))
+}
+
+/// Body of the live stats panel.
+pub fn live_stats_text(score: u32, wave: Option<u32>, biggest: Option<u32>) -> String {
+    let dash = || "—".to_string();
+    format!(
+        "SCORE    {score}\nWAVE     {}\nBIGGEST  {}",
+        wave.map_or_else(dash, |w| w.to_string()),
+        biggest.map_or_else(dash, |m| m.to_string()),
+    )
+}
+
+/// Live stats panel, top left; streamer mode only.
+pub fn streamer_stats_hud_spec() -> HudWidgetSpec {
+    // Score, wave and biggest-asteroid rows plus padding.
+    HudWidgetSpec::new(
+        HudWidget::STREAMER_STATS,
+        HudAnchor::TopLeft,
+        |config, _| Vec2::new(300.0, hud_line_height(config.stats_font_size) * 3.0 + 16.0),
+    )
+    .visible(HudVisibility::StreamerOnly)
+}
+
+/// Spawn the (hidden) live stats panel with the rest of the HUD.
+pub fn setup_streamer_stats(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    font: Res<GameFont>,
+    overlay: Res<OverlayState>,
+) {
+    commands
+        .spawn((
+            Node {
+                padding: UiRect::all(Val::Px(8.0)),
+                ..HudAnchored::node()
+            },
+            BackgroundColor(Color::srgba(0.02, 0.03, 0.06, 0.72)),
+            if overlay.streamer_mode {
+                Visibility::Visible
+            } else {
+                Visibility::Hidden
+            },
+            HudAnchored(HudWidget::STREAMER_STATS),
+            StreamerStatsPanel,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(live_stats_text(0, None, None)),
+                TextFont {
+                    font: font.0.clone(),
+                    font_size: config.stats_font_size,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                StreamerStatsText,
+            ));
+        });
+}
+
+/// Flip streamer mode on [`STREAMER_TOGGLE_KEY`].
+pub fn streamer_toggle_system(keys: Res<ButtonInput<KeyCode>>, mut overlay: ResMut<OverlayState>) {
+    if keys.just_pressed(STREAMER_TOGGLE_KEY) {
+        overlay.streamer_mode = !overlay.streamer_mode;
+        info!(
+            "Streamer mode {}",
+            if overlay.streamer_mode { "on" } else { "off" }
+        );
+    }
+}
+
+/// Show the stats panel and rescale the score and mode line to match the
+/// current streamer setting.
+#[allow(clippy::type_complexity)]
+pub fn streamer_hud_style_system(
+    overlay: Res<OverlayState>,
+    config: Res<PhysicsConfig>,
+    q_added: Query<(), Or<(Added<HudScoreText>, Added<StreamerStatsPanel>)>>,
+    mut q_panel: Query<&mut Visibility, With<StreamerStatsPanel>>,
+    mut q_score: Query<&mut TextFont, (With<HudScoreText>, Without<HudModeText>)>,
+    mut q_mode: Query<&mut TextFont, (With<HudModeText>, Without<HudScoreText>)>,
+) {
+    if !overlay.is_changed() && !config.is_changed() && q_added.is_empty() {
+        return;
+    }
+    let panel = if overlay.streamer_mode {
+        Visibility::Visible
+    } else {
+        Visibility::Hidden
+    };
+    for mut vis in q_panel.iter_mut() {
+        vis.set_if_neq(panel);
+    }
+
+    let scale = HudLayoutMode::from_overlay(&overlay).score_scale(&config);
+    for mut font in q_score.iter_mut() {
+        font.font_size = hud_score_font_size(&config) * scale;
+    }
+    for mut font in q_mode.iter_mut() {
+        font.font_size = hud_mode_font_size(&config) * scale;
+    }
+}
+
+/// Refresh the live stats panel while streamer mode is on.
+pub fn streamer_stats_system(
+    overlay: Res<OverlayState>,
+    score: Res<PlayerScore>,
+    director: Option<Res<CampaignWaveDirector>>,
+    survival: Option<Res<SurvivalState>>,
+    q_asteroids: Query<&AsteroidSize, With<Asteroid>>,
+    mut q_text: Query<&mut Text, With<StreamerStatsText>>,
+) {
+    if !overlay.streamer_mode {
+        return;
+    }
+    let body = live_stats_text(
+        score.total(),
+        current_wave(director.as_deref(), survival.as_deref()),
+        q_asteroids.iter().map(|size| size.0).max(),
+    );
+    for mut text in q_text.iter_mut() {
+        if text.0 != body {
+            text.0 = body.clone();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn live_stats_show_dashes_until_there_is_a_wave_or_asteroid() {
+        assert_eq!(
+            live_stats_text(120, None, None),
+            "SCORE    120\nWAVE     —\nBIGGEST  —"
+        );
+        assert_eq!(
+            live_stats_text(4500, Some(7), Some(96)),
+            "SCORE    4500\nWAVE     7\nBIGGEST  96"
+        );
+    }
+
+    #[test]
+    fn toggle_key_flips_streamer_mode() {
+        let mut app = App::new();
+        app.init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<OverlayState>()
+            .add_systems(Update, streamer_toggle_system);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .press(STREAMER_TOGGLE_KEY);
+        app.update();
+        assert!(app.world().resource::<OverlayState>().streamer_mode);
+
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(STREAMER_TOGGLE_KEY);
+        keys.clear();
+        keys.press(STREAMER_TOGGLE_KEY);
+        app.update();
+        assert!(!app.world().resource::<OverlayState>().streamer_mode);
+    }
+}
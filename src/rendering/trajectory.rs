@@ -0,0 +1,327 @@
+//! Predicted-path overlay ("Trajectory Prediction" in the debug panel).
+//!
+//! Each frame the overlay integrates the player ship, the lock-on target, and
+//! the asteroids nearest the ship forward by `trajectory_horizon_secs` and
+//! draws the result as a polyline on a retained [`TrajectoryLayer`] mesh.
+//!
+//! Predictions use the same force model as the fixed-tick simulation:
+//!
+//! - **Asteroids** accelerate toward the `trajectory_max_sources` heaviest
+//!   bodies at `G · m_source · asteroid_density / r²` (the
+//!   `nbody_gravity_system` force divided by the body's Rapier mass), with the
+//!   same min/max gravity distances.
+//! - **The ship** is not pulled by gravity in the simulation, so its path only
+//!   decays with `player_linear_damping`: it shows where the ship coasts with
+//!   the engines off, to be compared with the asteroid paths it is intercepting.
+//!
+//! Sources are frozen at their current positions for the whole horizon.  That
+//! is exact for anchored planets (the Orbit and Catalog wells) and a good
+//! short-range approximation elsewhere.  A path stops early where it would
+//! enter a source's contact range.
+
+use super::{line_segments_mesh, OverlayState};
+use crate::asteroid::{Asteroid, AsteroidSize, Planet};
+use crate::config::PhysicsConfig;
+use crate::player::{LockOnState, Player};
+use crate::simulation::governor::FrameTimeGovernor;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+
+/// Marker for the retained trajectory-prediction line mesh.
+#[derive(Component)]
+pub struct TrajectoryLayer;
+
+/// A body frozen in place for the duration of one prediction.
+#[derive(Clone, Copy, Debug)]
+pub struct GravitySource {
+    pub entity: Entity,
+    pub position: Vec2,
+    /// Gravitational mass (`AsteroidSize` as `f32`).
+    pub mass: f32,
+}
+
+/// Integration settings for one predicted body.
+#[derive(Clone, Copy, Debug)]
+pub struct TrajectoryParams {
+    pub step_secs: f32,
+    pub steps: usize,
+    /// `G · asteroid_density` for bodies that feel gravity, `0.0` otherwise.
+    pub gravity_scale: f32,
+    pub linear_damping: f32,
+    pub min_dist_sq: f32,
+    pub max_dist_sq: f32,
+}
+
+/// Integrate one body forward with semi-implicit Euler, appending each
+/// sampled position (starting with `start`) to `out`.
+///
+/// `skip` is the body's own entity so it never attracts itself.
+pub fn predict_trajectory(
+    start: Vec2,
+    velocity: Vec2,
+    skip: Entity,
+    sources: &[GravitySource],
+    params: &TrajectoryParams,
+    out: &mut Vec<Vec2>,
+) {
+    let mut position = start;
+    let mut velocity = velocity;
+    let damping = 1.0 / (1.0 + params.step_secs * params.linear_damping);
+    out.push(position);
+
+    for _ in 0..params.steps {
+        let mut accel = Vec2::ZERO;
+        if params.gravity_scale > 0.0 {
+            for source in sources {
+                if source.entity == skip {
+                    continue;
+                }
+                let delta = source.position - position;
+                let dist_sq = delta.length_squared();
+                if dist_sq < params.min_dist_sq {
+                    // Inside contact range: Rapier takes over from here.
+                    return;
+                }
+                if dist_sq > params.max_dist_sq {
+                    continue;
+                }
+                accel += delta.normalize_or_zero() * (params.gravity_scale * source.mass / dist_sq);
+            }
+        }
+        velocity = (velocity + accel * params.step_secs) * damping;
+        position += velocity * params.step_secs;
+        out.push(position);
+    }
+}
+
+/// Reusable buffers for [`sync_trajectory_layer_system`].
+#[derive(Default)]
+pub struct TrajectoryScratch {
+    sources: Vec<GravitySource>,
+    candidates: Vec<(f32, Entity, Vec2, Vec2)>,
+    path: Vec<Vec2>,
+    lines: Vec<(Vec2, Vec2)>,
+}
+
+impl TrajectoryScratch {
+    fn push_path(&mut self) {
+        for pair in self.path.windows(2) {
+            self.lines.push((pair[0], pair[1]));
+        }
+    }
+}
+
+/// Rebuild the trajectory overlay mesh while `show_trajectories` is on.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn sync_trajectory_layer_system(
+    overlay: Res<OverlayState>,
+    config: Res<PhysicsConfig>,
+    governor: Res<FrameTimeGovernor>,
+    lock_on: Res<LockOnState>,
+    q_player: Query<(Entity, &Transform, &Velocity), With<Player>>,
+    q_asteroids: Query<(Entity, &Transform, &Velocity, &AsteroidSize, Has<Planet>), With<Asteroid>>,
+    mut q_layer: Query<(&Mesh2d, &mut Visibility), With<TrajectoryLayer>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut scratch: Local<TrajectoryScratch>,
+) {
+    let Ok((mesh_handle, mut vis)) = q_layer.single_mut() else {
+        return;
+    };
+    if !overlay.show_trajectories {
+        if *vis != Visibility::Hidden {
+            *vis = Visibility::Hidden;
+        }
+        return;
+    }
+    *vis = Visibility::Visible;
+
+    let scratch = &mut *scratch;
+    scratch.lines.clear();
+
+    // Heaviest bodies dominate the field; cap the source list so cost stays
+    // bounded at high asteroid counts.
+    scratch.sources.clear();
+    scratch
+        .sources
+        .extend(
+            q_asteroids
+                .iter()
+                .map(|(entity, transform, _, size, _)| GravitySource {
+                    entity,
+                    position: transform.translation.truncate(),
+                    mass: size.0 as f32,
+                }),
+        );
+    let max_sources = config.trajectory_max_sources as usize;
+    if scratch.sources.len() > max_sources && max_sources > 0 {
+        scratch
+            .sources
+            .select_nth_unstable_by(max_sources - 1, |a, b| b.mass.total_cmp(&a.mass));
+        scratch.sources.truncate(max_sources);
+    }
+
+    let step_secs = config.trajectory_step_secs.max(0.005);
+    let max_gravity_dist = governor.effective_max_gravity_dist(&config);
+    let base = TrajectoryParams {
+        step_secs,
+        steps: (config.trajectory_horizon_secs.max(0.0) / step_secs).ceil() as usize,
+        gravity_scale: config.gravity_const * config.asteroid_density,
+        linear_damping: 0.0,
+        min_dist_sq: config.min_gravity_dist * config.min_gravity_dist,
+        max_dist_sq: max_gravity_dist * max_gravity_dist,
+    };
+
+    let player = q_player.single().ok();
+    if let Some((entity, transform, velocity)) = player {
+        let ship = TrajectoryParams {
+            gravity_scale: 0.0,
+            linear_damping: config.player_linear_damping,
+            ..base
+        };
+        scratch.path.clear();
+        predict_trajectory(
+            transform.translation.truncate(),
+            velocity.linvel,
+            entity,
+            &scratch.sources,
+            &ship,
+            &mut scratch.path,
+        );
+        scratch.push_path();
+    }
+
+    // Lock-on target first, then the asteroids nearest the ship.
+    let origin = player.map(|(_, transform, _)| transform.translation.truncate());
+    scratch.candidates.clear();
+    for (entity, transform, velocity, _, is_planet) in q_asteroids.iter() {
+        if is_planet {
+            continue;
+        }
+        let position = transform.translation.truncate();
+        let rank = if lock_on.target == Some(entity) {
+            -1.0
+        } else if let Some(origin) = origin {
+            origin.distance_squared(position)
+        } else {
+            continue;
+        };
+        scratch
+            .candidates
+            .push((rank, entity, position, velocity.linvel));
+    }
+    let max_asteroids = (config.trajectory_max_asteroids as usize).min(scratch.candidates.len());
+    if max_asteroids > 0 {
+        scratch
+            .candidates
+            .select_nth_unstable_by(max_asteroids - 1, |a, b| a.0.total_cmp(&b.0));
+    }
+    for i in 0..max_asteroids {
+        let (_, entity, position, velocity) = scratch.candidates[i];
+        scratch.path.clear();
+        predict_trajectory(
+            position,
+            velocity,
+            entity,
+            &scratch.sources,
+            &base,
+            &mut scratch.path,
+        );
+        scratch.push_path();
+    }
+
+    if let Some(mesh) = meshes.get_mut(&mesh_handle.0) {
+        *mesh = line_segments_mesh(&scratch.lines, 0.45);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(gravity_scale: f32) -> TrajectoryParams {
+        TrajectoryParams {
+            step_secs: 0.1,
+            steps: 10,
+            gravity_scale,
+            linear_damping: 0.0,
+            min_dist_sq: 1.0,
+            max_dist_sq: 1.0e8,
+        }
+    }
+
+    #[test]
+    fn no_sources_gives_a_straight_line() {
+        let mut path = Vec::new();
+        predict_trajectory(
+            Vec2::ZERO,
+            Vec2::new(10.0, 0.0),
+            Entity::PLACEHOLDER,
+            &[],
+            &params(1.0),
+            &mut path,
+        );
+        assert_eq!(path.len(), 11);
+        assert!((path[10].x - 10.0).abs() < 1e-4);
+        assert_eq!(path[10].y, 0.0);
+    }
+
+    #[test]
+    fn source_bends_the_path_toward_it() {
+        let source = GravitySource {
+            entity: Entity::from_raw_u32(7).unwrap(),
+            position: Vec2::new(5.0, 20.0),
+            mass: 500.0,
+        };
+        let mut path = Vec::new();
+        predict_trajectory(
+            Vec2::ZERO,
+            Vec2::new(10.0, 0.0),
+            Entity::PLACEHOLDER,
+            &[source],
+            &params(1.0),
+            &mut path,
+        );
+        assert!(path.last().unwrap().y > 0.5);
+
+        // Zero gravity scale (the ship) ignores the same source.
+        path.clear();
+        predict_trajectory(
+            Vec2::ZERO,
+            Vec2::new(10.0, 0.0),
+            Entity::PLACEHOLDER,
+            &[source],
+            &params(0.0),
+            &mut path,
+        );
+        assert_eq!(path.last().unwrap().y, 0.0);
+    }
+
+    #[test]
+    fn path_stops_at_contact_range_and_ignores_itself() {
+        let me = Entity::from_raw_u32(3).unwrap();
+        let sources = [
+            GravitySource {
+                entity: me,
+                position: Vec2::ZERO,
+                mass: 1.0e6,
+            },
+            GravitySource {
+                entity: Entity::from_raw_u32(4).unwrap(),
+                position: Vec2::new(3.0, 0.0),
+                mass: 1.0,
+            },
+        ];
+        let mut path = Vec::new();
+        predict_trajectory(
+            Vec2::ZERO,
+            Vec2::new(10.0, 0.0),
+            me,
+            &sources,
+            &params(1.0),
+            &mut path,
+        );
+        // Heads straight for the small body and stops before reaching it.
+        assert!(path.len() < 11);
+        assert!(path.iter().all(|p| p.y == 0.0));
+    }
+}
@@ -0,0 +1,357 @@
+//! In-game issue reports.
+//!
+//! The pause menu's **REPORT ISSUE** button writes one zip per press to
+//! `reports/report-<unix>-<run stamp>.zip`, holding everything needed to
+//! reproduce a bug without asking the player to hunt for files:
+//!
+//! | Entry | Contents |
+//! |-------|----------|
+//! | `save.toml` | The current session, in the save-slot format |
+//! | `run.toml` | [`RunMetadata`] (scenario, seed, mode, version) |
+//! | `log.txt` | The last [`RECENT_LOG_LINES`] log lines of this session |
+//! | `physics.toml` | `assets/physics.toml` as it is on disk, if present |
+//! | `config_effective.txt` | The live [`PhysicsConfig`], after hot reloads |
+//! | `entities.scn.ron` | Every gameplay entity, via [`dump_entities`] |
+//! | `screenshot.png` | The current frame, pause menu included |
+//!
+//! The screenshot is captured asynchronously, so the archive is written once
+//! the image lands on disk, or without it after
+//! [`REPORT_SCREENSHOT_TIMEOUT_FRAMES`].  Log lines are collected by
+//! [`recent_log_layer`], installed on Bevy's `LogPlugin` in `main.rs`.
+
+use crate::config::PhysicsConfig;
+use crate::reflection::dump_entities;
+use crate::run_info::RunMetadata;
+use crate::save::{encode_snapshot, SaveSources};
+use bevy::log::tracing::field::{Field, Visit};
+use bevy::log::tracing::{Event, Subscriber};
+use bevy::log::tracing_subscriber::layer::Context;
+use bevy::log::tracing_subscriber::Layer;
+use bevy::log::BoxedLayer;
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{save_to_disk, Screenshot};
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::io::{Seek, Write};
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+/// Log lines kept for the next report.
+pub const RECENT_LOG_LINES: usize = 500;
+
+/// Archive entry holding the reflected gameplay entities.
+pub const ENTITY_DUMP_ENTRY: &str = "entities.scn.ron";
+
+/// Frames to wait for the screenshot before zipping without it.
+pub const REPORT_SCREENSHOT_TIMEOUT_FRAMES: u32 = 120;
+
+static RECENT_LOG: Mutex<VecDeque<String>> = Mutex::new(VecDeque::new());
+static LOG_START: OnceLock<Instant> = OnceLock::new();
+
+/// Ask for an issue report of the current session.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct ReportIssueRequest;
+
+/// A report whose files are gathered and whose screenshot is in flight.
+#[derive(Debug)]
+pub struct PendingReportBundle {
+    pub zip_path: PathBuf,
+    pub screenshot_path: PathBuf,
+    pub entries: Vec<(&'static str, Vec<u8>)>,
+    pub frames_waited: u32,
+}
+
+#[derive(Resource, Debug, Default)]
+pub struct PendingReport(pub Option<PendingReportBundle>);
+
+pub struct ReportPlugin;
+
+impl Plugin for ReportPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ReportIssueRequest>()
+            .init_resource::<PendingReport>()
+            .add_systems(
+                Update,
+                (
+                    handle_report_requests_system,
+                    attach_entity_dump_system,
+                    // Not state-gated: a report started just before resuming
+                    // still finishes.
+                    finish_pending_report_system,
+                )
+                    .chain(),
+            );
+    }
+}
+
+pub fn report_dir() -> PathBuf {
+    PathBuf::from("reports")
+}
+
+/// Append a line, dropping the oldest once `capacity` is reached.
+pub fn push_bounded(lines: &mut VecDeque<String>, line: String, capacity: usize) {
+    while lines.len() >= capacity {
+        lines.pop_front();
+    }
+    lines.push_back(line);
+}
+
+/// The captured log, oldest line first.
+pub fn recent_log_text() -> String {
+    let lines = RECENT_LOG
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    let mut text = String::new();
+    for line in lines.iter() {
+        text.push_str(line);
+        text.push('\n');
+    }
+    text
+}
+
+/// `LogPlugin::custom_layer` hook that mirrors every log event into the
+/// in-memory ring read by [`recent_log_text`].
+pub fn recent_log_layer(_app: &mut App) -> Option<BoxedLayer> {
+    LOG_START.get_or_init(Instant::now);
+    Some(Box::new(RecentLogLayer))
+}
+
+struct RecentLogLayer;
+
+impl<S: Subscriber> Layer<S> for RecentLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let meta = event.metadata();
+        let elapsed = LOG_START.get_or_init(Instant::now).elapsed().as_secs_f32();
+        let mut line = format!("{elapsed:>9.3}s {:>5} {}: ", meta.level(), meta.target());
+        event.record(&mut LineVisitor(&mut line));
+        let mut lines = RECENT_LOG
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        push_bounded(&mut lines, line, RECENT_LOG_LINES);
+    }
+}
+
+/// Formats the `message` field as-is and any other fields as `name=value`.
+struct LineVisitor<'a>(&'a mut String);
+
+impl Visit for LineVisitor<'_> {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            let _ = write!(self.0, "{value:?}");
+        } else {
+            let _ = write!(self.0, " {}={value:?}", field.name());
+        }
+    }
+}
+
+/// Write `entries` as a deflate-compressed zip archive.
+pub fn write_report_zip<W: Write + Seek>(
+    writer: W,
+    entries: &[(&str, Vec<u8>)],
+) -> Result<W, String> {
+    let mut zip = zip::ZipWriter::new(writer);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    for (name, bytes) in entries {
+        zip.start_file(*name, options)
+            .map_err(|err| format!("failed to add {name}: {err}"))?;
+        zip.write_all(bytes)
+            .map_err(|err| format!("failed to write {name}: {err}"))?;
+    }
+    zip.finish()
+        .map_err(|err| format!("failed to finish archive: {err}"))
+}
+
+fn write_report_file(path: &Path, entries: &[(&str, Vec<u8>)]) -> Result<(), String> {
+    let file = std::fs::File::create(path)
+        .map_err(|err| format!("failed to create {}: {err}", path.display()))?;
+    write_report_zip(file, entries).map(|_| ())
+}
+
+/// Gather the report files and request the screenshot.
+pub fn handle_report_requests_system(
+    mut commands: Commands,
+    mut requests: MessageReader<ReportIssueRequest>,
+    mut pending: ResMut<PendingReport>,
+    sources: SaveSources,
+    config: Res<PhysicsConfig>,
+    run: Res<RunMetadata>,
+) {
+    if requests.read().count() == 0 {
+        return;
+    }
+    if pending.0.is_some() {
+        warn!("An issue report is already being written; ignoring request");
+        return;
+    }
+    let dir = report_dir();
+    if let Err(err) = std::fs::create_dir_all(&dir) {
+        error!("Failed to create {}: {}", dir.display(), err);
+        return;
+    }
+
+    let mut entries: Vec<(&'static str, Vec<u8>)> = Vec::new();
+    match encode_snapshot(&sources.snapshot()) {
+        Ok(save) => entries.push(("save.toml", save.into_bytes())),
+        Err(err) => warn!("Issue report: {}", err),
+    }
+    match toml::to_string(&*run) {
+        Ok(contents) => entries.push(("run.toml", contents.into_bytes())),
+        Err(err) => warn!("Issue report: failed to encode run metadata: {}", err),
+    }
+    // Read the log last so the lines above make it in.
+    info!("Writing issue report: {}", run.summary());
+    entries.push(("log.txt", recent_log_text().into_bytes()));
+    if let Ok(physics) = std::fs::read("assets/physics.toml") {
+        entries.push(("physics.toml", physics));
+    }
+    entries.push((
+        "config_effective.txt",
+        format!("{:#?}\n", *config).into_bytes(),
+    ));
+
+    let taken_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let stem = format!("report-{taken_at}-{}", run.stamp());
+    let screenshot_path = dir.join(format!("{stem}.png"));
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(screenshot_path.clone()));
+    pending.0 = Some(PendingReportBundle {
+        zip_path: dir.join(format!("{stem}.zip")),
+        screenshot_path,
+        entries,
+        frames_waited: 0,
+    });
+}
+
+/// Add the reflected gameplay entities to a report that was just gathered.
+///
+/// Exclusive, since the dump reads every entity through the type registry.
+pub fn attach_entity_dump_system(world: &mut World) {
+    let fresh = world
+        .resource::<PendingReport>()
+        .0
+        .as_ref()
+        .is_some_and(|bundle| {
+            !bundle
+                .entries
+                .iter()
+                .any(|(name, _)| *name == ENTITY_DUMP_ENTRY)
+        });
+    if !fresh {
+        return;
+    }
+    let entities: Vec<Entity> = world.query::<Entity>().iter(world).collect();
+    let dump = dump_entities(world, entities.into_iter());
+    let mut pending = world.resource_mut::<PendingReport>();
+    let Some(bundle) = pending.0.as_mut() else {
+        return;
+    };
+    match dump {
+        Ok(ron) => bundle.entries.push((ENTITY_DUMP_ENTRY, ron.into_bytes())),
+        Err(err) => {
+            // Still marks the report as handled, so the dump is not retried.
+            bundle
+                .entries
+                .push((ENTITY_DUMP_ENTRY, format!("{err}\n").into_bytes()));
+            warn!("Issue report: {}", err);
+        }
+    }
+}
+
+/// Zip the pending report once its screenshot exists (or has timed out).
+pub fn finish_pending_report_system(mut pending: ResMut<PendingReport>) {
+    let Some(bundle) = pending.0.as_mut() else {
+        return;
+    };
+    let screenshot = std::fs::read(&bundle.screenshot_path)
+        .ok()
+        .filter(|bytes| !bytes.is_empty());
+    if screenshot.is_none() {
+        bundle.frames_waited += 1;
+        if bundle.frames_waited <= REPORT_SCREENSHOT_TIMEOUT_FRAMES {
+            return;
+        }
+        warn!("Issue report: screenshot did not arrive; writing report without it");
+    }
+
+    let Some(mut bundle) = pending.0.take() else {
+        return;
+    };
+    if let Some(png) = screenshot {
+        bundle.entries.push(("screenshot.png", png));
+        let _ = std::fs::remove_file(&bundle.screenshot_path);
+    }
+    match write_report_file(&bundle.zip_path, &bundle.entries) {
+        Ok(()) => info!("Issue report saved to {}", bundle.zip_path.display()),
+        Err(err) => error!("Failed to write issue report: {}", err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{Cursor, Read};
+
+    #[test]
+    fn log_ring_keeps_only_the_newest_lines() {
+        let mut lines = VecDeque::new();
+        for i in 0..5 {
+            push_bounded(&mut lines, format!("line {i}"), 3);
+        }
+        assert_eq!(lines, ["line 2", "line 3", "line 4"]);
+    }
+
+    #[test]
+    fn report_zip_round_trips_every_entry() {
+        let entries = [
+            ("save.toml", b"version = 5\n".to_vec()),
+            ("log.txt", "INFO accretion: ✦ hello\n".as_bytes().to_vec()),
+            ("screenshot.png", vec![0u8, 1, 2, 255]),
+        ];
+        let cursor = write_report_zip(Cursor::new(Vec::new()), &entries).unwrap();
+
+        let mut archive = zip::ZipArchive::new(Cursor::new(cursor.into_inner())).unwrap();
+        assert_eq!(archive.len(), entries.len());
+        for (name, bytes) in &entries {
+            let mut file = archive.by_name(name).unwrap();
+            let mut read = Vec::new();
+            file.read_to_end(&mut read).unwrap();
+            assert_eq!(&read, bytes, "{name}");
+        }
+    }
+
+    #[test]
+    fn gathered_reports_get_a_reflected_entity_dump() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_plugins(crate::reflection::ReflectionPlugin)
+            .init_resource::<PendingReport>()
+            .add_systems(Update, attach_entity_dump_system);
+        app.world_mut()
+            .spawn((crate::asteroid::Asteroid, crate::asteroid::AsteroidSize(9)));
+
+        app.update();
+        assert!(app.world().resource::<PendingReport>().0.is_none());
+
+        app.world_mut().resource_mut::<PendingReport>().0 = Some(PendingReportBundle {
+            zip_path: PathBuf::new(),
+            screenshot_path: PathBuf::new(),
+            entries: Vec::new(),
+            frames_waited: 0,
+        });
+        app.update();
+        app.update();
+
+        let pending = app.world().resource::<PendingReport>();
+        let entries = &pending.0.as_ref().unwrap().entries;
+        assert_eq!(entries.len(), 1, "dumped once");
+        let (name, ron) = &entries[0];
+        assert_eq!(*name, ENTITY_DUMP_ENTRY);
+        assert!(String::from_utf8_lossy(ron).contains("AsteroidSize"));
+    }
+}
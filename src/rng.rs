@@ -0,0 +1,133 @@
+//! Random streams: gameplay randomness apart from visual randomness.
+//!
+//! Anything that can change what the simulation does draws from
+//! [`GameplayRng`]: fragment shapes, chip sizes, material and volatile rolls,
+//! ore drop jitter.  It is reseeded from [`RunMetadata::seed`] whenever a run
+//! starts, a campaign mission changes or a save is loaded, so the same seed
+//! replays the same gameplay draws.
+//!
+//! Particle jitter, spark colours and fragment tints draw from
+//! [`visual_rng`] instead.  That stream is unseeded and separate, so turning
+//! an effect on or off, or changing its particle count, never shifts a
+//! gameplay draw.  Code that only picks how something looks must not take
+//! [`GameplayRng`].
+
+use crate::run_info::RunMetadata;
+use bevy::prelude::*;
+use rand::rngs::{StdRng, ThreadRng};
+use rand::{RngCore, SeedableRng};
+
+/// Mixed into the run seed, so the gameplay stream is not the same sequence
+/// world generation draws from the seed.
+const GAMEPLAY_STREAM: u64 = 0x6761_6d65_706c_6179;
+
+/// Seeded stream for every random draw that affects gameplay.
+#[derive(Resource, Debug, Clone)]
+pub struct GameplayRng {
+    seed: u64,
+    rng: StdRng,
+}
+
+impl GameplayRng {
+    /// Stream for a run with world seed `seed`.
+    pub fn from_run_seed(seed: u64) -> Self {
+        Self {
+            seed,
+            rng: StdRng::seed_from_u64(seed ^ GAMEPLAY_STREAM),
+        }
+    }
+
+    /// The run seed the stream was started from.
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+}
+
+impl Default for GameplayRng {
+    fn default() -> Self {
+        Self::from_run_seed(0)
+    }
+}
+
+impl RngCore for GameplayRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), rand::Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
+
+/// Stream for randomness that only changes how things look.
+pub fn visual_rng() -> ThreadRng {
+    rand::thread_rng()
+}
+
+/// Restart the gameplay stream whenever the run metadata is replaced, and
+/// log the seed so an issue report's session log says which stream it ran.
+pub fn reseed_gameplay_rng_system(run: Res<RunMetadata>, mut rng: ResMut<GameplayRng>) {
+    if run.is_changed() {
+        *rng = GameplayRng::from_run_seed(run.seed);
+        info!("Gameplay RNG seeded from run seed {}", rng.seed());
+    }
+}
+
+pub struct RngPlugin;
+
+impl Plugin for RngPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<GameplayRng>()
+            .add_systems(PreUpdate, reseed_gameplay_rng_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::Rng;
+
+    #[test]
+    fn gameplay_stream_follows_the_run_seed() {
+        let mut a = GameplayRng::from_run_seed(7);
+        let mut b = GameplayRng::from_run_seed(7);
+        let draws: Vec<u32> = (0..8).map(|_| a.gen()).collect();
+        assert_eq!(draws, (0..8).map(|_| b.gen()).collect::<Vec<u32>>());
+        let mut other = GameplayRng::from_run_seed(8);
+        assert_ne!(draws, (0..8).map(|_| other.gen()).collect::<Vec<u32>>());
+
+        // Not the world-generation sequence for the same seed.
+        let mut world = StdRng::seed_from_u64(7);
+        let mut fresh = GameplayRng::from_run_seed(7);
+        assert_ne!(world.next_u64(), fresh.next_u64());
+    }
+
+    #[test]
+    fn visual_draws_leave_the_gameplay_stream_alone() {
+        let mut app = App::new();
+        app.insert_resource(RunMetadata {
+            seed: 42,
+            ..default()
+        })
+        .add_plugins(RngPlugin);
+        app.update();
+
+        let first = app.world_mut().resource_mut::<GameplayRng>().next_u64();
+        app.world_mut().resource_mut::<RunMetadata>().set_changed();
+        app.update();
+        for _ in 0..100 {
+            let _: f32 = visual_rng().gen();
+        }
+        let replay = app.world_mut().resource_mut::<GameplayRng>().next_u64();
+        assert_eq!(first, replay);
+        assert_eq!(app.world().resource::<GameplayRng>().seed(), 42);
+    }
+}
@@ -0,0 +1,245 @@
+//! Run metadata stamped onto every artifact a player might share.
+//!
+//! [`RunMetadata`] records what produced the current session: scenario,
+//! world seed, game mode, active mutators, and the crate version.  It is set
+//! when the world is spawned (and on each campaign mission change), restored
+//! when a save is loaded, and copied into:
+//!
+//! - save snapshots (the `[run]` table),
+//! - the periodic `[missile_telemetry]` log lines,
+//! - screenshot file names plus a `.toml` sidecar next to each image (**F12**),
+//! - the clipboard, via the pause menu's **COPY RUN INFO** button.
+//!
+//! Setting `ACCRETION_SEED` (decimal, or hex with a `0x` prefix) forces the
+//! seed of the next spawned world, so a shared stamp reproduces its starting
+//! layout.  A non-Normal [`crate::difficulty::Difficulty`] is recorded as a
+//! mutator (`easy` or `hard`), and any active assist as `assisted`.
+
+use crate::menu::{GameState, SelectedGameMode, SelectedScenario};
+use crate::save::SaveScenario;
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{save_to_disk, Screenshot};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Environment variable that overrides the world seed.
+pub const RUN_SEED_ENV: &str = "ACCRETION_SEED";
+
+/// Crate version baked in at build time.
+pub const GAME_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// What produced the current session.
+#[derive(Resource, Serialize, Deserialize, Debug, Clone, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct RunMetadata {
+    /// Scenario label, e.g. `FIELD`.
+    pub scenario: String,
+    /// World-generation seed (stored as hex: TOML integers are signed).
+    #[serde(with = "seed_hex")]
+    pub seed: u64,
+    /// `practice`, or `campaign m<mission>`.
+    pub mode: String,
+    pub mutators: Vec<String>,
+    /// Crate version that spawned the world.
+    pub version: String,
+}
+
+impl Default for RunMetadata {
+    fn default() -> Self {
+        Self {
+            scenario: SaveScenario::Field.label().to_string(),
+            seed: 0,
+            mode: "practice".to_string(),
+            mutators: Vec::new(),
+            version: GAME_VERSION.to_string(),
+        }
+    }
+}
+
+impl RunMetadata {
+    /// Record a freshly spawned world.
+    pub fn begin(
+        &mut self,
+        scenario: SelectedScenario,
+        seed: u64,
+        mode: SelectedGameMode,
+        mission_index: u32,
+    ) {
+        self.scenario = SaveScenario::from(scenario).label().to_string();
+        self.seed = seed;
+        self.mode = match mode {
+            SelectedGameMode::Practice => "practice".to_string(),
+            SelectedGameMode::Campaign => format!("campaign m{mission_index}"),
+        };
+        self.mutators.clear();
+        self.version = GAME_VERSION.to_string();
+    }
+
+    /// Compact, file-name-safe stamp: `field-0123456789abcdef-v0.1.0`.
+    pub fn stamp(&self) -> String {
+        format!(
+            "{}-{:016x}-v{}",
+            self.scenario.to_lowercase(),
+            self.seed,
+            self.version
+        )
+    }
+
+    /// One-line human-readable summary for the clipboard and logs.
+    pub fn summary(&self) -> String {
+        let mutators = if self.mutators.is_empty() {
+            "none".to_string()
+        } else {
+            self.mutators.join(", ")
+        };
+        format!(
+            "Accretion v{} · {} · seed 0x{:016x} · {} · mutators: {}",
+            self.version, self.scenario, self.seed, self.mode, mutators
+        )
+    }
+}
+
+/// Serialize the seed as a `0x`-prefixed hex string.
+mod seed_hex {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(seed: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{seed:016x}"))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        super::parse_seed(&text)
+            .ok_or_else(|| serde::de::Error::custom(format!("invalid seed '{text}'")))
+    }
+}
+
+/// Parse a seed written in decimal or as `0x`-prefixed hex.
+pub fn parse_seed(text: &str) -> Option<u64> {
+    let text = text.trim();
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+/// Seed for the next spawned world: `ACCRETION_SEED` if set and valid,
+/// otherwise random.
+pub fn next_run_seed() -> u64 {
+    std::env::var(RUN_SEED_ENV)
+        .ok()
+        .and_then(|text| parse_seed(&text))
+        .unwrap_or_else(rand::random)
+}
+
+/// Time the current run has spent in `Playing`; pause menus and the ore
+/// shop do not count.  Saved with the run and shown on load cards.
+#[derive(Resource, Debug, Clone, Copy, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct RunPlaytime {
+    pub secs: f32,
+}
+
+fn run_playtime_system(time: Res<Time>, mut playtime: ResMut<RunPlaytime>) {
+    playtime.secs += time.delta_secs();
+}
+
+pub struct RunInfoPlugin;
+
+impl Plugin for RunInfoPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<RunMetadata>()
+            .init_resource::<RunPlaytime>()
+            .add_systems(
+                Update,
+                screenshot_hotkey_system
+                    .run_if(in_state(GameState::Playing).or(in_state(GameState::Paused))),
+            )
+            .add_systems(
+                Update,
+                run_playtime_system.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+/// Put the run summary on the system clipboard.
+pub fn copy_run_info_to_clipboard(run: &RunMetadata) -> Result<(), String> {
+    let summary = run.summary();
+    arboard::Clipboard::new()
+        .and_then(|mut clipboard| clipboard.set_text(summary))
+        .map_err(|err| err.to_string())
+}
+
+fn screenshot_dir() -> PathBuf {
+    PathBuf::from("screenshots")
+}
+
+/// **F12**: save a screenshot named after the run, plus a `.toml` sidecar
+/// holding the full [`RunMetadata`].
+pub fn screenshot_hotkey_system(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    run: Res<RunMetadata>,
+) {
+    if !keys.just_pressed(KeyCode::F12) {
+        return;
+    }
+    let dir = screenshot_dir();
+    if let Err(err) = std::fs::create_dir_all(&dir) {
+        error!("Failed to create {}: {}", dir.display(), err);
+        return;
+    }
+    let taken_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let stem = format!("accretion-{taken_at}-{}", run.stamp());
+    let sidecar = dir.join(format!("{stem}.toml"));
+    match toml::to_string(&*run) {
+        Ok(contents) => {
+            if let Err(err) = std::fs::write(&sidecar, contents) {
+                error!("Failed to write {}: {}", sidecar.display(), err);
+            }
+        }
+        Err(err) => error!("Failed to encode run metadata: {}", err),
+    }
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(dir.join(format!("{stem}.png"))));
+    info!("Screenshot: {stem}.png");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_metadata_round_trips_through_toml_with_full_width_seed() {
+        let mut run = RunMetadata::default();
+        run.begin(
+            SelectedScenario::Orbit,
+            u64::MAX - 7,
+            SelectedGameMode::Campaign,
+            3,
+        );
+        assert_eq!(run.mode, "campaign m3");
+        assert_eq!(
+            run.stamp(),
+            format!("orbit-fffffffffffffff8-v{GAME_VERSION}")
+        );
+
+        let encoded = toml::to_string(&run).unwrap();
+        assert!(encoded.contains("seed = \"0xfffffffffffffff8\""));
+        let decoded: RunMetadata = toml::from_str(&encoded).unwrap();
+        assert_eq!(decoded, run);
+    }
+
+    #[test]
+    fn seeds_parse_from_decimal_or_hex() {
+        assert_eq!(parse_seed("42"), Some(42));
+        assert_eq!(parse_seed(" 0x2A "), Some(42));
+        assert_eq!(parse_seed("0xZZ"), None);
+        assert_eq!(parse_seed("seed"), None);
+    }
+}
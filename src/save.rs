@@ -2,24 +2,40 @@ use std::fs;
 use std::path::PathBuf;
 use std::time::{SystemTime, UNIX_EPOCH};
 
+use bevy::ecs::system::SystemParam;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::*;
 use serde::{Deserialize, Serialize};
 
 use crate::asteroid::{self, Asteroid, AsteroidSize, Vertices};
-use crate::campaign::CampaignSession;
+use crate::campaign::{CampaignSession, CampaignWaveDirector};
 use crate::config::PhysicsConfig;
+use crate::difficulty::Difficulty;
+use crate::lineage::Lineage;
 use crate::menu::{GameState, SelectedGameMode, SelectedScenario};
-use crate::mining::{OreAffinityLevel, PlayerOre};
+use crate::mining::{AsteroidMaterial, MiningDroneLevel, OreAffinityLevel, PlayerOre};
 use crate::player::state::{
     CampaignLoadout, CampaignPrimaryWeapon, CampaignSecondaryWeapon, IonCannonLevel, MissileAmmo,
     PlayerHealth, PlayerLives, PlayerScore, PrimaryWeaponFireRateLevel, PrimaryWeaponUpgradeTracks,
-    SecondaryWeaponLevel, TractorBeamLevel,
+    SecondaryWeaponLevel, ShieldLevel, TractorBeamLevel, TurretLevel,
 };
 use crate::player::Player;
+use crate::run_info::{RunMetadata, RunPlaytime};
+use crate::simulation::procgen::ProceduralField;
+use crate::simulation::sectors::SectorStore;
+use crate::simulation::volatile::Volatile;
+use crate::speedrun::SpeedrunSplits;
+use crate::survival::SurvivalState;
+
+#[path = "save/bundle.rs"]
+pub mod bundle;
+#[path = "save/leaderboard.rs"]
+pub mod leaderboard;
+#[path = "save/storage.rs"]
+pub mod storage;
 
 pub const SAVE_SLOT_COUNT: u8 = 3;
-const SAVE_VERSION: u32 = 3;
+const SAVE_VERSION: u32 = 8;
 const CAMPAIGN_SAVE_VERSION: u32 = 3;
 
 #[derive(Debug, Clone)]
@@ -29,9 +45,84 @@ pub struct SaveSlotMetadata {
     pub loadable: bool,
     pub scenario: Option<SaveScenario>,
     pub saved_at_unix: Option<u64>,
+    /// Card summary; `None` for empty or unreadable slots.
+    pub summary: Option<SaveSlotSummary>,
     pub status: String,
 }
 
+/// Load-card summary of a save slot.
+///
+/// Written to `slot_N.meta.toml` next to every slot file so the load screen
+/// can show it without parsing the full snapshot.  Saves from before the
+/// summary existed fall back to [`SaveSlotSummary::from_snapshot`] (without a
+/// wave).
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SaveSlotSummary {
+    /// `SAVE_VERSION` of the snapshot it describes.
+    pub version: u32,
+    pub saved_at_unix: u64,
+    pub scenario: SaveScenario,
+    /// Live and parked asteroids.
+    pub asteroid_count: u32,
+    /// Largest asteroid size, in mass units.
+    pub largest_mass: u32,
+    pub score: u32,
+    /// Survival or campaign wave at the time of saving.
+    pub wave: Option<u32>,
+    pub playtime_secs: f32,
+    pub upgrades: UpgradeSummary,
+}
+
+/// Upgrade levels listed in a load card's details.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UpgradeSummary {
+    pub primary_chip: u32,
+    pub primary_destroy: u32,
+    pub fire_rate: u32,
+    pub secondary: u32,
+    pub ore_affinity: u32,
+    pub tractor: u32,
+    pub shield: u32,
+    pub mining_drone: u32,
+}
+
+impl SaveSlotSummary {
+    pub fn from_snapshot(snapshot: &SaveSnapshot, wave: Option<u32>) -> Self {
+        let parked = snapshot
+            .sectors
+            .iter()
+            .flat_map(|sector| sector.asteroids.iter());
+        let (asteroid_count, largest_mass) = snapshot
+            .asteroids
+            .iter()
+            .chain(parked)
+            .fold((0_u32, 0_u32), |(count, largest), asteroid| {
+                (count + 1, largest.max(asteroid.size))
+            });
+        let res = &snapshot.resources;
+        Self {
+            version: snapshot.version,
+            saved_at_unix: snapshot.saved_at_unix,
+            scenario: snapshot.scenario,
+            asteroid_count,
+            largest_mass,
+            score: res.score_points,
+            wave,
+            playtime_secs: snapshot.playtime_secs,
+            upgrades: UpgradeSummary {
+                primary_chip: res.primary_weapon_chip_level,
+                primary_destroy: res.primary_weapon_destroy_level,
+                fire_rate: res.primary_weapon_fire_rate_level,
+                secondary: res.secondary_weapon_level,
+                ore_affinity: res.ore_affinity_level,
+                tractor: res.tractor_beam_level,
+                shield: res.shield_level,
+                mining_drone: res.mining_drone_level,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct CampaignSlotMetadata {
     pub slot: u8,
@@ -74,12 +165,32 @@ pub struct CampaignAutosaveState {
     pub last_saved_mission_index: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+/// Practice autosave and the "session running" flag file.
+///
+/// `saves/session.dirty` exists from the start of a practice session until
+/// it ends through the menus, game over or a normal quit.  Finding it at
+/// startup means the last session never ended cleanly.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct SessionAutosaveState {
+    /// A practice session holds the flag file.
+    pub active: bool,
+    pub since_save_secs: f32,
+}
+
+/// Autosave left by a session that exited uncleanly; the main menu offers to
+/// resume it.  `None` once resumed or discarded.
+#[derive(Resource, Default, Debug, Clone)]
+pub struct CrashRecovery(pub Option<SaveSnapshot>);
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SaveScenario {
     Field,
     Orbit,
     Comets,
     Shower,
+    Survival,
+    Catalog,
+    Frontier,
 }
 
 impl From<SelectedScenario> for SaveScenario {
@@ -89,6 +200,9 @@ impl From<SelectedScenario> for SaveScenario {
             SelectedScenario::Orbit => Self::Orbit,
             SelectedScenario::Comets => Self::Comets,
             SelectedScenario::Shower => Self::Shower,
+            SelectedScenario::Survival => Self::Survival,
+            SelectedScenario::Catalog => Self::Catalog,
+            SelectedScenario::Frontier => Self::Frontier,
         }
     }
 }
@@ -100,6 +214,9 @@ impl From<SaveScenario> for SelectedScenario {
             SaveScenario::Orbit => Self::Orbit,
             SaveScenario::Comets => Self::Comets,
             SaveScenario::Shower => Self::Shower,
+            SaveScenario::Survival => Self::Survival,
+            SaveScenario::Catalog => Self::Catalog,
+            SaveScenario::Frontier => Self::Frontier,
         }
     }
 }
@@ -112,6 +229,9 @@ impl SaveScenario {
             SaveScenario::Orbit => "ORBIT",
             SaveScenario::Comets => "COMETS",
             SaveScenario::Shower => "SHOWER",
+            SaveScenario::Survival => "SURVIVAL",
+            SaveScenario::Catalog => "CATALOG",
+            SaveScenario::Frontier => "FRONTIER",
         }
     }
 }
@@ -123,7 +243,24 @@ pub struct SaveSnapshot {
     pub scenario: SaveScenario,
     pub player: Option<PlayerSnapshot>,
     pub asteroids: Vec<AsteroidSnapshot>,
+    /// Asteroids parked in the sector store (see `simulation::sectors`).
+    pub sectors: Vec<SectorSnapshot>,
+    /// Chunks the FRONTIER field has already generated (see
+    /// `simulation::procgen`).
+    pub generated_chunks: Vec<[i32; 2]>,
+    /// Absolute chunk of the live world's origin after recentering (see
+    /// `simulation::origin`); absent in older saves, which never moved it.
+    #[serde(default)]
+    pub origin_chunk: [i32; 2],
+    /// Difficulty preset the run was started on; absent in older saves,
+    /// which all played Normal.
+    #[serde(default)]
+    pub difficulty: Difficulty,
+    /// Seconds the run has spent in `Playing`.
+    pub playtime_secs: f32,
     pub resources: ResourceSnapshot,
+    /// Scenario, seed, mode and version of the run that was saved.
+    pub run: RunMetadata,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -139,6 +276,7 @@ pub struct CampaignSaveSnapshot {
     pub primary_weapon_fire_rate_level: u32,
     pub secondary_weapon_level: u32,
     pub ion_cannon_level: u32,
+    pub shield_level: u32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -149,7 +287,11 @@ pub struct ResourceSnapshot {
     pub score_points: u32,
     pub lives_remaining: i32,
     pub lives_respawn_timer: Option<f32>,
-    pub ore_count: u32,
+    /// Raw ore per kind, indexed by `OreKind::index`.
+    pub ore_raw: [u32; 3],
+    pub ore_credits: u32,
+    /// Raw ore still in the refinery, indexed by `OreKind::index`.
+    pub ore_refining: [u32; 3],
     pub missile_ammo: u32,
     pub primary_weapon_chip_level: u32,
     pub primary_weapon_destroy_level: u32,
@@ -157,6 +299,9 @@ pub struct ResourceSnapshot {
     pub secondary_weapon_level: u32,
     pub ore_affinity_level: u32,
     pub tractor_beam_level: u32,
+    pub shield_level: u32,
+    pub mining_drone_level: u32,
+    pub turret_level: u32,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -179,6 +324,45 @@ pub struct AsteroidSnapshot {
     pub angvel: f32,
     pub size: u32,
     pub vertices: Vec<[f32; 2]>,
+    pub material: AsteroidMaterial,
+    /// Merge history; absent for bodies that never merged.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lineage: Option<Lineage>,
+    /// Detonates when hit (see `simulation::volatile`); absent when false.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub volatile: bool,
+}
+
+impl AsteroidSnapshot {
+    /// Record a live asteroid.
+    pub fn capture(
+        transform: &Transform,
+        vel: &Velocity,
+        size: &AsteroidSize,
+        vertices: &Vertices,
+        material: Option<&AsteroidMaterial>,
+        lineage: Option<&Lineage>,
+        volatile: bool,
+    ) -> Self {
+        Self {
+            pos: [transform.translation.x, transform.translation.y],
+            rot: transform.rotation.to_euler(EulerRot::XYZ).2,
+            linvel: [vel.linvel.x, vel.linvel.y],
+            angvel: vel.angvel,
+            size: size.0,
+            vertices: vertices.0.iter().map(|v| [v.x, v.y]).collect(),
+            material: material.copied().unwrap_or_default(),
+            lineage: lineage.cloned(),
+            volatile,
+        }
+    }
+}
+
+/// The parked asteroids of one sector.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SectorSnapshot {
+    pub coord: [i32; 2],
+    pub asteroids: Vec<AsteroidSnapshot>,
 }
 
 pub struct SavePlugin;
@@ -189,7 +373,18 @@ impl Plugin for SavePlugin {
             .init_resource::<PendingLoadedCampaign>()
             .init_resource::<ActiveCampaignSlot>()
             .init_resource::<CampaignAutosaveState>()
+            .init_resource::<SessionAutosaveState>()
+            .init_resource::<CrashRecovery>()
             .add_message::<SaveSlotRequest>()
+            .add_systems(Startup, detect_crash_recovery_system)
+            .add_systems(OnEnter(GameState::Playing), begin_session_system)
+            .add_systems(OnEnter(GameState::MainMenu), end_session_system)
+            .add_systems(OnEnter(GameState::GameOver), end_session_system)
+            .add_systems(Last, end_session_on_exit_system)
+            .add_systems(
+                Update,
+                autosave_session_system.run_if(in_state(GameState::Playing)),
+            )
             .add_systems(
                 Update,
                 handle_save_slot_requests_system.run_if(in_state(GameState::Paused)),
@@ -209,10 +404,22 @@ fn slot_path(slot: u8) -> PathBuf {
     save_dir().join(format!("slot_{slot}.toml"))
 }
 
+fn slot_summary_path(slot: u8) -> PathBuf {
+    save_dir().join(format!("slot_{slot}.meta.toml"))
+}
+
 fn campaign_slot_path(slot: u8) -> PathBuf {
     save_dir().join(format!("campaign_slot_{slot}.toml"))
 }
 
+fn autosave_path() -> PathBuf {
+    save_dir().join("autosave.toml")
+}
+
+fn session_flag_path() -> PathBuf {
+    save_dir().join("session.dirty")
+}
+
 pub fn slot_exists(slot: u8) -> bool {
     if !(1..=SAVE_SLOT_COUNT).contains(&slot) {
         return false;
@@ -259,6 +466,7 @@ pub fn slot_metadata(slot: u8) -> SaveSlotMetadata {
             loadable: false,
             scenario: None,
             saved_at_unix: None,
+            summary: None,
             status: "INVALID SLOT".to_string(),
         };
     }
@@ -270,30 +478,47 @@ pub fn slot_metadata(slot: u8) -> SaveSlotMetadata {
             loadable: false,
             scenario: None,
             saved_at_unix: None,
+            summary: None,
             status: "EMPTY".to_string(),
         };
     }
 
-    match load_slot(slot) {
-        Ok(snapshot) => SaveSlotMetadata {
+    let summary = read_slot_summary(slot).or_else(|| {
+        load_slot(slot)
+            .ok()
+            .map(|snapshot| SaveSlotSummary::from_snapshot(&snapshot, None))
+    });
+    match summary {
+        Some(summary) => SaveSlotMetadata {
             slot,
             exists: true,
             loadable: true,
-            scenario: Some(snapshot.scenario),
-            saved_at_unix: Some(snapshot.saved_at_unix),
+            scenario: Some(summary.scenario),
+            saved_at_unix: Some(summary.saved_at_unix),
+            summary: Some(summary),
             status: "READY".to_string(),
         },
-        Err(_) => SaveSlotMetadata {
+        None => SaveSlotMetadata {
             slot,
             exists: true,
             loadable: false,
             scenario: None,
             saved_at_unix: None,
+            summary: None,
             status: "CORRUPT".to_string(),
         },
     }
 }
 
+/// The summary written next to a slot, if present, readable and describing
+/// a save version this build loads.
+fn read_slot_summary(slot: u8) -> Option<SaveSlotSummary> {
+    let contents = fs::read_to_string(slot_summary_path(slot)).ok()?;
+    toml::from_str::<SaveSlotSummary>(&contents)
+        .ok()
+        .filter(|summary| summary.version == SAVE_VERSION)
+}
+
 pub fn campaign_slot_metadata(slot: u8) -> CampaignSlotMetadata {
     if !(1..=SAVE_SLOT_COUNT).contains(&slot) {
         return CampaignSlotMetadata {
@@ -421,9 +646,96 @@ fn migrate_snapshot_value(value: &mut toml::Value) -> Result<(), String> {
         if !resources.contains_key("tractor_beam_level") {
             resources.insert("tractor_beam_level".to_string(), toml::Value::Integer(0));
         }
+        if !resources.contains_key("shield_level") {
+            resources.insert("shield_level".to_string(), toml::Value::Integer(0));
+        }
+        if !resources.contains_key("mining_drone_level") {
+            resources.insert("mining_drone_level".to_string(), toml::Value::Integer(0));
+        }
+        if !resources.contains_key("turret_level") {
+            resources.insert("turret_level".to_string(), toml::Value::Integer(0));
+        }
+        // v3 and earlier had a single ore count; it was all iron.
+        if !resources.contains_key("ore_raw") {
+            let legacy_count = resources
+                .get("ore_count")
+                .and_then(toml::Value::as_integer)
+                .unwrap_or(0);
+            resources.insert(
+                "ore_raw".to_string(),
+                toml::Value::Array(vec![
+                    toml::Value::Integer(legacy_count),
+                    toml::Value::Integer(0),
+                    toml::Value::Integer(0),
+                ]),
+            );
+        }
+        if !resources.contains_key("ore_credits") {
+            resources.insert("ore_credits".to_string(), toml::Value::Integer(0));
+        }
+        if !resources.contains_key("ore_refining") {
+            resources.insert(
+                "ore_refining".to_string(),
+                toml::Value::Array(vec![toml::Value::Integer(0); 3]),
+            );
+        }
     }
 
-    if version == 1 || version == 2 {
+    if let Some(asteroids) = table
+        .get_mut("asteroids")
+        .and_then(toml::Value::as_array_mut)
+    {
+        for asteroid in asteroids.iter_mut().filter_map(toml::Value::as_table_mut) {
+            if !asteroid.contains_key("material") {
+                asteroid.insert(
+                    "material".to_string(),
+                    toml::Value::String("Rock".to_string()),
+                );
+            }
+        }
+    }
+
+    // v5 and earlier removed far asteroids instead of parking them.
+    if !table.contains_key("sectors") {
+        table.insert("sectors".to_string(), toml::Value::Array(Vec::new()));
+    }
+
+    // v6 and earlier had no procedural field.
+    if !table.contains_key("generated_chunks") {
+        table.insert(
+            "generated_chunks".to_string(),
+            toml::Value::Array(Vec::new()),
+        );
+    }
+
+    // v7 and earlier did not track playtime.
+    if !table.contains_key("playtime_secs") {
+        table.insert("playtime_secs".to_string(), toml::Value::Float(0.0));
+    }
+
+    // v4 and earlier did not record the run; the seed is unknown.
+    if !table.contains_key("run") {
+        let scenario = table
+            .get("scenario")
+            .and_then(toml::Value::as_str)
+            .unwrap_or("Field")
+            .to_uppercase();
+        let mut run = toml::map::Map::new();
+        run.insert("scenario".to_string(), toml::Value::String(scenario));
+        run.insert("seed".to_string(), toml::Value::String("0x0".to_string()));
+        run.insert(
+            "mode".to_string(),
+            toml::Value::String("practice".to_string()),
+        );
+        run.insert("mutators".to_string(), toml::Value::Array(Vec::new()));
+        run.insert(
+            "version".to_string(),
+            toml::Value::String("unknown".to_string()),
+        );
+        table.insert("run".to_string(), toml::Value::Table(run));
+    }
+
+    if (1..=7).contains(&version) {
         table.insert(
             "version".to_string(),
             toml::Value::Integer(SAVE_VERSION as i64),
@@ -506,6 +818,9 @@ fn migrate_campaign_snapshot_value(value: &mut toml::Value) -> Result<(), String
     if !table.contains_key("ion_cannon_level") {
         table.insert("ion_cannon_level".to_string(), toml::Value::Integer(0));
     }
+    if !table.contains_key("shield_level") {
+        table.insert("shield_level".to_string(), toml::Value::Integer(0));
+    }
 
     if version == 1 || version == 2 {
         table.insert(
@@ -522,17 +837,27 @@ fn migrate_campaign_snapshot_value(value: &mut toml::Value) -> Result<(), String
     Ok(())
 }
 
-fn write_slot(slot: u8, snapshot: &SaveSnapshot) -> Result<(), String> {
+/// Serialize a snapshot exactly as it is written to a slot file.
+pub fn encode_snapshot(snapshot: &SaveSnapshot) -> Result<String, String> {
+    toml::to_string_pretty(snapshot).map_err(|err| format!("failed to serialize save TOML: {err}"))
+}
+
+fn write_slot(slot: u8, snapshot: &SaveSnapshot, summary: &SaveSlotSummary) -> Result<(), String> {
     if !(1..=SAVE_SLOT_COUNT).contains(&slot) {
         return Err(format!("invalid slot {slot}"));
     }
 
     fs::create_dir_all(save_dir()).map_err(|err| format!("failed to create save dir: {err}"))?;
 
-    let serialized = toml::to_string_pretty(snapshot)
-        .map_err(|err| format!("failed to serialize save TOML: {err}"))?;
+    let serialized = encode_snapshot(snapshot)?;
 
     let path = slot_path(slot);
+    fs::write(&path, serialized)
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))?;
+
+    let serialized = toml::to_string_pretty(summary)
+        .map_err(|err| format!("failed to serialize save summary TOML: {err}"))?;
+    let path = slot_summary_path(slot);
     fs::write(&path, serialized).map_err(|err| format!("failed to write {}: {err}", path.display()))
 }
 
@@ -550,6 +875,43 @@ fn write_campaign_slot(slot: u8, snapshot: &CampaignSaveSnapshot) -> Result<(),
     fs::write(&path, serialized).map_err(|err| format!("failed to write {}: {err}", path.display()))
 }
 
+/// Write the practice autosave.
+pub fn write_autosave(snapshot: &SaveSnapshot) -> Result<(), String> {
+    fs::create_dir_all(save_dir()).map_err(|err| format!("failed to create save dir: {err}"))?;
+    let path = autosave_path();
+    fs::write(&path, encode_snapshot(snapshot)?)
+        .map_err(|err| format!("failed to write {}: {err}", path.display()))
+}
+
+pub fn load_autosave() -> Result<SaveSnapshot, String> {
+    let path = autosave_path();
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    parse_snapshot_with_migration(&contents)
+}
+
+/// Delete the practice autosave, if any.
+pub fn discard_autosave() {
+    let _ = fs::remove_file(autosave_path());
+}
+
+/// Consume the session flag left by an unclean exit and return the autosave
+/// it guards.  `None` after a clean exit or when no autosave was written.
+pub fn take_crash_recovery() -> Option<SaveSnapshot> {
+    let flag = session_flag_path();
+    if !flag.exists() {
+        return None;
+    }
+    let _ = fs::remove_file(flag);
+    match load_autosave() {
+        Ok(snapshot) => Some(snapshot),
+        Err(err) => {
+            warn!("Last session exited uncleanly but has no usable autosave: {err}");
+            None
+        }
+    }
+}
+
 pub fn ensure_campaign_slot(slot: u8) -> Result<CampaignSaveSnapshot, String> {
     if let Ok(existing) = load_campaign_slot(slot) {
         return Ok(existing);
@@ -567,6 +929,7 @@ pub fn ensure_campaign_slot(slot: u8) -> Result<CampaignSaveSnapshot, String> {
         primary_weapon_fire_rate_level: 0,
         secondary_weapon_level: 0,
         ion_cannon_level: 0,
+        shield_level: 0,
     };
     write_campaign_slot(slot, &snapshot)?;
     Ok(snapshot)
@@ -584,6 +947,7 @@ pub fn save_campaign_slot_progress(
     primary_weapon_fire_rate_level: u32,
     secondary_weapon_level: u32,
     ion_cannon_level: u32,
+    shield_level: u32,
 ) -> Result<(), String> {
     let snapshot = CampaignSaveSnapshot {
         version: CAMPAIGN_SAVE_VERSION,
@@ -599,6 +963,7 @@ pub fn save_campaign_slot_progress(
             .min(PrimaryWeaponFireRateLevel::MAX),
         secondary_weapon_level: secondary_weapon_level.min(SecondaryWeaponLevel::MAX),
         ion_cannon_level: ion_cannon_level.min(IonCannonLevel::MAX),
+        shield_level: shield_level.min(ShieldLevel::MAX),
     };
     write_campaign_slot(slot, &snapshot)
 }
@@ -622,77 +987,197 @@ pub fn save_campaign_slot_named(
         existing.primary_weapon_fire_rate_level,
         existing.secondary_weapon_level,
         existing.ion_cannon_level,
+        existing.shield_level,
     )
 }
 
-#[allow(clippy::too_many_arguments)]
-pub fn handle_save_slot_requests_system(
-    mut requests: MessageReader<SaveSlotRequest>,
-    scenario: Res<SelectedScenario>,
-    score: Res<PlayerScore>,
-    lives: Res<PlayerLives>,
-    ore: Res<PlayerOre>,
-    ammo: Res<MissileAmmo>,
-    primary_tracks: Res<PrimaryWeaponUpgradeTracks>,
-    fire_rate_level: Res<PrimaryWeaponFireRateLevel>,
-    secondary_level: Res<SecondaryWeaponLevel>,
-    affinity_level: Res<OreAffinityLevel>,
-    tractor_level: Res<TractorBeamLevel>,
-    q_player: Query<(&Transform, &Velocity, &PlayerHealth), With<Player>>,
-    q_asteroids: Query<(&Transform, &Velocity, &AsteroidSize, &Vertices), With<Asteroid>>,
-) {
-    for request in requests.read() {
-        let player_snapshot = q_player
-            .single()
-            .ok()
-            .map(|(transform, vel, hp)| PlayerSnapshot {
-                pos: [transform.translation.x, transform.translation.y],
-                rot: transform.rotation.to_euler(EulerRot::XYZ).2,
-                linvel: [vel.linvel.x, vel.linvel.y],
-                angvel: vel.angvel,
-                hp: hp.hp,
-                max_hp: hp.max_hp,
-                inv_timer: hp.inv_timer,
-                time_since_damage: hp.time_since_damage,
-            });
+/// Everything a [`SaveSnapshot`] is captured from.
+///
+/// Shared by slot saves and the pause menu's issue report so both record the
+/// same state.
+#[derive(SystemParam)]
+#[allow(clippy::type_complexity)]
+pub struct SaveSources<'w, 's> {
+    scenario: Res<'w, SelectedScenario>,
+    score: Res<'w, PlayerScore>,
+    lives: Res<'w, PlayerLives>,
+    ore: Res<'w, PlayerOre>,
+    ammo: Res<'w, MissileAmmo>,
+    primary_tracks: Res<'w, PrimaryWeaponUpgradeTracks>,
+    fire_rate_level: Res<'w, PrimaryWeaponFireRateLevel>,
+    secondary_level: Res<'w, SecondaryWeaponLevel>,
+    affinity_level: Res<'w, OreAffinityLevel>,
+    tractor_level: Res<'w, TractorBeamLevel>,
+    shield_level: Res<'w, ShieldLevel>,
+    drone_level: Res<'w, MiningDroneLevel>,
+    turret_level: Res<'w, TurretLevel>,
+    run: Res<'w, RunMetadata>,
+    difficulty: Res<'w, Difficulty>,
+    sectors: Res<'w, SectorStore>,
+    procgen: Res<'w, ProceduralField>,
+    playtime: Res<'w, RunPlaytime>,
+    survival: Option<Res<'w, SurvivalState>>,
+    campaign: Option<Res<'w, CampaignSession>>,
+    wave_director: Option<Res<'w, CampaignWaveDirector>>,
+    q_player:
+        Query<'w, 's, (&'static Transform, &'static Velocity, &'static PlayerHealth), With<Player>>,
+    q_asteroids: Query<
+        'w,
+        's,
+        (
+            &'static Transform,
+            &'static Velocity,
+            &'static AsteroidSize,
+            &'static Vertices,
+            Option<&'static AsteroidMaterial>,
+            Option<&'static Lineage>,
+            Has<Volatile>,
+        ),
+        With<Asteroid>,
+    >,
+}
+
+impl SaveSources<'_, '_> {
+    /// Current survival or campaign wave, if either is running.
+    pub fn wave(&self) -> Option<u32> {
+        if let Some(survival) = self.survival.as_ref().filter(|s| s.is_active()) {
+            return Some(survival.wave);
+        }
+        self.campaign
+            .as_ref()
+            .filter(|session| session.active)
+            .and(self.wave_director.as_ref())
+            .map(|director| director.current_wave)
+    }
 
-        let asteroids = q_asteroids
+    /// Capture the current session.
+    pub fn snapshot(&self) -> SaveSnapshot {
+        let player_snapshot =
+            self.q_player
+                .single()
+                .ok()
+                .map(|(transform, vel, hp)| PlayerSnapshot {
+                    pos: [transform.translation.x, transform.translation.y],
+                    rot: transform.rotation.to_euler(EulerRot::XYZ).2,
+                    linvel: [vel.linvel.x, vel.linvel.y],
+                    angvel: vel.angvel,
+                    hp: hp.hp,
+                    max_hp: hp.max_hp,
+                    inv_timer: hp.inv_timer,
+                    time_since_damage: hp.time_since_damage,
+                });
+
+        let asteroids = self
+            .q_asteroids
             .iter()
-            .map(|(transform, vel, size, vertices)| AsteroidSnapshot {
-                pos: [transform.translation.x, transform.translation.y],
-                rot: transform.rotation.to_euler(EulerRot::XYZ).2,
-                linvel: [vel.linvel.x, vel.linvel.y],
-                angvel: vel.angvel,
-                size: size.0,
-                vertices: vertices.0.iter().map(|v| [v.x, v.y]).collect(),
-            })
+            .map(
+                |(transform, vel, size, vertices, material, lineage, volatile)| {
+                    AsteroidSnapshot::capture(
+                        transform, vel, size, vertices, material, lineage, volatile,
+                    )
+                },
+            )
             .collect();
 
-        let snapshot = SaveSnapshot {
+        SaveSnapshot {
             version: SAVE_VERSION,
             saved_at_unix: current_unix_timestamp(),
-            scenario: SaveScenario::from(*scenario),
+            scenario: SaveScenario::from(*self.scenario),
             player: player_snapshot,
             asteroids,
+            sectors: self.sectors.to_snapshots(),
+            generated_chunks: self.procgen.to_snapshots(),
+            origin_chunk: self.procgen.origin.into(),
+            difficulty: *self.difficulty,
+            playtime_secs: self.playtime.secs,
             resources: ResourceSnapshot {
-                score_hits: score.hits,
-                score_destroyed: score.destroyed,
-                score_streak: score.streak,
-                score_points: score.points,
-                lives_remaining: lives.remaining,
-                lives_respawn_timer: lives.respawn_timer,
-                ore_count: ore.count,
-                missile_ammo: ammo.count,
-                primary_weapon_chip_level: primary_tracks.chip_level,
-                primary_weapon_destroy_level: primary_tracks.destroy_level,
-                primary_weapon_fire_rate_level: fire_rate_level.level,
-                secondary_weapon_level: secondary_level.level,
-                ore_affinity_level: affinity_level.level,
-                tractor_beam_level: tractor_level.level,
+                score_hits: self.score.hits,
+                score_destroyed: self.score.destroyed,
+                score_streak: self.score.streak,
+                score_points: self.score.points,
+                lives_remaining: self.lives.remaining,
+                lives_respawn_timer: self.lives.respawn_timer,
+                ore_raw: self.ore.raw,
+                ore_credits: self.ore.credits,
+                ore_refining: self.ore.refining,
+                missile_ammo: self.ammo.count,
+                primary_weapon_chip_level: self.primary_tracks.chip_level,
+                primary_weapon_destroy_level: self.primary_tracks.destroy_level,
+                primary_weapon_fire_rate_level: self.fire_rate_level.level,
+                secondary_weapon_level: self.secondary_level.level,
+                ore_affinity_level: self.affinity_level.level,
+                tractor_beam_level: self.tractor_level.level,
+                shield_level: self.shield_level.level,
+                mining_drone_level: self.drone_level.level,
+                turret_level: self.turret_level.level,
             },
-        };
+            run: self.run.clone(),
+        }
+    }
+}
+
+/// Spawn a live asteroid from a save or sector-store record.
+///
+/// Returns `None` for degenerate records with fewer than three vertices.
+pub fn spawn_asteroid_from_snapshot(
+    commands: &mut Commands,
+    asteroid: &AsteroidSnapshot,
+) -> Option<Entity> {
+    if asteroid.vertices.len() < 3 {
+        return None;
+    }
+
+    let hull: Vec<Vec2> = asteroid
+        .vertices
+        .iter()
+        .map(|v| Vec2::new(v[0], v[1]))
+        .collect();
+
+    let entity = asteroid::spawn_asteroid_with_vertices(
+        commands,
+        Vec2::new(asteroid.pos[0], asteroid.pos[1]),
+        &hull,
+        Color::WHITE,
+        asteroid.size,
+    );
+
+    let transform = Transform {
+        translation: Vec3::new(asteroid.pos[0], asteroid.pos[1], 0.05),
+        rotation: Quat::from_rotation_z(asteroid.rot),
+        scale: Vec3::ONE,
+    };
 
-        match write_slot(request.slot, &snapshot) {
+    commands.entity(entity).insert((
+        transform,
+        GlobalTransform::from(transform),
+        Velocity {
+            linvel: Vec2::new(asteroid.linvel[0], asteroid.linvel[1]),
+            angvel: asteroid.angvel,
+        },
+        asteroid.material,
+    ));
+    if let Some(lineage) = asteroid
+        .lineage
+        .as_ref()
+        .filter(|lineage| !lineage.nodes().is_empty())
+    {
+        commands.entity(entity).insert(lineage.clone());
+    }
+    if asteroid.volatile {
+        commands.entity(entity).insert(Volatile);
+    }
+    Some(entity)
+}
+
+pub fn handle_save_slot_requests_system(
+    mut requests: MessageReader<SaveSlotRequest>,
+    sources: SaveSources,
+) {
+    for request in requests.read() {
+        let snapshot = sources.snapshot();
+        let summary = SaveSlotSummary::from_snapshot(&snapshot, sources.wave());
+
+        match write_slot(request.slot, &snapshot, &summary) {
             Ok(()) => {
                 info!("Saved game to slot {}", request.slot);
             }
@@ -718,6 +1203,15 @@ pub fn apply_pending_loaded_snapshot_system(
     mut secondary_level: ResMut<SecondaryWeaponLevel>,
     mut affinity_level: ResMut<OreAffinityLevel>,
     mut tractor_level: ResMut<TractorBeamLevel>,
+    (mut shield_level, mut turret_level): (ResMut<ShieldLevel>, ResMut<TurretLevel>),
+    mut drone_level: ResMut<MiningDroneLevel>,
+    (mut run, mut sectors, mut procgen, mut playtime, mut difficulty): (
+        ResMut<RunMetadata>,
+        ResMut<SectorStore>,
+        ResMut<ProceduralField>,
+        ResMut<RunPlaytime>,
+        ResMut<Difficulty>,
+    ),
 ) {
     let Some(snapshot) = pending.0.take() else {
         warn!("No pending save snapshot found on load transition");
@@ -725,6 +1219,11 @@ pub fn apply_pending_loaded_snapshot_system(
     };
 
     *selected_scenario = SelectedScenario::from(snapshot.scenario);
+    *run = snapshot.run;
+    info!("Run: {}", run.summary());
+    *difficulty = snapshot.difficulty;
+    playtime.secs = snapshot.playtime_secs;
+    commands.insert_resource(SpeedrunSplits::for_loaded_run());
 
     *score = PlayerScore {
         hits: snapshot.resources.score_hits,
@@ -737,7 +1236,10 @@ pub fn apply_pending_loaded_snapshot_system(
         respawn_timer: snapshot.resources.lives_respawn_timer,
     };
     *ore = PlayerOre {
-        count: snapshot.resources.ore_count,
+        raw: snapshot.resources.ore_raw,
+        credits: snapshot.resources.ore_credits,
+        refining: snapshot.resources.ore_refining,
+        refine_progress_secs: 0.0,
     };
     ammo.count = snapshot.resources.missile_ammo;
     primary_tracks.chip_level = snapshot
@@ -764,41 +1266,25 @@ pub fn apply_pending_loaded_snapshot_system(
         .resources
         .tractor_beam_level
         .min(TractorBeamLevel::MAX);
+    shield_level.level = snapshot.resources.shield_level.min(ShieldLevel::MAX);
+    turret_level.level = snapshot.resources.turret_level.min(TurretLevel::MAX);
+    drone_level.level = snapshot
+        .resources
+        .mining_drone_level
+        .min(MiningDroneLevel::MAX);
 
-    for asteroid in snapshot.asteroids {
-        if asteroid.vertices.len() < 3 {
-            continue;
-        }
-
-        let hull: Vec<Vec2> = asteroid
-            .vertices
-            .iter()
-            .map(|v| Vec2::new(v[0], v[1]))
-            .collect();
-
-        let entity = asteroid::spawn_asteroid_with_vertices(
-            &mut commands,
-            Vec2::new(asteroid.pos[0], asteroid.pos[1]),
-            &hull,
-            Color::WHITE,
-            asteroid.size,
-        );
-
-        let transform = Transform {
-            translation: Vec3::new(asteroid.pos[0], asteroid.pos[1], 0.05),
-            rotation: Quat::from_rotation_z(asteroid.rot),
-            scale: Vec3::ONE,
-        };
-
-        commands.entity(entity).insert((
-            transform,
-            GlobalTransform::from(transform),
-            Velocity {
-                linvel: Vec2::new(asteroid.linvel[0], asteroid.linvel[1]),
-                angvel: asteroid.angvel,
-            },
-        ));
+    for asteroid in &snapshot.asteroids {
+        spawn_asteroid_from_snapshot(&mut commands, asteroid);
     }
+    *sectors = SectorStore::from_snapshots(snapshot.sectors);
+    *procgen = match *selected_scenario {
+        SelectedScenario::Frontier => ProceduralField::from_snapshots(
+            run.seed,
+            snapshot.generated_chunks,
+            snapshot.origin_chunk,
+        ),
+        _ => ProceduralField::default(),
+    };
 
     if let Some(player) = snapshot.player {
         commands.spawn((
@@ -852,6 +1338,7 @@ pub fn apply_pending_loaded_campaign_system(
     mut fire_rate_level: ResMut<PrimaryWeaponFireRateLevel>,
     mut secondary_level: ResMut<SecondaryWeaponLevel>,
     mut ion_level: ResMut<IonCannonLevel>,
+    mut shield_level: ResMut<ShieldLevel>,
 ) {
     let Some(snapshot) = pending.0.take() else {
         return;
@@ -874,6 +1361,7 @@ pub fn apply_pending_loaded_campaign_system(
         .secondary_weapon_level
         .min(SecondaryWeaponLevel::MAX);
     ion_level.level = snapshot.ion_cannon_level.min(IonCannonLevel::MAX);
+    shield_level.level = snapshot.shield_level.min(ShieldLevel::MAX);
     autosave_state.last_saved_mission_index = campaign_session.mission_index;
 }
 
@@ -887,6 +1375,7 @@ pub fn autosave_campaign_progress_system(
     fire_rate_level: Res<PrimaryWeaponFireRateLevel>,
     secondary_level: Res<SecondaryWeaponLevel>,
     ion_level: Res<IonCannonLevel>,
+    shield_level: Res<ShieldLevel>,
     mut autosave_state: ResMut<CampaignAutosaveState>,
 ) {
     if *selected_mode != SelectedGameMode::Campaign || !session.active {
@@ -911,6 +1400,7 @@ pub fn autosave_campaign_progress_system(
         fire_rate_level.level,
         secondary_level.level,
         ion_level.level,
+        shield_level.level,
     ) {
         Ok(()) => {
             autosave_state.last_saved_mission_index = session.mission_index;
@@ -924,6 +1414,77 @@ pub fn autosave_campaign_progress_system(
     }
 }
 
+/// Look for a session that exited uncleanly.
+pub fn detect_crash_recovery_system(mut recovery: ResMut<CrashRecovery>) {
+    recovery.0 = take_crash_recovery();
+    if recovery.0.is_some() {
+        info!("Found an autosave from a session that did not exit cleanly");
+    }
+}
+
+/// Raise the session flag when a practice session starts.
+///
+/// Also runs on every return to `Playing` from a menu; only the first entry
+/// of a session drops the previous session's autosave and writes the flag.
+pub fn begin_session_system(
+    selected_mode: Res<SelectedGameMode>,
+    mut state: ResMut<SessionAutosaveState>,
+) {
+    if *selected_mode != SelectedGameMode::Practice || state.active {
+        return;
+    }
+    discard_autosave();
+    if let Err(err) = fs::create_dir_all(save_dir())
+        .and_then(|()| fs::write(session_flag_path(), current_unix_timestamp().to_string()))
+    {
+        error!("Failed to write session flag: {err}");
+        return;
+    }
+    state.active = true;
+    state.since_save_secs = 0.0;
+}
+
+/// Drop the session flag when a session ends normally.
+pub fn end_session_system(mut state: ResMut<SessionAutosaveState>) {
+    if !state.active {
+        return;
+    }
+    let _ = fs::remove_file(session_flag_path());
+    state.active = false;
+}
+
+/// Quitting (menu button or closing the window) is a clean exit too.
+///
+/// Runs in `Last` so it sees the `AppExit` sent earlier in the frame.
+pub fn end_session_on_exit_system(
+    mut exit: MessageReader<AppExit>,
+    state: ResMut<SessionAutosaveState>,
+) {
+    if exit.read().count() > 0 {
+        end_session_system(state);
+    }
+}
+
+/// Autosave the practice session every `session_autosave_interval_secs`.
+pub fn autosave_session_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    mut state: ResMut<SessionAutosaveState>,
+    sources: SaveSources,
+) {
+    if !state.active {
+        return;
+    }
+    state.since_save_secs += time.delta_secs();
+    if state.since_save_secs < config.session_autosave_interval_secs {
+        return;
+    }
+    state.since_save_secs = 0.0;
+    if let Err(err) = write_autosave(&sources.snapshot()) {
+        error!("Failed to autosave session: {err}");
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1020,6 +1581,7 @@ mod tests {
             7,
             3,
             4,
+            2,
         )
         .expect("campaign slot progress write should succeed");
 
@@ -1032,6 +1594,7 @@ mod tests {
         assert_eq!(loaded.primary_weapon_fire_rate_level, 7);
         assert_eq!(loaded.secondary_weapon_level, 3);
         assert_eq!(loaded.ion_cannon_level, 4);
+        assert_eq!(loaded.shield_level, 2);
 
         restore_campaign_slot(slot, backup);
     }
@@ -1065,6 +1628,171 @@ tractor_beam_level = 0
         assert_eq!(migrated.resources.primary_weapon_chip_level, 4);
         assert_eq!(migrated.resources.primary_weapon_destroy_level, 4);
         assert_eq!(migrated.resources.primary_weapon_fire_rate_level, 0);
+        assert_eq!(migrated.resources.shield_level, 0);
+        assert_eq!(migrated.difficulty, Difficulty::Normal);
+    }
+
+    pub(super) const V3_SNAPSHOT: &str = r#"
+version = 3
+saved_at_unix = 123
+scenario = "Field"
+
+[[asteroids]]
+pos = [10.0, 20.0]
+rot = 0.0
+linvel = [0.0, 0.0]
+angvel = 0.0
+size = 3
+vertices = [[0.0, 0.0], [4.0, 0.0], [0.0, 4.0]]
+
+[resources]
+score_hits = 0
+score_destroyed = 0
+score_streak = 0
+score_points = 0
+lives_remaining = 3
+lives_respawn_timer = 0.0
+ore_count = 12
+missile_ammo = 5
+primary_weapon_chip_level = 0
+primary_weapon_destroy_level = 0
+primary_weapon_fire_rate_level = 0
+secondary_weapon_level = 0
+ore_affinity_level = 0
+tractor_beam_level = 0
+shield_level = 0
+"#;
+
+    #[test]
+    fn migrate_v3_snapshot_moves_ore_count_to_iron_and_defaults_materials() {
+        let migrated =
+            parse_snapshot_with_migration(V3_SNAPSHOT).expect("v3 snapshot should migrate");
+        assert_eq!(migrated.version, SAVE_VERSION);
+        assert_eq!(migrated.resources.ore_raw, [12, 0, 0]);
+        assert_eq!(migrated.resources.ore_credits, 0);
+        assert_eq!(migrated.resources.ore_refining, [0; 3]);
+        assert_eq!(migrated.resources.mining_drone_level, 0);
+        assert_eq!(migrated.resources.turret_level, 0);
+        assert_eq!(migrated.run.scenario, "FIELD");
+        assert_eq!(migrated.run.seed, 0);
+        assert_eq!(migrated.run.version, "unknown");
+        assert_eq!(migrated.asteroids[0].material, AsteroidMaterial::Rock);
+        assert!(migrated.sectors.is_empty());
+        assert!(migrated.generated_chunks.is_empty());
+        assert_eq!(migrated.playtime_secs, 0.0);
+    }
+
+    #[test]
+    fn perturbed_fragment_hull_round_trips_through_a_snapshot() {
+        use rand::SeedableRng;
+
+        let config = PhysicsConfig::default();
+        let mut rng = rand::rngs::StdRng::seed_from_u64(17);
+        let hull = asteroid::fragment_vertices_for_mass(6, &mut rng, &config);
+        let snapshot = AsteroidSnapshot::capture(
+            &Transform::default(),
+            &Velocity::zero(),
+            &AsteroidSize(6),
+            &Vertices(hull.clone()),
+            None,
+            None,
+            false,
+        );
+
+        let encoded = toml::to_string(&snapshot).unwrap();
+        let decoded: AsteroidSnapshot = toml::from_str(&encoded).unwrap();
+        let restored: Vec<Vec2> = decoded
+            .vertices
+            .iter()
+            .map(|v| Vec2::new(v[0], v[1]))
+            .collect();
+        assert_eq!(restored, hull);
+    }
+
+    #[test]
+    fn slot_summary_is_written_next_to_the_snapshot() {
+        let slot = 3u8;
+        let backups =
+            [slot_path(slot), slot_summary_path(slot)].map(|p| fs::read_to_string(p).ok());
+
+        let mut snapshot = parse_snapshot_with_migration(V3_SNAPSHOT).unwrap();
+        snapshot.playtime_secs = 95.0;
+        snapshot.resources.score_points = 420;
+        let summary = SaveSlotSummary::from_snapshot(&snapshot, Some(4));
+        assert_eq!(summary.asteroid_count, 1);
+        assert_eq!(summary.largest_mass, 3);
+        write_slot(slot, &snapshot, &summary).expect("slot should be written");
+
+        let meta = slot_metadata(slot);
+        assert!(meta.loadable);
+        assert_eq!(meta.summary.as_ref(), Some(&summary));
+        assert_eq!(meta.summary.unwrap().wave, Some(4));
+
+        // Without the sidecar the card falls back to the snapshot itself.
+        let _ = fs::remove_file(slot_summary_path(slot));
+        let fallback = slot_metadata(slot).summary.expect("summary from snapshot");
+        assert_eq!(fallback.wave, None);
+        assert_eq!(fallback.playtime_secs, 95.0);
+
+        for (path, backup) in [slot_path(slot), slot_summary_path(slot)]
+            .into_iter()
+            .zip(backups)
+        {
+            match backup {
+                Some(contents) => {
+                    let _ = fs::write(path, contents);
+                }
+                None => {
+                    let _ = fs::remove_file(path);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn unclean_exit_offers_the_autosave_once() {
+        use bevy::ecs::system::RunSystemOnce;
+
+        let paths = [autosave_path(), session_flag_path()];
+        let backups = paths.clone().map(|p| fs::read_to_string(p).ok());
+        let _ = fs::remove_file(session_flag_path());
+
+        let mut world = World::new();
+        world.insert_resource(SelectedGameMode::Practice);
+        world.insert_resource(SessionAutosaveState::default());
+        world.run_system_once(begin_session_system).unwrap();
+        assert!(session_flag_path().exists());
+
+        let mut snapshot = parse_snapshot_with_migration(V3_SNAPSHOT).unwrap();
+        snapshot.resources.score_points = 77;
+        write_autosave(&snapshot).unwrap();
+
+        // A clean end drops the flag, so nothing is offered.
+        world.run_system_once(end_session_system).unwrap();
+        assert!(!session_flag_path().exists());
+        assert!(take_crash_recovery().is_none());
+
+        // A session that never ended leaves the flag behind.
+        world.run_system_once(begin_session_system).unwrap();
+        assert!(
+            !autosave_path().exists(),
+            "a new session drops the old autosave"
+        );
+        write_autosave(&snapshot).unwrap();
+        let recovered = take_crash_recovery().expect("autosave offered after a crash");
+        assert_eq!(recovered.resources.score_points, 77);
+        assert!(take_crash_recovery().is_none(), "offered only once");
+
+        for (path, backup) in paths.into_iter().zip(backups) {
+            match backup {
+                Some(contents) => {
+                    let _ = fs::write(path, contents);
+                }
+                None => {
+                    let _ = fs::remove_file(path);
+                }
+            }
+        }
     }
 
     #[test]
@@ -1104,6 +1832,7 @@ ion_cannon_level = 1
             primary_weapon_fire_rate_level: 4,
             secondary_weapon_level: 2,
             ion_cannon_level: 3,
+            shield_level: 1,
         })));
         world.insert_resource(ActiveCampaignSlot {
             slot: 1,
@@ -1123,6 +1852,7 @@ ion_cannon_level = 1
         world.insert_resource(PrimaryWeaponFireRateLevel { level: 9 });
         world.insert_resource(SecondaryWeaponLevel { level: 9 });
         world.insert_resource(IonCannonLevel { level: 9 });
+        world.insert_resource(ShieldLevel { level: 4 });
 
         let mut schedule = Schedule::default();
         schedule.add_systems(apply_pending_loaded_campaign_system);
@@ -1146,5 +1876,6 @@ ion_cannon_level = 1
         assert_eq!(fire_rate_level.level, 4);
         assert_eq!(secondary_level.level, 2);
         assert_eq!(ion_level.level, 3);
+        assert_eq!(world.resource::<ShieldLevel>().level, 1);
     }
 }
@@ -0,0 +1,207 @@
+//! Shareable `.accretion` files: one saved world plus the physics it ran under.
+//!
+//! A bundle is a deflate-compressed zip holding
+//! - `bundle.toml`: format version, scenario, seed and export time,
+//! - `save.toml`: the snapshot, encoded exactly as a slot file,
+//! - `physics.toml`: the effective `PhysicsConfig` at export time.
+//!
+//! Exports are written to `exports/`.  Imports come from a file dropped on the
+//! window while the load screen is open, or from the newest bundle in
+//! `imports/` (the IMPORT button).  Importing replaces the live physics config
+//! with the bundled one, which stays active until `assets/physics.toml` is
+//! edited or the game restarts, and then loads the snapshot like a slot load.
+//! Snapshots from older save versions are migrated on import.
+
+use std::fs;
+use std::io::{Read, Seek, Write};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::{current_unix_timestamp, encode_snapshot, parse_snapshot_with_migration, SaveSnapshot};
+use crate::config::{parse_physics_config, PhysicsConfig};
+
+/// File extension of an exported world.
+pub const BUNDLE_EXTENSION: &str = "accretion";
+const BUNDLE_FORMAT: u32 = 1;
+
+pub fn export_dir() -> PathBuf {
+    PathBuf::from("exports")
+}
+
+pub fn import_dir() -> PathBuf {
+    PathBuf::from("imports")
+}
+
+/// `bundle.toml`: what the archive holds, readable without decoding the save.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BundleManifest {
+    pub format: u32,
+    pub exported_at_unix: u64,
+    pub scenario: String,
+    /// World-generation seed (hex, as in `RunMetadata`).
+    pub seed: String,
+}
+
+/// A decoded bundle.
+#[derive(Debug, Clone)]
+pub struct SaveBundle {
+    pub manifest: BundleManifest,
+    pub snapshot: SaveSnapshot,
+    pub physics: PhysicsConfig,
+}
+
+/// Write `snapshot` and `physics` as a bundle archive.
+pub fn write_bundle<W: Write + Seek>(
+    writer: W,
+    snapshot: &SaveSnapshot,
+    physics: &PhysicsConfig,
+) -> Result<W, String> {
+    let manifest = BundleManifest {
+        format: BUNDLE_FORMAT,
+        exported_at_unix: current_unix_timestamp(),
+        scenario: snapshot.run.scenario.clone(),
+        seed: format!("{:016x}", snapshot.run.seed),
+    };
+    let entries = [
+        (
+            "bundle.toml",
+            toml::to_string_pretty(&manifest)
+                .map_err(|err| format!("failed to encode bundle manifest: {err}"))?,
+        ),
+        ("save.toml", encode_snapshot(snapshot)?),
+        (
+            "physics.toml",
+            toml::to_string_pretty(physics)
+                .map_err(|err| format!("failed to encode physics config: {err}"))?,
+        ),
+    ];
+
+    let mut zip = zip::ZipWriter::new(writer);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    for (name, contents) in entries {
+        zip.start_file(name, options)
+            .map_err(|err| format!("failed to add {name}: {err}"))?;
+        zip.write_all(contents.as_bytes())
+            .map_err(|err| format!("failed to write {name}: {err}"))?;
+    }
+    zip.finish()
+        .map_err(|err| format!("failed to finish bundle: {err}"))
+}
+
+/// Decode a bundle archive, migrating an older snapshot if needed.
+pub fn read_bundle<R: Read + Seek>(reader: R) -> Result<SaveBundle, String> {
+    let mut zip =
+        zip::ZipArchive::new(reader).map_err(|err| format!("not an accretion bundle: {err}"))?;
+    let mut entry = |name: &str| -> Result<String, String> {
+        let mut file = zip
+            .by_name(name)
+            .map_err(|err| format!("bundle has no {name}: {err}"))?;
+        let mut contents = String::new();
+        file.read_to_string(&mut contents)
+            .map_err(|err| format!("failed to read {name}: {err}"))?;
+        Ok(contents)
+    };
+
+    let manifest: BundleManifest = toml::from_str(&entry("bundle.toml")?)
+        .map_err(|err| format!("failed to parse bundle.toml: {err}"))?;
+    if manifest.format > BUNDLE_FORMAT {
+        return Err(format!(
+            "bundle format {} is newer than this build supports ({BUNDLE_FORMAT})",
+            manifest.format
+        ));
+    }
+    let snapshot = parse_snapshot_with_migration(&entry("save.toml")?)?;
+    let physics = parse_physics_config(&entry("physics.toml")?)
+        .map_err(|err| format!("failed to parse physics.toml: {err}"))?;
+    Ok(SaveBundle {
+        manifest,
+        snapshot,
+        physics,
+    })
+}
+
+/// Export `snapshot` to `exports/<run stamp>-<time>.accretion`.
+pub fn export_bundle(snapshot: &SaveSnapshot, physics: &PhysicsConfig) -> Result<PathBuf, String> {
+    let dir = export_dir();
+    fs::create_dir_all(&dir).map_err(|err| format!("failed to create {}: {err}", dir.display()))?;
+    let path = dir.join(format!(
+        "{}-{}.{BUNDLE_EXTENSION}",
+        snapshot.run.stamp(),
+        current_unix_timestamp()
+    ));
+    let file = fs::File::create(&path)
+        .map_err(|err| format!("failed to create {}: {err}", path.display()))?;
+    write_bundle(file, snapshot, physics)?;
+    Ok(path)
+}
+
+/// Read a bundle from disk.
+pub fn import_bundle(path: &Path) -> Result<SaveBundle, String> {
+    let file =
+        fs::File::open(path).map_err(|err| format!("failed to open {}: {err}", path.display()))?;
+    read_bundle(file).map_err(|err| format!("{}: {err}", path.display()))
+}
+
+/// Newest `.accretion` file in `imports/`, if any.
+pub fn newest_import() -> Option<PathBuf> {
+    fs::read_dir(import_dir())
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|ext| ext == BUNDLE_EXTENSION)
+        })
+        .filter_map(|entry| Some((entry.metadata().ok()?.modified().ok()?, entry.path())))
+        .max_by_key(|(modified, _)| *modified)
+        .map(|(_, path)| path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::tests::V3_SNAPSHOT;
+    use std::io::Cursor;
+
+    #[test]
+    fn bundle_round_trips_snapshot_physics_and_seed() {
+        let mut snapshot = parse_snapshot_with_migration(V3_SNAPSHOT).unwrap();
+        snapshot.run.seed = 0xDEAD_BEEF_0000_0042;
+        snapshot.resources.score_points = 777;
+        let physics = PhysicsConfig {
+            gravity_const: 3.5,
+            physics_substeps: 7,
+            ..PhysicsConfig::default()
+        };
+
+        let bytes = write_bundle(Cursor::new(Vec::new()), &snapshot, &physics)
+            .unwrap()
+            .into_inner();
+        let bundle = read_bundle(Cursor::new(bytes)).unwrap();
+
+        assert_eq!(bundle.manifest.format, BUNDLE_FORMAT);
+        assert_eq!(bundle.manifest.seed, "deadbeef00000042");
+        assert_eq!(bundle.snapshot.run.seed, snapshot.run.seed);
+        assert_eq!(bundle.snapshot.resources.score_points, 777);
+        assert_eq!(bundle.snapshot.asteroids.len(), snapshot.asteroids.len());
+        assert_eq!(bundle.physics.gravity_const, 3.5);
+        assert_eq!(bundle.physics.physics_substeps, 7);
+    }
+
+    #[test]
+    fn archives_without_a_save_are_rejected() {
+        let mut zip = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file("bundle.toml", zip::write::SimpleFileOptions::default())
+            .unwrap();
+        zip.write_all(b"format = 1\nexported_at_unix = 0\nscenario = \"FIELD\"\nseed = \"0\"\n")
+            .unwrap();
+        let bytes = zip.finish().unwrap().into_inner();
+
+        let err = read_bundle(Cursor::new(bytes)).unwrap_err();
+        assert!(err.contains("no save.toml"), "{err}");
+        assert!(read_bundle(Cursor::new(b"not a zip".to_vec())).is_err());
+    }
+}
@@ -0,0 +1,446 @@
+//! Local per-scenario leaderboard.
+//!
+//! Every practice run that reaches game over is offered to
+//! `saves/leaderboard.toml`, which keeps the best
+//! [`LEADERBOARD_ENTRIES_PER_SCENARIO`] runs per [`SaveScenario`] ranked by
+//! score (ties go to the larger accretion, then the shorter run).  Each entry
+//! records the score, the mass of the largest accretion, the run duration,
+//! the date, and whether the run was played with assists
+//! ([`crate::difficulty::AssistOptions`]).  Assisted runs rank alongside the
+//! rest and are marked on every table.  The same file holds the speedrun
+//! times ([`crate::speedrun`]), so there is one board and one write path.
+//!
+//! The board is loaded once at startup into [`LeaderboardStore`]; the
+//! scenario-select cards show each scenario's best run and the game-over
+//! overlay shows the table with a "NEW RECORD" callout.  Writes go to a
+//! temporary file that is renamed over the board, so a crash mid-write never
+//! leaves a truncated file.  Campaign runs are not recorded; test runs
+//! (`ACCRETION_TEST`) keep the board in memory only.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{current_unix_timestamp, save_dir, SaveScenario};
+use crate::difficulty::{AssistOptions, ASSISTED_MUTATOR};
+use crate::lineage::{BiggestAccretion, Lineage};
+use crate::menu::{GameState, SelectedGameMode, SelectedScenario};
+use crate::player::state::PlayerScore;
+use crate::run_info::RunMetadata;
+use crate::session_stats::SessionStats;
+use crate::speedrun::{parse_legacy_speedrun_times, SpeedrunTime, SpeedrunTimes};
+
+/// Runs kept per scenario.
+pub const LEADERBOARD_ENTRIES_PER_SCENARIO: usize = 10;
+
+const LEADERBOARD_VERSION: u32 = 1;
+
+/// One finished run.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct LeaderboardEntry {
+    pub scenario: SaveScenario,
+    pub score: u32,
+    /// Mass of the largest accretion of the run.
+    pub largest_accretion: u32,
+    pub duration_secs: f32,
+    pub recorded_at_unix: u64,
+    /// Played with at least one assist on; absent in older boards.
+    #[serde(default)]
+    pub assisted: bool,
+}
+
+impl LeaderboardEntry {
+    /// Higher score first, then the larger accretion, then the shorter run.
+    fn ranks_above(&self, other: &Self) -> bool {
+        (self.score, self.largest_accretion) > (other.score, other.largest_accretion)
+            || ((self.score, self.largest_accretion) == (other.score, other.largest_accretion)
+                && self.duration_secs < other.duration_secs)
+    }
+}
+
+/// Contents of the leaderboard file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Leaderboard {
+    pub version: u32,
+    #[serde(default)]
+    pub entries: Vec<LeaderboardEntry>,
+    /// Best speedrun times per category; absent in older boards.
+    #[serde(default)]
+    pub speedruns: SpeedrunTimes,
+}
+
+impl Default for Leaderboard {
+    fn default() -> Self {
+        Self {
+            version: LEADERBOARD_VERSION,
+            entries: Vec::new(),
+            speedruns: SpeedrunTimes::default(),
+        }
+    }
+}
+
+impl Leaderboard {
+    /// Runs for `scenario`, best first.
+    pub fn scenario(&self, scenario: SaveScenario) -> impl Iterator<Item = &LeaderboardEntry> {
+        self.entries.iter().filter(move |e| e.scenario == scenario)
+    }
+
+    /// Best run for `scenario`.
+    pub fn best(&self, scenario: SaveScenario) -> Option<&LeaderboardEntry> {
+        self.scenario(scenario).next()
+    }
+
+    /// Insert `entry` and trim its scenario; returns its 1-based rank, or
+    /// `None` if it did not make the table.
+    pub fn insert(&mut self, entry: LeaderboardEntry) -> Option<usize> {
+        let scenario = entry.scenario;
+        let rank = self
+            .scenario(scenario)
+            .filter(|e| !entry.ranks_above(e))
+            .count();
+        if rank >= LEADERBOARD_ENTRIES_PER_SCENARIO {
+            return None;
+        }
+        let at = self
+            .entries
+            .iter()
+            .position(|e| e.scenario == scenario && entry.ranks_above(e))
+            .unwrap_or(self.entries.len());
+        self.entries.insert(at, entry);
+
+        let mut kept = 0;
+        self.entries.retain(|e| {
+            if e.scenario != scenario {
+                return true;
+            }
+            kept += 1;
+            kept <= LEADERBOARD_ENTRIES_PER_SCENARIO
+        });
+        Some(rank + 1)
+    }
+}
+
+/// Parse a leaderboard file.
+pub fn parse_leaderboard(contents: &str) -> Result<Leaderboard, String> {
+    let board: Leaderboard = toml::from_str(contents)
+        .map_err(|err| format!("failed to parse leaderboard TOML: {err}"))?;
+    if board.version != LEADERBOARD_VERSION {
+        return Err(format!(
+            "unsupported leaderboard version {} (expected {})",
+            board.version, LEADERBOARD_VERSION
+        ));
+    }
+    Ok(board)
+}
+
+/// Write `contents` to `path` through a sibling temporary file and a rename,
+/// so readers see either the old file or the new one.
+pub fn write_atomic(path: &Path, contents: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .map_err(|err| format!("failed to create {}: {err}", parent.display()))?;
+    }
+    let tmp = path.with_extension("toml.tmp");
+    fs::write(&tmp, contents).map_err(|err| format!("failed to write {}: {err}", tmp.display()))?;
+    fs::rename(&tmp, path).map_err(|err| {
+        let _ = fs::remove_file(&tmp);
+        format!("failed to replace {}: {err}", path.display())
+    })
+}
+
+/// `YYYY-MM-DD` (UTC) for a unix timestamp.
+pub fn format_date(unix_secs: u64) -> String {
+    // Civil-from-days (Howard Hinnant), valid for any date after 1970.
+    let days = unix_secs / 86_400;
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// Where the board lives, its loaded contents, and the rank of the run that
+/// just ended.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct LeaderboardStore {
+    /// `None` keeps the board in memory only.
+    pub path: Option<PathBuf>,
+    pub board: Leaderboard,
+    /// Scenario and rank of the run recorded at the last game over; cleared
+    /// when the game-over overlay closes.
+    pub last_record: Option<(SaveScenario, usize)>,
+}
+
+impl LeaderboardStore {
+    /// Add `entry` to the board and write it out; returns its rank.
+    pub fn record(&mut self, entry: LeaderboardEntry) -> Result<Option<usize>, String> {
+        let scenario = entry.scenario;
+        let rank = self.board.insert(entry);
+        self.last_record = rank.map(|rank| (scenario, rank));
+        if rank.is_some() {
+            self.write()?;
+        }
+        Ok(rank)
+    }
+
+    /// Add a speedrun `time` to the board and write it out; returns its rank
+    /// in its category.
+    pub fn record_speedrun(&mut self, time: SpeedrunTime) -> Result<Option<usize>, String> {
+        let rank = self.board.speedruns.insert(time);
+        if rank.is_some() {
+            self.write()?;
+        }
+        Ok(rank)
+    }
+
+    fn write(&self) -> Result<(), String> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let serialized = toml::to_string_pretty(&self.board)
+            .map_err(|err| format!("failed to serialize leaderboard TOML: {err}"))?;
+        write_atomic(path, &serialized)
+    }
+
+    /// Move the times of a standalone `speedrun_times.toml` onto the board,
+    /// then delete that file once the board is written.
+    fn import_legacy_speedruns(&mut self, legacy: &Path) -> Result<(), String> {
+        let Ok(contents) = fs::read_to_string(legacy) else {
+            return Ok(());
+        };
+        for time in parse_legacy_speedrun_times(&contents)? {
+            self.board.speedruns.insert(time);
+        }
+        self.write()?;
+        fs::remove_file(legacy)
+            .map_err(|err| format!("failed to remove {}: {err}", legacy.display()))
+    }
+}
+
+/// Registers the leaderboard store.
+pub struct LeaderboardPlugin {
+    /// Read and write `saves/leaderboard.toml`; off in test runs.
+    pub persist: bool,
+}
+
+impl Plugin for LeaderboardPlugin {
+    fn build(&self, app: &mut App) {
+        let store = LeaderboardStore {
+            path: self.persist.then(|| save_dir().join("leaderboard.toml")),
+            ..default()
+        };
+        app.insert_resource(store)
+            .add_systems(Startup, load_leaderboard_system)
+            .add_systems(OnEnter(GameState::GameOver), record_leaderboard_system)
+            .add_systems(OnExit(GameState::GameOver), clear_last_record_system);
+    }
+}
+
+/// Read the board from disk; a missing file is an empty board.
+pub fn load_leaderboard_system(mut store: ResMut<LeaderboardStore>) {
+    let Some(path) = store.path.clone() else {
+        return;
+    };
+    if let Ok(contents) = fs::read_to_string(&path) {
+        match parse_leaderboard(&contents) {
+            Ok(board) => store.board = board,
+            Err(err) => {
+                // Leave the unreadable board alone rather than overwrite it.
+                warn!("{err}");
+                return;
+            }
+        }
+    }
+    if let Err(err) = store.import_legacy_speedruns(&path.with_file_name("speedrun_times.toml")) {
+        warn!("{err}");
+    }
+}
+
+/// Offer the practice run that just ended to the board.
+///
+/// The run counts as assisted if it started with assists on (its
+/// `assisted` mutator) or has them on now, e.g. after loading a save.
+#[allow(clippy::too_many_arguments)]
+pub fn record_leaderboard_system(
+    mode: Res<SelectedGameMode>,
+    scenario: Res<SelectedScenario>,
+    score: Res<PlayerScore>,
+    biggest: Res<BiggestAccretion>,
+    stats: Res<SessionStats>,
+    run: Res<RunMetadata>,
+    assists: Res<AssistOptions>,
+    mut store: ResMut<LeaderboardStore>,
+) {
+    store.last_record = None;
+    if *mode != SelectedGameMode::Practice {
+        return;
+    }
+    let entry = LeaderboardEntry {
+        scenario: SaveScenario::from(*scenario),
+        score: score.total(),
+        largest_accretion: biggest
+            .lineage
+            .as_ref()
+            .map_or(0, Lineage::mass)
+            .max(stats.largest_body),
+        duration_secs: stats.time_survived_secs,
+        recorded_at_unix: current_unix_timestamp(),
+        assisted: assists.is_assisted() || run.mutators.iter().any(|m| m == ASSISTED_MUTATOR),
+    };
+    let summary = format!("{} score {}", entry.scenario.label(), entry.score);
+    match store.record(entry) {
+        Ok(Some(rank)) => info!("Leaderboard #{rank}: {summary}"),
+        Ok(None) => {}
+        Err(err) => warn!("{err}"),
+    }
+}
+
+fn clear_last_record_system(mut store: ResMut<LeaderboardStore>) {
+    store.last_record = None;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(scenario: SaveScenario, score: u32, largest: u32, secs: f32) -> LeaderboardEntry {
+        LeaderboardEntry {
+            scenario,
+            score,
+            largest_accretion: largest,
+            duration_secs: secs,
+            recorded_at_unix: 0,
+            assisted: false,
+        }
+    }
+
+    #[test]
+    fn entries_rank_by_score_then_accretion_and_keep_the_top_ten() {
+        let mut board = Leaderboard::default();
+        assert_eq!(
+            board.insert(entry(SaveScenario::Field, 100, 10, 60.0)),
+            Some(1)
+        );
+        assert_eq!(
+            board.insert(entry(SaveScenario::Field, 100, 20, 90.0)),
+            Some(1)
+        );
+        assert_eq!(
+            board.insert(entry(SaveScenario::Field, 100, 20, 30.0)),
+            Some(1)
+        );
+        assert_eq!(
+            board.insert(entry(SaveScenario::Field, 50, 90, 10.0)),
+            Some(4)
+        );
+        assert_eq!(board.insert(entry(SaveScenario::Orbit, 5, 1, 5.0)), Some(1));
+        for i in 0..10 {
+            board.insert(entry(SaveScenario::Field, 200 + i, 1, 100.0));
+        }
+        assert_eq!(board.insert(entry(SaveScenario::Field, 1, 1, 1.0)), None);
+
+        let field: Vec<_> = board.scenario(SaveScenario::Field).collect();
+        assert_eq!(field.len(), LEADERBOARD_ENTRIES_PER_SCENARIO);
+        assert!(field.iter().all(|e| e.score >= 200));
+        assert_eq!(board.best(SaveScenario::Field).unwrap().score, 209);
+        assert_eq!(board.scenario(SaveScenario::Orbit).count(), 1);
+        assert!(board.best(SaveScenario::Survival).is_none());
+
+        let reparsed = parse_leaderboard(&toml::to_string_pretty(&board).unwrap()).unwrap();
+        assert_eq!(reparsed, board);
+    }
+
+    #[test]
+    fn store_writes_the_board_atomically_and_remembers_the_rank() {
+        let dir =
+            std::env::temp_dir().join(format!("accretion_leaderboard_{}", std::process::id()));
+        let path = dir.join("leaderboard.toml");
+        let mut store = LeaderboardStore {
+            path: Some(path.clone()),
+            ..default()
+        };
+
+        assert_eq!(
+            store.record(entry(SaveScenario::Comets, 40, 8, 12.0)),
+            Ok(Some(1))
+        );
+        assert_eq!(
+            store.record(entry(SaveScenario::Comets, 70, 3, 20.0)),
+            Ok(Some(1))
+        );
+        assert_eq!(store.last_record, Some((SaveScenario::Comets, 1)));
+        assert!(!path.with_extension("toml.tmp").exists());
+
+        let on_disk = parse_leaderboard(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(on_disk, store.board);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn speedrun_times_share_the_board_and_legacy_files_are_imported() {
+        let dir = std::env::temp_dir().join(format!("accretion_speedruns_{}", std::process::id()));
+        let path = dir.join("leaderboard.toml");
+        let legacy = dir.join("speedrun_times.toml");
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            &legacy,
+            "version = 1\n\n[[times]]\ncategory = \"FIELD · practice\"\nwaves_cleared = 2\n\
+             secs = 80.0\nsplits = [40.0, 80.0]\nrecorded_at_unix = 0\n",
+        )
+        .unwrap();
+        let mut store = LeaderboardStore {
+            path: Some(path.clone()),
+            ..default()
+        };
+
+        store.import_legacy_speedruns(&legacy).unwrap();
+        assert!(!legacy.exists());
+        let faster = SpeedrunTime {
+            category: "FIELD · practice".to_string(),
+            waves_cleared: 2,
+            secs: 70.0,
+            splits: vec![35.0, 70.0],
+            recorded_at_unix: 0,
+        };
+        assert_eq!(store.record_speedrun(faster), Ok(Some(1)));
+        store
+            .record(entry(SaveScenario::Field, 30, 4, 70.0))
+            .unwrap();
+
+        let on_disk = parse_leaderboard(&fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(on_disk, store.board);
+        assert_eq!(on_disk.speedruns.category("FIELD · practice").count(), 2);
+        assert_eq!(on_disk.entries.len(), 1);
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn dates_format_as_utc_calendar_days() {
+        assert_eq!(format_date(0), "1970-01-01");
+        assert_eq!(format_date(951_782_400), "2000-02-29");
+        assert_eq!(format_date(1_791_072_000), "2026-10-04");
+    }
+
+    #[test]
+    fn assisted_flag_round_trips_and_defaults_off_for_older_boards() {
+        let mut board = Leaderboard::default();
+        board.insert(LeaderboardEntry {
+            assisted: true,
+            ..entry(SaveScenario::Field, 10, 2, 5.0)
+        });
+        let serialized = toml::to_string_pretty(&board).unwrap();
+        assert!(serialized.contains("assisted = true"));
+        assert_eq!(parse_leaderboard(&serialized).unwrap(), board);
+
+        let older = serialized.replace("assisted = true\n", "");
+        let parsed = parse_leaderboard(&older).unwrap();
+        assert!(!parsed.best(SaveScenario::Field).unwrap().assisted);
+    }
+}
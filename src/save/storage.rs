@@ -0,0 +1,430 @@
+//! Save storage: file sizes, the quota warning and pruning.
+//!
+//! [`StorageUsage::scan`] lists every file under `saves/` (slots, campaign
+//! slots, the session autosave, leaderboard, speedrun times and profiles),
+//! `reports/` (issue reports, which carry the session log), `replays/`
+//! (time-lapse recordings) and `telemetry/` (allocation reports and bench
+//! results) with its size and modification time.  Readers:
+//!
+//! - the load screen shows each slot's size (snapshot plus `.meta.toml`) and
+//!   a warning line once the total passes `save_storage_quota_mb`;
+//! - the Settings storage panel shows the total against the quota and offers
+//!   [`PruneTarget`]s: the session autosave, and all but the newest
+//!   `storage_prune_keep` issue reports, replays and telemetry logs.
+//!
+//! Save slots, campaign slots and records are never pruned; they are only
+//! counted.  The time-lapse, allocation report and bench output variables
+//! resolve a bare file name into `replays/` or `telemetry/` through
+//! [`tracked_output_path`]; a path with a directory is written as given and
+//! is not tracked.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+/// What a stored file is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StorageKind {
+    /// `slot_N.toml` or its `.meta.toml` summary.
+    Slot(u8),
+    CampaignSlot(u8),
+    /// The practice-session autosave kept for crash recovery.
+    Autosave,
+    /// An issue report zip, or a screenshot still waiting to be zipped.
+    Report,
+    /// Leaderboard, speedrun times, profiles and session markers.
+    Records,
+    /// A time-lapse recording.
+    Replay,
+    /// An allocation report or bench result.
+    Telemetry,
+}
+
+impl StorageKind {
+    /// Kind of a file under `saves/` named `name`.
+    fn of_save_file(name: &str) -> Self {
+        let slot = |prefix: &str| {
+            name.strip_prefix(prefix)
+                .and_then(|rest| rest.split('.').next())
+                .and_then(|n| n.parse::<u8>().ok())
+        };
+        if name == "autosave.toml" {
+            StorageKind::Autosave
+        } else if let Some(n) = slot("campaign_slot_") {
+            StorageKind::CampaignSlot(n)
+        } else if let Some(n) = slot("slot_") {
+            StorageKind::Slot(n)
+        } else {
+            StorageKind::Records
+        }
+    }
+}
+
+/// One file on disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredFile {
+    pub path: PathBuf,
+    pub kind: StorageKind,
+    pub bytes: u64,
+    /// Last modification (Unix seconds), 0 if unknown.
+    pub modified_unix: u64,
+}
+
+/// Files that can be deleted from the Settings storage panel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PruneTarget {
+    Autosave,
+    Reports,
+    Replays,
+    Telemetry,
+}
+
+impl PruneTarget {
+    pub const ALL: [PruneTarget; 4] = [
+        PruneTarget::Autosave,
+        PruneTarget::Reports,
+        PruneTarget::Replays,
+        PruneTarget::Telemetry,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PruneTarget::Autosave => "AUTOSAVE",
+            PruneTarget::Reports => "ISSUE REPORTS",
+            PruneTarget::Replays => "REPLAYS",
+            PruneTarget::Telemetry => "TELEMETRY LOGS",
+        }
+    }
+
+    fn kind(self) -> StorageKind {
+        match self {
+            PruneTarget::Autosave => StorageKind::Autosave,
+            PruneTarget::Reports => StorageKind::Report,
+            PruneTarget::Replays => StorageKind::Replay,
+            PruneTarget::Telemetry => StorageKind::Telemetry,
+        }
+    }
+}
+
+/// Time-lapse recordings.
+pub fn replay_dir() -> PathBuf {
+    PathBuf::from("replays")
+}
+
+/// Allocation reports and bench results.
+pub fn telemetry_dir() -> PathBuf {
+    PathBuf::from("telemetry")
+}
+
+/// Where an output named by an environment variable is written: a bare file
+/// name goes into `dir` (created here) so the storage panel can count and
+/// prune it; any other path is used as given.
+pub fn tracked_output_path(dir: &Path, path: &str) -> String {
+    let is_bare = Path::new(path)
+        .parent()
+        .is_some_and(|parent| parent.as_os_str().is_empty());
+    if !is_bare {
+        return path.to_string();
+    }
+    if let Err(err) = fs::create_dir_all(dir) {
+        eprintln!("storage: cannot create {}: {err}", dir.display());
+    }
+    dir.join(path).to_string_lossy().into_owned()
+}
+
+/// Files and bytes removed by a prune.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PruneOutcome {
+    pub files: u32,
+    pub bytes: u64,
+}
+
+/// Every tracked file, newest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StorageUsage {
+    pub files: Vec<StoredFile>,
+}
+
+impl StorageUsage {
+    /// Scan `saves/`, `reports/`, `replays/` and `telemetry/`.
+    pub fn scan_default() -> Self {
+        Self::scan(
+            &super::save_dir(),
+            &crate::report::report_dir(),
+            &replay_dir(),
+            &telemetry_dir(),
+        )
+    }
+
+    /// Scan `save_dir` (recursively), `report_dir`, `replay_dir` and
+    /// `telemetry_dir`.  Missing directories count as empty.
+    pub fn scan(
+        save_dir: &Path,
+        report_dir: &Path,
+        replay_dir: &Path,
+        telemetry_dir: &Path,
+    ) -> Self {
+        let mut files = Vec::new();
+        collect_files(save_dir, &mut |path, bytes, modified_unix| {
+            let in_root = path.parent() == Some(save_dir);
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            let kind = if in_root {
+                StorageKind::of_save_file(name)
+            } else {
+                StorageKind::Records
+            };
+            files.push(StoredFile {
+                path,
+                kind,
+                bytes,
+                modified_unix,
+            });
+        });
+        for (dir, kind) in [
+            (report_dir, StorageKind::Report),
+            (replay_dir, StorageKind::Replay),
+            (telemetry_dir, StorageKind::Telemetry),
+        ] {
+            collect_files(dir, &mut |path, bytes, modified_unix| {
+                files.push(StoredFile {
+                    path,
+                    kind,
+                    bytes,
+                    modified_unix,
+                });
+            });
+        }
+        files.sort_by(|a, b| {
+            b.modified_unix
+                .cmp(&a.modified_unix)
+                .then_with(|| a.path.cmp(&b.path))
+        });
+        Self { files }
+    }
+
+    pub fn total_bytes(&self) -> u64 {
+        self.files.iter().map(|file| file.bytes).sum()
+    }
+
+    pub fn kind_bytes(&self, kind: StorageKind) -> u64 {
+        self.files
+            .iter()
+            .filter(|file| file.kind == kind)
+            .map(|file| file.bytes)
+            .sum()
+    }
+
+    /// Whether the total is over a quota of `quota_mb` (0 or less: never).
+    pub fn over_quota(&self, quota_mb: f32) -> bool {
+        quota_mb > 0.0 && self.total_bytes() as f64 > quota_mb as f64 * MEGABYTE as f64
+    }
+
+    /// Files `target` would delete, keeping the newest `keep` of them.
+    pub fn prunable(&self, target: PruneTarget, keep: usize) -> Vec<&StoredFile> {
+        let keep = match target {
+            PruneTarget::Autosave => 0,
+            PruneTarget::Reports | PruneTarget::Replays | PruneTarget::Telemetry => keep,
+        };
+        self.files
+            .iter()
+            .filter(|file| file.kind == target.kind())
+            .skip(keep)
+            .collect()
+    }
+
+    /// Delete the files `target` selects, then drop them from the list.
+    /// Files that fail to delete are kept and reported in the error.
+    pub fn prune(&mut self, target: PruneTarget, keep: usize) -> Result<PruneOutcome, String> {
+        let mut outcome = PruneOutcome::default();
+        let mut removed = Vec::new();
+        let mut errors = Vec::new();
+        for file in self.prunable(target, keep) {
+            match fs::remove_file(&file.path) {
+                Ok(()) => {
+                    outcome.files += 1;
+                    outcome.bytes += file.bytes;
+                    removed.push(file.path.clone());
+                }
+                Err(err) => errors.push(format!("{}: {err}", file.path.display())),
+            }
+        }
+        self.files.retain(|file| !removed.contains(&file.path));
+        if errors.is_empty() {
+            Ok(outcome)
+        } else {
+            Err(format!("failed to delete {}", errors.join(", ")))
+        }
+    }
+}
+
+const MEGABYTE: u64 = 1024 * 1024;
+
+/// `512 B`, `12.3 KB`, `4.0 MB`.
+pub fn format_bytes(bytes: u64) -> String {
+    if bytes < 1024 {
+        format!("{bytes} B")
+    } else if bytes < MEGABYTE {
+        format!("{:.1} KB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MB", bytes as f64 / MEGABYTE as f64)
+    }
+}
+
+fn collect_files(dir: &Path, visit: &mut impl FnMut(PathBuf, u64, u64)) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        let Ok(meta) = entry.metadata() else {
+            continue;
+        };
+        if meta.is_dir() {
+            collect_files(&path, visit);
+        } else {
+            let modified = meta
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map_or(0, |d| d.as_secs());
+            visit(path, meta.len(), modified);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::{Duration, SystemTime};
+
+    fn write(path: &Path, bytes: usize, age_secs: u64) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![b'x'; bytes]).unwrap();
+        let modified = SystemTime::now() - Duration::from_secs(age_secs);
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(modified)
+            .unwrap();
+    }
+
+    #[test]
+    fn files_are_classified_sized_and_pruned_oldest_first() {
+        let root = std::env::temp_dir().join(format!("accretion_storage_{}", std::process::id()));
+        let saves = root.join("saves");
+        let reports = root.join("reports");
+        let replays = root.join("replays");
+        let telemetry = root.join("telemetry");
+        write(&saves.join("slot_1.toml"), 3000, 50);
+        write(&saves.join("slot_1.meta.toml"), 100, 50);
+        write(&saves.join("campaign_slot_2.toml"), 400, 40);
+        write(&saves.join("autosave.toml"), 2000, 10);
+        write(&saves.join("leaderboard.toml"), 50, 10);
+        write(&saves.join("profiles").join("slot_9.toml"), 70, 10);
+        for age in 1..=4u64 {
+            write(&reports.join(format!("report-{age}.zip")), 1000, age * 100);
+        }
+
+        let mut usage = StorageUsage::scan(&saves, &reports, &replays, &telemetry);
+        assert_eq!(usage.kind_bytes(StorageKind::Slot(1)), 3100);
+        assert_eq!(usage.kind_bytes(StorageKind::CampaignSlot(2)), 400);
+        assert_eq!(usage.kind_bytes(StorageKind::Autosave), 2000);
+        // Profiles live in a subdirectory and are never mistaken for slots.
+        assert_eq!(usage.kind_bytes(StorageKind::Records), 120);
+        assert_eq!(usage.kind_bytes(StorageKind::Report), 4000);
+        assert_eq!(usage.total_bytes(), 9620);
+        assert!(usage.over_quota(0.005));
+        assert!(!usage.over_quota(1.0) && !usage.over_quota(0.0));
+
+        let outcome = usage.prune(PruneTarget::Reports, 1).unwrap();
+        assert_eq!(
+            outcome,
+            PruneOutcome {
+                files: 3,
+                bytes: 3000
+            }
+        );
+        assert!(reports.join("report-1.zip").exists(), "the newest is kept");
+        assert!(!reports.join("report-4.zip").exists());
+        assert_eq!(usage.prune(PruneTarget::Autosave, 5).unwrap().files, 1);
+        assert!(saves.join("slot_1.toml").exists());
+        assert_eq!(
+            usage,
+            StorageUsage::scan(&saves, &reports, &replays, &telemetry)
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn replays_and_telemetry_logs_are_pruned_to_the_newest() {
+        let root =
+            std::env::temp_dir().join(format!("accretion_storage_logs_{}", std::process::id()));
+        let saves = root.join("saves");
+        let reports = root.join("reports");
+        let replays = root.join("replays");
+        let telemetry = root.join("telemetry");
+        write(&saves.join("slot_1.toml"), 300, 500);
+        write(&reports.join("report-1.zip"), 100, 500);
+        for age in 1..=3u64 {
+            write(&replays.join(format!("run-{age}.bin")), 2000, age * 100);
+        }
+        write(&telemetry.join("alloc.csv"), 500, 100);
+        write(&telemetry.join("bench.json"), 700, 200);
+        write(&telemetry.join("old-bench.json"), 900, 300);
+
+        let mut usage = StorageUsage::scan(&saves, &reports, &replays, &telemetry);
+        assert_eq!(usage.kind_bytes(StorageKind::Replay), 6000);
+        assert_eq!(usage.kind_bytes(StorageKind::Telemetry), 2100);
+
+        let outcome = usage.prune(PruneTarget::Replays, 1).unwrap();
+        assert_eq!(
+            outcome,
+            PruneOutcome {
+                files: 2,
+                bytes: 4000
+            }
+        );
+        assert!(replays.join("run-1.bin").exists(), "the newest is kept");
+        assert!(!replays.join("run-3.bin").exists());
+
+        let outcome = usage.prune(PruneTarget::Telemetry, 2).unwrap();
+        assert_eq!(
+            outcome,
+            PruneOutcome {
+                files: 1,
+                bytes: 900
+            }
+        );
+        assert!(telemetry.join("alloc.csv").exists());
+        assert!(!telemetry.join("old-bench.json").exists());
+
+        // Neither prune touches saves or reports.
+        assert!(saves.join("slot_1.toml").exists());
+        assert!(reports.join("report-1.zip").exists());
+        assert_eq!(
+            usage,
+            StorageUsage::scan(&saves, &reports, &replays, &telemetry)
+        );
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn bare_output_names_land_in_the_tracked_directory() {
+        let dir =
+            std::env::temp_dir().join(format!("accretion_storage_out_{}", std::process::id()));
+        let bare = tracked_output_path(&dir, "run.bin");
+        assert_eq!(Path::new(&bare), dir.join("run.bin"));
+        assert!(dir.is_dir());
+        assert_eq!(tracked_output_path(&dir, "/tmp/run.bin"), "/tmp/run.bin");
+        assert_eq!(tracked_output_path(&dir, "out/run.bin"), "out/run.bin");
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn sizes_read_in_the_largest_whole_unit() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(12_595), "12.3 KB");
+        assert_eq!(format_bytes(4 * MEGABYTE), "4.0 MB");
+    }
+}
@@ -0,0 +1,221 @@
+//! Score formulas and their runtime-tunable [`ScoringConfig`].
+//!
+//! Combat systems no longer hard-code point values; they call
+//! [`ScoringConfig::register_hit`] and read the bonus fields below.  The
+//! config is loaded from `assets/scoring.toml` at startup and hot-reloaded
+//! when the file changes, so balance passes (and future mutators) can adjust
+//! scoring without recompiling.
+//!
+//! | Event                        | Points awarded                                  |
+//! |------------------------------|-------------------------------------------------|
+//! | Projectile hit               | `hit_points × multiplier`                       |
+//! | Projectile full destroy      | `+ destroy_bonus_points × multiplier`           |
+//! | Missile hit                  | `missile_hit_points × multiplier`               |
+//! | Missile instant destroy      | `+ missile_destroy_bonus_points × multiplier`   |
+//! | Streak enters a higher tier  | `+ chain_bonus_points × new multiplier`         |
+//!
+//! The multiplier is `1` plus the number of `multiplier_streak_thresholds` the
+//! current streak has reached.  Enemy kill points (`enemy_kill_score`) and the
+//! survival wave multiplier stay in [`crate::config::PhysicsConfig`].
+
+use crate::constants::*;
+use crate::player::PlayerScore;
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::time::SystemTime;
+
+const SCORING_CONFIG_PATH: &str = "assets/scoring.toml";
+const HOT_RELOAD_POLL_SECS: f32 = 0.5;
+
+/// Runtime-tunable scoring formulas.
+///
+/// All fields default to the `SCORE_*` constants in `src/constants.rs`.
+/// Override any subset in `assets/scoring.toml`.
+#[derive(Resource, Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct ScoringConfig {
+    /// Base points for a primary projectile hit (scaled by the multiplier).
+    pub hit_points: u32,
+    /// Extra points when a projectile fully destroys its target.
+    pub destroy_bonus_points: u32,
+    /// Base points for a missile hit, whatever the outcome.
+    pub missile_hit_points: u32,
+    /// Extra points when a missile instantly destroys a small target.
+    pub missile_destroy_bonus_points: u32,
+    /// Streak lengths at which the multiplier steps up by one (ascending).
+    pub multiplier_streak_thresholds: Vec<u32>,
+    /// Flat bonus (scaled by the new multiplier) when a streak reaches a new
+    /// tier.  `0` disables chain bonuses.
+    pub chain_bonus_points: u32,
+}
+
+impl Default for ScoringConfig {
+    fn default() -> Self {
+        Self {
+            hit_points: SCORE_HIT_POINTS,
+            destroy_bonus_points: SCORE_DESTROY_BONUS_POINTS,
+            missile_hit_points: SCORE_MISSILE_HIT_POINTS,
+            missile_destroy_bonus_points: SCORE_MISSILE_DESTROY_BONUS_POINTS,
+            multiplier_streak_thresholds: SCORE_MULTIPLIER_STREAK_THRESHOLDS.to_vec(),
+            chain_bonus_points: SCORE_CHAIN_BONUS_POINTS,
+        }
+    }
+}
+
+impl ScoringConfig {
+    /// Score multiplier for a hit streak of `streak`.
+    pub fn multiplier(&self, streak: u32) -> u32 {
+        1 + self
+            .multiplier_streak_thresholds
+            .iter()
+            .filter(|&&threshold| streak >= threshold)
+            .count() as u32
+    }
+
+    /// Count a hit: extend the streak, award `base_points × multiplier` plus
+    /// any chain bonus, and return the multiplier for follow-up bonuses.
+    ///
+    /// The streak is incremented first so the hit that crosses a threshold
+    /// already scores at the new tier.
+    pub fn register_hit(&self, score: &mut PlayerScore, base_points: u32) -> u32 {
+        let previous = self.multiplier(score.streak);
+        score.hits += 1;
+        score.streak += 1;
+        let multiplier = self.multiplier(score.streak);
+        score.points += base_points * multiplier;
+        if multiplier > previous {
+            score.points += self.chain_bonus_points * multiplier;
+        }
+        multiplier
+    }
+
+    /// Sort and de-duplicate thresholds so hand-edited files behave.
+    fn normalized(mut self) -> Self {
+        self.multiplier_streak_thresholds.sort_unstable();
+        self.multiplier_streak_thresholds.dedup();
+        self
+    }
+}
+
+/// Startup system: overwrite [`ScoringConfig`] with `assets/scoring.toml`.
+///
+/// Missing keys keep their compiled defaults; a missing or malformed file
+/// leaves the defaults in place.
+pub fn load_scoring_config(mut scoring: ResMut<ScoringConfig>) {
+    match read_scoring_config_file(SCORING_CONFIG_PATH) {
+        Ok(loaded) => {
+            *scoring = loaded;
+            println!("✓ Loaded scoring config from {SCORING_CONFIG_PATH}");
+        }
+        Err(err) => {
+            println!("ℹ {err}; using current/default scoring config");
+        }
+    }
+}
+
+/// File-watch state for [`hot_reload_scoring_config`].
+#[derive(Default)]
+pub struct ScoringConfigWatch {
+    last_seen_modified: Option<SystemTime>,
+    poll_timer: f32,
+}
+
+/// Poll `assets/scoring.toml` and reload it when the file is modified.
+///
+/// The first successful poll only records the timestamp, matching
+/// `config::hot_reload_physics_config`.
+pub fn hot_reload_scoring_config(
+    time: Res<Time>,
+    mut watch: Local<ScoringConfigWatch>,
+    mut scoring: ResMut<ScoringConfig>,
+) {
+    watch.poll_timer += time.delta_secs();
+    if watch.poll_timer < HOT_RELOAD_POLL_SECS {
+        return;
+    }
+    watch.poll_timer = 0.0;
+
+    let Some(modified) = std::fs::metadata(SCORING_CONFIG_PATH)
+        .ok()
+        .and_then(|meta| meta.modified().ok())
+    else {
+        watch.last_seen_modified = None;
+        return;
+    };
+
+    let changed = watch
+        .last_seen_modified
+        .is_some_and(|previous| modified > previous);
+    watch.last_seen_modified = Some(modified);
+    if !changed {
+        return;
+    }
+
+    match read_scoring_config_file(SCORING_CONFIG_PATH) {
+        Ok(loaded) => {
+            *scoring = loaded;
+            info!("Hot-reloaded scoring config from {}", SCORING_CONFIG_PATH);
+        }
+        Err(err) => {
+            eprintln!("⚠ Failed hot-reload from {SCORING_CONFIG_PATH}: {err}");
+        }
+    }
+}
+
+fn read_scoring_config_file(path: &str) -> Result<ScoringConfig, String> {
+    let contents =
+        std::fs::read_to_string(path).map_err(|err| format!("failed reading {path}: {err}"))?;
+    parse_scoring_config(&contents).map_err(|err| format!("failed parsing {path}: {err}"))
+}
+
+fn parse_scoring_config(contents: &str) -> Result<ScoringConfig, toml::de::Error> {
+    toml::from_str::<ScoringConfig>(contents).map(ScoringConfig::normalized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_multiplier_curve_matches_legacy_tiers() {
+        let scoring = ScoringConfig::default();
+        let tiers: Vec<u32> = [0, 4, 5, 9, 10, 19, 20, 39, 40, 500]
+            .iter()
+            .map(|&streak| scoring.multiplier(streak))
+            .collect();
+        assert_eq!(tiers, vec![1, 1, 2, 2, 3, 3, 4, 4, 5, 5]);
+    }
+
+    #[test]
+    fn chain_bonus_paid_once_when_streak_reaches_new_tier() {
+        let scoring = ScoringConfig {
+            chain_bonus_points: 7,
+            ..Default::default()
+        };
+        let mut score = PlayerScore {
+            streak: 3,
+            ..Default::default()
+        };
+
+        assert_eq!(scoring.register_hit(&mut score, 1), 1);
+        assert_eq!(score.points, 1);
+        // Streak 5 crosses into ×2: 1×2 for the hit plus 7×2 chain bonus.
+        assert_eq!(scoring.register_hit(&mut score, 1), 2);
+        assert_eq!(score.points, 1 + 2 + 14);
+        assert_eq!(scoring.register_hit(&mut score, 1), 2);
+        assert_eq!(score.points, 17 + 2);
+        assert_eq!(score.hits, 3);
+    }
+
+    #[test]
+    fn partial_toml_overrides_and_sorts_thresholds() {
+        let scoring = parse_scoring_config(
+            "destroy_bonus_points = 9\nmultiplier_streak_thresholds = [8, 3, 3]\n",
+        )
+        .unwrap();
+        assert_eq!(scoring.destroy_bonus_points, 9);
+        assert_eq!(scoring.hit_points, SCORE_HIT_POINTS);
+        assert_eq!(scoring.multiplier_streak_thresholds, vec![3, 8]);
+        assert_eq!(scoring.multiplier(8), 3);
+    }
+}
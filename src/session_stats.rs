@@ -0,0 +1,348 @@
+//! Per-session run statistics for the end-of-session summary.
+//!
+//! [`SessionStats`] is filled by [`session_stats_system`] and shown by the
+//! game-over overlay and by the summary card the main menu opens after
+//! quitting from the pause menu.  It counts:
+//!
+//! - time survived (time spent in `Playing`),
+//! - asteroid merges and the largest merged body, read from merge
+//!   [`FeedbackEvent`]s,
+//! - when a merge first reached each [`SizeClass`], read from
+//!   [`SizeClassReached`],
+//! - hull damage taken and ship losses, read from [`PlayerDamaged`],
+//! - per [`StatWeapon`]: shots fired, hits and asteroids destroyed,
+//! - raw ore mined by pickups, the mining laser, drones and outposts.
+//!
+//! Shots are counted from newly spawned [`Projectile`] / [`Missile`] /
+//! [`IonCannonShot`] entities; deflected enemy shots are not the player's and
+//! are skipped.  Hits, kills and ore arrive as [`SessionStatEvent`] messages
+//! written where they happen.
+//!
+//! The stats are cleared when a session starts: on leaving the main menu and
+//! on a Play Again from the game-over overlay.
+
+use crate::enemy::DeflectedShot;
+use crate::feedback::{FeedbackEvent, FeedbackKind};
+use crate::menu::GameState;
+use crate::player::ion_cannon::IonCannonShot;
+use crate::player::state::{Missile, Projectile};
+use crate::player::TurretRound;
+use crate::simulation::size_class::{SizeClass, SizeClassReached};
+use crate::wave_telemetry::PlayerDamaged;
+use bevy::prelude::*;
+
+/// Weapon a session stat is credited to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Reflect)]
+pub enum StatWeapon {
+    /// The player's primary gun, whichever is equipped.
+    Primary,
+    Missile,
+    IonCannon,
+    Turret,
+    /// Beam weapon: destroys asteroids but fires no shots.
+    MiningLaser,
+}
+
+impl StatWeapon {
+    pub const ALL: [StatWeapon; 5] = [
+        StatWeapon::Primary,
+        StatWeapon::Missile,
+        StatWeapon::IonCannon,
+        StatWeapon::Turret,
+        StatWeapon::MiningLaser,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            StatWeapon::Primary => "Primary",
+            StatWeapon::Missile => "Missile",
+            StatWeapon::IonCannon => "Ion cannon",
+            StatWeapon::Turret => "Turrets",
+            StatWeapon::MiningLaser => "Mining laser",
+        }
+    }
+}
+
+/// Something the session summary counts that no other resource records.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub enum SessionStatEvent {
+    /// A player shot connected with an asteroid, planet, enemy, boss or enemy shot.
+    Hit(StatWeapon),
+    /// A player weapon destroyed an asteroid, directly or through a volatile
+    /// it lit.
+    AsteroidDestroyed(StatWeapon),
+    /// Raw ore added to the player's wallet.
+    OreMined(u32),
+}
+
+/// One weapon's showing over a session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Reflect)]
+pub struct WeaponStats {
+    pub shots: u32,
+    pub hits: u32,
+    pub asteroids_destroyed: u32,
+}
+
+impl WeaponStats {
+    /// Hits per shot, or `None` before the first shot.
+    pub fn accuracy(&self) -> Option<f32> {
+        (self.shots > 0).then(|| self.hits.min(self.shots) as f32 / self.shots as f32)
+    }
+}
+
+/// Statistics for the current session.
+#[derive(Resource, Debug, Clone, Default, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct SessionStats {
+    pub time_survived_secs: f32,
+    pub asteroids_merged: u32,
+    /// Mass of the largest body a merge produced.
+    pub largest_body: u32,
+    pub ore_mined: u32,
+    pub damage_taken: f32,
+    pub ships_lost: u32,
+    /// Indexed in [`StatWeapon::ALL`] order.
+    pub weapons: [WeaponStats; StatWeapon::ALL.len()],
+    /// Time survived when a merge first reached each class, indexed in
+    /// [`SizeClass::ALL`] order.
+    pub size_class_times: [Option<f32>; SizeClass::ALL.len()],
+}
+
+impl SessionStats {
+    pub fn weapon(&self, weapon: StatWeapon) -> &WeaponStats {
+        &self.weapons[weapon as usize]
+    }
+
+    pub fn weapon_mut(&mut self, weapon: StatWeapon) -> &mut WeaponStats {
+        &mut self.weapons[weapon as usize]
+    }
+
+    /// Asteroids destroyed by any weapon.
+    pub fn asteroids_destroyed(&self) -> u32 {
+        self.weapons.iter().map(|w| w.asteroids_destroyed).sum()
+    }
+
+    /// Accuracy over every weapon that fires shots.
+    pub fn accuracy(&self) -> Option<f32> {
+        let total = self
+            .weapons
+            .iter()
+            .fold(WeaponStats::default(), |acc, w| WeaponStats {
+                shots: acc.shots + w.shots,
+                hits: acc.hits + w.hits.min(w.shots),
+                asteroids_destroyed: 0,
+            });
+        total.accuracy()
+    }
+
+    /// Size classes reached this session and when, smallest first.
+    pub fn size_classes_reached(&self) -> impl Iterator<Item = (SizeClass, f32)> + '_ {
+        SizeClass::ALL
+            .iter()
+            .zip(&self.size_class_times)
+            .filter_map(|(&class, at)| at.map(|at| (class, at)))
+    }
+
+    /// Weapons that were used this session, in [`StatWeapon::ALL`] order.
+    pub fn used_weapons(&self) -> impl Iterator<Item = (StatWeapon, &WeaponStats)> {
+        StatWeapon::ALL
+            .iter()
+            .map(|&weapon| (weapon, self.weapon(weapon)))
+            .filter(|(_, stats)| **stats != WeaponStats::default())
+    }
+}
+
+/// Accumulate this frame's stats.
+///
+/// Runs in `Last`, ungated, so the fatal hit of a run-ending death is still
+/// counted on the frame Game Over is requested.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn session_stats_system(
+    time: Res<Time>,
+    state: Res<State<GameState>>,
+    mut events: MessageReader<SessionStatEvent>,
+    mut damage: MessageReader<PlayerDamaged>,
+    mut feedback: MessageReader<FeedbackEvent>,
+    mut size_classes: MessageReader<SizeClassReached>,
+    q_projectiles: Query<Has<TurretRound>, (Added<Projectile>, Without<DeflectedShot>)>,
+    q_missiles: Query<(), Added<Missile>>,
+    q_ion_shots: Query<(), Added<IonCannonShot>>,
+    mut stats: ResMut<SessionStats>,
+) {
+    if *state.get() == GameState::Playing {
+        stats.time_survived_secs += time.delta_secs();
+    }
+
+    for turret_round in q_projectiles.iter() {
+        let weapon = if turret_round {
+            StatWeapon::Turret
+        } else {
+            StatWeapon::Primary
+        };
+        stats.weapon_mut(weapon).shots += 1;
+    }
+    stats.weapon_mut(StatWeapon::Missile).shots += q_missiles.iter().count() as u32;
+    stats.weapon_mut(StatWeapon::IonCannon).shots += q_ion_shots.iter().count() as u32;
+
+    for event in events.read() {
+        match *event {
+            SessionStatEvent::Hit(weapon) => stats.weapon_mut(weapon).hits += 1,
+            SessionStatEvent::AsteroidDestroyed(weapon) => {
+                stats.weapon_mut(weapon).asteroids_destroyed += 1;
+            }
+            SessionStatEvent::OreMined(amount) => {
+                stats.ore_mined = stats.ore_mined.saturating_add(amount);
+            }
+        }
+    }
+
+    for hit in damage.read() {
+        stats.damage_taken += hit.amount;
+        stats.ships_lost += u32::from(hit.fatal);
+    }
+
+    for event in feedback.read() {
+        if event.kind == FeedbackKind::Merge {
+            stats.asteroids_merged += 1;
+            stats.largest_body = stats.largest_body.max(event.mass);
+        }
+    }
+
+    let now = stats.time_survived_secs;
+    for event in size_classes.read() {
+        // Reaching a class also reaches every smaller one.
+        for slot in &mut stats.size_class_times[..=event.class as usize] {
+            slot.get_or_insert(now);
+        }
+    }
+}
+
+/// Start a fresh session.
+pub fn reset_session_stats_system(mut stats: ResMut<SessionStats>) {
+    *stats = SessionStats::default();
+}
+
+pub struct SessionStatsPlugin;
+
+impl Plugin for SessionStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<SessionStatEvent>()
+            .add_message::<SizeClassReached>()
+            .init_resource::<SessionStats>()
+            .add_systems(Last, session_stats_system)
+            .add_systems(OnExit(GameState::MainMenu), reset_session_stats_system)
+            .add_systems(OnExit(GameState::GameOver), reset_session_stats_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wave_telemetry::DamageSource;
+    use bevy::state::app::StatesPlugin;
+
+    fn stats_app() -> App {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Playing)
+            .add_message::<SessionStatEvent>()
+            .add_message::<PlayerDamaged>()
+            .add_message::<FeedbackEvent>()
+            .add_message::<SizeClassReached>()
+            .init_resource::<SessionStats>()
+            .add_systems(Last, session_stats_system);
+        app
+    }
+
+    #[test]
+    fn accuracy_is_hits_over_shots_and_skips_beam_weapons() {
+        let mut stats = SessionStats::default();
+        assert_eq!(stats.accuracy(), None);
+
+        *stats.weapon_mut(StatWeapon::Primary) = WeaponStats {
+            shots: 8,
+            hits: 6,
+            asteroids_destroyed: 2,
+        };
+        *stats.weapon_mut(StatWeapon::Missile) = WeaponStats {
+            shots: 2,
+            hits: 0,
+            asteroids_destroyed: 0,
+        };
+        stats
+            .weapon_mut(StatWeapon::MiningLaser)
+            .asteroids_destroyed = 3;
+
+        assert_eq!(stats.weapon(StatWeapon::Primary).accuracy(), Some(0.75));
+        assert_eq!(stats.weapon(StatWeapon::MiningLaser).accuracy(), None);
+        assert_eq!(stats.accuracy(), Some(0.6));
+        assert_eq!(stats.asteroids_destroyed(), 5);
+        let used: Vec<StatWeapon> = stats.used_weapons().map(|(w, _)| w).collect();
+        assert_eq!(
+            used,
+            [
+                StatWeapon::Primary,
+                StatWeapon::Missile,
+                StatWeapon::MiningLaser
+            ]
+        );
+    }
+
+    #[test]
+    fn system_counts_shots_events_damage_and_merges() {
+        let mut app = stats_app();
+        app.world_mut().spawn(Projectile::default());
+        app.world_mut().spawn((Projectile::default(), TurretRound));
+        app.world_mut()
+            .spawn((Projectile::default(), DeflectedShot));
+        app.world_mut().spawn(Missile::default());
+
+        app.world_mut()
+            .write_message(SessionStatEvent::Hit(StatWeapon::Primary));
+        app.world_mut()
+            .write_message(SessionStatEvent::AsteroidDestroyed(StatWeapon::Missile));
+        app.world_mut().write_message(SessionStatEvent::OreMined(4));
+        app.world_mut().write_message(PlayerDamaged {
+            amount: 12.5,
+            source: DamageSource::Asteroid,
+            fatal: true,
+        });
+        for mass in [9, 30] {
+            app.world_mut().write_message(FeedbackEvent {
+                kind: FeedbackKind::Merge,
+                pos: Vec2::ZERO,
+                vel: Vec2::ZERO,
+                mass,
+            });
+        }
+        app.world_mut().write_message(FeedbackEvent {
+            kind: FeedbackKind::Destroy,
+            pos: Vec2::ZERO,
+            vel: Vec2::ZERO,
+            mass: 50,
+        });
+        app.world_mut().write_message(SizeClassReached {
+            entity: Entity::PLACEHOLDER,
+            class: SizeClass::Moonlet,
+            mass: 30,
+            pos: Vec2::ZERO,
+        });
+        app.update();
+        // Shots are counted once, when they appear.
+        app.update();
+
+        let stats = app.world().resource::<SessionStats>();
+        assert_eq!(stats.weapon(StatWeapon::Primary).shots, 1);
+        assert_eq!(stats.weapon(StatWeapon::Primary).hits, 1);
+        assert_eq!(stats.weapon(StatWeapon::Turret).shots, 1);
+        assert_eq!(stats.weapon(StatWeapon::Missile).shots, 1);
+        assert_eq!(stats.asteroids_destroyed(), 1);
+        assert_eq!(stats.ore_mined, 4);
+        assert_eq!(stats.damage_taken, 12.5);
+        assert_eq!(stats.ships_lost, 1);
+        assert_eq!(stats.asteroids_merged, 2);
+        assert_eq!(stats.largest_body, 30);
+        let classes: Vec<_> = stats.size_classes_reached().map(|(c, _)| c).collect();
+        assert_eq!(classes, vec![SizeClass::Boulder, SizeClass::Moonlet]);
+    }
+}
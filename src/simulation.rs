@@ -1,44 +1,126 @@
 //! Simulation plugin and systems for Bevy ECS
 //!
-//! This module owns the core physics systems (gravity, cluster formation, culling)
+//! This module owns the core physics systems (gravity, cluster formation, sector streaming)
 //! and the camera zoom / mouse-aim input handling.  Rendering logic lives in
 //! [`crate::rendering`]; player systems live in [`crate::player`].
 
+#[path = "simulation/density.rs"]
+pub mod density;
+#[path = "simulation/governor.rs"]
+pub mod governor;
+#[path = "simulation/merge_queue.rs"]
+pub mod merge_queue;
+#[path = "simulation/origin.rs"]
+pub mod origin;
+#[path = "simulation/volatile.rs"]
+pub mod volatile;
+use density::{density_field_system, reset_density_field_system, DensityField};
+use governor::{frame_governor_system, FrameTimeGovernor};
+#[path = "simulation/clusters.rs"]
+pub mod clusters;
+#[path = "simulation/collision_response.rs"]
+pub mod collision_response;
+use clusters::{cluster_labeling_system, ClusterStats};
+use collision_response::{
+    collision_response_system, record_pre_step_velocities_system, PreStepVelocities,
+};
+#[path = "simulation/rapier_guard.rs"]
+pub mod rapier_guard;
+use rapier_guard::{
+    apply_physics_resume_system, validate_rapier_handles_system, PhysicsResumeGuard,
+};
+#[path = "simulation/fixed_step.rs"]
+pub mod fixed_step;
+#[path = "simulation/procgen.rs"]
+pub mod procgen;
+#[path = "simulation/rings.rs"]
+pub mod rings;
+#[path = "simulation/sectors.rs"]
+pub mod sectors;
+#[path = "simulation/shockwave.rs"]
+pub mod shockwave;
+#[path = "simulation/size_class.rs"]
+pub mod size_class;
+#[path = "simulation/soft_body.rs"]
+pub mod soft_body;
+#[path = "simulation/time_control.rs"]
+pub mod time_control;
+use fixed_step::{
+    attach_physics_interpolation_system, interpolate_physics_poses_system,
+    record_physics_poses_system, restore_physics_poses_system, sync_physics_tick_rate_system,
+};
+use merge_queue::{merge_resolution_system, MergeIntent, MergeScratch};
+use procgen::{procedural_field_system, ProceduralField};
+use rings::{ring_circularization_system, tidal_disruption_system};
+use sectors::{sector_streaming_system, SectorStore};
+use shockwave::{shockwave_force_system, Shockwave};
+use size_class::{
+    reset_field_size_class_system, size_class_system, FieldSizeClass, SizeClassReached,
+};
+use soft_body::{soft_body_impact_system, soft_body_sensor_system};
+use time_control::{reset_time_control_system, time_control_system, TimeControl};
+use volatile::{
+    volatile_blast_ship_system, volatile_fuse_system, volatile_glow_system, volatile_tint_system,
+    VolatileBlast,
+};
+
+use crate::alloc_profile::AllocTag;
 use crate::asteroid::{
-    compute_convex_hull_from_points, rescale_vertices_to_area, Asteroid, AsteroidSize,
-    GravityForce, NeighborCount, Planet, Vertices,
+    Asteroid, AsteroidSize, GravityForce, MergeCooldown, NeighborCount, Planet, Vertices,
 };
 use crate::asteroid_rendering::{
-    attach_asteroid_mesh_system, refresh_asteroid_mesh_on_vertices_change_system,
-    sync_asteroid_render_mode_system,
+    asteroid_lod_system, attach_asteroid_mesh_system, instancing::HullInstances,
+    refresh_asteroid_mesh_on_vertices_change_system, sync_asteroid_render_mode_system,
+    AsteroidLodStats, AsteroidRenderAssets,
 };
 use crate::config::PhysicsConfig;
 use crate::enemy::Enemy;
+use crate::lineage::BiggestAccretion;
 use crate::menu::GameState;
 use crate::player::{
     aim_snap_system, apply_player_intent_system, attach_ion_cannon_shot_mesh_system,
     attach_missile_mesh_system, attach_player_ship_mesh_system, attach_player_ui_system,
-    attach_projectile_mesh_system, camera_follow_system, cleanup_player_ui_system,
-    despawn_old_ion_cannon_shots_system, despawn_old_missiles_system,
+    attach_projectile_mesh_system, attach_turret_mesh_system, camera_follow_system,
+    cleanup_player_ui_system, despawn_old_ion_cannon_shots_system, despawn_old_missiles_system,
     despawn_old_projectiles_system, gamepad_connection_system, gamepad_to_intent_system,
     ion_cannon_fire_system, ion_cannon_hit_enemy_system, ion_shot_particles_system,
-    keyboard_to_intent_system, missile_acceleration_system, missile_asteroid_hit_system,
-    missile_fire_system, missile_trail_particles_system, player_collision_damage_system,
-    player_intent_clear_system, player_respawn_system, player_thrust_particles_system,
-    projectile_asteroid_hit_system, projectile_fire_system, projectile_missile_planet_hit_system,
-    stunned_enemy_particles_system, sync_aim_indicator_system,
+    keyboard_to_intent_system, kill_cam_system, lock_on_camera_framing_system, lock_on_system,
+    missile_acceleration_system, missile_asteroid_hit_system, missile_fire_system,
+    missile_homing_system, missile_lock_on_steer_system, missile_trail_particles_system,
+    player_collision_damage_system, player_energy_system, player_intent_clear_system,
+    player_respawn_system, player_shield_system, player_thrust_particles_system,
+    player_turret_system, projectile_asteroid_hit_system, projectile_fire_system,
+    projectile_missile_planet_hit_system, record_kill_cam_history_system, reset_kill_cam_system,
+    stunned_enemy_particles_system, sync_aim_indicator_system, sync_lock_on_bracket_system,
     sync_player_and_projectile_mesh_visibility_system, sync_player_health_bar_system,
-    sync_projectile_outline_visibility_system, sync_projectile_rotation_system,
-    sync_ship_outline_visibility_and_color_system, tractor_beam_force_system,
-    tractor_hold_toggle_system, tractor_throw_cooldown_tick_system, AimDirection, AimIdleTimer,
-    CampaignLoadout, IonCannonCooldown, IonCannonLevel, MissileAmmo, MissileCooldown, Player,
-    PlayerIntent, PlayerLives, PlayerScore, PlayerUiEntities, PreferredGamepad, TractorBeamLevel,
-    TractorCaptureState, TractorHoldState, TractorThrowCooldown,
+    sync_player_shield_visual_system, sync_projectile_outline_visibility_system,
+    sync_projectile_rotation_system, sync_ship_outline_visibility_and_color_system,
+    tractor_beam_force_system, tractor_deflect_system, tractor_energy_drain_system,
+    tractor_hold_toggle_system, tractor_throw_cooldown_tick_system, turret_aim_fire_system,
+    AimDirection, AimIdleTimer, AimTargetMemory, CampaignLoadout, IonCannonCooldown,
+    IonCannonLevel, KillCamReplay, KillCamState, LockOnState, MissileAmmo, MissileCooldown, Player,
+    PlayerEnergy, PlayerIntent, PlayerLives, PlayerScore, PlayerUiEntities, PreferredGamepad,
+    ShieldLevel, TractorBeamLevel, TractorCaptureState, TractorDeflectWindow, TractorHoldState,
+    TractorThrowCooldown, TurretLevel,
+};
+use crate::rendering::gravity_heatmap::sync_gravity_heatmap_system;
+use crate::rendering::hover_tooltip::{hover_tooltip_system, HoverTooltipState};
+use crate::rendering::hud_layout::hud_anchor_system;
+use crate::rendering::inspector::{
+    asteroid_inspector_display_system, asteroid_select_click_system, AsteroidSelection,
 };
+use crate::rendering::motion_trail::{motion_trail_system, ShipTrail};
+use crate::rendering::physics_layers::sync_physics_debug_layers_system;
+use crate::rendering::split_preview::sync_missile_split_preview_system;
+use crate::rendering::streamer::{
+    streamer_hud_style_system, streamer_stats_system, streamer_toggle_system,
+};
+use crate::rendering::trajectory::sync_trajectory_layer_system;
 use crate::rendering::{
-    debug_panel_button_system, hud_mode_display_system, hud_score_display_system,
-    lives_hud_display_system, missile_hud_display_system, ore_hud_display_system,
-    physics_inspector_display_system, profiler_display_system, stats_display_system,
+    debug_panel_button_system, energy_hud_display_system, hud_clock_display_system,
+    hud_mode_display_system, hud_score_display_system, lives_hud_display_system,
+    missile_hud_display_system, ore_hud_display_system, physics_inspector_display_system,
+    profiler_display_system, register_builtin_hud_widgets, stats_display_system,
     sync_boundary_ring_visibility_system, sync_debug_line_layers_system,
     sync_loadout_hud_visibility_system, sync_physics_inspector_visibility_system,
     sync_profiler_visibility_system, sync_stats_overlay_visibility_system, OverlayState,
@@ -46,21 +128,36 @@ use crate::rendering::{
 use crate::spatial_partition::{rebuild_spatial_grid_system, SpatialGrid};
 use bevy::input::mouse::MouseWheel;
 use bevy::prelude::*;
+use bevy::tasks::{ComputeTaskPool, TaskPool};
 use bevy_rapier2d::prelude::*;
 use std::time::Instant;
 
 /// Tracks simulation statistics: active asteroids, culled count, merged count, split count, destroyed count
-#[derive(Resource, Default, Clone, Copy, Debug)]
+#[derive(Resource, Default, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
 pub struct SimulationStats {
     pub live_count: u32,
+    /// Asteroids streamed out into the sector store this session.
     pub culled_total: u32,
+    /// Asteroids currently parked in the sector store.
+    pub stored_count: u32,
     pub merged_total: u32,
     pub split_total: u32,
     pub destroyed_total: u32,
+    /// Bound clusters left for a later tick by `formation_merge_budget` on
+    /// the last formation pass.
+    pub formation_deferred: u32,
+    /// Running total of deferred cluster merges.
+    pub formation_deferred_total: u32,
+    /// Asteroids reinserted into the KD-tree on the last tick.
+    pub spatial_reinserted: u32,
+    /// Full KD-tree rebuilds this session.
+    pub spatial_rebuilds_total: u32,
 }
 
 /// Aggregated missile combat telemetry used for balancing and test logs.
-#[derive(Resource, Default, Clone, Copy, Debug)]
+#[derive(Resource, Default, Clone, Copy, Debug, Reflect)]
+#[reflect(Resource)]
 pub struct MissileTelemetry {
     pub shots_fired: u32,
     pub hits: u32,
@@ -75,6 +172,20 @@ pub struct MissileTelemetry {
 #[derive(Resource, Default, Clone, Copy, Debug)]
 pub struct CameraState {
     pub zoom: f32,
+    /// World-space camera offset from the player applied by lock-on framing.
+    pub frame_offset: Vec2,
+    /// Zoom override applied by lock-on framing; `None` uses `zoom`.
+    pub frame_zoom: Option<f32>,
+    /// Screen-shake offset set by `feedback::screen_shake_system`.
+    pub shake_offset: Vec2,
+}
+
+impl CameraState {
+    /// Zoom actually applied to the camera this frame.
+    #[inline]
+    pub fn effective_zoom(&self) -> f32 {
+        self.frame_zoom.unwrap_or(self.zoom)
+    }
 }
 
 /// Per-schedule timing breakdown for in-game profiler display.
@@ -113,6 +224,8 @@ pub(crate) struct GravityScratch {
     gravity_index: std::collections::HashMap<Entity, usize>,
     /// Reusable per-entity accumulated force deltas for gravity.
     gravity_force_deltas: Vec<Vec2>,
+    /// One reusable neighbor-query buffer per parallel gravity task.
+    parallel_neighbor_bufs: Vec<Vec<Entity>>,
 }
 
 /// Per-frame scratch buffers reused by `asteroid_formation_system`.
@@ -134,8 +247,8 @@ pub struct FormationScratch {
     cluster_indices: Vec<usize>,
     /// Reusable per-cluster masses buffer.
     masses: Vec<f32>,
-    /// Reusable world-space vertex accumulation buffer.
-    world_vertices: Vec<Vec2>,
+    /// Reusable summed contact impulse by asteroid index.
+    impulse: Vec<f32>,
 }
 
 pub struct SimulationPlugin;
@@ -143,15 +256,33 @@ pub struct SimulationPlugin;
 impl Plugin for SimulationPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(SimulationStats::default())
+            .insert_resource(AsteroidLodStats::default())
+            .insert_resource(AsteroidRenderAssets::default())
+            .init_resource::<HullInstances>()
             .insert_resource(MissileTelemetry::default())
             .insert_resource(OverlayState::default())
             .insert_resource(GravityScratch::default())
             .insert_resource(FormationScratch::default())
+            .insert_resource(MergeScratch::default())
+            .insert_resource(DensityField::default())
+            .insert_resource(PreStepVelocities::default())
+            .insert_resource(BiggestAccretion::default())
             .insert_resource(ProfilerStats::default())
             .insert_resource(ProfilerClock::default())
-            .insert_resource(CameraState { zoom: 1.0 })
+            .insert_resource(FrameTimeGovernor::default())
+            .insert_resource(TimeControl::default())
+            .insert_resource(ClusterStats::default())
+            .insert_resource(PhysicsResumeGuard::default())
+            .insert_resource(AsteroidSelection::default())
+            .insert_resource(HoverTooltipState::default())
+            .insert_resource(ShipTrail::default())
+            .insert_resource(CameraState {
+                zoom: 1.0,
+                ..default()
+            })
             .insert_resource(AimDirection::default())
             .insert_resource(AimIdleTimer::default())
+            .insert_resource(AimTargetMemory::default())
             .insert_resource(PreferredGamepad::default())
             .insert_resource(PlayerIntent::default())
             .insert_resource(PlayerScore::default())
@@ -161,28 +292,43 @@ impl Plugin for SimulationPlugin {
             .insert_resource(TractorHoldState::default())
             .insert_resource(TractorCaptureState::default())
             .insert_resource(TractorThrowCooldown::default())
+            .insert_resource(TractorDeflectWindow::default())
             .insert_resource(IonCannonLevel::default())
             .insert_resource(IonCannonCooldown::default())
+            .insert_resource(PlayerEnergy::default())
+            .insert_resource(LockOnState::default())
+            .insert_resource(KillCamState::default())
+            .insert_resource(KillCamReplay::default())
+            .insert_resource(ShieldLevel::default())
+            .insert_resource(TurretLevel::default())
             .insert_resource(MissileAmmo::default())
             .insert_resource(MissileCooldown::default())
             .insert_resource(PlayerUiEntities::default())
             .insert_resource(SpatialGrid::default())
+            .insert_resource(SectorStore::default())
+            .insert_resource(ProceduralField::default())
+            .insert_resource(FieldSizeClass::default())
+            .add_message::<Shockwave>()
+            .add_message::<VolatileBlast>()
+            .add_message::<SizeClassReached>()
+            .add_message::<MergeIntent>()
             .add_systems(
                 Update,
                 (
                     profiler_begin_update_system,
                     // ── Group 1: physics bookkeeping + input pipeline ─────────
                     (
-                        stats_counting_system, // Count asteroids for stats
-                        culling_system,        // Hard-remove asteroids past safety boundary
-                        particle_locking_system,
+                        stats_counting_system,              // Count asteroids for stats
+                        procedural_field_system,            // Generate unvisited chunks (FRONTIER)
+                        sector_streaming_system, // Park far asteroids in sectors, rehydrate near ones
                         gamepad_connection_system, // Track preferred gamepad
                         tractor_throw_cooldown_tick_system, // Tick throw cooldown timer
                         tractor_hold_toggle_system, // Toggle tractor hold mode (KB/gamepad)
-                        mouse_aim_system,          // Mouse cursor updates AimDirection
+                        mouse_aim_system,        // Mouse cursor updates AimDirection
                         player_intent_clear_system, // Reset ExternalForce + PlayerIntent
                         keyboard_to_intent_system, // KB thrust+strafe + cursor-facing → PlayerIntent
                         gamepad_to_intent_system,  // Gamepad sticks/triggers → PlayerIntent
+                        player_energy_system,      // Regen energy pool + throttle thrust intent
                         apply_player_intent_system, // PlayerIntent → ExternalForce / Velocity
                         player_thrust_particles_system, // Emit player exhaust opposite active thrust
                     )
@@ -194,15 +340,19 @@ impl Plugin for SimulationPlugin {
                             projectile_fire_system,              // Space/click/A fires
                             missile_fire_system,                 // X/right-click/B fires a missile
                             ion_cannon_fire_system,              // C/Y fires an ion shot
+                            lock_on_system,                      // T/R3 toggles target lock-on
                             missile_acceleration_system,         // Missiles ramp toward max speed
+                            missile_lock_on_steer_system,        // Bias missile heading toward lock
+                            missile_homing_system, // Upgraded missiles seek targets unlocked
                             missile_trail_particles_system, // Exhaust particles opposite velocity
-                            ion_shot_particles_system,      // Ion shot particle trail
+                            ion_shot_particles_system, // Ion shot particle trail
                             stunned_enemy_particles_system, // Stunned enemy particle feedback
-                            aim_snap_system,                // Snap aim after idle timeout
+                            aim_snap_system,       // Snap aim after idle timeout
                             despawn_old_projectiles_system, // Expire old projectiles
-                            despawn_old_missiles_system,    // Expire old missiles
+                            despawn_old_missiles_system, // Expire old missiles
                             despawn_old_ion_cannon_shots_system, // Expire old ion shots
-                            user_input_system,              // Mouse wheel zoom
+                            user_input_system,     // Mouse wheel zoom
+                            lock_on_camera_framing_system, // Frame ship + locked target
                         )
                             .chain(),
                         (
@@ -211,13 +361,15 @@ impl Plugin for SimulationPlugin {
                             attach_asteroid_mesh_system, // Attach Mesh2d to new asteroids
                             refresh_asteroid_mesh_on_vertices_change_system, // Rebuild asteroid meshes after in-place geometry edits
                             sync_asteroid_render_mode_system, // Swap fill/outline mesh on wireframe_only toggle
-                            attach_player_ship_mesh_system,   // Attach Mesh2d to player ship
-                            attach_player_ui_system,          // Spawn health bar + aim indicator
-                            attach_projectile_mesh_system,    // Attach Mesh2d to new projectiles
-                            attach_missile_mesh_system,       // Attach Mesh2d to new missiles
+                            asteroid_lod_system, // Swap distant asteroids to simplified/hidden LOD
+                            attach_player_ship_mesh_system, // Attach Mesh2d to player ship
+                            attach_player_ui_system, // Spawn health bar + aim indicator
+                            attach_projectile_mesh_system, // Attach Mesh2d to new projectiles
+                            attach_missile_mesh_system, // Attach Mesh2d to new missiles
                             attach_ion_cannon_shot_mesh_system, // Attach Mesh2d to new ion shots
                             sync_projectile_rotation_system, // Update projectile rotation to match velocity
                             sync_player_and_projectile_mesh_visibility_system, // Propagate wireframe_only
+                            sync_lock_on_bracket_system, // Spawn/move/despawn lock-on bracket
                         )
                             .chain(),
                     )
@@ -227,6 +379,11 @@ impl Plugin for SimulationPlugin {
                     (
                         sync_boundary_ring_visibility_system, // Show/hide boundary ring
                         sync_debug_line_layers_system,        // Refresh retained debug line layers
+                        (
+                            sync_trajectory_layer_system,
+                            sync_physics_debug_layers_system,
+                            sync_gravity_heatmap_system,
+                        ), // Predicted paths + physics debug layers + gravity heatmap
                         sync_stats_overlay_visibility_system, // Show/hide stats overlay
                         sync_physics_inspector_visibility_system, // Show/hide physics inspector
                         sync_profiler_visibility_system,      // Show/hide profiler overlay
@@ -241,7 +398,6 @@ impl Plugin for SimulationPlugin {
                         stats_display_system,          // Render stats overlay text
                         physics_inspector_display_system, // Render physics inspector text
                         profiler_display_system,       // Render profiler text
-                        soft_boundary_system,          // Shared inward spring near boundary
                         player_collision_damage_system, // Player takes damage from asteroids
                         player_respawn_system,         // Re-spawn ship after countdown
                         cleanup_player_ui_system,      // Despawn UI on player death
@@ -258,15 +414,115 @@ impl Plugin for SimulationPlugin {
             )
             .add_systems(
                 Update,
-                hud_mode_display_system.run_if(in_state(GameState::Playing)),
+                frame_governor_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                cluster_labeling_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                sync_missile_split_preview_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    asteroid_select_click_system,
+                    asteroid_inspector_display_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                hover_tooltip_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                motion_trail_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    volatile_tint_system.after(attach_asteroid_mesh_system),
+                    volatile_glow_system,
+                )
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (hud_mode_display_system, hud_clock_display_system)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Any state: the HUD persists through pause and shop, and the
+            // window can be resized from either.
+            .add_systems(Update, hud_anchor_system)
+            .add_systems(
+                Update,
+                (streamer_toggle_system, streamer_hud_style_system)
+                    .chain()
+                    .before(hud_anchor_system),
+            )
+            .add_systems(
+                Update,
+                streamer_stats_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                energy_hud_display_system.run_if(in_state(GameState::Playing)),
             )
             .add_systems(
                 Update,
                 sync_loadout_hud_visibility_system.run_if(in_state(GameState::Playing)),
             )
+            .add_systems(
+                Update,
+                (player_shield_system, sync_player_shield_visual_system)
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                (
+                    player_turret_system,
+                    turret_aim_fire_system,
+                    attach_turret_mesh_system,
+                )
+                    .chain()
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Physics tick rate follows `physics_tick_hz` and the player's time
+            // scale; see `fixed_step` and `time_control`.
+            .add_systems(
+                Startup,
+                sync_physics_tick_rate_system.after(crate::config::load_physics_config),
+            )
+            .add_systems(
+                Update,
+                time_control_system
+                    .before(sync_physics_tick_rate_system)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                Update,
+                sync_physics_tick_rate_system
+                    .run_if(resource_changed::<PhysicsConfig>.or(resource_changed::<TimeControl>)),
+            )
+            .add_systems(
+                FixedFirst,
+                (
+                    restore_physics_poses_system,
+                    origin::origin_recenter_system.run_if(in_state(GameState::Playing)),
+                )
+                    .chain(),
+            )
             // Rebuild grid, run gravity, and count neighbors in FixedUpdate.
             // neighbor_counting_system was previously in Update (60 Hz) — moving it here
             // avoids 60 KD-tree scans per second that produced no visible difference.
+            // soft_boundary_system runs after gravity resets asteroid forces so
+            // the spring is added exactly once per tick; shockwaves likewise
+            // add their one-tick push after the reset.  Ring particles are
+            // steered onto circular orbits before Rapier integrates the tick.
             .add_systems(
                 FixedUpdate,
                 (
@@ -274,7 +530,12 @@ impl Plugin for SimulationPlugin {
                     (
                         rebuild_spatial_grid_system,
                         nbody_gravity_system,
+                        shockwave_force_system,
+                        tractor_deflect_system, // Sees the hold before a throw clears it
                         tractor_beam_force_system,
+                        tractor_energy_drain_system,
+                        soft_boundary_system,
+                        ring_circularization_system,
                         neighbor_counting_system,
                     )
                         .chain(),
@@ -283,20 +544,75 @@ impl Plugin for SimulationPlugin {
                     .chain()
                     .run_if(in_state(GameState::Playing)),
             )
-            // asteroid_formation_system must run AFTER Rapier (PostUpdate) populates contacts.
-            // apply_deferred between the two systems flushes formation's despawns/spawns so
-            // projectile_asteroid_hit_system never double-processes an entity that was already
-            // merged and despawned by the formation system in the same frame.
+            // Rapier steps in FixedPostUpdate, so the impact-energy bounce,
+            // velocity locking and merging run right after its writeback on the
+            // same tick's contacts.  Tidal disruption runs last, so a merge
+            // inside a Roche radius is torn apart on the tick it happens.
+            .add_systems(
+                FixedPostUpdate,
+                (
+                    merge_cooldown_system,
+                    collision_response_system,
+                    particle_locking_system,
+                    asteroid_formation_system,
+                    merge_resolution_system,
+                    tidal_disruption_system,
+                )
+                    .chain()
+                    .after(PhysicsSet::Writeback)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // The bounce needs each body's closing speed before the inelastic
+            // solve, so velocities are recorded before Rapier reads them.
+            .add_systems(
+                FixedPostUpdate,
+                record_pre_step_velocities_system
+                    .before(PhysicsSet::SyncBackend)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            // Handle validation and deferred resume run in every state: the
+            // pipeline flag they guard is shared by all of them.
+            .add_systems(
+                FixedPostUpdate,
+                (
+                    validate_rapier_handles_system
+                        .after(PhysicsSet::SyncBackend)
+                        .before(PhysicsSet::StepSimulation),
+                    apply_physics_resume_system.after(PhysicsSet::Writeback),
+                ),
+            )
+            .add_systems(
+                FixedLast,
+                (
+                    attach_physics_interpolation_system,
+                    record_physics_poses_system,
+                )
+                    .chain(),
+            )
+            .add_systems(
+                FixedLast,
+                record_kill_cam_history_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                PostUpdate,
+                interpolate_physics_poses_system
+                    .before(bevy::transform::TransformSystems::Propagate),
+            )
+            // Weapon hit systems read the contacts left by the last physics tick;
+            // volatiles they lit detonate right after.
             .add_systems(
                 PostUpdate,
                 (
                     profiler_begin_post_update_system,
                     (
-                        asteroid_formation_system,
                         ion_cannon_hit_enemy_system,
                         projectile_asteroid_hit_system,
                         missile_asteroid_hit_system,
                         projectile_missile_planet_hit_system,
+                        soft_body_sensor_system,
+                        soft_body_impact_system,
+                        volatile_fuse_system,
+                        volatile_blast_ship_system,
                     )
                         .chain(),
                     profiler_end_post_update_system,
@@ -304,9 +620,30 @@ impl Plugin for SimulationPlugin {
                     .chain()
                     .run_if(in_state(GameState::Playing)),
             )
+            // The kill-cam runs in Last so it observes every player death resolved
+            // this frame (including enemy damage in PostUpdate) and can defer a
+            // pending GameOver transition before StateTransition applies it.
+            .add_systems(Last, kill_cam_system.run_if(in_state(GameState::Playing)))
+            .add_systems(
+                Update,
+                (size_class_system, density_field_system).run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(
+                OnExit(GameState::MainMenu),
+                (reset_field_size_class_system, reset_density_field_system),
+            )
+            .add_systems(
+                OnExit(GameState::GameOver),
+                (reset_field_size_class_system, reset_density_field_system),
+            )
+            .add_systems(
+                OnEnter(GameState::MainMenu),
+                (reset_kill_cam_system, reset_time_control_system),
+            )
             // debug_panel_button_system runs outside the Playing gate so the debug
             // overlay toggles remain functional while the game is paused.
             .add_systems(Update, debug_panel_button_system);
+        register_builtin_hud_widgets(app);
     }
 }
 
@@ -379,6 +716,7 @@ fn missile_telemetry_log_system(
     mut frame_counter: Local<u32>,
     mut last_logged_shots: Local<u32>,
     telemetry: Res<MissileTelemetry>,
+    run: Res<crate::run_info::RunMetadata>,
 ) {
     *frame_counter += 1;
 
@@ -410,7 +748,8 @@ fn missile_telemetry_log_system(
     };
 
     info!(
-        "[missile_telemetry] frame={} shots={} hits={} hit_rate={:.1}% outcomes{{destroy:{:.1}%, split:{:.1}%, decompose:{:.1}%}} mass{{destroyed:{}, decomposed:{}}} ttk_proxy={{frames_per_kill:{}}}",
+        "[missile_telemetry] run={} frame={} shots={} hits={} hit_rate={:.1}% outcomes{{destroy:{:.1}%, split:{:.1}%, decompose:{:.1}%}} mass{{destroyed:{}, decomposed:{}}} ttk_proxy={{frames_per_kill:{}}}",
+        run.stamp(),
         *frame_counter,
         telemetry.shots_fired,
         telemetry.hits,
@@ -430,11 +769,57 @@ fn missile_telemetry_log_system(
     *last_logged_shots = telemetry.shots_fired;
 }
 
+/// Age split lineages and release fragments from them.
+///
+/// A fragment loses its [`MergeCooldown`] once it is `merge_cooldown_secs`
+/// old and its bounding circle is at least `merge_rearm_gap` clear of every
+/// sibling still in play, or once it reaches `merge_cooldown_max_secs`.  The
+/// separation requirement is the hysteresis: touching siblings stay apart
+/// until they have actually drifted away from each other.
+pub fn merge_cooldown_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    mut query: Query<(Entity, &Transform, &Vertices, &mut MergeCooldown)>,
+) {
+    let dt = time.delta_secs();
+    let mut lineages: std::collections::HashMap<u64, Vec<(Entity, Vec2, f32)>> =
+        std::collections::HashMap::new();
+    for (entity, transform, vertices, mut cooldown) in query.iter_mut() {
+        cooldown.age += dt;
+        let radius = vertices.0.iter().map(|v| v.length()).fold(0.0, f32::max);
+        lineages.entry(cooldown.lineage).or_default().push((
+            entity,
+            transform.translation.truncate(),
+            radius,
+        ));
+    }
+
+    for (entity, _, _, cooldown) in query.iter() {
+        if cooldown.age < config.merge_cooldown_secs {
+            continue;
+        }
+        let siblings = &lineages[&cooldown.lineage];
+        let Some(&(_, pos, radius)) = siblings.iter().find(|(e, ..)| *e == entity) else {
+            continue;
+        };
+        let separated = siblings.iter().all(|&(other, other_pos, other_radius)| {
+            other == entity
+                || pos.distance(other_pos) - radius - other_radius >= config.merge_rearm_gap
+        });
+        if separated || cooldown.age >= config.merge_cooldown_max_secs {
+            commands.entity(entity).remove::<MergeCooldown>();
+        }
+    }
+}
+
 /// Lock and merge asteroids when they're slow and touching.
 /// Optimized: uses Rapier's contact pair iterator directly (O(C) where C = active contacts)
 /// instead of iterating all N² entity pairs to find touching ones.
+///
+/// Fragments of the same split (see [`MergeCooldown`]) are never locked.
 pub fn particle_locking_system(
-    mut query: Query<(Entity, &mut Velocity), With<Asteroid>>,
+    mut query: Query<(Entity, &mut Velocity, Option<&MergeCooldown>), With<Asteroid>>,
     rapier_context: ReadRapierContext,
     config: Res<PhysicsConfig>,
 ) {
@@ -458,7 +843,10 @@ pub fn particle_locking_system(
         };
 
         // Only sync velocities if both asteroids are slow
-        if let (Ok((_, v1)), Ok((_, v2))) = (query.get(e1), query.get(e2)) {
+        if let (Ok((_, v1, c1)), Ok((_, v2, c2))) = (query.get(e1), query.get(e2)) {
+            if MergeCooldown::blocks(c1, c2) {
+                continue;
+            }
             if v1.linvel.length() < velocity_threshold && v2.linvel.length() < velocity_threshold {
                 pairs_to_merge.push((e1, e2));
             }
@@ -467,7 +855,7 @@ pub fn particle_locking_system(
 
     // Sync velocities for qualifying pairs
     for (e1, e2) in pairs_to_merge {
-        if let Ok([(_, mut v1), (_, mut v2)]) = query.get_many_mut([e1, e2]) {
+        if let Ok([(_, mut v1, _), (_, mut v2, _)]) = query.get_many_mut([e1, e2]) {
             let avg_linvel = (v1.linvel + v2.linvel) * 0.5;
             let avg_angvel = (v1.angvel + v2.angvel) * 0.5;
             v1.linvel = avg_linvel;
@@ -507,6 +895,22 @@ pub(crate) fn gravity_force_between(
     Some(delta.normalize_or_zero() * (gravity_const * mass_i * mass_j / dist_sq))
 }
 
+/// Distance limits and strength shared by every gravity pair in one tick.
+#[derive(Clone, Copy, Debug)]
+struct GravityParams {
+    gravity_const: f32,
+    min_dist_sq: f32,
+    max_dist: f32,
+    max_dist_sq: f32,
+    /// Pairs weaker than this (squared force magnitude) are skipped; raised
+    /// by the frame-time governor under load.
+    force_epsilon_sq: f32,
+}
+
+/// Smallest number of bodies handed to one parallel gravity task.  Below
+/// this, task overhead outweighs the work, so small fields stay serial.
+const GRAVITY_PARALLEL_MIN_CHUNK: usize = 32;
+
 /// N-body gravity system: applies mass-scaled gravity between all asteroids.
 ///
 /// Force magnitude: `G · m_i · m_j / r²` where `m_i` and `m_j` are the
@@ -514,7 +918,10 @@ pub(crate) fn gravity_force_between(
 /// (and the Orbit scenario's massive central planetoid) are genuinely more
 /// gravitationally dominant, producing stable orbital dynamics.
 ///
-/// Uses KD-tree neighbor queries to avoid full O(N²) scans.
+/// Uses KD-tree neighbor queries to avoid full O(N²) scans.  With
+/// `gravity_parallel` enabled the accumulation runs on the compute task pool
+/// (see [`accumulate_gravity_parallel`]); otherwise each pair is evaluated
+/// once on this thread (see [`accumulate_gravity_serial`]).
 pub(crate) fn nbody_gravity_system(
     mut query: Query<
         (
@@ -527,14 +934,22 @@ pub(crate) fn nbody_gravity_system(
         With<Asteroid>,
     >,
     config: Res<PhysicsConfig>,
+    governor: Res<FrameTimeGovernor>,
     grid: Res<SpatialGrid>,
     mut scratch: ResMut<GravityScratch>,
 ) {
-    let gravity_const = config.gravity_const;
-    let min_gravity_dist = config.min_gravity_dist;
-    let max_gravity_dist = config.max_gravity_dist;
-    let min_gravity_dist_sq = min_gravity_dist * min_gravity_dist;
-    let max_gravity_dist_sq = max_gravity_dist * max_gravity_dist;
+    static ALLOC_TAG: AllocTag = AllocTag::new("FixedUpdate", "nbody_gravity_system");
+    let _alloc_scope = ALLOC_TAG.enter();
+    // Radius shrinks and the cutoff epsilon grows under load; see `governor`.
+    let max_gravity_dist = governor.effective_max_gravity_dist(&config);
+    let force_epsilon = governor.effective_gravity_force_epsilon(&config);
+    let params = GravityParams {
+        gravity_const: config.gravity_const,
+        min_dist_sq: config.min_gravity_dist * config.min_gravity_dist,
+        max_dist: max_gravity_dist,
+        max_dist_sq: max_gravity_dist * max_gravity_dist,
+        force_epsilon_sq: force_epsilon * force_epsilon,
+    };
 
     // CRITICAL: Reset all forces to zero first, then calculate fresh.
     for (_, _, _, mut force, mut grav) in query.iter_mut() {
@@ -573,15 +988,60 @@ pub(crate) fn nbody_gravity_system(
         .gravity_force_deltas
         .resize(entity_count, Vec2::ZERO);
 
-    // Calculate gravity by querying only nearby candidates from the spatial index.
-    for idx_i in 0..entity_count {
-        let (entity_i, pos_i, mass_i) = scratch.gravity_entities[idx_i];
-        grid.query_neighbors_into(entity_i, pos_i, max_gravity_dist, &mut scratch.neighbor_buf);
+    let GravityScratch {
+        neighbor_buf,
+        gravity_entities,
+        gravity_index,
+        gravity_force_deltas,
+        parallel_neighbor_bufs,
+        ..
+    } = &mut *scratch;
+    if config.gravity_parallel && entity_count >= 2 * GRAVITY_PARALLEL_MIN_CHUNK {
+        accumulate_gravity_parallel(
+            gravity_entities,
+            gravity_index,
+            &grid,
+            params,
+            gravity_force_deltas,
+            parallel_neighbor_bufs,
+        );
+    } else {
+        accumulate_gravity_serial(
+            gravity_entities,
+            gravity_index,
+            &grid,
+            params,
+            gravity_force_deltas,
+            neighbor_buf,
+        );
+    }
+
+    // Apply accumulated forces.
+    for idx in 0..entity_count {
+        let entity = scratch.gravity_entities[idx].0;
+        let force_delta = scratch.gravity_force_deltas[idx];
+        if let Ok((_, _, _, mut force, mut grav)) = query.get_mut(entity) {
+            force.force += force_delta;
+            grav.0 += force_delta;
+        }
+    }
+}
+
+/// Serial gravity accumulation: each pair is evaluated once and applied to
+/// both bodies (Newton's third law).  This is the reference result.
+fn accumulate_gravity_serial(
+    bodies: &[(Entity, Vec2, f32)],
+    index: &std::collections::HashMap<Entity, usize>,
+    grid: &SpatialGrid,
+    params: GravityParams,
+    deltas: &mut [Vec2],
+    neighbor_buf: &mut Vec<Entity>,
+) {
+    for (idx_i, &(entity_i, pos_i, mass_i)) in bodies.iter().enumerate() {
+        grid.query_neighbors_into(entity_i, pos_i, params.max_dist, neighbor_buf);
 
-        let neighbor_count = scratch.neighbor_buf.len();
-        for nn in 0..neighbor_count {
-            let entity_j = scratch.neighbor_buf[nn];
-            let Some(&idx_j) = scratch.gravity_index.get(&entity_j) else {
+        for entity_j in neighbor_buf.iter() {
+            let Some(&idx_j) = index.get(entity_j) else {
                 continue;
             };
 
@@ -590,32 +1050,90 @@ pub(crate) fn nbody_gravity_system(
                 continue;
             }
 
-            let (_, pos_j, mass_j) = scratch.gravity_entities[idx_j];
+            let (_, pos_j, mass_j) = bodies[idx_j];
             if let Some(force) = gravity_force_between(
                 pos_i,
                 pos_j,
-                gravity_const,
-                min_gravity_dist_sq,
-                max_gravity_dist_sq,
+                params.gravity_const,
+                params.min_dist_sq,
+                params.max_dist_sq,
                 mass_i,
                 mass_j,
-            ) {
+            )
+            .filter(|force| force.length_squared() >= params.force_epsilon_sq)
+            {
                 // Apply Newton's third law: equal and opposite forces
-                scratch.gravity_force_deltas[idx_i] += force;
-                scratch.gravity_force_deltas[idx_j] -= force;
+                deltas[idx_i] += force;
+                deltas[idx_j] -= force;
             }
         }
     }
+}
 
-    // Apply accumulated forces.
-    for idx in 0..entity_count {
-        let entity = scratch.gravity_entities[idx].0;
-        let force_delta = scratch.gravity_force_deltas[idx];
-        if let Ok((_, _, _, mut force, mut grav)) = query.get_mut(entity) {
-            force.force += force_delta;
-            grav.0 += force_delta;
-        }
+/// Parallel gravity accumulation on the [`ComputeTaskPool`].
+///
+/// Bodies are split into contiguous chunks, one task each.  A task sums the
+/// full force on each body in its chunk, so tasks only write their own slice
+/// of `deltas` and need no locks.  The price is evaluating every pair from
+/// both sides — twice the serial pair count, spread across all cores.
+///
+/// Per-body sums follow the KD-tree's neighbour order, so results do not
+/// depend on thread scheduling.
+fn accumulate_gravity_parallel(
+    bodies: &[(Entity, Vec2, f32)],
+    index: &std::collections::HashMap<Entity, usize>,
+    grid: &SpatialGrid,
+    params: GravityParams,
+    deltas: &mut [Vec2],
+    neighbor_bufs: &mut Vec<Vec<Entity>>,
+) {
+    let pool = ComputeTaskPool::get_or_init(TaskPool::default);
+    let chunk_size = bodies
+        .len()
+        .div_ceil(pool.thread_num().max(1))
+        .max(GRAVITY_PARALLEL_MIN_CHUNK);
+    let chunk_count = bodies.len().div_ceil(chunk_size);
+    if neighbor_bufs.len() < chunk_count {
+        neighbor_bufs.resize_with(chunk_count, Vec::new);
     }
+
+    pool.scope(|scope| {
+        for (chunk_idx, (delta_chunk, neighbor_buf)) in deltas
+            .chunks_mut(chunk_size)
+            .zip(neighbor_bufs.iter_mut())
+            .enumerate()
+        {
+            let first = chunk_idx * chunk_size;
+            scope.spawn(async move {
+                for (offset, delta) in delta_chunk.iter_mut().enumerate() {
+                    let (entity_i, pos_i, mass_i) = bodies[first + offset];
+                    grid.query_neighbors_into(entity_i, pos_i, params.max_dist, neighbor_buf);
+
+                    let mut total = Vec2::ZERO;
+                    for entity_j in neighbor_buf.iter() {
+                        let Some(&idx_j) = index.get(entity_j) else {
+                            continue;
+                        };
+                        let (_, pos_j, mass_j) = bodies[idx_j];
+                        if let Some(force) = gravity_force_between(
+                            pos_i,
+                            pos_j,
+                            params.gravity_const,
+                            params.min_dist_sq,
+                            params.max_dist_sq,
+                            mass_i,
+                            mass_j,
+                        )
+                        .filter(|force| force.length_squared() >= params.force_epsilon_sq)
+                        {
+                            total += force;
+                        }
+                    }
+                    *delta = total;
+                }
+            });
+        }
+    });
 }
 
 /// Updates `AimDirection` every frame from the mouse cursor position.
@@ -626,6 +1144,7 @@ pub fn mouse_aim_system(
     mut aim: ResMut<AimDirection>,
     mut idle: ResMut<AimIdleTimer>,
     windows: Query<&Window>,
+    camera_state: Res<CameraState>,
 ) {
     let Ok(window) = windows.single() else {
         return;
@@ -637,11 +1156,14 @@ pub fn mouse_aim_system(
     // Compute the direction from the window centre toward the cursor.
     // Because the camera follows the player, this is identical to the world-space
     // direction from the player to the cursor (zoom scale cancels on .normalize()).
+    // Lock-on framing shifts the camera off the ship, so that offset is added
+    // back in world units before normalising.
     let offset = Vec2::new(
         cursor.x - window.width() / 2.0,
         -(cursor.y - window.height() / 2.0), // flip Y: Bevy world +Y = screen up
     );
-    let dir = offset.normalize_or_zero();
+    let dir =
+        (offset * camera_state.effective_zoom() + camera_state.frame_offset).normalize_or_zero();
     if dir.length_squared() > 0.0 {
         // A valid off-centre cursor means mouse aim is actively controlling
         // facing, even when the cursor is stationary.
@@ -679,8 +1201,9 @@ pub fn camera_zoom_system(
     camera_state: Res<CameraState>,
     mut camera_query: Query<&mut Transform, With<Camera>>,
 ) {
+    let zoom = camera_state.effective_zoom();
     for mut transform in camera_query.iter_mut() {
-        transform.scale = Vec3::new(camera_state.zoom, camera_state.zoom, 1.0);
+        transform.scale = Vec3::new(zoom, zoom, 1.0);
     }
 }
 
@@ -692,6 +1215,8 @@ pub(crate) fn neighbor_counting_system(
     config: Res<PhysicsConfig>,
     mut scratch: ResMut<GravityScratch>,
 ) {
+    static ALLOC_TAG: AllocTag = AllocTag::new("FixedUpdate", "neighbor_counting_system");
+    let _alloc_scope = ALLOC_TAG.enter();
     let neighbor_threshold = config.neighbor_threshold;
 
     // Collect (entity, pos) pairs into the reusable buffer — avoids creating a
@@ -715,50 +1240,19 @@ pub(crate) fn neighbor_counting_system(
     }
 }
 
-/// Track statistics: live count, culled count, merged count.
-/// Must run BEFORE culling_system to detect which asteroids are about to be hard-culled.
+/// Track statistics: live count within `cull_distance` of the origin.
+///
+/// Streamed-out and parked counts are kept by `sector_streaming_system`.
 pub fn stats_counting_system(
     mut stats: ResMut<SimulationStats>,
-    query: Query<(Entity, &Transform), With<Asteroid>>,
-    config: Res<PhysicsConfig>,
-) {
-    let cull_distance = config.cull_distance;
-    let hard_cull_distance = config.hard_cull_distance;
-    let mut live_count = 0;
-    let mut hard_culled_this_frame = 0;
-
-    for (_, transform) in query.iter() {
-        let dist = transform.translation.truncate().length();
-        if dist <= cull_distance {
-            live_count += 1;
-        }
-        // Count only asteroids that will actually be removed this frame
-        if dist > hard_cull_distance {
-            hard_culled_this_frame += 1;
-        }
-    }
-
-    stats.live_count = live_count;
-    stats.culled_total += hard_culled_this_frame;
-}
-
-/// Hard-cull asteroids that have drifted past the safety boundary.
-///
-/// The soft boundary spring (`soft_boundary_system`) keeps most asteroids from
-/// reaching this distance; hard-culling is a last resort for very fast objects.
-pub fn culling_system(
-    mut commands: Commands,
-    query: Query<(Entity, &Transform), With<Asteroid>>,
+    query: Query<&Transform, With<Asteroid>>,
     config: Res<PhysicsConfig>,
 ) {
-    let hard_cull_distance = config.hard_cull_distance;
-
-    for (entity, transform) in query.iter() {
-        let dist = transform.translation.truncate().length();
-        if dist > hard_cull_distance {
-            commands.entity(entity).despawn();
-        }
-    }
+    let cull_distance_sq = config.cull_distance * config.cull_distance;
+    stats.live_count = query
+        .iter()
+        .filter(|t| t.translation.truncate().length_squared() <= cull_distance_sq)
+        .count() as u32;
 }
 
 /// Apply a restoring force to non-projectile dynamic actors beyond the soft boundary.
@@ -770,32 +1264,74 @@ pub fn culling_system(
 /// This creates a reflecting potential well that nudges stray entities back toward
 /// the simulation centre without the jarring discontinuity of hard-culling.  The
 /// spring activates only when `dist > soft_boundary_radius`.
+///
+/// Disabled while a [`ProceduralField`] is active: the FRONTIER world has no
+/// edge.
+///
+/// Runs once per fixed tick.  Asteroid forces are reset by gravity every tick,
+/// but ship forces are only rebuilt once per rendered frame, so when several
+/// ticks share a frame the spring added on the previous tick is swapped out
+/// rather than stacked.  An `ExternalForce` changed by any other system since
+/// this one last ran is treated as freshly rebuilt.
 #[allow(clippy::type_complexity)]
 pub fn soft_boundary_system(
     mut query: Query<
-        (&Transform, &mut ExternalForce),
+        (Entity, &Transform, &mut ExternalForce),
         Or<(With<Asteroid>, With<Player>, With<Enemy>)>,
     >,
     config: Res<PhysicsConfig>,
+    procgen: Option<Res<ProceduralField>>,
+    mut scratch: Local<SoftBoundaryScratch>,
 ) {
-    let inner_radius = config.soft_boundary_radius;
+    // An infinite radius still clears springs applied on the previous tick.
+    let inner_radius = if procgen.is_some_and(|field| field.enabled) {
+        f32::INFINITY
+    } else {
+        config.soft_boundary_radius
+    };
     let strength = config.soft_boundary_strength;
+    let SoftBoundaryScratch { applied, next } = &mut *scratch;
+    next.clear();
 
-    for (transform, mut ext_force) in query.iter_mut() {
+    for (entity, transform, mut ext_force) in query.iter_mut() {
         let pos = transform.translation.truncate();
         let dist = pos.length();
 
-        if dist > inner_radius && dist > 0.0 {
+        let spring = if dist > inner_radius && dist > 0.0 {
             let excess = dist - inner_radius;
             // Inward unit vector: −pos / dist
             let inward = -pos / dist;
-            ext_force.force += inward * (strength * excess);
+            inward * (strength * excess)
+        } else {
+            Vec2::ZERO
+        };
+        let stale = if ext_force.is_changed() {
+            Vec2::ZERO
+        } else {
+            applied.get(&entity).copied().unwrap_or(Vec2::ZERO)
+        };
+
+        if spring != stale {
+            ext_force.force += spring - stale;
+        }
+        if spring != Vec2::ZERO {
+            next.insert(entity, spring);
         }
     }
+
+    std::mem::swap(applied, next);
 }
 
-/// Form large asteroids by detecting clusters of touching asteroids
-/// and converting them into larger polygons.
+/// Spring force each entity received on the previous tick; see
+/// [`soft_boundary_system`].
+#[derive(Default)]
+pub struct SoftBoundaryScratch {
+    applied: std::collections::HashMap<Entity, Vec2>,
+    next: std::collections::HashMap<Entity, Vec2>,
+}
+
+/// Find clusters of touching asteroids that should form larger polygons, and
+/// write a [`MergeIntent`] for each; [`merge_resolution_system`] merges them.
 ///
 /// ## Merge criterion: gravitational binding energy
 ///
@@ -810,18 +1346,20 @@ pub fn soft_boundary_system(
 ///
 /// Mass is approximated as `AsteroidSize` units (uniform density).
 /// Moment of inertia per member: `I = ½ · m · r_eff²` where `r_eff = √(m / π)`.
+///
+/// Contacts between fragments of the same recent split ([`MergeCooldown`])
+/// are ignored, so siblings only join the same cluster through a third body.
 #[allow(clippy::type_complexity)]
 pub fn asteroid_formation_system(
-    mut commands: Commands,
-    query: Query<
-        (Entity, &Transform, &Velocity, &Vertices, &AsteroidSize),
-        (With<Asteroid>, Without<Planet>),
-    >,
+    query: Query<(Entity, &Transform, &Velocity, &AsteroidSize), (With<Asteroid>, Without<Planet>)>,
+    q_cooldown: Query<&MergeCooldown>,
     rapier_context: ReadRapierContext,
-    mut stats: ResMut<SimulationStats>,
     config: Res<PhysicsConfig>,
     mut scratch: ResMut<FormationScratch>,
+    mut intents: MessageWriter<MergeIntent>,
 ) {
+    static ALLOC_TAG: AllocTag = AllocTag::new("FixedPostUpdate", "asteroid_formation_system");
+    let _alloc_scope = ALLOC_TAG.enter();
     let gravity_const = config.gravity_const;
 
     let Ok(rapier) = rapier_context.single() else {
@@ -838,7 +1376,7 @@ pub fn asteroid_formation_system(
         let deficit = asteroid_count - scratch.index_by_entity.capacity();
         scratch.index_by_entity.reserve(deficit);
     }
-    for (idx, (entity, _, _, _, _)) in asteroids.iter().enumerate() {
+    for (idx, (entity, _, _, _)) in asteroids.iter().enumerate() {
         scratch.index_by_entity.insert(*entity, idx);
     }
 
@@ -850,6 +1388,8 @@ pub fn asteroid_formation_system(
     for neighbors in scratch.adjacency.iter_mut() {
         neighbors.clear();
     }
+    scratch.impulse.clear();
+    scratch.impulse.resize(asteroid_count, 0.0);
 
     for contact_pair in rapier
         .simulation
@@ -870,12 +1410,15 @@ pub fn asteroid_formation_system(
             continue;
         };
 
-        if idx1 == idx2 {
+        if idx1 == idx2 || MergeCooldown::blocks(q_cooldown.get(e1).ok(), q_cooldown.get(e2).ok()) {
             continue;
         }
 
         scratch.adjacency[idx1].push(idx2);
         scratch.adjacency[idx2].push(idx1);
+        let impulse = contact_pair.raw.total_impulse_magnitude();
+        scratch.impulse[idx1] += impulse;
+        scratch.impulse[idx2] += impulse;
     }
 
     scratch.processed.clear();
@@ -886,7 +1429,6 @@ pub fn asteroid_formation_system(
     scratch.queue.clear();
     scratch.cluster_indices.clear();
     scratch.masses.clear();
-    scratch.world_vertices.clear();
 
     for i in 0..asteroid_count {
         if scratch.processed[i] || scratch.adjacency[i].is_empty() {
@@ -934,7 +1476,7 @@ pub fn asteroid_formation_system(
         scratch.masses.clear();
         for cluster_i in 0..scratch.cluster_indices.len() {
             let idx = scratch.cluster_indices[cluster_i];
-            let size = asteroids[idx].4 .0;
+            let size = asteroids[idx].3 .0;
             scratch.masses.push(size as f32);
         }
         let total_mass: f32 = scratch.masses.iter().sum();
@@ -991,112 +1533,25 @@ pub fn asteroid_formation_system(
             continue;
         }
 
-        // ── Merge ─────────────────────────────────────────────────────────────
         for cluster_i in 0..scratch.cluster_indices.len() {
             let idx = scratch.cluster_indices[cluster_i];
             scratch.processed[idx] = true;
         }
-
-        // Composite inherits the centre-of-mass velocity (momentum-conserving).
-        // Angular velocity: simple average (moment-of-inertia weighting negligible here).
-        let avg_linvel = v_cm;
-        let avg_angvel: f32 = scratch
+        // Each contact impulse was added to both of its bodies.
+        let impulse = scratch
             .cluster_indices
             .iter()
-            .map(|&idx| asteroids[idx].2.angvel)
+            .map(|&idx| scratch.impulse[idx])
             .sum::<f32>()
-            / scratch.cluster_indices.len() as f32;
-
-        // Collect ALL vertices from ALL cluster members in world-space
-        scratch.world_vertices.clear();
-        let mut vertex_count = 0usize;
-        for cluster_i in 0..scratch.cluster_indices.len() {
-            let idx = scratch.cluster_indices[cluster_i];
-            vertex_count += asteroids[idx].3 .0.len();
-        }
-        scratch.world_vertices.reserve(vertex_count);
-        for cluster_i in 0..scratch.cluster_indices.len() {
-            let idx = scratch.cluster_indices[cluster_i];
-            let (_, transform, _vel, vertices, _sz) = asteroids[idx];
-            let rotation = transform.rotation;
-            let offset = transform.translation.truncate();
-
-            for local_v in &vertices.0 {
-                // Rotate local vertex by transform rotation
-                let world_v = offset + rotation.mul_vec3(local_v.extend(0.0)).truncate();
-                scratch.world_vertices.push(world_v);
-            }
-        }
-
-        // Compute convex hull from all world-space vertices
-        if let Some(hull) = compute_convex_hull_from_points(&scratch.world_vertices) {
-            // Need at least 3 vertices for a valid composite asteroid
-            if hull.len() >= 3 {
-                // Use the hull's geometric centroid as the spawn position so that the
-                // stored local vertices are centred on (0,0) in local space.
-                // Using the average of cluster entity positions would leave the vertices
-                // off-centre, causing the physics body and drawn outline to misalign.
-                let hull_centroid: Vec2 = hull.iter().copied().sum::<Vec2>() / hull.len() as f32;
-                let hull_local: Vec<Vec2> = hull.iter().map(|v| *v - hull_centroid).collect();
-
-                // Sanity check: skip merges that would produce pathologically large shapes.
-                // A legitimate merge of N small asteroids should never produce a hull
-                // that extends more than ~50 units per constituent member from the center.
-                let max_extent = hull_local
-                    .iter()
-                    .map(|v| v.length())
-                    .fold(0.0_f32, f32::max);
-                let extent_limit = config.hull_extent_base
-                    + scratch.cluster_indices.len() as f32 * config.hull_extent_per_member;
-                if max_extent > extent_limit {
-                    // Refuse to create this merge — it indicates corrupted vertex data.
-                    // Despawn nothing; leave the source asteroids intact.
-                    continue;
-                }
-
-                // Sum unit sizes of all cluster members
-                let total_size: u32 = scratch
-                    .cluster_indices
-                    .iter()
-                    .map(|&idx| asteroids[idx].4 .0)
-                    .sum();
-
-                // Scale the hull so its visual area matches total_size / density.
-                // This ensures merged composites look proportional to their mass
-                // regardless of how spread out the constituent asteroids were.
-                let target_area = total_size as f32 / config.asteroid_density;
-                let hull_local = rescale_vertices_to_area(&hull_local, target_area);
-
-                let avg_color = Color::srgb(0.5, 0.5, 0.5);
-                let composite = crate::asteroid::spawn_asteroid_with_vertices(
-                    &mut commands,
-                    hull_centroid,
-                    &hull_local,
-                    avg_color,
-                    total_size,
-                );
-
-                // Update velocity
-                if let Ok(mut cmd) = commands.get_entity(composite) {
-                    cmd.insert(Velocity {
-                        linvel: avg_linvel,
-                        angvel: avg_angvel,
-                    });
-                }
-
-                // Track merge: N asteroids became 1, so we merged (N-1) asteroids
-                let merge_count = (scratch.cluster_indices.len() - 1) as u32;
-                stats.merged_total += merge_count;
-
-                crate::particles::spawn_merge_particles(&mut commands, hull_centroid);
-
-                // Despawn all source asteroids
-                for &idx in &scratch.cluster_indices {
-                    let entity = asteroids[idx].0;
-                    commands.entity(entity).despawn();
-                }
-            }
-        }
+            * 0.5;
+        intents.write(MergeIntent {
+            members: scratch
+                .cluster_indices
+                .iter()
+                .map(|&idx| asteroids[idx].0)
+                .collect(),
+            impulse,
+        });
     }
 }
 
@@ -1203,6 +1658,162 @@ mod tests {
         assert_eq!(force, Vec2::ZERO);
     }
 
+    // ── merge cooldown ────────────────────────────────────────────────────────
+
+    #[test]
+    fn merge_cooldown_blocks_only_siblings() {
+        let a = MergeCooldown {
+            lineage: 1,
+            age: 0.0,
+        };
+        let b = MergeCooldown {
+            lineage: 2,
+            age: 0.0,
+        };
+        assert!(MergeCooldown::blocks(Some(&a), Some(&a)));
+        assert!(!MergeCooldown::blocks(Some(&a), Some(&b)));
+        assert!(!MergeCooldown::blocks(Some(&a), None));
+        assert!(!MergeCooldown::blocks(None, None));
+    }
+
+    #[test]
+    fn siblings_must_separate_before_the_cooldown_releases_them() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(crate::config::PhysicsConfig::default())
+            .add_systems(Update, merge_cooldown_system);
+        let cfg = app
+            .world()
+            .resource::<crate::config::PhysicsConfig>()
+            .clone();
+
+        let square = Vertices(vec![
+            Vec2::new(-4.0, -4.0),
+            Vec2::new(4.0, -4.0),
+            Vec2::new(4.0, 4.0),
+            Vec2::new(-4.0, 4.0),
+        ]);
+        let cooled = MergeCooldown {
+            lineage: 7,
+            age: cfg.merge_cooldown_secs,
+        };
+        let a = app
+            .world_mut()
+            .spawn((Transform::default(), square.clone(), cooled))
+            .id();
+        let b = app
+            .world_mut()
+            .spawn((
+                Transform::from_translation(Vec3::new(10.0, 0.0, 0.0)),
+                square,
+                cooled,
+            ))
+            .id();
+
+        // Cooled down but still touching: both stay tagged.
+        app.update();
+        assert!(app.world().get::<MergeCooldown>(a).is_some());
+        assert!(app.world().get::<MergeCooldown>(b).is_some());
+
+        // Drifted clear of each other: both are released.
+        app.world_mut()
+            .get_mut::<Transform>(b)
+            .unwrap()
+            .translation
+            .x = 12.0 + cfg.merge_rearm_gap + 1.0;
+        app.update();
+        assert!(app.world().get::<MergeCooldown>(a).is_none());
+        assert!(app.world().get::<MergeCooldown>(b).is_none());
+    }
+
+    #[test]
+    fn merge_cooldown_expires_for_siblings_that_never_separate() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(crate::config::PhysicsConfig::default())
+            .add_systems(Update, merge_cooldown_system);
+        let max = app
+            .world()
+            .resource::<crate::config::PhysicsConfig>()
+            .merge_cooldown_max_secs;
+
+        let triangle = Vertices(vec![
+            Vec2::new(0.0, 4.0),
+            Vec2::new(-4.0, -3.0),
+            Vec2::new(4.0, -3.0),
+        ]);
+        let old = MergeCooldown {
+            lineage: 3,
+            age: max,
+        };
+        let a = app
+            .world_mut()
+            .spawn((Transform::default(), triangle.clone(), old))
+            .id();
+        let b = app
+            .world_mut()
+            .spawn((Transform::default(), triangle, old))
+            .id();
+
+        app.update();
+        assert!(app.world().get::<MergeCooldown>(a).is_none());
+        assert!(app.world().get::<MergeCooldown>(b).is_none());
+    }
+
+    // ── parallel gravity ──────────────────────────────────────────────────────
+
+    #[test]
+    fn parallel_gravity_matches_serial_reference() {
+        let mut bodies = Vec::new();
+        let mut grid = SpatialGrid::default();
+        for i in 0..200_u32 {
+            let entity = Entity::from_raw_u32(i + 1).unwrap();
+            let pos = Vec2::new(
+                (i % 20) as f32 * 37.0,
+                (i / 20) as f32 * 41.0 + (i % 3) as f32,
+            );
+            bodies.push((entity, pos, 1.0 + (i % 5) as f32));
+            grid.insert(entity, pos);
+        }
+        grid.build();
+        let index: std::collections::HashMap<Entity, usize> = bodies
+            .iter()
+            .enumerate()
+            .map(|(idx, body)| (body.0, idx))
+            .collect();
+        // Also with a governor cutoff epsilon, which both paths must apply
+        // the same way.
+        for force_epsilon_sq in [0.0, 0.01] {
+            let params = GravityParams {
+                gravity_const: 10.0,
+                min_dist_sq: 25.0,
+                max_dist: 300.0,
+                max_dist_sq: 90_000.0,
+                force_epsilon_sq,
+            };
+
+            let mut serial = vec![Vec2::ZERO; bodies.len()];
+            accumulate_gravity_serial(&bodies, &index, &grid, params, &mut serial, &mut Vec::new());
+            let mut parallel = vec![Vec2::ZERO; bodies.len()];
+            accumulate_gravity_parallel(
+                &bodies,
+                &index,
+                &grid,
+                params,
+                &mut parallel,
+                &mut Vec::new(),
+            );
+
+            for (s, p) in serial.iter().zip(&parallel) {
+                assert!(
+                    (*s - *p).length() <= 1e-4 * s.length().max(1.0),
+                    "serial {s:?} vs parallel {p:?}"
+                );
+            }
+            assert!(serial.iter().any(|f| f.length() > 0.0));
+        }
+    }
+
     // ── gravity_force_between ─────────────────────────────────────────────────
 
     #[test]
@@ -0,0 +1,288 @@
+//! Periodic cluster labeling and per-cluster statistics.
+//!
+//! Every `cluster_label_interval_secs` [`cluster_labeling_system`] links
+//! asteroids closer than `cluster_link_distance` (via the KD-tree in
+//! [`SpatialGrid`]) and groups them with a [`UnionFind`].  Groups with at least
+//! `cluster_min_members` bodies become [`ClusterSummary`] entries in
+//! [`ClusterStats`].
+//!
+//! Labels persist across passes: a new group inherits the label that
+//! contributed most of its mass last pass.  When a cluster splits, the
+//! heaviest fragment keeps the label and the rest get fresh ones.  Growth rate
+//! is the change in a label's mass divided by the time between passes.
+//! Planets are excluded; they would dominate every cluster around them.
+
+use crate::asteroid::{compute_convex_hull_from_points, Asteroid, AsteroidSize, Planet};
+use crate::config::PhysicsConfig;
+use crate::spatial_partition::SpatialGrid;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Disjoint-set forest with path halving and union by size.
+#[derive(Debug, Default, Clone)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Reset to `n` singleton sets, reusing the allocation.
+    pub fn reset(&mut self, n: usize) {
+        self.parent.clear();
+        self.parent.extend(0..n);
+        self.size.clear();
+        self.size.resize(n, 1);
+    }
+
+    pub fn find(&mut self, mut i: usize) -> usize {
+        while self.parent[i] != i {
+            self.parent[i] = self.parent[self.parent[i]];
+            i = self.parent[i];
+        }
+        i
+    }
+
+    pub fn union(&mut self, a: usize, b: usize) {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+    }
+}
+
+/// One labeled cluster from the most recent pass.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClusterSummary {
+    pub label: u32,
+    pub members: u32,
+    /// Sum of member `AsteroidSize`s.
+    pub mass: u32,
+    /// Mass-weighted centre.
+    pub centroid: Vec2,
+    /// Mass change per second since the previous pass (`0.0` for new labels).
+    pub growth_per_sec: f32,
+    /// Convex hull of member centres (empty when degenerate).
+    pub hull: Vec<Vec2>,
+}
+
+/// Cluster labels and summaries from the most recent labeling pass.
+#[derive(Resource, Debug, Default)]
+pub struct ClusterStats {
+    /// Clusters sorted by descending mass.
+    pub clusters: Vec<ClusterSummary>,
+    /// Cluster label of every asteroid in a cluster.
+    pub labels: HashMap<Entity, u32>,
+    next_label: u32,
+    since_pass_secs: f32,
+}
+
+impl ClusterStats {
+    /// Move every summary by `delta` (world-origin recentering).
+    pub fn shift(&mut self, delta: Vec2) {
+        for cluster in &mut self.clusters {
+            cluster.centroid += delta;
+            for point in &mut cluster.hull {
+                *point += delta;
+            }
+        }
+    }
+
+    pub fn label_of(&self, entity: Entity) -> Option<u32> {
+        self.labels.get(&entity).copied()
+    }
+
+    /// Turn this pass's member groups into labeled summaries.
+    ///
+    /// `groups` holds `(entity, position, mass)` per member.  `elapsed_secs` is
+    /// the time since the previous pass.
+    pub fn apply_pass(&mut self, mut groups: Vec<Vec<(Entity, Vec2, u32)>>, elapsed_secs: f32) {
+        let group_mass = |group: &[(Entity, Vec2, u32)]| group.iter().map(|m| m.2).sum::<u32>();
+        groups.sort_by_key(|group| std::cmp::Reverse(group_mass(group)));
+
+        let previous_mass: HashMap<u32, u32> =
+            self.clusters.iter().map(|c| (c.label, c.mass)).collect();
+        let previous_labels = std::mem::take(&mut self.labels);
+        let mut clusters = Vec::with_capacity(groups.len());
+        let mut votes: HashMap<u32, u32> = HashMap::new();
+
+        for group in groups {
+            votes.clear();
+            for &(entity, _, mass) in &group {
+                if let Some(label) = previous_labels.get(&entity) {
+                    *votes.entry(*label).or_default() += mass;
+                }
+            }
+            // Heaviest groups pick first, so a split's largest fragment keeps
+            // the label.
+            let inherited = votes
+                .iter()
+                .filter(|(label, _)| !clusters.iter().any(|c: &ClusterSummary| c.label == **label))
+                .max_by_key(|(label, mass)| (**mass, std::cmp::Reverse(**label)))
+                .map(|(label, _)| *label);
+            let label = inherited.unwrap_or_else(|| {
+                self.next_label += 1;
+                self.next_label
+            });
+
+            let mass = group_mass(&group);
+            let weighted: Vec2 = group.iter().map(|m| m.1 * m.2 as f32).sum();
+            let centroid = if mass > 0 {
+                weighted / mass as f32
+            } else {
+                Vec2::ZERO
+            };
+            let growth_per_sec = match previous_mass.get(&label) {
+                Some(&before) if inherited.is_some() && elapsed_secs > 0.0 => {
+                    (mass as f32 - before as f32) / elapsed_secs
+                }
+                _ => 0.0,
+            };
+            let points: Vec<Vec2> = group.iter().map(|m| m.1).collect();
+            let hull = compute_convex_hull_from_points(&points).unwrap_or_default();
+
+            for &(entity, _, _) in &group {
+                self.labels.insert(entity, label);
+            }
+            clusters.push(ClusterSummary {
+                label,
+                members: group.len() as u32,
+                mass,
+                centroid,
+                growth_per_sec,
+                hull,
+            });
+        }
+        self.clusters = clusters;
+    }
+}
+
+/// Reusable buffers for [`cluster_labeling_system`].
+#[derive(Default)]
+pub struct ClusterScratch {
+    points: Vec<(Entity, Vec2, u32)>,
+    index: HashMap<Entity, usize>,
+    union_find: UnionFind,
+    neighbors: Vec<Entity>,
+}
+
+/// Relabel clusters every `cluster_label_interval_secs` of simulation time.
+#[allow(clippy::type_complexity)]
+pub fn cluster_labeling_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    grid: Res<SpatialGrid>,
+    q_asteroids: Query<(Entity, &Transform, &AsteroidSize), (With<Asteroid>, Without<Planet>)>,
+    mut stats: ResMut<ClusterStats>,
+    mut scratch: Local<ClusterScratch>,
+) {
+    stats.since_pass_secs += time.delta_secs();
+    if stats.since_pass_secs < config.cluster_label_interval_secs {
+        return;
+    }
+    let elapsed_secs = std::mem::take(&mut stats.since_pass_secs);
+
+    let scratch = &mut *scratch;
+    scratch.points.clear();
+    scratch.index.clear();
+    for (entity, transform, size) in q_asteroids.iter() {
+        scratch.index.insert(entity, scratch.points.len());
+        scratch
+            .points
+            .push((entity, transform.translation.truncate(), size.0));
+    }
+
+    // The grid may still hold bodies merged away since the last fixed tick;
+    // only indexed entities are linked.
+    scratch.union_find.reset(scratch.points.len());
+    for i in 0..scratch.points.len() {
+        let (entity, position, _) = scratch.points[i];
+        grid.query_neighbors_into(
+            entity,
+            position,
+            config.cluster_link_distance,
+            &mut scratch.neighbors,
+        );
+        for neighbor in &scratch.neighbors {
+            if let Some(&j) = scratch.index.get(neighbor) {
+                scratch.union_find.union(i, j);
+            }
+        }
+    }
+
+    let mut by_root: HashMap<usize, Vec<(Entity, Vec2, u32)>> = HashMap::new();
+    for i in 0..scratch.points.len() {
+        let root = scratch.union_find.find(i);
+        by_root.entry(root).or_default().push(scratch.points[i]);
+    }
+    let min_members = config.cluster_min_members.max(2) as usize;
+    let groups = by_root
+        .into_values()
+        .filter(|group| group.len() >= min_members)
+        .collect();
+    stats.apply_pass(groups, elapsed_secs);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(i: u32) -> Entity {
+        Entity::from_raw_u32(i).unwrap()
+    }
+
+    fn group(ids: &[u32], mass: u32) -> Vec<(Entity, Vec2, u32)> {
+        ids.iter()
+            .map(|&i| (entity(i), Vec2::new(i as f32, (i * i) as f32), mass))
+            .collect()
+    }
+
+    #[test]
+    fn union_find_groups_linked_indices() {
+        let mut uf = UnionFind::default();
+        uf.reset(6);
+        uf.union(0, 1);
+        uf.union(1, 2);
+        uf.union(4, 5);
+
+        assert_eq!(uf.find(0), uf.find(2));
+        assert_eq!(uf.find(4), uf.find(5));
+        assert_ne!(uf.find(0), uf.find(4));
+        assert_eq!(uf.find(3), 3);
+    }
+
+    #[test]
+    fn labels_persist_and_growth_is_tracked() {
+        let mut stats = ClusterStats::default();
+        stats.apply_pass(vec![group(&[1, 2, 3], 10), group(&[7, 8, 9], 1)], 2.0);
+        let heavy = stats.label_of(entity(1)).unwrap();
+        let light = stats.label_of(entity(7)).unwrap();
+        assert_ne!(heavy, light);
+        assert_eq!(stats.clusters[0].label, heavy);
+        assert_eq!(stats.clusters[0].growth_per_sec, 0.0);
+
+        // Heavy cluster picks up a new body: same label, 10 mass over 2 s.
+        stats.apply_pass(vec![group(&[1, 2, 3, 4], 10), group(&[7, 8, 9], 1)], 2.0);
+        assert_eq!(stats.label_of(entity(4)), Some(heavy));
+        assert_eq!(stats.label_of(entity(9)), Some(light));
+        assert_eq!(stats.clusters[0].growth_per_sec, 5.0);
+        assert_eq!(stats.clusters[1].growth_per_sec, 0.0);
+    }
+
+    #[test]
+    fn split_keeps_label_on_heaviest_fragment() {
+        let mut stats = ClusterStats::default();
+        stats.apply_pass(vec![group(&[1, 2, 3, 4, 5, 6], 5)], 1.0);
+        let original = stats.label_of(entity(1)).unwrap();
+
+        stats.apply_pass(vec![group(&[1, 2], 5), group(&[3, 4, 5, 6], 5)], 1.0);
+        assert_eq!(stats.label_of(entity(3)), Some(original));
+        assert_ne!(stats.label_of(entity(1)), Some(original));
+        assert_eq!(stats.clusters[0].growth_per_sec, -10.0);
+        assert_eq!(stats.clusters.len(), 2);
+    }
+}
@@ -0,0 +1,283 @@
+//! Energy-dependent collision response between asteroids.
+//!
+//! Asteroid colliders are perfectly inelastic in Rapier
+//! ([`asteroid_restitution`]), so every contact first stops the pair dead
+//! along the contact normal.  [`collision_response_system`] then looks at
+//! each touching pair's closing speed before the step (recorded by
+//! [`record_pre_step_velocities_system`]) and its impact energy
+//! `½·μ·v²`, with `μ` the reduced mass in `AsteroidSize` units:
+//!
+//! - at or below `restitution_low_energy` the contact stays inelastic, so
+//!   slow bodies stick and can merge;
+//! - between that and `restitution_high_energy` the restitution rises along
+//!   a smoothstep curve to `restitution_max`, and the pair is pushed apart
+//!   to that fraction of its closing speed ([`bounce_velocities`]);
+//! - at or above `impact_fragment_energy` each body of mass 2 or more also
+//!   sheds a fragment of about `impact_fragment_fraction` of its mass
+//!   ([`impact_shed_mass`]), flung off sideways from the impact.
+//!
+//! Planets are left to Rapier: they are fixed, so there is nothing to
+//! bounce.
+//!
+//! [`asteroid_restitution`]: crate::asteroid::asteroid_restitution
+
+use std::collections::HashMap;
+
+use crate::asteroid::{
+    collider_for_vertices, fragment_vertices_for_mass, polygon_area, rescale_vertices_to_area,
+    spawn_asteroid_with_vertices, Asteroid, AsteroidSize, BaseVertices, MergeCooldown, Planet,
+    Vertices,
+};
+use crate::config::PhysicsConfig;
+use crate::mining::AsteroidMaterial;
+use crate::rng::{visual_rng, GameplayRng};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+use rand::Rng;
+
+use super::SimulationStats;
+
+/// Asteroid velocities as they were before this tick's physics step.
+#[derive(Resource, Debug, Default)]
+pub struct PreStepVelocities(pub HashMap<Entity, Vec2>);
+
+/// Kinetic energy of the relative motion of two bodies of mass `m1` and
+/// `m2` closing at `closing_speed`.
+pub fn impact_energy(m1: f32, m2: f32, closing_speed: f32) -> f32 {
+    if m1 + m2 <= 0.0 {
+        return 0.0;
+    }
+    0.5 * (m1 * m2 / (m1 + m2)) * closing_speed * closing_speed
+}
+
+/// Restitution of an impact of `energy`: zero up to
+/// `restitution_low_energy`, easing up to `restitution_max` at
+/// `restitution_high_energy`.
+pub fn restitution_for_energy(energy: f32, config: &PhysicsConfig) -> f32 {
+    let (low, high) = (
+        config.restitution_low_energy,
+        config.restitution_high_energy,
+    );
+    if energy <= low {
+        return 0.0;
+    }
+    if energy >= high || high <= low {
+        return config.restitution_max;
+    }
+    let t = (energy - low) / (high - low);
+    config.restitution_max * t * t * (3.0 - 2.0 * t)
+}
+
+/// Velocities after pushing two bodies apart along `normal` (from body 1
+/// to body 2) so they separate at `restitution × closing_speed`.
+///
+/// Momentum is conserved.  A pair already separating at least that fast is
+/// left alone.
+pub fn bounce_velocities(
+    v1: Vec2,
+    v2: Vec2,
+    m1: f32,
+    m2: f32,
+    normal: Vec2,
+    closing_speed: f32,
+    restitution: f32,
+) -> (Vec2, Vec2) {
+    let target = -restitution * closing_speed;
+    let current = (v1 - v2).dot(normal);
+    if current <= target || m1 + m2 <= 0.0 {
+        return (v1, v2);
+    }
+    let change = target - current;
+    (
+        v1 + normal * change * m2 / (m1 + m2),
+        v2 - normal * change * m1 / (m1 + m2),
+    )
+}
+
+/// Mass a body of `mass` sheds in a fragmenting impact; zero for bodies
+/// too small to lose anything.
+pub fn impact_shed_mass(mass: u32, config: &PhysicsConfig) -> u32 {
+    if mass < 2 {
+        return 0;
+    }
+    ((mass as f32 * config.impact_fragment_fraction).round() as u32).clamp(1, mass - 1)
+}
+
+/// Remember every asteroid's velocity before Rapier steps.
+#[allow(clippy::type_complexity)]
+pub fn record_pre_step_velocities_system(
+    mut pre_step: ResMut<PreStepVelocities>,
+    q_asteroids: Query<(Entity, &Velocity), (With<Asteroid>, Without<Planet>)>,
+) {
+    pre_step.0.clear();
+    pre_step
+        .0
+        .extend(q_asteroids.iter().map(|(entity, v)| (entity, v.linvel)));
+}
+
+/// Bounce and fragment touching asteroids according to their impact energy.
+#[allow(clippy::type_complexity)]
+pub fn collision_response_system(
+    mut commands: Commands,
+    rapier_context: ReadRapierContext,
+    config: Res<PhysicsConfig>,
+    pre_step: Res<PreStepVelocities>,
+    mut stats: ResMut<SimulationStats>,
+    mut rng: ResMut<GameplayRng>,
+    mut q_bodies: Query<
+        (
+            &Transform,
+            &mut Velocity,
+            &mut AsteroidSize,
+            &mut Vertices,
+            &mut BaseVertices,
+            Option<&AsteroidMaterial>,
+        ),
+        (With<Asteroid>, Without<Planet>),
+    >,
+) {
+    let Ok(rapier) = rapier_context.single() else {
+        return;
+    };
+    let mut touching: Vec<(Entity, Entity, Vec2, Vec2)> = Vec::new();
+    for contact_pair in rapier
+        .simulation
+        .contact_pairs(rapier.colliders, rapier.rigidbody_set)
+    {
+        if !contact_pair.has_any_active_contact() {
+            continue;
+        }
+        let (Some(e1), Some(e2)) = (contact_pair.collider1(), contact_pair.collider2()) else {
+            continue;
+        };
+        if let (Some(&u1), Some(&u2)) = (pre_step.0.get(&e1), pre_step.0.get(&e2)) {
+            touching.push((e1, e2, u1, u2));
+        }
+    }
+
+    for (e1, e2, u1, u2) in touching {
+        let Ok([mut body1, mut body2]) = q_bodies.get_many_mut([e1, e2]) else {
+            continue;
+        };
+        let normal = (body2.0.translation - body1.0.translation)
+            .truncate()
+            .normalize_or_zero();
+        let closing_speed = (u1 - u2).dot(normal);
+        if normal == Vec2::ZERO || closing_speed <= 0.0 {
+            continue;
+        }
+        let (m1, m2) = (body1.2 .0 as f32, body2.2 .0 as f32);
+        let energy = impact_energy(m1, m2, closing_speed);
+        let restitution = restitution_for_energy(energy, &config);
+        if restitution > 0.0 {
+            let (v1, v2) = bounce_velocities(
+                body1.1.linvel,
+                body2.1.linvel,
+                m1,
+                m2,
+                normal,
+                closing_speed,
+                restitution,
+            );
+            body1.1.linvel = v1;
+            body2.1.linvel = v2;
+        }
+        if energy < config.impact_fragment_energy {
+            continue;
+        }
+
+        // Fragments fly off perpendicular to the impact, in opposite
+        // directions for the two bodies, so they clear both hulls.
+        let side = normal.perp();
+        for (entity, body, sign) in [(e1, body1, 1.0), (e2, body2, -1.0)] {
+            let (transform, velocity, mut size, mut vertices, mut base_vertices, material) = body;
+            let shed = impact_shed_mass(size.0, &config);
+            if shed == 0 {
+                continue;
+            }
+            let kept = size.0 - shed;
+            let scale = kept as f32 / size.0 as f32;
+            let base =
+                rescale_vertices_to_area(&base_vertices.0, polygon_area(&base_vertices.0) * scale);
+            vertices.0 = rescale_vertices_to_area(&vertices.0, polygon_area(&vertices.0) * scale);
+            commands
+                .entity(entity)
+                .try_insert(collider_for_vertices(&base));
+            base_vertices.0 = base;
+            size.0 = kept;
+            stats.split_total += 1;
+
+            let hull = fragment_vertices_for_mass(shed, &mut *rng, &config);
+            let reach = |points: &[Vec2]| points.iter().map(|v| v.length()).fold(0.0, f32::max);
+            let pos = transform.translation.truncate()
+                + side * sign * (reach(&base_vertices.0) + reach(&hull) + 1.0);
+            let grey = 0.4 + visual_rng().gen::<f32>() * 0.3;
+            let fragment = spawn_asteroid_with_vertices(
+                &mut commands,
+                pos,
+                &hull,
+                Color::srgb(grey, grey, grey),
+                shed,
+            );
+            let lineage = MergeCooldown::from_split(entity);
+            commands.entity(fragment).insert((
+                Velocity::linear(velocity.linvel + side * sign * closing_speed * 0.5),
+                material.copied().unwrap_or_default(),
+                lineage,
+            ));
+            commands.entity(entity).try_insert(lineage);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn restitution_is_zero_for_gentle_impacts_and_capped_for_hard_ones() {
+        let config = PhysicsConfig::default();
+        let (low, high) = (
+            config.restitution_low_energy,
+            config.restitution_high_energy,
+        );
+        assert_eq!(restitution_for_energy(0.0, &config), 0.0);
+        assert_eq!(restitution_for_energy(low, &config), 0.0);
+        let mid = restitution_for_energy((low + high) / 2.0, &config);
+        assert!((mid - config.restitution_max / 2.0).abs() < 1e-4);
+        assert!(restitution_for_energy(low + (high - low) * 0.25, &config) < mid);
+        assert_eq!(
+            restitution_for_energy(high, &config),
+            config.restitution_max
+        );
+        assert_eq!(
+            restitution_for_energy(high * 10.0, &config),
+            config.restitution_max
+        );
+        assert_eq!(impact_energy(2.0, 2.0, 10.0), 50.0);
+    }
+
+    #[test]
+    fn bounce_conserves_momentum_and_sets_the_separation_speed() {
+        // An inelastic solve left both bodies moving together at 1 u/s.
+        let (v1, v2) = bounce_velocities(Vec2::X, Vec2::X, 3.0, 1.0, Vec2::X, 8.0, 0.5);
+        assert!(((v1 * 3.0 + v2) - Vec2::X * 4.0).length() < 1e-4);
+        assert!(((v2 - v1).dot(Vec2::X) - 4.0).abs() < 1e-4);
+        // The lighter body takes most of the push.
+        assert!((v2 - Vec2::X).length() > (v1 - Vec2::X).length());
+
+        // Already separating faster than the bounce: untouched.
+        let apart = bounce_velocities(-Vec2::X * 5.0, Vec2::X * 5.0, 1.0, 1.0, Vec2::X, 8.0, 0.5);
+        assert_eq!(apart, (-Vec2::X * 5.0, Vec2::X * 5.0));
+    }
+
+    #[test]
+    fn fragmenting_impacts_shed_part_of_each_body() {
+        let config = PhysicsConfig::default();
+        assert_eq!(impact_shed_mass(1, &config), 0);
+        assert_eq!(impact_shed_mass(2, &config), 1);
+        let big = impact_shed_mass(40, &config);
+        assert_eq!(big, (40.0 * config.impact_fragment_fraction).round() as u32);
+        assert!(big < 40);
+    }
+}
@@ -0,0 +1,228 @@
+//! Coarse asteroid density map shared by spawners and the field generator.
+//!
+//! [`density_field_system`] sums asteroid mass per square cell of
+//! `density_cell_size` every `density_refresh_secs`, reading positions from
+//! the [`SpatialGrid`] rather than scanning the world again.  Cells without
+//! mass are not stored, so the map follows the field wherever it drifts.
+//!
+//! Readers:
+//!
+//! - the enemy spawner compares `enemy_spawn_density_samples` clear ring
+//!   points and spawns at the emptiest, so waves arrive out of open space;
+//! - asteroids destroyed in a *rich vein* (a cell holding at least
+//!   `ore_vein_mass`) drop `ore_vein_drop_multiplier` times the ore
+//!   ([`DensityField::ore_drop_count`]);
+//! - the FRONTIER generator thins a new chunk by the live mass already in it,
+//!   down to nothing at `procgen_crowd_mass`, so clusters that drift into
+//!   unexplored space are not doubled up.
+//!
+//! The map resets when a session starts and is re-keyed (or, if the shift
+//! does not fall on cell boundaries, cleared until the next refresh) when the
+//! world origin moves.
+
+use crate::asteroid::AsteroidSize;
+use crate::config::PhysicsConfig;
+use crate::spatial_partition::{sector_of, sectors_overlapping, SpatialGrid};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Asteroid mass per map cell.
+#[derive(Resource, Debug, Clone, Default)]
+pub struct DensityField {
+    cell_size: f32,
+    cells: HashMap<IVec2, f32>,
+    /// Seconds since the last refresh; `None` forces one on the next tick.
+    since_refresh: Option<f32>,
+}
+
+impl DensityField {
+    /// Side of one cell in world units.
+    pub fn cell_size(&self) -> f32 {
+        self.cell_size.max(1.0)
+    }
+
+    /// Replace the map with `bodies` as `(position, mass)` pairs.
+    pub fn refresh(&mut self, cell_size: f32, bodies: impl IntoIterator<Item = (Vec2, f32)>) {
+        self.cell_size = cell_size.max(1.0);
+        self.cells.clear();
+        for (pos, mass) in bodies {
+            *self
+                .cells
+                .entry(sector_of(pos, self.cell_size))
+                .or_default() += mass;
+        }
+        self.since_refresh = Some(0.0);
+    }
+
+    /// Drop everything and refresh on the next tick.
+    pub fn clear(&mut self) {
+        self.cells.clear();
+        self.since_refresh = None;
+    }
+
+    /// Move the map with a world shift of `delta`.
+    pub fn shift(&mut self, delta: Vec2) {
+        let cells = delta / self.cell_size();
+        if cells.round().distance(cells) > 1e-3 {
+            self.clear();
+            return;
+        }
+        let offset = cells.round().as_ivec2();
+        self.cells = self
+            .cells
+            .drain()
+            .map(|(cell, mass)| (cell + offset, mass))
+            .collect();
+    }
+
+    /// Mass in the cell containing `pos`.
+    pub fn mass_at(&self, pos: Vec2) -> f32 {
+        self.cells
+            .get(&sector_of(pos, self.cell_size()))
+            .copied()
+            .unwrap_or(0.0)
+    }
+
+    /// Mass in every cell a circle of `radius` around `pos` touches.
+    pub fn mass_near(&self, pos: Vec2, radius: f32) -> f32 {
+        sectors_overlapping(pos, radius, self.cell_size())
+            .filter_map(|cell| self.cells.get(&cell))
+            .sum()
+    }
+
+    /// Mass in the cells whose centres lie inside the rectangle.
+    pub fn mass_in_rect(&self, min: Vec2, max: Vec2) -> f32 {
+        let half = Vec2::splat(self.cell_size() * 0.5);
+        let first = sector_of(min + half, self.cell_size());
+        let last = sector_of(max - half, self.cell_size());
+        (first.y..=last.y)
+            .flat_map(|y| (first.x..=last.x).map(move |x| IVec2::new(x, y)))
+            .filter_map(|cell| self.cells.get(&cell))
+            .sum()
+    }
+
+    /// Whether `pos` lies in a rich vein.
+    pub fn is_vein(&self, pos: Vec2, config: &PhysicsConfig) -> bool {
+        config.ore_vein_mass > 0.0 && self.mass_at(pos) >= config.ore_vein_mass
+    }
+
+    /// Ore pickups an asteroid of `mass` destroyed at `pos` drops: one per
+    /// mass unit, scaled up inside a vein.
+    pub fn ore_drop_count(&self, mass: u32, pos: Vec2, config: &PhysicsConfig) -> u32 {
+        let base = mass.max(1);
+        if self.is_vein(pos, config) {
+            (base as f32 * config.ore_vein_drop_multiplier.max(1.0)).round() as u32
+        } else {
+            base
+        }
+    }
+
+    /// Fraction of the usual spawn chance left for a generated chunk that
+    /// already holds `live_mass`.
+    pub fn crowding_factor(live_mass: f32, config: &PhysicsConfig) -> f32 {
+        if config.procgen_crowd_mass <= 0.0 {
+            return 1.0;
+        }
+        (1.0 - live_mass / config.procgen_crowd_mass).clamp(0.0, 1.0)
+    }
+}
+
+/// Rebuild the map from the spatial index every `density_refresh_secs`.
+pub fn density_field_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    grid: Res<SpatialGrid>,
+    q_size: Query<&AsteroidSize>,
+    mut field: ResMut<DensityField>,
+) {
+    if let Some(since) = field.since_refresh.as_mut() {
+        *since += time.delta_secs();
+        if *since < config.density_refresh_secs {
+            return;
+        }
+    }
+    field.refresh(
+        config.density_cell_size,
+        grid.iter()
+            .filter_map(|(entity, pos)| q_size.get(entity).ok().map(|size| (pos, size.0 as f32))),
+    );
+}
+
+/// Start a fresh map.
+pub fn reset_density_field_system(mut field: ResMut<DensityField>) {
+    field.clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mass_is_summed_per_cell_and_follows_shifts() {
+        let mut field = DensityField::default();
+        field.refresh(
+            100.0,
+            [
+                (Vec2::new(10.0, 10.0), 4.0),
+                (Vec2::new(90.0, 40.0), 6.0),
+                (Vec2::new(150.0, 10.0), 3.0),
+                (Vec2::new(-20.0, -20.0), 1.0),
+            ],
+        );
+        assert_eq!(field.mass_at(Vec2::new(50.0, 50.0)), 10.0);
+        assert_eq!(field.mass_at(Vec2::new(120.0, 0.0)), 3.0);
+        assert_eq!(field.mass_at(Vec2::new(500.0, 500.0)), 0.0);
+        assert_eq!(field.mass_near(Vec2::new(95.0, 50.0), 10.0), 13.0);
+        assert_eq!(
+            field.mass_in_rect(Vec2::ZERO, Vec2::new(200.0, 100.0)),
+            13.0
+        );
+
+        // A shift by whole cells keeps the map; anything else clears it.
+        field.shift(Vec2::new(-200.0, 0.0));
+        assert_eq!(field.mass_at(Vec2::new(-150.0, 50.0)), 10.0);
+        field.shift(Vec2::new(30.0, 0.0));
+        assert_eq!(field.mass_near(Vec2::ZERO, 1000.0), 0.0);
+    }
+
+    #[test]
+    fn veins_drop_more_ore_and_crowds_thin_generation() {
+        let config = PhysicsConfig::default();
+        let mut field = DensityField::default();
+        field.refresh(
+            config.density_cell_size,
+            [(Vec2::new(5.0, 5.0), config.ore_vein_mass)],
+        );
+        assert_eq!(field.ore_drop_count(4, Vec2::new(2000.0, 5.0), &config), 4);
+        assert!(field.is_vein(Vec2::new(5.0, 5.0), &config));
+        assert_eq!(
+            field.ore_drop_count(4, Vec2::new(5.0, 5.0), &config),
+            (4.0 * config.ore_vein_drop_multiplier).round() as u32
+        );
+
+        assert_eq!(DensityField::crowding_factor(0.0, &config), 1.0);
+        assert_eq!(
+            DensityField::crowding_factor(config.procgen_crowd_mass, &config),
+            0.0
+        );
+    }
+
+    #[test]
+    fn the_system_reads_masses_through_the_spatial_index() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(PhysicsConfig::default())
+            .init_resource::<SpatialGrid>()
+            .init_resource::<DensityField>()
+            .add_systems(Update, density_field_system);
+        let a = app.world_mut().spawn(AsteroidSize(7)).id();
+        let b = app.world_mut().spawn(AsteroidSize(5)).id();
+        app.world_mut()
+            .resource_mut::<SpatialGrid>()
+            .rebuild(vec![(a, Vec2::new(10.0, 10.0)), (b, Vec2::new(20.0, 5.0))]);
+
+        app.update();
+        let field = app.world().resource::<DensityField>();
+        assert_eq!(field.mass_at(Vec2::ZERO), 12.0);
+    }
+}
@@ -0,0 +1,291 @@
+//! Fixed physics tick and render interpolation.
+//!
+//! Gravity, boundary springs, merging, and the Rapier step all run in the
+//! fixed schedules at `physics_tick_hz`, so a second of simulation is the same
+//! sequence of ticks at 30, 60, or 144 FPS.  Rendering happens every frame,
+//! so each moving body's drawn transform is blended between its last two
+//! tick poses:
+//!
+//! ```text
+//! FixedFirst   restore_physics_poses_system     Transform ← last tick pose
+//! FixedUpdate  gravity / boundary / tractor     (forces)
+//! FixedPostUpdate  Rapier step, writeback, merging
+//! FixedLast    record_physics_poses_system      previous ← current ← Transform
+//! PostUpdate   interpolate_physics_poses_system Transform ← lerp(previous, current, α)
+//! ```
+//!
+//! `α` is `Time<Fixed>::overstep_fraction()`.  Restoring the exact tick pose
+//! (and its `GlobalTransform`) before the next step keeps Rapier from reading
+//! the blended pose as a user teleport.  A `Transform` edited by gameplay code
+//! between ticks (respawn, rotation sync) no longer matches what was rendered,
+//! so that component is kept and the history snaps to it instead.
+
+use super::time_control::TimeControl;
+use crate::config::PhysicsConfig;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+/// Accepted tick-rate range; keeps hand-edited configs from stalling or
+/// flooding the fixed loop.
+const PHYSICS_TICK_HZ_RANGE: (f32, f32) = (10.0, 480.0);
+
+/// Last two physics-tick poses of a moving rigid body, plus the blended pose
+/// written for rendering this frame.
+#[derive(Component, Debug, Clone, Copy)]
+pub struct PhysicsInterpolation {
+    pub previous: Transform,
+    pub current: Transform,
+    /// Pose written by the interpolation pass; `None` once restored.
+    rendered: Option<Transform>,
+}
+
+impl PhysicsInterpolation {
+    fn at(transform: Transform) -> Self {
+        Self {
+            previous: transform,
+            current: transform,
+            rendered: None,
+        }
+    }
+
+    /// Blend between the last two tick poses.
+    pub fn blend(&self, alpha: f32) -> Transform {
+        Transform {
+            translation: self
+                .previous
+                .translation
+                .lerp(self.current.translation, alpha),
+            rotation: self.previous.rotation.slerp(self.current.rotation, alpha),
+            scale: self.current.scale,
+        }
+    }
+
+    /// Move the whole history by `delta` (world-origin recentering).
+    pub fn shift(&mut self, delta: Vec2) {
+        let delta = delta.extend(0.0);
+        self.previous.translation += delta;
+        self.current.translation += delta;
+        if let Some(rendered) = self.rendered.as_mut() {
+            rendered.translation += delta;
+        }
+    }
+
+    /// Undo the rendered blend on `transform`, keeping any component that
+    /// gameplay code changed since it was written.
+    fn restore(&mut self, transform: &mut Transform) {
+        let Some(rendered) = self.rendered.take() else {
+            return;
+        };
+        if transform.translation == rendered.translation {
+            transform.translation = self.current.translation;
+        } else {
+            self.previous.translation = transform.translation;
+            self.current.translation = transform.translation;
+        }
+        if transform.rotation == rendered.rotation {
+            transform.rotation = self.current.rotation;
+        } else {
+            self.previous.rotation = transform.rotation;
+            self.current.rotation = transform.rotation;
+        }
+    }
+}
+
+/// Clamped physics tick rate from config.
+pub fn physics_tick_hz(config: &PhysicsConfig) -> f32 {
+    config
+        .physics_tick_hz
+        .clamp(PHYSICS_TICK_HZ_RANGE.0, PHYSICS_TICK_HZ_RANGE.1)
+}
+
+/// Point `Time<Fixed>` and Rapier's [`TimestepMode`] at the configured tick.
+///
+/// Runs once at startup and again whenever [`PhysicsConfig`] or the player's
+/// [`TimeControl`] scale changes, so the tick rate hot-reloads with the rest
+/// of `physics.toml`.  A time scale stretches each tick's `dt` (and adds
+/// substeps when speeding up) while `Time<Virtual>` runs at the same scale,
+/// so ticks still arrive at `physics_tick_hz` in real time.
+pub fn sync_physics_tick_rate_system(
+    config: Res<PhysicsConfig>,
+    time_control: Option<Res<TimeControl>>,
+    mut fixed_time: ResMut<Time<Fixed>>,
+    mut timestep: ResMut<TimestepMode>,
+) {
+    let hz = physics_tick_hz(&config);
+    let scale = time_control.map_or(1.0, |control| control.scale);
+    let dt = scale / hz;
+    let substeps = config.physics_substeps.max(1) * scale.ceil().max(1.0) as usize;
+
+    if (fixed_time.timestep().as_secs_f32() - dt).abs() > f32::EPSILON {
+        fixed_time.set_timestep_seconds(dt as f64);
+        info!(
+            "Physics tick rate set to {:.1} Hz (time scale {}x)",
+            hz, scale
+        );
+    }
+    let current = matches!(
+        *timestep,
+        TimestepMode::Fixed { dt: d, substeps: s } if d == dt && s == substeps
+    );
+    if !current {
+        *timestep = TimestepMode::Fixed { dt, substeps };
+    }
+}
+
+/// Give newly spawned moving bodies an interpolation history.
+///
+/// Fixed bodies (planets) never move and are skipped.
+#[allow(clippy::type_complexity)]
+pub fn attach_physics_interpolation_system(
+    mut commands: Commands,
+    query: Query<(Entity, &Transform, &RigidBody), Without<PhysicsInterpolation>>,
+) {
+    for (entity, transform, body) in query.iter() {
+        if *body == RigidBody::Fixed {
+            continue;
+        }
+        commands
+            .entity(entity)
+            .insert(PhysicsInterpolation::at(*transform));
+    }
+}
+
+/// Put every body back on its last tick pose before the next fixed step.
+pub fn restore_physics_poses_system(
+    mut query: Query<(
+        &mut Transform,
+        &mut GlobalTransform,
+        &mut PhysicsInterpolation,
+    )>,
+) {
+    for (mut transform, mut global, mut interpolation) in query.iter_mut() {
+        if interpolation.rendered.is_none() {
+            continue;
+        }
+        interpolation.restore(&mut transform);
+        // Rapier compares GlobalTransform with the pose it last wrote back;
+        // propagation only runs in PostUpdate, so sync it here as well.
+        *global = GlobalTransform::from(*transform);
+    }
+}
+
+/// Shift the tick history after Rapier's writeback.
+pub fn record_physics_poses_system(mut query: Query<(&Transform, &mut PhysicsInterpolation)>) {
+    for (transform, mut interpolation) in query.iter_mut() {
+        interpolation.previous = interpolation.current;
+        interpolation.current = *transform;
+    }
+}
+
+/// Write the blended pose for rendering.
+///
+/// Runs in `PostUpdate` before transform propagation.  With
+/// `physics_interpolation` off this only restores any pose left blended
+/// from before the toggle.
+pub fn interpolate_physics_poses_system(
+    config: Res<PhysicsConfig>,
+    fixed_time: Res<Time<Fixed>>,
+    mut query: Query<(&mut Transform, &mut PhysicsInterpolation)>,
+) {
+    let alpha = fixed_time.overstep_fraction();
+    for (mut transform, mut interpolation) in query.iter_mut() {
+        // Drop last frame's blend first so edits made this frame are kept.
+        interpolation.restore(&mut transform);
+        if !config.physics_interpolation {
+            continue;
+        }
+        let blended = interpolation.blend(alpha);
+        transform.translation = blended.translation;
+        transform.rotation = blended.rotation;
+        interpolation.rendered = Some(*transform);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at_x(x: f32) -> Transform {
+        Transform::from_xyz(x, 0.0, 0.0)
+    }
+
+    #[test]
+    fn blend_lerps_between_tick_poses() {
+        let interpolation = PhysicsInterpolation {
+            previous: at_x(0.0),
+            current: at_x(10.0),
+            rendered: None,
+        };
+        assert_eq!(interpolation.blend(0.0).translation.x, 0.0);
+        assert_eq!(interpolation.blend(0.25).translation.x, 2.5);
+        assert_eq!(interpolation.blend(1.0).translation.x, 10.0);
+    }
+
+    #[test]
+    fn restore_returns_to_tick_pose_unless_gameplay_moved_the_body() {
+        let mut interpolation = PhysicsInterpolation {
+            previous: at_x(0.0),
+            current: at_x(10.0),
+            rendered: None,
+        };
+
+        // Untouched blend → back to the tick pose.
+        let mut transform = interpolation.blend(0.5);
+        interpolation.rendered = Some(transform);
+        interpolation.restore(&mut transform);
+        assert_eq!(transform.translation.x, 10.0);
+
+        // Respawn-style teleport → kept, and history snaps to it.
+        let mut transform = interpolation.blend(0.5);
+        interpolation.rendered = Some(transform);
+        transform.translation.x = -50.0;
+        interpolation.restore(&mut transform);
+        assert_eq!(transform.translation.x, -50.0);
+        assert_eq!(interpolation.previous.translation.x, -50.0);
+        assert_eq!(interpolation.current.translation.x, -50.0);
+    }
+
+    #[test]
+    fn tick_rate_is_clamped_to_safe_range() {
+        let config = PhysicsConfig {
+            physics_tick_hz: 5_000.0,
+            ..Default::default()
+        };
+        assert_eq!(physics_tick_hz(&config), PHYSICS_TICK_HZ_RANGE.1);
+        assert_eq!(
+            physics_tick_hz(&PhysicsConfig::default()),
+            crate::constants::PHYSICS_TICK_HZ
+        );
+    }
+
+    #[test]
+    fn time_scale_stretches_each_tick_and_adds_substeps() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(PhysicsConfig::default());
+        app.insert_resource(TimestepMode::Fixed {
+            dt: 1.0 / 60.0,
+            substeps: 1,
+        });
+        app.insert_resource(TimeControl {
+            scale: 4.0,
+            ..Default::default()
+        });
+        app.add_systems(Update, sync_physics_tick_rate_system);
+        app.update();
+
+        let hz = crate::constants::PHYSICS_TICK_HZ;
+        let base_substeps = PhysicsConfig::default().physics_substeps;
+        let fixed = app
+            .world()
+            .resource::<Time<Fixed>>()
+            .timestep()
+            .as_secs_f32();
+        assert!((fixed - 4.0 / hz).abs() < 1e-6);
+        assert!(matches!(
+            *app.world().resource::<TimestepMode>(),
+            TimestepMode::Fixed { dt, substeps }
+                if (dt - 4.0 / hz).abs() < 1e-6 && substeps == base_substeps * 4
+        ));
+    }
+}
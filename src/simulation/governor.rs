@@ -0,0 +1,272 @@
+//! Frame-time budget governor with adaptive simulation quality.
+//!
+//! [`frame_governor_system`] smooths the real frame time and steps the
+//! [`FrameTimeGovernor`] between [`QualityTier`]s:
+//!
+//! | Tier      | Gravity neighbour radius  | Gravity cutoff ε            | Particle budget           | LOD distances           |
+//! |-----------|---------------------------|-----------------------------|---------------------------|-------------------------|
+//! | `Full`    | `max_gravity_dist`        | `0`                         | `particle_budget`         | as configured           |
+//! | `Reduced` | `× gravity_radius_step`   | `gravity_epsilon_step`      | `× particle_budget_step`  | `× lod_distance_step`   |
+//! | `Minimal` | `× gravity_radius_step²`  | `2 × gravity_epsilon_step`  | `× particle_budget_step²` | `× lod_distance_step²`  |
+//!
+//! (Step names abbreviate the `frame_governor_*` config keys.)
+//!
+//! A tier is dropped after `frame_governor_degrade_secs` over
+//! `frame_governor_target_ms` and restored after `frame_governor_restore_secs`
+//! below `frame_governor_headroom_fraction` of it.  Frames between the two
+//! thresholds reset both timers, so the tier holds steady near the budget.
+//!
+//! Gravity pairs whose force falls below the cutoff ε are skipped, and the
+//! LOD multiplier pulls the simplified/hidden mesh thresholds toward the
+//! camera.  The profiler overlay shows the current level and every scaled
+//! value.
+//!
+//! The governor never edits [`PhysicsConfig`]; consumers read the scaled
+//! values through the `FrameTimeGovernor::effective_*` methods, so
+//! hot-reloading `physics.toml` keeps working while degraded.
+
+use crate::config::PhysicsConfig;
+use bevy::prelude::*;
+
+/// Weight of the newest frame in the exponential moving average.
+const FRAME_TIME_SMOOTHING: f32 = 0.1;
+
+/// Simulation quality level chosen by the governor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+pub enum QualityTier {
+    #[default]
+    Full,
+    Reduced,
+    Minimal,
+}
+
+impl QualityTier {
+    /// Number of degradation steps applied at this tier.
+    pub fn level(self) -> i32 {
+        match self {
+            QualityTier::Full => 0,
+            QualityTier::Reduced => 1,
+            QualityTier::Minimal => 2,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            QualityTier::Full => "Full",
+            QualityTier::Reduced => "Reduced",
+            QualityTier::Minimal => "Minimal",
+        }
+    }
+
+    fn lower(self) -> Self {
+        match self {
+            QualityTier::Full => QualityTier::Reduced,
+            QualityTier::Reduced | QualityTier::Minimal => QualityTier::Minimal,
+        }
+    }
+
+    fn higher(self) -> Self {
+        match self {
+            QualityTier::Full | QualityTier::Reduced => QualityTier::Full,
+            QualityTier::Minimal => QualityTier::Reduced,
+        }
+    }
+}
+
+/// Current quality tier plus the smoothed frame time that drives it.
+#[derive(Resource, Debug, Clone, Copy, Default)]
+pub struct FrameTimeGovernor {
+    pub tier: QualityTier,
+    /// Exponentially smoothed frame time (ms); `0.0` before the first sample.
+    pub smoothed_frame_ms: f32,
+    over_budget_secs: f32,
+    headroom_secs: f32,
+}
+
+impl FrameTimeGovernor {
+    /// Feed one frame's duration and step the tier if a threshold has held
+    /// long enough.  Returns `true` when the tier changed.
+    pub fn observe(&mut self, frame_ms: f32, config: &PhysicsConfig) -> bool {
+        if !config.frame_governor_enabled {
+            let changed = self.tier != QualityTier::Full;
+            *self = Self {
+                smoothed_frame_ms: frame_ms,
+                ..Self::default()
+            };
+            return changed;
+        }
+
+        self.smoothed_frame_ms = if self.smoothed_frame_ms <= 0.0 {
+            frame_ms
+        } else {
+            self.smoothed_frame_ms + (frame_ms - self.smoothed_frame_ms) * FRAME_TIME_SMOOTHING
+        };
+
+        let dt = frame_ms / 1000.0;
+        let budget = config.frame_governor_target_ms;
+        let previous = self.tier;
+
+        if self.smoothed_frame_ms > budget {
+            self.headroom_secs = 0.0;
+            self.over_budget_secs += dt;
+            if self.over_budget_secs >= config.frame_governor_degrade_secs {
+                self.over_budget_secs = 0.0;
+                self.tier = self.tier.lower();
+            }
+        } else if self.smoothed_frame_ms < budget * config.frame_governor_headroom_fraction {
+            self.over_budget_secs = 0.0;
+            self.headroom_secs += dt;
+            if self.headroom_secs >= config.frame_governor_restore_secs {
+                self.headroom_secs = 0.0;
+                self.tier = self.tier.higher();
+            }
+        } else {
+            self.over_budget_secs = 0.0;
+            self.headroom_secs = 0.0;
+        }
+
+        self.tier != previous
+    }
+
+    /// Gravity neighbour search radius at the current tier.
+    pub fn effective_max_gravity_dist(&self, config: &PhysicsConfig) -> f32 {
+        let scale = config
+            .frame_governor_gravity_radius_step
+            .powi(self.tier.level());
+        (config.max_gravity_dist * scale).max(config.min_gravity_dist)
+    }
+
+    /// Pair force magnitude below which gravity is skipped at the current
+    /// tier (`0.0` at full quality).
+    pub fn effective_gravity_force_epsilon(&self, config: &PhysicsConfig) -> f32 {
+        config.frame_governor_gravity_epsilon_step.max(0.0) * self.tier.level() as f32
+    }
+
+    /// Multiplier on `asteroid_lod_simplified_distance` and
+    /// `asteroid_lod_hidden_distance` at the current tier.
+    pub fn effective_lod_distance_scale(&self, config: &PhysicsConfig) -> f32 {
+        config
+            .frame_governor_lod_distance_step
+            .clamp(0.05, 1.0)
+            .powi(self.tier.level())
+    }
+
+    /// Live particle budget at the current tier.
+    pub fn effective_particle_budget(&self, config: &PhysicsConfig) -> u32 {
+        let scale = config
+            .frame_governor_particle_budget_step
+            .powi(self.tier.level());
+        ((config.particle_budget as f32 * scale).round() as u32).max(1)
+    }
+}
+
+/// Sample the real frame time and update [`FrameTimeGovernor`].
+///
+/// Uses `Time<Real>` so pausing or slowing virtual time never reads as
+/// headroom.
+pub fn frame_governor_system(
+    time: Res<Time<Real>>,
+    config: Res<PhysicsConfig>,
+    mut governor: ResMut<FrameTimeGovernor>,
+) {
+    let frame_ms = time.delta_secs() * 1000.0;
+    if frame_ms <= 0.0 {
+        return;
+    }
+    if governor.observe(frame_ms, &config) {
+        info!(
+            "Frame governor: quality {} (smoothed {:.1} ms, budget {:.1} ms)",
+            governor.tier.label(),
+            governor.smoothed_frame_ms,
+            config.frame_governor_target_ms
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn feed(governor: &mut FrameTimeGovernor, frame_ms: f32, secs: f32, config: &PhysicsConfig) {
+        let frames = (secs * 1000.0 / frame_ms).ceil() as usize;
+        for _ in 0..frames {
+            governor.observe(frame_ms, config);
+        }
+    }
+
+    #[test]
+    fn sustained_overload_degrades_then_recovery_restores() {
+        let config = PhysicsConfig::default();
+        let mut governor = FrameTimeGovernor::default();
+
+        feed(&mut governor, 40.0, 1.0, &config);
+        assert_eq!(governor.tier, QualityTier::Reduced);
+        feed(&mut governor, 40.0, 1.0, &config);
+        assert_eq!(governor.tier, QualityTier::Minimal);
+
+        // Let the moving average settle, then hold headroom long enough for
+        // exactly one restore step.
+        feed(
+            &mut governor,
+            8.0,
+            config.frame_governor_restore_secs + 0.5,
+            &config,
+        );
+        assert_eq!(governor.tier, QualityTier::Reduced);
+    }
+
+    #[test]
+    fn near_budget_frames_hold_the_current_tier() {
+        let config = PhysicsConfig::default();
+        let mut governor = FrameTimeGovernor {
+            tier: QualityTier::Reduced,
+            ..Default::default()
+        };
+
+        feed(
+            &mut governor,
+            config.frame_governor_target_ms * 0.9,
+            10.0,
+            &config,
+        );
+
+        assert_eq!(governor.tier, QualityTier::Reduced);
+    }
+
+    #[test]
+    fn degraded_tiers_raise_gravity_epsilon_and_lod_aggressiveness() {
+        let config = PhysicsConfig::default();
+        let full = FrameTimeGovernor::default();
+        let minimal = FrameTimeGovernor {
+            tier: QualityTier::Minimal,
+            ..Default::default()
+        };
+
+        assert_eq!(full.effective_gravity_force_epsilon(&config), 0.0);
+        assert_eq!(full.effective_lod_distance_scale(&config), 1.0);
+        assert_eq!(
+            minimal.effective_gravity_force_epsilon(&config),
+            2.0 * config.frame_governor_gravity_epsilon_step
+        );
+        let step = config.frame_governor_lod_distance_step;
+        assert_eq!(minimal.effective_lod_distance_scale(&config), step * step);
+    }
+
+    #[test]
+    fn degraded_tiers_scale_gravity_radius_and_particle_budget() {
+        let config = PhysicsConfig::default();
+        let governor = FrameTimeGovernor {
+            tier: QualityTier::Minimal,
+            ..Default::default()
+        };
+
+        let step = config.frame_governor_gravity_radius_step;
+        let expected = (config.max_gravity_dist * step * step).max(config.min_gravity_dist);
+        assert_eq!(governor.effective_max_gravity_dist(&config), expected);
+        assert!(governor.effective_particle_budget(&config) < config.particle_budget);
+        assert_eq!(
+            FrameTimeGovernor::default().effective_particle_budget(&config),
+            config.particle_budget
+        );
+    }
+}
@@ -0,0 +1,545 @@
+//! Merge intents: bound clusters found by `asteroid_formation_system`, merged
+//! once per tick by [`merge_resolution_system`].
+//!
+//! Detection only writes a [`MergeIntent`] per gravitationally bound cluster.
+//! Resolution then sees every intent of the tick at once:
+//!
+//! - Intents that share a body are joined into one component
+//!   ([`MergeScratch::resolve`]), so bodies touching in a chain merge into one
+//!   composite whatever order their contacts were reported in.
+//! - Each component merges atomically: one composite is spawned and every
+//!   member despawned, or nothing happens.  Components are disjoint, so no
+//!   body is despawned twice.
+//! - A component with a member that is no longer a free asteroid (despawned
+//!   earlier in the tick, or made a planet) is skipped.  The rest are still
+//!   touching and are found again next tick.
+//! - At most `formation_merge_budget` components merge per tick, those with
+//!   the largest contact impulse first ([`budget_merge_components`]).
+
+use crate::alloc_profile::AllocTag;
+use crate::asteroid::{
+    compute_convex_hull_from_points, rescale_vertices_to_area, simplify_hull, Asteroid,
+    AsteroidSize, Planet, Vertices,
+};
+use crate::config::PhysicsConfig;
+use crate::feedback::{FeedbackEvent, FeedbackKind};
+use crate::graphics::merge_animation::MergeAnimation;
+use crate::lineage::{BiggestAccretion, Lineage};
+use crate::mining::AsteroidMaterial;
+use crate::run_info::RunPlaytime;
+use crate::simulation::shockwave::Shockwave;
+use crate::simulation::size_class::{SizeClass, SizeClassReached};
+use crate::simulation::volatile::Volatile;
+use crate::simulation::SimulationStats;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+use std::collections::HashMap;
+
+/// Bodies of one gravitationally bound cluster that should become one.
+#[derive(Message, Debug, Clone, PartialEq)]
+pub struct MergeIntent {
+    pub members: Vec<Entity>,
+    /// Total contact impulse between the members this step.
+    pub impulse: f32,
+}
+
+/// Intents joined through shared members; merges into a single composite.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeComponent {
+    /// Members in ascending entity order.
+    pub members: Vec<Entity>,
+    /// Summed impulse of the joined intents.
+    pub impulse: f32,
+}
+
+/// Reusable buffers for [`merge_resolution_system`].
+#[derive(Resource, Default)]
+pub struct MergeScratch {
+    /// Union-find slot of each entity named by an intent.
+    slot_by_entity: HashMap<Entity, usize>,
+    entities: Vec<Entity>,
+    parent: Vec<usize>,
+    /// Intent impulse, booked on the intent's first member.
+    impulse: Vec<f32>,
+    /// Component index of each union-find root.
+    component_of_root: Vec<usize>,
+    /// Components of the last [`resolve`](Self::resolve) call.
+    pub components: Vec<MergeComponent>,
+    /// World-space vertex accumulation buffer.
+    world_vertices: Vec<Vec2>,
+}
+
+impl MergeScratch {
+    /// Join `intents` into disjoint components, ordered by their smallest
+    /// member so the result does not depend on intent order.
+    pub fn resolve<'a>(&mut self, intents: impl IntoIterator<Item = &'a MergeIntent>) {
+        self.slot_by_entity.clear();
+        self.entities.clear();
+        self.parent.clear();
+        self.impulse.clear();
+        self.components.clear();
+
+        for intent in intents {
+            let mut first = None;
+            for &entity in &intent.members {
+                let slot = *self.slot_by_entity.entry(entity).or_insert_with(|| {
+                    self.entities.push(entity);
+                    self.parent.push(self.parent.len());
+                    self.impulse.push(0.0);
+                    self.parent.len() - 1
+                });
+                match first {
+                    None => first = Some(slot),
+                    Some(root) => union(&mut self.parent, root, slot),
+                }
+            }
+            if let Some(slot) = first {
+                self.impulse[slot] += intent.impulse;
+            }
+        }
+
+        self.component_of_root.clear();
+        self.component_of_root
+            .resize(self.entities.len(), usize::MAX);
+        for slot in 0..self.entities.len() {
+            let root = find(&mut self.parent, slot);
+            if self.component_of_root[root] == usize::MAX {
+                self.component_of_root[root] = self.components.len();
+                self.components.push(MergeComponent {
+                    members: Vec::new(),
+                    impulse: 0.0,
+                });
+            }
+            let component = &mut self.components[self.component_of_root[root]];
+            component.members.push(self.entities[slot]);
+            component.impulse += self.impulse[slot];
+        }
+
+        self.components
+            .retain(|component| component.members.len() >= 2);
+        // By index: `Entity`'s own ordering does not follow spawn order.
+        for component in &mut self.components {
+            component
+                .members
+                .sort_unstable_by_key(|entity| entity.index());
+        }
+        self.components
+            .sort_unstable_by_key(|component| component.members[0].index());
+    }
+}
+
+fn find(parent: &mut [usize], mut slot: usize) -> usize {
+    while parent[slot] != slot {
+        parent[slot] = parent[parent[slot]];
+        slot = parent[slot];
+    }
+    slot
+}
+
+fn union(parent: &mut [usize], a: usize, b: usize) {
+    let (a, b) = (find(parent, a), find(parent, b));
+    if a != b {
+        parent[a.max(b)] = a.min(b);
+    }
+}
+
+/// Order `components` hardest impact first and return how many of them to
+/// merge now; the rest wait for a later tick.
+///
+/// A `budget` of 0 merges everything.
+pub fn budget_merge_components(components: &mut [MergeComponent], budget: u32) -> usize {
+    if budget == 0 || components.len() <= budget as usize {
+        return components.len();
+    }
+    // Stable, so equal impulses keep their entity order.
+    components.sort_by(|a, b| b.impulse.total_cmp(&a.impulse));
+    budget as usize
+}
+
+/// Merge this tick's [`MergeIntent`]s, one composite per component.
+///
+/// The composite:
+///
+/// - moves with the members' centre-of-mass velocity and their average spin;
+/// - takes the [`AsteroidMaterial`] carrying most of the members' mass;
+/// - gets a [`Lineage`] whose ancestors are the members' own lineages (or
+///   leaves for members that never merged); the heaviest lineage of the run
+///   is kept in [`BiggestAccretion`];
+/// - is [`Volatile`] if any member was;
+/// - carries a [`MergeAnimation`] of the members' outlines, so they are drawn
+///   flowing into it for `merge_animation_secs`;
+/// - emits a [`Shockwave`] at `shockwave_merge_min_mass` or more, and a
+///   [`SizeClassReached`] when it reaches a size class no member had.
+///
+/// Components left over by `formation_merge_budget` are reported in
+/// [`SimulationStats::formation_deferred`].
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn merge_resolution_system(
+    mut commands: Commands,
+    mut intents: MessageReader<MergeIntent>,
+    query: Query<
+        (Entity, &Transform, &Velocity, &Vertices, &AsteroidSize),
+        (With<Asteroid>, Without<Planet>),
+    >,
+    q_material: Query<&AsteroidMaterial>,
+    q_volatile: Query<(), With<Volatile>>,
+    q_lineage: Query<&Lineage>,
+    mut stats: ResMut<SimulationStats>,
+    config: Res<PhysicsConfig>,
+    mut scratch: ResMut<MergeScratch>,
+    playtime: Option<Res<RunPlaytime>>,
+    mut biggest: ResMut<BiggestAccretion>,
+    (mut shockwaves, mut feedback, mut size_classes): (
+        MessageWriter<Shockwave>,
+        MessageWriter<FeedbackEvent>,
+        MessageWriter<SizeClassReached>,
+    ),
+) {
+    static ALLOC_TAG: AllocTag = AllocTag::new("FixedPostUpdate", "merge_resolution_system");
+    let _alloc_scope = ALLOC_TAG.enter();
+
+    let scratch = &mut *scratch;
+    scratch.resolve(intents.read());
+    let merge_now = budget_merge_components(&mut scratch.components, config.formation_merge_budget);
+    let deferred = (scratch.components.len() - merge_now) as u32;
+    stats.formation_deferred = deferred;
+    stats.formation_deferred_total += deferred;
+
+    let MergeScratch {
+        components,
+        world_vertices,
+        ..
+    } = scratch;
+    for component in &components[..merge_now] {
+        let Some(members) = component
+            .members
+            .iter()
+            .map(|&entity| query.get(entity).ok())
+            .collect::<Option<Vec<_>>>()
+        else {
+            debug!(
+                "Skipping merge of {} bodies: a member is gone",
+                component.members.len()
+            );
+            continue;
+        };
+
+        // Composite inherits the centre-of-mass velocity (momentum-conserving).
+        // Angular velocity: simple average (moment-of-inertia weighting negligible here).
+        let total_size: u32 = members.iter().map(|(.., size)| size.0).sum();
+        let avg_linvel = members
+            .iter()
+            .map(|(_, _, vel, _, size)| vel.linvel * size.0 as f32)
+            .sum::<Vec2>()
+            / total_size as f32;
+        let avg_angvel = members
+            .iter()
+            .map(|(_, _, vel, ..)| vel.angvel)
+            .sum::<f32>()
+            / members.len() as f32;
+
+        // Collect ALL vertices from ALL members in world-space
+        world_vertices.clear();
+        for (_, transform, _, vertices, _) in &members {
+            let rotation = transform.rotation;
+            let offset = transform.translation.truncate();
+            for local_v in &vertices.0 {
+                // Rotate local vertex by transform rotation
+                world_vertices.push(offset + rotation.mul_vec3(local_v.extend(0.0)).truncate());
+            }
+        }
+
+        // Compute convex hull from all world-space vertices; a valid
+        // composite needs at least 3 of them.
+        let Some(hull) = compute_convex_hull_from_points(world_vertices) else {
+            continue;
+        };
+        if hull.len() < 3 {
+            continue;
+        }
+
+        // Use the hull's geometric centroid as the spawn position so that the
+        // stored local vertices are centred on (0,0) in local space.
+        // Using the average of member positions would leave the vertices
+        // off-centre, causing the physics body and drawn outline to misalign.
+        let hull_centroid: Vec2 = hull.iter().copied().sum::<Vec2>() / hull.len() as f32;
+        let hull_local: Vec<Vec2> = hull.iter().map(|v| *v - hull_centroid).collect();
+
+        // Sanity check: skip merges that would produce pathologically large shapes.
+        // A legitimate merge of N small asteroids should never produce a hull
+        // that extends more than ~50 units per constituent member from the center.
+        let max_extent = hull_local
+            .iter()
+            .map(|v| v.length())
+            .fold(0.0_f32, f32::max);
+        let extent_limit =
+            config.hull_extent_base + members.len() as f32 * config.hull_extent_per_member;
+        if max_extent > extent_limit {
+            // Refuse to create this merge — it indicates corrupted vertex data.
+            // Despawn nothing; leave the source asteroids intact.
+            continue;
+        }
+
+        // Scale the hull so its visual area matches total_size / density.
+        // This ensures merged composites look proportional to their mass
+        // regardless of how spread out the constituent asteroids were.
+        let target_area = total_size as f32 / config.asteroid_density;
+        let hull_local = rescale_vertices_to_area(&hull_local, target_area);
+        // Long merge chains leave many nearly collinear vertices;
+        // thin them out to keep collider and mesh cost down.
+        let hull_local = if hull_local.len() > config.hull_simplify_min_vertices {
+            simplify_hull(&hull_local, config.hull_simplify_epsilon)
+        } else {
+            hull_local
+        };
+
+        let avg_color = Color::srgb(0.5, 0.5, 0.5);
+        let composite = crate::asteroid::spawn_asteroid_with_vertices(
+            &mut commands,
+            hull_centroid,
+            &hull_local,
+            avg_color,
+            total_size,
+        );
+
+        let material = AsteroidMaterial::dominant(members.iter().map(|&(entity, .., size)| {
+            (q_material.get(entity).copied().unwrap_or_default(), size.0)
+        }));
+
+        let now = playtime.as_ref().map_or(0.0, |p| p.secs);
+        let lineage = Lineage::merge(
+            members
+                .iter()
+                .map(|&(entity, .., size)| {
+                    q_lineage.get(entity).map_or_else(
+                        |_| Lineage::leaf(size.0, now),
+                        |lineage| lineage.clone().with_mass(size.0),
+                    )
+                })
+                .collect(),
+            now,
+            config.lineage_max_nodes,
+        );
+        biggest.offer(&lineage);
+
+        if let Ok(mut cmd) = commands.get_entity(composite) {
+            cmd.insert((
+                Velocity {
+                    linvel: avg_linvel,
+                    angvel: avg_angvel,
+                },
+                material,
+                lineage,
+            ));
+            if members
+                .iter()
+                .any(|&(entity, ..)| q_volatile.contains(entity))
+            {
+                cmd.insert(Volatile);
+            }
+            if config.merge_animation_secs > 0.0 {
+                let sources = members
+                    .iter()
+                    .map(|(_, transform, _, vertices, _)| {
+                        vertices
+                            .0
+                            .iter()
+                            .map(|v| {
+                                transform.translation.truncate()
+                                    + transform.rotation.mul_vec3(v.extend(0.0)).truncate()
+                                    - hull_centroid
+                            })
+                            .collect()
+                    })
+                    .collect();
+                cmd.insert(MergeAnimation::new(sources, config.merge_animation_secs));
+            }
+        }
+
+        // Track merge: N asteroids became 1, so we merged (N-1) asteroids
+        stats.merged_total += (members.len() - 1) as u32;
+
+        feedback.write(FeedbackEvent {
+            kind: FeedbackKind::Merge,
+            pos: hull_centroid,
+            vel: avg_linvel,
+            mass: total_size,
+        });
+        let largest_part = members.iter().map(|(.., size)| size.0).max().unwrap_or(0);
+        if let Some(class) = SizeClass::crossed(largest_part, total_size) {
+            size_classes.write(SizeClassReached {
+                entity: composite,
+                class,
+                mass: total_size,
+                pos: hull_centroid,
+            });
+        }
+        if total_size >= config.shockwave_merge_min_mass {
+            shockwaves.write(Shockwave {
+                origin: hull_centroid,
+                mass: total_size,
+            });
+        }
+
+        // Despawn all source asteroids
+        for &(entity, ..) in &members {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn e(raw: u32) -> Entity {
+        Entity::from_raw_u32(raw).expect("test entity index must not be u32::MAX")
+    }
+
+    fn intent(members: &[u32], impulse: f32) -> MergeIntent {
+        MergeIntent {
+            members: members.iter().map(|&raw| e(raw)).collect(),
+            impulse,
+        }
+    }
+
+    #[test]
+    fn intents_sharing_a_body_merge_as_one_component() {
+        let intents = [
+            intent(&[3, 1], 2.0),
+            intent(&[7, 8], 1.0),
+            intent(&[2, 3], 4.0),
+            intent(&[9], 5.0),
+        ];
+        let mut scratch = MergeScratch::default();
+        scratch.resolve(&intents);
+        assert_eq!(
+            scratch.components,
+            vec![
+                MergeComponent {
+                    members: vec![e(1), e(2), e(3)],
+                    impulse: 6.0,
+                },
+                MergeComponent {
+                    members: vec![e(7), e(8)],
+                    impulse: 1.0,
+                },
+            ]
+        );
+
+        // Any intent order gives the same components.
+        let expected = scratch.components.clone();
+        for order in [[2, 0, 1, 3], [3, 1, 2, 0], [1, 3, 0, 2]] {
+            scratch.resolve(order.iter().map(|&i| &intents[i]));
+            assert_eq!(scratch.components, expected);
+        }
+    }
+
+    #[test]
+    fn budget_merges_the_hardest_impacts_first() {
+        let component = |impulse: f32, first: u32| MergeComponent {
+            members: vec![e(first), e(first + 1)],
+            impulse,
+        };
+        let mut components = vec![
+            component(1.0, 0),
+            component(9.0, 2),
+            component(4.0, 4),
+            component(7.0, 6),
+        ];
+        let now = budget_merge_components(&mut components, 2);
+        assert_eq!(now, 2);
+        let merged: Vec<Entity> = components[..now].iter().map(|c| c.members[0]).collect();
+        assert_eq!(merged, vec![e(2), e(6)]);
+
+        assert_eq!(budget_merge_components(&mut components, 0), 4);
+        assert_eq!(budget_merge_components(&mut components, 10), 4);
+    }
+
+    fn square(app: &mut App, pos: Vec2, size: u32) -> Entity {
+        let half = 3.0;
+        app.world_mut()
+            .spawn((
+                Asteroid,
+                AsteroidSize(size),
+                Transform::from_translation(pos.extend(0.0)),
+                Velocity {
+                    linvel: Vec2::new(size as f32, 0.0),
+                    angvel: 0.0,
+                },
+                Vertices(vec![
+                    Vec2::new(-half, -half),
+                    Vec2::new(half, -half),
+                    Vec2::new(half, half),
+                    Vec2::new(-half, half),
+                ]),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn a_chain_of_three_becomes_one_composite() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(PhysicsConfig::default())
+            .init_resource::<SimulationStats>()
+            .init_resource::<MergeScratch>()
+            .init_resource::<BiggestAccretion>()
+            .add_message::<MergeIntent>()
+            .add_message::<Shockwave>()
+            .add_message::<FeedbackEvent>()
+            .add_message::<SizeClassReached>()
+            .add_systems(Update, merge_resolution_system);
+        let a = square(&mut app, Vec2::new(0.0, 0.0), 2);
+        let b = square(&mut app, Vec2::new(6.0, 0.0), 3);
+        let c = square(&mut app, Vec2::new(12.0, 0.0), 5);
+        let gone = square(&mut app, Vec2::new(40.0, 0.0), 1);
+        let d = square(&mut app, Vec2::new(46.0, 0.0), 1);
+        app.world_mut().despawn(gone);
+
+        // B is in both pairs; merging them one by one would despawn it twice.
+        for members in [vec![a, b], vec![c, b], vec![gone, d]] {
+            app.world_mut().write_message(MergeIntent {
+                members,
+                impulse: 1.0,
+            });
+        }
+        app.update();
+
+        let world = app.world_mut();
+        assert!(world.get_entity(a).is_err());
+        assert!(world.get_entity(b).is_err());
+        assert!(world.get_entity(c).is_err());
+        assert!(
+            world.get_entity(d).is_ok(),
+            "stale component must be skipped"
+        );
+        let sizes: Vec<(u32, Vec2)> = world
+            .query_filtered::<(&AsteroidSize, &Velocity), With<Asteroid>>()
+            .iter(world)
+            .map(|(size, vel)| (size.0, vel.linvel))
+            .collect();
+        assert_eq!(sizes.len(), 2);
+        let (size, linvel) = sizes
+            .into_iter()
+            .find(|&(size, _)| size == 10)
+            .expect("one composite of all three");
+        assert_eq!(size, 10);
+        // Mass-weighted: (2·2 + 3·3 + 5·5) / 10.
+        assert!((linvel.x - 3.8).abs() < 1e-5);
+        assert_eq!(world.resource::<SimulationStats>().merged_total, 2);
+
+        // The members' outlines are kept, relative to the composite, for the
+        // merge animation.
+        let (transform, anim) = world
+            .query::<(&Transform, &MergeAnimation)>()
+            .single(world)
+            .unwrap();
+        assert_eq!(anim.sources.len(), 3);
+        let leftmost = anim.sources[0]
+            .iter()
+            .map(|v| v.x + transform.translation.x)
+            .fold(f32::INFINITY, f32::min);
+        assert!((leftmost - -3.0).abs() < 1e-4, "{leftmost}");
+    }
+}
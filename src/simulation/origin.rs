@@ -0,0 +1,313 @@
+//! World-origin recentering for the unbounded FRONTIER field.
+//!
+//! Positions are `f32`, whose spacing grows with distance from the origin:
+//! about 0.002 u at 20 000 u and 0.008 u at 100 000 u.  Contacts, gravity
+//! and slow drift all degrade as the ship explores outward.  When the ship is
+//! farther than `origin_recenter_distance` from the origin,
+//! [`origin_recenter_system`] moves the origin to the ship's sector.
+//!
+//! The shift is a whole number of sectors, so the sector grid, the
+//! [`SectorStore`] and the procgen chunks stay aligned.
+//! [`ProceduralField::origin`] accumulates it, which keeps chunk generation
+//! and the generated set in the seed's absolute chunk coordinates.
+//!
+//! [`recenter_world`] shifts:
+//! - `Transform` and `GlobalTransform` of every root entity outside the UI
+//!   (bodies, ships, shots, particles, the camera, world-space overlays);
+//!   children follow their parents;
+//! - each body's [`PhysicsInterpolation`] history;
+//! - the parked asteroids in the [`SectorStore`];
+//! - world-space points held in resources and components: kill-cam focus
+//!   and replay buffer, intermission beacon, mining-laser beam, ship motion
+//!   trail, cluster summaries, the density map and enemy formation anchors.
+//!
+//! Velocities are untouched, and the spatial index is rebuilt immediately.
+//! The system runs in `FixedFirst` right after the tick poses are restored,
+//! so Rapier sees every body teleported by the same vector before its step.
+//! Bounded scenarios are held near the origin by the soft boundary and are
+//! never recentered.
+
+use super::clusters::ClusterStats;
+use super::density::DensityField;
+use super::fixed_step::PhysicsInterpolation;
+use super::procgen::ProceduralField;
+use super::sectors::SectorStore;
+use crate::campaign::WaveIntermissionShop;
+use crate::config::PhysicsConfig;
+use crate::enemy::EnemyFormationTarget;
+use crate::mining::laser::MiningLaserState;
+use crate::player::{KillCamReplay, KillCamState, Player};
+use crate::rendering::motion_trail::ShipTrail;
+use crate::spatial_partition::{rebuild_spatial_grid_system, sector_of};
+use bevy::prelude::*;
+
+/// Move the origin to the ship's sector once it is far enough out.
+pub fn origin_recenter_system(world: &mut World) {
+    if !world
+        .get_resource::<ProceduralField>()
+        .is_some_and(|field| field.enabled)
+    {
+        return;
+    }
+    let config = world.resource::<PhysicsConfig>();
+    let (threshold, sector_size) = (config.origin_recenter_distance, config.sector_size);
+    let mut q_player = world.query_filtered::<&Transform, With<Player>>();
+    let Ok(player) = q_player.single(world) else {
+        return;
+    };
+    let pos = player.translation.truncate();
+    if pos.length() <= threshold {
+        return;
+    }
+    let chunks = sector_of(pos, sector_size);
+    info!(
+        "Recentering world origin by {} sectors ({:.0} u from origin)",
+        chunks,
+        pos.length()
+    );
+    recenter_world(world, chunks);
+}
+
+/// Shift the whole world so sector `chunks` becomes sector `(0, 0)`.
+pub fn recenter_world(world: &mut World, chunks: IVec2) {
+    if chunks == IVec2::ZERO {
+        return;
+    }
+    let sector_size = world.resource::<PhysicsConfig>().sector_size.max(1.0);
+    let delta = -chunks.as_vec2() * sector_size;
+
+    let mut q_roots = world.query_filtered::<(
+        &mut Transform,
+        Option<&mut GlobalTransform>,
+        Option<&mut PhysicsInterpolation>,
+    ), (Without<ChildOf>, Without<Node>)>();
+    for (mut transform, global, interpolation) in q_roots.iter_mut(world) {
+        transform.translation += delta.extend(0.0);
+        if let Some(mut global) = global {
+            *global = GlobalTransform::from(*transform);
+        }
+        if let Some(mut interpolation) = interpolation {
+            interpolation.shift(delta);
+        }
+    }
+
+    let mut q_anchors = world.query::<&mut EnemyFormationTarget>();
+    for mut target in q_anchors.iter_mut(world) {
+        target.world_anchor += delta;
+    }
+
+    if let Some(mut store) = world.get_resource_mut::<SectorStore>() {
+        store.shift(chunks, sector_size);
+    }
+    if let Some(mut field) = world.get_resource_mut::<ProceduralField>() {
+        field.origin += chunks;
+    }
+    if let Some(mut kill_cam) = world.get_resource_mut::<KillCamState>() {
+        kill_cam.focus += delta;
+        if let Some(pos) = kill_cam.last_player_pos.as_mut() {
+            *pos += delta;
+        }
+    }
+    if let Some(mut replay) = world.get_resource_mut::<KillCamReplay>() {
+        replay.shift(delta);
+    }
+    if let Some(mut shop) = world.get_resource_mut::<WaveIntermissionShop>() {
+        if let Some(pos) = shop.beacon_pos.as_mut() {
+            *pos += delta;
+        }
+    }
+    if let Some(mut laser) = world.get_resource_mut::<MiningLaserState>() {
+        if let Some((start, end)) = laser.beam.as_mut() {
+            *start += delta;
+            *end += delta;
+        }
+    }
+    if let Some(mut trail) = world.get_resource_mut::<ShipTrail>() {
+        trail.shift(delta);
+    }
+    if let Some(mut clusters) = world.get_resource_mut::<ClusterStats>() {
+        clusters.shift(delta);
+    }
+    if let Some(mut density) = world.get_resource_mut::<DensityField>() {
+        density.shift(delta);
+    }
+
+    if let Err(err) = world.run_system_cached(rebuild_spatial_grid_system) {
+        warn!("Spatial grid rebuild after recentering failed: {err}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroid::Asteroid;
+    use crate::save::AsteroidSnapshot;
+    use crate::spatial_partition::SpatialGrid;
+    use bevy_rapier2d::prelude::Velocity;
+
+    fn recenter_app(player_at: Vec2) -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(PhysicsConfig::default())
+            .insert_resource(ProceduralField::new(3))
+            .insert_resource(SectorStore::default())
+            .insert_resource(SpatialGrid::default())
+            .add_systems(Update, origin_recenter_system);
+        app.world_mut()
+            .spawn((Player, Transform::from_translation(player_at.extend(0.0))));
+        app
+    }
+
+    #[test]
+    fn far_ship_moves_the_origin_by_whole_sectors() {
+        let config = PhysicsConfig::default();
+        let far = Vec2::new(config.origin_recenter_distance + 1_234.5, -3_210.25);
+        let mut app = recenter_app(far);
+        let world = app.world_mut();
+        let rock_at = far + Vec2::new(40.0, -15.0);
+        let rock = world
+            .spawn((
+                Asteroid,
+                Transform::from_translation(rock_at.extend(0.0)),
+                Velocity::linear(Vec2::new(12.0, 3.0)),
+            ))
+            .id();
+        let parked_at = far + Vec2::new(3_000.0, 0.0);
+        world.resource_mut::<SectorStore>().store(
+            AsteroidSnapshot {
+                pos: parked_at.into(),
+                rot: 0.0,
+                linvel: [0.0, 0.0],
+                angvel: 0.0,
+                size: 2,
+                vertices: vec![[1.0, 0.0], [0.0, 1.0], [-1.0, 0.0]],
+                material: Default::default(),
+                lineage: None,
+                volatile: false,
+            },
+            config.sector_size,
+        );
+
+        app.update();
+
+        let world = app.world_mut();
+        let chunks = sector_of(far, config.sector_size);
+        let delta = -chunks.as_vec2() * config.sector_size;
+        assert_eq!(world.resource::<ProceduralField>().origin, chunks);
+
+        let mut q_player = world.query_filtered::<&Transform, With<Player>>();
+        let ship = q_player.single(world).unwrap().translation.truncate();
+        assert_eq!(ship, far + delta);
+        assert!(ship.abs().max_element() < config.sector_size);
+
+        let rock_pos = world.get::<Transform>(rock).unwrap().translation.truncate();
+        assert_eq!(rock_pos - ship, rock_at - far, "relative layout is exact");
+        assert_eq!(
+            world.get::<Velocity>(rock).unwrap().linvel,
+            Vec2::new(12.0, 3.0)
+        );
+        let nearby = world.resource::<SpatialGrid>().get_neighbors_excluding(
+            Entity::PLACEHOLDER,
+            rock_pos,
+            1.0,
+        );
+        assert_eq!(nearby, vec![rock], "spatial index was rebuilt");
+
+        let parked = world.resource_mut::<SectorStore>().take_within(
+            parked_at + delta,
+            1.0,
+            config.sector_size,
+        );
+        assert_eq!(parked.len(), 1, "parked asteroids moved with their sectors");
+
+        // Back near the origin: nothing more happens.
+        app.update();
+        assert_eq!(app.world().resource::<ProceduralField>().origin, chunks);
+    }
+
+    #[test]
+    fn recentering_moves_the_ship_trail_with_the_ship() {
+        let config = PhysicsConfig {
+            motion_trail_interval_secs: 0.0,
+            ..Default::default()
+        };
+        let far = Vec2::new(config.origin_recenter_distance + 500.0, 0.0);
+        let mut app = recenter_app(far);
+        let mut trail = ShipTrail::default();
+        let fast = config.motion_trail_min_speed + 1.0;
+        for step in 0..3 {
+            let pose = far - Vec2::new(20.0 * step as f32, 0.0);
+            trail.advance(0.01, Some((pose, Quat::IDENTITY, fast)), &config);
+        }
+        let before: Vec<Vec2> = trail.poses().map(|pose| pose.position).collect();
+        app.insert_resource(trail);
+
+        app.update();
+
+        let world = app.world_mut();
+        let delta = -sector_of(far, config.sector_size).as_vec2() * config.sector_size;
+        let mut q_player = world.query_filtered::<&Transform, With<Player>>();
+        let ship = q_player.single(world).unwrap().translation.truncate();
+        let after: Vec<Vec2> = world
+            .resource::<ShipTrail>()
+            .poses()
+            .map(|pose| pose.position)
+            .collect();
+        assert_eq!(after.len(), before.len());
+        for (old, new) in before.iter().zip(&after) {
+            assert_eq!(*new, *old + delta);
+            assert!(
+                new.distance(ship) <= 40.0,
+                "no streak back to the old origin"
+            );
+        }
+    }
+
+    #[test]
+    fn bounded_scenarios_are_never_recentered() {
+        let far = Vec2::splat(PhysicsConfig::default().origin_recenter_distance * 2.0);
+        let mut app = recenter_app(far);
+        app.insert_resource(ProceduralField::default());
+        app.update();
+
+        let world = app.world_mut();
+        let mut q_player = world.query_filtered::<&Transform, With<Player>>();
+        assert_eq!(q_player.single(world).unwrap().translation.truncate(), far);
+    }
+
+    /// A body coasting outward for a long session, integrated in `f32` with
+    /// and without recentering, against an `f64` reference.
+    #[test]
+    fn recentering_bounds_long_session_drift() {
+        let config = PhysicsConfig::default();
+        let velocity = Vec2::new(1_234.567, -987.654);
+        let dt = 1.0 / 60.0;
+        let steps = 60 * 60 * 5;
+
+        let mut app = recenter_app(Vec2::ZERO);
+        let mut naive = Vec2::ZERO;
+        let mut reference = bevy::math::DVec2::ZERO;
+        for _ in 0..steps {
+            naive += velocity * dt;
+            reference += velocity.as_dvec2() * f64::from(dt);
+            let world = app.world_mut();
+            let mut q_player = world.query_filtered::<&mut Transform, With<Player>>();
+            q_player.single_mut(world).unwrap().translation += (velocity * dt).extend(0.0);
+            app.update();
+        }
+
+        let world = app.world_mut();
+        let origin = world.resource::<ProceduralField>().origin;
+        let mut q_player = world.query_filtered::<&Transform, With<Player>>();
+        let local = q_player.single(world).unwrap().translation.truncate();
+        let absolute = origin.as_dvec2() * f64::from(config.sector_size) + local.as_dvec2();
+
+        let recentered_error = (absolute - reference).length();
+        let naive_error = (naive.as_dvec2() - reference).length();
+        assert!(origin != IVec2::ZERO);
+        assert!(
+            recentered_error * 10.0 < naive_error,
+            "recentered drift {recentered_error} vs naive {naive_error}"
+        );
+    }
+}
@@ -0,0 +1,290 @@
+//! Procedural infinite field for the FRONTIER scenario.
+//!
+//! Space is split into chunks that coincide with the streaming sectors of
+//! [`super::sectors`].  The first time the ship comes within
+//! `procgen_generate_radius` of a chunk, [`procedural_field_system`] generates
+//! the chunk's asteroids and parks them in the [`SectorStore`]; sector
+//! streaming then rehydrates them as the ship closes in, exactly like bodies
+//! that drifted out earlier.  The starting field is simply the chunks around
+//! the origin, generated on the first frame.
+//!
+//! Generation is a pure function of the world seed and the chunk coordinate.
+//! Density follows the same clustered noise as the
+//! FIELD scenario ([`FieldNoise`]), sampled in world space so clusters carry
+//! across chunk edges, and each chunk draws positions, shapes and materials
+//! from its own RNG ([`chunk_seed`]).  A seed therefore produces the same
+//! universe whatever order it is explored in, with one exception: a chunk
+//! that bodies from explored space have already drifted into is thinned by
+//! the live mass the [`DensityField`] finds there ([`generate_crowded_chunk`]).
+//!
+//! Each chunk is generated once: what the player mines or shatters stays
+//! gone.  The generated set is saved, in absolute chunk coordinates, along
+//! with the recentered origin (see [`super::origin`]).  The soft boundary is disabled while
+//! the field is active.
+
+use super::density::DensityField;
+use super::sectors::SectorStore;
+use crate::asteroid::{random_field_body, FieldNoise};
+use crate::config::PhysicsConfig;
+use crate::mining::AsteroidMaterial;
+use crate::player::Player;
+use crate::save::AsteroidSnapshot;
+use crate::spatial_partition::sectors_overlapping;
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::HashSet;
+
+/// Procedural generation state for the current world.
+#[derive(Resource, Debug, Default, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct ProceduralField {
+    /// Generate chunks as the ship explores (FRONTIER only).
+    pub enabled: bool,
+    /// World seed; the run seed.
+    pub seed: u64,
+    /// Absolute chunk that is sector `(0, 0)` of the live world; moved by
+    /// [`super::origin`] recentering.
+    pub origin: IVec2,
+    /// Generated chunks, in absolute coordinates.
+    generated: HashSet<IVec2>,
+}
+
+impl ProceduralField {
+    /// An active field with nothing generated yet.
+    pub fn new(seed: u64) -> Self {
+        Self {
+            enabled: true,
+            seed,
+            origin: IVec2::ZERO,
+            generated: HashSet::new(),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn is_generated(&self, coord: IVec2) -> bool {
+        self.generated.contains(&coord)
+    }
+
+    /// Number of chunks generated so far.
+    #[cfg(test)]
+    pub fn generated_count(&self) -> usize {
+        self.generated.len()
+    }
+
+    /// Save-file form of the generated set, sorted so saves are stable.
+    pub fn to_snapshots(&self) -> Vec<[i32; 2]> {
+        let mut out: Vec<[i32; 2]> = self.generated.iter().map(|c| [c.x, c.y]).collect();
+        out.sort();
+        out
+    }
+
+    /// Restore an active field from a save file.
+    pub fn from_snapshots(seed: u64, chunks: Vec<[i32; 2]>, origin: [i32; 2]) -> Self {
+        Self {
+            enabled: true,
+            seed,
+            origin: IVec2::from(origin),
+            generated: chunks.into_iter().map(IVec2::from).collect(),
+        }
+    }
+}
+
+/// RNG seed for one chunk: the world seed mixed with the chunk coordinate.
+pub fn chunk_seed(world_seed: u64, coord: IVec2) -> u64 {
+    let key = (u64::from(coord.x as u32) << 32) | u64::from(coord.y as u32);
+    splitmix64(world_seed ^ splitmix64(key))
+}
+
+fn splitmix64(mut z: u64) -> u64 {
+    z = z.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// [`generate_crowded_chunk`] for a chunk nothing has drifted into.
+#[cfg(test)]
+pub fn generate_chunk(
+    coord: IVec2,
+    world_seed: u64,
+    config: &PhysicsConfig,
+) -> Vec<AsteroidSnapshot> {
+    generate_crowded_chunk(coord, world_seed, config, 0.0)
+}
+
+/// Asteroids of chunk `coord` in the world of `world_seed`.
+///
+/// The chunk is a `procgen_samples_per_chunk`² grid of sample cells; each
+/// cell holds one asteroid with the field-noise probability scaled by
+/// `procgen_density`.  Nothing spawns inside `player_buffer_radius` of the
+/// origin, where the ship starts.  `live_mass` is the mass of bodies that
+/// already drifted into the chunk; the spawn chance is further scaled by
+/// [`DensityField::crowding_factor`].
+pub fn generate_crowded_chunk(
+    coord: IVec2,
+    world_seed: u64,
+    config: &PhysicsConfig,
+    live_mass: f32,
+) -> Vec<AsteroidSnapshot> {
+    let crowding = DensityField::crowding_factor(live_mass, config);
+    let noise = FieldNoise::from_rng(&mut StdRng::seed_from_u64(world_seed));
+    let mut rng = StdRng::seed_from_u64(chunk_seed(world_seed, coord));
+
+    let chunk_size = config.sector_size.max(1.0);
+    let samples = config.procgen_samples_per_chunk.max(1);
+    let step = chunk_size / samples as f32;
+    let chunk_origin = coord.as_vec2() * chunk_size;
+
+    let mut asteroids = Vec::new();
+    for sample_y in 0..samples {
+        for sample_x in 0..samples {
+            let cell_centre =
+                chunk_origin + Vec2::new(sample_x as f32 + 0.5, sample_y as f32 + 0.5) * step;
+            let spawn_prob = (noise.spawn_probability(cell_centre) * config.procgen_density)
+                .clamp(0.0, 1.0)
+                * crowding;
+            if rng.gen::<f32>() >= spawn_prob {
+                continue;
+            }
+
+            let position = cell_centre
+                + Vec2::new(
+                    rng.gen_range(-step * 0.4..step * 0.4),
+                    rng.gen_range(-step * 0.4..step * 0.4),
+                );
+            if position.length() < config.player_buffer_radius {
+                continue;
+            }
+
+            let body = random_field_body(&mut rng, config);
+            asteroids.push(AsteroidSnapshot {
+                pos: position.into(),
+                rot: body.rotation,
+                linvel: body.velocity.into(),
+                angvel: body.angvel,
+                size: body.size,
+                vertices: body.vertices.iter().map(|v| [v.x, v.y]).collect(),
+                material: AsteroidMaterial::roll(&mut rng, config),
+                lineage: None,
+                volatile: rng.gen::<f32>() < config.volatile_asteroid_fraction,
+            });
+        }
+    }
+    asteroids
+}
+
+/// Generate unvisited chunks around the ship into the sector store.
+///
+/// Chunks are generated at their absolute coordinate and moved into the live
+/// world by the recentered [`ProceduralField::origin`].
+///
+/// Runs just before `sector_streaming_system`, which spawns whatever of the
+/// new chunks lies inside the stream-in radius.
+pub fn procedural_field_system(
+    config: Res<PhysicsConfig>,
+    mut field: ResMut<ProceduralField>,
+    mut store: ResMut<SectorStore>,
+    density: Option<Res<DensityField>>,
+    q_player: Query<&Transform, With<Player>>,
+) {
+    if !field.enabled {
+        return;
+    }
+    let centre = q_player
+        .single()
+        .map_or(Vec2::ZERO, |t| t.translation.truncate());
+    let radius = config
+        .procgen_generate_radius
+        .max(config.sector_stream_in_radius);
+
+    let world_seed = field.seed;
+    let origin = field.origin;
+    let shift = -origin.as_vec2() * config.sector_size.max(1.0);
+    for coord in sectors_overlapping(centre, radius, config.sector_size) {
+        if !field.generated.insert(coord + origin) {
+            continue;
+        }
+        let chunk_min = coord.as_vec2() * config.sector_size.max(1.0);
+        let live_mass = density.as_ref().map_or(0.0, |density| {
+            density.mass_in_rect(chunk_min, chunk_min + Vec2::splat(config.sector_size))
+        });
+        for mut asteroid in generate_crowded_chunk(coord + origin, world_seed, &config, live_mass) {
+            asteroid.pos = (Vec2::from(asteroid.pos) + shift).into();
+            store.store(asteroid, config.sector_size);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spatial_partition::sector_of;
+
+    #[test]
+    fn chunks_are_deterministic_and_stay_inside_their_sector() {
+        let config = PhysicsConfig::default();
+        let coord = IVec2::new(4, -3);
+
+        let a = generate_chunk(coord, 0xACC2_E710, &config);
+        let b = generate_chunk(coord, 0xACC2_E710, &config);
+        assert!(!a.is_empty());
+        assert_eq!(a.len(), b.len());
+        for (x, y) in a.iter().zip(&b) {
+            assert_eq!(x.pos, y.pos);
+            assert_eq!(x.vertices, y.vertices);
+            assert_eq!(x.material, y.material);
+            assert_eq!(sector_of(Vec2::from(x.pos), config.sector_size), coord);
+        }
+
+        let other_seed = generate_chunk(coord, 0xACC2_E711, &config);
+        assert!(a.len() != other_seed.len() || a[0].pos != other_seed[0].pos);
+    }
+
+    #[test]
+    fn crowded_chunks_are_thinned() {
+        let config = PhysicsConfig::default();
+        let count = |live_mass: f32| -> usize {
+            (0..6)
+                .map(|x| generate_crowded_chunk(IVec2::new(x, 5), 0xACC2_E710, &config, live_mass))
+                .map(|chunk| chunk.len())
+                .sum()
+        };
+        let open = count(0.0);
+        assert_eq!(
+            open,
+            (0..6)
+                .map(|x| generate_chunk(IVec2::new(x, 5), 0xACC2_E710, &config).len())
+                .sum::<usize>()
+        );
+        assert!(count(config.procgen_crowd_mass * 0.5) < open);
+        assert_eq!(count(config.procgen_crowd_mass), 0);
+    }
+
+    #[test]
+    fn exploring_generates_each_chunk_once() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(PhysicsConfig::default())
+            .insert_resource(ProceduralField::new(7))
+            .insert_resource(SectorStore::default())
+            .add_systems(Update, procedural_field_system);
+
+        app.update();
+        let parked = app.world().resource::<SectorStore>().stored_count();
+        assert!(parked > 0);
+        assert!(app
+            .world()
+            .resource::<ProceduralField>()
+            .is_generated(IVec2::ZERO));
+
+        // Nothing around the origin is generated a second time.
+        app.update();
+        assert_eq!(app.world().resource::<SectorStore>().stored_count(), parked);
+
+        let field = app.world().resource::<ProceduralField>();
+        let restored = ProceduralField::from_snapshots(7, field.to_snapshots(), [0, 0]);
+        assert_eq!(restored.generated_count(), field.generated_count());
+        assert!(restored.is_generated(IVec2::new(-1, -1)));
+    }
+}
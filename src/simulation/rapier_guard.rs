@@ -0,0 +1,139 @@
+//! Guards around the Rapier step against parry2d BVH panics.
+//!
+//! Mass despawns (leaving a session, campaign retries) remove hundreds of
+//! bodies in one command flush.  If `step_simulation` runs with a live
+//! pipeline before Rapier's `SyncBackend` pass has dropped those handles,
+//! parry2d's BVH panics with "key not present".  Two layers prevent that:
+//!
+//! - **Deferred resume.**  `resume_physics` only sets
+//!   [`PhysicsResumeGuard::pending`]; [`apply_physics_resume_system`] turns the
+//!   pipeline back on after the next tick's writeback.  At least one full sync
+//!   pass with the pipeline off therefore always separates a transition's
+//!   despawns from the next step, however fast the state changes.
+//! - **Handle validation.**  Between `SyncBackend` and `StepSimulation`,
+//!   [`validate_rapier_handles_system`] checks that every entity Rapier maps to
+//!   a body or collider is still alive and that its handle still resolves.
+//!   Stale entries skip the step (pipeline off for that tick) and re-request a
+//!   resume, so the next sync gets a chance to flush them.  After
+//!   [`STALE_HANDLE_GRACE_TICKS`] consecutive skips the step proceeds anyway so
+//!   a leak Rapier never cleans up cannot freeze the world; that is logged as
+//!   an error.
+//!
+//! A scenario that is still being placed by the
+//! [`ScenarioSpawnQueue`](crate::loading::ScenarioSpawnQueue) also holds the
+//! resume back until its last body has spawned.
+
+use crate::loading::ScenarioSpawnQueue;
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+/// Consecutive ticks the step may be skipped for stale handles.
+pub const STALE_HANDLE_GRACE_TICKS: u32 = 30;
+
+/// Pending re-enable of the Rapier pipeline.
+#[derive(Resource, Debug, Default, Clone, Copy)]
+pub struct PhysicsResumeGuard {
+    /// Turn the pipeline on after the next fixed tick's writeback.
+    pub pending: bool,
+    /// Consecutive ticks skipped for stale handles.
+    pub stale_ticks: u32,
+}
+
+/// Count `(entity, handle)` entries whose entity is gone or whose handle no
+/// longer resolves.
+pub fn count_stale_handles<H: Copy>(
+    entries: impl IntoIterator<Item = (Entity, H)>,
+    alive: impl Fn(Entity) -> bool,
+    resolves: impl Fn(H) -> bool,
+) -> usize {
+    entries
+        .into_iter()
+        .filter(|&(entity, handle)| !alive(entity) || !resolves(handle))
+        .count()
+}
+
+/// Skip this tick's step if Rapier still maps despawned entities or dangling
+/// handles.
+pub fn validate_rapier_handles_system(
+    entities: &Entities,
+    rapier: ReadRapierContext,
+    mut guard: ResMut<PhysicsResumeGuard>,
+    mut rapier_config: Query<&mut RapierConfiguration>,
+) {
+    if !rapier_config.iter().any(|cfg| cfg.physics_pipeline_active) {
+        return;
+    }
+    let Ok(rapier) = rapier.single() else {
+        return;
+    };
+
+    let alive = |entity| entities.contains(entity);
+    let bodies = &rapier.rigidbody_set;
+    let colliders = &rapier.colliders;
+    let stale = count_stale_handles(
+        bodies.entity2body().iter().map(|(e, h)| (*e, *h)),
+        alive,
+        |handle| bodies.bodies.get(handle).is_some(),
+    ) + count_stale_handles(
+        colliders.entity2collider().iter().map(|(e, h)| (*e, *h)),
+        alive,
+        |handle| colliders.colliders.get(handle).is_some(),
+    );
+
+    if stale == 0 {
+        guard.stale_ticks = 0;
+        return;
+    }
+    guard.stale_ticks += 1;
+    if guard.stale_ticks > STALE_HANDLE_GRACE_TICKS {
+        if guard.stale_ticks == STALE_HANDLE_GRACE_TICKS + 1 {
+            error!(
+                "{stale} stale Rapier handle(s) persisted for {STALE_HANDLE_GRACE_TICKS} ticks; stepping anyway"
+            );
+        }
+        return;
+    }
+    warn!("{stale} stale Rapier handle(s) before step; skipping this tick");
+    for mut cfg in rapier_config.iter_mut() {
+        cfg.physics_pipeline_active = false;
+    }
+    guard.pending = true;
+}
+
+/// Re-enable the pipeline once a resume was requested and a tick has synced.
+///
+/// The resume stays pending while a [`ScenarioSpawnQueue`] is still placing
+/// bodies.
+pub fn apply_physics_resume_system(
+    mut guard: ResMut<PhysicsResumeGuard>,
+    spawn_queue: Option<Res<ScenarioSpawnQueue>>,
+    mut rapier_config: Query<&mut RapierConfiguration>,
+) {
+    if !guard.pending || spawn_queue.is_some_and(|queue| queue.is_loading()) {
+        return;
+    }
+    guard.pending = false;
+    for mut cfg in rapier_config.iter_mut() {
+        cfg.physics_pipeline_active = true;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entity(i: u32) -> Entity {
+        Entity::from_raw_u32(i).unwrap()
+    }
+
+    #[test]
+    fn dead_entities_and_dangling_handles_are_stale() {
+        let entries = [(entity(1), 10), (entity(2), 20), (entity(3), 30)];
+        let alive = |e: Entity| e != entity(2);
+        let resolves = |h: u32| h != 30;
+
+        assert_eq!(count_stale_handles(entries, alive, resolves), 2);
+        assert_eq!(count_stale_handles(entries, |_| true, |_| true), 0);
+    }
+}
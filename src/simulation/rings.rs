@@ -0,0 +1,294 @@
+//! Planetary rings: asteroids that stray inside a planet's Roche radius are
+//! torn apart, and the pieces settle into circular orbits.
+//!
+//! A planet's Roche radius is `roche_radius_scale` times its bounding radius.
+//! [`tidal_disruption_system`] breaks every asteroid heavier than
+//! `ring_fragment_max_mass` that is inside it into fragments of at most that
+//! mass ([`ring_fragment_masses`]), strung out along the body's direction of
+//! travel with its velocity.  The fragments are [`RingParticle`]s of that
+//! planet and siblings of one split, so they do not merge straight back.
+//! Bodies that do merge later inside the radius are torn apart again.
+//!
+//! [`ring_circularization_system`] then damps each ring particle's radial
+//! velocity and eases its tangential speed towards the circular orbit speed
+//! at its radius, at `ring_circularization_rate` per second.  Over a few
+//! orbits the debris spreads into a ring.  Particles that wander past
+//! `ring_zone_scale` Roche radii, or whose planet is gone, drop the tag.
+
+use crate::asteroid::{
+    fragment_vertices_for_mass, spawn_asteroid_with_vertices, Asteroid, AsteroidSize,
+    MergeCooldown, Planet, Vertices,
+};
+use crate::config::PhysicsConfig;
+use crate::mining::AsteroidMaterial;
+use crate::rng::{visual_rng, GameplayRng};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+use rand::Rng;
+
+use super::SimulationStats;
+
+/// Debris of a tidal disruption, orbiting `planet`.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct RingParticle {
+    pub planet: Entity,
+}
+
+/// Roche radius of a planet whose hull is `vertices`.
+pub fn roche_radius(vertices: &[Vec2], config: &PhysicsConfig) -> f32 {
+    let radius = vertices.iter().map(|v| v.length()).fold(0.0, f32::max);
+    radius * config.roche_radius_scale
+}
+
+/// Speed of a circular orbit at distance `r` from a body of `planet_mass`.
+///
+/// Gravity pulls a body of `AsteroidSize` m with `G·M·m/r²` while its Rapier
+/// mass is `m / asteroid_density`, so the speed does not depend on m.
+pub fn circular_orbit_speed(planet_mass: u32, r: f32, config: &PhysicsConfig) -> f32 {
+    if r <= 0.0 {
+        return 0.0;
+    }
+    (config.gravity_const * planet_mass as f32 * config.asteroid_density / r).sqrt()
+}
+
+/// Masses of the fragments a body of `mass` breaks into: as few as possible,
+/// none heavier than `max_mass`, and within one unit of each other.
+pub fn ring_fragment_masses(mass: u32, max_mass: u32) -> Vec<u32> {
+    let max_mass = max_mass.max(1);
+    let count = mass.div_ceil(max_mass);
+    if count == 0 {
+        return Vec::new();
+    }
+    let (base, extra) = (mass / count, mass % count);
+    (0..count).map(|i| base + u32::from(i < extra)).collect()
+}
+
+/// Velocity with the radial part (relative to a planet at rest, along
+/// `radial_dir`) scaled by `1 − blend` and the tangential part moved
+/// `blend` of the way to `orbit_speed`, keeping its sense of rotation.
+pub fn circularize_velocity(
+    velocity: Vec2,
+    radial_dir: Vec2,
+    orbit_speed: f32,
+    blend: f32,
+) -> Vec2 {
+    let tangent = radial_dir.perp();
+    let radial = velocity.dot(radial_dir);
+    let tangential = velocity.dot(tangent);
+    // Counter-clockwise when the body has no tangential motion yet.
+    let target = if tangential < 0.0 {
+        -orbit_speed
+    } else {
+        orbit_speed
+    };
+    radial_dir * radial * (1.0 - blend) + tangent * (tangential + (target - tangential) * blend)
+}
+
+/// Break asteroids that are inside a planet's Roche radius and heavier than
+/// `ring_fragment_max_mass` into ring particles.
+#[allow(clippy::type_complexity)]
+pub fn tidal_disruption_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    mut stats: ResMut<SimulationStats>,
+    mut rng: ResMut<GameplayRng>,
+    q_planets: Query<(Entity, &Transform, &Vertices), With<Planet>>,
+    q_bodies: Query<
+        (
+            Entity,
+            &Transform,
+            &Velocity,
+            &AsteroidSize,
+            Option<&AsteroidMaterial>,
+        ),
+        (With<Asteroid>, Without<Planet>),
+    >,
+) {
+    let planets: Vec<(Entity, Vec2, f32)> = q_planets
+        .iter()
+        .map(|(entity, transform, vertices)| {
+            (
+                entity,
+                transform.translation.truncate(),
+                roche_radius(&vertices.0, &config),
+            )
+        })
+        .collect();
+    if planets.is_empty() {
+        return;
+    }
+
+    let max_mass = config.ring_fragment_max_mass.max(1);
+    for (entity, transform, velocity, size, material) in q_bodies.iter() {
+        if size.0 <= max_mass {
+            continue;
+        }
+        let pos = transform.translation.truncate();
+        let Some(&(planet, ..)) = planets
+            .iter()
+            .find(|(_, planet_pos, roche)| pos.distance_squared(*planet_pos) < roche * roche)
+        else {
+            continue;
+        };
+
+        commands.entity(entity).despawn();
+        stats.split_total += 1;
+
+        // Tidal stretching: string the pieces out along the direction of
+        // travel, so they share the parent's orbit.
+        let masses = ring_fragment_masses(size.0, max_mass);
+        let along = velocity.linvel.normalize_or(Vec2::X);
+        let lineage = MergeCooldown::from_split(entity);
+        let hulls: Vec<Vec<Vec2>> = masses
+            .iter()
+            .map(|&mass| fragment_vertices_for_mass(mass, &mut *rng, &config))
+            .collect();
+        let spacing = 2.0
+            * hulls
+                .iter()
+                .flatten()
+                .map(|v| v.length())
+                .fold(0.0, f32::max)
+            + 1.0;
+        let middle = (masses.len() as f32 - 1.0) / 2.0;
+        for (i, (&mass, hull)) in masses.iter().zip(&hulls).enumerate() {
+            let offset = along * (i as f32 - middle) * spacing;
+            let grey = 0.4 + visual_rng().gen::<f32>() * 0.4;
+            let fragment = spawn_asteroid_with_vertices(
+                &mut commands,
+                pos + offset,
+                hull,
+                Color::srgb(grey, grey, grey),
+                mass,
+            );
+            commands.entity(fragment).insert((
+                Velocity {
+                    linvel: velocity.linvel,
+                    angvel: velocity.angvel,
+                },
+                material.copied().unwrap_or_default(),
+                lineage,
+                RingParticle { planet },
+            ));
+        }
+    }
+}
+
+/// Ease ring particles onto circular orbits around their planet.
+pub fn ring_circularization_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    q_planets: Query<(&Transform, &AsteroidSize, &Vertices), With<Planet>>,
+    mut q_particles: Query<(Entity, &Transform, &mut Velocity, &RingParticle), Without<Planet>>,
+) {
+    let blend = 1.0 - (-config.ring_circularization_rate * time.delta_secs()).exp();
+    for (entity, transform, mut velocity, particle) in q_particles.iter_mut() {
+        let Ok((planet_transform, planet_size, planet_vertices)) = q_planets.get(particle.planet)
+        else {
+            commands.entity(entity).remove::<RingParticle>();
+            continue;
+        };
+        let offset = transform.translation.truncate() - planet_transform.translation.truncate();
+        let r = offset.length();
+        let zone = roche_radius(&planet_vertices.0, &config) * config.ring_zone_scale;
+        if r > zone || r <= f32::EPSILON {
+            commands.entity(entity).remove::<RingParticle>();
+            continue;
+        }
+        let orbit_speed = circular_orbit_speed(planet_size.0, r, &config);
+        velocity.linvel = circularize_velocity(velocity.linvel, offset / r, orbit_speed, blend);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fragments_are_as_few_and_even_as_the_cap_allows() {
+        assert_eq!(ring_fragment_masses(7, 2), vec![2, 2, 2, 1]);
+        assert_eq!(ring_fragment_masses(6, 2), vec![2, 2, 2]);
+        assert_eq!(ring_fragment_masses(10, 4), vec![4, 3, 3]);
+        assert_eq!(ring_fragment_masses(1, 2), vec![1]);
+        assert_eq!(ring_fragment_masses(3, 0), vec![1, 1, 1]);
+        assert!(ring_fragment_masses(0, 2).is_empty());
+    }
+
+    #[test]
+    fn circularization_removes_radial_motion_and_keeps_the_spin() {
+        let radial_dir = Vec2::X;
+        let eccentric = Vec2::new(-3.0, 1.5);
+        let mut v = eccentric;
+        for _ in 0..200 {
+            v = circularize_velocity(v, radial_dir, 4.0, 0.05);
+        }
+        assert!(v.x.abs() < 1e-3, "radial speed damped: {v}");
+        assert!((v.y - 4.0).abs() < 1e-3, "orbit speed reached: {v}");
+
+        let clockwise = circularize_velocity(Vec2::new(0.0, -1.0), radial_dir, 4.0, 1.0);
+        assert_eq!(clockwise, Vec2::new(0.0, -4.0));
+    }
+
+    fn spawn_planet_at_origin(app: &mut App) -> Entity {
+        let hull: Vec<Vec2> = (0..16)
+            .map(|i| Vec2::from_angle(std::f32::consts::TAU * i as f32 / 16.0) * 40.0)
+            .collect();
+        app.world_mut()
+            .spawn((
+                Asteroid,
+                Planet,
+                AsteroidSize(400),
+                Transform::default(),
+                Vertices(hull),
+            ))
+            .id()
+    }
+
+    #[test]
+    fn bodies_inside_the_roche_radius_break_into_ring_particles() {
+        let config = PhysicsConfig::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(config.clone())
+            .insert_resource(SimulationStats::default())
+            .init_resource::<GameplayRng>()
+            .add_systems(Update, tidal_disruption_system);
+        let planet = spawn_planet_at_origin(&mut app);
+        let roche = roche_radius(&app.world().get::<Vertices>(planet).unwrap().0, &config);
+        let heavy = config.ring_fragment_max_mass * 3;
+        let mut body = |pos: Vec2, mass: u32| {
+            app.world_mut()
+                .spawn((
+                    Asteroid,
+                    AsteroidSize(mass),
+                    Transform::from_translation(pos.extend(0.0)),
+                    Velocity::linear(Vec2::Y * 5.0),
+                ))
+                .id()
+        };
+        let inside = body(Vec2::new(roche * 0.9, 0.0), heavy);
+        let outside = body(Vec2::new(roche * 1.5, 0.0), heavy);
+        let small = body(Vec2::new(-roche * 0.9, 0.0), config.ring_fragment_max_mass);
+        app.update();
+
+        assert!(app.world().get_entity(inside).is_err(), "disrupted");
+        assert!(app.world().get_entity(outside).is_ok());
+        assert!(app.world().get::<RingParticle>(small).is_none());
+
+        let mut q = app
+            .world_mut()
+            .query::<(&RingParticle, &AsteroidSize, &Velocity, &MergeCooldown)>();
+        let fragments: Vec<_> = q.iter(app.world()).collect();
+        assert_eq!(fragments.len(), 3);
+        assert_eq!(fragments.iter().map(|f| f.1 .0).sum::<u32>(), heavy);
+        for (particle, size, velocity, cooldown) in fragments {
+            assert_eq!(particle.planet, planet);
+            assert!(size.0 <= config.ring_fragment_max_mass);
+            assert_eq!(velocity.linvel, Vec2::Y * 5.0);
+            assert_eq!(*cooldown, MergeCooldown::from_split(inside));
+        }
+        assert_eq!(app.world().resource::<SimulationStats>().split_total, 1);
+    }
+}
@@ -0,0 +1,235 @@
+//! Sector streaming: asteroids far from the ship are parked in a
+//! [`SectorStore`] instead of being despawned, and come back when the ship
+//! returns.
+//!
+//! The world is divided into square sectors of `sector_size` world units
+//! ([`sector_of`]).  Each frame [`sector_streaming_system`]:
+//!
+//! 1. serializes every asteroid farther than `sector_stream_out_radius` from
+//!    the streaming centre into its sector as an [`AsteroidSnapshot`] and
+//!    despawns it;
+//! 2. respawns every stored asteroid within `sector_stream_in_radius` of the
+//!    centre, scanning only the sectors that radius overlaps.
+//!
+//! The centre is the ship, or the world origin when there is none (test
+//! scenarios, the respawn countdown).  The stream-in radius is smaller than
+//! the stream-out radius so a body on the edge does not flicker between the
+//! two states.
+//!
+//! Stored asteroids are frozen: no gravity, collisions or drift until they
+//! are rehydrated with the velocity they had when they left.  Planets are
+//! never streamed.  The store is part of the save snapshot.
+
+use super::volatile::Volatile;
+use crate::asteroid::{Asteroid, AsteroidSize, Planet, Vertices};
+use crate::config::PhysicsConfig;
+use crate::lineage::Lineage;
+use crate::mining::AsteroidMaterial;
+use crate::player::Player;
+use crate::save::{spawn_asteroid_from_snapshot, AsteroidSnapshot, SectorSnapshot};
+use crate::spatial_partition::{sector_of, sectors_overlapping};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+use std::collections::HashMap;
+
+/// Asteroids parked outside the active radius, keyed by sector.
+#[derive(Resource, Debug, Default, Clone)]
+pub struct SectorStore {
+    sectors: HashMap<IVec2, Vec<AsteroidSnapshot>>,
+}
+
+impl SectorStore {
+    /// Park `asteroid` in the sector containing its position.
+    pub fn store(&mut self, asteroid: AsteroidSnapshot, sector_size: f32) {
+        let coord = sector_of(Vec2::from(asteroid.pos), sector_size);
+        self.sectors.entry(coord).or_default().push(asteroid);
+    }
+
+    /// Remove and return every stored asteroid within `radius` of `centre`.
+    pub fn take_within(
+        &mut self,
+        centre: Vec2,
+        radius: f32,
+        sector_size: f32,
+    ) -> Vec<AsteroidSnapshot> {
+        let radius_sq = radius * radius;
+        let mut taken = Vec::new();
+        for coord in sectors_overlapping(centre, radius, sector_size) {
+            let Some(stored) = self.sectors.get_mut(&coord) else {
+                continue;
+            };
+            let mut i = 0;
+            while i < stored.len() {
+                if Vec2::from(stored[i].pos).distance_squared(centre) <= radius_sq {
+                    taken.push(stored.swap_remove(i));
+                } else {
+                    i += 1;
+                }
+            }
+            if stored.is_empty() {
+                self.sectors.remove(&coord);
+            }
+        }
+        taken
+    }
+
+    /// Re-key every sector after the world origin moved to sector `chunks`.
+    pub fn shift(&mut self, chunks: IVec2, sector_size: f32) {
+        let delta = -chunks.as_vec2() * sector_size.max(1.0);
+        self.sectors = std::mem::take(&mut self.sectors)
+            .into_iter()
+            .map(|(coord, mut asteroids)| {
+                for asteroid in &mut asteroids {
+                    asteroid.pos = (Vec2::from(asteroid.pos) + delta).into();
+                }
+                (coord - chunks, asteroids)
+            })
+            .collect();
+    }
+
+    /// Number of asteroids currently parked.
+    pub fn stored_count(&self) -> usize {
+        self.sectors.values().map(Vec::len).sum()
+    }
+
+    /// Number of sectors holding at least one asteroid.
+    #[cfg(test)]
+    pub fn sector_count(&self) -> usize {
+        self.sectors.len()
+    }
+
+    /// Save-file form, sorted by sector so saves are stable.
+    pub fn to_snapshots(&self) -> Vec<SectorSnapshot> {
+        let mut out: Vec<SectorSnapshot> = self
+            .sectors
+            .iter()
+            .map(|(coord, asteroids)| SectorSnapshot {
+                coord: [coord.x, coord.y],
+                asteroids: asteroids.clone(),
+            })
+            .collect();
+        out.sort_by_key(|sector| sector.coord);
+        out
+    }
+
+    /// Rebuild the store from a save file.
+    pub fn from_snapshots(sectors: Vec<SectorSnapshot>) -> Self {
+        let mut store = Self::default();
+        for sector in sectors {
+            if sector.asteroids.is_empty() {
+                continue;
+            }
+            store
+                .sectors
+                .entry(IVec2::from(sector.coord))
+                .or_default()
+                .extend(sector.asteroids);
+        }
+        store
+    }
+}
+
+/// Park asteroids that left the active radius and rehydrate those the ship
+/// has come back to.
+#[allow(clippy::type_complexity)]
+pub fn sector_streaming_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    mut store: ResMut<SectorStore>,
+    mut stats: ResMut<super::SimulationStats>,
+    q_player: Query<&Transform, With<Player>>,
+    q_asteroids: Query<
+        (
+            Entity,
+            &Transform,
+            Option<&Velocity>,
+            &AsteroidSize,
+            &Vertices,
+            Option<&AsteroidMaterial>,
+            Option<&Lineage>,
+            Has<Volatile>,
+        ),
+        (With<Asteroid>, Without<Planet>),
+    >,
+) {
+    let centre = q_player
+        .single()
+        .map_or(Vec2::ZERO, |t| t.translation.truncate());
+    let sector_size = config.sector_size;
+    let out_sq = config.sector_stream_out_radius * config.sector_stream_out_radius;
+
+    for (entity, transform, velocity, size, vertices, material, lineage, volatile) in
+        q_asteroids.iter()
+    {
+        if transform.translation.truncate().distance_squared(centre) <= out_sq {
+            continue;
+        }
+        let velocity = velocity.copied().unwrap_or_default();
+        store.store(
+            AsteroidSnapshot::capture(
+                transform, &velocity, size, vertices, material, lineage, volatile,
+            ),
+            sector_size,
+        );
+        commands.entity(entity).despawn();
+        stats.culled_total += 1;
+    }
+
+    let radius = config
+        .sector_stream_in_radius
+        .min(config.sector_stream_out_radius);
+    for asteroid in store.take_within(centre, radius, sector_size) {
+        spawn_asteroid_from_snapshot(&mut commands, &asteroid);
+    }
+    stats.stored_count = store.stored_count() as u32;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parked(x: f32, y: f32) -> AsteroidSnapshot {
+        AsteroidSnapshot {
+            pos: [x, y],
+            rot: 0.0,
+            linvel: [0.0, 0.0],
+            angvel: 0.0,
+            size: 2,
+            vertices: vec![[0.0, 0.0], [4.0, 0.0], [0.0, 4.0]],
+            material: AsteroidMaterial::Ice,
+            lineage: None,
+            volatile: false,
+        }
+    }
+
+    #[test]
+    fn stored_asteroids_come_back_only_near_the_centre() {
+        let mut store = SectorStore::default();
+        store.store(parked(3_000.0, 0.0), 1_000.0);
+        store.store(parked(3_100.0, 50.0), 1_000.0);
+        store.store(parked(-9_000.0, 0.0), 1_000.0);
+        assert_eq!(store.stored_count(), 3);
+        assert_eq!(store.sector_count(), 2);
+
+        assert!(store.take_within(Vec2::ZERO, 2_000.0, 1_000.0).is_empty());
+        let back = store.take_within(Vec2::new(2_000.0, 0.0), 1_050.0, 1_000.0);
+        assert_eq!(back.len(), 1);
+        assert_eq!(back[0].pos, [3_000.0, 0.0]);
+        assert_eq!(store.stored_count(), 2);
+    }
+
+    #[test]
+    fn store_round_trips_through_save_snapshots() {
+        let mut store = SectorStore::default();
+        store.store(parked(3_000.0, 0.0), 1_000.0);
+        store.store(parked(-2_500.0, 4_000.0), 1_000.0);
+
+        let snapshots = store.to_snapshots();
+        assert_eq!(snapshots.len(), 2);
+        assert!(snapshots[0].coord < snapshots[1].coord);
+
+        let restored = SectorStore::from_snapshots(snapshots);
+        assert_eq!(restored.stored_count(), 2);
+        assert_eq!(restored.to_snapshots().len(), 2);
+    }
+}
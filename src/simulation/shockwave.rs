@@ -0,0 +1,149 @@
+//! Impact shockwaves: a radial push from missile destroys and large merges.
+//!
+//! Weapon and merge systems write a [`Shockwave`] message with the mass that
+//! was destroyed (or merged).  [`shockwave_force_system`] runs in the fixed
+//! force pass right after gravity has reset every asteroid's `ExternalForce`,
+//! and adds `impulse / dt` for a single tick, so each body receives exactly
+//! the impulse from [`shockwave_impulse`] whatever the tick rate.
+//!
+//! The impulse scales with mass by `shockwave_impulse_per_mass`, is capped at
+//! `shockwave_max_impulse`, and falls off linearly to zero at
+//! `shockwave_radius`.  Only asteroids are pushed (planets included, though
+//! their mass makes the push negligible); ships are left to their own
+//! thrust.  Each wave also spawns an expanding ring of particles.
+
+use crate::asteroid::Asteroid;
+use crate::config::PhysicsConfig;
+use crate::particles::spawn_shockwave_ring_particles;
+use crate::spatial_partition::SpatialGrid;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::ExternalForce;
+
+/// Below this distance a body is treated as the source itself and not pushed.
+const SHOCKWAVE_MIN_DIST: f32 = 1.0;
+
+/// A radial impulse centred on `origin`, scaled by `mass`.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct Shockwave {
+    pub origin: Vec2,
+    /// Destroyed or merged mass (`AsteroidSize` units).
+    pub mass: u32,
+}
+
+/// Impulse a body `offset` away from the origin of a wave of `mass` receives.
+pub fn shockwave_impulse(offset: Vec2, mass: u32, config: &PhysicsConfig) -> Vec2 {
+    let dist = offset.length();
+    if dist < SHOCKWAVE_MIN_DIST || dist >= config.shockwave_radius {
+        return Vec2::ZERO;
+    }
+    let peak = (mass as f32 * config.shockwave_impulse_per_mass).min(config.shockwave_max_impulse);
+    let falloff = 1.0 - dist / config.shockwave_radius;
+    offset / dist * peak * falloff
+}
+
+/// Apply this tick's shockwaves to nearby asteroids and spawn their rings.
+pub fn shockwave_force_system(
+    mut commands: Commands,
+    mut waves: MessageReader<Shockwave>,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    grid: Res<SpatialGrid>,
+    mut q_asteroids: Query<(&Transform, &mut ExternalForce), With<Asteroid>>,
+    mut nearby: Local<Vec<Entity>>,
+) {
+    let dt = time.delta_secs();
+    for wave in waves.read() {
+        spawn_shockwave_ring_particles(&mut commands, wave.origin, config.shockwave_radius);
+        if dt <= 0.0 {
+            continue;
+        }
+        grid.within_radius(wave.origin, config.shockwave_radius, &mut nearby);
+        for &entity in nearby.iter() {
+            let Ok((transform, mut force)) = q_asteroids.get_mut(entity) else {
+                continue;
+            };
+            let offset = transform.translation.truncate() - wave.origin;
+            let impulse = shockwave_impulse(offset, wave.mass, &config);
+            if impulse != Vec2::ZERO {
+                force.force += impulse / dt;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::time::TimeUpdateStrategy;
+
+    #[test]
+    fn impulse_points_away_and_falls_off_to_the_radius() {
+        let config = PhysicsConfig::default();
+        let near = shockwave_impulse(Vec2::new(10.0, 0.0), 5, &config);
+        let far = shockwave_impulse(Vec2::new(0.0, -config.shockwave_radius * 0.75), 5, &config);
+
+        assert!(near.x > 0.0 && near.y == 0.0);
+        assert!(far.y < 0.0 && far.x == 0.0);
+        assert!(near.length() > far.length());
+        assert_eq!(
+            shockwave_impulse(Vec2::new(config.shockwave_radius, 0.0), 5, &config),
+            Vec2::ZERO
+        );
+        assert_eq!(shockwave_impulse(Vec2::ZERO, 5, &config), Vec2::ZERO);
+    }
+
+    #[test]
+    fn impulse_scales_with_mass_up_to_the_cap() {
+        let config = PhysicsConfig::default();
+        let offset = Vec2::new(config.shockwave_radius * 0.5, 0.0);
+        let small = shockwave_impulse(offset, 2, &config).length();
+        let double = shockwave_impulse(offset, 4, &config).length();
+        assert!((double - 2.0 * small).abs() < 1e-3);
+
+        let huge = shockwave_impulse(offset, 100_000, &config).length();
+        assert!((huge - config.shockwave_max_impulse * 0.5).abs() < 1e-3);
+    }
+
+    #[test]
+    fn one_tick_of_force_delivers_the_impulse() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<Shockwave>()
+            .insert_resource(PhysicsConfig::default())
+            .insert_resource(SpatialGrid::default())
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_millis(16),
+            ))
+            .add_systems(Update, shockwave_force_system);
+        let body = app
+            .world_mut()
+            .spawn((
+                Asteroid,
+                Transform::from_xyz(40.0, 0.0, 0.0),
+                ExternalForce::default(),
+            ))
+            .id();
+        let mut grid = SpatialGrid::default();
+        grid.insert(body, Vec2::new(40.0, 0.0));
+        grid.build();
+        app.insert_resource(grid);
+
+        // The first update only starts the clock; the second has a delta.
+        app.update();
+        app.world_mut().write_message(Shockwave {
+            origin: Vec2::ZERO,
+            mass: 6,
+        });
+        app.update();
+
+        let dt = app.world().resource::<Time>().delta_secs();
+        let force = app.world().get::<ExternalForce>(body).unwrap().force;
+        let expected = shockwave_impulse(
+            Vec2::new(40.0, 0.0),
+            6,
+            app.world().resource::<PhysicsConfig>(),
+        );
+        assert!(dt > 0.0);
+        assert!((force * dt - expected).length() < 1e-2);
+    }
+}
@@ -0,0 +1,218 @@
+//! Size classes: accretion milestones as gameplay beats.
+//!
+//! A merge whose product reaches one of [`SIZE_CLASS_THRESHOLDS`] (10, 25, 50
+//! and 100 mass units) that none of its parts had reached writes a
+//! [`SizeClassReached`] message from `merge_resolution_system`.  Only merges
+//! count: the starting field, ring debris and split fragments never cross a
+//! threshold on the way up.
+//!
+//! [`size_class_system`] keeps the field's record in [`FieldSizeClass`].  The
+//! first body of each class in a session:
+//!
+//! - shows a line such as "A planetoid is forming…" under the mode HUD for
+//!   `size_class_announce_secs`;
+//! - raises the floor of the ambient rumble layer by `size_class_rumble_step`
+//!   per class ([`FieldSizeClass::rumble_floor`]);
+//! - advances `ScriptClock::size_class`, so campaign scripts can react with
+//!   `ScriptStep::on_size_class`.
+//!
+//! `session_stats_system` stamps the time each class was first reached for
+//! the session summary.  The record resets when a session starts and when a
+//! campaign mission loads.
+
+use crate::config::PhysicsConfig;
+use bevy::prelude::*;
+
+/// Mass thresholds of the size classes, smallest first.
+pub const SIZE_CLASS_THRESHOLDS: [u32; 4] = [10, 25, 50, 100];
+
+/// Milestone size of an accreted body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+pub enum SizeClass {
+    Boulder,
+    Moonlet,
+    Planetoid,
+    Protoplanet,
+}
+
+impl SizeClass {
+    pub const ALL: [SizeClass; 4] = [
+        SizeClass::Boulder,
+        SizeClass::Moonlet,
+        SizeClass::Planetoid,
+        SizeClass::Protoplanet,
+    ];
+
+    /// Smallest mass of the class.
+    pub fn threshold(self) -> u32 {
+        SIZE_CLASS_THRESHOLDS[self as usize]
+    }
+
+    /// 1 for the smallest class up to 4 for the largest.
+    pub fn rank(self) -> u8 {
+        self as u8 + 1
+    }
+
+    /// Largest class a body of `mass` belongs to, if any.
+    pub fn of_mass(mass: u32) -> Option<SizeClass> {
+        Self::ALL
+            .iter()
+            .rev()
+            .copied()
+            .find(|class| mass >= class.threshold())
+    }
+
+    /// Largest class reached by growing from `before` to `after`, if the
+    /// growth crossed any threshold.
+    pub fn crossed(before: u32, after: u32) -> Option<SizeClass> {
+        Self::of_mass(after).filter(|&class| before < class.threshold())
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SizeClass::Boulder => "Boulder",
+            SizeClass::Moonlet => "Moonlet",
+            SizeClass::Planetoid => "Planetoid",
+            SizeClass::Protoplanet => "Protoplanet",
+        }
+    }
+
+    /// HUD line shown when the field first reaches the class.
+    pub fn announcement(self) -> &'static str {
+        match self {
+            SizeClass::Boulder => "A boulder is forming…",
+            SizeClass::Moonlet => "A moonlet is forming…",
+            SizeClass::Planetoid => "A planetoid is forming…",
+            SizeClass::Protoplanet => "A protoplanet is forming…",
+        }
+    }
+}
+
+/// A merge produced a body that reached `class`.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct SizeClassReached {
+    /// The merged body.
+    pub entity: Entity,
+    pub class: SizeClass,
+    pub mass: u32,
+    pub pos: Vec2,
+}
+
+/// Largest size class reached in the field this session.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct FieldSizeClass {
+    pub reached: Option<SizeClass>,
+    /// HUD line on screen and its remaining seconds.
+    pub announcement: Option<(&'static str, f32)>,
+}
+
+impl FieldSizeClass {
+    /// Rank of the largest class reached, 0 for none.
+    pub fn rank(&self) -> u8 {
+        self.reached.map_or(0, SizeClass::rank)
+    }
+
+    /// Lowest level the ambient rumble layer settles to.
+    pub fn rumble_floor(&self, config: &PhysicsConfig) -> f32 {
+        (self.rank() as f32 * config.size_class_rumble_step).clamp(0.0, 1.0)
+    }
+
+    /// Record `class`; returns whether it is a new record for the field.
+    pub fn record(&mut self, class: SizeClass, announce_secs: f32) -> bool {
+        if self.reached.is_some_and(|reached| reached >= class) {
+            return false;
+        }
+        self.reached = Some(class);
+        self.announcement = Some((class.announcement(), announce_secs));
+        true
+    }
+}
+
+/// Raise the field record and announce new size classes.
+pub fn size_class_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    mut reached: MessageReader<SizeClassReached>,
+    mut field: ResMut<FieldSizeClass>,
+) {
+    if let Some((_, remaining)) = field.announcement.as_mut() {
+        *remaining -= time.delta_secs();
+    }
+    if field
+        .announcement
+        .is_some_and(|(_, remaining)| remaining <= 0.0)
+    {
+        field.announcement = None;
+    }
+    for event in reached.read() {
+        if field.record(event.class, config.size_class_announce_secs) {
+            info!(
+                "Size class {} reached: mass {} at {}",
+                event.class.label(),
+                event.mass,
+                event.pos
+            );
+        }
+    }
+}
+
+/// Start a fresh field record.
+pub fn reset_field_size_class_system(mut field: ResMut<FieldSizeClass>) {
+    *field = FieldSizeClass::default();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_growth_across_a_threshold_counts() {
+        assert_eq!(SizeClass::of_mass(9), None);
+        assert_eq!(SizeClass::of_mass(10), Some(SizeClass::Boulder));
+        assert_eq!(SizeClass::of_mass(99), Some(SizeClass::Planetoid));
+        assert_eq!(SizeClass::of_mass(400), Some(SizeClass::Protoplanet));
+
+        assert_eq!(SizeClass::crossed(8, 12), Some(SizeClass::Boulder));
+        assert_eq!(SizeClass::crossed(12, 20), None);
+        assert_eq!(SizeClass::crossed(20, 60), Some(SizeClass::Planetoid));
+        assert_eq!(SizeClass::crossed(60, 40), None);
+    }
+
+    #[test]
+    fn the_field_announces_each_class_once() {
+        let config = PhysicsConfig::default();
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .insert_resource(config.clone())
+            .init_resource::<FieldSizeClass>()
+            .add_message::<SizeClassReached>()
+            .add_systems(Update, size_class_system);
+        let reach = |app: &mut App, class: SizeClass| {
+            app.world_mut().write_message(SizeClassReached {
+                entity: Entity::PLACEHOLDER,
+                class,
+                mass: class.threshold(),
+                pos: Vec2::ZERO,
+            });
+            app.update();
+        };
+
+        reach(&mut app, SizeClass::Planetoid);
+        let field = app.world().resource::<FieldSizeClass>().clone();
+        assert_eq!(field.reached, Some(SizeClass::Planetoid));
+        assert_eq!(
+            field.announcement.map(|(text, _)| text),
+            Some("A planetoid is forming…")
+        );
+        assert!((field.rumble_floor(&config) - 3.0 * config.size_class_rumble_step).abs() < 1e-6);
+
+        // A smaller class later is not news.
+        app.world_mut()
+            .resource_mut::<FieldSizeClass>()
+            .announcement = None;
+        reach(&mut app, SizeClass::Moonlet);
+        let field = app.world().resource::<FieldSizeClass>();
+        assert_eq!(field.reached, Some(SizeClass::Planetoid));
+        assert_eq!(field.announcement, None);
+    }
+}
@@ -0,0 +1,220 @@
+//! Soft-body deformation: heavy asteroids dent instead of only chipping.
+//!
+//! Asteroids of at least `soft_body_min_mass` report contact forces above
+//! `soft_body_force_threshold` ([`soft_body_sensor_system`] arms them as they
+//! reach that mass).  [`soft_body_impact_system`] turns each such contact,
+//! once per contact, into a strain from [`impact_strain`] and reshapes both
+//! bodies with [`deform_hull_on_impact`]: the side facing the other body is
+//! flattened and the far side bulges out.  Area (so mass) is unchanged and
+//! the hull stays convex, so the collider is rebuilt from it directly.
+//!
+//! Repeated impacts keep flattening a body until it reaches
+//! `soft_body_min_aspect` along the impact axis.  Craters carved by weapons
+//! keep their body-frame positions and are re-applied to the new hull.
+
+use std::collections::HashSet;
+
+use crate::asteroid::{
+    apply_crater_deformation, collider_for_vertices, deform_hull_on_impact, polygon_area,
+    rescale_vertices_to_area, Asteroid, AsteroidSize, BaseVertices, CraterData, Planet, Vertices,
+};
+use crate::config::PhysicsConfig;
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::*;
+
+/// Strain of an impact with contact force `force`: zero at the threshold,
+/// rising towards `soft_body_max_strain` for the hardest impacts.
+pub fn impact_strain(force: f32, config: &PhysicsConfig) -> f32 {
+    let threshold = config.soft_body_force_threshold;
+    if force <= threshold || force <= 0.0 {
+        return 0.0;
+    }
+    config.soft_body_max_strain * (1.0 - threshold / force)
+}
+
+/// Make asteroids that have grown heavy enough to deform report contact
+/// forces.
+#[allow(clippy::type_complexity)]
+pub fn soft_body_sensor_system(
+    mut commands: Commands,
+    config: Res<PhysicsConfig>,
+    q_asteroids: Query<
+        (Entity, &AsteroidSize),
+        (With<Asteroid>, Without<Planet>, Changed<AsteroidSize>),
+    >,
+) {
+    for (entity, size) in q_asteroids.iter() {
+        if size.0 < config.soft_body_min_mass {
+            continue;
+        }
+        commands.entity(entity).try_insert((
+            ActiveEvents::COLLISION_EVENTS | ActiveEvents::CONTACT_FORCE_EVENTS,
+            ContactForceEventThreshold(config.soft_body_force_threshold),
+        ));
+    }
+}
+
+/// Deform heavy asteroids once per hard contact with another body.
+#[allow(clippy::type_complexity)]
+pub fn soft_body_impact_system(
+    mut commands: Commands,
+    mut collision_events: MessageReader<CollisionEvent>,
+    mut contact_force_events: MessageReader<ContactForceEvent>,
+    mut q_bodies: Query<
+        (
+            &Transform,
+            &AsteroidSize,
+            &mut Vertices,
+            &mut BaseVertices,
+            &CraterData,
+        ),
+        (With<Asteroid>, Without<Planet>),
+    >,
+    q_positions: Query<&Transform, With<Asteroid>>,
+    mut impacting: Local<HashSet<(Entity, Entity)>>,
+    config: Res<PhysicsConfig>,
+) {
+    for event in collision_events.read() {
+        if let CollisionEvent::Stopped(e1, e2, _) = event {
+            impacting.remove(&((*e1).min(*e2), (*e1).max(*e2)));
+        }
+    }
+
+    for event in contact_force_events.read() {
+        let (a, b) = (event.collider1, event.collider2);
+        let strain = impact_strain(event.total_force_magnitude, &config);
+        if strain <= 0.0 || !impacting.insert((a.min(b), a.max(b))) {
+            continue;
+        }
+        for (body, other) in [(a, b), (b, a)] {
+            let Ok(other_transform) = q_positions.get(other) else {
+                continue;
+            };
+            let other_pos = other_transform.translation.truncate();
+            let Ok((transform, size, mut vertices, mut base_vertices, craters)) =
+                q_bodies.get_mut(body)
+            else {
+                continue;
+            };
+            if size.0 < config.soft_body_min_mass {
+                continue;
+            }
+            let contact_local = transform
+                .rotation
+                .inverse()
+                .mul_vec3((other_pos - transform.translation.truncate()).extend(0.0))
+                .truncate();
+            let Some(hull) = deform_hull_on_impact(
+                &base_vertices.0,
+                contact_local,
+                strain,
+                config.soft_body_min_aspect,
+            ) else {
+                continue;
+            };
+
+            let area = polygon_area(&hull);
+            let cratered = apply_crater_deformation(&hull, &craters.craters, &config)
+                .unwrap_or_else(|| hull.clone());
+            vertices.0 = rescale_vertices_to_area(&cratered, area);
+            commands
+                .entity(body)
+                .try_insert(collider_for_vertices(&hull));
+            base_vertices.0 = hull;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asteroid::canonical_vertices_for_mass;
+
+    #[test]
+    fn strain_rises_from_zero_at_the_threshold_towards_the_cap() {
+        let config = PhysicsConfig::default();
+        let threshold = config.soft_body_force_threshold;
+        assert_eq!(impact_strain(threshold * 0.5, &config), 0.0);
+        assert_eq!(impact_strain(threshold, &config), 0.0);
+        let hard = impact_strain(threshold * 2.0, &config);
+        let harder = impact_strain(threshold * 20.0, &config);
+        assert!(hard > 0.0 && hard < harder);
+        assert!(harder < config.soft_body_max_strain);
+    }
+
+    fn spawn_body(app: &mut App, pos: Vec2, mass: u32) -> Entity {
+        let config = PhysicsConfig::default();
+        let hull = rescale_vertices_to_area(
+            &canonical_vertices_for_mass(mass),
+            mass as f32 / config.asteroid_density,
+        );
+        app.world_mut()
+            .spawn((
+                Asteroid,
+                AsteroidSize(mass),
+                Transform::from_translation(pos.extend(0.0)),
+                Vertices(hull.clone()),
+                BaseVertices(hull),
+                CraterData::default(),
+            ))
+            .id()
+    }
+
+    fn hit(app: &mut App, a: Entity, b: Entity, force: f32) {
+        app.world_mut().write_message(ContactForceEvent {
+            collider1: a,
+            collider2: b,
+            total_force: Vec2::X * force,
+            total_force_magnitude: force,
+            max_force_direction: Vec2::X,
+            max_force_magnitude: force,
+        });
+    }
+
+    #[test]
+    fn hard_contact_deforms_heavy_bodies_once_per_contact() {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<CollisionEvent>()
+            .add_message::<ContactForceEvent>()
+            .insert_resource(PhysicsConfig::default())
+            .add_systems(Update, soft_body_impact_system);
+        let config = PhysicsConfig::default();
+        let heavy = spawn_body(&mut app, Vec2::ZERO, config.soft_body_min_mass + 8);
+        let light = spawn_body(&mut app, Vec2::new(30.0, 0.0), 2);
+        let before = app.world().get::<BaseVertices>(heavy).unwrap().0.clone();
+        let light_before = app.world().get::<BaseVertices>(light).unwrap().0.clone();
+
+        hit(
+            &mut app,
+            heavy,
+            light,
+            config.soft_body_force_threshold * 4.0,
+        );
+        app.update();
+
+        let after = app.world().get::<BaseVertices>(heavy).unwrap().0.clone();
+        assert_ne!(after, before, "heavy body deformed");
+        assert!((polygon_area(&after) - polygon_area(&before)).abs() < 0.5);
+        let reach = |hull: &[Vec2]| hull.iter().map(|v| v.x).fold(f32::MIN, f32::max);
+        assert!(
+            reach(&after) < reach(&before),
+            "flattened towards the contact"
+        );
+        assert_eq!(
+            app.world().get::<BaseVertices>(light).unwrap().0,
+            light_before,
+            "light body keeps its shape"
+        );
+
+        // The same contact keeps pressing: no further deformation.
+        hit(
+            &mut app,
+            light,
+            heavy,
+            config.soft_body_force_threshold * 4.0,
+        );
+        app.update();
+        assert_eq!(app.world().get::<BaseVertices>(heavy).unwrap().0, after);
+    }
+}
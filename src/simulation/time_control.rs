@@ -0,0 +1,247 @@
+//! Player time control: slow motion and fast-forward.
+//!
+//! Holding Shift runs the simulation at `time_control_slow_scale` (0.25×);
+//! `V` toggles fast-forward at `time_control_fast_scale` (4×).  Slow motion
+//! wins while both are active.  Fast-forward sits on `V` because `T` is
+//! already target lock-on
+//! ([`lock_on_system`](crate::player::lock_on_system)).
+//! [`time_control_system`] turns the input into [`TimeControl::scale`] and
+//! applies it in two places:
+//!
+//! - `Time<Virtual>` runs at the scale, so every system that reads `Time`
+//!   (cooldowns, particles, timers) slows down or speeds up with the world.
+//!   Menus and the HUD are not affected.
+//! - [`sync_physics_tick_rate_system`](super::fixed_step::sync_physics_tick_rate_system)
+//!   scales the fixed timestep and Rapier's `dt` by the same factor.  Ticks
+//!   therefore keep arriving at `physics_tick_hz` in real time: slow motion
+//!   stays smooth instead of stepping every fourth frame, and fast-forward
+//!   costs no extra ticks.  Substeps grow with the scale, so each Rapier
+//!   substep is never longer than at normal speed.
+//!
+//! Time control is locked to 1× while any enemy ship is within
+//! `time_control_danger_radius` of the player.  The kill-cam owns
+//! `Time<Virtual>` while it runs, and the scale drops to 1× until it ends.
+//!
+//! A [`HitStop`] from `feedback` multiplies the virtual speed only, not
+//! [`TimeControl::scale`]: the fixed timestep keeps its length, so physics
+//! ticks nearly stop for the hit-stop's few real-time frames.
+
+use crate::config::PhysicsConfig;
+use crate::enemy::{Boss, Enemy};
+use crate::feedback::HitStop;
+use crate::player::state::KillCamState;
+use crate::player::Player;
+use bevy::prelude::*;
+
+/// Player time-control input and the scale currently applied.
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Resource)]
+pub struct TimeControl {
+    /// Fast-forward toggled on with `V`.
+    pub fast_forward: bool,
+    /// Shift held this frame.
+    pub slow_held: bool,
+    /// An enemy is within the danger radius; the scale is forced to 1×.
+    pub locked: bool,
+    /// Simulation speed multiplier in effect.
+    pub scale: f32,
+}
+
+impl Default for TimeControl {
+    fn default() -> Self {
+        Self {
+            fast_forward: false,
+            slow_held: false,
+            locked: false,
+            scale: 1.0,
+        }
+    }
+}
+
+impl TimeControl {
+    /// Scale the current input asks for, ignoring the danger lock.
+    pub fn requested_scale(&self, config: &PhysicsConfig) -> f32 {
+        if !config.time_control_enabled {
+            1.0
+        } else if self.slow_held {
+            config.time_control_slow_scale.clamp(0.05, 1.0)
+        } else if self.fast_forward {
+            config.time_control_fast_scale.clamp(1.0, 8.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// HUD indicator text; `None` at normal speed.
+    pub fn hud_label(&self, config: &PhysicsConfig) -> Option<String> {
+        let requested = self.requested_scale(config);
+        if self.locked && requested != 1.0 {
+            Some("TIME LOCKED · ENEMIES NEAR".to_string())
+        } else if self.scale < 1.0 {
+            Some(format!("SLOW {}x", self.scale))
+        } else if self.scale > 1.0 {
+            Some(format!("FAST-FORWARD {}x", self.scale))
+        } else {
+            None
+        }
+    }
+}
+
+/// Read the time-control keys, apply the danger lock, and set the scale.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+pub fn time_control_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    config: Res<PhysicsConfig>,
+    kill_cam: Res<KillCamState>,
+    hit_stop: Res<HitStop>,
+    q_player: Query<&Transform, With<Player>>,
+    q_enemies: Query<&Transform, (Or<(With<Enemy>, With<Boss>)>, Without<Player>)>,
+    mut control: ResMut<TimeControl>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    let mut next = *control;
+    next.slow_held = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    if keys.just_pressed(KeyCode::KeyV) {
+        next.fast_forward = !next.fast_forward;
+    }
+
+    let danger_sq = config.time_control_danger_radius.max(0.0).powi(2);
+    next.locked = q_player.single().is_ok_and(|player| {
+        let pos = player.translation.truncate();
+        q_enemies
+            .iter()
+            .any(|enemy| enemy.translation.truncate().distance_squared(pos) <= danger_sq)
+    });
+
+    if kill_cam.is_active() {
+        next.scale = 1.0;
+        if next != *control {
+            *control = next;
+        }
+        return;
+    }
+
+    next.scale = if next.locked {
+        1.0
+    } else {
+        next.requested_scale(&config)
+    };
+    if next != *control {
+        *control = next;
+    }
+    let speed = next.scale * hit_stop.speed_factor();
+    if virtual_time.relative_speed() != speed {
+        virtual_time.set_relative_speed(speed);
+    }
+}
+
+/// Return to normal speed with fast-forward off.
+///
+/// Runs on `OnEnter(MainMenu)` so a new session never starts fast-forwarded.
+pub fn reset_time_control_system(
+    mut control: ResMut<TimeControl>,
+    mut virtual_time: ResMut<Time<Virtual>>,
+) {
+    *control = TimeControl::default();
+    virtual_time.set_relative_speed(1.0);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_time_control_test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins);
+        app.insert_resource(PhysicsConfig::default());
+        app.insert_resource(KillCamState::default());
+        app.insert_resource(HitStop::default());
+        app.insert_resource(TimeControl::default());
+        app.insert_resource(ButtonInput::<KeyCode>::default());
+        app.add_systems(Update, time_control_system);
+        app.world_mut().spawn((Player, Transform::default()));
+        app
+    }
+
+    fn press(app: &mut App, key: KeyCode) {
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(key);
+        keys.clear();
+        keys.press(key);
+    }
+
+    #[test]
+    fn shift_slows_and_v_toggles_fast_forward() {
+        let mut app = build_time_control_test_app();
+
+        press(&mut app, KeyCode::KeyV);
+        app.update();
+        assert_eq!(app.world().resource::<TimeControl>().scale, 4.0);
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().relative_speed(),
+            4.0
+        );
+
+        // Slow motion wins while both are active.
+        press(&mut app, KeyCode::ShiftLeft);
+        app.update();
+        assert_eq!(app.world().resource::<TimeControl>().scale, 0.25);
+
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .release(KeyCode::ShiftLeft);
+        app.update();
+        assert_eq!(app.world().resource::<TimeControl>().scale, 4.0);
+
+        press(&mut app, KeyCode::KeyV);
+        app.update();
+        assert_eq!(app.world().resource::<TimeControl>().scale, 1.0);
+    }
+
+    #[test]
+    fn nearby_enemies_lock_time_to_normal_speed() {
+        let mut app = build_time_control_test_app();
+        let enemy = app
+            .world_mut()
+            .spawn((Enemy, Transform::from_xyz(300.0, 0.0, 0.0)))
+            .id();
+
+        press(&mut app, KeyCode::KeyV);
+        app.update();
+        let control = *app.world().resource::<TimeControl>();
+        assert!(control.locked && control.fast_forward);
+        assert_eq!(control.scale, 1.0);
+        assert!(control
+            .hud_label(&PhysicsConfig::default())
+            .is_some_and(|label| label.contains("LOCKED")));
+
+        // The toggle survives the lock and resumes once the enemy is gone.
+        app.world_mut().despawn(enemy);
+        app.world_mut()
+            .resource_mut::<ButtonInput<KeyCode>>()
+            .clear();
+        app.update();
+        assert_eq!(app.world().resource::<TimeControl>().scale, 4.0);
+    }
+
+    #[test]
+    fn hit_stop_slows_virtual_time_without_changing_the_scale() {
+        let mut app = build_time_control_test_app();
+        app.world_mut()
+            .resource_mut::<HitStop>()
+            .trigger(0.08, 0.05);
+        app.update();
+        assert_eq!(app.world().resource::<TimeControl>().scale, 1.0);
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().relative_speed(),
+            0.05
+        );
+
+        *app.world_mut().resource_mut::<HitStop>() = HitStop::default();
+        app.update();
+        assert_eq!(
+            app.world().resource::<Time<Virtual>>().relative_speed(),
+            1.0
+        );
+    }
+}
@@ -0,0 +1,530 @@
+//! Volatile asteroids: rare bodies that detonate when hit.
+//!
+//! A fresh asteroid has a `volatile_asteroid_fraction` chance of being
+//! [`Volatile`] (see `mining::assign_asteroid_material_system`); the flag
+//! survives sector parking, saves and merges.  Any weapon hit on one lights a
+//! [`VolatileFuse`] instead of chipping or splitting it.
+//!
+//! When the fuse runs out [`volatile_fuse_system`] detonates the body:
+//! - it is destroyed and drops its ore, and a [`Shockwave`] of its mass pushes
+//!   the surrounding asteroids;
+//! - asteroids of at most `volatile_blast_destroy_size` within
+//!   `volatile_blast_radius` are destroyed and drop their ore;
+//! - other volatiles in the radius are lit with a `volatile_chain_delay_secs`
+//!   fuse, so a packed cluster goes off as a visible chain;
+//! - a [`VolatileBlast`] message is written for [`volatile_blast_ship_system`],
+//!   which damages and pushes the player and enemy ships in the radius.
+//!
+//! Every kill is credited to whoever lit the first fuse of the chain (see
+//! [`crate::attribution`]).
+//!
+//! Damage and push fall off linearly to zero at the radius.  Volatiles render
+//! in a hazard orange with a pulsing warning ring that quickens once lit.
+
+use std::collections::HashSet;
+
+use super::shockwave::Shockwave;
+use super::SimulationStats;
+use crate::asteroid::{Asteroid, AsteroidSize, Planet, Vertices};
+use crate::asteroid_rendering::instancing::{HullInstanceSlot, HullInstances};
+use crate::asteroid_rendering::AsteroidRenderHandles;
+use crate::attribution::{Actor, KillCredited, KillVictim};
+use crate::audio::{play_sfx, Sfx};
+use crate::config::PhysicsConfig;
+use crate::enemy::{Enemy, EnemyArchetype, EnemyHealth};
+use crate::graphics::palette::{Palette, PaletteKind};
+use crate::menu::GameState;
+use crate::mining::{spawn_ore_drop, AsteroidMaterial};
+use crate::particles::spawn_debris_particles;
+use crate::player::{Player, PlayerHealth, PlayerLives, PlayerScore, PlayerShield};
+use crate::rng::GameplayRng;
+use crate::spatial_partition::SpatialGrid;
+use crate::wave_telemetry::{DamageSource, PlayerDamaged};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+use rand::Rng;
+
+/// Warning ring pulse rate (Hz) while idle and once the fuse is lit.
+const GLOW_PULSE_HZ: f32 = 1.2;
+const GLOW_LIT_PULSE_HZ: f32 = 8.0;
+
+/// Marks an asteroid that detonates when hit.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+#[reflect(Component)]
+pub struct Volatile;
+
+/// A lit volatile; it detonates once `secs` reaches zero.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Reflect)]
+#[reflect(Component)]
+pub struct VolatileFuse {
+    pub secs: f32,
+    /// Who lit it; credited with the blast's kills.
+    pub by: Actor,
+}
+
+/// A volatile lit by `by` went off at `origin`.
+#[derive(Message, Debug, Clone, Copy, PartialEq)]
+pub struct VolatileBlast {
+    pub origin: Vec2,
+    pub by: Actor,
+}
+
+/// Light the fuse of a volatile asteroid that was just hit by `by`.  A fuse
+/// that is already burning (and its credit) is left alone.
+pub fn ignite_volatile(commands: &mut Commands, entity: Entity, secs: f32, by: Actor) {
+    commands
+        .entity(entity)
+        .try_insert_if_new(VolatileFuse { secs, by });
+}
+
+/// Blast strength at `dist` from the centre: 1 at the centre, falling
+/// linearly to 0 at `radius`.
+pub fn blast_falloff(dist: f32, radius: f32) -> f32 {
+    if radius <= 0.0 {
+        return 0.0;
+    }
+    (1.0 - dist / radius).clamp(0.0, 1.0)
+}
+
+/// Destroy an asteroid caught in a blast: ore drops, debris, one destroy.
+#[allow(clippy::too_many_arguments)]
+fn shatter(
+    commands: &mut Commands,
+    entity: Entity,
+    pos: Vec2,
+    vel: Vec2,
+    size: u32,
+    material: AsteroidMaterial,
+    config: &PhysicsConfig,
+    rng: &mut impl Rng,
+) {
+    commands.entity(entity).despawn();
+    let drop_count = size.max(1);
+    for i in 0..drop_count {
+        let angle = std::f32::consts::TAU * (i as f32 / drop_count as f32);
+        let offset = Vec2::new(angle.cos(), angle.sin()) * 6.0;
+        spawn_ore_drop(
+            commands,
+            pos + offset,
+            vel,
+            material.ore_kind(),
+            config,
+            rng,
+        );
+    }
+    spawn_debris_particles(commands, pos, vel, size + 2);
+}
+
+/// Burn down lit fuses and detonate the volatiles whose fuse ran out.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn volatile_fuse_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    grid: Res<SpatialGrid>,
+    mut stats: ResMut<SimulationStats>,
+    mut rng: ResMut<GameplayRng>,
+    mut q_fused: Query<(
+        Entity,
+        &mut VolatileFuse,
+        &Transform,
+        &AsteroidSize,
+        Option<&Velocity>,
+        Option<&AsteroidMaterial>,
+    )>,
+    q_asteroids: Query<
+        (
+            &Transform,
+            &AsteroidSize,
+            Option<&Velocity>,
+            Option<&AsteroidMaterial>,
+            Has<Volatile>,
+        ),
+        (With<Asteroid>, Without<Planet>, Without<VolatileFuse>),
+    >,
+    mut shockwaves: MessageWriter<Shockwave>,
+    mut blasts: MessageWriter<VolatileBlast>,
+    mut kills: MessageWriter<KillCredited>,
+    mut nearby: Local<Vec<Entity>>,
+) {
+    let dt = time.delta_secs();
+    let mut detonating = Vec::new();
+    for (entity, mut fuse, transform, size, velocity, material) in q_fused.iter_mut() {
+        fuse.secs -= dt;
+        if fuse.secs <= 0.0 {
+            detonating.push((
+                entity,
+                transform.translation.truncate(),
+                size.0,
+                velocity.map_or(Vec2::ZERO, |v| v.linvel),
+                material.copied().unwrap_or_default(),
+                fuse.by,
+            ));
+        }
+    }
+
+    let mut consumed: HashSet<Entity> = HashSet::new();
+    for (entity, pos, size, vel, material, by) in detonating {
+        let kill = KillCredited {
+            victim: KillVictim::Asteroid,
+            by,
+            indirect: true,
+        };
+        shatter(
+            &mut commands,
+            entity,
+            pos,
+            vel,
+            size,
+            material,
+            &config,
+            &mut *rng,
+        );
+        stats.destroyed_total += 1;
+        kills.write(kill);
+        shockwaves.write(Shockwave {
+            origin: pos,
+            mass: size,
+        });
+        blasts.write(VolatileBlast { origin: pos, by });
+        play_sfx(&mut commands, Sfx::Explosion, 0.8);
+
+        grid.query_neighbors_into(
+            Entity::PLACEHOLDER,
+            pos,
+            config.volatile_blast_radius,
+            &mut nearby,
+        );
+        for &other in nearby.iter() {
+            if consumed.contains(&other) {
+                continue;
+            }
+            let Ok((transform, other_size, velocity, other_material, volatile)) =
+                q_asteroids.get(other)
+            else {
+                continue;
+            };
+            let other_pos = transform.translation.truncate();
+            if other_pos.distance(pos) >= config.volatile_blast_radius {
+                continue;
+            }
+            if volatile {
+                ignite_volatile(&mut commands, other, config.volatile_chain_delay_secs, by);
+                consumed.insert(other);
+            } else if other_size.0 <= config.volatile_blast_destroy_size {
+                shatter(
+                    &mut commands,
+                    other,
+                    other_pos,
+                    velocity.map_or(Vec2::ZERO, |v| v.linvel),
+                    other_size.0,
+                    other_material.copied().unwrap_or_default(),
+                    &config,
+                    &mut *rng,
+                );
+                stats.destroyed_total += 1;
+                kills.write(kill);
+                consumed.insert(other);
+            }
+        }
+    }
+}
+
+/// Damage and push the player and enemy ships caught in this frame's blasts.
+///
+/// Player damage goes through the shield and respects invincibility frames
+/// like an asteroid impact; the push always applies.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn volatile_blast_ship_system(
+    mut commands: Commands,
+    mut blasts: MessageReader<VolatileBlast>,
+    config: Res<PhysicsConfig>,
+    mut q_player: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Velocity,
+            &mut PlayerHealth,
+            Option<&mut PlayerShield>,
+        ),
+        (With<Player>, Without<Enemy>),
+    >,
+    mut q_enemies: Query<
+        (
+            Entity,
+            &Transform,
+            &mut Velocity,
+            &mut EnemyHealth,
+            Option<&EnemyArchetype>,
+        ),
+        (With<Enemy>, Without<Player>),
+    >,
+    mut lives: ResMut<PlayerLives>,
+    mut score: ResMut<PlayerScore>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut damage_events: MessageWriter<PlayerDamaged>,
+    mut kills: MessageWriter<KillCredited>,
+) {
+    let radius = config.volatile_blast_radius;
+    for blast in blasts.read() {
+        for (entity, transform, mut velocity, mut health, archetype) in q_enemies.iter_mut() {
+            if health.hp <= 0.0 {
+                continue;
+            }
+            let offset = transform.translation.truncate() - blast.origin;
+            let strength = blast_falloff(offset.length(), radius);
+            if strength <= 0.0 {
+                continue;
+            }
+            velocity.linvel += offset.normalize_or_zero() * config.volatile_blast_push * strength;
+            health.hp -= config.volatile_blast_damage * strength;
+            if health.hp <= 0.0 {
+                commands.entity(entity).despawn();
+                play_sfx(&mut commands, Sfx::Explosion, 0.8);
+                kills.write(KillCredited {
+                    victim: KillVictim::Enemy(archetype.copied().unwrap_or(EnemyArchetype::Chaser)),
+                    by: blast.by,
+                    indirect: true,
+                });
+            }
+        }
+
+        let Ok((player_entity, transform, mut velocity, mut health, shield)) =
+            q_player.single_mut()
+        else {
+            continue;
+        };
+        let offset = transform.translation.truncate() - blast.origin;
+        let strength = blast_falloff(offset.length(), radius);
+        if strength <= 0.0 {
+            continue;
+        }
+        let away = offset.normalize_or_zero();
+        velocity.linvel += away * config.volatile_blast_push * strength;
+        if health.inv_timer > 0.0 {
+            continue;
+        }
+
+        let mut damage =
+            config.volatile_blast_damage * strength * config.player_damage_taken_multiplier;
+        if let Some(mut shield) = shield {
+            let forward = transform.rotation.mul_vec3(Vec3::Y).truncate();
+            // The blast hits the ship from the side facing the origin.
+            damage = shield.absorb(damage, -away, forward, &config);
+        }
+        health.inv_timer = config.invincibility_duration;
+        if damage <= 0.0 {
+            continue;
+        }
+        health.hp -= damage;
+        health.time_since_damage = 0.0;
+        damage_events.write(PlayerDamaged {
+            amount: damage,
+            source: DamageSource::Asteroid,
+            fatal: health.hp <= 0.0,
+        });
+        if health.hp <= 0.0 {
+            commands.entity(player_entity).despawn();
+            play_sfx(&mut commands, Sfx::Explosion, 1.0);
+            lives.remaining -= 1;
+            score.streak = 0;
+            if lives.remaining <= 0 {
+                lives.remaining = 0;
+                next_state.set(GameState::GameOver);
+            } else {
+                lives.respawn_timer = Some(config.respawn_delay_secs);
+            }
+            break;
+        }
+    }
+}
+
+/// Give volatiles their hazard fill once their render handles exist.
+#[allow(clippy::type_complexity)]
+pub fn volatile_tint_system(
+    mut q_volatiles: Query<
+        (
+            &mut AsteroidRenderHandles,
+            Option<&mut MeshMaterial2d<ColorMaterial>>,
+            Option<&mut HullInstanceSlot>,
+        ),
+        (
+            With<Volatile>,
+            Or<(Added<Volatile>, Added<AsteroidRenderHandles>)>,
+        ),
+    >,
+    mut materials: ResMut<Assets<ColorMaterial>>,
+    mut hull_instances: ResMut<HullInstances>,
+    palette: Res<Palette>,
+    mut volatile_material: Local<Option<(PaletteKind, Handle<ColorMaterial>)>>,
+) {
+    for (mut handles, active, slot) in q_volatiles.iter_mut() {
+        // Rebuilt when the palette changes between sessions.
+        let material = match volatile_material.as_ref() {
+            Some((kind, handle)) if *kind == palette.kind => handle.clone(),
+            _ => {
+                let handle = materials.add(ColorMaterial::from_color(palette.volatile));
+                *volatile_material = Some((palette.kind, handle.clone()));
+                handle
+            }
+        };
+        if let Some(mut active) = active {
+            if active.0 == handles.fill_material {
+                active.0 = material.clone();
+            }
+        }
+        if let Some(mut slot) = slot {
+            slot.color = palette.volatile;
+            hull_instances.set_color(slot.index, slot.color);
+        }
+        handles.fill_material = material;
+    }
+}
+
+/// Draw a pulsing warning ring around every volatile; lit ones pulse faster.
+pub fn volatile_glow_system(
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+    palette: Res<Palette>,
+    q_volatiles: Query<(&Transform, &Vertices, Has<VolatileFuse>), With<Volatile>>,
+) {
+    let t = time.elapsed_secs();
+    for (transform, vertices, lit) in q_volatiles.iter() {
+        let hz = if lit {
+            GLOW_LIT_PULSE_HZ
+        } else {
+            GLOW_PULSE_HZ
+        };
+        let pulse = 0.5 + 0.5 * (t * hz * std::f32::consts::TAU).sin();
+        let radius = vertices.0.iter().map(|v| v.length()).fold(0.0, f32::max);
+        gizmos.circle_2d(
+            Isometry2d::from_translation(transform.translation.truncate()),
+            radius + 3.0 + 3.0 * pulse,
+            palette.volatile.with_alpha(0.35 + 0.5 * pulse),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::session_stats::StatWeapon;
+    use bevy::time::TimeUpdateStrategy;
+
+    #[test]
+    fn falloff_is_full_at_the_centre_and_zero_at_the_radius() {
+        assert_eq!(blast_falloff(0.0, 100.0), 1.0);
+        assert_eq!(blast_falloff(25.0, 100.0), 0.75);
+        assert_eq!(blast_falloff(100.0, 100.0), 0.0);
+        assert_eq!(blast_falloff(250.0, 100.0), 0.0);
+        assert_eq!(blast_falloff(10.0, 0.0), 0.0);
+    }
+
+    fn blast_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .add_message::<Shockwave>()
+            .add_message::<VolatileBlast>()
+            .add_message::<KillCredited>()
+            .insert_resource(PhysicsConfig::default())
+            .insert_resource(SimulationStats::default())
+            .init_resource::<GameplayRng>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(
+                std::time::Duration::from_millis(50),
+            ))
+            .add_systems(Update, volatile_fuse_system);
+        app
+    }
+
+    fn spawn_rock(app: &mut App, pos: Vec2, size: u32, volatile: bool) -> Entity {
+        let mut entity = app.world_mut().spawn((
+            Asteroid,
+            AsteroidSize(size),
+            Transform::from_translation(pos.extend(0.0)),
+        ));
+        if volatile {
+            entity.insert(Volatile);
+        }
+        entity.id()
+    }
+
+    fn index(app: &mut App, bodies: &[(Entity, Vec2)]) {
+        let mut grid = SpatialGrid::default();
+        for &(entity, pos) in bodies {
+            grid.insert(entity, pos);
+        }
+        grid.build();
+        app.insert_resource(grid);
+    }
+
+    #[test]
+    fn detonation_destroys_small_rocks_and_lights_nearby_volatiles() {
+        let mut app = blast_app();
+        let config = PhysicsConfig::default();
+        let radius = config.volatile_blast_radius;
+        let small = config.volatile_blast_destroy_size;
+
+        let source = spawn_rock(&mut app, Vec2::ZERO, 2, true);
+        let pebble_at = Vec2::new(radius * 0.5, 0.0);
+        let pebble = spawn_rock(&mut app, pebble_at, small, false);
+        let boulder_at = Vec2::new(0.0, radius * 0.5);
+        let boulder = spawn_rock(&mut app, boulder_at, small + 10, false);
+        let neighbour_at = Vec2::new(-radius * 0.6, 0.0);
+        let neighbour = spawn_rock(&mut app, neighbour_at, 2, true);
+        let distant_at = Vec2::new(radius * 3.0, 0.0);
+        let distant = spawn_rock(&mut app, distant_at, 2, true);
+        index(
+            &mut app,
+            &[
+                (source, Vec2::ZERO),
+                (pebble, pebble_at),
+                (boulder, boulder_at),
+                (neighbour, neighbour_at),
+                (distant, distant_at),
+            ],
+        );
+
+        let by = Actor::Player(StatWeapon::Missile);
+        app.update();
+        app.world_mut()
+            .entity_mut(source)
+            .insert(VolatileFuse { secs: 0.0, by });
+        app.update();
+
+        let world = app.world();
+        assert!(world.get_entity(source).is_err(), "source detonated");
+        assert!(world.get_entity(pebble).is_err(), "small rock destroyed");
+        assert!(world.get_entity(boulder).is_ok(), "large rock survives");
+        assert_eq!(
+            world.get::<VolatileFuse>(neighbour).copied(),
+            Some(VolatileFuse {
+                secs: config.volatile_chain_delay_secs,
+                by,
+            }),
+            "nearby volatile lit with the chain delay and the same credit"
+        );
+        assert!(world.get::<VolatileFuse>(distant).is_none());
+        assert_eq!(world.resource::<SimulationStats>().destroyed_total, 2);
+        let kills: Vec<_> = world
+            .resource::<Messages<KillCredited>>()
+            .iter_current_update_messages()
+            .copied()
+            .collect();
+        let kill = KillCredited {
+            victim: KillVictim::Asteroid,
+            by,
+            indirect: true,
+        };
+        assert_eq!(kills, vec![kill, kill], "source and pebble credited");
+
+        // The chained volatile goes off once its delay has burned down.
+        let steps = (config.volatile_chain_delay_secs / 0.05).ceil() as usize + 1;
+        for _ in 0..steps {
+            app.update();
+        }
+        assert!(
+            app.world().get_entity(neighbour).is_err(),
+            "chain continued"
+        );
+    }
+}
@@ -16,15 +16,47 @@
 //! Query cost: O(K + log N) — exact Euclidean sphere test with subtree pruning.
 //! Zero per-frame heap allocations after the first frame.
 //!
-//! ## API compatibility
+//! ## Incremental updates
 //!
-//! The public API (`SpatialGrid`, `get_neighbors_excluding`,
-//! `rebuild_spatial_grid_system`) is identical to the old grid implementation
-//! so all call-sites require no changes.
+//! Most asteroids barely move between ticks, so
+//! [`update_in_place`](SpatialGrid::update_in_place) keeps the tree and only
+//! moves what it must:
+//!
+//! - Each node remembers its *anchor*, the position it was placed at; its
+//!   split plane stays on the anchor.  Its current position is refreshed
+//!   every tick.
+//! - A body that drifts more than the tolerance from its anchor on either
+//!   axis is tombstoned and reinserted as a new leaf.  New bodies are
+//!   inserted the same way; despawned ones are tombstoned.
+//! - Queries test current positions and widen every split test by the
+//!   largest drift still in the tree (`slack`), so results stay exact.
+//! - When the deepest leaf is more than `spatial_rebuild_imbalance` times the
+//!   balanced depth, or dead nodes push the node count past that multiple of
+//!   the live count, the tree is rebuilt from scratch.  So is it when more
+//!   than half the bodies moved (origin recentering, loads).
+//!
+//! ## Query API
+//!
+//! | Query                   | Returns                                          | Used by |
+//! |-------------------------|--------------------------------------------------|---------|
+//! | `query_neighbors_into`  | Entities within a radius, minus one entity       | Gravity, neighbor counts, clusters, volatile chains |
+//! | `within_radius`         | Entities within a radius                         | Missile homing, lock-on, turrets, hover tooltip, ambient mix, shockwaves, ore magnet |
+//! | `nearest_n`             | The `n` nearest entities with squared distances  | Middle-click inspector selection |
+//! | `ray_query`             | Entities within a margin of a segment            | Mining laser, enemy obstacle avoidance |
+//! | `iter`                  | Every indexed entity and its position            | Density field |
+//!
+//! Every query fills a caller-owned buffer, cleared first, so hot paths can
+//! keep one in a `Local` and never allocate.  The [`SpatialGrid`] resource
+//! indexes only asteroids (and planets); the ore magnet keeps a second,
+//! private index of ore pickups.
 
+use crate::alloc_profile::AllocTag;
 use crate::asteroid::Asteroid;
+use crate::config::PhysicsConfig;
+use crate::simulation::SimulationStats;
 use bevy::prelude::*;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 
 // ── Flat KD-tree node ─────────────────────────────────────────────────────────
 
@@ -34,9 +66,49 @@ const NULL_IDX: u32 = u32::MAX;
 #[derive(Clone)]
 struct KdFlat {
     entity: Entity,
+    /// Current position, refreshed by every update.
     pos: Vec2,
+    /// Position the node was placed at; the split plane lies on it.
+    anchor: Vec2,
     left: u32,  // index into SpatialGrid::nodes, or NULL_IDX
     right: u32, // index into SpatialGrid::nodes, or NULL_IDX
+    /// Removed or reinserted elsewhere; still splits space until the next
+    /// full rebuild, but never returned.
+    dead: bool,
+    /// Update stamp of the last tick the entity was seen in.
+    seen: u32,
+}
+
+impl KdFlat {
+    fn new(entity: Entity, pos: Vec2, seen: u32) -> Self {
+        Self {
+            entity,
+            pos,
+            anchor: pos,
+            left: NULL_IDX,
+            right: NULL_IDX,
+            dead: false,
+            seen,
+        }
+    }
+
+    /// Split coordinate on the axis used at `depth`.
+    fn split(&self, depth: usize) -> f32 {
+        if depth & 1 == 0 {
+            self.anchor.x
+        } else {
+            self.anchor.y
+        }
+    }
+}
+
+/// What one [`SpatialGrid::update_in_place`] call did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpatialUpdate {
+    /// Entities inserted as new leaves (moved past the tolerance, or new).
+    pub reinserted: u32,
+    /// The tree was rebuilt from scratch instead.
+    pub full_rebuild: bool,
 }
 
 // ── Public API ────────────────────────────────────────────────────────────────
@@ -51,6 +123,18 @@ pub struct SpatialGrid {
     nodes: Vec<KdFlat>,
     /// Root node index, or `NULL_IDX` when the tree is empty.
     root: u32,
+    /// Node index of every live entity.
+    index: HashMap<Entity, u32>,
+    /// Tombstoned nodes still in `nodes`.
+    dead: usize,
+    /// Depth of the deepest node (root = 0).
+    max_depth: usize,
+    /// Largest per-axis drift of a live node from its anchor.
+    slack: f32,
+    /// Stamp of the current update, for spotting despawned entities.
+    stamp: u32,
+    /// Entities waiting to be inserted as new leaves.
+    moved: Vec<(Entity, Vec2)>,
     /// Pending inserts for the `insert` / `build` API (used by tests).
     pending: Vec<(Entity, Vec2)>,
     /// Scratch sort-buffer reused by `rebuild_spatial_grid_system` so the ECS
@@ -64,6 +148,12 @@ impl Default for SpatialGrid {
         Self {
             nodes: Vec::new(),
             root: NULL_IDX,
+            index: HashMap::new(),
+            dead: 0,
+            max_depth: 0,
+            slack: 0.0,
+            stamp: 0,
+            moved: Vec::new(),
             pending: Vec::new(),
             pts_scratch: Vec::new(),
         }
@@ -74,6 +164,8 @@ impl std::fmt::Debug for SpatialGrid {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("SpatialGrid")
             .field("node_count", &self.nodes.len())
+            .field("dead", &self.dead)
+            .field("max_depth", &self.max_depth)
             .field("has_tree", &(self.root != NULL_IDX))
             .finish()
     }
@@ -97,6 +189,10 @@ impl SpatialGrid {
     pub fn rebuild_in_place(&mut self) {
         self.nodes.clear();
         self.pending.clear();
+        self.index.clear();
+        self.dead = 0;
+        self.max_depth = 0;
+        self.slack = 0.0;
         let n = self.pts_scratch.len();
         if n == 0 {
             self.root = NULL_IDX;
@@ -105,14 +201,137 @@ impl SpatialGrid {
         if self.nodes.capacity() < n {
             self.nodes.reserve(n - self.nodes.len());
         }
-        self.root = Self::build_recursive(&mut self.nodes, &mut self.pts_scratch, 0);
+        self.root = Self::build_recursive(
+            &mut self.nodes,
+            &mut self.pts_scratch,
+            0,
+            self.stamp,
+            &mut self.max_depth,
+        );
+        for (idx, node) in self.nodes.iter().enumerate() {
+            self.index.insert(node.entity, idx as u32);
+        }
+    }
+
+    /// Bring the index up to date with `pts_scratch`, moving only the
+    /// entities that drifted more than `tolerance` from where they were
+    /// placed (see the module docs).
+    ///
+    /// Falls back to [`rebuild_in_place`](Self::rebuild_in_place) when the
+    /// tree gets more than `max_imbalance` times deeper or larger than a
+    /// balanced one; a `tolerance` of zero always rebuilds.
+    pub fn update_in_place(&mut self, tolerance: f32, max_imbalance: f32) -> SpatialUpdate {
+        let rebuild = |grid: &mut Self| {
+            grid.rebuild_in_place();
+            SpatialUpdate {
+                reinserted: 0,
+                full_rebuild: true,
+            }
+        };
+        if tolerance <= 0.0 || self.root == NULL_IDX {
+            return rebuild(self);
+        }
+
+        self.stamp = self.stamp.wrapping_add(1);
+        let stamp = self.stamp;
+        self.moved.clear();
+        let mut slack = 0.0_f32;
+        for &(entity, pos) in &self.pts_scratch {
+            let Some(&idx) = self.index.get(&entity) else {
+                self.moved.push((entity, pos));
+                continue;
+            };
+            let node = &mut self.nodes[idx as usize];
+            if node.seen == stamp {
+                continue; // listed twice
+            }
+            node.seen = stamp;
+            let drift = (pos - node.anchor).abs().max_element();
+            if drift > tolerance {
+                node.dead = true;
+                self.dead += 1;
+                self.moved.push((entity, pos));
+            } else {
+                node.pos = pos;
+                slack = slack.max(drift);
+            }
+        }
+        // Entities not seen this tick are gone.
+        for (idx, node) in self.nodes.iter_mut().enumerate() {
+            if !node.dead && node.seen != stamp {
+                node.dead = true;
+                self.dead += 1;
+                if self.index.get(&node.entity) == Some(&(idx as u32)) {
+                    self.index.remove(&node.entity);
+                }
+            }
+        }
+
+        let live = self.pts_scratch.len();
+        if self.moved.len() * 2 > live {
+            return rebuild(self);
+        }
+        self.slack = slack;
+        let moved = std::mem::take(&mut self.moved);
+        for &(entity, pos) in &moved {
+            self.insert_leaf(entity, pos);
+        }
+        let reinserted = moved.len() as u32;
+        self.moved = moved;
+
+        let balanced_depth = (live as f32 + 1.0).log2().ceil().max(1.0);
+        if self.max_depth as f32 > max_imbalance * balanced_depth
+            || self.nodes.len() as f32 > max_imbalance * live.max(1) as f32
+        {
+            return rebuild(self);
+        }
+        SpatialUpdate {
+            reinserted,
+            full_rebuild: false,
+        }
+    }
+
+    /// Add `entity` at `pos` as a new leaf under the existing splits.
+    fn insert_leaf(&mut self, entity: Entity, pos: Vec2) {
+        let idx = self.nodes.len() as u32;
+        self.nodes.push(KdFlat::new(entity, pos, self.stamp));
+        self.index.insert(entity, idx);
+        if self.root == NULL_IDX {
+            self.root = idx;
+            return;
+        }
+        let mut current = self.root;
+        let mut depth = 0;
+        loop {
+            let node = &mut self.nodes[current as usize];
+            let coord = if depth & 1 == 0 { pos.x } else { pos.y };
+            let child = if coord <= node.split(depth) {
+                &mut node.left
+            } else {
+                &mut node.right
+            };
+            depth += 1;
+            if *child == NULL_IDX {
+                *child = idx;
+                break;
+            }
+            current = *child;
+        }
+        self.max_depth = self.max_depth.max(depth);
     }
 
     /// Recursive median-split builder.  Returns the index of the created node.
-    fn build_recursive(nodes: &mut Vec<KdFlat>, pts: &mut [(Entity, Vec2)], depth: usize) -> u32 {
+    fn build_recursive(
+        nodes: &mut Vec<KdFlat>,
+        pts: &mut [(Entity, Vec2)],
+        depth: usize,
+        stamp: u32,
+        max_depth: &mut usize,
+    ) -> u32 {
         if pts.is_empty() {
             return NULL_IDX;
         }
+        *max_depth = (*max_depth).max(depth);
 
         let axis = depth & 1; // 0 → X, 1 → Y
         pts.sort_unstable_by(|a, b| {
@@ -126,15 +345,10 @@ impl SpatialGrid {
 
         // Reserve the slot for this node before recursing so the index is known.
         let idx = nodes.len() as u32;
-        nodes.push(KdFlat {
-            entity,
-            pos,
-            left: NULL_IDX,
-            right: NULL_IDX,
-        });
+        nodes.push(KdFlat::new(entity, pos, stamp));
 
-        let left = Self::build_recursive(nodes, &mut pts[..mid], depth + 1);
-        let right = Self::build_recursive(nodes, &mut pts[mid + 1..], depth + 1);
+        let left = Self::build_recursive(nodes, &mut pts[..mid], depth + 1, stamp, max_depth);
+        let right = Self::build_recursive(nodes, &mut pts[mid + 1..], depth + 1, stamp, max_depth);
         nodes[idx as usize].left = left;
         nodes[idx as usize].right = right;
 
@@ -171,7 +385,7 @@ impl SpatialGrid {
         let axis = depth & 1;
 
         if axis == 0 {
-            let x = node.pos.x;
+            let x = node.anchor.x;
             out.push((Vec2::new(x, min.y), Vec2::new(x, max.y)));
             self.collect_debug_split_lines_recursive(
                 node.left,
@@ -188,7 +402,7 @@ impl SpatialGrid {
                 out,
             );
         } else {
-            let y = node.pos.y;
+            let y = node.anchor.y;
             out.push((Vec2::new(min.x, y), Vec2::new(max.x, y)));
             self.collect_debug_split_lines_recursive(
                 node.left,
@@ -214,6 +428,10 @@ impl SpatialGrid {
     pub fn clear(&mut self) {
         self.nodes.clear();
         self.pending.clear();
+        self.index.clear();
+        self.dead = 0;
+        self.max_depth = 0;
+        self.slack = 0.0;
         self.root = NULL_IDX;
     }
 
@@ -268,6 +486,118 @@ impl SpatialGrid {
         }
     }
 
+    /// Every indexed entity with its position as of the last update.
+    pub fn iter(&self) -> impl Iterator<Item = (Entity, Vec2)> + '_ {
+        self.nodes
+            .iter()
+            .filter(|node| !node.dead)
+            .map(|node| (node.entity, node.pos))
+    }
+
+    /// Fill `out` with every indexed entity within `radius` of `pos`.
+    pub fn within_radius(&self, pos: Vec2, radius: f32, out: &mut Vec<Entity>) {
+        self.query_neighbors_into(Entity::PLACEHOLDER, pos, radius, out);
+    }
+
+    /// Fill `out` with the `n` indexed entities nearest `pos` and their
+    /// squared distances, nearest first.
+    pub fn nearest_n(&self, pos: Vec2, n: usize, out: &mut Vec<(Entity, f32)>) {
+        out.clear();
+        if n > 0 && self.root != NULL_IDX {
+            self.query_nearest(self.root, pos, n, 0, out);
+        }
+    }
+
+    /// Fill `out` with every indexed entity whose position lies within
+    /// `margin` of the segment from `origin` along unit `dir` for `length`.
+    ///
+    /// Entities are positions, not shapes: pass a margin that covers the
+    /// largest body the caller cares about, then test hulls on the few
+    /// results.
+    pub fn ray_query(
+        &self,
+        origin: Vec2,
+        dir: Vec2,
+        length: f32,
+        margin: f32,
+        out: &mut Vec<Entity>,
+    ) {
+        out.clear();
+        if self.root == NULL_IDX {
+            return;
+        }
+        let end = origin + dir * length.max(0.0);
+        let ray = RayProbe {
+            origin,
+            end,
+            margin_sq: margin * margin,
+            min: origin.min(end) - Vec2::splat(margin),
+            max: origin.max(end) + Vec2::splat(margin),
+        };
+        self.query_ray(self.root, &ray, 0, out);
+    }
+
+    /// Recursive k-nearest search; `best` stays sorted by distance.
+    fn query_nearest(
+        &self,
+        idx: u32,
+        center: Vec2,
+        n: usize,
+        depth: usize,
+        best: &mut Vec<(Entity, f32)>,
+    ) {
+        if idx == NULL_IDX {
+            return;
+        }
+        let node = &self.nodes[idx as usize];
+        let dist_sq = node.pos.distance_squared(center);
+        if !node.dead && (best.len() < n || best.last().is_some_and(|&(_, worst)| dist_sq < worst))
+        {
+            let at = best.partition_point(|&(_, d)| d <= dist_sq);
+            best.insert(at, (node.entity, dist_sq));
+            best.truncate(n);
+        }
+
+        let centre = if depth & 1 == 0 { center.x } else { center.y };
+        let split_dist = node.split(depth) - centre;
+        let gap = (split_dist.abs() - self.slack).max(0.0);
+        let (near, far) = if split_dist >= 0.0 {
+            (node.left, node.right)
+        } else {
+            (node.right, node.left)
+        };
+        self.query_nearest(near, center, n, depth + 1, best);
+        if best.len() < n || best.last().is_some_and(|&(_, worst)| gap * gap <= worst) {
+            self.query_nearest(far, center, n, depth + 1, best);
+        }
+    }
+
+    /// Recursive capsule query along a segment.
+    fn query_ray(&self, idx: u32, ray: &RayProbe, depth: usize, results: &mut Vec<Entity>) {
+        if idx == NULL_IDX {
+            return;
+        }
+        let node = &self.nodes[idx as usize];
+        if !node.dead && ray.distance_sq(node.pos) <= ray.margin_sq {
+            results.push(node.entity);
+        }
+
+        // Left holds coordinates at or below the split, right at or above,
+        // each give or take the slack.
+        let split = node.split(depth);
+        let (lo, hi) = if depth & 1 == 0 {
+            (ray.min.x, ray.max.x)
+        } else {
+            (ray.min.y, ray.max.y)
+        };
+        if lo <= split + self.slack {
+            self.query_ray(node.left, ray, depth + 1, results);
+        }
+        if hi >= split - self.slack {
+            self.query_ray(node.right, ray, depth + 1, results);
+        }
+    }
+
     /// Recursive KD-tree sphere query.
     fn query_radius(
         &self,
@@ -282,17 +612,18 @@ impl SpatialGrid {
             return;
         }
         let node = &self.nodes[idx as usize];
-        let diff = node.pos - center;
 
-        // Include this node if in range and not excluded.
-        if diff.length_squared() <= radius_sq && node.entity != exclude {
+        // Include this node if live, in range and not excluded.
+        if !node.dead && node.pos.distance_squared(center) <= radius_sq && node.entity != exclude {
             results.push(node.entity);
         }
 
-        // Split-plane signed distance (positive → node is right/above center).
-        let axis = depth & 1;
-        let split_dist = if axis == 0 { diff.x } else { diff.y };
-        let split_dist_sq = split_dist * split_dist;
+        // Split-plane signed distance (positive → split is right/above center),
+        // less the slack nodes may have drifted across it.
+        let centre = if depth & 1 == 0 { center.x } else { center.y };
+        let split_dist = node.split(depth) - centre;
+        let gap = (split_dist.abs() - self.slack).max(0.0);
+        let split_dist_sq = gap * gap;
 
         let (near, far) = if split_dist >= 0.0 {
             (node.left, node.right)
@@ -307,23 +638,82 @@ impl SpatialGrid {
     }
 }
 
+/// Segment and bounds for [`SpatialGrid::ray_query`].
+struct RayProbe {
+    origin: Vec2,
+    end: Vec2,
+    margin_sq: f32,
+    /// Bounding box of the segment grown by the margin.
+    min: Vec2,
+    max: Vec2,
+}
+
+impl RayProbe {
+    fn distance_sq(&self, p: Vec2) -> f32 {
+        let seg = self.end - self.origin;
+        let len_sq = seg.length_squared();
+        let t = if len_sq <= f32::EPSILON {
+            0.0
+        } else {
+            ((p - self.origin).dot(seg) / len_sq).clamp(0.0, 1.0)
+        };
+        p.distance_squared(self.origin + seg * t)
+    }
+}
+
+// ── Sector coordinates ────────────────────────────────────────────────────────
+
+/// Square sector (of side `sector_size`) containing `pos`.
+///
+/// Sectors are far coarser than the KD-tree's reach and only index asteroids
+/// parked outside the simulation (see `simulation::sectors`).
+pub fn sector_of(pos: Vec2, sector_size: f32) -> IVec2 {
+    (pos / sector_size.max(1.0)).floor().as_ivec2()
+}
+
+/// Every sector a circle of `radius` around `center` touches.
+pub fn sectors_overlapping(
+    center: Vec2,
+    radius: f32,
+    sector_size: f32,
+) -> impl Iterator<Item = IVec2> {
+    let min = sector_of(center - Vec2::splat(radius), sector_size);
+    let max = sector_of(center + Vec2::splat(radius), sector_size);
+    (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| IVec2::new(x, y)))
+}
+
 // ── ECS rebuild system ────────────────────────────────────────────────────────
 
-/// Rebuild the KD-tree from current asteroid positions each physics tick.
+/// Bring the KD-tree up to date with current asteroid positions each physics
+/// tick.
 ///
 /// Scheduled in `FixedUpdate` just before `nbody_gravity_system`.  Uses the
 /// pre-allocated `pts_scratch` buffer in `SpatialGrid` to avoid any per-tick
-/// heap allocation.
+/// heap allocation, and [`SpatialGrid::update_in_place`] so only bodies that
+/// moved past `spatial_update_tolerance` are reinserted.
 pub fn rebuild_spatial_grid_system(
     mut grid: ResMut<SpatialGrid>,
+    config: Res<PhysicsConfig>,
+    stats: Option<ResMut<SimulationStats>>,
     query: Query<(Entity, &Transform), With<Asteroid>>,
 ) {
+    static ALLOC_TAG: AllocTag = AllocTag::new("FixedUpdate", "rebuild_spatial_grid_system");
+    let _alloc_scope = ALLOC_TAG.enter();
     grid.pts_scratch.clear();
     for (entity, transform) in query.iter() {
         grid.pts_scratch
             .push((entity, transform.translation.truncate()));
     }
-    grid.rebuild_in_place();
+    let update = grid.update_in_place(
+        config.spatial_update_tolerance,
+        config.spatial_rebuild_imbalance,
+    );
+    if let Some(mut stats) = stats {
+        stats.spatial_reinserted = update.reinserted;
+        if update.full_rebuild {
+            stats.spatial_rebuilds_total += 1;
+        }
+    }
 }
 
 // ── Unit tests ────────────────────────────────────────────────────────────────
@@ -489,4 +879,223 @@ mod tests {
             );
         }
     }
+
+    /// Scattered points for comparing the tree queries against brute force.
+    fn scattered(count: u32) -> Vec<(Entity, Vec2)> {
+        (0..count)
+            .map(|i| {
+                let x = ((i * 7919) % 1000) as f32 - 500.0;
+                let y = ((i * 104_729) % 997) as f32 - 498.0;
+                (e(i + 1), Vec2::new(x, y))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn nearest_n_matches_brute_force_in_order() {
+        let points = scattered(300);
+        let mut grid = SpatialGrid::default();
+        grid.rebuild(points.clone());
+
+        let mut found = Vec::new();
+        for center in [
+            Vec2::ZERO,
+            Vec2::new(420.0, -380.0),
+            Vec2::new(-900.0, 50.0),
+        ] {
+            grid.nearest_n(center, 5, &mut found);
+            let mut expected: Vec<f32> = points
+                .iter()
+                .map(|(_, p)| p.distance_squared(center))
+                .collect();
+            expected.sort_by(f32::total_cmp);
+            let distances: Vec<f32> = found.iter().map(|&(_, d)| d).collect();
+            assert_eq!(distances, expected[..5].to_vec());
+        }
+
+        grid.nearest_n(Vec2::ZERO, 0, &mut found);
+        assert!(found.is_empty());
+        grid.nearest_n(Vec2::ZERO, 1000, &mut found);
+        assert_eq!(found.len(), points.len());
+    }
+
+    #[test]
+    fn ray_query_matches_brute_force() {
+        let points = scattered(300);
+        let mut grid = SpatialGrid::default();
+        grid.rebuild(points.clone());
+
+        let origin = Vec2::new(-300.0, -200.0);
+        let dir = Vec2::new(3.0, 2.0).normalize();
+        let mut found = Vec::new();
+        grid.ray_query(origin, dir, 500.0, 30.0, &mut found);
+        let probe = RayProbe {
+            origin,
+            end: origin + dir * 500.0,
+            margin_sq: 900.0,
+            min: Vec2::ZERO,
+            max: Vec2::ZERO,
+        };
+        let mut expected: Vec<Entity> = points
+            .iter()
+            .filter(|(_, p)| probe.distance_sq(*p) <= 900.0)
+            .map(|(entity, _)| *entity)
+            .collect();
+        found.sort();
+        expected.sort();
+        assert!(!expected.is_empty());
+        assert_eq!(found, expected);
+    }
+
+    /// Radius, nearest and ray results against brute force over `points`.
+    fn assert_matches_brute_force(grid: &SpatialGrid, points: &[(Entity, Vec2)]) {
+        let mut found = Vec::new();
+        for center in [
+            Vec2::ZERO,
+            Vec2::new(300.0, -250.0),
+            Vec2::new(-480.0, 470.0),
+        ] {
+            grid.within_radius(center, 120.0, &mut found);
+            let mut expected: Vec<Entity> = points
+                .iter()
+                .filter(|(_, p)| p.distance_squared(center) <= 120.0 * 120.0)
+                .map(|(entity, _)| *entity)
+                .collect();
+            found.sort();
+            expected.sort();
+            assert_eq!(found, expected, "radius query around {center}");
+        }
+
+        let mut nearest = Vec::new();
+        grid.nearest_n(Vec2::new(10.0, 20.0), 6, &mut nearest);
+        let mut expected: Vec<f32> = points
+            .iter()
+            .map(|(_, p)| p.distance_squared(Vec2::new(10.0, 20.0)))
+            .collect();
+        expected.sort_by(f32::total_cmp);
+        let distances: Vec<f32> = nearest.iter().map(|&(_, d)| d).collect();
+        assert_eq!(distances, expected[..6].to_vec());
+
+        let origin = Vec2::new(-400.0, 300.0);
+        let dir = Vec2::new(1.0, -0.8).normalize();
+        grid.ray_query(origin, dir, 900.0, 25.0, &mut found);
+        let probe = RayProbe {
+            origin,
+            end: origin + dir * 900.0,
+            margin_sq: 625.0,
+            min: Vec2::ZERO,
+            max: Vec2::ZERO,
+        };
+        let mut expected: Vec<Entity> = points
+            .iter()
+            .filter(|(_, p)| probe.distance_sq(*p) <= 625.0)
+            .map(|(entity, _)| *entity)
+            .collect();
+        found.sort();
+        expected.sort();
+        assert_eq!(found, expected);
+    }
+
+    #[test]
+    fn incremental_updates_match_brute_force() {
+        let mut points = scattered(300);
+        let mut grid = SpatialGrid::default();
+        grid.pts_scratch.extend_from_slice(&points);
+        assert!(grid.update_in_place(6.0, 2.0).full_rebuild);
+
+        for tick in 0..12u32 {
+            // Everyone jitters within the tolerance; a few drift far.
+            for (i, (_, pos)) in points.iter_mut().enumerate() {
+                let phase = (i as u32 * 31 + tick * 17) as f32;
+                *pos += Vec2::new(phase.sin(), phase.cos()) * 2.5;
+                if (i as u32 + tick).is_multiple_of(23) {
+                    *pos += Vec2::new(40.0, -35.0);
+                }
+            }
+            // Some leave, some arrive.
+            points.retain(|(entity, _)| (entity.index() + tick) % 41 != 0);
+            points.push((e(1000 + tick), Vec2::new(tick as f32 * 20.0 - 100.0, 15.0)));
+
+            grid.pts_scratch.clear();
+            grid.pts_scratch.extend_from_slice(&points);
+            let update = grid.update_in_place(6.0, 2.0);
+            if !update.full_rebuild {
+                assert!(update.reinserted > 0);
+            }
+            assert_matches_brute_force(&grid, &points);
+        }
+    }
+
+    #[test]
+    fn degraded_tree_falls_back_to_a_full_rebuild() {
+        let mut points = scattered(64);
+        let mut grid = SpatialGrid::default();
+        grid.pts_scratch.extend_from_slice(&points);
+        grid.update_in_place(6.0, 2.0);
+
+        // Small jitter is absorbed without reinserting anything.
+        points[0].1 += Vec2::splat(3.0);
+        grid.pts_scratch.clear();
+        grid.pts_scratch.extend_from_slice(&points);
+        assert_eq!(
+            grid.update_in_place(6.0, 2.0),
+            SpatialUpdate {
+                reinserted: 0,
+                full_rebuild: false,
+            }
+        );
+
+        // Bodies marching in a line keep adding leaves down one branch until
+        // the tree is rebuilt.
+        let mut rebuilt = false;
+        for step in 0..30 {
+            for (i, (_, pos)) in points.iter_mut().take(8).enumerate() {
+                *pos = Vec2::new(600.0 + step as f32 * 10.0 + i as f32, 600.0);
+            }
+            grid.pts_scratch.clear();
+            grid.pts_scratch.extend_from_slice(&points);
+            rebuilt |= grid.update_in_place(6.0, 2.0).full_rebuild;
+            assert_matches_brute_force(&grid, &points);
+        }
+        assert!(rebuilt);
+
+        // Moving most of the field at once (an origin shift) rebuilds outright.
+        for (_, pos) in &mut points {
+            *pos += Vec2::new(250.0, 0.0);
+        }
+        grid.pts_scratch.clear();
+        grid.pts_scratch.extend_from_slice(&points);
+        assert!(grid.update_in_place(6.0, 2.0).full_rebuild);
+        assert_matches_brute_force(&grid, &points);
+
+        // Zero tolerance always rebuilds.
+        assert!(grid.update_in_place(0.0, 2.0).full_rebuild);
+    }
+
+    #[test]
+    fn within_radius_excludes_nothing() {
+        let mut grid = SpatialGrid::default();
+        grid.insert(e(1), Vec2::ZERO);
+        grid.insert(e(2), Vec2::new(5.0, 0.0));
+        grid.build();
+        let mut found = Vec::new();
+        grid.within_radius(Vec2::ZERO, 10.0, &mut found);
+        found.sort_by_key(|entity| entity.index());
+        assert_eq!(found, vec![e(1), e(2)]);
+    }
+
+    #[test]
+    fn sectors_cover_the_query_circle() {
+        assert_eq!(sector_of(Vec2::new(999.0, -1.0), 1000.0), IVec2::new(0, -1));
+        assert_eq!(
+            sector_of(Vec2::new(-1000.0, 2500.0), 1000.0),
+            IVec2::new(-1, 2)
+        );
+
+        let covered: Vec<IVec2> =
+            sectors_overlapping(Vec2::new(500.0, 500.0), 600.0, 1000.0).collect();
+        assert_eq!(covered.len(), 9);
+        assert!(covered.contains(&IVec2::new(-1, -1)));
+        assert!(covered.contains(&IVec2::new(1, 1)));
+    }
 }
@@ -0,0 +1,428 @@
+//! Session clock splits and the local speedrun times file.
+//!
+//! The session clock is [`RunPlaytime`]: it only advances in `Playing`, so
+//! pause menus and the ore shop never count.  `OverlayState::show_session_clock`
+//! shows it under the score; `OverlayState::speedrun_mode` adds the splits
+//! and records final times.
+//!
+//! [`speedrun_split_system`] takes a split whenever a wave is cleared and
+//! when a campaign mission's objective completes.  With speedrun mode on, a
+//! finished run is recorded on the local leaderboard
+//! ([`crate::save::leaderboard`]), which keeps the best
+//! [`SPEEDRUN_TIMES_PER_CATEGORY`] times per category (scenario and mode,
+//! e.g. `SURVIVAL · practice` or `FIELD · campaign m2`):
+//!
+//! - a campaign mission is timed when its objective completes;
+//! - other runs are timed at game over, by the time their last wave was
+//!   cleared.  Runs that cleared no wave are not recorded.
+//!
+//! Times rank by waves cleared, then by time.  Runs restored from a save
+//! still show splits but are never recorded.  Times from the standalone
+//! `saves/speedrun_times.toml` of earlier versions are moved onto the board
+//! at startup.
+
+use crate::campaign::{CampaignSession, CampaignWaveDirector, CampaignWavePhase};
+use crate::menu::GameState;
+use crate::rendering::OverlayState;
+use crate::run_info::{RunMetadata, RunPlaytime};
+use crate::save::leaderboard::LeaderboardStore;
+use crate::survival::{SurvivalPhase, SurvivalState};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Times kept per category.
+pub const SPEEDRUN_TIMES_PER_CATEGORY: usize = 10;
+
+/// Splits shown under the session clock.
+pub const HUD_SPLITS_SHOWN: usize = 4;
+
+const LEGACY_SPEEDRUN_TIMES_VERSION: u32 = 1;
+
+/// One split: what was reached and the session clock at that moment.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Split {
+    pub label: String,
+    pub at_secs: f32,
+}
+
+/// Splits for the current run.
+#[derive(Resource, Debug, Clone, Default, PartialEq)]
+pub struct SpeedrunSplits {
+    pub splits: Vec<Split>,
+    /// Waves cleared and objective state at the last update; `None` until
+    /// the first update, so a loaded run does not split for waves it
+    /// cleared before the save.
+    progress: Option<(u32, bool)>,
+    /// Waves cleared this mission (or run, outside the campaign).
+    pub waves_cleared: u32,
+    /// Restored from a save: splits run but no time is recorded.
+    pub loaded: bool,
+}
+
+impl SpeedrunSplits {
+    /// Splits for a run restored from a save.
+    pub fn for_loaded_run() -> Self {
+        Self {
+            loaded: true,
+            ..default()
+        }
+    }
+
+    /// Take splits for progress since the last update.
+    ///
+    /// Returns `true` when the objective completed on this update.  A drop
+    /// in waves cleared means a new campaign mission has started.
+    pub fn advance(&mut self, now: f32, prefix: &str, waves_cleared: u32, objective: bool) -> bool {
+        let Some((last_waves, last_objective)) = self.progress else {
+            self.progress = Some((waves_cleared, objective));
+            self.waves_cleared = waves_cleared;
+            return false;
+        };
+        let (from_wave, was_done) = if waves_cleared < last_waves {
+            (0, false)
+        } else {
+            (last_waves, last_objective)
+        };
+        for wave in from_wave + 1..=waves_cleared {
+            self.splits.push(Split {
+                label: format!("{prefix}WAVE {wave}"),
+                at_secs: now,
+            });
+        }
+        let completed = objective && !was_done;
+        if completed {
+            self.splits.push(Split {
+                label: format!("{prefix}COMPLETE"),
+                at_secs: now,
+            });
+        }
+        self.progress = Some((waves_cleared, objective));
+        self.waves_cleared = waves_cleared;
+        completed
+    }
+
+    /// Clock at the most recent wave split.
+    pub fn last_wave_secs(&self) -> Option<f32> {
+        self.splits
+            .iter()
+            .rev()
+            .find(|split| !split.label.ends_with("COMPLETE"))
+            .map(|split| split.at_secs)
+    }
+}
+
+/// Waves cleared so far, from whichever wave system is running.
+pub fn waves_cleared(
+    campaign: Option<&CampaignWaveDirector>,
+    survival: Option<&SurvivalState>,
+) -> u32 {
+    if let Some(survival) = survival.filter(|s| s.is_active()) {
+        return match survival.phase {
+            SurvivalPhase::Breather => survival.wave,
+            SurvivalPhase::Wave | SurvivalPhase::Inactive => survival.wave.saturating_sub(1),
+        };
+    }
+    match campaign.map(|director| (director.phase, director.current_wave)) {
+        Some((CampaignWavePhase::Warmup | CampaignWavePhase::ActiveWave, wave)) => {
+            wave.saturating_sub(1)
+        }
+        Some((
+            CampaignWavePhase::InterWaveBreak
+            | CampaignWavePhase::BossIntro
+            | CampaignWavePhase::BossActive
+            | CampaignWavePhase::BossOutro
+            | CampaignWavePhase::Complete,
+            wave,
+        )) => wave,
+        Some((CampaignWavePhase::Inactive, _)) | None => 0,
+    }
+}
+
+/// `m:ss.t`, or `h:mm:ss.t` past the hour.
+pub fn format_clock(secs: f32) -> String {
+    let tenths = (secs.max(0.0) * 10.0) as u64;
+    let (hours, minutes, seconds) = (tenths / 36_000, tenths / 600 % 60, tenths / 10 % 60);
+    if hours > 0 {
+        format!("{hours}:{minutes:02}:{seconds:02}.{}", tenths % 10)
+    } else {
+        format!("{minutes}:{seconds:02}.{}", tenths % 10)
+    }
+}
+
+/// One recorded run.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SpeedrunTime {
+    /// Scenario and mode, e.g. `SURVIVAL · practice`.
+    pub category: String,
+    pub waves_cleared: u32,
+    /// Session clock at the finish.
+    pub secs: f32,
+    /// Clock at each split, in order.
+    pub splits: Vec<f32>,
+    pub recorded_at_unix: u64,
+}
+
+impl SpeedrunTime {
+    /// More waves first, then the faster time.
+    fn ranks_above(&self, other: &Self) -> bool {
+        self.waves_cleared > other.waves_cleared
+            || (self.waves_cleared == other.waves_cleared && self.secs < other.secs)
+    }
+}
+
+/// Best times per category, stored on the leaderboard.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(transparent)]
+pub struct SpeedrunTimes {
+    pub times: Vec<SpeedrunTime>,
+}
+
+/// The standalone times file written before times moved onto the
+/// leaderboard.
+#[derive(Deserialize)]
+struct LegacySpeedrunTimes {
+    version: u32,
+    #[serde(default)]
+    times: Vec<SpeedrunTime>,
+}
+
+impl SpeedrunTimes {
+    /// Best times for `category`, fastest first.
+    pub fn category<'a>(
+        &'a self,
+        category: &'a str,
+    ) -> impl Iterator<Item = &'a SpeedrunTime> + 'a {
+        self.times.iter().filter(move |t| t.category == category)
+    }
+
+    /// Insert `time` and trim its category; returns its 1-based rank, or
+    /// `None` if it did not make the table.
+    pub fn insert(&mut self, time: SpeedrunTime) -> Option<usize> {
+        let rank = self
+            .category(&time.category)
+            .filter(|t| !time.ranks_above(t))
+            .count();
+        if rank >= SPEEDRUN_TIMES_PER_CATEGORY {
+            return None;
+        }
+        let category = time.category.clone();
+        let at = self
+            .times
+            .iter()
+            .position(|t| t.category == category && time.ranks_above(t))
+            .unwrap_or(self.times.len());
+        self.times.insert(at, time);
+
+        let mut kept = 0;
+        self.times.retain(|t| {
+            if t.category != category {
+                return true;
+            }
+            kept += 1;
+            kept <= SPEEDRUN_TIMES_PER_CATEGORY
+        });
+        Some(rank + 1)
+    }
+}
+
+/// Parse a standalone `speedrun_times.toml` from an earlier version.
+pub fn parse_legacy_speedrun_times(contents: &str) -> Result<Vec<SpeedrunTime>, String> {
+    let legacy: LegacySpeedrunTimes = toml::from_str(contents)
+        .map_err(|err| format!("failed to parse speedrun times TOML: {err}"))?;
+    if legacy.version != LEGACY_SPEEDRUN_TIMES_VERSION {
+        return Err(format!(
+            "unsupported speedrun times version {} (expected {})",
+            legacy.version, LEGACY_SPEEDRUN_TIMES_VERSION
+        ));
+    }
+    Ok(legacy.times)
+}
+
+/// Registers the split tracker.
+pub struct SpeedrunPlugin;
+
+impl Plugin for SpeedrunPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SpeedrunSplits>()
+            .add_systems(
+                Update,
+                speedrun_split_system.run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnEnter(GameState::GameOver), speedrun_game_over_system);
+    }
+}
+
+/// Take splits as waves clear; time a campaign mission when it completes.
+#[allow(clippy::too_many_arguments)]
+pub fn speedrun_split_system(
+    playtime: Res<RunPlaytime>,
+    overlay: Res<OverlayState>,
+    run: Res<RunMetadata>,
+    mut store: ResMut<LeaderboardStore>,
+    campaign: Option<Res<CampaignSession>>,
+    director: Option<Res<CampaignWaveDirector>>,
+    survival: Option<Res<SurvivalState>>,
+    mut splits: ResMut<SpeedrunSplits>,
+) {
+    let campaign = campaign.filter(|session| session.active);
+    let director = director.filter(|_| campaign.is_some());
+    let cleared = waves_cleared(director.as_deref(), survival.as_deref());
+    let objective = director
+        .as_ref()
+        .is_some_and(|director| director.mission_reward_granted);
+    let prefix = campaign
+        .as_ref()
+        .map(|session| format!("M{} ", session.mission_index.max(1)))
+        .unwrap_or_default();
+
+    let completed = splits.advance(playtime.secs, &prefix, cleared, objective);
+    if completed && overlay.speedrun_mode && !splits.loaded {
+        record_time(&mut store, &run, &splits, playtime.secs);
+    }
+}
+
+/// Time a non-campaign run by its last cleared wave.
+pub fn speedrun_game_over_system(
+    overlay: Res<OverlayState>,
+    run: Res<RunMetadata>,
+    mut store: ResMut<LeaderboardStore>,
+    campaign: Option<Res<CampaignSession>>,
+    splits: Res<SpeedrunSplits>,
+) {
+    if !overlay.speedrun_mode || splits.loaded || campaign.is_some_and(|c| c.active) {
+        return;
+    }
+    if let Some(secs) = splits.last_wave_secs().filter(|_| splits.waves_cleared > 0) {
+        record_time(&mut store, &run, &splits, secs);
+    }
+}
+
+fn record_time(
+    store: &mut LeaderboardStore,
+    run: &RunMetadata,
+    splits: &SpeedrunSplits,
+    secs: f32,
+) {
+    let time = SpeedrunTime {
+        category: format!("{} · {}", run.scenario, run.mode),
+        waves_cleared: splits.waves_cleared,
+        secs,
+        splits: splits.splits.iter().map(|split| split.at_secs).collect(),
+        recorded_at_unix: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs()),
+    };
+    let summary = format!("{} in {}", time.category, format_clock(secs));
+    match store.record_speedrun(time) {
+        Ok(Some(rank)) => info!("Speedrun time #{rank}: {summary}"),
+        Ok(None) => {}
+        Err(err) => warn!("{err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(category: &str, waves: u32, secs: f32) -> SpeedrunTime {
+        SpeedrunTime {
+            category: category.to_string(),
+            waves_cleared: waves,
+            secs,
+            splits: Vec::new(),
+            recorded_at_unix: 0,
+        }
+    }
+
+    #[test]
+    fn splits_follow_cleared_waves_and_the_objective() {
+        let mut splits = SpeedrunSplits::default();
+        assert!(!splits.advance(0.0, "M1 ", 0, false));
+        splits.advance(42.0, "M1 ", 1, false);
+        splits.advance(42.5, "M1 ", 1, false);
+        assert!(splits.advance(120.0, "M1 ", 3, true));
+        // The next mission restarts the wave count.
+        splits.advance(130.0, "M2 ", 0, false);
+        splits.advance(150.0, "M2 ", 1, false);
+
+        let labels: Vec<&str> = splits.splits.iter().map(|s| s.label.as_str()).collect();
+        assert_eq!(
+            labels,
+            [
+                "M1 WAVE 1",
+                "M1 WAVE 2",
+                "M1 WAVE 3",
+                "M1 COMPLETE",
+                "M2 WAVE 1"
+            ]
+        );
+        assert_eq!(splits.last_wave_secs(), Some(150.0));
+    }
+
+    #[test]
+    fn loaded_runs_do_not_split_for_waves_cleared_before_the_save() {
+        let mut splits = SpeedrunSplits::for_loaded_run();
+        splits.advance(300.0, "", 4, false);
+        assert!(splits.splits.is_empty());
+        splits.advance(330.0, "", 5, false);
+        assert_eq!(splits.splits.len(), 1);
+        assert_eq!(splits.splits[0].label, "WAVE 5");
+    }
+
+    #[test]
+    fn wave_count_reads_survival_before_the_campaign_director() {
+        let mut survival = SurvivalState {
+            phase: SurvivalPhase::Wave,
+            wave: 3,
+            ..default()
+        };
+        assert_eq!(waves_cleared(None, Some(&survival)), 2);
+        survival.phase = SurvivalPhase::Breather;
+        assert_eq!(waves_cleared(None, Some(&survival)), 3);
+
+        let director = CampaignWaveDirector {
+            phase: CampaignWavePhase::InterWaveBreak,
+            current_wave: 2,
+            ..default()
+        };
+        assert_eq!(waves_cleared(Some(&director), None), 2);
+        assert_eq!(waves_cleared(Some(&director), Some(&survival)), 3);
+    }
+
+    #[test]
+    fn clock_shows_tenths_and_hours() {
+        assert_eq!(format_clock(0.0), "0:00.0");
+        assert_eq!(format_clock(83.46), "1:23.4");
+        assert_eq!(format_clock(3_725.0), "1:02:05.0");
+    }
+
+    #[test]
+    fn times_rank_by_waves_then_speed_and_keep_the_top_ten() {
+        let mut times = SpeedrunTimes::default();
+        assert_eq!(times.insert(time("SURVIVAL · practice", 3, 200.0)), Some(1));
+        assert_eq!(times.insert(time("SURVIVAL · practice", 3, 150.0)), Some(1));
+        assert_eq!(times.insert(time("SURVIVAL · practice", 5, 400.0)), Some(1));
+        assert_eq!(times.insert(time("FIELD · campaign m1", 2, 90.0)), Some(1));
+        for i in 0..10 {
+            times.insert(time("SURVIVAL · practice", 6, 500.0 + i as f32));
+        }
+        assert_eq!(times.insert(time("SURVIVAL · practice", 1, 10.0)), None);
+
+        let survival: Vec<_> = times.category("SURVIVAL · practice").collect();
+        assert_eq!(survival.len(), SPEEDRUN_TIMES_PER_CATEGORY);
+        assert!(survival.iter().all(|t| t.waves_cleared == 6));
+        assert_eq!(survival[0].secs, 500.0);
+        assert_eq!(times.category("FIELD · campaign m1").count(), 1);
+    }
+
+    #[test]
+    fn legacy_times_files_parse_and_other_versions_are_rejected() {
+        let file = "version = 1\n\n[[times]]\ncategory = \"FIELD · practice\"\n\
+                    waves_cleared = 2\nsecs = 61.5\nsplits = [30.0, 61.5]\nrecorded_at_unix = 0\n";
+        let times = parse_legacy_speedrun_times(file).unwrap();
+        assert_eq!(times.len(), 1);
+        assert_eq!(times[0].splits, [30.0, 61.5]);
+        assert!(parse_legacy_speedrun_times(&file.replace("version = 1", "version = 2")).is_err());
+    }
+}
@@ -0,0 +1,394 @@
+//! Survival mode: escalating comet and enemy waves on a timer.
+//!
+//! Picking **SURVIVAL** on the practice scenario screen seeds a light asteroid
+//! field and hands control to [`survival_wave_system`], which alternates
+//! between two phases:
+//!
+//! | Phase      | Behaviour                                                        |
+//! |------------|------------------------------------------------------------------|
+//! | `Breather` | Countdown (`survival_first_wave_delay_secs` / `survival_breather_secs`); nothing new spawns |
+//! | `Wave`     | Comets launched at the ship every `survival_comet_interval_secs`; enemy ships spawn up to the wave budget |
+//!
+//! A wave is cleared once every comet has launched and every enemy in the
+//! wave budget has spawned and been destroyed.  Comets do not have to be
+//! destroyed — surviving them is the point.
+//!
+//! Enemy pacing reuses [`CampaignWaveDirector`]: `enemy_spawn_system` already
+//! spawns against its `ActiveWave` budget, and with the campaign session
+//! inactive `campaign_wave_director_system` leaves it untouched.
+//!
+//! Score earned during wave *n* is scaled by
+//! `1 + survival_score_mult_per_wave × (n − 1)` in
+//! [`survival_score_multiplier_system`], on top of the streak multiplier.
+
+use crate::campaign::{CampaignWaveDirector, CampaignWavePhase};
+use crate::config::PhysicsConfig;
+use crate::enemy::Enemy;
+use crate::menu::{SelectedGameMode, SelectedScenario};
+use crate::player::{Player, PlayerScore};
+use crate::run_info::RunMetadata;
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f32::consts::TAU;
+
+/// Mixed into the run seed, so comet launches do not replay the sequence
+/// world generation draws from the seed.
+const COMET_STREAM: u64 = 0x636f_6d65_7473;
+
+/// Runtime phase of the survival wave loop.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+pub enum SurvivalPhase {
+    #[default]
+    Inactive,
+    Breather,
+    Wave,
+}
+
+/// Survival-mode runtime state.
+#[derive(Resource, Debug, Clone, Default, Reflect)]
+#[reflect(Resource)]
+pub struct SurvivalState {
+    pub phase: SurvivalPhase,
+    /// 1-indexed wave currently running, or the last cleared wave during a
+    /// breather (0 before wave 1).
+    pub wave: u32,
+    pub phase_timer_secs: f32,
+    pub comets_remaining: u32,
+    pub comet_timer_secs: f32,
+    /// Score total already scaled by the wave multiplier.
+    pub scored_points: u32,
+    /// Comets launched this run; combined with `seed` for launch RNG.
+    pub comets_launched: u64,
+    /// Derived from [`RunMetadata::seed`], so a fixed `ACCRETION_SEED`
+    /// replays the same comets.
+    pub seed: u64,
+}
+
+impl SurvivalState {
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.phase != SurvivalPhase::Inactive
+    }
+
+    /// Score multiplier for the current wave (1.0 before and during wave 1).
+    pub fn score_multiplier(&self, config: &PhysicsConfig) -> f32 {
+        1.0 + config.survival_score_mult_per_wave.max(0.0) * self.wave.saturating_sub(1) as f32
+    }
+}
+
+fn start_wave(
+    state: &mut SurvivalState,
+    director: &mut CampaignWaveDirector,
+    config: &PhysicsConfig,
+) {
+    state.wave += 1;
+    state.phase = SurvivalPhase::Wave;
+    state.phase_timer_secs = 0.0;
+    state.comet_timer_secs = 0.0;
+
+    let escalation = state.wave - 1;
+    state.comets_remaining = config
+        .survival_comets_base
+        .saturating_add(config.survival_comets_per_wave.saturating_mul(escalation));
+
+    director.phase = CampaignWavePhase::ActiveWave;
+    director.current_wave = state.wave;
+    director.total_waves = state.wave;
+    director.phase_timer_secs = 0.0;
+    director.spawned_this_wave = 0;
+    director.target_spawns_this_wave = config
+        .survival_enemies_base
+        .saturating_add(config.survival_enemies_per_wave.saturating_mul(escalation));
+    director.max_concurrent_enemies = (1 + escalation / 2).min(config.enemy_max_count_cap.max(1));
+    let wave_speedup = 1.0 + escalation as f32 * 0.18;
+    director.spawn_cooldown_secs = (config.enemy_spawn_base_cooldown / wave_speedup)
+        .max(config.enemy_spawn_cooldown_min.max(0.25));
+}
+
+fn launch_comet(
+    commands: &mut Commands,
+    config: &PhysicsConfig,
+    state: &mut SurvivalState,
+    target: Vec2,
+) {
+    let mut rng = StdRng::seed_from_u64(state.seed.wrapping_add(state.comets_launched));
+    state.comets_launched += 1;
+
+    let outer = (config.soft_boundary_radius - 30.0).min(config.cull_distance - 80.0);
+    let angle: f32 = rng.gen_range(0.0..TAU);
+    let position = Vec2::new(angle.cos(), angle.sin()) * outer.max(1.0);
+
+    // Aim at the ship with a little spread so comets don't all converge on
+    // one point.
+    let to_target = (target - position).normalize_or(-position.normalize_or(Vec2::X));
+    let spread = Vec2::new(-to_target.y, to_target.x) * rng.gen_range(-0.15..0.15);
+    let speed = config.survival_comet_speed_base
+        + config.survival_comet_speed_per_wave * state.wave.saturating_sub(1) as f32;
+    let velocity = (to_target + spread).normalize_or(to_target) * speed.max(0.0);
+
+    crate::asteroid::spawn_inbound_comet(commands, config, &mut rng, position, velocity);
+}
+
+/// Initialize survival state when entering gameplay.
+///
+/// Any scenario other than Survival (or campaign mode) resets the state to
+/// `Inactive` so the wave loop stays dormant.  Runs after the world is
+/// spawned, so [`RunMetadata`] already holds this run's seed.
+pub fn bootstrap_survival_session(
+    mode: Res<SelectedGameMode>,
+    scenario: Res<SelectedScenario>,
+    config: Res<PhysicsConfig>,
+    run: Res<RunMetadata>,
+    score: Res<PlayerScore>,
+    mut state: ResMut<SurvivalState>,
+    mut director: ResMut<CampaignWaveDirector>,
+) {
+    if *mode != SelectedGameMode::Practice || *scenario != SelectedScenario::Survival {
+        *state = SurvivalState::default();
+        return;
+    }
+
+    *state = SurvivalState {
+        phase: SurvivalPhase::Breather,
+        phase_timer_secs: config.survival_first_wave_delay_secs.max(0.0),
+        scored_points: score.points,
+        seed: run.seed ^ COMET_STREAM,
+        ..Default::default()
+    };
+    *director = CampaignWaveDirector::default();
+}
+
+/// Drive the survival breather/wave loop: start waves, launch comets, and
+/// detect wave clears.
+pub fn survival_wave_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    mut state: ResMut<SurvivalState>,
+    mut director: ResMut<CampaignWaveDirector>,
+    q_player: Query<&Transform, With<Player>>,
+    q_enemies: Query<(), With<Enemy>>,
+) {
+    if !state.is_active() {
+        return;
+    }
+
+    let dt = time.delta_secs();
+    match state.phase {
+        SurvivalPhase::Breather => {
+            state.phase_timer_secs = (state.phase_timer_secs - dt).max(0.0);
+            if state.phase_timer_secs <= 0.0 {
+                start_wave(&mut state, &mut director, &config);
+            }
+        }
+        SurvivalPhase::Wave => {
+            if state.comets_remaining > 0 {
+                state.comet_timer_secs -= dt;
+                if state.comet_timer_secs <= 0.0 {
+                    let target = q_player
+                        .single()
+                        .map(|t| t.translation.truncate())
+                        .unwrap_or(Vec2::ZERO);
+                    launch_comet(&mut commands, &config, &mut state, target);
+                    state.comets_remaining -= 1;
+                    state.comet_timer_secs = config.survival_comet_interval_secs.max(0.05);
+                }
+            }
+
+            let enemies_done = director.spawned_this_wave >= director.target_spawns_this_wave
+                && q_enemies.is_empty();
+            if state.comets_remaining == 0 && enemies_done {
+                state.phase = SurvivalPhase::Breather;
+                state.phase_timer_secs = config.survival_breather_secs.max(0.0);
+                director.phase = CampaignWavePhase::Inactive;
+            }
+        }
+        SurvivalPhase::Inactive => {}
+    }
+}
+
+/// Scale score earned since last frame by the current wave multiplier.
+pub fn survival_score_multiplier_system(
+    config: Res<PhysicsConfig>,
+    mut state: ResMut<SurvivalState>,
+    mut score: ResMut<PlayerScore>,
+) {
+    if !state.is_active() {
+        return;
+    }
+    if score.points < state.scored_points {
+        // Score was reset externally (e.g. a loaded snapshot); rebaseline.
+        state.scored_points = score.points;
+        return;
+    }
+
+    let earned = score.points - state.scored_points;
+    if earned > 0 {
+        let bonus = (earned as f32 * (state.score_multiplier(&config) - 1.0)).round() as u32;
+        score.points = score.points.saturating_add(bonus);
+    }
+    state.scored_points = score.points;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn survival_world(state: SurvivalState) -> World {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        world.insert_resource(PhysicsConfig::default());
+        world.insert_resource(PlayerScore::default());
+        world.insert_resource(CampaignWaveDirector::default());
+        world.insert_resource(RunMetadata::default());
+        world.insert_resource(state);
+        world
+    }
+
+    /// Bootstrap a survival session for `run_seed`, run one wave start and
+    /// return the first comet's position and velocity.
+    fn first_comet(run_seed: u64) -> (Vec2, Vec2) {
+        let mut world = survival_world(SurvivalState::default());
+        world.insert_resource(SelectedGameMode::Practice);
+        world.insert_resource(SelectedScenario::Survival);
+        world.insert_resource(RunMetadata {
+            seed: run_seed,
+            ..Default::default()
+        });
+        world.spawn((Player, Transform::from_xyz(50.0, -20.0, 0.0)));
+
+        let mut bootstrap = Schedule::default();
+        bootstrap.add_systems(bootstrap_survival_session);
+        bootstrap.run(&mut world);
+        world.resource_mut::<SurvivalState>().phase_timer_secs = 0.0;
+
+        // Breather expires, then the first comet launches.
+        let mut waves = Schedule::default();
+        waves.add_systems(survival_wave_system);
+        waves.run(&mut world);
+        waves.run(&mut world);
+
+        let (transform, velocity) = world
+            .query_filtered::<(&Transform, &bevy_rapier2d::prelude::Velocity), With<crate::asteroid::Asteroid>>()
+            .single(&world)
+            .expect("one comet launched");
+        (transform.translation.truncate(), velocity.linvel)
+    }
+
+    #[test]
+    fn bootstrap_only_activates_for_practice_survival() {
+        let mut world = survival_world(SurvivalState::default());
+        world.insert_resource(SelectedGameMode::Practice);
+        world.insert_resource(SelectedScenario::Survival);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(bootstrap_survival_session);
+        schedule.run(&mut world);
+        let state = world.resource::<SurvivalState>();
+        assert_eq!(state.phase, SurvivalPhase::Breather);
+        assert_eq!(state.wave, 0);
+
+        world.insert_resource(SelectedScenario::Field);
+        schedule.run(&mut world);
+        assert!(!world.resource::<SurvivalState>().is_active());
+    }
+
+    #[test]
+    fn same_run_seed_launches_the_same_comets() {
+        assert_eq!(first_comet(0xACC), first_comet(0xACC));
+        assert_ne!(first_comet(0xACC), first_comet(0xACD));
+    }
+
+    #[test]
+    fn breather_expiry_starts_escalated_wave() {
+        let mut world = survival_world(SurvivalState {
+            phase: SurvivalPhase::Breather,
+            wave: 2,
+            ..Default::default()
+        });
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(survival_wave_system);
+        schedule.run(&mut world);
+
+        let config = PhysicsConfig::default();
+        let state = world.resource::<SurvivalState>();
+        assert_eq!(state.phase, SurvivalPhase::Wave);
+        assert_eq!(state.wave, 3);
+        assert_eq!(
+            state.comets_remaining,
+            config.survival_comets_base + 2 * config.survival_comets_per_wave
+        );
+        let director = world.resource::<CampaignWaveDirector>();
+        assert_eq!(director.phase, CampaignWavePhase::ActiveWave);
+        assert_eq!(
+            director.target_spawns_this_wave,
+            config.survival_enemies_base + 2 * config.survival_enemies_per_wave
+        );
+    }
+
+    #[test]
+    fn wave_launches_comets_then_clears_into_breather() {
+        let mut world = survival_world(SurvivalState {
+            phase: SurvivalPhase::Wave,
+            wave: 1,
+            comets_remaining: 1,
+            ..Default::default()
+        });
+        world.insert_resource(CampaignWaveDirector {
+            phase: CampaignWavePhase::ActiveWave,
+            target_spawns_this_wave: 1,
+            ..Default::default()
+        });
+        world.spawn((Player, Transform::default()));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(survival_wave_system);
+        schedule.run(&mut world);
+
+        assert_eq!(
+            world
+                .query::<&crate::asteroid::Asteroid>()
+                .iter(&world)
+                .count(),
+            1
+        );
+        let state = world.resource::<SurvivalState>();
+        assert_eq!(state.comets_remaining, 0);
+        assert_eq!(state.phase, SurvivalPhase::Wave);
+
+        // The wave's only enemy spawned and was destroyed.
+        world
+            .resource_mut::<CampaignWaveDirector>()
+            .spawned_this_wave = 1;
+        schedule.run(&mut world);
+        assert_eq!(
+            world.resource::<SurvivalState>().phase,
+            SurvivalPhase::Breather
+        );
+        assert_eq!(
+            world.resource::<CampaignWaveDirector>().phase,
+            CampaignWavePhase::Inactive
+        );
+    }
+
+    #[test]
+    fn later_waves_scale_earned_score() {
+        let mut world = survival_world(SurvivalState {
+            phase: SurvivalPhase::Wave,
+            wave: 5,
+            scored_points: 100,
+            ..Default::default()
+        });
+        world.resource_mut::<PlayerScore>().points = 140;
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(survival_score_multiplier_system);
+        schedule.run(&mut world);
+
+        // Wave 5 → ×2.0 with default tuning, so 40 earned points become 80.
+        assert_eq!(world.resource::<PlayerScore>().points, 180);
+        assert_eq!(world.resource::<SurvivalState>().scored_points, 180);
+    }
+}
@@ -0,0 +1,380 @@
+//! Parameter sweeps over [`PhysicsConfig`] on top of the headless runner.
+//!
+//! A [`SweepSpec`] names a scenario, a seed, a frame count and one
+//! [`SweepAxis`] per tunable.  [`run_sweep`] builds every combination (the
+//! full grid, or `samples` random draws), runs each one through
+//! [`run_scenario_headless_with`] and returns one [`SweepRow`] per run.
+//! [`sweep_csv`] turns the rows into a results table.
+//!
+//! Axes address config fields by their `physics.toml` key; `f32`, `u32`,
+//! `usize` and `i32` fields can be swept (integer values are rounded).  Every
+//! run uses the spec's seed, so rows differ only by the swept values.
+//!
+//! Specs are TOML, the same shape as `assets/sweeps/gravity.toml`:
+//!
+//! ```toml
+//! scenario = "Field"
+//! seed = 7
+//! frames = 600
+//! mode = "grid"
+//!
+//! [[param]]
+//! field = "gravity_const"
+//! min = 5.0
+//! max = 20.0
+//! steps = 4
+//! ```
+//!
+//! `cargo run --release --example sweep -- <spec.toml> [out.csv]` runs a spec
+//! file and writes the table.
+
+use crate::config::{parse_physics_config, PhysicsConfig};
+use crate::headless::{run_scenario_headless_with, ScenarioReport};
+use crate::save::SaveScenario;
+use bevy::reflect::Struct;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use std::fmt::Write as _;
+
+/// How [`run_sweep`] picks combinations.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum SweepMode {
+    /// Every combination of every axis's `steps` evenly spaced values.
+    #[default]
+    Grid,
+    /// `samples` combinations drawn uniformly from each axis's range.
+    Random,
+}
+
+/// One swept config field.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SweepAxis {
+    /// `PhysicsConfig` field name, as in `physics.toml`.
+    pub field: String,
+    pub min: f32,
+    pub max: f32,
+    /// Grid points from `min` to `max` inclusive (grid mode only).
+    #[serde(default = "default_steps")]
+    pub steps: u32,
+}
+
+fn default_steps() -> u32 {
+    2
+}
+
+impl SweepAxis {
+    /// Evenly spaced grid values; a single step is just `min`.
+    pub fn grid_values(&self) -> Vec<f32> {
+        let steps = self.steps.max(1);
+        if steps == 1 {
+            return vec![self.min];
+        }
+        (0..steps)
+            .map(|i| self.min + (self.max - self.min) * i as f32 / (steps - 1) as f32)
+            .collect()
+    }
+}
+
+/// A full sweep description.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct SweepSpec {
+    pub scenario: SaveScenario,
+    pub seed: u64,
+    /// Physics ticks per run.
+    pub frames: u32,
+    #[serde(default)]
+    pub mode: SweepMode,
+    /// Random mode: combinations to draw.
+    #[serde(default)]
+    pub samples: u32,
+    /// Optional `physics.toml` to start from instead of compiled defaults.
+    #[serde(default)]
+    pub base: Option<String>,
+    #[serde(rename = "param")]
+    pub axes: Vec<SweepAxis>,
+}
+
+impl SweepSpec {
+    /// Parse a spec from TOML.
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        toml::from_str(contents).map_err(|err| format!("failed parsing sweep spec: {err}"))
+    }
+
+    /// The config every run starts from: `base` if set, else the defaults.
+    pub fn base_config(&self) -> Result<PhysicsConfig, String> {
+        let Some(path) = &self.base else {
+            return Ok(PhysicsConfig::default());
+        };
+        let contents =
+            std::fs::read_to_string(path).map_err(|err| format!("failed reading {path}: {err}"))?;
+        parse_physics_config(&contents).map_err(|err| format!("failed parsing {path}: {err}"))
+    }
+
+    /// Swept values per run, in axis order.
+    pub fn combinations(&self) -> Vec<Vec<f32>> {
+        if self.axes.is_empty() {
+            return vec![Vec::new()];
+        }
+        match self.mode {
+            SweepMode::Grid => {
+                let mut combos = vec![Vec::new()];
+                for axis in &self.axes {
+                    let values = axis.grid_values();
+                    combos = combos
+                        .into_iter()
+                        .flat_map(|combo| {
+                            values.iter().map(move |&value| {
+                                let mut next = combo.clone();
+                                next.push(value);
+                                next
+                            })
+                        })
+                        .collect();
+                }
+                combos
+            }
+            SweepMode::Random => {
+                let mut rng = StdRng::seed_from_u64(self.seed);
+                (0..self.samples)
+                    .map(|_| {
+                        self.axes
+                            .iter()
+                            .map(|axis| {
+                                let (lo, hi) = (axis.min.min(axis.max), axis.min.max(axis.max));
+                                if hi > lo {
+                                    rng.gen_range(lo..=hi)
+                                } else {
+                                    lo
+                                }
+                            })
+                            .collect()
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+/// One finished run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepRow {
+    /// Swept values, in axis order, as applied to the config.
+    pub values: Vec<f32>,
+    pub report: ScenarioReport,
+}
+
+/// Set a numeric `PhysicsConfig` field by name.
+///
+/// Integer fields take `value` rounded (and clamped at zero when unsigned).
+/// Returns the value actually stored.
+pub fn set_config_field(
+    config: &mut PhysicsConfig,
+    field: &str,
+    value: f32,
+) -> Result<f32, String> {
+    let Some(slot) = config.field_mut(field) else {
+        return Err(format!("unknown PhysicsConfig field `{field}`"));
+    };
+    if let Some(slot) = slot.try_downcast_mut::<f32>() {
+        *slot = value;
+        return Ok(value);
+    }
+    let rounded = value.round();
+    if let Some(slot) = slot.try_downcast_mut::<u32>() {
+        *slot = rounded.max(0.0) as u32;
+        return Ok(*slot as f32);
+    }
+    if let Some(slot) = slot.try_downcast_mut::<usize>() {
+        *slot = rounded.max(0.0) as usize;
+        return Ok(*slot as f32);
+    }
+    if let Some(slot) = slot.try_downcast_mut::<i32>() {
+        *slot = rounded as i32;
+        return Ok(*slot as f32);
+    }
+    Err(format!(
+        "PhysicsConfig field `{field}` is {}, not a number",
+        slot.reflect_type_path()
+    ))
+}
+
+/// Run every combination of `spec`.
+///
+/// All axes are checked before the first run, so a typo fails fast.
+/// `on_row` is called after each run, e.g. for progress output.
+pub fn run_sweep(
+    spec: &SweepSpec,
+    mut on_row: impl FnMut(usize, usize, &SweepRow),
+) -> Result<Vec<SweepRow>, String> {
+    let base = spec.base_config()?;
+    let mut probe = base.clone();
+    for axis in &spec.axes {
+        set_config_field(&mut probe, &axis.field, axis.min)?;
+    }
+
+    let combos = spec.combinations();
+    let mut rows = Vec::with_capacity(combos.len());
+    for (index, combo) in combos.iter().enumerate() {
+        let mut config = base.clone();
+        let mut values = Vec::with_capacity(combo.len());
+        for (axis, &value) in spec.axes.iter().zip(combo) {
+            values.push(set_config_field(&mut config, &axis.field, value)?);
+        }
+        let report =
+            run_scenario_headless_with(config, spec.scenario.into(), spec.seed, spec.frames);
+        let row = SweepRow { values, report };
+        on_row(index, combos.len(), &row);
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+/// Results table: one column per axis, then the report columns.
+///
+/// The mass histogram is one column of `;`-separated bucket counts.
+pub fn sweep_csv(axes: &[SweepAxis], rows: &[SweepRow]) -> String {
+    let mut out = String::new();
+    for axis in axes {
+        let _ = write!(out, "{},", axis.field);
+    }
+    out.push_str(
+        "bodies,total_mass,largest_mass,merges,sim_secs,frame_avg_ms,frame_p95_ms,mass_histogram\n",
+    );
+    for row in rows {
+        for value in &row.values {
+            let _ = write!(out, "{value},");
+        }
+        let report = &row.report;
+        let histogram = report
+            .mass_histogram
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<_>>()
+            .join(";");
+        let _ = writeln!(
+            out,
+            "{},{},{},{},{:.3},{:.3},{:.3},{}",
+            report.bodies,
+            report.total_mass,
+            report.largest_mass,
+            report.merges,
+            report.sim_secs,
+            report.frame_ms.avg,
+            report.frame_ms.p95,
+            histogram,
+        );
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn axis(field: &str, min: f32, max: f32, steps: u32) -> SweepAxis {
+        SweepAxis {
+            field: field.to_string(),
+            min,
+            max,
+            steps,
+        }
+    }
+
+    fn spec(mode: SweepMode, axes: Vec<SweepAxis>) -> SweepSpec {
+        SweepSpec {
+            scenario: SaveScenario::Field,
+            seed: 3,
+            frames: 20,
+            mode,
+            samples: 5,
+            base: None,
+            axes,
+        }
+    }
+
+    #[test]
+    fn grid_covers_every_combination() {
+        let spec = spec(
+            SweepMode::Grid,
+            vec![
+                axis("gravity_const", 5.0, 20.0, 4),
+                axis("physics_substeps", 1.0, 2.0, 2),
+            ],
+        );
+        let combos = spec.combinations();
+        assert_eq!(combos.len(), 8);
+        assert_eq!(combos[0], vec![5.0, 1.0]);
+        assert_eq!(combos[1], vec![5.0, 2.0]);
+        assert_eq!(combos[7], vec![20.0, 2.0]);
+    }
+
+    #[test]
+    fn random_draws_stay_in_range_and_repeat_per_seed() {
+        let spec = spec(SweepMode::Random, vec![axis("gravity_const", 5.0, 20.0, 0)]);
+        let combos = spec.combinations();
+        assert_eq!(combos.len(), 5);
+        assert!(combos.iter().all(|c| (5.0..=20.0).contains(&c[0])));
+        assert_eq!(combos, spec.combinations());
+    }
+
+    #[test]
+    fn fields_are_set_by_name() {
+        let mut config = PhysicsConfig::default();
+        assert_eq!(
+            set_config_field(&mut config, "gravity_const", 12.5),
+            Ok(12.5)
+        );
+        assert_eq!(config.gravity_const, 12.5);
+        assert_eq!(
+            set_config_field(&mut config, "physics_substeps", 2.6),
+            Ok(3.0)
+        );
+        assert_eq!(config.physics_substeps, 3);
+        assert!(set_config_field(&mut config, "no_such_field", 1.0).is_err());
+    }
+
+    #[test]
+    fn spec_parses_from_toml() {
+        let spec = SweepSpec::parse(
+            r#"
+            scenario = "Shower"
+            seed = 9
+            frames = 100
+            mode = "random"
+            samples = 3
+
+            [[param]]
+            field = "gravity_const"
+            min = 1.0
+            max = 2.0
+            "#,
+        )
+        .unwrap();
+        assert_eq!(spec.scenario, SaveScenario::Shower);
+        assert_eq!(spec.mode, SweepMode::Random);
+        assert_eq!(spec.axes, vec![axis("gravity_const", 1.0, 2.0, 2)]);
+    }
+
+    #[test]
+    fn sweep_writes_one_row_per_run() {
+        let spec = spec(SweepMode::Grid, vec![axis("gravity_const", 5.0, 20.0, 2)]);
+        let mut seen = 0;
+        let rows = run_sweep(&spec, |_, total, _| {
+            seen += 1;
+            assert_eq!(total, 2);
+        })
+        .unwrap();
+        assert_eq!(seen, 2);
+        assert_eq!(rows[0].values, vec![5.0]);
+        assert_eq!(rows[0].report.total_mass, rows[1].report.total_mass);
+
+        let csv = sweep_csv(&spec.axes, &rows);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].starts_with("gravity_const,bodies,"));
+        let columns = lines[0].split(',').count();
+        assert!(lines[1..].iter().all(|l| l.split(',').count() == columns));
+    }
+}
@@ -7,12 +7,14 @@ use crate::simulation;
 use crate::testing::{
     self, spawn_test_all_three, spawn_test_all_three_225_enemy5, spawn_test_baseline_100,
     spawn_test_baseline_225, spawn_test_culling_verification, spawn_test_enemy_combat_scripted,
+    spawn_test_enemy_planet_avoidance, spawn_test_enemy_planet_avoidance_baseline,
     spawn_test_gentle_approach, spawn_test_gravity, spawn_test_gravity_boundary,
     spawn_test_high_speed_collision, spawn_test_kdtree_only, spawn_test_large_small_pair,
-    spawn_test_mixed_content_225_enemy8, spawn_test_mixed_content_324_enemy12,
-    spawn_test_mixed_size_asteroids, spawn_test_near_miss, spawn_test_orbit_pair,
-    spawn_test_passing_asteroid, spawn_test_perf_benchmark, spawn_test_soft_boundary_only,
-    spawn_test_three_triangles, spawn_test_tidal_only, spawn_test_two_triangles, TestConfig,
+    spawn_test_missile_split, spawn_test_mixed_content_225_enemy8,
+    spawn_test_mixed_content_324_enemy12, spawn_test_mixed_size_asteroids, spawn_test_near_miss,
+    spawn_test_orbit_pair, spawn_test_passing_asteroid, spawn_test_perf_benchmark,
+    spawn_test_ring_formation, spawn_test_soft_boundary_only, spawn_test_three_triangles,
+    spawn_test_tidal_only, spawn_test_two_triangles, TestConfig,
 };
 
 pub fn configure_test_mode(app: &mut App, test_name: &str) {
@@ -21,7 +23,13 @@ pub fn configure_test_mode(app: &mut App, test_name: &str) {
         ..Default::default()
     });
 
-    add_test_startup_system(app, test_name);
+    if test_name == testing::SUITE_TEST_NAME {
+        testing::configure_test_suite(app);
+    } else if test_name == testing::DETERMINISM_TEST_NAME {
+        testing::configure_determinism_test(app);
+    } else {
+        add_test_startup_system(app, test_name);
+    }
 
     app.add_systems(
         Update,
@@ -38,15 +46,40 @@ pub fn configure_test_mode(app: &mut App, test_name: &str) {
             testing::test_logging_system,
             testing::orbit_pair_calibrate_and_track_system,
             testing::enemy_combat_observer_system,
+            testing::enemy_avoidance_observer_system,
+            testing::ring_formation_observer_system,
             testing::test_verification_system,
         )
             .chain()
-            .after(simulation::asteroid_formation_system),
+            .after(player::projectile_missile_planet_hit_system),
+    );
+
+    app.add_systems(
+        Startup,
+        lock_time_to_physics_tick.after(config::load_physics_config),
     );
 
     println!("Running test: {}", test_name);
 }
 
+/// Advance exactly one physics tick per frame and render raw tick poses, so
+/// `frame_limit` counts ticks and results match across machines and refresh
+/// rates.
+///
+/// `Time::delta` is then always one tick, so anything that measures real
+/// frame cost (the perf timer in `testing::verification::test_logging_system`)
+/// must read the wall clock instead.
+fn lock_time_to_physics_tick(
+    mut commands: Commands,
+    mut physics_config: ResMut<config::PhysicsConfig>,
+) {
+    let hz = simulation::fixed_step::physics_tick_hz(&physics_config);
+    commands.insert_resource(bevy::time::TimeUpdateStrategy::ManualDuration(
+        std::time::Duration::from_secs_f32(1.0 / hz),
+    ));
+    physics_config.physics_interpolation = false;
+}
+
 fn add_test_startup_system(app: &mut App, test_name: &str) {
     match test_name {
         "two_triangles" => app.add_systems(
@@ -89,6 +122,10 @@ fn add_test_startup_system(app: &mut App, test_name: &str) {
             Startup,
             spawn_test_gravity_boundary.after(config::load_physics_config),
         ),
+        "missile_split" => app.add_systems(
+            Startup,
+            spawn_test_missile_split.after(config::load_physics_config),
+        ),
         "passing_asteroid" => app.add_systems(
             Startup,
             spawn_test_passing_asteroid.after(config::load_physics_config),
@@ -143,12 +180,31 @@ fn add_test_startup_system(app: &mut App, test_name: &str) {
             Startup,
             spawn_test_orbit_pair.after(config::load_physics_config),
         ),
+        "ring_formation" => app.add_systems(
+            Startup,
+            spawn_test_ring_formation.after(config::load_physics_config),
+        ),
         "enemy_combat_scripted" => app.add_systems(
             Startup,
             (player::spawn_player, spawn_test_enemy_combat_scripted)
                 .chain()
                 .after(config::load_physics_config),
         ),
+        "enemy_planet_avoidance" => app.add_systems(
+            Startup,
+            (player::spawn_player, spawn_test_enemy_planet_avoidance)
+                .chain()
+                .after(config::load_physics_config),
+        ),
+        "enemy_planet_avoidance_baseline" => app.add_systems(
+            Startup,
+            (
+                player::spawn_player,
+                spawn_test_enemy_planet_avoidance_baseline,
+            )
+                .chain()
+                .after(config::load_physics_config),
+        ),
         _ => app.add_systems(
             Startup,
             spawn_test_two_triangles.after(config::load_physics_config),
@@ -1,5 +1,13 @@
 //! Testing utilities for the simulation
 
+#[path = "testing/bench.rs"]
+mod bench;
+#[path = "testing/determinism.rs"]
+mod determinism;
+#[path = "testing/enemy_avoidance.rs"]
+mod enemy_avoidance;
+#[path = "testing/ring_formation.rs"]
+mod ring_formation;
 #[path = "testing/scenarios_core.rs"]
 mod scenarios_core;
 #[path = "testing/scenarios_orbit.rs"]
@@ -8,16 +16,31 @@ mod scenarios_orbit;
 mod scenarios_performance;
 #[path = "testing/scripted_enemy_combat.rs"]
 mod scripted_enemy_combat;
+#[path = "testing/suite.rs"]
+mod suite;
 #[path = "testing/types.rs"]
 mod types;
 #[path = "testing/verification.rs"]
 mod verification;
 
+/// Only the library's headless runner reads this; the binary does not build it.
+#[allow(unused_imports)]
+pub use bench::FrameStats;
+pub use bench::{export_bench_report, is_perf_test, BenchReport, EntityCounts};
+pub use determinism::{configure_determinism_test, DETERMINISM_TEST_NAME};
+pub use enemy_avoidance::{
+    enemy_avoidance_observer_system, spawn_test_enemy_planet_avoidance,
+    spawn_test_enemy_planet_avoidance_baseline,
+};
+pub use ring_formation::{
+    ring_formation_observer_system, spawn_test_ring_formation, RING_TEST_BINS,
+    RING_TEST_MAX_RADIAL_RATIO, RING_TEST_MIN_BINS, RING_TEST_MIN_PARTICLES,
+};
 pub use scenarios_core::{
     spawn_test_culling_verification, spawn_test_gentle_approach, spawn_test_gravity,
     spawn_test_gravity_boundary, spawn_test_high_speed_collision, spawn_test_large_small_pair,
-    spawn_test_mixed_size_asteroids, spawn_test_near_miss, spawn_test_passing_asteroid,
-    spawn_test_three_triangles, spawn_test_two_triangles,
+    spawn_test_missile_split, spawn_test_mixed_size_asteroids, spawn_test_near_miss,
+    spawn_test_passing_asteroid, spawn_test_three_triangles, spawn_test_two_triangles,
 };
 pub use scenarios_orbit::{orbit_pair_calibrate_and_track_system, spawn_test_orbit_pair};
 pub use scenarios_performance::{
@@ -29,8 +52,13 @@ pub use scenarios_performance::{
 pub use scripted_enemy_combat::{
     enemy_combat_observer_system, enemy_combat_script_system, spawn_test_enemy_combat_scripted,
 };
+pub use suite::{configure_test_suite, TestSuite, SUITE_TEST_NAME};
 pub use types::{
-    EnemyCombatObservations, EnemyCombatScriptState, OrbitCentralBody, OrbitTestBody,
-    ScriptAsteroidTarget, ScriptEnemyTarget, TestConfig,
+    CombatScriptShot, EnemyAvoidanceObservations, EnemyCombatObservations, EnemyCombatScriptState,
+    OrbitCentralBody, OrbitTestBody, RingFormationObservations, ScriptAsteroidTarget,
+    ScriptEnemyTarget, TestConfig,
 };
 pub use verification::{test_logging_system, test_verification_system};
+
+/// Wave balance telemetry for tuning sessions; see [`crate::wave_telemetry`].
+pub use crate::wave_telemetry::WaveTelemetry;
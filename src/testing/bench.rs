@@ -0,0 +1,425 @@
+//! Structured results for the performance scenarios.
+//!
+//! At the end of a perf test, `test_verification_system` builds a
+//! [`BenchReport`] from the frames recorded in [`TestConfig`] and prints it.
+//! When `ACCRETION_BENCH_OUT=<path>` is set the report is also written to
+//! disk, so runs can be compared across commits and machines:
+//!
+//! - `*.csv` — one summary row per run appended to the file (the header is
+//!   written when the file is new or empty), so repeated runs build a table.
+//! - anything else — the full report as JSON, overwriting the file: the
+//!   summary plus a timeline of frame time, entity counts and live heap bytes
+//!   every [`BENCH_SAMPLE_STRIDE`] frames.
+//!
+//! Frame times are wall-clock: test mode advances `Time` by exactly one
+//! physics tick per frame, so `Time::delta` says nothing about cost.  The
+//! first [`BENCH_WARMUP_FRAMES`] frames are excluded from the statistics.
+
+use super::TestConfig;
+use crate::alloc_profile::{self, AllocProfileSnapshot};
+use crate::run_info::GAME_VERSION;
+use std::fmt::Write as _;
+use std::io::Write as _;
+
+/// Environment variable naming the results file.
+pub const BENCH_OUT_ENV: &str = "ACCRETION_BENCH_OUT";
+
+/// Frames skipped at the start of a run before statistics are taken.
+pub const BENCH_WARMUP_FRAMES: usize = 10;
+
+/// Frames between timeline samples in the JSON report.
+pub const BENCH_SAMPLE_STRIDE: usize = 10;
+
+/// Frame budget for 60 FPS, in milliseconds.
+pub const FRAME_BUDGET_MS: f32 = 16.7;
+
+/// Test scenarios that record frame timings.
+pub const PERF_TESTS: &[&str] = &[
+    "perf_benchmark",
+    "baseline_100",
+    "baseline_225",
+    "tidal_only",
+    "soft_boundary_only",
+    "kdtree_only",
+    "all_three",
+    "all_three_225_enemy5",
+    "mixed_content_225_enemy8",
+    "mixed_content_324_enemy12",
+];
+
+const CSV_HEADER: &str = "test,version,frames,frame_avg_ms,frame_min_ms,frame_max_ms,frame_p50_ms,frame_p95_ms,frame_p99_ms,pct_60fps,post_update_avg_ms,post_update_p95_ms,post_update_p99_ms,initial_asteroids,final_asteroids,peak_entities,alloc_peak_live_bytes,alloc_total_bytes,alloc_calls";
+
+pub fn is_perf_test(test_name: &str) -> bool {
+    PERF_TESTS.contains(&test_name)
+}
+
+/// Entity counts recorded once per frame.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct EntityCounts {
+    pub asteroids: usize,
+    pub enemies: usize,
+    /// Every entity in the world, including UI and particles.
+    pub entities: usize,
+    /// Live heap bytes; 0 unless `ACCRETION_ALLOC_PROFILE=1`.
+    pub live_bytes: usize,
+}
+
+/// Summary statistics over a series of durations (ms).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct FrameStats {
+    pub avg: f32,
+    pub min: f32,
+    pub max: f32,
+    pub p50: f32,
+    pub p95: f32,
+    pub p99: f32,
+}
+
+impl FrameStats {
+    pub fn from_samples(samples: &[f32]) -> Self {
+        if samples.is_empty() {
+            return Self::default();
+        }
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        Self {
+            avg: samples.iter().sum::<f32>() / samples.len() as f32,
+            min: sorted[0],
+            max: sorted[sorted.len() - 1],
+            p50: percentile(&sorted, 0.50),
+            p95: percentile(&sorted, 0.95),
+            p99: percentile(&sorted, 0.99),
+        }
+    }
+}
+
+/// Linear-interpolated percentile of already sorted values.
+pub fn percentile(sorted_values: &[f32], p: f32) -> f32 {
+    if sorted_values.is_empty() {
+        return 0.0;
+    }
+    if sorted_values.len() == 1 {
+        return sorted_values[0];
+    }
+
+    let rank = (sorted_values.len() - 1) as f32 * p.clamp(0.0, 1.0);
+    let low = rank.floor() as usize;
+    let high = (low + 1).min(sorted_values.len() - 1);
+    let frac = rank - low as f32;
+
+    sorted_values[low] * (1.0 - frac) + sorted_values[high] * frac
+}
+
+/// Values after the warm-up frames, or all of them for a short run.
+fn steady(values: &[f32]) -> &[f32] {
+    if values.len() > BENCH_WARMUP_FRAMES {
+        &values[BENCH_WARMUP_FRAMES..]
+    } else {
+        values
+    }
+}
+
+/// Results of one perf scenario run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    pub test_name: String,
+    pub version: &'static str,
+    pub frames: u32,
+    /// Frames that went into `frame` and `post_update`.
+    pub steady_frames: usize,
+    pub frame: FrameStats,
+    pub post_update: FrameStats,
+    /// Steady frames within [`FRAME_BUDGET_MS`].
+    pub frames_within_budget: usize,
+    /// Share of steady frames within [`FRAME_BUDGET_MS`], in percent.
+    pub pct_60fps: f32,
+    pub initial_asteroids: usize,
+    pub final_asteroids: usize,
+    /// Allocator counters at the end of the run, when profiling is on.
+    pub alloc: Option<AllocProfileSnapshot>,
+    pub frame_times: Vec<f32>,
+    pub post_update_times: Vec<f32>,
+    pub entity_counts: Vec<EntityCounts>,
+}
+
+impl BenchReport {
+    pub fn from_test(test_config: &TestConfig, final_asteroids: usize) -> Self {
+        let frames = steady(&test_config.perf_frame_times);
+        let within_budget = frames.iter().filter(|&&t| t <= FRAME_BUDGET_MS).count();
+        Self {
+            test_name: test_config.test_name.clone(),
+            version: GAME_VERSION,
+            frames: test_config.frame_count,
+            steady_frames: frames.len(),
+            frame: FrameStats::from_samples(frames),
+            post_update: FrameStats::from_samples(steady(&test_config.post_update_frame_times)),
+            frames_within_budget: within_budget,
+            pct_60fps: 100.0 * within_budget as f32 / frames.len().max(1) as f32,
+            initial_asteroids: test_config.initial_asteroid_count,
+            final_asteroids,
+            alloc: alloc_profile::is_enabled().then(alloc_profile::snapshot),
+            frame_times: test_config.perf_frame_times.clone(),
+            post_update_times: test_config.post_update_frame_times.clone(),
+            entity_counts: test_config.entity_counts.clone(),
+        }
+    }
+
+    pub fn peak_entities(&self) -> usize {
+        self.entity_counts
+            .iter()
+            .map(|c| c.entities)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// The summary as one CSV row, without a trailing newline.
+    pub fn to_csv_row(&self) -> String {
+        let alloc = self.alloc.unwrap_or_default();
+        format!(
+            "{},{},{},{:.3},{:.3},{:.3},{:.3},{:.3},{:.3},{:.1},{:.3},{:.3},{:.3},{},{},{},{},{},{}",
+            csv_field(&self.test_name),
+            self.version,
+            self.frames,
+            self.frame.avg,
+            self.frame.min,
+            self.frame.max,
+            self.frame.p50,
+            self.frame.p95,
+            self.frame.p99,
+            self.pct_60fps,
+            self.post_update.avg,
+            self.post_update.p95,
+            self.post_update.p99,
+            self.initial_asteroids,
+            self.final_asteroids,
+            self.peak_entities(),
+            alloc.peak_live_bytes,
+            alloc.total_alloc_bytes,
+            alloc.alloc_calls,
+        )
+    }
+
+    /// The full report, including the sampled timeline, as JSON.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        out.push_str("{\n");
+        let _ = writeln!(out, "  \"test\": {},", json_string(&self.test_name));
+        let _ = writeln!(out, "  \"version\": {},", json_string(self.version));
+        let _ = writeln!(out, "  \"frames\": {},", self.frames);
+        let _ = writeln!(out, "  \"steady_frames\": {},", self.steady_frames);
+        let _ = writeln!(out, "  \"frame_ms\": {},", stats_json(&self.frame));
+        let _ = writeln!(
+            out,
+            "  \"post_update_ms\": {},",
+            stats_json(&self.post_update)
+        );
+        let _ = writeln!(out, "  \"pct_60fps\": {},", json_number(self.pct_60fps));
+        let _ = writeln!(out, "  \"initial_asteroids\": {},", self.initial_asteroids);
+        let _ = writeln!(out, "  \"final_asteroids\": {},", self.final_asteroids);
+        let _ = writeln!(out, "  \"peak_entities\": {},", self.peak_entities());
+        match self.alloc {
+            Some(alloc) => {
+                let _ = writeln!(
+                    out,
+                    "  \"alloc\": {{\"live_bytes\": {}, \"peak_live_bytes\": {}, \"total_alloc_bytes\": {}, \"total_dealloc_bytes\": {}, \"net_bytes\": {}, \"alloc_calls\": {}, \"dealloc_calls\": {}, \"realloc_calls\": {}}},",
+                    alloc.live_bytes,
+                    alloc.peak_live_bytes,
+                    alloc.total_alloc_bytes,
+                    alloc.total_dealloc_bytes,
+                    alloc.net_bytes(),
+                    alloc.alloc_calls,
+                    alloc.dealloc_calls,
+                    alloc.realloc_calls,
+                );
+            }
+            None => out.push_str("  \"alloc\": null,\n"),
+        }
+
+        out.push_str("  \"samples\": [");
+        let samples = (0..self.frame_times.len()).step_by(BENCH_SAMPLE_STRIDE);
+        for (n, i) in samples.enumerate() {
+            let counts = self.entity_counts.get(i).copied().unwrap_or_default();
+            let post_update = self.post_update_times.get(i).copied().unwrap_or(0.0);
+            let _ = write!(
+                out,
+                "{}\n    {{\"frame\": {}, \"frame_ms\": {}, \"post_update_ms\": {}, \"asteroids\": {}, \"enemies\": {}, \"entities\": {}, \"live_bytes\": {}}}",
+                if n == 0 { "" } else { "," },
+                i + 1,
+                json_number(self.frame_times[i]),
+                json_number(post_update),
+                counts.asteroids,
+                counts.enemies,
+                counts.entities,
+                counts.live_bytes,
+            );
+        }
+        out.push_str("\n  ]\n}\n");
+        out
+    }
+
+    /// Write the report to `path`: append a CSV row for `.csv`, otherwise
+    /// overwrite with JSON.
+    pub fn write_to(&self, path: &str) -> std::io::Result<()> {
+        let is_csv = std::path::Path::new(path)
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("csv"));
+        if !is_csv {
+            return std::fs::write(path, self.to_json());
+        }
+
+        let needs_header = !std::fs::metadata(path).is_ok_and(|meta| meta.len() > 0);
+        let mut body = String::new();
+        if needs_header {
+            body.push_str(CSV_HEADER);
+            body.push('\n');
+        }
+        body.push_str(&self.to_csv_row());
+        body.push('\n');
+        std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(body.as_bytes()))
+    }
+}
+
+/// Write `report` to `ACCRETION_BENCH_OUT` if it is set.
+pub fn export_bench_report(report: &BenchReport) {
+    let Ok(path) = std::env::var(BENCH_OUT_ENV) else {
+        return;
+    };
+    if path.trim().is_empty() {
+        return;
+    }
+    let path =
+        crate::save::storage::tracked_output_path(&crate::save::storage::telemetry_dir(), &path);
+    match report.write_to(&path) {
+        Ok(()) => println!("  bench results written to {path}"),
+        Err(err) => println!("  ✗ failed to write bench results to {path}: {err}"),
+    }
+}
+
+fn stats_json(stats: &FrameStats) -> String {
+    format!(
+        "{{\"avg\": {}, \"min\": {}, \"max\": {}, \"p50\": {}, \"p95\": {}, \"p99\": {}}}",
+        json_number(stats.avg),
+        json_number(stats.min),
+        json_number(stats.max),
+        json_number(stats.p50),
+        json_number(stats.p95),
+        json_number(stats.p99),
+    )
+}
+
+/// JSON has no NaN or infinity; write those as `null`.
+fn json_number(value: f32) -> String {
+    if value.is_finite() {
+        format!("{value:.4}")
+    } else {
+        "null".to_string()
+    }
+}
+
+fn json_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_report() -> BenchReport {
+        let frame_times: Vec<f32> = (1..=30).map(|i| i as f32).collect();
+        BenchReport {
+            test_name: "baseline_100".to_string(),
+            version: "0.0.0",
+            frames: 30,
+            steady_frames: 20,
+            frame: FrameStats::from_samples(steady(&frame_times)),
+            post_update: FrameStats::default(),
+            frames_within_budget: 6,
+            pct_60fps: 30.0,
+            initial_asteroids: 100,
+            final_asteroids: 80,
+            alloc: None,
+            post_update_times: vec![0.5; frame_times.len()],
+            entity_counts: (0..frame_times.len())
+                .map(|i| EntityCounts {
+                    asteroids: 100 - i,
+                    enemies: 2,
+                    entities: 150 + i,
+                    live_bytes: 0,
+                })
+                .collect(),
+            frame_times,
+        }
+    }
+
+    #[test]
+    fn frame_stats_interpolate_percentiles() {
+        let samples: Vec<f32> = (0..=100).map(|i| i as f32).collect();
+        let stats = FrameStats::from_samples(&samples);
+
+        assert_eq!(stats.min, 0.0);
+        assert_eq!(stats.max, 100.0);
+        assert_eq!(stats.avg, 50.0);
+        assert_eq!(stats.p50, 50.0);
+        assert!((stats.p95 - 95.0).abs() < 1e-4);
+        assert_eq!(FrameStats::from_samples(&[]), FrameStats::default());
+        assert_eq!(percentile(&[1.0, 3.0], 0.5), 2.0);
+    }
+
+    #[test]
+    fn csv_export_writes_the_header_once_and_appends_rows() {
+        let path = std::env::temp_dir().join(format!("accretion_bench_{}.csv", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let report = sample_report();
+
+        report.write_to(path.to_str().unwrap()).unwrap();
+        report.write_to(path.to_str().unwrap()).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], CSV_HEADER);
+        let columns = CSV_HEADER.split(',').count();
+        assert!(lines[1..].iter().all(|l| l.split(',').count() == columns));
+        assert!(lines[1].starts_with("baseline_100,0.0.0,30,"));
+    }
+
+    #[test]
+    fn json_report_carries_stats_and_a_sampled_timeline() {
+        let json = sample_report().to_json();
+
+        assert!(json.contains("\"test\": \"baseline_100\""));
+        assert!(json.contains("\"alloc\": null"));
+        assert!(json.contains("\"peak_entities\": 179"));
+        assert_eq!(json.matches("\"asteroids\":").count(), 3);
+        assert!(json.contains("{\"frame\": 11, \"frame_ms\": 11.0000"));
+        assert_eq!(json.matches('{').count(), json.matches('}').count());
+        assert_eq!(json_string("a\"b\\c\n"), "\"a\\\"b\\\\c\\n\"");
+    }
+}
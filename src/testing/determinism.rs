@@ -0,0 +1,301 @@
+//! Frame-rate independence check.
+//!
+//! `ACCRETION_TEST=determinism` runs one seeded asteroid cluster once per
+//! entry of [`DETERMINISM_FRAME_RATES`] inside the same process.  Each run
+//! drives `Time` with a different simulated frame duration, so the fixed
+//! physics loop sees a different number of ticks per rendered frame (and
+//! `Update` systems see a different `delta`), with render interpolation on.
+//!
+//! The cluster is sampled after exactly [`DETERMINISM_TICKS`] physics ticks,
+//! counted in `FixedLast`.  Every run must end with the same bodies: same
+//! count, same masses, and positions within
+//! [`DETERMINISM_POSITION_TOLERANCE`] of the first run.  A system that
+//! integrates physics with the frame `delta` instead of the fixed tick shows
+//! up as drift or a different merge history, and the process exits with
+//! code 1.
+//!
+//! Between runs [`determinism_advance_system`] despawns the previous bodies,
+//! switches the frame duration, and spawns the cluster again one frame
+//! later, once the new duration is in effect.
+
+use super::TestConfig;
+use crate::asteroid::{
+    fragment_vertices_for_mass, spawn_asteroid_with_vertices, Asteroid, AsteroidSize, Planet,
+};
+use crate::config::PhysicsConfig;
+use crate::mining::OrePickup;
+use crate::particles::Particle;
+use bevy::app::AppExit;
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use bevy_rapier2d::prelude::Velocity;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::collections::VecDeque;
+use std::io::Write;
+use std::time::Duration;
+
+/// `ACCRETION_TEST` value that runs the determinism check.
+pub const DETERMINISM_TEST_NAME: &str = "determinism";
+
+/// Seed of the asteroid cluster.
+pub const DETERMINISM_SEED: u64 = 0x00AC_C2E7;
+
+/// Simulated frame rates (Hz), one run each.  Both differ from the physics
+/// tick so frames carry a fractional number of ticks.
+pub const DETERMINISM_FRAME_RATES: [f32; 2] = [144.0, 24.0];
+
+/// Physics ticks per run.
+pub const DETERMINISM_TICKS: u32 = 360;
+
+/// Largest allowed distance (world units) between matching bodies.
+pub const DETERMINISM_POSITION_TOLERANCE: f32 = 0.5;
+
+const DETERMINISM_BODIES: usize = 32;
+
+/// One body at the end of a run.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BodySample {
+    pub pos: Vec2,
+    pub mass: u32,
+}
+
+/// Match `run` against `reference` body by body: same count, same masses,
+/// and every body within `tolerance` of an unclaimed body of equal mass.
+/// Returns the largest distance between matched bodies.
+pub fn compare_runs(
+    reference: &[BodySample],
+    run: &[BodySample],
+    tolerance: f32,
+) -> Result<f32, String> {
+    if reference.len() != run.len() {
+        return Err(format!(
+            "{} bodies, expected {}",
+            run.len(),
+            reference.len()
+        ));
+    }
+    let mut expected_masses: Vec<u32> = reference.iter().map(|b| b.mass).collect();
+    let mut masses: Vec<u32> = run.iter().map(|b| b.mass).collect();
+    expected_masses.sort_unstable();
+    masses.sort_unstable();
+    if expected_masses != masses {
+        return Err(format!("masses {masses:?}, expected {expected_masses:?}"));
+    }
+
+    let mut claimed = vec![false; run.len()];
+    let mut max_drift = 0.0f32;
+    for body in reference {
+        let Some((index, drift)) = run
+            .iter()
+            .enumerate()
+            .filter(|(i, other)| !claimed[*i] && other.mass == body.mass)
+            .map(|(i, other)| (i, other.pos.distance(body.pos)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+        else {
+            return Err(format!("no match for mass {} body", body.mass));
+        };
+        if drift > tolerance {
+            return Err(format!(
+                "mass {} body at ({:.2}, {:.2}) drifted {drift:.3} u (tolerance {tolerance} u)",
+                body.mass, body.pos.x, body.pos.y
+            ));
+        }
+        claimed[index] = true;
+        max_drift = max_drift.max(drift);
+    }
+    Ok(max_drift)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DeterminismPhase {
+    /// Between runs: start the next one or finish.
+    Idle,
+    /// Frame duration switched; the cluster spawns next frame.
+    Spawning(f32),
+    /// Counting ticks at this frame rate.
+    Running(f32),
+}
+
+/// Progress of the determinism check.
+#[derive(Resource)]
+pub struct DeterminismRun {
+    pending: VecDeque<f32>,
+    phase: DeterminismPhase,
+    ticks: u32,
+    spawner: SystemId,
+    /// Final bodies per finished run, keyed by frame rate.
+    pub results: Vec<(f32, Vec<BodySample>)>,
+}
+
+/// Queue one run per frame rate and register the systems that drive them.
+pub fn configure_determinism_test(app: &mut App) {
+    let spawner = app.world_mut().register_system(spawn_determinism_cluster);
+    app.insert_resource(TestConfig {
+        // The per-scenario logging and verification stay quiet; this check
+        // prints its own verdict.
+        enabled: false,
+        test_name: DETERMINISM_TEST_NAME.to_string(),
+        ..default()
+    })
+    .insert_resource(DeterminismRun {
+        pending: DETERMINISM_FRAME_RATES.into_iter().collect(),
+        phase: DeterminismPhase::Idle,
+        ticks: 0,
+        spawner,
+        results: Vec::new(),
+    })
+    .add_systems(PreUpdate, determinism_advance_system)
+    .add_systems(FixedLast, determinism_tick_system);
+}
+
+/// Spawn the seeded cluster: small bodies on a slow swirl around the origin,
+/// close enough to attract, collide and merge within a run.
+pub fn spawn_determinism_cluster(mut commands: Commands, config: Res<PhysicsConfig>) {
+    let mut rng = StdRng::seed_from_u64(DETERMINISM_SEED);
+    let grey = Color::srgb(0.5, 0.5, 0.5);
+    for _ in 0..DETERMINISM_BODIES {
+        let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+        let radius = rng.gen_range(30.0..180.0);
+        let dir = Vec2::from_angle(angle);
+        let mass = rng.gen_range(1..=4);
+        let vertices = fragment_vertices_for_mass(mass, &mut rng, &config);
+        let entity =
+            spawn_asteroid_with_vertices(&mut commands, dir * radius, &vertices, grey, mass);
+        commands.entity(entity).insert(Velocity {
+            linvel: dir.perp() * rng.gen_range(3.0..9.0),
+            angvel: rng.gen_range(-0.5..0.5),
+        });
+    }
+}
+
+/// Start the next run once the previous one has been sampled, or compare the
+/// runs and exit when none are left.
+pub fn determinism_advance_system(world: &mut World) {
+    let Some(run) = world.get_resource::<DeterminismRun>() else {
+        return;
+    };
+    match run.phase {
+        DeterminismPhase::Running(_) => {}
+        DeterminismPhase::Spawning(fps) => {
+            let spawner = run.spawner;
+            if let Err(err) = world.run_system(spawner) {
+                error!("determinism spawner failed: {err}");
+            }
+            let mut run = world.resource_mut::<DeterminismRun>();
+            run.ticks = 0;
+            run.phase = DeterminismPhase::Running(fps);
+        }
+        DeterminismPhase::Idle => {
+            let next = world.resource_mut::<DeterminismRun>().pending.pop_front();
+            let Some(fps) = next else {
+                finish_determinism_test(world);
+                return;
+            };
+            clear_bodies(world);
+            world.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+                1.0 / fps as f64,
+            )));
+            // Run with render interpolation, as the game does.
+            world.resource_mut::<PhysicsConfig>().physics_interpolation = true;
+            world.resource_mut::<DeterminismRun>().phase = DeterminismPhase::Spawning(fps);
+            println!("\n── Determinism: {fps} FPS, {DETERMINISM_TICKS} ticks ──");
+        }
+    }
+}
+
+/// Count physics ticks and sample the bodies at [`DETERMINISM_TICKS`].
+#[allow(clippy::type_complexity)]
+pub fn determinism_tick_system(
+    mut run: ResMut<DeterminismRun>,
+    q: Query<(&Transform, &AsteroidSize), (With<Asteroid>, Without<Planet>)>,
+) {
+    let DeterminismPhase::Running(fps) = run.phase else {
+        return;
+    };
+    run.ticks += 1;
+    if run.ticks < DETERMINISM_TICKS {
+        return;
+    }
+    let samples: Vec<BodySample> = q
+        .iter()
+        .map(|(transform, size)| BodySample {
+            pos: transform.translation.truncate(),
+            mass: size.0,
+        })
+        .collect();
+    println!(
+        "  {} bodies, total mass {}",
+        samples.len(),
+        samples.iter().map(|b| b.mass).sum::<u32>()
+    );
+    run.results.push((fps, samples));
+    run.phase = DeterminismPhase::Idle;
+}
+
+fn clear_bodies(world: &mut World) {
+    let mut q_bodies =
+        world.query_filtered::<Entity, Or<(With<Asteroid>, With<Particle>, With<OrePickup>)>>();
+    let entities: Vec<Entity> = q_bodies.iter(world).collect();
+    for entity in entities {
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn();
+        }
+    }
+}
+
+fn finish_determinism_test(world: &mut World) {
+    let Some(run) = world.remove_resource::<DeterminismRun>() else {
+        return;
+    };
+    let mut failed = false;
+    if let Some(((reference_fps, reference), rest)) = run.results.split_first() {
+        for (fps, samples) in rest {
+            match compare_runs(reference, samples, DETERMINISM_POSITION_TOLERANCE) {
+                Ok(drift) => println!(
+                    "✓ PASS: determinism — {fps} FPS matches {reference_fps} FPS (max drift {drift:.4} u)"
+                ),
+                Err(err) => {
+                    failed = true;
+                    println!("✗ FAIL: determinism — {fps} FPS vs {reference_fps} FPS: {err}");
+                }
+            }
+        }
+    }
+    let _ = std::io::stdout().flush();
+    world.write_message(if failed {
+        AppExit::error()
+    } else {
+        AppExit::Success
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn body(x: f32, mass: u32) -> BodySample {
+        BodySample {
+            pos: Vec2::new(x, 0.0),
+            mass,
+        }
+    }
+
+    #[test]
+    fn matching_runs_report_the_largest_drift() {
+        let reference = [body(0.0, 1), body(10.0, 1), body(-40.0, 6)];
+        // Same bodies, different order, small drift.
+        let run = [body(-40.1, 6), body(10.0, 1), body(0.05, 1)];
+        let drift = compare_runs(&reference, &run, 0.5).unwrap();
+        assert!((drift - 0.1).abs() < 1e-4);
+    }
+
+    #[test]
+    fn diverging_runs_fail() {
+        let reference = [body(0.0, 1), body(10.0, 3)];
+        assert!(compare_runs(&reference, &[body(0.0, 1)], 0.5).is_err());
+        assert!(compare_runs(&reference, &[body(0.0, 2), body(10.0, 2)], 0.5).is_err());
+        assert!(compare_runs(&reference, &[body(0.0, 1), body(12.0, 3)], 0.5).is_err());
+    }
+}
@@ -0,0 +1,173 @@
+use crate::asteroid::{spawn_planet, Asteroid, Planet, Vertices};
+use crate::config::PhysicsConfig;
+use crate::enemy::{
+    Enemy, EnemyFireCooldown, EnemyHealth, EnemyRenderMarker, EnemySpawnState, EnemyStun,
+    EnemyThrustVfxTimer,
+};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::{
+    ActiveEvents, Collider, CollisionGroups, Damping, ExternalForce, Group, Restitution, RigidBody,
+    Velocity,
+};
+
+use super::{EnemyAvoidanceObservations, TestConfig};
+
+/// Planet centre used by both avoidance scenarios; the player sits at the
+/// origin, so every enemy's straight-line path to it crosses the planet.
+const AVOIDANCE_TEST_PLANET_POS: Vec2 = Vec2::new(0.0, 220.0);
+
+/// Enemies queued up behind the planet, facing the player.
+const AVOIDANCE_TEST_ENEMY_POSITIONS: [Vec2; 6] = [
+    Vec2::new(-30.0, 520.0),
+    Vec2::new(0.0, 560.0),
+    Vec2::new(30.0, 520.0),
+    Vec2::new(-15.0, 640.0),
+    Vec2::new(15.0, 640.0),
+    Vec2::new(0.0, 720.0),
+];
+
+/// Spawn six enemies whose direct path to the player runs through a planet.
+///
+/// Use with `ACCRETION_TEST=enemy_planet_avoidance cargo run --release`.  The
+/// run passes when no enemy strikes the planet.
+pub fn spawn_test_enemy_planet_avoidance(
+    commands: Commands,
+    test_config: ResMut<TestConfig>,
+    enemy_spawn_state: ResMut<EnemySpawnState>,
+    config: ResMut<PhysicsConfig>,
+) {
+    spawn_avoidance_scenario(
+        commands,
+        test_config,
+        enemy_spawn_state,
+        config,
+        "enemy_planet_avoidance",
+        true,
+    );
+}
+
+/// Same layout as [`spawn_test_enemy_planet_avoidance`] with avoidance
+/// disabled, reporting the strike count the steering is meant to reduce.
+///
+/// Use with `ACCRETION_TEST=enemy_planet_avoidance_baseline cargo run --release`.
+pub fn spawn_test_enemy_planet_avoidance_baseline(
+    commands: Commands,
+    test_config: ResMut<TestConfig>,
+    enemy_spawn_state: ResMut<EnemySpawnState>,
+    config: ResMut<PhysicsConfig>,
+) {
+    spawn_avoidance_scenario(
+        commands,
+        test_config,
+        enemy_spawn_state,
+        config,
+        "enemy_planet_avoidance_baseline",
+        false,
+    );
+}
+
+fn spawn_avoidance_scenario(
+    mut commands: Commands,
+    mut test_config: ResMut<TestConfig>,
+    mut enemy_spawn_state: ResMut<EnemySpawnState>,
+    mut config: ResMut<PhysicsConfig>,
+    test_name: &str,
+    avoidance_enabled: bool,
+) {
+    test_config.test_name = test_name.to_string();
+    test_config.frame_limit = 480;
+
+    config.enemy_avoidance_enabled = avoidance_enabled;
+    enemy_spawn_state.timer_secs = 10_000.0;
+    commands.insert_resource(EnemyAvoidanceObservations::default());
+
+    spawn_planet(&mut commands, AVOIDANCE_TEST_PLANET_POS, &config);
+
+    for pos in AVOIDANCE_TEST_ENEMY_POSITIONS {
+        let toward_player = (-pos).normalize_or_zero();
+        commands.spawn((
+            (
+                Enemy,
+                EnemyRenderMarker,
+                EnemyHealth {
+                    hp: config.enemy_base_hp,
+                    max_hp: config.enemy_base_hp,
+                },
+                EnemyFireCooldown { timer: 10_000.0 },
+                EnemyThrustVfxTimer { timer: 0.0 },
+                EnemyStun {
+                    remaining_secs: 0.0,
+                },
+            ),
+            Transform::from_translation(pos.extend(0.25)),
+            Visibility::default(),
+            RigidBody::Dynamic,
+            Collider::ball(config.enemy_collider_radius),
+            Velocity {
+                linvel: toward_player * config.enemy_max_speed,
+                angvel: 0.0,
+            },
+            ExternalForce::default(),
+            Damping {
+                linear_damping: config.enemy_linear_damping,
+                angular_damping: config.enemy_angular_damping,
+            },
+            Restitution::coefficient(0.25),
+            CollisionGroups::new(
+                Group::GROUP_5,
+                Group::GROUP_1 | Group::GROUP_2 | Group::GROUP_3,
+            ),
+            ActiveEvents::COLLISION_EVENTS,
+        ));
+    }
+
+    println!("✓ Spawned test: {test_name}");
+    println!(
+        "  Player at origin; planet at ({:.0},{:.0}); {} enemies behind it at full speed; avoidance {}",
+        AVOIDANCE_TEST_PLANET_POS.x,
+        AVOIDANCE_TEST_PLANET_POS.y,
+        AVOIDANCE_TEST_ENEMY_POSITIONS.len(),
+        if avoidance_enabled { "on" } else { "off" },
+    );
+}
+
+/// Record every enemy that touches the planet and how many enemies remain.
+#[allow(clippy::type_complexity)]
+pub fn enemy_avoidance_observer_system(
+    test_config: Res<TestConfig>,
+    observations: Option<ResMut<EnemyAvoidanceObservations>>,
+    config: Res<PhysicsConfig>,
+    q_planet: Query<(&Transform, &Vertices), (With<Asteroid>, With<Planet>)>,
+    q_enemy: Query<(Entity, &Transform), With<Enemy>>,
+) {
+    if !test_config.enabled || !test_config.test_name.starts_with("enemy_planet_avoidance") {
+        return;
+    }
+    let Some(mut observations) = observations else {
+        return;
+    };
+    let Ok((planet_tf, planet_vertices)) = q_planet.single() else {
+        return;
+    };
+
+    let planet_pos = planet_tf.translation.truncate();
+    let planet_radius = planet_vertices
+        .0
+        .iter()
+        .map(|v| v.length())
+        .fold(0.0, f32::max);
+    let strike_distance = planet_radius + config.enemy_collider_radius + 1.0;
+
+    observations.enemies_remaining = 0;
+    for (entity, transform) in q_enemy.iter() {
+        observations.enemies_remaining += 1;
+        if transform.translation.truncate().distance(planet_pos) <= strike_distance
+            && observations.struck.insert(entity)
+        {
+            println!(
+                "[Observe] frame {}: enemy {:?} struck the planet",
+                test_config.frame_count, entity
+            );
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::asteroid::{
+    fragment_vertices_for_mass, spawn_asteroid_with_vertices, spawn_massive_planet, AsteroidSize,
+    Planet,
+};
+use crate::config::PhysicsConfig;
+use crate::simulation::rings::{circular_orbit_speed, RingParticle};
+use bevy::prelude::*;
+use bevy_rapier2d::prelude::Velocity;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::f32::consts::TAU;
+
+use super::{RingFormationObservations, TestConfig};
+
+/// Gravitational mass of the scenario's planet (the Orbit scenario's).
+const RING_TEST_PLANET_MASS: u32 = 2800;
+
+/// Asteroids that fall towards the planet, evenly spaced around it.
+const RING_TEST_BODIES: usize = 8;
+
+/// Mass of each falling asteroid.
+const RING_TEST_BODY_MASS: u32 = 6;
+
+/// Starting distance of the asteroids from the planet centre (u).
+const RING_TEST_START_RADIUS: f32 = 230.0;
+
+/// Angular bins the ring is sorted into for the spread check.
+pub const RING_TEST_BINS: usize = 12;
+
+/// Fewest live ring particles for a pass.
+pub const RING_TEST_MIN_PARTICLES: usize = 16;
+
+/// Fewest occupied angular bins for a pass.
+pub const RING_TEST_MIN_BINS: usize = 8;
+
+/// Largest mean |radial speed| / speed for the orbits to count as circular.
+pub const RING_TEST_MAX_RADIAL_RATIO: f32 = 0.1;
+
+/// Spawn a planet and eight asteroids on eccentric orbits that dip inside
+/// its Roche radius.
+///
+/// Use with `ACCRETION_TEST=ring_formation cargo run --release`.  The run
+/// passes when the asteroids have been torn into ring particles no heavier
+/// than `ring_fragment_max_mass` that spread around the planet on nearly
+/// circular orbits.
+pub fn spawn_test_ring_formation(
+    mut commands: Commands,
+    mut test_config: ResMut<TestConfig>,
+    config: Res<PhysicsConfig>,
+) {
+    test_config.test_name = "ring_formation".to_string();
+    test_config.frame_limit = 3000;
+    commands.insert_resource(RingFormationObservations::default());
+
+    let planet_radius = config.planetoid_base_radius * 5.2;
+    spawn_massive_planet(
+        &mut commands,
+        Vec2::ZERO,
+        RING_TEST_PLANET_MASS,
+        planet_radius,
+        &config,
+    );
+
+    // 0.9× circular speed plus a slow fall: periapsis ≈ 130 u, inside the
+    // ≈ 190 u Roche radius and clear of the ≈ 96 u surface.
+    let orbit_speed = circular_orbit_speed(RING_TEST_PLANET_MASS, RING_TEST_START_RADIUS, &config);
+    let mut rng = StdRng::seed_from_u64(0x0121_6650);
+    for i in 0..RING_TEST_BODIES {
+        let dir = Vec2::from_angle(TAU * i as f32 / RING_TEST_BODIES as f32);
+        let vertices = fragment_vertices_for_mass(RING_TEST_BODY_MASS, &mut rng, &config);
+        let entity = spawn_asteroid_with_vertices(
+            &mut commands,
+            dir * RING_TEST_START_RADIUS,
+            &vertices,
+            Color::srgb(0.6, 0.6, 0.6),
+            RING_TEST_BODY_MASS,
+        );
+        commands
+            .entity(entity)
+            .insert(Velocity::linear(dir.perp() * orbit_speed * 0.9 - dir * 1.5));
+    }
+
+    println!("✓ Spawned ring_formation test");
+    println!(
+        "  Planet mass {RING_TEST_PLANET_MASS} at origin; {RING_TEST_BODIES} asteroids of mass \
+         {RING_TEST_BODY_MASS} falling from r={RING_TEST_START_RADIUS}; Roche scale {}",
+        config.roche_radius_scale
+    );
+}
+
+/// Record the ring particles' count, heaviest mass, angular spread and how
+/// circular their orbits are.
+pub fn ring_formation_observer_system(
+    test_config: Res<TestConfig>,
+    observations: Option<ResMut<RingFormationObservations>>,
+    q_planet: Query<&Transform, With<Planet>>,
+    q_particles: Query<(&Transform, &Velocity, &AsteroidSize), With<RingParticle>>,
+) {
+    if !test_config.enabled || test_config.test_name != "ring_formation" {
+        return;
+    }
+    let (Some(mut observations), Ok(planet)) = (observations, q_planet.single()) else {
+        return;
+    };
+    let center = planet.translation.truncate();
+
+    let mut bins = [false; RING_TEST_BINS];
+    let mut radial_ratio_sum = 0.0;
+    let mut particles = 0;
+    let mut heaviest = 0;
+    for (transform, velocity, size) in q_particles.iter() {
+        let offset = transform.translation.truncate() - center;
+        let angle = offset.y.atan2(offset.x).rem_euclid(TAU);
+        bins[((angle / TAU * RING_TEST_BINS as f32) as usize).min(RING_TEST_BINS - 1)] = true;
+        let speed = velocity.linvel.length();
+        if speed > f32::EPSILON {
+            radial_ratio_sum += velocity.linvel.dot(offset.normalize_or_zero()).abs() / speed;
+        }
+        particles += 1;
+        heaviest = heaviest.max(size.0);
+    }
+
+    observations.particles = particles;
+    observations.heaviest = heaviest;
+    observations.bins_filled = bins.iter().filter(|&&filled| filled).count();
+    observations.mean_radial_ratio = if particles > 0 {
+        radial_ratio_sum / particles as f32
+    } else {
+        1.0
+    };
+}
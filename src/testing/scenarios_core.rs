@@ -390,3 +390,49 @@ pub fn spawn_test_passing_asteroid(mut commands: Commands, mut test_config: ResM
     println!("    - Pull down (toward large) as it approaches");
     println!("    - Pull backward (opposite motion) after it passes");
 }
+
+/// Spawn test scenario: a missile splits a resting asteroid; the fragments
+/// must not merge back into each other.
+pub fn spawn_test_missile_split(
+    mut commands: Commands,
+    mut test_config: ResMut<TestConfig>,
+    config: Res<crate::config::PhysicsConfig>,
+) {
+    use crate::player::state::Missile;
+    use bevy_rapier2d::prelude::{
+        ActiveCollisionTypes, ActiveEvents, Ccd, Collider, CollisionGroups, Group, RigidBody,
+        Sensor,
+    };
+
+    test_config.test_name = "missile_split".to_string();
+    test_config.frame_limit = 300;
+
+    let radius = 14.0;
+    let hexagon: Vec<Vec2> = (0..6)
+        .map(|i| {
+            let angle = i as f32 * std::f32::consts::TAU / 6.0;
+            Vec2::new(radius * angle.cos(), radius * angle.sin())
+        })
+        .collect();
+    let grey = Color::srgb(0.5, 0.5, 0.5);
+    spawn_asteroid_with_vertices(&mut commands, Vec2::ZERO, &hexagon, grey, 8);
+
+    commands.spawn((
+        Missile::default(),
+        Transform::from_translation(Vec3::new(-80.0, 0.0, 0.0)),
+        Visibility::default(),
+        RigidBody::KinematicVelocityBased,
+        Velocity {
+            linvel: Vec2::X * config.missile_initial_speed,
+            angvel: 0.0,
+        },
+        Collider::ball(config.missile_collider_radius),
+        Sensor,
+        Ccd { enabled: true },
+        CollisionGroups::new(Group::GROUP_3, Group::GROUP_1 | Group::GROUP_5),
+        ActiveCollisionTypes::DYNAMIC_KINEMATIC,
+        ActiveEvents::COLLISION_EVENTS,
+    ));
+
+    println!("✓ Spawned test: Missile into a resting size-8 asteroid");
+}
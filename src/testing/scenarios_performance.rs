@@ -427,6 +427,7 @@ fn spawn_perf_player_missile(
             age: 0.0,
             distance_traveled: 0.0,
             trail_emit_timer: 0.0,
+            homing_target: None,
         },
         Transform::from_translation((start + dir * 16.0).extend(0.0)),
         Visibility::default(),
@@ -14,9 +14,10 @@ use bevy_rapier2d::prelude::{
 };
 
 use super::{
-    EnemyCombatObservations, EnemyCombatScriptState, ScriptAsteroidTarget, ScriptEnemyTarget,
-    TestConfig,
+    CombatScriptShot, EnemyCombatObservations, EnemyCombatScriptState, ScriptAsteroidTarget,
+    ScriptEnemyTarget, TestConfig,
 };
+use crate::campaign::script::{ScriptClock, ScriptStep, ScriptTimeline};
 
 /// Spawn deterministic entities for scripted enemy-combat verification.
 ///
@@ -30,7 +31,14 @@ pub fn spawn_test_enemy_combat_scripted(
     test_config.test_name = "enemy_combat_scripted".to_string();
     test_config.frame_limit = 180;
 
-    commands.insert_resource(EnemyCombatScriptState::default());
+    commands.insert_resource(EnemyCombatScriptState {
+        timeline: ScriptTimeline::new(vec![
+            ScriptStep::at(10.0, CombatScriptShot::PlayerAtEnemy),
+            ScriptStep::at(25.0, CombatScriptShot::EnemyAtPlayer),
+            ScriptStep::at(40.0, CombatScriptShot::EnemyAtAsteroid),
+        ]),
+        ..default()
+    });
     commands.insert_resource(EnemyCombatObservations::default());
 
     enemy_spawn_state.timer_secs = 10_000.0;
@@ -164,49 +172,55 @@ pub fn enemy_combat_script_system(
     let enemy_pos = enemy_tf.translation.truncate();
     let asteroid_pos = asteroid_tf.translation.truncate();
 
-    if frame >= 10 && !script_state.player_shot_spawned {
-        let dir = enemy_pos - player_pos;
-        spawn_scripted_player_projectile(
-            &mut commands,
-            player_pos + dir.normalize_or_zero() * 16.0,
-            dir,
-            config.projectile_speed,
-        );
-        script_state.player_shot_spawned = true;
-        println!(
-            "[Script] frame {}: spawned player projectile toward enemy",
-            frame
-        );
-    }
-
-    if frame >= 25 && !script_state.enemy_shot_player_spawned {
-        let dir = player_pos - enemy_pos;
-        spawn_scripted_enemy_projectile(
-            &mut commands,
-            enemy_pos + dir.normalize_or_zero() * (config.enemy_collider_radius + 6.0),
-            dir,
-            config.enemy_projectile_speed,
-        );
-        script_state.enemy_shot_player_spawned = true;
-        println!(
-            "[Script] frame {}: spawned enemy projectile toward player",
-            frame
-        );
-    }
-
-    if frame >= 40 && !script_state.enemy_shot_asteroid_spawned {
-        let dir = asteroid_pos - enemy_pos;
-        spawn_scripted_enemy_projectile(
-            &mut commands,
-            enemy_pos + dir.normalize_or_zero() * (config.enemy_collider_radius + 6.0),
-            dir,
-            config.enemy_projectile_speed,
-        );
-        script_state.enemy_shot_asteroid_spawned = true;
-        println!(
-            "[Script] frame {}: spawned enemy projectile toward asteroid",
-            frame
-        );
+    let clock = ScriptClock {
+        time: frame as f32,
+        ..default()
+    };
+    for shot in script_state.timeline.advance(&clock) {
+        match shot {
+            CombatScriptShot::PlayerAtEnemy => {
+                let dir = enemy_pos - player_pos;
+                spawn_scripted_player_projectile(
+                    &mut commands,
+                    player_pos + dir.normalize_or_zero() * 16.0,
+                    dir,
+                    config.projectile_speed,
+                );
+                script_state.player_shot_spawned = true;
+                println!(
+                    "[Script] frame {}: spawned player projectile toward enemy",
+                    frame
+                );
+            }
+            CombatScriptShot::EnemyAtPlayer => {
+                let dir = player_pos - enemy_pos;
+                spawn_scripted_enemy_projectile(
+                    &mut commands,
+                    enemy_pos + dir.normalize_or_zero() * (config.enemy_collider_radius + 6.0),
+                    dir,
+                    config.enemy_projectile_speed,
+                );
+                script_state.enemy_shot_player_spawned = true;
+                println!(
+                    "[Script] frame {}: spawned enemy projectile toward player",
+                    frame
+                );
+            }
+            CombatScriptShot::EnemyAtAsteroid => {
+                let dir = asteroid_pos - enemy_pos;
+                spawn_scripted_enemy_projectile(
+                    &mut commands,
+                    enemy_pos + dir.normalize_or_zero() * (config.enemy_collider_radius + 6.0),
+                    dir,
+                    config.enemy_projectile_speed,
+                );
+                script_state.enemy_shot_asteroid_spawned = true;
+                println!(
+                    "[Script] frame {}: spawned enemy projectile toward asteroid",
+                    frame
+                );
+            }
+        }
     }
 }
 
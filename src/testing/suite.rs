@@ -0,0 +1,312 @@
+//! Regression suite: every scripted pass/fail scenario in one process.
+//!
+//! `ACCRETION_TEST=all` queues [`SUITE_SCENARIOS`] instead of a single test.
+//! [`test_suite_advance_system`] starts each scenario in turn: it despawns the
+//! previous scenario's bodies and ships, restores the physics config captured
+//! before the first run, resets [`TestConfig`] and the per-test observation
+//! resources, then runs the scenario's spawn systems as one-shot systems.
+//! When `test_verification_system` reaches the frame limit it records the
+//! verdict in [`TestSuite`] instead of exiting.
+//!
+//! After the last scenario the summary table is printed and the app exits
+//! with a non-zero code if any scenario failed.  Perf benchmarks are left out
+//! (they have no pass/fail verdict), as is the avoidance baseline.
+//!
+//! Scenarios share one process, so RNG streams and caches carry over from
+//! earlier runs; a scenario that only fails under the suite usually depends
+//! on state it never set up.
+
+use super::{
+    spawn_test_culling_verification, spawn_test_enemy_combat_scripted,
+    spawn_test_enemy_planet_avoidance, spawn_test_gentle_approach, spawn_test_gravity,
+    spawn_test_gravity_boundary, spawn_test_high_speed_collision, spawn_test_large_small_pair,
+    spawn_test_missile_split, spawn_test_mixed_size_asteroids, spawn_test_near_miss,
+    spawn_test_orbit_pair, spawn_test_passing_asteroid, spawn_test_ring_formation,
+    spawn_test_three_triangles, spawn_test_two_triangles, EnemyAvoidanceObservations,
+    EnemyCombatObservations, EnemyCombatScriptState, RingFormationObservations, TestConfig,
+};
+use crate::asteroid::Asteroid;
+use crate::config::PhysicsConfig;
+use crate::enemy::{Boss, Enemy, EnemyProjectile, EnemySpawnState};
+use crate::mining::{MiningDrone, OrePickup};
+use crate::particles::Particle;
+use crate::player::state::{Missile, PlayerLives, Projectile};
+use crate::player::{spawn_player, Player};
+use crate::simulation::MissileTelemetry;
+use bevy::app::AppExit;
+use bevy::ecs::system::SystemId;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::io::Write;
+
+/// `ACCRETION_TEST` value that runs the whole suite.
+pub const SUITE_TEST_NAME: &str = "all";
+
+/// Scenarios run by the suite, in order.
+pub const SUITE_SCENARIOS: &[&str] = &[
+    "two_triangles",
+    "three_triangles",
+    "gravity",
+    "high_speed_collision",
+    "near_miss",
+    "gentle_approach",
+    "culling_verification",
+    "mixed_size_asteroids",
+    "large_small_pair",
+    "gravity_boundary",
+    "missile_split",
+    "passing_asteroid",
+    "orbit_pair",
+    "ring_formation",
+    "enemy_combat_scripted",
+    "enemy_planet_avoidance",
+];
+
+/// Outcome of one suite scenario.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SuiteResult {
+    pub scenario: &'static str,
+    pub passed: bool,
+    pub frames: u32,
+    /// The verdict line printed by the verification system.
+    pub verdict: String,
+}
+
+struct QueuedScenario {
+    name: &'static str,
+    /// One-shot spawn systems, run in order.
+    spawners: Vec<SystemId>,
+}
+
+/// Suite progress: scenarios still queued, the one running, and results.
+#[derive(Resource, Default)]
+pub struct TestSuite {
+    queue: VecDeque<QueuedScenario>,
+    running: Option<&'static str>,
+    base_config: Option<PhysicsConfig>,
+    pub results: Vec<SuiteResult>,
+}
+
+impl TestSuite {
+    /// Scenario currently running, if any.
+    #[cfg(test)]
+    pub fn running(&self) -> Option<&'static str> {
+        self.running
+    }
+
+    /// Record the running scenario's verdict; the next one starts next frame.
+    pub fn record(&mut self, passed: bool, frames: u32, verdict: &str) {
+        if let Some(scenario) = self.running.take() {
+            self.results.push(SuiteResult {
+                scenario,
+                passed,
+                frames,
+                verdict: verdict.trim().to_string(),
+            });
+        }
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.iter().filter(|r| !r.passed).count()
+    }
+}
+
+/// Register the suite's spawn systems and the system that advances it.
+pub fn configure_test_suite(app: &mut App) {
+    let world = app.world_mut();
+    let queue = SUITE_SCENARIOS
+        .iter()
+        .map(|&name| QueuedScenario {
+            name,
+            spawners: register_spawners(world, name),
+        })
+        .collect();
+    app.insert_resource(TestSuite { queue, ..default() })
+        .add_systems(PreUpdate, test_suite_advance_system);
+}
+
+fn register_spawners(world: &mut World, name: &str) -> Vec<SystemId> {
+    let scenario = match name {
+        "two_triangles" => world.register_system(spawn_test_two_triangles),
+        "three_triangles" => world.register_system(spawn_test_three_triangles),
+        "gravity" => world.register_system(spawn_test_gravity),
+        "high_speed_collision" => world.register_system(spawn_test_high_speed_collision),
+        "near_miss" => world.register_system(spawn_test_near_miss),
+        "gentle_approach" => world.register_system(spawn_test_gentle_approach),
+        "culling_verification" => world.register_system(spawn_test_culling_verification),
+        "mixed_size_asteroids" => world.register_system(spawn_test_mixed_size_asteroids),
+        "large_small_pair" => world.register_system(spawn_test_large_small_pair),
+        "gravity_boundary" => world.register_system(spawn_test_gravity_boundary),
+        "missile_split" => world.register_system(spawn_test_missile_split),
+        "passing_asteroid" => world.register_system(spawn_test_passing_asteroid),
+        "orbit_pair" => world.register_system(spawn_test_orbit_pair),
+        "ring_formation" => world.register_system(spawn_test_ring_formation),
+        "enemy_combat_scripted" => world.register_system(spawn_test_enemy_combat_scripted),
+        "enemy_planet_avoidance" => world.register_system(spawn_test_enemy_planet_avoidance),
+        other => panic!("no suite spawner for scenario {other}"),
+    };
+    if matches!(name, "enemy_combat_scripted" | "enemy_planet_avoidance") {
+        vec![world.register_system(spawn_player), scenario]
+    } else {
+        vec![scenario]
+    }
+}
+
+/// Start the next queued scenario once the previous one has been recorded,
+/// or print the summary and exit when the queue is empty.
+pub fn test_suite_advance_system(world: &mut World) {
+    let Some(suite) = world.get_resource::<TestSuite>() else {
+        return;
+    };
+    if suite.running.is_some() {
+        return;
+    }
+
+    let next = world.resource_mut::<TestSuite>().queue.pop_front();
+    let Some(next) = next else {
+        let suite = world.resource::<TestSuite>();
+        println!("{}", format_suite_summary(&suite.results));
+        let _ = std::io::stdout().flush();
+        let exit = if suite.failed() > 0 {
+            AppExit::error()
+        } else {
+            AppExit::Success
+        };
+        // The suite is done; drop it so the summary is printed only once.
+        world.remove_resource::<TestSuite>();
+        world.write_message(exit);
+        return;
+    };
+
+    reset_world_for_scenario(world);
+    world.resource_mut::<TestSuite>().running = Some(next.name);
+    println!("\n── Suite: {} ──", next.name);
+    for spawner in next.spawners {
+        if let Err(err) = world.run_system(spawner) {
+            error!("suite spawner for {} failed: {err}", next.name);
+        }
+    }
+}
+
+/// Clear the previous scenario's entities and per-test state.
+fn reset_world_for_scenario(world: &mut World) {
+    let mut q_gameplay = world.query_filtered::<Entity, Or<(
+        With<Asteroid>,
+        With<Player>,
+        With<Enemy>,
+        With<Boss>,
+        With<MiningDrone>,
+        With<Projectile>,
+        With<Missile>,
+        With<EnemyProjectile>,
+        With<Particle>,
+        With<OrePickup>,
+    )>>();
+    let entities: Vec<Entity> = q_gameplay.iter(world).collect();
+    for entity in entities {
+        if let Ok(entity) = world.get_entity_mut(entity) {
+            entity.despawn();
+        }
+    }
+
+    let config = world.resource::<PhysicsConfig>().clone();
+    let base = world
+        .resource_mut::<TestSuite>()
+        .base_config
+        .get_or_insert(config)
+        .clone();
+    world.insert_resource(base);
+
+    world.insert_resource(TestConfig {
+        enabled: true,
+        ..default()
+    });
+    world.insert_resource(MissileTelemetry::default());
+    world.insert_resource(EnemySpawnState::default());
+    if let Some(mut lives) = world.get_resource_mut::<PlayerLives>() {
+        lives.reset();
+    }
+    world.remove_resource::<EnemyCombatScriptState>();
+    world.remove_resource::<EnemyCombatObservations>();
+    world.remove_resource::<EnemyAvoidanceObservations>();
+    world.remove_resource::<RingFormationObservations>();
+}
+
+/// Summary table printed after the last scenario.
+pub fn format_suite_summary(results: &[SuiteResult]) -> String {
+    let failed = results.iter().filter(|r| !r.passed).count();
+    let mut out = String::from("\n╔════════════════════════════════════════════╗\n");
+    out.push_str("║           SUITE SUMMARY                    ║\n");
+    out.push_str("╚════════════════════════════════════════════╝\n");
+    for result in results {
+        out.push_str(&format!(
+            "{} {:<24} {:>5} frames  {}\n",
+            if result.passed { "✓" } else { "✗" },
+            result.scenario,
+            result.frames,
+            result.verdict
+        ));
+    }
+    out.push_str(&format!(
+        "\n{} passed, {} failed, {} total",
+        results.len() - failed,
+        failed,
+        results.len()
+    ));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_files_the_verdict_under_the_running_scenario() {
+        let mut suite = TestSuite {
+            running: Some("near_miss"),
+            ..default()
+        };
+        suite.record(
+            false,
+            300,
+            "✗ FAIL: Expected 2 separate asteroids, got 2 → 1\n",
+        );
+        // Nothing is running any more, so a stray second verdict is dropped.
+        suite.record(true, 301, "✓ PASS");
+
+        assert_eq!(suite.running(), None);
+        assert_eq!(
+            suite.results,
+            vec![SuiteResult {
+                scenario: "near_miss",
+                passed: false,
+                frames: 300,
+                verdict: "✗ FAIL: Expected 2 separate asteroids, got 2 → 1".to_string(),
+            }]
+        );
+        assert_eq!(suite.failed(), 1);
+    }
+
+    #[test]
+    fn summary_lists_every_scenario_and_the_totals() {
+        let results = vec![
+            SuiteResult {
+                scenario: "two_triangles",
+                passed: true,
+                frames: 100,
+                verdict: "✓ PASS: Two triangles combined".to_string(),
+            },
+            SuiteResult {
+                scenario: "orbit_pair",
+                passed: false,
+                frames: 400,
+                verdict: "✗ FAIL: orbit decayed".to_string(),
+            },
+        ];
+        let summary = format_suite_summary(&results);
+
+        assert!(summary.contains("✓ two_triangles"));
+        assert!(summary.contains("✗ orbit_pair"));
+        assert!(summary.ends_with("1 passed, 1 failed, 2 total"));
+    }
+}
@@ -1,4 +1,7 @@
+use super::EntityCounts;
+use crate::campaign::script::ScriptTimeline;
 use bevy::prelude::*;
+use std::collections::HashSet;
 
 /// Test configuration
 #[derive(Resource)]
@@ -8,10 +11,12 @@ pub struct TestConfig {
     pub frame_limit: u32,
     pub frame_count: u32,
     pub initial_asteroid_count: usize,
-    /// Per-frame delta times (seconds) recorded for perf_benchmark test
+    /// Per-frame wall-clock times (milliseconds) recorded for perf tests.
     pub perf_frame_times: Vec<f32>,
     /// Per-frame `PostUpdate` schedule durations (milliseconds) from `ProfilerStats`.
     pub post_update_frame_times: Vec<f32>,
+    /// Per-frame entity counts recorded for perf tests.
+    pub entity_counts: Vec<EntityCounts>,
     /// For orbit_pair test: set to true once the orbiting body's velocity has been
     /// calibrated from the actual Rapier mass read back by [`ReadMassProperties`].
     pub velocity_calibrated: bool,
@@ -31,6 +36,7 @@ impl Default for TestConfig {
             initial_asteroid_count: 0,
             perf_frame_times: Vec::new(),
             post_update_frame_times: Vec::new(),
+            entity_counts: Vec::new(),
             velocity_calibrated: false,
             orbit_initial_dist: 0.0,
             orbit_final_dist: 0.0,
@@ -54,9 +60,21 @@ pub struct ScriptEnemyTarget;
 #[derive(Component)]
 pub struct ScriptAsteroidTarget;
 
+/// Shots scheduled by the scripted enemy combat test.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombatScriptShot {
+    PlayerAtEnemy,
+    EnemyAtPlayer,
+    EnemyAtAsteroid,
+}
+
 /// Internal state machine for scripted enemy combat playback.
+///
+/// The shot schedule runs on the campaign [`ScriptTimeline`] with the frame
+/// count as its clock.
 #[derive(Resource, Default)]
 pub struct EnemyCombatScriptState {
+    pub timeline: ScriptTimeline<CombatScriptShot>,
     pub player_shot_spawned: bool,
     pub enemy_shot_player_spawned: bool,
     pub enemy_shot_asteroid_spawned: bool,
@@ -75,6 +93,27 @@ pub struct EnemyCombatObservations {
     pub particles_first_frame: Option<u32>,
 }
 
+/// Ring state at the latest frame of the `ring_formation` scenario.
+#[derive(Resource, Default)]
+pub struct RingFormationObservations {
+    /// Live ring particles.
+    pub particles: usize,
+    /// Heaviest ring particle.
+    pub heaviest: u32,
+    /// Angular bins around the planet holding at least one particle.
+    pub bins_filled: usize,
+    /// Mean of |radial speed| / speed over the particles.
+    pub mean_radial_ratio: f32,
+}
+
+/// Planet-strike tracking for the `enemy_planet_avoidance` scenarios.
+#[derive(Resource, Default)]
+pub struct EnemyAvoidanceObservations {
+    /// Enemies that came within contact distance of the planet at least once.
+    pub struck: HashSet<Entity>,
+    pub enemies_remaining: usize,
+}
+
 #[derive(Component)]
 #[allow(dead_code)]
 pub struct TestMarker(pub usize); // Initial index for tracking
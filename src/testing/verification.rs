@@ -1,19 +1,30 @@
 use crate::alloc_profile;
 use crate::asteroid::{Asteroid, Vertices};
+use crate::config::PhysicsConfig;
+use crate::enemy::Enemy;
 use crate::simulation::MissileTelemetry;
 use crate::simulation::ProfilerStats;
 use bevy::prelude::*;
 use bevy_rapier2d::prelude::{ExternalForce, Velocity};
 use std::io::Write;
+use std::time::Instant;
 
-use super::{EnemyCombatObservations, EnemyCombatScriptState, TestConfig};
+use super::{
+    export_bench_report, is_perf_test, BenchReport, EnemyAvoidanceObservations,
+    EnemyCombatObservations, EnemyCombatScriptState, EntityCounts, RingFormationObservations,
+    TestConfig, TestSuite, WaveTelemetry, RING_TEST_BINS, RING_TEST_MAX_RADIAL_RATIO,
+    RING_TEST_MIN_BINS, RING_TEST_MIN_PARTICLES,
+};
 
+#[allow(clippy::too_many_arguments)]
 pub fn test_logging_system(
     mut test_config: ResMut<TestConfig>,
-    time: Res<Time>,
+    mut last_frame: Local<Option<Instant>>,
     profiler_stats: Res<ProfilerStats>,
     missile_telemetry: Res<MissileTelemetry>,
     q: Query<(Entity, &Transform, &Velocity, &Vertices, &ExternalForce), With<Asteroid>>,
+    q_enemies: Query<(), With<Enemy>>,
+    q_entities: Query<()>,
 ) {
     if !test_config.enabled {
         return;
@@ -22,23 +33,26 @@ pub fn test_logging_system(
     test_config.frame_count += 1;
     let asteroid_count = q.iter().count();
 
-    let is_perf_test = test_config.test_name == "perf_benchmark"
-        || test_config.test_name == "baseline_100"
-        || test_config.test_name == "baseline_225"
-        || test_config.test_name == "tidal_only"
-        || test_config.test_name == "soft_boundary_only"
-        || test_config.test_name == "kdtree_only"
-        || test_config.test_name == "all_three"
-        || test_config.test_name == "all_three_225_enemy5"
-        || test_config.test_name == "mixed_content_225_enemy8"
-        || test_config.test_name == "mixed_content_324_enemy12";
-
-    if is_perf_test {
-        let dt_ms = time.delta_secs() * 1000.0;
-        test_config.perf_frame_times.push(dt_ms);
+    if is_perf_test(&test_config.test_name) {
+        // Wall-clock: test mode's `Time` advances one fixed tick per frame.
+        let now = Instant::now();
+        let frame_ms = last_frame
+            .replace(now)
+            .map_or(0.0, |last| (now - last).as_secs_f32() * 1000.0);
+        test_config.perf_frame_times.push(frame_ms);
         test_config
             .post_update_frame_times
             .push(profiler_stats.post_update_ms);
+        test_config.entity_counts.push(EntityCounts {
+            asteroids: asteroid_count,
+            enemies: q_enemies.iter().count(),
+            entities: q_entities.iter().count(),
+            live_bytes: if alloc_profile::is_enabled() {
+                alloc_profile::snapshot().live_bytes
+            } else {
+                0
+            },
+        });
 
         if test_config.frame_count == 1 {
             test_config.initial_asteroid_count = asteroid_count;
@@ -168,12 +182,18 @@ pub fn test_logging_system(
 }
 
 /// Verify test results at the end
+#[allow(clippy::too_many_arguments)]
 pub fn test_verification_system(
-    test_config: Res<TestConfig>,
+    mut test_config: ResMut<TestConfig>,
+    suite: Option<ResMut<TestSuite>>,
     missile_telemetry: Res<MissileTelemetry>,
     q: Query<(&Transform, &Vertices), With<Asteroid>>,
     enemy_combat_obs: Option<Res<EnemyCombatObservations>>,
     enemy_combat_script: Option<Res<EnemyCombatScriptState>>,
+    enemy_avoidance_obs: Option<Res<EnemyAvoidanceObservations>>,
+    ring_obs: Option<Res<RingFormationObservations>>,
+    config: Res<PhysicsConfig>,
+    wave_telemetry: Option<Res<WaveTelemetry>>,
     mut exit: MessageWriter<bevy::app::AppExit>,
 ) {
     if !test_config.enabled || test_config.frame_count != test_config.frame_limit {
@@ -190,6 +210,72 @@ pub fn test_verification_system(
     println!("Initial asteroids: {}", test_config.initial_asteroid_count);
     println!("Final asteroids:   {}", final_count);
 
+    if test_config.test_name.starts_with("enemy_planet_avoidance") {
+        let (struck, remaining) = enemy_avoidance_obs
+            .map(|obs| (obs.struck.len(), obs.enemies_remaining))
+            .unwrap_or((usize::MAX, 0));
+        println!("Planet strikes: {struck}");
+        println!("Enemies remaining: {remaining}");
+
+        let verdict = if test_config.test_name == "enemy_planet_avoidance_baseline" {
+            "ℹ BASELINE: enemy_planet_avoidance_baseline — avoidance disabled; compare strikes with enemy_planet_avoidance".to_string()
+        } else if struck == 0 {
+            "✓ PASS: enemy_planet_avoidance — no enemy struck the planet".to_string()
+        } else {
+            format!("✗ FAIL: enemy_planet_avoidance — {struck} enemies struck the planet")
+        };
+        println!("{verdict}");
+
+        finish_test(&mut test_config, suite, &verdict, &mut exit);
+        return;
+    }
+
+    if test_config.test_name == "ring_formation" {
+        let Some(ring) = ring_obs else {
+            let verdict = "✗ FAIL: ring_formation — no ring observations recorded";
+            println!("{verdict}");
+            finish_test(&mut test_config, suite, verdict, &mut exit);
+            return;
+        };
+        println!(
+            "Ring particles: {} (heaviest {})",
+            ring.particles, ring.heaviest
+        );
+        println!("Angular bins filled: {}/{RING_TEST_BINS}", ring.bins_filled);
+        println!("Mean |v_r|/|v|: {:.3}", ring.mean_radial_ratio);
+
+        let verdict = if ring.particles < RING_TEST_MIN_PARTICLES {
+            format!(
+                "✗ FAIL: ring_formation — only {} ring particles (need {RING_TEST_MIN_PARTICLES})",
+                ring.particles
+            )
+        } else if ring.heaviest > config.ring_fragment_max_mass {
+            format!(
+                "✗ FAIL: ring_formation — ring particle of mass {} exceeds ring_fragment_max_mass {}",
+                ring.heaviest, config.ring_fragment_max_mass
+            )
+        } else if ring.bins_filled < RING_TEST_MIN_BINS {
+            format!(
+                "✗ FAIL: ring_formation — debris fills {}/{RING_TEST_BINS} angular bins (need {RING_TEST_MIN_BINS})",
+                ring.bins_filled
+            )
+        } else if ring.mean_radial_ratio > RING_TEST_MAX_RADIAL_RATIO {
+            format!(
+                "✗ FAIL: ring_formation — orbits not circular; mean |v_r|/|v| = {:.3} > {RING_TEST_MAX_RADIAL_RATIO}",
+                ring.mean_radial_ratio
+            )
+        } else {
+            format!(
+                "✓ PASS: ring_formation — ring formed; {} particles over {}/{RING_TEST_BINS} bins, mean |v_r|/|v| = {:.3}",
+                ring.particles, ring.bins_filled, ring.mean_radial_ratio
+            )
+        };
+        println!("{verdict}");
+
+        finish_test(&mut test_config, suite, &verdict, &mut exit);
+        return;
+    }
+
     if test_config.test_name == "enemy_combat_scripted" {
         let mut player_shot = false;
         let mut enemy_player_shot = false;
@@ -246,14 +332,13 @@ pub fn test_verification_system(
             && enemy_damage_before_player_damage
             && enemy_damage_before_asteroid_hit;
 
-        if pass {
-            println!(
-                "✓ PASS: enemy_combat_scripted — scripted runtime collision contracts observed"
-            );
+        let verdict = if pass {
+            "✓ PASS: enemy_combat_scripted — scripted runtime collision contracts observed"
         } else {
-            println!(
-                "✗ FAIL: enemy_combat_scripted — one or more scripted collision outcomes missing"
-            );
+            "✗ FAIL: enemy_combat_scripted — one or more scripted collision outcomes missing"
+        };
+        println!("{verdict}");
+        if !pass {
             if !enemy_damage_pre_asteroid_leg {
                 println!(
                     "  Additional failure: enemy damage did not occur before asteroid-leg shot frame (40)."
@@ -276,8 +361,7 @@ pub fn test_verification_system(
             }
         }
 
-        let _ = std::io::stdout().flush();
-        exit.write(bevy::app::AppExit::Success);
+        finish_test(&mut test_config, suite, verdict, &mut exit);
         return;
     }
 
@@ -314,85 +398,51 @@ pub fn test_verification_system(
         );
     }
 
-    if (test_config.test_name == "perf_benchmark"
-        || test_config.test_name == "baseline_100"
-        || test_config.test_name == "baseline_225"
-        || test_config.test_name == "tidal_only"
-        || test_config.test_name == "soft_boundary_only"
-        || test_config.test_name == "kdtree_only"
-        || test_config.test_name == "all_three"
-        || test_config.test_name == "all_three_225_enemy5"
-        || test_config.test_name == "mixed_content_225_enemy8"
-        || test_config.test_name == "mixed_content_324_enemy12")
-        && !test_config.perf_frame_times.is_empty()
-    {
-        let times = &test_config.perf_frame_times;
-        let steady = if times.len() > 10 {
-            &times[10..]
-        } else {
-            times.as_slice()
-        };
-        let avg = steady.iter().sum::<f32>() / steady.len() as f32;
-        let min = steady.iter().cloned().fold(f32::INFINITY, f32::min);
-        let max = steady.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
-        let mut sorted = steady.to_vec();
-        sorted.sort_by(|a, b| a.total_cmp(b));
-        let p50 = percentile(&sorted, 0.50);
-        let p95 = percentile(&sorted, 0.95);
-        let p99 = percentile(&sorted, 0.99);
-        let over_budget = steady.iter().filter(|&&t| t > 16.7).count();
-        let pct_60fps = 100.0 * (steady.len() - over_budget) as f32 / steady.len() as f32;
-
-        let post_times = &test_config.post_update_frame_times;
-        let post_steady = if post_times.len() > 10 {
-            &post_times[10..]
-        } else {
-            post_times.as_slice()
-        };
-        let post_avg = post_steady.iter().sum::<f32>() / post_steady.len() as f32;
-        let post_min = post_steady.iter().cloned().fold(f32::INFINITY, f32::min);
-        let post_max = post_steady
-            .iter()
-            .cloned()
-            .fold(f32::NEG_INFINITY, f32::max);
-        let mut post_sorted = post_steady.to_vec();
-        post_sorted.sort_by(|a, b| a.total_cmp(b));
-        let post_p50 = percentile(&post_sorted, 0.50);
-        let post_p95 = percentile(&post_sorted, 0.95);
-        let post_p99 = percentile(&post_sorted, 0.99);
-
-        println!("\n── Timing summary (frames 10–{}) ──", times.len());
-        println!("  avg frame: {:.2}ms", avg);
-        println!("  min frame: {:.2}ms", min);
-        println!("  max frame: {:.2}ms", max);
-        println!("  p50 frame: {:.2}ms", p50);
-        println!("  p95 frame: {:.2}ms", p95);
-        println!("  p99 frame: {:.2}ms", p99);
+    if let Some(telemetry) = wave_telemetry.filter(|t| !t.waves.is_empty() || t.open.is_some()) {
+        print!("\n{}", telemetry.report());
+    }
+
+    if is_perf_test(&test_config.test_name) && !test_config.perf_frame_times.is_empty() {
+        let report = BenchReport::from_test(&test_config, final_count);
+        let frame = report.frame;
+        let post = report.post_update;
+
+        println!(
+            "\n── Timing summary (frames 10–{}) ──",
+            report.frame_times.len()
+        );
+        println!("  avg frame: {:.2}ms", frame.avg);
+        println!("  min frame: {:.2}ms", frame.min);
+        println!("  max frame: {:.2}ms", frame.max);
+        println!("  p50 frame: {:.2}ms", frame.p50);
+        println!("  p95 frame: {:.2}ms", frame.p95);
+        println!("  p99 frame: {:.2}ms", frame.p99);
         println!(
             "  frames at 60 FPS (≤16.7ms): {}/{} ({:.1}%)",
-            steady.len() - over_budget,
-            steady.len(),
-            pct_60fps
+            report.frames_within_budget, report.steady_frames, report.pct_60fps
         );
-        if avg <= 16.7 {
+        if frame.avg <= 16.7 {
             println!("  ✓ Average frame time within 60 FPS budget");
         } else {
-            println!("  ✗ Average frame time {:.2}ms exceeds 16.7ms budget", avg);
+            println!(
+                "  ✗ Average frame time {:.2}ms exceeds 16.7ms budget",
+                frame.avg
+            );
         }
 
         println!(
             "\n── PostUpdate schedule summary (frames 10–{}) ──",
-            post_times.len()
+            report.post_update_times.len()
         );
-        println!("  post_update avg: {:.3}ms", post_avg);
-        println!("  post_update min: {:.3}ms", post_min);
-        println!("  post_update max: {:.3}ms", post_max);
-        println!("  post_update p50: {:.3}ms", post_p50);
-        println!("  post_update p95: {:.3}ms", post_p95);
-        println!("  post_update p99: {:.3}ms", post_p99);
-
-        if alloc_profile::is_enabled() {
-            let snapshot = alloc_profile::snapshot();
+        println!("  post_update avg: {:.3}ms", post.avg);
+        println!("  post_update min: {:.3}ms", post.min);
+        println!("  post_update max: {:.3}ms", post.max);
+        println!("  post_update p50: {:.3}ms", post.p50);
+        println!("  post_update p95: {:.3}ms", post.p95);
+        println!("  post_update p99: {:.3}ms", post.p99);
+        println!("  peak entities: {}", report.peak_entities());
+
+        if let Some(snapshot) = report.alloc {
             println!("\n── Allocator profile summary ──");
             println!("  alloc live bytes: {}", snapshot.live_bytes);
             println!("  alloc peak live bytes: {}", snapshot.peak_live_bytes);
@@ -403,7 +453,15 @@ pub fn test_verification_system(
                 "  alloc calls: {} dealloc calls: {} realloc calls: {}",
                 snapshot.alloc_calls, snapshot.dealloc_calls, snapshot.realloc_calls
             );
+            for tag in alloc_profile::tag_snapshots() {
+                println!(
+                    "  alloc tag {}/{}: calls {} bytes {} dealloc bytes {}",
+                    tag.schedule, tag.system, tag.alloc_calls, tag.alloc_bytes, tag.dealloc_bytes
+                );
+            }
         }
+
+        export_bench_report(&report);
     }
 
     let result = verify_test_result(
@@ -415,25 +473,29 @@ pub fn test_verification_system(
         test_config.velocity_calibrated,
     );
     println!("{}\n", result);
-    let _ = std::io::stdout().flush();
 
-    exit.write(bevy::app::AppExit::Success);
+    finish_test(&mut test_config, suite, &result, &mut exit);
 }
 
-fn percentile(sorted_values: &[f32], p: f32) -> f32 {
-    if sorted_values.is_empty() {
-        return 0.0;
-    }
-    if sorted_values.len() == 1 {
-        return sorted_values[0];
+/// End the current test: exit the app, or under `ACCRETION_TEST=all` record
+/// the verdict and let the suite start the next scenario.
+fn finish_test(
+    test_config: &mut TestConfig,
+    suite: Option<ResMut<TestSuite>>,
+    verdict: &str,
+    exit: &mut MessageWriter<bevy::app::AppExit>,
+) {
+    let _ = std::io::stdout().flush();
+    match suite {
+        Some(mut suite) => {
+            let passed = !verdict.starts_with('✗') && !verdict.starts_with('?');
+            suite.record(passed, test_config.frame_count, verdict);
+            test_config.enabled = false;
+        }
+        None => {
+            exit.write(bevy::app::AppExit::Success);
+        }
     }
-
-    let rank = (sorted_values.len() - 1) as f32 * p.clamp(0.0, 1.0);
-    let low = rank.floor() as usize;
-    let high = (low + 1).min(sorted_values.len() - 1);
-    let frac = rank - low as f32;
-
-    sorted_values[low] * (1.0 - frac) + sorted_values[high] * frac
 }
 
 fn verify_test_result(
@@ -490,6 +552,19 @@ fn verify_test_result(
                 )
             }
         }
+        "missile_split" => {
+            if initial == 1 && final_count >= 2 {
+                format!(
+                    "✓ PASS: Missile split held without re-merging (1 → {})",
+                    final_count
+                )
+            } else {
+                format!(
+                    "✗ FAIL: Expected split fragments to stay apart, got {} → {}",
+                    initial, final_count
+                )
+            }
+        }
         "near_miss" => {
             if initial == 2 && final_count == 2 {
                 "✓ PASS: Two asteroids passed each other without merging (remained 2)".to_string()
@@ -0,0 +1,583 @@
+//! Time-lapse capture and playback.
+//!
+//! Recording is off unless `ACCRETION_TIMELAPSE_RECORD` names an output file
+//! (a bare file name is written under `replays/`, where the Settings storage
+//! panel counts and prunes it).  [`TimelapseRecordPlugin`] then appends a snapshot of every asteroid
+//! (position and mass) every `ACCRETION_TIMELAPSE_INTERVAL` seconds of
+//! simulation time (default 5).  Frames are appended as they are captured, so
+//! a long session never holds its history in memory and a crash loses at most
+//! the frame being written.
+//!
+//! Setting `ACCRETION_TIMELAPSE_PLAY` to a recorded file starts the companion
+//! viewer instead of the game ([`run_timelapse_playback`]).  It shows
+//! `ACCRETION_TIMELAPSE_FPS` captured frames per second (default 30), so an
+//! hour captured at 5 s intervals replays in 24 s.  `Space` pauses, `R`
+//! restarts, and playback loops at the end.
+//!
+//! ## File format
+//!
+//! Little-endian throughout; 12 bytes per body per frame.
+//!
+//! ```text
+//! header  b"ACTL"  version: u32  interval_secs: f32
+//! frame   sim_time_secs: f32  body_count: u32  body_count × (x: f32, y: f32, mass: u32)
+//! ```
+//!
+//! A truncated final frame (recording interrupted mid-write) is dropped on load.
+
+use crate::asteroid::{Asteroid, AsteroidSize};
+use crate::constants::ASTEROID_DENSITY;
+use crate::menu::GameState;
+use bevy::prelude::*;
+use bevy::window::WindowResolution;
+use std::fmt;
+use std::io::Write as _;
+
+const TIMELAPSE_MAGIC: &[u8; 4] = b"ACTL";
+const TIMELAPSE_VERSION: u32 = 1;
+const HEADER_BYTES: usize = 12;
+const FRAME_HEADER_BYTES: usize = 8;
+const BODY_BYTES: usize = 12;
+
+const DEFAULT_CAPTURE_INTERVAL_SECS: f32 = 5.0;
+const DEFAULT_PLAYBACK_FPS: f32 = 30.0;
+
+/// One captured body.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TimelapseBody {
+    pub position: Vec2,
+    pub mass: u32,
+}
+
+/// Every body at one capture instant.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TimelapseFrame {
+    /// Simulation seconds since recording started.
+    pub sim_time_secs: f32,
+    pub bodies: Vec<TimelapseBody>,
+}
+
+/// A decoded recording.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Timelapse {
+    pub interval_secs: f32,
+    pub frames: Vec<TimelapseFrame>,
+}
+
+impl Timelapse {
+    /// World-space bounding box over every body in every frame.
+    pub fn bounds(&self) -> Option<Rect> {
+        let mut points = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.bodies.iter().map(|body| body.position));
+        let first = points.next()?;
+        Some(points.fold(Rect::from_corners(first, first), |rect, p| {
+            rect.union_point(p)
+        }))
+    }
+}
+
+/// Why a time-lapse file could not be read.
+#[derive(Debug, PartialEq)]
+pub enum TimelapseError {
+    /// The file does not start with the `ACTL` header.
+    NotATimelapse,
+    /// Written by a newer or older recorder.
+    UnsupportedVersion(u32),
+}
+
+impl fmt::Display for TimelapseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TimelapseError::NotATimelapse => {
+                write!(f, "not a time-lapse file (missing ACTL header)")
+            }
+            TimelapseError::UnsupportedVersion(version) => write!(
+                f,
+                "unsupported time-lapse version {version} (expected {TIMELAPSE_VERSION})"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TimelapseError {}
+
+// ── Encoding ──────────────────────────────────────────────────────────────────
+
+/// Append the file header to `out`.
+pub fn encode_timelapse_header(interval_secs: f32, out: &mut Vec<u8>) {
+    out.extend_from_slice(TIMELAPSE_MAGIC);
+    out.extend_from_slice(&TIMELAPSE_VERSION.to_le_bytes());
+    out.extend_from_slice(&interval_secs.to_le_bytes());
+}
+
+/// Append one frame to `out`.
+pub fn encode_timelapse_frame(frame: &TimelapseFrame, out: &mut Vec<u8>) {
+    out.reserve(FRAME_HEADER_BYTES + frame.bodies.len() * BODY_BYTES);
+    out.extend_from_slice(&frame.sim_time_secs.to_le_bytes());
+    out.extend_from_slice(&(frame.bodies.len() as u32).to_le_bytes());
+    for body in &frame.bodies {
+        out.extend_from_slice(&body.position.x.to_le_bytes());
+        out.extend_from_slice(&body.position.y.to_le_bytes());
+        out.extend_from_slice(&body.mass.to_le_bytes());
+    }
+}
+
+fn read_u32(bytes: &[u8], at: usize) -> Option<u32> {
+    bytes
+        .get(at..at + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+fn read_f32(bytes: &[u8], at: usize) -> Option<f32> {
+    read_u32(bytes, at).map(f32::from_bits)
+}
+
+/// Decode a whole recording, dropping a truncated final frame.
+pub fn decode_timelapse(bytes: &[u8]) -> Result<Timelapse, TimelapseError> {
+    if bytes.len() < HEADER_BYTES || &bytes[..4] != TIMELAPSE_MAGIC {
+        return Err(TimelapseError::NotATimelapse);
+    }
+    let version = read_u32(bytes, 4).unwrap_or_default();
+    if version != TIMELAPSE_VERSION {
+        return Err(TimelapseError::UnsupportedVersion(version));
+    }
+    let mut timelapse = Timelapse {
+        interval_secs: read_f32(bytes, 8).unwrap_or_default(),
+        frames: Vec::new(),
+    };
+
+    let mut at = HEADER_BYTES;
+    while let (Some(sim_time_secs), Some(count)) = (read_f32(bytes, at), read_u32(bytes, at + 4)) {
+        let body_start = at + FRAME_HEADER_BYTES;
+        let end = body_start + count as usize * BODY_BYTES;
+        if end > bytes.len() {
+            break;
+        }
+        let bodies = bytes[body_start..end]
+            .chunks_exact(BODY_BYTES)
+            .map(|chunk| TimelapseBody {
+                position: Vec2::new(
+                    read_f32(chunk, 0).unwrap_or_default(),
+                    read_f32(chunk, 4).unwrap_or_default(),
+                ),
+                mass: read_u32(chunk, 8).unwrap_or_default(),
+            })
+            .collect();
+        timelapse.frames.push(TimelapseFrame {
+            sim_time_secs,
+            bodies,
+        });
+        at = end;
+    }
+    Ok(timelapse)
+}
+
+// ── Recording ─────────────────────────────────────────────────────────────────
+
+/// Where and how often [`TimelapseRecordPlugin`] captures frames.
+#[derive(Resource, Debug, Clone, PartialEq)]
+pub struct TimelapseRecordSettings {
+    pub path: String,
+    /// Simulation seconds between captures.
+    pub interval_secs: f32,
+}
+
+impl TimelapseRecordSettings {
+    /// Read `ACCRETION_TIMELAPSE_RECORD` (path) and
+    /// `ACCRETION_TIMELAPSE_INTERVAL` (seconds).  Returns `None` when
+    /// recording is disabled.
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var("ACCRETION_TIMELAPSE_RECORD").ok()?;
+        if path.trim().is_empty() {
+            return None;
+        }
+        let interval_secs = std::env::var("ACCRETION_TIMELAPSE_INTERVAL")
+            .ok()
+            .and_then(|value| value.parse::<f32>().ok())
+            .filter(|secs| secs.is_finite() && *secs > 0.0)
+            .unwrap_or(DEFAULT_CAPTURE_INTERVAL_SECS);
+        Some(Self {
+            path: crate::save::storage::tracked_output_path(
+                &crate::save::storage::replay_dir(),
+                &path,
+            ),
+            interval_secs,
+        })
+    }
+}
+
+/// Recorder state: simulation clock and reusable encode buffer.
+#[derive(Resource, Debug, Default)]
+pub struct TimelapseRecordState {
+    pub sim_time_secs: f32,
+    pub since_capture_secs: f32,
+    pub frames_written: u64,
+    /// The first write truncates the file and writes the header.
+    pub header_written: bool,
+    /// Set after a write error so a bad path warns once instead of every frame.
+    pub failed: bool,
+    frame: TimelapseFrame,
+    buffer: Vec<u8>,
+}
+
+/// Capture a frame every `interval_secs` of simulation time.
+///
+/// Uses virtual time while `Playing`, so pausing, the kill-cam slow-down,
+/// and menus do not stretch the recording.  The first frame is captured on
+/// the first playing frame.
+pub fn timelapse_record_system(
+    time: Res<Time>,
+    settings: Res<TimelapseRecordSettings>,
+    mut state: ResMut<TimelapseRecordState>,
+    q_asteroids: Query<(&Transform, &AsteroidSize), With<Asteroid>>,
+) {
+    if state.failed {
+        return;
+    }
+    let dt = time.delta_secs();
+    let due = !state.header_written || state.since_capture_secs + dt >= settings.interval_secs;
+    state.sim_time_secs += dt;
+    state.since_capture_secs += dt;
+    if !due {
+        return;
+    }
+    state.since_capture_secs = 0.0;
+
+    let state = &mut *state;
+    state.frame.sim_time_secs = state.sim_time_secs;
+    state.frame.bodies.clear();
+    state
+        .frame
+        .bodies
+        .extend(q_asteroids.iter().map(|(transform, size)| TimelapseBody {
+            position: transform.translation.truncate(),
+            mass: size.0,
+        }));
+
+    state.buffer.clear();
+    if !state.header_written {
+        encode_timelapse_header(settings.interval_secs, &mut state.buffer);
+    }
+    encode_timelapse_frame(&state.frame, &mut state.buffer);
+
+    let result = std::fs::OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(state.header_written)
+        .truncate(!state.header_written)
+        .open(&settings.path)
+        .and_then(|mut file| file.write_all(&state.buffer));
+    match result {
+        Ok(()) => {
+            state.header_written = true;
+            state.frames_written += 1;
+        }
+        Err(err) => {
+            state.failed = true;
+            warn!("time-lapse: failed to write {}: {err}", settings.path);
+        }
+    }
+}
+
+/// Registers [`timelapse_record_system`] when
+/// [`TimelapseRecordSettings::from_env`] finds an output path; otherwise adds
+/// nothing.
+pub struct TimelapseRecordPlugin;
+
+impl Plugin for TimelapseRecordPlugin {
+    fn build(&self, app: &mut App) {
+        let Some(settings) = TimelapseRecordSettings::from_env() else {
+            return;
+        };
+        info!(
+            "time-lapse: recording to {} every {}s of simulation time",
+            settings.path, settings.interval_secs
+        );
+        app.insert_resource(settings)
+            .init_resource::<TimelapseRecordState>()
+            .add_systems(
+                Update,
+                timelapse_record_system.run_if(in_state(GameState::Playing)),
+            );
+    }
+}
+
+// ── Playback ──────────────────────────────────────────────────────────────────
+
+/// The recording being replayed and the playback cursor.
+#[derive(Resource, Debug)]
+pub struct TimelapsePlayback {
+    pub timelapse: Timelapse,
+    /// Captured frames advanced per real second.
+    pub frames_per_sec: f32,
+    /// Fractional frame index.
+    pub cursor: f32,
+    pub paused: bool,
+}
+
+impl TimelapsePlayback {
+    /// Advance the cursor by `dt` real seconds, looping at the end.
+    pub fn advance(&mut self, dt: f32) {
+        let len = self.timelapse.frames.len() as f32;
+        if self.paused || len == 0.0 {
+            return;
+        }
+        self.cursor = (self.cursor + dt * self.frames_per_sec) % len;
+    }
+
+    pub fn current_frame(&self) -> Option<&TimelapseFrame> {
+        self.timelapse.frames.get(self.cursor as usize)
+    }
+}
+
+/// Marker for the playback status line.
+#[derive(Component)]
+pub struct TimelapseStatusText;
+
+/// Drawn radius for a body: the radius of a disc with the body's Rapier area.
+fn body_radius(mass: u32) -> f32 {
+    (mass as f32 / ASTEROID_DENSITY / std::f32::consts::PI)
+        .sqrt()
+        .max(1.5)
+}
+
+/// Format simulation seconds as `1h 02m 03s`.
+fn format_sim_time(secs: f32) -> String {
+    let total = secs.max(0.0) as u64;
+    let (h, m, s) = (total / 3600, (total / 60) % 60, total % 60);
+    if h > 0 {
+        format!("{h}h {m:02}m {s:02}s")
+    } else {
+        format!("{m}m {s:02}s")
+    }
+}
+
+/// Spawn a camera framing the whole recording plus the status text.
+fn setup_timelapse_playback(
+    mut commands: Commands,
+    playback: Res<TimelapsePlayback>,
+    windows: Query<&Window>,
+) {
+    let (width, height) = windows
+        .single()
+        .map(|window| (window.width(), window.height()))
+        .unwrap_or((1200.0, 680.0));
+    let bounds = playback
+        .timelapse
+        .bounds()
+        .unwrap_or(Rect::from_center_size(Vec2::ZERO, Vec2::splat(1000.0)));
+    let size = bounds.size().max(Vec2::splat(100.0)) * 1.1;
+    let scale = (size.x / width).max(size.y / height);
+
+    commands.spawn((
+        Camera2d,
+        Transform::from_translation(bounds.center().extend(0.0))
+            .with_scale(Vec3::new(scale, scale, 1.0)),
+    ));
+    commands.spawn((
+        Text::new(""),
+        TextFont {
+            font_size: 18.0,
+            ..default()
+        },
+        TextColor(Color::srgb(0.85, 0.85, 0.85)),
+        Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(12.0),
+            top: Val::Px(10.0),
+            ..default()
+        },
+        TimelapseStatusText,
+    ));
+}
+
+/// Handle `Space` (pause) and `R` (restart), then advance the cursor.
+fn timelapse_playback_input_system(
+    time: Res<Time<Real>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut playback: ResMut<TimelapsePlayback>,
+) {
+    if keys.just_pressed(KeyCode::Space) {
+        playback.paused = !playback.paused;
+    }
+    if keys.just_pressed(KeyCode::KeyR) {
+        playback.cursor = 0.0;
+    }
+    playback.advance(time.delta_secs());
+}
+
+/// Draw the current frame and refresh the status line.
+fn timelapse_playback_draw_system(
+    playback: Res<TimelapsePlayback>,
+    mut gizmos: Gizmos,
+    mut q_text: Query<&mut Text, With<TimelapseStatusText>>,
+) {
+    let Some(frame) = playback.current_frame() else {
+        return;
+    };
+    for body in &frame.bodies {
+        let t = (body.mass as f32).log10().clamp(0.0, 3.0) / 3.0;
+        let color = Color::srgb(0.6 + 0.4 * t, 0.6 + 0.2 * t, 0.6 - 0.3 * t);
+        gizmos.circle_2d(
+            Isometry2d::from_translation(body.position),
+            body_radius(body.mass),
+            color,
+        );
+    }
+
+    if let Ok(mut text) = q_text.single_mut() {
+        **text = format!(
+            "t = {}   frame {}/{}   bodies {}   {:.0}x{}",
+            format_sim_time(frame.sim_time_secs),
+            playback.cursor as usize + 1,
+            playback.timelapse.frames.len(),
+            frame.bodies.len(),
+            playback.frames_per_sec * playback.timelapse.interval_secs,
+            if playback.paused { "   [paused]" } else { "" },
+        );
+    }
+}
+
+/// Run the standalone time-lapse viewer for the file at `path`.
+///
+/// Called from `main` in place of the game when `ACCRETION_TIMELAPSE_PLAY`
+/// is set.  A bare file name that is not in the working directory is looked
+/// up in `replays/`, where recording puts it.
+pub fn run_timelapse_playback(path: &str) {
+    let recorded = crate::save::storage::replay_dir().join(path);
+    let path = if !std::path::Path::new(path).exists() && recorded.exists() {
+        recorded.to_string_lossy().into_owned()
+    } else {
+        path.to_string()
+    };
+    let timelapse = match std::fs::read(&path)
+        .map_err(|err| err.to_string())
+        .and_then(|bytes| decode_timelapse(&bytes).map_err(|err| err.to_string()))
+    {
+        Ok(timelapse) => timelapse,
+        Err(err) => {
+            eprintln!("time-lapse: cannot load {path}: {err}");
+            return;
+        }
+    };
+    if timelapse.frames.is_empty() {
+        eprintln!("time-lapse: {path} has no complete frames");
+        return;
+    }
+    let frames_per_sec = std::env::var("ACCRETION_TIMELAPSE_FPS")
+        .ok()
+        .and_then(|value| value.parse::<f32>().ok())
+        .filter(|fps| fps.is_finite() && *fps > 0.0)
+        .unwrap_or(DEFAULT_PLAYBACK_FPS);
+
+    App::new()
+        .add_plugins(DefaultPlugins.set(WindowPlugin {
+            primary_window: Some(Window {
+                title: "Accretion — Time-lapse".into(),
+                resolution: WindowResolution::new(1200, 680),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }))
+        .insert_resource(ClearColor(Color::BLACK))
+        .insert_resource(TimelapsePlayback {
+            timelapse,
+            frames_per_sec,
+            cursor: 0.0,
+            paused: false,
+        })
+        .add_systems(Startup, setup_timelapse_playback)
+        .add_systems(
+            Update,
+            (
+                timelapse_playback_input_system,
+                timelapse_playback_draw_system,
+            )
+                .chain(),
+        )
+        .run();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame(sim_time_secs: f32, bodies: &[(f32, f32, u32)]) -> TimelapseFrame {
+        TimelapseFrame {
+            sim_time_secs,
+            bodies: bodies
+                .iter()
+                .map(|&(x, y, mass)| TimelapseBody {
+                    position: Vec2::new(x, y),
+                    mass,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn frames_round_trip_through_the_file_format() {
+        let frames = vec![
+            frame(0.0, &[(1.0, -2.0, 3), (100.5, 40.25, 1200)]),
+            frame(5.0, &[(2.0, -1.0, 1203)]),
+            frame(10.0, &[]),
+        ];
+        let mut bytes = Vec::new();
+        encode_timelapse_header(5.0, &mut bytes);
+        for f in &frames {
+            encode_timelapse_frame(f, &mut bytes);
+        }
+        assert_eq!(
+            bytes.len(),
+            HEADER_BYTES + 3 * FRAME_HEADER_BYTES + 3 * BODY_BYTES
+        );
+
+        let decoded = decode_timelapse(&bytes).unwrap();
+        assert_eq!(decoded.interval_secs, 5.0);
+        assert_eq!(decoded.frames, frames);
+    }
+
+    #[test]
+    fn truncated_final_frame_is_dropped_and_bad_headers_rejected() {
+        let mut bytes = Vec::new();
+        encode_timelapse_header(2.0, &mut bytes);
+        encode_timelapse_frame(&frame(0.0, &[(0.0, 0.0, 1)]), &mut bytes);
+        encode_timelapse_frame(&frame(2.0, &[(1.0, 1.0, 1), (2.0, 2.0, 2)]), &mut bytes);
+        bytes.truncate(bytes.len() - 5);
+
+        assert_eq!(decode_timelapse(&bytes).unwrap().frames.len(), 1);
+        assert_eq!(
+            decode_timelapse(b"nope").unwrap_err(),
+            TimelapseError::NotATimelapse
+        );
+        bytes[4] = 99;
+        assert_eq!(
+            decode_timelapse(&bytes).unwrap_err(),
+            TimelapseError::UnsupportedVersion(99)
+        );
+    }
+
+    #[test]
+    fn playback_loops_and_respects_pause() {
+        let mut playback = TimelapsePlayback {
+            timelapse: Timelapse {
+                interval_secs: 5.0,
+                frames: vec![frame(0.0, &[]), frame(5.0, &[]), frame(10.0, &[])],
+            },
+            frames_per_sec: 2.0,
+            cursor: 0.0,
+            paused: false,
+        };
+        playback.advance(0.75);
+        assert_eq!(playback.current_frame().unwrap().sim_time_secs, 5.0);
+        playback.advance(0.75);
+        assert_eq!(playback.current_frame().unwrap().sim_time_secs, 0.0);
+
+        playback.paused = true;
+        playback.advance(10.0);
+        assert_eq!(playback.current_frame().unwrap().sim_time_secs, 0.0);
+        assert_eq!(format_sim_time(3723.0), "1h 02m 03s");
+    }
+}
@@ -0,0 +1,215 @@
+//! Practice victory: one body comes to dominate the field.
+//!
+//! Practice scenarios (all but Survival) are won once the most massive
+//! asteroid holds at least `victory_dominant_mass` units and
+//! `victory_dominant_share` of the field's asteroid mass.  Planets are fixed
+//! scenery and count toward neither.  [`victory_check_system`] looks every
+//! [`CHECK_INTERVAL_SECS`] and, on a win:
+//!
+//! 1. captures a [`VictorySummary`] of the run;
+//! 2. requests a [`CinematicPurpose::Victory`] orbit around the body (see
+//!    `graphics::cinematic`), during which the summary is overlaid;
+//! 3. on [`CinematicFinished`], moves to [`VictoryPhase::Screen`] and the
+//!    overlay offers **KEEP PLAYING** (see `menu/victory.rs`).
+//!
+//! A session is won at most once; the state resets when the next session
+//! leaves the main menu.
+
+use crate::asteroid::{Asteroid, AsteroidSize, Planet};
+use crate::config::PhysicsConfig;
+use crate::graphics::cinematic::{
+    cinematic_camera_system, CinematicFinished, CinematicFocus, CinematicPurpose, CinematicRequest,
+};
+use crate::menu::{GameState, SelectedGameMode, SelectedScenario};
+use crate::player::PlayerScore;
+use crate::session_stats::SessionStats;
+use bevy::prelude::*;
+
+/// Seconds between dominance checks.
+pub const CHECK_INTERVAL_SECS: f32 = 0.5;
+
+/// The most massive asteroid and its share of the field's mass.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DominantBody {
+    pub entity: Entity,
+    pub mass: u32,
+    pub share: f32,
+}
+
+impl DominantBody {
+    /// Whether this body wins the run under `config`.
+    pub fn wins(&self, config: &PhysicsConfig) -> bool {
+        config.victory_dominant_mass > 0
+            && self.mass >= config.victory_dominant_mass
+            && self.share >= config.victory_dominant_share
+    }
+}
+
+/// The largest of `bodies` (`(entity, mass)`), `None` for an empty field.
+pub fn dominant_body(bodies: impl IntoIterator<Item = (Entity, u32)>) -> Option<DominantBody> {
+    let mut total = 0u64;
+    let mut largest: Option<(Entity, u32)> = None;
+    for (entity, mass) in bodies {
+        total += mass as u64;
+        if largest.is_none_or(|(_, best)| mass > best) {
+            largest = Some((entity, mass));
+        }
+    }
+    let (entity, mass) = largest?;
+    Some(DominantBody {
+        entity,
+        mass,
+        share: if total > 0 {
+            (mass as f64 / total as f64) as f32
+        } else {
+            0.0
+        },
+    })
+}
+
+/// The run at the moment of victory.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct VictorySummary {
+    pub mass: u32,
+    pub share: f32,
+    pub time_secs: f32,
+    pub merges: u32,
+    pub score: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VictoryPhase {
+    /// No win yet.
+    #[default]
+    Watching,
+    /// The victory orbit is playing with the summary overlaid.
+    Cinematic,
+    /// The orbit ended; the victory screen waits for KEEP PLAYING.
+    Screen,
+    /// Won and dismissed; play continues without further checks.
+    Dismissed,
+}
+
+#[derive(Resource, Debug, Clone, Default)]
+pub struct VictoryState {
+    pub phase: VictoryPhase,
+    pub summary: Option<VictorySummary>,
+    check_timer_secs: f32,
+}
+
+/// Check for a dominant body and move through the victory phases.
+///
+/// Runs before `cinematic_camera_system`, so the [`CinematicFinished`] that
+/// ends the orbit is seen a frame later and the Enter press that skipped
+/// the orbit does not also dismiss the victory screen.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn victory_check_system(
+    time: Res<Time>,
+    config: Res<PhysicsConfig>,
+    mode: Res<SelectedGameMode>,
+    scenario: Res<SelectedScenario>,
+    stats: Res<SessionStats>,
+    score: Res<PlayerScore>,
+    mut state: ResMut<VictoryState>,
+    mut finished: MessageReader<CinematicFinished>,
+    mut cinematics: MessageWriter<CinematicRequest>,
+    q_bodies: Query<(Entity, &AsteroidSize), (With<Asteroid>, Without<Planet>)>,
+) {
+    let victory_finished = finished
+        .read()
+        .any(|done| done.purpose == CinematicPurpose::Victory);
+    match state.phase {
+        VictoryPhase::Cinematic if victory_finished => {
+            state.phase = VictoryPhase::Screen;
+            return;
+        }
+        VictoryPhase::Watching => {}
+        _ => return,
+    }
+    if *mode != SelectedGameMode::Practice || *scenario == SelectedScenario::Survival {
+        return;
+    }
+
+    state.check_timer_secs += time.delta_secs();
+    if state.check_timer_secs < CHECK_INTERVAL_SECS {
+        return;
+    }
+    state.check_timer_secs = 0.0;
+
+    let Some(body) = dominant_body(q_bodies.iter().map(|(e, size)| (e, size.0))) else {
+        return;
+    };
+    if !body.wins(&config) {
+        return;
+    }
+    info!(
+        "Victory: body of mass {} holds {:.0}% of the field",
+        body.mass,
+        body.share * 100.0
+    );
+    state.phase = VictoryPhase::Cinematic;
+    state.summary = Some(VictorySummary {
+        mass: body.mass,
+        share: body.share,
+        time_secs: stats.time_survived_secs,
+        merges: stats.asteroids_merged,
+        score: score.total(),
+    });
+    cinematics.write(CinematicRequest::orbit(
+        CinematicFocus::Entity(body.entity),
+        config.cinematic_victory_secs,
+        CinematicPurpose::Victory,
+        &config,
+    ));
+}
+
+/// Forget the previous session's victory.
+pub fn reset_victory_system(mut state: ResMut<VictoryState>) {
+    *state = VictoryState::default();
+}
+
+pub struct VictoryPlugin;
+
+impl Plugin for VictoryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<VictoryState>()
+            .add_systems(
+                Update,
+                victory_check_system
+                    .before(cinematic_camera_system)
+                    .run_if(in_state(GameState::Playing)),
+            )
+            .add_systems(OnExit(GameState::MainMenu), reset_victory_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dominant_body_is_the_largest_with_its_share() {
+        let mut world = World::new();
+        let small = world.spawn_empty().id();
+        let big = world.spawn_empty().id();
+        let body = dominant_body([(small, 20), (big, 60), (small, 20)]).unwrap();
+        assert_eq!(body.entity, big);
+        assert_eq!(body.mass, 60);
+        assert!((body.share - 0.6).abs() < 1e-6);
+        assert_eq!(dominant_body([]), None);
+
+        let mut config = PhysicsConfig {
+            victory_dominant_mass: 50,
+            victory_dominant_share: 0.5,
+            ..PhysicsConfig::default()
+        };
+        assert!(body.wins(&config));
+        config.victory_dominant_share = 0.7;
+        assert!(!body.wins(&config), "not dominant enough");
+        config.victory_dominant_share = 0.5;
+        config.victory_dominant_mass = 80;
+        assert!(!body.wins(&config), "not massive enough");
+        config.victory_dominant_mass = 0;
+        assert!(!body.wins(&config), "0 disables the victory");
+    }
+}
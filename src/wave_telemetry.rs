@@ -0,0 +1,486 @@
+//! Per-wave difficulty telemetry and the balance report built from it.
+//!
+//! [`wave_telemetry_system`] opens a [`WaveRecord`] whenever the wave director
+//! enters `ActiveWave` (campaign and survival both drive it) and closes it
+//! when the director moves on, or as uncleared on Game Over.  While a wave is
+//! open it records:
+//!
+//! - time to clear,
+//! - hull damage the ship took and how many times it was destroyed,
+//! - per [`EnemyArchetype`]: ships spawned, ships lost and their total
+//!   lifetime, and the damage and kills credited to the archetype.
+//!
+//! Damage arrives as [`PlayerDamaged`] messages written by each damage site
+//! (asteroid impacts, enemy rams, enemy shots).  Enemy shots carry the firing
+//! archetype in an [`EnemyShotSource`]; untagged enemy shots are boss fire.
+//!
+//! Each closed wave is logged as a `[wave_telemetry]` line alongside the
+//! `[missile_telemetry]` stream.  [`analyze_balance`] folds recorded waves
+//! into a [`BalanceReport`] that flags archetypes dealing well above or below
+//! the average damage per ship; `testing` prints it at the end of a test run.
+//!
+//! [`EnemyShotSource`]: crate::enemy::EnemyShotSource
+
+use crate::campaign::{CampaignWaveDirector, CampaignWavePhase};
+use crate::enemy::{Enemy, EnemyArchetype};
+use crate::menu::GameState;
+use crate::run_info::RunMetadata;
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fmt;
+
+/// Archetypes with fewer spawns than this are reported without a verdict.
+pub const BALANCE_MIN_SPAWNS: u32 = 5;
+
+/// Damage per ship above this multiple of the average is over-performing.
+pub const BALANCE_OVER_RATIO: f32 = 1.35;
+
+/// Damage per ship below this multiple of the average is under-performing.
+pub const BALANCE_UNDER_RATIO: f32 = 0.65;
+
+/// What damaged the ship.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DamageSource {
+    Asteroid,
+    Enemy(EnemyArchetype),
+    Boss,
+}
+
+/// Hull damage the ship just took, after shield absorption.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct PlayerDamaged {
+    pub amount: f32,
+    pub source: DamageSource,
+    /// The hit destroyed the ship.
+    pub fatal: bool,
+}
+
+/// One archetype's showing during one wave.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ArchetypeWaveStats {
+    pub spawned: u32,
+    /// Ships destroyed or otherwise removed during the wave.
+    pub lost: u32,
+    /// Summed lifetime of the `lost` ships.
+    pub lifetime_secs: f32,
+    pub damage_dealt: f32,
+    pub kills: u32,
+}
+
+/// Difficulty telemetry for one wave.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WaveRecord {
+    pub wave: u32,
+    /// `false` when the run ended mid-wave.
+    pub cleared: bool,
+    pub duration_secs: f32,
+    pub damage_taken: f32,
+    pub deaths: u32,
+    /// Indexed in [`EnemyArchetype::ALL`] order.
+    pub archetypes: [ArchetypeWaveStats; EnemyArchetype::ALL.len()],
+}
+
+impl WaveRecord {
+    pub fn archetype(&self, archetype: EnemyArchetype) -> &ArchetypeWaveStats {
+        &self.archetypes[archetype as usize]
+    }
+
+    pub fn archetype_mut(&mut self, archetype: EnemyArchetype) -> &mut ArchetypeWaveStats {
+        &mut self.archetypes[archetype as usize]
+    }
+
+    /// Body of the `[wave_telemetry]` log line.
+    pub fn summary(&self) -> String {
+        let archetypes: Vec<String> = EnemyArchetype::ALL
+            .iter()
+            .map(|&archetype| {
+                let stats = self.archetype(archetype);
+                format!(
+                    "{}{{spawned:{}, lost:{}, damage:{:.1}, kills:{}}}",
+                    archetype.label(),
+                    stats.spawned,
+                    stats.lost,
+                    stats.damage_dealt,
+                    stats.kills,
+                )
+            })
+            .collect();
+        format!(
+            "wave={} cleared={} secs={:.1} damage_taken={:.1} deaths={} {}",
+            self.wave,
+            self.cleared,
+            self.duration_secs,
+            self.damage_taken,
+            self.deaths,
+            archetypes.join(" "),
+        )
+    }
+}
+
+/// Wave records for the current session.
+#[derive(Resource, Debug, Default)]
+pub struct WaveTelemetry {
+    /// Closed waves, oldest first.
+    pub waves: Vec<WaveRecord>,
+    /// The wave in progress, if any.
+    pub open: Option<WaveRecord>,
+    /// Live enemies and the elapsed time they spawned at.
+    alive: HashMap<Entity, (EnemyArchetype, f32)>,
+}
+
+impl WaveTelemetry {
+    /// Balance report over every closed wave plus the one in progress.
+    pub fn report(&self) -> BalanceReport {
+        let mut waves = self.waves.clone();
+        waves.extend(self.open.clone());
+        analyze_balance(&waves)
+    }
+
+    fn close_open(&mut self, cleared: bool, run: &RunMetadata) {
+        let Some(mut record) = self.open.take() else {
+            return;
+        };
+        record.cleared = cleared;
+        info!("[wave_telemetry] run={} {}", run.stamp(), record.summary());
+        self.waves.push(record);
+    }
+}
+
+/// How an archetype compares with the others.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BalanceVerdict {
+    Overperforming,
+    Balanced,
+    Underperforming,
+    /// Fewer than [`BALANCE_MIN_SPAWNS`] spawns.
+    TooFewSamples,
+}
+
+impl BalanceVerdict {
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Overperforming => "OVER",
+            Self::Balanced => "ok",
+            Self::Underperforming => "UNDER",
+            Self::TooFewSamples => "n/a",
+        }
+    }
+}
+
+/// One archetype's totals across the analysed waves.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ArchetypeBalance {
+    pub archetype: EnemyArchetype,
+    pub spawned: u32,
+    pub damage_dealt: f32,
+    pub damage_per_spawn: f32,
+    pub kills: u32,
+    /// Mean lifetime of ships lost during waves (0 when none were lost).
+    pub mean_lifetime_secs: f32,
+    pub verdict: BalanceVerdict,
+}
+
+/// Balance summary produced by [`analyze_balance`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceReport {
+    pub waves: u32,
+    pub cleared: u32,
+    /// Mean time to clear over cleared waves.
+    pub mean_clear_secs: f32,
+    pub mean_damage_per_wave: f32,
+    pub deaths: u32,
+    /// In [`EnemyArchetype::ALL`] order.
+    pub archetypes: Vec<ArchetypeBalance>,
+}
+
+/// Fold wave records into a balance report.
+///
+/// Each archetype is judged on hull damage dealt per ship spawned, relative
+/// to the mean of all archetypes with at least [`BALANCE_MIN_SPAWNS`] spawns.
+/// When no sampled archetype dealt damage, every sampled archetype is
+/// `Balanced`.
+pub fn analyze_balance(waves: &[WaveRecord]) -> BalanceReport {
+    let cleared: Vec<&WaveRecord> = waves.iter().filter(|w| w.cleared).collect();
+    let mean_clear_secs = if cleared.is_empty() {
+        0.0
+    } else {
+        cleared.iter().map(|w| w.duration_secs).sum::<f32>() / cleared.len() as f32
+    };
+    let mean_damage_per_wave = if waves.is_empty() {
+        0.0
+    } else {
+        waves.iter().map(|w| w.damage_taken).sum::<f32>() / waves.len() as f32
+    };
+
+    let mut archetypes: Vec<ArchetypeBalance> = EnemyArchetype::ALL
+        .iter()
+        .map(|&archetype| {
+            let mut total = ArchetypeWaveStats::default();
+            for wave in waves {
+                let stats = wave.archetype(archetype);
+                total.spawned += stats.spawned;
+                total.lost += stats.lost;
+                total.lifetime_secs += stats.lifetime_secs;
+                total.damage_dealt += stats.damage_dealt;
+                total.kills += stats.kills;
+            }
+            ArchetypeBalance {
+                archetype,
+                spawned: total.spawned,
+                damage_dealt: total.damage_dealt,
+                damage_per_spawn: total.damage_dealt / total.spawned.max(1) as f32,
+                kills: total.kills,
+                mean_lifetime_secs: total.lifetime_secs / total.lost.max(1) as f32,
+                verdict: BalanceVerdict::TooFewSamples,
+            }
+        })
+        .collect();
+
+    let sampled: Vec<f32> = archetypes
+        .iter()
+        .filter(|a| a.spawned >= BALANCE_MIN_SPAWNS)
+        .map(|a| a.damage_per_spawn)
+        .collect();
+    let reference = sampled.iter().sum::<f32>() / sampled.len().max(1) as f32;
+    for entry in archetypes
+        .iter_mut()
+        .filter(|a| a.spawned >= BALANCE_MIN_SPAWNS)
+    {
+        let ratio = if reference > 0.0 {
+            entry.damage_per_spawn / reference
+        } else {
+            1.0
+        };
+        entry.verdict = if ratio > BALANCE_OVER_RATIO {
+            BalanceVerdict::Overperforming
+        } else if ratio < BALANCE_UNDER_RATIO {
+            BalanceVerdict::Underperforming
+        } else {
+            BalanceVerdict::Balanced
+        };
+    }
+
+    BalanceReport {
+        waves: waves.len() as u32,
+        cleared: cleared.len() as u32,
+        mean_clear_secs,
+        mean_damage_per_wave,
+        deaths: waves.iter().map(|w| w.deaths).sum(),
+        archetypes,
+    }
+}
+
+impl fmt::Display for BalanceReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Balance report: waves={} cleared={} mean_clear={:.1}s damage/wave={:.1} deaths={}",
+            self.waves, self.cleared, self.mean_clear_secs, self.mean_damage_per_wave, self.deaths,
+        )?;
+        for entry in &self.archetypes {
+            writeln!(
+                f,
+                "  {:<10} [{:>5}] spawned={} damage={:.1} damage/ship={:.2} kills={} mean_life={:.1}s",
+                entry.archetype.label(),
+                entry.verdict.label(),
+                entry.spawned,
+                entry.damage_dealt,
+                entry.damage_per_spawn,
+                entry.kills,
+                entry.mean_lifetime_secs,
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub struct WaveTelemetryPlugin;
+
+impl Plugin for WaveTelemetryPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<PlayerDamaged>()
+            .init_resource::<WaveTelemetry>()
+            // Runs in `Last`, ungated, so the fatal hit of a run-ending death
+            // is still counted on the frame Game Over is requested.
+            .add_systems(Last, wave_telemetry_system);
+    }
+}
+
+/// Open, fill and close wave records.
+#[allow(clippy::too_many_arguments)]
+pub fn wave_telemetry_system(
+    time: Res<Time>,
+    state: Res<State<GameState>>,
+    director: Res<CampaignWaveDirector>,
+    run: Res<RunMetadata>,
+    mut damage: MessageReader<PlayerDamaged>,
+    q_spawned: Query<(Entity, &EnemyArchetype), Added<EnemyArchetype>>,
+    mut removed: RemovedComponents<Enemy>,
+    mut telemetry: ResMut<WaveTelemetry>,
+) {
+    let now = time.elapsed_secs();
+
+    match state.get() {
+        GameState::Playing => {
+            let in_wave = director.phase == CampaignWavePhase::ActiveWave;
+            let same_wave = telemetry
+                .open
+                .as_ref()
+                .is_some_and(|w| w.wave == director.current_wave);
+            if !in_wave || !same_wave {
+                telemetry.close_open(true, &run);
+            }
+            if in_wave && !same_wave {
+                telemetry.open = Some(WaveRecord {
+                    wave: director.current_wave,
+                    ..default()
+                });
+            }
+            if let Some(open) = telemetry.open.as_mut() {
+                open.duration_secs += time.delta_secs();
+            }
+        }
+        GameState::GameOver => telemetry.close_open(false, &run),
+        _ => {}
+    }
+
+    for hit in damage.read() {
+        let Some(open) = telemetry.open.as_mut() else {
+            continue;
+        };
+        open.damage_taken += hit.amount;
+        open.deaths += u32::from(hit.fatal);
+        if let DamageSource::Enemy(archetype) = hit.source {
+            let stats = open.archetype_mut(archetype);
+            stats.damage_dealt += hit.amount;
+            stats.kills += u32::from(hit.fatal);
+        }
+    }
+
+    for (entity, &archetype) in q_spawned.iter() {
+        telemetry.alive.insert(entity, (archetype, now));
+        if let Some(open) = telemetry.open.as_mut() {
+            open.archetype_mut(archetype).spawned += 1;
+        }
+    }
+
+    for entity in removed.read() {
+        let Some((archetype, spawned_at)) = telemetry.alive.remove(&entity) else {
+            continue;
+        };
+        if let Some(open) = telemetry.open.as_mut() {
+            let stats = open.archetype_mut(archetype);
+            stats.lost += 1;
+            stats.lifetime_secs += (now - spawned_at).max(0.0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy::state::app::StatesPlugin;
+
+    fn record(chaser: (u32, f32), skirmisher: (u32, f32)) -> WaveRecord {
+        let mut wave = WaveRecord {
+            wave: 1,
+            cleared: true,
+            duration_secs: 30.0,
+            damage_taken: chaser.1 + skirmisher.1,
+            ..default()
+        };
+        wave.archetype_mut(EnemyArchetype::Chaser).spawned = chaser.0;
+        wave.archetype_mut(EnemyArchetype::Chaser).damage_dealt = chaser.1;
+        wave.archetype_mut(EnemyArchetype::Skirmisher).spawned = skirmisher.0;
+        wave.archetype_mut(EnemyArchetype::Skirmisher).damage_dealt = skirmisher.1;
+        wave
+    }
+
+    fn verdict(report: &BalanceReport, archetype: EnemyArchetype) -> BalanceVerdict {
+        report.archetypes[archetype as usize].verdict
+    }
+
+    #[test]
+    fn analyzer_flags_archetypes_far_from_the_average() {
+        let waves = [record((4, 20.0), (3, 60.0)), record((4, 20.0), (3, 60.0))];
+        let report = analyze_balance(&waves);
+
+        assert_eq!(report.waves, 2);
+        assert_eq!(report.cleared, 2);
+        assert!((report.mean_damage_per_wave - 80.0).abs() < 1e-4);
+        assert_eq!(
+            verdict(&report, EnemyArchetype::Skirmisher),
+            BalanceVerdict::Overperforming
+        );
+        assert_eq!(
+            verdict(&report, EnemyArchetype::Chaser),
+            BalanceVerdict::Underperforming
+        );
+    }
+
+    #[test]
+    fn analyzer_withholds_verdict_on_small_samples() {
+        let report = analyze_balance(&[record((8, 40.0), (2, 90.0))]);
+        assert_eq!(
+            verdict(&report, EnemyArchetype::Skirmisher),
+            BalanceVerdict::TooFewSamples
+        );
+        // The only sampled archetype is its own reference.
+        assert_eq!(
+            verdict(&report, EnemyArchetype::Chaser),
+            BalanceVerdict::Balanced
+        );
+    }
+
+    #[test]
+    fn system_records_a_wave_from_start_to_clear() {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin))
+            .insert_state(GameState::Playing)
+            .insert_resource(CampaignWaveDirector::default())
+            .insert_resource(RunMetadata::default())
+            .add_plugins(WaveTelemetryPlugin);
+
+        app.world_mut().resource_mut::<CampaignWaveDirector>().phase =
+            CampaignWavePhase::ActiveWave;
+        app.world_mut()
+            .resource_mut::<CampaignWaveDirector>()
+            .current_wave = 3;
+        let enemy = app
+            .world_mut()
+            .spawn((Enemy, EnemyArchetype::Skirmisher))
+            .id();
+        app.update();
+
+        app.world_mut().write_message(PlayerDamaged {
+            amount: 12.0,
+            source: DamageSource::Enemy(EnemyArchetype::Skirmisher),
+            fatal: false,
+        });
+        app.world_mut().write_message(PlayerDamaged {
+            amount: 30.0,
+            source: DamageSource::Asteroid,
+            fatal: true,
+        });
+        app.world_mut().despawn(enemy);
+        app.update();
+
+        app.world_mut().resource_mut::<CampaignWaveDirector>().phase =
+            CampaignWavePhase::InterWaveBreak;
+        app.update();
+
+        let telemetry = app.world().resource::<WaveTelemetry>();
+        assert!(telemetry.open.is_none());
+        assert_eq!(telemetry.waves.len(), 1);
+        let wave = &telemetry.waves[0];
+        assert_eq!(wave.wave, 3);
+        assert!(wave.cleared);
+        assert!((wave.damage_taken - 42.0).abs() < 1e-4);
+        assert_eq!(wave.deaths, 1);
+        let skirmisher = wave.archetype(EnemyArchetype::Skirmisher);
+        assert_eq!(skirmisher.spawned, 1);
+        assert_eq!(skirmisher.lost, 1);
+        assert!((skirmisher.damage_dealt - 12.0).abs() < 1e-4);
+        assert_eq!(skirmisher.kills, 0);
+    }
+}
@@ -9,6 +9,9 @@
 //! 3. `Playing` state persists across frames with no new transition request.
 //! 4. `insert_state` can force-start directly in `Playing` (test-mode path).
 //! 5. Campaign flow can transition through `CampaignSelect`.
+//!    The Settings screen is reachable from `MainMenu` and returns to it.
+//! 6. Rapid `Paused → MainMenu → Playing` cycles keep the Rapier pipeline off
+//!    until a fixed tick has synced the despawns.
 
 use accretion::campaign::{
     CampaignMissionCatalog, CampaignProgressionState, CampaignSession, CampaignWaveDirector,
@@ -24,9 +27,14 @@ use accretion::player::rendering::{
     AimIndicatorMesh, HealthBarBg, HealthBarFill, PlayerUiEntities,
 };
 use accretion::player::state::{Missile, MissileAmmo, PlayerLives, PlayerScore, Projectile};
+use accretion::rendering::OverlayState;
+use accretion::simulation::rapier_guard::{apply_physics_resume_system, PhysicsResumeGuard};
 use accretion::simulation::SimulationStats;
 use bevy::prelude::*;
 use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use bevy_rapier2d::prelude::RapierConfiguration;
+use std::time::Duration;
 
 // ── Helpers ───────────────────────────────────────────────────────────────────
 
@@ -165,6 +173,32 @@ fn transition_main_menu_to_campaign_select() {
     );
 }
 
+/// The Settings screen is a round trip from the main menu.
+#[test]
+fn transition_main_menu_to_settings_and_back() {
+    let mut app = app_with_default_state();
+    app.update();
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::Settings);
+    app.update();
+    assert_eq!(
+        *app.world().resource::<State<GameState>>().get(),
+        GameState::Settings
+    );
+
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(GameState::MainMenu);
+    app.update();
+    assert_eq!(
+        *app.world().resource::<State<GameState>>().get(),
+        GameState::MainMenu,
+        "Back must return to the main menu"
+    );
+}
+
 /// Integration coverage for the campaign Play Again path:
 ///
 /// `GameOver -> Playing` should clear stale runtime entities/resources and
@@ -212,14 +246,22 @@ fn campaign_game_over_play_again_resets_runtime_and_progression_state() {
         remaining: 0,
         respawn_timer: Some(1.0),
     });
-    app.insert_resource(PlayerOre { count: 77 });
+    app.insert_resource(PlayerOre {
+        raw: [77, 0, 0],
+        ..Default::default()
+    });
     app.insert_resource(MissileAmmo { count: 1 });
     app.insert_resource(SimulationStats {
         live_count: 42,
         culled_total: 3,
+        stored_count: 0,
         merged_total: 4,
         split_total: 5,
         destroyed_total: 6,
+        formation_deferred: 2,
+        formation_deferred_total: 8,
+        spatial_reinserted: 4,
+        spatial_rebuilds_total: 6,
     });
     app.insert_resource(EnemySpawnState {
         timer_secs: 0.1,
@@ -388,14 +430,22 @@ fn campaign_game_over_play_again_final_mission_keeps_no_next_mission_pending() {
         remaining: 0,
         respawn_timer: Some(0.7),
     });
-    app.insert_resource(PlayerOre { count: 11 });
+    app.insert_resource(PlayerOre {
+        raw: [11, 0, 0],
+        ..Default::default()
+    });
     app.insert_resource(MissileAmmo { count: 2 });
     app.insert_resource(SimulationStats {
         live_count: 7,
         culled_total: 1,
+        stored_count: 0,
         merged_total: 2,
         split_total: 3,
         destroyed_total: 4,
+        formation_deferred: 2,
+        formation_deferred_total: 8,
+        spatial_reinserted: 4,
+        spatial_rebuilds_total: 6,
     });
     app.insert_resource(EnemySpawnState {
         timer_secs: 0.1,
@@ -490,14 +540,22 @@ fn campaign_game_over_play_again_mission_one_clears_stale_pending_advance() {
         remaining: 0,
         respawn_timer: Some(1.2),
     });
-    app.insert_resource(PlayerOre { count: 9 });
+    app.insert_resource(PlayerOre {
+        raw: [9, 0, 0],
+        ..Default::default()
+    });
     app.insert_resource(MissileAmmo { count: 1 });
     app.insert_resource(SimulationStats {
         live_count: 9,
         culled_total: 1,
+        stored_count: 0,
         merged_total: 1,
         split_total: 2,
         destroyed_total: 3,
+        formation_deferred: 2,
+        formation_deferred_total: 8,
+        spatial_reinserted: 4,
+        spatial_rebuilds_total: 6,
     });
     app.insert_resource(EnemySpawnState {
         timer_secs: 0.2,
@@ -546,3 +604,109 @@ fn campaign_game_over_play_again_mission_one_clears_stale_pending_advance() {
     assert!(!progression.mission_failed);
     assert_eq!(progression.next_mission_pending_shop, None);
 }
+
+fn spawn_session_bodies(mut commands: Commands) {
+    for _ in 0..50 {
+        commands.spawn(accretion::asteroid::Asteroid);
+    }
+}
+
+fn pipeline_active(app: &mut App) -> bool {
+    let world = app.world_mut();
+    let mut query = world.query::<&RapierConfiguration>();
+    query.single(world).unwrap().physics_pipeline_active
+}
+
+fn set_state(app: &mut App, state: GameState) {
+    app.world_mut()
+        .resource_mut::<NextState<GameState>>()
+        .set(state.clone());
+    app.update();
+    assert_eq!(*app.world().resource::<State<GameState>>().get(), state);
+}
+
+/// Regression for parry2d "key not present" panics: cycling
+/// `Playing → Paused → MainMenu → Playing` faster than the fixed tick must
+/// never leave the pipeline live on a frame whose transition spawned or
+/// despawned bodies.  The resume lands only after a fixed tick has run.
+#[test]
+fn rapid_pause_menu_cycles_defer_physics_resume() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, StatesPlugin));
+    // Frames advance no time, so fixed ticks only run when the test asks.
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::ZERO));
+    app.insert_state(GameState::Playing);
+
+    app.init_resource::<PhysicsResumeGuard>();
+    app.init_resource::<PlayerUiEntities>();
+    app.init_resource::<PlayerScore>();
+    app.init_resource::<PlayerLives>();
+    app.init_resource::<OverlayState>();
+    app.init_resource::<SimulationStats>();
+    app.init_resource::<PlayerOre>();
+    app.init_resource::<CampaignSession>();
+    app.world_mut().spawn(RapierConfiguration::new(1.0));
+
+    app.add_systems(OnEnter(GameState::Paused), accretion::menu::pause_physics);
+    app.add_systems(
+        OnTransition {
+            exited: GameState::Paused,
+            entered: GameState::Playing,
+        },
+        accretion::menu::resume_physics,
+    );
+    app.add_systems(
+        OnTransition {
+            exited: GameState::Paused,
+            entered: GameState::MainMenu,
+        },
+        accretion::menu::cleanup_game_world,
+    );
+    app.add_systems(
+        OnTransition {
+            exited: GameState::MainMenu,
+            entered: GameState::Playing,
+        },
+        (spawn_session_bodies, accretion::menu::resume_physics).chain(),
+    );
+    app.add_systems(FixedPostUpdate, apply_physics_resume_system);
+
+    app.update();
+    assert!(pipeline_active(&mut app));
+
+    for cycle in 0..5 {
+        set_state(&mut app, GameState::Paused);
+        assert!(!pipeline_active(&mut app), "cycle {cycle}: paused");
+
+        set_state(&mut app, GameState::MainMenu);
+        assert!(!pipeline_active(&mut app), "cycle {cycle}: menu");
+        let world = app.world_mut();
+        let asteroids = world
+            .query_filtered::<(), With<accretion::asteroid::Asteroid>>()
+            .iter(world)
+            .count();
+        assert_eq!(asteroids, 0, "cycle {cycle}: world cleaned up");
+
+        set_state(&mut app, GameState::Playing);
+        assert!(
+            !pipeline_active(&mut app),
+            "cycle {cycle}: pipeline must stay off until a tick syncs the new world"
+        );
+        assert!(app.world().resource::<PhysicsResumeGuard>().pending);
+
+        // Unpausing and pausing again before a tick drops the resume.
+        set_state(&mut app, GameState::Paused);
+        set_state(&mut app, GameState::Playing);
+        set_state(&mut app, GameState::Paused);
+        app.world_mut().run_schedule(FixedPostUpdate);
+        assert!(!pipeline_active(&mut app), "cycle {cycle}: re-paused");
+
+        set_state(&mut app, GameState::Playing);
+        app.world_mut().run_schedule(FixedPostUpdate);
+        assert!(
+            pipeline_active(&mut app),
+            "cycle {cycle}: resumed after tick"
+        );
+        assert!(!app.world().resource::<PhysicsResumeGuard>().pending);
+    }
+}
@@ -56,15 +56,12 @@ fn run_scenario_and_assert_pass(scenario: &str, expected_fragment: &str, timeout
     let log_path = log_root().join(format!("{scenario}.log"));
     fs::write(&log_path, &combined).expect("failed writing extended scenario log");
 
-    let marker = combined
-        .lines()
-        .filter(|line| {
-            line.contains("✓ PASS")
-                || line.contains("✗ FAIL")
-                || line.contains("PASS:")
-                || line.contains("FAIL:")
-        })
-        .next_back();
+    let marker = combined.lines().rfind(|line| {
+        line.contains("✓ PASS")
+            || line.contains("✗ FAIL")
+            || line.contains("PASS:")
+            || line.contains("FAIL:")
+    });
 
     let status_code = output.status.code().unwrap_or(-1);
     if status_code == 124 {
@@ -103,6 +100,12 @@ fn scenario_orbit_pair() {
     run_scenario_and_assert_pass("orbit_pair", "orbit_pair — orbit stable", 180);
 }
 
+#[test]
+#[ignore = "slow integration: runs release binary extended scenario"]
+fn scenario_ring_formation() {
+    run_scenario_and_assert_pass("ring_formation", "ring_formation — ring formed", 180);
+}
+
 #[test]
 #[ignore = "slow integration: runs release binary extended scenario"]
 fn scenario_enemy_combat_scripted() {
@@ -113,6 +116,16 @@ fn scenario_enemy_combat_scripted() {
     );
 }
 
+#[test]
+#[ignore = "slow integration: runs release binary extended scenario"]
+fn scenario_enemy_planet_avoidance() {
+    run_scenario_and_assert_pass(
+        "enemy_planet_avoidance",
+        "enemy_planet_avoidance — no enemy struck the planet",
+        180,
+    );
+}
+
 #[test]
 #[ignore = "slow integration: runs release binary extended scenario"]
 fn scenario_baseline_100() {
@@ -55,15 +55,12 @@ fn run_scenario_and_assert_pass(scenario: &str, expected_fragment: &str) {
     let log_path = log_root().join(format!("{scenario}.log"));
     fs::write(&log_path, &combined).expect("failed writing scenario log");
 
-    let marker = combined
-        .lines()
-        .filter(|line| {
-            line.contains("✓ PASS")
-                || line.contains("✗ FAIL")
-                || line.contains("PASS:")
-                || line.contains("FAIL:")
-        })
-        .next_back();
+    let marker = combined.lines().rfind(|line| {
+        line.contains("✓ PASS")
+            || line.contains("✗ FAIL")
+            || line.contains("PASS:")
+            || line.contains("FAIL:")
+    });
 
     let status_code = output.status.code().unwrap_or(-1);
 
@@ -127,6 +124,12 @@ fn scenario_near_miss() {
     run_scenario_and_assert_pass("near_miss", "passed each other without merging");
 }
 
+#[test]
+#[ignore = "slow integration: runs release binary scenario"]
+fn scenario_missile_split() {
+    run_scenario_and_assert_pass("missile_split", "Missile split held without re-merging");
+}
+
 #[test]
 #[ignore = "slow integration: runs release binary scenario"]
 fn scenario_gravity() {